@@ -2,8 +2,8 @@
 
 use anyhow::{Context, Result};
 use colored::Colorize;
-use std::path::{Path, PathBuf};
-use std::process::Command;
+use linkml_service::generator::{GeneratorRegistry, PostProcessorPipeline, rust_ast};
+use std::path::Path;
 
 /// Generation options
 pub struct GenerateOptions {
@@ -14,6 +14,24 @@ pub struct GenerateOptions {
     pub verbose: bool,
 }
 
+impl GenerateOptions {
+    /// Derives requested on top of the generator's own
+    fn derives(&self) -> Vec<&'static str> {
+        let mut derives = Vec::new();
+        if self.debug {
+            derives.push("Debug");
+        }
+        if self.clone {
+            derives.push("Clone");
+        }
+        if self.serde {
+            derives.push("Serialize");
+            derives.push("Deserialize");
+        }
+        derives
+    }
+}
+
 /// Generate Rust code from LinkML schemas
 pub async fn generate_code(
     schema_dir: &Path,
@@ -34,15 +52,18 @@ pub async fn generate_code(
 
     // Create mod.rs
     let mod_path = output_dir.join("mod.rs");
-    let mut mod_content = String::from("//! Generated LinkML types
+    let mut mod_content = String::from(
+        "//! Generated LinkML types
 //!
 //! This file was automatically generated by cargo-linkml.
 //! Do not edit manually.
 
-");
+",
+    );
 
     println!("Generating Rust code for {} schema(s)...", schemas.len());
 
+    let registry = GeneratorRegistry::with_defaults().await;
     let mut success_count = 0;
     let mut modules = Vec::new();
 
@@ -51,74 +72,79 @@ pub async fn generate_code(
 
         // Validate first if requested
         if options.validate_first {
-            match validate_schema(schema).await {
-                Ok(_) => {}
+            match crate::validator::check_schema(schema) {
+                Ok(result) if result.errors.is_empty() => {}
+                Ok(result) => {
+                    eprintln!(
+                        "{} Validation failed for {}: {}",
+                        "✗".red(),
+                        relative_path.display(),
+                        result.errors.join("; ")
+                    );
+                    continue;
+                }
                 Err(e) => {
-                    eprintln!("{} Validation failed for {}: {}", "✗".red(), relative_path.display(), e);
+                    eprintln!(
+                        "{} Validation failed for {}: {}",
+                        "✗".red(),
+                        relative_path.display(),
+                        e
+                    );
                     continue;
                 }
             }
         }
 
-        match generate_from_schema(schema, output_dir, &options).await {
+        match generate_from_schema(&registry, schema, output_dir, &options).await {
             Ok(module_name) => {
-                println!("{} Generated: {} → {}.rs", "✓".green(), relative_path.display(), module_name);
+                println!(
+                    "{} Generated: {} → {}.rs",
+                    "✓".green(),
+                    relative_path.display(),
+                    module_name
+                );
                 modules.push(module_name);
                 success_count += 1;
             }
             Err(e) => {
-                eprintln!("{} Failed to generate from {}: {}", "✗".red(), relative_path.display(), e);
+                eprintln!(
+                    "{} Failed to generate from {}: {:#}",
+                    "✗".red(),
+                    relative_path.display(),
+                    e
+                );
             }
         }
     }
 
     // Write mod.rs
     for module in &modules {
-        mod_content.push_str(&format!("pub mod {};
-", module));
+        mod_content.push_str(&format!("pub mod {};\n", module));
     }
 
     if !modules.is_empty() {
-        mod_content.push_str("
-// Re-exports
-");
+        mod_content.push_str("\n// Re-exports\n");
         for module in &modules {
-            mod_content.push_str(&format!("pub use {}::*;
-", module));
+            mod_content.push_str(&format!("pub use {}::*;\n", module));
         }
     }
 
     std::fs::write(&mod_path, mod_content)?;
 
     // Summary
-    println!("
-{} Generated {}/{} schemas successfully",
-             "Summary:".bold(),
-             success_count,
-             schemas.len());
+    println!(
+        "\n{} Generated {}/{} schemas successfully",
+        "Summary:".bold(),
+        success_count,
+        schemas.len()
+    );
     println!("Output directory: {}", output_dir.display());
 
-    // Run rustfmt on generated code
-    if success_count > 0 {
-        println!("
-Formatting generated code...");
-        format_generated_code(output_dir)?;
-    }
-
-    Ok(())
-}
-
-/// Validate a schema before generation
-async fn validate_schema(schema: &Path) -> Result<()> {
-    let mut cmd = Command::new("linkml");
-    cmd.arg("validate");
-    cmd.arg(schema);
-
-    let output = cmd.output()?;
-
-    if !output.status.success() {
-        let error = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("{}", error.trim());
+    if success_count < schemas.len() {
+        anyhow::bail!(
+            "Generation failed for {} schema(s)",
+            schemas.len() - success_count
+        );
     }
 
     Ok(())
@@ -126,121 +152,97 @@ async fn validate_schema(schema: &Path) -> Result<()> {
 
 /// Generate code from a single schema
 async fn generate_from_schema(
-    schema: &Path,
+    registry: &GeneratorRegistry,
+    schema_path: &Path,
     output_dir: &Path,
     options: &GenerateOptions,
 ) -> Result<String> {
     // Determine module name from schema file
-    let stem = schema.file_stem()
+    let stem = schema_path
+        .file_stem()
         .and_then(|s| s.to_str())
         .context("Invalid schema filename")?;
 
-    let module_name = stem.replace('-', "_").replace('.', "_").to_lowercase();
+    let module_name = stem.replace(['-', '.'], "_").to_lowercase();
     let output_file = output_dir.join(format!("{}.rs", module_name));
 
-    // Generate using LinkML
-    let mut cmd = Command::new("linkml");
-    cmd.arg("generate");
-    cmd.arg("-t").arg("rust");
-    cmd.arg("-o").arg(&output_file);
-
-    // Add derive options
-    let mut derives = vec![];
-    if options.serde {
-        derives.push("Serialize");
-        derives.push("Deserialize");
-    }
-    if options.debug {
-        derives.push("Debug");
-    }
-    if options.clone {
-        derives.push("Clone");
+    let schema = crate::load_schema(schema_path)?;
+    let generator = registry
+        .get("rust")
+        .await
+        .context("Rust generator is not registered")?;
+    if options.verbose {
+        println!("  Using generator '{}'", generator.name());
     }
+    generator.validate_schema(&schema)?;
+    let mut content = generator.generate(&schema)?;
 
+    let derives = options.derives();
     if !derives.is_empty() {
-        cmd.arg("--derives").arg(derives.join(","));
+        let derives = rust_ast::parse_derives(&derives.join(","))?;
+        content = rust_ast::add_derives_to_source(&content, &derives)?;
     }
 
-    cmd.arg(schema);
-
-    let output = cmd.output()?;
-
-    if !output.status.success() {
-        let error = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("Generation failed: {}", error);
-    }
-
-    // Post-process the generated file
-    post_process_generated_code(&output_file, options)?;
+    let content = post_process_generated_code(&content, options);
+    let content = PostProcessorPipeline::with_defaults().process("rs", &content)?;
+    std::fs::write(&output_file, content)
+        .with_context(|| format!("Failed to write {}", output_file.display()))?;
 
     Ok(module_name)
 }
 
 /// Post-process generated Rust code
-fn post_process_generated_code(file: &Path, options: &GenerateOptions) -> Result<()> {
-    let content = std::fs::read_to_string(file)?;
+fn post_process_generated_code(content: &str, options: &GenerateOptions) -> String {
     let mut lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
 
     // Add file header if not present
     if !lines.is_empty() && !lines[0].starts_with("//!") {
-        lines.insert(0, format!("//! Generated from LinkML schema"));
-        lines.insert(1, format!("//!"));
-        lines.insert(2, format!("//! This file was automatically generated by cargo-linkml."));
-        lines.insert(3, format!("//! Do not edit manually."));
+        lines.insert(0, "//! Generated from LinkML schema".to_string());
+        lines.insert(1, "//!".to_string());
+        lines.insert(
+            2,
+            "//! This file was automatically generated by cargo-linkml.".to_string(),
+        );
+        lines.insert(3, "//! Do not edit manually.".to_string());
         lines.insert(4, String::new());
     }
 
     // Add imports if using serde
-    if options.serde {
-        let mut found_serde = false;
-        for line in &lines {
-            if line.contains("use serde") {
-                found_serde = true;
-                break;
-            }
-        }
-
-        if !found_serde {
-            // Find where to insert (after doc comments)
-            let mut insert_pos = 0;
-            for (i, line) in lines.iter().enumerate() {
-                if !line.starts_with("//") && !line.is_empty() {
-                    insert_pos = i;
-                    break;
-                }
-            }
-
-            lines.insert(insert_pos, String::from("use serde::{Serialize, Deserialize};"));
-            lines.insert(insert_pos + 1, String::new());
-        }
+    if options.serde && !lines.iter().any(|line| line.contains("use serde")) {
+        // Insert after the leading doc comments
+        let insert_pos = lines
+            .iter()
+            .position(|line| !line.starts_with("//") && !line.is_empty())
+            .unwrap_or(lines.len());
+
+        lines.insert(
+            insert_pos,
+            String::from("use serde::{Serialize, Deserialize};"),
+        );
+        lines.insert(insert_pos + 1, String::new());
     }
 
-    // Write back
-    let content = lines.join("
-");
-    std::fs::write(file, content)?;
-
-    Ok(())
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
 }
 
-/// Format generated code using rustfmt
-fn format_generated_code(output_dir: &Path) -> Result<()> {
-    let mut cmd = Command::new("rustfmt");
-    cmd.arg("--edition").arg("2024");
-    cmd.arg(output_dir.join("*.rs"));
-
-    match cmd.output() {
-        Ok(output) => {
-            if output.status.success() {
-                println!("{} Code formatted successfully", "✓".green());
-            } else {
-                println!("{} rustfmt reported issues", "⚠".yellow());
-            }
-        }
-        Err(_) => {
-            println!("{} rustfmt not available, skipping formatting", "⚠".yellow());
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_post_process_adds_header_and_serde_import() {
+        let options = GenerateOptions {
+            serde: true,
+            debug: true,
+            clone: false,
+            validate_first: false,
+            verbose: false,
+        };
+        let processed = post_process_generated_code("pub struct Person {}\n", &options);
+        assert!(processed.starts_with("//! Generated from LinkML schema"));
+        assert!(processed.contains("use serde::{Serialize, Deserialize};\n\npub struct Person"));
+        assert_eq!(options.derives(), vec!["Debug", "Serialize", "Deserialize"]);
     }
-
-    Ok(())
 }
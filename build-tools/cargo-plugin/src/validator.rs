@@ -2,8 +2,8 @@
 
 use anyhow::Result;
 use colored::Colorize;
+use linkml_service::schema::{LintConfig, LintOptions, LintRuleRegistry, SchemaLinter, Severity};
 use std::path::Path;
-use std::process::Command;
 
 /// Validate LinkML schemas
 pub async fn validate_schemas(
@@ -28,29 +28,41 @@ pub async fn validate_schemas(
     for schema in &schemas {
         let relative_path = schema.strip_prefix(schema_dir).unwrap_or(schema);
 
-        match validate_schema(schema, verbose).await {
-            Ok(ValidationResult { warnings }) => {
+        match check_schema(schema) {
+            Ok(result) if result.errors.is_empty() => {
                 println!("{} Valid: {}", "✓".green(), relative_path.display());
-                if !warnings.is_empty() {
-                    warning_count += warnings.len();
-                    for warning in warnings {
-                        println!("  {} {}", "⚠".yellow(), warning);
+                warning_count += result.warnings.len();
+                for warning in &result.warnings {
+                    println!("  {} {}", "⚠".yellow(), warning);
+                }
+                if verbose {
+                    for info in &result.infos {
+                        println!("  {} {}", "ℹ".blue(), info);
                     }
                 }
             }
+            Ok(result) => {
+                error_count += 1;
+                eprintln!("{} Invalid: {}", "✗".red(), relative_path.display());
+                for error in &result.errors {
+                    eprintln!("  {}", error);
+                }
+            }
             Err(e) => {
                 error_count += 1;
                 eprintln!("{} Invalid: {}", "✗".red(), relative_path.display());
-                eprintln!("  {}", e);
+                eprintln!("  {:#}", e);
             }
         }
     }
 
     // Summary
-    println!("
-{}", "Summary:".bold());
+    println!("\n{}", "Summary:".bold());
     println!("  Total schemas: {}", schemas.len());
-    println!("  Valid: {}", (schemas.len() - error_count).to_string().green());
+    println!(
+        "  Valid: {}",
+        (schemas.len() - error_count).to_string().green()
+    );
     if error_count > 0 {
         println!("  Invalid: {}", error_count.to_string().red());
     }
@@ -66,35 +78,44 @@ pub async fn validate_schemas(
 }
 
 /// Result of schema validation
-struct ValidationResult {
-    warnings: Vec<String>,
+pub struct ValidationResult {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+    pub infos: Vec<String>,
 }
 
-/// Validate a single schema
-async fn validate_schema(schema: &Path, verbose: bool) -> Result<ValidationResult> {
-    let mut cmd = Command::new("linkml");
-    cmd.arg("validate");
-
-    if verbose {
-        cmd.arg("--verbose");
-    }
-
-    cmd.arg(schema);
-
-    let output = cmd.output()?;
-
-    if !output.status.success() {
-        let error = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("{}", error.trim());
+/// Parse and lint a single schema
+///
+/// Lint rules come from the nearest `.linkmllint.yaml`, as with `linkml lint`.
+/// Parse failures are returned as errors; lint findings are sorted into the result.
+pub fn check_schema(schema_path: &Path) -> Result<ValidationResult> {
+    let schema = crate::load_schema(schema_path)?;
+
+    let schema_dir = schema_path.parent().unwrap_or_else(|| Path::new("."));
+    let options = match LintConfig::discover(schema_dir) {
+        Some(config) => LintOptions::from_config(
+            &LintConfig::from_file(&config)?,
+            &LintRuleRegistry::default(),
+        )?,
+        None => LintOptions::default(),
+    };
+    let lint = SchemaLinter::new(options).lint(&schema)?;
+
+    let mut result = ValidationResult {
+        errors: Vec::new(),
+        warnings: Vec::new(),
+        infos: Vec::new(),
+    };
+    for issue in lint.issues {
+        let message = match &issue.element_name {
+            Some(element) => format!("[{}] {}: {}", issue.rule, element, issue.message),
+            None => format!("[{}] {}", issue.rule, issue.message),
+        };
+        match issue.severity {
+            Severity::Error => result.errors.push(message),
+            Severity::Warning => result.warnings.push(message),
+            Severity::Info => result.infos.push(message),
+        }
     }
-
-    // Parse warnings from output
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let warnings: Vec<String> = stdout
-        .lines()
-        .filter(|line| line.contains("WARNING") || line.contains("Warning"))
-        .map(|line| line.trim().to_string())
-        .collect();
-
-    Ok(ValidationResult { warnings })
+    Ok(result)
 }
@@ -0,0 +1,383 @@
+//! Generate Rust types from `LinkML` schemas in `build.rs`
+//!
+//! Regenerating the types at build time means generated code does not have
+//! to be committed. In the `main` of `build.rs`:
+//!
+//! ```no_run
+//! let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+//! linkml_build::generate("schemas/", out_dir).expect("LinkML code generation failed");
+//! ```
+//!
+//! and in the crate:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/linkml.rs"));
+//! ```
+//!
+//! Each schema becomes a module named after its file, so
+//! `schemas/person.linkml.yaml` is available as `person`. The generated code
+//! uses `serde` and `thiserror`, which the crate must depend on.
+//!
+//! Output is deterministic: schemas are processed in path order, the code
+//! contains no timestamps or absolute paths, and the output file is only
+//! rewritten when its content changes. `cargo:rerun-if-changed` lines are
+//! emitted for the schema directory and every schema, so cargo reruns the
+//! build script exactly when a schema is added, removed or edited.
+
+use linkml_service::LinkMLError;
+use linkml_service::generator::{Generator, GeneratorOptions, RustGenerator, rust_ast};
+use linkml_service::parser::Parser;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use walkdir::WalkDir;
+
+/// File written to the output directory by [`generate`]
+pub const DEFAULT_OUTPUT_FILE: &str = "linkml.rs";
+
+/// Errors raised while generating code
+#[derive(Debug, Error)]
+pub enum BuildError {
+    /// Reading schemas or writing the output failed
+    #[error("I/O error on {path}: {source}")]
+    Io {
+        /// File or directory involved
+        path: PathBuf,
+        /// Underlying error
+        source: std::io::Error,
+    },
+
+    /// An include or exclude pattern is not a valid glob
+    #[error("Invalid schema pattern '{pattern}': {source}")]
+    Pattern {
+        /// The pattern
+        pattern: String,
+        /// Underlying error
+        source: glob::PatternError,
+    },
+
+    /// A schema could not be parsed or turned into Rust code
+    #[error("Failed to generate code for {path}: {source}")]
+    Schema {
+        /// Schema file
+        path: PathBuf,
+        /// Underlying error
+        source: LinkMLError,
+    },
+
+    /// Two schemas map to the same module name
+    #[error("Schemas {first} and {second} both map to module '{module}'")]
+    DuplicateModule {
+        /// Module name
+        module: String,
+        /// First schema
+        first: PathBuf,
+        /// Second schema
+        second: PathBuf,
+    },
+}
+
+/// Result type for this crate
+pub type Result<T> = std::result::Result<T, BuildError>;
+
+/// Generate Rust types for all schemas in `schema_dir` into `out_dir`
+///
+/// Uses the defaults of [`Builder`]; returns the path of the written file.
+///
+/// # Errors
+///
+/// Returns an error if a schema cannot be read, parsed or generated, or if
+/// the output cannot be written
+pub fn generate(schema_dir: impl AsRef<Path>, out_dir: impl AsRef<Path>) -> Result<PathBuf> {
+    Builder::new(schema_dir).generate(out_dir)
+}
+
+/// Configurable code generation for `build.rs`
+#[derive(Debug, Clone)]
+pub struct Builder {
+    schema_dir: PathBuf,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    derives: Vec<String>,
+    output_file: String,
+    rerun_if_changed: bool,
+}
+
+impl Builder {
+    /// Create a builder for the schemas in `schema_dir`
+    ///
+    /// By default all `*.yaml` and `*.yml` files below the directory are
+    /// used and `cargo:rerun-if-changed` lines are emitted.
+    pub fn new(schema_dir: impl AsRef<Path>) -> Self {
+        Self {
+            schema_dir: schema_dir.as_ref().to_path_buf(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            derives: Vec::new(),
+            output_file: DEFAULT_OUTPUT_FILE.to_string(),
+            rerun_if_changed: true,
+        }
+    }
+
+    /// Only use schemas matching this glob (relative to the schema directory)
+    #[must_use]
+    pub fn with_include(mut self, pattern: impl Into<String>) -> Self {
+        self.include.push(pattern.into());
+        self
+    }
+
+    /// Skip schemas matching this glob (relative to the schema directory)
+    #[must_use]
+    pub fn with_exclude(mut self, pattern: impl Into<String>) -> Self {
+        self.exclude.push(pattern.into());
+        self
+    }
+
+    /// Add a derive to every generated struct and enum (e.g. `PartialEq`)
+    #[must_use]
+    pub fn with_derive(mut self, derive: impl Into<String>) -> Self {
+        self.derives.push(derive.into());
+        self
+    }
+
+    /// Name of the file written to the output directory
+    #[must_use]
+    pub fn with_output_file(mut self, name: impl Into<String>) -> Self {
+        self.output_file = name.into();
+        self
+    }
+
+    /// Whether to print `cargo:rerun-if-changed` lines
+    #[must_use]
+    pub fn with_rerun_if_changed(mut self, enabled: bool) -> Self {
+        self.rerun_if_changed = enabled;
+        self
+    }
+
+    /// Schemas that will be generated, in path order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema directory cannot be read or a pattern
+    /// is invalid
+    pub fn schemas(&self) -> Result<Vec<PathBuf>> {
+        let include = if self.include.is_empty() {
+            compile_patterns(&["**/*.yaml".to_string(), "**/*.yml".to_string()])?
+        } else {
+            compile_patterns(&self.include)?
+        };
+        let exclude = compile_patterns(&self.exclude)?;
+
+        let mut schemas = Vec::new();
+        for entry in WalkDir::new(&self.schema_dir) {
+            let entry = entry.map_err(|e| BuildError::Io {
+                path: e.path().unwrap_or(&self.schema_dir).to_path_buf(),
+                source: e.into(),
+            })?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let relative = entry
+                .path()
+                .strip_prefix(&self.schema_dir)
+                .unwrap_or(entry.path());
+            if include.iter().any(|p| p.matches_path(relative))
+                && !exclude.iter().any(|p| p.matches_path(relative))
+            {
+                schemas.push(entry.path().to_path_buf());
+            }
+        }
+        schemas.sort();
+        Ok(schemas)
+    }
+
+    /// Render the generated code without writing it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a schema cannot be read, parsed or generated
+    pub fn render(&self) -> Result<String> {
+        let schemas = self.schemas()?;
+        let mut options = GeneratorOptions::default();
+        if !self.derives.is_empty() {
+            options = options.set_custom(rust_ast::DERIVES_OPTION, &self.derives.join(","));
+        }
+        let generator = RustGenerator::with_options(options);
+
+        let mut modules: Vec<(String, &Path)> = Vec::new();
+        let mut out = String::from("// @generated by linkml-build. Do not edit.\n");
+        for schema in &schemas {
+            let relative = schema.strip_prefix(&self.schema_dir).unwrap_or(schema);
+            let module = module_name(relative);
+            if let Some((_, first)) = modules.iter().find(|(name, _)| *name == module) {
+                return Err(BuildError::DuplicateModule {
+                    module,
+                    first: first.to_path_buf(),
+                    second: relative.to_path_buf(),
+                });
+            }
+
+            let code = generate_schema(&generator, schema)?;
+            write!(
+                out,
+                "\n// Source: {}\n#[allow(unused_imports)]\npub mod {module} {{\n{code}}}\n",
+                relative.display().to_string().replace('\\', "/")
+            )
+            .expect("write! to String should never fail");
+            modules.push((module, relative));
+        }
+        Ok(out)
+    }
+
+    /// Generate code into `out_dir`, returning the path of the written file
+    ///
+    /// The file is left untouched when its content would not change.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a schema cannot be read, parsed or generated, or
+    /// if the output cannot be written
+    pub fn generate(&self, out_dir: impl AsRef<Path>) -> Result<PathBuf> {
+        if self.rerun_if_changed {
+            println!("cargo:rerun-if-changed={}", self.schema_dir.display());
+            for schema in self.schemas()? {
+                println!("cargo:rerun-if-changed={}", schema.display());
+            }
+        }
+
+        let code = self.render()?;
+        let out_dir = out_dir.as_ref();
+        std::fs::create_dir_all(out_dir).map_err(|source| BuildError::Io {
+            path: out_dir.to_path_buf(),
+            source,
+        })?;
+        let output = out_dir.join(&self.output_file);
+        if std::fs::read_to_string(&output).is_ok_and(|existing| existing == code) {
+            return Ok(output);
+        }
+        std::fs::write(&output, code).map_err(|source| BuildError::Io {
+            path: output.clone(),
+            source,
+        })?;
+        Ok(output)
+    }
+}
+
+fn compile_patterns(patterns: &[String]) -> Result<Vec<glob::Pattern>> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            glob::Pattern::new(pattern).map_err(|source| BuildError::Pattern {
+                pattern: pattern.clone(),
+                source,
+            })
+        })
+        .collect()
+}
+
+/// Parse one schema and generate its Rust code
+fn generate_schema(generator: &RustGenerator, path: &Path) -> Result<String> {
+    let schema_error = |source| BuildError::Schema {
+        path: path.to_path_buf(),
+        source,
+    };
+    let content = std::fs::read_to_string(path).map_err(|source| BuildError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let format = if path.extension().is_some_and(|ext| ext == "json") {
+        "json"
+    } else {
+        "yaml"
+    };
+    let schema = Parser::new()
+        .parse_str(&content, format)
+        .map_err(schema_error)?;
+    generator.validate_schema(&schema).map_err(schema_error)?;
+    generator.generate(&schema).map_err(schema_error)
+}
+
+/// Module name for a schema path relative to the schema directory
+///
+/// Extensions (including a `.linkml` infix) are dropped and anything that is
+/// not alphanumeric becomes `_`: `nested/my-schema.linkml.yaml` gives
+/// `nested_my_schema`.
+fn module_name(relative: &Path) -> String {
+    let stem = relative.with_extension("");
+    let stem = stem.to_string_lossy();
+    let stem = stem.strip_suffix(".linkml").unwrap_or(&stem);
+    let mut name: String = stem
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r"
+id: https://example.org/people
+name: people
+classes:
+  Person:
+    attributes:
+      name:
+        range: string
+        required: true
+";
+
+    #[test]
+    fn test_module_name() {
+        assert_eq!(module_name(Path::new("person.yaml")), "person");
+        assert_eq!(
+            module_name(Path::new("nested/my-schema.linkml.yaml")),
+            "nested_my_schema"
+        );
+        assert_eq!(module_name(Path::new("2024.yml")), "_2024");
+    }
+
+    #[test]
+    fn test_generate_is_deterministic_and_skips_unchanged_output() {
+        let schemas = tempfile::tempdir().expect("schema directory");
+        let out = tempfile::tempdir().expect("output directory");
+        std::fs::write(schemas.path().join("people.linkml.yaml"), SCHEMA).expect("write schema");
+        std::fs::write(schemas.path().join("notes.txt"), "not a schema").expect("write notes");
+
+        let builder = Builder::new(schemas.path()).with_rerun_if_changed(false);
+        assert_eq!(builder.schemas().expect("schemas found").len(), 1);
+
+        let output = builder.generate(out.path()).expect("generate");
+        let first = std::fs::read_to_string(&output).expect("read output");
+        assert!(
+            first.contains(
+                "// Source: people.linkml.yaml\n#[allow(unused_imports)]\npub mod people {"
+            )
+        );
+        assert!(first.contains("pub struct Person"));
+        assert!(!first.contains(&schemas.path().display().to_string()));
+
+        let modified = || {
+            std::fs::metadata(&output)
+                .and_then(|metadata| metadata.modified())
+                .expect("output modification time")
+        };
+        let first_modified = modified();
+        builder.generate(out.path()).expect("regenerate");
+        assert_eq!(
+            std::fs::read_to_string(&output).expect("read output"),
+            first
+        );
+        assert_eq!(modified(), first_modified);
+    }
+}
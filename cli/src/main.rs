@@ -0,0 +1,16 @@
+//! Standalone `LinkML` command-line tool
+//!
+//! Builds the enhanced `linkml` CLI as a binary of its own. The commands are
+//! those of `linkml_service::cli_enhanced`, run on the lightweight internal
+//! defaults of
+//! [`run_standalone`](linkml_service::cli_enhanced::run_standalone) so no
+//! `RootReal` logger, timestamp or cache service has to be wired. The service
+//! is built without its default `rootreal-services` feature, so no `RootReal`
+//! service crate is compiled in either.
+
+use linkml_service::{LinkMLError, cli_enhanced};
+
+#[tokio::main]
+async fn main() -> Result<(), LinkMLError> {
+    cli_enhanced::run_standalone().await
+}
@@ -143,6 +143,9 @@ pub struct PerformanceConfig {
 
     /// Cache size in MB
     pub cache_size_mb: usize,
+
+    /// Hold schemas with interned strings ([`crate::types::interned`])
+    pub intern_strings: bool,
 }
 
 impl Default for PerformanceConfig {
@@ -154,6 +157,7 @@ impl Default for PerformanceConfig {
             stream_buffer_size: 8192,
             enable_mmap: true,
             cache_size_mb: 256,
+            intern_strings: false,
         }
     }
 }
@@ -27,6 +27,9 @@ pub struct LinkMLServiceConfig {
 
     /// Performance configuration
     pub performance: PerformanceConfig,
+
+    /// Security limits configuration
+    pub security_limits: SecurityLimitsConfig,
 }
 
 /// `TypeDB` specific configuration
@@ -77,6 +80,9 @@ pub struct ParserConfig {
 
     /// Supported schema formats
     pub supported_formats: Vec<String>,
+
+    /// Maximum import depth
+    pub max_import_depth: usize,
 }
 
 /// Validator configuration
@@ -99,6 +105,9 @@ pub struct ValidatorConfig {
 
     /// Stop on first error
     pub fail_fast: bool,
+
+    /// Compiled validator cache size
+    pub compiled_cache_size: usize,
 }
 
 /// Generator configuration
@@ -141,6 +150,12 @@ pub struct CacheConfig {
 
     /// Cache eviction policy
     pub eviction_policy: EvictionPolicy,
+
+    /// Expression cache configuration
+    pub expression_cache: ExpressionCacheConfig,
+
+    /// Rule cache configuration
+    pub rule_cache: RuleCacheConfig,
 }
 
 /// Cache eviction policy
@@ -152,11 +167,52 @@ pub enum EvictionPolicy {
     Ttl,
 }
 
+/// Expression cache configuration
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExpressionCacheConfig {
+    /// Maximum cached expressions
+    pub max_entries: usize,
+
+    /// Expression cache TTL in seconds
+    pub ttl_seconds: u64,
+}
+
+/// Rule cache configuration
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RuleCacheConfig {
+    /// Maximum cached rules
+    pub max_entries: usize,
+
+    /// Rule cache TTL in seconds
+    pub ttl_seconds: u64,
+}
+
+/// Performance feature flags
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
+pub enum PerformanceFeature {
+    /// Performance monitoring
+    #[default]
+    Monitoring,
+    /// String interning optimization
+    StringInterning,
+    /// Background task processing
+    BackgroundTasks,
+    /// Cache warming on startup
+    CacheWarming,
+}
+
+/// Performance features configuration
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct PerformanceFeatures {
+    /// Enabled performance features
+    pub enabled_features: Vec<PerformanceFeature>,
+}
+
 /// Performance configuration
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PerformanceConfig {
-    /// Enable performance monitoring
-    pub enable_monitoring: bool,
+    /// Performance features
+    pub features: PerformanceFeatures,
 
     /// Memory limit in bytes
     pub memory_limit_bytes: Option<u64>,
@@ -164,17 +220,114 @@ pub struct PerformanceConfig {
     /// CPU limit (percentage)
     pub cpu_limit_percent: Option<u8>,
 
-    /// Enable string interning
-    pub enable_string_interning: bool,
-
     /// String intern pool size
     pub string_pool_size: usize,
+
+    /// Background task interval in seconds
+    pub background_task_interval_secs: u64,
+
+    /// String cache configuration
+    pub string_cache: StringCacheConfig,
+
+    /// Memory pool configuration
+    pub memory_pool: MemoryPoolConfig,
+
+    /// Cache TTL levels configuration
+    pub cache_ttl_levels: CacheTtlLevelsConfig,
+}
+
+/// Cache TTL levels configuration
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CacheTtlLevelsConfig {
+    /// L1 cache TTL in seconds
+    pub l1_seconds: u64,
+
+    /// L2 cache TTL in seconds
+    pub l2_seconds: u64,
+
+    /// L3 cache TTL in seconds
+    pub l3_seconds: u64,
+
+    /// Minimum TTL in seconds
+    pub min_ttl_seconds: u64,
+
+    /// Maximum TTL in seconds
+    pub max_ttl_seconds: u64,
+}
+
+/// String cache configuration
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StringCacheConfig {
+    /// Maximum cached strings
+    pub max_entries: usize,
+
+    /// Maximum string length to cache
+    pub max_string_length: usize,
+}
+
+/// Memory pool configuration
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MemoryPoolConfig {
+    /// Maximum pool size in bytes
+    pub max_size_bytes: usize,
+
+    /// Allocation chunk size
+    pub chunk_size_bytes: usize,
+}
+
+/// Security limits configuration
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SecurityLimitsConfig {
+    /// Maximum string length
+    pub max_string_length: usize,
+
+    /// Maximum expression depth
+    pub max_expression_depth: usize,
+
+    /// Maximum constraint count
+    pub max_constraint_count: usize,
+
+    /// Maximum cache entries
+    pub max_cache_entries: usize,
+
+    /// Maximum function arguments
+    pub max_function_args: usize,
+
+    /// Maximum identifier length
+    pub max_identifier_length: usize,
+
+    /// Maximum `JSON` size in bytes
+    pub max_json_size_bytes: usize,
+
+    /// Maximum slots per class
+    pub max_slots_per_class: usize,
+
+    /// Maximum classes per schema
+    pub max_classes_per_schema: usize,
+
+    /// Maximum validation time in milliseconds
+    pub max_validation_time_ms: u64,
+
+    /// Maximum memory usage in bytes
+    pub max_memory_usage_bytes: usize,
+
+    /// Maximum parallel validators
+    pub max_parallel_validators: usize,
+
+    /// Maximum cache memory in bytes
+    pub max_cache_memory_bytes: usize,
+
+    /// Maximum expression evaluation time in milliseconds
+    pub max_expression_time_ms: u64,
+
+    /// Maximum validation errors to collect
+    pub max_validation_errors: usize,
 }
 
 impl Validate for LinkMLServiceConfig {
     type Error = ConfigurationError;
 
-    fn validate(&self) -> std::result::Result<(), Self::Error> {
+    fn validate(&self) -> Result<(), Self::Error> {
         // Validate TypeDB config
         if self.typedb.server_address.is_empty() {
             return Err(ConfigurationError::validation_error(
@@ -230,6 +383,13 @@ impl Validate for LinkMLServiceConfig {
             ));
         }
 
+        // Validate security limits
+        if self.security_limits.max_string_length == 0 {
+            return Err(ConfigurationError::validation_error(
+                "Max string length must be greater than 0".to_string(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -258,6 +418,7 @@ impl Default for ParserConfig {
             cache_ttl_seconds: 3600,
             max_file_size_bytes: 10 * 1024 * 1024, // 10MB
             supported_formats: vec!["yaml".to_string(), "yml".to_string(), "json".to_string()],
+            max_import_depth: 10,
         }
     }
 }
@@ -271,6 +432,7 @@ impl Default for ValidatorConfig {
             timeout_ms: 60000,
             max_errors: 100,
             fail_fast: false,
+            compiled_cache_size: 10000,
         }
     }
 }
@@ -293,18 +455,97 @@ impl Default for CacheConfig {
             ttl_seconds: 3600,
             enable_compression: false,
             eviction_policy: EvictionPolicy::Lru,
+            expression_cache: ExpressionCacheConfig::default(),
+            rule_cache: RuleCacheConfig::default(),
+        }
+    }
+}
+
+impl Default for ExpressionCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 1000,
+            ttl_seconds: 3600,
+        }
+    }
+}
+
+impl Default for RuleCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 1000,
+            ttl_seconds: 3600,
         }
     }
 }
 
 impl Default for PerformanceConfig {
     fn default() -> Self {
+        use PerformanceFeature::{BackgroundTasks, Monitoring, StringInterning};
+
         Self {
-            enable_monitoring: true,
+            features: PerformanceFeatures {
+                enabled_features: vec![Monitoring, StringInterning, BackgroundTasks],
+            },
             memory_limit_bytes: None,
             cpu_limit_percent: None,
-            enable_string_interning: true,
             string_pool_size: 10000,
+            background_task_interval_secs: 3600,
+            string_cache: StringCacheConfig::default(),
+            memory_pool: MemoryPoolConfig::default(),
+            cache_ttl_levels: CacheTtlLevelsConfig::default(),
+        }
+    }
+}
+
+impl Default for StringCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 100_000,
+            max_string_length: 10_000,
+        }
+    }
+}
+
+impl Default for MemoryPoolConfig {
+    fn default() -> Self {
+        Self {
+            max_size_bytes: 10 * 1024 * 1024, // 10MB
+            chunk_size_bytes: 4096,
+        }
+    }
+}
+
+impl Default for CacheTtlLevelsConfig {
+    fn default() -> Self {
+        Self {
+            l1_seconds: 300,          // 5 minutes
+            l2_seconds: 3600,         // 1 hour
+            l3_seconds: 86400,        // 24 hours
+            min_ttl_seconds: 60,      // 1 minute
+            max_ttl_seconds: 604_800, // 7 days
+        }
+    }
+}
+
+impl Default for SecurityLimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_string_length: 1_000_000, // 1MB
+            max_expression_depth: 100,
+            max_constraint_count: 1000,
+            max_cache_entries: 10_000,
+            max_function_args: 20,
+            max_identifier_length: 256,
+            max_json_size_bytes: 10_000_000, // 10MB
+            max_slots_per_class: 1000,
+            max_classes_per_schema: 10_000,
+            max_validation_time_ms: 30_000,        // 30 seconds
+            max_memory_usage_bytes: 1_000_000_000, // 1GB
+            max_parallel_validators: 100,
+            max_cache_memory_bytes: 100_000_000, // 100MB
+            max_expression_time_ms: 1000,        // 1 second
+            max_validation_errors: 1000,
         }
     }
 }
@@ -318,7 +559,8 @@ impl LinkMLServiceConfig {
         config.typedb.server_address = String::from("localhost:1729");
         config.validator.fail_fast = true;
         config.cache.max_entries = 100;
-        config.performance.enable_monitoring = true;
+        // Performance monitoring is already enabled by default
+        config.security_limits.max_validation_time_ms = 1000; // More lenient for dev
         config
     }
 
@@ -331,6 +573,11 @@ impl LinkMLServiceConfig {
         config.validator.thread_count = 1;
         config.cache.max_entries = 10;
         config
+            .performance
+            .features
+            .enabled_features
+            .retain(|f| !matches!(f, PerformanceFeature::BackgroundTasks));
+        config
     }
 
     /// Production environment configuration
@@ -341,7 +588,12 @@ impl LinkMLServiceConfig {
         config.typedb.max_retries = 5;
         config.validator.enable_parallel = true;
         config.cache.enable_compression = true;
-        config.performance.enable_string_interning = true;
+        // String interning and cache warming already enabled by default
+        config
+            .performance
+            .features
+            .enabled_features
+            .push(PerformanceFeature::CacheWarming);
         config
     }
 }
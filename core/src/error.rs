@@ -1,5 +1,6 @@
 //! Error types for `LinkML` operations
 
+use crate::error_codes::ErrorCode;
 use thiserror::Error;
 use timestamp_core;
 
@@ -215,6 +216,25 @@ impl LinkMLError {
         Self::parse(message)
     }
 
+    /// Stable machine-readable code for this error
+    #[must_use]
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::ParseError { .. } => ErrorCode::SchemaParse,
+            Self::SchemaValidationError { .. } => ErrorCode::SchemaValidation,
+            Self::DataValidationError { .. } => ErrorCode::DataValidation,
+            Self::ImportError { .. } => ErrorCode::UnresolvedImport,
+            Self::PatternError { .. } => ErrorCode::InvalidPattern,
+            Self::CoercionError { .. } => ErrorCode::CoercionFailed,
+            Self::ConfigError(_) => ErrorCode::Configuration,
+            Self::IoError(_) => ErrorCode::Io,
+            Self::SerializationError(_) => ErrorCode::Serialization,
+            Self::ServiceError(_) => ErrorCode::Service,
+            Self::NotImplemented(_) => ErrorCode::NotImplemented,
+            Self::Other { .. } => ErrorCode::Internal,
+        }
+    }
+
     /// Create a generic error with source
     #[must_use]
     pub fn other_with_source<E>(message: impl Into<String>, source: E) -> Self
@@ -294,6 +314,16 @@ mod tests {
         assert!(display.contains("File not found"));
     }
 
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(
+            LinkMLError::import("common.yaml", "File not found").code(),
+            ErrorCode::UnresolvedImport
+        );
+        assert_eq!(LinkMLError::parse("bad").code().as_str(), "LML2001");
+        assert_eq!(LinkMLError::config("bad").code(), ErrorCode::Configuration);
+    }
+
     #[test]
     fn test_error_conversions() {
         let json_err = serde_json::from_str::<serde_json::Value>("invalid").unwrap_err();
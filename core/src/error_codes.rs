@@ -0,0 +1,473 @@
+//! Stable, machine-readable error codes
+//!
+//! Every [`LinkMLError`](crate::error::LinkMLError) and every validation issue
+//! maps to an [`ErrorCode`] such as `LML1001` (pattern mismatch) or `LML2004`
+//! (unresolved import). Codes are stable across releases: new codes may be
+//! added, but existing codes are never renumbered or reused, so downstream
+//! systems can branch on them instead of parsing messages.
+//!
+//! Codes are grouped by their first digit:
+//!
+//! - `LML1xxx`: data validation failures
+//! - `LML2xxx`: schema errors
+//! - `LML3xxx`: configuration and runtime errors
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+/// Broad category of an error code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    /// Data does not conform to the schema (`LML1xxx`)
+    DataValidation,
+    /// The schema itself is invalid or cannot be loaded (`LML2xxx`)
+    Schema,
+    /// Configuration, I/O and service failures (`LML3xxx`)
+    Runtime,
+}
+
+impl ErrorCategory {
+    /// Human-readable category title
+    #[must_use]
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::DataValidation => "Data validation",
+            Self::Schema => "Schema",
+            Self::Runtime => "Configuration and runtime",
+        }
+    }
+}
+
+macro_rules! error_codes {
+    ($(
+        $(#[doc = $doc:literal])*
+        $variant:ident = $code:literal, $name:literal, $category:ident;
+    )*) => {
+        /// Stable machine-readable error code
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[non_exhaustive]
+        pub enum ErrorCode {
+            $(
+                $(#[doc = $doc])*
+                $variant,
+            )*
+        }
+
+        impl ErrorCode {
+            /// Every registered error code, in numeric order
+            pub const ALL: &'static [ErrorCode] = &[$(ErrorCode::$variant),*];
+
+            /// The code as a string, e.g. `LML1001`
+            #[must_use]
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $code,)*
+                }
+            }
+
+            /// Snake-case identifier, e.g. `pattern_mismatch`
+            #[must_use]
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            /// Category the code belongs to
+            #[must_use]
+            pub fn category(self) -> ErrorCategory {
+                match self {
+                    $(Self::$variant => ErrorCategory::$category,)*
+                }
+            }
+
+            /// One-line description of the code
+            #[must_use]
+            pub fn summary(self) -> &'static str {
+                match self {
+                    $(Self::$variant => concat!($($doc),*).trim_ascii(),)*
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    /// Value does not match the slot `pattern` or `structured_pattern`
+    PatternMismatch = "LML1001", "pattern_mismatch", DataValidation;
+    /// Value has the wrong type for the slot range
+    TypeMismatch = "LML1002", "type_mismatch", DataValidation;
+    /// A required slot is missing or null
+    RequiredMissing = "LML1003", "required_missing", DataValidation;
+    /// Numeric value is outside `minimum_value`/`maximum_value`
+    RangeViolation = "LML1004", "range_violation", DataValidation;
+    /// Value is not a permissible value of the enum range
+    InvalidEnumValue = "LML1005", "invalid_enum_value", DataValidation;
+    /// Number of values violates `multivalued` or cardinality constraints
+    CardinalityViolation = "LML1006", "cardinality_violation", DataValidation;
+    /// Identifier or unique key value occurs more than once
+    UniqueKeyViolation = "LML1007", "unique_key_violation", DataValidation;
+    /// Reference to an object that does not exist
+    DanglingReference = "LML1008", "dangling_reference", DataValidation;
+    /// `any_of`, `all_of`, `exactly_one_of` or `none_of` is not satisfied
+    BooleanConstraintFailed = "LML1009", "boolean_constraint_failed", DataValidation;
+    /// A class rule is violated
+    RuleViolation = "LML1010", "rule_violation", DataValidation;
+    /// An expression (`equals_expression`, rule expression) failed or could not be evaluated
+    ExpressionFailed = "LML1011", "expression_failed", DataValidation;
+    /// String constraint (length, `equals_string`, `equals_string_in`) is violated
+    StringConstraintViolation = "LML1012", "string_constraint_violation", DataValidation;
+    /// Instance is not of an allowed class or type
+    InvalidInstanceType = "LML1013", "invalid_instance_type", DataValidation;
+    /// A conditional requirement is not met
+    ConditionalRequirementFailed = "LML1014", "conditional_requirement_failed", DataValidation;
+    /// A custom or plugin validator reported a failure
+    CustomValidationFailed = "LML1015", "custom_validation_failed", DataValidation;
+    /// Value could not be coerced to the target type
+    CoercionFailed = "LML1016", "coercion_failed", DataValidation;
+    /// Instance contains a slot that is not defined for its class
+    UnknownSlot = "LML1017", "unknown_slot", DataValidation;
+    /// A recommended slot is missing (reported as a warning)
+    RecommendedMissing = "LML1018", "recommended_missing", DataValidation;
+    /// Unit of a quantity is not valid UCUM or not commensurable with the slot `unit`
+    UnitMismatch = "LML1019", "unit_mismatch", DataValidation;
+    /// Instance uses a class, slot or enum whose status is `draft`
+    UnapprovedElement = "LML1020", "unapproved_element", DataValidation;
+    /// Instance uses a class or slot that is `deprecated`
+    DeprecatedElement = "LML1021", "deprecated_element", DataValidation;
+    /// Data validation failure without a more specific code
+    DataValidation = "LML1099", "data_validation", DataValidation;
+    /// Schema document could not be parsed
+    SchemaParse = "LML2001", "schema_parse_error", Schema;
+    /// Schema is structurally or semantically invalid
+    SchemaValidation = "LML2002", "schema_validation_error", Schema;
+    /// A pattern in the schema is not a valid regular expression
+    InvalidPattern = "LML2003", "invalid_pattern", Schema;
+    /// An import could not be resolved
+    UnresolvedImport = "LML2004", "unresolved_import", Schema;
+    /// Schema uses syntax that is not supported
+    UnsupportedSyntax = "LML2005", "unsupported_syntax", Schema;
+    /// Invalid configuration
+    Configuration = "LML3001", "configuration_error", Runtime;
+    /// File system or network I/O failure
+    Io = "LML3002", "io_error", Runtime;
+    /// Serialization or deserialization failure
+    Serialization = "LML3003", "serialization_error", Runtime;
+    /// A dependent service failed
+    Service = "LML3004", "service_error", Runtime;
+    /// The requested feature is not implemented
+    NotImplemented = "LML3005", "not_implemented", Runtime;
+    /// A constraint took longer than the validation time budget of its slot
+    TimeBudgetExceeded = "LML3006", "time_budget_exceeded", Runtime;
+    /// Unexpected internal error
+    Internal = "LML3099", "internal_error", Runtime;
+}
+
+impl ErrorCode {
+    /// Look up a code by its string form (`LML1001`) or name (`pattern_mismatch`)
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|c| c.as_str().eq_ignore_ascii_case(code) || c.name() == code)
+    }
+
+    /// Map a legacy, free-form validator code (e.g. `RULE_VIOLATION`) to a stable code
+    ///
+    /// Stable codes and names are accepted as well. Returns `None` for codes
+    /// that are not recognized.
+    #[must_use]
+    pub fn from_legacy(code: &str) -> Option<Self> {
+        if let Some(code) = Self::from_code(code) {
+            return Some(code);
+        }
+
+        let upper = code.trim().to_ascii_uppercase();
+        let mapped = match upper.as_str() {
+            "PATTERN_ERROR" | "INTERPOLATION_ERROR" | "INVALID_CONDITION_PATTERN" => {
+                Self::InvalidPattern
+            }
+            "UNSUPPORTED_SYNTAX" => Self::UnsupportedSyntax,
+            "STRUCTURED_PATTERN_VIOLATION" | "PATTERN_MISMATCH" => Self::PatternMismatch,
+            "TYPE_MISMATCH" | "RULE_TYPE_ERROR" | "RULE_EXPRESSION_TYPE_ERROR" => {
+                Self::TypeMismatch
+            }
+            "E_REQUIRED"
+            | "REQUIRED_FIELD_MISSING"
+            | "REQUIRED_FIELD_NULL"
+            | "RULE_REQUIRED_FIELD" => Self::RequiredMissing,
+            "ENUM_VIOLATION" | "CUSTOM_ENUM_VIOLATION" => Self::InvalidEnumValue,
+            "DUPLICATE_IDENTIFIER" | "DUPLICATE_UNIQUE_KEY" => Self::UniqueKeyViolation,
+            "INVALID_INSTANCE_TYPE" => Self::InvalidInstanceType,
+            "UNKNOWN_SLOT" | "UNKNOWN_FIELD" | "ADDITIONAL_PROPERTY" => Self::UnknownSlot,
+            "LENGTH_VIOLATION" | "EQUALS_STRING_IN_VIOLATION" | "RULE_EQUALS_STRING" => {
+                Self::StringConstraintViolation
+            }
+            "EQUALS_EXPRESSION_MISMATCH"
+            | "EXPRESSION_EVALUATION_ERROR"
+            | "RULE_EQUALS_EXPRESSION"
+            | "RULE_EXPRESSION_ERROR"
+            | "RULE_EXPRESSION_FAILED"
+            | "RULE_EVALUATION_ERROR" => Self::ExpressionFailed,
+            "CROSS_FIELD_VIOLATION" => Self::CustomValidationFailed,
+            "INVALID_UNIT" | "INCOMPATIBLE_UNIT" => Self::UnitMismatch,
+            other if other.ends_with("_FORMAT_INVALID") => Self::PatternMismatch,
+            other if other.starts_with("RULE_") => Self::RuleViolation,
+            other
+                if other.contains("ANY_OF")
+                    || other.contains("ALL_OF")
+                    || other.contains("EXACTLY_ONE_OF")
+                    || other.contains("NONE_OF") =>
+            {
+                Self::BooleanConstraintFailed
+            }
+            _ => return None,
+        };
+        Some(mapped)
+    }
+
+    /// Default code for issues reported by a validator, derived from its name
+    ///
+    /// Falls back to [`ErrorCode::DataValidation`] for unknown validators.
+    #[must_use]
+    pub fn for_validator(validator: &str) -> Self {
+        let name = validator.to_ascii_lowercase();
+        let has = |needle: &str| name.contains(needle);
+
+        if has("pattern") {
+            Self::PatternMismatch
+        } else if has("required") {
+            Self::RequiredMissing
+        } else if has("recommended") {
+            Self::RecommendedMissing
+        } else if has("permissible") || has("enum") {
+            Self::InvalidEnumValue
+        } else if has("multivalued") || has("cardinality") {
+            Self::CardinalityViolation
+        } else if has("unique") || has("identifier") {
+            Self::UniqueKeyViolation
+        } else if has("status") {
+            Self::UnapprovedElement
+        } else if has("deprecat") {
+            Self::DeprecatedElement
+        } else if has("unit") {
+            Self::UnitMismatch
+        } else if has("reference") {
+            Self::DanglingReference
+        } else if has("boolean")
+            || has("any_of")
+            || has("all_of")
+            || has("exactly_one")
+            || has("none_of")
+        {
+            Self::BooleanConstraintFailed
+        } else if has("conditional") {
+            Self::ConditionalRequirementFailed
+        } else if has("rule") {
+            Self::RuleViolation
+        } else if has("expression") {
+            Self::ExpressionFailed
+        } else if has("string") || has("length") {
+            Self::StringConstraintViolation
+        } else if has("instance") {
+            Self::InvalidInstanceType
+        } else if has("range") {
+            Self::RangeViolation
+        } else if has("type") {
+            Self::TypeMismatch
+        } else if has("custom") {
+            Self::CustomValidationFailed
+        } else {
+            Self::DataValidation
+        }
+    }
+
+    /// Documentation anchor for the code, e.g. `lml1001`
+    #[must_use]
+    pub fn anchor(self) -> String {
+        self.as_str().to_ascii_lowercase()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ErrorCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_code(s).ok_or_else(|| format!("Unknown error code '{s}'"))
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        code.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Registry entry describing an error code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ErrorCodeInfo {
+    /// The code
+    pub code: ErrorCode,
+    /// Snake-case identifier
+    pub name: &'static str,
+    /// Category
+    pub category: ErrorCategory,
+    /// One-line description
+    pub summary: &'static str,
+}
+
+/// All registered error codes with their metadata
+#[must_use]
+pub fn registry() -> Vec<ErrorCodeInfo> {
+    ErrorCode::ALL
+        .iter()
+        .map(|&code| ErrorCodeInfo {
+            code,
+            name: code.name(),
+            category: code.category(),
+            summary: code.summary(),
+        })
+        .collect()
+}
+
+/// Render the error code reference as Markdown, one table per category
+#[must_use]
+pub fn render_markdown() -> String {
+    let mut out = String::from("# LinkML Error Codes\n");
+    for category in [
+        ErrorCategory::DataValidation,
+        ErrorCategory::Schema,
+        ErrorCategory::Runtime,
+    ] {
+        // Writing to a String cannot fail
+        let _ = write!(
+            out,
+            "\n## {}\n\n| Code | Name | Description |\n|------|------|-------------|\n",
+            category.title()
+        );
+        for info in registry().iter().filter(|info| info.category == category) {
+            let _ = writeln!(
+                out,
+                "| <a id=\"{}\"></a>`{}` | `{}` | {} |",
+                info.code.anchor(),
+                info.code,
+                info.name,
+                info.summary
+            );
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_codes_are_unique_and_well_formed() {
+        let codes: HashSet<&str> = ErrorCode::ALL.iter().map(|c| c.as_str()).collect();
+        let names: HashSet<&str> = ErrorCode::ALL.iter().map(|c| c.name()).collect();
+        assert_eq!(codes.len(), ErrorCode::ALL.len());
+        assert_eq!(names.len(), ErrorCode::ALL.len());
+
+        for code in ErrorCode::ALL {
+            let digits = code.as_str().strip_prefix("LML").expect("LML prefix");
+            assert_eq!(digits.len(), 4);
+            let expected_prefix = match code.category() {
+                ErrorCategory::DataValidation => '1',
+                ErrorCategory::Schema => '2',
+                ErrorCategory::Runtime => '3',
+            };
+            assert!(digits.starts_with(expected_prefix), "{code} category");
+            assert!(!code.summary().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_stable_code_values() {
+        assert_eq!(ErrorCode::PatternMismatch.as_str(), "LML1001");
+        assert_eq!(ErrorCode::UnresolvedImport.as_str(), "LML2004");
+        assert_eq!(
+            ErrorCode::PatternMismatch.summary(),
+            "Value does not match the slot `pattern` or `structured_pattern`"
+        );
+    }
+
+    #[test]
+    fn test_parsing_and_serde() {
+        assert_eq!(
+            "lml1003".parse::<ErrorCode>(),
+            Ok(ErrorCode::RequiredMissing)
+        );
+        assert_eq!(
+            ErrorCode::from_code("unresolved_import"),
+            Some(ErrorCode::UnresolvedImport)
+        );
+        assert!("LML9999".parse::<ErrorCode>().is_err());
+
+        let json = serde_json::to_string(&ErrorCode::RangeViolation).expect("serializes");
+        assert_eq!(json, "\"LML1004\"");
+        let back: ErrorCode = serde_json::from_str(&json).expect("deserializes");
+        assert_eq!(back, ErrorCode::RangeViolation);
+    }
+
+    #[test]
+    fn test_legacy_and_validator_mapping() {
+        assert_eq!(
+            ErrorCode::from_legacy("RULE_VIOLATION"),
+            Some(ErrorCode::RuleViolation)
+        );
+        assert_eq!(
+            ErrorCode::from_legacy("ANY_OF_CONSTRAINT_FAILED"),
+            Some(ErrorCode::BooleanConstraintFailed)
+        );
+        assert_eq!(
+            ErrorCode::from_legacy("EMAIL_FORMAT_INVALID"),
+            Some(ErrorCode::PatternMismatch)
+        );
+        assert_eq!(ErrorCode::from_legacy("SOMETHING_ELSE"), None);
+
+        assert_eq!(
+            ErrorCode::for_validator("pattern_validator"),
+            ErrorCode::PatternMismatch
+        );
+        assert_eq!(
+            ErrorCode::for_validator("range_validator"),
+            ErrorCode::RangeViolation
+        );
+        assert_eq!(
+            ErrorCode::for_validator("type_validator"),
+            ErrorCode::TypeMismatch
+        );
+        assert_eq!(
+            ErrorCode::for_validator("mystery"),
+            ErrorCode::DataValidation
+        );
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let markdown = render_markdown();
+        assert!(markdown.contains("## Data validation"));
+        assert!(markdown.contains("| <a id=\"lml2004\"></a>`LML2004` | `unresolved_import` |"));
+        assert_eq!(markdown.matches("| <a id=").count(), ErrorCode::ALL.len());
+    }
+}
@@ -88,6 +88,9 @@
 /// Core error types for `LinkML` operations
 pub mod error;
 
+/// Stable machine-readable error codes
+pub mod error_codes;
+
 /// Core trait definitions for `LinkML` services
 pub mod traits;
 
@@ -104,6 +107,7 @@ pub mod validation;
 pub mod configuration;
 
 /// Enhanced configuration with full externalization
+#[deprecated(since = "2.0.0", note = "use `linkml_core::configuration`")]
 pub mod configuration_v2;
 
 /// Utility functions and helpers
@@ -122,9 +126,14 @@ pub mod metadata;
 pub mod string_pool;
 
 /// Optimized type definitions using string interning
+#[deprecated(since = "2.0.0", note = "use `linkml_core::types::interned`")]
 pub mod types_v2;
 
 /// Optimized utility functions that minimize cloning
+#[deprecated(
+    since = "2.0.0",
+    note = "use `linkml_core::utils`, or `linkml_core::utils::borrowed::get_class_slots`"
+)]
 pub mod utils_v2;
 
 /// Optimized `HashMap` utilities
@@ -135,8 +144,9 @@ pub mod schema_arc;
 
 // Re-export commonly used types
 pub use config::LinkMLConfig;
-pub use configuration_v2::LinkMLServiceConfig;
+pub use configuration::LinkMLServiceConfig;
 pub use error::{LinkMLError, Result};
+pub use error_codes::ErrorCode;
 pub use serde_json::Value;
 pub use settings::SchemaSettings;
 pub use traits::{LinkMLService, SchemaFormat, SchemaOperations, ValidationOperations};
@@ -144,11 +154,13 @@ pub use types::{
     ClassDefinition, SchemaDefinition, SlotDefinition, StructuredPattern, ValidationError,
     ValidationReport, ValidationWarning,
 };
+pub use validation::{LinkMLValidate, Validated};
 
 /// Prelude module for convenient imports
 pub mod prelude {
     pub use crate::config::LinkMLConfig;
     pub use crate::error::{LinkMLError, Result};
+    pub use crate::error_codes::ErrorCode;
     pub use crate::settings::*;
     pub use crate::traits::*;
     pub use crate::types::*;
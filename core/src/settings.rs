@@ -7,6 +7,9 @@ use crate::error::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Maximum edit distance of near-miss suggestions unless configured
+pub const DEFAULT_SUGGESTION_DISTANCE: usize = 2;
+
 /// Schema settings that control processing behavior
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct SchemaSettings {
@@ -73,6 +76,16 @@ pub struct ValidationSettings {
     /// Whether to coerce types when possible
     #[serde(skip_serializing_if = "Option::is_none")]
     pub type_coercion: Option<bool>,
+
+    /// Whether enum slots also accept values that are `is_a` descendants of
+    /// their permissible values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_descendant_values: Option<bool>,
+
+    /// Maximum edit distance of the names suggested for unknown enum values,
+    /// slots and classes; 0 disables suggestions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion_distance: Option<usize>,
 }
 
 /// Code generation settings
@@ -376,6 +389,19 @@ impl ValidationSettings {
     pub fn allows_additional_properties(&self) -> bool {
         self.allow_additional_properties.unwrap_or(true)
     }
+
+    /// Check if descendants of permissible values are accepted
+    #[must_use]
+    pub fn accepts_descendant_values(&self) -> bool {
+        self.accept_descendant_values.unwrap_or(false)
+    }
+
+    /// Maximum edit distance of near-miss suggestions
+    #[must_use]
+    pub fn suggestion_distance(&self) -> usize {
+        self.suggestion_distance
+            .unwrap_or(DEFAULT_SUGGESTION_DISTANCE)
+    }
 }
 
 impl GenerationSettings {
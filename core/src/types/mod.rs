@@ -7,6 +7,9 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 
+/// Memory-optimized counterparts of these types using interned strings
+pub mod interned;
+
 /// Schema definition - the root of a `LinkML` schema
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct SchemaDefinition {
@@ -64,6 +67,10 @@ pub struct SchemaDefinition {
     #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
     pub subsets: IndexMap<String, SubsetDefinition>,
 
+    /// Reusable groups of attributes that classes include
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub attribute_groups: IndexMap<String, AttributeGroupDefinition>,
+
     /// Default range for slots
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_range: Option<String>,
@@ -145,6 +152,10 @@ pub struct ClassDefinition {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub mixins: Vec<String>,
 
+    /// Attribute groups whose attributes are included in this class
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attribute_groups: Vec<String>,
+
     /// Slots used by this class
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub slots: Vec<String>,
@@ -206,6 +217,18 @@ pub struct ClassDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deprecated: Option<String>,
 
+    /// Class replacing this deprecated class one to one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated_element_has_exact_replacement: Option<String>,
+
+    /// Class that may replace this deprecated class
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated_element_has_possible_replacement: Option<String>,
+
+    /// Approval status, e.g. `draft`, `testing` or `production`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
     /// Todos
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub todos: Vec<String>,
@@ -367,6 +390,10 @@ pub struct SlotDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub required: Option<bool>,
 
+    /// Should this slot be set? Missing values are warnings, not errors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommended: Option<bool>,
+
     /// Is this slot multivalued?
     #[serde(skip_serializing_if = "Option::is_none")]
     pub multivalued: Option<bool>,
@@ -379,6 +406,10 @@ pub struct SlotDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<bool>,
 
+    /// Does the value of this slot name the class of its object?
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub designates_type: Option<bool>,
+
     /// Is this slot readonly (cannot be modified after creation)?
     #[serde(skip_serializing_if = "Option::is_none")]
     pub readonly: Option<bool>,
@@ -448,6 +479,10 @@ pub struct SlotDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inlined_as_list: Option<bool>,
 
+    /// Is this slot inlined as a dictionary keyed by identifier?
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inlined_as_dict: Option<bool>,
+
     /// `any_of` constraint - at least one must be satisfied
     #[serde(skip_serializing_if = "Option::is_none")]
     pub any_of: Option<Vec<AnonymousSlotExpression>>,
@@ -480,6 +515,14 @@ pub struct SlotDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub structured_pattern: Option<StructuredPattern>,
 
+    /// Unit of measure of the slot values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<UnitOfMeasure>,
+
+    /// Shape of array-valued slots
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub array: Option<ArrayExpression>,
+
     /// Annotations for the slot
     #[serde(skip_serializing_if = "Option::is_none")]
     pub annotations: Option<Annotations>,
@@ -497,6 +540,18 @@ pub struct SlotDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deprecated: Option<String>,
 
+    /// Slot replacing this deprecated slot one to one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated_element_has_exact_replacement: Option<String>,
+
+    /// Slot that may replace this deprecated slot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated_element_has_possible_replacement: Option<String>,
+
+    /// Approval status, e.g. `draft`, `testing` or `production`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
     /// Todos for this slot
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub todos: Vec<String>,
@@ -547,6 +602,92 @@ pub struct SlotDefinition {
     pub broad_mappings: Vec<String>,
 }
 
+/// Unit of measure of a quantity slot
+///
+/// `ucum_code` is the canonical unit of the slot: values given without a
+/// unit are in this unit, and values with a unit must be convertible to it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct UnitOfMeasure {
+    /// Code of the unit in the Unified Code for Units of Measure, e.g. `mg/dL`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ucum_code: Option<String>,
+
+    /// Display symbol, e.g. `°C`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+
+    /// Abbreviation of the unit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abbreviation: Option<String>,
+
+    /// Full name of the unit, e.g. `milligram per deciliter`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descriptive_name: Option<String>,
+
+    /// Equivalent terms in unit ontologies such as QUDT or UO
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exact_mappings: Vec<String>,
+
+    /// Kind of quantity measured, e.g. `qudt:Mass`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_quantity_kind: Option<String>,
+}
+
+impl UnitOfMeasure {
+    /// UCUM code of the unit, falling back to the symbol
+    #[must_use]
+    pub fn code(&self) -> Option<&str> {
+        self.ucum_code.as_deref().or(self.symbol.as_deref())
+    }
+}
+
+/// Shape of an array-valued slot
+///
+/// The element type of the array is the `range` of the slot. Without
+/// `dimensions`, only the number of dimensions is constrained.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArrayExpression {
+    /// Exact number of dimensions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exact_number_dimensions: Option<usize>,
+
+    /// Minimum number of dimensions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_number_dimensions: Option<usize>,
+
+    /// Maximum number of dimensions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_number_dimensions: Option<usize>,
+
+    /// The leading dimensions of the array, outermost first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dimensions: Vec<DimensionExpression>,
+}
+
+/// One dimension of an [`ArrayExpression`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DimensionExpression {
+    /// Name of the dimension, e.g. `time` or `channel`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+
+    /// Description of the dimension
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Exact length of the dimension
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exact_cardinality: Option<usize>,
+
+    /// Minimum length of the dimension
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_cardinality: Option<usize>,
+
+    /// Maximum length of the dimension
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_cardinality: Option<usize>,
+}
+
 /// Structured pattern for advanced pattern matching
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct StructuredPattern {
@@ -632,11 +773,76 @@ pub struct EnumDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code_set_version: Option<String>,
 
+    /// Ontology query whose results are permissible values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reachable_from: Option<ReachabilityQuery>,
+
+    /// Identifier pattern whose matches are permissible values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matches: Option<MatchQuery>,
+
+    /// Identifiers of concepts that are permissible values
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub concepts: Vec<String>,
+
+    /// Approval status, e.g. `draft`, `testing` or `production`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
     /// Annotations for the enum
     #[serde(skip_serializing_if = "Option::is_none")]
     pub annotations: Option<Annotations>,
 }
 
+impl EnumDefinition {
+    /// Whether the values of the enum are defined by `reachable_from`,
+    /// `matches` or `concepts` rather than only by `permissible_values`
+    #[must_use]
+    pub fn is_dynamic(&self) -> bool {
+        self.reachable_from.is_some() || self.matches.is_some() || !self.concepts.is_empty()
+    }
+}
+
+/// Query for the terms reachable from source nodes of an ontology
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ReachabilityQuery {
+    /// Ontology to query, as a CURIE or URI
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_ontology: Option<String>,
+
+    /// Terms the traversal starts from
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub source_nodes: Vec<String>,
+
+    /// Relationships to traverse; `rdfs:subClassOf` if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationship_types: Vec<String>,
+
+    /// Only follow one relationship from the source nodes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_direct: Option<bool>,
+
+    /// Include the source nodes themselves
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_self: Option<bool>,
+
+    /// Traverse to ancestors instead of descendants
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traverse_up: Option<bool>,
+}
+
+/// Query for the terms whose identifiers match a pattern
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct MatchQuery {
+    /// Regular expression identifiers must match
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier_pattern: Option<String>,
+
+    /// Ontology the matching terms must belong to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_ontology: Option<String>,
+}
+
 /// Permissible value metadata
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct PermissibleValueMetadata {
@@ -649,6 +855,9 @@ pub struct PermissibleValueMetadata {
     /// Title
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    /// Parent permissible value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_a: Option<String>,
 }
 
 /// Permissible value (legacy enum, kept for backward compatibility)
@@ -666,9 +875,31 @@ pub enum PermissibleValue {
         /// Meaning URI
         #[serde(skip_serializing_if = "Option::is_none")]
         meaning: Option<String>,
+        /// Parent permissible value, possibly of another enum
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        is_a: Option<String>,
     },
 }
 
+impl PermissibleValue {
+    /// Text of the value
+    #[must_use]
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Simple(text) | Self::Complex { text, .. } => text,
+        }
+    }
+
+    /// Parent value this value is a specialization of
+    #[must_use]
+    pub fn is_a(&self) -> Option<&str> {
+        match self {
+            Self::Simple(_) => None,
+            Self::Complex { is_a, .. } => is_a.as_deref(),
+        }
+    }
+}
+
 /// Prefix definition
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
@@ -697,6 +928,23 @@ pub struct SubsetDefinition {
     pub description: Option<String>,
 }
 
+/// Reusable group of attributes, such as audit fields, included into classes
+/// without making them subclasses of a mixin
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct AttributeGroupDefinition {
+    /// Name of the group
+    #[serde(default)]
+    pub name: String,
+
+    /// Description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Attributes added to every class including the group
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub attributes: IndexMap<String, SlotDefinition>,
+}
+
 /// Validation report
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ValidationReport {
@@ -1021,6 +1269,58 @@ impl SchemaDefinition {
             ..Default::default()
         }
     }
+
+    /// Texts of the permissible values of an enum
+    ///
+    /// With `include_descendants`, values of any enum in the schema whose
+    /// `is_a` chain reaches one of the enum's values are included too, so an
+    /// enum of broad anatomical terms also accepts their specializations.
+    /// Returns `None` if the enum does not exist.
+    #[must_use]
+    pub fn enum_values(
+        &self,
+        enum_name: &str,
+        include_descendants: bool,
+    ) -> Option<std::collections::HashSet<String>> {
+        let enum_def = self.enums.get(enum_name)?;
+        let mut values: std::collections::HashSet<String> = enum_def
+            .permissible_values
+            .iter()
+            .map(|pv| pv.text().to_string())
+            .collect();
+        if !include_descendants {
+            return Some(values);
+        }
+
+        let parents: HashMap<&str, &str> = self
+            .enums
+            .values()
+            .flat_map(|enum_def| &enum_def.permissible_values)
+            .filter_map(|pv| Some((pv.text(), pv.is_a()?)))
+            .collect();
+        let descendants: Vec<String> = parents
+            .keys()
+            .filter(|value| {
+                // Walk up the hierarchy, guarding against cycles
+                let mut current = **value;
+                let mut steps = 0;
+                while let Some(parent) = parents.get(current) {
+                    if values.contains(*parent) {
+                        return true;
+                    }
+                    current = parent;
+                    steps += 1;
+                    if steps > parents.len() {
+                        break;
+                    }
+                }
+                false
+            })
+            .map(|value| (*value).to_string())
+            .collect();
+        values.extend(descendants);
+        Some(values)
+    }
 }
 
 impl Annotatable for SchemaDefinition {
@@ -1137,6 +1437,7 @@ where
                         text: key,
                         description: metadata.description,
                         meaning: metadata.meaning,
+                        is_a: metadata.is_a,
                     }
                 } else {
                     PermissibleValue::Simple(key)
@@ -1180,9 +1481,43 @@ mod tests {
             text: "test".to_string(),
             description: Some("A test value".to_string()),
             meaning: None,
+            is_a: None,
         };
         let json = serde_json::to_string(&complex)?;
         assert!(json.contains("description"));
         Ok(())
     }
+
+    #[test]
+    fn test_permissible_value_hierarchy() -> crate::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/anatomy
+name: anatomy
+enums:
+  Limb:
+    permissible_values:
+      arm:
+      leg:
+  AnatomicalTerm:
+    permissible_values:
+      arm:
+      upper_arm:
+        is_a: arm
+      humerus:
+        is_a: upper_arm
+      head:
+",
+        )?;
+        let terms = &schema.enums["AnatomicalTerm"].permissible_values;
+        assert_eq!(terms[2].is_a(), Some("upper_arm"));
+
+        let limb = schema.enum_values("Limb", false).unwrap_or_default();
+        assert!(!limb.contains("humerus"));
+        let limb = schema.enum_values("Limb", true).unwrap_or_default();
+        assert!(limb.contains("upper_arm") && limb.contains("humerus"));
+        assert!(!limb.contains("head"));
+        assert!(schema.enum_values("Missing", true).is_none());
+        Ok(())
+    }
 }
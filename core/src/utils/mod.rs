@@ -6,6 +6,15 @@ use indexmap::IndexMap;
 use std::collections::{HashSet, VecDeque};
 use std::hash::BuildHasher;
 
+/// Variants of these helpers that borrow from the schema instead of cloning
+pub mod borrowed;
+
+pub use borrowed::{
+    camel_to_snake, get_all_imports, get_class_hierarchy, get_element_uri, get_slot_definition,
+    get_subclasses, is_abstract_class, is_builtin_type, is_valid_type, merge_slot_definitions_cow,
+    snake_to_camel,
+};
+
 /// Check if a string is a valid `LinkML` identifier
 #[must_use]
 pub fn is_valid_identifier(s: &str) -> bool {
@@ -179,8 +188,10 @@ pub fn merge_slot_definitions(
         range: override_def.range.clone().or_else(|| base.range.clone()),
         domain: override_def.domain.clone().or_else(|| base.domain.clone()),
         required: override_def.required.or(base.required),
+        recommended: override_def.recommended.or(base.recommended),
         multivalued: override_def.multivalued.or(base.multivalued),
         identifier: override_def.identifier.or(base.identifier),
+        designates_type: override_def.designates_type.or(base.designates_type),
         pattern: override_def
             .pattern
             .clone()
@@ -223,6 +234,7 @@ pub fn merge_slot_definitions(
             .clone()
             .or_else(|| base.ifabsent.clone()),
         inlined_as_list: override_def.inlined_as_list.or(base.inlined_as_list),
+        inlined_as_dict: override_def.inlined_as_dict.or(base.inlined_as_dict),
         any_of: override_def.any_of.clone().or_else(|| base.any_of.clone()),
         all_of: override_def.all_of.clone().or_else(|| base.all_of.clone()),
         exactly_one_of: override_def
@@ -247,6 +259,8 @@ pub fn merge_slot_definitions(
             .structured_pattern
             .clone()
             .or_else(|| base.structured_pattern.clone()),
+        unit: override_def.unit.clone().or_else(|| base.unit.clone()),
+        array: override_def.array.clone().or_else(|| base.array.clone()),
         annotations: crate::annotations::merge_annotations(
             base.annotations.as_ref(),
             override_def.annotations.as_ref(),
@@ -257,6 +271,18 @@ pub fn merge_slot_definitions(
             .deprecated
             .clone()
             .or_else(|| base.deprecated.clone()),
+        deprecated_element_has_exact_replacement: override_def
+            .deprecated_element_has_exact_replacement
+            .clone()
+            .or_else(|| base.deprecated_element_has_exact_replacement.clone()),
+        deprecated_element_has_possible_replacement: override_def
+            .deprecated_element_has_possible_replacement
+            .clone()
+            .or_else(|| base.deprecated_element_has_possible_replacement.clone()),
+        status: override_def
+            .status
+            .clone()
+            .or_else(|| base.status.clone()),
         todos: merge_vec(&base.todos, &override_def.todos),
         notes: merge_vec(&base.notes, &override_def.notes),
         comments: merge_vec(&base.comments, &override_def.comments),
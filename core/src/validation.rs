@@ -2,8 +2,10 @@
 //!
 //! This module provides comprehensive validation functionality for LinkML schemas,
 //! including severity levels, validation results, and validation context.
+//! Types generated from a schema implement [`LinkMLValidate`] and check its
+//! constraints themselves; [`Validated`] applies that check on deserialization.
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 
@@ -92,6 +94,18 @@ impl ValidationMessage {
         self
     }
 
+    /// Place the path of this message below a parent path, as for messages of
+    /// a nested object
+    #[must_use]
+    pub fn with_parent_path(mut self, parent: &str) -> Self {
+        self.path = Some(match self.path.take() {
+            Some(path) if path.starts_with('[') => format!("{parent}{path}"),
+            Some(path) => format!("{parent}.{path}"),
+            None => parent.to_string(),
+        });
+        self
+    }
+
     /// Add context information
     #[must_use]
     pub fn with_context(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
@@ -262,6 +276,87 @@ impl fmt::Display for ValidationResult {
     }
 }
 
+/// Types that check the constraints of the `LinkML` class they represent
+///
+/// The Rust generator implements this trait for the structs it generates
+/// when its `linkml_validate` option is set, so typed models are validated
+/// against the constraints embedded at generation time without converting
+/// them to `serde_json::Value`.
+pub trait LinkMLValidate {
+    /// Name of the class the type was generated from
+    const CLASS_NAME: &'static str;
+
+    /// Constraint violations of this instance, with paths relative to it
+    fn validation_messages(&self) -> Vec<ValidationMessage>;
+
+    /// Check the constraints of this instance
+    fn validate_linkml(&self) -> ValidationResult {
+        let mut result = ValidationResult::new();
+        for message in self.validation_messages() {
+            result.add_message(message);
+        }
+        result
+    }
+}
+
+/// A value that satisfied the constraints of its class when it was
+/// deserialized
+///
+/// Deserializing `Validated<T>` deserializes a `T` and fails with the
+/// constraint violations if it has errors, so invalid data is rejected at
+/// the serde boundary.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Validated<T>(T);
+
+impl<T> Validated<T> {
+    /// The validated value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: LinkMLValidate> Validated<T> {
+    /// Check the constraints of a value
+    ///
+    /// # Errors
+    ///
+    /// Returns the validation result if the value has errors.
+    pub fn new(value: T) -> std::result::Result<Self, ValidationResult> {
+        let result = value.validate_linkml();
+        if result.has_errors() {
+            Err(result)
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl<T> std::ops::Deref for Validated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Validated<T>
+where
+    T: Deserialize<'de> + LinkMLValidate,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Self::new(T::deserialize(deserializer)?).map_err(|result| {
+            let errors: Vec<String> = result
+                .messages
+                .iter()
+                .filter(|message| message.severity >= ValidationSeverity::Error)
+                .map(ToString::to_string)
+                .collect();
+            serde::de::Error::custom(format!("invalid {}: {}", T::CLASS_NAME, errors.join("; ")))
+        })
+    }
+}
+
 /// Context for validation operations
 #[derive(Debug, Clone)]
 pub struct ValidationContext {
@@ -320,3 +415,49 @@ impl Default for ValidationContext {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Sample {
+        id: String,
+    }
+
+    impl LinkMLValidate for Sample {
+        const CLASS_NAME: &'static str = "Sample";
+
+        fn validation_messages(&self) -> Vec<ValidationMessage> {
+            if self.id.is_empty() {
+                vec![
+                    ValidationMessage::new(ValidationSeverity::Error, "Required value missing")
+                        .with_path("id")
+                        .with_rule("required"),
+                ]
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    #[test]
+    fn test_validated_deserialization() {
+        let sample: Validated<Sample> =
+            serde_json::from_str(r#"{"id": "s1"}"#).expect("valid sample");
+        assert_eq!(sample.id, "s1");
+
+        let error = serde_json::from_str::<Validated<Sample>>(r#"{"id": ""}"#)
+            .expect_err("empty identifier");
+        assert!(
+            error.to_string().starts_with(
+                "invalid Sample: [ERROR] Required value missing at id [rule: required]"
+            )
+        );
+
+        let nested = ValidationMessage::new(ValidationSeverity::Error, "nested")
+            .with_path("id")
+            .with_parent_path("friends[0]");
+        assert_eq!(nested.path.as_deref(), Some("friends[0].id"));
+    }
+}
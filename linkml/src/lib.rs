@@ -0,0 +1,111 @@
+//! Stable `LinkML` API
+//!
+//! The supported entry point for applications that load `LinkML` schemas,
+//! validate data against them, generate code and navigate schemas with
+//! [`SchemaView`]. Everything exported here follows semantic versioning and
+//! builds on the minimum supported Rust version of this crate; the modules of
+//! `linkml_service` and `linkml_core` behind it may change in any release.
+//!
+//! ```no_run
+//! # async fn run() -> linkml::Result<()> {
+//! let schema = linkml::load_schema("person.yaml")?;
+//!
+//! let data = serde_json::json!({"id": "P1", "name": "Ada"});
+//! let report = linkml::validate(&schema, &data, "Person").await?;
+//! assert!(report.valid);
+//!
+//! let typescript = linkml::generate(&schema, "typescript")?;
+//! # Ok(())
+//! # }
+//! ```
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![warn(clippy::all, clippy::pedantic)]
+
+use std::path::Path;
+
+use linkml_service::generator::GeneratorRegistry;
+use linkml_service::parser::Parser;
+use linkml_service::validator::ValidationEngine;
+
+pub use linkml_core::error::{LinkMLError, Result};
+pub use linkml_core::traits::SchemaFormat;
+pub use linkml_core::types::{
+    ClassDefinition, EnumDefinition, PermissibleValue, SchemaDefinition, SlotDefinition,
+    TypeDefinition,
+};
+pub use linkml_service::schema_view::{SchemaView, SchemaViewError};
+pub use linkml_service::validator::{Severity, ValidationIssue, ValidationReport};
+pub use serde_json::Value;
+
+/// Load a schema from a `YAML` or `JSON` file
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, its extension is neither
+/// `yaml`, `yml` nor `json`, or it is not a valid schema.
+pub fn load_schema(path: impl AsRef<Path>) -> Result<SchemaDefinition> {
+    Parser::new().parse_file(path.as_ref())
+}
+
+/// Load a schema from a string in the given format
+///
+/// # Errors
+///
+/// Returns an error if the content is not a valid schema.
+pub fn load_schema_str(content: &str, format: SchemaFormat) -> Result<SchemaDefinition> {
+    let format = match format {
+        SchemaFormat::Yaml => "yaml",
+        SchemaFormat::Json => "json",
+    };
+    Parser::new().parse_str(content, format)
+}
+
+/// Validate data as an instance of a class of the schema
+///
+/// Validation failures are reported as issues of the returned report, not as
+/// an error.
+///
+/// # Errors
+///
+/// Returns an error if the schema cannot be compiled for validation or the
+/// class does not exist.
+pub async fn validate(
+    schema: &SchemaDefinition,
+    data: &Value,
+    class_name: &str,
+) -> Result<ValidationReport> {
+    ValidationEngine::new(schema)?
+        .validate_as_class(data, class_name, None)
+        .await
+}
+
+/// Generate code or another artifact from a schema
+///
+/// `generator` is a name listed by [`generators`], such as `typescript`,
+/// `jsonschema` or `typeql`.
+///
+/// # Errors
+///
+/// Returns an error if no generator has this name or generation fails.
+pub fn generate(schema: &SchemaDefinition, generator: &str) -> Result<String> {
+    GeneratorRegistry::default_generators()
+        .into_iter()
+        .find(|candidate| candidate.name() == generator)
+        .ok_or_else(|| {
+            LinkMLError::NotImplemented(format!("Generator '{generator}' is not registered"))
+        })?
+        .generate(schema)
+}
+
+/// Names of the generators accepted by [`generate`], sorted
+#[must_use]
+pub fn generators() -> Vec<String> {
+    let mut names: Vec<String> = GeneratorRegistry::default_generators()
+        .iter()
+        .map(|generator| generator.name().to_string())
+        .collect();
+    names.sort();
+    names
+}
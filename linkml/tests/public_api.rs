@@ -0,0 +1,181 @@
+//! Semver guards for the stable `linkml` API
+
+use std::path::Path;
+
+use linkml::{
+    LinkMLError, Result, SchemaDefinition, SchemaFormat, SchemaView, ValidationReport, Value,
+};
+use serde_json::json;
+
+const SCHEMA: &str = r"
+id: https://example.org/people
+name: people
+default_range: string
+classes:
+  Person:
+    attributes:
+      id:
+        identifier: true
+      name:
+        required: true
+      age:
+        range: integer
+";
+
+#[test]
+fn facade_signatures() {
+    let _: fn(&Path) -> Result<SchemaDefinition> = linkml::load_schema::<&Path>;
+    let _: fn(&str, SchemaFormat) -> Result<SchemaDefinition> = linkml::load_schema_str;
+    let _: fn(&SchemaDefinition, &str) -> Result<String> = linkml::generate;
+    let _: fn() -> Vec<String> = linkml::generators;
+}
+
+#[tokio::test]
+async fn test_load_validate_generate() -> Result<()> {
+    let schema = linkml::load_schema_str(SCHEMA, SchemaFormat::Yaml)?;
+
+    let valid: Value = json!({"id": "P1", "name": "Ada", "age": 36});
+    let report: ValidationReport = linkml::validate(&schema, &valid, "Person").await?;
+    assert!(report.valid);
+
+    let invalid = json!({"id": "P2", "age": "unknown"});
+    let report = linkml::validate(&schema, &invalid, "Person").await?;
+    assert!(!report.valid);
+    assert!(!report.issues.is_empty());
+
+    let generators = linkml::generators();
+    assert!(generators.is_sorted());
+    assert!(generators.iter().any(|name| name == "jsonschema"));
+    assert!(linkml::generate(&schema, "jsonschema")?.contains("Person"));
+    assert!(matches!(
+        linkml::generate(&schema, "no-such-generator"),
+        Err(LinkMLError::NotImplemented(_))
+    ));
+
+    let view = SchemaView::new(schema)?;
+    assert!(view.all_class_names()?.contains(&"Person".to_string()));
+    Ok(())
+}
+
+/// Items of `linkml_core` and `linkml_service` that the facade re-exports
+///
+/// `cargo-public-api` lists them as `pub use` lines only, so their fields,
+/// variants and methods are taken from the public API of the crate that
+/// defines them.
+const REEXPORTED: &[&str] = &[
+    "linkml_core::error::LinkMLError",
+    "linkml_core::error::Result",
+    "linkml_core::traits::SchemaFormat",
+    "linkml_core::types::ClassDefinition",
+    "linkml_core::types::EnumDefinition",
+    "linkml_core::types::PermissibleValue",
+    "linkml_core::types::SchemaDefinition",
+    "linkml_core::types::SlotDefinition",
+    "linkml_core::types::TypeDefinition",
+    "linkml_service::schema_view::view::SchemaView",
+    "linkml_service::schema_view::view::SchemaViewError",
+    "linkml_service::validator::report::Severity",
+    "linkml_service::validator::report::ValidationIssue",
+    "linkml_service::validator::report::ValidationReport",
+];
+
+/// Public API of the crate at `manifest`, relative to this crate
+///
+/// Blanket, auto trait and derived impls are left out to keep the snapshot
+/// to the items written by hand.
+fn public_api_of(manifest: &str) -> public_api::PublicApi {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join(manifest);
+    let rustdoc_json = rustdoc_json::Builder::default()
+        .toolchain("nightly")
+        .manifest_path(&manifest)
+        .build()
+        .unwrap_or_else(|e| panic!("rustdoc JSON of {}: {e}", manifest.display()));
+    public_api::Builder::from_rustdoc_json(rustdoc_json)
+        .omit_blanket_impls(true)
+        .omit_auto_trait_impls(true)
+        .omit_auto_derived_impls(true)
+        .build()
+        .unwrap_or_else(|e| panic!("public API of {}: {e}", manifest.display()))
+}
+
+/// Path of the item a `cargo-public-api` line declares, or of the type an
+/// `impl` line implements for
+fn item_path(line: &str) -> &str {
+    let declaration = match line.strip_prefix("impl") {
+        Some(header) => header
+            .rsplit_once(" for ")
+            .map_or(header, |(_, self_type)| self_type),
+        None => line
+            .split_whitespace()
+            .find(|word| word.contains("::"))
+            .unwrap_or(line),
+    };
+    declaration
+        .trim()
+        .split(['(', '<', ' '])
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches(':')
+}
+
+/// Whether a `cargo-public-api` line belongs to a re-exported item
+fn is_reexported(line: &str) -> bool {
+    let path = item_path(line);
+    REEXPORTED.iter().any(|item| {
+        path.strip_prefix(item)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+    })
+}
+
+/// Compares the public API listed by `cargo-public-api` with the snapshot
+///
+/// The snapshot lists the items of the `linkml` crate followed by the
+/// items it re-exports from `linkml_core` and `linkml_service`, inlined.
+#[test]
+#[ignore = "needs a nightly toolchain to build rustdoc JSON"]
+fn public_api() {
+    let mut api: Vec<String> = public_api_of("Cargo.toml")
+        .items()
+        .map(ToString::to_string)
+        .collect();
+    for manifest in ["../core/Cargo.toml", "../service/Cargo.toml"] {
+        api.extend(
+            public_api_of(manifest)
+                .items()
+                .map(ToString::to_string)
+                .filter(|line| is_reexported(line)),
+        );
+    }
+    insta::assert_snapshot!(api.join("\n"));
+}
+
+#[test]
+fn test_item_path() {
+    assert_eq!(
+        item_path("pub struct linkml_core::types::SchemaDefinition"),
+        "linkml_core::types::SchemaDefinition"
+    );
+    assert_eq!(
+        item_path("pub linkml_core::types::SchemaDefinition::name: alloc::string::String"),
+        "linkml_core::types::SchemaDefinition::name"
+    );
+    assert_eq!(
+        item_path("pub type linkml_core::error::Result<T> = core::result::Result<T, E>"),
+        "linkml_core::error::Result"
+    );
+    assert_eq!(
+        item_path("impl core::fmt::Display for linkml_service::validator::report::Severity"),
+        "linkml_service::validator::report::Severity"
+    );
+    assert_eq!(
+        item_path("impl linkml_core::types::SlotDefinition"),
+        "linkml_core::types::SlotDefinition"
+    );
+    assert!(is_reexported(
+        "pub fn linkml_core::error::LinkMLError::service(message: &str) -> Self"
+    ));
+    assert!(!is_reexported(
+        "pub fn linkml_core::types::SchemaDefinitionV2::new() -> Self"
+    ));
+    assert!(!is_reexported("pub struct linkml_core::SchemaDefinition"));
+}
@@ -52,6 +52,7 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
             aliases: vec![],
             flags: HashMap::new(),
             extensions: HashMap::new(),
+            is_a: None,
         });
     status_enum
         .permissible_values
@@ -62,6 +63,7 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
             aliases: vec![],
             flags: HashMap::new(),
             extensions: HashMap::new(),
+            is_a: None,
         });
     status_enum
         .permissible_values
@@ -174,11 +174,13 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
                     text: "lost".to_string(),
                     description: Some("Book is lost".to_string()),
                     meaning: None,
+                    is_a: None,
                 },
                 PermissibleValue::Complex {
                     text: "damaged".to_string(),
                     description: Some("Book is damaged".to_string()),
                     meaning: None,
+                    is_a: None,
                 },
             ],
             ..Default::default()
@@ -0,0 +1,190 @@
+//! Runtime capability introspection
+//!
+//! [`capabilities()`] describes what this build of the service can do: the
+//! cargo features it was compiled with, the generators, loaders and dumpers
+//! it ships, the plugin API it accepts, and the `LinkML` metamodel version
+//! it implements. Orchestration layers and clients use it to negotiate
+//! functionality at runtime instead of probing for errors:
+//!
+//! ```rust
+//! # fn main() -> Result<(), serde_json::Error> {
+//! let caps = linkml_service::capabilities();
+//! if caps.has_generator("typescript") && caps.has_loader("yaml") {
+//!     // offer YAML to TypeScript conversion
+//! }
+//! println!("{}", serde_json::to_string_pretty(&caps)?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::generator::{GeneratorInfo, GeneratorRegistry};
+use crate::plugin::api::PLUGIN_API_VERSION;
+use serde::Serialize;
+
+/// Version of the `LinkML` metamodel implemented by this crate
+pub const METAMODEL_VERSION: &str = "1.7.0";
+
+/// How completely the `LinkML` specification is implemented
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConformanceLevel {
+    /// Schemas load and validate, but some metamodel elements are ignored
+    Partial,
+    /// Every metamodel element of the reported version is honoured
+    Full,
+}
+
+/// Plugin support of this build
+#[derive(Debug, Clone, Serialize)]
+pub struct PluginCapabilities {
+    /// Whether plugins can be loaded
+    pub enabled: bool,
+    /// Plugin API version that plugins must be built against
+    pub api_version: u32,
+}
+
+/// Structured description of the functionality of this build
+#[derive(Debug, Clone, Serialize)]
+pub struct Capabilities {
+    /// Crate version
+    pub version: &'static str,
+    /// Implemented `LinkML` metamodel version
+    pub metamodel_version: &'static str,
+    /// Conformance with the `LinkML` specification
+    pub conformance: ConformanceLevel,
+    /// Metamodel features honoured by validation and loading
+    pub spec_features: Vec<&'static str>,
+    /// Optional cargo features compiled in
+    pub features: Vec<&'static str>,
+    /// Plugin support
+    pub plugins: PluginCapabilities,
+    /// Built-in generators
+    pub generators: Vec<GeneratorInfo>,
+    /// Data formats that can be loaded
+    pub loaders: Vec<&'static str>,
+    /// Data formats that can be dumped
+    pub dumpers: Vec<&'static str>,
+}
+
+impl Capabilities {
+    /// Whether an optional cargo feature is compiled in
+    #[must_use]
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.contains(&feature)
+    }
+
+    /// Whether a built-in generator is available
+    #[must_use]
+    pub fn has_generator(&self, name: &str) -> bool {
+        self.generators
+            .iter()
+            .any(|generator| generator.name == name)
+    }
+
+    /// Whether data in a format can be loaded
+    #[must_use]
+    pub fn has_loader(&self, format: &str) -> bool {
+        self.loaders.contains(&format)
+    }
+
+    /// Whether data can be dumped in a format
+    #[must_use]
+    pub fn has_dumper(&self, format: &str) -> bool {
+        self.dumpers.contains(&format)
+    }
+}
+
+/// Describe the functionality of this build
+#[must_use]
+pub fn capabilities() -> Capabilities {
+    let mut features = Vec::new();
+    let mut loaders = vec![
+        "json", "yaml", "csv", "tsv", "xml", "rdf", "excel", "fhir", "api", "typedb",
+    ];
+    let mut dumpers = vec!["json", "yaml", "csv", "tsv", "xml", "rdf", "api", "typedb"];
+    if cfg!(feature = "database") {
+        features.push("database");
+        loaders.push("database");
+        dumpers.push("database");
+    }
+    if cfg!(feature = "lakehouse") {
+        features.push("lakehouse");
+        dumpers.extend(["iceberg", "delta"]);
+    }
+    if cfg!(feature = "scientific") {
+        features.push("scientific");
+        loaders.extend(["zarr", "netcdf"]);
+        dumpers.extend(["zarr", "hdf5"]);
+    }
+    if cfg!(feature = "kafka") {
+        features.push("kafka");
+    }
+    if cfg!(feature = "object-store") {
+        features.push("object-store");
+    }
+    if cfg!(feature = "google-sheets") {
+        features.push("google-sheets");
+    }
+
+    let mut generators: Vec<GeneratorInfo> = GeneratorRegistry::default_generators()
+        .iter()
+        .map(|generator| GeneratorInfo::of(generator.as_ref()))
+        .collect();
+    generators.sort_by(|a, b| a.name.cmp(&b.name));
+    generators.dedup_by(|a, b| a.name == b.name);
+
+    Capabilities {
+        version: env!("CARGO_PKG_VERSION"),
+        metamodel_version: METAMODEL_VERSION,
+        // Metamodel elements outside `spec_features` (e.g. `any_of` on classes,
+        // `classification_rules`) are not honoured yet
+        conformance: ConformanceLevel::Partial,
+        spec_features: vec![
+            "imports",
+            "is_a",
+            "mixins",
+            "attributes",
+            "slot_usage",
+            "rules",
+            "unique_keys",
+            "boolean_slot_expressions",
+            "equals_expression",
+            "ifabsent",
+            "designates_type",
+            "recommended",
+            "inlined_as_dict",
+            "permissible_value_hierarchies",
+            "structured_pattern",
+            "unit",
+            "array",
+        ],
+        features,
+        plugins: PluginCapabilities {
+            enabled: true,
+            api_version: PLUGIN_API_VERSION,
+        },
+        generators,
+        loaders,
+        dumpers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities() {
+        let caps = capabilities();
+        assert_eq!(caps.metamodel_version, METAMODEL_VERSION);
+        assert!(caps.has_loader("yaml") && caps.has_dumper("json"));
+        assert_eq!(caps.has_feature("database"), cfg!(feature = "database"));
+        assert!(!caps.generators.is_empty());
+        let names: Vec<&str> = caps.generators.iter().map(|g| g.name.as_str()).collect();
+        assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let json = serde_json::to_value(&caps).expect("capabilities serialize");
+        assert_eq!(json["conformance"], "partial");
+        assert_eq!(json["plugins"]["api_version"], PLUGIN_API_VERSION);
+    }
+}
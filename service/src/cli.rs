@@ -2368,7 +2368,7 @@ def migrate():
 /// Returns an error if the CLI service cannot be created or initialized.
 pub async fn run() -> linkml_core::error::Result<()> {
     // Create timestamp service (available)
-    let timestamp_service = timestamp_service::wiring::wire_timestamp();
+    let timestamp_service = crate::utils::timestamp::wire_timestamp();
 
     // For CLI usage, we'll skip the complex service dependencies
     // and use a minimal service that doesn't require all the complex setup
@@ -2379,10 +2379,7 @@ pub async fn run() -> linkml_core::error::Result<()> {
     })?;
 
     // Create and run CLI app
-    let app = CliApp::new(
-        Arc::new(linkml_service),
-        timestamp_service.clone().into_inner(),
-    );
+    let app = CliApp::new(Arc::new(linkml_service), timestamp_service);
     app.run().await
 }
 
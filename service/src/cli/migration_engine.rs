@@ -255,11 +255,10 @@ impl MigrationEngine {
     /// Create a new migration engine
     #[must_use]
     pub fn new(from_schema: SchemaDefinition, to_schema: SchemaDefinition) -> Self {
-        let timestamp_service = timestamp_service::wiring::wire_timestamp();
         Self {
             from_schema,
             to_schema,
-            timestamp_service: timestamp_service.into_inner(),
+            timestamp_service: crate::utils::timestamp::wire_timestamp(),
         }
     }
 
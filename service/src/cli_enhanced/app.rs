@@ -1,19 +1,54 @@
 //! `LinkML` enhanced CLI application.
 
 use super::types::{
-    AuthType, ConflictResolution, DiffFormat, DumpFormat, LinkMLCli, LinkMLCommand, LintFormat,
-    LoadFormat, MergeStrategy, OutputFormat, SchemaFormat,
+    AuthType, ConflictResolution, DiffFormat, DraftElementPolicy, DumpFormat, LinkMLCli,
+    LinkMLCommand, LintFormat, LoadFormat, MergeStrategy, MessageBundleFormat, OutputFormat,
+    QualityFormat, SchemaFormat, SearchElementType, TerminologyServiceKind, TrendDimension,
+    TrendPeriod, ValidationOutputFormat,
 };
+#[cfg(feature = "database")]
+use crate::cli_enhanced::commands::deploy_sql::DeploySqlCommand;
+use crate::cli_enhanced::commands::dist::DistCommand;
+use crate::cli_enhanced::commands::mock_serve::MockServeCommand;
+use crate::cli_enhanced::commands::push_registry::PushRegistryCommand;
 use crate::cli_enhanced::commands::serve::ServeCommand;
-use crate::generator::{Generator, GeneratorOptions, GeneratorRegistry, IndentStyle};
+use crate::dataset::{MANIFEST_SCHEMA, ManifestBuilder, ManifestOptions};
+use crate::generator::{
+    FragmentCache, GenerationLimits, Generator, GeneratorOptions, GeneratorRegistry,
+    GuardedGenerator, HtmlGenerator, IndentStyle, MarkdownGenerator, PostProcessorPipeline,
+    Provenance, SssomGenerator, SssomGeneratorConfig, provenance, rust_ast,
+};
+use crate::object_storage::{ObjectStorage, ObjectStoreOptions, object_uri};
+use crate::project::{PROJECT_FILE, Project, ValidationBaseline};
+use crate::schema::attribute_groups::expanded;
 use crate::schema::{
-    DiffOptions, LintOptions, MergeOptions, SchemaDiff, SchemaLinter, SchemaMerge, Severity,
+    BioPortalClient, DiffOptions, FormatOptions, LintConfig, LintOptions, LintRuleRegistry,
+    MappingSuggester, MergeOptions, OlsClient, PatchOptions, QualityScorer, SchemaDiff,
+    SchemaFormatter, SchemaLinter, SchemaMerge, SchemaPatch, SchemaPatcher, Severity,
+    SuggestOptions, TerminologyService, create_patch, mapping_suggest, update_schema_text,
+};
+use crate::schema_view::search::element_type_label;
+use crate::schema_view::{
+    DraftPolicy, ElementType, SchemaView, SearchOptions, draft_elements, without_drafts,
+};
+use crate::storage::{
+    AuditRecord, ExportFormat, FileRecordStore, RecordFilter, SeriesDimension, Storage,
+    StoredReport, TrendBucket, analytics,
 };
 use crate::utils::timestamp::SyncTimestampUtils;
+use crate::validator::batch::{BatchManifest, BatchValidator, DatasetStatus};
+use crate::validator::discovery::{self, SchemaDiscovery};
 use crate::validator::engine::{ValidationEngine, ValidationOptions};
+use crate::validator::fixes;
+use crate::validator::localization::{BundleFormat, MessageBundle, ReportLocalizer};
 use crate::validator::report::ValidationReport;
+use crate::validator::report_formats;
+use crate::validator::rule_config::ValidationRuleConfig;
+use crate::validator::source_map::{SourceLocation, SourceMap};
 use clap::Parser;
+use indexmap::IndexMap;
 use linkml_core::error::{LinkMLError, Result};
+use linkml_core::error_codes;
 use linkml_core::types::SchemaDefinition;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -21,7 +56,7 @@ use std::fmt::Write as FmtWrite;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::fs;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 /// Main `LinkML` CLI application
 pub struct LinkMLApp {
@@ -29,6 +64,13 @@ pub struct LinkMLApp {
     timestamp_utils: Arc<SyncTimestampUtils>,
 }
 
+/// Schema and validation engine shared by the data files validated against it
+struct DataValidator {
+    schema: SchemaDefinition,
+    engine: ValidationEngine,
+    localizer: Option<ReportLocalizer>,
+}
+
 impl LinkMLApp {
     /// Create a new `LinkML` application from command line arguments with timestamp service
     #[must_use]
@@ -84,7 +126,15 @@ impl LinkMLApp {
         self.init_logging();
         info!("Starting LinkML CLI application");
 
-        match self.execute_command().await {
+        let result = match &self.cli.config {
+            Some(path) => crate::config::init_config(path).map(|_| ()),
+            None => Ok(()),
+        };
+        let result = match result {
+            Ok(()) => self.execute_command().await,
+            Err(err) => Err(err),
+        };
+        match result {
             Ok(()) => {
                 info!("Command completed successfully");
                 Ok(())
@@ -129,27 +179,64 @@ impl LinkMLApp {
                 max_errors,
                 stats,
                 parallel,
+                output_format,
+                rules,
+                store,
+                messages,
+                explain,
+                apply_fixes,
+                locations,
+                draft_elements,
             } => {
                 self.validate_command(
-                    schema,
+                    schema.as_deref(),
                     data,
                     class_name.as_deref(),
                     *strict,
                     *max_errors,
                     *stats,
                     *parallel,
+                    *output_format,
+                    rules.as_deref(),
+                    store.as_deref(),
+                    messages.as_deref(),
+                    *explain,
+                    *apply_fixes,
+                    *locations,
+                    Self::draft_policy(*draft_elements),
                 )
                 .await
             }
+            LinkMLCommand::ValidateBatch {
+                manifest,
+                threads,
+                output,
+            } => {
+                self.validate_batch_command(manifest, *threads, output.as_deref())
+                    .await
+            }
             LinkMLCommand::Generate {
                 schema,
                 generator,
                 output,
                 options,
+                fragment_cache,
+                check,
+                draft_elements,
                 ..
             } => {
-                self.generate_command(schema, generator, output, options)
-                    .await
+                let project = self.project()?;
+                let schema = Self::project_schema(project.as_ref(), schema.as_ref())?;
+                self.generate_command(
+                    &schema,
+                    generator,
+                    output,
+                    options,
+                    fragment_cache.as_deref(),
+                    *check,
+                    Self::draft_policy(*draft_elements),
+                )
+                .await
             }
             LinkMLCommand::Convert {
                 input,
@@ -170,7 +257,157 @@ impl LinkMLApp {
                 strict,
                 format,
             } => {
-                self.lint_command(schema, rules, config.as_ref(), *fix, *strict, *format)
+                let project = self.project()?;
+                let schema = Self::project_schema(project.as_ref(), schema.as_ref())?;
+                let policy = project
+                    .map(|project| project.manifest.lint)
+                    .unwrap_or_default();
+                let config = config.clone().or(policy.config);
+                self.lint_command(
+                    &schema,
+                    rules,
+                    config.as_ref(),
+                    *fix,
+                    *strict || policy.strict,
+                    *format,
+                )
+                .await
+            }
+            LinkMLCommand::Quality {
+                schema,
+                config,
+                format,
+                badge,
+                min_score,
+            } => {
+                let project = self.project()?;
+                let schema = Self::project_schema(project.as_ref(), schema.as_ref())?;
+                let policy = project
+                    .map(|project| project.manifest.lint)
+                    .unwrap_or_default();
+                let config = config.clone().or(policy.config);
+                self.quality_command(
+                    &schema,
+                    config.as_ref(),
+                    *format,
+                    badge.as_ref(),
+                    min_score.or(policy.min_quality),
+                )
+                .await
+            }
+            LinkMLCommand::Check {
+                update_baselines,
+                skip_generate,
+            } => self.check_command(*update_baselines, *skip_generate).await,
+            LinkMLCommand::Format {
+                schemas,
+                check,
+                stdout,
+                sort_elements,
+                indent,
+                lossless,
+            } => {
+                let options = FormatOptions::default()
+                    .with_indent(*indent)
+                    .with_sorted_elements(*sort_elements)
+                    .with_lossless(*lossless);
+                self.format_command(schemas, &options, *check, *stdout)
+            }
+            LinkMLCommand::Search {
+                schema,
+                query,
+                limit,
+                element_types,
+                synonyms,
+                docs_index,
+            } => {
+                self.search_command(
+                    schema,
+                    query,
+                    *limit,
+                    element_types,
+                    synonyms,
+                    docs_index.as_ref(),
+                )
+                .await
+            }
+            LinkMLCommand::SuggestMappings {
+                schema,
+                output,
+                services,
+                ontologies,
+                ols_url,
+                max_per_element,
+                include_mapped,
+            } => {
+                self.suggest_mappings_command(
+                    schema,
+                    output.as_ref(),
+                    services,
+                    ontologies,
+                    ols_url,
+                    *max_per_element,
+                    *include_mapped,
+                )
+                .await
+            }
+            LinkMLCommand::Manifest {
+                directory,
+                schema,
+                output,
+                class_name,
+                name,
+                extensions,
+                no_validate,
+                print_schema,
+            } => {
+                if *print_schema {
+                    print!("{MANIFEST_SCHEMA}");
+                    return Ok(());
+                }
+                let mut options = ManifestOptions::default()
+                    .with_validation(!no_validate)
+                    .with_extensions(extensions.clone())
+                    .with_generated_at(self.timestamp_utils.now_rfc3339()?);
+                if let Some(class_name) = class_name {
+                    options = options.with_target_class(class_name);
+                }
+                if let Some(name) = name {
+                    options = options.with_name(name);
+                }
+                self.manifest_command(directory, schema, output.as_deref(), options)
+                    .await
+            }
+            LinkMLCommand::Reports {
+                store,
+                schema,
+                schema_version,
+                since,
+                until,
+                limit,
+                trend,
+                by,
+                csv,
+            } => {
+                let mut filter = RecordFilter::default();
+                filter.schema_name.clone_from(schema);
+                filter.schema_version.clone_from(schema_version);
+                filter.since = since.as_deref().map(|s| self.parse_time(s)).transpose()?;
+                filter.until = until.as_deref().map(|s| self.parse_time(s)).transpose()?;
+                filter.limit = *limit;
+                let trend = trend.or(by.map(|_| TrendPeriod::Day));
+                self.reports_command(store, &filter, trend, *by, *csv).await
+            }
+            LinkMLCommand::ErrorCodes { markdown } => {
+                self.error_codes_command(*markdown);
+                Ok(())
+            }
+            LinkMLCommand::Messages {
+                schema,
+                format,
+                output,
+            } => {
+                self.messages_command(schema.as_deref(), *format, output.as_deref())
                     .await
             }
             LinkMLCommand::Diff {
@@ -193,6 +430,15 @@ impl LinkMLApp {
                 )
                 .await
             }
+            LinkMLCommand::Patch {
+                schema,
+                patch,
+                check,
+                allow_breaking,
+            } => {
+                self.patch_command(schema, patch, *check, *allow_breaking)
+                    .await
+            }
             LinkMLCommand::Merge {
                 schemas,
                 output,
@@ -211,6 +457,9 @@ impl LinkMLApp {
                 )
                 .await
             }
+            LinkMLCommand::Materialize { schema, output } => {
+                self.materialize_command(schema, output.as_ref()).await
+            }
             LinkMLCommand::Dump {
                 schema,
                 input,
@@ -265,6 +514,76 @@ impl LinkMLApp {
                 }
                 self.serve_command(schema, *port, host).await
             }
+            LinkMLCommand::MockServe {
+                schema,
+                port,
+                host,
+                count,
+            } => {
+                let schema = self.load_schema(schema).await?;
+                MockServeCommand::new(schema, *port)
+                    .with_host(host.as_str())
+                    .with_count(*count)
+                    .execute()
+                    .await
+            }
+            #[cfg(feature = "database")]
+            LinkMLCommand::DeploySql {
+                schema,
+                dsn,
+                db_schema,
+                dry_run,
+            } => {
+                let schema = self.load_schema(schema).await?;
+                DeploySqlCommand::new(schema, dsn.as_str())
+                    .with_db_schema(db_schema.as_str())
+                    .with_dry_run(*dry_run)
+                    .execute()
+                    .await
+            }
+            LinkMLCommand::PushRegistry {
+                schema,
+                url,
+                subject,
+                artifact,
+                class_name,
+                compatibility,
+                username,
+                password,
+                dry_run,
+            } => {
+                let schema = self.load_schema(schema).await?;
+                let mut command = PushRegistryCommand::new(schema, url.as_str(), subject.as_str())
+                    .with_artifact(*artifact)
+                    .with_dry_run(*dry_run);
+                if let Some(class_name) = class_name {
+                    command = command.with_class(class_name.as_str());
+                }
+                if let Some(level) = compatibility {
+                    command = command.with_compatibility(*level);
+                }
+                if let Some(username) = username {
+                    command = command.with_basic_auth(
+                        username.as_str(),
+                        password.as_deref().unwrap_or_default(),
+                    );
+                }
+                command.execute().await
+            }
+            LinkMLCommand::Dist {
+                targets,
+                output,
+                manifest_path,
+                builder,
+                dry_run,
+            } => {
+                DistCommand::new(manifest_path.clone(), output.clone())
+                    .with_targets(targets.clone())
+                    .with_builder(builder.as_str())
+                    .with_dry_run(*dry_run)
+                    .execute()
+                    .await
+            }
             LinkMLCommand::Shell { .. } => Err(LinkMLError::not_implemented(
                 "Interactive shell is migrating to the Task Management framework",
             )),
@@ -274,6 +593,7 @@ impl LinkMLApp {
                 schema_id,
                 schema_name,
                 schema_format,
+                credentials,
                 progress,
             } => {
                 self.sheets2schema_command(
@@ -282,6 +602,7 @@ impl LinkMLApp {
                     schema_id.as_ref(),
                     schema_name.as_ref(),
                     *schema_format,
+                    credentials.as_ref(),
                     *progress,
                 )
                 .await
@@ -293,6 +614,8 @@ impl LinkMLApp {
                 examples,
                 freeze_headers,
                 filters,
+                credentials,
+                data_entry,
                 progress,
             } => {
                 self.schema2sheets_command(
@@ -302,6 +625,8 @@ impl LinkMLApp {
                     *examples,
                     *freeze_headers,
                     *filters,
+                    credentials.as_ref(),
+                    *data_entry,
                     *progress,
                 )
                 .await
@@ -311,18 +636,41 @@ impl LinkMLApp {
 
     async fn validate_command(
         &self,
-        schema_path: &Path,
+        schema_path: Option<&Path>,
         data_paths: &[PathBuf],
         class_name: Option<&str>,
         strict: bool,
         max_errors: usize,
         show_stats: bool,
         parallel: bool,
+        output_format: ValidationOutputFormat,
+        rule_config: Option<&Path>,
+        store: Option<&Path>,
+        messages: Option<&Path>,
+        explain: bool,
+        apply_fixes: bool,
+        locations: bool,
+        draft_elements: DraftPolicy,
     ) -> Result<()> {
-        let schema = self.load_schema(schema_path).await?;
-        let engine = ValidationEngine::new(&schema)
-            .map_err(|err| LinkMLError::service(format!("Failed to build validator: {err}")))?;
+        let project = self.project()?;
+        let project_data: Vec<PathBuf>;
+        let data_paths = if data_paths.is_empty() {
+            project_data = project
+                .iter()
+                .flat_map(|project| &project.manifest.validate)
+                .flat_map(|data_set| data_set.data.iter().cloned())
+                .collect();
+            if project_data.is_empty() {
+                return Err(LinkMLError::config(format!(
+                    "No data files given and no {PROJECT_FILE} project defines data sets"
+                )));
+            }
+            project_data.as_slice()
+        } else {
+            data_paths
+        };
 
+        let bundle = messages.map(MessageBundle::from_file).transpose()?;
         let options = ValidationOptions {
             fail_fast: if strict { Some(true) } else { None },
             parallel: Some(parallel),
@@ -332,24 +680,142 @@ impl LinkMLApp {
             use_cache: Some(true),
             fail_on_warning: if strict { Some(true) } else { None },
             custom_validators: Vec::new(),
+            rule_config: rule_config
+                .map(ValidationRuleConfig::from_file)
+                .transpose()?
+                .map(Arc::new),
+            normalize: None,
+            convert_units: None,
+            null_policy: None,
+            empty_collection_policy: None,
+            explain: Some(explain),
+            fixes: Some(apply_fixes),
+            draft_elements: Some(draft_elements),
+            ..ValidationOptions::default()
         };
 
+        let mut discovery = SchemaDiscovery::new();
+        let mut validators: IndexMap<PathBuf, DataValidator> = IndexMap::new();
+        let mut data_set_rules: HashMap<PathBuf, Arc<ValidationRuleConfig>> = HashMap::new();
         let mut any_failures = false;
-        for data_path in data_paths {
-            let value = self.load_data_value(data_path).await?;
-            let mut report = if let Some(target) = class_name {
-                engine
-                    .validate_as_class(&value, target, Some(options.clone()))
-                    .await?
-            } else {
-                engine.validate(&value, Some(options.clone())).await?
-            };
+        let mut collected = Vec::new();
+        let mut to_store: IndexMap<PathBuf, Vec<(String, ValidationReport)>> = IndexMap::new();
+        for data_path in discovery::collect_data_files(data_paths)? {
+            let data_set = project
+                .as_ref()
+                .and_then(|project| project.data_set_for(&data_path));
+            let content = self.read_data_file(&data_path).await?;
+            let mut value = Self::parse_data_value(&data_path, &content)?;
+            let mut source_map = locations.then(|| SourceMap::parse(&content)).transpose()?;
+            // Fixes are written to the document as loaded, schema key included
+            let original = apply_fixes.then(|| value.clone());
+            let (target_schema, target_class) = Self::validation_target(
+                &mut discovery,
+                &data_path,
+                &mut value,
+                schema_path,
+                class_name,
+                project.as_ref(),
+            )?;
+            if !validators.contains_key(&target_schema) {
+                let validator = self.data_validator(&target_schema, bundle.as_ref()).await?;
+                validators.insert(target_schema.clone(), validator);
+            }
+            let validator = &validators[&target_schema];
+
+            let mut file_options = options.clone();
+            if file_options.rule_config.is_none()
+                && let Some(rules) = data_set.and_then(|data_set| data_set.rules.as_ref())
+            {
+                let config = match data_set_rules.get(rules) {
+                    Some(config) => Arc::clone(config),
+                    None => {
+                        let config = Arc::new(ValidationRuleConfig::from_file(rules)?);
+                        data_set_rules.insert(rules.clone(), Arc::clone(&config));
+                        config
+                    }
+                };
+                file_options.rule_config = Some(config);
+            }
+            let mut report = Self::validate_value(
+                &validator.engine,
+                &value,
+                target_class.as_deref(),
+                file_options.clone(),
+            )
+            .await?;
+            if let Some(mut original) = original {
+                let fixed = fixes::apply_fixes(&mut original, &report)?;
+                if fixed > 0 {
+                    self.write_data_value(&data_path, &original).await?;
+                    if source_map.is_some() {
+                        let content = self.read_data_file(&data_path).await?;
+                        source_map = Some(SourceMap::parse(&content)?);
+                    }
+                    if !self.cli.quiet {
+                        println!("Applied {fixed} fixes to {}", data_path.display());
+                    }
+                    fixes::apply_fixes(&mut value, &report)?;
+                    report = Self::validate_value(
+                        &validator.engine,
+                        &value,
+                        target_class.as_deref(),
+                        file_options,
+                    )
+                    .await?;
+                }
+            }
+            if let Some(localizer) = &validator.localizer {
+                localizer.localize(&mut report);
+            }
+            if let Some(source_map) = &source_map {
+                source_map.locate(&mut report);
+            }
 
             if !report.valid {
                 any_failures = true;
             }
+            if store.is_some() {
+                to_store
+                    .entry(target_schema)
+                    .or_default()
+                    .push((data_path.display().to_string(), report.clone()));
+            }
 
-            self.render_validation_report(data_path, &mut report, max_errors, show_stats)?;
+            if output_format == ValidationOutputFormat::Text {
+                self.render_validation_report(
+                    &data_path,
+                    &mut report,
+                    source_map.as_ref(),
+                    max_errors,
+                    show_stats,
+                )?;
+            } else {
+                collected.push((data_path.display().to_string(), report));
+            }
+        }
+
+        if output_format != ValidationOutputFormat::Text {
+            let reports: Vec<(&str, &ValidationReport)> = collected
+                .iter()
+                .map(|(path, report)| (path.as_str(), report))
+                .collect();
+            let rendered = if output_format == ValidationOutputFormat::Sarif {
+                serde_json::to_string_pretty(&report_formats::to_sarif(&reports))
+                    .map_err(|err| LinkMLError::SerializationError(err.to_string()))?
+            } else {
+                report_formats::to_junit_xml(&reports)
+            };
+            // Machine-readable output is printed as-is, without the global format envelope
+            println!("{rendered}");
+        }
+
+        if let Some(store) = store {
+            for (target_schema, reports) in to_store {
+                let schema = &validators[&target_schema].schema;
+                self.store_validation_run(store, &target_schema, schema, reports)
+                    .await?;
+            }
         }
 
         if strict && any_failures {
@@ -364,152 +830,1147 @@ impl LinkMLApp {
         Ok(())
     }
 
-    async fn generate_command(
+    /// Validate a data value as an instance of a class, or of the class
+    /// inferred from the data
+    async fn validate_value(
+        engine: &ValidationEngine,
+        value: &Value,
+        class_name: Option<&str>,
+        options: ValidationOptions,
+    ) -> Result<ValidationReport> {
+        match class_name {
+            Some(class_name) => {
+                engine
+                    .validate_as_class(value, class_name, Some(options))
+                    .await
+            }
+            None => engine.validate(value, Some(options)).await,
+        }
+    }
+
+    /// Schema, validation engine and localizer of a schema file
+    async fn data_validator(
+        &self,
+        schema_path: &Path,
+        bundle: Option<&MessageBundle>,
+    ) -> Result<DataValidator> {
+        let schema = self.load_schema(schema_path).await?;
+        let engine = ValidationEngine::new(&schema)
+            .map_err(|err| LinkMLError::service(format!("Failed to build validator: {err}")))?;
+        let localizer = bundle
+            .cloned()
+            .map(|bundle| ReportLocalizer::new(bundle, Some(&schema)));
+        Ok(DataValidator {
+            schema,
+            engine,
+            localizer,
+        })
+    }
+
+    /// Schema and target class of a data file
+    ///
+    /// Explicit `--schema` and `--class` arguments win; anything missing is
+    /// discovered from a sidecar pointer, the document's `$linkml` key or a
+    /// directory configuration, and finally taken from the project data set
+    /// containing the file or the project's default schema. The `$linkml` key
+    /// is removed from the document so it does not fail validation.
+    fn validation_target(
+        discovery: &mut SchemaDiscovery,
+        data_path: &Path,
+        value: &mut Value,
+        schema_path: Option<&Path>,
+        class_name: Option<&str>,
+        project: Option<&Project>,
+    ) -> Result<(PathBuf, Option<String>)> {
+        let discovered = if schema_path.is_some() && class_name.is_some() {
+            None
+        } else {
+            discovery.discover(data_path, value)?
+        };
+        discovery::strip_schema_key(value);
+
+        let class = class_name
+            .map(str::to_string)
+            .or_else(|| discovered.as_ref().and_then(|found| found.class.clone()))
+            .or_else(|| {
+                project
+                    .and_then(|project| project.data_set_for(data_path))
+                    .and_then(|data_set| data_set.class.clone())
+            });
+        if let Some(schema) = schema_path {
+            return Ok((schema.to_path_buf(), class));
+        }
+        if discovered.is_none()
+            && let Some(project) = project
+            && let Some(schema) = project.schema_or_default(
+                project
+                    .data_set_for(data_path)
+                    .and_then(|data_set| data_set.schema.as_ref()),
+            )
+        {
+            return Ok((schema.to_path_buf(), class));
+        }
+        let Some(found) = discovered else {
+            return Err(LinkMLError::config(format!(
+                "No schema found for {}: pass --schema, add a {}.{} sidecar, a {} key, a {} directory configuration or a {PROJECT_FILE} project",
+                data_path.display(),
+                data_path
+                    .file_name()
+                    .map_or_else(String::new, |name| name.to_string_lossy().into_owned()),
+                discovery::SIDECAR_EXTENSION,
+                discovery::SCHEMA_KEY,
+                discovery::DIRECTORY_CONFIG
+            )));
+        };
+        debug!(
+            "Validating {} against {} (from {})",
+            data_path.display(),
+            found.schema.display(),
+            found.source
+        );
+        Ok((found.schema, class))
+    }
+
+    /// Record a validation run: schema snapshot, one report per file and an audit entry
+    async fn store_validation_run(
+        &self,
+        store: &Path,
+        schema_path: &Path,
+        schema: &SchemaDefinition,
+        reports: Vec<(String, ValidationReport)>,
+    ) -> Result<()> {
+        let storage = Storage::new(Arc::new(FileRecordStore::new(store)));
+        storage.initialize().await?;
+        let recorded_at = self.timestamp_utils.now()?;
+        storage.schemas().save(schema, recorded_at).await?;
+
+        let failed = reports.iter().filter(|(_, report)| !report.valid).count();
+        let files = reports.len();
+        for (source, report) in reports {
+            storage
+                .reports()
+                .save(&StoredReport {
+                    schema_name: schema.name.clone(),
+                    schema_version: schema.version.clone(),
+                    source: Some(source),
+                    recorded_at,
+                    report,
+                })
+                .await?;
+        }
+        storage
+            .audit()
+            .record(&AuditRecord {
+                actor: std::env::var("USER").unwrap_or_else(|_| "linkml-cli".to_string()),
+                action: "data.validate".to_string(),
+                target: schema_path.display().to_string(),
+                schema_name: Some(schema.name.clone()),
+                schema_version: schema.version.clone(),
+                recorded_at,
+                details: serde_json::json!({"files": files, "failed": failed}),
+            })
+            .await?;
+        if !self.cli.quiet {
+            println!("Stored {files} validation reports in {}", store.display());
+        }
+        Ok(())
+    }
+
+    /// Validate the data sets of a batch manifest concurrently
+    ///
+    /// The JSON summary goes to `output`, or to stdout when no output file is
+    /// given; with an output file a line per data set is printed instead.
+    async fn validate_batch_command(
+        &self,
+        manifest: &Path,
+        threads: Option<usize>,
+        output: Option<&Path>,
+    ) -> Result<()> {
+        let manifest = BatchManifest::load(manifest)?;
+        let validator = match threads {
+            Some(threads) => BatchValidator::new(threads)?,
+            None => BatchValidator::from_config(&crate::config::get_config().validator)?,
+        };
+        info!(
+            "Validating {} data set(s) on {} thread(s)",
+            manifest.datasets.len(),
+            validator.threads()
+        );
+        let summary = tokio::task::spawn_blocking(move || validator.validate(&manifest))
+            .await
+            .map_err(|e| LinkMLError::service(format!("Batch validation failed: {e}")))?;
+
+        let json = serde_json::to_string_pretty(&summary)
+            .map_err(|e| LinkMLError::SerializationError(e.to_string()))?;
+        if let Some(output) = output {
+            fs::write(output, &json).await.map_err(|e| {
+                LinkMLError::io_error(format!("Failed to write {}: {e}", output.display()))
+            })?;
+            if !self.cli.quiet {
+                for dataset in &summary.datasets {
+                    let outcome = match dataset.status {
+                        DatasetStatus::Passed => "passed".to_string(),
+                        DatasetStatus::Failed => format!(
+                            "{} invalid file(s), {} error(s)",
+                            dataset.invalid_files, dataset.errors
+                        ),
+                        DatasetStatus::Error => dataset.error.clone().unwrap_or_default(),
+                    };
+                    println!(
+                        "{} ({} file(s), {} ms): {outcome}",
+                        dataset.name,
+                        dataset.files.len(),
+                        dataset.duration_ms
+                    );
+                }
+                println!("Wrote batch summary to {}", output.display());
+            }
+        } else {
+            println!("{json}");
+        }
+
+        if summary.exit_code == 0 {
+            return Ok(());
+        }
+        Err(LinkMLError::service(format!(
+            "Batch validation failed: {} data set(s) invalid, {} could not be validated",
+            summary.failed, summary.errored
+        )))
+    }
+
+    async fn reports_command(
+        &self,
+        store: &Path,
+        filter: &RecordFilter,
+        trend: Option<TrendPeriod>,
+        by: Option<TrendDimension>,
+        csv: bool,
+    ) -> Result<()> {
+        let storage = Storage::new(Arc::new(FileRecordStore::new(store)));
+        let json_output = matches!(self.cli.format, OutputFormat::Json);
+
+        let Some(period) = trend else {
+            let reports = storage.reports().find(filter).await?;
+            return self.print_stored_reports(&reports, json_output);
+        };
+
+        let bucket = match period {
+            TrendPeriod::Day => TrendBucket::Day,
+            TrendPeriod::Week => TrendBucket::Week,
+            TrendPeriod::Month => TrendBucket::Month,
+        };
+        let export_format = if csv {
+            Some(ExportFormat::Csv)
+        } else if json_output {
+            Some(ExportFormat::Json)
+        } else {
+            None
+        };
+
+        let mut buffer = String::new();
+        if let Some(by) = by {
+            let dimension = match by {
+                TrendDimension::Class => SeriesDimension::Class,
+                TrendDimension::Slot => SeriesDimension::Slot,
+                TrendDimension::Code => SeriesDimension::ErrorCode,
+            };
+            let points = storage
+                .reports()
+                .issue_series(filter, bucket, dimension)
+                .await?;
+            if let Some(format) = export_format {
+                println!("{}", analytics::export(&points, format)?.trim_end());
+                return Ok(());
+            }
+            buffer.push_str(&format!(
+                "period\t{dimension}\treports\taffected\terrors\twarnings\terror_rate\terrors_per_report\n"
+            ));
+            for point in &points {
+                buffer.push_str(&format!(
+                    "{}\t{}\t{}\t{}\t{}\t{}\t{:.3}\t{:.2}\n",
+                    point.period_start,
+                    point.key,
+                    point.reports,
+                    point.affected_reports,
+                    point.errors,
+                    point.warnings,
+                    point.error_rate,
+                    point.errors_per_report
+                ));
+            }
+        } else {
+            let points = storage.reports().error_rate_trend(filter, bucket).await?;
+            if let Some(format) = export_format {
+                println!("{}", analytics::export(&points, format)?.trim_end());
+                return Ok(());
+            }
+            buffer.push_str(
+                "period\treports\tfailed\terrors\twarnings\tfailure_rate\terrors_per_report\n",
+            );
+            for point in &points {
+                buffer.push_str(&format!(
+                    "{}\t{}\t{}\t{}\t{}\t{:.3}\t{:.2}\n",
+                    point.period_start,
+                    point.reports,
+                    point.failed_reports,
+                    point.errors,
+                    point.warnings,
+                    point.failure_rate,
+                    point.errors_per_report
+                ));
+            }
+        }
+        self.print_output(buffer.trim_end());
+        Ok(())
+    }
+
+    /// Print stored reports, one line per report or as JSON
+    fn print_stored_reports(&self, reports: &[StoredReport], json_output: bool) -> Result<()> {
+        if json_output {
+            let rendered = serde_json::to_string_pretty(reports)
+                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?;
+            println!("{rendered}");
+            return Ok(());
+        }
+        let mut buffer = String::new();
+        if reports.is_empty() {
+            buffer.push_str("No stored reports match");
+        }
+        for stored in reports {
+            buffer.push_str(&format!(
+                "{}\t{}@{}\t{}\t{}\n",
+                stored.recorded_at.to_rfc3339(),
+                stored.schema_name,
+                stored.schema_version.as_deref().unwrap_or("-"),
+                stored.source.as_deref().unwrap_or("-"),
+                stored.report.summary()
+            ));
+        }
+        self.print_output(buffer.trim_end());
+        Ok(())
+    }
+
+    /// Parse a `--since`/`--until` value: a date (midnight UTC) or a date-time
+    fn parse_time(&self, value: &str) -> Result<chrono::DateTime<chrono::Utc>> {
+        self.timestamp_utils.parse_datetime(value).or_else(|_| {
+            let date = self.timestamp_utils.parse_date(value)?;
+            Ok(date.and_time(chrono::NaiveTime::MIN).and_utc())
+        })
+    }
+
+    async fn generate_command(
+        &self,
+        schema_path: &Path,
+        generator_name: &str,
+        output_path: &Path,
+        options: &[String],
+        fragment_cache: Option<&Path>,
+        check: bool,
+        drafts: DraftPolicy,
+    ) -> Result<()> {
+        let mut schema = self.load_schema(schema_path).await?;
+        match drafts {
+            DraftPolicy::Include => {}
+            DraftPolicy::Warn => {
+                for (element_type, name) in draft_elements(&schema) {
+                    warn!(
+                        "Generating from draft {} '{name}'",
+                        element_type_label(element_type)
+                    );
+                }
+            }
+            DraftPolicy::Exclude => schema = without_drafts(&schema),
+        }
+        let registry = GeneratorRegistry::with_defaults().await;
+
+        let resolved_name = Self::resolve_generator_name(generator_name);
+        let generator_options = self.parse_generator_options(options)?;
+        let schema_key = if schema.id.is_empty() {
+            &schema.name
+        } else {
+            &schema.id
+        };
+        let cache = fragment_cache
+            .map(|dir| Arc::new(FragmentCache::scoped(dir, &resolved_name, schema_key)));
+        let generator: Arc<dyn Generator> = match (resolved_name.as_str(), &cache) {
+            ("html", Some(cache)) => {
+                Arc::new(HtmlGenerator::new().with_fragment_cache(Arc::clone(cache)))
+            }
+            ("markdown", Some(cache)) => {
+                Arc::new(MarkdownGenerator::new().with_fragment_cache(Arc::clone(cache)))
+            }
+            // The registered generator stamps the current date
+            ("sssom", None) if generator_options.deterministic => {
+                Arc::new(SssomGenerator::with_options(
+                    SssomGeneratorConfig::default(),
+                    generator_options.clone(),
+                ))
+            }
+            (_, Some(_)) => {
+                return Err(LinkMLError::config(format!(
+                    "--fragment-cache is only supported by the html and markdown generators, not '{generator_name}'"
+                )));
+            }
+            (_, None) => registry.get(&resolved_name).await.ok_or_else(|| {
+                LinkMLError::NotImplemented(format!(
+                    "Generator '{generator_name}' is not registered"
+                ))
+            })?,
+        };
+        let generator: Arc<dyn Generator> = Arc::new(GuardedGenerator::new(
+            generator,
+            GenerationLimits::from_config(),
+        ));
+
+        generator
+            .validate_schema(&schema)
+            .map_err(|err| LinkMLError::schema_validation(err.to_string()))?;
+        let mut content = generator.generate(&schema)?;
+
+        if generator.get_file_extension() == "rs"
+            && let Some(spec) = generator_options.get_custom(rust_ast::DERIVES_OPTION)
+        {
+            content = rust_ast::add_derives_to_source(&content, &rust_ast::parse_derives(spec)?)?;
+        }
+
+        if generator_options.enable_formatting {
+            content = PostProcessorPipeline::with_defaults()
+                .process(generator.get_file_extension(), &content)?;
+        }
+
+        let extension = generator.get_file_extension();
+        if generator_options.provenance {
+            let generated_at = match generator_options.fixed_generation_time() {
+                Some(generated_at) => generated_at,
+                None => self.timestamp_utils.now()?,
+            };
+            content = Provenance::new(&schema, generator.name(), generated_at)?
+                .stamp(extension, &content);
+        }
+
+        if check {
+            return self
+                .check_generated_output(output_path, generator.as_ref(), &schema, &content)
+                .await;
+        }
+
+        let target_file = self
+            .prepare_output_path(output_path, generator.as_ref())
+            .await?;
+        self.storage()?.write(&target_file, content).await?;
+
+        if !self.cli.quiet {
+            println!("Generated output: {}", target_file.display());
+        }
+        // Markdown sites are built by other tools, so their search index is
+        // written next to the page; HTML output embeds its own
+        if resolved_name == "markdown" {
+            let index_file = target_file.with_extension("search.json");
+            let rendered = serde_json::to_string_pretty(&MarkdownGenerator::search_index(&schema))
+                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?;
+            self.storage()?.write(&index_file, rendered).await?;
+            if !self.cli.quiet {
+                println!("Wrote search index: {}", index_file.display());
+            }
+        }
+        if let Some(cache) = cache {
+            let pruned = cache.prune_unused()?;
+            if !self.cli.quiet {
+                println!("Fragment cache: {} ({pruned} stale removed)", cache.stats());
+            }
+        }
+
+        info!("Code generation completed using {}", generator.name());
+        Ok(())
+    }
+
+    /// Fail if the output at `output_path` differs from freshly generated
+    /// `content`, ignoring provenance timestamps
+    async fn check_generated_output(
+        &self,
+        output_path: &Path,
+        generator: &dyn Generator,
+        schema: &SchemaDefinition,
+        content: &str,
+    ) -> Result<()> {
+        let target_file = if output_path.is_dir() {
+            output_path.join(generator.get_default_filename())
+        } else {
+            output_path.to_path_buf()
+        };
+        let storage = self.storage()?;
+        let extension = generator.get_file_extension();
+        let drift = if storage.exists(&target_file).await? {
+            let existing = storage.read_to_string(&target_file).await?;
+            if provenance::is_up_to_date(extension, &existing, content) {
+                None
+            } else {
+                match Provenance::parse(extension, &existing) {
+                    Some(recorded) if recorded.schema_hash != Provenance::schema_hash(schema)? => {
+                        Some(format!(
+                            "schema changed since generation from version {}",
+                            recorded.schema_version.as_deref().unwrap_or("unversioned")
+                        ))
+                    }
+                    _ => Some("generated output differs".to_string()),
+                }
+            }
+        } else {
+            Some("output does not exist".to_string())
+        };
+
+        match drift {
+            None => {
+                if !self.cli.quiet {
+                    println!("Up to date: {}", target_file.display());
+                }
+                Ok(())
+            }
+            Some(reason) => Err(LinkMLError::SchemaValidationError {
+                message: format!(
+                    "{} is out of date ({reason}); regenerate it with `linkml generate`",
+                    target_file.display()
+                ),
+                element: Some(target_file.display().to_string()),
+            }),
+        }
+    }
+
+    async fn convert_command(
+        &self,
+        input: &Path,
+        output: &Path,
+        from: Option<SchemaFormat>,
+        to: SchemaFormat,
+        pretty: bool,
+        validate: bool,
+    ) -> Result<()> {
+        let input_format = from.unwrap_or_else(|| Self::detect_schema_format(input));
+        let schema = self.read_schema_with_format(input, input_format).await?;
+
+        if validate {
+            self.basic_schema_sanity_check(&schema, input)?;
+        }
+
+        let serialized = match to {
+            SchemaFormat::Yaml => serde_yaml::to_string(&schema)
+                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?,
+            SchemaFormat::Json | SchemaFormat::JsonLd => {
+                if pretty {
+                    serde_json::to_string_pretty(&schema)?
+                } else {
+                    serde_json::to_string(&schema)?
+                }
+            }
+        };
+
+        self.storage()?.write(output, serialized).await?;
+
+        if !self.cli.quiet {
+            println!(
+                "Converted schema {} -> {}",
+                input.display(),
+                output.display()
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn lint_command(
+        &self,
+        schema_path: &Path,
+        rule_filters: &[String],
+        config_path: Option<&PathBuf>,
+        apply_fixes: bool,
+        strict: bool,
+        format: LintFormat,
+    ) -> Result<()> {
+        let mut options = Self::lint_options(schema_path, config_path)?;
+        if !rule_filters.is_empty() {
+            options.filter_rules(rule_filters);
+        }
+
+        let mut schema = self.load_schema(schema_path).await?;
+        let linter = SchemaLinter::new(options);
+        let mut result = linter.lint(&schema)?;
+
+        if apply_fixes && !result.fixable_issues.is_empty() {
+            let original = schema.clone();
+            let fixed = linter.fix(&mut schema, &mut result)?;
+            if fixed > 0 {
+                Self::write_schema(schema_path, &original, &schema).await?;
+                if !self.cli.quiet {
+                    println!(
+                        "Applied {fixed} automatic fixes to {}",
+                        schema_path.display()
+                    );
+                }
+            }
+        }
+
+        let output = match format {
+            LintFormat::Pretty => Self::render_lint_pretty(&result),
+            LintFormat::Json => serde_json::to_string_pretty(&result)
+                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?,
+            LintFormat::Github => Self::render_lint_github(&result),
+            LintFormat::Junit => Self::render_lint_junit(&result),
+        };
+
+        self.print_output(&output);
+
+        let has_errors = result
+            .issues
+            .iter()
+            .any(|issue| issue.severity == Severity::Error);
+        if strict && has_errors {
+            return Err(LinkMLError::SchemaValidationError {
+                message: "Linting detected errors".to_string(),
+                element: Some(schema_path.display().to_string()),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Lint options from an explicit config, or a `.linkmllint.yaml` found next to the schema
+    fn lint_options(schema_path: &Path, config_path: Option<&PathBuf>) -> Result<LintOptions> {
+        let config_path = config_path.cloned().or_else(|| {
+            let schema_dir = schema_path.parent().unwrap_or_else(|| Path::new("."));
+            LintConfig::discover(schema_dir)
+        });
+        match &config_path {
+            Some(path) => LintOptions::from_config(
+                &LintConfig::from_file(path)?,
+                &LintRuleRegistry::default(),
+            ),
+            None => Ok(LintOptions::default()),
+        }
+    }
+
+    async fn quality_command(
+        &self,
+        schema_path: &Path,
+        config_path: Option<&PathBuf>,
+        format: QualityFormat,
+        badge_path: Option<&PathBuf>,
+        min_score: Option<f64>,
+    ) -> Result<()> {
+        let schema = self.load_schema(schema_path).await?;
+        let report =
+            QualityScorer::new(Self::lint_options(schema_path, config_path)?).score(&schema)?;
+
+        let output = match format {
+            QualityFormat::Pretty => {
+                let mut output = format!(
+                    "Schema quality of {}: {:.1}/100 (grade {})\n",
+                    report.schema, report.score, report.grade
+                );
+                for (name, metric) in [
+                    ("Lint", &report.lint),
+                    ("Documentation", &report.documentation),
+                    ("Mappings", &report.mappings),
+                    ("Complexity", &report.complexity),
+                ] {
+                    let _ = writeln!(
+                        output,
+                        "  {name:<14} {:>5.1}  (weight {:.2}; {})",
+                        metric.score, metric.weight, metric.detail
+                    );
+                }
+                output
+            }
+            QualityFormat::Json => serde_json::to_string_pretty(&report)
+                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?,
+        };
+        self.print_output(&output);
+
+        if let Some(path) = badge_path {
+            tokio::fs::write(path, report.to_badge_svg()).await?;
+            if !self.cli.quiet {
+                println!("Wrote quality badge to {}", path.display());
+            }
+        }
+
+        if let Some(min_score) = min_score
+            && !report.passes(min_score)
+        {
+            return Err(LinkMLError::SchemaValidationError {
+                message: format!(
+                    "Schema quality score {:.1} is below the minimum of {min_score}",
+                    report.score
+                ),
+                element: Some(schema_path.display().to_string()),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Run the project's lint policy, validate its data sets against their
+    /// baselines and regenerate its artifacts, reporting every failure
+    async fn check_command(&self, update_baselines: bool, skip_generate: bool) -> Result<()> {
+        let project = self.project()?.ok_or_else(|| {
+            LinkMLError::config(format!(
+                "No {PROJECT_FILE} found in the working directory or its ancestors; pass --project"
+            ))
+        })?;
+
+        let mut failures = self.check_schemas(&project).await?;
+        failures.extend(self.check_data_sets(&project, update_baselines).await?);
+        if !skip_generate {
+            for target in &project.manifest.generate {
+                let schema = Self::project_schema(Some(&project), target.schema.as_ref())?;
+                if let Err(err) = self
+                    .generate_command(
+                        &schema,
+                        &target.generator,
+                        &target.output,
+                        &target.options,
+                        None,
+                        false,
+                        DraftPolicy::Include,
+                    )
+                    .await
+                {
+                    failures.push(format!("generator {}: {err}", target.generator));
+                }
+            }
+        }
+
+        if failures.is_empty() {
+            if !self.cli.quiet {
+                println!("Project check passed");
+            }
+            return Ok(());
+        }
+        for failure in &failures {
+            eprintln!("  {failure}");
+        }
+        Err(LinkMLError::service(format!(
+            "Project check failed with {} problem(s)",
+            failures.len()
+        )))
+    }
+
+    /// Lint and score the project's schemas against its lint policy
+    async fn check_schemas(&self, project: &Project) -> Result<Vec<String>> {
+        let policy = &project.manifest.lint;
+        let mut failures = Vec::new();
+        for schema_path in &project.manifest.schemas {
+            let name = project.relative(schema_path);
+            let schema = self.load_schema(schema_path).await?;
+            let result =
+                SchemaLinter::new(Self::lint_options(schema_path, policy.config.as_ref())?)
+                    .lint(&schema)?;
+            let errors = result
+                .issues
+                .iter()
+                .filter(|issue| issue.severity == Severity::Error)
+                .count();
+            if !self.cli.quiet {
+                println!(
+                    "Lint {name}: {} issue(s), {errors} error(s)",
+                    result.issues.len()
+                );
+            }
+            if policy.strict && errors > 0 {
+                failures.push(format!("{name}: {errors} lint error(s)"));
+            }
+
+            if let Some(min_score) = policy.min_quality {
+                let report =
+                    QualityScorer::new(Self::lint_options(schema_path, policy.config.as_ref())?)
+                        .score(&schema)?;
+                if !self.cli.quiet {
+                    println!("Quality {name}: {:.1}/100", report.score);
+                }
+                if !report.passes(min_score) {
+                    failures.push(format!(
+                        "{name}: quality score {:.1} is below the minimum of {min_score}",
+                        report.score
+                    ));
+                }
+            }
+        }
+        Ok(failures)
+    }
+
+    /// Validate the project's data sets, comparing them with their baselines
+    /// or accepting their current issues when `update_baselines` is set
+    async fn check_data_sets(
+        &self,
+        project: &Project,
+        update_baselines: bool,
+    ) -> Result<Vec<String>> {
+        let mut failures = Vec::new();
+        for data_set in &project.manifest.validate {
+            let schema_path = Self::project_schema(Some(project), data_set.schema.as_ref())?;
+            let validator = self.data_validator(&schema_path, None).await?;
+            let options = ValidationOptions {
+                rule_config: data_set
+                    .rules
+                    .as_deref()
+                    .map(ValidationRuleConfig::from_file)
+                    .transpose()?
+                    .map(Arc::new),
+                ..ValidationOptions::default()
+            };
+
+            let mut reports = Vec::new();
+            for data_path in discovery::collect_data_files(&data_set.data)? {
+                let mut value = self.load_data_value(&data_path).await?;
+                discovery::strip_schema_key(&mut value);
+                let report = match &data_set.class {
+                    Some(class) => {
+                        validator
+                            .engine
+                            .validate_as_class(&value, class, Some(options.clone()))
+                            .await?
+                    }
+                    None => {
+                        validator
+                            .engine
+                            .validate(&value, Some(options.clone()))
+                            .await?
+                    }
+                };
+                reports.push((project.relative(&data_path), report));
+            }
+            let reports: Vec<(String, &ValidationReport)> = reports
+                .iter()
+                .map(|(file, report)| (file.clone(), report))
+                .collect();
+            let invalid = reports.iter().filter(|(_, report)| !report.valid).count();
+            if !self.cli.quiet {
+                println!(
+                    "Validate {}: {} file(s), {invalid} invalid",
+                    data_set
+                        .data
+                        .iter()
+                        .map(|path| project.relative(path))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    reports.len()
+                );
+            }
+
+            let Some(baseline_path) = &data_set.baseline else {
+                failures.extend(
+                    reports
+                        .iter()
+                        .filter(|(_, report)| !report.valid)
+                        .map(|(file, _)| format!("{file}: validation failed")),
+                );
+                continue;
+            };
+            if update_baselines {
+                ValidationBaseline::from_reports(&reports).save(baseline_path)?;
+                if !self.cli.quiet {
+                    println!("Updated baseline {}", project.relative(baseline_path));
+                }
+                continue;
+            }
+            let comparison = ValidationBaseline::load(baseline_path)?.compare(&reports);
+            for (file, issues) in &comparison.new {
+                for issue in issues {
+                    failures.push(format!(
+                        "{file}: new issue at {}: {}",
+                        issue.path, issue.message
+                    ));
+                }
+            }
+            if comparison.fixed_count() > 0 && !self.cli.quiet {
+                println!(
+                    "{} baseline issue(s) fixed; run `linkml check --update-baselines` to shrink {}",
+                    comparison.fixed_count(),
+                    project.relative(baseline_path)
+                );
+            }
+        }
+        Ok(failures)
+    }
+
+    fn format_command(
+        &self,
+        schemas: &[PathBuf],
+        options: &FormatOptions,
+        check: bool,
+        to_stdout: bool,
+    ) -> Result<()> {
+        let formatter = SchemaFormatter::new(options.clone());
+        let mut unformatted = Vec::new();
+
+        for path in schemas {
+            let outcome = formatter.format_file(path, !check && !to_stdout)?;
+            if to_stdout {
+                print!("{}", outcome.formatted);
+            } else if check {
+                if outcome.changed {
+                    println!("Would reformat {}", path.display());
+                    unformatted.push(path.display().to_string());
+                }
+            } else if outcome.changed && !self.cli.quiet {
+                println!("Formatted {}", path.display());
+            }
+        }
+
+        if !unformatted.is_empty() {
+            return Err(LinkMLError::SchemaValidationError {
+                message: format!(
+                    "{} of {} schema(s) are not canonically formatted",
+                    unformatted.len(),
+                    schemas.len()
+                ),
+                element: Some(unformatted.join(", ")),
+            });
+        }
+        if check && !self.cli.quiet {
+            println!("{} schema(s) already formatted", schemas.len());
+        }
+        Ok(())
+    }
+
+    /// Write an edited schema back to disk in the format implied by its
+    /// extension
+    ///
+    /// YAML files are edited in place from `original`, the schema as loaded,
+    /// so only the changed lines are rewritten; files that cannot be edited
+    /// in place are rewritten in canonical form.
+    async fn write_schema(
+        path: &Path,
+        original: &SchemaDefinition,
+        schema: &SchemaDefinition,
+    ) -> Result<()> {
+        let rendered = match Self::detect_schema_format(path) {
+            SchemaFormat::Json | SchemaFormat::JsonLd => serde_json::to_string_pretty(schema)
+                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?,
+            SchemaFormat::Yaml => {
+                let content = fs::read_to_string(path).await?;
+                match update_schema_text(&content, original, schema) {
+                    Ok(updated) => updated,
+                    Err(_) => SchemaFormatter::default().format_schema(schema)?,
+                }
+            }
+        };
+        fs::write(path, rendered).await?;
+        Ok(())
+    }
+
+    async fn search_command(
         &self,
         schema_path: &Path,
-        generator_name: &str,
-        output_path: &Path,
-        options: &[String],
+        query: &str,
+        limit: usize,
+        element_types: &[SearchElementType],
+        synonyms: &[String],
+        docs_index: Option<&PathBuf>,
     ) -> Result<()> {
-        let schema = self.load_schema(schema_path).await?;
-        let registry = GeneratorRegistry::with_defaults().await;
-
-        let resolved_name = Self::resolve_generator_name(generator_name);
-        let generator = registry.get(&resolved_name).await.ok_or_else(|| {
-            LinkMLError::NotImplemented(format!("Generator '{generator_name}' is not registered"))
-        })?;
-
-        let generator_options = self.parse_generator_options(options)?;
-        generator
-            .validate_schema(&schema)
-            .map_err(|err| LinkMLError::schema_validation(err.to_string()))?;
-        let content = generator.generate(&schema)?;
+        let mut options = SearchOptions::default()
+            .with_limit(limit)
+            .with_element_types(
+                element_types
+                    .iter()
+                    .map(|element_type| match element_type {
+                        SearchElementType::Class => ElementType::Class,
+                        SearchElementType::Slot => ElementType::Slot,
+                        SearchElementType::Type => ElementType::Type,
+                        SearchElementType::Enum => ElementType::Enum,
+                        SearchElementType::Subset => ElementType::Subset,
+                    })
+                    .collect(),
+            );
+        for entry in synonyms {
+            let (term, values) = entry.split_once('=').ok_or_else(|| {
+                LinkMLError::config(format!(
+                    "Invalid synonym '{entry}', expected TERM=SYNONYM[,SYNONYM...]"
+                ))
+            })?;
+            options = options.with_synonyms(term.trim(), values.split(',').map(str::trim));
+        }
 
-        let target_file = self
-            .prepare_output_path(output_path, generator.as_ref())
-            .await?;
-        fs::write(&target_file, content)
-            .await
-            .map_err(LinkMLError::from)?;
+        let schema = self.load_schema(schema_path).await?;
+        let view = SchemaView::new(schema)?;
+        let index = view.search_index()?;
+
+        if let Some(path) = docs_index {
+            let rendered = serde_json::to_string_pretty(&index.to_docs_index())
+                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?;
+            fs::write(path, rendered).await?;
+            if !self.cli.quiet {
+                println!("Wrote search index: {}", path.display());
+            }
+        }
 
-        if !self.cli.quiet {
-            println!("Generated output: {}", target_file.display());
+        let hits = index.search(query, &options);
+        if matches!(self.cli.format, OutputFormat::Json) {
+            let rendered = serde_json::to_string_pretty(&hits)
+                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?;
+            println!("{rendered}");
+            return Ok(());
         }
 
-        info!("Code generation completed using {}", generator.name());
-        drop(generator_options);
+        let mut buffer = String::new();
+        if hits.is_empty() {
+            write!(&mut buffer, "No elements match '{query}'")
+                .map_err(|e| LinkMLError::service(format!("Failed to write to buffer: {e}")))?;
+        }
+        for hit in &hits {
+            writeln!(
+                &mut buffer,
+                "{:.2}\t{}\t{}\t{:?}: {}",
+                hit.score,
+                element_type_label(hit.element_type),
+                hit.name,
+                hit.field,
+                hit.matched_text
+            )
+            .map_err(|e| LinkMLError::service(format!("Failed to write to buffer: {e}")))?;
+        }
+        self.print_output(buffer.trim_end());
         Ok(())
     }
 
-    async fn convert_command(
+    async fn suggest_mappings_command(
         &self,
-        input: &Path,
-        output: &Path,
-        from: Option<SchemaFormat>,
-        to: SchemaFormat,
-        pretty: bool,
-        validate: bool,
+        schema_path: &Path,
+        output: Option<&PathBuf>,
+        service_kinds: &[TerminologyServiceKind],
+        ontologies: &[String],
+        ols_url: &str,
+        max_per_element: usize,
+        include_mapped: bool,
     ) -> Result<()> {
-        let input_format = from.unwrap_or_else(|| Self::detect_schema_format(input));
-        let schema = self.read_schema_with_format(input, input_format).await?;
-
-        if validate {
-            self.basic_schema_sanity_check(&schema, input)?;
-        }
-
-        let serialized = match to {
-            SchemaFormat::Yaml => serde_yaml::to_string(&schema)
-                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?,
-            SchemaFormat::Json | SchemaFormat::JsonLd => {
-                if pretty {
-                    serde_json::to_string_pretty(&schema)?
-                } else {
-                    serde_json::to_string(&schema)?
+        let mut services: Vec<Arc<dyn TerminologyService>> = Vec::new();
+        for kind in service_kinds {
+            match kind {
+                TerminologyServiceKind::Ols => services.push(Arc::new(
+                    OlsClient::with_base_url(ols_url).with_ontologies(ontologies.to_vec()),
+                )),
+                TerminologyServiceKind::Bioportal => {
+                    let api_key = std::env::var("BIOPORTAL_API_KEY").map_err(|_| {
+                        LinkMLError::config("BioPortal requires the BIOPORTAL_API_KEY variable")
+                    })?;
+                    services.push(Arc::new(
+                        BioPortalClient::new(api_key).with_ontologies(ontologies.to_vec()),
+                    ));
                 }
             }
-        };
-
-        if let Some(parent) = output.parent()
-            && !parent.as_os_str().is_empty()
-        {
-            fs::create_dir_all(parent).await?;
         }
 
-        fs::write(output, serialized).await?;
-
-        if !self.cli.quiet {
-            println!(
-                "Converted schema {} -> {}",
-                input.display(),
-                output.display()
-            );
+        let schema = self.load_schema(schema_path).await?;
+        let suggester = MappingSuggester::new(services).with_options(SuggestOptions {
+            max_suggestions_per_element: max_per_element,
+            skip_mapped: !include_mapped,
+            ..SuggestOptions::default()
+        });
+        let suggestions = suggester.suggest(&schema).await?;
+        let tsv = mapping_suggest::to_sssom_tsv(&schema, &suggestions);
+
+        if let Some(path) = output {
+            fs::write(path, tsv).await?;
+            if !self.cli.quiet {
+                println!(
+                    "Wrote {} mapping suggestions for review: {}",
+                    suggestions.len(),
+                    path.display()
+                );
+            }
+        } else {
+            print!("{tsv}");
         }
-
         Ok(())
     }
 
-    async fn lint_command(
+    async fn manifest_command(
         &self,
+        directory: &Path,
         schema_path: &Path,
-        rule_filters: &[String],
-        config_path: Option<&PathBuf>,
-        apply_fixes: bool,
-        strict: bool,
-        format: LintFormat,
+        output: Option<&Path>,
+        mut options: ManifestOptions,
     ) -> Result<()> {
-        let mut options = LintOptions::default();
-        if !rule_filters.is_empty() {
-            options.filter_rules(rule_filters);
+        let schema = self.load_schema(schema_path).await?;
+        if let Some(path) = output {
+            options = options.with_exclude(path);
         }
+        let manifest = ManifestBuilder::new(&schema)
+            .with_options(options)
+            .scan(directory)
+            .await?;
 
-        if let Some(config) = config_path {
-            let config_content = fs::read_to_string(config).await.map_err(|err| {
-                LinkMLError::DataValidationError {
-                    message: format!("Failed to read lint config: {err}"),
-                    path: Some(config.display().to_string()),
-                    expected: Some("readable file".to_string()),
-                    actual: Some("read error".to_string()),
+        let as_json = output.map_or(matches!(self.cli.format, OutputFormat::Json), |path| {
+            path.extension().is_some_and(|ext| ext == "json")
+        });
+        let rendered = if as_json {
+            serde_json::to_string_pretty(&manifest)
+                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?
+        } else {
+            serde_yaml::to_string(&manifest)
+                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?
+        };
+
+        match output {
+            Some(path) => {
+                fs::write(path, rendered).await?;
+                if !self.cli.quiet {
+                    println!(
+                        "Wrote manifest for {} files ({} records) to {}",
+                        manifest.summary.file_count,
+                        manifest.summary.total_records,
+                        path.display()
+                    );
                 }
-            })?;
-            let parsed: HashMap<String, serde_json::Value> = serde_yaml::from_str(&config_content)
-                .or_else(|_| serde_json::from_str(&config_content))
-                .map_err(|err| LinkMLError::config(format!("Invalid lint config: {err}")))?;
-            options.apply_config(parsed);
+            }
+            None => print!("{rendered}"),
         }
+        Ok(())
+    }
 
-        let schema = self.load_schema(schema_path).await?;
-        let linter = SchemaLinter::new(options);
-        let mut result = linter.lint(&schema)?;
-
-        if apply_fixes {
-            let mut mutable_schema = schema.clone();
-            let fixed = linter.fix(&mut mutable_schema, &mut result)?;
-            if fixed > 0 && !self.cli.quiet {
-                println!("Applied {fixed} automatic fixes");
-            }
+    fn error_codes_command(&self, markdown: bool) {
+        if markdown {
+            print!("{}", error_codes::render_markdown());
+            return;
+        }
+        if matches!(self.cli.format, OutputFormat::Json) {
+            let registry = serde_json::to_string_pretty(&error_codes::registry())
+                .unwrap_or_else(|_| "[]".to_string());
+            println!("{registry}");
+            return;
+        }
+        for info in error_codes::registry() {
+            println!("{}\t{}\t{}", info.code, info.name, info.summary);
         }
+    }
 
-        let output = match format {
-            LintFormat::Pretty => Self::render_lint_pretty(&result),
-            LintFormat::Json => serde_json::to_string_pretty(&result)
-                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?,
-            LintFormat::Github => Self::render_lint_github(&result),
-            LintFormat::Junit => Self::render_lint_junit(&result),
+    async fn messages_command(
+        &self,
+        schema_path: Option<&Path>,
+        format: Option<MessageBundleFormat>,
+        output_path: Option<&Path>,
+    ) -> Result<()> {
+        let schema = match schema_path {
+            Some(path) => Some(self.load_schema(path).await?),
+            None => None,
         };
+        let format = match format {
+            Some(MessageBundleFormat::Fluent) => BundleFormat::Fluent,
+            Some(MessageBundleFormat::Gettext) => BundleFormat::Gettext,
+            None => output_path
+                .and_then(BundleFormat::from_path)
+                .unwrap_or(BundleFormat::Fluent),
+        };
+        let rendered = MessageBundle::extract(schema.as_ref()).render(format);
 
-        self.print_output(&output);
-
-        let has_errors = result
-            .issues
-            .iter()
-            .any(|issue| issue.severity == Severity::Error);
-        if strict && has_errors {
-            return Err(LinkMLError::SchemaValidationError {
-                message: "Linting detected errors".to_string(),
-                element: Some(schema_path.display().to_string()),
-            });
+        if let Some(path) = output_path {
+            if let Some(parent) = path.parent()
+                && !parent.as_os_str().is_empty()
+            {
+                fs::create_dir_all(parent).await?;
+            }
+            fs::write(path, &rendered).await?;
+        } else {
+            print!("{rendered}");
         }
-
         Ok(())
     }
 
@@ -537,8 +1998,7 @@ impl LinkMLApp {
         let rendered = match format {
             DiffFormat::Unified => self.render_diff_unified(schema1, schema2, &diff),
             DiffFormat::SideBySide => Self::render_diff_side_by_side(&diff),
-            DiffFormat::JsonPatch => serde_json::to_string_pretty(&diff)
-                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?,
+            DiffFormat::JsonPatch => create_patch(&first, &second)?.to_json_patch()?,
             DiffFormat::Html => Self::render_diff_html(&diff),
             DiffFormat::Markdown => Self::render_diff_markdown(&diff),
         };
@@ -557,6 +2017,58 @@ impl LinkMLApp {
         Ok(())
     }
 
+    async fn patch_command(
+        &self,
+        schema_path: &Path,
+        patch_path: &Path,
+        check: bool,
+        allow_breaking: bool,
+    ) -> Result<()> {
+        let mut schema = self.load_schema(schema_path).await?;
+        let patch = SchemaPatch::parse(&fs::read_to_string(patch_path).await?)?;
+        let patcher = SchemaPatcher::new(PatchOptions {
+            allow_breaking,
+            ..PatchOptions::default()
+        });
+
+        if check {
+            let conflicts = patcher.check_patch(&schema, &patch);
+            if conflicts.is_empty() {
+                if !self.cli.quiet {
+                    println!(
+                        "{} applies cleanly to {}",
+                        patch_path.display(),
+                        schema_path.display()
+                    );
+                }
+                return Ok(());
+            }
+            for conflict in &conflicts {
+                println!("{conflict}");
+            }
+            return Err(LinkMLError::SchemaValidationError {
+                message: format!(
+                    "{} conflict(s) applying {}",
+                    conflicts.len(),
+                    patch_path.display()
+                ),
+                element: Some(schema_path.display().to_string()),
+            });
+        }
+
+        let original = schema.clone();
+        patcher.apply_atomic(&mut schema, &patch)?;
+        Self::write_schema(schema_path, &original, &schema).await?;
+        if !self.cli.quiet {
+            println!(
+                "Applied {} operation(s) to {}",
+                patch.operations.len(),
+                schema_path.display()
+            );
+        }
+        Ok(())
+    }
+
     async fn merge_command(
         &self,
         schemas: &[PathBuf],
@@ -616,6 +2128,37 @@ impl LinkMLApp {
         Ok(())
     }
 
+    async fn materialize_command(
+        &self,
+        schema_path: &Path,
+        output: Option<&PathBuf>,
+    ) -> Result<()> {
+        // Loading through the view resolves imports relative to the schema
+        let view = SchemaView::load_from_file(schema_path).await?;
+        let materialized = view.materialize()?;
+        let serialized = serde_yaml::to_string(&materialized)
+            .map_err(|err| LinkMLError::SerializationError(err.to_string()))?;
+
+        if let Some(path) = output {
+            if let Some(parent) = path.parent()
+                && !parent.as_os_str().is_empty()
+            {
+                fs::create_dir_all(parent).await?;
+            }
+            fs::write(path, serialized).await?;
+            if !self.cli.quiet {
+                println!(
+                    "Materialized {} classes into {}",
+                    materialized.classes.len(),
+                    path.display()
+                );
+            }
+        } else {
+            print!("{serialized}");
+        }
+        Ok(())
+    }
+
     async fn load_command(
         &self,
         schema_path: &Path,
@@ -642,19 +2185,20 @@ impl LinkMLApp {
         }
 
         // Load data based on format
+        let storage = self.storage()?;
         let loaded_data = match format {
             LoadFormat::Json => {
-                let content = fs::read_to_string(input_path).await?;
+                let content = storage.read_to_string(input_path).await?;
                 serde_json::from_str::<serde_json::Value>(&content)
                     .map_err(|e| LinkMLError::data_validation(format!("JSON parse error: {e}")))?
             }
             LoadFormat::Yaml => {
-                let content = fs::read_to_string(input_path).await?;
+                let content = storage.read_to_string(input_path).await?;
                 serde_yaml::from_str::<serde_json::Value>(&content)
                     .map_err(|e| LinkMLError::data_validation(format!("YAML parse error: {e}")))?
             }
             LoadFormat::Csv => {
-                let content = fs::read_to_string(input_path).await?;
+                let content = storage.read_to_string(input_path).await?;
                 self.load_csv_data(&content, &load_options)?
             }
             LoadFormat::Xml => {
@@ -698,6 +2242,12 @@ impl LinkMLApp {
                 use_cache: Some(true),
                 fail_on_warning: None,
                 custom_validators: Vec::new(),
+                rule_config: None,
+                normalize: None,
+                convert_units: None,
+                null_policy: None,
+                empty_collection_policy: None,
+                ..ValidationOptions::default()
             };
 
             let report = if let Some(target_class) = class_name {
@@ -731,12 +2281,7 @@ impl LinkMLApp {
             _ => serde_json::to_string_pretty(&loaded_data)?,
         };
 
-        // Ensure output directory exists
-        if let Some(parent) = output_path.parent() {
-            fs::create_dir_all(parent).await?;
-        }
-
-        fs::write(output_path, output_content).await?;
+        self.storage()?.write(output_path, output_content).await?;
 
         if !self.cli.quiet {
             println!(
@@ -814,12 +2359,7 @@ impl LinkMLApp {
             }
         };
 
-        // Ensure output directory exists
-        if let Some(parent) = output_path.parent() {
-            fs::create_dir_all(parent).await?;
-        }
-
-        fs::write(output_path, output_content).await?;
+        self.storage()?.write(output_path, output_content).await?;
 
         if !self.cli.quiet {
             println!(
@@ -846,6 +2386,7 @@ impl LinkMLApp {
         schema_id: Option<&String>,
         schema_name: Option<&String>,
         format: SchemaFormat,
+        credentials: Option<&PathBuf>,
         progress: bool,
     ) -> Result<()> {
         use crate::cli_enhanced::commands::sheets2schema::{
@@ -864,6 +2405,10 @@ impl LinkMLApp {
             command = command.with_schema_name(name.clone());
         }
 
+        if let Some(credentials) = credentials {
+            command = command.with_credentials(credentials.clone());
+        }
+
         let cmd_format = match format {
             SchemaFormat::Yaml => CmdSchemaFormat::Yaml,
             SchemaFormat::Json => CmdSchemaFormat::Json,
@@ -884,19 +2429,54 @@ impl LinkMLApp {
         metadata_sheets: bool,
         _freeze_headers: bool,
         _filters: bool,
+        credentials: Option<&PathBuf>,
+        data_entry: bool,
         progress: bool,
     ) -> Result<()> {
         use crate::cli_enhanced::commands::schema2sheets::Schema2SheetsCommand;
 
-        let command = Schema2SheetsCommand::new(schema.to_path_buf(), output.to_path_buf())
+        let mut command = Schema2SheetsCommand::new(schema.to_path_buf(), output.to_path_buf())
             .with_metadata(include_metadata)
             .with_metadata_sheets(metadata_sheets)
+            .with_data_entry(data_entry)
             .with_progress(progress && !self.cli.quiet)
             .with_verbose(self.cli.verbose);
+        if let Some(credentials) = credentials {
+            command = command.with_credentials(credentials.clone());
+        }
 
         command.execute().await
     }
 
+    /// Storage for local paths and `--storage-option` configured object stores
+    fn storage(&self) -> Result<ObjectStorage> {
+        Ok(ObjectStorage::new(ObjectStoreOptions::from_pairs(
+            &self.cli.storage_options,
+        )?))
+    }
+
+    /// The `--project` file, or the nearest `linkml.yaml` above the working
+    /// directory
+    fn project(&self) -> Result<Option<Project>> {
+        match &self.cli.project {
+            Some(path) => Project::load(path).map(Some),
+            None => Project::discover(&std::env::current_dir()?),
+        }
+    }
+
+    /// An explicit schema, or the project's default schema
+    fn project_schema(project: Option<&Project>, schema: Option<&PathBuf>) -> Result<PathBuf> {
+        schema
+            .map(PathBuf::as_path)
+            .or_else(|| project.and_then(Project::default_schema))
+            .map(Path::to_path_buf)
+            .ok_or_else(|| {
+                LinkMLError::config(format!(
+                    "No schema given and no {PROJECT_FILE} project defines one"
+                ))
+            })
+    }
+
     async fn load_schema(&self, path: &Path) -> Result<SchemaDefinition> {
         let format = Self::detect_schema_format(path);
         self.read_schema_with_format(path, format).await
@@ -907,57 +2487,81 @@ impl LinkMLApp {
         path: &Path,
         format: SchemaFormat,
     ) -> Result<SchemaDefinition> {
-        let content =
-            fs::read_to_string(path)
-                .await
-                .map_err(|err| LinkMLError::DataValidationError {
-                    message: format!("Failed to read schema file: {err}"),
-                    path: Some(path.display().to_string()),
-                    expected: Some("readable file".to_string()),
-                    actual: Some("read error".to_string()),
-                })?;
+        let content = self.storage()?.read_to_string(path).await.map_err(|err| {
+            LinkMLError::DataValidationError {
+                message: format!("Failed to read schema file: {err}"),
+                path: Some(path.display().to_string()),
+                expected: Some("readable file".to_string()),
+                actual: Some("read error".to_string()),
+            }
+        })?;
 
         let schema = match format {
-            SchemaFormat::Yaml => {
-                serde_yaml::from_str(&content).map_err(|err| LinkMLError::ParseError {
+            SchemaFormat::Yaml => serde_yaml::from_str(&content)
+                .map(expanded)
+                .map_err(|err| LinkMLError::ParseError {
                     message: err.to_string(),
                     location: Some(path.display().to_string()),
-                })?
-            }
-            SchemaFormat::Json | SchemaFormat::JsonLd => {
-                serde_json::from_str(&content).map_err(|err| LinkMLError::ParseError {
+                })?,
+            SchemaFormat::Json | SchemaFormat::JsonLd => serde_json::from_str(&content)
+                .map(expanded)
+                .map_err(|err| LinkMLError::ParseError {
                     message: err.to_string(),
                     location: Some(path.display().to_string()),
-                })?
-            }
+                })?,
         };
 
         Ok(schema)
     }
 
     async fn load_data_value(&self, path: &Path) -> Result<Value> {
-        let content =
-            fs::read_to_string(path)
-                .await
-                .map_err(|err| LinkMLError::DataValidationError {
-                    message: format!("Failed to read data file: {err}"),
-                    path: Some(path.display().to_string()),
-                    expected: Some("readable file".to_string()),
-                    actual: Some("read error".to_string()),
-                })?;
+        let content = self.read_data_file(path).await?;
+        Self::parse_data_value(path, &content)
+    }
+
+    async fn read_data_file(&self, path: &Path) -> Result<String> {
+        self.storage()?
+            .read_to_string(path)
+            .await
+            .map_err(|err| LinkMLError::DataValidationError {
+                message: format!("Failed to read data file: {err}"),
+                path: Some(path.display().to_string()),
+                expected: Some("readable file".to_string()),
+                actual: Some("read error".to_string()),
+            })
+    }
 
+    /// Parse data file content as YAML or `JSON` by extension
+    fn parse_data_value(path: &Path, content: &str) -> Result<Value> {
         if matches!(
             path.extension().and_then(|ext| ext.to_str()),
             Some("yaml" | "yml")
         ) {
-            serde_yaml::from_str(&content)
+            serde_yaml::from_str(content)
                 .map_err(|err| LinkMLError::data_validation(format!("YAML parse error: {err}")))
         } else {
-            serde_json::from_str(&content)
+            serde_json::from_str(content)
                 .map_err(|err| LinkMLError::data_validation(format!("JSON parse error: {err}")))
         }
     }
 
+    /// Write a data value back to its file, as YAML or `JSON` by extension
+    async fn write_data_value(&self, path: &Path, value: &Value) -> Result<()> {
+        let content = if matches!(
+            path.extension().and_then(|ext| ext.to_str()),
+            Some("yaml" | "yml")
+        ) {
+            serde_yaml::to_string(value)
+                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?
+        } else {
+            let mut json = serde_json::to_string_pretty(value)
+                .map_err(|err| LinkMLError::SerializationError(err.to_string()))?;
+            json.push('\n');
+            json
+        };
+        self.storage()?.write(path, content).await
+    }
+
     fn basic_schema_sanity_check(&self, schema: &SchemaDefinition, source: &Path) -> Result<()> {
         if schema.name.trim().is_empty() {
             return Err(LinkMLError::schema_validation(format!(
@@ -993,6 +2597,14 @@ impl LinkMLApp {
         output_path: &Path,
         generator: &dyn Generator,
     ) -> Result<PathBuf> {
+        if object_uri(output_path).is_some() {
+            return Ok(if output_path.extension().is_some() {
+                output_path.to_path_buf()
+            } else {
+                output_path.join(generator.get_default_filename())
+            });
+        }
+
         if let Ok(metadata) = fs::metadata(output_path).await
             && metadata.is_dir()
         {
@@ -1013,8 +2625,17 @@ impl LinkMLApp {
         }
     }
 
+    fn draft_policy(policy: DraftElementPolicy) -> DraftPolicy {
+        match policy {
+            DraftElementPolicy::Include => DraftPolicy::Include,
+            DraftElementPolicy::Warn => DraftPolicy::Warn,
+            DraftElementPolicy::Exclude => DraftPolicy::Exclude,
+        }
+    }
+
     fn parse_generator_options(&self, options: &[String]) -> Result<GeneratorOptions> {
-        let mut generator_options = GeneratorOptions::default();
+        let mut generator_options = GeneratorOptions::default()
+            .with_formatting(crate::config::get_config().generator.enable_formatting);
 
         // Parse key=value pairs from options
         for option in options {
@@ -1058,6 +2679,16 @@ impl LinkMLApp {
                             )));
                         }
                     },
+                    "format" => match value.trim().to_lowercase().as_str() {
+                        "true" | "yes" | "1" => generator_options.enable_formatting = true,
+                        "false" | "no" | "0" => generator_options.enable_formatting = false,
+                        _ => {
+                            return Err(LinkMLError::config(format!(
+                                "Invalid format value: '{}'. Expected boolean.",
+                                value.trim()
+                            )));
+                        }
+                    },
                     "namespace" => {
                         let namespace = value.trim().to_string();
                         if namespace.is_empty() {
@@ -1080,9 +2711,37 @@ impl LinkMLApp {
                             .custom
                             .insert("package_name".to_string(), package);
                     }
+                    "provenance" => match value.trim().to_lowercase().as_str() {
+                        "true" | "yes" | "1" => generator_options.provenance = true,
+                        "false" | "no" | "0" => generator_options.provenance = false,
+                        _ => {
+                            return Err(LinkMLError::config(format!(
+                                "Invalid provenance value: '{}'. Expected boolean.",
+                                value.trim()
+                            )));
+                        }
+                    },
+                    "deterministic" => match value.trim().to_lowercase().as_str() {
+                        "true" | "yes" | "1" => generator_options.deterministic = true,
+                        "false" | "no" | "0" => generator_options.deterministic = false,
+                        _ => {
+                            return Err(LinkMLError::config(format!(
+                                "Invalid deterministic value: '{}'. Expected boolean.",
+                                value.trim()
+                            )));
+                        }
+                    },
+                    "derives" => {
+                        // Validate eagerly so typos surface before generation
+                        rust_ast::parse_derives(value)?;
+                        generator_options.custom.insert(
+                            rust_ast::DERIVES_OPTION.to_string(),
+                            value.trim().to_string(),
+                        );
+                    }
                     unknown_key => {
                         return Err(LinkMLError::config(format!(
-                            "Unknown generator option: '{}'. Supported options: indent, pretty, include_docs, namespace, package",
+                            "Unknown generator option: '{}'. Supported options: indent, pretty, include_docs, format, provenance, deterministic, namespace, package, derives",
                             unknown_key
                         )));
                     }
@@ -1282,6 +2941,7 @@ impl LinkMLApp {
         &self,
         data_path: &Path,
         report: &mut ValidationReport,
+        source_map: Option<&SourceMap>,
         max_errors: usize,
         show_stats: bool,
     ) -> Result<()> {
@@ -1298,6 +2958,32 @@ impl LinkMLApp {
             for issue in report.issues.iter().take(max_errors.max(1)) {
                 writeln!(&mut buffer, "  {issue}")
                     .map_err(|e| LinkMLError::service(format!("Failed to write to buffer: {e}")))?;
+                if let Some(source_map) = source_map
+                    && let Some(location) = SourceLocation::of_issue(issue)
+                {
+                    writeln!(&mut buffer, "    --> {}:{location}", data_path.display()).map_err(
+                        |e| LinkMLError::service(format!("Failed to write to buffer: {e}")),
+                    )?;
+                    for line in source_map.snippet(location, 1) {
+                        writeln!(&mut buffer, "    {line}").map_err(|e| {
+                            LinkMLError::service(format!("Failed to write to buffer: {e}"))
+                        })?;
+                    }
+                }
+                if let Some(explanation) = &issue.explanation {
+                    let lines = explanation.lines();
+                    for (index, line) in lines.iter().enumerate() {
+                        let border = match index {
+                            0 if lines.len() == 1 => '─',
+                            0 => '┌',
+                            _ if index + 1 == lines.len() => '└',
+                            _ => '│',
+                        };
+                        writeln!(&mut buffer, "    {border} {line}").map_err(|e| {
+                            LinkMLError::service(format!("Failed to write to buffer: {e}"))
+                        })?;
+                    }
+                }
             }
             if report.issues.len() > max_errors {
                 writeln!(
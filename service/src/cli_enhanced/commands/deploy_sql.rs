@@ -0,0 +1,124 @@
+//! Direct `PostgreSQL` schema deployment
+//!
+//! `linkml deploy-sql schema.yaml --dsn postgres://…` generates `PostgreSQL`
+//! DDL with the [`SQLGenerator`], compares it with the catalog of the live
+//! database and applies the missing enum types, tables, columns, constraints
+//! and indexes in one transaction. With `--dry-run` the migration script is
+//! printed instead. Objects the schema does not declare are reported, never
+//! dropped.
+
+use linkml_core::{
+    error::{LinkMLError, Result},
+    types::SchemaDefinition,
+};
+
+use crate::generator::{AsyncGenerator, GeneratorOptions, SQLGenerator};
+use crate::loader::{DatabaseOptions, SqlDeployer};
+
+/// Command deploying a schema to a `PostgreSQL` database
+pub struct DeploySqlCommand {
+    /// Schema to deploy
+    pub schema: SchemaDefinition,
+    /// `PostgreSQL` connection string
+    pub dsn: String,
+    /// Database schema the tables live in
+    pub db_schema: String,
+    /// Print the migration instead of applying it
+    pub dry_run: bool,
+}
+
+impl DeploySqlCommand {
+    /// Create a deployment command for a loaded schema
+    #[must_use]
+    pub fn new(schema: SchemaDefinition, dsn: impl Into<String>) -> Self {
+        Self {
+            schema,
+            dsn: dsn.into(),
+            db_schema: "public".to_string(),
+            dry_run: false,
+        }
+    }
+
+    /// Set the database schema the tables live in
+    #[must_use]
+    pub fn with_db_schema(mut self, db_schema: impl Into<String>) -> Self {
+        self.db_schema = db_schema.into();
+        self
+    }
+
+    /// Print the migration instead of applying it
+    #[must_use]
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Generate the `PostgreSQL` DDL of the schema
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SQL generator rejects the schema
+    pub async fn ddl(&self) -> Result<String> {
+        let options = GeneratorOptions::new()
+            .with_docs(false)
+            .set_custom("dialect", "postgresql");
+        let outputs = AsyncGenerator::generate(&SQLGenerator::new(), &self.schema, &options)
+            .await
+            .map_err(|e| LinkMLError::service(format!("Failed to generate SQL DDL: {e}")))?;
+        Ok(outputs
+            .into_iter()
+            .map(|output| output.content)
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    /// Plan the migration and apply it, or print it on a dry run
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the DDL cannot be generated, the database cannot
+    /// be reached or the migration fails, in which case nothing is applied
+    pub async fn execute(&self) -> Result<()> {
+        let ddl = self.ddl().await?;
+        let options = DatabaseOptions {
+            connection_string: self.dsn.clone(),
+            schema_name: Some(self.db_schema.clone()),
+            max_connections: 1,
+            ..DatabaseOptions::default()
+        };
+        let deployer = SqlDeployer::connect(&options)
+            .await
+            .map_err(|e| LinkMLError::service(e.to_string()))?;
+        let plan = deployer
+            .plan(&ddl)
+            .await
+            .map_err(|e| LinkMLError::service(e.to_string()))?;
+
+        if self.dry_run {
+            print!("{}", plan.to_sql());
+            if plan.is_empty() {
+                println!("-- Database schema {} is up to date", self.db_schema);
+            }
+            return Ok(());
+        }
+
+        for warning in &plan.warnings {
+            eprintln!("Warning: {warning}");
+        }
+        if plan.is_empty() {
+            println!("Database schema {} is up to date", self.db_schema);
+            return Ok(());
+        }
+        deployer
+            .apply(&plan)
+            .await
+            .map_err(|e| LinkMLError::service(format!("Deployment rolled back: {e}")))?;
+        println!(
+            "Deployed {} to {}: {} statements applied",
+            self.schema.name,
+            self.db_schema,
+            plan.statements.len()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,417 @@
+//! `dist` command implementation
+//!
+//! Builds self-contained release archives of the standalone `linkml` binary
+//! (the `linkml-cli` crate) for package managers. Linux binaries target musl
+//! and Windows binaries link the C runtime statically, so neither needs
+//! shared libraries beyond the operating system; macOS binaries link only
+//! system frameworks. The default configuration and the metamodel schemas
+//! are compiled into the binary, so a release runs without a `config/`
+//! directory next to it.
+//!
+//! Each target yields `linkml-<version>-<target>.tar.gz`, or `.zip` for
+//! Windows targets, containing the binary, `README.md` and `LICENSE`, and a
+//! `SHA256SUMS` file lists the checksums that Homebrew formulas and apt
+//! repository metadata need. Archives are made with the `tar` and `zip`
+//! tools.
+//!
+//! The static linking flags are added to the `RUSTFLAGS` (or
+//! `CARGO_ENCODED_RUSTFLAGS`) already set in the environment.
+
+use linkml_core::error::{LinkMLError, Result};
+use sha2::{Digest, Sha256};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+
+/// Targets built when none are given
+pub const DEFAULT_TARGETS: &[&str] = &[
+    "x86_64-unknown-linux-musl",
+    "aarch64-unknown-linux-musl",
+    "x86_64-apple-darwin",
+    "aarch64-apple-darwin",
+    "x86_64-pc-windows-msvc",
+];
+
+/// Name of the binary built by the `linkml-cli` crate
+const BINARY_NAME: &str = "linkml";
+
+/// Archive format of a release
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    /// Gzipped tarball, for Unix targets
+    TarGz,
+    /// Zip archive, for Windows targets
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// File extension of the format
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::TarGz => "tar.gz",
+            Self::Zip => "zip",
+        }
+    }
+}
+
+/// How one target is built and packaged
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistTarget {
+    /// Rust target triple
+    pub triple: String,
+    /// `RUSTFLAGS` for a statically linked binary, if the target needs any
+    pub rustflags: Option<&'static str>,
+    /// File name of the built binary
+    pub binary: String,
+    /// Format of the release archive
+    pub archive: ArchiveFormat,
+}
+
+impl DistTarget {
+    /// Build settings for a target triple
+    #[must_use]
+    pub fn new(triple: &str) -> Self {
+        let windows = triple.contains("windows");
+        let rustflags = (triple.contains("musl") || (windows && triple.ends_with("msvc")))
+            .then_some("-C target-feature=+crt-static");
+        let (binary, archive) = if windows {
+            (format!("{BINARY_NAME}.exe"), ArchiveFormat::Zip)
+        } else {
+            (BINARY_NAME.to_string(), ArchiveFormat::TarGz)
+        };
+        Self {
+            triple: triple.to_string(),
+            rustflags,
+            binary,
+            archive,
+        }
+    }
+
+    /// Base name of the release archive, without extension
+    #[must_use]
+    pub fn archive_name(&self, version: &str) -> String {
+        format!("{BINARY_NAME}-{version}-{}", self.triple)
+    }
+
+    /// File name of the release archive
+    #[must_use]
+    pub fn archive_file_name(&self, version: &str) -> String {
+        format!(
+            "{}.{}",
+            self.archive_name(version),
+            self.archive.extension()
+        )
+    }
+}
+
+/// Command building release archives of the `linkml` binary
+pub struct DistCommand {
+    /// Manifest of the `linkml-cli` crate
+    pub manifest_path: PathBuf,
+    /// Directory receiving the archives and `SHA256SUMS`
+    pub output: PathBuf,
+    /// Target triples to build
+    pub targets: Vec<String>,
+    /// Build tool, `cargo` or a drop-in such as `cross`
+    pub builder: String,
+    /// Version in archive names
+    pub version: String,
+    /// Print the build commands instead of running them
+    pub dry_run: bool,
+    /// `RUSTFLAGS` of the environment
+    inherited_rustflags: Option<String>,
+    /// `CARGO_ENCODED_RUSTFLAGS` of the environment
+    inherited_encoded_rustflags: Option<String>,
+}
+
+impl DistCommand {
+    /// Create a dist command for the crate at `manifest_path`
+    #[must_use]
+    pub fn new(manifest_path: PathBuf, output: PathBuf) -> Self {
+        Self {
+            manifest_path,
+            output,
+            targets: DEFAULT_TARGETS.iter().map(ToString::to_string).collect(),
+            builder: "cargo".to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            dry_run: false,
+            inherited_rustflags: std::env::var("RUSTFLAGS").ok(),
+            inherited_encoded_rustflags: std::env::var("CARGO_ENCODED_RUSTFLAGS").ok(),
+        }
+    }
+
+    /// Set the targets to build (the defaults if empty)
+    #[must_use]
+    pub fn with_targets(mut self, targets: Vec<String>) -> Self {
+        if !targets.is_empty() {
+            self.targets = targets;
+        }
+        self
+    }
+
+    /// Set the build tool
+    #[must_use]
+    pub fn with_builder(mut self, builder: impl Into<String>) -> Self {
+        self.builder = builder.into();
+        self
+    }
+
+    /// Set the version used in archive names
+    #[must_use]
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    /// Only print the build commands
+    #[must_use]
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Build and package every target
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - The manifest does not exist
+    /// - A build or archive command fails or cannot be started
+    /// - The output directory or checksum file cannot be written
+    pub async fn execute(&self) -> Result<()> {
+        if !self.manifest_path.exists() {
+            return Err(LinkMLError::io_error(format!(
+                "Manifest not found: {} (pass --manifest-path to the linkml-cli crate)",
+                self.manifest_path.display()
+            )));
+        }
+        let target_dir = self.output.join("build");
+        let mut checksums = String::new();
+
+        for target in self.targets.iter().map(|triple| DistTarget::new(triple)) {
+            let mut build = self.build_command(&target, &target_dir);
+            if self.dry_run {
+                println!("{}", describe(&build));
+                continue;
+            }
+            run(&mut build, &format!("Build for {}", target.triple)).await?;
+
+            let archive = self.package(&target, &target_dir).await?;
+            let bytes = tokio::fs::read(&archive).await?;
+            let file_name = archive
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let _ = writeln!(checksums, "{:x}  {file_name}", Sha256::digest(&bytes));
+            println!("Built {}", archive.display());
+        }
+
+        if !self.dry_run {
+            tokio::fs::write(self.output.join("SHA256SUMS"), checksums).await?;
+        }
+        Ok(())
+    }
+
+    /// Release build of the binary for a target
+    fn build_command(&self, target: &DistTarget, target_dir: &Path) -> Command {
+        let mut command = Command::new(&self.builder);
+        command
+            .arg("build")
+            .arg("--release")
+            .arg("--manifest-path")
+            .arg(&self.manifest_path)
+            .arg("--bin")
+            .arg(BINARY_NAME)
+            .arg("--target")
+            .arg(&target.triple)
+            .arg("--target-dir")
+            .arg(target_dir);
+        if let Some(rustflags) = target.rustflags {
+            let (key, value) = self.rustflags_env(rustflags);
+            command.env(key, value);
+        }
+        command
+    }
+
+    /// Environment variable and value passing `flags` to the build on top of
+    /// the flags already set in the environment
+    fn rustflags_env(&self, flags: &str) -> (&'static str, String) {
+        // Cargo ignores RUSTFLAGS when CARGO_ENCODED_RUSTFLAGS is set
+        if let Some(encoded) = &self.inherited_encoded_rustflags {
+            let mut value = encoded.clone();
+            for flag in flags.split_whitespace() {
+                if !value.is_empty() {
+                    value.push('\x1f');
+                }
+                value.push_str(flag);
+            }
+            return ("CARGO_ENCODED_RUSTFLAGS", value);
+        }
+        match self.inherited_rustflags.as_deref().map(str::trim) {
+            Some(existing) if !existing.is_empty() => ("RUSTFLAGS", format!("{existing} {flags}")),
+            _ => ("RUSTFLAGS", flags.to_string()),
+        }
+    }
+
+    /// Stage the binary with its documents and archive it
+    async fn package(&self, target: &DistTarget, target_dir: &Path) -> Result<PathBuf> {
+        let name = target.archive_name(&self.version);
+        let staging = self.output.join(&name);
+        tokio::fs::create_dir_all(&staging).await?;
+
+        let binary = target_dir
+            .join(&target.triple)
+            .join("release")
+            .join(&target.binary);
+        tokio::fs::copy(&binary, staging.join(&target.binary))
+            .await
+            .map_err(|e| {
+                LinkMLError::io_error(format!("Built binary {}: {e}", binary.display()))
+            })?;
+        for document in ["README.md", "LICENSE"] {
+            if let Some(source) = self.find_document(document) {
+                tokio::fs::copy(&source, staging.join(document)).await?;
+            }
+        }
+
+        let archive_file = target.archive_file_name(&self.version);
+        let archive = self.output.join(&archive_file);
+        let mut command = match target.archive {
+            ArchiveFormat::TarGz => {
+                let mut tar = Command::new("tar");
+                tar.arg("-czf")
+                    .arg(&archive)
+                    .arg("-C")
+                    .arg(&self.output)
+                    .arg(&name);
+                tar
+            }
+            ArchiveFormat::Zip => {
+                // zip adds to an existing archive instead of replacing it
+                if archive.exists() {
+                    tokio::fs::remove_file(&archive).await?;
+                }
+                let mut zip = Command::new("zip");
+                zip.arg("-qr")
+                    .arg(&archive_file)
+                    .arg(&name)
+                    .current_dir(&self.output);
+                zip
+            }
+        };
+        run(&mut command, &format!("Archiving {name}")).await?;
+        tokio::fs::remove_dir_all(&staging).await?;
+        Ok(archive)
+    }
+
+    /// A document next to the manifest or in one of its parent directories
+    fn find_document(&self, name: &str) -> Option<PathBuf> {
+        self.manifest_path
+            .parent()?
+            .ancestors()
+            .map(|dir| dir.join(name))
+            .find(|path| path.is_file())
+    }
+}
+
+/// Run a command to completion
+async fn run(command: &mut Command, what: &str) -> Result<()> {
+    let status = command.status().await.map_err(|e| {
+        LinkMLError::service(format!("{what}: cannot run {}: {e}", describe(command)))
+    })?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(LinkMLError::service(format!("{what} failed with {status}")))
+    }
+}
+
+/// Shell-like rendering of a command and its environment
+fn describe(command: &Command) -> String {
+    let command = command.as_std();
+    let mut line = String::new();
+    for (key, value) in command.get_envs() {
+        if let Some(value) = value {
+            let _ = write!(
+                line,
+                "{}='{}' ",
+                key.to_string_lossy(),
+                value.to_string_lossy()
+            );
+        }
+    }
+    line.push_str(&command.get_program().to_string_lossy());
+    for arg in command.get_args() {
+        line.push(' ');
+        line.push_str(&arg.to_string_lossy());
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dist_targets() {
+        let musl = DistTarget::new("x86_64-unknown-linux-musl");
+        assert_eq!(musl.rustflags, Some("-C target-feature=+crt-static"));
+        assert_eq!(musl.binary, "linkml");
+        assert_eq!(
+            musl.archive_name("2.0.0"),
+            "linkml-2.0.0-x86_64-unknown-linux-musl"
+        );
+
+        assert_eq!(
+            musl.archive_file_name("2.0.0"),
+            "linkml-2.0.0-x86_64-unknown-linux-musl.tar.gz"
+        );
+
+        let windows = DistTarget::new("x86_64-pc-windows-msvc");
+        assert_eq!(windows.binary, "linkml.exe");
+        assert!(windows.rustflags.is_some());
+        assert_eq!(
+            windows.archive_file_name("2.0.0"),
+            "linkml-2.0.0-x86_64-pc-windows-msvc.zip"
+        );
+        assert_eq!(DistTarget::new("aarch64-apple-darwin").rustflags, None);
+
+        let mut command = DistCommand::new(PathBuf::from("cli/Cargo.toml"), PathBuf::from("dist"))
+            .with_targets(vec!["x86_64-unknown-linux-musl".to_string()])
+            .with_builder("cross");
+        command.inherited_rustflags = None;
+        command.inherited_encoded_rustflags = None;
+        let build = command.build_command(&musl, Path::new("dist/build"));
+        assert_eq!(
+            describe(&build),
+            "RUSTFLAGS='-C target-feature=+crt-static' cross build --release \
+             --manifest-path cli/Cargo.toml --bin linkml --target x86_64-unknown-linux-musl \
+             --target-dir dist/build"
+        );
+
+        command.inherited_rustflags = Some("-D warnings".to_string());
+        assert_eq!(
+            command.rustflags_env("-C target-feature=+crt-static"),
+            (
+                "RUSTFLAGS",
+                "-D warnings -C target-feature=+crt-static".to_string()
+            )
+        );
+        command.inherited_encoded_rustflags = Some("--cfg\x1ffoo".to_string());
+        assert_eq!(
+            command.rustflags_env("-C target-feature=+crt-static"),
+            (
+                "CARGO_ENCODED_RUSTFLAGS",
+                "--cfg\x1ffoo\x1f-C\x1ftarget-feature=+crt-static".to_string()
+            )
+        );
+        assert_eq!(
+            DistCommand::new(PathBuf::new(), PathBuf::new())
+                .with_targets(Vec::new())
+                .targets
+                .len(),
+            DEFAULT_TARGETS.len()
+        );
+    }
+}
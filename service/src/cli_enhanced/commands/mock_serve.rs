@@ -0,0 +1,358 @@
+//! Schema-driven mock REST server
+//!
+//! `linkml mock-serve schema.yaml` serves synthetic, schema-valid instances of
+//! every concrete class and validates posted payloads, so that frontend teams
+//! can develop against the schema contract before the real backend exists.
+//!
+//! Endpoints:
+//! - `GET /mock` lists the classes and their endpoints
+//! - `GET /mock/{class}?count=N` returns `N` generated instances
+//! - `GET /mock/{class}/{index}` returns the instance at a 1-based index
+//! - `POST /mock/{class}` validates the body, answering `201 Created` with the
+//!   payload or `422 Unprocessable Entity` with the validation report
+//!
+//! Instances come from [`sample_instances`], so they are deterministic: the
+//! same schema always serves the same data.
+
+use axum::{
+    Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
+    routing::get,
+};
+use linkml_core::{
+    error::{LinkMLError, Result},
+    types::SchemaDefinition,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::{net::SocketAddr, sync::Arc};
+use tracing::info;
+
+use crate::generator::example_tests::sample_instances;
+use crate::validator::engine::ValidationEngine;
+
+/// Default number of instances served per class
+pub const DEFAULT_COUNT: usize = 10;
+
+/// Largest `count` accepted by the list endpoint
+const MAX_COUNT: usize = 1000;
+
+/// State shared between the mock handlers
+#[derive(Clone)]
+struct MockState {
+    schema: Arc<SchemaDefinition>,
+    validator: Arc<ValidationEngine>,
+    count: usize,
+}
+
+/// Query parameters of the list endpoint
+#[derive(Deserialize)]
+struct ListQuery {
+    count: Option<usize>,
+}
+
+/// Entry of the class index served at `GET /mock`
+#[derive(Serialize)]
+struct MockEndpoint {
+    class_name: String,
+    path: String,
+    description: Option<String>,
+}
+
+/// Command serving mock data for the classes of a schema
+pub struct MockServeCommand {
+    /// Schema whose classes are served
+    pub schema: SchemaDefinition,
+    /// Port to serve on
+    pub port: u16,
+    /// Host to bind to
+    pub host: String,
+    /// Number of instances returned by the list endpoints
+    pub count: usize,
+}
+
+impl MockServeCommand {
+    /// Create a mock server command for a loaded schema
+    #[must_use]
+    pub fn new(schema: SchemaDefinition, port: u16) -> Self {
+        Self {
+            schema,
+            port,
+            host: "127.0.0.1".to_string(),
+            count: DEFAULT_COUNT,
+        }
+    }
+
+    /// Set the host to bind to
+    #[must_use]
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.host = host.into();
+        self
+    }
+
+    /// Set the number of instances returned by the list endpoints
+    #[must_use]
+    pub fn with_count(mut self, count: usize) -> Self {
+        self.count = count.min(MAX_COUNT);
+        self
+    }
+
+    /// Router with the mock endpoints
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the validation engine cannot be built for the schema
+    pub fn router(&self) -> Result<Router> {
+        let state = MockState {
+            schema: Arc::new(self.schema.clone()),
+            validator: Arc::new(ValidationEngine::new(&self.schema)?),
+            count: self.count,
+        };
+        Ok(Router::new()
+            .route("/mock", get(list_classes))
+            .route("/mock/{class}", get(list_instances).post(create_instance))
+            .route("/mock/{class}/{index}", get(get_instance))
+            .with_state(state))
+    }
+
+    /// Serve the mock endpoints until Ctrl+C
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address is invalid, cannot be bound, or the
+    /// server fails
+    pub async fn execute(&self) -> Result<()> {
+        let app = self
+            .router()?
+            .layer(tower_http::cors::CorsLayer::permissive());
+        let addr: SocketAddr = format!("{}:{}", self.host, self.port)
+            .parse()
+            .map_err(|e| LinkMLError::config(format!("Invalid host:port combination: {e}")))?;
+        let listener = tokio::net::TcpListener::bind(addr)
+            .await
+            .map_err(|e| LinkMLError::service(format!("Failed to bind to address {addr}: {e}")))?;
+
+        println!("LinkML Mock Server");
+        println!("Schema: {}", self.schema.name);
+        println!("Address: http://{addr}");
+        println!("Endpoints:");
+        println!("  GET  /mock                 - List mocked classes");
+        for class_name in mocked_classes(&self.schema) {
+            println!("  GET  /mock/{class_name}");
+            println!("  GET  /mock/{class_name}/{{index}}");
+            println!("  POST /mock/{class_name}");
+        }
+        println!("Press Ctrl+C to stop");
+        info!("Mock server listening on {}", addr);
+
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async {
+                if let Err(e) = tokio::signal::ctrl_c().await {
+                    eprintln!("Failed to install Ctrl+C handler: {e}");
+                }
+            })
+            .await
+            .map_err(|e| LinkMLError::service(format!("Server error: {e}")))
+    }
+}
+
+/// Concrete classes of the schema, in schema order
+fn mocked_classes(schema: &SchemaDefinition) -> impl Iterator<Item = &str> {
+    schema
+        .classes
+        .iter()
+        .filter(|(_, class)| class.abstract_ != Some(true) && class.mixin != Some(true))
+        .map(|(name, _)| name.as_str())
+}
+
+/// JSON error body with a status code
+fn error_response(status: StatusCode, message: impl Into<String>) -> Response {
+    (status, Json(json!({ "error": message.into() }))).into_response()
+}
+
+/// Generated instances, or the status and message to answer with
+fn instances(
+    state: &MockState,
+    class_name: &str,
+    count: usize,
+) -> std::result::Result<Vec<Value>, (StatusCode, String)> {
+    if !mocked_classes(&state.schema).any(|name| name == class_name) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("Unknown class: {class_name}"),
+        ));
+    }
+    sample_instances(&state.schema, class_name, count)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Handler for `GET /mock`
+async fn list_classes(State(state): State<MockState>) -> Json<Vec<MockEndpoint>> {
+    Json(
+        mocked_classes(&state.schema)
+            .map(|class_name| MockEndpoint {
+                class_name: class_name.to_string(),
+                path: format!("/mock/{class_name}"),
+                description: state.schema.classes[class_name].description.clone(),
+            })
+            .collect(),
+    )
+}
+
+/// Handler for `GET /mock/{class}`
+async fn list_instances(
+    State(state): State<MockState>,
+    Path(class_name): Path<String>,
+    Query(query): Query<ListQuery>,
+) -> Response {
+    let count = query.count.unwrap_or(state.count).min(MAX_COUNT);
+    match instances(&state, &class_name, count) {
+        Ok(instances) => Json(instances).into_response(),
+        Err((status, message)) => error_response(status, message),
+    }
+}
+
+/// Handler for `GET /mock/{class}/{index}`
+async fn get_instance(
+    State(state): State<MockState>,
+    Path((class_name, index)): Path<(String, usize)>,
+) -> Response {
+    if index == 0 || index > MAX_COUNT {
+        return error_response(
+            StatusCode::NOT_FOUND,
+            format!("Index must be between 1 and {MAX_COUNT}"),
+        );
+    }
+    match instances(&state, &class_name, index) {
+        Ok(instances) => match instances.into_iter().nth(index - 1) {
+            Some(instance) => Json(instance).into_response(),
+            None => error_response(
+                StatusCode::NOT_FOUND,
+                format!("No valid {class_name} instance can be generated"),
+            ),
+        },
+        Err((status, message)) => error_response(status, message),
+    }
+}
+
+/// Handler for `POST /mock/{class}`
+async fn create_instance(
+    State(state): State<MockState>,
+    Path(class_name): Path<String>,
+    Json(payload): Json<Value>,
+) -> Response {
+    if !mocked_classes(&state.schema).any(|name| name == class_name) {
+        return error_response(
+            StatusCode::NOT_FOUND,
+            format!("Unknown class: {class_name}"),
+        );
+    }
+    match state
+        .validator
+        .validate_as_class(&payload, &class_name, None)
+        .await
+    {
+        Ok(report) if report.valid => (StatusCode::CREATED, Json(payload)).into_response(),
+        Ok(report) => (StatusCode::UNPROCESSABLE_ENTITY, Json(report)).into_response(),
+        Err(e) => error_response(StatusCode::BAD_REQUEST, e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use linkml_core::types::{ClassDefinition, SlotDefinition};
+    use tower::ServiceExt;
+
+    async fn call(
+        router: &Router,
+        method: &str,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+            .expect("valid request");
+        let response = router
+            .clone()
+            .oneshot(request)
+            .await
+            .expect("router responds");
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("response body");
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_mock_endpoints() {
+        let mut schema = SchemaDefinition {
+            name: "people".to_string(),
+            ..Default::default()
+        };
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                identifier: Some(true),
+                required: Some(true),
+                range: Some("string".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "age".to_string(),
+            SlotDefinition {
+                range: Some("integer".to_string()),
+                required: Some(true),
+                minimum_value: Some(json!(0)),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "age".to_string()],
+                ..Default::default()
+            },
+        );
+        let router = MockServeCommand::new(schema, 0)
+            .with_count(3)
+            .router()
+            .expect("mock router");
+
+        let (status, index) = call(&router, "GET", "/mock", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(index[0]["path"], "/mock/Person");
+
+        let (status, people) = call(&router, "GET", "/mock/Person", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(people.as_array().map(Vec::len), Some(3));
+        assert_eq!(people[2]["id"], "Person-3");
+
+        let (status, person) = call(&router, "GET", "/mock/Person/2", None).await;
+        assert_eq!(
+            (status, &person["id"]),
+            (StatusCode::OK, &json!("Person-2"))
+        );
+
+        let (status, _) = call(&router, "GET", "/mock/Robot", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, created) = call(&router, "POST", "/mock/Person", Some(person.clone())).await;
+        assert_eq!((status, created), (StatusCode::CREATED, person));
+
+        let (status, _) = call(&router, "POST", "/mock/Person", Some(json!({"age": 5}))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
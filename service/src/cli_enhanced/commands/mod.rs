@@ -10,6 +10,11 @@
 // mod load;
 // mod merge;
 
+#[cfg(feature = "database")]
+pub mod deploy_sql;
+pub mod dist;
+pub mod mock_serve;
+pub mod push_registry;
 pub mod schema2sheets;
 pub mod serve;
 pub mod sheets2schema;
@@ -21,6 +26,11 @@ pub mod sheets2schema;
 // pub use lint::LintCommand;
 // pub use load::LoadCommand;
 // pub use merge::MergeCommand;
+#[cfg(feature = "database")]
+pub use deploy_sql::DeploySqlCommand;
+pub use dist::DistCommand;
+pub use mock_serve::MockServeCommand;
+pub use push_registry::PushRegistryCommand;
 pub use schema2sheets::Schema2SheetsCommand;
 pub use serve::ServeCommand;
 pub use sheets2schema::Sheets2SchemaCommand;
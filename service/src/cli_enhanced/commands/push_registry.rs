@@ -0,0 +1,156 @@
+//! Publishing schemas to a Confluent-compatible schema registry
+//!
+//! `linkml push-registry schema.yaml --url http://localhost:8081 --subject
+//! orders-value` generates the Avro (or, with `--artifact json-schema`, the
+//! JSON Schema) artifact, checks it against the registered versions of the
+//! subject with [`RegistryPublisher`] and registers it. With `--dry-run` only
+//! the compatibility check runs.
+
+use linkml_core::{
+    error::{LinkMLError, Result},
+    types::SchemaDefinition,
+};
+
+use crate::schema_registry::confluent::CompatibilityReport;
+use crate::schema_registry::{
+    ArtifactFormat, CompatibilityLevel, ConfluentClient, RegistryPublisher,
+};
+
+/// Command publishing a schema to a schema registry subject
+pub struct PushRegistryCommand {
+    /// Schema to publish
+    pub schema: SchemaDefinition,
+    /// Schema registry URL
+    pub url: String,
+    /// Subject to register under
+    pub subject: String,
+    /// Artifact to register
+    pub artifact: ArtifactFormat,
+    /// Class the artifact describes
+    pub class_name: Option<String>,
+    /// Level to check instead of the subject's
+    pub compatibility: Option<CompatibilityLevel>,
+    /// Basic authentication user and password
+    pub credentials: Option<(String, String)>,
+    /// Check compatibility without registering
+    pub dry_run: bool,
+}
+
+impl PushRegistryCommand {
+    /// Create a command publishing a loaded schema under `subject`
+    #[must_use]
+    pub fn new(
+        schema: SchemaDefinition,
+        url: impl Into<String>,
+        subject: impl Into<String>,
+    ) -> Self {
+        Self {
+            schema,
+            url: url.into(),
+            subject: subject.into(),
+            artifact: ArtifactFormat::default(),
+            class_name: None,
+            compatibility: None,
+            credentials: None,
+            dry_run: false,
+        }
+    }
+
+    /// Set the artifact to register
+    #[must_use]
+    pub fn with_artifact(mut self, artifact: ArtifactFormat) -> Self {
+        self.artifact = artifact;
+        self
+    }
+
+    /// Generate the artifact for one class
+    #[must_use]
+    pub fn with_class(mut self, class_name: impl Into<String>) -> Self {
+        self.class_name = Some(class_name.into());
+        self
+    }
+
+    /// Check this level instead of the subject's
+    #[must_use]
+    pub fn with_compatibility(mut self, level: CompatibilityLevel) -> Self {
+        self.compatibility = Some(level);
+        self
+    }
+
+    /// Authenticate with basic authentication
+    #[must_use]
+    pub fn with_basic_auth(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Only check compatibility
+    #[must_use]
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Check the schema against the subject and register it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registry cannot be reached, the schema breaks
+    /// the compatibility level or the registry rejects it
+    pub async fn execute(&self) -> Result<()> {
+        let mut client = ConfluentClient::new(self.url.as_str());
+        if let Some((username, password)) = &self.credentials {
+            client = client.with_basic_auth(username.as_str(), password.as_str());
+        }
+        let mut publisher = RegistryPublisher::new(client).with_format(self.artifact);
+        if let Some(class_name) = &self.class_name {
+            publisher = publisher.with_class(class_name.as_str());
+        }
+        if let Some(level) = self.compatibility {
+            publisher = publisher.with_compatibility(level);
+        }
+
+        if self.dry_run {
+            let (_, report) = publisher.check(&self.subject, &self.schema).await?;
+            print_report(&self.subject, &report);
+            if report.is_compatible() {
+                return Ok(());
+            }
+            return Err(LinkMLError::schema_validation(format!(
+                "Schema '{}' is not {} compatible with subject '{}'",
+                self.schema.name, report.level, self.subject
+            )));
+        }
+
+        let (id, report) = publisher.publish(&self.subject, &self.schema).await?;
+        print_report(&self.subject, &report);
+        println!(
+            "Registered {} {} artifact under subject '{}' with schema id {id}",
+            self.schema.name, self.artifact, self.subject
+        );
+        Ok(())
+    }
+}
+
+fn print_report(subject: &str, report: &CompatibilityReport) {
+    println!("Compatibility level of '{subject}': {}", report.level);
+    if !report.checked_versions.is_empty() {
+        println!(
+            "Compared with LinkML schemas of versions {:?}",
+            report.checked_versions
+        );
+    }
+    if !report.registry_checked_versions.is_empty() {
+        println!(
+            "Versions {:?} have no LinkML schema and were checked by the registry",
+            report.registry_checked_versions
+        );
+    }
+    for violation in &report.violations {
+        println!("  - {violation}");
+    }
+}
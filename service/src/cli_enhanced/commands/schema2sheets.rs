@@ -1,8 +1,16 @@
 //! `schema2sheets` command implementation
 //!
 //! Converts LinkML schema to Excel SchemaSheets format.
-
-use crate::schemasheets::SchemaSheetsGenerator;
+//!
+//! The output can also be a Google Sheets URL, whose tabs are replaced with
+//! the generated sheets through the Sheets API. With `--data-entry` the
+//! workbook is a data-entry template with a sheet per class instead.
+
+use crate::schemasheets::google::spreadsheet_id_of;
+use crate::schemasheets::parser::workbook_sheets;
+use crate::schemasheets::{
+    DataSheetsGenerator, GoogleCredentials, GoogleSheetsClient, SchemaSheetsGenerator,
+};
 use indicatif::{ProgressBar, ProgressStyle};
 use linkml_core::error::{LinkMLError, Result};
 use linkml_core::prelude::*;
@@ -22,6 +30,10 @@ pub struct Schema2SheetsCommand {
     pub progress: bool,
     /// Verbose output
     pub verbose: bool,
+    /// Service account key file for Google Sheets output
+    pub credentials: Option<PathBuf>,
+    /// Generate a data-entry workbook for instances of the schema
+    pub data_entry: bool,
 }
 
 impl Schema2SheetsCommand {
@@ -35,6 +47,8 @@ impl Schema2SheetsCommand {
             metadata_sheets: true,
             progress: true,
             verbose: false,
+            credentials: None,
+            data_entry: false,
         }
     }
 
@@ -66,6 +80,20 @@ impl Schema2SheetsCommand {
         self
     }
 
+    /// Authenticate to Google Sheets with a service account key file
+    #[must_use]
+    pub fn with_credentials(mut self, credentials: PathBuf) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// Generate a data-entry workbook instead of the schema sheets
+    #[must_use]
+    pub fn with_data_entry(mut self, data_entry: bool) -> Self {
+        self.data_entry = data_entry;
+        self
+    }
+
     /// Execute the command
     ///
     /// # Errors
@@ -125,7 +153,26 @@ impl Schema2SheetsCommand {
         generator.include_all_metadata = self.include_metadata;
         generator.generate_metadata_sheets = self.metadata_sheets;
 
-        generator.generate_file(&schema, &self.output).await?;
+        if let Some(spreadsheet_id) = spreadsheet_id_of(&self.output) {
+            let client =
+                GoogleSheetsClient::new(GoogleCredentials::discover(self.credentials.as_deref())?);
+            if self.data_entry {
+                let buffer = DataSheetsGenerator::new().generate_buffer(&schema, &[])?;
+                client
+                    .write(spreadsheet_id, &workbook_sheets(buffer)?)
+                    .await?;
+            } else {
+                client
+                    .write_schema(spreadsheet_id, &generator, &schema)
+                    .await?;
+            }
+        } else if self.data_entry {
+            DataSheetsGenerator::new()
+                .generate_file(&schema, &[], &self.output)
+                .await?;
+        } else {
+            generator.generate_file(&schema, &self.output).await?;
+        }
 
         if let Some(ref pb) = progress {
             pb.inc(1);
@@ -254,7 +301,7 @@ impl Schema2SheetsCommand {
 
         // Step 2: Resolve imports if present
         if !schema.imports.is_empty() {
-            use crate::parser::import_resolver_v2::ImportResolverV2;
+            use crate::parser::import_resolver::ImportResolverV2;
             use linkml_core::settings::ImportSettings;
 
             // Configure import settings with base path
@@ -32,6 +32,7 @@ use serde_json::Value;
 use std::{collections::HashMap, net::SocketAddr, sync::Arc};
 use tracing::{info, warn};
 
+use crate::schema_registry::{MultiTenantRegistry, registry_router};
 use crate::validator::{
     engine::{ValidationEngine, ValidationOptions},
     report::ValidationReport,
@@ -77,7 +78,7 @@ impl From<ValidationOptionsDto> for ValidationOptions {
             parallel: dto.parallel,
             allow_additional_properties: dto.allow_additional_properties,
             fail_on_warning: dto.fail_on_warning,
-            custom_validators: Vec::new(),
+            ..Self::default()
         }
     }
 }
@@ -131,8 +132,13 @@ pub struct ServeCommand {
     pub host: String,
     /// Enable verbose logging
     pub verbose: bool,
+    /// Schemas registered by clients under `/linkml/registry`
+    pub registry: Arc<MultiTenantRegistry>,
 }
 
+/// Tenant the served schema is registered for
+pub const DEFAULT_TENANT: &str = "default";
+
 impl ServeCommand {
     /// Create a new serve command
     ///
@@ -146,6 +152,7 @@ impl ServeCommand {
             port,
             host: "localhost".to_string(),
             verbose: false,
+            registry: Arc::new(MultiTenantRegistry::new()),
         }
     }
 
@@ -163,6 +170,14 @@ impl ServeCommand {
         self
     }
 
+    /// Serve the schema registry `registry`, e.g. one backed by a storage
+    /// repository, instead of an in-memory one
+    #[must_use]
+    pub fn with_registry(mut self, registry: Arc<MultiTenantRegistry>) -> Self {
+        self.registry = registry;
+        self
+    }
+
     /// Execute the serve command
     ///
     /// **DEPRECATED**: This method creates its own HTTP server which violates `RootReal`'s
@@ -228,6 +243,19 @@ impl ServeCommand {
         // Create validation engine
         let validator = ValidationEngine::new(&schema_definition)?;
 
+        // Make the served schema available by reference to registry clients
+        if schema_definition.version.is_some() {
+            if let Err(e) = self
+                .registry
+                .register(DEFAULT_TENANT, schema_definition.clone(), None)
+                .await
+            {
+                warn!("Served schema not added to the registry: {e}");
+            }
+        } else {
+            info!("Served schema declares no version and is not added to the registry");
+        }
+
         // Create LinkML application state for handlers
         let linkml_state = AppState {
             schema: Arc::new(schema_definition),
@@ -251,7 +279,10 @@ impl ServeCommand {
         warn!("4. Frontend-framework CORS service integration");
 
         // Create LinkML-specific router with proper RootReal patterns
-        let linkml_router = create_linkml_router(linkml_state);
+        let linkml_router = create_linkml_router(linkml_state).nest(
+            "/linkml/registry",
+            registry_router(Arc::clone(&self.registry)),
+        );
 
         // TODO: Use frontend-framework CORS service when available
         // let cors_config = if self.is_development_mode() {
@@ -290,6 +321,16 @@ impl ServeCommand {
         println!("  GET  /linkml/schema   - Get schema definition");
         println!("  POST /linkml/validate - Validate data against schema");
         println!("  GET  /linkml/health   - Health check");
+        println!(
+            "  GET  /linkml/registry/tenants/{{tenant}}/schemas        - List registered schemas"
+        );
+        println!("  POST /linkml/registry/tenants/{{tenant}}/schemas        - Register a schema");
+        println!(
+            "  GET  /linkml/registry/tenants/{{tenant}}/schemas/{{ref}}  - Get a registered schema"
+        );
+        println!(
+            "  POST /linkml/registry/tenants/{{tenant}}/validate       - Validate against name:version"
+        );
         println!("Integration: Uses RootReal REST API, CORS, and Shutdown services");
         println!("Press Ctrl+C for graceful shutdown");
 
@@ -2,6 +2,9 @@
 //!
 //! Converts Excel SchemaSheets to LinkML schema.
 //!
+//! The input can also be a Google Sheets URL, which is read through the
+//! Sheets API in SchemaSheets format.
+//!
 //! Supports two modes:
 //! 1. **SchemaSheets Format**: Parses metadata from SchemaSheets-formatted Excel files
 //!    for lossless roundtrip conversion (preserves all schema metadata)
@@ -10,13 +13,14 @@
 
 use crate::inference::DataIntrospector;
 use crate::inference::introspectors::excel::ExcelIntrospector;
-use crate::schemasheets::SchemaSheetsParser;
+use crate::schemasheets::google::spreadsheet_id_of;
+use crate::schemasheets::{GoogleCredentials, GoogleSheetsClient, SchemaSheetsParser};
+use crate::utils::logging::wire_logger;
+use crate::utils::timestamp::wire_timestamp;
 use indicatif::{ProgressBar, ProgressStyle};
 use linkml_core::error::{LinkMLError, Result};
 use linkml_core::prelude::*;
-use logger_service::wiring::wire_logger;
 use std::path::{Path, PathBuf};
-use timestamp_service::wiring::wire_timestamp;
 
 /// Command for converting Excel SchemaSheets to LinkML schema
 pub struct Sheets2SchemaCommand {
@@ -38,6 +42,8 @@ pub struct Sheets2SchemaCommand {
     pub progress: bool,
     /// Verbose output
     pub verbose: bool,
+    /// Service account key file for Google Sheets input
+    pub credentials: Option<PathBuf>,
 }
 
 /// Schema output format
@@ -63,6 +69,7 @@ impl Sheets2SchemaCommand {
             force_introspection: false,
             progress: true,
             verbose: false,
+            credentials: None,
         }
     }
 
@@ -115,6 +122,13 @@ impl Sheets2SchemaCommand {
         self
     }
 
+    /// Authenticate to Google Sheets with a service account key file
+    #[must_use]
+    pub fn with_credentials(mut self, credentials: PathBuf) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
     /// Execute the command
     ///
     /// # Errors
@@ -125,6 +139,10 @@ impl Sheets2SchemaCommand {
     /// - Schema generation fails
     /// - Output file can't be written
     pub async fn execute(&self) -> Result<()> {
+        if let Some(spreadsheet_id) = spreadsheet_id_of(&self.input) {
+            return self.execute_google(spreadsheet_id).await;
+        }
+
         // Validate input file exists
         if !self.input.exists() {
             return Err(LinkMLError::io_error(format!(
@@ -201,6 +219,36 @@ impl Sheets2SchemaCommand {
         Ok(())
     }
 
+    /// Convert a Google spreadsheet in SchemaSheets format
+    async fn execute_google(&self, spreadsheet_id: &str) -> Result<()> {
+        if self.force_introspection {
+            return Err(LinkMLError::config(
+                "Data introspection reads Excel files only; export the spreadsheet as .xlsx",
+            ));
+        }
+        if self.verbose {
+            eprintln!("Reading Google spreadsheet {spreadsheet_id}...");
+        }
+
+        let client =
+            GoogleSheetsClient::new(GoogleCredentials::discover(self.credentials.as_deref())?);
+        let schema = client
+            .read_schema(
+                spreadsheet_id,
+                &SchemaSheetsParser::new(),
+                self.schema_id.as_deref(),
+            )
+            .await?;
+
+        let output_path = self
+            .output
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(format!("{}.{}", schema.id, self.extension())));
+        self.write_schema(&schema, &output_path)?;
+        println!("Schema generated: {}", output_path.display());
+        Ok(())
+    }
+
     /// Parse using SchemaSheets format parser
     ///
     /// Note: This function is async to maintain a consistent interface with other parsing methods,
@@ -244,12 +292,7 @@ impl Sheets2SchemaCommand {
         }
 
         // Wire services
-        let timestamp = wire_timestamp().into_arc();
-        let logger = wire_logger(timestamp.clone(), logger_core::LoggerConfig::default())
-            .map_err(|e| LinkMLError::service(format!("Failed to wire logger: {e}")))?
-            .into_arc();
-
-        let introspector = ExcelIntrospector::new(logger, timestamp);
+        let introspector = ExcelIntrospector::new(wire_logger()?, wire_timestamp());
 
         if let Some(pb) = progress {
             pb.inc(1);
@@ -329,14 +372,19 @@ impl Sheets2SchemaCommand {
         } else {
             // Default: <input>.yaml or <input>.json
             let mut path = self.input.clone();
-            path.set_extension(match self.format {
-                SchemaFormat::Yaml => "yaml",
-                SchemaFormat::Json => "json",
-            });
+            path.set_extension(self.extension());
             path
         }
     }
 
+    /// File extension of the output format
+    fn extension(&self) -> &'static str {
+        match self.format {
+            SchemaFormat::Yaml => "yaml",
+            SchemaFormat::Json => "json",
+        }
+    }
+
     /// Determine schema ID from options or filename
     fn determine_schema_id(&self) -> String {
         if let Some(ref id) = self.schema_id {
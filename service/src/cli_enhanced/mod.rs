@@ -10,7 +10,8 @@ mod types;
 pub use app::LinkMLApp;
 pub use types::{
     AuthType, ConflictResolution, DiffFormat, DumpFormat, LinkMLCli, LinkMLCommand, LintFormat,
-    LoadFormat, MergeStrategy, OutputFormat, SchemaFormat,
+    LoadFormat, MergeStrategy, OutputFormat, QualityFormat, SchemaFormat, SearchElementType,
+    TerminologyServiceKind, TrendDimension, TrendPeriod, ValidationOutputFormat,
 };
 
 /// Main entry point for the enhanced CLI
@@ -18,8 +19,21 @@ pub use types::{
 /// # Errors
 /// Returns error if CLI execution fails or encounters invalid arguments.
 pub async fn run() -> linkml_core::error::Result<()> {
-    use timestamp_service::wiring::wire_timestamp;
-    let timestamp_service = wire_timestamp();
-    let app = LinkMLApp::from_args_with_timestamp(timestamp_service.into_inner());
+    let app = LinkMLApp::from_args_with_timestamp(crate::utils::timestamp::wire_timestamp());
+    app.run().await
+}
+
+/// Entry point for the standalone `linkml` binary
+///
+/// Runs the enhanced CLI on lightweight internal defaults (the system clock
+/// and `tracing` logging) instead of the wired `RootReal` services, for
+/// installations outside a `RootReal` deployment.
+///
+/// # Errors
+/// Returns error if CLI execution fails or encounters invalid arguments.
+pub async fn run_standalone() -> linkml_core::error::Result<()> {
+    let app = LinkMLApp::from_args_with_timestamp(std::sync::Arc::new(
+        crate::utils::timestamp::SystemClock,
+    ));
     app.run().await
 }
@@ -0,0 +1,199 @@
+//! Classification of configuration changes
+//!
+//! When the Configuration Service delivers a new configuration, only some
+//! settings can take effect in a running service: cache sizes and TTLs,
+//! security limits, timeouts and generator options are read each time they
+//! are used. Others, such as the `TypeDB` connection, thread counts or the
+//! network address, are fixed when components start. [`diff_config`] splits
+//! a change along [`RUNTIME_SETTINGS`] and produces the effective
+//! configuration with only the runtime settings applied; the rest is
+//! reported in [`ConfigChange::restart_required`] until the service restarts.
+
+use super::LinkMLConfig;
+use linkml_core::error::{LinkMLError, Result};
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+/// Settings applied to a running service, as dotted paths; a path covers
+/// every setting below it
+pub const RUNTIME_SETTINGS: &[&str] = &[
+    "cache.max_entries",
+    "cache.ttl_seconds",
+    "cache.expression_cache",
+    "cache.rule_cache",
+    "security_limits",
+    "validator.batch_size",
+    "validator.timeout_ms",
+    "validator.max_errors",
+    "validator.fail_fast",
+    "parser.max_recursion_depth",
+    "parser.max_file_size_bytes",
+    "parser.max_import_depth",
+    "parser.cache_ttl_seconds",
+    "expression.timeout_seconds",
+    "expression.max_recursion_depth",
+    "performance.memory_limit_bytes",
+    "performance.cpu_limit_percent",
+    "performance.cache_ttl_levels",
+    "multi_layer_cache.l3_max_size_bytes",
+    "generator",
+    "cli",
+];
+
+/// Whether a setting, given as dotted path, takes effect without a restart
+#[must_use]
+pub fn is_runtime_setting(path: &str) -> bool {
+    RUNTIME_SETTINGS.iter().any(|setting| {
+        path.strip_prefix(setting)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    })
+}
+
+/// A configuration change delivered by the Configuration Service
+#[derive(Debug, Clone)]
+pub struct ConfigChange {
+    /// Effective configuration after applying the runtime settings
+    pub config: Arc<LinkMLConfig>,
+    /// Changed settings that were applied
+    pub applied: Vec<String>,
+    /// Changed settings that take effect only after a restart
+    pub restart_required: Vec<String>,
+}
+
+impl ConfigChange {
+    /// Whether no setting changed
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.applied.is_empty() && self.restart_required.is_empty()
+    }
+}
+
+/// Compare the previously loaded configuration with a new one and apply the
+/// changed runtime settings to the effective configuration
+///
+/// # Errors
+///
+/// Returns an error if the configurations cannot be converted to and from
+/// JSON.
+pub fn diff_config(
+    effective: &LinkMLConfig,
+    previous: &LinkMLConfig,
+    new: &LinkMLConfig,
+) -> Result<ConfigChange> {
+    let mut previous_settings = Map::new();
+    flatten("", &serde_json::to_value(previous)?, &mut previous_settings);
+    let mut new_settings = Map::new();
+    flatten("", &serde_json::to_value(new)?, &mut new_settings);
+
+    let changed: BTreeSet<&String> = previous_settings
+        .iter()
+        .filter(|(path, value)| new_settings.get(*path) != Some(value))
+        .map(|(path, _)| path)
+        .chain(
+            new_settings
+                .keys()
+                .filter(|path| !previous_settings.contains_key(*path)),
+        )
+        .collect();
+    let (applied, restart_required): (Vec<String>, Vec<String>) = changed
+        .into_iter()
+        .cloned()
+        .partition(|path| is_runtime_setting(path));
+
+    let mut config = serde_json::to_value(effective)?;
+    for path in &applied {
+        set_setting(&mut config, path, new_settings.get(path).cloned());
+    }
+    let config: LinkMLConfig = serde_json::from_value(config)
+        .map_err(|e| LinkMLError::config(format!("Cannot apply configuration change: {e}")))?;
+
+    Ok(ConfigChange {
+        config: Arc::new(config),
+        applied,
+        restart_required,
+    })
+}
+
+/// Leaf settings of a configuration by dotted path
+fn flatten(prefix: &str, value: &Value, settings: &mut Map<String, Value>) {
+    match value {
+        Value::Object(object) if !object.is_empty() => {
+            for (key, value) in object {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten(&path, value, settings);
+            }
+        }
+        _ => {
+            settings.insert(prefix.to_string(), value.clone());
+        }
+    }
+}
+
+/// Set or, with `None`, remove the setting at a dotted path
+fn set_setting(config: &mut Value, path: &str, value: Option<Value>) {
+    let mut segments: Vec<&str> = path.split('.').collect();
+    let Some(last) = segments.pop() else {
+        return;
+    };
+    let mut target = config;
+    for segment in segments {
+        let Some(object) = target.as_object_mut() else {
+            return;
+        };
+        target = object
+            .entry(segment)
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+    if let Some(object) = target.as_object_mut() {
+        match value {
+            Some(value) => {
+                object.insert(last.to_string(), value);
+            }
+            None => {
+                object.remove(last);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EMBEDDED_DEFAULT_CONFIG, parse_config};
+
+    #[test]
+    fn test_diff_config() {
+        let previous: LinkMLConfig = parse_config(EMBEDDED_DEFAULT_CONFIG).expect("config");
+        let mut new = previous.clone();
+        new.cache.max_entries += 1;
+        new.security_limits.max_validation_errors += 1;
+        new.typedb.server_address = "typedb.example.org:1729".to_string();
+
+        let change = diff_config(&previous, &previous, &new).expect("diff");
+        assert_eq!(
+            change.applied,
+            vec![
+                "cache.max_entries".to_string(),
+                "security_limits.max_validation_errors".to_string()
+            ]
+        );
+        assert_eq!(change.restart_required, vec!["typedb.server_address"]);
+        assert_eq!(change.config.cache.max_entries, new.cache.max_entries);
+        assert_eq!(
+            change.config.typedb.server_address,
+            previous.typedb.server_address
+        );
+
+        assert!(diff_config(&new, &new, &new).expect("diff").is_empty());
+        assert!(is_runtime_setting(
+            "generator.generator_options.rust.template_path"
+        ));
+        assert!(!is_runtime_setting("cache.enable_compression"));
+        assert!(!is_runtime_setting("clients"));
+    }
+}
@@ -1,9 +1,14 @@
 //! Integration with `RootReal`'s Configuration Service
 //!
-//! This module replaces the standalone hot-reload implementation with proper
-//! Configuration Service integration, following `RootReal`'s architectural patterns.
+//! [`ConfigurationManager`] reloads the configuration from the Configuration
+//! Service, revalidates it and applies the settings that are safe to change
+//! at runtime (see [`super::changes`]). Every change is published as a
+//! [`ConfigChange`]; [`ConfigurationWatcher`] passes it on to the registered
+//! [`ConfigurationChangeHandler`]s, including the settings that only take
+//! effect after a restart.
 
 use super::LinkMLConfig as LinkMLServiceConfig; // Use the local config type
+use super::changes::{ConfigChange, diff_config};
 use async_trait::async_trait;
 use configuration_core::{ConfigurationService, Validate};
 use linkml_core::error::{LinkMLError, Result};
@@ -11,22 +16,30 @@ use serde::{Serialize, de::DeserializeOwned};
 #[allow(unused_imports)] // False positive - used in Debug impl for MigrationResult
 use std::fmt;
 use std::sync::Arc;
-use tokio::sync::{RwLock, watch};
-use tracing::{error, info, warn};
+use tokio::sync::{RwLock, broadcast, watch};
+use tracing::{debug, error, info, warn};
+
+/// Capacity of the configuration change channel
+const CHANGE_CHANNEL_CAPACITY: usize = 16;
 
 /// Configuration manager that integrates with `RootReal`'s Configuration Service
 ///
-/// This replaces the standalone `ConfigHotReloader` and properly uses
-/// the Configuration Service for all configuration management including hot-reload.
+/// Keeps both the configuration last loaded from the service and the
+/// effective configuration, which differs from it in the settings that
+/// wait for a restart.
 pub struct ConfigurationManager<C: ConfigurationService + Send + Sync + 'static> {
     /// The Configuration Service instance
     config_service: Arc<C>,
-    /// Current configuration cached
+    /// Effective configuration
     current_config: Arc<RwLock<LinkMLServiceConfig>>,
+    /// Configuration last loaded from the Configuration Service
+    loaded_config: RwLock<LinkMLServiceConfig>,
     /// Configuration change notifier
     config_tx: watch::Sender<Arc<LinkMLServiceConfig>>,
     /// Configuration change receiver
     config_rx: watch::Receiver<Arc<LinkMLServiceConfig>>,
+    /// Classified configuration changes
+    changes_tx: broadcast::Sender<ConfigChange>,
 }
 
 impl<C: ConfigurationService + Send + Sync + 'static> ConfigurationManager<C> {
@@ -49,12 +62,15 @@ impl<C: ConfigurationService + Send + Sync + 'static> ConfigurationManager<C> {
 
         let config_arc = Arc::new(config);
         let (tx, rx) = watch::channel(Arc::clone(&config_arc));
+        let (changes_tx, _) = broadcast::channel(CHANGE_CHANNEL_CAPACITY);
 
         Ok(Self {
             config_service,
             current_config: Arc::new(RwLock::new((*config_arc).clone())),
+            loaded_config: RwLock::new((*config_arc).clone()),
             config_tx: tx,
             config_rx: rx,
+            changes_tx,
         })
     }
 
@@ -80,10 +96,16 @@ impl<C: ConfigurationService + Send + Sync + 'static> ConfigurationManager<C> {
 
     /// Reload configuration from the Configuration Service
     ///
+    /// The new configuration is validated before anything is applied. Changed
+    /// runtime settings are applied to the effective configuration; the
+    /// remaining changes are reported in [`ConfigChange::restart_required`].
+    /// Subscribers are notified only if a setting changed.
+    ///
     /// # Errors
-    /// Returns error if configuration service reload fails.
-    pub async fn reload(&self) -> Result<()> {
-        info!("Reloading LinkML configuration from Configuration Service");
+    /// Returns error if configuration service reload fails or the new
+    /// configuration is invalid.
+    pub async fn reload(&self) -> Result<ConfigChange> {
+        debug!("Reloading LinkML configuration from Configuration Service");
 
         // Trigger reload in Configuration Service
         self.config_service
@@ -103,28 +125,50 @@ impl<C: ConfigurationService + Send + Sync + 'static> ConfigurationManager<C> {
             LinkMLError::config(format!("New configuration validation failed: {e}"))
         })?;
 
-        // Update current configuration
-        {
+        let change = {
+            let mut loaded = self.loaded_config.write().await;
             let mut current = self.current_config.write().await;
-            *current = new_config.clone();
+            let change = diff_config(&current, &loaded, &new_config)?;
+            *current = (*change.config).clone();
+            *loaded = new_config;
+            change
+        };
+        if change.is_empty() {
+            return Ok(change);
         }
 
-        // Notify subscribers of configuration change
-        let config_arc = Arc::new(new_config);
-        if let Err(e) = self.config_tx.send(Arc::clone(&config_arc)) {
-            warn!("Failed to notify configuration subscribers: {}", e);
+        if !change.restart_required.is_empty() {
+            warn!(
+                "Configuration settings changed that take effect after a restart: {}",
+                change.restart_required.join(", ")
+            );
         }
-
-        info!("LinkML configuration reloaded successfully");
-        Ok(())
+        if !change.applied.is_empty() {
+            if let Err(e) = self.config_tx.send(Arc::clone(&change.config)) {
+                warn!("Failed to notify configuration subscribers: {}", e);
+            }
+            info!(
+                "LinkML configuration reloaded, applied: {}",
+                change.applied.join(", ")
+            );
+        }
+        // Without subscribers there is nobody to notify
+        let _ = self.changes_tx.send(change.clone());
+        Ok(change)
     }
 
-    /// Subscribe to configuration changes
+    /// Subscribe to the effective configuration
     #[must_use]
     pub fn subscribe(&self) -> watch::Receiver<Arc<LinkMLServiceConfig>> {
         self.config_rx.clone()
     }
 
+    /// Subscribe to configuration changes, including those that need a restart
+    #[must_use]
+    pub fn subscribe_changes(&self) -> broadcast::Receiver<ConfigChange> {
+        self.changes_tx.subscribe()
+    }
+
     /// Update a configuration value
     ///
     /// # Errors
@@ -140,8 +184,7 @@ impl<C: ConfigurationService + Send + Sync + 'static> ConfigurationManager<C> {
             .map_err(|e| LinkMLError::config(format!("Failed to set config value '{key}': {e}")))?;
 
         // Reload to get the updated configuration
-        self.reload().await?;
-        Ok(())
+        self.reload().await.map(|_| ())
     }
 
     /// Start monitoring for configuration changes
@@ -173,7 +216,7 @@ impl<C: ConfigurationService + Send + Sync + 'static> ConfigurationManager<C> {
                         interval.tick().await;
 
                         match manager.reload().await {
-                            Ok(()) => info!("Configuration check completed"),
+                            Ok(_) => debug!("Configuration check completed"),
                             Err(e) => error!("Configuration reload failed: {}", e),
                         }
                     }
@@ -196,8 +239,17 @@ impl<C: ConfigurationService + Send + Sync + 'static> ConfigurationManager<C> {
 /// Implement this trait to handle configuration changes in your service.
 #[async_trait]
 pub trait ConfigurationChangeHandler: Send + Sync {
-    /// Called when configuration changes
+    /// Called when runtime settings of the configuration changed
     async fn on_configuration_change(&self, new_config: &LinkMLServiceConfig) -> Result<()>;
+
+    /// Called when settings changed that take effect only after a restart
+    ///
+    /// `settings` are the dotted paths of the changed settings, e.g.
+    /// `typedb.server_address`.
+    async fn on_restart_required(&self, settings: &[String]) -> Result<()> {
+        let _ = settings;
+        Ok(())
+    }
 }
 
 /// Configuration watcher that handles configuration changes
@@ -222,6 +274,23 @@ impl<C: ConfigurationService + Send + Sync + 'static> ConfigurationWatcher<C> {
         handlers.push(handler);
     }
 
+    /// Pass a change on to the registered handlers
+    async fn dispatch(&self, change: &ConfigChange) {
+        let handlers = self.handlers.read().await;
+        for handler in handlers.iter() {
+            if !change.applied.is_empty()
+                && let Err(e) = handler.on_configuration_change(&change.config).await
+            {
+                error!("Configuration change handler failed: {}", e);
+            }
+            if !change.restart_required.is_empty()
+                && let Err(e) = handler.on_restart_required(&change.restart_required).await
+            {
+                error!("Configuration restart handler failed: {}", e);
+            }
+        }
+    }
+
     /// Start watching for configuration changes.
     ///
     /// # Errors
@@ -235,33 +304,21 @@ impl<C: ConfigurationService + Send + Sync + 'static> ConfigurationWatcher<C> {
         TM: task_management_core::TaskManagementService + Send + Sync + 'static,
     {
         let watcher = Arc::clone(&self);
-        let mut rx = self.manager.subscribe();
+        let mut rx = self.manager.subscribe_changes();
 
         let task_id = task_manager
             .spawn_task(
                 async move {
                     loop {
-                        match rx.changed().await {
-                            Ok(()) => {
-                                let new_config = {
-                                    let guard = rx.borrow_and_update();
-                                    Arc::clone(&*guard)
-                                };
+                        match rx.recv().await {
+                            Ok(change) => {
                                 info!("Configuration change detected");
-
-                                let handlers = watcher.handlers.read().await;
-                                for handler in handlers.iter() {
-                                    if let Err(e) =
-                                        handler.on_configuration_change(&new_config).await
-                                    {
-                                        error!("Configuration change handler failed: {}", e);
-                                    }
-                                }
+                                watcher.dispatch(&change).await;
                             }
-                            Err(e) => {
-                                error!("Configuration watch error: {}", e);
-                                break;
+                            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                                warn!("Configuration watcher skipped {} changes", skipped);
                             }
+                            Err(broadcast::error::RecvError::Closed) => break,
                         }
                     }
                 },
@@ -479,8 +536,13 @@ pub mod migration {
         }
 
         // Check if configuration can be successfully reloaded
-        if let Err(e) = manager.reload().await {
-            issues.push(format!("Configuration reload test failed: {e}"));
+        match manager.reload().await {
+            Ok(change) if !change.restart_required.is_empty() => issues.push(format!(
+                "Configuration changes need a restart: {}",
+                change.restart_required.join(", ")
+            )),
+            Ok(_) => {}
+            Err(e) => issues.push(format!("Configuration reload test failed: {e}")),
         }
 
         // Test configuration value access
@@ -499,15 +561,10 @@ pub mod migration {
         Migration Guide: From Standalone Hot-Reload to Configuration Service
         =====================================================================
 
-        1. Replace ConfigHotReloader with ConfigurationManager:
+        The file-watching `config::hot_reload` module has been removed.
 
-           OLD:
-           ```rust
-           use crate::config::hot_reload::{ConfigHotReloader, init_hot_reload};
-           let reloader = ConfigHotReloader::new(config_path)?;
-           ```
+        1. Replace ConfigHotReloader with ConfigurationManager:
 
-           NEW:
            ```rust
            use crate::config::configuration_integration::ConfigurationManager;
            let manager = ConfigurationManager::new(config_service).await?;
@@ -515,12 +572,6 @@ pub mod migration {
 
         2. Replace get_hot_config() with manager.get_config():
 
-           OLD:
-           ```rust
-           let config = get_hot_config().await?;
-           ```
-
-           NEW:
            ```rust
            let config = manager.get_config().await;
            ```
@@ -537,7 +588,8 @@ pub mod migration {
            watcher.start_watching(task_manager).await?;
            ```
 
-        5. Remove all direct file watching with notify crate
+           Handlers receive runtime settings in on_configuration_change() and
+           the settings waiting for a restart in on_restart_required().
         "
     }
 }
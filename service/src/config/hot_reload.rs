@@ -1,297 +0,0 @@
-//! Configuration hot-reload functionality
-//!
-//! This module provides automatic configuration reloading when files change.
-
-use super::{LinkMLConfig, load_config, validation::validate_values};
-use linkml_core::error::LinkMLError;
-use notify::{Event, EventKind, RecursiveMode, Watcher};
-use std::path::{Path, PathBuf};
-use std::sync::{Arc, RwLock};
-use tokio::sync::watch;
-use tracing::{error, info, warn};
-
-/// Configuration hot-reload manager
-pub struct ConfigHotReloader {
-    /// Current configuration
-    config: Arc<RwLock<LinkMLConfig>>,
-    /// Configuration file path
-    config_path: PathBuf,
-    /// Sender for configuration updates
-    tx: watch::Sender<Arc<LinkMLConfig>>,
-    /// Receiver for configuration updates
-    rx: watch::Receiver<Arc<LinkMLConfig>>,
-    /// File watcher
-    watcher: Option<notify::RecommendedWatcher>,
-}
-
-impl ConfigHotReloader {
-    /// Create a new hot-reload manager
-    /// Returns an error if the operation fails
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - The configuration file cannot be read
-    /// - The configuration file contains invalid YAML/JSON
-    /// - The configuration values fail validation
-    pub fn new(config_path: impl AsRef<Path>) -> linkml_core::error::Result<Self> {
-        let config_path = config_path.as_ref().to_path_buf();
-
-        // Load initial configuration
-        let config: LinkMLConfig = load_config(&config_path)?;
-        validate_values(&config)?;
-
-        let config_arc = Arc::new(config);
-        let (tx, rx) = watch::channel(Arc::clone(&config_arc));
-
-        Ok(Self {
-            config: Arc::new(RwLock::new(config_arc.as_ref().clone())),
-            config_path,
-            tx,
-            rx,
-            watcher: None,
-        })
-    }
-
-    /// Start watching for configuration changes
-    /// Returns an error if the operation fails
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - The file watcher cannot be created
-    /// - The configuration file path cannot be watched
-    pub fn start_watching(&mut self) -> linkml_core::error::Result<()> {
-        let config_path = self.config_path.clone();
-        let tx = self.tx.clone();
-        let config = self.config.clone();
-
-        // Create watcher
-        let mut watcher = notify::recommended_watcher(move |res: std::result::Result<Event, notify::Error>| {
-            match res {
-                Ok(event) => {
-                    if matches!(event.kind, EventKind::Modify(_)) {
-                        // Reload configuration
-                        match Self::reload_config(&config_path, &config) {
-                            Ok(new_config) => {
-                                info!("Configuration reloaded successfully");
-                                if let Err(e) = tx.send(new_config) {
-                                    error!("Failed to send configuration update: {}", e);
-                                }
-                            }
-                            Err(e) => {
-                                warn!("Failed to reload configuration: {}. Keeping current config.", e);
-                            }
-                        }
-                    }
-                }
-                Err(e) => error!("File watch error: {}", e)}
-        }).map_err(|e| LinkMLError::other(
-            format!("Failed to create file watcher: {e}")
-        ))?;
-
-        // Watch the configuration file
-        watcher
-            .watch(&self.config_path, RecursiveMode::NonRecursive)
-            .map_err(|e| LinkMLError::other(format!("Failed to watch config file: {e}")))?;
-
-        self.watcher = Some(watcher);
-        info!(
-            "Configuration hot-reload started for: {}",
-            self.config_path.display()
-        );
-
-        Ok(())
-    }
-
-    /// Stop watching for configuration changes
-    pub fn stop_watching(&mut self) {
-        self.watcher = None;
-        info!("Configuration hot-reload stopped");
-    }
-
-    /// Reload configuration from file
-    fn reload_config(
-        path: &Path,
-        current_config: &Arc<RwLock<LinkMLConfig>>,
-    ) -> linkml_core::error::Result<Arc<LinkMLConfig>> {
-        // Load new configuration
-        let new_config: LinkMLConfig = load_config(path)?;
-
-        // Validate new configuration
-        validate_values(&new_config)?;
-
-        // Update the stored configuration
-        if let Ok(mut config_guard) = current_config.write() {
-            *config_guard = new_config.clone();
-        }
-
-        Ok(Arc::new(new_config))
-    }
-
-    /// Get current configuration
-    #[must_use]
-    pub fn get_config(&self) -> Arc<LinkMLConfig> {
-        if let Ok(config_guard) = self.config.read() {
-            Arc::new(config_guard.clone())
-        } else {
-            // Fallback to last known good config from channel
-            self.rx.borrow().clone()
-        }
-    }
-
-    /// Subscribe to configuration updates
-    #[must_use]
-    pub fn subscribe(&self) -> watch::Receiver<Arc<LinkMLConfig>> {
-        self.rx.clone()
-    }
-
-    /// Wait for next configuration update
-    /// Returns an error if the operation fails
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the configuration update channel is closed
-    pub async fn wait_for_update(&mut self) -> linkml_core::error::Result<Arc<LinkMLConfig>> {
-        self.rx.changed().await.map_err(|_| {
-            LinkMLError::ConfigError("Configuration update channel closed".to_string())
-        })?;
-        Ok(self.rx.borrow().clone())
-    }
-}
-
-/// Global configuration hot-reloader instance
-static HOT_RELOADER: std::sync::OnceLock<Arc<tokio::sync::Mutex<ConfigHotReloader>>> =
-    std::sync::OnceLock::new();
-
-/// Initialize global hot-reload configuration
-/// Returns an error if the operation fails
-///
-/// # Errors
-///
-/// Returns an error if:
-/// - The hot reloader cannot be created
-/// - File watching cannot be started
-/// - The global reloader is already initialized
-pub fn init_hot_reload(config_path: impl AsRef<Path>) -> linkml_core::error::Result<()> {
-    let mut reloader = ConfigHotReloader::new(config_path)?;
-    reloader.start_watching()?;
-
-    HOT_RELOADER
-        .set(Arc::new(tokio::sync::Mutex::new(reloader)))
-        .map_err(|_| LinkMLError::ConfigError("Hot-reloader already initialized".to_string()))?;
-
-    Ok(())
-}
-
-/// Get hot-reloaded configuration
-/// Returns an error if the operation fails
-///
-/// # Errors
-///
-/// Returns an error if the hot-reloader has not been initialized
-pub async fn get_hot_config() -> linkml_core::error::Result<Arc<LinkMLConfig>> {
-    let reloader = HOT_RELOADER
-        .get()
-        .ok_or_else(|| LinkMLError::ConfigError("Hot-reloader not initialized".to_string()))?;
-
-    let reloader_guard = reloader.lock().await;
-    Ok(reloader_guard.get_config())
-}
-
-/// Subscribe to configuration updates
-/// Returns an error if the operation fails
-///
-/// # Errors
-///
-/// Returns an error if the hot-reloader has not been initialized
-pub async fn subscribe_to_updates() -> linkml_core::error::Result<watch::Receiver<Arc<LinkMLConfig>>>
-{
-    let reloader = HOT_RELOADER
-        .get()
-        .ok_or_else(|| LinkMLError::ConfigError("Hot-reloader not initialized".to_string()))?;
-
-    let reloader_guard = reloader.lock().await;
-    Ok(reloader_guard.subscribe())
-}
-
-#[cfg(test)]
-#[allow(deprecated)]
-mod tests {
-    use super::*;
-    use std::fs;
-    use tempfile::NamedTempFile;
-
-    #[tokio::test]
-    #[deprecated(
-        note = "Use configuration_integration module instead for proper RootReal integration"
-    )]
-    // This test is deprecated in favor of configuration_integration module tests
-    // but kept for backwards compatibility during migration
-    async fn test_hot_reload_creation() -> std::result::Result<(), anyhow::Error> {
-        // Create a test config and serialize it to a temp file
-        let temp_file = NamedTempFile::new()?;
-        let test_config = crate::config::test_helpers::create_test_config();
-        let config_content = serde_yaml::to_string(&test_config)?;
-        fs::write(temp_file.path(), &config_content)?;
-
-        // Create hot reloader
-        let reloader = ConfigHotReloader::new(temp_file.path());
-        assert!(reloader.is_ok());
-
-        let reloader = reloader?;
-        let config = reloader.get_config();
-        assert_eq!(config.typedb.default_database, "linkml_test");
-        Ok(())
-    }
-
-    #[tokio::test]
-    #[deprecated(
-        note = "Use configuration_integration module instead for proper RootReal integration"
-    )]
-    // This test is deprecated in favor of configuration_integration module tests
-    // but kept for backwards compatibility during migration
-    async fn test_hot_reload_watching() -> std::result::Result<(), anyhow::Error> {
-        // Create a test config and serialize it to a temp file
-        let temp_file = NamedTempFile::new()?;
-        let test_config = crate::config::test_helpers::create_test_config();
-        let config_content = serde_yaml::to_string(&test_config)?;
-        fs::write(temp_file.path(), &config_content)?;
-
-        // Create and start hot reloader
-        let mut reloader = ConfigHotReloader::new(temp_file.path())?;
-        reloader.start_watching()?;
-
-        // Get initial config
-        let initial_config = reloader.get_config();
-        assert_eq!(initial_config.typedb.batch_size, 1000);
-
-        // Subscribe to updates
-        let mut rx = reloader.subscribe();
-
-        // Modify config file
-        let mut modified_content = config_content.clone();
-        modified_content = modified_content.replace("batch_size: 1000", "batch_size: 2000");
-        fs::write(temp_file.path(), modified_content).expect("should write modified config: {}");
-
-        // Wait a bit for file system events and check for update
-        tokio::select! {
-            () = tokio::time::sleep(std::time::Duration::from_millis(500)) => {
-                // File watching might not trigger in test environment,
-                // but we should still check if config was reloaded
-                let updated_config = reloader.get_config();
-                // In a real environment with file watching, this would be 2000
-                // In test environment without file watching, it remains 1000
-                assert!(updated_config.typedb.batch_size == 1000 || updated_config.typedb.batch_size == 2000);
-            }
-            _ = rx.changed() => {
-                // If we received an update notification
-                let new_config = rx.borrow();
-                assert_eq!(new_config.typedb.batch_size, 2000);
-            }
-        }
-
-        reloader.stop_watching();
-        Ok(())
-    }
-}
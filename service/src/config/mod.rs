@@ -3,9 +3,8 @@
 //! This module provides configuration loading from YAML files with
 //! environment variable substitution support.
 
+pub mod changes;
 pub mod configuration_integration;
-#[deprecated(note = "Use configuration_integration module instead for proper RootReal integration")]
-pub mod hot_reload;
 pub mod validation;
 
 use configuration_core::Validate;
@@ -13,7 +12,7 @@ use linkml_core::{LinkMLError, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::env;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Load configuration from `YAML` file with environment variable substitution
 /// Returns an error if the operation fails
@@ -25,9 +24,17 @@ use std::path::Path;
 pub fn load_config<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T> {
     // Read the file
     let contents = std::fs::read_to_string(path).map_err(LinkMLError::IoError)?;
+    parse_config(&contents)
+}
 
+/// Parse configuration from `YAML` text with environment variable substitution
+///
+/// # Errors
+///
+/// Returns `LinkMLError::ConfigError` if the YAML cannot be parsed
+pub fn parse_config<T: for<'de> Deserialize<'de>>(contents: &str) -> Result<T> {
     // Substitute environment variables
-    let substituted = substitute_env_vars(&contents);
+    let substituted = substitute_env_vars(contents);
 
     // Parse YAML
     serde_yaml::from_str(&substituted)
@@ -117,6 +124,47 @@ pub struct TypeDBConfig {
     pub pool_size: usize,
     /// Whether to include inferred facts in query results
     pub include_inferred: bool,
+    /// `TypeQL` dialect spoken by the server
+    #[serde(default)]
+    pub dialect: TypeQLDialect,
+}
+
+/// `TypeQL` language version targeted by generators and the `TypeDB` driver
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeQLDialect {
+    /// `TypeDB` 2.x: `sub entity` definitions, rules and `get` queries
+    #[default]
+    #[serde(rename = "2.x", alias = "2")]
+    V2,
+    /// `TypeDB` 3.x: kind-first definitions, annotations, functions and
+    /// `select` queries
+    #[serde(rename = "3.x", alias = "3")]
+    V3,
+}
+
+impl TypeQLDialect {
+    /// Version label, `2.x` or `3.x`
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::V2 => "2.x",
+            Self::V3 => "3.x",
+        }
+    }
+}
+
+impl std::str::FromStr for TypeQLDialect {
+    type Err = LinkMLError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().trim_start_matches(['v', 'V']) {
+            "2" | "2.x" => Ok(Self::V2),
+            "3" | "3.x" => Ok(Self::V3),
+            other => Err(LinkMLError::config(format!(
+                "Unknown TypeQL dialect '{other}', expected 2.x or 3.x"
+            ))),
+        }
+    }
 }
 
 /// Parser configuration
@@ -261,6 +309,7 @@ impl PerformanceFeatures {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerformanceConfig {
     /// Feature flags for performance optimizations
+    #[serde(default)]
     pub features: PerformanceFeatures,
     /// Memory limit in bytes
     pub memory_limit_bytes: u64,
@@ -344,6 +393,14 @@ pub struct SecurityLimits {
     pub max_expression_time_ms: u64,
     /// Maximum validation errors to collect
     pub max_validation_errors: usize,
+    /// Maximum `is_a`/mixin depth of a class in a schema passed to a generator
+    pub max_generation_inheritance_depth: usize,
+    /// Maximum permissible values of an enum in a schema passed to a generator
+    pub max_generation_enum_values: usize,
+    /// Maximum size of a generated artifact in bytes
+    pub max_generated_output_bytes: u64,
+    /// Maximum generation time in milliseconds
+    pub max_generation_time_ms: u64,
 }
 
 /// Network configuration
@@ -384,6 +441,10 @@ pub struct PatternValidatorConfig {
 pub struct MultiLayerCacheConfig {
     /// L3 cache maximum size in bytes
     pub l3_max_size_bytes: u64,
+    /// Directory of the persistent L3 cache of compiled validators, shared by
+    /// service instances that mount it; no persistent cache when unset
+    #[serde(default)]
+    pub l3_directory: Option<String>,
 }
 
 /// Background services configuration
@@ -416,28 +477,99 @@ pub const DEFAULT_CONFIG_PATH: &str = "config/default.yaml";
 /// Production configuration file path
 pub const PRODUCTION_CONFIG_PATH: &str = "config/production.yaml";
 
+/// Default configuration compiled into the binary, used when
+/// [`DEFAULT_CONFIG_PATH`] does not exist
+pub const EMBEDDED_DEFAULT_CONFIG: &str = include_str!("../../examples/schema/default.yaml");
+
+/// Production configuration compiled into the binary, used when
+/// [`PRODUCTION_CONFIG_PATH`] does not exist
+pub const EMBEDDED_PRODUCTION_CONFIG: &str = include_str!("../../examples/schema/production.yaml");
+
+/// Environment variable naming a configuration file that overrides the
+/// environment-selected default
+pub const CONFIG_ENV_VAR: &str = "LINKML_CONFIG";
+
+/// Where the active configuration came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// A configuration file
+    File(PathBuf),
+    /// The configuration compiled into the binary, because the
+    /// environment's configuration file does not exist
+    Embedded {
+        /// The configuration file that was looked for
+        missing: PathBuf,
+    },
+    /// The built-in fallback values, because no configuration could be
+    /// loaded
+    Fallback {
+        /// Why loading failed
+        reason: String,
+    },
+}
+
+impl ConfigSource {
+    /// Whether a configuration file was expected but not used
+    #[must_use]
+    pub fn is_fallback(&self) -> bool {
+        !matches!(self, Self::File(_))
+    }
+}
+
+impl std::fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "file {}", path.display()),
+            Self::Embedded { missing } => {
+                write!(f, "embedded defaults ({} not found)", missing.display())
+            }
+            Self::Fallback { reason } => write!(f, "built-in fallback values ({reason})"),
+        }
+    }
+}
+
 /// Load default configuration
 /// Returns an error if the operation fails
 ///
+/// Falls back to [`EMBEDDED_DEFAULT_CONFIG`] when the file does not exist,
+/// so installed binaries work outside the source tree.
+///
 /// # Errors
 ///
 /// Returns `LinkMLError::IoError` if the default config file cannot be read
 /// Returns `LinkMLError::ConfigError` if the YAML cannot be parsed
 pub fn load_default_config() -> Result<LinkMLConfig> {
-    let path = Path::new(DEFAULT_CONFIG_PATH);
-    load_config(path)
+    load_config_or_embedded(Path::new(DEFAULT_CONFIG_PATH), EMBEDDED_DEFAULT_CONFIG)
+        .map(|(config, _)| config)
 }
 
 /// Load production configuration
 /// Returns an error if the operation fails
 ///
+/// Falls back to [`EMBEDDED_PRODUCTION_CONFIG`] when the file does not
+/// exist.
+///
 /// # Errors
 ///
 /// Returns `LinkMLError::IoError` if the production config file cannot be read
 /// Returns `LinkMLError::ConfigError` if the YAML cannot be parsed
 pub fn load_production_config() -> Result<LinkMLConfig> {
-    let path = Path::new(PRODUCTION_CONFIG_PATH);
-    load_config(path)
+    load_config_or_embedded(
+        Path::new(PRODUCTION_CONFIG_PATH),
+        EMBEDDED_PRODUCTION_CONFIG,
+    )
+    .map(|(config, _)| config)
+}
+
+fn load_config_or_embedded(path: &Path, embedded: &str) -> Result<(LinkMLConfig, ConfigSource)> {
+    if path.exists() {
+        Ok((load_config(path)?, ConfigSource::File(path.to_path_buf())))
+    } else {
+        let source = ConfigSource::Embedded {
+            missing: path.to_path_buf(),
+        };
+        Ok((parse_config(embedded)?, source))
+    }
 }
 
 /// Load configuration based on environment
@@ -448,29 +580,107 @@ pub fn load_production_config() -> Result<LinkMLConfig> {
 /// Returns `LinkMLError::IoError` if the config file cannot be read
 /// Returns `LinkMLError::ConfigError` if the YAML cannot be parsed
 pub fn load_environment_config() -> Result<LinkMLConfig> {
-    let env = env::var("LINKML_ENV").unwrap_or_else(|_| "default".to_string());
+    resolve_config(None).map(|(config, _)| config)
+}
 
+/// Load the configuration and report where it came from
+///
+/// The first of these is used:
+///
+/// 1. `path`, if given
+/// 2. the file named by [`CONFIG_ENV_VAR`] (`LINKML_CONFIG`)
+/// 3. the file for `LINKML_ENV` (`production`/`prod` or default) relative to
+///    the working directory
+/// 4. the configuration for `LINKML_ENV` compiled into the binary
+///
+/// An explicitly named file must exist; only the environment's default
+/// file falls back to the embedded configuration.
+///
+/// # Errors
+///
+/// Returns `LinkMLError::IoError` if the config file cannot be read
+/// Returns `LinkMLError::ConfigError` if the YAML cannot be parsed
+pub fn resolve_config(path: Option<&Path>) -> Result<(LinkMLConfig, ConfigSource)> {
+    let explicit = path
+        .map(Path::to_path_buf)
+        .or_else(|| env::var_os(CONFIG_ENV_VAR).map(PathBuf::from));
+    if let Some(path) = explicit {
+        let config = load_config(&path).map_err(|e| {
+            LinkMLError::config(format!("Configuration file {}: {e}", path.display()))
+        })?;
+        return Ok((config, ConfigSource::File(path)));
+    }
+
+    let env = env::var("LINKML_ENV").unwrap_or_else(|_| "default".to_string());
     match env.as_str() {
-        "production" | "prod" => load_production_config(),
-        _ => load_default_config(),
+        "production" | "prod" => load_config_or_embedded(
+            Path::new(PRODUCTION_CONFIG_PATH),
+            EMBEDDED_PRODUCTION_CONFIG,
+        ),
+        _ => load_config_or_embedded(Path::new(DEFAULT_CONFIG_PATH), EMBEDDED_DEFAULT_CONFIG),
     }
 }
 
 /// Get a configuration instance (singleton pattern)
-static INSTANCE: std::sync::OnceLock<LinkMLConfig> = std::sync::OnceLock::new();
+static INSTANCE: std::sync::OnceLock<(LinkMLConfig, ConfigSource)> = std::sync::OnceLock::new();
+
+/// Load the global configuration from `path`
+///
+/// Must be called before the first [`get_config`]; command line tools call
+/// it with their `--config` argument.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be loaded or the global
+/// configuration has already been initialized.
+pub fn init_config(path: &Path) -> Result<&'static LinkMLConfig> {
+    let loaded = resolve_config(Some(path))?;
+    INSTANCE.set(loaded).map_err(|_| {
+        LinkMLError::config("The LinkML configuration has already been initialized")
+    })?;
+    Ok(get_config())
+}
 
 /// Get the global configuration instance
+///
+/// Resolved with [`resolve_config`] on first use. If the configuration
+/// comes from the embedded defaults or the fallback values, a warning with
+/// the `config_source` and `reason` fields is logged; services repeat it
+/// through their logger on initialization (see [`config_source`]).
 pub fn get_config() -> &'static LinkMLConfig {
+    &instance().0
+}
+
+/// Where the global configuration came from
+pub fn config_source() -> &'static ConfigSource {
+    &instance().1
+}
+
+fn instance() -> &'static (LinkMLConfig, ConfigSource) {
     INSTANCE.get_or_init(|| {
-        load_environment_config().unwrap_or_else(|e| {
-            // Log the error (in a real implementation, proper logging should be used)
-            eprintln!(
-                "Warning: Failed to load LinkML configuration: {e}. Using fallback defaults."
-            );
-            // Return a minimal working configuration as fallback
-            // This ensures the system can still operate even if config files are missing
-            create_fallback_config()
-        })
+        let (config, source) = resolve_config(None).unwrap_or_else(|e| {
+            // Keep the system operational even if the configuration is broken
+            let source = ConfigSource::Fallback {
+                reason: e.to_string(),
+            };
+            (create_fallback_config(), source)
+        });
+        match &source {
+            ConfigSource::File(path) => {
+                tracing::debug!(config_source = %path.display(), "Loaded LinkML configuration");
+            }
+            ConfigSource::Embedded { missing } => tracing::warn!(
+                config_source = "embedded",
+                missing = %missing.display(),
+                "LinkML configuration file not found, using embedded defaults"
+            ),
+            ConfigSource::Fallback { reason } => tracing::warn!(
+                config_source = "fallback",
+                reason = %reason,
+                "Failed to load LinkML configuration, using built-in fallback values"
+            ),
+        }
+        (config, source)
     })
 }
 
@@ -504,6 +714,7 @@ fn create_fallback_typedb_config() -> TypeDBConfig {
         retry_delay_ms: 1000,
         include_inferred: false,
         pool_size: 10,
+        dialect: TypeQLDialect::default(),
     }
 }
 
@@ -606,6 +817,10 @@ fn create_fallback_security_limits() -> SecurityLimits {
         max_cache_memory_bytes: 104_857_600,
         max_expression_time_ms: 5_000,
         max_validation_errors: 100,
+        max_generation_inheritance_depth: 100,
+        max_generation_enum_values: 50_000,
+        max_generated_output_bytes: 268_435_456,
+        max_generation_time_ms: 120_000,
     }
 }
 
@@ -636,6 +851,7 @@ fn create_fallback_pattern_validator_config() -> PatternValidatorConfig {
 fn create_fallback_multi_layer_cache_config() -> MultiLayerCacheConfig {
     MultiLayerCacheConfig {
         l3_max_size_bytes: 100 * 1024 * 1024, // 100MB
+        l3_directory: None,
     }
 }
 
@@ -655,6 +871,35 @@ mod tests {
         let result = substitute_env_vars(content);
         assert_eq!(result, "val1 and val2");
     }
+
+    #[test]
+    fn test_resolve_explicit_config() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("linkml.yaml");
+        std::fs::write(&path, EMBEDDED_DEFAULT_CONFIG).expect("write config");
+
+        let (_, source) = resolve_config(Some(path.as_path())).expect("explicit config");
+        assert_eq!(source, ConfigSource::File(path.clone()));
+        assert!(!source.is_fallback());
+
+        // An explicitly named file never falls back silently
+        let missing = dir.path().join("missing.yaml");
+        assert!(resolve_config(Some(missing.as_path())).is_err());
+
+        let (_, source) =
+            load_config_or_embedded(&missing, EMBEDDED_DEFAULT_CONFIG).expect("embedded config");
+        assert!(source.is_fallback());
+        assert!(source.to_string().contains("missing.yaml"));
+    }
+
+    #[test]
+    fn test_embedded_configs_parse() {
+        let default: LinkMLConfig = parse_config(EMBEDDED_DEFAULT_CONFIG).expect("default config");
+        assert!(default.security_limits.max_generation_enum_values > 0);
+        let production: LinkMLConfig =
+            parse_config(EMBEDDED_PRODUCTION_CONFIG).expect("production config");
+        assert!(production.security_limits.max_validation_errors > 0);
+    }
 }
 
 /// Test helpers for creating valid configuration instances
@@ -675,6 +920,7 @@ pub mod test_helpers {
                 retry_delay_ms: 1000,
                 pool_size: 10,
                 include_inferred: false,
+                dialect: TypeQLDialect::default(),
             },
             parser: ParserConfig {
                 max_recursion_depth: 100,
@@ -755,6 +1001,10 @@ pub mod test_helpers {
                 max_cache_memory_bytes: 500_000_000,
                 max_expression_time_ms: 5000,
                 max_validation_errors: 1000,
+                max_generation_inheritance_depth: 100,
+                max_generation_enum_values: 50_000,
+                max_generated_output_bytes: 268_435_456,
+                max_generation_time_ms: 120_000,
             },
             network: NetworkConfig {
                 default_host: "localhost".to_string(),
@@ -773,6 +1023,7 @@ pub mod test_helpers {
             },
             multi_layer_cache: MultiLayerCacheConfig {
                 l3_max_size_bytes: 100_000_000,
+                l3_directory: None,
             },
             background_services: BackgroundServicesConfig {
                 cache_ttl_check_interval_secs: 60,
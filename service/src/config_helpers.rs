@@ -57,6 +57,7 @@ pub fn create_fallback_service_config() -> LinkMLConfig {
             retry_delay_ms: 1000,
             pool_size: 10,
             include_inferred: false,
+            dialect: crate::config::TypeQLDialect::default(),
         },
         parser: crate::config::ParserConfig {
             max_recursion_depth: 100,
@@ -133,6 +134,10 @@ pub fn create_fallback_service_config() -> LinkMLConfig {
             max_cache_memory_bytes: 104_857_600,
             max_expression_time_ms: 5_000,
             max_validation_errors: 100,
+            max_generation_inheritance_depth: 100,
+            max_generation_enum_values: 50_000,
+            max_generated_output_bytes: 268_435_456,
+            max_generation_time_ms: 120_000,
         },
         network: crate::config::NetworkConfig {
             default_host: "localhost".to_string(),
@@ -151,6 +156,7 @@ pub fn create_fallback_service_config() -> LinkMLConfig {
         },
         multi_layer_cache: crate::config::MultiLayerCacheConfig {
             l3_max_size_bytes: 100 * 1024 * 1024,
+            l3_directory: None,
         },
         background_services: crate::config::BackgroundServicesConfig::default(),
         cli: crate::config::CliConfig::default(),
@@ -246,6 +252,13 @@ pub fn validate_configuration_constraints(config: &LinkMLConfig) -> Result<()> {
             "Max expression depth must be greater than 0".to_string(),
         ));
     }
+    if config.security_limits.max_generation_inheritance_depth == 0
+        || config.security_limits.max_generated_output_bytes == 0
+    {
+        return Err(LinkMLError::ConfigError(
+            "Generation limits must be greater than 0".to_string(),
+        ));
+    }
 
     Ok(())
 }
@@ -289,6 +302,10 @@ pub fn convert_service_to_core_config(
             stream_buffer_size: 8192,
             enable_mmap: true,
             cache_size_mb: (service_config.cache.max_entries / 1000).max(1), // Rough conversion
+            intern_strings: service_config
+                .performance
+                .features
+                .is_enabled(&crate::config::PerformanceFeature::StringInterning),
         },
         generation: GenerationConfig {
             output_dir: PathBuf::from(&service_config.generator.output_directory),
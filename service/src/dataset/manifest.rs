@@ -0,0 +1,673 @@
+//! Dataset manifest generation
+//!
+//! A manifest describes a directory of data files: content hashes, record
+//! counts per class, the schema the data conforms to, and a validation
+//! summary. The manifest structure is itself described by a LinkML schema
+//! ([`MANIFEST_SCHEMA`]), so catalogs can validate and ingest it like any
+//! other LinkML data.
+
+use crate::validator::engine::{ValidationEngine, ValidationOptions};
+use crate::validator::report::ValidationReport;
+use indexmap::IndexMap;
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::{ClassDefinition, SchemaDefinition};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// LinkML schema describing [`DatasetManifest`]
+pub const MANIFEST_SCHEMA: &str = include_str!("../../../schemas/dataset_manifest.yaml");
+
+/// Manifest for a directory of data files
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasetManifest {
+    /// Dataset name
+    pub name: String,
+    /// Directory the file paths are relative to
+    pub root: String,
+    /// Time the manifest was generated (RFC 3339)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated_at: Option<String>,
+    /// Schema the data files conform to
+    pub schema: SchemaReference,
+    /// Data files, ordered by path
+    pub files: Vec<DataFileEntry>,
+    /// Totals over all files
+    pub summary: ManifestSummary,
+}
+
+/// Reference to the schema a dataset conforms to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaReference {
+    /// Schema URI
+    pub id: String,
+    /// Schema name
+    pub name: String,
+    /// Schema version
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+impl From<&SchemaDefinition> for SchemaReference {
+    fn from(schema: &SchemaDefinition) -> Self {
+        Self {
+            id: schema.id.clone(),
+            name: schema.name.clone(),
+            version: schema.version.clone(),
+        }
+    }
+}
+
+/// Metadata for a single data file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataFileEntry {
+    /// Path relative to the manifest root, using forward slashes
+    pub path: String,
+    /// Serialization format (`json` or `yaml`)
+    pub format: String,
+    /// File size in bytes
+    pub size_bytes: u64,
+    /// Hex-encoded SHA-256 digest of the file content
+    pub sha256: String,
+    /// Number of records in the file
+    pub record_count: usize,
+    /// Number of records per class
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub record_counts: IndexMap<String, usize>,
+    /// Validation outcome, absent when the file was not validated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation: Option<FileValidation>,
+}
+
+/// Validation summary for a data file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileValidation {
+    /// Whether the file passed validation
+    pub valid: bool,
+    /// Number of validation errors
+    pub error_count: usize,
+    /// Number of validation warnings
+    pub warning_count: usize,
+    /// Number of issues per stable error code
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub error_codes: IndexMap<String, usize>,
+}
+
+impl From<&ValidationReport> for FileValidation {
+    fn from(report: &ValidationReport) -> Self {
+        let mut error_codes: IndexMap<String, usize> = IndexMap::new();
+        for issue in &report.issues {
+            *error_codes
+                .entry(issue.error_code().as_str().to_string())
+                .or_default() += 1;
+        }
+        error_codes.sort_keys();
+        Self {
+            valid: report.valid,
+            error_count: report.stats.error_count,
+            warning_count: report.stats.warning_count,
+            error_codes,
+        }
+    }
+}
+
+/// Totals over all files of a dataset
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestSummary {
+    /// Number of files
+    pub file_count: usize,
+    /// Combined size of all files in bytes
+    pub total_size_bytes: u64,
+    /// Number of records over all files
+    pub total_records: usize,
+    /// Number of records per class over all files
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub record_counts: IndexMap<String, usize>,
+    /// Number of files that passed validation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_files: Option<usize>,
+    /// Number of files that failed validation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invalid_files: Option<usize>,
+}
+
+impl ManifestSummary {
+    /// Compute totals for a list of file entries
+    #[must_use]
+    pub fn from_files(files: &[DataFileEntry]) -> Self {
+        let mut summary = Self {
+            file_count: files.len(),
+            ..Self::default()
+        };
+        for file in files {
+            summary.total_size_bytes += file.size_bytes;
+            summary.total_records += file.record_count;
+            for (class, count) in &file.record_counts {
+                *summary.record_counts.entry(class.clone()).or_default() += count;
+            }
+            if let Some(validation) = &file.validation {
+                let counter = if validation.valid {
+                    summary.valid_files.get_or_insert(0)
+                } else {
+                    summary.invalid_files.get_or_insert(0)
+                };
+                *counter += 1;
+            }
+        }
+        if summary.valid_files.is_some() || summary.invalid_files.is_some() {
+            summary.valid_files.get_or_insert(0);
+            summary.invalid_files.get_or_insert(0);
+        }
+        summary
+    }
+}
+
+/// Options for manifest generation
+#[derive(Debug, Clone)]
+pub struct ManifestOptions {
+    /// Dataset name (defaults to the directory name)
+    pub name: Option<String>,
+    /// Class of the top-level objects (defaults to the schema's tree root)
+    pub target_class: Option<String>,
+    /// Whether to validate each file against the schema
+    pub validate: bool,
+    /// File extensions to include
+    pub extensions: Vec<String>,
+    /// Whether to descend into subdirectories
+    pub recursive: bool,
+    /// Generation time recorded in the manifest
+    pub generated_at: Option<String>,
+    /// Files to skip, e.g. a previously written manifest
+    pub exclude: Vec<PathBuf>,
+}
+
+impl Default for ManifestOptions {
+    fn default() -> Self {
+        Self {
+            name: None,
+            target_class: None,
+            validate: true,
+            extensions: vec!["json".to_string(), "yaml".to_string(), "yml".to_string()],
+            recursive: true,
+            generated_at: None,
+            exclude: Vec::new(),
+        }
+    }
+}
+
+impl ManifestOptions {
+    /// Set the dataset name
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the class of the top-level objects
+    #[must_use]
+    pub fn with_target_class(mut self, class: impl Into<String>) -> Self {
+        self.target_class = Some(class.into());
+        self
+    }
+
+    /// Enable or disable validation
+    #[must_use]
+    pub fn with_validation(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Set the file extensions to include
+    #[must_use]
+    pub fn with_extensions(mut self, extensions: Vec<String>) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Set the generation time recorded in the manifest
+    #[must_use]
+    pub fn with_generated_at(mut self, generated_at: impl Into<String>) -> Self {
+        self.generated_at = Some(generated_at.into());
+        self
+    }
+
+    /// Skip a file when scanning
+    #[must_use]
+    pub fn with_exclude(mut self, path: impl Into<PathBuf>) -> Self {
+        self.exclude.push(path.into());
+        self
+    }
+}
+
+/// Builds dataset manifests for a schema
+pub struct ManifestBuilder<'a> {
+    schema: &'a SchemaDefinition,
+    options: ManifestOptions,
+}
+
+impl<'a> ManifestBuilder<'a> {
+    /// Create a builder for data described by `schema`
+    #[must_use]
+    pub fn new(schema: &'a SchemaDefinition) -> Self {
+        Self {
+            schema,
+            options: ManifestOptions::default(),
+        }
+    }
+
+    /// Set the manifest options
+    #[must_use]
+    pub fn with_options(mut self, options: ManifestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Scan a directory and build its manifest
+    ///
+    /// Files are listed in path order so manifests of unchanged data are
+    /// byte-for-byte identical (apart from `generated_at`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be read, a file cannot be
+    /// parsed, or the validator cannot be built
+    pub async fn scan(&self, root: &Path) -> Result<DatasetManifest> {
+        let engine = if self.options.validate {
+            Some(ValidationEngine::new(self.schema)?)
+        } else {
+            None
+        };
+
+        let mut files = Vec::new();
+        for path in self.data_files(root)? {
+            let content = tokio::fs::read(&path).await?;
+            let relative = relative_path(root, &path);
+            let value = parse_data(&path, &content)?;
+            let report = match &engine {
+                Some(engine) => Some(self.validate(engine, &value).await?),
+                None => None,
+            };
+            files.push(self.describe(relative, &path, &content, &value, report.as_ref()));
+        }
+
+        let name = self.options.name.clone().unwrap_or_else(|| {
+            root.canonicalize()
+                .ok()
+                .as_deref()
+                .unwrap_or(root)
+                .file_name()
+                .map_or_else(
+                    || "dataset".to_string(),
+                    |n| n.to_string_lossy().into_owned(),
+                )
+        });
+
+        Ok(DatasetManifest {
+            name,
+            root: root.display().to_string(),
+            generated_at: self.options.generated_at.clone(),
+            schema: SchemaReference::from(self.schema),
+            summary: ManifestSummary::from_files(&files),
+            files,
+        })
+    }
+
+    /// Build the manifest entry for a parsed data file
+    #[must_use]
+    pub fn describe(
+        &self,
+        relative: String,
+        path: &Path,
+        content: &[u8],
+        value: &Value,
+        report: Option<&ValidationReport>,
+    ) -> DataFileEntry {
+        let (record_count, record_counts) =
+            count_records(self.schema, value, self.options.target_class.as_deref());
+        DataFileEntry {
+            path: relative,
+            format: data_format(path).to_string(),
+            size_bytes: content.len() as u64,
+            sha256: format!("{:x}", Sha256::digest(content)),
+            record_count,
+            record_counts,
+            validation: report.map(FileValidation::from),
+        }
+    }
+
+    async fn validate(&self, engine: &ValidationEngine, value: &Value) -> Result<ValidationReport> {
+        let options = Some(ValidationOptions::default());
+        match &self.options.target_class {
+            Some(class) => engine.validate_as_class(value, class, options).await,
+            None => engine.validate(value, options).await,
+        }
+    }
+
+    /// Data files under `root` with a configured extension, in path order
+    fn data_files(&self, root: &Path) -> Result<Vec<PathBuf>> {
+        let max_depth = if self.options.recursive {
+            usize::MAX
+        } else {
+            1
+        };
+        let excluded: Vec<PathBuf> = self
+            .options
+            .exclude
+            .iter()
+            .filter_map(|path| path.canonicalize().ok())
+            .collect();
+        let mut files = Vec::new();
+        for entry in WalkDir::new(root).max_depth(max_depth).sort_by_file_name() {
+            let entry = entry.map_err(|e| LinkMLError::io_error(e.to_string()))?;
+            let matches_extension = entry.path().extension().is_some_and(|ext| {
+                self.options
+                    .extensions
+                    .iter()
+                    .any(|wanted| ext.eq_ignore_ascii_case(wanted.trim_start_matches('.')))
+            });
+            let is_excluded = || {
+                entry
+                    .path()
+                    .canonicalize()
+                    .is_ok_and(|path| excluded.contains(&path))
+            };
+            if entry.file_type().is_file() && matches_extension && !is_excluded() {
+                files.push(entry.into_path());
+            }
+        }
+        Ok(files)
+    }
+}
+
+/// Count the records in a data value
+///
+/// A top-level array holds records of the target class. A top-level object of
+/// a container class (usually the tree root) is unpacked: every slot whose
+/// range is a class contributes its values as records of that class (lists and
+/// dictionaries keyed by identifier alike). Any other object is a single
+/// record of the target class.
+///
+/// Returns the total and the per-class counts.
+#[must_use]
+pub fn count_records(
+    schema: &SchemaDefinition,
+    value: &Value,
+    target_class: Option<&str>,
+) -> (usize, IndexMap<String, usize>) {
+    let class_name = target_class.map(str::to_string).or_else(|| {
+        let mut roots = schema
+            .classes
+            .iter()
+            .filter(|(_, class)| class.tree_root == Some(true));
+        match (roots.next(), roots.next()) {
+            (Some((name, _)), None) => Some(name.clone()),
+            _ => None,
+        }
+    });
+
+    let mut counts: IndexMap<String, usize> = IndexMap::new();
+    let mut add = |class: Option<&str>, count: usize| {
+        if let Some(class) = class
+            && count > 0
+        {
+            *counts.entry(class.to_string()).or_default() += count;
+        }
+        count
+    };
+
+    let total = match value {
+        Value::Array(items) => add(class_name.as_deref(), items.len()),
+        Value::Object(object) => {
+            let container_slots = class_name
+                .as_deref()
+                .and_then(|name| schema.classes.get(name))
+                .map(|class| class_valued_slots(schema, class))
+                .unwrap_or_default();
+            let mut total = 0;
+            let mut unpacked = false;
+            for (slot, range) in &container_slots {
+                let count = match object.get(slot) {
+                    Some(Value::Array(items)) => items.len(),
+                    Some(Value::Object(map)) if is_inlined_dict(map) => map.len(),
+                    Some(Value::Object(_)) => 1,
+                    _ => continue,
+                };
+                unpacked = true;
+                total += add(Some(range), count);
+            }
+            if unpacked {
+                total
+            } else {
+                add(class_name.as_deref(), 1)
+            }
+        }
+        Value::Null => 0,
+        _ => 1,
+    };
+    (total, counts)
+}
+
+/// Slots of a class whose range is another class, with that range
+fn class_valued_slots(schema: &SchemaDefinition, class: &ClassDefinition) -> Vec<(String, String)> {
+    let range_of = |name: &str| {
+        class
+            .slot_usage
+            .get(name)
+            .and_then(|usage| usage.range.clone())
+            .or_else(|| class.attributes.get(name).and_then(|a| a.range.clone()))
+            .or_else(|| schema.slots.get(name).and_then(|s| s.range.clone()))
+    };
+    class
+        .slots
+        .iter()
+        .chain(class.attributes.keys())
+        .filter_map(|name| {
+            let range = range_of(name)?;
+            schema
+                .classes
+                .contains_key(&range)
+                .then(|| (name.clone(), range))
+        })
+        .collect()
+}
+
+/// Whether an object is a dictionary of inlined objects keyed by identifier
+fn is_inlined_dict(map: &serde_json::Map<String, Value>) -> bool {
+    !map.is_empty() && map.values().all(|v| v.is_object() || v.is_null())
+}
+
+fn data_format(path: &Path) -> &'static str {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml") => "yaml",
+        _ => "json",
+    }
+}
+
+fn parse_data(path: &Path, content: &[u8]) -> Result<Value> {
+    let parsed = if data_format(path) == "yaml" {
+        serde_yaml::from_slice(content).map_err(|e| e.to_string())
+    } else {
+        serde_json::from_slice(content).map_err(|e| e.to_string())
+    };
+    parsed.map_err(|e| LinkMLError::parse_at(e, path.display().to_string()))
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::report::ValidationIssue;
+    use linkml_core::types::SlotDefinition;
+    use serde_json::json;
+
+    fn create_test_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("people");
+        schema.id = "https://example.org/people".to_string();
+        schema.version = Some("1.2.0".to_string());
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: vec!["name".to_string()],
+                ..Default::default()
+            },
+        );
+        schema
+            .classes
+            .insert("Organization".to_string(), ClassDefinition::default());
+        schema.classes.insert(
+            "Container".to_string(),
+            ClassDefinition {
+                tree_root: Some(true),
+                slots: vec!["persons".to_string(), "organizations".to_string()],
+                ..Default::default()
+            },
+        );
+        for (slot, range) in [
+            ("name", "string"),
+            ("persons", "Person"),
+            ("organizations", "Organization"),
+        ] {
+            schema.slots.insert(
+                slot.to_string(),
+                SlotDefinition {
+                    range: Some(range.to_string()),
+                    ..Default::default()
+                },
+            );
+        }
+        schema
+    }
+
+    #[test]
+    fn test_count_records() {
+        let schema = create_test_schema();
+
+        let container = json!({
+            "persons": [{"name": "a"}, {"name": "b"}],
+            "organizations": {"org1": {}, "org2": {}, "org3": {}},
+        });
+        let (total, counts) = count_records(&schema, &container, None);
+        assert_eq!(total, 5);
+        assert_eq!(counts["Person"], 2);
+        assert_eq!(counts["Organization"], 3);
+
+        let (total, counts) = count_records(&schema, &json!([{}, {}]), Some("Person"));
+        assert_eq!(total, 2);
+        assert_eq!(counts["Person"], 2);
+
+        let (total, counts) = count_records(&schema, &json!({"name": "a"}), Some("Person"));
+        assert_eq!(total, 1);
+        assert_eq!(counts["Person"], 1);
+    }
+
+    #[test]
+    fn test_describe_file() {
+        let schema = create_test_schema();
+        let builder = ManifestBuilder::new(&schema);
+        let content = br#"{"persons": [{"name": "a"}]}"#;
+        let path = Path::new("data/people.json");
+        let value = parse_data(path, content).expect("valid JSON");
+
+        let mut report = ValidationReport::new(&schema.id);
+        report.add_issue(ValidationIssue::error(
+            "bad",
+            "$.persons[0]",
+            "pattern_validator",
+        ));
+        let entry = builder.describe(
+            "data/people.json".to_string(),
+            path,
+            content,
+            &value,
+            Some(&report),
+        );
+
+        assert_eq!(entry.format, "json");
+        assert_eq!(entry.size_bytes, content.len() as u64);
+        assert_eq!(entry.sha256.len(), 64);
+        assert_eq!(entry.record_count, 1);
+        let validation = entry.validation.expect("validated");
+        assert!(!validation.valid);
+        assert_eq!(validation.error_codes["LML1001"], 1);
+    }
+
+    #[test]
+    fn test_summary() {
+        let entry = |records: usize, valid: Option<bool>| DataFileEntry {
+            path: String::new(),
+            format: "json".to_string(),
+            size_bytes: 10,
+            sha256: String::new(),
+            record_count: records,
+            record_counts: [("Person".to_string(), records)].into_iter().collect(),
+            validation: valid.map(|valid| FileValidation {
+                valid,
+                error_count: usize::from(!valid),
+                warning_count: 0,
+                error_codes: IndexMap::new(),
+            }),
+        };
+
+        let summary = ManifestSummary::from_files(&[entry(2, Some(true)), entry(3, Some(false))]);
+        assert_eq!(summary.file_count, 2);
+        assert_eq!(summary.total_size_bytes, 20);
+        assert_eq!(summary.total_records, 5);
+        assert_eq!(summary.record_counts["Person"], 5);
+        assert_eq!(summary.valid_files, Some(1));
+        assert_eq!(summary.invalid_files, Some(1));
+
+        let unvalidated = ManifestSummary::from_files(&[entry(1, None)]);
+        assert_eq!(unvalidated.valid_files, None);
+    }
+
+    #[tokio::test]
+    async fn test_scan_directory() {
+        let schema = create_test_schema();
+        let dir = tempfile::tempdir().expect("temp dir");
+        std::fs::create_dir(dir.path().join("nested")).expect("create dir");
+        std::fs::write(dir.path().join("b.json"), r#"{"persons": [{"name": "a"}]}"#)
+            .expect("write file");
+        std::fs::write(dir.path().join("nested/a.yaml"), "- name: x\n- name: y\n")
+            .expect("write file");
+        std::fs::write(dir.path().join("notes.txt"), "ignored").expect("write file");
+        std::fs::write(dir.path().join("manifest.yaml"), "name: old\n").expect("write file");
+
+        let manifest = ManifestBuilder::new(&schema)
+            .with_options(
+                ManifestOptions::default()
+                    .with_name("people-data")
+                    .with_validation(false)
+                    .with_exclude(dir.path().join("manifest.yaml")),
+            )
+            .scan(dir.path())
+            .await
+            .expect("scan succeeds");
+
+        assert_eq!(manifest.name, "people-data");
+        assert_eq!(manifest.schema.version.as_deref(), Some("1.2.0"));
+        let paths: Vec<&str> = manifest.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, ["b.json", "nested/a.yaml"]);
+        assert_eq!(manifest.files[1].format, "yaml");
+        assert_eq!(manifest.summary.total_records, 3);
+        assert!(manifest.files.iter().all(|f| f.validation.is_none()));
+    }
+
+    #[test]
+    fn test_manifest_schema_parses() {
+        let schema: SchemaDefinition =
+            serde_yaml::from_str(MANIFEST_SCHEMA).expect("manifest schema is valid YAML");
+        assert_eq!(schema.name, "dataset_manifest");
+        assert!(schema.classes.contains_key("DatasetManifest"));
+        assert!(schema.classes.contains_key("DataFile"));
+    }
+}
@@ -0,0 +1,8 @@
+//! Dataset-level metadata for collections of LinkML data files
+
+pub mod manifest;
+
+pub use manifest::{
+    DataFileEntry, DatasetManifest, FileValidation, MANIFEST_SCHEMA, ManifestBuilder,
+    ManifestOptions, ManifestSummary, SchemaReference, count_records,
+};
@@ -77,9 +77,7 @@ impl ExpressionCache {
 
     /// Create expression cache from `LinkML` service configuration
     #[must_use]
-    pub fn from_service_config(
-        config: &linkml_core::configuration_v2::ExpressionCacheConfig,
-    ) -> Self {
+    pub fn from_service_config(config: &linkml_core::configuration::ExpressionCacheConfig) -> Self {
         // Ensure capacity is at least 1
         let capacity = NonZeroUsize::new(config.max_entries.max(1))
             .expect("config.max_entries.max(1) is always >= 1");
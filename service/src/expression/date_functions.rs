@@ -509,12 +509,12 @@ impl BuiltinFunction for DayFunction {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::timestamp::wire_timestamp;
     use serde_json::json;
-    use timestamp_service::wiring::wire_timestamp;
 
     #[test]
     fn test_now_today() {
-        let timestamp_service = wire_timestamp().into_inner();
+        let timestamp_service = wire_timestamp();
 
         let now_fn = NowFunction::new(timestamp_service.clone());
         let result = now_fn.call(vec![]).expect("now_fn.call should succeed");
@@ -98,7 +98,7 @@ impl ExpressionEngineV2 {
     #[must_use]
     pub fn new(config: EngineConfig) -> Self {
         let function_registry = Arc::new(FunctionRegistry::new());
-        let timestamp_service = timestamp_service::wiring::wire_sync_timestamp();
+        let timestamp_service = crate::utils::timestamp::wire_sync_timestamp();
 
         Self {
             parser: Parser::new(),
@@ -149,7 +149,7 @@ impl ExpressionEngineV2 {
         config: EngineConfig,
         function_registry: Arc<FunctionRegistry>,
     ) -> Self {
-        let timestamp_service = timestamp_service::wiring::wire_sync_timestamp();
+        let timestamp_service = crate::utils::timestamp::wire_sync_timestamp();
 
         Self {
             parser: Parser::new(),
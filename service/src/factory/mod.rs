@@ -1,4 +1,9 @@
 //! Factory functions for creating LinkML service instances
+//!
+//! The functions here take the service dependencies directly; those in
+//! [`configuration`] load the configuration through the Configuration Service
+//! first, and those in [`dbms`] add the DBMS-backed storage. All of them are
+//! re-exported from this module.
 
 use std::sync::Arc;
 
@@ -18,6 +23,18 @@ use task_management_core::TaskManagementService;
 use timeout_core::TimeoutService;
 use timestamp_core::{TimestampError, TimestampService};
 
+pub mod configuration;
+pub mod dbms;
+
+pub use configuration::{
+    Environment, create_linkml_service_for_environment, create_linkml_service_from_source,
+    create_linkml_service_with_configuration, create_linkml_service_with_custom_config,
+};
+pub use dbms::{
+    create_linkml_service_with_dbms, create_linkml_service_with_dbms_and_config,
+    create_linkml_storage,
+};
+
 /// Create a new `LinkML` service instance with all dependencies
 ///
 /// Generic parameters for non-dyn-compatible services:
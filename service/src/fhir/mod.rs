@@ -0,0 +1,143 @@
+//! FHIR resource profiles
+//!
+//! Clinical models written in `LinkML` are exchanged as FHIR resources. A
+//! class profiles a FHIR resource type and its slots constrain elements of
+//! that resource:
+//!
+//! - the `fhir_resource` annotation of a class names the profiled resource
+//!   type, which defaults to the class name
+//! - the `fhir_path` annotation of a slot gives the dotted element path
+//!   relative to the resource, e.g. `name.family`, which defaults to the
+//!   slot name
+//!
+//! [`FhirProfileGenerator`] writes a `StructureDefinition` per concrete class
+//! that has an identifier or a `fhir_resource` annotation, with the slots as
+//! the differential, and a `ValueSet` per enum used. Classes without either
+//! are nested as backbone elements of the resources using them. The
+//! canonical URL of a profile is derived from the schema id, see
+//! [`profile_url`].
+//!
+//! [`FhirLoader`] reads FHIR JSON resources, single or in a `Bundle`, into
+//! instances of the classes profiling them, and
+//! [`FhirLoader::validate_string`] validates each resource against its
+//! profile class:
+//!
+//! ```rust,no_run
+//! use linkml_service::fhir::FhirLoader;
+//! # use linkml_core::prelude::*;
+//!
+//! # async fn example(schema: &SchemaDefinition) -> linkml_core::error::Result<()> {
+//! let bundle = std::fs::read_to_string("patients.json")?;
+//! for report in FhirLoader::new().validate_string(&bundle, schema).await? {
+//!     if !report.valid {
+//!         println!("{:?}: {} issues", report.target_class, report.issues.len());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod profile;
+pub mod resource;
+
+pub use profile::FhirProfileGenerator;
+pub use resource::FhirLoader;
+
+use linkml_core::annotations::AnnotationValue;
+use linkml_core::prelude::*;
+
+use crate::generator::base::induced_slots;
+
+/// Class annotation naming the FHIR resource type a class profiles
+pub const FHIR_RESOURCE_ANNOTATION: &str = "fhir_resource";
+
+/// Slot annotation giving the element path of a slot within its resource
+pub const FHIR_PATH_ANNOTATION: &str = "fhir_path";
+
+/// Base of the canonical URLs of the core FHIR resource definitions
+pub const FHIR_CORE_BASE: &str = "http://hl7.org/fhir/StructureDefinition/";
+
+/// FHIR resource type profiled by a class
+#[must_use]
+pub fn resource_type(class_name: &str, class: &ClassDefinition) -> String {
+    match class
+        .annotations
+        .as_ref()
+        .and_then(|annotations| annotations.get(FHIR_RESOURCE_ANNOTATION))
+    {
+        Some(AnnotationValue::String(resource)) => resource.clone(),
+        _ => class_name.to_string(),
+    }
+}
+
+/// Element path of a slot relative to its resource
+#[must_use]
+pub fn element_path(slot: &SlotDefinition) -> String {
+    match slot
+        .annotations
+        .as_ref()
+        .and_then(|annotations| annotations.get(FHIR_PATH_ANNOTATION))
+    {
+        Some(AnnotationValue::String(path)) => path.clone(),
+        _ => slot.name.clone(),
+    }
+}
+
+/// Canonical URL of the profile of a class, e.g.
+/// `https://example.org/clinical/StructureDefinition/Patient`
+#[must_use]
+pub fn profile_url(schema: &SchemaDefinition, class_name: &str) -> String {
+    format!(
+        "{}/StructureDefinition/{class_name}",
+        schema.id.trim_end_matches('/')
+    )
+}
+
+/// Canonical URL of the value set of an enum
+#[must_use]
+pub fn value_set_url(schema: &SchemaDefinition, enum_name: &str) -> String {
+    format!("{}/ValueSet/{enum_name}", schema.id.trim_end_matches('/'))
+}
+
+/// Classes profiling a FHIR resource
+///
+/// These are the concrete classes that are annotated with a resource type or
+/// have an identifier. Classes without either are nested datatypes of the
+/// resources using them.
+pub(crate) fn profile_classes(
+    schema: &SchemaDefinition,
+) -> impl Iterator<Item = (&String, &ClassDefinition)> {
+    schema.classes.iter().filter(|(class_name, class)| {
+        class.abstract_ != Some(true)
+            && class.mixin != Some(true)
+            && (class
+                .annotations
+                .as_ref()
+                .is_some_and(|annotations| annotations.contains_key(FHIR_RESOURCE_ANNOTATION))
+                || induced_slots(schema, class_name)
+                    .iter()
+                    .any(|slot| slot.identifier == Some(true)))
+    })
+}
+
+/// FHIR primitive type of a type range
+pub(crate) fn primitive_type(schema: &SchemaDefinition, range: &str) -> &'static str {
+    let mut range = range;
+    for _ in 0..=schema.types.len() {
+        match range {
+            "string" | "str" | "ncname" | "nodeidentifier" => return "string",
+            "integer" | "int" => return "integer",
+            "float" | "double" | "decimal" => return "decimal",
+            "boolean" | "bool" => return "boolean",
+            "date" => return "date",
+            "datetime" => return "dateTime",
+            "time" => return "time",
+            "uri" | "uriorcurie" | "curie" => return "uri",
+            _ => match schema.types.get(range).and_then(|t| t.base_type.as_deref()) {
+                Some(base_type) => range = base_type,
+                None => break,
+            },
+        }
+    }
+    "string"
+}
@@ -0,0 +1,433 @@
+//! FHIR `StructureDefinition` generation
+//!
+//! Each resource class becomes a profile constraining its resource type. The
+//! slots of the class form the differential: cardinality from `required` and
+//! `multivalued`, the element type from the range, a `short` from the
+//! description and a required binding for enum ranges. Inlined class ranges
+//! are backbone elements whose slots are constrained beneath them, other
+//! class ranges are references to the profile of the range. Profiles and the
+//! value sets of the enums they bind are written as a `collection` bundle.
+
+use linkml_core::error::LinkMLError;
+use linkml_core::prelude::*;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeSet;
+
+use super::{
+    FHIR_CORE_BASE, element_path, primitive_type, profile_classes, profile_url, resource_type,
+    value_set_url,
+};
+use crate::generator::base::induced_slots;
+use crate::generator::traits::Generator;
+
+/// FHIR release the generated profiles conform to
+const FHIR_VERSION: &str = "4.0.1";
+
+/// Generator of FHIR `StructureDefinition` profiles
+#[derive(Debug, Default)]
+pub struct FhirProfileGenerator {
+    status: Option<String>,
+}
+
+impl FhirProfileGenerator {
+    /// Create a new FHIR profile generator
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Publication status of the generated resources, `draft` by default
+    #[must_use]
+    pub fn with_status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    fn status(&self) -> &str {
+        self.status.as_deref().unwrap_or("draft")
+    }
+
+    /// `StructureDefinition` profiling the resource of a class
+    fn structure_definition(
+        &self,
+        schema: &SchemaDefinition,
+        class_name: &str,
+        class: &ClassDefinition,
+        enums: &mut BTreeSet<String>,
+    ) -> Value {
+        let resource = resource_type(class_name, class);
+        // A class refining another profile of the same resource derives from it
+        let base = class
+            .is_a
+            .as_deref()
+            .and_then(|parent| Some((parent, schema.classes.get(parent)?)))
+            .filter(|(parent, parent_class)| resource_type(parent, parent_class) == resource)
+            .map_or_else(
+                || format!("{FHIR_CORE_BASE}{resource}"),
+                |(parent, _)| profile_url(schema, parent),
+            );
+
+        let mut root = Map::new();
+        root.insert("id".to_string(), json!(resource));
+        root.insert("path".to_string(), json!(resource));
+        if let Some(description) = &class.description {
+            root.insert("short".to_string(), json!(description));
+        }
+        let mut elements = vec![Value::Object(root)];
+        let mut visited = vec![class_name.to_string()];
+        differential_elements(
+            schema,
+            class_name,
+            &resource,
+            &mut visited,
+            enums,
+            &mut elements,
+        );
+
+        let mut definition = Map::new();
+        definition.insert("resourceType".to_string(), json!("StructureDefinition"));
+        definition.insert("id".to_string(), json!(class_name));
+        definition.insert("url".to_string(), json!(profile_url(schema, class_name)));
+        if let Some(version) = &schema.version {
+            definition.insert("version".to_string(), json!(version));
+        }
+        definition.insert("name".to_string(), json!(class_name));
+        definition.insert("status".to_string(), json!(self.status()));
+        if let Some(description) = &class.description {
+            definition.insert("description".to_string(), json!(description));
+        }
+        definition.insert("fhirVersion".to_string(), json!(FHIR_VERSION));
+        definition.insert("kind".to_string(), json!("resource"));
+        definition.insert("abstract".to_string(), json!(false));
+        definition.insert("type".to_string(), json!(resource));
+        definition.insert("baseDefinition".to_string(), json!(base));
+        definition.insert("derivation".to_string(), json!("constraint"));
+        definition.insert("differential".to_string(), json!({ "element": elements }));
+        Value::Object(definition)
+    }
+
+    /// `ValueSet` of the permissible values of an enum
+    fn value_set(&self, schema: &SchemaDefinition, enum_name: &str) -> Option<Value> {
+        let enum_def = schema.enums.get(enum_name)?;
+        let concepts: Vec<Value> = enum_def
+            .permissible_values
+            .iter()
+            .map(|value| match value {
+                PermissibleValue::Complex {
+                    text,
+                    description: Some(description),
+                    ..
+                } => json!({ "code": text, "display": description }),
+                _ => json!({ "code": value.text() }),
+            })
+            .collect();
+        let system = enum_def
+            .code_set
+            .clone()
+            .unwrap_or_else(|| value_set_url(schema, enum_name));
+
+        let mut value_set = Map::new();
+        value_set.insert("resourceType".to_string(), json!("ValueSet"));
+        value_set.insert("id".to_string(), json!(enum_name));
+        value_set.insert("url".to_string(), json!(value_set_url(schema, enum_name)));
+        value_set.insert("name".to_string(), json!(enum_name));
+        value_set.insert("status".to_string(), json!(self.status()));
+        if let Some(description) = &enum_def.description {
+            value_set.insert("description".to_string(), json!(description));
+        }
+        value_set.insert(
+            "compose".to_string(),
+            json!({ "include": [{ "system": system, "concept": concepts }] }),
+        );
+        Some(Value::Object(value_set))
+    }
+
+    /// Bundle of the profiles of all resource classes and their value sets
+    fn bundle(&self, schema: &SchemaDefinition) -> Value {
+        let mut enums = BTreeSet::new();
+        let mut entries: Vec<Value> = profile_classes(schema)
+            .map(|(class_name, class)| {
+                let definition = self.structure_definition(schema, class_name, class, &mut enums);
+                json!({ "fullUrl": definition["url"], "resource": definition })
+            })
+            .collect();
+        entries.extend(
+            enums
+                .iter()
+                .filter_map(|enum_name| self.value_set(schema, enum_name))
+                .map(|value_set| json!({ "fullUrl": value_set["url"], "resource": value_set })),
+        );
+        json!({ "resourceType": "Bundle", "type": "collection", "entry": entries })
+    }
+}
+
+/// Differential elements of the slots of a class beneath `parent_path`
+fn differential_elements(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    parent_path: &str,
+    visited: &mut Vec<String>,
+    enums: &mut BTreeSet<String>,
+    elements: &mut Vec<Value>,
+) {
+    for slot in induced_slots(schema, class_name) {
+        let path = format!("{parent_path}.{}", element_path(&slot));
+        let mut element = Map::new();
+        element.insert("id".to_string(), json!(path));
+        element.insert("path".to_string(), json!(path));
+        if let Some(description) = &slot.description {
+            element.insert("short".to_string(), json!(description));
+        }
+        let required = slot.required == Some(true) || slot.identifier == Some(true);
+        element.insert("min".to_string(), json!(u8::from(required)));
+        let max = if slot.multivalued == Some(true) {
+            "*"
+        } else {
+            "1"
+        };
+        element.insert("max".to_string(), json!(max));
+
+        let range = slot.range.as_deref().unwrap_or("string");
+        let mut backbone = None;
+        if schema.enums.contains_key(range) {
+            element.insert("type".to_string(), json!([{ "code": "code" }]));
+            element.insert(
+                "binding".to_string(),
+                json!({ "strength": "required", "valueSet": value_set_url(schema, range) }),
+            );
+            enums.insert(range.to_string());
+        } else if schema.classes.contains_key(range) {
+            if is_inlined(schema, &slot, range) {
+                element.insert("type".to_string(), json!([{ "code": "BackboneElement" }]));
+                backbone = Some(range);
+            } else {
+                element.insert(
+                    "type".to_string(),
+                    json!([{ "code": "Reference", "targetProfile": [profile_url(schema, range)] }]),
+                );
+            }
+        } else {
+            element.insert(
+                "type".to_string(),
+                json!([{ "code": primitive_type(schema, range) }]),
+            );
+        }
+        elements.push(Value::Object(element));
+
+        // Recursive classes are constrained once, at their first level
+        if let Some(range) = backbone
+            && !visited.iter().any(|name| name == range)
+        {
+            visited.push(range.to_string());
+            differential_elements(schema, range, &path, visited, enums, elements);
+            visited.pop();
+        }
+    }
+}
+
+/// Whether values of a class range are nested in the resource rather than
+/// referenced
+fn is_inlined(schema: &SchemaDefinition, slot: &SlotDefinition, range: &str) -> bool {
+    slot.inlined == Some(true)
+        || slot.inlined_as_list == Some(true)
+        || !induced_slots(schema, range)
+            .iter()
+            .any(|slot| slot.identifier == Some(true))
+}
+
+impl Generator for FhirProfileGenerator {
+    fn name(&self) -> &'static str {
+        "fhir"
+    }
+
+    fn description(&self) -> &'static str {
+        "Generates FHIR StructureDefinition profiles and value sets from LinkML schemas"
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> linkml_core::error::Result<()> {
+        if schema.id.is_empty() {
+            return Err(LinkMLError::data_validation(
+                "Schema must have an id to derive canonical URLs of FHIR profiles",
+            ));
+        }
+        Ok(())
+    }
+
+    fn generate(&self, schema: &SchemaDefinition) -> std::result::Result<String, LinkMLError> {
+        Generator::validate_schema(self, schema)?;
+        serde_json::to_string_pretty(&self.bundle(schema))
+            .map_err(|e| LinkMLError::service(format!("Failed to serialize FHIR bundle: {e}")))
+    }
+
+    fn get_file_extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn get_default_filename(&self) -> &'static str {
+        "profiles"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::annotations::{AnnotationValue, Annotations};
+    use linkml_core::types::{ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition};
+
+    fn clinical_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/clinical/".to_string(),
+            name: "clinical".to_string(),
+            ..Default::default()
+        };
+        let annotated = |key: &str, value: &str| {
+            let mut annotations = Annotations::new();
+            annotations.insert(key.to_string(), AnnotationValue::String(value.to_string()));
+            Some(annotations)
+        };
+        for (name, slot) in [
+            (
+                "id",
+                SlotDefinition {
+                    identifier: Some(true),
+                    ..Default::default()
+                },
+            ),
+            (
+                "family_name",
+                SlotDefinition {
+                    description: Some("Surname".to_string()),
+                    annotations: annotated("fhir_path", "name.family"),
+                    ..Default::default()
+                },
+            ),
+            (
+                "gender",
+                SlotDefinition {
+                    range: Some("Gender".to_string()),
+                    required: Some(true),
+                    ..Default::default()
+                },
+            ),
+            (
+                "contacts",
+                SlotDefinition {
+                    range: Some("Contact".to_string()),
+                    multivalued: Some(true),
+                    ..Default::default()
+                },
+            ),
+            (
+                "phone",
+                SlotDefinition {
+                    range: Some("string".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "subject",
+                SlotDefinition {
+                    range: Some("Patient".to_string()),
+                    required: Some(true),
+                    ..Default::default()
+                },
+            ),
+        ] {
+            schema.slots.insert(name.to_string(), slot);
+        }
+        schema.enums.insert(
+            "Gender".to_string(),
+            EnumDefinition {
+                permissible_values: vec![
+                    PermissibleValue::Simple("female".to_string()),
+                    PermissibleValue::Simple("male".to_string()),
+                ],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Patient".to_string(),
+            ClassDefinition {
+                slots: vec![
+                    "id".to_string(),
+                    "family_name".to_string(),
+                    "gender".to_string(),
+                    "contacts".to_string(),
+                ],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Contact".to_string(),
+            ClassDefinition {
+                slots: vec!["phone".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "BloodPressure".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "subject".to_string()],
+                annotations: annotated("fhir_resource", "Observation"),
+                ..Default::default()
+            },
+        );
+        schema
+    }
+
+    #[test]
+    fn test_profile_generation() {
+        let mut schema = clinical_schema();
+        let output = FhirProfileGenerator::new()
+            .generate(&schema)
+            .expect("should generate FHIR profiles");
+        let bundle: Value = serde_json::from_str(&output).expect("valid JSON");
+        assert_eq!(bundle["resourceType"], "Bundle");
+        let resource = |id: &str| {
+            bundle["entry"]
+                .as_array()
+                .and_then(|entries| entries.iter().find(|e| e["resource"]["id"] == id))
+                .map(|entry| entry["resource"].clone())
+                .expect("resource should be in the bundle")
+        };
+
+        let patient = resource("Patient");
+        assert_eq!(
+            patient["url"],
+            "https://example.org/clinical/StructureDefinition/Patient"
+        );
+        assert_eq!(
+            patient["baseDefinition"],
+            "http://hl7.org/fhir/StructureDefinition/Patient"
+        );
+        let elements = &patient["differential"]["element"];
+        assert_eq!(elements[2]["path"], "Patient.name.family");
+        assert_eq!(elements[2]["short"], "Surname");
+        assert_eq!(elements[3]["min"], 1);
+        assert_eq!(
+            elements[3]["binding"]["valueSet"],
+            "https://example.org/clinical/ValueSet/Gender"
+        );
+        assert_eq!(elements[4]["max"], "*");
+        assert_eq!(elements[4]["type"][0]["code"], "BackboneElement");
+        assert_eq!(elements[5]["path"], "Patient.contacts.phone");
+
+        let observation = resource("BloodPressure");
+        assert_eq!(observation["type"], "Observation");
+        let subject = &observation["differential"]["element"][2];
+        assert_eq!(subject["type"][0]["code"], "Reference");
+        assert_eq!(
+            subject["type"][0]["targetProfile"][0],
+            "https://example.org/clinical/StructureDefinition/Patient"
+        );
+
+        let gender = resource("Gender");
+        assert_eq!(
+            gender["compose"]["include"][0]["concept"][1]["code"],
+            "male"
+        );
+
+        schema.id = String::new();
+        assert!(FhirProfileGenerator::new().generate(&schema).is_err());
+    }
+}
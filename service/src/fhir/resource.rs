@@ -0,0 +1,421 @@
+//! Loading and validating FHIR JSON resources
+//!
+//! A resource is read into an instance of the class profiling it: the class
+//! whose profile URL is listed in `meta.profile`, or else a class profiling
+//! its `resourceType`. Slot values are selected by their element paths, where
+//! arrays along the path are flattened, backbone elements become nested
+//! objects and references are reduced to the id they point to.
+
+use async_trait::async_trait;
+use linkml_core::prelude::*;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{element_path, profile_classes, profile_url, resource_type};
+use crate::generator::base::induced_slots;
+use crate::loader::normalize::apply_normalizers;
+use crate::loader::traits::{DataInstance, DataLoader, LoadOptions, LoaderError, LoaderResult};
+use crate::validator::{ValidationEngine, ValidationReport};
+
+/// Loader of FHIR JSON resources and bundles
+#[derive(Debug, Default)]
+pub struct FhirLoader;
+
+impl FhirLoader {
+    /// Create a new FHIR loader
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Validate each resource of a FHIR document against its profile class
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not a FHIR resource or bundle, or
+    /// if no class of the schema profiles one of its resources
+    pub async fn validate_string(
+        &self,
+        content: &str,
+        schema: &SchemaDefinition,
+    ) -> linkml_core::error::Result<Vec<ValidationReport>> {
+        let engine = ValidationEngine::new(schema)?;
+        let mut reports = Vec::new();
+        for resource in resources(content)? {
+            let class_name = profile_class(schema, &resource, None)?;
+            let data = object_data(schema, &class_name, &resource);
+            reports.push(
+                engine
+                    .validate_as_class(&Value::Object(data), &class_name, None)
+                    .await?,
+            );
+        }
+        Ok(reports)
+    }
+}
+
+#[async_trait]
+impl DataLoader for FhirLoader {
+    fn name(&self) -> &'static str {
+        "fhir"
+    }
+
+    fn description(&self) -> &'static str {
+        "Load FHIR JSON resources and bundles into instances of their profile classes"
+    }
+
+    fn supported_extensions(&self) -> Vec<&str> {
+        vec![".json", ".fhir.json"]
+    }
+
+    async fn load_file(
+        &self,
+        path: &Path,
+        schema: &SchemaDefinition,
+        options: &LoadOptions,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        let content = std::fs::read_to_string(path)?;
+        self.load_string(&content, schema, options).await
+    }
+
+    async fn load_string(
+        &self,
+        content: &str,
+        schema: &SchemaDefinition,
+        options: &LoadOptions,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        if options.validate {
+            self.validate_schema(schema)?;
+        }
+
+        let mut instances = Vec::new();
+        for resource in resources(content)? {
+            if options.limit.is_some_and(|limit| instances.len() >= limit) {
+                break;
+            }
+            let class_name = match profile_class(schema, &resource, options.target_class.as_deref())
+            {
+                Ok(class_name) => class_name,
+                Err(_) if options.skip_invalid => continue,
+                Err(e) => return Err(e),
+            };
+            let mut metadata = HashMap::new();
+            if let Some(resource_type) = resource.get("resourceType").and_then(Value::as_str) {
+                metadata.insert("resource_type".to_string(), resource_type.to_string());
+            }
+            instances.push(DataInstance {
+                id: resource.get("id").and_then(Value::as_str).map(String::from),
+                data: object_data(schema, &class_name, &resource)
+                    .into_iter()
+                    .collect(),
+                class_name,
+                metadata,
+            });
+        }
+
+        apply_normalizers(instances, schema, options)
+    }
+
+    async fn load_bytes(
+        &self,
+        data: &[u8],
+        schema: &SchemaDefinition,
+        options: &LoadOptions,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        let content =
+            String::from_utf8(data.to_vec()).map_err(|e| LoaderError::Parse(e.to_string()))?;
+        self.load_string(&content, schema, options).await
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> LoaderResult<()> {
+        if profile_classes(schema).next().is_none() {
+            return Err(LoaderError::SchemaValidation(
+                "Schema has no class profiling a FHIR resource".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Resources of a FHIR document: the entries of a bundle or a single resource
+fn resources(content: &str) -> LoaderResult<Vec<Value>> {
+    let document: Value =
+        serde_json::from_str(content).map_err(|e| LoaderError::Parse(e.to_string()))?;
+    match document.get("resourceType").and_then(Value::as_str) {
+        Some("Bundle") => Ok(document
+            .get("entry")
+            .and_then(Value::as_array)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter_map(|entry| entry.get("resource").cloned())
+                    .collect()
+            })
+            .unwrap_or_default()),
+        Some(_) => Ok(vec![document]),
+        None => Err(LoaderError::InvalidFormat(
+            "FHIR resource has no resourceType".to_string(),
+        )),
+    }
+}
+
+/// Class profiling a resource
+fn profile_class(
+    schema: &SchemaDefinition,
+    resource: &Value,
+    target_class: Option<&str>,
+) -> LoaderResult<String> {
+    if let Some(target_class) = target_class {
+        return Ok(target_class.to_string());
+    }
+    let declared: Vec<&str> = resource
+        .pointer("/meta/profile")
+        .and_then(Value::as_array)
+        .map(|profiles| profiles.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    if let Some((class_name, _)) = profile_classes(schema)
+        .find(|(class_name, _)| declared.contains(&profile_url(schema, class_name).as_str()))
+    {
+        return Ok(class_name.clone());
+    }
+
+    let resource_name = resource
+        .get("resourceType")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    // A class named after the resource wins over other profiles of it
+    let candidates: Vec<&String> = profile_classes(schema)
+        .filter(|(class_name, class)| resource_type(class_name, class) == resource_name)
+        .map(|(class_name, _)| class_name)
+        .collect();
+    candidates
+        .iter()
+        .find(|class_name| class_name.as_str() == resource_name)
+        .or_else(|| candidates.first())
+        .map(|class_name| (*class_name).clone())
+        .ok_or_else(|| {
+            LoaderError::SchemaValidation(format!(
+                "No class of the schema profiles FHIR resource type '{resource_name}'"
+            ))
+        })
+}
+
+/// Slot values of an object of a class, selected by element path
+fn object_data(schema: &SchemaDefinition, class_name: &str, object: &Value) -> Map<String, Value> {
+    let mut data = Map::new();
+    for slot in induced_slots(schema, class_name) {
+        let path = element_path(&slot);
+        let mut values = Vec::new();
+        select(object, &path.split('.').collect::<Vec<_>>(), &mut values);
+        if values.is_empty() {
+            continue;
+        }
+
+        let range = slot.range.as_deref().unwrap_or_default();
+        let mut values: Vec<Value> = if schema.classes.contains_key(range) {
+            values
+                .into_iter()
+                .map(|value| match value {
+                    Value::Object(reference) if reference.contains_key("reference") => reference
+                        .get("reference")
+                        .and_then(Value::as_str)
+                        .map_or(Value::Null, |target| {
+                            // `Patient/123` and absolute URLs refer to id `123`
+                            Value::String(target.rsplit('/').next().unwrap_or(target).to_string())
+                        }),
+                    Value::Object(_) => Value::Object(object_data(schema, range, value)),
+                    _ => value.clone(),
+                })
+                .collect()
+        } else {
+            values.into_iter().cloned().collect()
+        };
+
+        let value = if slot.multivalued == Some(true) || values.len() > 1 {
+            Value::Array(values)
+        } else {
+            values.remove(0)
+        };
+        data.insert(slot.name.clone(), value);
+    }
+    data
+}
+
+/// Values at a dotted path, flattening the arrays along it
+fn select<'a>(value: &'a Value, path: &[&str], values: &mut Vec<&'a Value>) {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                select(item, path, values);
+            }
+        }
+        _ => match path.split_first() {
+            None if !value.is_null() => values.push(value),
+            None => {}
+            Some((name, rest)) => {
+                if let Some(child) = value.get(*name) {
+                    select(child, rest, values);
+                }
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::annotations::{AnnotationValue, Annotations};
+    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+
+    fn clinical_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/clinical".to_string(),
+            name: "clinical".to_string(),
+            ..Default::default()
+        };
+        let path = |path: &str| {
+            let mut annotations = Annotations::new();
+            annotations.insert(
+                "fhir_path".to_string(),
+                AnnotationValue::String(path.to_string()),
+            );
+            Some(annotations)
+        };
+        for (name, slot) in [
+            (
+                "id",
+                SlotDefinition {
+                    identifier: Some(true),
+                    ..Default::default()
+                },
+            ),
+            (
+                "family_name",
+                SlotDefinition {
+                    required: Some(true),
+                    annotations: path("name.family"),
+                    ..Default::default()
+                },
+            ),
+            (
+                "given_names",
+                SlotDefinition {
+                    multivalued: Some(true),
+                    annotations: path("name.given"),
+                    ..Default::default()
+                },
+            ),
+            (
+                "birth_date",
+                SlotDefinition {
+                    range: Some("date".to_string()),
+                    annotations: path("birthDate"),
+                    ..Default::default()
+                },
+            ),
+            (
+                "subject",
+                SlotDefinition {
+                    range: Some("Patient".to_string()),
+                    ..Default::default()
+                },
+            ),
+        ] {
+            schema.slots.insert(name.to_string(), slot);
+        }
+        schema.classes.insert(
+            "Patient".to_string(),
+            ClassDefinition {
+                slots: vec![
+                    "id".to_string(),
+                    "family_name".to_string(),
+                    "given_names".to_string(),
+                    "birth_date".to_string(),
+                ],
+                ..Default::default()
+            },
+        );
+        let mut annotations = Annotations::new();
+        annotations.insert(
+            "fhir_resource".to_string(),
+            AnnotationValue::String("Observation".to_string()),
+        );
+        schema.classes.insert(
+            "Encounter".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "subject".to_string()],
+                annotations: Some(annotations),
+                ..Default::default()
+            },
+        );
+        schema
+    }
+
+    const BUNDLE: &str = r#"{
+        "resourceType": "Bundle",
+        "type": "collection",
+        "entry": [
+            {"resource": {
+                "resourceType": "Patient",
+                "id": "p1",
+                "name": [{"family": "Chalmers", "given": ["Peter", "James"]}],
+                "birthDate": "1974-12-25"
+            }},
+            {"resource": {
+                "resourceType": "Observation",
+                "id": "o1",
+                "subject": {"reference": "Patient/p1"}
+            }},
+            {"resource": {
+                "resourceType": "Patient",
+                "id": "p2",
+                "meta": {"profile": ["https://example.org/clinical/StructureDefinition/Patient"]},
+                "birthDate": "1980-01-01"
+            }}
+        ]
+    }"#;
+
+    #[tokio::test]
+    async fn test_load_bundle() -> LoaderResult<()> {
+        let schema = clinical_schema();
+        let instances = FhirLoader::new()
+            .load_string(BUNDLE, &schema, &LoadOptions::default())
+            .await?;
+
+        assert_eq!(instances.len(), 3);
+        assert_eq!(instances[0].class_name, "Patient");
+        assert_eq!(instances[0].id.as_deref(), Some("p1"));
+        assert_eq!(instances[0].data["family_name"], "Chalmers");
+        assert_eq!(
+            instances[0].data["given_names"],
+            serde_json::json!(["Peter", "James"])
+        );
+        assert_eq!(instances[1].class_name, "Encounter");
+        assert_eq!(instances[1].data["subject"], "p1");
+        assert_eq!(instances[1].metadata["resource_type"], "Observation");
+
+        let unprofiled = r#"{"resourceType": "Condition", "id": "c1"}"#;
+        assert!(
+            FhirLoader::new()
+                .load_string(unprofiled, &schema, &LoadOptions::default())
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_validate_resources() -> linkml_core::error::Result<()> {
+        let reports = FhirLoader::new()
+            .validate_string(BUNDLE, &clinical_schema())
+            .await?;
+
+        assert_eq!(reports.len(), 3);
+        assert!(reports[0].valid);
+        assert!(reports[1].valid);
+        // The second patient has no family name
+        assert!(!reports[2].valid);
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+//! Language-specific code generation hints from schema annotations
+//!
+//! Schema authors can steer the generated code without forking templates by
+//! annotating classes, slots and enums with keys prefixed by the target
+//! language:
+//!
+//! ```yaml
+//! classes:
+//!   Person:
+//!     annotations:
+//!       rust:derive: [Hash, Eq]
+//!       python:base_class: MyBase
+//! slots:
+//!   id:
+//!     annotations:
+//!       ts:readonly: true
+//!       rust:attr: serde(alias = "identifier")
+//! ```
+//!
+//! | Key | Elements | Effect |
+//! |-----|----------|--------|
+//! | `rust:derive` | classes, enums | extra derives on the struct or enum |
+//! | `rust:attr` | classes, enums, slots | extra attributes on the item or field |
+//! | `python:base_class` | classes | base classes for Pydantic models and dataclasses |
+//! | `ts:readonly` | classes, slots | `readonly` properties in TypeScript interfaces |
+//!
+//! List-valued hints accept a YAML list or a comma separated string, and
+//! LinkML's `{tag, value}` annotation form is unwrapped. Generators that do
+//! not understand a prefix ignore it.
+
+use super::rust_ast;
+use super::traits::{GeneratorError, GeneratorResult};
+use linkml_core::annotations::{Annotatable, AnnotationValue};
+
+/// Extra derives for a generated Rust struct or enum
+pub const RUST_DERIVE: &str = "rust:derive";
+
+/// Extra attributes for a generated Rust item or field, without `#[...]`
+pub const RUST_ATTR: &str = "rust:attr";
+
+/// Base classes of a generated Python class
+pub const PYTHON_BASE_CLASS: &str = "python:base_class";
+
+/// Mark generated TypeScript properties as `readonly`
+pub const TS_READONLY: &str = "ts:readonly";
+
+/// String values of a list-valued hint
+///
+/// Strings are split on commas; nested lists are flattened. Returns an empty
+/// list when the annotation is absent.
+pub fn strings(element: &impl Annotatable, key: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    if let Some(value) = element.get_annotation(key) {
+        collect_strings(value, &mut values);
+    }
+    values
+}
+
+/// Whether a boolean hint is set (`true` or `"true"`)
+pub fn flag(element: &impl Annotatable, key: &str) -> bool {
+    element.get_annotation(key).is_some_and(is_true)
+}
+
+/// Validated `rust:derive` paths of an element
+///
+/// # Errors
+///
+/// Returns `GeneratorError::Configuration` naming the element if an entry is
+/// not a valid Rust path
+pub fn rust_derives(element: &impl Annotatable, name: &str) -> GeneratorResult<Vec<String>> {
+    let derives = strings(element, RUST_DERIVE);
+    rust_ast::parse_derives(&derives.join(",")).map_err(|e| in_element(name, RUST_DERIVE, &e))?;
+    Ok(derives)
+}
+
+/// Validated `rust:attr` attributes of an element, without `#[...]`
+///
+/// A value wrapped in `#[...]` is accepted and unwrapped.
+///
+/// # Errors
+///
+/// Returns `GeneratorError::Configuration` naming the element if an entry is
+/// not a valid attribute
+pub fn rust_attributes(element: &impl Annotatable, name: &str) -> GeneratorResult<Vec<String>> {
+    let values = match element.get_annotation(RUST_ATTR) {
+        // Attribute arguments contain commas, so a single string is one attribute
+        Some(AnnotationValue::String(attr)) => vec![attr.clone()],
+        _ => strings(element, RUST_ATTR),
+    };
+    values
+        .into_iter()
+        .map(|attr| {
+            let attr = attr
+                .trim()
+                .strip_prefix("#[")
+                .and_then(|inner| inner.strip_suffix(']'))
+                .unwrap_or(attr.trim())
+                .to_string();
+            rust_ast::parse_attribute(&attr).map_err(|e| in_element(name, RUST_ATTR, &e))?;
+            Ok(attr)
+        })
+        .collect()
+}
+
+/// Derive attribute line for derives not already in `existing`
+///
+/// Used by string-based generators; returns `None` when nothing is missing.
+pub fn missing_derive_line(derives: &[String], existing: &[&str]) -> Option<String> {
+    let missing: Vec<&str> = derives
+        .iter()
+        .map(|derive| derive.trim())
+        .filter(|derive| {
+            let last = derive.rsplit("::").next().unwrap_or(derive).trim();
+            !existing.contains(&last)
+        })
+        .collect();
+    (!missing.is_empty()).then(|| format!("#[derive({})]", missing.join(", ")))
+}
+
+fn collect_strings(value: &AnnotationValue, values: &mut Vec<String>) {
+    match value {
+        AnnotationValue::String(text) => values.extend(
+            text.split(',')
+                .map(str::trim)
+                .filter(|part| !part.is_empty())
+                .map(str::to_string),
+        ),
+        AnnotationValue::Array(items) => {
+            for item in items {
+                collect_strings(item, values);
+            }
+        }
+        AnnotationValue::Object(map) => {
+            if let Some(inner) = map.get("value") {
+                collect_strings(inner, values);
+            }
+        }
+        AnnotationValue::Bool(_) | AnnotationValue::Number(_) | AnnotationValue::Null => {}
+    }
+}
+
+fn is_true(value: &AnnotationValue) -> bool {
+    match value {
+        AnnotationValue::Bool(flag) => *flag,
+        AnnotationValue::String(text) => text.trim().eq_ignore_ascii_case("true"),
+        AnnotationValue::Object(map) => map.get("value").is_some_and(is_true),
+        _ => false,
+    }
+}
+
+fn in_element(name: &str, key: &str, error: &GeneratorError) -> GeneratorError {
+    GeneratorError::Configuration(format!("Annotation '{key}' on '{name}': {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::prelude::*;
+
+    fn annotated(entries: &[(&str, AnnotationValue)]) -> ClassDefinition {
+        ClassDefinition {
+            annotations: Some(
+                entries
+                    .iter()
+                    .map(|(key, value)| ((*key).to_string(), value.clone()))
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_hint_values() {
+        let class = annotated(&[
+            (
+                RUST_DERIVE,
+                AnnotationValue::Array(vec!["Hash".into(), "std::cmp::Eq".into()]),
+            ),
+            (
+                RUST_ATTR,
+                "#[serde(rename_all = \"camelCase\", deny_unknown_fields)]".into(),
+            ),
+            (PYTHON_BASE_CLASS, "Base, Mixin".into()),
+            (TS_READONLY, "true".into()),
+        ]);
+
+        assert_eq!(strings(&class, PYTHON_BASE_CLASS), ["Base", "Mixin"]);
+        assert!(flag(&class, TS_READONLY));
+        assert!(!flag(&class, "ts:other"));
+
+        let derives = rust_derives(&class, "Person").expect("derives");
+        assert_eq!(
+            missing_derive_line(&derives, &["Debug", "Eq"]).as_deref(),
+            Some("#[derive(Hash)]")
+        );
+        assert_eq!(
+            rust_attributes(&class, "Person").expect("attributes"),
+            ["serde(rename_all = \"camelCase\", deny_unknown_fields)"]
+        );
+
+        let invalid = annotated(&[(RUST_DERIVE, "not a path".into())]);
+        let error = rust_derives(&invalid, "Person")
+            .expect_err("invalid derive")
+            .to_string();
+        assert!(error.contains("'rust:derive' on 'Person'"));
+    }
+}
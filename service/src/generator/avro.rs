@@ -0,0 +1,356 @@
+//! Avro schema generator for `LinkML` schemas
+//!
+//! Generates an Avro schema (`.avsc`) with one record per class. The root
+//! class, a class marked `tree_root`, or otherwise a union of all concrete
+//! classes is the top-level type. Inlined objects become nested records,
+//! references take the type of the identifier of the referenced class and
+//! enums become Avro enums. Every named type is defined at its first use and
+//! referenced by name afterwards, which also covers recursive classes.
+//! Optional slots are unions with `null` defaulting to `null`.
+
+use linkml_core::prelude::*;
+use serde_json::{Map, Value, json};
+use std::collections::HashSet;
+
+use super::base::induced_slots;
+use super::traits::{Generator, GeneratorError, GeneratorResult};
+use linkml_core::error::LinkMLError;
+
+/// Generator of Avro schemas
+#[derive(Debug, Default)]
+pub struct AvroGenerator {
+    root_class: Option<String>,
+}
+
+impl AvroGenerator {
+    /// Create a new Avro schema generator
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generate the record of `class_name` as the top-level type
+    #[must_use]
+    pub fn with_root_class(mut self, class_name: impl Into<String>) -> Self {
+        self.root_class = Some(class_name.into());
+        self
+    }
+
+    /// Classes forming the top-level type
+    fn roots(&self, schema: &SchemaDefinition) -> GeneratorResult<Vec<String>> {
+        if let Some(root) = &self.root_class {
+            if !schema.classes.contains_key(root) {
+                return Err(GeneratorError::Generation(format!(
+                    "Root class '{root}' is not defined in the schema"
+                )));
+            }
+            return Ok(vec![root.clone()]);
+        }
+        if let Some((name, _)) = schema
+            .classes
+            .iter()
+            .find(|(_, class)| class.tree_root == Some(true))
+        {
+            return Ok(vec![name.clone()]);
+        }
+        Ok(schema
+            .classes
+            .iter()
+            .filter(|(_, class)| class.abstract_ != Some(true) && class.mixin != Some(true))
+            .map(|(name, _)| name.clone())
+            .collect())
+    }
+
+    fn generate_schema(&self, schema: &SchemaDefinition) -> GeneratorResult<String> {
+        let roots = self.roots(schema)?;
+        let mut writer = AvroWriter {
+            schema,
+            defined: HashSet::new(),
+        };
+        let mut records: Vec<Value> = roots
+            .iter()
+            .map(|class_name| {
+                let mut record = writer.record(class_name);
+                if let Value::Object(fields) = &mut record {
+                    fields.insert("namespace".to_string(), json!(avro_name(&schema.name)));
+                }
+                record
+            })
+            .collect();
+        let avro = if records.len() == 1 {
+            records.remove(0)
+        } else {
+            Value::Array(records)
+        };
+        serde_json::to_string_pretty(&avro).map_err(|e| {
+            GeneratorError::Generation(format!("Failed to serialize Avro schema: {e}"))
+        })
+    }
+}
+
+impl Generator for AvroGenerator {
+    fn name(&self) -> &'static str {
+        "avro"
+    }
+
+    fn description(&self) -> &'static str {
+        "Generates Avro schemas with nested records and enums from LinkML schemas"
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> linkml_core::error::Result<()> {
+        if schema.name.is_empty() {
+            return Err(LinkMLError::data_validation(
+                "Schema must have a name for Avro schema generation",
+            ));
+        }
+        Ok(())
+    }
+
+    fn generate(&self, schema: &SchemaDefinition) -> std::result::Result<String, LinkMLError> {
+        Generator::validate_schema(self, schema)?;
+        Ok(self.generate_schema(schema)?)
+    }
+
+    fn get_file_extension(&self) -> &'static str {
+        "avsc"
+    }
+
+    fn get_default_filename(&self) -> &'static str {
+        "schema"
+    }
+}
+
+/// Builds Avro types, remembering the named types already defined
+struct AvroWriter<'a> {
+    schema: &'a SchemaDefinition,
+    defined: HashSet<String>,
+}
+
+impl AvroWriter<'_> {
+    /// Record of a class, or its name if the record is already defined
+    fn record(&mut self, class_name: &str) -> Value {
+        let name = avro_name(class_name);
+        if !self.defined.insert(name.clone()) {
+            return json!(name);
+        }
+        let fields: Vec<Value> = induced_slots(self.schema, class_name)
+            .iter()
+            .map(|slot| self.field(slot))
+            .collect();
+        let mut record = Map::new();
+        record.insert("type".to_string(), json!("record"));
+        record.insert("name".to_string(), json!(name));
+        if let Some(description) = self
+            .schema
+            .classes
+            .get(class_name)
+            .and_then(|class| class.description.as_ref())
+        {
+            record.insert("doc".to_string(), json!(description));
+        }
+        record.insert("fields".to_string(), json!(fields));
+        Value::Object(record)
+    }
+
+    fn field(&mut self, slot: &SlotDefinition) -> Value {
+        let value_type = slot
+            .range
+            .as_deref()
+            .map_or_else(|| json!("string"), |range| self.value_type(slot, range));
+        let value_type = if slot.multivalued == Some(true) {
+            json!({ "type": "array", "items": value_type })
+        } else {
+            value_type
+        };
+
+        let mut field = Map::new();
+        field.insert("name".to_string(), json!(avro_name(&slot.name)));
+        if slot.required == Some(true) || slot.identifier == Some(true) {
+            field.insert("type".to_string(), value_type);
+        } else {
+            field.insert("type".to_string(), json!(["null", value_type]));
+            field.insert("default".to_string(), Value::Null);
+        }
+        if let Some(description) = &slot.description {
+            field.insert("doc".to_string(), json!(description));
+        }
+        Value::Object(field)
+    }
+
+    /// Avro type of a single value of a slot
+    fn value_type(&mut self, slot: &SlotDefinition, range: &str) -> Value {
+        if let Some(enum_def) = self.schema.enums.get(range) {
+            let name = avro_name(range);
+            if !self.defined.insert(name.clone()) {
+                return json!(name);
+            }
+            let symbols: Vec<String> = enum_def
+                .permissible_values
+                .iter()
+                .map(|value| avro_name(value.text()))
+                .collect();
+            let mut avro_enum = json!({ "type": "enum", "name": name, "symbols": symbols });
+            if let Some(description) = &enum_def.description {
+                avro_enum["doc"] = json!(description);
+            }
+            return avro_enum;
+        }
+        if !self.schema.classes.contains_key(range) {
+            return atomic_type(self.schema, range);
+        }
+        let key = induced_slots(self.schema, range)
+            .into_iter()
+            .find(|slot| slot.identifier == Some(true));
+        if slot.inlined == Some(true) || key.is_none() {
+            return self.record(range);
+        }
+        // References hold the identifier of the referenced object
+        key.and_then(|key| key.range)
+            .filter(|key_range| !self.schema.classes.contains_key(key_range))
+            .map_or_else(
+                || json!("string"),
+                |key_range| atomic_type(self.schema, &key_range),
+            )
+    }
+}
+
+/// Avro type of a type range
+fn atomic_type(schema: &SchemaDefinition, range: &str) -> Value {
+    let mut range = range;
+    for _ in 0..=schema.types.len() {
+        match range {
+            "integer" | "int" => return json!("long"),
+            "float" => return json!("float"),
+            "double" => return json!("double"),
+            "decimal" => {
+                return json!({ "type": "bytes", "logicalType": "decimal", "precision": 38, "scale": 18 });
+            }
+            "boolean" | "bool" => return json!("boolean"),
+            "date" => return json!({ "type": "int", "logicalType": "date" }),
+            "datetime" => return json!({ "type": "long", "logicalType": "timestamp-millis" }),
+            _ => match schema.types.get(range).and_then(|t| t.base_type.as_deref()) {
+                Some(base_type) => range = base_type,
+                None => break,
+            },
+        }
+    }
+    json!("string")
+}
+
+/// Name valid in Avro: letters, digits and `_`, not starting with a digit
+fn avro_name(name: &str) -> String {
+    let mut avro: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if avro.is_empty() || avro.starts_with(|c: char| c.is_ascii_digit()) {
+        avro.insert(0, '_');
+    }
+    avro
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::{ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition};
+
+    #[test]
+    fn test_avro_generation() {
+        let mut schema = SchemaDefinition {
+            name: "sales".to_string(),
+            ..Default::default()
+        };
+        for (name, slot) in [
+            (
+                "id",
+                SlotDefinition {
+                    identifier: Some(true),
+                    range: Some("string".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "status",
+                SlotDefinition {
+                    range: Some("OrderStatus".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "lines",
+                SlotDefinition {
+                    range: Some("OrderLine".to_string()),
+                    multivalued: Some(true),
+                    required: Some(true),
+                    ..Default::default()
+                },
+            ),
+            (
+                "quantity",
+                SlotDefinition {
+                    range: Some("integer".to_string()),
+                    description: Some("Units ordered".to_string()),
+                    ..Default::default()
+                },
+            ),
+        ] {
+            schema.slots.insert(name.to_string(), slot);
+        }
+        schema.enums.insert(
+            "OrderStatus".to_string(),
+            EnumDefinition {
+                permissible_values: vec![
+                    PermissibleValue::Simple("open".to_string()),
+                    PermissibleValue::Simple("on-hold".to_string()),
+                ],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Order".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "status".to_string(), "lines".to_string()],
+                tree_root: Some(true),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "OrderLine".to_string(),
+            ClassDefinition {
+                slots: vec!["quantity".to_string()],
+                ..Default::default()
+            },
+        );
+
+        let output = AvroGenerator::new()
+            .generate(&schema)
+            .expect("should generate an Avro schema");
+        let avro: Value = serde_json::from_str(&output).expect("valid JSON");
+        assert_eq!(avro["type"], "record");
+        assert_eq!(avro["name"], "Order");
+        assert_eq!(avro["namespace"], "sales");
+        let fields = &avro["fields"];
+        assert_eq!(fields[0]["type"], "string");
+        assert_eq!(fields[1]["type"][0], "null");
+        assert_eq!(fields[1]["default"], Value::Null);
+        assert_eq!(fields[1]["type"][1]["symbols"], json!(["open", "on_hold"]));
+        // OrderLine has no identifier, so its objects are nested records
+        let line = &fields[2]["type"]["items"];
+        assert_eq!(line["name"], "OrderLine");
+        assert_eq!(line["fields"][0]["type"][1], "long");
+        assert_eq!(line["fields"][0]["doc"], "Units ordered");
+
+        let output = AvroGenerator::new()
+            .with_root_class("OrderLine")
+            .generate(&schema)
+            .expect("should generate an Avro schema");
+        let avro: Value = serde_json::from_str(&output).expect("valid JSON");
+        assert_eq!(avro["name"], "OrderLine");
+        assert!(
+            AvroGenerator::new()
+                .with_root_class("Invoice")
+                .generate(&schema)
+                .is_err()
+        );
+    }
+}
@@ -2,7 +2,7 @@
 
 use super::traits::GeneratorResult;
 use linkml_core::prelude::*;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 
 /// Common type mappings for various languages
 pub struct TypeMapper;
@@ -51,8 +51,8 @@ impl TypeMapper {
 /// Import manager for tracking and organizing imports
 #[derive(Debug, Default)]
 pub struct ImportManager {
-    /// Module -> Set of imports from that module
-    imports: HashMap<String, HashSet<String>>,
+    /// Module -> Set of imports from that module, ordered by module
+    imports: BTreeMap<String, HashSet<String>>,
     /// Direct import statements
     direct_imports: HashSet<String>,
 }
@@ -312,6 +312,96 @@ pub fn collect_all_slots(
     Ok(slots)
 }
 
+/// A class followed by its ancestors and mixins, depth first
+///
+/// Unknown classes are included by name; cycles are cut at the first repeat.
+#[must_use]
+pub fn class_ancestry(schema: &SchemaDefinition, class_name: &str) -> Vec<String> {
+    fn collect(schema: &SchemaDefinition, class_name: &str, ancestry: &mut Vec<String>) {
+        if ancestry.iter().any(|name| name == class_name) {
+            return;
+        }
+        ancestry.push(class_name.to_string());
+        if let Some(class) = schema.classes.get(class_name) {
+            for parent in class.is_a.iter().chain(&class.mixins) {
+                collect(schema, parent, ancestry);
+            }
+        }
+    }
+
+    let mut ancestry = Vec::new();
+    collect(schema, class_name, &mut ancestry);
+    ancestry
+}
+
+/// Slots of a class including inherited ones and attributes, with the
+/// `slot_usage` of the class and its ancestors applied and `name` set
+///
+/// Slots referenced but not defined in the schema are left out.
+#[must_use]
+pub fn induced_slots(schema: &SchemaDefinition, class_name: &str) -> Vec<SlotDefinition> {
+    let lineage: Vec<&ClassDefinition> = class_ancestry(schema, class_name)
+        .iter()
+        .filter_map(|name| schema.classes.get(name))
+        .collect();
+    let mut names: Vec<&String> = Vec::new();
+    for class in &lineage {
+        for name in class.slots.iter().chain(class.attributes.keys()) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let mut slot = lineage
+                .iter()
+                .find_map(|class| class.attributes.get(name))
+                .or_else(|| schema.slots.get(name))
+                .cloned()?;
+            // The most specific usage is applied last
+            for class in lineage.iter().rev() {
+                if let Some(usage) = class.slot_usage.get(name) {
+                    apply_slot_usage(&mut slot, usage);
+                }
+            }
+            slot.name.clone_from(name);
+            Some(slot)
+        })
+        .collect()
+}
+
+/// Refine a slot with the `slot_usage` of a class
+pub fn apply_slot_usage(slot: &mut SlotDefinition, usage: &SlotDefinition) {
+    for (field, value) in [
+        (&mut slot.range, &usage.range),
+        (&mut slot.description, &usage.description),
+        (&mut slot.pattern, &usage.pattern),
+    ] {
+        if value.is_some() {
+            field.clone_from(value);
+        }
+    }
+    for (field, value) in [
+        (&mut slot.required, usage.required),
+        (&mut slot.multivalued, usage.multivalued),
+        (&mut slot.identifier, usage.identifier),
+        (&mut slot.key, usage.key),
+        (&mut slot.unique, usage.unique),
+        (&mut slot.inlined, usage.inlined),
+    ] {
+        if value.is_some() {
+            *field = value;
+        }
+    }
+    if let Some(annotations) = &usage.annotations {
+        slot.annotations
+            .get_or_insert_with(Default::default)
+            .extend(annotations.clone());
+    }
+}
+
 /// Check if a type is optional (not required)
 #[must_use]
 pub fn is_optional_slot(slot: &SlotDefinition) -> bool {
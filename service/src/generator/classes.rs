@@ -60,6 +60,13 @@ impl RustGenerator {
             "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]"
         )
         .map_err(Self::fmt_error_to_generator_error)?;
+        for line in Self::annotation_attr_lines(
+            class_name,
+            class,
+            &["Debug", "Clone", "PartialEq", "Serialize", "Deserialize"],
+        )? {
+            writeln!(&mut output, "{line}").map_err(Self::fmt_error_to_generator_error)?;
+        }
 
         // Add serde rename if class name differs from struct name
         if class_name != struct_name.to_lowercase() {
@@ -1,11 +1,14 @@
 //! Core Rust generator implementation
 
-use super::GeneratorOptions;
+use super::annotation_hints;
 use super::base::BaseCodeFormatter;
+use super::rust_ast;
 use super::traits::{CodeFormatter, Generator, GeneratorError, GeneratorResult};
 
+use linkml_core::annotations::Annotatable;
 use linkml_core::prelude::*;
-use std::fmt::Write;
+use quote::quote;
+use syn::{Attribute, Field, File, Item, parse_quote};
 
 /// Rust code generator for `LinkML` schemas
 pub struct RustGenerator {
@@ -90,190 +93,203 @@ impl RustGenerator {
         }
     }
 
-    /// Generate an enum from `LinkML` enum definition
-    pub(super) fn generate_enum(
-        enum_name: &str,
-        enum_def: &EnumDefinition,
-    ) -> GeneratorResult<String> {
-        let mut output = String::new();
-
-        // Add documentation if available
-        if let Some(ref desc) = enum_def.description {
-            writeln!(
-                &mut output,
-                "
-/// {desc}"
-            )
-            .map_err(Self::fmt_error_to_generator_error)?;
-        } else {
-            writeln!(
-                &mut output,
-                "
-/// {enum_name} enum"
-            )
-            .map_err(Self::fmt_error_to_generator_error)?;
-        }
-
-        writeln!(
-            &mut output,
-            "#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]"
-        )
-        .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output, "pub enum {enum_name} {{")
-            .map_err(Self::fmt_error_to_generator_error)?;
+    /// Build the enum item for a `LinkML` enum definition
+    pub(super) fn enum_item(enum_name: &str, enum_def: &EnumDefinition) -> GeneratorResult<Item> {
+        let docs = rust_ast::doc_attrs(
+            enum_def
+                .description
+                .as_deref()
+                .unwrap_or(&format!("{enum_name} enum")),
+        );
+        let name = rust_ast::ident(enum_name)?;
 
-        // Generate enum variants
+        let mut variants = Vec::with_capacity(enum_def.permissible_values.len());
         for pv in &enum_def.permissible_values {
-            match pv {
-                PermissibleValue::Simple(value) => {
-                    // Convert to PascalCase for Rust enum variant
-                    let variant_name = BaseCodeFormatter::to_pascal_case(value);
-                    writeln!(&mut output, "    #[serde(rename = \"{value}\")]")
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                    writeln!(&mut output, "    {variant_name},")
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                }
+            let (text, description) = match pv {
+                PermissibleValue::Simple(text) => (text, None),
                 PermissibleValue::Complex {
                     text, description, ..
-                } => {
-                    if let Some(desc) = description {
-                        writeln!(&mut output, "    /// {desc}")
-                            .map_err(Self::fmt_error_to_generator_error)?;
-                    }
-                    let variant_name = BaseCodeFormatter::to_pascal_case(text);
-                    writeln!(&mut output, "    #[serde(rename = \"{text}\")]")
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                    writeln!(&mut output, "    {variant_name},")
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                }
+                } => (text, description.as_deref()),
+            };
+            // PascalCase variant name, serialized under the original value
+            let variant = rust_ast::ident(&BaseCodeFormatter::to_pascal_case(text))?;
+            let variant_docs = description.map(rust_ast::doc_attrs).unwrap_or_default();
+            variants.push(quote! {
+                #(#variant_docs)*
+                #[serde(rename = #text)]
+                #variant
+            });
+        }
+
+        let attrs = Self::annotation_attrs(enum_name, enum_def)?;
+        let mut item = parse_quote! {
+            #(#docs)*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+            #(#attrs)*
+            pub enum #name {
+                #(#variants),*
+            }
+        };
+        Self::add_annotation_derives(&mut item, enum_name, enum_def)?;
+        Ok(item)
+    }
+
+    /// Build an impl with a `parent` method mapping each value to its `is_a`
+    /// parent, or `None` if the enum has no value hierarchy
+    pub(super) fn enum_parent_item(
+        enum_name: &str,
+        enum_def: &EnumDefinition,
+    ) -> GeneratorResult<Option<Item>> {
+        let name = rust_ast::ident(enum_name)?;
+        let mut arms = Vec::new();
+        for pv in &enum_def.permissible_values {
+            if let Some(parent) = pv.is_a() {
+                let variant = rust_ast::ident(&BaseCodeFormatter::to_pascal_case(pv.text()))?;
+                arms.push(quote! { Self::#variant => Some(#parent) });
             }
         }
+        if arms.is_empty() {
+            return Ok(None);
+        }
+        if arms.len() < enum_def.permissible_values.len() {
+            arms.push(quote! { _ => None });
+        }
 
-        writeln!(
-            &mut output,
-            "}}
-"
-        )
-        .map_err(Self::fmt_error_to_generator_error)?;
-        Ok(output)
+        Ok(Some(parse_quote! {
+            impl #name {
+                /// Parent value in the permissible value hierarchy
+                #[must_use]
+                pub fn parent(&self) -> Option<&'static str> {
+                    match self {
+                        #(#arms),*
+                    }
+                }
+            }
+        }))
     }
 
-    /// Generate a class/struct from `LinkML` class definition
-    pub(super) fn generate_class(
+    /// Build the struct item for a `LinkML` class definition
+    pub(super) fn class_item(
         &self,
         class_name: &str,
         class_def: &ClassDefinition,
         schema: &SchemaDefinition,
-    ) -> GeneratorResult<String> {
-        let mut output = String::new();
-
-        // Add documentation
-        if let Some(ref desc) = class_def.description {
-            writeln!(
-                &mut output,
-                "
-/// {desc}"
-            )
-            .map_err(Self::fmt_error_to_generator_error)?;
-        } else {
-            writeln!(
-                &mut output,
-                "
-/// {class_name}"
-            )
-            .map_err(Self::fmt_error_to_generator_error)?;
+    ) -> GeneratorResult<Item> {
+        let docs = rust_ast::doc_attrs(class_def.description.as_deref().unwrap_or(class_name));
+        let name = rust_ast::ident(class_name)?;
+
+        let mut fields = Vec::new();
+        for slot_name in &self.collect_class_slots(class_def, schema) {
+            if let Some(slot_def) = schema.slots.get(slot_name) {
+                fields.push(Self::field(slot_name, slot_def, schema)?);
+            }
         }
 
-        // Add derive macros
-        writeln!(
-            &mut output,
-            "#[derive(Debug, Clone, Serialize, Deserialize)]"
-        )
-        .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output, "pub struct {class_name} {{")
-            .map_err(Self::fmt_error_to_generator_error)?;
-
-        // Collect all slots for this class
-        let slots = self.collect_class_slots(class_def, schema);
-
-        if slots.is_empty() {
-            writeln!(&mut output, "    // No fields defined")
-                .map_err(Self::fmt_error_to_generator_error)?;
-        } else {
-            // Generate fields for each slot
-            for slot_name in &slots {
-                if let Some(slot_def) = schema.slots.get(slot_name) {
-                    Self::generate_field(&mut output, slot_name, slot_def, schema)?;
-                }
+        let attrs = Self::annotation_attrs(class_name, class_def)?;
+        let mut item = parse_quote! {
+            #(#docs)*
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            #(#attrs)*
+            pub struct #name {
+                #(#fields),*
             }
-        }
+        };
+        Self::add_annotation_derives(&mut item, class_name, class_def)?;
+        Ok(item)
+    }
+
+    /// Attributes requested through the `rust:attr` annotation
+    fn annotation_attrs(name: &str, element: &impl Annotatable) -> GeneratorResult<Vec<Attribute>> {
+        annotation_hints::rust_attributes(element, name)?
+            .iter()
+            .map(|attr| rust_ast::parse_attribute(attr))
+            .collect()
+    }
+
+    /// Attribute lines from the `rust:derive` and `rust:attr` annotations
+    ///
+    /// Used by the string-based generator; derives already listed in
+    /// `existing` are skipped.
+    pub(super) fn annotation_attr_lines(
+        name: &str,
+        element: &impl Annotatable,
+        existing: &[&str],
+    ) -> GeneratorResult<Vec<String>> {
+        let derives = annotation_hints::rust_derives(element, name)?;
+        let mut lines: Vec<String> = annotation_hints::missing_derive_line(&derives, existing)
+            .into_iter()
+            .collect();
+        lines.extend(
+            annotation_hints::rust_attributes(element, name)?
+                .into_iter()
+                .map(|attr| format!("#[{attr}]")),
+        );
+        Ok(lines)
+    }
 
-        writeln!(
-            &mut output,
-            "}}
-"
-        )
-        .map_err(Self::fmt_error_to_generator_error)?;
-        Ok(output)
+    /// Add derives requested through the `rust:derive` annotation
+    fn add_annotation_derives(
+        item: &mut Item,
+        name: &str,
+        element: &impl Annotatable,
+    ) -> GeneratorResult<()> {
+        let derives = annotation_hints::rust_derives(element, name)?;
+        if !derives.is_empty() {
+            rust_ast::add_derives_to_item(item, &rust_ast::parse_derives(&derives.join(","))?);
+        }
+        Ok(())
     }
 
-    /// Generate a field from a slot definition
-    pub(super) fn generate_field(
-        output: &mut String,
+    /// Build a struct field from a slot definition
+    pub(super) fn field(
         slot_name: &str,
         slot_def: &SlotDefinition,
         schema: &SchemaDefinition,
-    ) -> GeneratorResult<()> {
-        // Add field documentation
-        if let Some(ref desc) = slot_def.description {
-            writeln!(output, "    /// {desc}").map_err(Self::fmt_error_to_generator_error)?;
-        }
+    ) -> GeneratorResult<Field> {
+        let docs = slot_def
+            .description
+            .as_deref()
+            .map(rust_ast::doc_attrs)
+            .unwrap_or_default();
 
-        // Add serde rename if needed (to preserve original casing)
-        let rust_field_name = Self::convert_field_name(slot_name);
-        if rust_field_name != slot_name {
-            writeln!(output, "    #[serde(rename = \"{slot_name}\")]")
-                .map_err(Self::fmt_error_to_generator_error)?;
-        }
+        // Serde rename preserves the original slot name when the field name differs
+        let field_name = rust_ast::ident(&Self::convert_field_name(slot_name))?;
+        let rename = (field_name != slot_name).then(|| quote!(#[serde(rename = #slot_name)]));
 
         // Determine field type
-        let base_type = if let Some(ref range) = slot_def.range {
-            // Check if it's an enum
-            if schema.enums.contains_key(range) {
-                range.clone()
+        let base_type = match slot_def.range.as_deref() {
+            Some(range) if schema.enums.contains_key(range) => {
+                let ty = rust_ast::ident(range)?;
+                quote!(#ty)
             }
-            // Check if it's a class
-            else if schema.classes.contains_key(range) {
-                format!("Box<{range}>") // Box to avoid infinite size for recursive types
+            // Box to avoid infinite size for recursive types
+            Some(range) if schema.classes.contains_key(range) => {
+                let ty = rust_ast::ident(range)?;
+                quote!(Box<#ty>)
             }
-            // Otherwise treat as primitive
-            else {
-                Self::linkml_type_to_rust(range).to_string()
+            Some(range) => {
+                let ty = rust_ast::parse_type(Self::linkml_type_to_rust(range))?;
+                quote!(#ty)
             }
-        } else {
-            "String".to_string() // Default type
+            None => quote!(String),
         };
 
-        // Handle multivalued
-        let field_type = if slot_def.multivalued.unwrap_or(false) {
-            format!("Vec<{base_type}>")
-        } else {
+        let multivalued = slot_def.multivalued.unwrap_or(false);
+        let field_type = if multivalued {
+            quote!(Vec<#base_type>)
+        } else if slot_def.required.unwrap_or(false) {
             base_type
+        } else {
+            quote!(Option<#base_type>)
         };
 
-        // Handle optional
-        let final_type =
-            if !slot_def.required.unwrap_or(false) && !slot_def.multivalued.unwrap_or(false) {
-                format!("Option<{field_type}>")
-            } else {
-                field_type
-            };
-
-        // Write the field
-        writeln!(output, "    pub {rust_field_name}: {final_type},")
-            .map_err(Self::fmt_error_to_generator_error)?;
-
-        Ok(())
+        let attrs = Self::annotation_attrs(slot_name, slot_def)?;
+        Ok(parse_quote! {
+            #(#docs)*
+            #rename
+            #(#attrs)*
+            pub #field_name: #field_type
+        })
     }
 
     /// Collect all slots for a class (including inherited)
@@ -301,81 +317,103 @@ impl RustGenerator {
         slots
     }
 
-    /// Generate file header with imports
-    pub(super) fn generate_header(schema: &SchemaDefinition) -> GeneratorResult<String> {
-        let mut output = String::new();
-
-        // File header
-        writeln!(
-            &mut output,
-            "//! Generated from LinkML schema: {}",
+    /// Module-level docs and imports for a generated file
+    pub(super) fn header_file(schema: &SchemaDefinition) -> File {
+        let mut attrs = rust_ast::inner_doc_attrs(&format!(
+            "Generated from LinkML schema: {}",
             if schema.name.is_empty() {
                 "unnamed"
             } else {
                 &schema.name
             }
-        )
-        .map_err(Self::fmt_error_to_generator_error)?;
-
+        ));
         if let Some(desc) = &schema.description {
-            writeln!(&mut output, "//! {desc}").map_err(Self::fmt_error_to_generator_error)?;
+            attrs.extend(rust_ast::inner_doc_attrs(desc));
         }
 
-        writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+        File {
+            shebang: None,
+            attrs,
+            items: vec![
+                parse_quote!(
+                    use serde::{Deserialize, Serialize};
+                ),
+                parse_quote!(
+                    use std::collections::HashMap;
+                ),
+                parse_quote!(
+                    use thiserror::Error;
+                ),
+            ],
+        }
+    }
 
-        // Standard imports
-        writeln!(&mut output, "use serde::{{Deserialize, Serialize}};")
-            .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output, "use std::collections::HashMap;")
-            .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output, "use thiserror::Error;")
-            .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+    /// Generate file header with imports
+    pub(super) fn generate_header(schema: &SchemaDefinition) -> GeneratorResult<String> {
+        Ok(rust_ast::render(&Self::header_file(schema)))
+    }
 
-        Ok(output)
+    /// Validation error enum shared by generated types
+    pub(super) fn validation_error_item() -> Item {
+        parse_quote! {
+            /// Validation errors for generated types
+            #[derive(Debug, Error)]
+            pub enum ValidationError {
+                #[error("Required field missing: {field}")]
+                RequiredField { field: &'static str },
+                #[error("Invalid value for field {field}: {message}")]
+                InvalidValue { field: &'static str, message: String },
+                #[error("Pattern validation failed for field {field}")]
+                PatternValidation { field: &'static str },
+            }
+        }
     }
 
     /// Generate validation error enum
     pub(super) fn generate_validation_error() -> GeneratorResult<String> {
-        let mut output = String::new();
-
-        writeln!(&mut output, "/// Validation errors for generated types")
-            .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output, "#[derive(Debug, Error)]")
-            .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output, "pub enum ValidationError {{")
-            .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(
-            &mut output,
-            "    #[error(\"Required field missing: {{field}}\")]"
-        )
-        .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output, "    RequiredField {{ field: &'static str }},")
-            .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(
-            &mut output,
-            "    #[error(\"Invalid value for field {{field}}: {{message}}\")]"
-        )
-        .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(
-            &mut output,
-            "    InvalidValue {{ field: &'static str, message: String }},"
-        )
-        .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(
-            &mut output,
-            "    #[error(\"Pattern validation failed for field {{field}}\")]"
-        )
-        .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(
-            &mut output,
-            "    PatternValidation {{ field: &'static str }},"
-        )
-        .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output, "}}").map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
-
-        Ok(output)
+        Ok(rust_ast::render_items(vec![Self::validation_error_item()]))
+    }
+
+    /// Build the complete `syn` AST for a schema
+    ///
+    /// Extra derives listed in the `derives` custom option (comma separated
+    /// paths) are added to every generated struct and enum. With the
+    /// `linkml_validate` option set to `true`, every struct also implements
+    /// `linkml_core::LinkMLValidate`. The returned file can be post-processed
+    /// further before rendering with `prettyplease`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError` if a schema name cannot be turned into a Rust
+    /// identifier or the configured derives are invalid.
+    pub fn generate_file(&self, schema: &SchemaDefinition) -> GeneratorResult<File> {
+        let mut file = Self::header_file(schema);
+        file.items.push(Self::validation_error_item());
+
+        // Enums first, then structs
+        for (enum_name, enum_def) in &schema.enums {
+            file.items.push(Self::enum_item(enum_name, enum_def)?);
+            file.items
+                .extend(Self::enum_parent_item(enum_name, enum_def)?);
+        }
+        let validate = self
+            .options
+            .get_custom(rust_ast::VALIDATE_OPTION)
+            .is_some_and(|value| value == "true");
+        for (class_name, class_def) in &schema.classes {
+            file.items
+                .push(self.class_item(class_name, class_def, schema)?);
+            if validate {
+                file.items
+                    .push(self.validate_impl_item(class_name, class_def, schema)?);
+            }
+        }
+
+        if let Some(spec) = self.options.get_custom(rust_ast::DERIVES_OPTION) {
+            rust_ast::add_derives(&mut file, &rust_ast::parse_derives(spec)?);
+        }
+
+        Ok(file)
     }
 }
 
@@ -405,39 +443,10 @@ impl Generator for RustGenerator {
     }
 
     fn generate(&self, schema: &SchemaDefinition) -> linkml_core::error::Result<String> {
-        let _options = GeneratorOptions::default();
-        let mut output = String::new();
-
-        // Generate header
-        output.push_str(
-            &Self::generate_header(schema)
-                .map_err(|e| LinkMLError::data_validation(e.to_string()))?,
-        );
-
-        // Generate validation error enum
-        output.push_str(
-            &Self::generate_validation_error()
-                .map_err(|e| LinkMLError::data_validation(e.to_string()))?,
-        );
-
-        // Generate enums first
-        for (enum_name, enum_def) in &schema.enums {
-            output.push_str(
-                &Self::generate_enum(enum_name, enum_def)
-                    .map_err(|e| LinkMLError::data_validation(e.to_string()))?,
-            );
-        }
-
-        // Generate basic structs for classes
-        for (class_name, class_def) in &schema.classes {
-            output.push_str(
-                &self
-                    .generate_class(class_name, class_def, schema)
-                    .map_err(|e| LinkMLError::data_validation(e.to_string()))?,
-            );
-        }
-
-        Ok(output)
+        let file = self
+            .generate_file(schema)
+            .map_err(|e| LinkMLError::data_validation(e.to_string()))?;
+        Ok(rust_ast::render(&file))
     }
 
     fn get_file_extension(&self) -> &'static str {
@@ -463,8 +472,216 @@ impl CodeFormatter for RustGenerator {
     }
 
     fn format_code(&self, code: &str) -> GeneratorResult<String> {
-        // In a real implementation, this would use rustfmt
-        // For now, just return the code as-is
-        Ok(code.to_string())
+        use super::formatting::{OutputPostProcessor, RustFormatter};
+        RustFormatter::new().process(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::GeneratorOptions;
+
+    fn create_test_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("people");
+        schema.description = Some("People \"and\" {things}".to_string());
+
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                description: Some("A person\nwith a multi-line description".to_string()),
+                slots: vec![
+                    "id".to_string(),
+                    "type".to_string(),
+                    "firstName".to_string(),
+                    "status".to_string(),
+                    "friends".to_string(),
+                ],
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                required: Some(true),
+                description: Some("Identifier with \"quotes\" and \\ backslash".to_string()),
+                ..Default::default()
+            },
+        );
+        schema
+            .slots
+            .insert("type".to_string(), SlotDefinition::default());
+        schema.slots.insert(
+            "firstName".to_string(),
+            SlotDefinition {
+                range: Some("integer".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "status".to_string(),
+            SlotDefinition {
+                range: Some("Status".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "friends".to_string(),
+            SlotDefinition {
+                range: Some("Person".to_string()),
+                multivalued: Some(true),
+                ..Default::default()
+            },
+        );
+        schema.enums.insert(
+            "Status".to_string(),
+            EnumDefinition {
+                permissible_values: vec![
+                    PermissibleValue::Simple("active".to_string()),
+                    PermissibleValue::Complex {
+                        text: "on-hold \"paused\"".to_string(),
+                        description: Some("Temporarily paused".to_string()),
+                        meaning: None,
+                        is_a: None,
+                    },
+                ],
+                ..Default::default()
+            },
+        );
+
+        schema
+    }
+
+    #[test]
+    fn test_generated_code_parses() {
+        let schema = create_test_schema();
+        let output =
+            Generator::generate(&RustGenerator::new(), &schema).expect("should generate Rust code");
+
+        syn::parse_file(&output).expect("generated Rust code should parse");
+        assert!(output.starts_with("//! Generated from LinkML schema: people\n"));
+        assert!(output.contains("pub struct Person {"));
+        assert!(output.contains("/// with a multi-line description"));
+        assert!(output.contains("#[serde(rename = \"type\")]\n    pub type_: Option<String>,"));
+        assert!(
+            output.contains("#[serde(rename = \"firstName\")]\n    pub first_name: Option<i64>,")
+        );
+        assert!(output.contains("pub status: Option<Status>,"));
+        assert!(output.contains("pub friends: Vec<Box<Person>>,"));
+        assert!(output.contains(r#"#[serde(rename = "on-hold \"paused\"")]"#));
+    }
+
+    #[test]
+    fn test_configured_derives_are_added() {
+        let schema = create_test_schema();
+        let generator = RustGenerator::with_options(
+            GeneratorOptions::default().set_custom(rust_ast::DERIVES_OPTION, "Hash, Debug"),
+        );
+        let file = generator
+            .generate_file(&schema)
+            .expect("should build the Rust AST");
+        let output = rust_ast::render(&file);
+
+        assert!(output.contains("#[derive(Debug, Clone, Serialize, Deserialize, Hash)]"));
+        assert!(output.contains(
+            "#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash)]"
+        ));
+    }
+
+    #[test]
+    fn test_linkml_validate_impl() {
+        let mut schema = create_test_schema();
+        if let Some(slot) = schema.slots.get_mut("id") {
+            slot.pattern = Some("^P[0-9]+$".to_string());
+        }
+        if let Some(slot) = schema.slots.get_mut("firstName") {
+            slot.minimum_value = Some(serde_json::json!(0));
+            slot.maximum_value = Some(serde_json::json!(10.5));
+        }
+        let generator = RustGenerator::with_options(
+            GeneratorOptions::default().set_custom(rust_ast::VALIDATE_OPTION, "true"),
+        );
+        let output = rust_ast::render(&generator.generate_file(&schema).expect("Rust AST"));
+
+        syn::parse_file(&output).expect("generated Rust code should parse");
+        assert!(output.contains("impl linkml_core::LinkMLValidate for Person {"));
+        assert!(output.contains("const CLASS_NAME: &'static str = \"Person\";"));
+        assert!(output.contains("regex::Regex::new(\"^P[0-9]+$\")"));
+        assert!(output.contains("if *value > 10 {"));
+        assert!(output.contains("for (index, value) in self.friends.iter().enumerate() {"));
+        assert!(
+            !Generator::generate(&RustGenerator::new(), &schema)
+                .expect("Rust code")
+                .contains("LinkMLValidate")
+        );
+
+        if let Some(slot) = schema.slots.get_mut("id") {
+            slot.pattern = Some("(".to_string());
+        }
+        assert!(generator.generate_file(&schema).is_err());
+    }
+
+    #[test]
+    fn test_invalid_derives_are_rejected() {
+        let generator = RustGenerator::with_options(
+            GeneratorOptions::default().set_custom(rust_ast::DERIVES_OPTION, "not a path"),
+        );
+        assert!(generator.generate_file(&create_test_schema()).is_err());
+    }
+
+    #[test]
+    fn test_annotation_derives_and_attributes() {
+        let mut schema = create_test_schema();
+        if let Some(person) = schema.classes.get_mut("Person") {
+            person.annotations = Some(
+                [(
+                    annotation_hints::RUST_DERIVE.to_string(),
+                    "Default, Clone".into(),
+                )]
+                .into_iter()
+                .collect(),
+            );
+        }
+        if let Some(id) = schema.slots.get_mut("id") {
+            id.annotations = Some(
+                [(
+                    annotation_hints::RUST_ATTR.to_string(),
+                    "serde(alias = \"identifier\")".into(),
+                )]
+                .into_iter()
+                .collect(),
+            );
+        }
+
+        let output = RustGenerator::new()
+            .generate(&schema)
+            .expect("should generate Rust code");
+        assert!(output.contains("#[derive(Debug, Clone, Serialize, Deserialize, Default)]"));
+        assert!(output.contains("#[serde(alias = \"identifier\")]\n    pub id: String,"));
+        // Enums are unaffected by class annotations
+        assert!(
+            output.contains("#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]")
+        );
+    }
+
+    #[test]
+    fn test_enum_value_hierarchy() {
+        let mut schema = create_test_schema();
+        if let Some(status) = schema.enums.get_mut("Status") {
+            status.permissible_values.push(PermissibleValue::Complex {
+                text: "sabbatical".to_string(),
+                description: None,
+                meaning: None,
+                is_a: Some("on-hold \"paused\"".to_string()),
+            });
+        }
+
+        let output = RustGenerator::new()
+            .generate(&schema)
+            .expect("should generate Rust code");
+        syn::parse_file(&output).expect("generated Rust code should parse");
+        assert!(output.contains("pub fn parent(&self) -> Option<&'static str> {"));
+        assert!(output.contains(r#"Self::Sabbatical => Some("on-hold \"paused\""),"#));
+        assert!(output.contains("_ => None,"));
     }
 }
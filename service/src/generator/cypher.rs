@@ -0,0 +1,613 @@
+//! Cypher generator for `Neo4j` property graphs
+//!
+//! This module maps `LinkML` schemas to the property graph model also used by
+//! [`crate::loader::neo4j`]: every class is a node label, nodes carry the
+//! labels of their class and all its ancestors, scalar slots are node
+//! properties and object-valued slots are relationships from the node to the
+//! node of the range class, typed by the slot name in `UPPER_SNAKE` case.
+//!
+//! The generated script creates the constraints `Neo4j` 5 can enforce:
+//! uniqueness of identifiers, unique keys and `unique` slots and, on
+//! Enterprise Edition, property existence for required slots and property
+//! types. Relationship cardinality and enumerations cannot be expressed as
+//! constraints, so they are documented together with commented queries
+//! listing the nodes that violate them. Dates and times are typed as strings,
+//! as which they are stored by the dumper.
+
+use linkml_core::prelude::*;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use super::base::{BaseCodeFormatter, class_ancestry, induced_slots};
+use super::traits::{Generator, GeneratorError, GeneratorResult};
+use linkml_core::error::LinkMLError;
+
+/// Options for Cypher generation
+#[derive(Debug, Clone)]
+pub struct CypherOptions {
+    /// Emit the property existence and type constraints only available on
+    /// `Neo4j` Enterprise Edition
+    pub enterprise: bool,
+    /// Emit commented queries checking what constraints cannot enforce
+    pub validation_queries: bool,
+}
+
+impl Default for CypherOptions {
+    fn default() -> Self {
+        Self {
+            enterprise: true,
+            validation_queries: true,
+        }
+    }
+}
+
+/// Generator of `Neo4j` schema constraints in Cypher
+pub struct CypherGenerator {
+    /// Generation options
+    options: CypherOptions,
+}
+
+/// A node constraint on a property or set of properties
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Constraint {
+    /// Unique combination of properties, named by the key
+    Unique(String, Vec<String>),
+    /// Required property
+    Exists(String),
+    /// Property with a Cypher type
+    Type(String, String),
+}
+
+impl CypherGenerator {
+    /// Convert `fmt::Error` to `GeneratorError`
+    fn fmt_error_to_generator_error(e: std::fmt::Error) -> GeneratorError {
+        GeneratorError::Io(std::io::Error::other(e))
+    }
+
+    /// Create a new Cypher generator
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            options: CypherOptions::default(),
+        }
+    }
+
+    /// Create with custom options
+    #[must_use]
+    pub fn with_options(options: CypherOptions) -> Self {
+        Self { options }
+    }
+
+    /// Generate the Cypher schema script
+    fn generate_cypher(&self, schema: &SchemaDefinition) -> GeneratorResult<String> {
+        let mut output = String::new();
+        self.write_header(&mut output, schema)
+            .map_err(Self::fmt_error_to_generator_error)?;
+        Self::write_relationships(&mut output, schema)
+            .map_err(Self::fmt_error_to_generator_error)?;
+        self.write_constraints(&mut output, schema)
+            .map_err(Self::fmt_error_to_generator_error)?;
+        if self.options.validation_queries {
+            Self::write_validation_queries(&mut output, schema)
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        Ok(output)
+    }
+
+    fn write_header(&self, output: &mut String, schema: &SchemaDefinition) -> std::fmt::Result {
+        writeln!(output, "// Neo4j schema for {}", schema.name)?;
+        if let Some(description) = &schema.description {
+            writeln!(output, "// {description}")?;
+        }
+        writeln!(
+            output,
+            "// Edition: {}",
+            if self.options.enterprise {
+                "Enterprise"
+            } else {
+                "Community"
+            }
+        )?;
+        writeln!(output)?;
+        writeln!(output, "// Node labels")?;
+        for class_name in schema.classes.keys() {
+            let labels = class_labels(schema, class_name)
+                .iter()
+                .map(|label| quote_name(label))
+                .collect::<Vec<_>>()
+                .join(":");
+            write!(output, "// (:{labels})")?;
+            if let Some(description) = &schema.classes[class_name].description {
+                write!(output, " - {description}")?;
+            }
+            writeln!(output)?;
+        }
+        Ok(())
+    }
+
+    fn write_relationships(output: &mut String, schema: &SchemaDefinition) -> std::fmt::Result {
+        let mut relationships = Vec::new();
+        for (class_name, class) in &schema.classes {
+            for slot in own_slots(schema, class_name, class) {
+                if let Some(range) = object_range(schema, &slot) {
+                    let minimum = u8::from(slot.required == Some(true));
+                    let maximum = if slot.multivalued == Some(true) {
+                        "*"
+                    } else {
+                        "1"
+                    };
+                    relationships.push(format!(
+                        "// (:{})-[:{}]->(:{}) {minimum}..{maximum}",
+                        quote_name(class_name),
+                        quote_name(&relationship_type(&slot.name)),
+                        quote_name(range)
+                    ));
+                }
+            }
+        }
+        if !relationships.is_empty() {
+            writeln!(output)?;
+            writeln!(output, "// Relationship types")?;
+            for relationship in relationships {
+                writeln!(output, "{relationship}")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_constraints(
+        &self,
+        output: &mut String,
+        schema: &SchemaDefinition,
+    ) -> std::fmt::Result {
+        writeln!(output)?;
+        writeln!(output, "// Constraints")?;
+        for class_name in schema.classes.keys() {
+            // Nodes carry the labels of all ancestors, so constraints already
+            // declared for an ancestor label apply to this class as well
+            let inherited: HashSet<Constraint> = class_labels(schema, class_name)
+                .iter()
+                .skip(1)
+                .flat_map(|ancestor| self.class_constraints(schema, ancestor))
+                .collect();
+            for constraint in self.class_constraints(schema, class_name) {
+                if !inherited.contains(&constraint) {
+                    writeln!(output, "{}", constraint_statement(class_name, &constraint))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Constraints on the nodes of a class, including inherited slots
+    fn class_constraints(&self, schema: &SchemaDefinition, class_name: &str) -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        for slot in induced_slots(schema, class_name) {
+            if object_range(schema, &slot).is_some() {
+                continue;
+            }
+            let identifying = slot.identifier == Some(true) || slot.key == Some(true);
+            if identifying || slot.unique == Some(true) {
+                constraints.push(Constraint::Unique(
+                    slot.name.clone(),
+                    vec![slot.name.clone()],
+                ));
+            }
+            if !self.options.enterprise {
+                continue;
+            }
+            if identifying || slot.required == Some(true) {
+                constraints.push(Constraint::Exists(slot.name.clone()));
+            }
+            if let Some(property_type) = slot
+                .range
+                .as_deref()
+                .and_then(|range| property_type(schema, range))
+            {
+                let property_type = if slot.multivalued == Some(true) {
+                    format!("LIST<{property_type} NOT NULL>")
+                } else {
+                    property_type.to_string()
+                };
+                constraints.push(Constraint::Type(slot.name.clone(), property_type));
+            }
+        }
+        for label in class_labels(schema, class_name) {
+            let Some(class) = schema.classes.get(&label) else {
+                continue;
+            };
+            for (key_name, key) in &class.unique_keys {
+                if !key.unique_key_slots.is_empty() {
+                    constraints.push(Constraint::Unique(
+                        key_name.clone(),
+                        key.unique_key_slots.clone(),
+                    ));
+                }
+            }
+        }
+        constraints
+    }
+
+    fn write_validation_queries(
+        output: &mut String,
+        schema: &SchemaDefinition,
+    ) -> std::fmt::Result {
+        let mut queries = Vec::new();
+        for (class_name, class) in &schema.classes {
+            let label = quote_name(class_name);
+            for slot in own_slots(schema, class_name, class) {
+                if let Some(range) = object_range(schema, &slot) {
+                    let relationship = quote_name(&relationship_type(&slot.name));
+                    let range = quote_name(range);
+                    if slot.required == Some(true) {
+                        queries.push(format!(
+                            "// MATCH (n:{label}) WHERE NOT (n)-[:{relationship}]->(:{range}) RETURN n;"
+                        ));
+                    }
+                    if slot.multivalued != Some(true) {
+                        queries.push(format!(
+                            "// MATCH (n:{label})-[r:{relationship}]->() WITH n, count(r) AS links WHERE links > 1 RETURN n;"
+                        ));
+                    }
+                } else if let Some(enum_def) = slot
+                    .range
+                    .as_ref()
+                    .and_then(|range| schema.enums.get(range))
+                    .filter(|enum_def| !enum_def.permissible_values.is_empty())
+                {
+                    let property = format!("n.{}", quote_name(&slot.name));
+                    let values = enum_def
+                        .permissible_values
+                        .iter()
+                        .map(|value| string_literal(value.text()))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let condition = if slot.multivalued == Some(true) {
+                        format!("any(value IN {property} WHERE NOT value IN [{values}])")
+                    } else {
+                        format!("NOT {property} IN [{values}]")
+                    };
+                    queries.push(format!(
+                        "// MATCH (n:{label}) WHERE {property} IS NOT NULL AND {condition} RETURN n;"
+                    ));
+                }
+            }
+        }
+        if !queries.is_empty() {
+            writeln!(output)?;
+            writeln!(
+                output,
+                "// Validation queries for what constraints cannot enforce; each returns"
+            )?;
+            writeln!(output, "// the violating nodes")?;
+            for query in queries {
+                writeln!(output, "{query}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for CypherGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Generator for CypherGenerator {
+    fn name(&self) -> &'static str {
+        "cypher"
+    }
+
+    fn description(&self) -> &'static str {
+        "Generates Neo4j node and relationship constraints in Cypher from LinkML schemas"
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> linkml_core::error::Result<()> {
+        if schema.name.is_empty() {
+            return Err(LinkMLError::data_validation(
+                "Schema must have a name for Cypher generation",
+            ));
+        }
+        Ok(())
+    }
+
+    fn generate(&self, schema: &SchemaDefinition) -> std::result::Result<String, LinkMLError> {
+        Generator::validate_schema(self, schema)?;
+        Ok(self.generate_cypher(schema)?)
+    }
+
+    fn get_file_extension(&self) -> &'static str {
+        "cypher"
+    }
+
+    fn get_default_filename(&self) -> &'static str {
+        "schema"
+    }
+}
+
+/// Labels of the nodes of a class: the class itself followed by its
+/// ancestors and mixins
+#[must_use]
+pub fn class_labels(schema: &SchemaDefinition, class_name: &str) -> Vec<String> {
+    class_ancestry(schema, class_name)
+}
+
+/// Identifier slot of a class: an `identifier` slot, or else a `key` slot
+#[must_use]
+pub fn identifier_slot(schema: &SchemaDefinition, class_name: &str) -> Option<String> {
+    let slots = induced_slots(schema, class_name);
+    slots
+        .iter()
+        .find(|slot| slot.identifier == Some(true))
+        .or_else(|| slots.iter().find(|slot| slot.key == Some(true)))
+        .map(|slot| slot.name.clone())
+}
+
+/// Range class of an object-valued slot
+#[must_use]
+pub fn object_range<'a>(schema: &SchemaDefinition, slot: &'a SlotDefinition) -> Option<&'a str> {
+    slot.range
+        .as_deref()
+        .filter(|range| schema.classes.contains_key(*range))
+}
+
+/// Relationship type of an object-valued slot, e.g. `WORKS_FOR` for
+/// `worksFor` or `works_for`
+#[must_use]
+pub fn relationship_type(slot_name: &str) -> String {
+    let mut relationship = String::new();
+    let mut previous: Option<char> = None;
+    for c in slot_name.chars() {
+        if c.is_alphanumeric() {
+            if c.is_uppercase() && previous.is_some_and(|p| p.is_lowercase() || p.is_numeric()) {
+                relationship.push('_');
+            }
+            relationship.extend(c.to_uppercase());
+        } else if !relationship.is_empty() && !relationship.ends_with('_') {
+            relationship.push('_');
+        }
+        previous = Some(c);
+    }
+    relationship.trim_end_matches('_').to_string()
+}
+
+/// Label, relationship type or property name, quoted with backticks unless
+/// it is a plain identifier
+#[must_use]
+pub fn quote_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let plain = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        name.to_string()
+    } else {
+        format!("`{}`", name.replace('`', "``"))
+    }
+}
+
+/// Slots declared or refined by a class itself
+fn own_slots(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    class: &ClassDefinition,
+) -> Vec<SlotDefinition> {
+    induced_slots(schema, class_name)
+        .into_iter()
+        .filter(|slot| {
+            class.slots.contains(&slot.name)
+                || class.attributes.contains_key(&slot.name)
+                || class.slot_usage.contains_key(&slot.name)
+        })
+        .collect()
+}
+
+/// Cypher property type of a range, if it is a type or an enum
+fn property_type(schema: &SchemaDefinition, range: &str) -> Option<&'static str> {
+    let mut range = range;
+    let mut visited = HashSet::new();
+    loop {
+        match range {
+            "string" | "str" | "uri" | "uriorcurie" | "curie" | "ncname" | "nodeidentifier"
+            | "objectidentifier" | "date" | "datetime" | "time" | "date_or_datetime" => {
+                return Some("STRING");
+            }
+            "integer" | "int" => return Some("INTEGER"),
+            "float" | "double" | "decimal" => return Some("FLOAT"),
+            "boolean" | "bool" => return Some("BOOLEAN"),
+            _ if schema.enums.contains_key(range) => return Some("STRING"),
+            _ => {}
+        }
+        if !visited.insert(range) {
+            return None;
+        }
+        range = schema.types.get(range)?.base_type.as_deref()?;
+    }
+}
+
+/// `CREATE CONSTRAINT` statement for a constraint on a label
+fn constraint_statement(label: &str, constraint: &Constraint) -> String {
+    let prefix = BaseCodeFormatter::to_snake_case(label);
+    let property = |name: &str| format!("n.{}", quote_name(name));
+    let (name, requirement) = match constraint {
+        Constraint::Unique(key, properties) => {
+            let requirement = match properties.as_slice() {
+                [single] => format!("{} IS UNIQUE", property(single)),
+                _ => format!(
+                    "({}) IS UNIQUE",
+                    properties
+                        .iter()
+                        .map(|name| property(name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            };
+            (format!("{prefix}_{key}_unique"), requirement)
+        }
+        Constraint::Exists(name) => (
+            format!("{prefix}_{name}_exists"),
+            format!("{} IS NOT NULL", property(name)),
+        ),
+        Constraint::Type(name, property_type) => (
+            format!("{prefix}_{name}_type"),
+            format!("{} IS :: {property_type}", property(name)),
+        ),
+    };
+    format!(
+        "CREATE CONSTRAINT {} IF NOT EXISTS FOR (n:{}) REQUIRE {requirement};",
+        quote_name(&name),
+        quote_name(label)
+    )
+}
+
+/// Single-quoted Cypher string literal
+fn string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::{ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition};
+
+    fn create_test_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition {
+            name: "people".to_string(),
+            ..Default::default()
+        };
+        for (name, slot) in [
+            (
+                "id",
+                SlotDefinition {
+                    identifier: Some(true),
+                    range: Some("string".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "age",
+                SlotDefinition {
+                    range: Some("integer".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "status",
+                SlotDefinition {
+                    range: Some("Status".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "worksFor",
+                SlotDefinition {
+                    range: Some("Organization".to_string()),
+                    required: Some(true),
+                    ..Default::default()
+                },
+            ),
+            (
+                "knows",
+                SlotDefinition {
+                    range: Some("Person".to_string()),
+                    multivalued: Some(true),
+                    ..Default::default()
+                },
+            ),
+        ] {
+            schema.slots.insert(name.to_string(), slot);
+        }
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: vec![
+                    "id".to_string(),
+                    "age".to_string(),
+                    "status".to_string(),
+                    "knows".to_string(),
+                ],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Employee".to_string(),
+            ClassDefinition {
+                is_a: Some("Person".to_string()),
+                slots: vec!["worksFor".to_string()],
+                slot_usage: [(
+                    "age".to_string(),
+                    SlotDefinition {
+                        required: Some(true),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Organization".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.enums.insert(
+            "Status".to_string(),
+            EnumDefinition {
+                permissible_values: vec![
+                    PermissibleValue::Simple("ACTIVE".to_string()),
+                    PermissibleValue::Simple("RETIRED".to_string()),
+                ],
+                ..Default::default()
+            },
+        );
+        schema
+    }
+
+    #[test]
+    fn test_cypher_generation() {
+        let schema = create_test_schema();
+        let output = CypherGenerator::new()
+            .generate(&schema)
+            .expect("should generate cypher");
+
+        assert!(output.contains("// (:Employee:Person)"));
+        assert!(output.contains("// (:Employee)-[:WORKS_FOR]->(:Organization) 1..1"));
+        assert!(output.contains("// (:Person)-[:KNOWS]->(:Person) 0..*"));
+        assert!(output.contains(
+            "CREATE CONSTRAINT person_id_unique IF NOT EXISTS FOR (n:Person) REQUIRE n.id IS UNIQUE;"
+        ));
+        assert!(output.contains("FOR (n:Person) REQUIRE n.age IS :: INTEGER;"));
+        // Inherited constraints are only declared for the ancestor label
+        assert!(!output.contains("FOR (n:Employee) REQUIRE n.id IS UNIQUE"));
+        assert!(output.contains("FOR (n:Employee) REQUIRE n.age IS NOT NULL;"));
+        assert!(output.contains(
+            "// MATCH (n:Employee) WHERE NOT (n)-[:WORKS_FOR]->(:Organization) RETURN n;"
+        ));
+        assert!(output.contains("NOT n.status IN ['ACTIVE', 'RETIRED']"));
+
+        let community = CypherGenerator::with_options(CypherOptions {
+            enterprise: false,
+            validation_queries: false,
+        })
+        .generate(&schema)
+        .expect("should generate cypher");
+        assert!(community.contains("IS UNIQUE"));
+        assert!(!community.contains("IS NOT NULL"));
+        assert!(!community.contains("// MATCH"));
+    }
+
+    #[test]
+    fn test_naming() {
+        assert_eq!(relationship_type("worksFor"), "WORKS_FOR");
+        assert_eq!(relationship_type("has-part"), "HAS_PART");
+        assert_eq!(relationship_type("member_of"), "MEMBER_OF");
+        assert_eq!(quote_name("Person"), "Person");
+        assert_eq!(quote_name("my label"), "`my label`");
+        assert_eq!(quote_name("a`b"), "`a``b`");
+    }
+}
@@ -0,0 +1,311 @@
+//! dbt model properties generator for `LinkML` schemas
+//!
+//! Generates a dbt properties file (`schema.yml`) declaring one model per
+//! concrete class, named like the tables of the SQL generator. Columns carry
+//! the slot description, a data type and the generic data tests the schema
+//! implies: `not_null` for required slots and identifiers, `unique` for
+//! identifiers and unique slots, `accepted_values` for enums and
+//! `relationships` for references to other classes. Slot lineage is recorded
+//! in the `meta.lineage` of each column.
+
+use linkml_core::prelude::*;
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use super::base::{BaseCodeFormatter, induced_slots};
+use super::lineage::SlotLineage;
+use super::traits::{Generator, GeneratorError, GeneratorResult};
+use linkml_core::error::LinkMLError;
+
+/// dbt properties file
+#[derive(Serialize)]
+struct DbtProperties {
+    version: u8,
+    models: Vec<DbtModel>,
+}
+
+/// Model entry of a dbt properties file
+#[derive(Serialize)]
+struct DbtModel {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    columns: Vec<DbtColumn>,
+}
+
+/// Column entry of a dbt model
+#[derive(Serialize)]
+struct DbtColumn {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_type: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    data_tests: Vec<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<Value>,
+}
+
+/// Generator of dbt model properties
+#[derive(Default)]
+pub struct DbtGenerator;
+
+impl DbtGenerator {
+    /// Create a new dbt generator
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Generate the properties file
+    fn generate_properties(schema: &SchemaDefinition) -> GeneratorResult<String> {
+        let models = schema
+            .classes
+            .iter()
+            .filter(|(_, class)| class.abstract_ != Some(true) && class.mixin != Some(true))
+            .map(|(class_name, class)| DbtModel {
+                name: model_name(class_name),
+                description: class.description.clone(),
+                columns: induced_slots(schema, class_name)
+                    .iter()
+                    .map(|slot| column(schema, slot))
+                    .collect(),
+            })
+            .collect();
+        let properties = DbtProperties { version: 2, models };
+        serde_yaml::to_string(&properties).map_err(|e| {
+            GeneratorError::Generation(format!("Failed to serialize dbt properties: {e}"))
+        })
+    }
+}
+
+impl Generator for DbtGenerator {
+    fn name(&self) -> &'static str {
+        "dbt"
+    }
+
+    fn description(&self) -> &'static str {
+        "Generates dbt model properties with column tests and lineage metadata from LinkML schemas"
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> linkml_core::error::Result<()> {
+        if schema.name.is_empty() {
+            return Err(LinkMLError::data_validation(
+                "Schema must have a name for dbt generation",
+            ));
+        }
+        Ok(())
+    }
+
+    fn generate(&self, schema: &SchemaDefinition) -> std::result::Result<String, LinkMLError> {
+        Generator::validate_schema(self, schema)?;
+        Ok(Self::generate_properties(schema)?)
+    }
+
+    fn get_file_extension(&self) -> &'static str {
+        "yml"
+    }
+
+    fn get_default_filename(&self) -> &'static str {
+        "schema"
+    }
+}
+
+/// Model name of a class, as the SQL generator names its table
+fn model_name(class_name: &str) -> String {
+    BaseCodeFormatter::to_snake_case(class_name)
+}
+
+/// Column entry of a slot
+fn column(schema: &SchemaDefinition, slot: &SlotDefinition) -> DbtColumn {
+    let multivalued = slot.multivalued == Some(true);
+    let identifier = slot.identifier == Some(true);
+    let range = slot.range.as_deref();
+
+    let mut data_tests = Vec::new();
+    if identifier || slot.required == Some(true) {
+        data_tests.push(json!("not_null"));
+    }
+    if !multivalued && (identifier || slot.unique == Some(true)) {
+        data_tests.push(json!("unique"));
+    }
+    if !multivalued {
+        if let Some(enum_def) = range.and_then(|range| schema.enums.get(range))
+            && !enum_def.permissible_values.is_empty()
+        {
+            let values: Vec<&str> = enum_def
+                .permissible_values
+                .iter()
+                .map(PermissibleValue::text)
+                .collect();
+            data_tests.push(json!({ "accepted_values": { "values": values } }));
+        }
+        if let Some((range, class)) = range.and_then(|range| schema.classes.get_key_value(range))
+            && class.abstract_ != Some(true)
+            && class.mixin != Some(true)
+            && let Some(key) = induced_slots(schema, range)
+                .into_iter()
+                .find(|slot| slot.identifier == Some(true))
+        {
+            data_tests.push(json!({
+                "relationships": {
+                    "to": format!("ref('{}')", model_name(range)),
+                    "field": key.name,
+                }
+            }));
+        }
+    }
+
+    DbtColumn {
+        name: slot.name.clone(),
+        description: slot.description.clone(),
+        data_type: if multivalued {
+            None
+        } else {
+            range.and_then(|range| data_type(schema, range))
+        },
+        data_tests,
+        meta: SlotLineage::of(slot).map(|lineage| json!({ "lineage": lineage.to_json() })),
+    }
+}
+
+/// Warehouse data type of a range
+fn data_type(schema: &SchemaDefinition, range: &str) -> Option<String> {
+    let mut range = range;
+    for _ in 0..=schema.types.len() {
+        let data_type = match range {
+            "string" | "str" | "uri" | "uriorcurie" | "curie" | "ncname" => "varchar",
+            "integer" | "int" => "integer",
+            "float" | "double" => "double",
+            "decimal" => "numeric",
+            "boolean" | "bool" => "boolean",
+            "date" => "date",
+            "datetime" => "timestamp",
+            "time" => "time",
+            _ if schema.enums.contains_key(range) => "varchar",
+            // References hold the identifier of the referenced row
+            _ if schema.classes.contains_key(range) => {
+                return induced_slots(schema, range)
+                    .into_iter()
+                    .find(|slot| slot.identifier == Some(true))
+                    .and_then(|key| key.range)
+                    .filter(|key_range| key_range != range)
+                    .and_then(|key_range| data_type(schema, &key_range));
+            }
+            _ => {
+                range = schema.types.get(range)?.base_type.as_deref()?;
+                continue;
+            }
+        };
+        return Some(data_type.to_string());
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::lineage;
+    use linkml_core::types::{ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition};
+
+    #[test]
+    fn test_dbt_generation() {
+        let mut schema = SchemaDefinition {
+            name: "sales".to_string(),
+            ..Default::default()
+        };
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                identifier: Some(true),
+                range: Some("string".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "amount".to_string(),
+            SlotDefinition {
+                range: Some("decimal".to_string()),
+                required: Some(true),
+                annotations: Some(
+                    [
+                        (lineage::SOURCE_SYSTEM.to_string(), "erp".into()),
+                        (
+                            lineage::TRANSFORMATION.to_string(),
+                            "Converted to EUR".into(),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "status".to_string(),
+            SlotDefinition {
+                range: Some("Status".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "customer".to_string(),
+            SlotDefinition {
+                range: Some("Customer".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "SalesOrder".to_string(),
+            ClassDefinition {
+                description: Some("An order".to_string()),
+                slots: vec![
+                    "id".to_string(),
+                    "amount".to_string(),
+                    "status".to_string(),
+                    "customer".to_string(),
+                ],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Customer".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.enums.insert(
+            "Status".to_string(),
+            EnumDefinition {
+                permissible_values: vec![PermissibleValue::Simple("open".to_string())],
+                ..Default::default()
+            },
+        );
+
+        let output = DbtGenerator::new()
+            .generate(&schema)
+            .expect("should generate dbt properties");
+        let properties: serde_yaml::Value = serde_yaml::from_str(&output).expect("valid YAML");
+        let columns = &properties["models"][0]["columns"];
+        assert_eq!(properties["models"][0]["name"], "sales_order");
+        assert_eq!(columns[0]["name"], "id");
+        assert_eq!(columns[0]["data_tests"][1], "unique");
+        assert_eq!(columns[1]["data_type"], "numeric");
+        assert_eq!(columns[1]["meta"]["lineage"]["source_system"], "erp");
+        assert_eq!(
+            columns[1]["meta"]["lineage"]["transformation"],
+            "Converted to EUR"
+        );
+        assert_eq!(
+            columns[2]["data_tests"][0]["accepted_values"]["values"][0],
+            "open"
+        );
+        assert_eq!(
+            columns[3]["data_tests"][0]["relationships"]["to"],
+            "ref('customer')"
+        );
+        assert_eq!(columns[3]["data_type"], "varchar");
+    }
+}
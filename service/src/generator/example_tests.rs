@@ -0,0 +1,495 @@
+//! Test cases derived from schema examples and counterexamples
+//!
+//! With `GeneratorOptions::generate_tests` set, the Rust and TypeScript
+//! generators emit unit tests (and [`PydanticGenerator::generate_tests`]
+//! a pytest module) asserting that the generated models accept every slot
+//! example and reject counterexamples. This keeps generated models honest
+//! about the constraints the schema declares.
+//!
+//! Each class gets a minimal valid instance built from its required slots,
+//! preferring slot `examples` over synthesized values. Positive cases set one
+//! example at a time on that instance. Counterexamples break exactly one
+//! constraint: a missing required slot, a value outside `minimum_value` or
+//! `maximum_value`, a string not matching `pattern`, a value outside the
+//! permissible values, or a value of the wrong type. Classes whose minimal
+//! instance cannot be built (for example a required slot with a pattern and
+//! no example) get no cases.
+//!
+//! [`PydanticGenerator::generate_tests`]: super::pydantic::PydanticGenerator::generate_tests
+
+use super::base::collect_all_slots;
+use super::traits::GeneratorResult;
+use linkml_core::prelude::*;
+use serde_json::{Value, json};
+
+/// Value that is not a permissible value of any sensible enum
+const NOT_PERMISSIBLE: &str = "NOT_A_PERMISSIBLE_VALUE";
+
+/// Strings tried, in order, as values that do not match a slot pattern
+const PATTERN_MISMATCHES: &[&str] = &["", "!", "not matching", "0"];
+
+/// A single generated test case
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExampleCase {
+    /// Class the data is an instance of
+    pub class_name: String,
+    /// Identifier-safe case name, unique within the class
+    pub name: String,
+    /// Human-readable description of what the case checks
+    pub description: String,
+    /// Instance data
+    pub data: Value,
+    /// Whether the data should be accepted
+    pub valid: bool,
+}
+
+impl ExampleCase {
+    /// Identifier-safe test name combining class and case name
+    pub fn test_name(&self) -> String {
+        identifier(&format!("{}_{}", self.class_name, self.name))
+    }
+}
+
+/// Test cases for all classes of the schema, in schema order
+///
+/// # Errors
+///
+/// Returns an error if the slots of a class cannot be collected
+pub fn example_cases(schema: &SchemaDefinition) -> GeneratorResult<Vec<ExampleCase>> {
+    let mut cases = Vec::new();
+    for class_name in schema.classes.keys() {
+        cases.extend(class_cases(schema, class_name)?);
+    }
+    Ok(cases)
+}
+
+/// Test cases for one class: a minimal instance, examples and counterexamples
+///
+/// Abstract classes and mixins have no cases.
+///
+/// # Errors
+///
+/// Returns an error if the slots of the class cannot be collected
+pub fn class_cases(
+    schema: &SchemaDefinition,
+    class_name: &str,
+) -> GeneratorResult<Vec<ExampleCase>> {
+    let concrete = schema
+        .classes
+        .get(class_name)
+        .is_some_and(|class| class.abstract_ != Some(true) && class.mixin != Some(true));
+    if !concrete {
+        return Ok(Vec::new());
+    }
+    let Some(base) = minimal_instance(schema, class_name, &mut Vec::new())? else {
+        return Ok(Vec::new());
+    };
+    let case = |name: String, description: String, data: Value, valid: bool| ExampleCase {
+        class_name: class_name.to_string(),
+        name: identifier(&name),
+        description,
+        data,
+        valid,
+    };
+
+    let mut cases = vec![case(
+        "minimal".to_string(),
+        format!("Minimal valid {class_name}"),
+        base.clone(),
+        true,
+    )];
+    let slots = class_slots(schema, class_name)?;
+
+    for (slot_name, slot) in &slots {
+        if range_class(schema, slot).is_some() {
+            continue;
+        }
+        for (index, example) in slot.examples.iter().enumerate() {
+            cases.push(case(
+                format!("{slot_name}_example_{index}"),
+                format!("Example for {slot_name}: {}", example.value),
+                with_slot(
+                    &base,
+                    slot_name,
+                    slot_value(slot, typed_value(schema, slot, &example.value)),
+                ),
+                true,
+            ));
+        }
+    }
+
+    for (slot_name, slot) in &slots {
+        for (name, description, value) in counterexample_values(schema, slot_name, slot) {
+            let data = match value {
+                Some(value) => with_slot(&base, slot_name, value),
+                None => without_slot(&base, slot_name),
+            };
+            cases.push(case(name, description, data, false));
+        }
+    }
+    Ok(cases)
+}
+
+/// Schema-valid instances of a class, cycling through its valid cases
+///
+/// Instances are numbered from 1; a plain string identifier slot is set to
+/// `{class_name}-{n}` so that each instance has a distinct identifier.
+/// Returns no instances if the class has no valid cases.
+///
+/// # Errors
+///
+/// Returns an error if the slots of the class cannot be collected
+pub fn sample_instances(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    count: usize,
+) -> GeneratorResult<Vec<Value>> {
+    let valid: Vec<Value> = class_cases(schema, class_name)?
+        .into_iter()
+        .filter(|case| case.valid)
+        .map(|case| case.data)
+        .collect();
+    if valid.is_empty() {
+        return Ok(Vec::new());
+    }
+    let identifier_slot = class_slots(schema, class_name)?
+        .into_iter()
+        .find(|(_, slot)| slot.identifier == Some(true))
+        .filter(|(_, slot)| {
+            slot.pattern.is_none()
+                && slot.multivalued != Some(true)
+                && permissible_values(schema, slot).is_empty()
+                && matches!(primitive(schema, slot).as_str(), "string" | "str")
+        })
+        .map(|(name, _)| name);
+    Ok(valid
+        .iter()
+        .cycle()
+        .take(count)
+        .enumerate()
+        .map(|(index, data)| match identifier_slot {
+            Some(slot_name) => with_slot(
+                data,
+                slot_name,
+                json!(format!("{class_name}-{}", index + 1)),
+            ),
+            None => data.clone(),
+        })
+        .collect())
+}
+
+/// Values breaking one constraint of a slot, `None` meaning "omit the slot"
+fn counterexample_values(
+    schema: &SchemaDefinition,
+    slot_name: &str,
+    slot: &SlotDefinition,
+) -> Vec<(String, String, Option<Value>)> {
+    let mut values = Vec::new();
+    if slot.required == Some(true) {
+        values.push((
+            format!("missing_{slot_name}"),
+            format!("{slot_name} is required"),
+            None,
+        ));
+    }
+    if let Some(min) = slot.minimum_value.as_ref().and_then(Value::as_f64) {
+        values.push((
+            format!("{slot_name}_below_minimum"),
+            format!("{slot_name} must be at least {min}"),
+            Some(slot_value(slot, number(schema, slot, min - 1.0))),
+        ));
+    }
+    if let Some(max) = slot.maximum_value.as_ref().and_then(Value::as_f64) {
+        values.push((
+            format!("{slot_name}_above_maximum"),
+            format!("{slot_name} must be at most {max}"),
+            Some(slot_value(slot, number(schema, slot, max + 1.0))),
+        ));
+    }
+    if let Some(pattern) = &slot.pattern
+        && let Ok(regex) = regex::Regex::new(pattern)
+        && let Some(mismatch) = PATTERN_MISMATCHES.iter().find(|text| !regex.is_match(text))
+    {
+        values.push((
+            format!("{slot_name}_pattern_mismatch"),
+            format!("{slot_name} must match {pattern}"),
+            Some(slot_value(slot, json!(mismatch))),
+        ));
+    }
+    if !permissible_values(schema, slot).is_empty() {
+        values.push((
+            format!("{slot_name}_not_permissible"),
+            format!("{slot_name} must be a permissible value"),
+            Some(slot_value(slot, json!(NOT_PERMISSIBLE))),
+        ));
+    }
+    if matches!(
+        primitive(schema, slot).as_str(),
+        "integer" | "int" | "float" | "double" | "decimal" | "boolean" | "bool"
+    ) {
+        values.push((
+            format!("{slot_name}_wrong_type"),
+            format!("{slot_name} must be a {}", primitive(schema, slot)),
+            Some(slot_value(slot, json!("not a value of this type"))),
+        ));
+    }
+    values
+}
+
+/// Instance with all required slots of a class filled in
+///
+/// Returns `None` if a required slot has no value that is known to be valid.
+fn minimal_instance(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    visiting: &mut Vec<String>,
+) -> GeneratorResult<Option<Value>> {
+    if visiting.iter().any(|visited| visited == class_name) {
+        return Ok(None);
+    }
+    visiting.push(class_name.to_string());
+    let mut object = serde_json::Map::new();
+    for (slot_name, slot) in class_slots(schema, class_name)? {
+        if slot.required != Some(true) {
+            continue;
+        }
+        let Some(value) = required_value(schema, slot, visiting)? else {
+            visiting.pop();
+            return Ok(None);
+        };
+        object.insert(slot_name.to_string(), slot_value(slot, value));
+    }
+    visiting.pop();
+    Ok(Some(Value::Object(object)))
+}
+
+/// A value known to satisfy the constraints of a required slot
+fn required_value(
+    schema: &SchemaDefinition,
+    slot: &SlotDefinition,
+    visiting: &mut Vec<String>,
+) -> GeneratorResult<Option<Value>> {
+    if let Some(class_name) = range_class(schema, slot) {
+        return minimal_instance(schema, class_name, visiting);
+    }
+    if let Some(example) = slot.examples.first() {
+        return Ok(Some(typed_value(schema, slot, &example.value)));
+    }
+    if let Some(first) = permissible_values(schema, slot).first() {
+        return Ok(Some(json!(first)));
+    }
+    if slot.pattern.is_some() {
+        return Ok(None);
+    }
+    let bound = slot
+        .minimum_value
+        .as_ref()
+        .or(slot.maximum_value.as_ref())
+        .and_then(Value::as_f64);
+    Ok(Some(match primitive(schema, slot).as_str() {
+        "integer" | "int" | "float" | "double" | "decimal" => {
+            number(schema, slot, bound.unwrap_or(1.0))
+        }
+        "boolean" | "bool" => json!(true),
+        "date" => json!("2000-01-01"),
+        "datetime" => json!("2000-01-01T00:00:00Z"),
+        "time" => json!("12:00:00"),
+        "uri" | "uriorcurie" => json!("https://example.org/example"),
+        _ => json!("example"),
+    }))
+}
+
+/// Slots of a class with their definitions, including inherited slots
+fn class_slots<'s>(
+    schema: &'s SchemaDefinition,
+    class_name: &str,
+) -> GeneratorResult<Vec<(&'s str, &'s SlotDefinition)>> {
+    let Some(class) = schema.classes.get(class_name) else {
+        return Ok(Vec::new());
+    };
+    let named = ClassDefinition {
+        name: class_name.to_string(),
+        ..class.clone()
+    };
+    Ok(collect_all_slots(&named, schema)?
+        .iter()
+        .filter_map(|name| schema.slots.get_key_value(name))
+        .map(|(name, slot)| (name.as_str(), slot))
+        .collect())
+}
+
+/// Class named by the slot range, if any
+fn range_class<'s>(schema: &'s SchemaDefinition, slot: &SlotDefinition) -> Option<&'s str> {
+    let range = slot.range.as_deref()?;
+    schema
+        .classes
+        .get_key_value(range)
+        .map(|(name, _)| name.as_str())
+}
+
+/// Built-in type the slot range resolves to through `typeof`
+fn primitive(schema: &SchemaDefinition, slot: &SlotDefinition) -> String {
+    let mut range = slot.range.clone().unwrap_or_else(|| "string".to_string());
+    for _ in 0..schema.types.len() {
+        match schema.types.get(&range).and_then(|ty| ty.base_type.clone()) {
+            Some(base) => range = base,
+            None => break,
+        }
+    }
+    range
+}
+
+/// Permissible values of the slot or of its enum range
+fn permissible_values(schema: &SchemaDefinition, slot: &SlotDefinition) -> Vec<String> {
+    let values = match slot
+        .range
+        .as_deref()
+        .and_then(|range| schema.enums.get(range))
+    {
+        Some(enum_def) => &enum_def.permissible_values,
+        None => &slot.permissible_values,
+    };
+    values
+        .iter()
+        .map(|value| match value {
+            PermissibleValue::Simple(text) | PermissibleValue::Complex { text, .. } => text.clone(),
+        })
+        .collect()
+}
+
+/// Example text as a JSON value of the slot's type
+fn typed_value(schema: &SchemaDefinition, slot: &SlotDefinition, text: &str) -> Value {
+    match primitive(schema, slot).as_str() {
+        "integer" | "int" => text
+            .parse::<i64>()
+            .map_or_else(|_| json!(text), Value::from),
+        "float" | "double" | "decimal" => text
+            .parse::<f64>()
+            .map_or_else(|_| json!(text), Value::from),
+        "boolean" | "bool" => text
+            .parse::<bool>()
+            .map_or_else(|_| json!(text), Value::from),
+        _ => json!(text),
+    }
+}
+
+/// A number of the slot's type; integers are truncated towards zero
+fn number(schema: &SchemaDefinition, slot: &SlotDefinition, value: f64) -> Value {
+    if matches!(primitive(schema, slot).as_str(), "integer" | "int") {
+        #[allow(clippy::cast_possible_truncation)]
+        let integer = value as i64;
+        json!(integer)
+    } else {
+        json!(value)
+    }
+}
+
+/// Wrap a value in a list for multivalued slots
+fn slot_value(slot: &SlotDefinition, value: Value) -> Value {
+    if slot.multivalued == Some(true) {
+        Value::Array(vec![value])
+    } else {
+        value
+    }
+}
+
+fn with_slot(base: &Value, slot_name: &str, value: Value) -> Value {
+    let mut data = base.clone();
+    if let Some(object) = data.as_object_mut() {
+        object.insert(slot_name.to_string(), value);
+    }
+    data
+}
+
+fn without_slot(base: &Value, slot_name: &str) -> Value {
+    let mut data = base.clone();
+    if let Some(object) = data.as_object_mut() {
+        object.remove(slot_name);
+    }
+    data
+}
+
+/// Lowercase identifier with runs of other characters collapsed to `_`
+fn identifier(text: &str) -> String {
+    let mut name = String::new();
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c.to_ascii_lowercase());
+        } else if !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    name.trim_matches('_').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::metadata::Example;
+
+    #[test]
+    fn test_cases_from_examples_and_constraints() {
+        let mut schema = SchemaDefinition::default();
+        let example = |value: &str| Example {
+            value: value.to_string(),
+            description: None,
+        };
+        schema.slots.insert(
+            "name".to_string(),
+            SlotDefinition {
+                range: Some("string".to_string()),
+                required: Some(true),
+                pattern: Some("^[A-Z]".to_string()),
+                examples: vec![example("Ada")],
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "age".to_string(),
+            SlotDefinition {
+                range: Some("integer".to_string()),
+                minimum_value: Some(json!(0)),
+                examples: vec![example("36"), example("7")],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: vec!["name".to_string(), "age".to_string()],
+                ..Default::default()
+            },
+        );
+
+        let cases = example_cases(&schema).expect("example cases");
+        let summary: Vec<(String, bool)> = cases
+            .iter()
+            .map(|case| (case.test_name(), case.valid))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("person_minimal".to_string(), true),
+                ("person_name_example_0".to_string(), true),
+                ("person_age_example_0".to_string(), true),
+                ("person_age_example_1".to_string(), true),
+                ("person_missing_name".to_string(), false),
+                ("person_name_pattern_mismatch".to_string(), false),
+                ("person_age_below_minimum".to_string(), false),
+                ("person_age_wrong_type".to_string(), false),
+            ]
+        );
+        assert_eq!(cases[0].data, json!({"name": "Ada"}));
+        assert_eq!(cases[2].data, json!({"name": "Ada", "age": 36}));
+        assert_eq!(cases[6].data, json!({"name": "Ada", "age": -1}));
+
+        let samples = sample_instances(&schema, "Person", 5).expect("sample instances");
+        assert_eq!(samples.len(), 5);
+        assert_eq!(samples[3], json!({"name": "Ada", "age": 7}));
+        assert_eq!(samples[4], samples[0]);
+
+        // Without an example the pattern makes a minimal instance unknowable
+        schema.slots["name"].examples.clear();
+        assert!(example_cases(&schema).expect("example cases").is_empty());
+    }
+}
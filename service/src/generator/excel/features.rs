@@ -14,13 +14,22 @@ bitflags! {
         const ADD_FILTERS = 0b1000;
         /// Enforce regex patterns using Excel formulas when supported.
         const PATTERN_VALIDATION = 0b1_0000;
+        /// Restrict object-valued slots to identifiers on the range class sheet.
+        const CROSS_SHEET_LOOKUPS = 0b10_0000;
+        /// Highlight missing required values and constraint violations.
+        const CONDITIONAL_FORMATTING = 0b100_0000;
+        /// Include a legend sheet explaining cell styles and rules.
+        const INCLUDE_LEGEND = 0b1000_0000;
 
         /// All features enabled (default).
         const ALL = Self::INCLUDE_SUMMARY.bits()
                   | Self::ADD_VALIDATION.bits()
                   | Self::FREEZE_HEADERS.bits()
                   | Self::ADD_FILTERS.bits()
-                  | Self::PATTERN_VALIDATION.bits();
+                  | Self::PATTERN_VALIDATION.bits()
+                  | Self::CROSS_SHEET_LOOKUPS.bits()
+                  | Self::CONDITIONAL_FORMATTING.bits()
+                  | Self::INCLUDE_LEGEND.bits();
 
         /// Basic features only (no validation or filters).
         const BASIC = Self::INCLUDE_SUMMARY.bits()
@@ -68,6 +68,25 @@ impl ExcelGenerator {
         self.features.contains(ExcelFeatures::PATTERN_VALIDATION)
     }
 
+    /// Check if object-valued slots get cross-sheet identifier lookups.
+    #[must_use]
+    pub fn cross_sheet_lookups(&self) -> bool {
+        self.features.contains(ExcelFeatures::CROSS_SHEET_LOOKUPS)
+    }
+
+    /// Check if conditional formatting is enabled.
+    #[must_use]
+    pub fn conditional_formatting(&self) -> bool {
+        self.features
+            .contains(ExcelFeatures::CONDITIONAL_FORMATTING)
+    }
+
+    /// Check if legend sheet is enabled.
+    #[must_use]
+    pub fn include_legend(&self) -> bool {
+        self.features.contains(ExcelFeatures::INCLUDE_LEGEND)
+    }
+
     /// Configure example data generation (reserved for future use).
     #[must_use]
     pub fn with_examples(self, _enabled: bool) -> Self {
@@ -118,4 +137,37 @@ impl ExcelGenerator {
         }
         self
     }
+
+    /// Configure cross-sheet identifier lookups for object-valued slots.
+    #[must_use]
+    pub fn with_cross_sheet_lookups(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.features.insert(ExcelFeatures::CROSS_SHEET_LOOKUPS);
+        } else {
+            self.features.remove(ExcelFeatures::CROSS_SHEET_LOOKUPS);
+        }
+        self
+    }
+
+    /// Configure conditional formatting of invalid cells.
+    #[must_use]
+    pub fn with_conditional_formatting(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.features.insert(ExcelFeatures::CONDITIONAL_FORMATTING);
+        } else {
+            self.features.remove(ExcelFeatures::CONDITIONAL_FORMATTING);
+        }
+        self
+    }
+
+    /// Configure legend sheet generation.
+    #[must_use]
+    pub fn with_legend(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.features.insert(ExcelFeatures::INCLUDE_LEGEND);
+        } else {
+            self.features.remove(ExcelFeatures::INCLUDE_LEGEND);
+        }
+        self
+    }
 }
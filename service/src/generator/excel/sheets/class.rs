@@ -10,7 +10,9 @@ use super::super::generator::ExcelGenerator;
 use super::super::{cast, pattern};
 
 const SAMPLE_ROW_COUNT: usize = 5;
-const DATA_START_ROW: u32 = 3;
+pub(super) const DATA_START_ROW: u32 = 3;
+/// Last row index of a worksheet, used to apply rules to whole columns.
+pub(super) const LAST_ROW: u32 = 1_048_575;
 
 impl ExcelGenerator {
     #[allow(clippy::too_many_arguments)]
@@ -51,6 +53,10 @@ impl ExcelGenerator {
             self.add_data_validations(worksheet, &slots, schema, DATA_START_ROW)?;
         }
 
+        if self.conditional_formatting() {
+            self.add_conditional_formats(worksheet, &slots, schema, DATA_START_ROW)?;
+        }
+
         if self.freeze_headers() {
             worksheet
                 .set_freeze_panes(3, 0)
@@ -190,19 +196,45 @@ impl ExcelGenerator {
                     })
                     .collect();
 
-                let data_validation = DataValidation::new()
-                    .allow_list_strings(
-                        &values
-                            .iter()
-                            .map(std::string::String::as_str)
-                            .collect::<Vec<_>>(),
-                    )
-                    .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+                let data_validation = if let Some(reference) =
+                    Self::enum_values_reference(&values, range, schema)
+                {
+                    DataValidation::new().allow_list_formula(Formula::new(format!("={reference}")))
+                } else {
+                    DataValidation::new()
+                        .allow_list_strings(
+                            &values
+                                .iter()
+                                .map(std::string::String::as_str)
+                                .collect::<Vec<_>>(),
+                        )
+                        .map_err(|e| GeneratorError::Generation(e.to_string()))?
+                };
 
                 worksheet
                     .add_data_validation(start_row, col, 1_048_575, col, &data_validation)
                     .map_err(|e| GeneratorError::Generation(e.to_string()))?;
 
+                has_validation = true;
+            } else if self.cross_sheet_lookups()
+                && !slot_def.multivalued.unwrap_or(false)
+                && let Some(range) = &slot_def.range
+                && let Some(reference) = self.identifier_reference(range, schema)?
+            {
+                let validation = DataValidation::new()
+                    .allow_list_formula(Formula::new(format!("={reference}")))
+                    .set_error_title("Unknown reference")
+                    .map_err(|e| GeneratorError::Generation(e.to_string()))?
+                    .set_error_message(format!(
+                        "Value must be the identifier of a {range} on the '{}' sheet",
+                        Self::sanitize_sheet_name(range)
+                    ))
+                    .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+
+                worksheet
+                    .add_data_validation(start_row, col, LAST_ROW, col, &validation)
+                    .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+
                 has_validation = true;
             }
 
@@ -264,6 +296,21 @@ impl ExcelGenerator {
                                 DataValidationRule::Between(min_val, max_val),
                             );
                         }
+                    } else if let Some(min_val) = slot_def
+                        .minimum_value
+                        .as_ref()
+                        .and_then(serde_json::Value::as_f64)
+                    {
+                        validation = validation.allow_decimal_number(
+                            DataValidationRule::GreaterThanOrEqualTo(min_val),
+                        );
+                    } else if let Some(max_val) = slot_def
+                        .maximum_value
+                        .as_ref()
+                        .and_then(serde_json::Value::as_f64)
+                    {
+                        validation = validation
+                            .allow_decimal_number(DataValidationRule::LessThanOrEqualTo(max_val));
                     } else {
                         validation = validation
                             .allow_decimal_number(DataValidationRule::Between(f64::MIN, f64::MAX));
@@ -345,7 +392,7 @@ impl ExcelGenerator {
         Ok(())
     }
 
-    fn collect_class_slots(
+    pub(super) fn collect_class_slots(
         &self,
         _class_name: &str,
         class_def: &ClassDefinition,
@@ -0,0 +1,105 @@
+use crate::generator::traits::{GeneratorError, GeneratorResult};
+use linkml_core::prelude::{SchemaDefinition, SlotDefinition};
+use rust_xlsxwriter::utility::column_number_to_name;
+use rust_xlsxwriter::{Color, ConditionalFormatFormula, Format, FormatBorder, Worksheet};
+
+use super::super::cast;
+use super::super::generator::ExcelGenerator;
+use super::class::LAST_ROW;
+
+/// Format highlighting cells that break a schema constraint.
+pub(super) fn invalid_value_format() -> Format {
+    Format::new()
+        .set_background_color(Color::RGB(0x00FF_C7CE))
+        .set_font_color(Color::RGB(0x009C_0006))
+        .set_border(FormatBorder::Thin)
+}
+
+impl ExcelGenerator {
+    /// Highlight data cells that are missing or violate their slot's constraints.
+    ///
+    /// Data validation only checks values as they are typed, so pasted or
+    /// pre-existing values are caught here instead. Required cells are only
+    /// flagged once another cell in the same row has been filled in.
+    pub(super) fn add_conditional_formats(
+        &self,
+        worksheet: &mut Worksheet,
+        slots: &[(String, SlotDefinition)],
+        schema: &SchemaDefinition,
+        start_row: u32,
+    ) -> GeneratorResult<()> {
+        let invalid_format = invalid_value_format();
+        let first_row = start_row + 1; // Excel rows are 1-based.
+        let last_col =
+            column_number_to_name(cast::usize_to_u16_column(slots.len().saturating_sub(1))?);
+
+        for (col_index, (_slot_name, slot_def)) in slots.iter().enumerate() {
+            let col = cast::usize_to_u16_column(col_index)?;
+            let cell = format!("{}{first_row}", column_number_to_name(col));
+            let mut conditions = Vec::new();
+
+            if slot_def.required.unwrap_or(false) {
+                conditions.push(format!(
+                    "AND(COUNTA($A{first_row}:${last_col}{first_row})>0,ISBLANK({cell}))"
+                ));
+            }
+
+            if let Some(condition) = range_violation(slot_def, &cell) {
+                conditions.push(condition);
+            }
+
+            if self.pattern_validation()
+                && let Some(pattern) = &slot_def.pattern
+            {
+                let escaped_pattern = pattern.replace('"', "\"\"");
+                conditions.push(format!(
+                    "AND({cell}<>\"\",NOT(REGEXMATCH({cell},\"{escaped_pattern}\")))"
+                ));
+            }
+
+            if self.cross_sheet_lookups()
+                && !slot_def.multivalued.unwrap_or(false)
+                && let Some(range) = &slot_def.range
+                && let Some(reference) = self.identifier_reference(range, schema)?
+            {
+                conditions.push(format!("AND({cell}<>\"\",COUNTIF({reference},{cell})=0)"));
+            }
+
+            let rule = match conditions.len() {
+                0 => continue,
+                1 => conditions.remove(0),
+                _ => format!("OR({})", conditions.join(",")),
+            };
+
+            let conditional_format = ConditionalFormatFormula::new()
+                .set_rule(format!("={rule}").as_str())
+                .set_format(&invalid_format);
+            worksheet
+                .add_conditional_format(start_row, col, LAST_ROW, col, &conditional_format)
+                .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Condition matching numbers outside the slot's minimum/maximum bounds.
+fn range_violation(slot_def: &SlotDefinition, cell: &str) -> Option<String> {
+    let min = slot_def
+        .minimum_value
+        .as_ref()
+        .and_then(serde_json::Value::as_f64)
+        .map(|min| format!("{cell}<{min}"));
+    let max = slot_def
+        .maximum_value
+        .as_ref()
+        .and_then(serde_json::Value::as_f64)
+        .map(|max| format!("{cell}>{max}"));
+
+    let bounds = match (min, max) {
+        (Some(min), Some(max)) => format!("OR({min},{max})"),
+        (Some(bound), None) | (None, Some(bound)) => bound,
+        (None, None) => return None,
+    };
+    Some(format!("AND(ISNUMBER({cell}),{bounds})"))
+}
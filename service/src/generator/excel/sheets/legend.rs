@@ -0,0 +1,120 @@
+use crate::generator::traits::GeneratorError;
+use rust_xlsxwriter::{Format, Workbook};
+
+use super::super::generator::ExcelGenerator;
+use super::conditional::invalid_value_format;
+
+impl ExcelGenerator {
+    /// Generate a sheet explaining the cell styles and entry rules used on
+    /// the class sheets.
+    pub(crate) fn generate_legend_sheet(
+        &self,
+        workbook: &mut Workbook,
+        header_format: &Format,
+        required_format: &Format,
+        optional_format: &Format,
+        type_format: &Format,
+    ) -> Result<(), GeneratorError> {
+        let worksheet = workbook
+            .add_worksheet()
+            .set_name("Legend")
+            .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+
+        worksheet
+            .write_string_with_format(0, 0, "Style", header_format)
+            .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+        worksheet
+            .write_string_with_format(0, 1, "Meaning", header_format)
+            .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+
+        let invalid_format = invalid_value_format();
+        let mut entries = vec![
+            (
+                "slot",
+                header_format,
+                "Column header named after a slot; hover for its description",
+            ),
+            (
+                "<range>",
+                type_format,
+                "Type, enumeration or class expected in the column",
+            ),
+            (
+                "Required",
+                required_format,
+                "Required slot: every filled row needs a value",
+            ),
+            ("Optional", optional_format, "Optional slot"),
+        ];
+        if self.conditional_formatting() {
+            entries.push((
+                "Invalid",
+                &invalid_format,
+                "Missing required value, out-of-range number, pattern mismatch or unknown reference",
+            ));
+        }
+
+        let mut row = 1;
+        for (label, format, meaning) in entries {
+            worksheet
+                .write_string_with_format(row, 0, label, format)
+                .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+            worksheet
+                .write_string(row, 1, meaning)
+                .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+            row += 1;
+        }
+
+        let mut rules = Vec::new();
+        if self.add_validation() {
+            rules.push((
+                "Dropdown",
+                "Enumerated slot: pick one of the permissible values listed on the Enumerations sheet",
+            ));
+            rules.push((
+                "Range",
+                "Numeric slot: value must lie within the slot's minimum and maximum",
+            ));
+        }
+        if self.pattern_validation() {
+            rules.push((
+                "Pattern",
+                "Value must match the slot's regular expression (Microsoft 365 and Excel for the web)",
+            ));
+        }
+        if self.cross_sheet_lookups() {
+            rules.push((
+                "Lookup",
+                "Object-valued slot: pick an identifier from the sheet of the referenced class",
+            ));
+        }
+
+        if !rules.is_empty() {
+            row += 1;
+            worksheet
+                .write_string_with_format(row, 0, "Rule", header_format)
+                .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+            worksheet
+                .write_string_with_format(row, 1, "Meaning", header_format)
+                .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+            for (rule, meaning) in rules {
+                row += 1;
+                worksheet
+                    .write_string(row, 0, rule)
+                    .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+                worksheet
+                    .write_string(row, 1, meaning)
+                    .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+            }
+        }
+
+        worksheet
+            .set_column_width(0, 15)
+            .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+        worksheet
+            .set_column_width(1, 80)
+            .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+
+        Ok(())
+    }
+}
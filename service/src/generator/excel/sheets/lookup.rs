@@ -0,0 +1,75 @@
+use crate::generator::traits::GeneratorResult;
+use linkml_core::prelude::SchemaDefinition;
+use rust_xlsxwriter::utility::column_number_to_name;
+
+use super::super::cast;
+use super::super::generator::ExcelGenerator;
+use super::class::{DATA_START_ROW, LAST_ROW};
+
+/// Excel rejects inline dropdown lists longer than this many characters.
+const MAX_INLINE_LIST_LEN: usize = 255;
+
+impl ExcelGenerator {
+    /// Absolute reference to the identifier column on the sheet of `range`.
+    ///
+    /// Returns `None` unless `range` is a concrete class with an identifier
+    /// slot, as only those get a sheet whose rows can be referenced.
+    pub(crate) fn identifier_reference(
+        &self,
+        range: &str,
+        schema: &SchemaDefinition,
+    ) -> GeneratorResult<Option<String>> {
+        let Some(class_def) = schema.classes.get(range) else {
+            return Ok(None);
+        };
+        if class_def.abstract_.unwrap_or(false) {
+            return Ok(None);
+        }
+
+        let slots = self.collect_class_slots(range, class_def, schema)?;
+        let Some(col_index) = slots
+            .iter()
+            .position(|(_, slot)| slot.identifier.unwrap_or(false))
+        else {
+            return Ok(None);
+        };
+
+        let letter = column_number_to_name(cast::usize_to_u16_column(col_index)?);
+        let sheet = Self::sanitize_sheet_name(range).replace('\'', "''");
+        Ok(Some(format!(
+            "'{sheet}'!${letter}${}:${letter}${}",
+            DATA_START_ROW + 1,
+            LAST_ROW + 1
+        )))
+    }
+
+    /// Reference to the values of `enum_name` on the Enumerations sheet.
+    ///
+    /// Used instead of an inline list when the values exceed Excel's limit
+    /// for inline dropdowns.
+    pub(super) fn enum_values_reference(
+        values: &[String],
+        enum_name: &str,
+        schema: &SchemaDefinition,
+    ) -> Option<String> {
+        let inline_len = values.iter().map(String::len).sum::<usize>() + values.len();
+        if inline_len <= MAX_INLINE_LIST_LEN {
+            return None;
+        }
+
+        // Mirrors the layout written by `generate_enums_sheet`: one row per
+        // permissible value below a single header row.
+        let mut first_row = 2;
+        for (name, enum_def) in &schema.enums {
+            let count = enum_def.permissible_values.len();
+            if name == enum_name {
+                return Some(format!(
+                    "Enumerations!$B${first_row}:$B${}",
+                    first_row + count - 1
+                ));
+            }
+            first_row += count;
+        }
+        None
+    }
+}
@@ -1,4 +1,7 @@
 pub(super) mod class;
+pub(super) mod conditional;
 pub(super) mod enums;
+pub(super) mod legend;
+pub(super) mod lookup;
 pub(super) mod summary;
 pub(super) mod validation_info;
@@ -44,6 +44,16 @@ impl ExcelGenerator {
             self.generate_summary_sheet(&mut workbook, schema, &header_format)?;
         }
 
+        if self.include_legend() {
+            self.generate_legend_sheet(
+                &mut workbook,
+                &header_format,
+                &required_format,
+                &optional_format,
+                &type_format,
+            )?;
+        }
+
         for (class_name, class_def) in &schema.classes {
             if class_def.abstract_.unwrap_or(false) {
                 continue;
@@ -216,6 +226,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cross_sheet_lookup_and_legend() -> anyhow::Result<()> {
+        use calamine::Reader;
+
+        let mut schema = create_test_schema();
+        schema.classes.insert(
+            "Organization".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "label".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                identifier: Some(true),
+                ..Default::default()
+            },
+        );
+        schema
+            .slots
+            .insert("label".to_string(), SlotDefinition::default());
+        schema.slots.insert(
+            "employer".to_string(),
+            SlotDefinition {
+                range: Some("Organization".to_string()),
+                ..Default::default()
+            },
+        );
+        if let Some(person) = schema.classes.get_mut("Person") {
+            person.slots.push("employer".to_string());
+        }
+
+        let generator = ExcelGenerator::new();
+        assert_eq!(
+            generator
+                .identifier_reference("Organization", &schema)?
+                .as_deref(),
+            Some("'Organization'!$A$4:$A$1048576")
+        );
+        assert_eq!(generator.identifier_reference("Person", &schema)?, None);
+
+        let buffer = generator.generate_workbook(&schema)?;
+        let workbook = calamine::open_workbook_auto_from_rs(std::io::Cursor::new(buffer))?;
+        let sheets = workbook.sheet_names();
+        assert!(sheets.contains(&"Legend".to_string()));
+        assert!(sheets.contains(&"Organization".to_string()));
+
+        let without_legend = ExcelGenerator::new().with_legend(false);
+        let buffer = without_legend.generate_workbook(&schema)?;
+        let workbook = calamine::open_workbook_auto_from_rs(std::io::Cursor::new(buffer))?;
+        assert!(!workbook.sheet_names().contains(&"Legend".to_string()));
+        Ok(())
+    }
+
     #[test]
     fn test_sheet_name_sanitization() -> std::result::Result<(), Box<dyn std::error::Error>> {
         assert_eq!(ExcelGenerator::sanitize_sheet_name("Simple"), "Simple");
@@ -1,5 +1,6 @@
 //! Field generation and type mapping
 
+use super::annotation_hints;
 use super::base::{BaseCodeFormatter, collect_all_slots};
 use super::core::RustGenerator;
 use super::traits::{GeneratorOptions, GeneratorResult, IndentStyle};
@@ -40,6 +41,11 @@ impl RustGenerator {
                         "#[serde(default, skip_serializing_if = \"Vec::is_empty\")]".to_string(),
                     );
                 }
+                attrs.extend(
+                    annotation_hints::rust_attributes(slot, slot_name)?
+                        .into_iter()
+                        .map(|attr| format!("#[{attr}]")),
+                );
 
                 // Write attributes
                 for attr in &attrs {
@@ -149,6 +155,20 @@ impl RustGenerator {
             "#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]"
         )
         .map_err(Self::fmt_error_to_generator_error)?;
+        for line in Self::annotation_attr_lines(
+            enum_name,
+            enum_def,
+            &[
+                "Debug",
+                "Clone",
+                "PartialEq",
+                "Eq",
+                "Serialize",
+                "Deserialize",
+            ],
+        )? {
+            writeln!(&mut output, "{line}").map_err(Self::fmt_error_to_generator_error)?;
+        }
         writeln!(&mut output, "pub enum {struct_name} {{")
             .map_err(Self::fmt_error_to_generator_error)?;
 
@@ -225,9 +245,70 @@ impl RustGenerator {
         writeln!(&mut output, "{}}}", indent.single())
             .map_err(Self::fmt_error_to_generator_error)?;
 
+        output.push_str(&Self::generate_enum_parent_rust(
+            &struct_name,
+            enum_def,
+            indent,
+        )?);
+
         writeln!(&mut output, "}}").map_err(Self::fmt_error_to_generator_error)?;
         writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
 
         Ok(output)
     }
+
+    /// Generate the `parent` method mapping each value to its `is_a` parent
+    ///
+    /// Empty if the enum has no value hierarchy.
+    fn generate_enum_parent_rust(
+        struct_name: &str,
+        enum_def: &EnumDefinition,
+        indent: &IndentStyle,
+    ) -> GeneratorResult<String> {
+        let mut output = String::new();
+        let parents: Vec<(&str, &str)> = enum_def
+            .permissible_values
+            .iter()
+            .filter_map(|pv| Some((pv.text(), pv.is_a()?)))
+            .collect();
+        if parents.is_empty() {
+            return Ok(output);
+        }
+
+        writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            &mut output,
+            "{}/// Parent value in the permissible value hierarchy",
+            indent.single()
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            &mut output,
+            "{}pub fn parent(&self) -> Option<&'static str> {{",
+            indent.single()
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "{}match self {{", indent.to_string(2))
+            .map_err(Self::fmt_error_to_generator_error)?;
+        for (value, parent) in &parents {
+            writeln!(
+                &mut output,
+                "{}{}::{} => Some({:?}),",
+                indent.to_string(3),
+                struct_name,
+                BaseCodeFormatter::to_pascal_case(value),
+                parent
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        if parents.len() < enum_def.permissible_values.len() {
+            writeln!(&mut output, "{}_ => None,", indent.to_string(3))
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        writeln!(&mut output, "{}}}", indent.to_string(2))
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "{}}}", indent.single())
+            .map_err(Self::fmt_error_to_generator_error)?;
+        Ok(output)
+    }
 }
@@ -0,0 +1,430 @@
+//! Output post-processors for generated code
+//!
+//! Generators build their output with string templates, which rarely matches
+//! what downstream format checks (`cargo fmt --check`, `black --check`) expect.
+//! This module provides native, in-process formatters that are applied to the
+//! generated text after generation when `enable_formatting` is set, so no
+//! external tool has to be installed.
+
+mod python;
+
+use super::traits::{GeneratorError, GeneratorResult};
+use std::sync::Arc;
+
+/// A post-processing step applied to generated output
+pub trait OutputPostProcessor: Send + Sync {
+    /// Get the post-processor name
+    fn name(&self) -> &str;
+
+    /// Whether this post-processor handles files with the given extension
+    fn applies_to(&self, extension: &str) -> bool;
+
+    /// Process generated content
+    ///
+    /// # Errors
+    /// Returns an error if the content cannot be processed (for example
+    /// because it is not syntactically valid for the target language)
+    fn process(&self, content: &str) -> GeneratorResult<String>;
+}
+
+/// Formats Rust code with `prettyplease`
+///
+/// The generated source is parsed with `syn` and re-emitted with
+/// `prettyplease`, which produces rustfmt-compatible layout. Doc comments are
+/// preserved because `syn` keeps them as attributes; a leading block of plain
+/// `//` header comments is carried over verbatim.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RustFormatter;
+
+impl RustFormatter {
+    /// Create a new Rust formatter
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl OutputPostProcessor for RustFormatter {
+    fn name(&self) -> &'static str {
+        "prettyplease"
+    }
+
+    fn applies_to(&self, extension: &str) -> bool {
+        extension == "rs"
+    }
+
+    fn process(&self, content: &str) -> GeneratorResult<String> {
+        // Plain `//` comments are not part of the syn AST, so keep the
+        // leading header block (e.g. "// Generated by ...") by hand.
+        let header: Vec<&str> = content
+            .lines()
+            .take_while(|line| {
+                let trimmed = line.trim_start();
+                trimmed.starts_with("//")
+                    && !trimmed.starts_with("///")
+                    && !trimmed.starts_with("//!")
+            })
+            .collect();
+
+        let file = syn::parse_file(content).map_err(|e| {
+            GeneratorError::Generation(format!("Generated Rust code does not parse: {e}"))
+        })?;
+        let formatted = prettyplease::unparse(&file);
+
+        if header.is_empty() {
+            Ok(formatted)
+        } else {
+            Ok(format!("{}\n\n{formatted}", header.join("\n")))
+        }
+    }
+}
+
+/// Black-compatible formatting for generated Python code
+///
+/// Applies black's default style to the constructs generated code contains:
+/// four-space indentation instead of tabs, no trailing whitespace, black's
+/// blank lines around definitions (comments directly above a definition stay
+/// attached to it), double-quoted strings, and lines split at brackets to
+/// fit 88 columns. This is not a general-purpose Python formatter; spacing
+/// between tokens is kept as generated.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PythonFormatter;
+
+impl PythonFormatter {
+    /// Create a new Python formatter
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl OutputPostProcessor for PythonFormatter {
+    fn name(&self) -> &'static str {
+        "python-black-compat"
+    }
+
+    fn applies_to(&self, extension: &str) -> bool {
+        matches!(extension, "py" | "pyi")
+    }
+
+    fn process(&self, content: &str) -> GeneratorResult<String> {
+        Ok(python::format(content))
+    }
+}
+
+/// Language-agnostic whitespace cleanup
+///
+/// Strips trailing whitespace, collapses runs of more than two blank lines and
+/// guarantees exactly one trailing newline. Applied to every extension that
+/// has no dedicated formatter.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WhitespaceNormalizer;
+
+impl WhitespaceNormalizer {
+    /// Create a new whitespace normalizer
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl OutputPostProcessor for WhitespaceNormalizer {
+    fn name(&self) -> &'static str {
+        "whitespace"
+    }
+
+    fn applies_to(&self, _extension: &str) -> bool {
+        true
+    }
+
+    fn process(&self, content: &str) -> GeneratorResult<String> {
+        let mut output = String::with_capacity(content.len());
+        let mut blank_run = 0usize;
+
+        for line in content.lines() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                blank_run += 1;
+                if blank_run > 2 || output.is_empty() {
+                    continue;
+                }
+            } else {
+                blank_run = 0;
+            }
+            output.push_str(line);
+            output.push('\n');
+        }
+
+        while output.ends_with("\n\n") {
+            output.pop();
+        }
+        if output.is_empty() {
+            return Ok(String::new());
+        }
+        if !output.ends_with('\n') {
+            output.push('\n');
+        }
+        Ok(output)
+    }
+}
+
+/// Ordered collection of post-processors selected by file extension
+///
+/// Language-specific formatters take precedence; the first one whose
+/// [`OutputPostProcessor::applies_to`] matches is used, and the fallback
+/// whitespace normalizer only runs when no dedicated formatter matched.
+pub struct PostProcessorPipeline {
+    processors: Vec<Arc<dyn OutputPostProcessor>>,
+    fallback: Option<Arc<dyn OutputPostProcessor>>,
+    strict: bool,
+}
+
+impl PostProcessorPipeline {
+    /// Create an empty pipeline
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            processors: Vec::new(),
+            fallback: None,
+            strict: false,
+        }
+    }
+
+    /// Create a pipeline with the built-in formatters registered
+    #[must_use]
+    pub fn with_defaults() -> Self {
+        Self::new()
+            .with_processor(Arc::new(RustFormatter::new()))
+            .with_processor(Arc::new(PythonFormatter::new()))
+            .with_fallback(Arc::new(WhitespaceNormalizer::new()))
+    }
+
+    /// Register a language-specific post-processor
+    #[must_use]
+    pub fn with_processor(mut self, processor: Arc<dyn OutputPostProcessor>) -> Self {
+        self.processors.push(processor);
+        self
+    }
+
+    /// Set the post-processor used when no language-specific one applies
+    #[must_use]
+    pub fn with_fallback(mut self, processor: Arc<dyn OutputPostProcessor>) -> Self {
+        self.fallback = Some(processor);
+        self
+    }
+
+    /// Fail instead of returning the unformatted content when a formatter errors
+    #[must_use]
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Names of the registered post-processors, in precedence order
+    #[must_use]
+    pub fn processor_names(&self) -> Vec<String> {
+        self.processors
+            .iter()
+            .chain(self.fallback.iter())
+            .map(|p| p.name().to_string())
+            .collect()
+    }
+
+    /// Post-process generated content destined for a file with `extension`
+    ///
+    /// In non-strict mode a formatter failure is logged and the original
+    /// content is returned, so a formatter limitation never blocks generation.
+    ///
+    /// # Errors
+    /// Returns the formatter error in strict mode
+    pub fn process(&self, extension: &str, content: &str) -> GeneratorResult<String> {
+        let extension = extension.trim_start_matches('.');
+        let processor = self
+            .processors
+            .iter()
+            .find(|p| p.applies_to(extension))
+            .or_else(|| self.fallback.as_ref().filter(|p| p.applies_to(extension)));
+
+        let Some(processor) = processor else {
+            return Ok(content.to_string());
+        };
+
+        match processor.process(content) {
+            Ok(formatted) => Ok(formatted),
+            Err(e) if !self.strict => {
+                tracing::warn!(
+                    "Formatter '{}' failed for .{} output, keeping unformatted code: {}",
+                    processor.name(),
+                    extension,
+                    e
+                );
+                Ok(content.to_string())
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Default for PostProcessorPipeline {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+/// Format generated content with the default pipeline
+///
+/// # Errors
+/// Never fails in practice because the default pipeline is lenient; the
+/// `Result` is kept for parity with [`PostProcessorPipeline::process`].
+pub fn format_generated_output(extension: &str, content: &str) -> GeneratorResult<String> {
+    PostProcessorPipeline::with_defaults().process(extension, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rust_formatter_normalizes_layout() {
+        let code = "//! Generated\nuse serde::Serialize;\n#[derive(Debug)]\npub struct Person {pub name: String,\n    pub age:Option<i64>}\n";
+        let formatted = RustFormatter::new()
+            .process(code)
+            .expect("valid Rust should format");
+
+        assert!(formatted.contains("pub struct Person {\n    pub name: String,"));
+        assert!(formatted.contains("pub age: Option<i64>,"));
+        assert!(formatted.starts_with("//! Generated"));
+    }
+
+    #[test]
+    fn test_rust_formatter_keeps_header_comments() {
+        let code = "// Generated by linkml\n// Do not edit\nstruct A;\n";
+        let formatted = RustFormatter::new()
+            .process(code)
+            .expect("valid Rust should format");
+
+        assert!(formatted.starts_with("// Generated by linkml\n// Do not edit\n\n"));
+        assert!(formatted.contains("struct A;"));
+    }
+
+    #[test]
+    fn test_rust_formatter_rejects_invalid_code() {
+        let result = RustFormatter::new().process("pub struct Broken {");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_python_formatter_blank_lines() {
+        let code = "from dataclasses import dataclass\n@dataclass\nclass Person:\n    name: str\n\n\n\n    def greet(self):\t\n        return self.name\nclass Other:\n    pass";
+        let formatted = PythonFormatter::new()
+            .process(code)
+            .expect("python formatting should not fail");
+
+        let expected = "from dataclasses import dataclass\n\n\n@dataclass\nclass Person:\n    name: str\n\n    def greet(self):\n        return self.name\n\n\nclass Other:\n    pass\n";
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn test_python_formatter_preserves_docstrings() {
+        let code = "class A:\n    \"\"\"Summary.\n\n\n    Details.\n    \"\"\"\n    x: int\n";
+        let formatted = PythonFormatter::new()
+            .process(code)
+            .expect("python formatting should not fail");
+
+        assert!(formatted.contains("Summary.\n\n\n    Details."));
+    }
+
+    #[test]
+    fn test_python_formatter_nested_definitions() {
+        let code = "def outer():\n    value = 1\n    def inner():\n        return value\n    return inner\nclass A:\n    \"\"\"Doc.\"\"\"\n    x: int = 1\n    def f(self):\n        pass\n";
+        let formatted = PythonFormatter::new()
+            .process(code)
+            .expect("python formatting should not fail");
+
+        let expected = "def outer():\n    value = 1\n\n    def inner():\n        return value\n\n    return inner\n\n\nclass A:\n    \"\"\"Doc.\"\"\"\n\n    x: int = 1\n\n    def f(self):\n        pass\n";
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn test_python_formatter_keeps_comments_with_definitions() {
+        let code = "import os\n# Attached comment\n# spanning two lines\ndef helper():\n    pass\n# Detached comment\n\ndef other():\n    pass\n";
+        let formatted = PythonFormatter::new()
+            .process(code)
+            .expect("python formatting should not fail");
+
+        let expected = "import os\n\n\n# Attached comment\n# spanning two lines\ndef helper():\n    pass\n\n\n# Detached comment\n\n\ndef other():\n    pass\n";
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn test_python_formatter_normalizes_quotes() {
+        let code = "a = 'plain'\nb = 'it\"s'\nc = 'don\\'t'\nd = f'{name!r}'\ne = r'\\d+'\nf = U'text'\ng = '''Doc.'''\nh = \"kept\"  # it's a comment\n";
+        let formatted = PythonFormatter::new()
+            .process(code)
+            .expect("python formatting should not fail");
+
+        let expected = "a = \"plain\"\nb = 'it\"s'\nc = \"don't\"\nd = f\"{name!r}\"\ne = r\"\\d+\"\nf = \"text\"\ng = \"\"\"Doc.\"\"\"\nh = \"kept\"  # it's a comment\n";
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn test_python_formatter_splits_long_lines() {
+        let code = "class Person(BaseModel):\n    name: Optional[str] = Field(None, description=\"The name of the person, as on documents\")\n    alias: Optional[str] = Field(default=None, description=\"Another name of the person, used informally by friends and family\")\n    def rename(self, first_name: str, last_name: str, middle_name: Optional[str] = None) -> None:\n        pass\n";
+        let formatted = PythonFormatter::new()
+            .process(code)
+            .expect("python formatting should not fail");
+
+        let expected = "class Person(BaseModel):\n    name: Optional[str] = Field(\n        None, description=\"The name of the person, as on documents\"\n    )\n    alias: Optional[str] = Field(\n        default=None,\n        description=\"Another name of the person, used informally by friends and family\",\n    )\n\n    def rename(\n        self, first_name: str, last_name: str, middle_name: Optional[str] = None\n    ) -> None:\n        pass\n";
+        assert_eq!(formatted, expected);
+        assert!(formatted.lines().all(|line| line.len() <= 88));
+    }
+
+    #[test]
+    fn test_python_formatter_magic_trailing_comma_and_joining() {
+        let code = "values = [1, 2,]\npoint = (1,)\ncall = make(\n    first, second\n)\nfrom typing import Any, ClassVar, Dict, List, Literal, Optional, Union, TYPE_CHECKING, Annotated\n";
+        let formatted = PythonFormatter::new()
+            .process(code)
+            .expect("python formatting should not fail");
+
+        let expected = "values = [\n    1,\n    2,\n]\npoint = (1,)\ncall = make(first, second)\nfrom typing import (\n    Any,\n    ClassVar,\n    Dict,\n    List,\n    Literal,\n    Optional,\n    Union,\n    TYPE_CHECKING,\n    Annotated,\n)\n";
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn test_whitespace_normalizer() {
+        let formatted = WhitespaceNormalizer::new()
+            .process("\n\na  \n\n\n\n\nb\t\n\n")
+            .expect("whitespace normalization should not fail");
+        assert_eq!(formatted, "a\n\n\nb\n");
+    }
+
+    #[test]
+    fn test_pipeline_selects_by_extension() {
+        let pipeline = PostProcessorPipeline::with_defaults();
+
+        let rust = pipeline
+            .process(".rs", "struct   A ;")
+            .expect("rust formatting should succeed");
+        assert_eq!(rust, "struct A;\n");
+
+        let sql = pipeline
+            .process("sql", "CREATE TABLE a (id INT);   \n\n\n\n")
+            .expect("fallback formatting should succeed");
+        assert_eq!(sql, "CREATE TABLE a (id INT);\n");
+    }
+
+    #[test]
+    fn test_pipeline_lenient_and_strict_failures() {
+        let broken = "fn broken( {";
+
+        let lenient = PostProcessorPipeline::with_defaults()
+            .process("rs", broken)
+            .expect("lenient pipeline keeps original content");
+        assert_eq!(lenient, broken);
+
+        let strict = PostProcessorPipeline::with_defaults()
+            .strict(true)
+            .process("rs", broken);
+        assert!(strict.is_err());
+    }
+}
@@ -0,0 +1,887 @@
+//! Black-compatible layout for generated Python code
+//!
+//! Re-implements the parts of black's algorithm that generated code runs
+//! into: its blank line rules, string prefix and quote normalization, and the
+//! splitting of lines longer than 88 columns at brackets and commas,
+//! including magic trailing commas. Bracketed constructs spread over several
+//! lines are joined when they fit on one. Whitespace between tokens is kept
+//! as generated, and black's splits at operators and its removal of redundant
+//! parentheses are not implemented.
+
+/// Black's default maximum line length
+const LINE_LENGTH: usize = 88;
+
+/// Keywords after which a bracket opens a literal rather than a call
+const KEYWORDS: &[&str] = &[
+    "and", "as", "assert", "await", "del", "elif", "else", "for", "from", "if", "import", "in",
+    "is", "lambda", "not", "or", "return", "while", "with", "yield",
+];
+
+/// Formats Python source the way black would
+pub(super) fn format(content: &str) -> String {
+    let content = normalize_quotes(&content.replace('\t', "    "));
+    let statements = statements(&content);
+    if statements.is_empty() {
+        // Like black, blank files become empty or a single newline
+        return if content.contains('\n') { "\n" } else { "" }.to_string();
+    }
+    let mut output: Vec<String> = Vec::new();
+
+    for (statement, blank_lines) in statements.iter().zip(blank_lines(&statements)) {
+        output.extend(std::iter::repeat_n(String::new(), blank_lines));
+        if let [line] = statement.lines.as_slice() {
+            let depth = indentation(line);
+            split_line(Line::new(depth, &line[depth..]), &mut output);
+        } else {
+            output.extend(statement.lines.iter().cloned());
+        }
+    }
+
+    let mut result = output.join("\n");
+    result.push('\n');
+    result
+}
+
+/// A statement or standalone comment with the blank lines preceding it
+struct Statement {
+    /// Physical lines; more than one only if they could not be joined
+    lines: Vec<String>,
+    /// Blank lines before the statement in the input
+    before: usize,
+}
+
+impl Statement {
+    fn depth(&self) -> usize {
+        indentation(&self.lines[0])
+    }
+
+    fn code(&self) -> &str {
+        self.lines[0].trim_start()
+    }
+
+    fn is_comment(&self) -> bool {
+        self.code().starts_with('#')
+    }
+
+    fn is_decorator(&self) -> bool {
+        self.code().starts_with('@')
+    }
+
+    fn is_def(&self) -> bool {
+        is_def(self.code())
+    }
+
+    fn is_class(&self) -> bool {
+        self.code().starts_with("class ")
+    }
+
+    fn is_import(&self) -> bool {
+        self.code().starts_with("import ") || self.code().starts_with("from ")
+    }
+
+    /// A one-line definition with a `...` body, such as an overload
+    fn is_stub_def(&self) -> bool {
+        self.is_def()
+            && self
+                .lines
+                .last()
+                .is_some_and(|line| line.ends_with(": ..."))
+    }
+
+    fn is_docstring(&self) -> bool {
+        self.code().starts_with("\"\"\"")
+    }
+
+    fn opens_block(&self) -> bool {
+        let last = self.lines.last().map_or("", String::as_str);
+        let code = tokenize(last)
+            .iter()
+            .find(|token| token.kind == Kind::Comment)
+            .map_or(last, |comment| &last[..comment.start]);
+        code.trim_end().ends_with(':')
+    }
+
+    fn keyword(&self) -> &str {
+        self.code()
+            .split([' ', ':', '('])
+            .next()
+            .unwrap_or_default()
+    }
+}
+
+/// Groups physical lines into statements
+///
+/// Lines continued inside brackets are joined when black would put them on
+/// one line; otherwise, or if they contain comments, multi-line strings or
+/// backslash continuations, they are kept as generated.
+fn statements(content: &str) -> Vec<Statement> {
+    let mut statements = Vec::new();
+    let mut lines: Vec<String> = Vec::new();
+    let mut before = 0;
+    let mut joinable = true;
+    let mut state = LexState::default();
+
+    for line in content.lines() {
+        let line = line.trim_end();
+        if lines.is_empty() && line.is_empty() {
+            before += 1;
+            continue;
+        }
+
+        joinable &= state.string.is_none();
+        let has_comment = scan(line, &mut state);
+        let backslash = state.string.is_none() && !has_comment && line.ends_with('\\');
+        joinable &= !has_comment && !backslash;
+        lines.push(line.to_string());
+
+        if state.string.is_none() && state.depth == 0 && !backslash {
+            let lines = std::mem::take(&mut lines);
+            let lines = match join_lines(&lines) {
+                joined if lines.len() > 1 && joinable && fits_on_one_line(&joined) => vec![joined],
+                _ => lines,
+            };
+            statements.push(Statement { lines, before });
+            before = 0;
+            joinable = true;
+        }
+    }
+    if !lines.is_empty() {
+        statements.push(Statement { lines, before });
+    }
+
+    statements
+}
+
+/// Joins the physical lines of a bracketed construct into one line
+fn join_lines(lines: &[String]) -> String {
+    let mut joined = String::new();
+    for line in lines {
+        let part = if joined.is_empty() {
+            line.as_str()
+        } else {
+            line.trim()
+        };
+        if part.is_empty() {
+            continue;
+        }
+        if !joined.is_empty()
+            && !joined.ends_with(['(', '[', '{'])
+            && !part.starts_with([')', ']', '}'])
+        {
+            joined.push(' ');
+        }
+        joined.push_str(part);
+    }
+    joined
+}
+
+/// Whether black puts a bracketed construct on a single line
+fn fits_on_one_line(line: &str) -> bool {
+    let code = line.trim_start();
+    line.chars().count() <= LINE_LENGTH && !has_magic_trailing_comma(code, &tokenize(code))
+}
+
+/// Number of blank lines black puts before each statement
+fn blank_lines(statements: &[Statement]) -> Vec<usize> {
+    let mut blank_lines: Vec<usize> = Vec::with_capacity(statements.len());
+    // Depths of the definitions whose bodies may still be open
+    let mut definitions: Vec<usize> = Vec::new();
+    // First comment of the comment block right before the current statement
+    let mut leading_comment: Option<usize> = None;
+
+    for (index, statement) in statements.iter().enumerate() {
+        let depth = statement.depth();
+        let mut before = statement.before.min(if depth == 0 { 2 } else { 1 });
+
+        let Some(previous) = index.checked_sub(1).map(|previous| &statements[previous]) else {
+            if statement.is_def() || statement.is_class() {
+                definitions.push(depth);
+            }
+            if statement.is_comment() {
+                leading_comment = Some(index);
+            }
+            blank_lines.push(0);
+            continue;
+        };
+
+        // One blank line after the module docstring
+        if index == 1 && previous.is_docstring() && !statement.is_def() && !statement.is_class() {
+            before = 1;
+        }
+        // Leaving the body of a definition
+        while let Some(definition) = definitions.pop_if(|definition| *definition >= depth) {
+            before = if depth > 0 {
+                1
+            } else if definition > 0
+                && statement.opens_block()
+                && !matches!(
+                    statement.keyword(),
+                    "with" | "try" | "for" | "while" | "if" | "match"
+                )
+            {
+                // A dependent clause such as `else:` after a conditional
+                // definition
+                1
+            } else {
+                2
+            };
+        }
+
+        let mut blank = if statement.is_decorator() || statement.is_def() || statement.is_class() {
+            if !statement.is_decorator() {
+                definitions.push(depth);
+            }
+            if previous.is_decorator()
+                || (previous.depth() < depth && (previous.is_def() || previous.is_class()))
+                || (previous.is_stub_def() && statement.before == 0)
+            {
+                0
+            } else if previous.is_comment() && previous.depth() == depth && before == 0 {
+                // Comments directly above a definition stay attached to it;
+                // the definition's blank lines go before the comments
+                if let Some(comment) = leading_comment.filter(|&comment| {
+                    comment > 0
+                        && !statements[comment - 1].is_class()
+                        && !statements[comment - 1].opens_block()
+                        && blank_lines[comment] <= 1
+                }) {
+                    blank_lines[comment] = blank_lines[comment].max(if depth > 0 { 1 } else { 2 });
+                }
+                0
+            } else if depth > 0 {
+                1
+            } else {
+                2
+            }
+        } else if previous.is_import() && !statement.is_import() && depth == previous.depth() {
+            before.max(1)
+        } else if previous.opens_block() {
+            0
+        } else {
+            before
+        };
+
+        // A class docstring is followed by a blank line
+        if index >= 2 && previous.is_docstring() && statements[index - 2].is_class() {
+            blank = blank.max(1);
+        }
+
+        if statement.is_comment() {
+            if !previous.is_decorator() && (leading_comment.is_none() || blank > 0) {
+                leading_comment = Some(index);
+            }
+        } else if !statement.is_decorator() {
+            leading_comment = None;
+        }
+        blank_lines.push(blank);
+    }
+
+    blank_lines
+}
+
+/// Lexer state carried from one physical line to the next
+#[derive(Default)]
+struct LexState {
+    /// Number of open brackets
+    depth: usize,
+    /// Closing delimiter of a string continued on the next line
+    string: Option<&'static str>,
+}
+
+/// Advances `state` over a physical line, returning whether it has a comment
+fn scan(line: &str, state: &mut LexState) -> bool {
+    let bytes = line.as_bytes();
+    let mut index = 0;
+    if let Some(delimiter) = state.string {
+        match find_string_end(line, 0, delimiter) {
+            Some(end) => {
+                state.string = None;
+                index = end;
+            }
+            None => return false,
+        }
+    }
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'#' => return true,
+            b'"' | b'\'' => {
+                let delimiter = delimiter(&line[index..]);
+                if let Some(end) = find_string_end(line, index + delimiter.len(), delimiter) {
+                    index = end;
+                } else {
+                    if delimiter.len() == 3 {
+                        state.string = Some(delimiter);
+                    }
+                    return false;
+                }
+            }
+            b'(' | b'[' | b'{' => {
+                state.depth += 1;
+                index += 1;
+            }
+            b')' | b']' | b'}' => {
+                state.depth = state.depth.saturating_sub(1);
+                index += 1;
+            }
+            _ => index += 1,
+        }
+    }
+    false
+}
+
+/// Opening delimiter of the string literal at the start of `text`
+fn delimiter(text: &str) -> &'static str {
+    if text.starts_with("\"\"\"") {
+        "\"\"\""
+    } else if text.starts_with("'''") {
+        "'''"
+    } else if text.starts_with('"') {
+        "\""
+    } else {
+        "'"
+    }
+}
+
+/// Byte offset just past the closing `delimiter`, searching from `from`
+///
+/// Strings in single quotes end at the end of the line at the latest.
+fn find_string_end(text: &str, from: usize, delimiter: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut index = from;
+    while index < bytes.len() {
+        if bytes[index] == b'\\' {
+            index += 2;
+        } else if bytes[index] == b'\n' && delimiter.len() == 1 {
+            return None;
+        } else if bytes[index..].starts_with(delimiter.as_bytes()) {
+            return Some(index + delimiter.len());
+        } else {
+            index += 1;
+        }
+    }
+    None
+}
+
+/// Byte offset just past the string literal starting at `start`
+fn string_end(text: &str, start: usize) -> usize {
+    let delimiter = delimiter(&text[start..]);
+    find_string_end(text, start + delimiter.len(), delimiter).unwrap_or(text.len())
+}
+
+/// Length of the string prefix (`r`, `b`, `f`, `u` or a pair) ending `text`
+fn string_prefix_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let len = bytes
+        .iter()
+        .rev()
+        .take_while(|byte| b"rRbBfFuU".contains(byte))
+        .count();
+    let identifier = bytes[..bytes.len() - len]
+        .last()
+        .is_some_and(|byte| byte.is_ascii_alphanumeric() || *byte == b'_');
+    if len <= 2 && !identifier { len } else { 0 }
+}
+
+/// Rewrites every string literal with black's prefix and quote preferences
+fn normalize_quotes(content: &str) -> String {
+    let bytes = content.as_bytes();
+    let mut output = String::with_capacity(content.len());
+    let mut copied = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'#' => {
+                index = content[index..]
+                    .find('\n')
+                    .map_or(bytes.len(), |end| index + end);
+            }
+            b'"' | b'\'' => {
+                let delimiter = delimiter(&content[index..]);
+                let Some(end) = find_string_end(content, index + delimiter.len(), delimiter) else {
+                    index += delimiter.len();
+                    continue;
+                };
+                let start = index - string_prefix_len(&content[..index]);
+                output.push_str(&content[copied..start]);
+                output.push_str(&normalize_string(&content[start..end]));
+                copied = end;
+                index = end;
+            }
+            _ => index += 1,
+        }
+    }
+
+    output.push_str(&content[copied..]);
+    output
+}
+
+/// Normalizes one string literal like black's `normalize_string_prefix` and
+/// `normalize_string_quotes`
+///
+/// Double quotes are preferred unless they need more escapes than the
+/// original quotes.
+fn normalize_string(literal: &str) -> String {
+    let quote = literal.find(['"', '\'']).unwrap_or_default();
+    let prefix: String = literal[..quote]
+        .chars()
+        .filter(|c| !matches!(c, 'u' | 'U'))
+        .map(|c| match c {
+            'F' => 'f',
+            'B' => 'b',
+            c => c,
+        })
+        .collect();
+    let literal = &literal[quote..];
+
+    let (original, preferred) = match delimiter(literal) {
+        "\"\"\"" => return format!("{prefix}{literal}"),
+        "'''" => ("'''", "\"\"\""),
+        "\"" => ("\"", "'"),
+        _ => ("'", "\""),
+    };
+    let body = &literal[original.len()..literal.len() - original.len()];
+
+    let (body, mut new_body) = if prefix.contains(['r', 'R']) {
+        // Quotes cannot be escaped in raw strings
+        if body.matches(preferred).count() != body.matches(&format!("\\{preferred}")).count() {
+            return format!("{prefix}{literal}");
+        }
+        (body.to_string(), body.to_string())
+    } else {
+        let body = escape_quote(body, preferred, false);
+        let new_body = escape_quote(&escape_quote(&body, original, false), preferred, true);
+        (body, new_body)
+    };
+    let kept = format!("{prefix}{original}{body}{original}");
+
+    if prefix.contains('f') && has_backslash_in_expression(&new_body) {
+        return kept;
+    }
+    if preferred == "\"\"\"" && new_body.ends_with('"') {
+        new_body.pop();
+        new_body.push_str("\\\"");
+    }
+
+    let escapes = body.matches('\\').count();
+    let new_escapes = new_body.matches('\\').count();
+    if new_escapes > escapes || (new_escapes == escapes && original == "\"") {
+        kept
+    } else {
+        format!("{prefix}{preferred}{new_body}{preferred}")
+    }
+}
+
+/// Escapes, or unescapes, every occurrence of `quote` in a string body
+fn escape_quote(body: &str, quote: &str, escape: bool) -> String {
+    let mut output = String::with_capacity(body.len());
+    let mut rest = body;
+    while let Some(index) = rest.find(quote) {
+        output.push_str(&rest[..index]);
+        let backslashes = output.len() - output.trim_end_matches('\\').len();
+        if escape && backslashes.is_multiple_of(2) {
+            output.push('\\');
+        } else if !escape && !backslashes.is_multiple_of(2) {
+            output.pop();
+        }
+        output.push_str(quote);
+        rest = &rest[index + quote.len()..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Whether a replacement field of an f-string body contains a backslash
+fn has_backslash_in_expression(body: &str) -> bool {
+    let mut rest = body;
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+        if let Some(escaped) = rest.strip_prefix('{') {
+            rest = escaped;
+            continue;
+        }
+        let end = rest.find('}').unwrap_or(rest.len());
+        if rest[..end].contains('\\') {
+            return true;
+        }
+        rest = &rest[end..];
+    }
+    false
+}
+
+/// A line being split, black's `Line`
+struct Line {
+    /// Indentation in columns
+    depth: usize,
+    /// The line without its indentation
+    code: String,
+    /// Whether the line is the content of a split bracket pair
+    inside_brackets: bool,
+    /// Whether the line has to be split at its commas even if it fits
+    explode: bool,
+}
+
+impl Line {
+    fn new(depth: usize, code: &str) -> Self {
+        Self {
+            depth,
+            code: code.to_string(),
+            inside_brackets: false,
+            explode: false,
+        }
+    }
+
+    fn fits(&self, code: &str) -> bool {
+        self.depth + code.chars().count() <= LINE_LENGTH
+    }
+
+    fn render(&self) -> String {
+        format!("{}{}", " ".repeat(self.depth), self.code)
+    }
+}
+
+/// Token kinds that matter for splitting lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Open,
+    Close,
+    Comma,
+    Comment,
+    Other,
+}
+
+/// A token of a line, as a byte range
+#[derive(Debug, Clone, Copy)]
+struct Token {
+    kind: Kind,
+    start: usize,
+    end: usize,
+}
+
+/// Splits a line of code into brackets, commas, a comment and everything
+/// else, keeping string literals whole
+fn tokenize(code: &str) -> Vec<Token> {
+    let bytes = code.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let start = index;
+        let kind = match bytes[index] {
+            b' ' => {
+                index += 1;
+                continue;
+            }
+            b'#' => {
+                index = bytes.len();
+                Kind::Comment
+            }
+            b'(' | b'[' | b'{' => {
+                index += 1;
+                Kind::Open
+            }
+            b')' | b']' | b'}' => {
+                index += 1;
+                Kind::Close
+            }
+            b',' => {
+                index += 1;
+                Kind::Comma
+            }
+            b'"' | b'\'' => {
+                index = string_end(code, index);
+                Kind::Other
+            }
+            _ => {
+                while index < bytes.len() && !b" #()[]{},\"'".contains(&bytes[index]) {
+                    index += 1;
+                }
+                if index < bytes.len()
+                    && matches!(bytes[index], b'"' | b'\'')
+                    && string_prefix_len(&code[..index]) == index - start
+                {
+                    index = string_end(code, index);
+                }
+                Kind::Other
+            }
+        };
+        tokens.push(Token {
+            kind,
+            start,
+            end: index,
+        });
+    }
+
+    tokens
+}
+
+/// Index of the matching closing token for every opening token
+fn matching_brackets(tokens: &[Token]) -> Vec<Option<usize>> {
+    let mut matching = vec![None; tokens.len()];
+    let mut open = Vec::new();
+    for (index, token) in tokens.iter().enumerate() {
+        match token.kind {
+            Kind::Open => open.push(index),
+            Kind::Close => {
+                if let Some(opening) = open.pop() {
+                    matching[opening] = Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    matching
+}
+
+/// The commas at the top level of `tokens`
+fn top_level_commas(tokens: &[Token]) -> Vec<&Token> {
+    let mut depth = 0usize;
+    let mut commas = Vec::new();
+    for token in tokens {
+        match token.kind {
+            Kind::Open => depth += 1,
+            Kind::Close => depth = depth.saturating_sub(1),
+            Kind::Comma if depth == 0 => commas.push(token),
+            _ => {}
+        }
+    }
+    commas
+}
+
+/// Whether the bracket at `open` starts a literal rather than a call,
+/// subscript or parameter list
+fn is_atom(code: &str, tokens: &[Token], open: usize) -> bool {
+    let Some(previous) = open.checked_sub(1).map(|previous| tokens[previous]) else {
+        return true;
+    };
+    match previous.kind {
+        Kind::Close => false,
+        Kind::Other => {
+            let text = &code[previous.start..previous.end];
+            let trailer = text
+                .bytes()
+                .last()
+                .is_some_and(|byte| byte.is_ascii_alphanumeric() || b"_\"'".contains(&byte));
+            !trailer || KEYWORDS.contains(&text)
+        }
+        _ => true,
+    }
+}
+
+/// Whether a bracket in the line closes right after a trailing comma
+///
+/// Like in black, the trailing comma of a one-element tuple or subscript
+/// does not count.
+fn has_magic_trailing_comma(code: &str, tokens: &[Token]) -> bool {
+    let matching = matching_brackets(tokens);
+    matching.iter().enumerate().any(|(open, close)| {
+        let Some(close) = *close else {
+            return false;
+        };
+        if tokens[close - 1].kind != Kind::Comma {
+            return false;
+        }
+        let single = top_level_commas(&tokens[open + 1..close]).len() == 1;
+        match &code[tokens[close].start..tokens[close].end] {
+            ")" => !single || !is_atom(code, tokens, open),
+            "]" => !single || is_atom(code, tokens, open),
+            _ => true,
+        }
+    })
+}
+
+fn is_def(code: &str) -> bool {
+    code.starts_with("def ") || code.starts_with("async def ")
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// Emits `line`, split the way black splits lines that are too long or
+/// have magic trailing commas
+fn split_line(line: Line, output: &mut Vec<String>) {
+    let tokens = tokenize(&line.code);
+    let has_comment = tokens.iter().any(|token| token.kind == Kind::Comment);
+    if has_comment
+        || (line.fits(&line.code)
+            && !line.explode
+            && !has_magic_trailing_comma(&line.code, &tokens))
+    {
+        output.push(line.render());
+        return;
+    }
+
+    let lines = if line.inside_brackets {
+        delimiter_split(&line, &tokens).or_else(|| right_hand_split(&line, &tokens))
+    } else if is_def(&line.code) {
+        left_hand_split(&line, &tokens)
+    } else if let Some(line) = parenthesize_import(&line, &tokens) {
+        right_hand_split(&line, &tokenize(&line.code))
+    } else {
+        right_hand_split(&line, &tokens)
+    };
+
+    match lines {
+        Some(lines) => {
+            for line in lines {
+                split_line(line, output);
+            }
+        }
+        None => output.push(line.render()),
+    }
+}
+
+/// Wraps the names of a `from ... import` without brackets in parentheses
+fn parenthesize_import(line: &Line, tokens: &[Token]) -> Option<Line> {
+    if tokens.iter().any(|token| token.kind == Kind::Open) {
+        return None;
+    }
+    let (module, names) = line.code.strip_prefix("from ")?.split_once(" import ")?;
+    Some(Line::new(
+        line.depth,
+        &format!("from {module} import ({names})"),
+    ))
+}
+
+/// Splits at the last bracket pair, black's `right_hand_split`
+///
+/// Trailing bracket pairs are skipped, as long as they fit, when that makes
+/// the part before the opening bracket fit.
+fn right_hand_split(line: &Line, tokens: &[Token]) -> Option<Vec<Line>> {
+    let matching = matching_brackets(tokens);
+    // Non-empty top-level bracket pairs, last one first
+    let mut pairs = Vec::new();
+    let mut depth = 0usize;
+    for (index, token) in tokens.iter().enumerate().rev() {
+        match token.kind {
+            Kind::Close => depth += 1,
+            Kind::Open => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    if let Some(close) = matching[index].filter(|&close| close > index + 1) {
+                        pairs.push((index, close));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut attempts = Vec::new();
+    if !has_magic_trailing_comma(&line.code, tokens) {
+        attempts.extend(pairs.first().copied());
+    }
+    for (position, &(open, close)) in pairs.iter().enumerate() {
+        if !line.fits(&line.code[tokens[close].start..]) {
+            break;
+        }
+        if position > 0 {
+            attempts.push((open, close));
+        }
+        if tokens[close - 1].kind == Kind::Comma {
+            break;
+        }
+    }
+
+    attempts
+        .into_iter()
+        .map(|(open, close)| bracket_split(line, tokens, open, close))
+        .find(|lines| line.fits(&lines[0].code))
+        .or_else(|| {
+            pairs
+                .first()
+                .map(|&(open, close)| bracket_split(line, tokens, open, close))
+        })
+}
+
+/// Splits a `def` line at its parameters, black's `left_hand_split`
+fn left_hand_split(line: &Line, tokens: &[Token]) -> Option<Vec<Line>> {
+    let matching = matching_brackets(tokens);
+    let mut index = 0;
+    while index < tokens.len() {
+        match matching[index] {
+            Some(close) if close == index + 1 => index = close + 1,
+            Some(close) => return Some(bracket_split(line, tokens, index, close)),
+            None => index += 1,
+        }
+    }
+    None
+}
+
+/// Splits `line` into the head up to the opening bracket at `open`, the
+/// indented bracket contents and the tail from the closing bracket at
+/// `close`, black's `bracket_split_build_line`
+fn bracket_split(line: &Line, tokens: &[Token], open: usize, close: usize) -> Vec<Line> {
+    let code = &line.code;
+    let head = code[..tokens[open].end].trim_end();
+    let tail = &code[tokens[close].start..];
+    let mut body = code[tokens[open].end..tokens[close].start]
+        .trim()
+        .to_string();
+    let body_tokens = &tokens[open + 1..close];
+
+    let is_import = code.starts_with("from ") && code.contains(" import (");
+    let parameters = is_def(code)
+        && tokens.iter().position(|token| token.kind == Kind::Open) == Some(open)
+        && !body_tokens.iter().any(|token| token.kind == Kind::Comma);
+    if (is_import || parameters) && !body.ends_with(',') {
+        body.push(',');
+    }
+
+    // Like black's `should_split_line`: collections, imports and bodies with
+    // a magic trailing comma get one element per line
+    let trailing_comma = body.ends_with(',');
+    let commas = top_level_commas(&tokenize(&body)).len() - usize::from(trailing_comma);
+    let explode = commas > 0 && (trailing_comma || is_import || is_atom(code, tokens, open));
+
+    vec![
+        Line::new(line.depth, head),
+        Line {
+            depth: line.depth + 4,
+            code: body,
+            inside_brackets: true,
+            explode,
+        },
+        Line::new(line.depth, tail),
+    ]
+}
+
+/// Puts every element of a comma-separated bracket body on its own line
+/// with a trailing comma, black's `delimiter_split`
+fn delimiter_split(line: &Line, tokens: &[Token]) -> Option<Vec<Line>> {
+    let code = &line.code;
+    let mut elements = Vec::new();
+    let mut start = 0;
+    for comma in top_level_commas(tokens) {
+        elements.push(code[start..comma.start].trim());
+        start = comma.end;
+    }
+    let last = code[start..].trim();
+    if elements.is_empty() || (elements.len() == 1 && last.is_empty()) {
+        return None;
+    }
+
+    let mut lines: Vec<Line> = elements
+        .iter()
+        .map(|element| Line {
+            inside_brackets: true,
+            ..Line::new(line.depth, &format!("{element},"))
+        })
+        .collect();
+    if !last.is_empty() {
+        // A trailing comma after `*args` or `**kwargs` needs Python 3.5+,
+        // which black only assumes when the file uses newer features
+        let code = if last.starts_with('*') {
+            last.to_string()
+        } else {
+            format!("{last},")
+        };
+        lines.push(Line {
+            inside_brackets: true,
+            ..Line::new(line.depth, &code)
+        });
+    }
+    Some(lines)
+}
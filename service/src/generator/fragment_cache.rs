@@ -0,0 +1,333 @@
+//! Fragment cache for documentation generators
+//!
+//! The HTML and Markdown documentation generators render every class, slot,
+//! type and enum as a separate fragment. With a [`FragmentCache`] attached,
+//! each fragment is keyed by a hash of everything it is rendered from, so
+//! regenerating a large documentation site after editing one class only
+//! re-renders the fragments that depend on that class. A cache created with
+//! [`FragmentCache::with_directory`] keeps fragments on disk between runs;
+//! [`FragmentCache::scoped`] gives each generator and schema its own
+//! subdirectory so several of them can share one cache directory.
+
+use super::traits::GeneratorResult;
+use parking_lot::RwLock;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// File extension of fragments stored on disk
+const FRAGMENT_EXTENSION: &str = "fragment";
+
+/// Hex digits of the schema id hash naming a [`FragmentCache::scoped`] directory
+const SCHEMA_HASH_LEN: usize = 16;
+
+/// Key of a cached fragment
+///
+/// Built from the generator, the element kind and name, and every value the
+/// fragment is rendered from. The crate version is part of the key so
+/// fragments rendered by an older generator are never reused.
+#[derive(Clone)]
+pub struct FragmentKey {
+    hasher: Sha256,
+}
+
+impl FragmentKey {
+    /// Start a key for element `name` of `kind` rendered by `generator`
+    pub fn new(generator: &str, kind: &str, name: &str) -> Self {
+        let mut hasher = Sha256::new();
+        for part in [env!("CARGO_PKG_VERSION"), generator, kind, name] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        Self { hasher }
+    }
+
+    /// Add a value the fragment depends on
+    #[must_use]
+    pub fn with<T: Serialize + ?Sized>(mut self, value: &T) -> Self {
+        // Serializing schema elements cannot fail; an empty part still keeps
+        // the key distinct through the separator
+        if let Ok(bytes) = serde_json::to_vec(value) {
+            self.hasher.update(&bytes);
+        }
+        self.hasher.update([0]);
+        self
+    }
+
+    fn digest(self) -> String {
+        format!("{:x}", self.hasher.finalize())
+    }
+}
+
+/// Hit and miss counts of a [`FragmentCache`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct FragmentCacheStats {
+    /// Fragments served from the cache
+    pub hits: usize,
+    /// Fragments that had to be rendered
+    pub misses: usize,
+    /// Fragments held in memory
+    pub entries: usize,
+}
+
+impl FragmentCacheStats {
+    /// Share of lookups served from the cache (0.0 to 1.0)
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+impl fmt::Display for FragmentCacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate)",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0
+        )
+    }
+}
+
+/// Cache of rendered documentation fragments
+#[derive(Default)]
+pub struct FragmentCache {
+    directory: Option<PathBuf>,
+    entries: RwLock<HashMap<String, String>>,
+    used: RwLock<HashSet<String>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl FragmentCache {
+    /// Create an in-memory cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a cache that also keeps fragments in `directory`
+    ///
+    /// The directory is created when the first fragment is stored.
+    pub fn with_directory(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: Some(directory.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Create a cache for `generator` and the schema `schema_id` in its own
+    /// subdirectory of `directory`
+    ///
+    /// Fragments are kept in `<directory>/<generator>/<schema-hash>/`, so
+    /// [`Self::prune_unused`] only removes fragments this generator wrote for
+    /// this schema, never those of other generators or schemas sharing the
+    /// directory.
+    pub fn scoped(directory: impl AsRef<Path>, generator: &str, schema_id: &str) -> Self {
+        let schema_hash = format!("{:x}", Sha256::digest(schema_id.as_bytes()));
+        Self::with_directory(
+            directory
+                .as_ref()
+                .join(generator)
+                .join(&schema_hash[..SCHEMA_HASH_LEN]),
+        )
+    }
+
+    /// Directory fragments are persisted in, if any
+    pub fn directory(&self) -> Option<&Path> {
+        self.directory.as_deref()
+    }
+
+    /// Return the fragment for `key`, rendering and storing it on a miss
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `render`, or an I/O error if the fragment cannot
+    /// be written to the cache directory
+    pub fn get_or_render(
+        &self,
+        key: FragmentKey,
+        render: impl FnOnce() -> GeneratorResult<String>,
+    ) -> GeneratorResult<String> {
+        let digest = key.digest();
+        self.used.write().insert(digest.clone());
+
+        if let Some(fragment) = self.lookup(&digest) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(fragment);
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let fragment = render()?;
+        if let Some(directory) = &self.directory {
+            std::fs::create_dir_all(directory)?;
+            std::fs::write(fragment_path(directory, &digest), &fragment)?;
+        }
+        self.entries.write().insert(digest, fragment.clone());
+        Ok(fragment)
+    }
+
+    fn lookup(&self, digest: &str) -> Option<String> {
+        if let Some(fragment) = self.entries.read().get(digest) {
+            return Some(fragment.clone());
+        }
+        let directory = self.directory.as_ref()?;
+        let fragment = std::fs::read_to_string(fragment_path(directory, digest)).ok()?;
+        self.entries
+            .write()
+            .insert(digest.to_string(), fragment.clone());
+        Some(fragment)
+    }
+
+    /// Hit and miss counts since creation or the last [`Self::reset_stats`]
+    pub fn stats(&self) -> FragmentCacheStats {
+        FragmentCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.entries.read().len(),
+        }
+    }
+
+    /// Reset the hit and miss counts
+    pub fn reset_stats(&self) {
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+
+    /// Remove fragments not looked up since the cache was created
+    ///
+    /// Call after generating to drop fragments of deleted or changed
+    /// elements from memory and from the cache directory. Returns the number
+    /// of fragments removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the cache directory cannot be read or a stale
+    /// fragment cannot be deleted
+    pub fn prune_unused(&self) -> GeneratorResult<usize> {
+        let used = self.used.read();
+        let mut entries = self.entries.write();
+        let mut removed: HashSet<String> = entries
+            .keys()
+            .filter(|digest| !used.contains(*digest))
+            .cloned()
+            .collect();
+        entries.retain(|digest, _| used.contains(digest));
+
+        if let Some(directory) = &self.directory
+            && directory.is_dir()
+        {
+            for entry in std::fs::read_dir(directory)? {
+                let path = entry?.path();
+                if path.extension().is_none_or(|ext| ext != FRAGMENT_EXTENSION) {
+                    continue;
+                }
+                if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str())
+                    && !used.contains(stem)
+                {
+                    std::fs::remove_file(&path)?;
+                    removed.insert(stem.to_string());
+                }
+            }
+        }
+        Ok(removed.len())
+    }
+}
+
+impl fmt::Debug for FragmentCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FragmentCache")
+            .field("directory", &self.directory)
+            .field("stats", &self.stats())
+            .finish_non_exhaustive()
+    }
+}
+
+fn fragment_path(directory: &Path, digest: &str) -> PathBuf {
+    directory.join(format!("{digest}.{FRAGMENT_EXTENSION}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_changes_with_content_and_disk_cache_survives() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let key = |text: &str| FragmentKey::new("html", "class", "Person").with(text);
+
+        let cache = FragmentCache::with_directory(dir.path());
+        let first = cache.get_or_render(key("v1"), || Ok("<p>v1</p>".to_string()));
+        assert_eq!(first.expect("render v1"), "<p>v1</p>");
+        let again = cache.get_or_render(key("v1"), || panic!("should be cached"));
+        assert_eq!(again.expect("cached v1"), "<p>v1</p>");
+        assert_eq!((cache.stats().hits, cache.stats().misses), (1, 1));
+
+        // A new process sees the stored fragment; the edited element misses
+        let cache = FragmentCache::with_directory(dir.path());
+        cache
+            .get_or_render(key("v1"), || panic!("should be on disk"))
+            .expect("stored v1");
+        cache
+            .get_or_render(key("v2"), || Ok("<p>v2</p>".to_string()))
+            .expect("render v2");
+        assert_eq!(
+            cache.stats().to_string(),
+            "1 hits, 1 misses (50.0% hit rate)"
+        );
+
+        let cache = FragmentCache::with_directory(dir.path());
+        cache
+            .get_or_render(key("v2"), || panic!("should be on disk"))
+            .expect("stored v2");
+        assert_eq!(cache.prune_unused().expect("prune fragments"), 1);
+        assert_eq!(
+            std::fs::read_dir(dir.path())
+                .expect("read cache dir")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_scoped_caches_do_not_prune_each_other() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let render = |cache: &FragmentCache, generator: &str| {
+            cache
+                .get_or_render(FragmentKey::new(generator, "class", "Person"), || {
+                    Ok(generator.to_string())
+                })
+                .expect("render fragment");
+        };
+
+        let html = FragmentCache::scoped(dir.path(), "html", "https://example.org/a");
+        let markdown = FragmentCache::scoped(dir.path(), "markdown", "https://example.org/a");
+        let other_schema = FragmentCache::scoped(dir.path(), "html", "https://example.org/b");
+        for (cache, generator) in [
+            (&html, "html"),
+            (&markdown, "markdown"),
+            (&other_schema, "html"),
+        ] {
+            render(cache, generator);
+        }
+
+        // A later html run for schema a that renders nothing keeps the
+        // fragments of markdown and of schema b
+        let rerun = FragmentCache::scoped(dir.path(), "html", "https://example.org/a");
+        assert_eq!(rerun.directory(), html.directory());
+        assert_eq!(rerun.prune_unused().expect("prune fragments"), 1);
+
+        let markdown = FragmentCache::scoped(dir.path(), "markdown", "https://example.org/a");
+        render(&markdown, "markdown");
+        let other_schema = FragmentCache::scoped(dir.path(), "html", "https://example.org/b");
+        render(&other_schema, "html");
+        assert_eq!((markdown.stats().hits, other_schema.stats().hits), (1, 1));
+    }
+}
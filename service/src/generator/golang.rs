@@ -6,7 +6,7 @@ use super::traits::{Generator, GeneratorError};
 use crate::generator::GeneratorResult;
 use convert_case::{Case, Casing};
 use linkml_core::prelude::*;
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 
 /// Go code generator
@@ -103,7 +103,7 @@ impl GoGenerator {
     /// Generate imports
     fn generate_imports(schema: &SchemaDefinition) -> GeneratorResult<String> {
         let mut output = String::new();
-        let mut imports = HashSet::new();
+        let mut imports = BTreeSet::new();
 
         // Always need fmt for errors
         imports.insert("fmt");
@@ -865,6 +865,7 @@ mod tests {
                     text: "ACTIVE".to_string(),
                     description: Some("Active status".to_string()),
                     meaning: None,
+                    is_a: None,
                 },
                 linkml_core::types::PermissibleValue::Simple("INACTIVE".to_string()),
             ],
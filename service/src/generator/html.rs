@@ -1,9 +1,13 @@
 //! HTML documentation generation for `LinkML` schemas
 
+use super::fragment_cache::{FragmentCache, FragmentKey};
 use super::options::IndentStyle;
 use super::traits::{CodeFormatter, Generator, GeneratorResult};
+use crate::schema_view::search::SearchIndex;
+use crate::schema_view::view::ElementType;
 use linkml_core::prelude::*;
 use std::fmt::Write;
+use std::sync::Arc;
 
 /// HTML documentation generator for `LinkML` schemas
 pub struct HtmlGenerator {
@@ -11,6 +15,10 @@ pub struct HtmlGenerator {
     name: String,
     /// Generator options
     options: super::traits::GeneratorOptions,
+    /// Cache of rendered class, slot and enum fragments
+    fragment_cache: Option<Arc<FragmentCache>>,
+    /// Whether to embed the search index and a search box
+    include_search: bool,
 }
 
 impl HtmlGenerator {
@@ -25,6 +33,8 @@ impl HtmlGenerator {
         Self {
             name: "html".to_string(),
             options: super::traits::GeneratorOptions::default(),
+            fragment_cache: None,
+            include_search: true,
         }
     }
     /// Create a new HTML generator with options
@@ -33,9 +43,63 @@ impl HtmlGenerator {
         Self {
             name: "html".to_string(),
             options,
+            fragment_cache: None,
+            include_search: true,
         }
     }
 
+    /// Reuse rendered element fragments from `cache`
+    #[must_use]
+    pub fn with_fragment_cache(mut self, cache: Arc<FragmentCache>) -> Self {
+        self.fragment_cache = Some(cache);
+        self
+    }
+
+    /// Configure the embedded search box and search index
+    #[must_use]
+    pub fn with_search(mut self, enabled: bool) -> Self {
+        self.include_search = enabled;
+        self
+    }
+
+    /// Search index of the page, with the ids of the class, slot and enum
+    /// sections as anchors
+    #[must_use]
+    pub fn search_index(schema: &SchemaDefinition) -> serde_json::Value {
+        SearchIndex::build(schema).to_docs_index_with(|element_type, name| {
+            let kind = match element_type {
+                ElementType::Class => "class",
+                ElementType::Slot => "slot",
+                ElementType::Enum => "enum",
+                ElementType::Type | ElementType::Subset => return None,
+            };
+            Some(format!("{kind}-{}", Self::to_anchor(name)))
+        })
+    }
+
+    /// Generate the embedded search index and the script searching it
+    fn generate_search(schema: &SchemaDefinition) -> GeneratorResult<String> {
+        let mut output = String::new();
+        // `</` would end the script element early
+        let index = serde_json::to_string(&Self::search_index(schema))
+            .map_err(|e| super::traits::GeneratorError::Generation(e.to_string()))?
+            .replace("</", "<\\/");
+
+        writeln!(
+            &mut output,
+            "    <script type=\"application/json\" id=\"search-index\">{index}</script>"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            &mut output,
+            "    <script>{}</script>",
+            Self::get_search_script()
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+
+        Ok(output)
+    }
+
     /// Generate HTML page header
     fn generate_header(&self, title: &str, schema: &SchemaDefinition) -> GeneratorResult<String> {
         let mut output = String::new();
@@ -68,6 +132,15 @@ impl HtmlGenerator {
         // Navigation
         writeln!(&mut output, "    <nav class=\"sidebar\">")
             .map_err(Self::fmt_error_to_generator_error)?;
+        if self.include_search {
+            writeln!(
+                &mut output,
+                "        <input type=\"search\" id=\"search\" placeholder=\"Search...\" aria-label=\"Search schema\">"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut output, "        <ul id=\"search-results\"></ul>")
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
         writeln!(&mut output, "        <h2>Contents</h2>")
             .map_err(Self::fmt_error_to_generator_error)?;
         writeln!(&mut output, "        <ul>").map_err(Self::fmt_error_to_generator_error)?;
@@ -246,147 +319,17 @@ impl HtmlGenerator {
             .map_err(Self::fmt_error_to_generator_error)?;
 
         for (class_name, class) in &schema.classes {
-            writeln!(
-                &mut output,
-                "            <div id=\"class-{}\" class=\"class\">",
-                Self::to_anchor(class_name)
-            )
-            .map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(
-                &mut output,
-                "                <h3>{}</h3>",
-                self.escape_html(class_name)
-            )
-            .map_err(Self::fmt_error_to_generator_error)?;
-
-            if let Some(desc) = &class.description {
-                writeln!(
-                    &mut output,
-                    "                <p class=\"description\">{}</p>",
-                    self.escape_html(desc)
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            // Class properties
-            writeln!(&mut output, "                <div class=\"properties\">")
-                .map_err(Self::fmt_error_to_generator_error)?;
-
-            if let Some(parent) = &class.is_a {
-                writeln!(&mut output, "                    <p><strong>Inherits from:</strong> <a href=\"#class-{}\">{}</a></p>",
-                    Self::to_anchor(parent),
-                    self.escape_html(parent)
-                ).map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            if !class.mixins.is_empty() {
-                writeln!(
-                    &mut output,
-                    "                    <p><strong>Mixins:</strong> {}</p>",
-                    class
-                        .mixins
-                        .iter()
-                        .map(|m| format!(
-                            "<a href=\"#class-{}\">{}</a>",
-                            Self::to_anchor(m),
-                            self.escape_html(m)
-                        ))
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            if class.abstract_ == Some(true) {
-                writeln!(
-                    &mut output,
-                    "                    <p class=\"badge abstract\">Abstract</p>"
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            // Class slots
-            if !class.slots.is_empty() {
-                writeln!(&mut output, "                    <h4>Slots</h4>")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(&mut output, "                    <table class=\"slots\">")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(&mut output, "                        <thead>")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(&mut output, "                            <tr>")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(&mut output, "                                <th>Name</th>")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(
-                    &mut output,
-                    "                                <th>Range</th>"
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(
-                    &mut output,
-                    "                                <th>Required</th>"
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(
-                    &mut output,
-                    "                                <th>Description</th>"
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(&mut output, "                            </tr>")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(&mut output, "                        </thead>")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(&mut output, "                        <tbody>")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-
-                for slot_name in &class.slots {
-                    if let Some(slot) = schema.slots.get(slot_name) {
-                        writeln!(&mut output, "                            <tr>")
-                            .map_err(Self::fmt_error_to_generator_error)?;
-                        writeln!(
-                            &mut output,
-                            "                                <td><a href=\"#slot-{}\">{}</a></td>",
-                            Self::to_anchor(slot_name),
-                            self.escape_html(slot_name)
-                        )
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                        writeln!(
-                            &mut output,
-                            "                                <td>{}</td>",
-                            self.escape_html(slot.range.as_deref().unwrap_or("string"))
-                        )
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                        writeln!(
-                            &mut output,
-                            "                                <td>{}</td>",
-                            if slot.required == Some(true) {
-                                "✓"
-                            } else {
-                                ""
-                            }
-                        )
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                        writeln!(
-                            &mut output,
-                            "                                <td>{}</td>",
-                            self.escape_html(slot.description.as_deref().unwrap_or(""))
-                        )
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                        writeln!(&mut output, "                            </tr>")
-                            .map_err(Self::fmt_error_to_generator_error)?;
-                    }
-                }
-
-                writeln!(&mut output, "                        </tbody>")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(&mut output, "                    </table>")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            writeln!(&mut output, "                </div>")
-                .map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut output, "            </div>")
-                .map_err(Self::fmt_error_to_generator_error)?;
+            let slots: Vec<_> = class
+                .slots
+                .iter()
+                .map(|name| (name, schema.slots.get(name)))
+                .collect();
+            let key = self
+                .fragment_key("class", class_name)
+                .with(class)
+                .with(&slots);
+            output
+                .push_str(&self.fragment(key, || self.generate_class(class_name, class, schema))?);
         }
 
         writeln!(&mut output, "        </section>").map_err(Self::fmt_error_to_generator_error)?;
@@ -411,186 +354,413 @@ impl HtmlGenerator {
             .map_err(Self::fmt_error_to_generator_error)?;
 
         for (slot_name, slot) in &schema.slots {
+            let key = self.fragment_key("slot", slot_name).with(slot);
+            output.push_str(&self.fragment(key, || self.generate_slot(slot_name, slot))?);
+        }
+
+        writeln!(&mut output, "        </section>").map_err(Self::fmt_error_to_generator_error)?;
+
+        Ok(output)
+    }
+
+    /// Generate enums section
+    fn generate_enums(&self, schema: &SchemaDefinition) -> GeneratorResult<String> {
+        let mut output = String::new();
+
+        if schema.enums.is_empty() {
+            return Ok(output);
+        }
+
+        writeln!(
+            &mut output,
+            "        <section id=\"enums\" class=\"section\">"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "            <h2>Enumerations</h2>")
+            .map_err(Self::fmt_error_to_generator_error)?;
+
+        for (enum_name, enum_def) in &schema.enums {
+            let key = self.fragment_key("enum", enum_name).with(enum_def);
+            output.push_str(&self.fragment(key, || self.generate_enum(enum_name, enum_def))?);
+        }
+
+        writeln!(&mut output, "        </section>").map_err(Self::fmt_error_to_generator_error)?;
+
+        Ok(output)
+    }
+
+    /// Render one class
+    fn generate_class(
+        &self,
+        class_name: &str,
+        class: &ClassDefinition,
+        schema: &SchemaDefinition,
+    ) -> GeneratorResult<String> {
+        let mut output = String::new();
+
+        writeln!(
+            &mut output,
+            "            <div id=\"class-{}\" class=\"class\">",
+            Self::to_anchor(class_name)
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            &mut output,
+            "                <h3>{}</h3>",
+            self.escape_html(class_name)
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+
+        if let Some(desc) = &class.description {
             writeln!(
                 &mut output,
-                "            <div id=\"slot-{}\" class=\"slot\">",
-                Self::to_anchor(slot_name)
+                "                <p class=\"description\">{}</p>",
+                self.escape_html(desc)
             )
             .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        // Class properties
+        writeln!(&mut output, "                <div class=\"properties\">")
+            .map_err(Self::fmt_error_to_generator_error)?;
+
+        if let Some(parent) = &class.is_a {
+            writeln!(&mut output, "                    <p><strong>Inherits from:</strong> <a href=\"#class-{}\">{}</a></p>",
+                Self::to_anchor(parent),
+                self.escape_html(parent)
+            ).map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        if !class.mixins.is_empty() {
             writeln!(
                 &mut output,
-                "                <h3>{}</h3>",
-                self.escape_html(slot_name)
+                "                    <p><strong>Mixins:</strong> {}</p>",
+                class
+                    .mixins
+                    .iter()
+                    .map(|m| format!(
+                        "<a href=\"#class-{}\">{}</a>",
+                        Self::to_anchor(m),
+                        self.escape_html(m)
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
             )
             .map_err(Self::fmt_error_to_generator_error)?;
+        }
 
-            if let Some(desc) = &slot.description {
-                writeln!(
-                    &mut output,
-                    "                <p class=\"description\">{}</p>",
-                    self.escape_html(desc)
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-            }
+        if class.abstract_ == Some(true) {
+            writeln!(
+                &mut output,
+                "                    <p class=\"badge abstract\">Abstract</p>"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
 
-            // Slot properties table
-            writeln!(&mut output, "                <table class=\"properties\">")
+        // Class slots
+        if !class.slots.is_empty() {
+            writeln!(&mut output, "                    <h4>Slots</h4>")
                 .map_err(Self::fmt_error_to_generator_error)?;
-
-            if let Some(range) = &slot.range {
-                writeln!(
-                    &mut output,
-                    "                    <tr><th>Range:</th><td>{}</td></tr>",
-                    self.escape_html(range)
-                )
+            writeln!(&mut output, "                    <table class=\"slots\">")
                 .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            if slot.required == Some(true) {
-                writeln!(
-                    &mut output,
-                    "                    <tr><th>Required:</th><td>Yes</td></tr>"
-                )
+            writeln!(&mut output, "                        <thead>")
                 .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            if slot.multivalued == Some(true) {
-                writeln!(
-                    &mut output,
-                    "                    <tr><th>Multivalued:</th><td>Yes</td></tr>"
-                )
+            writeln!(&mut output, "                            <tr>")
                 .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            if let Some(pattern) = &slot.pattern {
-                writeln!(
-                    &mut output,
-                    "                    <tr><th>Pattern:</th><td><code>{}</code></td></tr>",
-                    self.escape_html(pattern)
-                )
+            writeln!(&mut output, "                                <th>Name</th>")
                 .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            if let Some(minimum) = &slot.minimum_value {
-                writeln!(
-                    &mut output,
-                    "                    <tr><th>Minimum:</th><td>{}</td></tr>",
-                    self.escape_html(&minimum.to_string())
-                )
+            writeln!(
+                &mut output,
+                "                                <th>Range</th>"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(
+                &mut output,
+                "                                <th>Required</th>"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(
+                &mut output,
+                "                                <th>Description</th>"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut output, "                            </tr>")
                 .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            if let Some(maximum) = &slot.maximum_value {
-                writeln!(
-                    &mut output,
-                    "                    <tr><th>Maximum:</th><td>{}</td></tr>",
-                    self.escape_html(&maximum.to_string())
-                )
+            writeln!(&mut output, "                        </thead>")
+                .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut output, "                        <tbody>")
                 .map_err(Self::fmt_error_to_generator_error)?;
+
+            for slot_name in &class.slots {
+                if let Some(slot) = schema.slots.get(slot_name) {
+                    writeln!(&mut output, "                            <tr>")
+                        .map_err(Self::fmt_error_to_generator_error)?;
+                    writeln!(
+                        &mut output,
+                        "                                <td><a href=\"#slot-{}\">{}</a></td>",
+                        Self::to_anchor(slot_name),
+                        self.escape_html(slot_name)
+                    )
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                    writeln!(
+                        &mut output,
+                        "                                <td>{}</td>",
+                        self.escape_html(slot.range.as_deref().unwrap_or("string"))
+                    )
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                    writeln!(
+                        &mut output,
+                        "                                <td>{}</td>",
+                        if slot.required == Some(true) {
+                            "✓"
+                        } else {
+                            ""
+                        }
+                    )
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                    writeln!(
+                        &mut output,
+                        "                                <td>{}</td>",
+                        self.escape_html(slot.description.as_deref().unwrap_or(""))
+                    )
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                    writeln!(&mut output, "                            </tr>")
+                        .map_err(Self::fmt_error_to_generator_error)?;
+                }
             }
 
-            writeln!(&mut output, "                </table>")
+            writeln!(&mut output, "                        </tbody>")
                 .map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut output, "            </div>")
+            writeln!(&mut output, "                    </table>")
                 .map_err(Self::fmt_error_to_generator_error)?;
         }
 
-        writeln!(&mut output, "        </section>").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "                </div>")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "            </div>").map_err(Self::fmt_error_to_generator_error)?;
 
         Ok(output)
     }
 
-    /// Generate enums section
-    fn generate_enums(&self, schema: &SchemaDefinition) -> GeneratorResult<String> {
+    /// Render one slot
+    fn generate_slot(&self, slot_name: &str, slot: &SlotDefinition) -> GeneratorResult<String> {
         let mut output = String::new();
 
-        if schema.enums.is_empty() {
-            return Ok(output);
-        }
-
         writeln!(
             &mut output,
-            "        <section id=\"enums\" class=\"section\">"
+            "            <div id=\"slot-{}\" class=\"slot\">",
+            Self::to_anchor(slot_name)
         )
         .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output, "            <h2>Enumerations</h2>")
+        writeln!(
+            &mut output,
+            "                <h3>{}</h3>",
+            self.escape_html(slot_name)
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+
+        if let Some(desc) = &slot.description {
+            writeln!(
+                &mut output,
+                "                <p class=\"description\">{}</p>",
+                self.escape_html(desc)
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        // Slot properties table
+        writeln!(&mut output, "                <table class=\"properties\">")
             .map_err(Self::fmt_error_to_generator_error)?;
 
-        for (enum_name, enum_def) in &schema.enums {
+        if let Some(range) = &slot.range {
             writeln!(
                 &mut output,
-                "            <div id=\"enum-{}\" class=\"enum\">",
-                Self::to_anchor(enum_name)
+                "                    <tr><th>Range:</th><td>{}</td></tr>",
+                self.escape_html(range)
             )
             .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        if slot.required == Some(true) {
             writeln!(
                 &mut output,
-                "                <h3>{}</h3>",
-                self.escape_html(enum_name)
+                "                    <tr><th>Required:</th><td>Yes</td></tr>"
             )
             .map_err(Self::fmt_error_to_generator_error)?;
+        }
 
-            if let Some(desc) = &enum_def.description {
-                writeln!(
-                    &mut output,
-                    "                <p class=\"description\">{}</p>",
-                    self.escape_html(desc)
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-            }
+        if slot.multivalued == Some(true) {
+            writeln!(
+                &mut output,
+                "                    <tr><th>Multivalued:</th><td>Yes</td></tr>"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
 
-            // Permissible values
-            writeln!(&mut output, "                <h4>Values</h4>")
-                .map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut output, "                <ul class=\"enum-values\">")
-                .map_err(Self::fmt_error_to_generator_error)?;
+        if let Some(pattern) = &slot.pattern {
+            writeln!(
+                &mut output,
+                "                    <tr><th>Pattern:</th><td><code>{}</code></td></tr>",
+                self.escape_html(pattern)
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
 
-            for value in &enum_def.permissible_values {
-                match value {
-                    PermissibleValue::Simple(text) => {
-                        writeln!(
-                            &mut output,
-                            "                    <li><code>{}</code></li>",
-                            self.escape_html(text)
-                        )
+        if let Some(minimum) = &slot.minimum_value {
+            writeln!(
+                &mut output,
+                "                    <tr><th>Minimum:</th><td>{}</td></tr>",
+                self.escape_html(&minimum.to_string())
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        if let Some(maximum) = &slot.maximum_value {
+            writeln!(
+                &mut output,
+                "                    <tr><th>Maximum:</th><td>{}</td></tr>",
+                self.escape_html(&maximum.to_string())
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        writeln!(&mut output, "                </table>")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "            </div>").map_err(Self::fmt_error_to_generator_error)?;
+
+        Ok(output)
+    }
+
+    /// Render one enum
+    fn generate_enum(&self, enum_name: &str, enum_def: &EnumDefinition) -> GeneratorResult<String> {
+        let mut output = String::new();
+
+        writeln!(
+            &mut output,
+            "            <div id=\"enum-{}\" class=\"enum\">",
+            Self::to_anchor(enum_name)
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            &mut output,
+            "                <h3>{}</h3>",
+            self.escape_html(enum_name)
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+
+        if let Some(desc) = &enum_def.description {
+            writeln!(
+                &mut output,
+                "                <p class=\"description\">{}</p>",
+                self.escape_html(desc)
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        // Permissible values
+        writeln!(&mut output, "                <h4>Values</h4>")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "                <ul class=\"enum-values\">")
+            .map_err(Self::fmt_error_to_generator_error)?;
+
+        for value in &enum_def.permissible_values {
+            match value {
+                PermissibleValue::Simple(text) => {
+                    writeln!(
+                        &mut output,
+                        "                    <li><code>{}</code></li>",
+                        self.escape_html(text)
+                    )
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                }
+                PermissibleValue::Complex {
+                    text, description, ..
+                } => {
+                    writeln!(&mut output, "                    <li>")
                         .map_err(Self::fmt_error_to_generator_error)?;
-                    }
-                    PermissibleValue::Complex {
-                        text, description, ..
-                    } => {
-                        writeln!(&mut output, "                    <li>")
-                            .map_err(Self::fmt_error_to_generator_error)?;
+                    writeln!(
+                        &mut output,
+                        "                        <code>{}</code>",
+                        self.escape_html(text)
+                    )
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                    if let Some(desc) = description {
                         writeln!(
                             &mut output,
-                            "                        <code>{}</code>",
-                            self.escape_html(text)
+                            "                        <span class=\"value-desc\"> - {}</span>",
+                            self.escape_html(desc)
                         )
                         .map_err(Self::fmt_error_to_generator_error)?;
-                        if let Some(desc) = description {
-                            writeln!(
-                                &mut output,
-                                "                        <span class=\"value-desc\"> - {}</span>",
-                                self.escape_html(desc)
-                            )
-                            .map_err(Self::fmt_error_to_generator_error)?;
-                        }
-                        writeln!(&mut output, "                    </li>")
-                            .map_err(Self::fmt_error_to_generator_error)?;
                     }
+                    writeln!(&mut output, "                    </li>")
+                        .map_err(Self::fmt_error_to_generator_error)?;
                 }
             }
-
-            writeln!(&mut output, "                </ul>")
-                .map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut output, "            </div>")
-                .map_err(Self::fmt_error_to_generator_error)?;
         }
 
-        writeln!(&mut output, "        </section>").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "                </ul>")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "            </div>").map_err(Self::fmt_error_to_generator_error)?;
 
         Ok(output)
     }
 
+    /// Start the cache key of an element fragment
+    fn fragment_key(&self, kind: &str, name: &str) -> FragmentKey {
+        FragmentKey::new(&self.name, kind, name).with(&self.options.custom.get("strict_escaping"))
+    }
+
+    /// Render a fragment through the fragment cache, if one is attached
+    fn fragment(
+        &self,
+        key: FragmentKey,
+        render: impl FnOnce() -> GeneratorResult<String>,
+    ) -> GeneratorResult<String> {
+        match &self.fragment_cache {
+            Some(cache) => cache.get_or_render(key, render),
+            None => render(),
+        }
+    }
+
+    /// Get the script filtering the embedded search index as the user types
+    ///
+    /// Names rank above titles and aliases, which rank above descriptions
+    /// and mappings.
+    fn get_search_script() -> &'static str {
+        r##"
+        (function () {
+            const index = JSON.parse(document.getElementById("search-index").textContent);
+            const input = document.getElementById("search");
+            const results = document.getElementById("search-results");
+            const rank = (doc, query) => {
+                const has = (text) => text && text.toLowerCase().includes(query);
+                if (has(doc.name)) return 3;
+                if (has(doc.title) || doc.aliases.some(has)) return 2;
+                if (has(doc.description) || doc.mappings.some(has)) return 1;
+                return 0;
+            };
+            input.addEventListener("input", () => {
+                const query = input.value.trim().toLowerCase();
+                results.replaceChildren();
+                if (!query) return;
+                index.documents
+                    .map((doc) => [rank(doc, query), doc])
+                    .filter(([score]) => score > 0)
+                    .sort((a, b) => b[0] - a[0] || a[1].name.localeCompare(b[1].name))
+                    .slice(0, 20)
+                    .forEach(([, doc]) => {
+                        const link = document.createElement("a");
+                        link.href = "#" + doc.id;
+                        link.textContent = doc.name + " (" + doc.type + ")";
+                        const item = document.createElement("li");
+                        item.appendChild(link);
+                        results.appendChild(item);
+                    });
+            });
+        })();
+        "##
+    }
+
     /// Get embedded CSS styles
     fn get_css() -> &'static str {
         r#"
@@ -613,6 +783,13 @@ impl HtmlGenerator {
             overflow-y: auto;
         }
 
+        .sidebar input[type="search"] {
+            width: 100%;
+            box-sizing: border-box;
+            padding: 6px;
+            margin-bottom: 10px;
+        }
+
         .sidebar h2 {
             margin-top: 0;
             font-size: 1.2rem;
@@ -870,6 +1047,9 @@ impl Generator for HtmlGenerator {
         // Add types section if implemented
         // output.push_str(&self.generate_types(schema)?);
 
+        if self.include_search {
+            output.push_str(&Self::generate_search(schema)?);
+        }
         output.push_str(&Self::generate_footer()?);
 
         // Return the generated HTML content
@@ -961,7 +1141,7 @@ impl CodeFormatter for HtmlGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use linkml_core::types::{ClassDefinition, SchemaDefinition};
+    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
 
     #[tokio::test]
     async fn test_html_generation() -> anyhow::Result<()> {
@@ -997,6 +1177,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_search_index_links_to_sections() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition {
+            id: "test".to_string(),
+            name: "test".to_string(),
+            ..Default::default()
+        };
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                description: Some("A person </script> with a name".to_string()),
+                ..Default::default()
+            },
+        );
+        schema
+            .slots
+            .insert("birth_date".to_string(), SlotDefinition::default());
+
+        let index = HtmlGenerator::search_index(&schema);
+        let ids: Vec<&str> = index["documents"]
+            .as_array()
+            .expect("documents array")
+            .iter()
+            .filter_map(|document| document["id"].as_str())
+            .collect();
+        assert_eq!(ids, vec!["class-person", "slot-birth-date"]);
+
+        let html = HtmlGenerator::new().generate(&schema)?;
+        assert!(html.contains("id=\"search-index\""));
+        assert!(html.contains("A person <\\/script> with a name"));
+        assert!(html.contains("id=\"slot-birth-date\""));
+
+        let html = HtmlGenerator::new().with_search(false).generate(&schema)?;
+        assert!(!html.contains("search-index"));
+        Ok(())
+    }
+
     #[test]
     fn test_html_escaping() {
         let generator = HtmlGenerator::new();
@@ -1018,4 +1235,48 @@ mod tests {
         assert_eq!(HtmlGenerator::to_anchor("test_class"), "test-class");
         assert_eq!(HtmlGenerator::to_anchor("Test123!@#"), "test123");
     }
+
+    #[test]
+    fn test_fragment_cache_rerenders_only_changed_elements() {
+        let mut schema = SchemaDefinition {
+            id: "test".to_string(),
+            name: "people".to_string(),
+            ..Default::default()
+        };
+        for name in ["Person", "Address", "Company"] {
+            schema.classes.insert(
+                name.to_string(),
+                ClassDefinition {
+                    name: name.to_string(),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let cache = Arc::new(FragmentCache::new());
+        let generator = HtmlGenerator::new().with_fragment_cache(Arc::clone(&cache));
+        let first = generator.generate(&schema).expect("should generate HTML");
+        assert_eq!(cache.stats().misses, 3);
+
+        cache.reset_stats();
+        assert_eq!(
+            generator.generate(&schema).expect("should generate HTML"),
+            first
+        );
+        assert_eq!((cache.stats().hits, cache.stats().misses), (3, 0));
+
+        cache.reset_stats();
+        if let Some(person) = schema.classes.get_mut("Person") {
+            person.description = Some("Edited".to_string());
+        }
+        let edited = generator.generate(&schema).expect("should generate HTML");
+        assert!(edited.contains("Edited"));
+        assert_eq!((cache.stats().hits, cache.stats().misses), (2, 1));
+        assert_eq!(
+            edited,
+            HtmlGenerator::new()
+                .generate(&schema)
+                .expect("should generate HTML")
+        );
+    }
 }
@@ -1,6 +1,8 @@
 //! Trait implementations for the `RustGenerator`
 
 use super::core::RustGenerator;
+use super::example_tests::{self, ExampleCase};
+use super::rust_ast;
 use super::traits::{AsyncGenerator, GeneratedOutput, GeneratorOptions, GeneratorResult};
 use async_trait::async_trait;
 
@@ -84,9 +86,22 @@ impl AsyncGenerator for RustGenerator {
         }
 
         // Generate classes
+        let validate = options
+            .get_custom(rust_ast::VALIDATE_OPTION)
+            .is_some_and(|value| value == "true");
         for (class_name, class) in &schema.classes {
             let class_code = Self::generate_class_rust(class_name, class, schema, options, indent)?;
             main_output.push_str(&class_code);
+            if validate {
+                let item = self.validate_impl_item(class_name, class, schema)?;
+                main_output.push_str(&rust_ast::render_items(vec![item]));
+            }
+        }
+
+        // Extra derives from configuration are applied on the parsed AST
+        if let Some(spec) = options.get_custom(rust_ast::DERIVES_OPTION) {
+            main_output =
+                rust_ast::add_derives_to_source(&main_output, &rust_ast::parse_derives(spec)?)?;
         }
 
         // Create output
@@ -174,7 +189,7 @@ impl RustGenerator {
         output: &mut String,
         class_name: &str,
         _class: &ClassDefinition,
-        _schema: &SchemaDefinition,
+        schema: &SchemaDefinition,
         _options: &GeneratorOptions,
     ) -> GeneratorResult<()> {
         let struct_name = super::base::BaseCodeFormatter::to_pascal_case(class_name);
@@ -214,6 +229,57 @@ impl RustGenerator {
         writeln!(output, "    }}").map_err(Self::fmt_error_to_generator_error)?;
         writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
 
+        // Schema examples must validate, counterexamples must not
+        for case in example_tests::class_cases(schema, class_name)? {
+            Self::generate_example_test(output, &struct_name, &case)?;
+        }
+
         Ok(())
     }
+
+    /// Generate a test asserting that an example is accepted or rejected
+    fn generate_example_test(
+        output: &mut String,
+        struct_name: &str,
+        case: &ExampleCase,
+    ) -> GeneratorResult<()> {
+        let json = raw_string_literal(&case.data.to_string());
+        let description = case.description.replace(['\n', '\r'], " ");
+        writeln!(output, "    #[test]").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "    fn test_{}() {{", case.test_name())
+            .map_err(Self::fmt_error_to_generator_error)?;
+        if case.valid {
+            writeln!(output, "        // {description}")
+                .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(
+                output,
+                "        let instance = {struct_name}::from_json({json}).expect(\"Example should deserialize\");"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(output, "        assert!(instance.validate().is_ok());")
+                .map_err(Self::fmt_error_to_generator_error)?;
+        } else {
+            writeln!(output, "        // Counterexample: {description}")
+                .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(
+                output,
+                "        let rejected = {struct_name}::from_json({json}).map_or(true, |instance| instance.validate().is_err());"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(output, "        assert!(rejected);")
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        writeln!(output, "    }}").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+        Ok(())
+    }
+}
+
+/// Raw string literal for `text`, with enough `#`s to contain it
+fn raw_string_literal(text: &str) -> String {
+    let mut hashes = "#".to_string();
+    while text.contains(&format!("\"{hashes}")) {
+        hashes.push('#');
+    }
+    format!("r{hashes}\"{text}\"{hashes}")
 }
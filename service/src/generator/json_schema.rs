@@ -4,7 +4,7 @@ use super::options::IndentStyle;
 use super::traits::{CodeFormatter, Generator, GeneratorError, GeneratorResult};
 use linkml_core::prelude::*;
 use serde_json::{Value as JsonValue, json};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// `JSON` Schema generator for `LinkML` schemas
 pub struct JsonSchemaGenerator {
@@ -38,7 +38,7 @@ impl JsonSchemaGenerator {
         class_name: &str,
         class: &ClassDefinition,
         schema: &SchemaDefinition,
-        definitions: &mut HashMap<String, JsonValue>,
+        definitions: &mut BTreeMap<String, JsonValue>,
     ) -> GeneratorResult<JsonValue> {
         let mut properties = serde_json::Map::new();
         let mut required = Vec::new();
@@ -196,7 +196,7 @@ impl JsonSchemaGenerator {
     fn generate_enum_schema(
         enum_name: &str,
         enum_def: &EnumDefinition,
-        definitions: &mut HashMap<String, JsonValue>,
+        definitions: &mut BTreeMap<String, JsonValue>,
     ) {
         let values: Vec<String> = enum_def
             .permissible_values
@@ -226,7 +226,7 @@ impl JsonSchemaGenerator {
         type_name: &str,
         type_def: &TypeDefinition,
         schema: &SchemaDefinition,
-        definitions: &mut HashMap<String, JsonValue>,
+        definitions: &mut BTreeMap<String, JsonValue>,
     ) -> GeneratorResult<()> {
         let base_schema = self.get_base_type_schema(type_def.base_type.as_ref(), schema)?;
 
@@ -253,8 +253,21 @@ impl JsonSchemaGenerator {
         Ok(())
     }
 
+    /// Classes the generated schema validates documents as
+    pub(crate) fn root_classes(schema: &SchemaDefinition) -> Vec<String> {
+        schema
+            .classes
+            .iter()
+            .filter(|(_, class)| {
+                class.tree_root == Some(true)
+                    || (class.is_a.is_none() && class.abstract_ != Some(true))
+            })
+            .map(|(class_name, _)| class_name.clone())
+            .collect()
+    }
+
     /// Collect all slots including inherited ones
-    fn collect_all_slots(
+    pub(crate) fn collect_all_slots(
         class: &ClassDefinition,
         schema: &SchemaDefinition,
     ) -> GeneratorResult<Vec<String>> {
@@ -317,7 +330,7 @@ impl Generator for JsonSchemaGenerator {
         // Validate schema
         self.validate_schema(schema)?;
 
-        let mut definitions = HashMap::new();
+        let mut definitions = BTreeMap::new();
 
         // Generate enum definitions
         for (enum_name, enum_def) in &schema.enums {
@@ -330,17 +343,10 @@ impl Generator for JsonSchemaGenerator {
         }
 
         // Generate class definitions
-        let mut root_classes = Vec::new();
         for (class_name, class) in &schema.classes {
             self.generate_class_schema(class_name, class, schema, &mut definitions)?;
-
-            // Track root classes
-            if class.tree_root == Some(true)
-                || (class.is_a.is_none() && class.abstract_ != Some(true))
-            {
-                root_classes.push(class_name.clone());
-            }
         }
+        let root_classes = Self::root_classes(schema);
 
         // Build the main schema
         let mut json_schema = json!({
@@ -382,6 +388,52 @@ impl Generator for JsonSchemaGenerator {
     }
 }
 
+/// Generator of the mapping from generated `JSON` Schema back to classes and
+/// slots
+///
+/// Clients validating with the `JSON` Schema of [`JsonSchemaGenerator`] use
+/// the mapping to report errors in `LinkML` terms, see
+/// [`JsonSchemaMapping`](crate::validator::JsonSchemaMapping).
+#[derive(Debug, Default)]
+pub struct JsonSchemaMappingGenerator;
+
+impl JsonSchemaMappingGenerator {
+    /// Create a new mapping generator
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Generator for JsonSchemaMappingGenerator {
+    fn name(&self) -> &'static str {
+        "json-schema-mapping"
+    }
+
+    fn description(&self) -> &'static str {
+        "Generate the mapping of JSON Schema errors back to LinkML classes and slots"
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> linkml_core::error::Result<()> {
+        JsonSchemaGenerator::new().validate_schema(schema)
+    }
+
+    fn generate(&self, schema: &SchemaDefinition) -> std::result::Result<String, LinkMLError> {
+        self.validate_schema(schema)?;
+        let mapping = crate::validator::JsonSchemaMapping::from_schema(schema)?;
+        serde_json::to_string_pretty(&mapping)
+            .map_err(|e| LinkMLError::service(format!("JSON formatting error: {e}")))
+    }
+
+    fn get_file_extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn get_default_filename(&self) -> &'static str {
+        "schema.mapping"
+    }
+}
+
 impl CodeFormatter for JsonSchemaGenerator {
     fn name(&self) -> &'static str {
         "jsonschema"
@@ -0,0 +1,276 @@
+//! Size and depth guards for generated artifacts
+//!
+//! Pathological schemas, whether adversarial or accidental, can make
+//! generators run for minutes and write multi-gigabyte files: an `is_a`
+//! chain thousands of classes deep is expanded into every descendant, and
+//! an enum with hundreds of thousands of values is emitted once per use.
+//! [`GenerationLimits`] rejects such schemas before generation and
+//! oversized or slow output after it, with a diagnostic naming the limit,
+//! instead of handing the artifact on. [`GuardedGenerator`] applies the
+//! limits to any [`Generator`].
+//!
+//! The limits come from the `security_limits` section of the service
+//! configuration.
+
+use super::traits::Generator;
+use crate::config::SecurityLimits;
+use linkml_core::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Bounds on the schemas a generator accepts and the artifacts it produces
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenerationLimits {
+    /// Maximum number of ancestors on the longest `is_a`/mixin chain of a class
+    pub max_inheritance_depth: usize,
+    /// Maximum number of permissible values of an enum
+    pub max_enum_values: usize,
+    /// Maximum size of a generated artifact in bytes
+    pub max_output_bytes: u64,
+    /// Maximum time a generator may take
+    pub max_duration: Duration,
+}
+
+impl Default for GenerationLimits {
+    fn default() -> Self {
+        Self {
+            max_inheritance_depth: 100,
+            max_enum_values: 50_000,
+            max_output_bytes: 256 * 1024 * 1024,
+            max_duration: Duration::from_secs(120),
+        }
+    }
+}
+
+impl From<&SecurityLimits> for GenerationLimits {
+    fn from(limits: &SecurityLimits) -> Self {
+        Self {
+            max_inheritance_depth: limits.max_generation_inheritance_depth,
+            max_enum_values: limits.max_generation_enum_values,
+            max_output_bytes: limits.max_generated_output_bytes,
+            max_duration: Duration::from_millis(limits.max_generation_time_ms),
+        }
+    }
+}
+
+impl GenerationLimits {
+    /// Limits of the global service configuration
+    #[must_use]
+    pub fn from_config() -> Self {
+        Self::from(&crate::config::get_config().security_limits)
+    }
+
+    /// Check that a schema is within the limits before generating from it
+    ///
+    /// # Errors
+    ///
+    /// Returns a schema validation error naming the first class or enum that
+    /// exceeds a limit.
+    pub fn check_schema(&self, schema: &SchemaDefinition) -> Result<()> {
+        if let Some((name, enum_def)) = schema
+            .enums
+            .iter()
+            .find(|(_, enum_def)| enum_def.permissible_values.len() > self.max_enum_values)
+        {
+            return Err(LinkMLError::schema_validation(format!(
+                "Enum '{name}' has {} permissible values, more than the generation limit of {} \
+                 (security_limits.max_generation_enum_values)",
+                enum_def.permissible_values.len(),
+                self.max_enum_values
+            )));
+        }
+
+        let mut depths = HashMap::new();
+        for name in schema.classes.keys() {
+            let depth = self.inheritance_depth(schema, name, &mut depths, &mut Vec::new());
+            if depth > self.max_inheritance_depth {
+                return Err(LinkMLError::schema_validation(format!(
+                    "Class '{name}' has an inheritance depth of at least {depth}, more than the \
+                     generation limit of {} (security_limits.max_generation_inheritance_depth)",
+                    self.max_inheritance_depth
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a generated artifact and the time it took against the limits
+    ///
+    /// # Errors
+    ///
+    /// Returns a service error if the artifact is too large or generation
+    /// took too long.
+    pub fn check_output(&self, generator: &str, output: &str, elapsed: Duration) -> Result<()> {
+        let size = output.len() as u64;
+        if size > self.max_output_bytes {
+            return Err(LinkMLError::service(format!(
+                "Generator '{generator}' produced {size} bytes, more than the limit of {} bytes \
+                 (security_limits.max_generated_output_bytes)",
+                self.max_output_bytes
+            )));
+        }
+        if elapsed > self.max_duration {
+            return Err(LinkMLError::service(format!(
+                "Generator '{generator}' took {} ms, more than the limit of {} ms \
+                 (security_limits.max_generation_time_ms)",
+                elapsed.as_millis(),
+                self.max_duration.as_millis()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Number of ancestors on the longest `is_a`/mixin chain of a class
+    ///
+    /// The walk stops once it is deeper than the limit, so the result is
+    /// exact up to the limit and a lower bound beyond it. Cycles are
+    /// reported by schema validation and are cut here.
+    fn inheritance_depth<'a>(
+        &self,
+        schema: &'a SchemaDefinition,
+        class_name: &'a str,
+        depths: &mut HashMap<&'a str, usize>,
+        path: &mut Vec<&'a str>,
+    ) -> usize {
+        if let Some(depth) = depths.get(class_name) {
+            return *depth;
+        }
+        let Some(class) = schema.classes.get(class_name) else {
+            return 0;
+        };
+        if path.contains(&class_name) || path.len() > self.max_inheritance_depth {
+            return 0;
+        }
+
+        path.push(class_name);
+        let depth = class
+            .is_a
+            .iter()
+            .chain(&class.mixins)
+            .filter(|parent| schema.classes.contains_key(*parent))
+            .map(|parent| self.inheritance_depth(schema, parent, depths, path) + 1)
+            .max()
+            .unwrap_or(0);
+        path.pop();
+
+        depths.insert(class_name, depth);
+        depth
+    }
+}
+
+/// Generator that enforces [`GenerationLimits`] around another generator
+pub struct GuardedGenerator {
+    inner: Arc<dyn Generator>,
+    limits: GenerationLimits,
+}
+
+impl GuardedGenerator {
+    /// Guard a generator with the given limits
+    #[must_use]
+    pub fn new(inner: Arc<dyn Generator>, limits: GenerationLimits) -> Self {
+        Self { inner, limits }
+    }
+
+    /// The limits enforced
+    #[must_use]
+    pub fn limits(&self) -> &GenerationLimits {
+        &self.limits
+    }
+}
+
+impl Generator for GuardedGenerator {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn generate(&self, schema: &SchemaDefinition) -> Result<String> {
+        self.limits.check_schema(schema)?;
+        let start = Instant::now();
+        let output = self.inner.generate(schema)?;
+        self.limits
+            .check_output(self.inner.name(), &output, start.elapsed())?;
+        Ok(output)
+    }
+
+    fn get_file_extension(&self) -> &str {
+        self.inner.get_file_extension()
+    }
+
+    fn file_extensions(&self) -> Vec<&str> {
+        self.inner.file_extensions()
+    }
+
+    fn get_default_filename(&self) -> &str {
+        self.inner.get_default_filename()
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> Result<()> {
+        self.inner.validate_schema(schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(length: usize) -> SchemaDefinition {
+        let mut schema = SchemaDefinition::default();
+        for index in 0..length {
+            let class = ClassDefinition {
+                is_a: index.checked_sub(1).map(|parent| format!("C{parent}")),
+                ..Default::default()
+            };
+            schema.classes.insert(format!("C{index}"), class);
+        }
+        schema
+    }
+
+    #[test]
+    fn test_generation_limits() {
+        let limits = GenerationLimits {
+            max_inheritance_depth: 10,
+            max_enum_values: 3,
+            max_output_bytes: 8,
+            max_duration: Duration::from_secs(1),
+        };
+        assert!(limits.check_schema(&chain(11)).is_ok());
+        let error = limits
+            .check_schema(&chain(12))
+            .expect_err("inheritance too deep")
+            .to_string();
+        assert!(error.contains("'C11'") && error.contains("max_generation_inheritance_depth"));
+
+        let mut cyclic = chain(3);
+        cyclic.classes.get_mut("C0").expect("C0 class").is_a = Some("C2".to_string());
+        assert!(limits.check_schema(&cyclic).is_ok());
+
+        let mut schema = chain(1);
+        schema.enums.insert(
+            "Big".to_string(),
+            EnumDefinition {
+                permissible_values: (0..4)
+                    .map(|value| PermissibleValue::Simple(value.to_string()))
+                    .collect(),
+                ..Default::default()
+            },
+        );
+        assert!(limits.check_schema(&schema).is_err());
+
+        assert!(limits.check_output("g", "12345678", Duration::ZERO).is_ok());
+        assert!(
+            limits
+                .check_output("g", "123456789", Duration::ZERO)
+                .is_err()
+        );
+        assert!(
+            limits
+                .check_output("g", "", Duration::from_secs(2))
+                .is_err()
+        );
+    }
+}
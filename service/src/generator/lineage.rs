@@ -0,0 +1,119 @@
+//! Slot-level data lineage from schema annotations
+//!
+//! Where the data of a slot comes from is recorded once, on the slot:
+//!
+//! ```yaml
+//! slots:
+//!   revenue:
+//!     annotations:
+//!       lineage:source_system: erp
+//!       lineage:source_field: GL_ENTRY.AMOUNT
+//!       lineage:transformation: Converted to EUR at the booking date rate
+//! ```
+//!
+//! Generators carry it into the metadata of their output: the Markdown
+//! documentation lists it per class, `OpenAPI` properties get an
+//! `x-lineage` extension, dbt columns a `meta.lineage` entry and Spark fields
+//! a `lineage` metadata entry. Annotations in `slot_usage` refine the lineage
+//! of a slot for one class. LinkML's `{tag, value}` annotation form is
+//! unwrapped.
+
+use linkml_core::annotations::{Annotatable, AnnotationValue};
+use serde::Serialize;
+use serde_json::Value;
+
+/// System the data of a slot originates from
+pub const SOURCE_SYSTEM: &str = "lineage:source_system";
+
+/// Field, column or path of the data in the source system
+pub const SOURCE_FIELD: &str = "lineage:source_field";
+
+/// How the source data is transformed into the slot value
+pub const TRANSFORMATION: &str = "lineage:transformation";
+
+/// Lineage of a slot
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SlotLineage {
+    /// System the data originates from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_system: Option<String>,
+    /// Field, column or path in the source system
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_field: Option<String>,
+    /// Transformation applied to the source data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transformation: Option<String>,
+}
+
+impl SlotLineage {
+    /// Lineage annotated on an element, or `None` if it has none
+    pub fn of(element: &impl Annotatable) -> Option<Self> {
+        let lineage = Self {
+            source_system: text(element, SOURCE_SYSTEM),
+            source_field: text(element, SOURCE_FIELD),
+            transformation: text(element, TRANSFORMATION),
+        };
+        (lineage != Self::default()).then_some(lineage)
+    }
+
+    /// Lineage as a JSON object with the keys that are set
+    #[must_use]
+    pub fn to_json(&self) -> Value {
+        serde_json::to_value(self).unwrap_or(Value::Null)
+    }
+}
+
+/// Text of a scalar annotation
+fn text(element: &impl Annotatable, key: &str) -> Option<String> {
+    fn scalar(value: &AnnotationValue) -> Option<String> {
+        match value {
+            AnnotationValue::String(text) => Some(text.trim().to_string()),
+            AnnotationValue::Number(number) => Some(number.to_string()),
+            AnnotationValue::Bool(flag) => Some(flag.to_string()),
+            AnnotationValue::Object(map) => map.get("value").and_then(scalar),
+            AnnotationValue::Array(_) | AnnotationValue::Null => None,
+        }
+    }
+    element
+        .get_annotation(key)
+        .and_then(scalar)
+        .filter(|text| !text.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::SlotDefinition;
+    use serde_json::json;
+
+    #[test]
+    fn test_slot_lineage() {
+        let mut slot = SlotDefinition::default();
+        assert_eq!(SlotLineage::of(&slot), None);
+
+        slot.annotations = Some(
+            [
+                (SOURCE_SYSTEM.to_string(), AnnotationValue::from("erp")),
+                (
+                    TRANSFORMATION.to_string(),
+                    AnnotationValue::Object(
+                        [
+                            ("tag".to_string(), AnnotationValue::from(TRANSFORMATION)),
+                            ("value".to_string(), AnnotationValue::from("Sum, in EUR")),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    ),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let lineage = SlotLineage::of(&slot).expect("lineage");
+        assert_eq!(lineage.source_system.as_deref(), Some("erp"));
+        assert_eq!(
+            lineage.to_json(),
+            json!({"source_system": "erp", "transformation": "Sum, in EUR"})
+        );
+    }
+}
@@ -3,21 +3,32 @@
 //! This generator creates comprehensive Markdown documentation from `LinkML` schemas,
 //! including class hierarchies, slot tables, enumerations, and cross-references.
 
+use super::base::apply_slot_usage;
+use super::fragment_cache::{FragmentCache, FragmentKey};
+use super::lineage::SlotLineage;
+use super::mermaid::MermaidGenerator;
 use super::traits::Generator;
+use crate::schema_view::search::SearchIndex;
+use crate::schema_view::view::ElementType;
 use linkml_core::{error::LinkMLError, prelude::*};
 use std::collections::BTreeMap;
 use std::fmt::Write;
+use std::sync::Arc;
 
 /// Markdown documentation generator
 pub struct MarkdownGenerator {
     /// Whether to include table of contents
     include_toc: bool,
-    /// Whether to include inheritance diagrams
+    /// Whether to include per-class neighborhood diagrams
     include_diagrams: bool,
+    /// Relation depth of the per-class neighborhood diagrams
+    diagram_depth: usize,
     /// Whether to include examples
     include_examples: bool,
     /// Generator options
     options: super::traits::GeneratorOptions,
+    /// Cache of rendered class and enum fragments
+    fragment_cache: Option<Arc<FragmentCache>>,
 }
 
 impl MarkdownGenerator {
@@ -32,8 +43,10 @@ impl MarkdownGenerator {
         Self {
             include_toc: true,
             include_diagrams: true,
+            diagram_depth: 1,
             include_examples: true,
             options: super::traits::GeneratorOptions::default(),
+            fragment_cache: None,
         }
     }
 
@@ -59,6 +72,13 @@ impl MarkdownGenerator {
         self
     }
 
+    /// Configure how many relation hops the per-class diagrams show
+    #[must_use]
+    pub fn with_diagram_depth(mut self, depth: usize) -> Self {
+        self.diagram_depth = depth;
+        self
+    }
+
     /// Configure example generation
     #[must_use]
     pub fn with_examples(mut self, enabled: bool) -> Self {
@@ -66,6 +86,28 @@ impl MarkdownGenerator {
         self
     }
 
+    /// Reuse rendered class and enum fragments from `cache`
+    #[must_use]
+    pub fn with_fragment_cache(mut self, cache: Arc<FragmentCache>) -> Self {
+        self.fragment_cache = Some(cache);
+        self
+    }
+
+    /// Search index of the documentation, for the search of a documentation
+    /// site built from it
+    ///
+    /// Classes and enums link to their own heading; slots and types, which
+    /// are rendered as table rows, link to their section.
+    #[must_use]
+    pub fn search_index(schema: &SchemaDefinition) -> serde_json::Value {
+        SearchIndex::build(schema).to_docs_index_with(|element_type, name| match element_type {
+            ElementType::Class | ElementType::Enum => Some(name.to_lowercase().replace(' ', "-")),
+            ElementType::Slot => Some("slots".to_string()),
+            ElementType::Type => Some("types".to_string()),
+            ElementType::Subset => None,
+        })
+    }
+
     /// Generate the schema header
     fn generate_header(schema: &SchemaDefinition) -> super::traits::GeneratorResult<String> {
         let mut output = String::new();
@@ -260,160 +302,30 @@ impl MarkdownGenerator {
         sorted_classes.sort_by_key(|(name, _)| name.as_str());
 
         for (class_name, class_def) in sorted_classes {
-            writeln!(
-                &mut output,
-                "### {class_name}
-"
-            )
-            .map_err(Self::fmt_error_to_generator_error)?;
-
-            if let Some(description) = &class_def.description {
-                writeln!(
-                    &mut output,
-                    "{description}
-"
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            // Metadata table
-            writeln!(
-                &mut output,
-                "#### Metadata
-"
-            )
-            .map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut output, "| Property | Value |")
-                .map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut output, "|----------|-------|")
-                .map_err(Self::fmt_error_to_generator_error)?;
-
-            if let Some(is_a) = &class_def.is_a {
-                writeln!(
-                    &mut output,
-                    "| Parent Class | [{}](#{}) |",
-                    is_a,
-                    is_a.to_lowercase().replace(' ', "-")
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            if let Some(abstract_) = class_def.abstract_ {
-                writeln!(&mut output, "| Abstract | {abstract_} |")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            if let Some(mixin) = class_def.mixin {
-                writeln!(&mut output, "| Mixin | {mixin} |")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            if !class_def.mixins.is_empty() {
-                let mixins = class_def.mixins.join(", ");
-                writeln!(&mut output, "| Uses Mixins | {mixins} |")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            // Slots table
-            if !class_def.slots.is_empty() || !class_def.attributes.is_empty() {
-                writeln!(
-                    &mut output,
-                    "
-#### Slots
-"
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(&mut output, "| Slot | Required | Type | Description |")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(&mut output, "|------|----------|------|-------------|")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-
-                // Collect all slots (direct and inherited)
-                let mut all_slots: BTreeMap<String, SlotDefinition> = BTreeMap::new();
-
-                // Direct slots
-                for slot_name in &class_def.slots {
-                    if let Some(slot_def) = schema.slots.get(slot_name) {
-                        all_slots.insert(slot_name.clone(), slot_def.clone());
-                    }
-                }
-
-                // Attributes (inline slots)
-                for (attr_name, attr_def) in &class_def.attributes {
-                    all_slots.insert(attr_name.clone(), attr_def.clone());
-                }
-
-                // Slot usage overrides
-                for (slot_name, slot_usage) in &class_def.slot_usage {
-                    if let Some(base_slot) = schema.slots.get(slot_name) {
-                        // Apply overrides to base slot
-                        let mut overridden = base_slot.clone();
-                        if slot_usage.required.is_some() {
-                            overridden.required = slot_usage.required;
-                        }
-                        if slot_usage.range.is_some() {
-                            overridden.range.clone_from(&slot_usage.range);
-                        }
-                        if slot_usage.description.is_some() {
-                            overridden.description.clone_from(&slot_usage.description);
-                        }
-                        all_slots.insert(slot_name.clone(), overridden);
-                    }
-                }
-
-                for (slot_name, slot_def) in &all_slots {
-                    let required = slot_def.required.unwrap_or(false);
-                    let range = slot_def.range.as_deref().unwrap_or("string");
-                    let description = slot_def.description.as_deref().unwrap_or("");
-
-                    writeln!(
-                        &mut output,
-                        "| {} | {} | {} | {} |",
-                        slot_name,
-                        if required { "✓" } else { "" },
-                        range,
-                        description
-                    )
-                    .map_err(Self::fmt_error_to_generator_error)?;
-                }
-            }
-
-            // Examples
-            if self.include_examples {
-                writeln!(
-                    &mut output,
-                    "
-#### Example
-"
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(&mut output, "```yaml").map_err(Self::fmt_error_to_generator_error)?;
-                writeln!(&mut output, "{class_name}:")
-                    .map_err(Self::fmt_error_to_generator_error)?;
-
-                // Generate example values for each slot
-                for slot_name in &class_def.slots {
-                    if let Some(slot_def) = schema.slots.get(slot_name) {
-                        let example_value = Self::generate_example_value(slot_def);
-                        writeln!(&mut output, "  {slot_name}: {example_value}")
-                            .map_err(Self::fmt_error_to_generator_error)?;
-                    }
-                }
-
-                writeln!(
-                    &mut output,
-                    "```
-"
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-            }
-
-            writeln!(
-                &mut output,
-                "---
-"
-            )
-            .map_err(Self::fmt_error_to_generator_error)?;
+            // The diagram reaches into neighbouring classes, so it is part of the key
+            let diagram = if self.include_diagrams {
+                Some(MermaidGenerator::new().generate_class_neighborhood(
+                    schema,
+                    class_name,
+                    self.diagram_depth,
+                )?)
+            } else {
+                None
+            };
+            let slots: Vec<_> = class_def
+                .slots
+                .iter()
+                .chain(class_def.slot_usage.keys())
+                .map(|name| (name, schema.slots.get(name)))
+                .collect();
+            let key = self
+                .fragment_key("class", class_name)
+                .with(class_def)
+                .with(&slots)
+                .with(&diagram);
+            output.push_str(&self.fragment(key, || {
+                self.generate_class(class_name, class_def, schema, diagram.as_deref())
+            })?);
         }
 
         Ok(output)
@@ -469,7 +381,7 @@ impl MarkdownGenerator {
     }
 
     /// Generate enumeration documentation
-    fn generate_enums(schema: &SchemaDefinition) -> super::traits::GeneratorResult<String> {
+    fn generate_enums(&self, schema: &SchemaDefinition) -> super::traits::GeneratorResult<String> {
         let mut output = String::new();
 
         if schema.enums.is_empty() {
@@ -487,96 +399,330 @@ impl MarkdownGenerator {
         sorted_enums.sort_by_key(|(name, _)| name.as_str());
 
         for (enum_name, enum_def) in sorted_enums {
+            let key = self.fragment_key("enum", enum_name).with(enum_def);
+            output.push_str(&self.fragment(key, || Self::generate_enum(enum_name, enum_def))?);
+        }
+
+        Ok(output)
+    }
+
+    /// Generate types documentation
+    fn generate_types(schema: &SchemaDefinition) -> super::traits::GeneratorResult<String> {
+        let mut output = String::new();
+
+        if schema.types.is_empty() {
+            return Ok(output);
+        }
+
+        writeln!(
+            &mut output,
+            "## Types
+"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "| Type | Base | Pattern | Description |")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "|------|------|---------|-------------|")
+            .map_err(Self::fmt_error_to_generator_error)?;
+
+        let mut sorted_types: Vec<_> = schema.types.iter().collect();
+        sorted_types.sort_by_key(|(name, _)| name.as_str());
+
+        for (type_name, type_def) in sorted_types {
+            let base = type_def.base_type.as_deref().unwrap_or("");
+            let pattern = type_def.pattern.as_deref().unwrap_or("");
+            let description = type_def.description.as_deref().unwrap_or("");
+
+            let pattern_str = if pattern.is_empty() {
+                String::new()
+            } else {
+                format!("`{pattern}`")
+            };
+
             writeln!(
                 &mut output,
-                "### {enum_name}
+                "| {type_name} | {base} | {pattern_str} | {description} |"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        writeln!(
+            &mut output,
+            "
+---
+"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+
+        Ok(output)
+    }
+
+    /// Render one class; `diagram` is its neighborhood diagram, if enabled
+    fn generate_class(
+        &self,
+        class_name: &str,
+        class_def: &ClassDefinition,
+        schema: &SchemaDefinition,
+        diagram: Option<&str>,
+    ) -> super::traits::GeneratorResult<String> {
+        let mut output = String::new();
+
+        writeln!(
+            &mut output,
+            "### {class_name}
+"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+
+        if let Some(description) = &class_def.description {
+            writeln!(
+                &mut output,
+                "{description}
 "
             )
             .map_err(Self::fmt_error_to_generator_error)?;
+        }
 
-            if let Some(description) = &enum_def.description {
-                writeln!(
-                    &mut output,
-                    "{description}
+        // Metadata table
+        writeln!(
+            &mut output,
+            "#### Metadata
 "
-                )
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "| Property | Value |")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "|----------|-------|")
+            .map_err(Self::fmt_error_to_generator_error)?;
+
+        if let Some(is_a) = &class_def.is_a {
+            writeln!(
+                &mut output,
+                "| Parent Class | [{}](#{}) |",
+                is_a,
+                is_a.to_lowercase().replace(' ', "-")
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        if let Some(abstract_) = class_def.abstract_ {
+            writeln!(&mut output, "| Abstract | {abstract_} |")
                 .map_err(Self::fmt_error_to_generator_error)?;
-            }
+        }
+
+        if let Some(mixin) = class_def.mixin {
+            writeln!(&mut output, "| Mixin | {mixin} |")
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
 
+        if !class_def.mixins.is_empty() {
+            let mixins = class_def.mixins.join(", ");
+            writeln!(&mut output, "| Uses Mixins | {mixins} |")
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        // Slots table
+        if !class_def.slots.is_empty() || !class_def.attributes.is_empty() {
             writeln!(
                 &mut output,
-                "#### Permissible Values
+                "
+#### Slots
 "
             )
             .map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut output, "| Value | Description |")
+            writeln!(&mut output, "| Slot | Required | Type | Description |")
                 .map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut output, "|-------|-------------|")
+            writeln!(&mut output, "|------|----------|------|-------------|")
                 .map_err(Self::fmt_error_to_generator_error)?;
 
-            for pv in &enum_def.permissible_values {
-                let (value, description) = match pv {
-                    linkml_core::types::PermissibleValue::Simple(s) => (s.as_str(), ""),
-                    linkml_core::types::PermissibleValue::Complex {
-                        text, description, ..
-                    } => (text.as_str(), description.as_deref().unwrap_or("")),
-                };
-                writeln!(&mut output, "| {value} | {description} |")
-                    .map_err(Self::fmt_error_to_generator_error)?;
+            // Collect all slots (direct and inherited)
+            let mut all_slots: BTreeMap<String, SlotDefinition> = BTreeMap::new();
+
+            // Direct slots
+            for slot_name in &class_def.slots {
+                if let Some(slot_def) = schema.slots.get(slot_name) {
+                    all_slots.insert(slot_name.clone(), slot_def.clone());
+                }
             }
 
+            // Attributes (inline slots)
+            for (attr_name, attr_def) in &class_def.attributes {
+                all_slots.insert(attr_name.clone(), attr_def.clone());
+            }
+
+            // Slot usage overrides
+            for (slot_name, slot_usage) in &class_def.slot_usage {
+                if let Some(base_slot) = schema.slots.get(slot_name) {
+                    // Apply overrides to base slot
+                    let mut overridden = base_slot.clone();
+                    apply_slot_usage(&mut overridden, slot_usage);
+                    all_slots.insert(slot_name.clone(), overridden);
+                }
+            }
+
+            for (slot_name, slot_def) in &all_slots {
+                let required = slot_def.required.unwrap_or(false);
+                let range = slot_def.range.as_deref().unwrap_or("string");
+                let description = slot_def.description.as_deref().unwrap_or("");
+
+                writeln!(
+                    &mut output,
+                    "| {} | {} | {} | {} |",
+                    slot_name,
+                    if required { "✓" } else { "" },
+                    range,
+                    description
+                )
+                .map_err(Self::fmt_error_to_generator_error)?;
+            }
+
+            output.push_str(&Self::generate_lineage(&all_slots)?);
+        }
+
+        // Neighborhood diagram: the class, its ancestors and direct relations
+        if let Some(diagram) = diagram {
             writeln!(
                 &mut output,
                 "
----
+#### Diagram
+"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut output, "```mermaid").map_err(Self::fmt_error_to_generator_error)?;
+            write!(&mut output, "{diagram}").map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut output, "```").map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        // Examples
+        if self.include_examples {
+            writeln!(
+                &mut output,
+                "
+#### Example
+"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut output, "```yaml").map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut output, "{class_name}:").map_err(Self::fmt_error_to_generator_error)?;
+
+            // Generate example values for each slot
+            for slot_name in &class_def.slots {
+                if let Some(slot_def) = schema.slots.get(slot_name) {
+                    let example_value = Self::generate_example_value(slot_def);
+                    writeln!(&mut output, "  {slot_name}: {example_value}")
+                        .map_err(Self::fmt_error_to_generator_error)?;
+                }
+            }
+
+            writeln!(
+                &mut output,
+                "```
 "
             )
             .map_err(Self::fmt_error_to_generator_error)?;
         }
 
+        writeln!(
+            &mut output,
+            "---
+"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+
         Ok(output)
     }
 
-    /// Generate types documentation
-    fn generate_types(schema: &SchemaDefinition) -> super::traits::GeneratorResult<String> {
+    /// Generate the lineage table of the slots of a class that record it
+    fn generate_lineage(
+        slots: &BTreeMap<String, SlotDefinition>,
+    ) -> super::traits::GeneratorResult<String> {
         let mut output = String::new();
-
-        if schema.types.is_empty() {
+        let lineage: Vec<(&String, SlotLineage)> = slots
+            .iter()
+            .filter_map(|(slot_name, slot_def)| {
+                SlotLineage::of(slot_def).map(|lineage| (slot_name, lineage))
+            })
+            .collect();
+        if lineage.is_empty() {
             return Ok(output);
         }
 
         writeln!(
             &mut output,
-            "## Types
+            "
+#### Lineage
 "
         )
         .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output, "| Type | Base | Pattern | Description |")
-            .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut output, "|------|------|---------|-------------|")
+        writeln!(
+            &mut output,
+            "| Slot | Source System | Source Field | Transformation |"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            &mut output,
+            "|------|---------------|--------------|----------------|"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        for (slot_name, lineage) in lineage {
+            writeln!(
+                &mut output,
+                "| {} | {} | {} | {} |",
+                slot_name,
+                lineage.source_system.as_deref().unwrap_or(""),
+                lineage.source_field.as_deref().unwrap_or(""),
+                lineage.transformation.as_deref().unwrap_or("")
+            )
             .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        Ok(output)
+    }
 
-        let mut sorted_types: Vec<_> = schema.types.iter().collect();
-        sorted_types.sort_by_key(|(name, _)| name.as_str());
-
-        for (type_name, type_def) in sorted_types {
-            let base = type_def.base_type.as_deref().unwrap_or("");
-            let pattern = type_def.pattern.as_deref().unwrap_or("");
-            let description = type_def.description.as_deref().unwrap_or("");
+    /// Render one enumeration
+    fn generate_enum(
+        enum_name: &str,
+        enum_def: &EnumDefinition,
+    ) -> super::traits::GeneratorResult<String> {
+        let mut output = String::new();
 
-            let pattern_str = if pattern.is_empty() {
-                String::new()
-            } else {
-                format!("`{pattern}`")
-            };
+        writeln!(
+            &mut output,
+            "### {enum_name}
+"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
 
+        if let Some(description) = &enum_def.description {
             writeln!(
                 &mut output,
-                "| {type_name} | {base} | {pattern_str} | {description} |"
+                "{description}
+"
             )
             .map_err(Self::fmt_error_to_generator_error)?;
         }
 
+        writeln!(
+            &mut output,
+            "#### Permissible Values
+"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "| Value | Description |")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "|-------|-------------|")
+            .map_err(Self::fmt_error_to_generator_error)?;
+
+        for pv in &enum_def.permissible_values {
+            let (value, description) = match pv {
+                linkml_core::types::PermissibleValue::Simple(s) => (s.as_str(), ""),
+                linkml_core::types::PermissibleValue::Complex {
+                    text, description, ..
+                } => (text.as_str(), description.as_deref().unwrap_or("")),
+            };
+            writeln!(&mut output, "| {value} | {description} |")
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
         writeln!(
             &mut output,
             "
@@ -588,6 +734,23 @@ impl MarkdownGenerator {
         Ok(output)
     }
 
+    /// Start the cache key of an element fragment
+    fn fragment_key(&self, kind: &str, name: &str) -> FragmentKey {
+        FragmentKey::new("markdown", kind, name).with(&self.include_examples)
+    }
+
+    /// Render a fragment through the fragment cache, if one is attached
+    fn fragment(
+        &self,
+        key: FragmentKey,
+        render: impl FnOnce() -> super::traits::GeneratorResult<String>,
+    ) -> super::traits::GeneratorResult<String> {
+        match &self.fragment_cache {
+            Some(cache) => cache.get_or_render(key, render),
+            None => render(),
+        }
+    }
+
     /// Generate example value for a slot
     fn generate_example_value(slot: &SlotDefinition) -> &'static str {
         match slot.range.as_deref() {
@@ -645,7 +808,7 @@ impl Generator for MarkdownGenerator {
         content.push_str(&Self::generate_overview(schema)?);
         content.push_str(&self.generate_classes(schema)?);
         content.push_str(&Self::generate_slots(schema)?);
-        content.push_str(&Self::generate_enums(schema)?);
+        content.push_str(&self.generate_enums(schema)?);
         content.push_str(&Self::generate_types(schema)?);
 
         // Footer
@@ -724,6 +887,7 @@ mod tests {
                 text: "ACTIVE".to_string(),
                 description: Some("Currently employed".to_string()),
                 meaning: None,
+                is_a: None,
             });
 
         status_enum
@@ -732,6 +896,7 @@ mod tests {
                 text: "INACTIVE".to_string(),
                 description: Some("Not currently employed".to_string()),
                 meaning: None,
+                is_a: None,
             });
 
         schema
@@ -758,6 +923,31 @@ mod tests {
         assert!(result.contains("### Person"));
         assert!(result.contains("## Enumerations"));
         assert!(result.contains("### EmploymentStatus"));
+        assert!(result.contains("```mermaid\n---\ntitle: Person neighborhood"));
+
+        let without_diagrams = MarkdownGenerator::new()
+            .with_diagrams(false)
+            .generate(&schema)
+            .expect("should generate markdown documentation");
+        assert!(!without_diagrams.contains("```mermaid"));
+        assert!(!result.contains("#### Lineage"));
+
+        let mut with_lineage = schema;
+        if let Some(name) = with_lineage.slots.get_mut("name") {
+            name.annotations = Some(
+                [(
+                    crate::generator::lineage::SOURCE_SYSTEM.to_string(),
+                    "crm".into(),
+                )]
+                .into_iter()
+                .collect(),
+            );
+        }
+        let result = MarkdownGenerator::new()
+            .generate(&with_lineage)
+            .expect("should generate markdown documentation");
+        assert!(result.contains("#### Lineage"));
+        assert!(result.contains("| name | crm |  |  |"));
         Ok(())
     }
 
@@ -779,4 +969,38 @@ mod tests {
         slot.range = Some("boolean".to_string());
         assert_eq!(MarkdownGenerator::generate_example_value(&slot), "true");
     }
+
+    #[test]
+    fn test_fragment_cache_tracks_slot_and_diagram_dependencies() -> anyhow::Result<()> {
+        let mut schema = create_test_schema();
+        schema.classes.insert(
+            "Employee".to_string(),
+            ClassDefinition {
+                is_a: Some("Person".to_string()),
+                ..Default::default()
+            },
+        );
+        let cache = Arc::new(FragmentCache::new());
+        let generator = MarkdownGenerator::new().with_fragment_cache(Arc::clone(&cache));
+        generator.generate(&schema)?;
+        assert_eq!(cache.stats().misses, 3);
+
+        // Editing a slot re-renders the class using it
+        cache.reset_stats();
+        if let Some(age) = schema.slots.get_mut("age") {
+            age.description = Some("Age in years".to_string());
+        }
+        let output = generator.generate(&schema)?;
+        assert!(output.contains("Age in years"));
+        assert_eq!((cache.stats().hits, cache.stats().misses), (2, 1));
+
+        // A new range shows in Person's table and in the diagram of its subclass
+        cache.reset_stats();
+        if let Some(age) = schema.slots.get_mut("age") {
+            age.range = Some("float".to_string());
+        }
+        generator.generate(&schema)?;
+        assert_eq!((cache.stats().hits, cache.stats().misses), (1, 2));
+        Ok(())
+    }
 }
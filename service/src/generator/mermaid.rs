@@ -9,6 +9,7 @@ use linkml_core::{error::LinkMLError, prelude::*};
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 
+use super::neighborhood::{ClassNeighborhood, NeighborhoodEdgeKind};
 use super::traits::{Generator, GeneratorError, GeneratorOptions, GeneratorResult, IndentStyle};
 
 /// Mermaid diagram type
@@ -22,6 +23,8 @@ pub enum MermaidDiagramType {
     StateDiagram,
     /// Flowchart
     Flowchart,
+    /// Class diagram restricted to one class, its ancestors and its direct relations
+    Neighborhood,
 }
 
 bitflags! {
@@ -54,6 +57,10 @@ pub struct MermaidOptions {
     pub features: MermaidFeatures,
     /// Theme (default, dark, forest, neutral)
     pub theme: String,
+    /// Focus class for neighborhood diagrams
+    pub focus_class: Option<String>,
+    /// Number of relation hops included in neighborhood diagrams
+    pub neighborhood_depth: usize,
 }
 
 impl Default for MermaidOptions {
@@ -62,6 +69,8 @@ impl Default for MermaidOptions {
             diagram_type: MermaidDiagramType::EntityRelationship,
             features: MermaidFeatures::DEFAULT | MermaidFeatures::SHOW_TYPES,
             theme: "default".to_string(),
+            focus_class: None,
+            neighborhood_depth: 1,
         }
     }
 }
@@ -99,6 +108,20 @@ impl MermaidGenerator {
         self
     }
 
+    /// Set the focus class used by neighborhood diagrams
+    #[must_use]
+    pub fn with_focus_class(mut self, class_name: impl Into<String>) -> Self {
+        self.options.focus_class = Some(class_name.into());
+        self
+    }
+
+    /// Set the number of relation hops shown in neighborhood diagrams
+    #[must_use]
+    pub fn with_neighborhood_depth(mut self, depth: usize) -> Self {
+        self.options.neighborhood_depth = depth;
+        self
+    }
+
     /// Generate Mermaid diagram
     fn generate_mermaid(&self, schema: &SchemaDefinition) -> GeneratorResult<String> {
         match self.options.diagram_type {
@@ -106,6 +129,14 @@ impl MermaidGenerator {
             MermaidDiagramType::ClassDiagram => self.generate_class_diagram(schema),
             MermaidDiagramType::StateDiagram => Self::generate_state_diagram(schema),
             MermaidDiagramType::Flowchart => self.generate_flowchart(schema),
+            MermaidDiagramType::Neighborhood => {
+                let focus = self.options.focus_class.as_deref().ok_or_else(|| {
+                    GeneratorError::Configuration(
+                        "Neighborhood diagrams require a focus class".to_string(),
+                    )
+                })?;
+                self.generate_class_neighborhood(schema, focus, self.options.neighborhood_depth)
+            }
         }
     }
 
@@ -149,17 +180,32 @@ impl MermaidGenerator {
             for slot_name in &all_slots {
                 if let Some(slot_def) = schema.slots.get(slot_name) {
                     let data_type = Self::get_mermaid_type(slot_def.range.as_ref());
-                    let key_marker = if slot_def.identifier == Some(true) {
-                        "PK"
-                    } else {
-                        ""
-                    };
+                    let key_marker = Self::get_er_key_markers(slot_def, schema);
                     let required_marker =
-                        if slot_def.required == Some(true) && key_marker.is_empty() {
+                        if slot_def.required == Some(true) && slot_def.identifier != Some(true) {
                             "*"
                         } else {
                             ""
                         };
+                    let description = slot_def
+                        .description
+                        .as_deref()
+                        .unwrap_or("")
+                        .replace('"', "'")
+                        .chars()
+                        .take(50)
+                        .collect::<String>();
+                    let comment = if self
+                        .options
+                        .features
+                        .contains(MermaidFeatures::SHOW_CARDINALITY)
+                    {
+                        format!("[{}] {}", Self::get_slot_cardinality(slot_def), description)
+                            .trim_end()
+                            .to_string()
+                    } else {
+                        description
+                    };
 
                     writeln!(
                         &mut output,
@@ -167,14 +213,7 @@ impl MermaidGenerator {
                         data_type,
                         Self::sanitize_name(slot_name),
                         key_marker,
-                        slot_def
-                            .description
-                            .as_deref()
-                            .unwrap_or("")
-                            .replace('"', "'")
-                            .chars()
-                            .take(50)
-                            .collect::<String>()
+                        comment
                     )
                     .map_err(Self::fmt_error_to_generator_error)?;
 
@@ -209,14 +248,15 @@ impl MermaidGenerator {
                     && let Some(range) = &slot_def.range
                     && schema.classes.contains_key(range)
                 {
-                    // This is an object reference
+                    // This is an object reference, labelled with the slot that carries it
                     let cardinality = Self::get_er_cardinality(slot_def);
                     writeln!(
                         &mut output,
-                        "    {} {} {} : has",
+                        "    {} {} {} : {}",
                         Self::sanitize_name(class_name),
                         cardinality,
-                        Self::sanitize_name(range)
+                        Self::sanitize_name(range),
+                        Self::sanitize_name(slot_name)
                     )
                     .map_err(Self::fmt_error_to_generator_error)?;
                 }
@@ -407,6 +447,162 @@ impl MermaidGenerator {
         Ok(output)
     }
 
+    /// Generate a class diagram of a single class, its ancestors and its relations
+    ///
+    /// Relations (outgoing and incoming slot references) are followed up to
+    /// `depth` hops; ancestors are always included. The focus class lists all
+    /// of its slots (including inherited ones) and is highlighted, while the
+    /// surrounding classes are shown as plain boxes to keep the diagram small
+    /// enough to embed in per-class documentation pages.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::Configuration` if the class does not exist, or
+    /// `GeneratorError::Io` if writing the diagram fails.
+    pub fn generate_class_neighborhood(
+        &self,
+        schema: &SchemaDefinition,
+        class_name: &str,
+        depth: usize,
+    ) -> GeneratorResult<String> {
+        let neighborhood = ClassNeighborhood::compute(schema, class_name, depth)?;
+        let focus = Self::sanitize_name(class_name);
+        let mut output = String::new();
+
+        writeln!(&mut output, "---").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "title: {class_name} neighborhood")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "---").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "classDiagram").map_err(Self::fmt_error_to_generator_error)?;
+
+        if self.options.theme != "default" {
+            writeln!(
+                &mut output,
+                "    %%{{init: {{'theme':'{}'}}}}%%",
+                self.options.theme
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        for name in &neighborhood.classes {
+            if let Some(class_def) = schema.classes.get(name) {
+                self.write_neighborhood_class(
+                    &mut output,
+                    name,
+                    class_def,
+                    schema,
+                    neighborhood.is_focus(name),
+                )?;
+            }
+        }
+
+        writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+
+        for edge in &neighborhood.edges {
+            let from = Self::sanitize_name(&edge.from);
+            let to = Self::sanitize_name(&edge.to);
+            match &edge.kind {
+                NeighborhoodEdgeKind::IsA => {
+                    writeln!(&mut output, "    {to} <|-- {from}")
+                        .map_err(Self::fmt_error_to_generator_error)?;
+                }
+                NeighborhoodEdgeKind::Mixin => {
+                    writeln!(&mut output, "    {to} <|.. {from} : mixin")
+                        .map_err(Self::fmt_error_to_generator_error)?;
+                }
+                NeighborhoodEdgeKind::Reference {
+                    slot,
+                    required,
+                    multivalued,
+                } => {
+                    writeln!(
+                        &mut output,
+                        "    {from} --> \"{}\" {to} : {}",
+                        Self::cardinality_label(*required, *multivalued),
+                        Self::sanitize_name(slot)
+                    )
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                }
+            }
+        }
+
+        writeln!(
+            &mut output,
+            "    style {focus} fill:#ffe8a3,stroke:#b8860b,stroke-width:2px"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+
+        Ok(output)
+    }
+
+    /// Write one class box of a neighborhood diagram; only the focus lists its slots
+    fn write_neighborhood_class(
+        &self,
+        output: &mut String,
+        name: &str,
+        class_def: &ClassDefinition,
+        schema: &SchemaDefinition,
+        is_focus: bool,
+    ) -> GeneratorResult<()> {
+        writeln!(output, "    class {} {{", Self::sanitize_name(name))
+            .map_err(Self::fmt_error_to_generator_error)?;
+
+        if class_def.abstract_.unwrap_or(false) {
+            writeln!(output, "        <<abstract>>").map_err(Self::fmt_error_to_generator_error)?;
+        } else if class_def.mixin.unwrap_or(false) {
+            writeln!(output, "        <<mixin>>").map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        if is_focus
+            && self
+                .options
+                .features
+                .contains(MermaidFeatures::INCLUDE_SLOTS)
+        {
+            for slot_name in &self.collect_all_slots(name, class_def, schema) {
+                let Some(slot_def) = schema
+                    .slots
+                    .get(slot_name)
+                    .or_else(|| class_def.attributes.get(slot_name))
+                else {
+                    continue;
+                };
+                let data_type = if self.options.features.contains(MermaidFeatures::SHOW_TYPES) {
+                    Self::get_class_diagram_type(slot_def.range.as_ref())
+                } else {
+                    String::new()
+                };
+                let cardinality = if self
+                    .options
+                    .features
+                    .contains(MermaidFeatures::SHOW_CARDINALITY)
+                {
+                    format!(" [{}]", Self::get_slot_cardinality(slot_def))
+                } else {
+                    String::new()
+                };
+                let visibility = if slot_def.required == Some(true) {
+                    "+"
+                } else {
+                    "-"
+                };
+
+                writeln!(
+                    output,
+                    "        {}{}{}{}",
+                    visibility,
+                    data_type,
+                    Self::sanitize_name(slot_name),
+                    cardinality
+                )
+                .map_err(Self::fmt_error_to_generator_error)?;
+            }
+        }
+
+        writeln!(output, "    }}").map_err(Self::fmt_error_to_generator_error)?;
+        Ok(())
+    }
+
     /// Generate State diagram
     fn generate_state_diagram(schema: &SchemaDefinition) -> GeneratorResult<String> {
         let mut output = String::new();
@@ -572,6 +768,42 @@ impl MermaidGenerator {
         }
     }
 
+    /// Get UML-style cardinality of a slot (`1`, `0..1`, `1..*`, `0..*`)
+    fn get_slot_cardinality(slot: &SlotDefinition) -> &'static str {
+        Self::cardinality_label(
+            slot.required.unwrap_or(false),
+            slot.multivalued.unwrap_or(false),
+        )
+    }
+
+    /// Cardinality label for a required/multivalued combination
+    fn cardinality_label(required: bool, multivalued: bool) -> &'static str {
+        match (required, multivalued) {
+            (true, false) => "1",
+            (false, false) => "0..1",
+            (true, true) => "1..*",
+            (false, true) => "0..*",
+        }
+    }
+
+    /// Get ER key markers for a slot (`PK`, `FK`, `UK`, comma separated)
+    fn get_er_key_markers(slot: &SlotDefinition, schema: &SchemaDefinition) -> String {
+        let mut markers = Vec::new();
+        if slot.identifier == Some(true) {
+            markers.push("PK");
+        } else if slot.key == Some(true) || slot.unique == Some(true) {
+            markers.push("UK");
+        }
+        if slot
+            .range
+            .as_ref()
+            .is_some_and(|range| schema.classes.contains_key(range))
+        {
+            markers.push("FK");
+        }
+        markers.join(", ")
+    }
+
     /// Get Mermaid data type for ER diagrams
     fn get_mermaid_type(range: Option<&String>) -> &'static str {
         match range.map(String::as_str) {
@@ -650,14 +882,22 @@ impl MermaidGenerator {
 
         // Add documentation comments if requested
         if options.include_docs {
+            // The user name depends on the environment, so reproducible
+            // output leaves it out
+            let generated_by = if options.deterministic {
+                String::new()
+            } else {
+                format!(
+                    "%% Generated at: {}\n",
+                    std::env::var("USER").unwrap_or_else(|_| "unknown".to_string())
+                )
+            };
             let doc_header = format!(
                 "%% Generated by LinkML Mermaid Generator
 %% Schema: {}
-%% Generated at: {}
-
+{generated_by}
 ",
-                "schema",
-                std::env::var("USER").unwrap_or_else(|_| "unknown".to_string())
+                "schema"
             );
             output = doc_header + &output;
         }
@@ -751,6 +991,7 @@ impl Generator for MermaidGenerator {
             MermaidDiagramType::ClassDiagram => "mermaid-class",
             MermaidDiagramType::StateDiagram => "mermaid-state",
             MermaidDiagramType::Flowchart => "mermaid-flow",
+            MermaidDiagramType::Neighborhood => "mermaid-neighborhood",
         }
     }
 
@@ -766,6 +1007,9 @@ impl Generator for MermaidGenerator {
                 "Generates Mermaid state diagrams from LinkML schemas"
             }
             MermaidDiagramType::Flowchart => "Generates Mermaid flowcharts from LinkML schemas",
+            MermaidDiagramType::Neighborhood => {
+                "Generates Mermaid class neighborhood diagrams from LinkML schemas"
+            }
         }
     }
 
@@ -910,10 +1154,69 @@ mod tests {
         assert!(output.contains("Person {"));
         assert!(output.contains("Address {"));
         // The relationship format might be different, let's check for the basic components
-        assert!(output.contains("Person") && output.contains("Address") && output.contains("has"));
+        assert!(output.contains("Person ||--o| Address : address"));
+        // Identifiers and references are marked as keys, with cardinality comments
+        assert!(output.contains("string id PK \"[0..1]\""));
+        assert!(output.contains("string address FK \"[0..1]\""));
+        assert!(output.contains("string name  \"[1]\""));
         Ok(())
     }
 
+    #[test]
+    fn test_neighborhood_diagram() {
+        let mut schema = create_test_schema();
+        schema.classes.insert(
+            "NamedThing".to_string(),
+            ClassDefinition {
+                abstract_: Some(true),
+                ..Default::default()
+            },
+        );
+        if let Some(person) = schema.classes.get_mut("Person") {
+            person.is_a = Some("NamedThing".to_string());
+        }
+        schema.classes.insert(
+            "Building".to_string(),
+            ClassDefinition {
+                slots: vec!["address".to_string()],
+                ..Default::default()
+            },
+        );
+
+        let generator = MermaidGenerator::new()
+            .with_diagram_type(MermaidDiagramType::Neighborhood)
+            .with_focus_class("Person");
+        let output = generator
+            .generate(&schema)
+            .expect("should generate neighborhood diagram");
+
+        assert!(output.contains("title: Person neighborhood"));
+        assert!(output.contains("NamedThing <|-- Person"));
+        assert!(output.contains("Person --> \"0..1\" Address : address"));
+        assert!(output.contains("-string: id [0..1]"));
+        assert!(output.contains("style Person fill:"));
+        // Building is two hops away through Address
+        assert!(!output.contains("class Building"));
+
+        let wider = generator
+            .with_neighborhood_depth(2)
+            .generate(&schema)
+            .expect("should generate neighborhood diagram");
+        assert!(wider.contains("Building --> \"0..1\" Address : address"));
+    }
+
+    #[test]
+    fn test_neighborhood_requires_focus() {
+        let schema = create_test_schema();
+        let generator = MermaidGenerator::new().with_diagram_type(MermaidDiagramType::Neighborhood);
+        assert!(generator.generate(&schema).is_err());
+        assert!(
+            generator
+                .generate_class_neighborhood(&schema, "Missing", 1)
+                .is_err()
+        );
+    }
+
     #[tokio::test]
     async fn test_class_diagram_generation() -> anyhow::Result<()> {
         let schema = create_test_schema();
@@ -923,6 +1226,9 @@ mod tests {
             generate_tests: false,
             indent: IndentStyle::Spaces(4),
             output_format: crate::generator::traits::OutputFormat::Markdown,
+            enable_formatting: false,
+            provenance: false,
+            deterministic: false,
             custom: std::collections::HashMap::new(),
         };
 
@@ -4,7 +4,11 @@
 //! supporting multiple target languages and formats.
 
 // Core generator infrastructure
+pub mod annotation_hints;
 pub mod base;
+pub mod example_tests;
+pub mod formatting;
+pub mod limits;
 pub mod namespace_manager;
 pub mod options;
 pub mod plugin;
@@ -17,14 +21,19 @@ pub mod classes;
 pub mod core;
 pub mod fields;
 pub mod implementations;
+pub mod rust_ast;
 pub mod rust_traits;
 pub mod validation;
 
 // Language-specific generators
 pub mod array_support;
+pub mod avro;
 pub mod csv;
+pub mod cypher;
+pub mod dbt;
 pub mod doc;
 pub mod excel;
+pub mod fragment_cache;
 pub mod golang;
 pub mod graphql_generator;
 pub mod graphviz;
@@ -34,18 +43,22 @@ pub mod javascript;
 pub mod json_ld;
 pub mod json_schema;
 pub mod jsonld_context;
+pub mod lineage;
 pub mod markdown;
 pub mod mermaid;
+pub mod neighborhood;
 pub mod openapi;
 pub mod plantuml;
 pub mod prefix_map;
 pub mod protobuf;
+pub mod provenance;
 pub mod pydantic;
 pub mod python_dataclass;
 pub mod rdf;
 pub mod rust_generator;
 pub mod shacl;
 pub mod shex;
+pub mod spark;
 pub mod sparql;
 pub mod sql;
 pub mod sqlalchemy;
@@ -65,7 +78,14 @@ pub mod yuml;
 
 // Re-export main types
 pub use core::RustGenerator;
+pub use formatting::{
+    OutputPostProcessor, PostProcessorPipeline, PythonFormatter, RustFormatter,
+    WhitespaceNormalizer, format_generated_output,
+};
+pub use fragment_cache::{FragmentCache, FragmentCacheStats, FragmentKey};
+pub use limits::{GenerationLimits, GuardedGenerator};
 pub use options::{GeneratorOptions, IndentStyle, OutputFormat};
+pub use provenance::Provenance;
 pub use registry::{GeneratorInfo, GeneratorRegistry};
 pub use traits::{
     AsyncGenerator, CodeFormatter, GeneratedOutput, Generator, GeneratorConfig, GeneratorError,
@@ -73,7 +93,10 @@ pub use traits::{
 };
 
 // Re-export generators
+pub use avro::AvroGenerator;
 pub use csv::CsvGenerator;
+pub use cypher::{CypherGenerator, CypherOptions};
+pub use dbt::DbtGenerator;
 pub use excel::ExcelGenerator;
 pub use golang::GoGenerator;
 pub use graphql_generator::GraphQLGenerator;
@@ -82,10 +105,11 @@ pub use html::HtmlGenerator;
 pub use java::JavaGenerator;
 pub use javascript::JavaScriptGenerator;
 pub use json_ld::JsonLdGenerator;
-pub use json_schema::JsonSchemaGenerator;
+pub use json_schema::{JsonSchemaGenerator, JsonSchemaMappingGenerator};
 pub use jsonld_context::{JsonLdContextGenerator, JsonLdContextGeneratorConfig};
 pub use markdown::MarkdownGenerator;
 pub use mermaid::{MermaidDiagramType, MermaidGenerator};
+pub use neighborhood::{ClassNeighborhood, NeighborhoodEdge, NeighborhoodEdgeKind};
 pub use namespace_manager::TargetLanguage;
 pub use namespace_manager::{NamespaceManagerGenerator, NamespaceManagerGeneratorConfig};
 pub use openapi::OpenApiGenerator;
@@ -94,13 +118,16 @@ pub use prefix_map::{PrefixMapFormat, PrefixMapGenerator, PrefixMapGeneratorConf
 pub use protobuf::ProtobufGenerator;
 pub use pydantic::PydanticGenerator;
 pub use python_dataclass::PythonDataclassGenerator;
-pub use rdf::RdfGenerator;
+pub use rdf::{OwlProfile, RdfGenerator};
 pub use shacl::ShaclGenerator;
 pub use shex::ShExGenerator;
+pub use spark::SparkGenerator;
 pub use sparql::SparqlGenerator;
 pub use sql::SQLGenerator;
 pub use sqlalchemy::{SQLAlchemyGenerator, SQLAlchemyGeneratorConfig};
-pub use sssom::{SssomFormat, SssomGenerator, SssomGeneratorConfig};
+pub use sssom::{
+    SssomFormat, SssomGenerator, SssomGeneratorConfig, SssomMappingRecord, SssomMappingSet,
+};
 pub use summary::{SummaryFormat, SummaryGenerator, SummaryGeneratorConfig};
 pub use typeql_generator::TypeQLGenerator;
 pub use typescript::TypeScriptGenerator;
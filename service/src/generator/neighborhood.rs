@@ -0,0 +1,357 @@
+//! Class neighborhood computation for focused diagrams
+//!
+//! A neighborhood is the subgraph of a schema around a single focus class:
+//! the focus itself, all of its ancestors (`is_a` chain and mixins), and every
+//! class reachable through slot references (outgoing or incoming) within a
+//! configurable number of hops. Diagram generators use it to render small,
+//! per-class diagrams that can be embedded in generated documentation.
+
+use super::traits::{GeneratorError, GeneratorResult};
+use indexmap::IndexSet;
+use linkml_core::prelude::*;
+use std::collections::VecDeque;
+
+/// Kind of edge between two classes in a neighborhood
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NeighborhoodEdgeKind {
+    /// `from` is a subclass of `to`
+    IsA,
+    /// `from` uses `to` as a mixin
+    Mixin,
+    /// `from` has a slot whose range is `to`
+    Reference {
+        /// Slot carrying the reference
+        slot: String,
+        /// Whether the slot is required
+        required: bool,
+        /// Whether the slot is multivalued
+        multivalued: bool,
+    },
+}
+
+/// Directed edge between two classes in a neighborhood
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NeighborhoodEdge {
+    /// Source class
+    pub from: String,
+    /// Target class
+    pub to: String,
+    /// Edge kind
+    pub kind: NeighborhoodEdgeKind,
+}
+
+/// The classes and edges surrounding a focus class
+#[derive(Debug, Clone)]
+pub struct ClassNeighborhood {
+    /// The focus class
+    pub focus: String,
+    /// Relation depth used to build the neighborhood
+    pub depth: usize,
+    /// Ancestors of the focus class, nearest first
+    pub ancestors: Vec<String>,
+    /// All classes in the neighborhood (focus first, insertion order)
+    pub classes: IndexSet<String>,
+    /// Edges whose endpoints are both in the neighborhood
+    pub edges: Vec<NeighborhoodEdge>,
+}
+
+impl ClassNeighborhood {
+    /// Compute the neighborhood of `focus` with relations followed up to `depth` hops
+    ///
+    /// A depth of zero yields only the focus class and its ancestors.
+    ///
+    /// # Errors
+    /// Returns `GeneratorError::Configuration` if the focus class does not exist
+    pub fn compute(schema: &SchemaDefinition, focus: &str, depth: usize) -> GeneratorResult<Self> {
+        if !schema.classes.contains_key(focus) {
+            return Err(GeneratorError::Configuration(format!(
+                "Focus class '{focus}' not found in schema"
+            )));
+        }
+
+        let mut classes = IndexSet::new();
+        classes.insert(focus.to_string());
+
+        // Relations, breadth-first up to the requested depth
+        let mut queue = VecDeque::from([(focus.to_string(), 0usize)]);
+        while let Some((current, distance)) = queue.pop_front() {
+            if distance >= depth {
+                continue;
+            }
+            for neighbor in Self::related_classes(schema, &current) {
+                if classes.insert(neighbor.clone()) {
+                    queue.push_back((neighbor, distance + 1));
+                }
+            }
+        }
+
+        // Ancestors are always shown in full, independent of depth
+        let ancestors = Self::ancestors(schema, focus);
+        for ancestor in &ancestors {
+            classes.insert(ancestor.clone());
+        }
+
+        let edges = Self::edges_within(schema, &classes);
+
+        Ok(Self {
+            focus: focus.to_string(),
+            depth,
+            ancestors,
+            classes,
+            edges,
+        })
+    }
+
+    /// Whether a class belongs to the neighborhood
+    #[must_use]
+    pub fn contains(&self, class_name: &str) -> bool {
+        self.classes.contains(class_name)
+    }
+
+    /// Whether a class is the focus class
+    #[must_use]
+    pub fn is_focus(&self, class_name: &str) -> bool {
+        self.focus == class_name
+    }
+
+    /// Slots declared directly on a class (slots plus attributes)
+    fn own_slots<'a>(
+        schema: &'a SchemaDefinition,
+        class_def: &'a ClassDefinition,
+    ) -> Vec<(&'a str, &'a SlotDefinition)> {
+        let mut slots: Vec<(&str, &SlotDefinition)> = class_def
+            .slots
+            .iter()
+            .filter_map(|name| {
+                let usage = class_def.slot_usage.get(name);
+                usage
+                    .filter(|u| u.range.is_some())
+                    .or_else(|| schema.slots.get(name))
+                    .map(|def| (name.as_str(), def))
+            })
+            .collect();
+        slots.extend(
+            class_def
+                .attributes
+                .iter()
+                .map(|(name, def)| (name.as_str(), def)),
+        );
+        slots
+    }
+
+    /// Classes directly connected to `class_name` through slot references
+    fn related_classes(schema: &SchemaDefinition, class_name: &str) -> Vec<String> {
+        let mut related = Vec::new();
+
+        if let Some(class_def) = schema.classes.get(class_name) {
+            for (_, slot) in Self::own_slots(schema, class_def) {
+                if let Some(range) = &slot.range
+                    && schema.classes.contains_key(range)
+                {
+                    related.push(range.clone());
+                }
+            }
+        }
+
+        for (other_name, other_def) in &schema.classes {
+            let references_focus = Self::own_slots(schema, other_def)
+                .iter()
+                .any(|(_, slot)| slot.range.as_deref() == Some(class_name));
+            if references_focus {
+                related.push(other_name.clone());
+            }
+        }
+
+        related
+    }
+
+    /// All ancestors of a class, nearest first, without duplicates
+    fn ancestors(schema: &SchemaDefinition, class_name: &str) -> Vec<String> {
+        let mut ancestors = IndexSet::new();
+        let mut queue = VecDeque::from([class_name.to_string()]);
+
+        while let Some(current) = queue.pop_front() {
+            let Some(class_def) = schema.classes.get(&current) else {
+                continue;
+            };
+            for parent in class_def.is_a.iter().chain(class_def.mixins.iter()) {
+                if parent != class_name && ancestors.insert(parent.clone()) {
+                    queue.push_back(parent.clone());
+                }
+            }
+        }
+
+        ancestors.into_iter().collect()
+    }
+
+    /// Edges between classes that are both part of the neighborhood
+    fn edges_within(
+        schema: &SchemaDefinition,
+        classes: &IndexSet<String>,
+    ) -> Vec<NeighborhoodEdge> {
+        let mut edges = Vec::new();
+
+        for class_name in classes {
+            let Some(class_def) = schema.classes.get(class_name) else {
+                continue;
+            };
+
+            if let Some(parent) = &class_def.is_a
+                && classes.contains(parent)
+            {
+                edges.push(NeighborhoodEdge {
+                    from: class_name.clone(),
+                    to: parent.clone(),
+                    kind: NeighborhoodEdgeKind::IsA,
+                });
+            }
+
+            for mixin in &class_def.mixins {
+                if classes.contains(mixin) {
+                    edges.push(NeighborhoodEdge {
+                        from: class_name.clone(),
+                        to: mixin.clone(),
+                        kind: NeighborhoodEdgeKind::Mixin,
+                    });
+                }
+            }
+
+            for (slot_name, slot) in Self::own_slots(schema, class_def) {
+                if let Some(range) = &slot.range
+                    && classes.contains(range)
+                {
+                    edges.push(NeighborhoodEdge {
+                        from: class_name.clone(),
+                        to: range.clone(),
+                        kind: NeighborhoodEdgeKind::Reference {
+                            slot: slot_name.to_string(),
+                            required: slot.required.unwrap_or(false),
+                            multivalued: slot.multivalued.unwrap_or(false),
+                        },
+                    });
+                }
+            }
+        }
+
+        edges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn class_ref_slot(range: &str) -> SlotDefinition {
+        SlotDefinition {
+            range: Some(range.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn create_test_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("neighborhood_test");
+
+        schema
+            .classes
+            .insert("Thing".to_string(), ClassDefinition::default());
+        schema.classes.insert(
+            "Named".to_string(),
+            ClassDefinition {
+                mixin: Some(true),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                is_a: Some("Thing".to_string()),
+                mixins: vec!["Named".to_string()],
+                slots: vec!["employer".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Organization".to_string(),
+            ClassDefinition {
+                is_a: Some("Thing".to_string()),
+                slots: vec!["address".to_string()],
+                ..Default::default()
+            },
+        );
+        schema
+            .classes
+            .insert("Address".to_string(), ClassDefinition::default());
+        schema.classes.insert(
+            "Dataset".to_string(),
+            ClassDefinition {
+                attributes: [("creator".to_string(), class_ref_slot("Person"))]
+                    .into_iter()
+                    .collect(),
+                ..Default::default()
+            },
+        );
+        schema
+            .classes
+            .insert("Unrelated".to_string(), ClassDefinition::default());
+
+        schema
+            .slots
+            .insert("employer".to_string(), class_ref_slot("Organization"));
+        schema
+            .slots
+            .insert("address".to_string(), class_ref_slot("Address"));
+
+        schema
+    }
+
+    #[test]
+    fn test_depth_one_neighborhood() {
+        let schema = create_test_schema();
+        let hood =
+            ClassNeighborhood::compute(&schema, "Person", 1).expect("Person exists in the schema");
+
+        assert!(hood.is_focus("Person"));
+        assert!(hood.contains("Organization"), "outgoing reference");
+        assert!(hood.contains("Dataset"), "incoming reference");
+        assert!(
+            hood.contains("Thing") && hood.contains("Named"),
+            "ancestors"
+        );
+        assert!(!hood.contains("Address"), "two hops away");
+        assert!(!hood.contains("Unrelated"));
+        assert_eq!(
+            hood.ancestors,
+            vec!["Thing".to_string(), "Named".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_depth_two_reaches_further() {
+        let schema = create_test_schema();
+        let hood =
+            ClassNeighborhood::compute(&schema, "Person", 2).expect("Person exists in the schema");
+
+        assert!(hood.contains("Address"));
+        assert!(hood.edges.iter().any(|e| e.from == "Organization"
+            && e.to == "Address"
+            && matches!(&e.kind, NeighborhoodEdgeKind::Reference { slot, .. } if slot == "address")));
+    }
+
+    #[test]
+    fn test_depth_zero_only_ancestors() {
+        let schema = create_test_schema();
+        let hood =
+            ClassNeighborhood::compute(&schema, "Person", 0).expect("Person exists in the schema");
+
+        let classes: Vec<&str> = hood.classes.iter().map(String::as_str).collect();
+        assert_eq!(classes, vec!["Person", "Thing", "Named"]);
+        assert!(hood.edges.iter().all(|e| e.from == "Person"));
+        assert_eq!(hood.edges.len(), 2);
+    }
+
+    #[test]
+    fn test_unknown_focus_class() {
+        let schema = create_test_schema();
+        assert!(ClassNeighborhood::compute(&schema, "Missing", 1).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 //! `OpenAPI` schema generation for `LinkML` schemas
 
+use super::lineage::SlotLineage;
 use super::options::IndentStyle;
 use super::traits::{CodeFormatter, Generator, GeneratorError, GeneratorResult};
 use linkml_core::{error::LinkMLError, prelude::*};
@@ -235,6 +236,10 @@ impl OpenApiGenerator {
             property["readOnly"] = json!(true);
         }
 
+        if let Some(lineage) = SlotLineage::of(slot) {
+            property["x-lineage"] = lineage.to_json();
+        }
+
         Ok(property)
     }
 
@@ -7,6 +7,7 @@ use linkml_core::{error::LinkMLError, prelude::*};
 use std::collections::HashSet;
 use std::fmt::Write;
 
+use super::neighborhood::{ClassNeighborhood, NeighborhoodEdgeKind};
 use super::traits::{Generator, GeneratorError, GeneratorResult};
 
 /// `PlantUML` diagram type
@@ -24,6 +25,8 @@ pub enum PlantUmlDiagramType {
     MindMap,
     /// Component diagram
     Component,
+    /// Class diagram restricted to one class, its ancestors and its direct relations
+    Neighborhood,
 }
 
 /// `PlantUML` skin parameters
@@ -75,6 +78,12 @@ pub struct PlantUmlOptions {
     pub show_cardinality: bool,
     /// Direction (top to bottom, left to right)
     pub direction: String,
+    /// Focus class for neighborhood diagrams
+    pub focus_class: Option<String>,
+    /// Number of relation hops included in neighborhood diagrams
+    pub neighborhood_depth: usize,
+    /// Background color used to highlight the focus class
+    pub focus_color: String,
 }
 
 impl Default for PlantUmlOptions {
@@ -88,6 +97,9 @@ impl Default for PlantUmlOptions {
             skin: PlantUmlSkin::default(),
             show_cardinality: true,
             direction: "top to bottom".to_string(),
+            focus_class: None,
+            neighborhood_depth: 1,
+            focus_color: "#FFD966".to_string(),
         }
     }
 }
@@ -125,6 +137,20 @@ impl PlantUmlGenerator {
         self
     }
 
+    /// Set the focus class used by neighborhood diagrams
+    #[must_use]
+    pub fn with_focus_class(mut self, class_name: impl Into<String>) -> Self {
+        self.options.focus_class = Some(class_name.into());
+        self
+    }
+
+    /// Set the number of relation hops shown in neighborhood diagrams
+    #[must_use]
+    pub fn with_neighborhood_depth(mut self, depth: usize) -> Self {
+        self.options.neighborhood_depth = depth;
+        self
+    }
+
     /// Generate `PlantUML` diagram
     fn generate_plantuml(&self, schema: &SchemaDefinition) -> GeneratorResult<String> {
         match self.options.diagram_type {
@@ -134,6 +160,14 @@ impl PlantUmlGenerator {
             PlantUmlDiagramType::State => PlantUmlGenerator::generate_state_diagram(schema),
             PlantUmlDiagramType::MindMap => self.generate_mindmap(schema),
             PlantUmlDiagramType::Component => PlantUmlGenerator::generate_component_diagram(schema),
+            PlantUmlDiagramType::Neighborhood => {
+                let focus = self.options.focus_class.as_deref().ok_or_else(|| {
+                    GeneratorError::Configuration(
+                        "Neighborhood diagrams require a focus class".to_string(),
+                    )
+                })?;
+                self.generate_class_neighborhood(schema, focus, self.options.neighborhood_depth)
+            }
         }
     }
 
@@ -179,7 +213,7 @@ impl PlantUmlGenerator {
 
         // Generate classes
         for (class_name, class_def) in &schema.classes {
-            self.generate_class(&mut output, class_name, class_def, schema)?;
+            self.generate_class(&mut output, class_name, class_def, schema, None)?;
             writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
         }
 
@@ -202,20 +236,22 @@ impl PlantUmlGenerator {
         Ok(output)
     }
 
-    /// Generate a class
+    /// Generate a class, optionally with a highlight background color
     fn generate_class(
         &self,
         output: &mut String,
         class_name: &str,
         class_def: &ClassDefinition,
         schema: &SchemaDefinition,
+        color: Option<&str>,
     ) -> GeneratorResult<()> {
         // Class declaration
+        let color = color.map(|c| format!(" {c}")).unwrap_or_default();
         if class_def.abstract_.unwrap_or(false) {
-            writeln!(output, "abstract class {class_name} {{")
+            writeln!(output, "abstract class {class_name}{color} {{")
                 .map_err(Self::fmt_error_to_generator_error)?;
         } else {
-            writeln!(output, "class {class_name} {{")
+            writeln!(output, "class {class_name}{color} {{")
                 .map_err(Self::fmt_error_to_generator_error)?;
         }
 
@@ -491,20 +527,20 @@ impl PlantUmlGenerator {
                 .map_err(Self::fmt_error_to_generator_error)?;
 
             let all_slots = self.collect_all_slots(class_name, class_def, schema);
-            for slot_name in &all_slots {
-                if let Some(slot_def) = schema.slots.get(slot_name) {
-                    let key_marker = if slot_def.identifier == Some(true) {
-                        "*"
-                    } else if slot_def.required == Some(true) {
-                        "+"
-                    } else {
-                        ""
-                    };
-
-                    let type_str = slot_def.range.as_deref().unwrap_or("string");
-                    writeln!(&mut output, "  {key_marker}{slot_name} : {type_str}")
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                }
+            let (identifiers, attributes): (Vec<_>, Vec<_>) = all_slots
+                .iter()
+                .filter_map(|slot_name| schema.slots.get(slot_name).map(|def| (slot_name, def)))
+                .partition(|(_, def)| def.identifier == Some(true));
+
+            // Identifiers go above the separator, as in IE notation
+            for (slot_name, slot_def) in &identifiers {
+                self.write_er_attribute(&mut output, slot_name, slot_def, schema)?;
+            }
+            if !identifiers.is_empty() {
+                writeln!(&mut output, "  --").map_err(Self::fmt_error_to_generator_error)?;
+            }
+            for (slot_name, slot_def) in &attributes {
+                self.write_er_attribute(&mut output, slot_name, slot_def, schema)?;
             }
 
             writeln!(&mut output, "}}").map_err(Self::fmt_error_to_generator_error)?;
@@ -520,15 +556,179 @@ impl PlantUmlGenerator {
                     && let Some(range) = &slot_def.range
                     && schema.classes.contains_key(range)
                 {
-                    let rel = if slot_def.multivalued.unwrap_or(false) {
-                        "}o--||"
-                    } else {
-                        "||--||"
-                    };
+                    let rel = Self::get_er_relation(slot_def);
+                    writeln!(&mut output, "{class_name} {rel} {range} : {slot_name}")
+                        .map_err(Self::fmt_error_to_generator_error)?;
+                }
+            }
+        }
+
+        writeln!(&mut output, "@enduml").map_err(Self::fmt_error_to_generator_error)?;
+
+        Ok(output)
+    }
+
+    /// Write a single ER attribute line with key stereotypes and cardinality
+    fn write_er_attribute(
+        &self,
+        output: &mut String,
+        slot_name: &str,
+        slot_def: &SlotDefinition,
+        schema: &SchemaDefinition,
+    ) -> GeneratorResult<()> {
+        let mandatory = if slot_def.required == Some(true) || slot_def.identifier == Some(true) {
+            "* "
+        } else {
+            ""
+        };
+        let type_str = slot_def.range.as_deref().unwrap_or("string");
+        write!(output, "  {mandatory}{slot_name} : {type_str}")
+            .map_err(Self::fmt_error_to_generator_error)?;
+
+        if self.options.show_cardinality {
+            let cardinality = if slot_def.identifier == Some(true) {
+                "1".to_string()
+            } else {
+                Self::get_cardinality(slot_def)
+            };
+            write!(output, " [{cardinality}]").map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        let mut stereotypes = Vec::new();
+        if slot_def.identifier == Some(true) {
+            stereotypes.push("PK");
+        } else if slot_def.key == Some(true) || slot_def.unique == Some(true) {
+            stereotypes.push("UK");
+        }
+        if slot_def
+            .range
+            .as_ref()
+            .is_some_and(|range| schema.classes.contains_key(range))
+        {
+            stereotypes.push("FK");
+        }
+        for stereotype in stereotypes {
+            write!(output, " <<{stereotype}>>").map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+        Ok(())
+    }
+
+    /// Get IE (crow's foot) relation for a reference slot
+    ///
+    /// The referencing side is always "zero or many"; the referenced side
+    /// reflects whether the slot is required and/or multivalued.
+    fn get_er_relation(slot: &SlotDefinition) -> &'static str {
+        match (
+            slot.required.unwrap_or(false),
+            slot.multivalued.unwrap_or(false),
+        ) {
+            (true, false) => "}o--||",
+            (false, false) => "}o--o|",
+            (true, true) => "}o--|{",
+            (false, true) => "}o--o{",
+        }
+    }
+
+    /// Generate a class diagram of a single class, its ancestors and its relations
+    ///
+    /// Relations (outgoing and incoming slot references) are followed up to
+    /// `depth` hops; ancestors are always included. The focus class is shown
+    /// with its slots and highlighted with the configured focus color, while
+    /// surrounding classes are rendered as plain boxes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::Configuration` if the class does not exist, or
+    /// `GeneratorError::Io` if writing the diagram fails.
+    pub fn generate_class_neighborhood(
+        &self,
+        schema: &SchemaDefinition,
+        class_name: &str,
+        depth: usize,
+    ) -> GeneratorResult<String> {
+        let neighborhood = ClassNeighborhood::compute(schema, class_name, depth)?;
+        let mut output = String::new();
+
+        writeln!(&mut output, "@startuml").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            &mut output,
+            "'PlantUML neighborhood diagram for {class_name}"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "title {class_name} neighborhood")
+            .map_err(Self::fmt_error_to_generator_error)?;
+
+        self.apply_skin(&mut output)?;
+        if self.options.direction.as_str() == "left to right" {
+            writeln!(&mut output, "left to right direction")
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+
+        for name in &neighborhood.classes {
+            let Some(class_def) = schema.classes.get(name) else {
+                continue;
+            };
+
+            if neighborhood.is_focus(name) {
+                self.generate_class(
+                    &mut output,
+                    name,
+                    class_def,
+                    schema,
+                    Some(&self.options.focus_color),
+                )?;
+            } else {
+                let keyword = if class_def.abstract_.unwrap_or(false) {
+                    "abstract class"
+                } else {
+                    "class"
+                };
+                let stereotype = if class_def.mixin.unwrap_or(false) {
+                    " <<mixin>>"
+                } else {
+                    ""
+                };
+                writeln!(&mut output, "{keyword} {name}{stereotype}")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+            }
+        }
+
+        writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "' Relationships").map_err(Self::fmt_error_to_generator_error)?;
 
-                    writeln!(&mut output, "{class_name} {rel} {range} : has")
+        for edge in &neighborhood.edges {
+            let (from, to) = (&edge.from, &edge.to);
+            match &edge.kind {
+                NeighborhoodEdgeKind::IsA => {
+                    writeln!(&mut output, "{from} --|> {to}")
+                        .map_err(Self::fmt_error_to_generator_error)?;
+                }
+                NeighborhoodEdgeKind::Mixin => {
+                    writeln!(&mut output, "{from} ..|> {to} : <<mixin>>")
                         .map_err(Self::fmt_error_to_generator_error)?;
                 }
+                NeighborhoodEdgeKind::Reference {
+                    slot,
+                    required,
+                    multivalued,
+                } => {
+                    if self.options.show_cardinality {
+                        let cardinality = match (required, multivalued) {
+                            (true, false) => "1",
+                            (false, false) => "0..1",
+                            (true, true) => "1..*",
+                            (false, true) => "*",
+                        };
+                        writeln!(&mut output, "{from} --> \"{cardinality}\" {to} : {slot}")
+                            .map_err(Self::fmt_error_to_generator_error)?;
+                    } else {
+                        writeln!(&mut output, "{from} --> {to} : {slot}")
+                            .map_err(Self::fmt_error_to_generator_error)?;
+                    }
+                }
             }
         }
 
@@ -852,6 +1052,7 @@ impl Generator for PlantUmlGenerator {
             PlantUmlDiagramType::State => "plantuml-state",
             PlantUmlDiagramType::MindMap => "plantuml-mindmap",
             PlantUmlDiagramType::Component => "plantuml-component",
+            PlantUmlDiagramType::Neighborhood => "plantuml-neighborhood",
         }
     }
 
@@ -982,9 +1183,63 @@ mod tests {
         assert!(output.contains("@startuml"));
         assert!(output.contains("!define ENTITY"));
         assert!(output.contains("TABLE(Person)"));
+        // Identifiers are listed above the separator with a PK stereotype
+        assert!(output.contains("  * id : string [1] <<PK>>\n  --\n  * name : string [1]"));
         Ok(())
     }
 
+    #[test]
+    fn test_er_relationships_use_slot_names() {
+        let mut schema = create_test_schema();
+        schema.classes.insert(
+            "Organization".to_string(),
+            ClassDefinition {
+                slots: vec!["members".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "members".to_string(),
+            SlotDefinition {
+                range: Some("Person".to_string()),
+                multivalued: Some(true),
+                required: Some(true),
+                ..Default::default()
+            },
+        );
+
+        let output = PlantUmlGenerator::new()
+            .with_diagram_type(PlantUmlDiagramType::EntityRelationship)
+            .generate(&schema)
+            .expect("should generate PlantUML ER diagram");
+
+        assert!(output.contains("Organization }o--|{ Person : members"));
+        assert!(output.contains("  * members : Person [1..*] <<FK>>"));
+    }
+
+    #[test]
+    fn test_neighborhood_diagram() {
+        let mut schema = create_test_schema();
+        schema
+            .classes
+            .insert("Unrelated".to_string(), ClassDefinition::default());
+
+        let output = PlantUmlGenerator::new()
+            .with_diagram_type(PlantUmlDiagramType::Neighborhood)
+            .with_focus_class("Person")
+            .generate(&schema)
+            .expect("should generate PlantUML neighborhood diagram");
+
+        assert!(output.contains("title Person neighborhood"));
+        assert!(output.contains("class Person #FFD966 {"));
+        assert!(output.contains("abstract class Base"));
+        assert!(output.contains("Person --|> Base"));
+        assert!(!output.contains("Unrelated"));
+
+        let missing = PlantUmlGenerator::new().with_diagram_type(PlantUmlDiagramType::Neighborhood);
+        assert!(missing.generate(&schema).is_err());
+    }
+
     #[tokio::test]
     async fn test_mindmap_generation() -> anyhow::Result<()> {
         let schema = create_test_schema();
@@ -92,6 +92,13 @@ impl PrefixMapGenerator {
         }
     }
 
+    /// Additional prefixes in prefix order, independent of hash order
+    fn additional_prefixes(&self) -> Vec<(&String, &String)> {
+        let mut prefixes: Vec<_> = self.config.additional_prefixes.iter().collect();
+        prefixes.sort();
+        prefixes
+    }
+
     /// Generate prefix map in the configured format
     fn generate_prefix_map(&self, schema: &SchemaDefinition) -> Result<String, LinkMLError> {
         match self.config.format {
@@ -119,7 +126,7 @@ impl PrefixMapGenerator {
         }
 
         // Add additional prefixes
-        for (prefix, uri) in &self.config.additional_prefixes {
+        for (prefix, uri) in self.additional_prefixes() {
             if self.config.validate_prefixes {
                 Self::validate_prefix(prefix, uri)?;
             }
@@ -188,7 +195,7 @@ impl PrefixMapGenerator {
         }
 
         // Add additional prefixes
-        for (prefix, uri) in &self.config.additional_prefixes {
+        for (prefix, uri) in self.additional_prefixes() {
             let mut prefix_info = Map::new();
             prefix_info.insert("uri".to_string(), json!(uri));
             prefix_info.insert("custom".to_string(), json!(true));
@@ -232,7 +239,7 @@ impl PrefixMapGenerator {
         }
 
         // Add additional prefixes
-        for (prefix, uri) in &self.config.additional_prefixes {
+        for (prefix, uri) in self.additional_prefixes() {
             lines.push(format!("@prefix {prefix}: <{uri}> ."));
         }
 
@@ -290,7 +297,7 @@ impl PrefixMapGenerator {
         if !self.config.additional_prefixes.is_empty() {
             lines.push(String::new());
             lines.push("  # Additional prefixes".to_string());
-            for (prefix, uri) in &self.config.additional_prefixes {
+            for (prefix, uri) in self.additional_prefixes() {
                 lines.push(format!("  {prefix}: {uri}"));
             }
         }
@@ -322,7 +329,7 @@ impl PrefixMapGenerator {
         }
 
         // Additional prefixes
-        for (prefix, uri) in &self.config.additional_prefixes {
+        for (prefix, uri) in self.additional_prefixes() {
             lines.push(format!("{prefix},{uri},custom,false"));
         }
 
@@ -417,11 +417,13 @@ mod tests {
                     text: "pending".to_string(),
                     description: Some("Pending status".to_string()),
                     meaning: None,
+                    is_a: None,
                 },
                 PermissibleValue::Complex {
                     text: "approved".to_string(),
                     description: Some("Approved status".to_string()),
                     meaning: None,
+                    is_a: None,
                 },
             ],
             ..Default::default()
@@ -0,0 +1,292 @@
+//! Provenance headers of generated artifacts
+//!
+//! With [`GeneratorOptions::provenance`](super::GeneratorOptions::provenance)
+//! set, generated output starts with a comment recording the schema it was
+//! generated from (id, version and a hash of its content), the generator and
+//! its version, and when it was generated:
+//!
+//! ```text
+//! # Code generated by the LinkML python generator 2.0.0. DO NOT EDIT.
+//! # schema-id: https://example.org/people
+//! # schema-version: 1.2.0
+//! # schema-sha256: 5f0c…
+//! # generated-at: 2026-10-16T09:30:00Z
+//! ```
+//!
+//! The first line follows the Go convention for generated files, so linters
+//! and review tools skip them. Formats without comments (JSON, CSV, …) are
+//! left unstamped. [`is_up_to_date`] compares an existing artifact with a
+//! fresh generation while ignoring the timestamp, which is what
+//! `linkml generate --check` uses to detect drift in CI.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+
+/// Start of the first header line, identifying a provenance header
+const HEADER_START: &str = "Code generated by the LinkML ";
+
+const SCHEMA_ID: &str = "schema-id: ";
+const SCHEMA_VERSION: &str = "schema-version: ";
+const SCHEMA_HASH: &str = "schema-sha256: ";
+const GENERATED_AT: &str = "generated-at: ";
+
+/// Where and by what an artifact was generated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Provenance {
+    /// Schema id
+    pub schema_id: String,
+    /// Schema version, if the schema declares one
+    pub schema_version: Option<String>,
+    /// SHA-256 of the schema content, hex encoded
+    pub schema_hash: String,
+    /// Generator name
+    pub generator: String,
+    /// Version of the generator
+    pub generator_version: String,
+    /// Generation time
+    pub generated_at: DateTime<Utc>,
+}
+
+impl Provenance {
+    /// Provenance of output generated from `schema` by `generator` at
+    /// `generated_at`, which callers take from the timestamp service
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema cannot be serialized for hashing
+    pub fn new(
+        schema: &SchemaDefinition,
+        generator: &str,
+        generated_at: DateTime<Utc>,
+    ) -> Result<Self> {
+        Ok(Self {
+            schema_id: schema.id.clone(),
+            schema_version: schema.version.clone(),
+            schema_hash: Self::schema_hash(schema)?,
+            generator: generator.to_string(),
+            generator_version: env!("CARGO_PKG_VERSION").to_string(),
+            generated_at,
+        })
+    }
+
+    /// SHA-256 of a schema's content, hex encoded
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema cannot be serialized
+    pub fn schema_hash(schema: &SchemaDefinition) -> Result<String> {
+        let bytes = serde_json::to_vec(schema)
+            .map_err(|e| LinkMLError::SerializationError(e.to_string()))?;
+        Ok(format!("{:x}", Sha256::digest(&bytes)))
+    }
+
+    /// Header lines without comment delimiters
+    fn lines(&self) -> Vec<String> {
+        vec![
+            format!(
+                "{HEADER_START}{} generator {}. DO NOT EDIT.",
+                self.generator, self.generator_version
+            ),
+            format!("{SCHEMA_ID}{}", self.schema_id),
+            format!(
+                "{SCHEMA_VERSION}{}",
+                self.schema_version.as_deref().unwrap_or("unversioned")
+            ),
+            format!("{SCHEMA_HASH}{}", self.schema_hash),
+            format!(
+                "{GENERATED_AT}{}",
+                self.generated_at.to_rfc3339_opts(SecondsFormat::Secs, true)
+            ),
+        ]
+    }
+
+    /// Prepend the header to output with file extension `extension`
+    ///
+    /// Output of formats without comments is returned unchanged. The header
+    /// goes after a leading shebang, XML declaration or doctype, which must
+    /// stay first.
+    #[must_use]
+    pub fn stamp(&self, extension: &str, content: &str) -> String {
+        let Some((open, close)) = comment_delimiters(extension) else {
+            return content.to_string();
+        };
+        let mut header = String::new();
+        for line in self.lines() {
+            let _ = writeln!(header, "{open}{line}{close}");
+        }
+
+        let first_line_end = content.find('\n').map_or(content.len(), |end| end + 1);
+        let first_line = content[..first_line_end].trim_start().to_ascii_lowercase();
+        if ["#!", "<?xml", "<!doctype"]
+            .iter()
+            .any(|prefix| first_line.starts_with(prefix))
+        {
+            let (first, rest) = content.split_at(first_line_end);
+            let newline = if first.ends_with('\n') { "" } else { "\n" };
+            format!("{first}{newline}{header}{rest}")
+        } else {
+            format!("{header}{content}")
+        }
+    }
+
+    /// Read the header of stamped output, if it has one
+    #[must_use]
+    pub fn parse(extension: &str, content: &str) -> Option<Self> {
+        let (_, lines) = header_lines(extension, content)?;
+        let first = lines.first()?.strip_prefix(HEADER_START)?;
+        let (generator, version) = first
+            .strip_suffix(". DO NOT EDIT.")?
+            .split_once(" generator ")?;
+        let value = |key: &str| lines.iter().find_map(|line| line.strip_prefix(key));
+        Some(Self {
+            schema_id: value(SCHEMA_ID)?.to_string(),
+            schema_version: value(SCHEMA_VERSION)
+                .filter(|version| *version != "unversioned")
+                .map(str::to_string),
+            schema_hash: value(SCHEMA_HASH)?.to_string(),
+            generator: generator.to_string(),
+            generator_version: version.to_string(),
+            generated_at: DateTime::parse_from_rfc3339(value(GENERATED_AT)?)
+                .ok()?
+                .with_timezone(&Utc),
+        })
+    }
+}
+
+/// Line comment delimiters of an output format, by file extension
+#[must_use]
+pub fn comment_delimiters(extension: &str) -> Option<(&'static str, &'static str)> {
+    let extension = extension
+        .rsplit('.')
+        .next()
+        .unwrap_or(extension)
+        .to_ascii_lowercase();
+    match extension.as_str() {
+        "py" | "yaml" | "yml" | "ttl" | "owl" | "nt" | "tql" | "shex" | "sparql" | "rq"
+        | "graphql" | "toml" | "sh" | "r" => Some(("# ", "")),
+        "rs" | "ts" | "js" | "java" | "go" | "proto" | "cypher" | "dot" | "kt" | "scala" | "cs"
+        | "swift" | "c" | "h" | "cpp" => Some(("// ", "")),
+        "sql" => Some(("-- ", "")),
+        "md" | "html" | "rdf" | "xml" | "xsd" | "svg" => Some(("<!-- ", " -->")),
+        "puml" => Some(("' ", "")),
+        "mmd" => Some(("%% ", "")),
+        "css" => Some(("/* ", " */")),
+        _ => None,
+    }
+}
+
+/// Whether `existing` output matches a fresh generation `regenerated`
+///
+/// Both are compared including their provenance headers, except for the
+/// generation time, so a changed schema, generator version or generator
+/// output all count as drift.
+#[must_use]
+pub fn is_up_to_date(extension: &str, existing: &str, regenerated: &str) -> bool {
+    without_timestamp(extension, existing) == without_timestamp(extension, regenerated)
+}
+
+/// Output with the `generated-at` line of its header removed
+fn without_timestamp(extension: &str, content: &str) -> String {
+    let Some((start, header)) = header_lines(extension, content) else {
+        return content.to_string();
+    };
+    let Some(offset) = header
+        .iter()
+        .position(|line| line.starts_with(GENERATED_AT))
+    else {
+        return content.to_string();
+    };
+    content
+        .split_inclusive('\n')
+        .enumerate()
+        .filter(|(index, _)| *index != start + offset)
+        .map(|(_, line)| line)
+        .collect()
+}
+
+/// Index of the first header line and the header lines without comment
+/// delimiters
+fn header_lines<'a>(extension: &str, content: &'a str) -> Option<(usize, Vec<&'a str>)> {
+    let (open, close) = comment_delimiters(extension)?;
+    let uncomment = |line: &'a str| {
+        line.trim_end()
+            .strip_prefix(open)
+            .and_then(|line| line.strip_suffix(close))
+    };
+    // The header is first, or second after a shebang, XML declaration or
+    // doctype
+    let start = content
+        .lines()
+        .take(2)
+        .position(|line| uncomment(line).is_some_and(|line| line.starts_with(HEADER_START)))?;
+    let lines = content
+        .lines()
+        .skip(start)
+        .map_while(uncomment)
+        .take(5)
+        .collect();
+    Some((start, lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn schema(description: &str) -> SchemaDefinition {
+        SchemaDefinition {
+            id: "https://example.org/people".to_string(),
+            name: "people".to_string(),
+            version: Some("1.2.0".to_string()),
+            description: Some(description.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn provenance(schema: &SchemaDefinition, hour: u32) -> Provenance {
+        let generated_at = Utc
+            .with_ymd_and_hms(2026, 10, 16, hour, 0, 0)
+            .single()
+            .expect("valid time");
+        Provenance::new(schema, "python", generated_at).expect("provenance")
+    }
+
+    #[test]
+    fn test_stamp_parse_and_drift() {
+        let people = schema("People");
+        let morning = provenance(&people, 9);
+        let stamped = morning.stamp("py", "class Person:\n    pass\n");
+        assert!(stamped.starts_with("# Code generated by the LinkML python generator "));
+        assert!(stamped.contains("# schema-version: 1.2.0\n"));
+        assert!(
+            stamped.ends_with("# generated-at: 2026-10-16T09:00:00Z\nclass Person:\n    pass\n")
+        );
+        assert_eq!(Provenance::parse("py", &stamped).as_ref(), Some(&morning));
+
+        // Regenerating later from the same schema is not drift
+        let later = provenance(&people, 17).stamp("py", "class Person:\n    pass\n");
+        assert!(is_up_to_date("py", &stamped, &later));
+        assert!(!is_up_to_date(
+            "py",
+            &stamped,
+            &later.replace("pass", "...")
+        ));
+
+        // A changed schema is drift even when the code is the same, through the hash
+        let edited = provenance(&schema("All people"), 9).stamp("py", "class Person:\n    pass\n");
+        assert!(!is_up_to_date("py", &stamped, &edited));
+
+        // Headers go after a doctype; formats without comments stay unchanged
+        let html = morning.stamp("html", "<!DOCTYPE html>\n<html></html>\n");
+        assert!(html.starts_with("<!DOCTYPE html>\n<!-- Code generated by the LinkML "));
+        assert_eq!(
+            Provenance::parse("html", &html).map(|found| found.schema_hash),
+            Some(Provenance::schema_hash(&people).expect("hash"))
+        );
+        assert_eq!(morning.stamp("json", "{}"), "{}");
+        assert!(Provenance::parse("py", "class Person: pass\n").is_none());
+    }
+}
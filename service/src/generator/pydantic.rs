@@ -1,8 +1,10 @@
 //! Pydantic v2 code generator for `LinkML` schemas
 
+use super::annotation_hints;
 use super::base::{
     BaseCodeFormatter, ImportManager, TypeMapper, collect_all_slots, is_optional_slot,
 };
+use super::example_tests;
 use super::options::{GeneratorOptions, IndentStyle};
 use super::traits::{CodeFormatter, Generator, GeneratorError, GeneratorResult};
 use linkml_core::error::LinkMLError;
@@ -47,6 +49,20 @@ impl PydanticGenerator {
         GeneratorError::Io(std::io::Error::other(e))
     }
 
+    /// Base classes: the parent class first, then any from the
+    /// `python:base_class` annotation, falling back to `BaseModel`
+    fn class_bases(class: &ClassDefinition) -> String {
+        let mut bases: Vec<String> = class.is_a.iter().cloned().collect();
+        bases.extend(annotation_hints::strings(
+            class,
+            annotation_hints::PYTHON_BASE_CLASS,
+        ));
+        if bases.is_empty() {
+            bases.push("BaseModel".to_string());
+        }
+        bases.join(", ")
+    }
+
     /// Generate code for a single class
     fn generate_class(
         &self,
@@ -61,15 +77,9 @@ impl PydanticGenerator {
         // Always need BaseModel
         imports.add_import("pydantic", "BaseModel");
 
-        // Check if we need inheritance
-        let base_class = if let Some(ref parent) = class.is_a {
-            parent.clone()
-        } else {
-            "BaseModel".to_string()
-        };
-
         // Generate class definition
-        writeln!(&mut output, "class {class_name}({base_class}):")
+        let bases = Self::class_bases(class);
+        writeln!(&mut output, "class {class_name}({bases}):")
             .map_err(Self::fmt_error_to_generator_error)?;
 
         // Generate class documentation
@@ -514,6 +524,65 @@ impl Generator for PydanticGenerator {
 }
 
 impl PydanticGenerator {
+    /// Generate a pytest module asserting that schema examples validate and
+    /// counterexamples raise `ValidationError`; see [`example_tests`]
+    ///
+    /// `module` is the import path of the generated models, e.g.
+    /// `schema_pydantic`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the test cases cannot be derived
+    pub fn generate_tests(
+        &self,
+        schema: &SchemaDefinition,
+        module: &str,
+    ) -> GeneratorResult<String> {
+        let cases = example_tests::example_cases(schema)?;
+        let mut classes: Vec<&str> = cases.iter().map(|case| case.class_name.as_str()).collect();
+        classes.dedup();
+
+        let mut output = String::new();
+        writeln!(
+            output,
+            "\"\"\"Tests generated from examples in LinkML schema: {}\"\"\"",
+            schema.name
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "import pytest").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "from pydantic import ValidationError")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        if !classes.is_empty() {
+            writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(output, "from {module} import {}", classes.join(", "))
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        for case in &cases {
+            let data = python_literal(&case.data);
+            let description = case.description.replace(['\n', '\r'], " ");
+            writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(output, "def test_{}():", case.test_name())
+                .map_err(Self::fmt_error_to_generator_error)?;
+            if case.valid {
+                writeln!(output, "    # {description}")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(output, "    {}.model_validate({data})", case.class_name)
+                    .map_err(Self::fmt_error_to_generator_error)?;
+            } else {
+                writeln!(output, "    # Counterexample: {description}")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(output, "    with pytest.raises(ValidationError):")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(output, "        {}.model_validate({data})", case.class_name)
+                    .map_err(Self::fmt_error_to_generator_error)?;
+            }
+        }
+        Ok(output)
+    }
+
     /// Generate an enum from permissible values
     fn generate_enum(
         &self,
@@ -544,6 +613,35 @@ impl PydanticGenerator {
             }
         }
 
+        Self::write_parent_map(output, &enum_name, &slot.permissible_values)
+    }
+
+    /// Write a `<ENUM>_PARENTS` dict mapping values to their `is_a` parent
+    fn write_parent_map(
+        output: &mut String,
+        enum_name: &str,
+        values: &[PermissibleValue],
+    ) -> GeneratorResult<()> {
+        let parents: Vec<(&str, &str)> = values
+            .iter()
+            .filter_map(|pv| Some((pv.text(), pv.is_a()?)))
+            .collect();
+        if parents.is_empty() {
+            return Ok(());
+        }
+
+        let map_name = format!(
+            "{}_PARENTS",
+            BaseCodeFormatter::to_snake_case(enum_name).to_uppercase()
+        );
+        writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "{map_name}: dict[str, str] = {{")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        for (value, parent) in parents {
+            writeln!(output, "    \"{value}\": \"{parent}\",")
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        writeln!(output, "}}").map_err(Self::fmt_error_to_generator_error)?;
         Ok(())
     }
 }
@@ -638,6 +736,38 @@ impl CodeFormatter for PydanticGenerator {
     }
 }
 
+/// Python literal for a JSON value
+fn python_literal(value: &serde_json::Value) -> String {
+    use serde_json::Value;
+    match value {
+        Value::Null => "None".to_string(),
+        Value::Bool(true) => "True".to_string(),
+        Value::Bool(false) => "False".to_string(),
+        Value::Number(number) => number.to_string(),
+        // JSON string escapes are valid in Python string literals
+        Value::String(_) => value.to_string(),
+        Value::Array(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(python_literal)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::Object(map) => format!(
+            "{{{}}}",
+            map.iter()
+                .map(|(key, value)| format!(
+                    "{}: {}",
+                    Value::from(key.as_str()),
+                    python_literal(value)
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -691,4 +821,89 @@ mod tests {
         assert!(output.contains("age: Optional[int] = Field(None)"));
         assert!(output.contains("model_config ="));
     }
+
+    #[test]
+    fn test_generate_tests_from_examples() {
+        let mut schema = SchemaDefinition {
+            name: "test_schema".to_string(),
+            ..Default::default()
+        };
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: vec!["name".to_string(), "active".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "name".to_string(),
+            SlotDefinition {
+                required: Some(true),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "active".to_string(),
+            SlotDefinition {
+                range: Some("boolean".to_string()),
+                examples: vec![linkml_core::metadata::Example {
+                    value: "false".to_string(),
+                    description: None,
+                }],
+                ..Default::default()
+            },
+        );
+
+        let output = PydanticGenerator::new()
+            .generate_tests(&schema, "people")
+            .expect("should generate tests");
+        assert!(output.contains("from people import Person"));
+        assert!(
+            output
+                .contains("def test_person_active_example_0():\n    # Example for active: false\n")
+        );
+        assert!(output.contains("\"active\": False"));
+        assert!(output.contains(
+            "def test_person_missing_name():\n    # Counterexample: name is required\n    with pytest.raises(ValidationError):\n        Person.model_validate({})"
+        ));
+    }
+
+    #[test]
+    fn test_enum_parent_map() {
+        let mut schema = SchemaDefinition {
+            name: "anatomy".to_string(),
+            ..Default::default()
+        };
+        schema.classes.insert(
+            "Observation".to_string(),
+            ClassDefinition {
+                slots: vec!["body_part".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "body_part".to_string(),
+            SlotDefinition {
+                permissible_values: vec![
+                    PermissibleValue::Simple("arm".to_string()),
+                    PermissibleValue::Complex {
+                        text: "upper_arm".to_string(),
+                        description: None,
+                        meaning: None,
+                        is_a: Some("arm".to_string()),
+                    },
+                ],
+                ..Default::default()
+            },
+        );
+
+        let output = PydanticGenerator::new()
+            .generate(&schema)
+            .expect("should generate Pydantic output");
+        assert!(output.contains("class BodyPart(str, Enum):"));
+        assert!(
+            output
+                .contains("BODY_PART_PARENTS: dict[str, str] = {\n    \"upper_arm\": \"arm\",\n}")
+        );
+    }
 }
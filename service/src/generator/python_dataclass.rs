@@ -1,5 +1,6 @@
 //! Python dataclass code generator for `LinkML` schemas
 
+use super::annotation_hints;
 use super::base::{
     BaseCodeFormatter, ImportManager, TypeMapper, collect_all_slots, get_default_value_str,
     is_optional_slot,
@@ -124,6 +125,14 @@ impl PythonDataclassGenerator {
         // Always need dataclass
         imports.add_import("dataclasses", "dataclass");
 
+        // Bases from the `python:base_class` annotation
+        let bases = annotation_hints::strings(class, annotation_hints::PYTHON_BASE_CLASS);
+        let bases = if bases.is_empty() {
+            String::new()
+        } else {
+            format!("({})", bases.join(", "))
+        };
+
         // Generate class documentation
         if options.include_docs
             && (class.description.is_some()
@@ -132,7 +141,7 @@ impl PythonDataclassGenerator {
                     .is_some_and(|v| v == "true"))
         {
             writeln!(&mut output, "@dataclass").map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut output, "class {class_name}:")
+            writeln!(&mut output, "class {class_name}{bases}:")
                 .map_err(Self::fmt_error_to_generator_error)?;
             writeln!(&mut output, "    \"\"\"").map_err(Self::fmt_error_to_generator_error)?;
 
@@ -162,7 +171,7 @@ impl PythonDataclassGenerator {
             writeln!(&mut output, "    \"\"\"").map_err(Self::fmt_error_to_generator_error)?;
         } else {
             writeln!(&mut output, "@dataclass").map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut output, "class {class_name}:")
+            writeln!(&mut output, "class {class_name}{bases}:")
                 .map_err(Self::fmt_error_to_generator_error)?;
         }
 
@@ -591,6 +600,35 @@ impl PythonDataclassGenerator {
             }
         }
 
+        Self::write_parent_map(output, &enum_name, &slot.permissible_values)
+    }
+
+    /// Write a `<ENUM>_PARENTS` dict mapping values to their `is_a` parent
+    fn write_parent_map(
+        output: &mut String,
+        enum_name: &str,
+        values: &[PermissibleValue],
+    ) -> GeneratorResult<()> {
+        let parents: Vec<(&str, &str)> = values
+            .iter()
+            .filter_map(|pv| Some((pv.text(), pv.is_a()?)))
+            .collect();
+        if parents.is_empty() {
+            return Ok(());
+        }
+
+        let map_name = format!(
+            "{}_PARENTS",
+            BaseCodeFormatter::to_snake_case(enum_name).to_uppercase()
+        );
+        writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "{map_name}: dict[str, str] = {{")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        for (value, parent) in parents {
+            writeln!(output, "    \"{value}\": \"{parent}\",")
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        writeln!(output, "}}").map_err(Self::fmt_error_to_generator_error)?;
         Ok(())
     }
 }
@@ -2,9 +2,19 @@
 //!
 //! This generator produces plain RDF/Turtle representation of `LinkML` schemas,
 //! focusing on the data model rather than OWL ontology features.
-
+//!
+//! In OWL mode ([`RdfGenerator::owl`]) the schema becomes an OWL 2 ontology
+//! instead, like Python's `gen-owl`: classes with `rdfs:subClassOf` axioms
+//! from `is_a` and mixins, object and datatype properties with domains and
+//! ranges, cardinality and value restrictions from the slots of each class,
+//! and element metadata as annotation properties. The axioms stay within the
+//! chosen [`OwlProfile`].
+
+use super::base::induced_slots;
 use super::traits::Generator;
+use linkml_core::annotations::{AnnotationValue, Annotations};
 use linkml_core::prelude::*;
+use std::collections::BTreeSet;
 use std::fmt::Write;
 
 /// Helper macro to convert `fmt::Error` to `LinkML`Error with newline
@@ -14,6 +24,28 @@ macro_rules! writeln_rdf {
     };
 }
 
+/// OWL 2 profile the axioms of the OWL mode stay within
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OwlProfile {
+    /// OWL 2 DL: cardinality restrictions, enumerations and union domains
+    #[default]
+    Dl,
+    /// OWL 2 RL: only axioms rule engines apply, so minimum cardinalities,
+    /// `owl:oneOf` enumerations and union domains are left out
+    Rl,
+}
+
+impl OwlProfile {
+    /// IRI identifying the profile
+    #[must_use]
+    pub fn iri(self) -> &'static str {
+        match self {
+            Self::Dl => "http://www.w3.org/ns/owl-profile/DL",
+            Self::Rl => "http://www.w3.org/ns/owl-profile/RL",
+        }
+    }
+}
+
 /// RDF/Turtle generator for `LinkML` schemas
 pub struct RdfGenerator {
     /// Base URI for the schema
@@ -24,6 +56,8 @@ pub struct RdfGenerator {
     compact_syntax: bool,
     /// Whether to include `LinkML`-specific properties
     include_linkml_props: bool,
+    /// Profile of the OWL axioms, generating an OWL ontology when set
+    owl_profile: Option<OwlProfile>,
     /// Generator options
     options: super::traits::GeneratorOptions,
 }
@@ -43,6 +77,7 @@ impl RdfGenerator {
             include_metadata: true,
             compact_syntax: true,
             include_linkml_props: true,
+            owl_profile: None,
             options: super::traits::GeneratorOptions::default(),
         }
     }
@@ -63,6 +98,7 @@ impl RdfGenerator {
             include_metadata: true,
             compact_syntax: false,
             include_linkml_props: false,
+            owl_profile: None,
             options: super::traits::GeneratorOptions::default(),
         }
     }
@@ -75,10 +111,31 @@ impl RdfGenerator {
             include_metadata: false,
             compact_syntax: true,
             include_linkml_props: false,
+            owl_profile: None,
+            options: super::traits::GeneratorOptions::default(),
+        }
+    }
+
+    /// Create a new RDF generator in OWL mode with the OWL 2 DL profile
+    #[must_use]
+    pub fn owl() -> Self {
+        Self {
+            base_uri: None,
+            include_metadata: true,
+            compact_syntax: false,
+            include_linkml_props: false,
+            owl_profile: Some(OwlProfile::Dl),
             options: super::traits::GeneratorOptions::default(),
         }
     }
 
+    /// Generate an OWL ontology within `profile`
+    #[must_use]
+    pub fn with_owl_profile(mut self, profile: OwlProfile) -> Self {
+        self.owl_profile = Some(profile);
+        self
+    }
+
     /// Set the base URI
     #[must_use]
     pub fn with_base_uri(mut self, uri: String) -> Self {
@@ -108,6 +165,18 @@ impl RdfGenerator {
         // Write prefixes
         self.write_prefixes(&mut output, schema, base_uri)?;
 
+        if let Some(profile) = self.owl_profile {
+            let mut writer = OwlWriter {
+                schema,
+                base_uri,
+                profile,
+                output,
+                annotation_properties: BTreeSet::new(),
+            };
+            writer.write_ontology()?;
+            return Ok(writer.output);
+        }
+
         // Write schema metadata
         self.write_schema_metadata(&mut output, schema, base_uri)?;
 
@@ -163,6 +232,10 @@ impl RdfGenerator {
             writeln_rdf!(output, "@prefix linkml: <https://w3id.org/linkml/> .")?;
         }
 
+        if self.owl_profile.is_some() {
+            writeln_rdf!(output, "@prefix owl: <http://www.w3.org/2002/07/owl#> .")?;
+        }
+
         // Schema-specific prefixes
         for (prefix, def) in &schema.prefixes {
             let reference = match def {
@@ -522,6 +595,7 @@ impl RdfGenerator {
                     text,
                     description,
                     meaning,
+                    ..
                 } => (text.as_str(), description.as_deref(), meaning.as_deref()),
             };
             writeln_rdf!(output, ":{} a <{}> ;", text.replace(' ', "_"), enum_uri)?;
@@ -573,6 +647,455 @@ fn escape_literal(s: &str) -> String {
         .replace('\t', "\\t")
 }
 
+/// Documentation, mappings and annotations of a class or slot
+struct Metadata<'a> {
+    description: Option<&'a String>,
+    aliases: &'a [String],
+    mappings: [(&'static str, &'a [String]); 5],
+    see_also: &'a [String],
+    deprecated: bool,
+    annotations: Option<&'a Annotations>,
+}
+
+impl<'a> From<&'a ClassDefinition> for Metadata<'a> {
+    fn from(class: &'a ClassDefinition) -> Self {
+        Self {
+            description: class.description.as_ref(),
+            aliases: &class.aliases,
+            mappings: [
+                ("skos:exactMatch", &class.exact_mappings),
+                ("skos:closeMatch", &class.close_mappings),
+                ("skos:relatedMatch", &class.related_mappings),
+                ("skos:narrowMatch", &class.narrow_mappings),
+                ("skos:broadMatch", &class.broad_mappings),
+            ],
+            see_also: &class.see_also,
+            deprecated: class.deprecated.is_some(),
+            annotations: class.annotations.as_ref(),
+        }
+    }
+}
+
+impl<'a> From<&'a SlotDefinition> for Metadata<'a> {
+    fn from(slot: &'a SlotDefinition) -> Self {
+        Self {
+            description: slot.description.as_ref(),
+            aliases: &slot.aliases,
+            mappings: [
+                ("skos:exactMatch", &slot.exact_mappings),
+                ("skos:closeMatch", &slot.close_mappings),
+                ("skos:relatedMatch", &slot.related_mappings),
+                ("skos:narrowMatch", &slot.narrow_mappings),
+                ("skos:broadMatch", &slot.broad_mappings),
+            ],
+            see_also: &slot.see_also,
+            deprecated: slot.deprecated.is_some(),
+            annotations: slot.annotations.as_ref(),
+        }
+    }
+}
+
+/// Writer of the OWL axioms of a schema
+struct OwlWriter<'a> {
+    schema: &'a SchemaDefinition,
+    base_uri: &'a str,
+    profile: OwlProfile,
+    output: String,
+    /// Annotation properties used, declared after all entities
+    annotation_properties: BTreeSet<String>,
+}
+
+impl OwlWriter<'_> {
+    fn write_ontology(&mut self) -> Result<()> {
+        let schema = self.schema;
+        let mut header = vec![
+            ("rdfs:label".to_string(), literal(&schema.name)),
+            (
+                "dcterms:conformsTo".to_string(),
+                format!("<{}>", self.profile.iri()),
+            ),
+        ];
+        if let Some(title) = &schema.title {
+            header.push(("dcterms:title".to_string(), literal(title)));
+        }
+        if let Some(description) = &schema.description {
+            header.push(("dcterms:description".to_string(), literal(description)));
+        }
+        if let Some(version) = &schema.version {
+            header.push(("owl:versionInfo".to_string(), literal(version)));
+        }
+        if let Some(license) = &schema.license {
+            header.push(("dcterms:license".to_string(), literal(license)));
+        }
+        for import in schema
+            .imports
+            .iter()
+            .filter(|import| import.contains("://"))
+        {
+            header.push(("owl:imports".to_string(), format!("<{import}>")));
+        }
+        self.annotation_properties.extend(
+            header
+                .iter()
+                .filter(|(property, _)| property.starts_with("dcterms:"))
+                .map(|(property, _)| property.clone()),
+        );
+        self.statement(&format!("<{}>", self.base_uri), "owl:Ontology", &header)?;
+
+        for (name, class) in &schema.classes {
+            self.write_class(name, class)?;
+        }
+        let mut written = BTreeSet::new();
+        for (name, slot) in &schema.slots {
+            written.insert(name.as_str());
+            self.write_property(name, slot)?;
+        }
+        for class in schema.classes.values() {
+            for (name, slot) in &class.attributes {
+                if written.insert(name.as_str()) {
+                    self.write_property(name, slot)?;
+                }
+            }
+        }
+        for (name, type_def) in &schema.types {
+            self.write_datatype(name, type_def)?;
+        }
+        for (name, enum_def) in &schema.enums {
+            self.write_enum(name, enum_def)?;
+        }
+
+        // Annotation properties of RDFS and OWL are built in
+        for property in std::mem::take(&mut self.annotation_properties) {
+            if !property.starts_with("rdfs:") && !property.starts_with("owl:") {
+                self.statement(&property, "owl:AnnotationProperty", &[])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// `owl:Class` with its superclasses and slot restrictions
+    fn write_class(&mut self, name: &str, class: &ClassDefinition) -> Result<()> {
+        let mut pairs = vec![("rdfs:label".to_string(), literal(name))];
+        self.metadata(&mut pairs, &Metadata::from(class));
+
+        let parents = class.is_a.iter().chain(&class.mixins);
+        for parent in parents {
+            pairs.push(("rdfs:subClassOf".to_string(), self.class_term(parent)));
+        }
+        for parent in &class.subclass_of {
+            pairs.push(("rdfs:subClassOf".to_string(), iri(parent)));
+        }
+
+        // Slots the class declares or refines; inherited restrictions apply
+        // through the superclasses
+        let own: Vec<&String> = class
+            .slots
+            .iter()
+            .chain(class.attributes.keys())
+            .chain(class.slot_usage.keys())
+            .collect();
+        let slots: Vec<SlotDefinition> = induced_slots(self.schema, name)
+            .into_iter()
+            .filter(|slot| own.contains(&&slot.name))
+            .collect();
+        for slot in &slots {
+            for restriction in self.restrictions(slot) {
+                pairs.push(("rdfs:subClassOf".to_string(), restriction));
+            }
+        }
+        let keys: Vec<String> = slots
+            .iter()
+            .filter(|slot| slot.identifier == Some(true))
+            .map(|slot| self.slot_term(&slot.name))
+            .collect();
+        if !keys.is_empty() {
+            pairs.push(("owl:hasKey".to_string(), format!("( {} )", keys.join(" "))));
+        }
+
+        self.statement(&self.class_term(name), "owl:Class", &pairs)
+    }
+
+    /// Value and cardinality restrictions a slot places on its class
+    fn restrictions(&self, slot: &SlotDefinition) -> Vec<String> {
+        let property = self.slot_term(&slot.name);
+        let mut restrictions = Vec::new();
+        if let Some(range) = &slot.range {
+            restrictions.push(format!(
+                "[ a owl:Restriction ; owl:onProperty {property} ; owl:allValuesFrom {} ]",
+                self.range_term(range)
+            ));
+        }
+
+        let required = slot.required == Some(true) || slot.identifier == Some(true);
+        let single = slot.multivalued != Some(true);
+        let cardinality = match (self.profile, required, single) {
+            (OwlProfile::Dl, true, true) => Some("owl:cardinality"),
+            (OwlProfile::Dl, true, false) => Some("owl:minCardinality"),
+            // Maximum cardinalities of 0 or 1 are the only ones within RL
+            (_, _, true) => Some("owl:maxCardinality"),
+            _ => None,
+        };
+        if let Some(cardinality) = cardinality {
+            restrictions.push(format!(
+                "[ a owl:Restriction ; owl:onProperty {property} ; {cardinality} \"1\"^^xsd:nonNegativeInteger ]"
+            ));
+        }
+        restrictions
+    }
+
+    /// Object or datatype property of a slot
+    fn write_property(&mut self, name: &str, slot: &SlotDefinition) -> Result<()> {
+        let object = slot.range.as_deref().is_some_and(|range| {
+            self.schema.classes.contains_key(range) || self.schema.enums.contains_key(range)
+        });
+        let mut pairs = vec![("rdfs:label".to_string(), literal(name))];
+        self.metadata(&mut pairs, &Metadata::from(slot));
+
+        let domains: Vec<String> = match &slot.domain {
+            Some(domain) => vec![self.class_term(domain)],
+            None => self
+                .schema
+                .classes
+                .iter()
+                .filter(|(_, class)| {
+                    class.slots.iter().any(|slot_name| slot_name == name)
+                        || class.attributes.contains_key(name)
+                })
+                .map(|(class_name, _)| self.class_term(class_name))
+                .collect(),
+        };
+        match (domains.len(), self.profile) {
+            (0, _) => {}
+            (1, _) => pairs.push(("rdfs:domain".to_string(), domains[0].clone())),
+            // A union is not a superclass expression of OWL 2 RL
+            (_, OwlProfile::Rl) => {}
+            (_, OwlProfile::Dl) => pairs.push((
+                "rdfs:domain".to_string(),
+                format!("[ a owl:Class ; owl:unionOf ( {} ) ]", domains.join(" ")),
+            )),
+        }
+        if let Some(range) = &slot.range {
+            pairs.push(("rdfs:range".to_string(), self.range_term(range)));
+        }
+        if let Some(parent) = &slot.is_a {
+            pairs.push(("rdfs:subPropertyOf".to_string(), self.slot_term(parent)));
+        }
+        if object && let Some(inverse) = &slot.inverse {
+            pairs.push(("owl:inverseOf".to_string(), self.slot_term(inverse)));
+        }
+
+        let kind = if object {
+            "owl:ObjectProperty"
+        } else {
+            "owl:DatatypeProperty"
+        };
+        self.statement(&self.slot_term(name), kind, &pairs)
+    }
+
+    /// `rdfs:Datatype` equivalent to the base of a type
+    fn write_datatype(&mut self, name: &str, type_def: &TypeDefinition) -> Result<()> {
+        if type_def.uri.is_some() {
+            // The type stands for an existing datatype
+            return Ok(());
+        }
+        let mut pairs = vec![("rdfs:label".to_string(), literal(name))];
+        if let Some(description) = &type_def.description {
+            pairs.push(("skos:definition".to_string(), literal(description)));
+            self.annotation_properties
+                .insert("skos:definition".to_string());
+        }
+        if let Some(base_type) = &type_def.base_type {
+            pairs.push((
+                "owl:equivalentClass".to_string(),
+                self.range_term(base_type),
+            ));
+        }
+        self.statement(&format!(":{}", local_name(name)), "rdfs:Datatype", &pairs)
+    }
+
+    /// Class of an enum and its permissible values as individuals
+    fn write_enum(&mut self, name: &str, enum_def: &EnumDefinition) -> Result<()> {
+        let individuals: Vec<String> = enum_def
+            .permissible_values
+            .iter()
+            .map(|value| self.value_term(name, value))
+            .collect();
+
+        let mut pairs = vec![("rdfs:label".to_string(), literal(name))];
+        self.metadata(
+            &mut pairs,
+            &Metadata {
+                description: enum_def.description.as_ref(),
+                aliases: &[],
+                mappings: [
+                    ("skos:exactMatch", &[]),
+                    ("skos:closeMatch", &[]),
+                    ("skos:relatedMatch", &[]),
+                    ("skos:narrowMatch", &[]),
+                    ("skos:broadMatch", &[]),
+                ],
+                see_also: &[],
+                deprecated: false,
+                annotations: enum_def.annotations.as_ref(),
+            },
+        );
+        // Enumerated classes are not expressible in OWL 2 RL
+        if self.profile == OwlProfile::Dl && !individuals.is_empty() && !enum_def.is_dynamic() {
+            pairs.push((
+                "owl:equivalentClass".to_string(),
+                format!("[ a owl:Class ; owl:oneOf ( {} ) ]", individuals.join(" ")),
+            ));
+        }
+        let enum_term = self.range_term(name);
+        self.statement(&enum_term, "owl:Class", &pairs)?;
+
+        for (value, individual) in enum_def.permissible_values.iter().zip(&individuals) {
+            let mut pairs = vec![
+                ("a".to_string(), enum_term.clone()),
+                ("rdfs:label".to_string(), literal(value.text())),
+            ];
+            if let PermissibleValue::Complex {
+                description: Some(description),
+                ..
+            } = value
+            {
+                pairs.push(("skos:definition".to_string(), literal(description)));
+                self.annotation_properties
+                    .insert("skos:definition".to_string());
+            }
+            self.statement(individual, "owl:NamedIndividual", &pairs)?;
+        }
+        Ok(())
+    }
+
+    /// Annotation assertions of the metadata of an element
+    fn metadata(&mut self, pairs: &mut Vec<(String, String)>, metadata: &Metadata) {
+        let mut annotate = |property: &str, value: String| {
+            self.annotation_properties.insert(property.to_string());
+            pairs.push((property.to_string(), value));
+        };
+        if let Some(description) = metadata.description {
+            annotate("skos:definition", literal(description));
+        }
+        for alias in metadata.aliases {
+            annotate("skos:altLabel", literal(alias));
+        }
+        for (property, mappings) in metadata.mappings {
+            for mapping in mappings {
+                annotate(property, iri(mapping));
+            }
+        }
+        for see_also in metadata.see_also {
+            annotate("rdfs:seeAlso", iri(see_also));
+        }
+        if metadata.deprecated {
+            annotate("owl:deprecated", "true".to_string());
+        }
+        for (key, value) in metadata.annotations.into_iter().flatten() {
+            let value = match value {
+                AnnotationValue::String(text) => literal(text),
+                AnnotationValue::Bool(flag) => flag.to_string(),
+                AnnotationValue::Number(number) => format!("\"{number}\"^^xsd:decimal"),
+                _ => continue,
+            };
+            let property = if key.contains(':') {
+                iri(key)
+            } else {
+                format!(":{}", local_name(key))
+            };
+            annotate(&property, value);
+        }
+    }
+
+    fn class_term(&self, name: &str) -> String {
+        self.schema
+            .classes
+            .get(name)
+            .and_then(|class| class.class_uri.as_deref())
+            .map_or_else(|| format!(":{}", local_name(name)), iri)
+    }
+
+    fn slot_term(&self, name: &str) -> String {
+        self.schema
+            .slots
+            .get(name)
+            .or_else(|| {
+                self.schema
+                    .classes
+                    .values()
+                    .find_map(|class| class.attributes.get(name))
+            })
+            .and_then(|slot| slot.slot_uri.as_deref())
+            .map_or_else(|| format!(":{}", local_name(name)), iri)
+    }
+
+    /// Class, enum class or datatype of a range
+    fn range_term(&self, range: &str) -> String {
+        if self.schema.classes.contains_key(range) {
+            return self.class_term(range);
+        }
+        if self.schema.enums.contains_key(range) {
+            return format!(":{}", local_name(range));
+        }
+        match self.schema.types.get(range) {
+            Some(type_def) => type_def
+                .uri
+                .as_deref()
+                .map_or_else(|| format!(":{}", local_name(range)), iri),
+            None => map_range_to_xsd(range),
+        }
+    }
+
+    /// Individual of a permissible value, its meaning when it has one
+    fn value_term(&self, enum_name: &str, value: &PermissibleValue) -> String {
+        match value {
+            PermissibleValue::Complex {
+                meaning: Some(meaning),
+                ..
+            } => iri(meaning),
+            _ => format!(":{}_{}", local_name(enum_name), local_name(value.text())),
+        }
+    }
+
+    /// Write `subject a kind` with further predicate-object pairs
+    fn statement(&mut self, subject: &str, kind: &str, pairs: &[(String, String)]) -> Result<()> {
+        let mut lines = vec![format!("{subject} a {kind}")];
+        lines.extend(
+            pairs
+                .iter()
+                .map(|(predicate, object)| format!("    {predicate} {object}")),
+        );
+        writeln_rdf!(self.output, "{} .\n", lines.join(" ;\n"))
+    }
+}
+
+/// Turtle string literal
+fn literal(text: &str) -> String {
+    format!("\"{}\"", escape_literal(text))
+}
+
+/// Term for a URI or CURIE
+fn iri(reference: &str) -> String {
+    if reference.contains("://") {
+        format!("<{reference}>")
+    } else {
+        reference.to_string()
+    }
+}
+
+/// Name usable as a Turtle local name
+fn local_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
 impl Generator for RdfGenerator {
     fn validate_schema(&self, schema: &SchemaDefinition) -> Result<()> {
         // Validate schema has a name
@@ -589,11 +1112,19 @@ impl Generator for RdfGenerator {
     }
 
     fn name(&self) -> &'static str {
-        "rdf"
+        if self.owl_profile.is_some() {
+            "owl"
+        } else {
+            "rdf"
+        }
     }
 
     fn description(&self) -> &'static str {
-        "Generate RDF/Turtle representation of LinkML schema"
+        if self.owl_profile.is_some() {
+            "Generate an OWL 2 ontology with class and property axioms from LinkML schema"
+        } else {
+            "Generate RDF/Turtle representation of LinkML schema"
+        }
     }
 
     fn get_file_extension(&self) -> &'static str {
@@ -604,3 +1135,173 @@ impl Generator for RdfGenerator {
         "schema.ttl"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clinical_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/clinical".to_string(),
+            name: "clinical".to_string(),
+            version: Some("1.0.0".to_string()),
+            ..Default::default()
+        };
+        for (name, slot) in [
+            (
+                "id",
+                SlotDefinition {
+                    identifier: Some(true),
+                    range: Some("string".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "name",
+                SlotDefinition {
+                    range: Some("string".to_string()),
+                    description: Some("Full name".to_string()),
+                    exact_mappings: vec!["schema:name".to_string()],
+                    ..Default::default()
+                },
+            ),
+            (
+                "conditions",
+                SlotDefinition {
+                    range: Some("Condition".to_string()),
+                    multivalued: Some(true),
+                    required: Some(true),
+                    ..Default::default()
+                },
+            ),
+            (
+                "severity",
+                SlotDefinition {
+                    range: Some("Severity".to_string()),
+                    ..Default::default()
+                },
+            ),
+        ] {
+            schema.slots.insert(name.to_string(), slot);
+        }
+        schema.classes.insert(
+            "Entity".to_string(),
+            ClassDefinition {
+                abstract_: Some(true),
+                slots: vec!["id".to_string(), "name".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Patient".to_string(),
+            ClassDefinition {
+                is_a: Some("Entity".to_string()),
+                slots: vec!["conditions".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Condition".to_string(),
+            ClassDefinition {
+                is_a: Some("Entity".to_string()),
+                slots: vec!["severity".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.enums.insert(
+            "Severity".to_string(),
+            EnumDefinition {
+                permissible_values: vec![
+                    PermissibleValue::Simple("mild".to_string()),
+                    PermissibleValue::Simple("severe".to_string()),
+                ],
+                ..Default::default()
+            },
+        );
+        schema
+    }
+
+    #[test]
+    fn test_owl_dl_axioms() {
+        let owl = RdfGenerator::owl()
+            .generate(&clinical_schema())
+            .expect("should generate OWL");
+
+        assert!(owl.contains("@prefix owl: <http://www.w3.org/2002/07/owl#> ."));
+        assert!(owl.contains("<https://example.org/clinical> a owl:Ontology"));
+        assert!(owl.contains("owl:versionInfo \"1.0.0\""));
+        assert!(owl.contains("dcterms:conformsTo <http://www.w3.org/ns/owl-profile/DL>"));
+        assert!(owl.contains(
+            ":Patient a owl:Class ;\n    rdfs:label \"Patient\" ;\n    rdfs:subClassOf :Entity"
+        ));
+        assert!(owl.contains(
+            "[ a owl:Restriction ; owl:onProperty :conditions ; owl:allValuesFrom :Condition ]"
+        ));
+        assert!(owl.contains(
+            "[ a owl:Restriction ; owl:onProperty :conditions ; owl:minCardinality \"1\"^^xsd:nonNegativeInteger ]"
+        ));
+        assert!(owl.contains(
+            "[ a owl:Restriction ; owl:onProperty :id ; owl:cardinality \"1\"^^xsd:nonNegativeInteger ]"
+        ));
+        assert!(owl.contains("owl:hasKey ( :id )"));
+        assert!(owl.contains(":conditions a owl:ObjectProperty"));
+        assert!(owl.contains(":name a owl:DatatypeProperty"));
+        assert!(owl.contains("    skos:definition \"Full name\""));
+        assert!(owl.contains("    skos:exactMatch schema:name"));
+        assert!(owl.contains("skos:definition a owl:AnnotationProperty"));
+        assert!(owl.contains("owl:oneOf ( :Severity_mild :Severity_severe )"));
+        assert!(owl.contains(":Severity_mild a owl:NamedIndividual ;\n    a :Severity"));
+        assert_eq!(RdfGenerator::owl().name(), "owl");
+    }
+
+    #[test]
+    fn test_owl_rl_profile() {
+        let mut schema = clinical_schema();
+        schema.classes.insert(
+            "Encounter".to_string(),
+            ClassDefinition {
+                slots: vec!["severity".to_string()],
+                ..Default::default()
+            },
+        );
+        let owl = RdfGenerator::owl()
+            .with_owl_profile(OwlProfile::Rl)
+            .generate(&schema)
+            .expect("should generate OWL");
+
+        assert!(owl.contains("dcterms:conformsTo <http://www.w3.org/ns/owl-profile/RL>"));
+        assert!(owl.contains(
+            "[ a owl:Restriction ; owl:onProperty :severity ; owl:maxCardinality \"1\"^^xsd:nonNegativeInteger ]"
+        ));
+        assert!(!owl.contains("owl:minCardinality"));
+        assert!(!owl.contains("owl:cardinality"));
+        assert!(!owl.contains("owl:oneOf"));
+        assert!(!owl.contains("owl:unionOf"));
+    }
+
+    #[test]
+    fn test_owl_output_is_reproducible() {
+        let schema = clinical_schema();
+        let first = RdfGenerator::owl()
+            .generate(&schema)
+            .expect("should generate OWL");
+        let second = RdfGenerator::owl()
+            .generate(&schema.clone())
+            .expect("should generate OWL");
+        assert_eq!(first, second);
+
+        // Elements follow schema order and annotation properties are sorted
+        let classes: Vec<_> = first
+            .lines()
+            .filter_map(|line| line.strip_suffix(" a owl:Class ;"))
+            .collect();
+        assert_eq!(classes, [":Entity", ":Patient", ":Condition", ":Severity"]);
+        let annotation_properties: Vec<_> = first
+            .lines()
+            .filter_map(|line| line.strip_suffix(" a owl:AnnotationProperty ."))
+            .collect();
+        let mut sorted = annotation_properties.clone();
+        sorted.sort_unstable();
+        assert_eq!(annotation_properties, sorted);
+    }
+}
@@ -2,6 +2,7 @@
 
 use super::traits::{Generator, GeneratorError, GeneratorResult};
 use crate::plugin::{GeneratorPlugin, PluginManager, PluginStatus, PluginType};
+use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
@@ -38,40 +39,56 @@ impl GeneratorRegistry {
 
     /// Create a registry with default generators
     pub async fn with_defaults() -> Self {
+        let registry = Self::new();
+        for generator in Self::default_generators() {
+            if let Err(e) = registry.register(generator).await {
+                eprintln!("Failed to register generator: {e}");
+            }
+        }
+
+        registry
+    }
+
+    /// The built-in generators registered by [`Self::with_defaults`]
+    #[must_use]
+    pub fn default_generators() -> Vec<Arc<dyn Generator>> {
         use super::{
-            CsvGenerator, ExcelGenerator, GoGenerator, GraphQLGenerator, GraphvizGenerator,
-            HtmlGenerator, JavaGenerator, JavaScriptGenerator, JsonLdContextGenerator,
-            JsonLdContextGeneratorConfig, JsonLdGenerator, JsonSchemaGenerator, MarkdownGenerator,
+            AvroGenerator, CsvGenerator, CypherGenerator, DbtGenerator, ExcelGenerator,
+            GoGenerator, GraphQLGenerator, GraphvizGenerator, HtmlGenerator, JavaGenerator,
+            JavaScriptGenerator, JsonLdContextGenerator, JsonLdContextGeneratorConfig,
+            JsonLdGenerator, JsonSchemaGenerator, JsonSchemaMappingGenerator, MarkdownGenerator,
             MermaidDiagramType, MermaidGenerator, NamespaceManagerGenerator,
             NamespaceManagerGeneratorConfig, OpenApiGenerator, PlantUmlGenerator, PrefixMapFormat,
             PrefixMapGenerator, PrefixMapGeneratorConfig, ProtobufGenerator, PydanticGenerator,
             PythonDataclassGenerator, RdfGenerator, RustGenerator, SQLAlchemyGenerator,
-            SQLAlchemyGeneratorConfig, SQLGenerator, ShExGenerator, ShaclGenerator,
+            SQLAlchemyGeneratorConfig, SQLGenerator, ShExGenerator, ShaclGenerator, SparkGenerator,
             SparqlGenerator, SssomFormat, SssomGenerator, SssomGeneratorConfig, SummaryFormat,
             SummaryGenerator, SummaryGeneratorConfig, TargetLanguage as NsTargetLanguage,
             TypeScriptGenerator, ValidationFramework, YamlValidatorGenerator,
             YamlValidatorGeneratorConfig, YumlGenerator, typeql_generator::create_typeql_generator,
         };
 
-        let registry = Self::new();
-
-        // Register all available generators
-        let generators: Vec<Arc<dyn Generator>> = vec![
+        vec![
             Arc::new(PythonDataclassGenerator::new()),
             Arc::new(PydanticGenerator::new()),
             Arc::new(TypeScriptGenerator::new()),
             Arc::new(JavaScriptGenerator::new()),
             Arc::new(JavaGenerator::new()),
+            Arc::new(AvroGenerator::new()),
             Arc::new(CsvGenerator::new()),
             Arc::new(CsvGenerator::tsv()),
+            Arc::new(CypherGenerator::new()),
+            Arc::new(DbtGenerator::new()),
             Arc::new(GoGenerator::new()),
             Arc::new(ExcelGenerator::new()),
+            Arc::new(crate::fhir::FhirProfileGenerator::new()),
             Arc::new(GraphQLGenerator::new()),
             Arc::new(GraphvizGenerator::new()),
             Arc::new(RustGenerator::new()),
             Arc::new(create_typeql_generator()),
             Arc::new(HtmlGenerator::new()),
             Arc::new(JsonSchemaGenerator::new()),
+            Arc::new(JsonSchemaMappingGenerator::new()),
             Arc::new(JsonLdGenerator::new()),
             Arc::new(JsonLdContextGenerator::new(
                 JsonLdContextGeneratorConfig::default(),
@@ -80,12 +97,14 @@ impl GeneratorRegistry {
             Arc::new(MermaidGenerator::new()), // ER diagram (default)
             Arc::new(MermaidGenerator::new().with_diagram_type(MermaidDiagramType::ClassDiagram)),
             Arc::new(OpenApiGenerator::new()),
-            Arc::new(RdfGenerator::new()),    // OWL mode
+            Arc::new(RdfGenerator::new()),    // RDF mode
+            Arc::new(RdfGenerator::owl()),    // OWL mode
             Arc::new(RdfGenerator::rdfs()),   // RDFS mode
             Arc::new(RdfGenerator::simple()), // Simple RDF mode
             Arc::new(ProtobufGenerator::new()),
             Arc::new(ShaclGenerator::new()),
             Arc::new(ShExGenerator::new()),
+            Arc::new(SparkGenerator::new()),
             Arc::new(SparqlGenerator::new()),
             Arc::new(SQLAlchemyGenerator::new(
                 SQLAlchemyGeneratorConfig::default(),
@@ -157,15 +176,7 @@ impl GeneratorRegistry {
                                                                                 //     target: ProjectTarget::Rust,
                                                                                 //     ..Default::default()
                                                                                 // })), // Project generator (Rust)
-        ];
-
-        for generator in generators {
-            if let Err(e) = registry.register(generator).await {
-                eprintln!("Failed to register generator: {e}");
-            }
-        }
-
-        registry
+        ]
     }
 
     /// Register a generator
@@ -228,15 +239,9 @@ impl GeneratorRegistry {
     pub async fn get_info(&self, name: &str) -> Option<GeneratorInfo> {
         let generators = self.generators.read().await;
 
-        generators.get(name).map(|generator| GeneratorInfo {
-            name: generator.name().to_string(),
-            description: generator.description().to_string(),
-            file_extensions: generator
-                .file_extensions()
-                .iter()
-                .map(|s| (*s).to_string())
-                .collect(),
-        })
+        generators
+            .get(name)
+            .map(|generator| GeneratorInfo::of(generator.as_ref()))
     }
 
     /// Get information for all generators
@@ -245,15 +250,7 @@ impl GeneratorRegistry {
 
         generators
             .values()
-            .map(|generator| GeneratorInfo {
-                name: generator.name().to_string(),
-                description: generator.description().to_string(),
-                file_extensions: generator
-                    .file_extensions()
-                    .iter()
-                    .map(|s| (*s).to_string())
-                    .collect(),
-            })
+            .map(|generator| GeneratorInfo::of(generator.as_ref()))
             .collect()
     }
 
@@ -371,7 +368,7 @@ impl Default for GeneratorRegistry {
 }
 
 /// Information about a registered generator
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GeneratorInfo {
     /// Generator name
     pub name: String,
@@ -383,6 +380,22 @@ pub struct GeneratorInfo {
     pub file_extensions: Vec<String>,
 }
 
+impl GeneratorInfo {
+    /// Information about a generator
+    #[must_use]
+    pub fn of(generator: &dyn Generator) -> Self {
+        Self {
+            name: generator.name().to_string(),
+            description: generator.description().to_string(),
+            file_extensions: generator
+                .file_extensions()
+                .iter()
+                .map(|s| (*s).to_string())
+                .collect(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
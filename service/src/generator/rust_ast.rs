@@ -0,0 +1,264 @@
+//! `syn` AST helpers for Rust code generation
+//!
+//! The Rust generator builds `syn` items rather than concatenating strings and
+//! renders them with `prettyplease`. Identifiers are validated (keywords and
+//! invalid characters are escaped) and literals are quoted by `quote`, so the
+//! generated code is always balanced and parseable. The same helpers support
+//! programmatic post-processing of generated code, such as adding derives
+//! requested through configuration.
+
+use super::traits::{GeneratorError, GeneratorResult};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, File, Ident, Item, Meta, Path, Token, Type, parse_quote};
+
+/// Custom generator option holding extra derives (comma separated paths)
+pub const DERIVES_OPTION: &str = "derives";
+
+/// Custom generator option that, set to `true`, implements
+/// `linkml_core::LinkMLValidate` for every generated struct
+pub const VALIDATE_OPTION: &str = "linkml_validate";
+
+/// Build a valid identifier from a schema name
+///
+/// Keywords get a trailing underscore (`type` becomes `type_`) and characters
+/// that are not valid in identifiers are replaced by underscores.
+///
+/// # Errors
+/// Returns `GeneratorError::Generation` if no valid identifier can be derived
+pub fn ident(name: &str) -> GeneratorResult<Ident> {
+    if let Ok(ident) = syn::parse_str::<Ident>(name) {
+        return Ok(ident);
+    }
+
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if sanitized.is_empty() || sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        sanitized.insert(0, '_');
+    }
+
+    syn::parse_str::<Ident>(&sanitized)
+        .or_else(|_| syn::parse_str::<Ident>(&format!("{sanitized}_")))
+        .map_err(|e| {
+            GeneratorError::Generation(format!(
+                "Cannot derive a Rust identifier from '{name}': {e}"
+            ))
+        })
+}
+
+/// Outer doc attributes (`///`) for a possibly multi-line text
+#[must_use]
+pub fn doc_attrs(text: &str) -> Vec<Attribute> {
+    text.lines()
+        .map(|line| {
+            let line = format!(" {}", line.trim_end());
+            parse_quote!(#[doc = #line])
+        })
+        .collect()
+}
+
+/// Inner doc attributes (`//!`) for a possibly multi-line text
+#[must_use]
+pub fn inner_doc_attrs(text: &str) -> Vec<Attribute> {
+    text.lines()
+        .map(|line| {
+            let line = format!(" {}", line.trim_end());
+            parse_quote!(#![doc = #line])
+        })
+        .collect()
+}
+
+/// Parse a Rust type expression
+///
+/// # Errors
+/// Returns `GeneratorError::Generation` if the string is not a valid type
+pub fn parse_type(ty: &str) -> GeneratorResult<Type> {
+    syn::parse_str(ty)
+        .map_err(|e| GeneratorError::Generation(format!("Invalid Rust type '{ty}': {e}")))
+}
+
+/// Parse a comma separated list of derive paths, e.g. `"Hash, Eq, schemars::JsonSchema"`
+///
+/// # Errors
+/// Returns `GeneratorError::Configuration` if an entry is not a valid path
+pub fn parse_derives(spec: &str) -> GeneratorResult<Vec<Path>> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            syn::parse_str::<Path>(entry).map_err(|e| {
+                GeneratorError::Configuration(format!("Invalid derive '{entry}': {e}"))
+            })
+        })
+        .collect()
+}
+
+/// Parse the content of an outer attribute, e.g. `serde(rename = "id")`
+///
+/// # Errors
+/// Returns `GeneratorError::Configuration` if the text is not a valid attribute
+pub fn parse_attribute(meta: &str) -> GeneratorResult<Attribute> {
+    let meta: Meta = syn::parse_str(meta)
+        .map_err(|e| GeneratorError::Configuration(format!("Invalid attribute '{meta}': {e}")))?;
+    Ok(parse_quote!(#[#meta]))
+}
+
+/// Add derives to every struct and enum in a file, skipping ones already present
+///
+/// Existing `#[derive(...)]` lists are merged into the first derive attribute
+/// of each item; items without one get a new attribute.
+pub fn add_derives(file: &mut File, derives: &[Path]) {
+    for item in &mut file.items {
+        add_derives_to_item(item, derives);
+    }
+}
+
+/// Parse Rust source, add derives to all structs and enums, and render it again
+///
+/// # Errors
+/// Returns `GeneratorError::Generation` if the source does not parse
+pub fn add_derives_to_source(source: &str, derives: &[Path]) -> GeneratorResult<String> {
+    let mut file = syn::parse_file(source).map_err(|e| {
+        GeneratorError::Generation(format!("Generated Rust code does not parse: {e}"))
+    })?;
+    add_derives(&mut file, derives);
+    Ok(render(&file))
+}
+
+/// Render a file with `prettyplease`
+#[must_use]
+pub fn render(file: &File) -> String {
+    prettyplease::unparse(file)
+}
+
+/// Render a list of items as a standalone fragment
+#[must_use]
+pub fn render_items(items: Vec<Item>) -> String {
+    render(&File {
+        shebang: None,
+        attrs: Vec::new(),
+        items,
+    })
+}
+
+/// Add derives to a single struct or enum (recursing into inline modules)
+pub fn add_derives_to_item(item: &mut Item, derives: &[Path]) {
+    let attrs = match item {
+        Item::Struct(item) => &mut item.attrs,
+        Item::Enum(item) => &mut item.attrs,
+        Item::Mod(module) => {
+            if let Some((_, items)) = &mut module.content {
+                for nested in items {
+                    add_derives_to_item(nested, derives);
+                }
+            }
+            return;
+        }
+        _ => return,
+    };
+
+    let mut existing: Vec<Path> = Vec::new();
+    let mut first_derive = None;
+    for (index, attr) in attrs.iter().enumerate() {
+        if attr.path().is_ident("derive")
+            && let Ok(paths) = attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+        {
+            first_derive.get_or_insert(index);
+            existing.extend(paths);
+        }
+    }
+
+    let missing: Vec<&Path> = derives
+        .iter()
+        .filter(|derive| !existing.iter().any(|present| same_derive(present, derive)))
+        .collect();
+    if missing.is_empty() {
+        return;
+    }
+
+    if let Some(index) = first_derive {
+        let Ok(mut paths) =
+            attrs[index].parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+        else {
+            return;
+        };
+        paths.extend(missing.into_iter().cloned());
+        attrs[index] = parse_quote!(#[derive(#paths)]);
+    } else {
+        attrs.push(parse_quote!(#[derive(#(#missing),*)]));
+    }
+}
+
+/// Two derive paths name the same derive if their final segments match
+fn same_derive(a: &Path, b: &Path) -> bool {
+    match (a.segments.last(), b.segments.last()) {
+        (Some(a), Some(b)) => a.ident == b.ident,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ident_escaping() {
+        assert_eq!(ident("name").expect("valid ident").to_string(), "name");
+        assert_eq!(ident("type").expect("keyword").to_string(), "type_");
+        assert_eq!(
+            ident("first-name").expect("hyphen").to_string(),
+            "first_name"
+        );
+        assert_eq!(ident("3d").expect("digit").to_string(), "_3d");
+        assert_eq!(ident("").expect("empty").to_string(), "__");
+    }
+
+    #[test]
+    fn test_doc_attrs_escape_content() {
+        let item: Item = {
+            let docs = doc_attrs("Says \"hi\" \\ and {braces}\nsecond line");
+            parse_quote! {
+                #(#docs)*
+                pub struct Greeting;
+            }
+        };
+        let rendered = render_items(vec![item]);
+        assert!(rendered.contains("/// Says \"hi\" \\ and {braces}"));
+        assert!(rendered.contains("/// second line"));
+    }
+
+    #[test]
+    fn test_add_derives_merges_and_deduplicates() {
+        let source = r"
+            #[derive(Debug, Clone)]
+            pub struct Person { pub name: String }
+
+            pub enum Status { Active }
+
+            pub fn helper() {}
+        ";
+        let derives = parse_derives("Hash, Clone, schemars::JsonSchema").expect("valid derives");
+        let output = add_derives_to_source(source, &derives).expect("source parses");
+
+        assert!(output.contains("#[derive(Debug, Clone, Hash, schemars::JsonSchema)]"));
+        assert!(output.contains("#[derive(Hash, Clone, schemars::JsonSchema)]\npub enum Status"));
+    }
+
+    #[test]
+    fn test_parse_derives_rejects_invalid_paths() {
+        assert!(parse_derives("Hash, not a path").is_err());
+        assert!(parse_derives(" , ").expect("empty is fine").is_empty());
+    }
+
+    #[test]
+    fn test_add_derives_to_invalid_source() {
+        assert!(add_derives_to_source("pub struct {", &[]).is_err());
+    }
+}
@@ -4,7 +4,7 @@
 //! SHACL is a W3C standard for validating RDF graphs against a set of conditions.
 
 use linkml_core::types::{ClassDefinition, PermissibleValue, SchemaDefinition, SlotDefinition};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fmt::Write;
 
 use super::traits::{Generator, GeneratorError, GeneratorOptions, GeneratorResult};
@@ -15,7 +15,7 @@ pub struct ShaclGenerator {
     /// Generator options
     options: GeneratorOptions,
     /// Namespace prefixes
-    prefixes: HashMap<String, String>,
+    prefixes: BTreeMap<String, String>,
 }
 
 impl ShaclGenerator {
@@ -27,7 +27,7 @@ impl ShaclGenerator {
     /// Create a new SHACL generator
     #[must_use]
     pub fn new() -> Self {
-        let mut prefixes = HashMap::new();
+        let mut prefixes = BTreeMap::new();
 
         // Standard prefixes
         prefixes.insert("sh".to_string(), "http://www.w3.org/ns/shacl#".to_string());
@@ -481,6 +481,32 @@ mod tests {
         assert_eq!(ShaclGenerator::get_xsd_datatype("CustomType"), None);
     }
 
+    #[test]
+    fn test_prefixes_in_stable_order() {
+        let schema = SchemaDefinition {
+            id: "https://example.org/people".to_string(),
+            name: "people".to_string(),
+            ..Default::default()
+        };
+        let prefixes = ShaclGenerator::new()
+            .generate_prefixes(&schema)
+            .expect("prefixes");
+        let names: Vec<_> = prefixes
+            .lines()
+            .filter_map(|line| line.strip_prefix("@prefix "))
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["owl", "rdf", "rdfs", "sh", "xsd", "people"]);
+        // Separately constructed generators hash differently
+        assert_eq!(
+            ShaclGenerator::new()
+                .generate_prefixes(&schema)
+                .expect("prefixes"),
+            prefixes
+        );
+    }
+
     #[test]
     fn test_case_conversion() {
         assert_eq!(ShaclGenerator::to_snake_case("PersonName"), "person_name");
@@ -4,7 +4,7 @@
 //! `ShEx` is a language for describing RDF graph structures as sets of constraints.
 
 use linkml_core::prelude::*;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 use std::fmt::Write;
 
 use super::traits::{Generator, GeneratorError, GeneratorResult};
@@ -59,7 +59,7 @@ pub struct ShExGenerator {
     /// Generation options
     options: ShExOptions,
     /// Namespace prefixes
-    prefixes: HashMap<String, String>,
+    prefixes: BTreeMap<String, String>,
 }
 
 impl ShExGenerator {
@@ -71,7 +71,7 @@ impl ShExGenerator {
     /// Create a new `ShEx` generator
     #[must_use]
     pub fn new() -> Self {
-        let mut prefixes = HashMap::new();
+        let mut prefixes = BTreeMap::new();
 
         // Standard prefixes
         prefixes.insert(
@@ -502,7 +502,7 @@ ex:MyShape a shex:Shape ;
     fn write_prefixes(
         &self,
         output: &mut String,
-        prefixes: &HashMap<String, String>,
+        prefixes: &BTreeMap<String, String>,
     ) -> GeneratorResult<()> {
         for (prefix, uri) in prefixes {
             writeln!(output, "PREFIX {prefix}: <{uri}>")
@@ -0,0 +1,263 @@
+//! Spark schema generator for `LinkML` schemas
+//!
+//! Generates Spark `StructType` schemas in the JSON form read by
+//! `StructType.fromJson` (`pyspark.sql.types.StructType.fromJson` in Python),
+//! one per concrete class, keyed by class name. Inlined objects become nested
+//! structs, references take the type of the identifier of the referenced
+//! class and multivalued slots become arrays. Field metadata carries the slot
+//! description as `comment`, which Spark SQL shows as the column comment,
+//! and the slot lineage as `lineage`.
+
+use linkml_core::prelude::*;
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+
+use super::base::induced_slots;
+use super::lineage::SlotLineage;
+use super::traits::{Generator, GeneratorError, GeneratorResult};
+use linkml_core::error::LinkMLError;
+
+/// Field of a Spark struct type
+#[derive(Serialize)]
+struct StructField {
+    name: String,
+    #[serde(rename = "type")]
+    data_type: Value,
+    nullable: bool,
+    metadata: Map<String, Value>,
+}
+
+/// Generator of Spark struct type schemas
+#[derive(Default)]
+pub struct SparkGenerator;
+
+impl SparkGenerator {
+    /// Create a new Spark schema generator
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Generate the struct types of all concrete classes
+    fn generate_schemas(schema: &SchemaDefinition) -> GeneratorResult<String> {
+        let mut schemas = Map::new();
+        for (class_name, class) in &schema.classes {
+            if class.abstract_ != Some(true) && class.mixin != Some(true) {
+                schemas.insert(
+                    class_name.clone(),
+                    struct_type(schema, class_name, &mut vec![class_name.as_str()]),
+                );
+            }
+        }
+        serde_json::to_string_pretty(&schemas).map_err(|e| {
+            GeneratorError::Generation(format!("Failed to serialize Spark schemas: {e}"))
+        })
+    }
+}
+
+impl Generator for SparkGenerator {
+    fn name(&self) -> &'static str {
+        "spark"
+    }
+
+    fn description(&self) -> &'static str {
+        "Generates Spark StructType schemas with lineage metadata from LinkML schemas"
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> linkml_core::error::Result<()> {
+        if schema.name.is_empty() {
+            return Err(LinkMLError::data_validation(
+                "Schema must have a name for Spark schema generation",
+            ));
+        }
+        Ok(())
+    }
+
+    fn generate(&self, schema: &SchemaDefinition) -> std::result::Result<String, LinkMLError> {
+        Generator::validate_schema(self, schema)?;
+        Ok(Self::generate_schemas(schema)?)
+    }
+
+    fn get_file_extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn get_default_filename(&self) -> &'static str {
+        "spark_schema"
+    }
+}
+
+/// Struct type of a class; `stack` holds the classes being expanded, so that
+/// recursive inlining stops
+fn struct_type<'a>(
+    schema: &'a SchemaDefinition,
+    class_name: &str,
+    stack: &mut Vec<&'a str>,
+) -> Value {
+    let fields: Vec<StructField> = induced_slots(schema, class_name)
+        .into_iter()
+        .map(|slot| {
+            let element_type = slot.range.as_deref().map_or_else(
+                || json!("string"),
+                |range| field_type(schema, &slot, range, stack),
+            );
+            let data_type = if slot.multivalued == Some(true) {
+                json!({ "type": "array", "elementType": element_type, "containsNull": false })
+            } else {
+                element_type
+            };
+            let mut metadata = Map::new();
+            if let Some(description) = &slot.description {
+                metadata.insert("comment".to_string(), json!(description));
+            }
+            if let Some(lineage) = SlotLineage::of(&slot) {
+                metadata.insert("lineage".to_string(), lineage.to_json());
+            }
+            StructField {
+                nullable: slot.required != Some(true) && slot.identifier != Some(true),
+                name: slot.name,
+                data_type,
+                metadata,
+            }
+        })
+        .collect();
+    json!({ "type": "struct", "fields": fields })
+}
+
+/// Spark type of a single value of a slot
+fn field_type<'a>(
+    schema: &'a SchemaDefinition,
+    slot: &SlotDefinition,
+    range: &str,
+    stack: &mut Vec<&'a str>,
+) -> Value {
+    let Some((range, _)) = schema.classes.get_key_value(range) else {
+        return json!(atomic_type(schema, range));
+    };
+    let key = induced_slots(schema, range)
+        .into_iter()
+        .find(|slot| slot.identifier == Some(true));
+    let inlined = slot.inlined == Some(true) || key.is_none();
+    if inlined && !stack.contains(&range.as_str()) {
+        stack.push(range);
+        let nested = struct_type(schema, range, stack);
+        stack.pop();
+        return nested;
+    }
+    // References hold the identifier of the referenced object
+    json!(
+        key.and_then(|key| key.range)
+            .filter(|key_range| !schema.classes.contains_key(key_range))
+            .map_or("string", |key_range| atomic_type(schema, &key_range))
+    )
+}
+
+/// Spark type of a type or enum range
+fn atomic_type(schema: &SchemaDefinition, range: &str) -> &'static str {
+    let mut range = range;
+    for _ in 0..=schema.types.len() {
+        match range {
+            "integer" | "int" => return "long",
+            "float" | "double" => return "double",
+            "decimal" => return "decimal(38,18)",
+            "boolean" | "bool" => return "boolean",
+            "date" => return "date",
+            "datetime" => return "timestamp",
+            _ => match schema.types.get(range).and_then(|t| t.base_type.as_deref()) {
+                Some(base_type) => range = base_type,
+                None => break,
+            },
+        }
+    }
+    "string"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::lineage;
+    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+
+    #[test]
+    fn test_spark_generation() {
+        let mut schema = SchemaDefinition {
+            name: "sales".to_string(),
+            ..Default::default()
+        };
+        for (name, slot) in [
+            (
+                "id",
+                SlotDefinition {
+                    identifier: Some(true),
+                    range: Some("string".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "amount",
+                SlotDefinition {
+                    range: Some("decimal".to_string()),
+                    description: Some("Order total".to_string()),
+                    annotations: Some(
+                        [(lineage::SOURCE_FIELD.to_string(), "ORDERS.TOTAL".into())]
+                            .into_iter()
+                            .collect(),
+                    ),
+                    ..Default::default()
+                },
+            ),
+            (
+                "lines",
+                SlotDefinition {
+                    range: Some("OrderLine".to_string()),
+                    multivalued: Some(true),
+                    required: Some(true),
+                    ..Default::default()
+                },
+            ),
+            (
+                "quantity",
+                SlotDefinition {
+                    range: Some("integer".to_string()),
+                    ..Default::default()
+                },
+            ),
+        ] {
+            schema.slots.insert(name.to_string(), slot);
+        }
+        schema.classes.insert(
+            "Order".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "amount".to_string(), "lines".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "OrderLine".to_string(),
+            ClassDefinition {
+                slots: vec!["quantity".to_string()],
+                ..Default::default()
+            },
+        );
+
+        let output = SparkGenerator::new()
+            .generate(&schema)
+            .expect("should generate Spark schemas");
+        let schemas: Value = serde_json::from_str(&output).expect("valid JSON");
+        let fields = &schemas["Order"]["fields"];
+        assert_eq!(fields[0]["nullable"], false);
+        assert_eq!(fields[1]["type"], "decimal(38,18)");
+        assert_eq!(fields[1]["metadata"]["comment"], "Order total");
+        assert_eq!(
+            fields[1]["metadata"]["lineage"]["source_field"],
+            "ORDERS.TOTAL"
+        );
+        // OrderLine has no identifier, so its objects are nested structs
+        assert_eq!(fields[2]["type"]["type"], "array");
+        assert_eq!(
+            fields[2]["type"]["elementType"]["fields"][0]["type"],
+            "long"
+        );
+        assert_eq!(fields[2]["nullable"], false);
+    }
+}
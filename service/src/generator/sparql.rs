@@ -4,7 +4,7 @@
 //! querying RDF data that conforms to the schema structure.
 
 use linkml_core::prelude::*;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 use std::fmt::Write;
 
 use super::traits::{Generator, GeneratorError, GeneratorResult};
@@ -63,7 +63,7 @@ pub struct SparqlGenerator {
     /// Generation options
     options: SparqlOptions,
     /// Namespace prefixes
-    prefixes: HashMap<String, String>,
+    prefixes: BTreeMap<String, String>,
 }
 
 impl SparqlGenerator {
@@ -75,7 +75,7 @@ impl SparqlGenerator {
     /// Create a new SPARQL generator
     #[must_use]
     pub fn new() -> Self {
-        let mut prefixes = HashMap::new();
+        let mut prefixes = BTreeMap::new();
 
         // Standard prefixes
         prefixes.insert(
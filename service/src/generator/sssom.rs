@@ -1,10 +1,14 @@
 //! SSSOM (Simple Standard for Sharing Ontological Mappings) generator for `LinkML` schemas
 //!
 //! This module generates SSSOM-compliant mapping files from `LinkML` schemas,
-//! enabling interoperability between different ontologies and vocabularies.
+//! enabling interoperability between different ontologies and vocabularies,
+//! and reads SSSOM TSV mapping sets with [`SssomMappingSet::from_tsv`], e.g.
+//! to translate data between schemas with
+//! [`SssomTranslator`](crate::transform::sssom_translator::SssomTranslator).
 
 use crate::generator::traits::{Generator, GeneratorConfig};
 use crate::utils::timestamp::TimestampUtils;
+use indexmap::IndexMap;
 use linkml_core::annotations::AnnotationValue;
 use linkml_core::error::LinkMLError;
 use linkml_core::types::{ClassDefinition, PrefixDefinition, SchemaDefinition, SlotDefinition};
@@ -84,7 +88,7 @@ impl SssomGenerator {
     /// Create a new SSSOM generator
     #[must_use]
     pub fn new(config: SssomGeneratorConfig) -> Self {
-        let timestamp_service = timestamp_service::wiring::wire_timestamp().into_inner();
+        let timestamp_service = crate::utils::timestamp::wire_timestamp();
         let timestamp_utils = Arc::new(TimestampUtils::new(timestamp_service));
         Self {
             config,
@@ -99,7 +103,7 @@ impl SssomGenerator {
         config: SssomGeneratorConfig,
         options: super::traits::GeneratorOptions,
     ) -> Self {
-        let timestamp_service = timestamp_service::wiring::wire_timestamp().into_inner();
+        let timestamp_service = crate::utils::timestamp::wire_timestamp();
         let timestamp_utils = Arc::new(TimestampUtils::new(timestamp_service));
         Self {
             config,
@@ -127,6 +131,14 @@ impl SssomGenerator {
         self.options.custom.get(key)
     }
 
+    /// Date recorded as `mapping_date`, fixed in deterministic mode
+    async fn mapping_date(&self) -> Result<String, LinkMLError> {
+        match self.options.fixed_generation_time() {
+            Some(generated_at) => Ok(generated_at.format("%Y-%m-%d").to_string()),
+            None => self.timestamp_utils.today_string().await,
+        }
+    }
+
     /// Generate SSSOM mappings from schema
     async fn generate_sssom(&self, schema: &SchemaDefinition) -> Result<String, LinkMLError> {
         let mappings = self.extract_mappings(schema).await?;
@@ -143,7 +155,7 @@ impl SssomGenerator {
         schema: &SchemaDefinition,
     ) -> Result<Vec<SssomMapping>, LinkMLError> {
         let mut mappings = Vec::new();
-        let mapping_date = self.timestamp_utils.today_string().await?;
+        let mapping_date = self.mapping_date().await?;
 
         // Extract class mappings
         for (class_name, class_def) in &schema.classes {
@@ -462,7 +474,7 @@ impl SssomGenerator {
                     .expect("writeln! to String should never fail");
             }
 
-            let today = self.timestamp_utils.today_string().await?;
+            let today = self.mapping_date().await?;
             writeln!(output, "# mapping_date: {today}")
                 .expect("writeln! to String should never fail");
 
@@ -575,7 +587,7 @@ impl SssomGenerator {
             metadata.insert("creator_id".to_string(), json!(creator));
         }
 
-        let today = self.timestamp_utils.today_string().await?;
+        let today = self.mapping_date().await?;
         metadata.insert("mapping_date".to_string(), json!(today));
 
         if let Some(description) = &schema.description {
@@ -655,6 +667,159 @@ impl SssomGenerator {
     }
 }
 
+/// Mapping set read from an SSSOM TSV file
+#[derive(Debug, Clone, Default)]
+pub struct SssomMappingSet {
+    /// `mapping_set_id` of the metadata header
+    pub mapping_set_id: Option<String>,
+    /// `curie_map` of the metadata header
+    pub curie_map: IndexMap<String, String>,
+    /// Mappings in file order
+    pub mappings: Vec<SssomMappingRecord>,
+}
+
+/// One row of an SSSOM mapping set
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SssomMappingRecord {
+    /// Mapped element, as a CURIE or IRI
+    pub subject_id: String,
+    /// Label of the subject
+    pub subject_label: Option<String>,
+    /// Mapping predicate, e.g. `skos:exactMatch`
+    pub predicate_id: String,
+    /// Element the subject maps to, as a CURIE or IRI
+    pub object_id: String,
+    /// Label of the object
+    pub object_label: Option<String>,
+    /// How the mapping was established, e.g. `semapv:ManualMappingCuration`
+    pub mapping_justification: Option<String>,
+    /// Confidence between 0 and 1
+    pub confidence: Option<f64>,
+    /// Free-text comment
+    pub comment: Option<String>,
+}
+
+impl SssomMappingSet {
+    /// Parse an SSSOM TSV file: an optional YAML metadata header in `#`
+    /// comment lines followed by a table with at least the `subject_id`,
+    /// `predicate_id` and `object_id` columns
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header is not valid YAML, a required column
+    /// is missing or a confidence is not a number.
+    pub fn from_tsv(content: &str) -> Result<Self, LinkMLError> {
+        let mut header = String::new();
+        let mut lines = content.lines().peekable();
+        while let Some(line) = lines.next_if(|line| line.starts_with('#')) {
+            let text = line.trim_start_matches('#');
+            let text = text.strip_prefix(' ').unwrap_or(text);
+            // Titles such as `SSSOM Metadata` are not part of the YAML
+            if text.starts_with(' ') || text.contains(':') {
+                header.push_str(text);
+                header.push('\n');
+            }
+        }
+
+        let mut set = Self::default();
+        if !header.trim().is_empty() {
+            let metadata: serde_yaml::Value = serde_yaml::from_str(&header)
+                .map_err(|e| LinkMLError::parse(format!("Invalid SSSOM metadata: {e}")))?;
+            set.mapping_set_id = metadata
+                .get("mapping_set_id")
+                .and_then(serde_yaml::Value::as_str)
+                .map(String::from);
+            if let Some(curie_map) = metadata.get("curie_map").and_then(|v| v.as_mapping()) {
+                for (prefix, expansion) in curie_map {
+                    if let (Some(prefix), Some(expansion)) = (prefix.as_str(), expansion.as_str()) {
+                        set.curie_map
+                            .insert(prefix.to_string(), expansion.to_string());
+                    }
+                }
+            }
+        }
+
+        let mut lines = lines.filter(|line| !line.trim().is_empty());
+        let Some(columns) = lines.next() else {
+            return Ok(set);
+        };
+        let columns: Vec<&str> = columns.split('\t').map(str::trim).collect();
+        let column = |name: &str| {
+            columns
+                .iter()
+                .position(|column| *column == name)
+                .ok_or_else(|| LinkMLError::parse(format!("SSSOM table has no '{name}' column")))
+        };
+        let (subject, predicate, object) = (
+            column("subject_id")?,
+            column("predicate_id")?,
+            column("object_id")?,
+        );
+        let optional = |name: &str| columns.iter().position(|column| *column == name);
+        let (subject_label, object_label, justification, confidence, comment) = (
+            optional("subject_label"),
+            optional("object_label"),
+            optional("mapping_justification"),
+            optional("confidence"),
+            optional("comment"),
+        );
+
+        for (row, line) in lines.enumerate() {
+            let cells: Vec<&str> = line.split('\t').collect();
+            let cell = |index: Option<usize>| {
+                index
+                    .and_then(|index| cells.get(index))
+                    .map(|cell| cell.trim())
+                    .filter(|cell| !cell.is_empty())
+                    .map(String::from)
+            };
+            let confidence = cell(confidence)
+                .map(|value| {
+                    value.parse::<f64>().map_err(|_| {
+                        LinkMLError::parse(format!(
+                            "SSSOM row {}: confidence '{value}' is not a number",
+                            row + 1
+                        ))
+                    })
+                })
+                .transpose()?;
+            set.mappings.push(SssomMappingRecord {
+                subject_id: cell(Some(subject)).unwrap_or_default(),
+                subject_label: cell(subject_label),
+                predicate_id: cell(Some(predicate)).unwrap_or_default(),
+                object_id: cell(Some(object)).unwrap_or_default(),
+                object_label: cell(object_label),
+                mapping_justification: cell(justification),
+                confidence,
+                comment: cell(comment),
+            });
+        }
+        Ok(set)
+    }
+
+    /// Read an SSSOM TSV file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn from_file(path: &std::path::Path) -> Result<Self, LinkMLError> {
+        Self::from_tsv(&std::fs::read_to_string(path)?)
+    }
+
+    /// Expand a CURIE with the `curie_map`, leaving IRIs and CURIEs with
+    /// unknown prefixes unchanged
+    #[must_use]
+    pub fn expand(&self, id: &str) -> String {
+        id.split_once(':')
+            .and_then(|(prefix, local)| {
+                self.curie_map
+                    .get(prefix)
+                    .map(|expansion| format!("{expansion}{local}"))
+            })
+            .unwrap_or_else(|| id.to_string())
+    }
+}
+
 impl Generator for SssomGenerator {
     fn name(&self) -> &'static str {
         "sssom"
@@ -744,4 +909,40 @@ mod tests {
         assert!(result.lines().count() > 10); // Should have metadata header and mappings
         Ok(())
     }
+
+    #[test]
+    fn test_sssom_tsv_reading() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let tsv = "# SSSOM Metadata\n\
+                   # mapping_set_id: https://example.com/mappings.sssom.tsv\n\
+                   # curie_map:\n\
+                   #   a: https://example.com/a/\n\
+                   #   b: https://example.com/b/\n\
+                   subject_id\tpredicate_id\tobject_id\tconfidence\tcomment\n\
+                   a:Person\tskos:exactMatch\tb:Individual\t0.9\t\n\
+                   a:name\tskos:closeMatch\tb:full_name\t\tsame meaning\n";
+        let set = SssomMappingSet::from_tsv(tsv)?;
+
+        assert_eq!(
+            set.mapping_set_id.as_deref(),
+            Some("https://example.com/mappings.sssom.tsv")
+        );
+        assert_eq!(
+            set.expand("b:Individual"),
+            "https://example.com/b/Individual"
+        );
+        assert_eq!(set.mappings.len(), 2);
+        assert_eq!(set.mappings[0].confidence, Some(0.9));
+        assert_eq!(set.mappings[1].predicate_id, "skos:closeMatch");
+        assert_eq!(set.mappings[1].confidence, None);
+        assert_eq!(set.mappings[1].comment.as_deref(), Some("same meaning"));
+
+        assert!(SssomMappingSet::from_tsv("subject_id\tobject_id\n").is_err());
+        assert!(
+            SssomMappingSet::from_tsv(
+                "subject_id\tpredicate_id\tobject_id\tconfidence\na:x\tskos:exactMatch\tb:y\thigh\n"
+            )
+            .is_err()
+        );
+        Ok(())
+    }
 }
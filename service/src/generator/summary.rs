@@ -9,7 +9,7 @@ use linkml_core::error::LinkMLError;
 use linkml_core::types::{
     ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition, TypeDefinition,
 };
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 use std::fmt::Write;
 
 /// Summary generator configuration
@@ -99,7 +99,7 @@ struct SchemaStats {
     // Relationship statistics
     inheritance_relationships: usize,
     mixin_relationships: usize,
-    slot_usage_count: HashMap<String, usize>,
+    slot_usage_count: BTreeMap<String, usize>,
 
     // Complexity metrics
     schema_complexity_score: f64,
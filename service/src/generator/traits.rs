@@ -3,6 +3,7 @@
 //! This module defines the fundamental traits and types used by all code generators.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use linkml_core::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -11,6 +12,10 @@ use thiserror::Error;
 /// Result type for generator operations
 pub type GeneratorResult<T> = std::result::Result<T, GeneratorError>;
 
+/// Environment variable carrying the build time of reproducible builds, in
+/// seconds since the Unix epoch
+pub const SOURCE_DATE_EPOCH: &str = "SOURCE_DATE_EPOCH";
+
 /// Errors that can occur during code generation
 #[derive(Debug, Error)]
 pub enum GeneratorError {
@@ -65,6 +70,7 @@ impl From<GeneratorError> for LinkMLError {
 
 /// Options for code generation
 #[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct GeneratorOptions {
     /// Include documentation in generated code
     pub include_docs: bool,
@@ -78,6 +84,19 @@ pub struct GeneratorOptions {
     /// Output format
     pub output_format: OutputFormat,
 
+    /// Run generated output through the native formatters in
+    /// [`crate::generator::formatting`]
+    pub enable_formatting: bool,
+
+    /// Start generated output with a provenance header, see
+    /// [`crate::generator::provenance`]
+    pub provenance: bool,
+
+    /// Produce byte-identical output for identical schemas: times recorded
+    /// in generated output come from `SOURCE_DATE_EPOCH` instead of the
+    /// clock, see [`GeneratorOptions::fixed_generation_time`]
+    pub deterministic: bool,
+
     /// Custom options for specific generators
     pub custom: HashMap<String, String>,
 }
@@ -127,6 +146,43 @@ impl GeneratorOptions {
         self
     }
 
+    /// Set whether generated output is post-processed by native formatters
+    #[must_use]
+    pub fn with_formatting(mut self, enable_formatting: bool) -> Self {
+        self.enable_formatting = enable_formatting;
+        self
+    }
+
+    /// Set whether generated output carries a provenance header
+    #[must_use]
+    pub fn with_provenance(mut self, provenance: bool) -> Self {
+        self.provenance = provenance;
+        self
+    }
+
+    /// Set whether output must be reproducible byte for byte
+    #[must_use]
+    pub fn with_deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// Time to record in generated output instead of the current time
+    ///
+    /// In deterministic mode this is `SOURCE_DATE_EPOCH`, as set by
+    /// reproducible build tooling, or the Unix epoch if it is unset or
+    /// invalid. Otherwise `None`, and generators use the current time.
+    #[must_use]
+    pub fn fixed_generation_time(&self) -> Option<DateTime<Utc>> {
+        self.deterministic.then(|| {
+            std::env::var(SOURCE_DATE_EPOCH)
+                .ok()
+                .and_then(|seconds| seconds.trim().parse::<i64>().ok())
+                .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+                .unwrap_or(DateTime::UNIX_EPOCH)
+        })
+    }
+
     /// Set a custom option
     #[must_use]
     pub fn set_custom(mut self, key: &str, value: &str) -> Self {
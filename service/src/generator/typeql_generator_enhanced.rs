@@ -5,7 +5,14 @@
 //! - Full constraint support
 //! - Complex inheritance handling
 //! - Migration script generation
-//! - `TypeDB` 3.0 feature support
+//! - `TypeDB` 2.x and 3.x dialects
+//!
+//! The dialect defaults to the `typedb.dialect` configuration option and can
+//! be overridden per run with the `typeql_dialect` custom generator option
+//! (`2.x` or `3.x`). The 3.x dialect declares types kind first
+//! (`entity person, owns name;`), puts value constraints on attribute types
+//! as `@regex`, `@range` and `@values` annotations, expresses required slots
+//! as `@card(1..1)` and emits validation functions instead of rules.
 
 use super::options::{GeneratorOptions, IndentStyle};
 use super::traits::{
@@ -14,6 +21,7 @@ use super::traits::{
 use super::typeql_constraints::TypeQLConstraintTranslator;
 use super::typeql_relation_analyzer::RelationAnalyzer;
 use super::typeql_role_inheritance::RoleInheritanceResolver;
+use crate::config::TypeQLDialect;
 use crate::utils::timestamp::SyncTimestampUtils;
 use async_trait::async_trait;
 use linkml_core::error::LinkMLError;
@@ -44,7 +52,7 @@ pub enum TypeQLError {
     InheritanceCycle(String),
 }
 
-/// Enhanced `TypeQL` schema generator for `TypeDB` 3.0+
+/// Enhanced `TypeQL` schema generator for `TypeDB` 2.x and 3.x
 pub struct EnhancedTypeQLGenerator {
     /// Generator name
     name: String,
@@ -60,6 +68,8 @@ pub struct EnhancedTypeQLGenerator {
     identifier_map: RwLock<HashMap<String, String>>,
     /// Generator options
     options: super::traits::GeneratorOptions,
+    /// `TypeQL` dialect used unless the `typeql_dialect` option overrides it
+    dialect: TypeQLDialect,
     /// Timestamp utilities for generating timestamps
     timestamp_utils: Arc<SyncTimestampUtils>,
 }
@@ -145,7 +155,7 @@ impl EnhancedTypeQLGenerator {
     /// Create a new enhanced `TypeQL` generator
     #[must_use]
     pub fn new() -> Self {
-        let timestamp_service = timestamp_service::wiring::wire_timestamp().into_inner();
+        let timestamp_service = crate::utils::timestamp::wire_timestamp();
         let timestamp_utils = Arc::new(SyncTimestampUtils::new(timestamp_service));
         Self {
             name: "typeql-enhanced".to_string(),
@@ -155,6 +165,7 @@ impl EnhancedTypeQLGenerator {
             role_inheritance_resolver: RwLock::new(RoleInheritanceResolver::new()),
             identifier_map: RwLock::new(HashMap::new()),
             options: super::traits::GeneratorOptions::default(),
+            dialect: crate::config::get_config().typedb.dialect,
             timestamp_utils,
         }
     }
@@ -172,6 +183,7 @@ impl EnhancedTypeQLGenerator {
             role_inheritance_resolver: RwLock::new(RoleInheritanceResolver::new()),
             identifier_map: RwLock::new(HashMap::new()),
             options: super::traits::GeneratorOptions::default(),
+            dialect: crate::config::get_config().typedb.dialect,
             timestamp_utils,
         }
     }
@@ -184,6 +196,22 @@ impl EnhancedTypeQLGenerator {
         generator
     }
 
+    /// Set the `TypeQL` dialect
+    #[must_use]
+    pub fn with_dialect(mut self, dialect: TypeQLDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Dialect selected by the `typeql_dialect` option, else the generator's
+    fn dialect(&self, options: &GeneratorOptions) -> GeneratorResult<TypeQLDialect> {
+        options.get_custom("typeql_dialect").map_or(Ok(self.dialect), |value| {
+            value
+                .parse()
+                .map_err(|e: LinkMLError| GeneratorError::Configuration(e.to_string()))
+        })
+    }
+
     /// Analyze schema and determine optimal `TypeQL` structure
     fn analyze_schema(&self, schema: &SchemaDefinition) -> GeneratorResult<()> {
         // First pass: identify all types using advanced relation analysis
@@ -239,9 +267,10 @@ impl EnhancedTypeQLGenerator {
     ) -> GeneratorResult<String> {
         let mut output = String::new();
         let indent = &options.indent;
+        let dialect = self.dialect(options)?;
 
         // Header with metadata
-        Self::write_header(&mut output, schema)?;
+        Self::write_header(&mut output, schema, dialect, options)?;
 
         // Define section
         writeln!(
@@ -260,12 +289,12 @@ define
             if let Some(class) = schema.classes.get(type_name)
                 && (class.abstract_.unwrap_or(false) || class.mixin.unwrap_or(false))
             {
-                self.generate_abstract_type(&mut output, type_name, class, schema, indent)?;
+                self.generate_abstract_type(&mut output, type_name, class, schema, indent, dialect)?;
             }
         }
 
         // 2. Generate attributes
-        self.generate_all_attributes(&mut output, schema, indent)?;
+        self.generate_all_attributes(&mut output, schema, dialect)?;
 
         // 3. Generate concrete entities
         for type_name in &ordered_types {
@@ -278,7 +307,7 @@ define
                     .get(type_name)
                 && !class.abstract_.unwrap_or(false)
             {
-                self.generate_entity(&mut output, type_name, class, schema, indent)?;
+                self.generate_entity(&mut output, type_name, class, schema, indent, dialect)?;
             }
         }
 
@@ -292,7 +321,7 @@ define
                     .type_cache
                     .get(type_name)
             {
-                self.generate_relation(&mut output, type_name, class, schema, indent)?;
+                self.generate_relation(&mut output, type_name, class, schema, indent, dialect)?;
             }
         }
 
@@ -309,20 +338,26 @@ define
 "
             )
             .map_err(Self::fmt_error_to_generator_error)?;
-            self.generate_constraints(&mut output, schema, indent)?;
-            self.generate_validation_rules(&mut output, schema, indent)?;
+            self.generate_constraints(&mut output, schema, indent, dialect)?;
+            self.generate_validation_rules(&mut output, schema, indent, dialect)?;
         }
 
         Ok(output)
     }
 
     /// Write schema header with metadata
-    fn write_header(output: &mut String, schema: &SchemaDefinition) -> GeneratorResult<()> {
+    fn write_header(
+        output: &mut String,
+        schema: &SchemaDefinition,
+        dialect: TypeQLDialect,
+        options: &GeneratorOptions,
+    ) -> GeneratorResult<()> {
         writeln!(output, "# TypeQL Schema generated from LinkML")
             .map_err(Self::fmt_error_to_generator_error)?;
         writeln!(output, "# Generator: Enhanced TypeQL Generator v2.0")
             .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(output, "# TypeDB Version: 3.0+").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "# TypeDB Version: {}", dialect.as_str())
+            .map_err(Self::fmt_error_to_generator_error)?;
 
         if !schema.name.is_empty() {
             writeln!(output, "# Schema: {}", schema.name)
@@ -339,16 +374,13 @@ define
         }
 
         // Add generation timestamp
-        let timestamp = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        let generated_at = options
+            .fixed_generation_time()
+            .unwrap_or_else(chrono::Utc::now);
         writeln!(
             output,
             "# Generated: {}",
-            timestamp
-                .replace('T', " ")
-                .replace('Z', "")
-                .split('.')
-                .next()
-                .unwrap_or(&timestamp)
+            generated_at.format("%Y-%m-%d %H:%M:%S")
         )
         .map_err(Self::fmt_error_to_generator_error)?;
 
@@ -419,6 +451,7 @@ define
         class: &ClassDefinition,
         schema: &SchemaDefinition,
         indent: &IndentStyle,
+        dialect: TypeQLDialect,
     ) -> GeneratorResult<()> {
         let type_name = self.convert_identifier(name);
 
@@ -434,11 +467,18 @@ define
             "entity"
         };
 
-        write!(output, "{type_name} sub {base_type}, abstract")
-            .map_err(Self::fmt_error_to_generator_error)?;
+        match dialect {
+            TypeQLDialect::V2 => write!(output, "{type_name} sub {base_type}, abstract"),
+            TypeQLDialect::V3 => write!(
+                output,
+                "{}",
+                self.type_head_v3(base_type, &type_name, class, schema, true)
+            ),
+        }
+        .map_err(Self::fmt_error_to_generator_error)?;
 
         // Add attributes owned by abstract type
-        let attributes = self.collect_direct_attributes(class, schema);
+        let attributes = self.collect_direct_attributes(class, schema, dialect);
         if attributes.is_empty() {
             writeln!(output, ";").map_err(Self::fmt_error_to_generator_error)?;
         } else {
@@ -470,6 +510,7 @@ define
         class: &ClassDefinition,
         schema: &SchemaDefinition,
         indent: &IndentStyle,
+        dialect: TypeQLDialect,
     ) -> GeneratorResult<()> {
         let type_name = self.convert_identifier(name);
 
@@ -478,19 +519,28 @@ define
             writeln!(output, "# Entity: {desc}").map_err(Self::fmt_error_to_generator_error)?;
         }
 
-        // Build inheritance chain
-        let inheritance = self.build_inheritance_chain(class, schema);
-
-        write!(output, "{type_name} sub").map_err(Self::fmt_error_to_generator_error)?;
-        if inheritance.is_empty() {
-            write!(output, " entity").map_err(Self::fmt_error_to_generator_error)?;
+        if dialect == TypeQLDialect::V3 {
+            write!(
+                output,
+                "{}",
+                self.type_head_v3("entity", &type_name, class, schema, false)
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
         } else {
-            write!(output, " {}", inheritance.join(", sub "))
-                .map_err(Self::fmt_error_to_generator_error)?;
+            // Build inheritance chain
+            let inheritance = self.build_inheritance_chain(class, schema);
+
+            write!(output, "{type_name} sub").map_err(Self::fmt_error_to_generator_error)?;
+            if inheritance.is_empty() {
+                write!(output, " entity").map_err(Self::fmt_error_to_generator_error)?;
+            } else {
+                write!(output, " {}", inheritance.join(", sub "))
+                    .map_err(Self::fmt_error_to_generator_error)?;
+            }
         }
 
         // Collect all attributes (including constraints)
-        let all_attributes = self.collect_all_attributes(class, schema);
+        let all_attributes = self.collect_all_attributes(class, schema, dialect);
 
         // Add roles this entity can play
         let roles = self.collect_playable_roles(name, schema);
@@ -540,6 +590,7 @@ define
         class: &ClassDefinition,
         schema: &SchemaDefinition,
         indent: &IndentStyle,
+        dialect: TypeQLDialect,
     ) -> GeneratorResult<()> {
         let type_name = self.convert_identifier(name);
 
@@ -568,20 +619,30 @@ define
             .map_err(Self::fmt_error_to_generator_error)?;
         }
 
-        // Build inheritance chain
-        let inheritance = self.build_inheritance_chain(class, schema);
-
-        write!(output, "{type_name} sub").map_err(Self::fmt_error_to_generator_error)?;
-        if inheritance.is_empty() {
-            write!(output, " relation").map_err(Self::fmt_error_to_generator_error)?;
+        if dialect == TypeQLDialect::V3 {
+            let abstract_ = class.abstract_.unwrap_or(false);
+            write!(
+                output,
+                "{}",
+                self.type_head_v3("relation", &type_name, class, schema, abstract_)
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
         } else {
-            write!(output, " {}", inheritance.join(", sub "))
-                .map_err(Self::fmt_error_to_generator_error)?;
-        }
+            // Build inheritance chain
+            let inheritance = self.build_inheritance_chain(class, schema);
+
+            write!(output, "{type_name} sub").map_err(Self::fmt_error_to_generator_error)?;
+            if inheritance.is_empty() {
+                write!(output, " relation").map_err(Self::fmt_error_to_generator_error)?;
+            } else {
+                write!(output, " {}", inheritance.join(", sub "))
+                    .map_err(Self::fmt_error_to_generator_error)?;
+            }
 
-        // Handle abstract relations
-        if class.abstract_.unwrap_or(false) {
-            write!(output, ", abstract").map_err(Self::fmt_error_to_generator_error)?;
+            // Handle abstract relations
+            if class.abstract_.unwrap_or(false) {
+                write!(output, ", abstract").map_err(Self::fmt_error_to_generator_error)?;
+            }
         }
 
         writeln!(output, ",").map_err(Self::fmt_error_to_generator_error)?;
@@ -635,7 +696,7 @@ define
         }
 
         // Add attributes owned by relation
-        let attributes = self.collect_direct_attributes(class, schema);
+        let attributes = self.collect_direct_attributes(class, schema, dialect);
         if attributes.is_empty() {
             writeln!(output, ";").map_err(Self::fmt_error_to_generator_error)?;
         } else {
@@ -679,7 +740,7 @@ define
         &self,
         output: &mut String,
         schema: &SchemaDefinition,
-        _indent: &IndentStyle,
+        dialect: TypeQLDialect,
     ) -> GeneratorResult<()> {
         writeln!(
             output,
@@ -713,7 +774,14 @@ define
 
         // Generate attribute definitions
         for (attr_name, slot) in attr_definitions {
-            self.generate_attribute_definition(output, &attr_name, slot, schema)?;
+            match dialect {
+                TypeQLDialect::V2 => {
+                    self.generate_attribute_definition(output, &attr_name, slot, schema)?;
+                }
+                TypeQLDialect::V3 => {
+                    self.generate_attribute_definition_v3(output, &attr_name, slot, schema)?;
+                }
+            }
         }
 
         Ok(())
@@ -733,7 +801,7 @@ define
         }
 
         // Determine value type
-        let value_type = self.map_range_to_typeql(slot.range.as_ref(), schema);
+        let value_type = self.map_range_to_typeql(slot.range.as_ref(), schema, TypeQLDialect::V2);
 
         write!(output, "{name} sub attribute, value {value_type}")
             .map_err(Self::fmt_error_to_generator_error)?;
@@ -783,12 +851,24 @@ define
         output: &mut String,
         schema: &SchemaDefinition,
         indent: &IndentStyle,
+        dialect: TypeQLDialect,
     ) -> GeneratorResult<()> {
         // Unique key constraints
         for (class_name, class) in &schema.classes {
             if !class.unique_keys.is_empty() {
                 for (_key_name, unique_key) in &class.unique_keys {
-                    self.generate_unique_constraint(output, class_name, unique_key, indent)?;
+                    match dialect {
+                        TypeQLDialect::V2 => {
+                            self.generate_unique_constraint(
+                                output, class_name, unique_key, indent,
+                            )?;
+                        }
+                        TypeQLDialect::V3 => {
+                            self.generate_unique_function_v3(
+                                output, class_name, unique_key, indent,
+                            )?;
+                        }
+                    }
                 }
             }
         }
@@ -802,7 +882,20 @@ define
         output: &mut String,
         schema: &SchemaDefinition,
         indent: &IndentStyle,
+        dialect: TypeQLDialect,
     ) -> GeneratorResult<()> {
+        if dialect == TypeQLDialect::V3 {
+            // Required slots are @card(1..1) annotations in 3.x
+            writeln!(output, "# Validation Functions\n")
+                .map_err(Self::fmt_error_to_generator_error)?;
+            for (class_name, class) in &schema.classes {
+                for rule in &class.rules {
+                    self.generate_rule_function_v3(output, class_name, rule, schema, indent)?;
+                }
+            }
+            return Ok(());
+        }
+
         writeln!(
             output,
             "# Validation Rules
@@ -995,6 +1088,201 @@ define
         Ok(())
     }
 
+    /// `TypeQL` 3.x type declaration up to its first capability: kind,
+    /// label, `@abstract` and the supertype
+    ///
+    /// `TypeDB` 3.x has single inheritance, so only `is_a` becomes the
+    /// supertype; mixin slots are owned directly.
+    fn type_head_v3(
+        &self,
+        kind: &str,
+        type_name: &str,
+        class: &ClassDefinition,
+        schema: &SchemaDefinition,
+        abstract_: bool,
+    ) -> String {
+        let mut head = format!("{kind} {type_name}");
+        if abstract_ {
+            head.push_str(" @abstract");
+        }
+        if let Some(parent) = class.is_a.as_ref().filter(|p| schema.classes.contains_key(*p)) {
+            head.push_str(", sub ");
+            head.push_str(&self.convert_identifier(parent));
+        }
+        head
+    }
+
+    /// Annotations of an `owns` statement in `TypeQL` 3.x
+    ///
+    /// Value constraints move to the attribute type, and since `owns`
+    /// defaults to `@card(0..1)`, required single-valued slots that are not
+    /// keys get `@card(1..1)`.
+    fn owns_annotations_v3(&self, slot: &SlotDefinition) -> Vec<String> {
+        let mut annotations: Vec<String> = self
+            .constraint_translator
+            .write()
+            .expect("constraint translator lock should not be poisoned")
+            .translate_slot_constraints(slot)
+            .into_iter()
+            .filter(|c| c.starts_with('@'))
+            .collect();
+        if slot.required == Some(true)
+            && slot.multivalued != Some(true)
+            && !annotations
+                .iter()
+                .any(|a| a == "@key" || a.starts_with("@card"))
+        {
+            annotations.push("@card(1..1)".to_string());
+        }
+        annotations
+    }
+
+    /// Generate a `TypeQL` 3.x attribute type with value annotations
+    fn generate_attribute_definition_v3(
+        &self,
+        output: &mut String,
+        name: &str,
+        slot: &SlotDefinition,
+        schema: &SchemaDefinition,
+    ) -> GeneratorResult<()> {
+        if let Some(desc) = &slot.description {
+            writeln!(output, "# {desc}").map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        let value_type = self.map_range_to_typeql(slot.range.as_ref(), schema, TypeQLDialect::V3);
+        write!(output, "attribute {name}, value {value_type}")
+            .map_err(Self::fmt_error_to_generator_error)?;
+
+        if let Some(pattern) = &slot.pattern
+            && regex::Regex::new(pattern).is_ok()
+        {
+            write!(output, " @regex(\"{}\")", Self::escape_string_v3(pattern))
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        if matches!(value_type, "integer" | "double" | "decimal") {
+            let min = slot.minimum_value.as_ref().and_then(Self::value_to_number);
+            let max = slot.maximum_value.as_ref().and_then(Self::value_to_number);
+            if min.is_some() || max.is_some() {
+                write!(
+                    output,
+                    " @range({}..{})",
+                    min.map(|v| v.to_string()).unwrap_or_default(),
+                    max.map(|v| v.to_string()).unwrap_or_default()
+                )
+                .map_err(Self::fmt_error_to_generator_error)?;
+            }
+        }
+
+        if let Some(enum_def) = slot.range.as_ref().and_then(|r| schema.enums.get(r))
+            && !enum_def.permissible_values.is_empty()
+        {
+            let values: Vec<String> = enum_def
+                .permissible_values
+                .iter()
+                .map(|value| match value {
+                    PermissibleValue::Simple(text) | PermissibleValue::Complex { text, .. } => {
+                        format!("\"{}\"", Self::escape_string_v3(text))
+                    }
+                })
+                .collect();
+            write!(output, " @values({})", values.join(", "))
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        writeln!(output, ";").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+        Ok(())
+    }
+
+    /// Escape backslashes and quotes in a `TypeQL` 3.x string literal
+    fn escape_string_v3(text: &str) -> String {
+        text.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+
+    /// Generate a `TypeQL` 3.x function returning instances that share a
+    /// multi-slot unique key with another instance
+    fn generate_unique_function_v3(
+        &self,
+        output: &mut String,
+        class_name: &str,
+        unique_key: &UniqueKeyDefinition,
+        indent: &IndentStyle,
+    ) -> GeneratorResult<()> {
+        if unique_key.unique_key_slots.len() == 1 {
+            // Single field unique constraint handled by @key annotation
+            return Ok(());
+        }
+
+        let type_name = self.convert_identifier(class_name);
+        writeln!(output, "fun {type_name}-duplicate-key() -> {{ {type_name} }}:")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "match").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "{}$x isa {type_name};", indent.single())
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "{}$y isa {type_name};", indent.single())
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "{}not {{ $x is $y; }};", indent.single())
+            .map_err(Self::fmt_error_to_generator_error)?;
+        for slot in &unique_key.unique_key_slots {
+            let attr = self.convert_identifier(slot);
+            let var = attr.replace('-', "_");
+            writeln!(output, "{}$x has {attr} ${var};", indent.single())
+                .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(output, "{}$y has {attr} ${var};", indent.single())
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        writeln!(output, "return {{ $x }};").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+        Ok(())
+    }
+
+    /// Generate a `TypeQL` 3.x function returning the instances that violate
+    /// a `LinkML` rule: those matching its preconditions but not its
+    /// postconditions
+    fn generate_rule_function_v3(
+        &self,
+        output: &mut String,
+        class_name: &str,
+        rule: &Rule,
+        schema: &SchemaDefinition,
+        indent: &IndentStyle,
+    ) -> GeneratorResult<()> {
+        let type_name = self.convert_identifier(class_name);
+        let title = rule.title.as_deref().unwrap_or("unnamed");
+        let function_name = format!("{type_name}-violates-{}", self.convert_identifier(title));
+
+        writeln!(
+            output,
+            "# Rule: {}",
+            rule.description.as_deref().unwrap_or(title)
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "fun {function_name}() -> {{ {type_name} }}:")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "match").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "{}$x isa {type_name};", indent.single())
+            .map_err(Self::fmt_error_to_generator_error)?;
+        if let Some(preconditions) = &rule.preconditions {
+            self.generate_rule_conditions(output, "$x", preconditions, schema, indent)?;
+        }
+        if let Some(postconditions) = &rule.postconditions {
+            let mut negated = String::new();
+            self.generate_rule_conditions(&mut negated, "$x", postconditions, schema, indent)?;
+            writeln!(output, "{}not {{", indent.single())
+                .map_err(Self::fmt_error_to_generator_error)?;
+            for line in negated.lines() {
+                writeln!(output, "{}{line}", indent.single())
+                    .map_err(Self::fmt_error_to_generator_error)?;
+            }
+            writeln!(output, "{}}};", indent.single())
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        writeln!(output, "return {{ $x }};").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+        Ok(())
+    }
+
     /// Helper methods...
     /// Build inheritance chain for a class
     fn build_inheritance_chain(
@@ -1022,12 +1310,13 @@ define
         &self,
         class: &ClassDefinition,
         schema: &SchemaDefinition,
+        dialect: TypeQLDialect,
     ) -> Vec<(String, Vec<String>)> {
         let mut attributes = Vec::new();
         let mut seen = HashSet::new();
 
         // Direct attributes
-        for (attr_name, constraints) in self.collect_direct_attributes(class, schema) {
+        for (attr_name, constraints) in self.collect_direct_attributes(class, schema, dialect) {
             if !seen.contains(&attr_name) {
                 seen.insert(attr_name.clone());
                 attributes.push((attr_name, constraints));
@@ -1042,6 +1331,7 @@ define
         &self,
         class: &ClassDefinition,
         schema: &SchemaDefinition,
+        dialect: TypeQLDialect,
     ) -> Vec<(String, Vec<String>)> {
         let mut attributes = Vec::new();
 
@@ -1055,7 +1345,10 @@ define
                 && !schema.classes.contains_key(range)
             {
                 let attr_name = self.convert_identifier(slot_name);
-                let constraints = self.collect_slot_constraints(slot);
+                let constraints = match dialect {
+                    TypeQLDialect::V2 => self.collect_slot_constraints(slot),
+                    TypeQLDialect::V3 => self.owns_annotations_v3(slot),
+                };
                 attributes.push((attr_name, constraints));
             }
         }
@@ -1169,23 +1462,27 @@ define
         &self,
         range: Option<&String>,
         schema: &SchemaDefinition,
+        dialect: TypeQLDialect,
     ) -> &'static str {
-        match range.map(String::as_str) {
-            Some("string" | "str" | "uri" | "url" | "curie" | "ncname") => "string",
-            Some("integer" | "int") => "long",
-            Some("float" | "double" | "decimal" | "number") => "double",
-            Some("boolean" | "bool") => "boolean",
-            Some("date" | "datetime" | "time") => "datetime",
-            Some(custom) => {
+        match (range.map(String::as_str), dialect) {
+            (Some("string" | "str" | "uri" | "url" | "curie" | "ncname"), _) => "string",
+            (Some("integer" | "int"), TypeQLDialect::V2) => "long",
+            (Some("integer" | "int"), TypeQLDialect::V3) => "integer",
+            (Some("decimal"), TypeQLDialect::V3) => "decimal",
+            (Some("float" | "double" | "decimal" | "number"), _) => "double",
+            (Some("boolean" | "bool"), _) => "boolean",
+            (Some("date"), TypeQLDialect::V3) => "date",
+            (Some("date" | "datetime" | "time"), _) => "datetime",
+            (Some(custom), _) => {
                 // Check if it's a custom type definition
                 if let Some(type_def) = schema.types.get(custom) {
                     // Resolve base type
-                    self.map_range_to_typeql(type_def.base_type.as_ref(), schema)
+                    self.map_range_to_typeql(type_def.base_type.as_ref(), schema, dialect)
                 } else {
                     "string" // Default fallback
                 }
             }
-            None => "string",
+            (None, _) => "string",
         }
     }
 
@@ -1703,10 +2000,10 @@ impl SchemaAnalyzer {
             self.check_inheritance_cycle(class_name, class, schema, &mut HashSet::new())?;
         }
 
-        // Validate relation roles
-        for (class_name, class_type) in &self.type_cache {
-            if let TypeQLType::Relation = class_type
-                && let Some(class) = schema.classes.get(class_name)
+        // Validate relation roles, in schema order so the reported relation
+        // does not depend on hash order
+        for (class_name, class) in &schema.classes {
+            if let Some(TypeQLType::Relation) = self.type_cache.get(class_name)
                 && !class.slots.iter().any(|slot_name| {
                     schema
                         .slots
@@ -2068,10 +2365,13 @@ impl EnhancedTypeQLGenerator {
             schema.version.as_ref().unwrap_or(&"unknown".to_string())
         )
         .map_err(Self::fmt_error_to_generator_error)?;
-        let timestamp = self
-            .timestamp_utils
-            .now_rfc3339()
-            .unwrap_or_else(|_| "unknown".to_string());
+        let timestamp = match options.fixed_generation_time() {
+            Some(generated_at) => generated_at.to_rfc3339(),
+            None => self
+                .timestamp_utils
+                .now_rfc3339()
+                .unwrap_or_else(|_| "unknown".to_string()),
+        };
         writeln!(
             &mut output,
             "# Generated: {}",
@@ -2317,6 +2617,83 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_typeql_3_dialect() -> anyhow::Result<()> {
+        let generator = EnhancedTypeQLGenerator::new().with_dialect(TypeQLDialect::V2);
+        let mut schema = SchemaDefinition {
+            name: "People".to_string(),
+            ..Default::default()
+        };
+        schema.slots.insert(
+            "name".to_string(),
+            SlotDefinition {
+                name: "name".to_string(),
+                range: Some("string".to_string()),
+                identifier: Some(true),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "age".to_string(),
+            SlotDefinition {
+                name: "age".to_string(),
+                range: Some("integer".to_string()),
+                required: Some(true),
+                minimum_value: Some(json!(0)),
+                maximum_value: Some(json!(150)),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "email".to_string(),
+            SlotDefinition {
+                name: "email".to_string(),
+                range: Some("string".to_string()),
+                pattern: Some("^\\S+@\\S+$".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                name: "Person".to_string(),
+                slots: vec!["name".to_string(), "age".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Student".to_string(),
+            ClassDefinition {
+                name: "Student".to_string(),
+                is_a: Some("Person".to_string()),
+                slots: vec!["email".to_string()],
+                ..Default::default()
+            },
+        );
+
+        let mut options = GeneratorOptions::default();
+        options
+            .custom
+            .insert("typeql_dialect".to_string(), "3.x".to_string());
+        let outputs = AsyncGenerator::generate(&generator, &schema, &options).await?;
+        let content = &outputs[0].content;
+
+        assert!(content.contains("# TypeDB Version: 3.x"));
+        assert!(content.contains("attribute age, value integer @range(0..150);"));
+        assert!(content.contains("attribute email, value string @regex(\"^\\\\S+@\\\\S+$\");"));
+        assert!(content.contains("entity person,\n    owns name @key,\n    owns age @card(1..1);"));
+        assert!(content.contains("entity student, sub person,\n    owns email;"));
+        assert!(content.contains("# Validation Functions"));
+        assert!(!content.contains("sub entity"));
+        assert!(!content.contains("rule "));
+
+        options
+            .custom
+            .insert("typeql_dialect".to_string(), "4.x".to_string());
+        assert!(AsyncGenerator::generate(&generator, &schema, &options).await.is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_advanced_identifier_conversion() {
         let generator = EnhancedTypeQLGenerator::new();
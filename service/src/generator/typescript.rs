@@ -1,6 +1,8 @@
 //! TypeScript code generator for `LinkML` schemas
 
+use super::annotation_hints;
 use super::base::{BaseCodeFormatter, TypeMapper, collect_all_slots, is_optional_slot};
+use super::example_tests;
 use super::options::{GeneratorOptions, IndentStyle};
 use super::traits::{
     AsyncGenerator, CodeFormatter, GeneratedOutput, Generator, GeneratorError, GeneratorResult,
@@ -137,6 +139,7 @@ impl TypeScriptGenerator {
 
         // Collect all slots including inherited
         let slots = collect_all_slots(class, schema)?;
+        let class_readonly = annotation_hints::flag(class, annotation_hints::TS_READONLY);
 
         // Generate fields
         for slot_name in &slots {
@@ -151,7 +154,9 @@ impl TypeScriptGenerator {
                     }
                 }
 
-                self.generate_field(&mut output, slot_name, slot, schema, options)?;
+                let readonly =
+                    class_readonly || annotation_hints::flag(slot, annotation_hints::TS_READONLY);
+                self.generate_field(&mut output, slot_name, slot, readonly, schema, options)?;
             }
         }
 
@@ -186,6 +191,7 @@ impl TypeScriptGenerator {
         output: &mut String,
         slot_name: &str,
         slot: &SlotDefinition,
+        readonly: bool,
         schema: &SchemaDefinition,
         options: &GeneratorOptions,
     ) -> GeneratorResult<()> {
@@ -217,8 +223,13 @@ impl TypeScriptGenerator {
         // Handle optional
         let optional_marker = if is_optional_slot(slot) { "?" } else { "" };
 
-        writeln!(output, "  {slot_name}{optional_marker}: {field_type};")
-            .map_err(Self::fmt_error_to_generator_error)?;
+        let modifier = if readonly { "readonly " } else { "" };
+
+        writeln!(
+            output,
+            "  {modifier}{slot_name}{optional_marker}: {field_type};"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
 
         Ok(())
     }
@@ -549,10 +560,111 @@ impl TypeScriptGenerator {
         }
 
         writeln!(output, "}}").map_err(Self::fmt_error_to_generator_error)?;
+
+        // Parent of each value in the permissible value hierarchy
+        let parents: Vec<(&str, &str)> = slot
+            .permissible_values
+            .iter()
+            .filter_map(|pv| Some((pv.text(), pv.is_a()?)))
+            .collect();
+        if !parents.is_empty() {
+            writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(
+                output,
+                "export const {enum_name}Parents: Record<string, string> = {{"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            for (value, parent) in parents {
+                writeln!(output, "  \"{value}\": \"{parent}\",")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+            }
+            writeln!(output, "}};").map_err(Self::fmt_error_to_generator_error)?;
+        }
         Ok(())
     }
 }
 
+impl TypeScriptGenerator {
+    /// Generate Jest tests asserting that schema examples are accepted and
+    /// counterexamples rejected; see [`example_tests`]
+    ///
+    /// Tests call the generated validators when `generate_validators` is
+    /// enabled and the type guards otherwise. Returns `None` when neither is
+    /// generated or no class has test cases.
+    fn generate_tests(
+        &self,
+        schema: &SchemaDefinition,
+        module: &str,
+        options: &GeneratorOptions,
+    ) -> GeneratorResult<Option<String>> {
+        let custom = |key: &str| options.get_custom(key).map(String::as_str);
+        let validators = custom("generate_validators") == Some("true");
+        if !validators && custom("generate_type_guards") == Some("false") {
+            return Ok(None);
+        }
+        let cases = example_tests::example_cases(schema)?;
+        if cases.is_empty() {
+            return Ok(None);
+        }
+
+        let check = |class_name: &str| {
+            if validators {
+                format!("validate{class_name}")
+            } else {
+                format!("is{class_name}")
+            }
+        };
+        let mut classes: Vec<&str> = cases.iter().map(|case| case.class_name.as_str()).collect();
+        classes.dedup();
+
+        let mut output = String::new();
+        writeln!(output, "/**").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            output,
+            " * Tests generated from examples in LinkML schema: {}",
+            schema.name
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, " */").map_err(Self::fmt_error_to_generator_error)?;
+        let imports: Vec<String> = classes.iter().map(|class_name| check(class_name)).collect();
+        writeln!(
+            output,
+            "import {{ {} }} from './{module}';",
+            imports.join(", ")
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+
+        for class_name in classes {
+            writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(output, "describe('{class_name}', () => {{")
+                .map_err(Self::fmt_error_to_generator_error)?;
+            for case in cases.iter().filter(|case| case.class_name == class_name) {
+                let prefix = if case.valid { "" } else { "Counterexample: " };
+                let result = if validators { ".valid" } else { "" };
+                writeln!(
+                    output,
+                    "  // {prefix}{}",
+                    case.description.replace(['\n', '\r'], " ")
+                )
+                .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(output, "  test('{}', () => {{", case.name)
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(
+                    output,
+                    "    expect({}({}){result}).toBe({});",
+                    check(class_name),
+                    case.data,
+                    case.valid
+                )
+                .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(output, "  }});").map_err(Self::fmt_error_to_generator_error)?;
+            }
+            writeln!(output, "}});").map_err(Self::fmt_error_to_generator_error)?;
+        }
+        Ok(Some(output))
+    }
+}
+
 #[async_trait]
 impl AsyncGenerator for TypeScriptGenerator {
     fn name(&self) -> &str {
@@ -634,9 +746,10 @@ impl AsyncGenerator for TypeScriptGenerator {
             writeln!(&mut content).map_err(Self::fmt_error_to_generator_error)?;
         }
 
+        let module = schema.name.to_lowercase().replace('-', "_");
         outputs.push(GeneratedOutput {
             content,
-            filename: format!("{}.ts", schema.name.to_lowercase().replace('-', "_")),
+            filename: format!("{module}.ts"),
             metadata: {
                 let mut meta = HashMap::new();
                 meta.insert("generator".to_string(), self.name.clone());
@@ -646,6 +759,20 @@ impl AsyncGenerator for TypeScriptGenerator {
             },
         });
 
+        if options.generate_tests
+            && let Some(tests) = self.generate_tests(schema, &module, options)?
+        {
+            let mut metadata = HashMap::new();
+            metadata.insert("generator".to_string(), self.name.clone());
+            metadata.insert("schema".to_string(), schema.name.clone());
+            metadata.insert("file_type".to_string(), "tests".to_string());
+            outputs.push(GeneratedOutput {
+                content: tests,
+                filename: format!("{module}.test.ts"),
+                metadata,
+            });
+        }
+
         Ok(outputs)
     }
 
@@ -809,4 +936,62 @@ mod tests {
         assert!(output.content.contains("age?: number;"));
         assert!(output.content.contains("export function isPerson"));
     }
+
+    #[tokio::test]
+    async fn test_readonly_annotation() {
+        let mut schema = SchemaDefinition {
+            name: "test_schema".to_string(),
+            ..Default::default()
+        };
+        let readonly = || {
+            Some(
+                [(annotation_hints::TS_READONLY.to_string(), true.into())]
+                    .into_iter()
+                    .collect(),
+            )
+        };
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                name: "Person".to_string(),
+                slots: vec!["id".to_string(), "name".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Event".to_string(),
+            ClassDefinition {
+                name: "Event".to_string(),
+                slots: vec!["name".to_string()],
+                annotations: readonly(),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                required: Some(true),
+                annotations: readonly(),
+                ..Default::default()
+            },
+        );
+        schema
+            .slots
+            .insert("name".to_string(), SlotDefinition::default());
+
+        let outputs = AsyncGenerator::generate(
+            &TypeScriptGenerator::new(),
+            &schema,
+            &GeneratorOptions::new(),
+        )
+        .await
+        .expect("should generate TypeScript output");
+        let content = &outputs[0].content;
+        assert!(
+            content.contains(
+                "export interface Person {\n  readonly id: unknown;\n  name?: unknown;\n}"
+            )
+        );
+        assert!(content.contains("export interface Event {\n  readonly name?: unknown;\n}"));
+    }
 }
@@ -1,10 +1,16 @@
 //! Validation method generation
+//!
+//! Besides the inherent `validate()` method of the string-based generator,
+//! this module builds `linkml_core::LinkMLValidate` implementations, which
+//! embed the slot constraints of a class in the generated type.
 
 use super::base::collect_all_slots;
 use super::core::RustGenerator;
-use super::traits::{GeneratorOptions, GeneratorResult, IndentStyle};
+use super::rust_ast;
+use super::traits::{GeneratorError, GeneratorOptions, GeneratorResult, IndentStyle};
 use linkml_core::prelude::*;
 use std::fmt::Write;
+use syn::{Expr, Item, Stmt, parse_quote};
 
 impl RustGenerator {
     /// Generate `validate()` method
@@ -300,3 +306,254 @@ impl RustGenerator {
         Ok(())
     }
 }
+
+impl RustGenerator {
+    /// Build the `LinkMLValidate` implementation of a class
+    ///
+    /// Required strings and lists must not be empty, strings must match the
+    /// `pattern` of their slot, and numbers must lie within its
+    /// `minimum_value` and `maximum_value`. Fields whose range is a class are
+    /// validated recursively, with their messages placed below the field.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError` if a slot name cannot be turned into an
+    /// identifier, or if a pattern or bound is invalid.
+    pub(super) fn validate_impl_item(
+        &self,
+        class_name: &str,
+        class_def: &ClassDefinition,
+        schema: &SchemaDefinition,
+    ) -> GeneratorResult<Item> {
+        let name = rust_ast::ident(class_name)?;
+        let mut checks = Vec::new();
+        for slot_name in &self.collect_class_slots(class_def, schema) {
+            if let Some(slot) = schema.slots.get(slot_name) {
+                checks.extend(Self::slot_checks(slot_name, slot, schema)?);
+            }
+        }
+
+        let body: Vec<Stmt> = if checks.is_empty() {
+            parse_quote!(Vec::new())
+        } else {
+            parse_quote! {
+                use linkml_core::validation::{ValidationMessage, ValidationSeverity};
+                let mut messages = Vec::new();
+                #(#checks)*
+                messages
+            }
+        };
+        Ok(parse_quote! {
+            impl linkml_core::LinkMLValidate for #name {
+                const CLASS_NAME: &'static str = #class_name;
+
+                fn validation_messages(&self) -> Vec<linkml_core::validation::ValidationMessage> {
+                    #(#body)*
+                }
+            }
+        })
+    }
+
+    /// Statements checking the field of a slot, if it has constraints
+    fn slot_checks(
+        slot_name: &str,
+        slot: &SlotDefinition,
+        schema: &SchemaDefinition,
+    ) -> GeneratorResult<Option<Stmt>> {
+        let field = rust_ast::ident(&Self::convert_field_name(slot_name))?;
+        let range = slot.range.as_deref();
+        let is_class = range.is_some_and(|range| schema.classes.contains_key(range));
+        let rust_type = match range {
+            Some(range) if schema.enums.contains_key(range) || is_class => "",
+            Some(range) => Self::linkml_type_to_rust(range),
+            None => "String",
+        };
+
+        let value_checks = Self::value_checks(slot_name, slot, rust_type, is_class)?;
+
+        let required = slot.required.unwrap_or(false);
+        let required_check: Stmt = parse_quote! {
+            messages.push(
+                ValidationMessage::new(ValidationSeverity::Error, "Required value missing")
+                    .with_path(#slot_name)
+                    .with_rule("required"),
+            );
+        };
+        let stmt = if slot.multivalued.unwrap_or(false) {
+            let emptiness: Option<Stmt> = required.then(|| {
+                parse_quote! {
+                    if self.#field.is_empty() {
+                        #required_check
+                    }
+                }
+            });
+            let elements: Option<Stmt> = (!value_checks.is_empty()).then(|| {
+                parse_quote! {
+                    for (index, value) in self.#field.iter().enumerate() {
+                        let path = format!("{}[{}]", #slot_name, index);
+                        #(#value_checks)*
+                    }
+                }
+            });
+            if emptiness.is_none() && elements.is_none() {
+                return Ok(None);
+            }
+            parse_quote! {
+                {
+                    #emptiness
+                    #elements
+                }
+            }
+        } else if required {
+            let emptiness: Option<Stmt> = (rust_type == "String").then(|| {
+                parse_quote! {
+                    if value.is_empty() {
+                        #required_check
+                    }
+                }
+            });
+            if emptiness.is_none() && value_checks.is_empty() {
+                return Ok(None);
+            }
+            let path: Option<Stmt> = (!value_checks.is_empty())
+                .then(|| parse_quote!(let path = #slot_name.to_string();));
+            parse_quote! {
+                {
+                    let value = &self.#field;
+                    #path
+                    #emptiness
+                    #(#value_checks)*
+                }
+            }
+        } else {
+            if value_checks.is_empty() {
+                return Ok(None);
+            }
+            parse_quote! {
+                if let Some(value) = &self.#field {
+                    let path = #slot_name.to_string();
+                    #(#value_checks)*
+                }
+            }
+        };
+        Ok(Some(stmt))
+    }
+
+    /// Statements checking one value of a slot, bound to `value` and with
+    /// its path in `path`
+    fn value_checks(
+        slot_name: &str,
+        slot: &SlotDefinition,
+        rust_type: &str,
+        is_class: bool,
+    ) -> GeneratorResult<Vec<Stmt>> {
+        let mut value_checks: Vec<Stmt> = Vec::new();
+        if rust_type == "String"
+            && let Some(pattern) = &slot.pattern
+        {
+            regex::Regex::new(pattern).map_err(|e| {
+                GeneratorError::Validation(format!("Invalid pattern of slot '{slot_name}': {e}"))
+            })?;
+            let pattern_message = format!(
+                "Value '{{}}' does not match pattern '{}'",
+                pattern.replace('{', "{{").replace('}', "}}")
+            );
+            value_checks.push(parse_quote! {
+                {
+                    static PATTERN: std::sync::LazyLock<Option<regex::Regex>> =
+                        std::sync::LazyLock::new(|| regex::Regex::new(#pattern).ok());
+                    if PATTERN.as_ref().is_some_and(|pattern| !pattern.is_match(value)) {
+                        messages.push(
+                            ValidationMessage::new(
+                                ValidationSeverity::Error,
+                                format!(#pattern_message, value),
+                            )
+                            .with_path(path.clone())
+                            .with_rule("pattern"),
+                        );
+                    }
+                }
+            });
+        }
+        if matches!(rust_type, "i64" | "f64") {
+            let integer = rust_type == "i64";
+            for (bound, is_minimum) in [(&slot.minimum_value, true), (&slot.maximum_value, false)] {
+                let Some(bound) = bound else {
+                    continue;
+                };
+                let literal = Self::bound_literal(slot_name, bound, integer, is_minimum)?;
+                let bound: Expr = syn::parse_str(&literal)
+                    .map_err(|e| GeneratorError::Generation(e.to_string()))?;
+                let (comparison, rule, text): (Expr, _, _) = if is_minimum {
+                    (
+                        parse_quote!(*value < #bound),
+                        "minimum_value",
+                        "less than the minimum",
+                    )
+                } else {
+                    (
+                        parse_quote!(*value > #bound),
+                        "maximum_value",
+                        "greater than the maximum",
+                    )
+                };
+                let message = format!("Value {{}} is {text} {literal}");
+                value_checks.push(parse_quote! {
+                    if #comparison {
+                        messages.push(
+                            ValidationMessage::new(ValidationSeverity::Error, format!(#message, value))
+                                .with_path(path.clone())
+                                .with_rule(#rule),
+                        );
+                    }
+                });
+            }
+        }
+        if is_class {
+            value_checks.push(parse_quote! {
+                messages.extend(
+                    linkml_core::LinkMLValidate::validation_messages(&**value)
+                        .into_iter()
+                        .map(|message| message.with_parent_path(&path)),
+                );
+            });
+        }
+        Ok(value_checks)
+    }
+
+    /// Source text of a `minimum_value` or `maximum_value` for a field of type
+    /// `i64` or `f64`
+    ///
+    /// Fractional bounds of integer fields are rounded inwards, so that the
+    /// comparison stays exact.
+    fn bound_literal(
+        slot_name: &str,
+        bound: &serde_json::Value,
+        integer: bool,
+        is_minimum: bool,
+    ) -> GeneratorResult<String> {
+        let invalid = || {
+            GeneratorError::Validation(format!(
+                "Bound {bound} of slot '{slot_name}' is not a number"
+            ))
+        };
+        if integer {
+            if let Some(bound) = bound.as_i64() {
+                return Ok(bound.to_string());
+            }
+            let bound = bound.as_f64().ok_or_else(invalid)?;
+            let rounded = if is_minimum {
+                bound.ceil()
+            } else {
+                bound.floor()
+            };
+            Ok(format!("{rounded:.0}"))
+        } else {
+            let bound = bound
+                .as_f64()
+                .filter(|bound| bound.is_finite())
+                .ok_or_else(invalid)?;
+            Ok(format!("{bound:?}"))
+        }
+    }
+}
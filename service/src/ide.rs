@@ -9,7 +9,11 @@
 //! - Find references
 //! - Hover information
 //! - Code actions and quick fixes
+//! - Workspace symbols backed by the schema search index
 
+use crate::schema_view::search::{SearchIndex, SearchOptions};
+use crate::schema_view::view::ElementType;
+use crate::validator::{Severity, SourceLocation, SourceMap, ValidationIssue};
 use linkml_core::types::SchemaDefinition;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
@@ -665,6 +669,44 @@ pub struct Position {
     pub character: u32,
 }
 
+impl From<SourceLocation> for Position {
+    fn from(location: SourceLocation) -> Self {
+        let zero_based = |value: usize| u32::try_from(value.saturating_sub(1)).unwrap_or(u32::MAX);
+        Self {
+            line: zero_based(location.line),
+            character: zero_based(location.column),
+        }
+    }
+}
+
+impl From<&ValidationIssue> for Diagnostic {
+    /// Diagnostic for a data validation issue, placed at the line and column
+    /// recorded by a [`SourceMap`](crate::validator::SourceMap)
+    fn from(issue: &ValidationIssue) -> Self {
+        let position = SourceLocation::of_issue(issue).map_or(
+            Position {
+                line: 0,
+                character: 0,
+            },
+            Position::from,
+        );
+        Self {
+            range: Range {
+                start: position,
+                end: position,
+            },
+            severity: match issue.severity {
+                Severity::Error => DiagnosticSeverity::Error,
+                Severity::Warning => DiagnosticSeverity::Warning,
+                Severity::Info => DiagnosticSeverity::Information,
+            },
+            message: issue.message.clone(),
+            source: "linkml".to_string(),
+            code: Some(issue.error_code().to_string()),
+        }
+    }
+}
+
 impl<S> DiagnosticProvider<S>
 where
     S: linkml_core::traits::LinkMLService,
@@ -749,6 +791,181 @@ where
     }
 }
 
+/// Workspace symbol provider backed by the schema search index
+///
+/// Answers LSP `workspace/symbol` requests with the ranked fuzzy matches of
+/// [`SearchIndex::search`] over every open schema document. An empty query
+/// lists all elements, as the protocol asks.
+#[derive(Debug, Clone)]
+pub struct WorkspaceSymbolProvider {
+    /// Open schema documents by URI
+    documents: HashMap<String, SymbolDocument>,
+    /// Maximum number of symbols returned
+    limit: usize,
+}
+
+/// Indexed schema document
+#[derive(Debug, Clone)]
+struct SymbolDocument {
+    schema_name: String,
+    index: SearchIndex,
+    source_map: Option<SourceMap>,
+}
+
+/// Symbol of a workspace symbol response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolInformation {
+    /// Element name
+    pub name: String,
+    /// LSP `SymbolKind` code
+    pub kind: u8,
+    /// Where the element is defined
+    pub location: Location,
+    /// Name of the schema defining the element
+    pub container_name: Option<String>,
+}
+
+/// Location in a document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Location {
+    /// Document URI
+    pub uri: String,
+    /// Range in the document
+    pub range: Range,
+}
+
+impl Default for WorkspaceSymbolProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorkspaceSymbolProvider {
+    /// Create a provider returning at most 100 symbols per request
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            documents: HashMap::new(),
+            limit: 100,
+        }
+    }
+
+    /// Set the maximum number of symbols returned
+    #[must_use]
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Index the schema of an open document
+    ///
+    /// `content` is the document text the schema was loaded from and is used
+    /// to locate the elements; symbols of text that cannot be mapped are
+    /// placed at the start of the document.
+    pub fn set_document(
+        &mut self,
+        uri: impl Into<String>,
+        schema: &SchemaDefinition,
+        content: &str,
+    ) {
+        self.documents.insert(
+            uri.into(),
+            SymbolDocument {
+                schema_name: schema.name.clone(),
+                index: SearchIndex::build(schema),
+                source_map: SourceMap::parse(content).ok(),
+            },
+        );
+    }
+
+    /// Forget a closed document
+    pub fn remove_document(&mut self, uri: &str) {
+        self.documents.remove(uri);
+    }
+
+    /// Symbols matching `query`, best matches first
+    #[must_use]
+    pub fn symbols(&self, query: &str) -> Vec<SymbolInformation> {
+        let mut ranked: Vec<(f64, SymbolInformation)> = Vec::new();
+        let options = SearchOptions::default().with_limit(self.limit);
+        for (uri, document) in &self.documents {
+            if query.trim().is_empty() {
+                ranked.extend(
+                    document.index.elements().map(|(name, element_type)| {
+                        (1.0, document.symbol(uri, name, element_type))
+                    }),
+                );
+            } else {
+                ranked.extend(
+                    document
+                        .index
+                        .search(query, &options)
+                        .into_iter()
+                        .map(|hit| (hit.score, document.symbol(uri, &hit.name, hit.element_type))),
+                );
+            }
+        }
+        ranked.sort_by(|(a_score, a), (b_score, b)| {
+            b_score
+                .total_cmp(a_score)
+                .then_with(|| a.name.cmp(&b.name))
+                .then_with(|| a.location.uri.cmp(&b.location.uri))
+        });
+        ranked
+            .into_iter()
+            .take(self.limit)
+            .map(|(_, symbol)| symbol)
+            .collect()
+    }
+}
+
+impl SymbolDocument {
+    fn symbol(&self, uri: &str, name: &str, element_type: ElementType) -> SymbolInformation {
+        let section = match element_type {
+            ElementType::Class => "classes",
+            ElementType::Slot => "slots",
+            ElementType::Type => "types",
+            ElementType::Enum => "enums",
+            ElementType::Subset => "subsets",
+        };
+        let position = self
+            .source_map
+            .as_ref()
+            .and_then(|source_map| source_map.location(&format!("$.{section}.{name}")))
+            .map_or(
+                Position {
+                    line: 0,
+                    character: 0,
+                },
+                Position::from,
+            );
+        SymbolInformation {
+            name: name.to_string(),
+            kind: symbol_kind(element_type),
+            location: Location {
+                uri: uri.to_string(),
+                range: Range {
+                    start: position,
+                    end: position,
+                },
+            },
+            container_name: Some(self.schema_name.clone()).filter(|name| !name.is_empty()),
+        }
+    }
+}
+
+/// LSP `SymbolKind` code of a schema element
+fn symbol_kind(element_type: ElementType) -> u8 {
+    match element_type {
+        ElementType::Class => 5,  // Class
+        ElementType::Slot => 7,   // Property
+        ElementType::Type => 26,  // TypeParameter
+        ElementType::Enum => 10,  // Enum
+        ElementType::Subset => 3, // Namespace
+    }
+}
+
 /// VS Code extension configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VSCodeExtension {
@@ -991,4 +1208,42 @@ mod tests {
         assert!(!grammar.patterns.is_empty());
         assert!(!grammar.repository.is_empty());
     }
+
+    #[test]
+    fn test_workspace_symbols() {
+        let content = "id: https://example.org/people\nname: people\nclasses:\n  Person:\n    aliases:\n      - human\n  Organization: {}\nslots:\n  person_name: {}\n";
+        let schema: SchemaDefinition = serde_yaml::from_str(content).expect("parse schema");
+        let mut provider = WorkspaceSymbolProvider::new();
+        provider.set_document("file:///people.yaml", &schema, content);
+
+        let symbols = provider.symbols("human");
+        assert_eq!(symbols[0].name, "Person");
+        assert_eq!(symbols[0].kind, 5);
+        assert_eq!(symbols[0].container_name.as_deref(), Some("people"));
+        assert_eq!(symbols[0].location.range.start.line, 3);
+
+        assert_eq!(provider.symbols("").len(), 3);
+        provider.remove_document("file:///people.yaml");
+        assert!(provider.symbols("").is_empty());
+    }
+
+    #[test]
+    fn test_issue_diagnostic() -> linkml_core::error::Result<()> {
+        let source_map = crate::validator::SourceMap::parse("name: Ada\nage: old\n")?;
+        let mut report = crate::validator::ValidationReport::new("people");
+        report.add_issue(ValidationIssue::error("Expected integer", "$.age", "type"));
+        source_map.locate(&mut report);
+
+        let diagnostic = Diagnostic::from(&report.issues[0]);
+        assert_eq!(
+            diagnostic.range.start,
+            Position {
+                line: 1,
+                character: 5
+            }
+        );
+        assert_eq!(diagnostic.severity, DiagnosticSeverity::Error);
+        assert_eq!(diagnostic.message, "Expected integer");
+        Ok(())
+    }
 }
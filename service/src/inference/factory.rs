@@ -5,16 +5,21 @@
 
 #![allow(deprecated)]
 
+#[cfg(feature = "rootreal-services")]
 use crate::inference::engine::InferenceEngine;
 use crate::inference::introspectors::{CsvIntrospector, JsonIntrospector, XmlIntrospector};
 use crate::inference::traits::InferenceResult;
+#[cfg(feature = "rootreal-services")]
 use crate::inference::types::InferenceConfig;
+#[cfg(feature = "rootreal-services")]
+use crate::utils::logging::wire_logger;
+#[cfg(feature = "rootreal-services")]
+use crate::utils::timestamp::wire_timestamp;
+#[cfg(feature = "rootreal-services")]
 use format_identification_service::create_format_identification_service;
 use logger_core::{LoggerError, LoggerService};
-use logger_service::factory::create_logger_service;
 use std::sync::Arc;
 use timestamp_core::{TimestampError, TimestampService};
-use timestamp_service::wiring::wire_timestamp;
 
 /// Factory function to create a  service instance
 ///
@@ -189,6 +194,9 @@ pub fn create_csv_introspector(
 
 /// Create a fully-configured inference engine with all services
 ///
+/// Requires the `rootreal-services` feature, which provides the format
+/// identification service.
+///
 /// This factory function creates an InferenceEngine with complete service integration:
 /// - Format Identification Service for automatic format detection
 /// - All introspectors (XML, JSON, CSV) with Parse Service integration
@@ -216,15 +224,13 @@ pub fn create_csv_introspector(
 /// # Ok(())
 /// # }
 /// ```
+#[cfg(feature = "rootreal-services")]
 pub async fn create_inference_engine() -> InferenceResult<Arc<InferenceEngine>> {
     // Create core services
-    let logger = create_logger_service()
-        .await
+    let logger = wire_logger()
         .map_err(|e| crate::inference::traits::InferenceError::ServiceError(e.to_string()))?;
 
-    let timestamp = wire_timestamp();
-
-    create_inference_engine_with_services(logger, timestamp.into_inner()).await
+    create_inference_engine_with_services(logger, wire_timestamp()).await
 }
 
 /// Create inference engine with provided services
@@ -255,6 +261,7 @@ pub async fn create_inference_engine() -> InferenceResult<Arc<InferenceEngine>>
 /// # Ok(())
 /// # }
 /// ```
+#[cfg(feature = "rootreal-services")]
 pub async fn create_inference_engine_with_services(
     logger: Arc<dyn LoggerService<Error = LoggerError>>,
     timestamp: Arc<dyn TimestampService<Error = TimestampError>>,
@@ -331,6 +338,7 @@ pub async fn create_inference_engine_with_services(
 /// # Ok(())
 /// # }
 /// ```
+#[cfg(feature = "rootreal-services")]
 pub async fn create_inference_engine_with_config(
     config: InferenceConfig,
     logger: Arc<dyn LoggerService<Error = LoggerError>>,
@@ -373,12 +381,13 @@ pub async fn create_inference_engine_with_config(
 mod tests {
     use super::*;
     use crate::inference::traits::DataIntrospector;
+    use crate::utils::logging::wire_logger;
+    use crate::utils::timestamp::wire_timestamp;
 
     #[tokio::test]
     async fn test_create_introspectors() {
-        let logger = logger_service::test_utils::create_test_logger_service();
-        // Use test utility to get timestamp without Send + Sync auto-traits
-        let timestamp = timestamp_service::test_utils::create_test_timestamp_service();
+        let logger = wire_logger().expect("should wire logger");
+        let timestamp = wire_timestamp();
 
         // Test introspector creation using direct instantiation
         // Note: Using analyze_bytes (correct method name from DataIntrospector trait)
@@ -392,6 +401,7 @@ mod tests {
         assert!(csv.analyze_bytes(b"a,b,c\n1,2,3").await.is_ok());
     }
 
+    #[cfg(feature = "rootreal-services")]
     #[tokio::test]
     async fn test_create_inference_engine() {
         // This will fail if any service is not properly configured
@@ -403,11 +413,11 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "rootreal-services")]
     #[tokio::test]
     async fn test_create_inference_engine_with_custom_config() {
-        let logger = logger_service::test_utils::create_test_logger_service();
-        // Use test utility to get timestamp without Send + Sync auto-traits
-        let timestamp = timestamp_service::test_utils::create_test_timestamp_service();
+        let logger = wire_logger().expect("should wire logger");
+        let timestamp = wire_timestamp();
 
         let config = InferenceConfig {
             min_samples_for_type_inference: 10,
@@ -440,9 +440,9 @@ fn excel_type_to_linkml(excel_type: &str) -> &str {
 /// ```no_run
 /// use linkml_service::inference::introspectors::excel::wire_excel_introspector;
 /// use logger_service::wiring::wire_logger;
-/// use timestamp_service::wiring::wire_timestamp;
+/// use crate::utils::timestamp::wire_timestamp;
 ///
-/// let timestamp = wire_timestamp().into_arc();
+/// let timestamp = wire_timestamp();
 /// let logger = wire_logger(timestamp.clone()).into_arc();
 /// let excel_introspector = wire_excel_introspector(logger, timestamp);
 /// ```
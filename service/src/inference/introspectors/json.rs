@@ -462,13 +462,8 @@ mod tests {
         Arc<dyn LoggerService<Error = LoggerError>>,
         Arc<dyn TimestampService<Error = TimestampError>>,
     ) {
-        let timestamp = timestamp_service::wiring::wire_timestamp().into_arc();
-        let logger = logger_service::wiring::wire_logger(
-            timestamp.clone(),
-            logger_core::LoggerConfig::default(),
-        )
-        .expect("Failed to wire logger")
-        .into_arc();
+        let timestamp = crate::utils::timestamp::wire_timestamp();
+        let logger = crate::utils::logging::wire_logger().expect("Failed to wire logger");
         (logger, timestamp)
     }
 
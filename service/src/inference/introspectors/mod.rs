@@ -1,14 +1,17 @@
 //! Format-specific introspector implementations
 //!
 //! This module contains implementations of the DataIntrospector trait
-//! for different data formats (XML, JSON, CSV, Excel/ODS).
+//! for different data formats (XML, JSON, CSV, Excel/ODS), and an
+//! introspector deriving schemas from OWL ontologies.
 
 pub mod csv;
 pub mod excel;
 pub mod json;
+pub mod owl;
 pub mod xml;
 
 pub use csv::CsvIntrospector;
 pub use excel::ExcelIntrospector;
 pub use json::JsonIntrospector;
+pub use owl::{OwlIntrospectionConfig, OwlIntrospector};
 pub use xml::XmlIntrospector;
@@ -0,0 +1,884 @@
+//! OWL introspector for schema inference
+//!
+//! This module reads an OWL ontology serialized as RDF and derives a LinkML
+//! schema from its declarations rather than from instance statistics:
+//! - `owl:Class` declarations become classes, with `is_a` from the first
+//!   named superclass and `subclass_of` for the others
+//! - object, datatype and plain RDF properties become slots, with ranges from
+//!   `rdfs:range` and cardinality from `owl:FunctionalProperty`
+//! - `rdfs:domain` and property restrictions on superclasses attach slots to
+//!   classes, restrictions refining them through `slot_usage`
+//! - classes enumerated with `owl:oneOf`, and optionally classes that only
+//!   have named individuals as members, become enums
+//!
+//! [`OwlIntrospectionConfig`] holds the heuristics that are a matter of taste.
+
+use crate::inference::traits::{InferenceError, InferenceResult};
+use crate::ontology::{OBO_PURL, obo_curie};
+use crate::rdf::{Graph, Node};
+use convert_case::{Case, Casing};
+use indexmap::IndexMap;
+use linkml_core::types::{
+    ClassDefinition, EnumDefinition, PermissibleValue, PrefixDefinition, SchemaDefinition,
+    SlotDefinition,
+};
+use oxigraph::io::RdfFormat;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const RDFS: &str = "http://www.w3.org/2000/01/rdf-schema#";
+const OWL: &str = "http://www.w3.org/2002/07/owl#";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+const SKOS: &str = "http://www.w3.org/2004/02/skos/core#";
+const DCTERMS: &str = "http://purl.org/dc/terms/";
+/// IAO `definition`, the textual definition of OBO terms
+const IAO_DEFINITION: &str = "http://purl.obolibrary.org/obo/IAO_0000115";
+
+/// Prefixes used for CURIEs of terms outside the ontology namespace
+const KNOWN_PREFIXES: [(&str, &str); 6] = [
+    ("rdf", RDF),
+    ("rdfs", RDFS),
+    ("owl", OWL),
+    ("xsd", XSD),
+    ("skos", SKOS),
+    ("dcterms", DCTERMS),
+];
+
+/// Heuristics of the OWL introspection
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct OwlIntrospectionConfig {
+    /// Schema id, by default the ontology IRI
+    pub schema_id: Option<String>,
+    /// Schema name, by default derived from the schema id
+    pub schema_name: Option<String>,
+    /// Name elements after their `rdfs:label` instead of the local name of
+    /// their IRI, for ontologies with opaque identifiers such as OBO
+    pub names_from_labels: bool,
+    /// Leave out classes and properties outside the ontology namespace
+    pub local_terms_only: bool,
+    /// Turn classes enumerated with `owl:oneOf` into enums
+    pub enums_from_one_of: bool,
+    /// Turn classes whose only members are named individuals, and that have
+    /// neither subclasses nor properties, into enums
+    pub enums_from_individuals: bool,
+    /// Make slots multivalued unless their property is functional or a
+    /// restriction allows a single value
+    pub multivalued_by_default: bool,
+}
+
+impl Default for OwlIntrospectionConfig {
+    fn default() -> Self {
+        Self {
+            schema_id: None,
+            schema_name: None,
+            names_from_labels: false,
+            local_terms_only: false,
+            enums_from_one_of: true,
+            enums_from_individuals: true,
+            multivalued_by_default: true,
+        }
+    }
+}
+
+/// Introspector deriving a LinkML schema from an OWL ontology
+///
+/// Unlike the data introspectors, an ontology already declares its structure,
+/// so no statistics are collected and the schema is read off the axioms.
+#[derive(Debug, Clone, Default)]
+pub struct OwlIntrospector {
+    config: OwlIntrospectionConfig,
+}
+
+impl OwlIntrospector {
+    /// Create an introspector with the default heuristics
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an introspector with the given heuristics
+    #[must_use]
+    pub fn with_config(config: OwlIntrospectionConfig) -> Self {
+        Self { config }
+    }
+
+    /// Introspect an ontology file, choosing the syntax by extension:
+    /// `.owl`/`.rdf` (RDF/XML), `.ttl` (Turtle) or `.nt` (N-Triples)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or has an
+    /// unsupported extension.
+    pub fn introspect_file(&self, path: &Path) -> InferenceResult<SchemaDefinition> {
+        let format = match path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_lowercase)
+            .as_deref()
+        {
+            Some("owl" | "rdf") => RdfFormat::RdfXml,
+            Some("ttl") => RdfFormat::Turtle,
+            Some("nt") => RdfFormat::NTriples,
+            _ => {
+                return Err(InferenceError::UnsupportedFormat {
+                    puid: String::new(),
+                    format_name: format!("'{}': expected .owl, .rdf, .ttl or .nt", path.display()),
+                });
+            }
+        };
+        let content = std::fs::read_to_string(path)?;
+        self.introspect_str(&content, format)
+    }
+
+    /// Introspect an ontology serialized in `format`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not valid in `format`.
+    pub fn introspect_str(
+        &self,
+        content: &str,
+        format: RdfFormat,
+    ) -> InferenceResult<SchemaDefinition> {
+        let graph = Graph::parse(content, format)
+            .map_err(|e| InferenceError::InvalidDataStructure(format!("Invalid ontology: {e}")))?;
+        Ok(Introspection::new(&graph, &self.config).schema())
+    }
+}
+
+/// Cardinality and range a restriction places on a property
+#[derive(Debug, Default)]
+struct Restriction {
+    range: Option<String>,
+    required: bool,
+    multivalued: Option<bool>,
+}
+
+/// State of one introspection run
+struct Introspection<'a> {
+    graph: &'a Graph,
+    config: &'a OwlIntrospectionConfig,
+    /// Schema id
+    id: String,
+    /// Namespace of the ontology terms
+    namespace: String,
+    /// Prefix of the namespace, the schema name
+    prefix: String,
+    /// Schema element names by IRI
+    names: HashMap<String, String>,
+    classes: Vec<String>,
+    properties: Vec<String>,
+    enums: Vec<String>,
+    /// Prefixes used in CURIEs, by prefix
+    used_prefixes: IndexMap<String, String>,
+}
+
+impl<'a> Introspection<'a> {
+    fn new(graph: &'a Graph, config: &'a OwlIntrospectionConfig) -> Self {
+        let owl = |name: &str| format!("{OWL}{name}");
+        let class_types = [owl("Class"), format!("{RDFS}Class")];
+        let property_types = [
+            owl("ObjectProperty"),
+            owl("DatatypeProperty"),
+            format!("{RDF}Property"),
+        ];
+        let class_types: Vec<&str> = class_types.iter().map(String::as_str).collect();
+        let property_types: Vec<&str> = property_types.iter().map(String::as_str).collect();
+
+        let declared_classes: Vec<String> = graph
+            .typed(&class_types)
+            .into_iter()
+            .filter(|iri| iri != &owl("Thing"))
+            .collect();
+        let properties = graph.typed(&property_types);
+        let namespace = common_namespace(declared_classes.iter().chain(&properties))
+            .or_else(|| ontology_iri(graph).map(|iri| format!("{}/", iri.trim_end_matches('/'))))
+            .unwrap_or_default();
+        let id = config
+            .schema_id
+            .clone()
+            .or_else(|| ontology_iri(graph).map(String::from))
+            .unwrap_or_else(|| namespace.trim_end_matches(['/', '#']).to_string());
+        let prefix = config.schema_name.clone().unwrap_or_else(|| {
+            local_name(id.trim_end_matches(['/', '#']))
+                .trim_end_matches(".owl")
+                .to_case(Case::Snake)
+        });
+
+        let mut introspection = Self {
+            graph,
+            config,
+            id,
+            namespace,
+            prefix,
+            names: HashMap::new(),
+            classes: Vec::new(),
+            properties: Vec::new(),
+            enums: Vec::new(),
+            used_prefixes: IndexMap::new(),
+        };
+        let local =
+            |iri: &String| !config.local_terms_only || iri.starts_with(&introspection.namespace);
+        let (enums, classes): (Vec<String>, Vec<String>) = declared_classes
+            .iter()
+            .filter(|iri| local(iri))
+            .cloned()
+            .partition(|iri| introspection.is_enum(iri, &properties));
+        let properties: Vec<String> = properties
+            .iter()
+            .filter(|iri| local(iri))
+            .cloned()
+            .collect();
+
+        let mut taken = HashSet::new();
+        for iri in &classes {
+            let name = introspection.element_name(iri, Case::Pascal, &mut taken);
+            introspection.names.insert(iri.clone(), name);
+        }
+        for iri in &enums {
+            let name = introspection.element_name(iri, Case::Pascal, &mut taken);
+            introspection.names.insert(iri.clone(), name);
+        }
+        for iri in &properties {
+            let name = introspection.element_name(iri, Case::Snake, &mut taken);
+            introspection.names.insert(iri.clone(), name);
+        }
+        introspection.classes = classes;
+        introspection.enums = enums;
+        introspection.properties = properties;
+        introspection
+    }
+
+    fn schema(mut self) -> SchemaDefinition {
+        let graph = self.graph;
+        let ontology = ontology_iri(graph).map(|iri| Node::Iri(iri.to_string()));
+        let mut schema = SchemaDefinition {
+            id: self.id.clone(),
+            name: self.prefix.clone(),
+            default_prefix: Some(self.prefix.clone()),
+            imports: vec!["linkml:types".to_string()],
+            ..Default::default()
+        };
+        if let Some(ontology) = &ontology {
+            schema.title = graph.literal(
+                ontology,
+                &[&format!("{DCTERMS}title"), &format!("{RDFS}label")],
+            );
+            schema.description = graph.literal(
+                ontology,
+                &[&format!("{DCTERMS}description"), &format!("{RDFS}comment")],
+            );
+            schema.version = graph.literal(ontology, &[&format!("{OWL}versionInfo")]);
+        }
+        schema.prefixes.insert(
+            "linkml".to_string(),
+            PrefixDefinition::Simple("https://w3id.org/linkml/".to_string()),
+        );
+        schema.prefixes.insert(
+            self.prefix.clone(),
+            PrefixDefinition::Simple(self.namespace.clone()),
+        );
+
+        for iri in self.enums.clone() {
+            let enum_def = self.enum_definition(&iri);
+            schema.enums.insert(self.names[&iri].clone(), enum_def);
+        }
+        for iri in self.properties.clone() {
+            let slot = self.slot_definition(&iri);
+            schema.slots.insert(self.names[&iri].clone(), slot);
+        }
+        for iri in self.classes.clone() {
+            let class = self.class_definition(&iri, &schema.slots);
+            schema.classes.insert(self.names[&iri].clone(), class);
+        }
+
+        for (prefix, namespace) in std::mem::take(&mut self.used_prefixes) {
+            schema
+                .prefixes
+                .entry(prefix)
+                .or_insert(PrefixDefinition::Simple(namespace));
+        }
+        schema
+    }
+
+    /// Whether a class becomes an enum
+    fn is_enum(&self, iri: &str, properties: &[String]) -> bool {
+        let graph = self.graph;
+        let node = Node::Iri(iri.to_string());
+        if self.config.enums_from_one_of && !self.one_of(&node).is_empty() {
+            return true;
+        }
+        if !self.config.enums_from_individuals || self.individuals(iri).is_empty() {
+            return false;
+        }
+        let subclass_of = format!("{RDFS}subClassOf");
+        let domain = format!("{RDFS}domain");
+        let has_subclasses = graph.subjects().iter().any(|subject| {
+            graph
+                .objects(subject, &subclass_of)
+                .any(|object| object.iri() == Some(iri))
+        });
+        let has_properties = properties.iter().any(|property| {
+            graph
+                .objects(&Node::Iri(property.clone()), &domain)
+                .any(|object| object.iri() == Some(iri))
+        });
+        !has_subclasses && !has_properties
+    }
+
+    /// Members of an `owl:oneOf` enumeration of a class
+    fn one_of(&self, class: &Node) -> Vec<Node> {
+        let one_of = format!("{OWL}oneOf");
+        if let Some(list) = self.graph.object(class, &one_of) {
+            return self.graph.list(list);
+        }
+        self.graph
+            .objects(class, &format!("{OWL}equivalentClass"))
+            .find_map(|equivalent| self.graph.object(equivalent, &one_of))
+            .map(|list| self.graph.list(list))
+            .unwrap_or_default()
+    }
+
+    /// Named individuals typed with a class
+    fn individuals(&self, class: &str) -> Vec<String> {
+        let rdf_type = format!("{RDF}type");
+        self.graph
+            .subjects()
+            .iter()
+            .filter(|subject| {
+                self.graph
+                    .objects(subject, &rdf_type)
+                    .any(|object| object.iri() == Some(class))
+            })
+            .filter_map(|subject| subject.iri().map(String::from))
+            .collect()
+    }
+
+    fn enum_definition(&mut self, iri: &str) -> EnumDefinition {
+        let node = Node::Iri(iri.to_string());
+        let mut members: Vec<String> = self
+            .one_of(&node)
+            .iter()
+            .filter_map(|member| member.iri().map(String::from))
+            .collect();
+        if members.is_empty() {
+            members = self.individuals(iri);
+        }
+        let permissible_values = members
+            .iter()
+            .map(|member| {
+                let member_node = Node::Iri(member.clone());
+                let text = self
+                    .graph
+                    .literal(&member_node, &[&format!("{RDFS}label")])
+                    .filter(|_| self.config.names_from_labels)
+                    .unwrap_or_else(|| local_name(member).to_string());
+                PermissibleValue::Complex {
+                    text,
+                    description: self.description(&member_node),
+                    meaning: Some(self.curie(member)),
+                    is_a: None,
+                }
+            })
+            .collect();
+        EnumDefinition {
+            name: self.names[iri].clone(),
+            description: self.description(&node),
+            permissible_values,
+            ..Default::default()
+        }
+    }
+
+    fn slot_definition(&mut self, iri: &str) -> SlotDefinition {
+        let graph = self.graph;
+        let node = Node::Iri(iri.to_string());
+        let object_property = graph.is_a(&node, &[&format!("{OWL}ObjectProperty")]);
+        let functional = graph.is_a(&node, &[&format!("{OWL}FunctionalProperty")]);
+
+        let range = graph
+            .object(&node, &format!("{RDFS}range"))
+            .and_then(Node::iri)
+            .map(|range| self.range_name(range, object_property));
+        let domains = self.domains(&node);
+        let mut slot = SlotDefinition {
+            name: self.names[iri].clone(),
+            description: self.description(&node),
+            range,
+            multivalued: if functional {
+                Some(false)
+            } else {
+                self.config.multivalued_by_default.then_some(true)
+            },
+            slot_uri: Some(self.curie(iri)),
+            domain: match domains.as_slice() {
+                [domain] => Some(domain.clone()),
+                _ => None,
+            },
+            is_a: graph
+                .object(&node, &format!("{RDFS}subPropertyOf"))
+                .and_then(Node::iri)
+                .and_then(|parent| self.names.get(parent).cloned()),
+            inverse: graph
+                .object(&node, &format!("{OWL}inverseOf"))
+                .and_then(Node::iri)
+                .and_then(|inverse| self.names.get(inverse).cloned()),
+            ..Default::default()
+        };
+        self.annotate_slot(&mut slot, &node);
+        slot
+    }
+
+    fn annotate_slot(&mut self, slot: &mut SlotDefinition, node: &Node) {
+        slot.aliases = self.aliases(node, &slot.name);
+        if self.deprecated(node) {
+            slot.deprecated = Some("Deprecated in the source ontology".to_string());
+        }
+    }
+
+    fn class_definition(
+        &mut self,
+        iri: &str,
+        slots: &IndexMap<String, SlotDefinition>,
+    ) -> ClassDefinition {
+        let graph = self.graph;
+        let node = Node::Iri(iri.to_string());
+        let mut class = ClassDefinition {
+            name: self.names[iri].clone(),
+            description: self.description(&node),
+            class_uri: Some(self.curie(iri)),
+            ..Default::default()
+        };
+        class.aliases = self.aliases(&node, &class.name);
+        if self.deprecated(&node) {
+            class.deprecated = Some("Deprecated in the source ontology".to_string());
+        }
+
+        let subclass_of = format!("{RDFS}subClassOf");
+        for superclass in graph.objects(&node, &subclass_of) {
+            match superclass {
+                Node::Iri(parent) if parent == &format!("{OWL}Thing") => {}
+                Node::Iri(parent) => match self.names.get(parent) {
+                    Some(name) if class.is_a.is_none() && self.classes.contains(parent) => {
+                        class.is_a = Some(name.clone());
+                    }
+                    _ => class.subclass_of.push(self.curie(parent)),
+                },
+                Node::Blank(_) => {
+                    if let Some((property, restriction)) = self.restriction(superclass) {
+                        self.apply_restriction(&mut class, &property, restriction, slots);
+                    }
+                }
+                Node::Literal { .. } => {}
+            }
+        }
+
+        // Slots whose domain is the class
+        for (slot_name, slot) in slots {
+            if slot.domain.as_deref() == Some(class.name.as_str())
+                && !class.slots.contains(slot_name)
+            {
+                class.slots.push(slot_name.clone());
+            }
+        }
+        class
+    }
+
+    /// Property and constraints of an `owl:Restriction`
+    fn restriction(&self, node: &Node) -> Option<(String, Restriction)> {
+        let graph = self.graph;
+        let property = graph
+            .object(node, &format!("{OWL}onProperty"))
+            .and_then(Node::iri)
+            .and_then(|property| self.names.get(property))?
+            .clone();
+        let count = |predicates: &[&str]| {
+            predicates.iter().find_map(|predicate| {
+                graph
+                    .object(node, &format!("{OWL}{predicate}"))
+                    .and_then(Node::literal)
+                    .and_then(|value| value.parse::<u64>().ok())
+            })
+        };
+        let exact = count(&["cardinality", "qualifiedCardinality"]);
+        let min = count(&["minCardinality", "minQualifiedCardinality"]).or(exact);
+        let max = count(&["maxCardinality", "maxQualifiedCardinality"]).or(exact);
+
+        let some = graph.object(node, &format!("{OWL}someValuesFrom"));
+        let range = some
+            .or_else(|| graph.object(node, &format!("{OWL}allValuesFrom")))
+            .or_else(|| graph.object(node, &format!("{OWL}onClass")))
+            .or_else(|| graph.object(node, &format!("{OWL}onDataRange")))
+            .and_then(Node::iri)
+            .and_then(|range| {
+                self.names
+                    .get(range)
+                    .cloned()
+                    .or_else(|| range.strip_prefix(XSD).map(xsd_range).map(String::from))
+            });
+        Some((
+            property,
+            Restriction {
+                range,
+                required: some.is_some() || min.is_some_and(|min| min > 0),
+                multivalued: max.map(|max| max > 1),
+            },
+        ))
+    }
+
+    /// Refine a slot of a class with a restriction
+    fn apply_restriction(
+        &self,
+        class: &mut ClassDefinition,
+        property: &str,
+        restriction: Restriction,
+        slots: &IndexMap<String, SlotDefinition>,
+    ) {
+        if !class.slots.iter().any(|slot| slot == property) {
+            class.slots.push(property.to_string());
+        }
+        let slot = slots.get(property);
+        let usage = class
+            .slot_usage
+            .entry(property.to_string())
+            .or_insert_with(|| SlotDefinition {
+                name: property.to_string(),
+                ..Default::default()
+            });
+        if let Some(range) = restriction.range
+            && slot.and_then(|slot| slot.range.as_ref()) != Some(&range)
+        {
+            usage.range = Some(range);
+        }
+        if restriction.required {
+            usage.required = Some(true);
+        }
+        if let Some(multivalued) = restriction.multivalued
+            && slot.and_then(|slot| slot.multivalued) != Some(multivalued)
+        {
+            usage.multivalued = Some(multivalued);
+        }
+        if *usage
+            == (SlotDefinition {
+                name: property.to_string(),
+                ..Default::default()
+            })
+        {
+            class.slot_usage.shift_remove(property);
+        }
+    }
+
+    /// Classes of the `rdfs:domain` of a property, expanding unions
+    fn domains(&self, property: &Node) -> Vec<String> {
+        let graph = self.graph;
+        graph
+            .objects(property, &format!("{RDFS}domain"))
+            .flat_map(|domain| match domain {
+                Node::Blank(_) => graph
+                    .object(domain, &format!("{OWL}unionOf"))
+                    .map(|list| graph.list(list))
+                    .unwrap_or_default(),
+                _ => vec![domain.clone()],
+            })
+            .filter_map(|domain| domain.iri().and_then(|iri| self.names.get(iri)).cloned())
+            .collect()
+    }
+
+    /// LinkML range of an `rdfs:range`
+    fn range_name(&self, range: &str, object_property: bool) -> String {
+        if let Some(name) = self.names.get(range) {
+            return name.clone();
+        }
+        if let Some(local) = range.strip_prefix(XSD) {
+            return xsd_range(local).to_string();
+        }
+        if object_property {
+            "uriorcurie".to_string()
+        } else {
+            "string".to_string()
+        }
+    }
+
+    fn description(&self, node: &Node) -> Option<String> {
+        self.graph.literal(
+            node,
+            &[
+                &format!("{SKOS}definition"),
+                IAO_DEFINITION,
+                &format!("{RDFS}comment"),
+                &format!("{DCTERMS}description"),
+            ],
+        )
+    }
+
+    /// Labels of a term other than its name
+    fn aliases(&self, node: &Node, name: &str) -> Vec<String> {
+        let mut aliases: Vec<String> = Vec::new();
+        for predicate in [format!("{RDFS}label"), format!("{SKOS}altLabel")] {
+            for label in self
+                .graph
+                .objects(node, &predicate)
+                .filter_map(Node::literal)
+            {
+                if label != name && !aliases.iter().any(|alias| alias == label) {
+                    aliases.push(label.to_string());
+                }
+            }
+        }
+        aliases
+    }
+
+    fn deprecated(&self, node: &Node) -> bool {
+        self.graph
+            .literal(node, &[&format!("{OWL}deprecated")])
+            .is_some_and(|flag| flag == "true")
+    }
+
+    /// Unique element name of a term
+    fn element_name(&self, iri: &str, case: Case, taken: &mut HashSet<String>) -> String {
+        let label = self
+            .graph
+            .literal(&Node::Iri(iri.to_string()), &[&format!("{RDFS}label")])
+            .filter(|_| self.config.names_from_labels);
+        let base = match label {
+            Some(label) => label.to_case(case),
+            None => local_name(iri).to_string(),
+        };
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while !taken.insert(name.clone()) {
+            name = format!("{base}_{suffix}");
+            suffix += 1;
+        }
+        name
+    }
+
+    /// CURIE of a term, recording the prefix it uses
+    fn curie(&mut self, iri: &str) -> String {
+        if let Some(local) = iri.strip_prefix(&self.namespace)
+            && is_local_name(local)
+        {
+            return format!("{}:{local}", self.prefix);
+        }
+        if let Some(curie) = obo_curie(iri) {
+            if let Some((prefix, _)) = curie.split_once(':') {
+                self.used_prefixes
+                    .insert(prefix.to_string(), format!("{OBO_PURL}{prefix}_"));
+            }
+            return curie;
+        }
+        for (prefix, namespace) in KNOWN_PREFIXES {
+            if let Some(local) = iri.strip_prefix(namespace)
+                && is_local_name(local)
+            {
+                self.used_prefixes
+                    .insert(prefix.to_string(), namespace.to_string());
+                return format!("{prefix}:{local}");
+            }
+        }
+        iri.to_string()
+    }
+}
+
+/// IRI of the `owl:Ontology` of a graph
+fn ontology_iri(graph: &Graph) -> Option<&str> {
+    let ontology = format!("{OWL}Ontology");
+    graph
+        .subjects()
+        .iter()
+        .find(|subject| graph.is_a(subject, &[ontology.as_str()]))
+        .and_then(Node::iri)
+}
+
+/// Namespace shared by most of the given IRIs
+fn common_namespace<'a>(iris: impl Iterator<Item = &'a String>) -> Option<String> {
+    let mut counts: IndexMap<&str, usize> = IndexMap::new();
+    for iri in iris {
+        let namespace = &iri[..iri.len() - local_name(iri).len()];
+        if !namespace.is_empty() {
+            *counts.entry(namespace).or_default() += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .max_by(|(_, a), (_, b)| a.cmp(b))
+        .map(|(namespace, _)| namespace.to_string())
+}
+
+/// Part of an IRI after its last `#` or `/`
+fn local_name(iri: &str) -> &str {
+    iri.rsplit(['#', '/'])
+        .next()
+        .filter(|local| !local.is_empty())
+        .unwrap_or(iri)
+}
+
+/// Whether a string can be the local part of a CURIE
+fn is_local_name(local: &str) -> bool {
+    !local.is_empty() && !local.contains(['/', '#', '?'])
+}
+
+/// LinkML type of a local name in the XSD namespace
+fn xsd_range(local: &str) -> &'static str {
+    match local {
+        "integer" | "int" | "long" | "short" | "byte" | "nonNegativeInteger"
+        | "positiveInteger" | "negativeInteger" | "nonPositiveInteger" | "unsignedInt"
+        | "unsignedLong" | "unsignedShort" | "unsignedByte" => "integer",
+        "decimal" => "decimal",
+        "float" => "float",
+        "double" => "double",
+        "boolean" => "boolean",
+        "date" => "date",
+        "dateTime" | "dateTimeStamp" => "datetime",
+        "time" => "time",
+        "anyURI" => "uri",
+        _ => "string",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONTOLOGY: &str = r#"
+@prefix : <http://example.org/zoo#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+<http://example.org/zoo> a owl:Ontology ;
+    rdfs:label "Zoo ontology" ;
+    owl:versionInfo "1.0" .
+
+:Animal a owl:Class ;
+    rdfs:comment "A living animal" ;
+    rdfs:subClassOf [
+        a owl:Restriction ;
+        owl:onProperty :name ;
+        owl:cardinality "1"^^xsd:nonNegativeInteger
+    ] .
+
+:Mammal a owl:Class ;
+    rdfs:label "mammal" ;
+    skos:altLabel "mammalian" ;
+    rdfs:subClassOf :Animal , [
+        a owl:Restriction ;
+        owl:onProperty :keeper ;
+        owl:someValuesFrom :Keeper
+    ] .
+
+:Keeper a owl:Class .
+
+:Diet a owl:Class ;
+    owl:oneOf ( :Herbivore :Carnivore ) .
+
+:Status a owl:Class .
+:Healthy a owl:NamedIndividual , :Status ; rdfs:label "healthy" .
+:Sick a owl:NamedIndividual , :Status .
+
+:name a owl:DatatypeProperty , owl:FunctionalProperty ;
+    rdfs:domain :Animal ;
+    rdfs:range xsd:string .
+
+:age a owl:DatatypeProperty ;
+    rdfs:domain :Animal ;
+    rdfs:range xsd:nonNegativeInteger .
+
+:diet a owl:ObjectProperty ;
+    rdfs:domain :Animal ;
+    rdfs:range :Diet .
+
+:keeper a owl:ObjectProperty ;
+    rdfs:range :Keeper ;
+    owl:inverseOf :keeps .
+
+:keeps a owl:ObjectProperty ;
+    rdfs:domain :Keeper .
+"#;
+
+    fn introspect(config: OwlIntrospectionConfig) -> SchemaDefinition {
+        OwlIntrospector::with_config(config)
+            .introspect_str(ONTOLOGY, RdfFormat::Turtle)
+            .expect("ontology should parse")
+    }
+
+    #[test]
+    fn test_schema_from_ontology() {
+        let schema = introspect(OwlIntrospectionConfig::default());
+        assert_eq!(schema.id, "http://example.org/zoo");
+        assert_eq!(schema.name, "zoo");
+        assert_eq!(schema.title.as_deref(), Some("Zoo ontology"));
+        assert_eq!(schema.version.as_deref(), Some("1.0"));
+        assert_eq!(
+            schema.prefixes.get("zoo"),
+            Some(&PrefixDefinition::Simple(
+                "http://example.org/zoo#".to_string()
+            ))
+        );
+
+        let animal = &schema.classes["Animal"];
+        assert_eq!(animal.description.as_deref(), Some("A living animal"));
+        assert_eq!(animal.class_uri.as_deref(), Some("zoo:Animal"));
+        assert_eq!(animal.slots, ["name", "age", "diet"]);
+        assert_eq!(animal.slot_usage["name"].required, Some(true));
+
+        let mammal = &schema.classes["Mammal"];
+        assert_eq!(mammal.is_a.as_deref(), Some("Animal"));
+        assert_eq!(mammal.aliases, ["mammal", "mammalian"]);
+        assert_eq!(mammal.slots, ["keeper"]);
+        assert_eq!(mammal.slot_usage["keeper"].required, Some(true));
+
+        let name = &schema.slots["name"];
+        assert_eq!(name.range.as_deref(), Some("string"));
+        assert_eq!(name.multivalued, Some(false));
+        assert_eq!(name.domain.as_deref(), Some("Animal"));
+        assert_eq!(schema.slots["age"].range.as_deref(), Some("integer"));
+        assert_eq!(schema.slots["age"].multivalued, Some(true));
+        assert_eq!(schema.slots["diet"].range.as_deref(), Some("Diet"));
+        assert_eq!(schema.slots["keeper"].inverse.as_deref(), Some("keeps"));
+        assert_eq!(schema.classes["Keeper"].slots, ["keeps"]);
+    }
+
+    #[test]
+    fn test_enums_from_one_of_and_individuals() {
+        let schema = introspect(OwlIntrospectionConfig::default());
+        assert!(!schema.classes.contains_key("Diet"));
+        let diet: Vec<&str> = schema.enums["Diet"]
+            .permissible_values
+            .iter()
+            .map(PermissibleValue::text)
+            .collect();
+        assert_eq!(diet, ["Herbivore", "Carnivore"]);
+        assert!(matches!(
+            &schema.enums["Status"].permissible_values[0],
+            PermissibleValue::Complex { text, meaning, .. }
+                if text == "Healthy" && meaning.as_deref() == Some("zoo:Healthy")
+        ));
+
+        let schema = introspect(OwlIntrospectionConfig {
+            enums_from_individuals: false,
+            ..Default::default()
+        });
+        assert!(schema.classes.contains_key("Status"));
+        assert!(schema.enums.contains_key("Diet"));
+    }
+
+    #[test]
+    fn test_names_from_labels() {
+        let schema = introspect(OwlIntrospectionConfig {
+            names_from_labels: true,
+            multivalued_by_default: false,
+            ..Default::default()
+        });
+        assert!(schema.classes.contains_key("Mammal"));
+        assert_eq!(schema.classes["Mammal"].aliases, ["mammal", "mammalian"]);
+        assert_eq!(schema.slots["age"].multivalued, None);
+        assert!(matches!(
+            &schema.enums["Status"].permissible_values[0],
+            PermissibleValue::Complex { text, .. } if text == "healthy"
+        ));
+    }
+}
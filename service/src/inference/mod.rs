@@ -9,7 +9,7 @@
 //! The inference system consists of several components:
 //!
 //! - **Inference Engine** (`engine.rs`) - Orchestrates format detection and schema generation
-//! - **Schema Builder** (`builder.rs`) - Fluent API for programmatic schema construction
+//! - **Schema Builder** (`crate::schema::builder`) - Fluent API for programmatic schema construction
 //! - **Type Inference** (`type_inference.rs`) - Detect data types from sample values
 //! - **Format Introspectors** (`introspectors/`) - Format-specific structure analysis
 //! - **Factory Functions** (`factory.rs`) - Service creation with dependency injection
@@ -39,7 +39,6 @@
 //! # }
 //! ```
 
-pub mod builder;
 pub mod engine;
 pub mod factory;
 pub mod introspectors;
@@ -58,14 +57,16 @@ pub mod traits;
 pub mod type_inference;
 pub mod types;
 
-pub use builder::{BuilderResult, ClassBuilder, SchemaBuilder, SlotBuilder};
+pub use crate::schema::builder;
+pub use builder::{BuilderResult, ClassBuilder, EnumBuilder, SchemaBuilder, SlotBuilder};
 pub use engine::InferenceEngine;
+#[cfg(feature = "rootreal-services")]
+pub use factory::create_inference_engine;
 #[allow(deprecated)]
-pub use factory::{
-    create_csv_introspector, create_inference_engine, create_json_introspector,
-    create_xml_introspector,
+pub use factory::{create_csv_introspector, create_json_introspector, create_xml_introspector};
+pub use introspectors::{
+    CsvIntrospector, JsonIntrospector, OwlIntrospectionConfig, OwlIntrospector, XmlIntrospector,
 };
-pub use introspectors::{CsvIntrospector, JsonIntrospector, XmlIntrospector};
 pub use traits::{DataIntrospector, InferenceError, InferenceResult, InferredType, TypeInferencer};
 pub use type_inference::{StandardTypeInferencer, create_type_inferencer};
 pub use types::{
@@ -4,8 +4,11 @@
 //! - Multiple inheritance with C3 linearization
 //! - Mixin support with proper resolution order
 //! - Diamond inheritance pattern handling
-//! - Slot override and usage merging
+//! - Slot override and usage merging with `LinkML` precedence
+//!   (`slot_usage` over attributes over inherited slot definitions)
 
 pub mod resolver;
 
-pub use resolver::{InheritanceResolver, get_inheritance_chain, is_subclass_of};
+pub use resolver::{
+    InheritanceResolver, MroDiagnostic, MroProblem, get_inheritance_chain, is_subclass_of,
+};
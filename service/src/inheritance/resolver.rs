@@ -2,18 +2,65 @@
 //!
 //! This module handles full multiple inheritance including mixins,
 //! slot overrides, and diamond inheritance patterns.
+//!
+//! Inherited definitions follow the precedence of `LinkML`: for each
+//! metaslot, `slot_usage` of the class wins over `slot_usage` of its
+//! ancestors, which wins over attributes, which win over the global slot
+//! and finally its `is_a` and mixin parent slots. Ancestors are ranked in the
+//! order `linkml-runtime`'s `SchemaView.class_ancestors` lists them, see
+//! [`InheritanceResolver::class_precedence`].
+//!
+//! The method resolution order of a class, [`InheritanceResolver::mro`], is
+//! its C3 linearization. Hierarchies without one, and slots that unrelated
+//! ancestors refine differently, are explained with suggested fixes by
+//! [`InheritanceResolver::mro_diagnostics`].
 
-use linkml_core::annotations::Annotations;
 use linkml_core::prelude::*;
+use linkml_core::utils::merge_slot_definitions;
+use serde_json::Value;
 use std::collections::{HashMap, HashSet, VecDeque};
 
+/// Kind of problem with the resolution order of a class
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MroProblem {
+    /// The class is its own ancestor
+    Cycle,
+    /// The parents order shared ancestors inconsistently, so no C3
+    /// linearization exists
+    Inconsistent,
+    /// Unrelated ancestors refine a slot differently and only the order of
+    /// the parents decides which refinement is inherited
+    Ambiguous,
+}
+
+/// Problem with the resolution order of a class
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MroDiagnostic {
+    /// Class whose resolution order is affected
+    pub class: String,
+    /// Kind of problem
+    pub problem: MroProblem,
+    /// Description of the problem
+    pub message: String,
+    /// Suggested fix
+    pub suggestion: Option<String>,
+}
+
+impl std::fmt::Display for MroDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, ". {suggestion}")?;
+        }
+        Ok(())
+    }
+}
+
 /// Inheritance resolver for `LinkML` schemas
 pub struct InheritanceResolver<'a> {
     schema: &'a SchemaDefinition,
     /// Cache of resolved classes
     resolved_cache: HashMap<String, ClassDefinition>,
-    /// Track visited classes to detect cycles
-    visited: HashSet<String>,
 }
 
 impl<'a> InheritanceResolver<'a> {
@@ -23,7 +70,6 @@ impl<'a> InheritanceResolver<'a> {
         Self {
             schema,
             resolved_cache: HashMap::new(),
-            visited: HashSet::new(),
         }
     }
 
@@ -38,13 +84,6 @@ impl<'a> InheritanceResolver<'a> {
             return Ok(resolved.clone());
         }
 
-        // Check for cycles
-        if self.visited.contains(class_name) {
-            return Err(LinkMLError::service(format!(
-                "Circular inheritance detected for class '{class_name}'"
-            )));
-        }
-
         // Get base class
         let base_class = self
             .schema
@@ -53,139 +92,255 @@ impl<'a> InheritanceResolver<'a> {
             .ok_or_else(|| LinkMLError::service(format!("Class '{class_name}' not found")))?
             .clone();
 
-        self.visited.insert(class_name.to_string());
+        // Reject cycles and hierarchies without a consistent linearization
+        self.linearize(class_name)?;
 
         // Start with the base class
         let mut resolved = base_class.clone();
 
-        // Collect all ancestors (is_a chain + mixins)
-        let ancestors = self.get_all_ancestors(&base_class)?;
-
-        // Apply inheritance in correct order (C3 linearization)
-        let mro = self.c3_linearization(class_name, &ancestors)?;
-
-        // Merge each ancestor into the resolved class
-        for ancestor_name in mro.iter().rev() {
-            if ancestor_name != class_name
-                && let Some(ancestor) = self.schema.classes.get(ancestor_name)
-            {
+        // Merge ancestors, nearest first, so nearer definitions take precedence
+        for ancestor_name in self.class_precedence(class_name).iter().skip(1) {
+            if let Some(ancestor) = self.schema.classes.get(ancestor_name) {
                 Self::merge_class(&mut resolved, ancestor);
             }
         }
 
-        // Apply own attributes last (they override inherited)
+        // Resolve the slot parents of own slot_usage
         self.apply_own_attributes(&mut resolved, &base_class);
 
-        self.visited.remove(class_name);
         self.resolved_cache
             .insert(class_name.to_string(), resolved.clone());
 
         Ok(resolved)
     }
 
-    /// Get all ancestors of a class (`is_a` + mixins, recursively)
-    fn get_all_ancestors(&self, class: &ClassDefinition) -> Result<Vec<Vec<String>>> {
-        let mut ancestors = Vec::new();
-
-        // Add is_a parent
-        if let Some(parent) = &class.is_a {
-            let parent_ancestors = if let Some(parent_class) = self.schema.classes.get(parent) {
-                let mut chain = self.get_all_ancestors(parent_class)?;
-                chain.insert(0, vec![parent.clone()]);
-                chain
-            } else {
-                vec![vec![parent.clone()]]
+    /// Ancestors of a class, starting with the class itself, in order of
+    /// precedence
+    ///
+    /// This is the order of `linkml-runtime`'s `SchemaView.class_ancestors`:
+    /// parents are listed as they are discovered, `is_a` before mixins, while
+    /// the hierarchy is walked depth first from the last discovered parent.
+    /// Of two ancestors setting the same metaslot, the one listed first wins.
+    #[must_use]
+    pub fn class_precedence(&self, class_name: &str) -> Vec<String> {
+        let mut order = vec![class_name.to_string()];
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut todo = vec![class_name.to_string()];
+        while let Some(current) = todo.pop() {
+            visited.insert(current.clone());
+            let Some(class) = self.schema.classes.get(&current) else {
+                continue;
             };
-            ancestors.push(parent_ancestors.into_iter().flatten().collect());
+            for parent in class.is_a.iter().chain(&class.mixins) {
+                if !visited.contains(parent) && !order.contains(parent) {
+                    todo.push(parent.clone());
+                    order.push(parent.clone());
+                }
+            }
         }
+        order
+    }
 
-        // Add mixins
-        for mixin in &class.mixins {
-            if let Some(mixin_class) = self.schema.classes.get(mixin) {
-                let mixin_ancestors = self.get_all_ancestors(mixin_class)?;
-                let mut chain = vec![mixin.clone()];
-                chain.extend(mixin_ancestors.into_iter().flatten());
-                ancestors.push(chain);
-            } else {
-                ancestors.push(vec![mixin.clone()]);
-            }
+    /// Method resolution order of a class: its C3 linearization, starting
+    /// with the class
+    ///
+    /// The linearization lists every class before its own ancestors and
+    /// keeps the order in which each class lists its parents (`is_a` first,
+    /// then mixins).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class is not defined, is its own ancestor, or
+    /// its parents order shared ancestors inconsistently; the message
+    /// includes a suggested fix, see [`Self::mro_diagnostics`].
+    pub fn mro(&self, class_name: &str) -> Result<Vec<String>> {
+        if !self.schema.classes.contains_key(class_name) {
+            return Err(LinkMLError::service(format!(
+                "Class '{class_name}' not found"
+            )));
+        }
+        self.linearize(class_name)
+    }
+
+    /// Problems with the resolution order of a class
+    ///
+    /// Reports a cycle or missing C3 linearization in the hierarchy of the
+    /// class, or else the slots that unrelated ancestors refine differently,
+    /// where only the order of the parents of the class decides which
+    /// refinement is inherited. Conflicts already present in one parent are
+    /// reported for that parent only.
+    #[must_use]
+    pub fn mro_diagnostics(&self, class_name: &str) -> Vec<MroDiagnostic> {
+        match self.linearize_from(class_name, &mut Vec::new()) {
+            Ok(mro) => self.ambiguities(class_name, &mro),
+            Err(diagnostic) => vec![diagnostic],
         }
+    }
 
-        Ok(ancestors)
+    fn linearize(&self, class_name: &str) -> Result<Vec<String>> {
+        self.linearize_from(class_name, &mut Vec::new())
+            .map_err(|diagnostic| LinkMLError::service(diagnostic.to_string()))
     }
 
-    /// C3 linearization for method resolution order
-    fn c3_linearization(&self, class_name: &str, ancestors: &[Vec<String>]) -> Result<Vec<String>> {
-        // Start with the class itself
-        let mut result = vec![class_name.to_string()];
+    fn linearize_from(
+        &self,
+        class_name: &str,
+        stack: &mut Vec<String>,
+    ) -> std::result::Result<Vec<String>, MroDiagnostic> {
+        if let Some(start) = stack.iter().position(|name| name == class_name) {
+            let mut cycle = stack[start..].to_vec();
+            cycle.push(class_name.to_string());
+            let last = &stack[stack.len() - 1];
+            return Err(MroDiagnostic {
+                class: class_name.to_string(),
+                problem: MroProblem::Cycle,
+                message: format!(
+                    "Circular inheritance detected for class '{class_name}': {}",
+                    cycle.join(" -> ")
+                ),
+                suggestion: Some(format!(
+                    "Remove '{class_name}' from the parents of '{last}'"
+                )),
+            });
+        }
+        // Undefined parents are leaves
+        let Some(class) = self.schema.classes.get(class_name) else {
+            return Ok(vec![class_name.to_string()]);
+        };
 
-        // Create working lists
-        let mut lists: Vec<VecDeque<String>> = ancestors
+        stack.push(class_name.to_string());
+        let parents: Vec<String> = class.is_a.iter().chain(&class.mixins).cloned().collect();
+        let mut linearizations = Vec::with_capacity(parents.len());
+        for parent in &parents {
+            linearizations.push(self.linearize_from(parent, stack)?);
+        }
+        stack.pop();
+
+        let mut lists: Vec<VecDeque<String>> = linearizations
             .iter()
-            .map(|a| a.iter().cloned().collect())
+            .map(|linearization| linearization.iter().cloned().collect())
             .collect();
-
-        // Add the list of direct parents
-        let mut direct_parents = VecDeque::new();
-        if let Some(class) = self.schema.classes.get(class_name) {
-            if let Some(parent) = &class.is_a {
-                direct_parents.push_back(parent.clone());
+        lists.push(parents.iter().cloned().collect());
+        match c3_merge(lists) {
+            Some(merged) => {
+                let mut result = vec![class_name.to_string()];
+                result.extend(merged);
+                Ok(result)
             }
-            for mixin in &class.mixins {
-                direct_parents.push_back(mixin.clone());
-            }
-        }
-        if !direct_parents.is_empty() {
-            lists.push(direct_parents);
+            None => Err(inconsistency(class_name, &parents, &linearizations)),
         }
+    }
 
-        // C3 merge
-        while lists.iter().any(|l| !l.is_empty()) {
-            // Find a class that appears at the head of a list
-            // and doesn't appear in the tail of any other list
-            let mut candidate = None;
-
-            for list in &lists {
-                if let Some(head) = list.front() {
-                    // Check if this head appears in any tail
-                    let mut in_tail = false;
-                    for other_list in &lists {
-                        let tail: Vec<_> = other_list.iter().skip(1).cloned().collect();
-                        if tail.contains(head) {
-                            in_tail = true;
-                            break;
-                        }
-                    }
-
-                    if !in_tail {
-                        candidate = Some(head.clone());
-                        break;
+    /// Slots refined differently by unrelated ancestors of a class
+    fn ambiguities(&self, class_name: &str, mro: &[String]) -> Vec<MroDiagnostic> {
+        let Some(class) = self.schema.classes.get(class_name) else {
+            return Vec::new();
+        };
+        // Ancestors of each ancestor, including itself
+        let ancestry: HashMap<&String, HashSet<String>> = mro
+            .iter()
+            .skip(1)
+            .map(|name| {
+                let ancestors = self
+                    .linearize_from(name, &mut Vec::new())
+                    .unwrap_or_default();
+                (name, ancestors.into_iter().collect())
+            })
+            .collect();
+        let parents: Vec<&String> = class.is_a.iter().chain(&class.mixins).collect();
+        let precedence = self.class_precedence(class_name);
+
+        let mut slot_names: Vec<&String> = Vec::new();
+        for name in mro.iter().skip(1) {
+            if let Some(ancestor) = self.schema.classes.get(name) {
+                for slot_name in ancestor.slot_usage.keys().chain(ancestor.attributes.keys()) {
+                    if !slot_names.contains(&slot_name) {
+                        slot_names.push(slot_name);
                     }
                 }
             }
+        }
 
-            if let Some(selected) = candidate {
-                // Add to result if not already there
-                if !result.contains(&selected) {
-                    result.push(selected.clone());
+        let mut diagnostics = Vec::new();
+        for slot_name in slot_names {
+            let own = refinement(class, slot_name);
+            let refinements: Vec<Refinement<'_>> = mro
+                .iter()
+                .skip(1)
+                .filter_map(|name| {
+                    let ancestor = self.schema.classes.get(name)?;
+                    Some((name, refinement(ancestor, slot_name)))
+                })
+                .filter(|(_, refined)| !refined.is_empty())
+                .collect();
+
+            let mut metaslots: Vec<&String> = Vec::new();
+            let mut conflicting: Vec<&String> = Vec::new();
+            let keys: Vec<&String> = refinements
+                .iter()
+                .flat_map(|(_, refined)| refined.keys())
+                .collect();
+            for metaslot in keys {
+                if metaslots.contains(&metaslot) || own.contains_key(metaslot.as_str()) {
+                    continue;
                 }
-
-                // Remove from all lists
-                for list in &mut lists {
-                    list.retain(|x| x != &selected);
+                let frontier = nearest_refinements(&refinements, metaslot, &ancestry);
+                let first = &frontier[0].1[metaslot.as_str()];
+                if frontier
+                    .iter()
+                    .all(|(_, refined)| refined[metaslot.as_str()] == *first)
+                {
+                    continue;
+                }
+                // Conflicts within a parent are reported for that parent
+                let inherited = parents.iter().any(|parent| {
+                    ancestry.get(*parent).is_some_and(|ancestors| {
+                        frontier
+                            .iter()
+                            .all(|(name, _)| ancestors.contains(name.as_str()))
+                    })
+                });
+                if inherited {
+                    continue;
+                }
+                metaslots.push(metaslot);
+                for (name, _) in frontier {
+                    if !conflicting.contains(name) {
+                        conflicting.push(name);
+                    }
                 }
-            } else {
-                // No valid candidate found - inconsistent hierarchy
-                return Err(LinkMLError::service(
-                    "Inconsistent class hierarchy detected (C3 linearization failed)",
-                ));
             }
-        }
+            if metaslots.is_empty() {
+                continue;
+            }
 
-        Ok(result)
+            conflicting.sort_by_key(|name| precedence.iter().position(|other| other == *name));
+            let names: Vec<String> = conflicting.iter().map(|name| format!("'{name}'")).collect();
+            let metaslots: Vec<&str> = metaslots.iter().map(|name| name.as_str()).collect();
+            diagnostics.push(MroDiagnostic {
+                class: class_name.to_string(),
+                problem: MroProblem::Ambiguous,
+                message: format!(
+                    "Unrelated ancestors {} of class '{class_name}' refine slot '{slot_name}' \
+                     differently ({}); '{}' takes precedence only by the order of the parents \
+                     of '{class_name}'",
+                    names.join(", "),
+                    metaslots.join(", "),
+                    conflicting[0]
+                ),
+                suggestion: Some(format!(
+                    "Add a slot_usage for '{slot_name}' to '{class_name}' that sets {} explicitly",
+                    metaslots.join(", ")
+                )),
+            });
+        }
+        diagnostics
     }
 
     /// Merge an ancestor class into the resolved class
+    ///
+    /// Ancestors are merged nearest first, so definitions already present
+    /// take precedence over those of `source`.
     fn merge_class(target: &mut ClassDefinition, source: &ClassDefinition) {
         // Merge slots (don't duplicate)
         for slot in &source.slots {
@@ -194,12 +349,14 @@ impl<'a> InheritanceResolver<'a> {
             }
         }
 
-        // Merge slot_usage (ancestor definitions are defaults)
+        // Merge slot_usage metaslot by metaslot, ancestor values as defaults
+        // and bounds combined
         for (slot_name, slot_def) in &source.slot_usage {
-            target
-                .slot_usage
-                .entry(slot_name.clone())
-                .or_insert_with(|| slot_def.clone());
+            let merged = match target.slot_usage.get(slot_name) {
+                Some(existing) => apply_usage(slot_def, existing),
+                None => slot_def.clone(),
+            };
+            target.slot_usage.insert(slot_name.clone(), merged);
         }
 
         // Merge attributes
@@ -230,11 +387,15 @@ impl<'a> InheritanceResolver<'a> {
         // - is_a and mixins are not inherited
     }
 
-    /// Apply the class's own attributes (override inherited)
+    /// Fill the merged `slot_usage` of the class's own usages from the slots
+    /// they name as `is_a`
     fn apply_own_attributes(&self, target: &mut ClassDefinition, source: &ClassDefinition) {
-        // Own slot_usage overrides inherited, with slot inheritance resolution
         for (slot_name, slot_def) in &source.slot_usage {
-            let mut resolved_slot = slot_def.clone();
+            let mut resolved_slot = target
+                .slot_usage
+                .get(slot_name)
+                .cloned()
+                .unwrap_or_else(|| slot_def.clone());
 
             // If this slot_usage has is_a, resolve it
             if let Some(parent_slot_name) = &slot_def.is_a
@@ -296,101 +457,294 @@ impl<'a> InheritanceResolver<'a> {
     ) -> Result<HashMap<String, SlotDefinition>> {
         let resolved_class = self.resolve_class(class_name)?;
         let mut resolved_slots = HashMap::new();
+        for slot_name in resolved_class
+            .slots
+            .iter()
+            .chain(resolved_class.attributes.keys())
+        {
+            if !resolved_slots.contains_key(slot_name) {
+                let slot = self.resolve_slot_usage(class_name, slot_name)?;
+                resolved_slots.insert(slot_name.clone(), slot);
+            }
+        }
+        Ok(resolved_slots)
+    }
 
-        // Process each slot
-        for slot_name in &resolved_class.slots {
-            // Start with the base slot definition
-            let mut slot = self
-                .schema
-                .slots
-                .get(slot_name)
-                .ok_or_else(|| LinkMLError::service(format!("Slot '{slot_name}' not found")))?
-                .clone();
+    /// Resolve a slot in the context of a class
+    ///
+    /// Each metaslot takes the first value set by, in order:
+    ///
+    /// 1. the `slot_usage` of the class and then of its ancestors, in
+    ///    [`class_precedence`](Self::class_precedence) order; bounds are
+    ///    combined instead, so `minimum_value` takes the largest and
+    ///    `maximum_value` the smallest value
+    /// 2. the attribute of that name of the nearest class defining one
+    /// 3. the global slot definition
+    /// 4. the `is_a` and mixin parents of the slot, nearest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if neither the schema nor any class in the hierarchy
+    /// defines the slot.
+    pub fn resolve_slot_usage(&self, class_name: &str, slot_name: &str) -> Result<SlotDefinition> {
+        let precedence = self.class_precedence(class_name);
+        let classes: Vec<&ClassDefinition> = precedence
+            .iter()
+            .filter_map(|name| self.schema.classes.get(name))
+            .collect();
 
-            // Apply slot_usage overrides
-            if let Some(override_def) = resolved_class.slot_usage.get(slot_name) {
-                self.apply_slot_override(&mut slot, override_def);
+        let attribute = classes
+            .iter()
+            .find_map(|class| class.attributes.get(slot_name));
+        let global = self.schema.slots.get(slot_name);
+        let mut slot = match (attribute, global) {
+            (Some(attribute), Some(global)) => override_slot(global, attribute),
+            (Some(definition), None) | (None, Some(definition)) => definition.clone(),
+            (None, None) => {
+                return Err(LinkMLError::service(format!(
+                    "Slot '{slot_name}' not found for class '{class_name}'"
+                )));
             }
+        };
 
-            // Check attributes for inline definitions
-            if let Some(attr_def) = resolved_class.attributes.get(slot_name) {
-                self.apply_slot_override(&mut slot, attr_def);
+        // Farthest ancestor first, so nearer usages override it
+        for class in classes.iter().rev() {
+            if let Some(usage) = class.slot_usage.get(slot_name) {
+                slot = apply_usage(&slot, usage);
             }
+        }
 
-            resolved_slots.insert(slot_name.clone(), slot);
+        // Unset metaslots come from the parent slots
+        let mut visited = HashSet::new();
+        let mut parents: VecDeque<String> = slot.is_a.iter().chain(&slot.mixins).cloned().collect();
+        while let Some(parent_name) = parents.pop_front() {
+            if !visited.insert(parent_name.clone()) {
+                continue;
+            }
+            if let Some(parent) = self.schema.slots.get(&parent_name) {
+                slot = override_slot(parent, &slot);
+                parents.extend(parent.is_a.iter().chain(&parent.mixins).cloned());
+            }
         }
 
-        Ok(resolved_slots)
+        slot_name.clone_into(&mut slot.name);
+        Ok(slot)
     }
+}
 
-    /// Apply slot override/usage to a slot definition
-    fn apply_slot_override(&self, target: &mut SlotDefinition, override_def: &SlotDefinition) {
-        // Handle slot inheritance through is_a
-        if let Some(parent_slot_name) = &override_def.is_a
-            && let Some(parent_slot) = self.schema.slots.get(parent_slot_name)
-        {
-            // First apply parent slot properties as defaults
-            self.apply_slot_override(target, parent_slot);
+/// Definition of `base` with the metaslots set by `overrides` replaced,
+/// keeping the name, `is_a` and mixins of `overrides` when it sets them
+fn override_slot(base: &SlotDefinition, overrides: &SlotDefinition) -> SlotDefinition {
+    let mut merged = merge_slot_definitions(base, overrides);
+    if overrides.name.is_empty() {
+        merged.name.clone_from(&base.name);
+    }
+    if overrides.mixins.is_empty() {
+        merged.mixins.clone_from(&base.mixins);
+    }
+    merged
+}
+
+/// Definition of `slot` refined by a `slot_usage`, combining bounds
+fn apply_usage(slot: &SlotDefinition, usage: &SlotDefinition) -> SlotDefinition {
+    let mut refined = override_slot(slot, usage);
+    refined.minimum_value = combine_bound(
+        slot.minimum_value.as_ref(),
+        usage.minimum_value.as_ref(),
+        true,
+    );
+    refined.maximum_value = combine_bound(
+        slot.maximum_value.as_ref(),
+        usage.maximum_value.as_ref(),
+        false,
+    );
+    refined
+}
+
+/// Bound of a slot after applying a `slot_usage` bound: the tighter of the
+/// two if both are numbers, otherwise the usage's if set
+fn combine_bound(current: Option<&Value>, usage: Option<&Value>, lower: bool) -> Option<Value> {
+    match (current, usage) {
+        (Some(current), Some(usage)) => match (current.as_f64(), usage.as_f64()) {
+            (Some(a), Some(b)) if (lower && a > b) || (!lower && a < b) => Some(current.clone()),
+            _ => Some(usage.clone()),
+        },
+        (current, None) => current.cloned(),
+        (None, usage) => usage.cloned(),
+    }
+}
+
+/// C3 merge of linearizations; `None` if no class can be placed next
+/// without preceding one of its own ancestors
+fn c3_merge(mut lists: Vec<VecDeque<String>>) -> Option<Vec<String>> {
+    let mut result = Vec::new();
+    loop {
+        lists.retain(|list| !list.is_empty());
+        if lists.is_empty() {
+            return Some(result);
         }
+        // The first head that appears in no list's tail
+        let selected = lists
+            .iter()
+            .filter_map(VecDeque::front)
+            .find(|head| {
+                !lists
+                    .iter()
+                    .any(|list| list.iter().skip(1).any(|name| name == *head))
+            })?
+            .clone();
+        for list in &mut lists {
+            list.retain(|name| *name != selected);
+        }
+        result.push(selected);
+    }
+}
 
-        // Merge annotations (parent annotations as defaults, override annotations win)
-        if let Some(override_annotations) = &override_def.annotations {
-            if target.annotations.is_none() {
-                target.annotations = Some(Annotations::new());
-            }
-            if let Some(target_annotations) = &mut target.annotations {
-                // Add override annotations (they win over inherited)
-                for (key, value) in override_annotations {
-                    target_annotations.insert(key.clone(), value.clone());
+/// Ancestor refining a slot, with the metaslots it sets
+type Refinement<'a> = (&'a String, serde_json::Map<String, Value>);
+
+/// Refinements setting a metaslot that no refinement by a descendant
+/// overrides
+fn nearest_refinements<'r, 'a>(
+    refinements: &'r [Refinement<'a>],
+    metaslot: &str,
+    ancestry: &HashMap<&String, HashSet<String>>,
+) -> Vec<&'r Refinement<'a>> {
+    let setters: Vec<&Refinement<'a>> = refinements
+        .iter()
+        .filter(|(_, refined)| refined.contains_key(metaslot))
+        .collect();
+    setters
+        .iter()
+        .filter(|(name, _)| {
+            !setters
+                .iter()
+                .any(|(other, _)| other != name && ancestry[*other].contains(name.as_str()))
+        })
+        .copied()
+        .collect()
+}
+
+/// Metaslots a class sets for a slot through an attribute or `slot_usage`,
+/// without the ones combined rather than overridden during resolution
+fn refinement(class: &ClassDefinition, slot_name: &str) -> serde_json::Map<String, Value> {
+    let definition = match (
+        class.attributes.get(slot_name),
+        class.slot_usage.get(slot_name),
+    ) {
+        (Some(attribute), Some(usage)) => override_slot(attribute, usage),
+        (Some(definition), None) | (None, Some(definition)) => definition.clone(),
+        (None, None) => return serde_json::Map::new(),
+    };
+    let Ok(Value::Object(mut metaslots)) = serde_json::to_value(definition) else {
+        return serde_json::Map::new();
+    };
+    metaslots.retain(|name, value| {
+        !matches!(name.as_str(), "name" | "minimum_value" | "maximum_value")
+            && !value.is_null()
+            && !value.is_array()
+            && value.as_object().is_none_or(|object| !object.is_empty())
+    });
+    metaslots
+}
+
+/// Diagnostic of a class whose parents admit no C3 linearization
+///
+/// Looks for two classes that the linearizations of the parents, or the
+/// list of parents itself, order in opposite ways.
+fn inconsistency(
+    class_name: &str,
+    parents: &[String],
+    linearizations: &[Vec<String>],
+) -> MroDiagnostic {
+    let position = |list: &[String], name: &String| list.iter().position(|other| other == name);
+    let sources: Vec<(Option<&String>, &[String])> = parents
+        .iter()
+        .zip(linearizations)
+        .map(|(parent, linearization)| (Some(parent), linearization.as_slice()))
+        .chain(std::iter::once((None, parents)))
+        .collect();
+    let describe = |source: Option<&String>| match source {
+        Some(parent) => format!("the linearization of '{parent}'"),
+        None => format!("the parents of '{class_name}'"),
+    };
+
+    for (index, (first_source, first)) in sources.iter().enumerate() {
+        for (second_source, second) in &sources[index + 1..] {
+            for (i, earlier) in first.iter().enumerate() {
+                for later in &first[i + 1..] {
+                    let (Some(a), Some(b)) = (position(second, later), position(second, earlier))
+                    else {
+                        continue;
+                    };
+                    if a > b {
+                        continue;
+                    }
+                    // `earlier` precedes `later` in the first source, but
+                    // follows it in the second
+                    let suggestion = match (first_source, second_source) {
+                        (Some(_), None)
+                            if linearizations.iter().zip(parents).any(
+                                |(linearization, parent)| {
+                                    parent == earlier && linearization.contains(later)
+                                },
+                            ) =>
+                        {
+                            format!(
+                                "List '{earlier}' before '{later}' among the parents of \
+                                 '{class_name}', or remove '{later}', which '{class_name}' \
+                                 inherits through '{earlier}'"
+                            )
+                        }
+                        (Some(_), None) => format!(
+                            "List '{earlier}' before '{later}' among the parents of '{class_name}'"
+                        ),
+                        _ => format!(
+                            "Make {} and {} order '{earlier}' and '{later}' the same way",
+                            describe(*first_source),
+                            describe(*second_source)
+                        ),
+                    };
+                    return MroDiagnostic {
+                        class: class_name.to_string(),
+                        problem: MroProblem::Inconsistent,
+                        message: format!(
+                            "Inconsistent class hierarchy for '{class_name}' (C3 linearization \
+                             failed): {} puts '{earlier}' before '{later}', {} puts '{later}' \
+                             before '{earlier}'",
+                            describe(*first_source),
+                            describe(*second_source)
+                        ),
+                        suggestion: Some(suggestion),
+                    };
                 }
             }
         }
+    }
 
-        // Override non-None values
-        if override_def.description.is_some() {
-            target.description.clone_from(&override_def.description);
-        }
-        if override_def.required.is_some() {
-            target.required = override_def.required;
-        }
-        if override_def.multivalued.is_some() {
-            target.multivalued = override_def.multivalued;
-        }
-        if override_def.range.is_some() {
-            target.range.clone_from(&override_def.range);
-        }
-        if override_def.pattern.is_some() {
-            target.pattern.clone_from(&override_def.pattern);
-        }
-        if override_def.minimum_value.is_some() {
-            target.minimum_value.clone_from(&override_def.minimum_value);
-        }
-        if override_def.maximum_value.is_some() {
-            target.maximum_value.clone_from(&override_def.maximum_value);
-        }
-        if override_def.ifabsent.is_some() {
-            target.ifabsent.clone_from(&override_def.ifabsent);
-        }
+    MroDiagnostic {
+        class: class_name.to_string(),
+        problem: MroProblem::Inconsistent,
+        message: format!(
+            "Inconsistent class hierarchy for '{class_name}' (C3 linearization failed)"
+        ),
+        suggestion: Some(format!(
+            "Reorder the parents of '{class_name}' and its ancestors so that they list \
+             shared ancestors in the same order"
+        )),
     }
 }
 
 /// Get the complete inheritance chain for a class
 /// Returns an error if the operation fails
 ///
+/// The chain is the resolution order of the class, see
+/// [`InheritanceResolver::mro`].
+///
 /// # Errors
 ///
 pub fn get_inheritance_chain(class_name: &str, schema: &SchemaDefinition) -> Result<Vec<String>> {
-    let mut resolver = InheritanceResolver::new(schema);
-    let _resolved = resolver.resolve_class(class_name)?;
-
-    // The MRO gives us the complete inheritance chain
-    let class = schema
-        .classes
-        .get(class_name)
-        .ok_or_else(|| LinkMLError::service(format!("Class '{class_name}' not found")))?;
-
-    let ancestors = resolver.get_all_ancestors(class)?;
-    resolver.c3_linearization(class_name, &ancestors)
+    InheritanceResolver::new(schema).mro(class_name)
 }
 
 /// Check if a class is a subclass of another (considering mixins)
@@ -556,4 +910,188 @@ mod tests {
         assert_eq!(a_count, 1, "Diamond inheritance should not duplicate slots");
         Ok(())
     }
+
+    #[test]
+    fn test_slot_usage_precedence_in_diamond() -> std::result::Result<(), Box<dyn std::error::Error>>
+    {
+        //     A        A sets range and maximum_value 100
+        //    / \
+        //   B   C      B sets description, C sets description,
+        //    \ /       required and maximum_value 50
+        //     D
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/diamond
+name: diamond
+classes:
+  A:
+    slots: [size]
+    slot_usage:
+      size:
+        range: integer
+        maximum_value: 100
+  B:
+    is_a: A
+    slot_usage:
+      size:
+        description: size from B
+  C:
+    is_a: A
+    mixin: true
+    slot_usage:
+      size:
+        description: size from C
+        required: true
+        maximum_value: 50
+  D:
+    is_a: B
+    mixins: [C]
+slots:
+  size:
+    range: string
+    maximum_value: 1000
+",
+        )?;
+
+        let mut resolver = InheritanceResolver::new(&schema);
+        assert_eq!(resolver.class_precedence("D"), ["D", "B", "C", "A"]);
+
+        let size = resolver.resolve_slot_usage("D", "size")?;
+        assert_eq!(size.name, "size");
+        assert_eq!(size.description.as_deref(), Some("size from B"));
+        assert_eq!(size.required, Some(true));
+        assert_eq!(size.range.as_deref(), Some("integer"));
+        assert_eq!(size.maximum_value, Some(serde_json::json!(50)));
+
+        // The merged slot_usage of the class agrees
+        let usage = &resolver.resolve_class("D")?.slot_usage["size"];
+        assert_eq!(usage.description.as_deref(), Some("size from B"));
+        assert_eq!(usage.range.as_deref(), Some("integer"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_slot_usage_over_attributes_over_inherited()
+    -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/precedence
+name: precedence
+classes:
+  Thing:
+    attributes:
+      label:
+        description: label attribute
+        pattern: '^[a-z]+$'
+  Named:
+    is_a: Thing
+    slot_usage:
+      label:
+        pattern: '^[A-Z][a-z]+$'
+slots:
+  text:
+    range: string
+    required: true
+    description: any text
+  label:
+    is_a: text
+    description: global label
+    multivalued: false
+",
+        )?;
+
+        let resolver = InheritanceResolver::new(&schema);
+        let label = resolver.resolve_slot_usage("Named", "label")?;
+        assert_eq!(label.pattern.as_deref(), Some("^[A-Z][a-z]+$"));
+        assert_eq!(label.description.as_deref(), Some("label attribute"));
+        assert_eq!(label.multivalued, Some(false));
+        assert_eq!(label.required, Some(true));
+        assert_eq!(label.range.as_deref(), Some("string"));
+
+        assert!(resolver.resolve_slot_usage("Named", "missing").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mro_diagnostics() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/mro
+name: mro
+classes:
+  Base: {}
+  Named:
+    is_a: Base
+  Misordered:
+    mixins: [Base, Named]
+  Loop1:
+    is_a: Loop2
+  Loop2:
+    is_a: Loop1
+  Left:
+    mixin: true
+    slot_usage:
+      id:
+        required: true
+        description: from Left
+  Right:
+    mixin: true
+    slot_usage:
+      id:
+        required: false
+  Both:
+    mixins: [Left, Right]
+  Settled:
+    mixins: [Left, Right]
+    slot_usage:
+      id:
+        required: true
+  Child:
+    is_a: Both
+slots:
+  id: {}
+",
+        )?;
+        let resolver = InheritanceResolver::new(&schema);
+
+        assert_eq!(resolver.mro("Named")?, vec!["Named", "Base"]);
+        assert_eq!(
+            resolver.mro("Child")?,
+            vec!["Child", "Both", "Left", "Right"]
+        );
+        assert!(resolver.mro_diagnostics("Named").is_empty());
+
+        let diagnostics = resolver.mro_diagnostics("Misordered");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].problem, MroProblem::Inconsistent);
+        assert_eq!(
+            diagnostics[0].suggestion.as_deref(),
+            Some(
+                "List 'Named' before 'Base' among the parents of 'Misordered', or remove \
+                 'Base', which 'Misordered' inherits through 'Named'"
+            )
+        );
+        let error = resolver.mro("Misordered").unwrap_err().to_string();
+        assert!(error.contains("C3 linearization failed"));
+        assert!(error.contains("List 'Named' before 'Base'"));
+
+        let diagnostics = resolver.mro_diagnostics("Loop1");
+        assert_eq!(diagnostics[0].problem, MroProblem::Cycle);
+        assert!(diagnostics[0].message.contains("Loop1 -> Loop2 -> Loop1"));
+        assert_eq!(
+            diagnostics[0].suggestion.as_deref(),
+            Some("Remove 'Loop1' from the parents of 'Loop2'")
+        );
+
+        // Left and Right disagree on `required`; only Both resolves it by
+        // the order of its mixins
+        let diagnostics = resolver.mro_diagnostics("Both");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].problem, MroProblem::Ambiguous);
+        assert!(diagnostics[0].message.contains("'Left', 'Right'"));
+        assert!(diagnostics[0].message.contains("(required)"));
+        assert!(resolver.mro_diagnostics("Settled").is_empty());
+        assert!(resolver.mro_diagnostics("Child").is_empty());
+        Ok(())
+    }
 }
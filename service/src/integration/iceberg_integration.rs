@@ -6,6 +6,10 @@
 //! - Partitioning and clustering strategies
 //! - ACID transactions and snapshot isolation
 //! - Format migration between `DuckLake` and Iceberg
+//!
+//! With the `lakehouse` feature, [`IcebergIntegration::table`] opens tables
+//! under the configured base path for writing validated instances (see
+//! [`crate::integration::lakehouse`]).
 
 use linkml_core::error::LinkMLError;
 use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
@@ -105,6 +109,19 @@ impl IcebergIntegration {
         }
     }
 
+    /// Open the Iceberg table `<base_path>/<database_name>/<table_name>`
+    #[cfg(feature = "lakehouse")]
+    #[must_use]
+    pub fn table(&self, table_name: &str) -> crate::integration::lakehouse::LakehouseTable {
+        crate::integration::lakehouse::LakehouseTable::new(
+            self.config
+                .base_path
+                .join(&self.config.database_name)
+                .join(table_name),
+            crate::integration::lakehouse::TableFormat::Iceberg,
+        )
+    }
+
     /// Convert `LinkML` schema to Iceberg table schema
     ///
     /// # Errors
@@ -0,0 +1,220 @@
+//! Minimal Avro object container encoding for Iceberg manifests
+//!
+//! Iceberg stores manifests and manifest lists as Avro object container
+//! files. Only the primitives those files need are implemented here;
+//! the writer always uses the `null` codec.
+
+use linkml_core::error::{LinkMLError, Result};
+use std::collections::HashMap;
+
+const MAGIC: &[u8; 4] = b"Obj\x01";
+
+/// Binary encoder for Avro datums
+#[derive(Debug, Default)]
+pub(super) struct Encoder {
+    buf: Vec<u8>,
+}
+
+impl Encoder {
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(super) fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+
+    /// Zig-zag variable-length `long` (also used for `int`)
+    pub(super) fn long(&mut self, value: i64) {
+        let mut n = ((value << 1) ^ (value >> 63)) as u64;
+        while n >= 0x80 {
+            self.buf.push((n as u8) | 0x80);
+            n >>= 7;
+        }
+        self.buf.push(n as u8);
+    }
+
+    pub(super) fn int(&mut self, value: i32) {
+        self.long(i64::from(value));
+    }
+
+    pub(super) fn boolean(&mut self, value: bool) {
+        self.buf.push(u8::from(value));
+    }
+
+    pub(super) fn bytes(&mut self, value: &[u8]) {
+        self.long(value.len() as i64);
+        self.buf.extend_from_slice(value);
+    }
+
+    pub(super) fn string(&mut self, value: &str) {
+        self.bytes(value.as_bytes());
+    }
+
+    /// Branch index of a union; the branch value follows
+    pub(super) fn union_branch(&mut self, index: i64) {
+        self.long(index);
+    }
+}
+
+/// Binary decoder for Avro datums
+pub(super) struct Decoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    pub(super) fn long(&mut self) -> Result<i64> {
+        let mut n: u64 = 0;
+        let mut shift = 0;
+        loop {
+            let byte = *self
+                .data
+                .get(self.pos)
+                .ok_or_else(|| LinkMLError::deserialization("Truncated Avro data"))?;
+            self.pos += 1;
+            n |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+            if shift > 63 {
+                return Err(LinkMLError::deserialization("Invalid Avro varint"));
+            }
+        }
+        Ok((n >> 1) as i64 ^ -((n & 1) as i64))
+    }
+
+    pub(super) fn int(&mut self) -> Result<i32> {
+        i32::try_from(self.long()?)
+            .map_err(|_| LinkMLError::deserialization("Avro int out of range"))
+    }
+
+    pub(super) fn fixed(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self.pos + len;
+        let slice = self
+            .data
+            .get(self.pos..end)
+            .ok_or_else(|| LinkMLError::deserialization("Truncated Avro data"))?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    pub(super) fn bytes(&mut self) -> Result<&'a [u8]> {
+        let len = usize::try_from(self.long()?)
+            .map_err(|_| LinkMLError::deserialization("Negative Avro length"))?;
+        self.fixed(len)
+    }
+
+    pub(super) fn string(&mut self) -> Result<String> {
+        String::from_utf8(self.bytes()?.to_vec())
+            .map_err(|err| LinkMLError::deserialization(format!("Invalid Avro string: {err}")))
+    }
+}
+
+/// Write an object container file holding pre-encoded records
+pub(super) fn write_container(
+    schema: &serde_json::Value,
+    metadata: &[(&str, String)],
+    records: &[Vec<u8>],
+    sync: [u8; 16],
+) -> Vec<u8> {
+    let mut header = Encoder::new();
+    header.long(metadata.len() as i64 + 2);
+    header.string("avro.schema");
+    header.bytes(schema.to_string().as_bytes());
+    header.string("avro.codec");
+    header.bytes(b"null");
+    for (key, value) in metadata {
+        header.string(key);
+        header.bytes(value.as_bytes());
+    }
+    header.long(0);
+
+    let mut out = MAGIC.to_vec();
+    out.extend(header.into_bytes());
+    out.extend_from_slice(&sync);
+    if !records.is_empty() {
+        let body: Vec<u8> = records.concat();
+        let mut block = Encoder::new();
+        block.long(records.len() as i64);
+        block.long(body.len() as i64);
+        out.extend(block.into_bytes());
+        out.extend(body);
+        out.extend_from_slice(&sync);
+    }
+    out
+}
+
+/// Contents of an object container file
+pub(super) struct Container {
+    /// File metadata, including `avro.schema`
+    pub metadata: HashMap<String, String>,
+    /// Concatenated datums of all blocks
+    pub data: Vec<u8>,
+    /// Number of datums
+    pub count: usize,
+}
+
+/// Read an uncompressed object container file
+pub(super) fn read_container(bytes: &[u8]) -> Result<Container> {
+    let mut decoder = Decoder::new(bytes);
+    if decoder.fixed(4)? != MAGIC {
+        return Err(LinkMLError::deserialization("Not an Avro container file"));
+    }
+
+    let mut metadata = HashMap::new();
+    loop {
+        let mut entries = decoder.long()?;
+        if entries == 0 {
+            break;
+        }
+        if entries < 0 {
+            // Negative counts are followed by the block size in bytes
+            decoder.long()?;
+            entries = -entries;
+        }
+        for _ in 0..entries {
+            let key = decoder.string()?;
+            let value = String::from_utf8_lossy(decoder.bytes()?).into_owned();
+            metadata.insert(key, value);
+        }
+    }
+    if metadata
+        .get("avro.codec")
+        .is_some_and(|codec| codec != "null")
+    {
+        return Err(LinkMLError::not_implemented(
+            "Only uncompressed Avro files are supported",
+        ));
+    }
+    let sync = decoder.fixed(16)?;
+
+    let mut data = Vec::new();
+    let mut count = 0;
+    while !decoder.is_empty() {
+        let objects = usize::try_from(decoder.long()?)
+            .map_err(|_| LinkMLError::deserialization("Negative Avro block count"))?;
+        let size = usize::try_from(decoder.long()?)
+            .map_err(|_| LinkMLError::deserialization("Negative Avro block size"))?;
+        data.extend_from_slice(decoder.fixed(size)?);
+        if decoder.fixed(16)? != sync {
+            return Err(LinkMLError::deserialization("Avro sync marker mismatch"));
+        }
+        count += objects;
+    }
+
+    Ok(Container {
+        metadata,
+        data,
+        count,
+    })
+}
@@ -0,0 +1,291 @@
+//! Delta Lake transaction log
+//!
+//! Commits are written as `_delta_log/<version>.json` files holding one
+//! action per line. Checkpoints are neither written nor read, so tables
+//! created by other engines are only supported until their first
+//! checkpoint.
+
+use super::parquet_io::NULL_PARTITION;
+use super::{ColumnType, CommitContext, TableColumn, TableSchema, TableState};
+use linkml_core::error::{LinkMLError, Result};
+use serde_json::{Map, Value, json};
+use std::io::Write as _;
+use std::path::Path;
+
+const LOG_DIR: &str = "_delta_log";
+
+/// Field metadata key holding the stable field id
+const FIELD_ID_KEY: &str = "linkml.field_id";
+
+/// Field metadata key holding the element field id of list columns
+const ELEMENT_ID_KEY: &str = "linkml.element_id";
+
+/// Read the latest table state by replaying the log
+pub(super) fn read_state(root: &Path) -> Result<Option<TableState>> {
+    let log_dir = root.join(LOG_DIR);
+    if !log_dir.is_dir() {
+        return Ok(None);
+    }
+
+    let mut versions: Vec<(i64, std::path::PathBuf)> = std::fs::read_dir(&log_dir)?
+        .filter_map(std::result::Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            let version = path
+                .extension()
+                .is_some_and(|ext| ext == "json")
+                .then(|| path.file_stem()?.to_str()?.parse::<i64>().ok())
+                .flatten()?;
+            Some((version, path))
+        })
+        .collect();
+    versions.sort_by_key(|(version, _)| *version);
+    let Some((latest, _)) = versions.last() else {
+        return Ok(None);
+    };
+    let latest = *latest;
+
+    let mut metadata = None;
+    for (_, path) in &versions {
+        for line in std::fs::read_to_string(path)?.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let action: Value = serde_json::from_str(line).map_err(|err| {
+                LinkMLError::deserialization(format!("Invalid Delta log entry: {err}"))
+            })?;
+            if let Some(meta) = action.get("metaData") {
+                metadata = Some(meta.clone());
+            }
+        }
+    }
+    let metadata =
+        metadata.ok_or_else(|| LinkMLError::deserialization("Delta log has no metaData action"))?;
+
+    let schema_json: Value = metadata
+        .get("schemaString")
+        .and_then(Value::as_str)
+        .map(serde_json::from_str)
+        .transpose()
+        .map_err(|err| LinkMLError::deserialization(format!("Invalid Delta schema: {err}")))?
+        .unwrap_or(Value::Null);
+
+    Ok(Some(TableState {
+        version: latest,
+        schema: parse_schema(&schema_json)?,
+        partition_by: string_list(metadata.get("partitionColumns")),
+        properties: metadata
+            .get("configuration")
+            .and_then(Value::as_object)
+            .map(|config| {
+                config
+                    .iter()
+                    .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        raw: metadata,
+    }))
+}
+
+/// Write the next log entry; returns the new table version
+pub(super) fn commit(root: &Path, context: &CommitContext<'_>) -> Result<i64> {
+    let version = context.state.map_or(0, |state| state.version + 1);
+    let mut actions = Vec::new();
+
+    if context.state.is_none() {
+        actions.push(json!({"protocol": {"minReaderVersion": 1, "minWriterVersion": 2}}));
+    }
+    let metadata_changed = context
+        .state
+        .is_none_or(|state| context.schema_changed || state.properties != context.properties);
+    if metadata_changed {
+        let table_id = context
+            .state
+            .and_then(|state| state.raw.get("id").and_then(Value::as_str))
+            .map_or_else(|| uuid::Uuid::new_v4().to_string(), str::to_string);
+        let created_time = context
+            .state
+            .and_then(|state| state.raw.get("createdTime").cloned())
+            .unwrap_or_else(|| json!(context.timestamp_ms));
+        actions.push(json!({"metaData": {
+            "id": table_id,
+            "format": {"provider": "parquet", "options": {}},
+            "schemaString": schema_json(context.schema).to_string(),
+            "partitionColumns": context.partition_by,
+            "configuration": context.properties,
+            "createdTime": created_time,
+        }}));
+    }
+
+    for file in context.files {
+        let partition_values: Map<String, Value> = file
+            .partition
+            .iter()
+            .map(|(column, value)| {
+                let value = value
+                    .as_deref()
+                    .filter(|value| *value != NULL_PARTITION)
+                    .map_or(Value::Null, |value| Value::String(value.to_string()));
+                (column.clone(), value)
+            })
+            .collect();
+        actions.push(json!({"add": {
+            // Directory names are already percent-escaped; escape the escapes for the URI
+            "path": file.path.replace('%', "%25"),
+            "partitionValues": partition_values,
+            "size": file.size_bytes,
+            "modificationTime": context.timestamp_ms,
+            "dataChange": true,
+            "stats": json!({"numRecords": file.record_count}).to_string(),
+        }}));
+    }
+
+    actions.push(json!({"commitInfo": {
+        "timestamp": context.timestamp_ms,
+        "operation": "WRITE",
+        "operationParameters": {
+            "mode": "Append",
+            "partitionBy": serde_json::to_string(context.partition_by)?,
+        },
+        "engineInfo": concat!("linkml-rs/", env!("CARGO_PKG_VERSION")),
+    }}));
+
+    let log_dir = root.join(LOG_DIR);
+    std::fs::create_dir_all(&log_dir)?;
+    let path = log_dir.join(format!("{version:020}.json"));
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .map_err(|err| {
+            if err.kind() == std::io::ErrorKind::AlreadyExists {
+                LinkMLError::service(format!(
+                    "Concurrent commit: Delta table version {version} already exists"
+                ))
+            } else {
+                err.into()
+            }
+        })?;
+    for action in &actions {
+        writeln!(file, "{action}")?;
+    }
+    Ok(version)
+}
+
+/// Delta schema JSON of a table
+fn schema_json(table: &TableSchema) -> Value {
+    let fields: Vec<Value> = table
+        .columns
+        .iter()
+        .map(|column| {
+            let mut metadata = Map::new();
+            metadata.insert(FIELD_ID_KEY.to_string(), json!(column.id));
+            if let Some(element_id) = column.element_id {
+                metadata.insert(ELEMENT_ID_KEY.to_string(), json!(element_id));
+            }
+            if let Some(doc) = &column.doc {
+                metadata.insert("comment".to_string(), json!(doc));
+            }
+            json!({
+                "name": column.name,
+                "type": type_json(&column.column_type),
+                "nullable": !column.required,
+                "metadata": metadata,
+            })
+        })
+        .collect();
+    json!({"type": "struct", "fields": fields})
+}
+
+fn type_json(column_type: &ColumnType) -> Value {
+    match column_type {
+        ColumnType::List(inner) => json!({
+            "type": "array",
+            "elementType": type_json(inner),
+            "containsNull": true,
+        }),
+        other => json!(other.to_string()),
+    }
+}
+
+fn parse_schema(schema: &Value) -> Result<TableSchema> {
+    let mut table = TableSchema::default();
+    let fields = schema
+        .get("fields")
+        .and_then(Value::as_array)
+        .map_or(&[][..], Vec::as_slice);
+    for field in fields {
+        let name = field
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| LinkMLError::deserialization("Delta schema field without a name"))?;
+        let column_type = parse_type(field.get("type").unwrap_or(&Value::Null))?;
+        let metadata = field.get("metadata");
+        let id = metadata
+            .and_then(|meta| meta.get(FIELD_ID_KEY))
+            .and_then(Value::as_i64)
+            .and_then(|id| i32::try_from(id).ok());
+        let required = !field
+            .get("nullable")
+            .and_then(Value::as_bool)
+            .unwrap_or(true);
+        let doc = metadata
+            .and_then(|meta| meta.get("comment"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        match id {
+            Some(id) => table.columns.push(TableColumn {
+                id,
+                name: name.to_string(),
+                element_id: metadata
+                    .and_then(|meta| meta.get(ELEMENT_ID_KEY))
+                    .and_then(Value::as_i64)
+                    .and_then(|id| i32::try_from(id).ok()),
+                column_type,
+                required,
+                doc,
+            }),
+            // Tables written by other engines carry no field ids
+            None => table.add_column(name, column_type, required, doc),
+        }
+    }
+    Ok(table)
+}
+
+fn parse_type(value: &Value) -> Result<ColumnType> {
+    match value {
+        Value::String(name) => match name.as_str() {
+            "string" => Ok(ColumnType::String),
+            "long" | "integer" | "short" | "byte" => Ok(ColumnType::Long),
+            "double" | "float" => Ok(ColumnType::Double),
+            "boolean" => Ok(ColumnType::Boolean),
+            "date" => Ok(ColumnType::Date),
+            "timestamp" | "timestamp_ntz" => Ok(ColumnType::Timestamp),
+            other => Err(LinkMLError::not_implemented(format!(
+                "Delta column type '{other}' is not supported"
+            ))),
+        },
+        Value::Object(object) if object.get("type").and_then(Value::as_str) == Some("array") => {
+            Ok(ColumnType::List(Box::new(parse_type(
+                object.get("elementType").unwrap_or(&Value::Null),
+            )?)))
+        }
+        other => Err(LinkMLError::not_implemented(format!(
+            "Delta column type {other} is not supported"
+        ))),
+    }
+}
+
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
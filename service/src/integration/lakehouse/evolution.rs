@@ -0,0 +1,174 @@
+//! Table schema evolution derived from `LinkML` schema diffs
+
+use super::{ColumnType, TableSchema};
+use crate::schema::{DiffOptions, SchemaDiff};
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
+use serde::{Deserialize, Serialize};
+
+/// A change to the columns of a lakehouse table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum ColumnChange {
+    /// A slot was added; the column is created as optional
+    Added {
+        /// Column name
+        name: String,
+        /// Column type
+        column_type: ColumnType,
+    },
+    /// A slot was removed; the column stays in the table as optional
+    Retired {
+        /// Column name
+        name: String,
+    },
+    /// A slot is no longer required
+    MadeOptional {
+        /// Column name
+        name: String,
+    },
+}
+
+impl ColumnChange {
+    /// Name of the affected column
+    pub fn column(&self) -> &str {
+        match self {
+            Self::Added { name, .. } | Self::Retired { name } | Self::MadeOptional { name } => name,
+        }
+    }
+}
+
+/// Plan the column changes needed to write data of `current` into a table
+/// last written with `previous`
+///
+/// Changes reported by the schema diff come first. Changes the diff cannot
+/// see for this class (attributes, inherited slots, or tables written
+/// without a recorded schema) are then found by comparing `table` with the
+/// layout derived from the current schema (`incoming`).
+///
+/// # Errors
+///
+/// Returns an error when a column's type would change, which neither
+/// Iceberg nor Delta can apply to existing data files.
+pub fn plan_evolution(
+    previous: Option<&SchemaDefinition>,
+    current: &SchemaDefinition,
+    class_name: &str,
+    table: &TableSchema,
+    incoming: &TableSchema,
+) -> Result<Vec<ColumnChange>> {
+    let mut changes = Vec::new();
+
+    if let Some(previous) = previous {
+        let diff = SchemaDiff::new(DiffOptions::default()).diff(previous, current)?;
+        if let Some(class_diff) = diff.modified_classes.iter().find(|c| c.name == class_name) {
+            for name in &class_diff.added_slots {
+                if let Some(column) = incoming.column(name)
+                    && table.column(name).is_none()
+                {
+                    changes.push(ColumnChange::Added {
+                        name: name.clone(),
+                        column_type: column.column_type.clone(),
+                    });
+                }
+            }
+            for name in &class_diff.removed_slots {
+                if table.column(name).is_some() && incoming.column(name).is_none() {
+                    changes.push(ColumnChange::Retired { name: name.clone() });
+                }
+            }
+        }
+        for slot_diff in &diff.modified_slots {
+            if table.column(&slot_diff.name).is_none() {
+                continue;
+            }
+            for attribute in ["range", "multivalued"] {
+                if let Some(change) = slot_diff.changed_attributes.get(attribute) {
+                    check_type(table, incoming, &slot_diff.name).map_err(|err| {
+                        LinkMLError::schema_validation(format!(
+                            "{err} (slot {attribute} changed from {} to {})",
+                            display(change.old_value.as_ref()),
+                            display(change.new_value.as_ref())
+                        ))
+                    })?;
+                }
+            }
+        }
+    }
+
+    reconcile(table, incoming, &mut changes)?;
+    Ok(changes)
+}
+
+/// Add changes visible only in the column layouts
+fn reconcile(
+    table: &TableSchema,
+    incoming: &TableSchema,
+    changes: &mut Vec<ColumnChange>,
+) -> Result<()> {
+    let planned =
+        |changes: &[ColumnChange], name: &str| changes.iter().any(|change| change.column() == name);
+
+    for column in &incoming.columns {
+        match table.column(&column.name) {
+            None if !planned(changes, &column.name) => changes.push(ColumnChange::Added {
+                name: column.name.clone(),
+                column_type: column.column_type.clone(),
+            }),
+            None => {}
+            Some(existing) => {
+                check_type(table, incoming, &column.name)?;
+                if existing.required && !column.required && !planned(changes, &column.name) {
+                    changes.push(ColumnChange::MadeOptional {
+                        name: column.name.clone(),
+                    });
+                }
+            }
+        }
+    }
+    for column in &table.columns {
+        if column.required
+            && incoming.column(&column.name).is_none()
+            && !planned(changes, &column.name)
+        {
+            changes.push(ColumnChange::Retired {
+                name: column.name.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn check_type(table: &TableSchema, incoming: &TableSchema, name: &str) -> Result<()> {
+    if let (Some(existing), Some(column)) = (table.column(name), incoming.column(name))
+        && existing.column_type != column.column_type
+    {
+        return Err(LinkMLError::schema_validation(format!(
+            "Column '{name}' cannot change type from {} to {}",
+            existing.column_type, column.column_type
+        )));
+    }
+    Ok(())
+}
+
+fn display(value: Option<&serde_json::Value>) -> String {
+    value.map_or_else(|| "unset".to_string(), ToString::to_string)
+}
+
+/// Apply planned changes to a table schema; existing field ids are kept
+pub(super) fn apply(table: &TableSchema, changes: &[ColumnChange]) -> TableSchema {
+    let mut evolved = table.clone();
+    for change in changes {
+        match change {
+            ColumnChange::Added { name, column_type } => {
+                evolved.add_column(name, column_type.clone(), false, None);
+            }
+            ColumnChange::Retired { name } | ColumnChange::MadeOptional { name } => {
+                if let Some(column) = evolved.columns.iter_mut().find(|c| &c.name == name) {
+                    column.required = false;
+                }
+            }
+        }
+    }
+    evolved
+}
@@ -0,0 +1,723 @@
+//! Apache Iceberg table metadata (format version 2)
+//!
+//! Tables use the filesystem catalog layout: `metadata/v<N>.metadata.json`
+//! plus `metadata/version-hint.text` pointing at the current version.
+//! Each append adds one snapshot whose manifest list carries over the
+//! manifests of the previous snapshot. Manifest lists are read back with
+//! the schema this module writes, so tables whose snapshots were written
+//! by other engines cannot be appended to.
+
+use super::avro::{self, Decoder, Encoder};
+use super::parquet_io::{NULL_PARTITION, WrittenFile};
+use super::{ColumnType, CommitContext, TableColumn, TableSchema, TableState};
+use linkml_core::error::{LinkMLError, Result};
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+
+const METADATA_DIR: &str = "metadata";
+const VERSION_HINT: &str = "version-hint.text";
+
+/// First field id of partition fields, as used by the Iceberg reference implementation
+const PARTITION_FIELD_ID_START: i32 = 1000;
+
+/// Entry of a manifest list
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct ManifestFile {
+    pub manifest_path: String,
+    pub manifest_length: i64,
+    pub partition_spec_id: i32,
+    pub content: i32,
+    pub sequence_number: i64,
+    pub min_sequence_number: i64,
+    pub added_snapshot_id: i64,
+    pub added_files_count: i32,
+    pub existing_files_count: i32,
+    pub deleted_files_count: i32,
+    pub added_rows_count: i64,
+    pub existing_rows_count: i64,
+    pub deleted_rows_count: i64,
+}
+
+impl ManifestFile {
+    fn encode(&self) -> Vec<u8> {
+        let mut encoder = Encoder::new();
+        encoder.string(&self.manifest_path);
+        encoder.long(self.manifest_length);
+        encoder.int(self.partition_spec_id);
+        encoder.int(self.content);
+        encoder.long(self.sequence_number);
+        encoder.long(self.min_sequence_number);
+        encoder.long(self.added_snapshot_id);
+        encoder.int(self.added_files_count);
+        encoder.int(self.existing_files_count);
+        encoder.int(self.deleted_files_count);
+        encoder.long(self.added_rows_count);
+        encoder.long(self.existing_rows_count);
+        encoder.long(self.deleted_rows_count);
+        encoder.into_bytes()
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> Result<Self> {
+        Ok(Self {
+            manifest_path: decoder.string()?,
+            manifest_length: decoder.long()?,
+            partition_spec_id: decoder.int()?,
+            content: decoder.int()?,
+            sequence_number: decoder.long()?,
+            min_sequence_number: decoder.long()?,
+            added_snapshot_id: decoder.long()?,
+            added_files_count: decoder.int()?,
+            existing_files_count: decoder.int()?,
+            deleted_files_count: decoder.int()?,
+            added_rows_count: decoder.long()?,
+            existing_rows_count: decoder.long()?,
+            deleted_rows_count: decoder.long()?,
+        })
+    }
+}
+
+/// Read the current table state via the version hint
+pub(super) fn read_state(root: &Path) -> Result<Option<TableState>> {
+    let hint = root.join(METADATA_DIR).join(VERSION_HINT);
+    if !hint.is_file() {
+        return Ok(None);
+    }
+    let version: i64 = std::fs::read_to_string(&hint)?
+        .trim()
+        .parse()
+        .map_err(|err| LinkMLError::deserialization(format!("Invalid version hint: {err}")))?;
+    let path = metadata_path(root, version);
+    let raw: Value = serde_json::from_str(&std::fs::read_to_string(&path)?).map_err(|err| {
+        LinkMLError::deserialization(format!(
+            "Invalid Iceberg metadata {}: {err}",
+            path.display()
+        ))
+    })?;
+
+    let current_schema_id = raw.get("current-schema-id").and_then(Value::as_i64);
+    let schema_json = raw
+        .get("schemas")
+        .and_then(Value::as_array)
+        .and_then(|schemas| {
+            schemas
+                .iter()
+                .find(|schema| schema.get("schema-id").and_then(Value::as_i64) == current_schema_id)
+        })
+        .ok_or_else(|| LinkMLError::deserialization("Iceberg metadata has no current schema"))?;
+    let schema = parse_schema(schema_json)?;
+
+    let default_spec = raw.get("default-spec-id").and_then(Value::as_i64);
+    let partition_by = raw
+        .get("partition-specs")
+        .and_then(Value::as_array)
+        .and_then(|specs| {
+            specs
+                .iter()
+                .find(|spec| spec.get("spec-id").and_then(Value::as_i64) == default_spec)
+        })
+        .and_then(|spec| spec.get("fields").and_then(Value::as_array))
+        .map(|fields| partition_columns(fields, &schema))
+        .transpose()?
+        .unwrap_or_default();
+
+    let properties = raw
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|props| {
+            props
+                .iter()
+                .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(Some(TableState {
+        version,
+        schema,
+        partition_by,
+        properties,
+        raw,
+    }))
+}
+
+/// Write a new metadata version (and a snapshot when files were added)
+pub(super) fn commit(root: &Path, context: &CommitContext<'_>) -> Result<i64> {
+    std::fs::create_dir_all(root.join(METADATA_DIR))?;
+    let location = std::fs::canonicalize(root)?;
+    let version = context.state.map_or(1, |state| state.version + 1);
+
+    let mut metadata = match context.state {
+        Some(state) => state.raw.clone(),
+        None => new_metadata(&location, context),
+    };
+
+    if context.schema_changed {
+        let schema_id = metadata["schemas"]
+            .as_array()
+            .and_then(|schemas| {
+                schemas
+                    .iter()
+                    .filter_map(|s| s.get("schema-id").and_then(Value::as_i64))
+                    .max()
+            })
+            .map_or(0, |max| max + 1);
+        push(
+            &mut metadata,
+            "schemas",
+            schema_json(context.schema, schema_id),
+        );
+        metadata["current-schema-id"] = json!(schema_id);
+    }
+    let last_column_id = metadata["last-column-id"]
+        .as_i64()
+        .unwrap_or_default()
+        .max(i64::from(context.schema.last_column_id()));
+    metadata["last-column-id"] = json!(last_column_id);
+    metadata["properties"] = json!(context.properties);
+    metadata["last-updated-ms"] = json!(context.timestamp_ms);
+
+    if !context.files.is_empty() {
+        add_snapshot(root, &location, &mut metadata, context)?;
+    }
+    if let Some(state) = context.state {
+        push(
+            &mut metadata,
+            "metadata-log",
+            json!({
+                "timestamp-ms": state.raw.get("last-updated-ms").cloned().unwrap_or(Value::Null),
+                "metadata-file": path_string(&metadata_path(&location, state.version)),
+            }),
+        );
+    }
+
+    let path = metadata_path(root, version);
+    let file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .map_err(|err| {
+            if err.kind() == std::io::ErrorKind::AlreadyExists {
+                LinkMLError::service(format!(
+                    "Concurrent commit: Iceberg metadata version {version} already exists"
+                ))
+            } else {
+                err.into()
+            }
+        })?;
+    serde_json::to_writer_pretty(file, &metadata)?;
+    std::fs::write(
+        root.join(METADATA_DIR).join(VERSION_HINT),
+        version.to_string(),
+    )?;
+    Ok(version)
+}
+
+/// Manifests referenced by the current snapshot
+pub(super) fn snapshot_manifests(root: &Path, metadata: &Value) -> Result<Vec<ManifestFile>> {
+    let Some(snapshot) = current_snapshot(metadata) else {
+        return Ok(Vec::new());
+    };
+    let list_path = snapshot
+        .get("manifest-list")
+        .and_then(Value::as_str)
+        .ok_or_else(|| LinkMLError::deserialization("Snapshot has no manifest list"))?;
+    let bytes = std::fs::read(resolve(root, list_path))?;
+    let container = avro::read_container(&bytes)?;
+    if container.metadata.get("avro.schema") != Some(&manifest_list_schema().to_string()) {
+        return Err(LinkMLError::not_implemented(
+            "Appending to Iceberg snapshots written by other engines is not supported",
+        ));
+    }
+    let mut decoder = Decoder::new(&container.data);
+    (0..container.count)
+        .map(|_| ManifestFile::decode(&mut decoder))
+        .collect()
+}
+
+fn add_snapshot(
+    root: &Path,
+    location: &Path,
+    metadata: &mut Value,
+    context: &CommitContext<'_>,
+) -> Result<()> {
+    let snapshot_id = (uuid::Uuid::new_v4().as_u64_pair().0 >> 1) as i64;
+    let sequence_number = metadata["last-sequence-number"]
+        .as_i64()
+        .unwrap_or_default()
+        + 1;
+    let parent = current_snapshot(metadata).and_then(|s| s.get("snapshot-id").cloned());
+    let schema_id = metadata["current-schema-id"].as_i64().unwrap_or_default();
+    let commit_id = uuid::Uuid::new_v4();
+
+    let manifest_path = location
+        .join(METADATA_DIR)
+        .join(format!("{commit_id}-m0.avro"));
+    let manifest_length = write_manifest(&manifest_path, location, metadata, context, snapshot_id)?;
+
+    let mut manifests = snapshot_manifests(root, metadata)?;
+    let added_rows: u64 = context.files.iter().map(|file| file.record_count).sum();
+    manifests.push(ManifestFile {
+        manifest_path: path_string(&manifest_path),
+        manifest_length: manifest_length as i64,
+        partition_spec_id: 0,
+        content: 0,
+        sequence_number,
+        min_sequence_number: sequence_number,
+        added_snapshot_id: snapshot_id,
+        added_files_count: context.files.len() as i32,
+        existing_files_count: 0,
+        deleted_files_count: 0,
+        added_rows_count: added_rows as i64,
+        existing_rows_count: 0,
+        deleted_rows_count: 0,
+    });
+    let total_records: i64 = manifests.iter().map(|m| m.added_rows_count).sum();
+    let total_files: i64 = manifests
+        .iter()
+        .map(|m| i64::from(m.added_files_count))
+        .sum();
+
+    let list_path = location
+        .join(METADATA_DIR)
+        .join(format!("snap-{snapshot_id}-1-{commit_id}.avro"));
+    let records: Vec<Vec<u8>> = manifests.iter().map(ManifestFile::encode).collect();
+    let mut list_metadata = vec![
+        ("snapshot-id", snapshot_id.to_string()),
+        ("sequence-number", sequence_number.to_string()),
+        ("format-version", "2".to_string()),
+    ];
+    if let Some(parent) = &parent {
+        list_metadata.push(("parent-snapshot-id", parent.to_string()));
+    }
+    std::fs::write(
+        &list_path,
+        avro::write_container(
+            &manifest_list_schema(),
+            &list_metadata,
+            &records,
+            *commit_id.as_bytes(),
+        ),
+    )?;
+
+    let mut snapshot = json!({
+        "snapshot-id": snapshot_id,
+        "sequence-number": sequence_number,
+        "timestamp-ms": context.timestamp_ms,
+        "manifest-list": path_string(&list_path),
+        "summary": {
+            "operation": "append",
+            "added-data-files": context.files.len().to_string(),
+            "added-records": added_rows.to_string(),
+            "total-data-files": total_files.to_string(),
+            "total-records": total_records.to_string(),
+        },
+        "schema-id": schema_id,
+    });
+    if let Some(parent) = parent {
+        snapshot["parent-snapshot-id"] = parent;
+    }
+    push(metadata, "snapshots", snapshot);
+    push(
+        metadata,
+        "snapshot-log",
+        json!({"timestamp-ms": context.timestamp_ms, "snapshot-id": snapshot_id}),
+    );
+    metadata["current-snapshot-id"] = json!(snapshot_id);
+    metadata["last-sequence-number"] = json!(sequence_number);
+    metadata["refs"] = json!({"main": {"snapshot-id": snapshot_id, "type": "branch"}});
+    Ok(())
+}
+
+/// Write the manifest listing this commit's data files; returns its length
+fn write_manifest(
+    path: &Path,
+    location: &Path,
+    metadata: &Value,
+    context: &CommitContext<'_>,
+    snapshot_id: i64,
+) -> Result<u64> {
+    let partition_fields = partition_fields(context.schema, context.partition_by)?;
+    let spec_json: Vec<Value> = partition_fields
+        .iter()
+        .map(|(column, field_id)| {
+            json!({
+                "name": column.name,
+                "transform": "identity",
+                "source-id": column.id,
+                "field-id": field_id,
+            })
+        })
+        .collect();
+
+    let records = context
+        .files
+        .iter()
+        .map(|file| encode_entry(file, location, &partition_fields, snapshot_id))
+        .collect::<Result<Vec<_>>>()?;
+
+    let current_schema = metadata["schemas"]
+        .as_array()
+        .and_then(|schemas| {
+            schemas
+                .iter()
+                .find(|s| s.get("schema-id") == metadata.get("current-schema-id"))
+        })
+        .cloned()
+        .unwrap_or_else(|| schema_json(context.schema, 0));
+    let manifest_metadata = [
+        ("schema", current_schema.to_string()),
+        (
+            "schema-id",
+            current_schema["schema-id"]
+                .as_i64()
+                .unwrap_or_default()
+                .to_string(),
+        ),
+        ("partition-spec", Value::Array(spec_json).to_string()),
+        ("partition-spec-id", "0".to_string()),
+        ("format-version", "2".to_string()),
+        ("content", "data".to_string()),
+    ];
+    let bytes = avro::write_container(
+        &manifest_entry_schema(&partition_fields),
+        &manifest_metadata,
+        &records,
+        *uuid::Uuid::new_v4().as_bytes(),
+    );
+    std::fs::write(path, &bytes)?;
+    Ok(bytes.len() as u64)
+}
+
+fn encode_entry(
+    file: &WrittenFile,
+    location: &Path,
+    partition_fields: &[(&TableColumn, i32)],
+    snapshot_id: i64,
+) -> Result<Vec<u8>> {
+    let mut encoder = Encoder::new();
+    encoder.int(1); // status: ADDED
+    encoder.union_branch(1);
+    encoder.long(snapshot_id);
+    encoder.union_branch(0); // sequence_number: inherited from the manifest list
+    encoder.union_branch(0); // file_sequence_number: inherited
+    encoder.int(0); // content: DATA
+    encoder.string(&path_string(&location.join(&file.path)));
+    encoder.string("PARQUET");
+    for (column, value) in partition_fields
+        .iter()
+        .map(|(column, _)| *column)
+        .zip(file.partition.iter().map(|(_, value)| value.as_deref()))
+    {
+        encode_partition_value(&mut encoder, column, value)?;
+    }
+    encoder.long(file.record_count as i64);
+    encoder.long(file.size_bytes as i64);
+    Ok(encoder.into_bytes())
+}
+
+fn encode_partition_value(
+    encoder: &mut Encoder,
+    column: &TableColumn,
+    value: Option<&str>,
+) -> Result<()> {
+    let Some(value) = value.filter(|value| *value != NULL_PARTITION) else {
+        encoder.union_branch(0);
+        return Ok(());
+    };
+    let invalid = || {
+        LinkMLError::data_validation(format!(
+            "Partition value '{value}' is not a valid {} for column '{}'",
+            column.column_type, column.name
+        ))
+    };
+    encoder.union_branch(1);
+    match column.column_type {
+        ColumnType::Long => encoder.long(value.parse().map_err(|_| invalid())?),
+        ColumnType::Boolean => encoder.boolean(value.parse().map_err(|_| invalid())?),
+        ColumnType::Date => {
+            let date =
+                chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| invalid())?;
+            let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap_or_default();
+            encoder.int((date - epoch).num_days() as i32);
+        }
+        _ => encoder.string(value),
+    }
+    Ok(())
+}
+
+/// Partition columns with their partition field ids
+fn partition_fields<'a>(
+    schema: &'a TableSchema,
+    partition_by: &[String],
+) -> Result<Vec<(&'a TableColumn, i32)>> {
+    partition_by
+        .iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let column = schema.column(name).ok_or_else(|| {
+                LinkMLError::config(format!("Partition column '{name}' is not in the table"))
+            })?;
+            Ok((column, PARTITION_FIELD_ID_START + index as i32))
+        })
+        .collect()
+}
+
+fn new_metadata(location: &Path, context: &CommitContext<'_>) -> Value {
+    let spec_fields: Vec<Value> = context
+        .partition_by
+        .iter()
+        .enumerate()
+        .filter_map(|(index, name)| {
+            let column = context.schema.column(name)?;
+            Some(json!({
+                "name": name,
+                "transform": "identity",
+                "source-id": column.id,
+                "field-id": PARTITION_FIELD_ID_START + index as i32,
+            }))
+        })
+        .collect();
+    json!({
+        "format-version": 2,
+        "table-uuid": uuid::Uuid::new_v4().to_string(),
+        "location": path_string(location),
+        "last-sequence-number": 0,
+        "last-updated-ms": context.timestamp_ms,
+        "last-column-id": context.schema.last_column_id(),
+        "current-schema-id": 0,
+        "schemas": [schema_json(context.schema, 0)],
+        "default-spec-id": 0,
+        "partition-specs": [{"spec-id": 0, "fields": spec_fields}],
+        "last-partition-id": PARTITION_FIELD_ID_START - 1 + context.partition_by.len() as i32,
+        "default-sort-order-id": 0,
+        "sort-orders": [{"order-id": 0, "fields": []}],
+        "properties": {},
+        "current-snapshot-id": -1,
+        "snapshots": [],
+        "snapshot-log": [],
+        "metadata-log": [],
+    })
+}
+
+fn schema_json(table: &TableSchema, schema_id: i64) -> Value {
+    let fields: Vec<Value> = table
+        .columns
+        .iter()
+        .map(|column| {
+            let mut field = json!({
+                "id": column.id,
+                "name": column.name,
+                "required": column.required,
+                "type": type_json(column),
+            });
+            if let Some(doc) = &column.doc {
+                field["doc"] = json!(doc);
+            }
+            field
+        })
+        .collect();
+    json!({"type": "struct", "schema-id": schema_id, "fields": fields})
+}
+
+fn type_json(column: &TableColumn) -> Value {
+    match &column.column_type {
+        ColumnType::List(inner) => json!({
+            "type": "list",
+            "element-id": column.element_id.unwrap_or(column.id + 1),
+            "element": primitive_name(inner),
+            "element-required": false,
+        }),
+        other => json!(primitive_name(other)),
+    }
+}
+
+fn primitive_name(column_type: &ColumnType) -> &'static str {
+    match column_type {
+        ColumnType::Long => "long",
+        ColumnType::Double => "double",
+        ColumnType::Boolean => "boolean",
+        ColumnType::Date => "date",
+        ColumnType::Timestamp => "timestamptz",
+        ColumnType::String | ColumnType::List(_) => "string",
+    }
+}
+
+fn parse_schema(schema: &Value) -> Result<TableSchema> {
+    let fields = schema
+        .get("fields")
+        .and_then(Value::as_array)
+        .map_or(&[][..], Vec::as_slice);
+    let columns = fields
+        .iter()
+        .map(|field| {
+            let name = field
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            let id = field
+                .get("id")
+                .and_then(Value::as_i64)
+                .ok_or_else(|| LinkMLError::deserialization("Iceberg field without an id"))?;
+            let (column_type, element_id) = match field.get("type") {
+                Some(Value::Object(list)) if list.get("type") == Some(&json!("list")) => (
+                    ColumnType::List(Box::new(parse_primitive(list.get("element"))?)),
+                    list.get("element-id")
+                        .and_then(Value::as_i64)
+                        .map(|id| id as i32),
+                ),
+                other => (parse_primitive(other)?, None),
+            };
+            Ok(TableColumn {
+                id: id as i32,
+                name: name.to_string(),
+                column_type,
+                element_id,
+                required: field
+                    .get("required")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(false),
+                doc: field.get("doc").and_then(Value::as_str).map(str::to_string),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(TableSchema { columns })
+}
+
+fn parse_primitive(value: Option<&Value>) -> Result<ColumnType> {
+    match value.and_then(Value::as_str) {
+        Some("string" | "uuid") => Ok(ColumnType::String),
+        Some("long" | "int") => Ok(ColumnType::Long),
+        Some("double" | "float") => Ok(ColumnType::Double),
+        Some("boolean") => Ok(ColumnType::Boolean),
+        Some("date") => Ok(ColumnType::Date),
+        Some("timestamp" | "timestamptz") => Ok(ColumnType::Timestamp),
+        _ => Err(LinkMLError::not_implemented(format!(
+            "Iceberg type {} is not supported",
+            value.map_or_else(|| "null".to_string(), ToString::to_string)
+        ))),
+    }
+}
+
+fn partition_columns(fields: &[Value], schema: &TableSchema) -> Result<Vec<String>> {
+    fields
+        .iter()
+        .map(|field| {
+            if field.get("transform").and_then(Value::as_str) != Some("identity") {
+                return Err(LinkMLError::not_implemented(
+                    "Only identity partition transforms are supported",
+                ));
+            }
+            let source_id = field.get("source-id").and_then(Value::as_i64);
+            schema
+                .columns
+                .iter()
+                .find(|column| Some(i64::from(column.id)) == source_id)
+                .map(|column| column.name.clone())
+                .ok_or_else(|| LinkMLError::deserialization("Partition source column not found"))
+        })
+        .collect()
+}
+
+fn manifest_entry_schema(partition_fields: &[(&TableColumn, i32)]) -> Value {
+    let partition: Vec<Value> = partition_fields
+        .iter()
+        .map(|(column, field_id)| {
+            let avro_type = match column.column_type {
+                ColumnType::Long => json!("long"),
+                ColumnType::Boolean => json!("boolean"),
+                ColumnType::Date => json!({"type": "int", "logicalType": "date"}),
+                _ => json!("string"),
+            };
+            json!({
+                "name": column.name,
+                "type": ["null", avro_type],
+                "default": null,
+                "field-id": field_id,
+            })
+        })
+        .collect();
+    let optional_long = |name: &str, id: i32| json!({"name": name, "type": ["null", "long"], "default": null, "field-id": id});
+    json!({
+        "type": "record",
+        "name": "manifest_entry",
+        "fields": [
+            {"name": "status", "type": "int", "field-id": 0},
+            optional_long("snapshot_id", 1),
+            optional_long("sequence_number", 3),
+            optional_long("file_sequence_number", 4),
+            {"name": "data_file", "field-id": 2, "type": {
+                "type": "record",
+                "name": "r2",
+                "fields": [
+                    {"name": "content", "type": "int", "field-id": 134},
+                    {"name": "file_path", "type": "string", "field-id": 100},
+                    {"name": "file_format", "type": "string", "field-id": 101},
+                    {"name": "partition", "field-id": 102, "type": {
+                        "type": "record", "name": "r102", "fields": partition,
+                    }},
+                    {"name": "record_count", "type": "long", "field-id": 103},
+                    {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+                ],
+            }},
+        ],
+    })
+}
+
+fn manifest_list_schema() -> Value {
+    let field = |name: &str, avro_type: &str, id: i32| json!({"name": name, "type": avro_type, "field-id": id});
+    json!({
+        "type": "record",
+        "name": "manifest_file",
+        "fields": [
+            field("manifest_path", "string", 500),
+            field("manifest_length", "long", 501),
+            field("partition_spec_id", "int", 502),
+            field("content", "int", 517),
+            field("sequence_number", "long", 515),
+            field("min_sequence_number", "long", 516),
+            field("added_snapshot_id", "long", 503),
+            field("added_files_count", "int", 504),
+            field("existing_files_count", "int", 505),
+            field("deleted_files_count", "int", 506),
+            field("added_rows_count", "long", 512),
+            field("existing_rows_count", "long", 513),
+            field("deleted_rows_count", "long", 514),
+        ],
+    })
+}
+
+fn current_snapshot(metadata: &Value) -> Option<&Value> {
+    let current = metadata.get("current-snapshot-id")?.as_i64()?;
+    metadata
+        .get("snapshots")?
+        .as_array()?
+        .iter()
+        .find(|snapshot| snapshot.get("snapshot-id").and_then(Value::as_i64) == Some(current))
+}
+
+fn push(metadata: &mut Value, key: &str, value: Value) {
+    match metadata.get_mut(key).and_then(Value::as_array_mut) {
+        Some(items) => items.push(value),
+        None => metadata[key] = Value::Array(vec![value]),
+    }
+}
+
+fn metadata_path(root: &Path, version: i64) -> PathBuf {
+    root.join(METADATA_DIR)
+        .join(format!("v{version}.metadata.json"))
+}
+
+/// Paths in metadata are absolute; relative ones are resolved against the table root
+fn resolve(root: &Path, path: &str) -> PathBuf {
+    let path = path.strip_prefix("file://").unwrap_or(path);
+    let path = Path::new(path);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        root.join(path)
+    }
+}
+
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
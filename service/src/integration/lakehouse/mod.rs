@@ -0,0 +1,896 @@
+//! Lakehouse table output for validated `LinkML` data
+//!
+//! [`LakehouseTable`] appends instances of one class to an Apache Iceberg
+//! or Delta Lake table on the local filesystem. Every append validates the
+//! instances, writes one Parquet data file per partition and records a
+//! single table commit (an Iceberg snapshot or a Delta log entry).
+//!
+//! The `LinkML` schema used for a commit is stored in the table properties.
+//! When a later commit uses a newer schema version, the two versions are
+//! compared with [`SchemaDiff`](crate::schema::SchemaDiff) and the result is
+//! applied to the table schema: added slots become new optional columns,
+//! removed slots remain as optional columns so existing files stay readable,
+//! and slots that are no longer required are relaxed. Range or cardinality
+//! changes have no safe table evolution and are rejected.
+
+mod avro;
+mod delta;
+mod evolution;
+mod iceberg;
+mod parquet_io;
+
+pub use evolution::{ColumnChange, plan_evolution};
+
+use crate::loader::traits::{DataDumper, DataInstance, DumpOptions, DumperError, DumperResult};
+use crate::validator::engine::{ValidationEngine, ValidationOptions};
+use async_trait::async_trait;
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::{SchemaDefinition, SlotDefinition};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Table property holding the `LinkML` schema (JSON) of the latest commit
+pub const LINKML_SCHEMA_PROPERTY: &str = "linkml.schema";
+
+/// Table property holding the `LinkML` class stored in the table
+pub const LINKML_CLASS_PROPERTY: &str = "linkml.class";
+
+/// Open table format of a lakehouse table
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TableFormat {
+    /// Apache Iceberg (format version 2)
+    #[default]
+    Iceberg,
+    /// Delta Lake (reader version 1, writer version 2)
+    Delta,
+}
+
+impl fmt::Display for TableFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Iceberg => write!(f, "iceberg"),
+            Self::Delta => write!(f, "delta"),
+        }
+    }
+}
+
+impl FromStr for TableFormat {
+    type Err = LinkMLError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "iceberg" => Ok(Self::Iceberg),
+            "delta" | "delta_lake" | "deltalake" => Ok(Self::Delta),
+            other => Err(LinkMLError::config(format!(
+                "Unknown table format '{other}' (expected iceberg or delta)"
+            ))),
+        }
+    }
+}
+
+/// Column type of a lakehouse table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColumnType {
+    /// UTF-8 string; also used for enums, references and nested objects
+    String,
+    /// 64-bit signed integer
+    Long,
+    /// 64-bit float
+    Double,
+    /// Boolean
+    Boolean,
+    /// Calendar date
+    Date,
+    /// Timestamp with time zone, microsecond precision
+    Timestamp,
+    /// List of values of the inner type
+    List(Box<ColumnType>),
+}
+
+impl ColumnType {
+    /// Column type for a slot's range and cardinality
+    pub fn for_slot(slot: &SlotDefinition, schema: &SchemaDefinition) -> Self {
+        let base = Self::for_range(slot.range.as_deref().unwrap_or("string"), schema);
+        if slot.multivalued.unwrap_or(false) {
+            Self::List(Box::new(base))
+        } else {
+            base
+        }
+    }
+
+    /// Column type for a range, following custom types to their base type
+    pub fn for_range(range: &str, schema: &SchemaDefinition) -> Self {
+        let mut current = range;
+        let mut seen = HashSet::new();
+        loop {
+            match current {
+                "integer" | "int" | "long" => return Self::Long,
+                "float" | "double" | "decimal" => return Self::Double,
+                "boolean" | "bool" => return Self::Boolean,
+                "date" => return Self::Date,
+                "datetime" => return Self::Timestamp,
+                _ => {}
+            }
+            let parent = schema
+                .types
+                .get(current)
+                .and_then(|type_def| type_def.base_type.as_deref());
+            match parent {
+                Some(parent) if seen.insert(current) => current = parent,
+                _ => return Self::String,
+            }
+        }
+    }
+
+    /// Whether values of this type can be used as partition values
+    pub fn is_partitionable(&self) -> bool {
+        matches!(self, Self::String | Self::Long | Self::Boolean | Self::Date)
+    }
+}
+
+impl fmt::Display for ColumnType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String => write!(f, "string"),
+            Self::Long => write!(f, "long"),
+            Self::Double => write!(f, "double"),
+            Self::Boolean => write!(f, "boolean"),
+            Self::Date => write!(f, "date"),
+            Self::Timestamp => write!(f, "timestamp"),
+            Self::List(inner) => write!(f, "list<{inner}>"),
+        }
+    }
+}
+
+/// Column of a lakehouse table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableColumn {
+    /// Field id; stable across schema versions
+    pub id: i32,
+
+    /// Column name (the slot name)
+    pub name: String,
+
+    /// Column type
+    pub column_type: ColumnType,
+
+    /// Field id of list elements, for list columns
+    pub element_id: Option<i32>,
+
+    /// Whether every row has a value
+    pub required: bool,
+
+    /// Column documentation from the slot description
+    pub doc: Option<String>,
+}
+
+/// Column layout of a lakehouse table
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TableSchema {
+    /// Columns in table order
+    pub columns: Vec<TableColumn>,
+}
+
+impl TableSchema {
+    /// Derive the table layout of a class: one column per induced slot,
+    /// including inherited slots and attributes
+    pub fn from_class(schema: &SchemaDefinition, class_name: &str) -> Result<Self> {
+        if !schema.classes.contains_key(class_name) {
+            return Err(LinkMLError::schema_validation(format!(
+                "Class '{class_name}' not found in schema"
+            )));
+        }
+        let mut table = Self::default();
+        for (name, slot) in induced_slots(schema, class_name) {
+            table.add_column(
+                &name,
+                ColumnType::for_slot(&slot, schema),
+                slot.required.unwrap_or(false),
+                slot.description.clone(),
+            );
+        }
+        Ok(table)
+    }
+
+    /// Look up a column by name
+    pub fn column(&self, name: &str) -> Option<&TableColumn> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+
+    /// Highest field id in use, including list element ids
+    pub fn last_column_id(&self) -> i32 {
+        self.columns
+            .iter()
+            .map(|column| column.element_id.unwrap_or(column.id).max(column.id))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Append a column, allocating fresh field ids
+    pub fn add_column(
+        &mut self,
+        name: &str,
+        column_type: ColumnType,
+        required: bool,
+        doc: Option<String>,
+    ) {
+        let id = self.last_column_id() + 1;
+        let element_id = matches!(column_type, ColumnType::List(_)).then_some(id + 1);
+        self.columns.push(TableColumn {
+            id,
+            name: name.to_string(),
+            column_type,
+            element_id,
+            required,
+            doc,
+        });
+    }
+}
+
+/// Options for appending to a lakehouse table
+#[derive(Debug, Clone)]
+pub struct TableWriteOptions {
+    /// Table format used when the table is created
+    pub format: TableFormat,
+
+    /// Class whose instances are written (defaults to the tree root)
+    pub class_name: Option<String>,
+
+    /// Identity partition columns, used when the table is created
+    pub partition_by: Vec<String>,
+
+    /// Validate instances before writing; invalid data aborts the commit
+    pub validate: bool,
+
+    /// Extra table properties
+    pub properties: HashMap<String, String>,
+
+    /// Commit timestamp in milliseconds since the epoch (defaults to now)
+    pub commit_timestamp_ms: Option<i64>,
+}
+
+impl Default for TableWriteOptions {
+    fn default() -> Self {
+        Self {
+            format: TableFormat::default(),
+            class_name: None,
+            partition_by: Vec::new(),
+            validate: true,
+            properties: HashMap::new(),
+            commit_timestamp_ms: None,
+        }
+    }
+}
+
+impl TableWriteOptions {
+    /// Set the table format
+    #[must_use]
+    pub fn with_format(mut self, format: TableFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Set the class whose instances are written
+    #[must_use]
+    pub fn with_class(mut self, class_name: impl Into<String>) -> Self {
+        self.class_name = Some(class_name.into());
+        self
+    }
+
+    /// Set the partition columns
+    #[must_use]
+    pub fn with_partition_by(mut self, columns: Vec<String>) -> Self {
+        self.partition_by = columns;
+        self
+    }
+
+    /// Enable or disable validation
+    #[must_use]
+    pub fn with_validation(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Add a table property
+    #[must_use]
+    pub fn with_property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the commit timestamp
+    #[must_use]
+    pub fn with_commit_timestamp(mut self, timestamp_ms: i64) -> Self {
+        self.commit_timestamp_ms = Some(timestamp_ms);
+        self
+    }
+}
+
+/// Result of a table commit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableCommit {
+    /// Table format
+    pub format: TableFormat,
+
+    /// Table version after the commit (Delta log version or Iceberg metadata version)
+    pub version: i64,
+
+    /// Whether the commit created the table
+    pub created: bool,
+
+    /// Data files added, relative to the table location
+    pub data_files: Vec<String>,
+
+    /// Number of records written
+    pub record_count: u64,
+
+    /// Schema changes applied by the commit
+    pub schema_changes: Vec<ColumnChange>,
+}
+
+/// State of an existing table, as read from its metadata
+#[derive(Debug, Clone)]
+pub(crate) struct TableState {
+    /// Current table version
+    pub version: i64,
+    /// Current table schema
+    pub schema: TableSchema,
+    /// Identity partition columns
+    pub partition_by: Vec<String>,
+    /// Table properties
+    pub properties: HashMap<String, String>,
+    /// Format-specific metadata carried into the next commit
+    pub raw: serde_json::Value,
+}
+
+impl TableState {
+    /// `LinkML` schema recorded by the latest commit
+    fn linkml_schema(&self) -> Option<SchemaDefinition> {
+        self.properties
+            .get(LINKML_SCHEMA_PROPERTY)
+            .and_then(|json| serde_json::from_str(json).ok())
+    }
+}
+
+/// Everything a format needs to record a commit
+pub(crate) struct CommitContext<'a> {
+    pub state: Option<&'a TableState>,
+    pub schema: &'a TableSchema,
+    pub schema_changed: bool,
+    pub partition_by: &'a [String],
+    pub files: &'a [parquet_io::WrittenFile],
+    pub properties: HashMap<String, String>,
+    pub timestamp_ms: i64,
+}
+
+/// A lakehouse table on the local filesystem
+#[derive(Debug, Clone)]
+pub struct LakehouseTable {
+    location: PathBuf,
+    format: TableFormat,
+}
+
+impl LakehouseTable {
+    /// Table at `location` in the given format
+    pub fn new(location: impl Into<PathBuf>, format: TableFormat) -> Self {
+        Self {
+            location: location.into(),
+            format,
+        }
+    }
+
+    /// Table root directory
+    pub fn location(&self) -> &Path {
+        &self.location
+    }
+
+    /// Table format
+    pub fn format(&self) -> TableFormat {
+        self.format
+    }
+
+    /// Whether the table has been created
+    pub fn exists(&self) -> Result<bool> {
+        Ok(self.read_state()?.is_some())
+    }
+
+    /// Current table schema, if the table exists
+    pub fn schema(&self) -> Result<Option<TableSchema>> {
+        Ok(self.read_state()?.map(|state| state.schema))
+    }
+
+    fn read_state(&self) -> Result<Option<TableState>> {
+        match self.format {
+            TableFormat::Iceberg => iceberg::read_state(&self.location),
+            TableFormat::Delta => delta::read_state(&self.location),
+        }
+    }
+
+    /// Validate and append instances of one class as a single commit
+    pub async fn append(
+        &self,
+        instances: &[DataInstance],
+        schema: &SchemaDefinition,
+        options: &TableWriteOptions,
+    ) -> Result<TableCommit> {
+        let class_name = target_class(instances, schema, options)?;
+        let rows: Vec<&DataInstance> = instances
+            .iter()
+            .filter(|instance| instance.class_name.is_empty() || instance.class_name == class_name)
+            .collect();
+        if options.validate {
+            validate_rows(&rows, schema, &class_name).await?;
+        }
+
+        let incoming = TableSchema::from_class(schema, &class_name)?;
+        let state = self.read_state()?;
+        let (table_schema, changes, partition_by) = match &state {
+            None => (incoming, Vec::new(), options.partition_by.clone()),
+            Some(state) => {
+                if !options.partition_by.is_empty() && options.partition_by != state.partition_by {
+                    return Err(LinkMLError::config(format!(
+                        "Table is partitioned by [{}]; partition evolution is not supported",
+                        state.partition_by.join(", ")
+                    )));
+                }
+                let previous = state.linkml_schema();
+                let changes = plan_evolution(
+                    previous.as_ref(),
+                    schema,
+                    &class_name,
+                    &state.schema,
+                    &incoming,
+                )?;
+                let evolved = evolution::apply(&state.schema, &changes);
+                (evolved, changes, state.partition_by.clone())
+            }
+        };
+        check_partition_columns(&table_schema, &partition_by)?;
+
+        let files = parquet_io::write_partitioned(
+            &self.location,
+            &table_schema,
+            &partition_by,
+            &rows,
+            self.format == TableFormat::Iceberg,
+        )?;
+
+        let mut properties = state
+            .as_ref()
+            .map(|state| state.properties.clone())
+            .unwrap_or_default();
+        properties.extend(options.properties.clone());
+        properties.insert(LINKML_CLASS_PROPERTY.to_string(), class_name);
+        properties.insert(
+            LINKML_SCHEMA_PROPERTY.to_string(),
+            serde_json::to_string(schema)?,
+        );
+
+        let context = CommitContext {
+            state: state.as_ref(),
+            schema: &table_schema,
+            schema_changed: !changes.is_empty(),
+            partition_by: &partition_by,
+            files: &files,
+            properties,
+            timestamp_ms: options
+                .commit_timestamp_ms
+                .unwrap_or_else(|| chrono::Utc::now().timestamp_millis()),
+        };
+        let version = match self.format {
+            TableFormat::Iceberg => iceberg::commit(&self.location, &context)?,
+            TableFormat::Delta => delta::commit(&self.location, &context)?,
+        };
+
+        Ok(TableCommit {
+            format: self.format,
+            version,
+            created: state.is_none(),
+            data_files: files.iter().map(|file| file.path.clone()).collect(),
+            record_count: files.iter().map(|file| file.record_count).sum(),
+            schema_changes: changes,
+        })
+    }
+}
+
+/// Data dumper writing instances to an Iceberg or Delta table directory
+#[derive(Debug, Clone, Default)]
+pub struct LakehouseDumper {
+    options: TableWriteOptions,
+}
+
+impl LakehouseDumper {
+    /// Create a dumper with the given write options
+    pub fn new(options: TableWriteOptions) -> Self {
+        Self { options }
+    }
+}
+
+#[async_trait]
+impl DataDumper for LakehouseDumper {
+    fn name(&self) -> &'static str {
+        match self.options.format {
+            TableFormat::Iceberg => "iceberg",
+            TableFormat::Delta => "delta",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        "Append validated instances to an Iceberg or Delta Lake table"
+    }
+
+    fn supported_extensions(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    async fn dump_file(
+        &self,
+        instances: &[DataInstance],
+        path: &Path,
+        schema: &SchemaDefinition,
+        options: &DumpOptions,
+    ) -> DumperResult<()> {
+        let limit = options
+            .limit
+            .unwrap_or(instances.len())
+            .min(instances.len());
+        LakehouseTable::new(path, self.options.format)
+            .append(&instances[..limit], schema, &self.options)
+            .await
+            .map(|_| ())
+            .map_err(|err| DumperError::Other(Box::new(err)))
+    }
+
+    async fn dump_string(
+        &self,
+        _instances: &[DataInstance],
+        _schema: &SchemaDefinition,
+        _options: &DumpOptions,
+    ) -> DumperResult<String> {
+        Err(DumperError::Configuration(
+            "Lakehouse tables are directories; use dump_file with the table location".to_string(),
+        ))
+    }
+
+    async fn dump_bytes(
+        &self,
+        _instances: &[DataInstance],
+        _schema: &SchemaDefinition,
+        _options: &DumpOptions,
+    ) -> DumperResult<Vec<u8>> {
+        Err(DumperError::Configuration(
+            "Lakehouse tables are directories; use dump_file with the table location".to_string(),
+        ))
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> DumperResult<()> {
+        if let Some(class_name) = &self.options.class_name
+            && !schema.classes.contains_key(class_name)
+        {
+            return Err(DumperError::SchemaValidation(format!(
+                "Class '{class_name}' not found in schema"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Slots of a class with their effective definitions, most specific first
+fn induced_slots(schema: &SchemaDefinition, class_name: &str) -> Vec<(String, SlotDefinition)> {
+    let mut lineage = Vec::new();
+    let mut pending = vec![class_name.to_string()];
+    let mut seen = HashSet::new();
+    while let Some(name) = pending.pop() {
+        if !seen.insert(name.clone()) {
+            continue;
+        }
+        if let Some(class) = schema.classes.get(&name) {
+            // Mixins are pushed first so the parent is visited next
+            pending.extend(class.mixins.iter().rev().cloned());
+            pending.extend(class.is_a.clone());
+            lineage.push(class);
+        }
+    }
+
+    let mut slots: Vec<(String, SlotDefinition)> = Vec::new();
+    for class in &lineage {
+        let names = class.slots.iter().chain(class.attributes.keys());
+        for name in names {
+            if slots.iter().any(|(existing, _)| existing == name) {
+                continue;
+            }
+            let base = class
+                .attributes
+                .get(name)
+                .or_else(|| schema.slots.get(name))
+                .cloned()
+                .unwrap_or_default();
+            slots.push((name.clone(), base));
+        }
+    }
+
+    // slot_usage of the most specific class wins
+    for (name, slot) in &mut slots {
+        if let Some(usage) = lineage.iter().find_map(|class| class.slot_usage.get(name)) {
+            if usage.range.is_some() {
+                slot.range.clone_from(&usage.range);
+            }
+            if usage.required.is_some() {
+                slot.required = usage.required;
+            }
+            if usage.multivalued.is_some() {
+                slot.multivalued = usage.multivalued;
+            }
+        }
+    }
+    slots
+}
+
+fn target_class(
+    instances: &[DataInstance],
+    schema: &SchemaDefinition,
+    options: &TableWriteOptions,
+) -> Result<String> {
+    if let Some(class_name) = &options.class_name {
+        return Ok(class_name.clone());
+    }
+    let mut classes: Vec<&str> = instances
+        .iter()
+        .map(|instance| instance.class_name.as_str())
+        .filter(|name| !name.is_empty())
+        .collect();
+    classes.dedup();
+    match classes.as_slice() {
+        [single] => Ok((*single).to_string()),
+        [] => schema
+            .classes
+            .iter()
+            .find(|(_, class)| class.tree_root == Some(true))
+            .map(|(name, _)| name.clone())
+            .ok_or_else(|| {
+                LinkMLError::config("No target class given and the schema has no tree_root class")
+            }),
+        _ => Err(LinkMLError::config(
+            "Instances span several classes; set the target class explicitly",
+        )),
+    }
+}
+
+async fn validate_rows(
+    rows: &[&DataInstance],
+    schema: &SchemaDefinition,
+    class_name: &str,
+) -> Result<()> {
+    let engine = ValidationEngine::new(schema)?;
+    let mut invalid = 0;
+    let mut first_error = None;
+    for row in rows {
+        let value = serde_json::Value::Object(row.data.clone().into_iter().collect());
+        let report = engine
+            .validate_as_class(&value, class_name, Some(ValidationOptions::default()))
+            .await?;
+        if !report.valid {
+            invalid += 1;
+            if first_error.is_none() {
+                first_error = report.errors().next().map(|issue| issue.message.clone());
+            }
+        }
+    }
+    if invalid > 0 {
+        return Err(LinkMLError::data_validation(format!(
+            "{invalid} of {} instance(s) failed validation; nothing was written{}",
+            rows.len(),
+            first_error
+                .map(|message| format!(" (first error: {message})"))
+                .unwrap_or_default()
+        )));
+    }
+    Ok(())
+}
+
+fn check_partition_columns(table: &TableSchema, partition_by: &[String]) -> Result<()> {
+    for name in partition_by {
+        let column = table.column(name).ok_or_else(|| {
+            LinkMLError::config(format!(
+                "Partition column '{name}' is not a slot of the class"
+            ))
+        })?;
+        if !column.column_type.is_partitionable() {
+            return Err(LinkMLError::config(format!(
+                "Column '{name}' of type {} cannot be used for partitioning",
+                column.column_type
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::ClassDefinition;
+
+    pub(super) fn person_schema(extra_slot: bool) -> SchemaDefinition {
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/people".to_string(),
+            name: "people".to_string(),
+            version: Some(if extra_slot { "2.0.0" } else { "1.0.0" }.to_string()),
+            ..Default::default()
+        };
+        let mut person = ClassDefinition {
+            name: "Person".to_string(),
+            tree_root: Some(true),
+            slots: vec!["id".to_string(), "name".to_string(), "country".to_string()],
+            ..Default::default()
+        };
+        let slot = |name: &str, range: &str, required: bool| SlotDefinition {
+            name: name.to_string(),
+            range: Some(range.to_string()),
+            required: Some(required),
+            ..Default::default()
+        };
+        schema
+            .slots
+            .insert("id".to_string(), slot("id", "string", true));
+        schema
+            .slots
+            .insert("name".to_string(), slot("name", "string", true));
+        schema
+            .slots
+            .insert("country".to_string(), slot("country", "string", false));
+        if extra_slot {
+            person.slots.push("age".to_string());
+            schema
+                .slots
+                .insert("age".to_string(), slot("age", "integer", false));
+        }
+        schema.classes.insert("Person".to_string(), person);
+        schema
+    }
+
+    pub(super) fn person(id: &str, country: &str, age: Option<i64>) -> DataInstance {
+        let mut data = HashMap::new();
+        data.insert("id".to_string(), serde_json::json!(id));
+        data.insert(
+            "name".to_string(),
+            serde_json::json!(format!("Person {id}")),
+        );
+        data.insert("country".to_string(), serde_json::json!(country));
+        if let Some(age) = age {
+            data.insert("age".to_string(), serde_json::json!(age));
+        }
+        DataInstance {
+            class_name: "Person".to_string(),
+            data,
+            id: Some(id.to_string()),
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_table_schema_from_class() {
+        let mut schema = person_schema(false);
+        schema.slots.get_mut("name").expect("name slot").multivalued = Some(true);
+        let table = TableSchema::from_class(&schema, "Person").expect("table schema");
+
+        let names: Vec<_> = table.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["id", "name", "country"]);
+        let name = table.column("name").expect("name column");
+        assert_eq!(
+            name.column_type,
+            ColumnType::List(Box::new(ColumnType::String))
+        );
+        assert_eq!((name.id, name.element_id), (2, Some(3)));
+        assert_eq!(table.column("country").expect("country").id, 4);
+        assert_eq!(table.last_column_id(), 4);
+        assert!(TableSchema::from_class(&schema, "Missing").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_delta_append_and_evolve() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let table = LakehouseTable::new(dir.path(), TableFormat::Delta);
+        let options = TableWriteOptions::default()
+            .with_format(TableFormat::Delta)
+            .with_partition_by(vec!["country".to_string()])
+            .with_commit_timestamp(1_700_000_000_000);
+
+        let first = table
+            .append(
+                &[person("p1", "NL", None), person("p2", "DE", None)],
+                &person_schema(false),
+                &options,
+            )
+            .await
+            .expect("first commit");
+        assert!(first.created);
+        assert_eq!(first.version, 0);
+        assert_eq!(first.record_count, 2);
+        assert_eq!(first.data_files.len(), 2);
+        assert!(
+            first
+                .data_files
+                .iter()
+                .any(|f| f.starts_with("data/country=NL/"))
+        );
+
+        let second = table
+            .append(
+                &[person("p3", "NL", Some(42))],
+                &person_schema(true),
+                &options,
+            )
+            .await
+            .expect("second commit");
+        assert_eq!(second.version, 1);
+        assert_eq!(
+            second.schema_changes,
+            vec![ColumnChange::Added {
+                name: "age".to_string(),
+                column_type: ColumnType::Long,
+            }]
+        );
+        let schema = table.schema().expect("read schema").expect("table exists");
+        assert_eq!(schema.column("age").expect("age column").id, 4);
+        assert!(
+            dir.path()
+                .join("_delta_log/00000000000000000001.json")
+                .exists()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_iceberg_append_and_evolve() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let table = LakehouseTable::new(dir.path(), TableFormat::Iceberg);
+        let options = TableWriteOptions::default().with_commit_timestamp(1_700_000_000_000);
+
+        table
+            .append(&[person("p1", "NL", None)], &person_schema(false), &options)
+            .await
+            .expect("first commit");
+        let second = table
+            .append(
+                &[person("p2", "DE", Some(7))],
+                &person_schema(true),
+                &options,
+            )
+            .await
+            .expect("second commit");
+        assert_eq!(second.version, 2);
+        assert_eq!(second.schema_changes.len(), 1);
+
+        let state = iceberg::read_state(dir.path())
+            .expect("read metadata")
+            .expect("table exists");
+        assert_eq!(state.raw["schemas"].as_array().map(Vec::len), Some(2));
+        assert_eq!(state.raw["snapshots"].as_array().map(Vec::len), Some(2));
+        let manifests = iceberg::snapshot_manifests(dir.path(), &state.raw).expect("manifests");
+        assert_eq!(manifests.len(), 2);
+        assert_eq!(manifests.iter().map(|m| m.added_rows_count).sum::<i64>(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_incompatible_change_is_rejected() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let table = LakehouseTable::new(dir.path(), TableFormat::Delta);
+        let options = TableWriteOptions::default();
+        table
+            .append(&[person("p1", "NL", None)], &person_schema(false), &options)
+            .await
+            .expect("first commit");
+
+        let mut changed = person_schema(false);
+        changed.slots.get_mut("country").expect("country").range = Some("integer".to_string());
+        let err = table
+            .append(&[], &changed, &options.clone().with_validation(false))
+            .await
+            .expect_err("range change must be rejected");
+        assert!(err.to_string().contains("country"));
+    }
+}
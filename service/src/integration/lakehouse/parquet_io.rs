@@ -0,0 +1,230 @@
+//! Parquet data files for lakehouse tables
+
+use super::{ColumnType, TableColumn, TableSchema};
+use crate::loader::traits::DataInstance;
+use arrow_array::RecordBatch;
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use linkml_core::error::{LinkMLError, Result};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Partition value Hive and Delta use for nulls
+pub(super) const NULL_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// A data file written for a commit
+#[derive(Debug, Clone)]
+pub(crate) struct WrittenFile {
+    /// Path relative to the table root, with `/` separators
+    pub path: String,
+    /// Identity partition values, in partition column order
+    pub partition: Vec<(String, Option<String>)>,
+    /// Number of rows
+    pub record_count: u64,
+    /// File size in bytes
+    pub size_bytes: u64,
+}
+
+/// Arrow schema of a table; field ids are kept as Parquet field ids
+pub(super) fn arrow_schema(table: &TableSchema) -> Schema {
+    Schema::new(table.columns.iter().map(arrow_field).collect::<Vec<_>>())
+}
+
+fn arrow_field(column: &TableColumn) -> Field {
+    let data_type = match &column.column_type {
+        ColumnType::List(inner) => {
+            let mut element = Field::new("element", arrow_type(inner), true);
+            if let Some(element_id) = column.element_id {
+                element = element.with_metadata(field_id(element_id));
+            }
+            DataType::List(Arc::new(element))
+        }
+        other => arrow_type(other),
+    };
+    Field::new(&column.name, data_type, !column.required).with_metadata(field_id(column.id))
+}
+
+fn arrow_type(column_type: &ColumnType) -> DataType {
+    match column_type {
+        ColumnType::String => DataType::Utf8,
+        ColumnType::Long => DataType::Int64,
+        ColumnType::Double => DataType::Float64,
+        ColumnType::Boolean => DataType::Boolean,
+        ColumnType::Date => DataType::Date32,
+        ColumnType::Timestamp => DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+        ColumnType::List(inner) => {
+            DataType::List(Arc::new(Field::new("element", arrow_type(inner), true)))
+        }
+    }
+}
+
+fn field_id(id: i32) -> HashMap<String, String> {
+    HashMap::from([("PARQUET:field_id".to_string(), id.to_string())])
+}
+
+/// Write rows as one Parquet file per partition under `<root>/data`
+///
+/// Delta keeps partition values only in the log, so its data files leave
+/// the partition columns out; Iceberg data files contain every column.
+pub(super) fn write_partitioned(
+    root: &Path,
+    table: &TableSchema,
+    partition_by: &[String],
+    rows: &[&DataInstance],
+    include_partition_columns: bool,
+) -> Result<Vec<WrittenFile>> {
+    let mut groups: BTreeMap<Vec<Option<String>>, Vec<&DataInstance>> = BTreeMap::new();
+    for row in rows {
+        let key = partition_by
+            .iter()
+            .map(|column| row.data.get(column).and_then(partition_value))
+            .collect();
+        groups.entry(key).or_default().push(row);
+    }
+
+    let file_table = if include_partition_columns {
+        table.clone()
+    } else {
+        TableSchema {
+            columns: table
+                .columns
+                .iter()
+                .filter(|column| !partition_by.contains(&column.name))
+                .cloned()
+                .collect(),
+        }
+    };
+    let schema = Arc::new(arrow_schema(&file_table));
+    let batch_id = uuid::Uuid::new_v4();
+    let mut files = Vec::with_capacity(groups.len());
+    for (index, (values, group)) in groups.into_iter().enumerate() {
+        let mut segments: Vec<String> = partition_by
+            .iter()
+            .zip(&values)
+            .map(|(column, value)| {
+                format!(
+                    "{}={}",
+                    escape_segment(column),
+                    escape_segment(value.as_deref().unwrap_or(NULL_PARTITION))
+                )
+            })
+            .collect();
+        segments.push(format!("part-{index:05}-{batch_id}.parquet"));
+        let relative = segments.join("/");
+
+        let batch = record_batch(&schema, &file_table, &group)?;
+        let size_bytes = write_parquet(&root.join("data").join(&relative), &schema, &batch)?;
+        files.push(WrittenFile {
+            path: format!("data/{relative}"),
+            partition: partition_by.iter().cloned().zip(values).collect(),
+            record_count: group.len() as u64,
+            size_bytes,
+        });
+    }
+    Ok(files)
+}
+
+fn record_batch(
+    schema: &Arc<Schema>,
+    table: &TableSchema,
+    rows: &[&DataInstance],
+) -> Result<RecordBatch> {
+    let records: Vec<Map<String, Value>> = rows
+        .iter()
+        .map(|row| {
+            table
+                .columns
+                .iter()
+                .filter_map(|column| {
+                    let value = row.data.get(&column.name)?;
+                    Some((column.name.clone(), coerce(value, &column.column_type)))
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut decoder = arrow_json::ReaderBuilder::new(schema.clone())
+        .build_decoder()
+        .map_err(arrow_error)?;
+    decoder.serialize(&records).map_err(arrow_error)?;
+    Ok(decoder
+        .flush()
+        .map_err(arrow_error)?
+        .unwrap_or_else(|| RecordBatch::new_empty(schema.clone())))
+}
+
+fn write_parquet(path: &Path, schema: &Arc<Schema>, batch: &RecordBatch) -> Result<u64> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = std::fs::File::create(path)?;
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer =
+        ArrowWriter::try_new(file, schema.clone(), Some(properties)).map_err(parquet_error)?;
+    writer.write(batch).map_err(parquet_error)?;
+    writer.close().map_err(parquet_error)?;
+    Ok(std::fs::metadata(path)?.len())
+}
+
+/// Bring loader values into the shape the column type expects
+fn coerce(value: &Value, column_type: &ColumnType) -> Value {
+    match (column_type, value) {
+        (_, Value::Null) => Value::Null,
+        (ColumnType::List(inner), Value::Array(items)) => {
+            Value::Array(items.iter().map(|item| coerce(item, inner)).collect())
+        }
+        (ColumnType::List(inner), single) => Value::Array(vec![coerce(single, inner)]),
+        (ColumnType::String, Value::String(_)) => value.clone(),
+        (ColumnType::String, other) => Value::String(other.to_string()),
+        (ColumnType::Long, Value::String(text)) => text
+            .trim()
+            .parse::<i64>()
+            .map_or_else(|_| value.clone(), Value::from),
+        (ColumnType::Double, Value::String(text)) => text
+            .trim()
+            .parse::<f64>()
+            .map_or_else(|_| value.clone(), Value::from),
+        (ColumnType::Boolean, Value::String(text)) => match text.to_ascii_lowercase().as_str() {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => value.clone(),
+        },
+        _ => value.clone(),
+    }
+}
+
+/// String form of a partition value; `None` for nulls
+fn partition_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(text.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Percent-encode characters that are unsafe in a path segment
+fn escape_segment(segment: &str) -> String {
+    let mut escaped = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.') {
+            escaped.push(char::from(byte));
+        } else {
+            escaped.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    escaped
+}
+
+fn arrow_error(err: arrow_schema::ArrowError) -> LinkMLError {
+    LinkMLError::serialization(format!("Failed to build record batch: {err}"))
+}
+
+fn parquet_error(err: parquet::errors::ParquetError) -> LinkMLError {
+    LinkMLError::serialization(format!("Failed to write Parquet file: {err}"))
+}
@@ -2,6 +2,8 @@
 
 pub mod cache_adapter;
 pub mod iceberg_integration;
+#[cfg(feature = "lakehouse")]
+pub mod lakehouse;
 pub mod typedb_integration;
 
 pub use cache_adapter::CacheServiceAdapter;
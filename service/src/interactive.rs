@@ -3,13 +3,19 @@
 //! This module provides an interactive REPL for:
 //! - Loading and reloading schemas
 //! - Validating data interactively
-//! - Exploring schema structure
-//! - Testing validation rules
-//! - Debugging validation issues
-
+//! - Exploring schema structure: classes, slots and the constraints a class
+//!   induces on its slots through inheritance and `slot_usage`
+//! - Validating pasted `JSON` snippets against a chosen class
+//! - Debugging validation issues: `explain` shows which validator reported
+//!   each issue and the effective constraint of the slot at its path
+
+use crate::generator::base::induced_slots;
+use crate::inheritance::InheritanceResolver;
+use crate::validator::explain::{self, slot_at_path};
+use crate::validator::{ValidationEngine, ValidationIssue, ValidationReport};
 use colored::Colorize;
 use linkml_core::error::LinkMLError;
-use linkml_core::types::SchemaDefinition;
+use linkml_core::types::{SchemaDefinition, SlotDefinition};
 use rustyline::Helper;
 use rustyline::completion::{Completer, FilenameCompleter, Pair};
 use rustyline::error::ReadlineError;
@@ -32,6 +38,8 @@ pub struct InteractiveSession<S> {
     current_schema: Option<String>,
     /// Validation history
     history: Vec<ValidationHistoryEntry>,
+    /// Most recent validation, for `explain`
+    last_validation: Option<LastValidation>,
     /// Session configuration
     config: InteractiveConfig,
     /// `LinkML` service
@@ -85,6 +93,16 @@ struct ValidationHistoryEntry {
     timestamp: chrono::DateTime<chrono::Local>,
 }
 
+/// Most recent validation of a session
+struct LastValidation {
+    /// Schema used
+    schema_name: String,
+    /// Class the data was validated as
+    class_name: String,
+    /// Validation report
+    report: ValidationReport,
+}
+
 /// Interactive commands
 #[derive(Debug, Clone)]
 enum Command {
@@ -113,6 +131,14 @@ enum Command {
     Type { name: String },
     /// Show enum details
     Enum { name: String },
+    /// List classes, optionally matching a pattern
+    Classes { pattern: Option<String> },
+    /// List the slots of the schema or of a class
+    Slots { class: Option<String> },
+    /// Show the induced constraints of the slots of a class
+    Induced { class: String, slot: Option<String> },
+    /// Explain the issues of the last validation
+    Explain { issue: Option<usize> },
     /// Search in schema
     Search { pattern: String },
     /// Show validation history
@@ -136,6 +162,7 @@ impl<S: linkml_core::traits::LinkMLService> InteractiveSession<S> {
             schemas: HashMap::new(),
             current_schema: None,
             history: Vec::new(),
+            last_validation: None,
             config,
             service,
             timestamp_service,
@@ -183,7 +210,7 @@ impl<S: linkml_core::traits::LinkMLService> InteractiveSession<S> {
                 Ok(line) => {
                     let _ = rl.add_history_entry(&line);
 
-                    match self.parse_command(&line) {
+                    match parse_command(&line) {
                         Ok(Command::Quit) => break,
                         Ok(cmd) => {
                             if let Err(e) = self.execute_command(cmd).await {
@@ -214,146 +241,6 @@ impl<S: linkml_core::traits::LinkMLService> InteractiveSession<S> {
         Ok(())
     }
 
-    /// Parse command from input
-    fn parse_command(&self, input: &str) -> crate::Result<Command> {
-        let parts: Vec<&str> = input.split_whitespace().collect();
-
-        if parts.is_empty() {
-            return Err(LinkMLError::service("Empty command"));
-        }
-
-        let first_part = parts
-            .first()
-            .ok_or_else(|| LinkMLError::service("Empty command".to_string()))?;
-
-        match first_part.to_lowercase().as_str() {
-            "load" => {
-                if parts.len() < 2 {
-                    return Err(LinkMLError::service("Usage: load <path> [name]"));
-                }
-                let path_str = parts
-                    .get(1)
-                    .ok_or_else(|| LinkMLError::service("Missing path argument"))?;
-                Ok(Command::Load {
-                    path: PathBuf::from(path_str),
-                    name: parts.get(2).map(|s| (*s).to_string()),
-                })
-            }
-
-            "reload" => Ok(Command::Reload),
-
-            "list" | "ls" => Ok(Command::List),
-
-            "use" => {
-                if parts.len() < 2 {
-                    return Err(LinkMLError::service("Usage: use <schema-name>"));
-                }
-                let name = parts
-                    .get(1)
-                    .ok_or_else(|| LinkMLError::service("Missing schema name argument"))?;
-                Ok(Command::Use {
-                    name: (*name).to_string(),
-                })
-            }
-
-            "validate" | "v" => {
-                if parts.len() < 2 {
-                    return Err(LinkMLError::service("Usage: validate <json> [class]"));
-                }
-
-                let json_str = if parts.len() > 1 {
-                    parts[1..].join(" ")
-                } else {
-                    return Err(LinkMLError::service("Missing JSON data"));
-                };
-                let data = serde_json::from_str(&json_str)
-                    .map_err(|e| LinkMLError::service(format!("Invalid JSON: {e}")))?;
-
-                Ok(Command::Validate {
-                    data,
-                    class: None, // Would parse class from command
-                })
-            }
-
-            "validate-file" | "vf" => {
-                if parts.len() < 2 {
-                    return Err(LinkMLError::service("Usage: validate-file <path> [class]"));
-                }
-                let path_str = parts
-                    .get(1)
-                    .ok_or_else(|| LinkMLError::service("Missing file path argument"))?;
-                Ok(Command::ValidateFile {
-                    path: PathBuf::from(path_str),
-                    class: parts.get(2).map(|s| (*s).to_string()),
-                })
-            }
-
-            "info" | "i" => Ok(Command::Info {
-                item: parts.get(1).map(|s| (*s).to_string()),
-            }),
-
-            "class" | "c" => {
-                if parts.len() < 2 {
-                    return Err(LinkMLError::service("Usage: class <name>"));
-                }
-                Ok(Command::Class {
-                    name: parts[1].to_string(),
-                })
-            }
-
-            "slot" | "s" => {
-                if parts.len() < 2 {
-                    return Err(LinkMLError::service("Usage: slot <name>"));
-                }
-                Ok(Command::Slot {
-                    name: parts[1].to_string(),
-                })
-            }
-
-            "type" | "t" => {
-                if parts.len() < 2 {
-                    return Err(LinkMLError::service("Usage: type <name>"));
-                }
-                Ok(Command::Type {
-                    name: parts[1].to_string(),
-                })
-            }
-
-            "enum" | "e" => {
-                if parts.len() < 2 {
-                    return Err(LinkMLError::service("Usage: enum <name>"));
-                }
-                Ok(Command::Enum {
-                    name: parts[1].to_string(),
-                })
-            }
-
-            "search" => {
-                if parts.len() < 2 {
-                    return Err(LinkMLError::service("Usage: search <pattern>"));
-                }
-                Ok(Command::Search {
-                    pattern: parts[1..].join(" "),
-                })
-            }
-
-            "history" | "h" => Ok(Command::History {
-                count: parts.get(1).and_then(|s| s.parse().ok()),
-            }),
-
-            "clear" | "cls" => Ok(Command::Clear),
-
-            "help" | "?" => Ok(Command::Help),
-
-            "quit" | "exit" | "q" => Ok(Command::Quit),
-
-            _ => Err(LinkMLError::service(format!(
-                "Unknown command: {}",
-                parts[0]
-            ))),
-        }
-    }
-
     /// Execute command
     async fn execute_command(&mut self, command: Command) -> crate::Result<()> {
         match command {
@@ -401,6 +288,22 @@ impl<S: linkml_core::traits::LinkMLService> InteractiveSession<S> {
                 self.show_enum(&name)?;
             }
 
+            Command::Classes { pattern } => {
+                self.list_classes(pattern.as_deref())?;
+            }
+
+            Command::Slots { class } => {
+                self.list_slots(class.as_deref())?;
+            }
+
+            Command::Induced { class, slot } => {
+                self.show_induced(&class, slot.as_deref())?;
+            }
+
+            Command::Explain { issue } => {
+                self.explain(issue)?;
+            }
+
             Command::Search { pattern } => {
                 self.search_schema(&pattern)?;
             }
@@ -510,53 +413,67 @@ impl<S: linkml_core::traits::LinkMLService> InteractiveSession<S> {
     async fn validate_data(&mut self, data: &Value, class_name: Option<&str>) -> crate::Result<()> {
         let schema_name = self
             .current_schema
-            .as_ref()
+            .clone()
             .ok_or_else(|| LinkMLError::service("No schema loaded"))?;
-
-        let schema = self
-            .schemas
-            .get(schema_name)
-            .ok_or_else(|| LinkMLError::service("Schema not found"))?;
+        let schema = self.get_current_schema()?;
 
         println!("Validating data...");
 
         let start = std::time::Instant::now();
-        let class_name = class_name.unwrap_or("Root"); // Default to Root class
-        let report = self.service.validate(data, schema, class_name).await?;
+        let engine = ValidationEngine::new(schema)?;
+        let report = match class_name {
+            Some(class_name) => {
+                if !schema.classes.contains_key(class_name) {
+                    return Err(LinkMLError::service(format!(
+                        "Class '{class_name}' not found"
+                    )));
+                }
+                engine.validate_as_class(data, class_name, None).await?
+            }
+            // Inferred from a type designator or the tree root
+            None => engine.validate(data, None).await?,
+        };
         let duration = start.elapsed();
 
         // Display results
+        let class_name = report.target_class.clone().unwrap_or_default();
         if report.valid {
             println!(
-                "{} Validation {} ({:.2}ms)",
+                "{} Validation {} as {} ({:.2}ms)",
                 "✓".green(),
                 "PASSED".green().bold(),
+                class_name,
                 duration.as_secs_f64() * 1000.0
             );
         } else {
             println!(
-                "{} Validation {} ({:.2}ms)",
+                "{} Validation {} as {} ({:.2}ms)",
                 "✗".red(),
                 "FAILED".red().bold(),
+                class_name,
                 duration.as_secs_f64() * 1000.0
             );
-
+        }
+        if !report.issues.is_empty() {
             println!(
                 "
 {}",
                 "Issues:".yellow()
             );
-            for (i, error) in report.errors.iter().enumerate() {
-                let severity = "ERROR".red();
-
+            for (i, issue) in report.issues.iter().enumerate() {
                 println!(
                     "  {}. [{}] {}: {}",
                     i + 1,
-                    severity,
-                    error.path.as_deref().unwrap_or(""),
-                    error.message
+                    issue.severity.to_string().red(),
+                    issue.path,
+                    issue.message
                 );
             }
+            println!(
+                "
+Use '{}' to see which validator reported an issue",
+                "explain [n]".green()
+            );
         }
 
         // Add to history
@@ -568,9 +485,14 @@ impl<S: linkml_core::traits::LinkMLService> InteractiveSession<S> {
             schema_name: schema_name.clone(),
             data: data.clone(),
             valid: report.valid,
-            issue_count: report.errors.len() + report.warnings.len(),
+            issue_count: report.issues.len(),
             timestamp: local_timestamp,
         });
+        self.last_validation = Some(LastValidation {
+            schema_name,
+            class_name,
+            report,
+        });
 
         Ok(())
     }
@@ -735,6 +657,114 @@ impl<S: linkml_core::traits::LinkMLService> InteractiveSession<S> {
         }
     }
 
+    /// List classes
+    fn list_classes(&self, pattern: Option<&str>) -> crate::Result<()> {
+        let schema = self.get_current_schema()?;
+        let lines = class_listing(schema, pattern);
+        if lines.is_empty() {
+            println!("No classes found");
+        } else {
+            println!("{}", "Classes:".bold());
+            for line in lines {
+                println!("  {line}");
+            }
+        }
+        Ok(())
+    }
+
+    /// List the slots of the schema or of a class
+    fn list_slots(&self, class_name: Option<&str>) -> crate::Result<()> {
+        let schema = self.get_current_schema()?;
+        let names: Vec<String> = match class_name {
+            Some(class_name) => {
+                if !schema.classes.contains_key(class_name) {
+                    return Err(LinkMLError::service(format!(
+                        "Class '{class_name}' not found"
+                    )));
+                }
+                induced_slots(schema, class_name)
+                    .into_iter()
+                    .map(|slot| slot.name)
+                    .collect()
+            }
+            None => schema.slots.keys().cloned().collect(),
+        };
+        if names.is_empty() {
+            println!("No slots found");
+        } else {
+            println!("{}", "Slots:".bold());
+            for name in names {
+                println!("  - {name}");
+            }
+        }
+        Ok(())
+    }
+
+    /// Show the induced constraints of the slots of a class
+    fn show_induced(&self, class_name: &str, slot_name: Option<&str>) -> crate::Result<()> {
+        let schema = self.get_current_schema()?;
+        println!("{}", format!("Induced slots of {class_name}:").bold());
+        for (name, constraints) in induced_constraints(schema, class_name, slot_name)? {
+            if constraints.is_empty() {
+                println!("  {} {}", "•".green(), name);
+            } else {
+                println!("  {} {}: {}", "•".green(), name, constraints.join(", "));
+            }
+        }
+        Ok(())
+    }
+
+    /// Explain the issues of the last validation
+    fn explain(&self, issue: Option<usize>) -> crate::Result<()> {
+        let last = self
+            .last_validation
+            .as_ref()
+            .ok_or_else(|| LinkMLError::service("Nothing validated yet"))?;
+        let schema = self
+            .schemas
+            .get(&last.schema_name)
+            .ok_or_else(|| LinkMLError::service("Schema not found"))?;
+        if last.report.issues.is_empty() {
+            println!("The last validation reported no issues");
+            return Ok(());
+        }
+
+        let selected: Vec<(usize, &ValidationIssue)> = match issue {
+            Some(number) => {
+                let selected = number
+                    .checked_sub(1)
+                    .and_then(|index| last.report.issues.get(index))
+                    .ok_or_else(|| {
+                        LinkMLError::service(format!(
+                            "No issue {number}; the last validation reported {}",
+                            last.report.issues.len()
+                        ))
+                    })?;
+                vec![(number, selected)]
+            }
+            None => last
+                .report
+                .issues
+                .iter()
+                .enumerate()
+                .map(|(index, issue)| (index + 1, issue))
+                .collect(),
+        };
+        for (number, issue) in selected {
+            println!(
+                "{}. [{}] {}: {}",
+                number,
+                issue.severity.to_string().red(),
+                issue.path,
+                issue.message
+            );
+            for line in explain_issue(schema, &last.class_name, issue) {
+                println!("   {line}");
+            }
+        }
+        Ok(())
+    }
+
     /// Search in schema
     fn search_schema(&self, pattern: &str) -> crate::Result<()> {
         let schema = self.get_current_schema()?;
@@ -827,7 +857,7 @@ impl<S: linkml_core::traits::LinkMLService> InteractiveSession<S> {
         );
         println!("  {} <name>            Switch to a schema", "use".green());
         println!(
-            "  {} <json> [class]    Validate JSON data",
+            "  {} [class] <json>    Validate JSON data, pasted over one or more lines",
             "validate".green()
         );
         println!(
@@ -842,6 +872,19 @@ impl<S: linkml_core::traits::LinkMLService> InteractiveSession<S> {
         println!("  {} <name>            Show slot details", "slot".green());
         println!("  {} <name>            Show type details", "type".green());
         println!("  {} <name>            Show enum details", "enum".green());
+        println!("  {} [pattern]       List classes", "classes".green());
+        println!(
+            "  {} [class]           List the slots of the schema or a class",
+            "slots".green()
+        );
+        println!(
+            "  {} <class> [slot]  Show induced slot constraints",
+            "induced".green()
+        );
+        println!(
+            "  {} [n]             Explain issues of the last validation",
+            "explain".green()
+        );
         println!("  {} <pattern>         Search in schema", "search".green());
         println!(
             "  {} [count]           Show validation history",
@@ -865,7 +908,7 @@ impl<S: linkml_core::traits::LinkMLService> InteractiveSession<S> {
         );
         println!();
         println!(
-            "Shortcuts: v=validate, vf=validate-file, i=info, c=class, s=slot, t=type, e=enum, h=history"
+            "Shortcuts: v=validate, vf=validate-file, i=info, c=class, s=slot, t=type, e=enum, h=history, why=explain"
         );
     }
 
@@ -882,6 +925,325 @@ impl<S: linkml_core::traits::LinkMLService> InteractiveSession<S> {
     }
 }
 
+/// Parse command from input
+fn parse_command(input: &str) -> crate::Result<Command> {
+    let parts: Vec<&str> = input.split_whitespace().collect();
+
+    if parts.is_empty() {
+        return Err(LinkMLError::service("Empty command"));
+    }
+
+    let first_part = parts
+        .first()
+        .ok_or_else(|| LinkMLError::service("Empty command".to_string()))?;
+
+    match first_part.to_lowercase().as_str() {
+        "load" => {
+            if parts.len() < 2 {
+                return Err(LinkMLError::service("Usage: load <path> [name]"));
+            }
+            let path_str = parts
+                .get(1)
+                .ok_or_else(|| LinkMLError::service("Missing path argument"))?;
+            Ok(Command::Load {
+                path: PathBuf::from(path_str),
+                name: parts.get(2).map(|s| (*s).to_string()),
+            })
+        }
+
+        "reload" => Ok(Command::Reload),
+
+        "list" | "ls" => Ok(Command::List),
+
+        "use" => {
+            if parts.len() < 2 {
+                return Err(LinkMLError::service("Usage: use <schema-name>"));
+            }
+            let name = parts
+                .get(1)
+                .ok_or_else(|| LinkMLError::service("Missing schema name argument"))?;
+            Ok(Command::Use {
+                name: (*name).to_string(),
+            })
+        }
+
+        "validate" | "v" => parse_validate(input.trim_start()[first_part.len()..].trim()),
+
+        "validate-file" | "vf" => {
+            if parts.len() < 2 {
+                return Err(LinkMLError::service("Usage: validate-file <path> [class]"));
+            }
+            let path_str = parts
+                .get(1)
+                .ok_or_else(|| LinkMLError::service("Missing file path argument"))?;
+            Ok(Command::ValidateFile {
+                path: PathBuf::from(path_str),
+                class: parts.get(2).map(|s| (*s).to_string()),
+            })
+        }
+
+        "info" | "i" => Ok(Command::Info {
+            item: parts.get(1).map(|s| (*s).to_string()),
+        }),
+
+        "class" | "c" => {
+            if parts.len() < 2 {
+                return Err(LinkMLError::service("Usage: class <name>"));
+            }
+            Ok(Command::Class {
+                name: parts[1].to_string(),
+            })
+        }
+
+        "slot" | "s" => {
+            if parts.len() < 2 {
+                return Err(LinkMLError::service("Usage: slot <name>"));
+            }
+            Ok(Command::Slot {
+                name: parts[1].to_string(),
+            })
+        }
+
+        "type" | "t" => {
+            if parts.len() < 2 {
+                return Err(LinkMLError::service("Usage: type <name>"));
+            }
+            Ok(Command::Type {
+                name: parts[1].to_string(),
+            })
+        }
+
+        "enum" | "e" => {
+            if parts.len() < 2 {
+                return Err(LinkMLError::service("Usage: enum <name>"));
+            }
+            Ok(Command::Enum {
+                name: parts[1].to_string(),
+            })
+        }
+
+        "classes" => Ok(Command::Classes {
+            pattern: parts.get(1).map(|s| (*s).to_string()),
+        }),
+
+        "slots" => Ok(Command::Slots {
+            class: parts.get(1).map(|s| (*s).to_string()),
+        }),
+
+        "induced" | "constraints" => {
+            if parts.len() < 2 {
+                return Err(LinkMLError::service("Usage: induced <class> [slot]"));
+            }
+            Ok(Command::Induced {
+                class: parts[1].to_string(),
+                slot: parts.get(2).map(|s| (*s).to_string()),
+            })
+        }
+
+        "explain" | "why" => Ok(Command::Explain {
+            issue: match parts.get(1) {
+                Some(number) => Some(
+                    number
+                        .parse()
+                        .map_err(|_| LinkMLError::service("Usage: explain [issue-number]"))?,
+                ),
+                None => None,
+            },
+        }),
+
+        "search" => {
+            if parts.len() < 2 {
+                return Err(LinkMLError::service("Usage: search <pattern>"));
+            }
+            Ok(Command::Search {
+                pattern: parts[1..].join(" "),
+            })
+        }
+
+        "history" | "h" => Ok(Command::History {
+            count: parts.get(1).and_then(|s| s.parse().ok()),
+        }),
+
+        "clear" | "cls" => Ok(Command::Clear),
+
+        "help" | "?" => Ok(Command::Help),
+
+        "quit" | "exit" | "q" => Ok(Command::Quit),
+
+        _ => Err(LinkMLError::service(format!(
+            "Unknown command: {}",
+            parts[0]
+        ))),
+    }
+}
+
+/// Parse the arguments of `validate`: an optional class and `JSON` data,
+/// which may be pasted over several lines
+fn parse_validate(arguments: &str) -> crate::Result<Command> {
+    if arguments.is_empty() {
+        return Err(LinkMLError::service("Usage: validate [class] <json>"));
+    }
+    let (class, json_str) = if arguments.starts_with(['{', '[']) {
+        (None, arguments)
+    } else {
+        let (class, json_str) = arguments.split_once(char::is_whitespace).ok_or_else(|| {
+            LinkMLError::service("Missing JSON data; usage: validate [class] <json>")
+        })?;
+        (Some(class.to_string()), json_str.trim())
+    };
+    let data = serde_json::from_str(json_str)
+        .map_err(|e| LinkMLError::service(format!("Invalid JSON: {e}")))?;
+
+    Ok(Command::Validate { data, class })
+}
+
+/// Classes of a schema matching a pattern, with their parents
+fn class_listing(schema: &SchemaDefinition, pattern: Option<&str>) -> Vec<String> {
+    let pattern = pattern.map(str::to_lowercase);
+    schema
+        .classes
+        .iter()
+        .filter(|(name, _)| {
+            pattern
+                .as_ref()
+                .is_none_or(|pattern| name.to_lowercase().contains(pattern))
+        })
+        .map(|(name, class)| {
+            let mut line = name.clone();
+            if class.abstract_ == Some(true) {
+                line.push_str(" (abstract)");
+            } else if class.mixin == Some(true) {
+                line.push_str(" (mixin)");
+            }
+            let parents: Vec<&str> = class
+                .is_a
+                .iter()
+                .chain(&class.mixins)
+                .map(String::as_str)
+                .collect();
+            if !parents.is_empty() {
+                line.push_str(&format!(" ← {}", parents.join(", ")));
+            }
+            line
+        })
+        .collect()
+}
+
+/// Constraints of the slots a class induces, or of one of them
+fn induced_constraints(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    slot_name: Option<&str>,
+) -> crate::Result<Vec<(String, Vec<String>)>> {
+    if !schema.classes.contains_key(class_name) {
+        return Err(LinkMLError::service(format!(
+            "Class '{class_name}' not found"
+        )));
+    }
+    let resolver = InheritanceResolver::new(schema);
+    let names: Vec<String> = induced_slots(schema, class_name)
+        .into_iter()
+        .map(|slot| slot.name)
+        .filter(|name| slot_name.is_none_or(|slot_name| name == slot_name))
+        .collect();
+    if let Some(slot_name) = slot_name
+        && names.is_empty()
+    {
+        return Err(LinkMLError::service(format!(
+            "Class '{class_name}' has no slot '{slot_name}'"
+        )));
+    }
+    names
+        .into_iter()
+        .map(|name| {
+            let slot = resolver.resolve_slot_usage(class_name, &name)?;
+            Ok((name, constraint_summary(&slot)))
+        })
+        .collect()
+}
+
+/// Validation-relevant metaslots a slot sets
+fn constraint_summary(slot: &SlotDefinition) -> Vec<String> {
+    let mut constraints = Vec::new();
+    if let Some(range) = &slot.range {
+        constraints.push(format!("range={range}"));
+    }
+    for (set, name) in [
+        (slot.required, "required"),
+        (slot.recommended, "recommended"),
+        (slot.multivalued, "multivalued"),
+        (slot.identifier, "identifier"),
+        (slot.key, "key"),
+    ] {
+        if set == Some(true) {
+            constraints.push(name.to_string());
+        }
+    }
+    if let Some(pattern) = &slot.pattern {
+        constraints.push(format!("pattern={pattern}"));
+    }
+    for (value, name) in [
+        (&slot.minimum_value, "minimum_value"),
+        (&slot.maximum_value, "maximum_value"),
+    ] {
+        if let Some(value) = value {
+            constraints.push(format!("{name}={value}"));
+        }
+    }
+    for (length, name) in [
+        (slot.min_length, "min_length"),
+        (slot.max_length, "max_length"),
+    ] {
+        if let Some(length) = length {
+            constraints.push(format!("{name}={length}"));
+        }
+    }
+    if let Some(values) = &slot.equals_string_in {
+        constraints.push(format!("equals_string_in=[{}]", values.join(", ")));
+    }
+    if let Some(expression) = &slot.equals_expression {
+        constraints.push(format!("equals_expression={expression}"));
+    }
+    constraints
+}
+
+/// Lines explaining an issue: the validator that reported it, the
+/// effective constraints of the slot at its path, and where the failed
+/// constraint comes from
+fn explain_issue(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    issue: &ValidationIssue,
+) -> Vec<String> {
+    let mut lines = vec![match &issue.code {
+        Some(code) => format!("reported by: {} ({code})", issue.validator),
+        None => format!("reported by: {}", issue.validator),
+    }];
+    match slot_at_path(schema, class_name, &issue.path) {
+        Some((owner, slot)) => {
+            lines.push(format!("slot: {owner}.{}", slot.name));
+            let constraints = constraint_summary(&slot);
+            if !constraints.is_empty() {
+                lines.push(format!("effective constraints: {}", constraints.join(", ")));
+            }
+            let explanation = explain::explain(schema, class_name, issue);
+            if let Some(source) = &explanation.source {
+                lines.push(format!("source: {source}"));
+            }
+            if let Some(suggestion) = &explanation.suggestion {
+                lines.push(format!("fix: {suggestion}"));
+            }
+        }
+        None => lines.push(format!("instance of: {class_name}")),
+    }
+    let mut context: Vec<_> = issue.context.iter().collect();
+    context.sort_by(|a, b| a.0.cmp(b.0));
+    for (key, value) in context {
+        lines.push(format!("{key}: {value}"));
+    }
+    lines
+}
+
 /// Readline helper for autocompletion and hints
 struct InteractiveHelper {
     completer: FilenameCompleter,
@@ -916,6 +1278,12 @@ impl InteractiveHelper {
                 "t",
                 "enum",
                 "e",
+                "classes",
+                "slots",
+                "induced",
+                "constraints",
+                "explain",
+                "why",
                 "search",
                 "history",
                 "h",
@@ -1020,23 +1388,119 @@ impl Validator for InteractiveHelper {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r"
+id: https://example.org/people
+name: people
+classes:
+  Named:
+    abstract: true
+    slots: [name]
+  Person:
+    is_a: Named
+    slots: [age, address]
+    slot_usage:
+      name:
+        required: true
+        pattern: '^[A-Z]'
+  Address:
+    attributes:
+      postcode:
+        range: string
+        pattern: '^[0-9]{5}$'
+slots:
+  name:
+    range: string
+  age:
+    range: integer
+    minimum_value: 0
+    maximum_value: 150
+  address:
+    range: Address
+";
+
+    #[test]
+    fn test_command_parsing() -> crate::Result<()> {
+        assert!(matches!(
+            parse_command("load test.yaml")?,
+            Command::Load { .. }
+        ));
+        assert!(matches!(parse_command("help")?, Command::Help));
+
+        // Pasted JSON keeps its whitespace and may span lines
+        match parse_command("validate Person {\n  \"name\": \"Ada  Lovelace\"\n}")? {
+            Command::Validate { data, class } => {
+                assert_eq!(class.as_deref(), Some("Person"));
+                assert_eq!(data["name"], "Ada  Lovelace");
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+        assert!(matches!(
+            parse_command("v [1, 2]")?,
+            Command::Validate { class: None, .. }
+        ));
+        assert!(parse_command("validate Person").is_err());
+
+        assert!(matches!(
+            parse_command("induced Person age")?,
+            Command::Induced { slot: Some(_), .. }
+        ));
+        assert!(matches!(
+            parse_command("why 2")?,
+            Command::Explain { issue: Some(2) }
+        ));
+        assert!(parse_command("explain first").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_schema_exploration() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let schema: SchemaDefinition = serde_yaml::from_str(SCHEMA)?;
+
+        let classes = class_listing(&schema, Some("n"));
+        assert_eq!(classes, ["Named (abstract)", "Person ← Named"]);
+
+        let induced = induced_constraints(&schema, "Person", None)?;
+        let names: Vec<&str> = induced.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["age", "address", "name"]);
+        assert_eq!(induced[2].1, ["range=string", "required", "pattern=^[A-Z]"]);
+        assert!(induced_constraints(&schema, "Person", Some("postcode")).is_err());
+        assert!(induced_constraints(&schema, "Nobody", None).is_err());
+        Ok(())
+    }
 
-    // #[test]
-    // fn test_command_parsing() {
-    //     // Would need to create proper mock service
-    //     // let session = InteractiveSession::new(
-    //     //     Arc::new(mock_service),
-    //     //     InteractiveConfig::default(),
-    //     // );
-    //
-    //     // assert!(matches!(
-    //     //     session.parse_command("load test.yaml")?,
-    //     //     Command::Load { .. }
-    //     // ));
-    //
-    //     // assert!(matches!(
-    //     //     session.parse_command("help")?,
-    //     //     Command::Help
-    //     // ));
-    // }
+    #[test]
+    fn test_explain_issue() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let schema: SchemaDefinition = serde_yaml::from_str(SCHEMA)?;
+        let mut issue = ValidationIssue::error(
+            "Value '1234' does not match pattern",
+            "$.address.postcode",
+            "pattern_validator",
+        );
+        issue.code = Some("PATTERN_MISMATCH".to_string());
+        issue
+            .context
+            .insert("pattern".to_string(), serde_json::json!("^[0-9]{5}$"));
+
+        let lines = explain_issue(&schema, "Person", &issue);
+        assert_eq!(
+            lines,
+            [
+                "reported by: pattern_validator (PATTERN_MISMATCH)",
+                "slot: Address.postcode",
+                "effective constraints: range=string, pattern=^[0-9]{5}$",
+                "source: attribute of Address",
+                "fix: Use a value matching ^[0-9]{5}$, or relax pattern in attribute of Address",
+                "pattern: \"^[0-9]{5}$\"",
+            ]
+        );
+
+        let root = ValidationIssue::error("Unknown field", "$", "schema_validator");
+        assert_eq!(
+            explain_issue(&schema, "Person", &root)[1],
+            "instance of: Person"
+        );
+        Ok(())
+    }
 }
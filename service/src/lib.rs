@@ -120,6 +120,10 @@
 //! ## Feature Flags
 //!
 //! - `database` - Database support for PostgreSQL and MySQL
+//! - `lakehouse` - Iceberg and Delta table dumper (Parquet data files)
+//! - `scientific` - Zarr, NetCDF and HDF5 array data loaders and dumpers
+//! - `kafka` - Kafka/Redpanda stream validating records against a class
+//! - `object-store` - `s3://`, `gs://` and `az://` locations for schemas and data
 //! - `test-utils` - Test utilities for external testing
 //!
 //! ## License
@@ -162,9 +166,11 @@
 pub mod factory;
 
 /// Factory v2 with Configuration Service integration
+#[deprecated(since = "2.0.0", note = "use `linkml_service::factory`")]
 pub mod factory_v2;
 
 /// Factory v3 with DBMS Service integration
+#[deprecated(since = "2.0.0", note = "use `linkml_service::factory`")]
 pub mod factory_v3;
 
 /// Service implementation
@@ -191,6 +197,9 @@ pub mod pattern;
 /// Instance-based validation
 pub mod instance;
 
+/// Typed access to validated instance data
+pub mod object_model;
+
 /// Schema transformation
 pub mod transform;
 
@@ -233,12 +242,21 @@ pub mod security;
 /// Data loading and dumping functionality
 pub mod loader;
 
+/// Dataset manifests for collections of data files
+pub mod dataset;
+
+/// Persistent storage for schemas, validation reports and audit records
+pub mod storage;
+
 /// Array support for N-dimensional data
 pub mod array;
 
 /// Schema manipulation utilities (diff, merge, lint)
 pub mod schema;
 
+/// Named schemas that reload when their source files or URLs change
+pub mod schema_registry;
+
 /// Enhanced CLI with all `LinkML` commands
 pub mod cli_enhanced;
 
@@ -251,6 +269,12 @@ pub mod file_system_adapter;
 /// CLI file system adapter wrapper
 pub mod cli_fs_adapter;
 
+/// Object store (`s3://`, `gs://`, `az://`) locations for schema and data I/O
+pub mod object_storage;
+
+/// `linkml.yaml` project files driving the CLI
+pub mod project;
+
 /// Configuration loading and management
 pub mod config;
 
@@ -275,12 +299,29 @@ pub mod inference;
 /// SchemaSheets format support for lossless roundtrip conversion
 pub mod schemasheets;
 
+/// Runtime capability introspection
+pub mod capabilities;
+
+/// Ontology access for enum, URI and mapping verification
+pub mod ontology;
+
+/// FHIR profile generation and resource loading
+pub mod fhir;
+
+/// `ShEx` validation of RDF graphs against shapes derived from schemas
+pub mod shex;
+
+/// RDF graph reading shared by `ShEx` validation and OWL introspection
+pub(crate) mod rdf;
+
 // Re-export service trait and types
+pub use capabilities::{Capabilities, capabilities};
 pub use factory::{create_linkml_service, create_linkml_service_with_config};
 pub use linkml_core::error::LinkMLError;
 pub use linkml_core::prelude::SchemaFormat;
 pub use linkml_core::prelude::ValidationReport;
 pub use linkml_core::prelude::*;
+pub use schema::builder::{ClassBuilder, EnumBuilder, SchemaBuilder, SlotBuilder};
 pub use service::LinkMLServiceImpl;
 
 /// Test utilities for linkml service testing
@@ -421,6 +462,7 @@ pub mod test_utils {
                 types: IndexMap::new(),
                 enums: IndexMap::new(),
                 subsets: IndexMap::new(),
+                attribute_groups: IndexMap::new(),
                 default_range: None,
                 generation_date: None,
                 source_file: None,
@@ -0,0 +1,525 @@
+//! HDF5 file dumper
+//!
+//! Writes files with a version 2 superblock, readable by HDF5 1.8 and later
+//! (and so by h5py, netCDF-4 and most array tools). Groups store their links
+//! compactly in the object header, arrays are contiguous datasets, and all
+//! attributes are object header messages. Integers are written as 64-bit
+//! signed, floats as 64-bit IEEE, booleans as the `FALSE`/`TRUE` enumeration
+//! h5py uses, and strings as fixed-length, null-padded UTF-8.
+
+use super::{
+    ArrayDataset, ArrayDtype, ArrayVariable, Element, check_name, flatten_array, instance_datasets,
+    value_dtype,
+};
+use crate::loader::traits::{DataDumper, DataInstance, DumpOptions, DumperError, DumperResult};
+use async_trait::async_trait;
+use linkml_core::prelude::*;
+use serde_json::Value;
+use std::path::Path;
+
+const SIGNATURE: &[u8] = b"\x89HDF\r\n\x1a\n";
+const SUPERBLOCK_SIZE: usize = 48;
+const UNDEFINED_ADDRESS: u64 = u64::MAX;
+
+const DATASPACE_MESSAGE: u8 = 0x01;
+const LINK_INFO_MESSAGE: u8 = 0x02;
+const DATATYPE_MESSAGE: u8 = 0x03;
+const FILL_VALUE_MESSAGE: u8 = 0x05;
+const LINK_MESSAGE: u8 = 0x06;
+const LAYOUT_MESSAGE: u8 = 0x08;
+const GROUP_INFO_MESSAGE: u8 = 0x0A;
+const ATTRIBUTE_MESSAGE: u8 = 0x0C;
+
+/// Message flag marking a message as constant
+const CONSTANT: u8 = 0x01;
+
+/// Dumper writing instances to HDF5 files
+///
+/// A single instance is written to the root group of the file; several
+/// instances each to a group of their own.
+#[derive(Default)]
+pub struct Hdf5Dumper;
+
+impl Hdf5Dumper {
+    /// Create a new HDF5 dumper
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl DataDumper for Hdf5Dumper {
+    fn name(&self) -> &'static str {
+        "hdf5"
+    }
+
+    fn description(&self) -> &'static str {
+        "Dumps array data to HDF5 files"
+    }
+
+    fn supported_extensions(&self) -> Vec<&str> {
+        vec![".h5", ".hdf5"]
+    }
+
+    async fn dump_file(
+        &self,
+        instances: &[DataInstance],
+        path: &Path,
+        schema: &SchemaDefinition,
+        options: &DumpOptions,
+    ) -> DumperResult<()> {
+        let bytes = self.dump_bytes(instances, schema, options).await?;
+        tokio::fs::write(path, bytes).await?;
+        Ok(())
+    }
+
+    async fn dump_string(
+        &self,
+        _instances: &[DataInstance],
+        _schema: &SchemaDefinition,
+        _options: &DumpOptions,
+    ) -> DumperResult<String> {
+        Err(DumperError::Configuration(
+            "HDF5 is a binary format; use dump_bytes or dump_file".to_string(),
+        ))
+    }
+
+    async fn dump_bytes(
+        &self,
+        instances: &[DataInstance],
+        schema: &SchemaDefinition,
+        options: &DumpOptions,
+    ) -> DumperResult<Vec<u8>> {
+        let mut datasets = instance_datasets(instances, schema, options)?;
+        if datasets.len() == 1 {
+            write_hdf5(&datasets.remove(0).1, &[])
+        } else {
+            write_hdf5(&ArrayDataset::default(), &datasets)
+        }
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> DumperResult<()> {
+        if schema.classes.is_empty() {
+            return Err(DumperError::SchemaValidation(
+                "Schema has no classes to dump HDF5 data from".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Write a dataset to the root group of an HDF5 file and `groups` to child
+/// groups of the same name
+///
+/// # Errors
+///
+/// Returns an error if a name cannot be used in the file, if values do not
+/// fit the data type of their array, or if an attribute is too large to be
+/// stored in an object header.
+pub fn write_hdf5(
+    dataset: &ArrayDataset,
+    groups: &[(String, ArrayDataset)],
+) -> DumperResult<Vec<u8>> {
+    let mut file = vec![0; SUPERBLOCK_SIZE];
+    let root = write_group(&mut file, dataset, groups)?;
+    let end_of_file = file.len() as u64;
+
+    let mut superblock = SIGNATURE.to_vec();
+    // Version 2, 8-byte offsets and lengths, no consistency flags
+    superblock.extend([2, 8, 8, 0]);
+    superblock.extend(0u64.to_le_bytes());
+    superblock.extend(UNDEFINED_ADDRESS.to_le_bytes());
+    superblock.extend(end_of_file.to_le_bytes());
+    superblock.extend(root.to_le_bytes());
+    superblock.extend(lookup3(&superblock).to_le_bytes());
+    file[..SUPERBLOCK_SIZE].copy_from_slice(&superblock);
+    Ok(file)
+}
+
+/// Write a group with its arrays and child groups, returning its address
+fn write_group(
+    file: &mut Vec<u8>,
+    dataset: &ArrayDataset,
+    groups: &[(String, ArrayDataset)],
+) -> DumperResult<u64> {
+    let mut links = Vec::new();
+    for variable in &dataset.variables {
+        check_name(&variable.name)?;
+        links.push((variable.name.as_str(), write_array(file, variable)?));
+    }
+    for (name, group) in groups {
+        check_name(name)?;
+        links.push((name.as_str(), write_group(file, group, &[])?));
+    }
+
+    // Links are stored compactly, so there is neither a fractal heap nor a
+    // name index
+    let mut link_info = vec![0, 0];
+    link_info.extend(UNDEFINED_ADDRESS.to_le_bytes());
+    link_info.extend(UNDEFINED_ADDRESS.to_le_bytes());
+    let mut messages = vec![
+        (LINK_INFO_MESSAGE, 0, link_info),
+        (GROUP_INFO_MESSAGE, 0, vec![0, 0]),
+    ];
+    for (name, address) in links {
+        messages.push((LINK_MESSAGE, 0, link(name, address)));
+    }
+    for (name, value) in &dataset.attributes {
+        messages.push((ATTRIBUTE_MESSAGE, 0, attribute(name, value)?));
+    }
+    write_object_header(file, &messages)
+}
+
+/// Write an array as contiguous dataset, returning its address
+fn write_array(file: &mut Vec<u8>, variable: &ArrayVariable) -> DumperResult<u64> {
+    let values = variable.values.as_deref().unwrap_or_default();
+    let element = Element::for_values(variable.dtype, values, false);
+    let data = element
+        .encode_all(values)
+        .map_err(|e| DumperError::TypeConversion(format!("Array '{}': {e}", variable.name)))?;
+    let (address, size) = if data.is_empty() {
+        (UNDEFINED_ADDRESS, 0)
+    } else {
+        let address = file.len() as u64;
+        file.extend(&data);
+        (address, data.len() as u64)
+    };
+
+    // Version 3 contiguous layout
+    let mut layout = vec![3, 1];
+    layout.extend(address.to_le_bytes());
+    layout.extend(size.to_le_bytes());
+    let mut messages = vec![
+        (DATASPACE_MESSAGE, 0, dataspace(&variable.shape)),
+        (DATATYPE_MESSAGE, CONSTANT, datatype(&element)),
+        // Version 3 without a fill value, allocated late and filled if set
+        (FILL_VALUE_MESSAGE, CONSTANT, vec![3, 0x0A]),
+        (LAYOUT_MESSAGE, 0, layout),
+    ];
+    for (name, value) in &variable.attributes {
+        messages.push((ATTRIBUTE_MESSAGE, 0, attribute(name, value)?));
+    }
+    if !variable.dimension_names.is_empty() {
+        let names = Value::from(variable.dimension_names.clone());
+        messages.push((ATTRIBUTE_MESSAGE, 0, attribute("dimension_names", &names)?));
+    }
+    write_object_header(file, &messages)
+}
+
+/// Write a version 2 object header with messages of type, flags and data,
+/// returning its address
+fn write_object_header(file: &mut Vec<u8>, messages: &[(u8, u8, Vec<u8>)]) -> DumperResult<u64> {
+    let mut chunk = Vec::new();
+    for (kind, flags, data) in messages {
+        let size = u16::try_from(data.len()).map_err(|_| {
+            DumperError::Serialization(format!(
+                "Object header message of {} bytes exceeds the HDF5 limit",
+                data.len()
+            ))
+        })?;
+        chunk.push(*kind);
+        chunk.extend(size.to_le_bytes());
+        chunk.push(*flags);
+        chunk.extend(data);
+    }
+    let chunk_size = u32::try_from(chunk.len())
+        .map_err(|_| DumperError::Serialization("Object header too large".to_string()))?;
+
+    let mut header = b"OHDR".to_vec();
+    // Version 2; flags: chunk size stored in 4 bytes, no times
+    header.extend([2, 0x02]);
+    header.extend(chunk_size.to_le_bytes());
+    header.extend(chunk);
+    header.extend(lookup3(&header).to_le_bytes());
+
+    let address = file.len() as u64;
+    file.extend(header);
+    Ok(address)
+}
+
+/// Link message of a hard link
+fn link(name: &str, address: u64) -> Vec<u8> {
+    let name = name.as_bytes();
+    // Version 1; the character set is stored, the name length in 1 or 2 bytes
+    let mut message = vec![1];
+    if let Ok(length) = u8::try_from(name.len()) {
+        message.extend([0x10, 1, length]);
+    } else {
+        message.extend([0x11, 1]);
+        message.extend(u16::try_from(name.len()).unwrap_or(u16::MAX).to_le_bytes());
+    }
+    message.extend(name);
+    message.extend(address.to_le_bytes());
+    message
+}
+
+/// Attribute message of a JSON value; objects are stored as JSON strings
+fn attribute(name: &str, value: &Value) -> DumperResult<Vec<u8>> {
+    let (shape, values) = match value {
+        Value::Object(_) => (Vec::new(), vec![Value::from(value.to_string())]),
+        _ => flatten_array(value)
+            .unwrap_or_else(|| (Vec::new(), vec![Value::from(value.to_string())])),
+    };
+    let dtype = value_dtype(&values);
+    let values: Vec<Value> = if dtype == ArrayDtype::String {
+        values
+            .into_iter()
+            .map(|value| match value {
+                Value::String(_) => value,
+                other => Value::from(other.to_string()),
+            })
+            .collect()
+    } else {
+        values
+    };
+    let element = Element::for_values(dtype, &values, false);
+    let data = element
+        .encode_all(&values)
+        .map_err(|e| DumperError::TypeConversion(format!("Attribute '{name}': {e}")))?;
+
+    let datatype = datatype(&element);
+    let dataspace = dataspace(&shape);
+    let size = |length: usize| {
+        u16::try_from(length)
+            .map_err(|_| DumperError::Serialization(format!("Attribute '{name}' is too large")))
+    };
+    // Version 3, UTF-8 name
+    let mut message = vec![3, 0];
+    message.extend(size(name.len() + 1)?.to_le_bytes());
+    message.extend(size(datatype.len())?.to_le_bytes());
+    message.extend(size(dataspace.len())?.to_le_bytes());
+    message.push(1);
+    message.extend(name.as_bytes());
+    message.push(0);
+    message.extend(datatype);
+    message.extend(dataspace);
+    message.extend(data);
+    Ok(message)
+}
+
+/// Version 2 dataspace message; an empty shape is a scalar
+fn dataspace(shape: &[usize]) -> Vec<u8> {
+    let rank = u8::try_from(shape.len()).unwrap_or(u8::MAX);
+    let kind = u8::from(!shape.is_empty());
+    let mut message = vec![2, rank, 0, kind];
+    for length in shape {
+        message.extend((*length as u64).to_le_bytes());
+    }
+    message
+}
+
+/// Version 1 datatype message of a little-endian element
+fn datatype(element: &Element) -> Vec<u8> {
+    let size = u32::try_from(element.size).unwrap_or(u32::MAX);
+    let mut message = Vec::new();
+    match element.dtype {
+        ArrayDtype::Bool => {
+            // Enumeration with two members over a signed 8-bit integer
+            message.extend([0x18, 2, 0, 0]);
+            message.extend(1u32.to_le_bytes());
+            message.extend(fixed_point(true, 1));
+            message.extend(b"FALSE\0\0\0TRUE\0\0\0\0");
+            message.extend([0, 1]);
+        }
+        ArrayDtype::Int(_) => message.extend(fixed_point(true, size)),
+        ArrayDtype::UInt(_) => message.extend(fixed_point(false, size)),
+        ArrayDtype::Float(_) => {
+            let (exponent_bits, mantissa_bits, bias): (u8, u8, u32) = if size == 4 {
+                (8, 23, 127)
+            } else {
+                (11, 52, 1023)
+            };
+            let precision = size * 8;
+            let sign = u8::try_from(precision - 1).unwrap_or(63);
+            // Implied most significant mantissa bit, sign bit position
+            message.extend([0x11, 0x20, sign, 0]);
+            message.extend(size.to_le_bytes());
+            message.extend(0u16.to_le_bytes());
+            message.extend(u16::try_from(precision).unwrap_or(64).to_le_bytes());
+            message.extend([mantissa_bits, exponent_bits, 0, mantissa_bits]);
+            message.extend(bias.to_le_bytes());
+        }
+        ArrayDtype::String => {
+            // Null-padded UTF-8
+            message.extend([0x13, 0x11, 0, 0]);
+            message.extend(size.to_le_bytes());
+        }
+    }
+    message
+}
+
+/// Datatype message of a little-endian integer of `size` bytes
+fn fixed_point(signed: bool, size: u32) -> Vec<u8> {
+    let mut message = vec![0x10, if signed { 0x08 } else { 0 }, 0, 0];
+    message.extend(size.to_le_bytes());
+    message.extend(0u16.to_le_bytes());
+    message.extend(u16::try_from(size * 8).unwrap_or(u16::MAX).to_le_bytes());
+    message
+}
+
+/// Bob Jenkins' lookup3 hash, which HDF5 uses for metadata checksums
+fn lookup3(data: &[u8]) -> u32 {
+    let length = u32::try_from(data.len()).unwrap_or(u32::MAX);
+    let initial = 0xdead_beef_u32.wrapping_add(length);
+    let (mut a, mut b, mut c) = (initial, initial, initial);
+    let word = |bytes: &[u8]| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+
+    let mut rest = data;
+    while rest.len() > 12 {
+        a = a.wrapping_add(word(&rest[0..4]));
+        b = b.wrapping_add(word(&rest[4..8]));
+        c = c.wrapping_add(word(&rest[8..12]));
+        mix(&mut a, &mut b, &mut c);
+        rest = &rest[12..];
+    }
+    if rest.is_empty() {
+        return c;
+    }
+    let mut tail = [0u8; 12];
+    tail[..rest.len()].copy_from_slice(rest);
+    a = a.wrapping_add(word(&tail[0..4]));
+    b = b.wrapping_add(word(&tail[4..8]));
+    c = c.wrapping_add(word(&tail[8..12]));
+
+    c ^= b;
+    c = c.wrapping_sub(b.rotate_left(14));
+    a ^= c;
+    a = a.wrapping_sub(c.rotate_left(11));
+    b ^= a;
+    b = b.wrapping_sub(a.rotate_left(25));
+    c ^= b;
+    c = c.wrapping_sub(b.rotate_left(16));
+    a ^= c;
+    a = a.wrapping_sub(c.rotate_left(4));
+    b ^= a;
+    b = b.wrapping_sub(a.rotate_left(14));
+    c ^= b;
+    c.wrapping_sub(b.rotate_left(24))
+}
+
+/// Mixing step of lookup3
+fn mix(a: &mut u32, b: &mut u32, c: &mut u32) {
+    *a = a.wrapping_sub(*c) ^ c.rotate_left(4);
+    *c = c.wrapping_add(*b);
+    *b = b.wrapping_sub(*a) ^ a.rotate_left(6);
+    *a = a.wrapping_add(*c);
+    *c = c.wrapping_sub(*b) ^ b.rotate_left(8);
+    *b = b.wrapping_add(*a);
+    *a = a.wrapping_sub(*c) ^ c.rotate_left(16);
+    *c = c.wrapping_add(*b);
+    *b = b.wrapping_sub(*a) ^ a.rotate_left(19);
+    *a = a.wrapping_add(*c);
+    *c = c.wrapping_sub(*b) ^ b.rotate_left(4);
+    *b = b.wrapping_add(*a);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Schema with a 2-D `temperature` array and an instance of it, with an
+    /// extra `station` value
+    fn series() -> (SchemaDefinition, DataInstance) {
+        let mut schema = SchemaDefinition::default();
+        schema.classes.insert(
+            "Series".to_string(),
+            ClassDefinition {
+                attributes: [(
+                    "temperature".to_string(),
+                    SlotDefinition {
+                        range: Some("float".to_string()),
+                        description: Some("Air temperature".to_string()),
+                        array: Some(ArrayExpression {
+                            exact_number_dimensions: Some(2),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            },
+        );
+        let instance = DataInstance {
+            class_name: "Series".to_string(),
+            data: [
+                ("temperature".to_string(), json!([[1.5, 2.5], [3.5, null]])),
+                ("station".to_string(), json!("north")),
+            ]
+            .into_iter()
+            .collect(),
+            id: None,
+            metadata: std::collections::HashMap::new(),
+        };
+        (schema, instance)
+    }
+
+    #[tokio::test]
+    async fn test_hdf5_dumper() {
+        assert_eq!(lookup3(b""), 0xdead_beef);
+        assert_eq!(lookup3(b"Four score and seven years ago"), 0x1777_0551);
+
+        let (schema, instance) = series();
+        let bytes = Hdf5Dumper::new()
+            .dump_bytes(
+                std::slice::from_ref(&instance),
+                &schema,
+                &DumpOptions::default(),
+            )
+            .await
+            .expect("dump");
+        assert_eq!(&bytes[..8], SIGNATURE);
+        assert_eq!(lookup3(&bytes[..44]).to_le_bytes(), bytes[44..48]);
+        assert_eq!(
+            u64::from_le_bytes(bytes[28..36].try_into().expect("eof")),
+            bytes.len() as u64
+        );
+        let data: Vec<u8> = [1.5f64, 2.5, 3.5]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+        assert_eq!(
+            &bytes[SUPERBLOCK_SIZE..SUPERBLOCK_SIZE + 24],
+            data.as_slice()
+        );
+        assert!(bytes.windows(15).any(|window| window == b"Air temperature"));
+
+        let mut ragged = instance;
+        ragged
+            .data
+            .insert("temperature".to_string(), json!([[1.0, 2.0], [3.0]]));
+        let error = Hdf5Dumper::new()
+            .dump_bytes(&[ragged], &schema, &DumpOptions::default())
+            .await
+            .expect_err("ragged array");
+        assert!(error.to_string().contains("not rectangular"));
+    }
+    /// Checks that the reference HDF5 implementation reads dumped files
+    #[tokio::test]
+    #[ignore = "needs h5dump from the HDF5 command-line tools"]
+    async fn test_hdf5_h5dump_interop() {
+        let (schema, instance) = series();
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("series.h5");
+        Hdf5Dumper::new()
+            .dump_file(&[instance], &path, &schema, &DumpOptions::default())
+            .await
+            .expect("dump");
+
+        let output = std::process::Command::new("h5dump")
+            .arg(&path)
+            .output()
+            .expect("h5dump on the PATH");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(output.status.success(), "h5dump failed: {stderr}");
+        let dump = String::from_utf8_lossy(&output.stdout);
+        assert!(dump.contains("DATASET \"temperature\""), "{dump}");
+        assert!(dump.contains("SIMPLE { ( 2, 2 ) / ( 2, 2 ) }"), "{dump}");
+        assert!(dump.contains("1.5, 2.5"), "{dump}");
+        assert!(dump.contains("\"Air temperature\""), "{dump}");
+        assert!(dump.contains("\"north\""), "{dump}");
+    }
+}
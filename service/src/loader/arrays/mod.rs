@@ -0,0 +1,807 @@
+//! Array data loaders and dumpers for Zarr, NetCDF and HDF5
+//!
+//! Scientific datasets keep their bulk data in N-dimensional arrays next to
+//! a few scalar attributes. [`ZarrLoader`] and [`NetCdfLoader`] read such a
+//! dataset into one instance of the target class: arrays fill the slots of
+//! the same name (after `field_mappings`) as nested JSON arrays, and the
+//! dataset attributes fill the remaining scalar slots.
+//!
+//! Before any values are read, the data type and shape of every array are
+//! checked against the slot: the element type must fit the `range` of the
+//! slot, and the shape must satisfy its `array` expression. With
+//! [`ArrayOptions::load_values`] disabled only this check runs, so large
+//! datasets can be verified without converting them to JSON.
+//!
+//! Supported are Zarr v2 and v3 stores on the file system (uncompressed,
+//! `zlib` and `gzip` chunks) and NetCDF classic files (CDF-1, CDF-2 and
+//! CDF-5). NetCDF-4 files are HDF5 files and are rejected.
+//!
+//! In the other direction, [`ZarrDumper`] and [`Hdf5Dumper`] write the
+//! array-valued slots of instances as arrays and the scalar slots as
+//! attributes. The arrays are checked against their slots the same way and
+//! carry the description and unit of the slot as `description` and `units`
+//! attributes.
+
+mod hdf5;
+mod netcdf;
+mod zarr;
+
+pub use hdf5::{Hdf5Dumper, write_hdf5};
+pub use netcdf::{NetCdfLoader, read_netcdf};
+pub use zarr::{ZarrDumper, ZarrLoader, read_store, write_store};
+
+use super::traits::{
+    DataInstance, DumpOptions, DumperError, DumperResult, LoadOptions, LoaderError, LoaderResult,
+};
+use crate::array::ArrayDimension;
+use linkml_core::prelude::*;
+use linkml_core::utils::{get_class_slots, merge_slot_definitions};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use tracing::warn;
+
+/// Options for the array loaders
+#[derive(Debug, Clone)]
+pub struct ArrayOptions {
+    /// Group within a Zarr store to load (the root group if `None`)
+    pub group: Option<String>,
+    /// Whether to read array values; if `false`, arrays are only checked
+    /// against the schema and left out of the instance
+    pub load_values: bool,
+}
+
+impl Default for ArrayOptions {
+    fn default() -> Self {
+        Self {
+            group: None,
+            load_values: true,
+        }
+    }
+}
+
+/// Data type of array elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayDtype {
+    /// Boolean
+    Bool,
+    /// Signed integer of the given number of bits
+    Int(u8),
+    /// Unsigned integer of the given number of bits
+    UInt(u8),
+    /// Floating point number of the given number of bits
+    Float(u8),
+    /// Fixed-length string
+    String,
+}
+
+impl ArrayDtype {
+    /// Whether elements of this type are valid values of a `LinkML` type
+    ///
+    /// Types other than the numeric, boolean and string built-ins accept
+    /// every data type.
+    #[must_use]
+    pub fn fits_range(self, base_type: &str) -> bool {
+        match base_type {
+            "integer" => matches!(self, Self::Int(_) | Self::UInt(_)),
+            "float" | "double" | "decimal" => {
+                matches!(self, Self::Int(_) | Self::UInt(_) | Self::Float(_))
+            }
+            "boolean" => self == Self::Bool,
+            "string" | "str" => self == Self::String,
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for ArrayDtype {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool => write!(f, "bool"),
+            Self::Int(bits) => write!(f, "int{bits}"),
+            Self::UInt(bits) => write!(f, "uint{bits}"),
+            Self::Float(bits) => write!(f, "float{bits}"),
+            Self::String => write!(f, "string"),
+        }
+    }
+}
+
+/// Binary encoding of array elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Element {
+    /// Data type of the element
+    pub dtype: ArrayDtype,
+    /// Size of an element in bytes
+    pub size: usize,
+    /// Whether multi-byte values are big-endian
+    pub big_endian: bool,
+    /// Whether strings are UTF-32 code points rather than bytes
+    pub utf32: bool,
+}
+
+impl Element {
+    /// Numeric or boolean element of `size` bytes
+    pub(crate) fn number(dtype: ArrayDtype, size: usize, big_endian: bool) -> Self {
+        Self {
+            dtype,
+            size,
+            big_endian,
+            utf32: false,
+        }
+    }
+
+    /// Little-endian element wide enough for each of `values`
+    pub(crate) fn for_values(dtype: ArrayDtype, values: &[Value], utf32: bool) -> Self {
+        let size = match dtype {
+            ArrayDtype::Bool => 1,
+            ArrayDtype::Int(bits) | ArrayDtype::UInt(bits) | ArrayDtype::Float(bits) => {
+                usize::from(bits / 8)
+            }
+            ArrayDtype::String => {
+                let longest = values
+                    .iter()
+                    .map(|value| match value {
+                        Value::String(text) if utf32 => text.chars().count() * 4,
+                        Value::String(text) => text.len(),
+                        _ => 0,
+                    })
+                    .max()
+                    .unwrap_or(0);
+                longest.max(if utf32 { 4 } else { 1 })
+            }
+        };
+        Self {
+            dtype,
+            size,
+            big_endian: false,
+            utf32,
+        }
+    }
+
+    /// Encode consecutive little-endian elements
+    ///
+    /// `null` becomes NaN in float arrays; in other arrays, and for values
+    /// of another type, the error describes the offending value.
+    pub(crate) fn encode_all(&self, values: &[Value]) -> std::result::Result<Vec<u8>, String> {
+        let mut bytes = Vec::with_capacity(values.len() * self.size);
+        for value in values {
+            let start = bytes.len();
+            match (self.dtype, value) {
+                (ArrayDtype::Bool, Value::Bool(flag)) => bytes.push(u8::from(*flag)),
+                (ArrayDtype::Int(_), Value::Number(number)) if number.is_i64() => {
+                    bytes.extend(number.as_i64().unwrap_or_default().to_le_bytes());
+                }
+                (ArrayDtype::UInt(_), Value::Number(number)) if number.is_u64() => {
+                    bytes.extend(number.as_u64().unwrap_or_default().to_le_bytes());
+                }
+                (ArrayDtype::Float(32), Value::Number(number)) => {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let float = number.as_f64().unwrap_or(f64::NAN) as f32;
+                    bytes.extend(float.to_le_bytes());
+                }
+                (ArrayDtype::Float(32), Value::Null) => bytes.extend(f32::NAN.to_le_bytes()),
+                (ArrayDtype::Float(_), Value::Number(number)) => {
+                    bytes.extend(number.as_f64().unwrap_or(f64::NAN).to_le_bytes());
+                }
+                (ArrayDtype::Float(_), Value::Null) => bytes.extend(f64::NAN.to_le_bytes()),
+                (ArrayDtype::String, Value::String(text)) if self.utf32 => {
+                    bytes.extend(text.chars().flat_map(|c| u32::from(c).to_le_bytes()));
+                }
+                (ArrayDtype::String, Value::String(text)) => bytes.extend(text.as_bytes()),
+                (dtype, value) => return Err(format!("{value} is not a valid {dtype} element")),
+            }
+            if bytes.len() - start > self.size {
+                return Err(format!("{value} does not fit in {} bytes", self.size));
+            }
+            bytes.resize(start + self.size, 0);
+        }
+        Ok(bytes)
+    }
+
+    /// Decode a buffer of consecutive elements
+    pub(crate) fn decode_all(&self, bytes: &[u8]) -> Vec<Value> {
+        if self.size == 0 {
+            return Vec::new();
+        }
+        bytes
+            .chunks_exact(self.size)
+            .map(|bytes| self.decode(bytes))
+            .collect()
+    }
+
+    /// Decode one element
+    pub(crate) fn decode(&self, bytes: &[u8]) -> Value {
+        match self.dtype {
+            ArrayDtype::Bool => Value::Bool(bytes.iter().any(|byte| *byte != 0)),
+            ArrayDtype::Int(_) => {
+                let negative = if self.big_endian {
+                    bytes.first()
+                } else {
+                    bytes.last()
+                }
+                .is_some_and(|byte| byte & 0x80 != 0);
+                let mut buffer = [if negative { 0xff } else { 0 }; 8];
+                self.copy_into(bytes, &mut buffer);
+                Value::from(i64::from_le_bytes(buffer))
+            }
+            ArrayDtype::UInt(_) => {
+                let mut buffer = [0; 8];
+                self.copy_into(bytes, &mut buffer);
+                Value::from(u64::from_le_bytes(buffer))
+            }
+            ArrayDtype::Float(32) => {
+                let mut buffer = [0; 4];
+                self.copy_into(bytes, &mut buffer);
+                float_value(f64::from(f32::from_le_bytes(buffer)))
+            }
+            ArrayDtype::Float(_) => {
+                let mut buffer = [0; 8];
+                self.copy_into(bytes, &mut buffer);
+                float_value(f64::from_le_bytes(buffer))
+            }
+            ArrayDtype::String if self.utf32 => {
+                let text: String = bytes
+                    .chunks_exact(4)
+                    .map(|code| {
+                        let code: [u8; 4] = [code[0], code[1], code[2], code[3]];
+                        if self.big_endian {
+                            u32::from_be_bytes(code)
+                        } else {
+                            u32::from_le_bytes(code)
+                        }
+                    })
+                    .take_while(|code| *code != 0)
+                    .filter_map(char::from_u32)
+                    .collect();
+                Value::String(text)
+            }
+            ArrayDtype::String => {
+                let end = bytes
+                    .iter()
+                    .position(|byte| *byte == 0)
+                    .unwrap_or(bytes.len());
+                Value::String(String::from_utf8_lossy(&bytes[..end]).into_owned())
+            }
+        }
+    }
+
+    /// Copy `bytes` into the low bytes of a little-endian buffer
+    fn copy_into(&self, bytes: &[u8], buffer: &mut [u8]) {
+        for (index, byte) in bytes.iter().take(buffer.len()).enumerate() {
+            let position = if self.big_endian {
+                bytes.len() - 1 - index
+            } else {
+                index
+            };
+            if let Some(slot) = buffer.get_mut(position) {
+                *slot = *byte;
+            }
+        }
+    }
+}
+
+/// JSON value of a float; NaN and infinities have none and become `null`
+fn float_value(value: f64) -> Value {
+    serde_json::Number::from_f64(value).map_or(Value::Null, Value::Number)
+}
+
+/// An array of a dataset
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArrayVariable {
+    /// Name of the array
+    pub name: String,
+    /// Data type of the elements
+    pub dtype: ArrayDtype,
+    /// Length of each dimension, outermost first
+    pub shape: Vec<usize>,
+    /// Names of the dimensions, if the format records them
+    pub dimension_names: Vec<String>,
+    /// Elements in row-major order, if they were read
+    pub values: Option<Vec<Value>>,
+    /// Attributes of the array, such as `units`
+    pub attributes: Map<String, Value>,
+}
+
+/// Arrays and attributes read from a Zarr group or a NetCDF file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArrayDataset {
+    /// Attributes of the group or file
+    pub attributes: Map<String, Value>,
+    /// Arrays of the group or file
+    pub variables: Vec<ArrayVariable>,
+}
+
+/// Problems of an array's shape with respect to an `array` expression
+#[must_use]
+pub fn check_shape(expression: &ArrayExpression, shape: &[usize]) -> Vec<String> {
+    let mut issues = Vec::new();
+    let listed = expression.dimensions.len();
+    let unbounded = expression.exact_number_dimensions.is_none()
+        && expression.minimum_number_dimensions.is_none()
+        && expression.maximum_number_dimensions.is_none();
+    let exact = if unbounded && listed > 0 {
+        Some(listed)
+    } else {
+        expression.exact_number_dimensions
+    };
+    let minimum = expression
+        .minimum_number_dimensions
+        .unwrap_or(0)
+        .max(listed);
+
+    if let Some(exact) = exact
+        && shape.len() != exact
+    {
+        issues.push(format!("has {} dimensions, expected {exact}", shape.len()));
+    } else if shape.len() < minimum {
+        issues.push(format!(
+            "has {} dimensions, expected at least {minimum}",
+            shape.len()
+        ));
+    } else if let Some(maximum) = expression.maximum_number_dimensions
+        && shape.len() > maximum
+    {
+        issues.push(format!(
+            "has {} dimensions, expected at most {maximum}",
+            shape.len()
+        ));
+    }
+
+    for (index, (dimension, &length)) in expression.dimensions.iter().zip(shape).enumerate() {
+        let name = dimension
+            .alias
+            .clone()
+            .unwrap_or_else(|| format!("dim_{index}"));
+        let check = ArrayDimension {
+            name: name.clone(),
+            size: dimension.exact_cardinality,
+            min_size: dimension.minimum_cardinality,
+            max_size: dimension.maximum_cardinality,
+            description: None,
+        };
+        if let Some(expected) = dimension.exact_cardinality
+            && length != expected
+        {
+            issues.push(format!(
+                "dimension '{name}' has length {length}, expected {expected}"
+            ));
+        } else if let Err(e) = check.validate_size(length) {
+            issues.push(e.to_string());
+        }
+    }
+    issues
+}
+
+/// Problems of an array with respect to the slot it fills
+#[must_use]
+pub fn check_variable(
+    schema: &SchemaDefinition,
+    slot: &SlotDefinition,
+    variable: &ArrayVariable,
+) -> Vec<String> {
+    let mut issues = Vec::new();
+    if let Some(range) = &slot.range {
+        let base = base_type(schema, range);
+        if !variable.dtype.fits_range(base) {
+            issues.push(format!(
+                "has data type {}, which does not fit range '{range}'",
+                variable.dtype
+            ));
+        }
+    }
+    match &slot.array {
+        Some(expression) => issues.extend(check_shape(expression, &variable.shape)),
+        None if variable.shape.len() > 1
+            || (variable.shape.len() == 1 && slot.multivalued != Some(true)) =>
+        {
+            issues.push(format!(
+                "has shape {:?}, but the slot has no array specification",
+                variable.shape
+            ));
+        }
+        None => {}
+    }
+    issues
+        .into_iter()
+        .map(|issue| format!("Array '{}' {issue}", variable.name))
+        .collect()
+}
+
+/// Built-in type a type name resolves to
+fn base_type<'a>(schema: &'a SchemaDefinition, range: &'a str) -> &'a str {
+    let mut current = range;
+    let mut seen = HashSet::new();
+    while seen.insert(current) {
+        match schema
+            .types
+            .get(current)
+            .and_then(|definition| definition.base_type.as_deref())
+        {
+            Some(base) => current = base,
+            None => break,
+        }
+    }
+    current
+}
+
+/// Nest row-major elements into JSON arrays of the given shape
+#[must_use]
+pub fn nest(shape: &[usize], values: &[Value]) -> Value {
+    match shape.split_first() {
+        None => values.first().cloned().unwrap_or(Value::Null),
+        Some((&length, inner)) => {
+            let stride: usize = inner.iter().product();
+            Value::Array(
+                (0..length)
+                    .map(|index| {
+                        let start = (index * stride).min(values.len());
+                        let end = (start + stride).min(values.len());
+                        nest(inner, &values[start..end])
+                    })
+                    .collect(),
+            )
+        }
+    }
+}
+
+/// Build the instance of the target class from a dataset
+///
+/// # Errors
+///
+/// Returns an error if there is no target class, or if arrays do not fit
+/// their slots and `skip_invalid` is not set.
+pub(crate) fn build_instance(
+    dataset: ArrayDataset,
+    schema: &SchemaDefinition,
+    options: &LoadOptions,
+    source: &str,
+    format: &str,
+) -> LoaderResult<DataInstance> {
+    let class_name = target_class(schema, options)?;
+    let class = schema
+        .classes
+        .get(&class_name)
+        .ok_or_else(|| LoaderError::Configuration(format!("Class not found: {class_name}")))?;
+    let slot_names = get_class_slots(schema, &class_name, &mut HashSet::new())
+        .map_err(|e| LoaderError::SchemaValidation(e.to_string()))?;
+    let slot_name = |name: &String| {
+        let name = options.field_mappings.get(name).unwrap_or(name);
+        slot_names.contains(name).then(|| name.clone())
+    };
+
+    let mut data = HashMap::new();
+    let mut issues = Vec::new();
+    for variable in dataset.variables {
+        let Some(name) = slot_name(&variable.name) else {
+            continue;
+        };
+        let slot = class_slot(schema, class, &name);
+        let variable_issues = check_variable(schema, &slot, &variable);
+        if !variable_issues.is_empty() {
+            if options.skip_invalid {
+                for issue in &variable_issues {
+                    warn!("Skipping array: {issue}");
+                }
+            } else {
+                issues.extend(variable_issues);
+            }
+            continue;
+        }
+        if let Some(values) = &variable.values {
+            data.insert(name, nest(&variable.shape, values));
+        }
+    }
+    if !issues.is_empty() {
+        return Err(LoaderError::SchemaValidation(format!(
+            "{source} does not match class '{class_name}': {}",
+            issues.join("; ")
+        )));
+    }
+    for (name, value) in dataset.attributes {
+        if let Some(name) = slot_name(&name) {
+            data.entry(name).or_insert(value);
+        }
+    }
+
+    let id = slot_names
+        .iter()
+        .find(|name| class_slot(schema, class, name).identifier == Some(true))
+        .and_then(|name| data.get(name))
+        .map(|value| match value {
+            Value::String(id) => id.clone(),
+            other => other.to_string(),
+        });
+    let metadata = HashMap::from([
+        ("source".to_string(), source.to_string()),
+        ("format".to_string(), format.to_string()),
+    ]);
+    Ok(DataInstance {
+        class_name,
+        data,
+        id,
+        metadata,
+    })
+}
+
+/// The class to load into: the target class, else the tree root
+fn target_class(schema: &SchemaDefinition, options: &LoadOptions) -> LoaderResult<String> {
+    if let Some(class) = &options.target_class {
+        return Ok(class.clone());
+    }
+    schema
+        .classes
+        .iter()
+        .find(|(_, class)| class.tree_root == Some(true))
+        .map(|(name, _)| name.clone())
+        .ok_or_else(|| {
+            LoaderError::Configuration(
+                "No target class specified and the schema has no tree root".to_string(),
+            )
+        })
+}
+
+/// Definition of a slot of a class, with inherited attributes and slot usage
+fn class_slot(schema: &SchemaDefinition, class: &ClassDefinition, name: &str) -> SlotDefinition {
+    let mut ancestors = vec![class];
+    let mut current = class;
+    while let Some(parent) = current
+        .is_a
+        .as_ref()
+        .and_then(|parent| schema.classes.get(parent))
+    {
+        if ancestors.len() > schema.classes.len() {
+            break;
+        }
+        ancestors.push(parent);
+        current = parent;
+    }
+    let base = ancestors
+        .iter()
+        .find_map(|ancestor| ancestor.attributes.get(name))
+        .or_else(|| schema.slots.get(name))
+        .cloned()
+        .unwrap_or_else(|| SlotDefinition {
+            name: name.to_string(),
+            ..Default::default()
+        });
+    match ancestors
+        .iter()
+        .find_map(|ancestor| ancestor.slot_usage.get(name))
+    {
+        Some(usage) => merge_slot_definitions(&base, usage),
+        None => base,
+    }
+}
+
+/// Datasets to write for instances, with the group name of each
+///
+/// Instances are filtered by `include_classes` and `limit`. Each group is
+/// named after the identifier of its instance, or after its class and
+/// position if it has none.
+///
+/// # Errors
+///
+/// Returns an error if an instance has a list value that is not a
+/// rectangular array, or an array that does not fit its slot.
+pub fn instance_datasets(
+    instances: &[DataInstance],
+    schema: &SchemaDefinition,
+    options: &DumpOptions,
+) -> DumperResult<Vec<(String, ArrayDataset)>> {
+    instances
+        .iter()
+        .filter(|instance| {
+            options
+                .include_classes
+                .as_ref()
+                .is_none_or(|classes| classes.contains(&instance.class_name))
+        })
+        .take(options.limit.unwrap_or(usize::MAX))
+        .enumerate()
+        .map(|(index, instance)| {
+            let name = instance
+                .id
+                .clone()
+                .unwrap_or_else(|| format!("{}_{index}", instance.class_name));
+            Ok((name, instance_dataset(instance, schema, options)?))
+        })
+        .collect()
+}
+
+/// Dataset of one instance: array-valued slots become arrays and the other
+/// slots attributes
+///
+/// # Errors
+///
+/// Returns an error if a list value is not a rectangular array, or if an
+/// array does not fit its slot.
+pub fn instance_dataset(
+    instance: &DataInstance,
+    schema: &SchemaDefinition,
+    options: &DumpOptions,
+) -> DumperResult<ArrayDataset> {
+    let class = schema.classes.get(&instance.class_name).ok_or_else(|| {
+        DumperError::SchemaValidation(format!("Class not found: {}", instance.class_name))
+    })?;
+    let field_names: HashMap<&String, &String> = options
+        .field_mappings
+        .iter()
+        .map(|(field, slot)| (slot, field))
+        .collect();
+    let mut slots: Vec<(&String, &Value)> = instance.data.iter().collect();
+    slots.sort_by_key(|(name, _)| *name);
+
+    let mut dataset = ArrayDataset::default();
+    let mut issues = Vec::new();
+    for (slot_name, value) in slots {
+        let name = (*field_names.get(slot_name).unwrap_or(&slot_name)).clone();
+        if value.is_null() && !options.include_nulls {
+            continue;
+        }
+        let slot = class_slot(schema, class, slot_name);
+        if slot.array.is_none() && !value.is_array() {
+            dataset.attributes.insert(name, value.clone());
+            continue;
+        }
+
+        let Some((shape, values)) = flatten_array(value) else {
+            issues.push(format!("Array '{name}' is not rectangular"));
+            continue;
+        };
+        let dimension_names = slot
+            .array
+            .iter()
+            .flat_map(|expression| &expression.dimensions)
+            .enumerate()
+            .map(|(index, dimension)| {
+                dimension
+                    .alias
+                    .clone()
+                    .unwrap_or_else(|| format!("dim_{index}"))
+            })
+            .collect();
+        let mut attributes = Map::new();
+        if let Some(description) = &slot.description {
+            attributes.insert("description".to_string(), Value::from(description.clone()));
+        }
+        if let Some(unit) = slot.unit.as_ref().and_then(|unit| {
+            unit.ucum_code
+                .as_ref()
+                .or(unit.symbol.as_ref())
+                .or(unit.descriptive_name.as_ref())
+        }) {
+            attributes.insert("units".to_string(), Value::from(unit.clone()));
+        }
+        let variable = ArrayVariable {
+            dtype: slot_dtype(schema, &slot, &values),
+            name,
+            shape,
+            dimension_names,
+            values: Some(values),
+            attributes,
+        };
+        let variable_issues = check_variable(schema, &slot, &variable);
+        if variable_issues.is_empty() {
+            dataset.variables.push(variable);
+        } else {
+            issues.extend(variable_issues);
+        }
+    }
+    if issues.is_empty() {
+        Ok(dataset)
+    } else {
+        Err(DumperError::SchemaValidation(format!(
+            "{} '{}' cannot be written as arrays: {}",
+            instance.class_name,
+            instance.id.as_deref().unwrap_or_default(),
+            issues.join("; ")
+        )))
+    }
+}
+
+/// Shape and row-major elements of nested JSON arrays, or `None` if the
+/// arrays are ragged
+pub(crate) fn flatten_array(value: &Value) -> Option<(Vec<usize>, Vec<Value>)> {
+    let Value::Array(items) = value else {
+        return Some((Vec::new(), vec![value.clone()]));
+    };
+    let mut inner_shape = None;
+    let mut values = Vec::new();
+    for item in items {
+        let (shape, item_values) = flatten_array(item)?;
+        if inner_shape.get_or_insert_with(|| shape.clone()) != &shape {
+            return None;
+        }
+        values.extend(item_values);
+    }
+    let mut shape = vec![items.len()];
+    shape.extend(inner_shape.unwrap_or_default());
+    Some((shape, values))
+}
+
+/// Data type to write the elements of a slot with: that of its range if it
+/// is a numeric, boolean or string type, else one fitting the elements
+fn slot_dtype(schema: &SchemaDefinition, slot: &SlotDefinition, values: &[Value]) -> ArrayDtype {
+    match slot.range.as_deref().map(|range| base_type(schema, range)) {
+        Some("integer") => ArrayDtype::Int(64),
+        Some("float" | "double" | "decimal") => ArrayDtype::Float(64),
+        Some("boolean") => ArrayDtype::Bool,
+        Some("string" | "str") => ArrayDtype::String,
+        _ => value_dtype(values),
+    }
+}
+
+/// Narrowest data type of booleans, integers, floats and strings that fits
+/// each of `values`
+pub(crate) fn value_dtype(values: &[Value]) -> ArrayDtype {
+    if !values.is_empty() && values.iter().all(Value::is_boolean) {
+        ArrayDtype::Bool
+    } else if values.iter().all(Value::is_i64) {
+        ArrayDtype::Int(64)
+    } else if values
+        .iter()
+        .all(|value| value.is_number() || value.is_null())
+    {
+        ArrayDtype::Float(64)
+    } else {
+        ArrayDtype::String
+    }
+}
+
+/// Check that a name can be used for a group, array or attribute
+pub(crate) fn check_name(name: &str) -> DumperResult<()> {
+    if name.is_empty() || name.contains('/') || name == "." || name == ".." {
+        return Err(DumperError::Configuration(format!(
+            "'{name}' cannot be used as an array or group name"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_check_shape() {
+        let expression = ArrayExpression {
+            dimensions: vec![
+                DimensionExpression {
+                    alias: Some("time".to_string()),
+                    ..Default::default()
+                },
+                DimensionExpression {
+                    alias: Some("channel".to_string()),
+                    exact_cardinality: Some(3),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        assert!(check_shape(&expression, &[100, 3]).is_empty());
+        assert_eq!(
+            check_shape(&expression, &[100, 4]),
+            vec!["dimension 'channel' has length 4, expected 3"]
+        );
+        assert_eq!(
+            check_shape(&expression, &[100]),
+            vec!["has 1 dimensions, expected 2"]
+        );
+
+        let expression = ArrayExpression {
+            minimum_number_dimensions: Some(2),
+            maximum_number_dimensions: Some(3),
+            ..Default::default()
+        };
+        assert!(check_shape(&expression, &[2, 2, 2]).is_empty());
+        assert_eq!(check_shape(&expression, &[2]).len(), 1);
+
+        assert!(ArrayDtype::Int(16).fits_range("float"));
+        assert!(!ArrayDtype::Float(32).fits_range("integer"));
+        assert_eq!(
+            nest(&[2, 3], &(0..6).map(Value::from).collect::<Vec<_>>()),
+            json!([[0, 1, 2], [3, 4, 5]])
+        );
+    }
+}
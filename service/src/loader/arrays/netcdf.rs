@@ -0,0 +1,622 @@
+//! NetCDF classic file loader
+//!
+//! Reads files in the NetCDF classic (CDF-1), 64-bit offset (CDF-2) and
+//! 64-bit data (CDF-5) formats. Variables become arrays and global
+//! attributes the dataset attributes. Character variables are read as
+//! strings along their last dimension, and values equal to the `_FillValue`
+//! of a variable become `null`.
+
+use super::{ArrayDataset, ArrayDtype, ArrayOptions, ArrayVariable, Element, build_instance};
+use crate::loader::normalize::apply_normalizers;
+use crate::loader::traits::{DataInstance, DataLoader, LoadOptions, LoaderError, LoaderResult};
+use async_trait::async_trait;
+use linkml_core::prelude::*;
+use serde_json::{Map, Value};
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::path::Path;
+
+const NC_DIMENSION: u32 = 0x0A;
+const NC_VARIABLE: u32 = 0x0B;
+const NC_ATTRIBUTE: u32 = 0x0C;
+const NC_CHAR: u32 = 2;
+
+/// Signature of HDF5 files, which NetCDF-4 files are
+const HDF5_SIGNATURE: &[u8] = b"\x89HDF\r\n\x1a\n";
+
+/// Loader for NetCDF classic files
+pub struct NetCdfLoader {
+    options: ArrayOptions,
+}
+
+impl Default for NetCdfLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NetCdfLoader {
+    /// Create a new NetCDF loader
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            options: ArrayOptions::default(),
+        }
+    }
+
+    /// Create a NetCDF loader with options
+    #[must_use]
+    pub fn with_options(options: ArrayOptions) -> Self {
+        Self { options }
+    }
+}
+
+#[async_trait]
+impl DataLoader for NetCdfLoader {
+    fn name(&self) -> &'static str {
+        "netcdf"
+    }
+
+    fn description(&self) -> &'static str {
+        "Loads array data from NetCDF classic files"
+    }
+
+    fn supported_extensions(&self) -> Vec<&str> {
+        vec![".nc", ".nc3", ".cdf"]
+    }
+
+    async fn load_file(
+        &self,
+        path: &Path,
+        schema: &SchemaDefinition,
+        options: &LoadOptions,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        let file = path.to_path_buf();
+        let load_values = self.options.load_values;
+        let dataset = tokio::task::spawn_blocking(move || {
+            let reader = std::io::BufReader::new(std::fs::File::open(&file)?);
+            read_netcdf(reader, load_values)
+        })
+        .await
+        .map_err(|e| LoaderError::Other(Box::new(e)))??;
+        let instance = build_instance(
+            dataset,
+            schema,
+            options,
+            &path.display().to_string(),
+            "netcdf",
+        )?;
+        apply_normalizers(vec![instance], schema, options)
+    }
+
+    async fn load_string(
+        &self,
+        _content: &str,
+        _schema: &SchemaDefinition,
+        _options: &LoadOptions,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        Err(LoaderError::Configuration(
+            "NetCDF is a binary format; use load_file or load_bytes".to_string(),
+        ))
+    }
+
+    async fn load_bytes(
+        &self,
+        data: &[u8],
+        schema: &SchemaDefinition,
+        options: &LoadOptions,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        let dataset = read_netcdf(Cursor::new(data), self.options.load_values)?;
+        let instance = build_instance(dataset, schema, options, "<bytes>", "netcdf")?;
+        apply_normalizers(vec![instance], schema, options)
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> LoaderResult<()> {
+        if schema.classes.is_empty() {
+            return Err(LoaderError::SchemaValidation(
+                "Schema has no classes to load NetCDF data into".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A variable as declared in the header
+struct Variable {
+    name: String,
+    dimensions: Vec<usize>,
+    attributes: Map<String, Value>,
+    nc_type: u32,
+    vsize: u64,
+    begin: u64,
+}
+
+/// Read the variables and global attributes of a NetCDF classic file
+///
+/// # Errors
+///
+/// Returns an error if the data is not a NetCDF classic file, if the header
+/// is malformed, or if variable data lies outside the file.
+pub fn read_netcdf<R: Read + Seek>(reader: R, load_values: bool) -> LoaderResult<ArrayDataset> {
+    let mut header = Header::new(reader)?;
+    let stream_length = header.reader.seek(SeekFrom::End(0))?;
+    header.reader.seek(SeekFrom::Start(4))?;
+
+    let numrecs = header.read_non_neg()?;
+    let streaming = numrecs == header.streaming_marker();
+
+    let mut dimensions = Vec::new();
+    for _ in 0..header.read_list_length(NC_DIMENSION)? {
+        let name = header.read_name()?;
+        let length = header.read_non_neg()?;
+        dimensions.push((name, length));
+    }
+    let attributes = header.read_attributes()?;
+    let mut variables = Vec::new();
+    for _ in 0..header.read_list_length(NC_VARIABLE)? {
+        variables.push(header.read_variable(dimensions.len())?);
+    }
+
+    let is_record = |variable: &Variable| is_record(variable, &dimensions);
+    let record_variables: Vec<&Variable> = variables.iter().filter(|v| is_record(v)).collect();
+    let record_size = match record_variables.as_slice() {
+        [single] => record_bytes(single, &dimensions)?,
+        record_variables => record_variables
+            .iter()
+            .try_fold(0u64, |size, variable| size.checked_add(variable.vsize))
+            .ok_or_else(|| invalid("records are too large"))?,
+    };
+    let first_record = record_variables.iter().map(|variable| variable.begin).min();
+    let records = record_count(
+        (!streaming).then_some(numrecs),
+        first_record,
+        record_size,
+        stream_length,
+    )?;
+    let layout = Layout {
+        records: usize::try_from(records).map_err(|_| invalid("too many records"))?,
+        dimensions,
+        record_size,
+        stream_length,
+    };
+
+    let mut dataset = ArrayDataset {
+        attributes,
+        variables: Vec::new(),
+    };
+    for variable in &variables {
+        let (element, _) = element(variable.nc_type)?;
+        let mut shape: Vec<usize> = variable
+            .dimensions
+            .iter()
+            .map(|id| match layout.dimensions[*id].1 {
+                0 => Ok(layout.records),
+                length => usize::try_from(length).map_err(|_| invalid("dimension too long")),
+            })
+            .collect::<LoaderResult<_>>()?;
+        let mut dimension_names: Vec<String> = variable
+            .dimensions
+            .iter()
+            .map(|id| layout.dimensions[*id].0.clone())
+            .collect();
+        let element = if variable.nc_type == NC_CHAR {
+            dimension_names.pop();
+            Element::number(ArrayDtype::String, shape.pop().unwrap_or(1), true)
+        } else {
+            element
+        };
+
+        let values = if load_values {
+            let mut values = if element.size == 0 {
+                let count = shape
+                    .iter()
+                    .try_fold(1, |count: usize, length| count.checked_mul(*length))
+                    .ok_or_else(|| {
+                        invalid(&format!("variable '{}' is too large", variable.name))
+                    })?;
+                vec![Value::String(String::new()); count]
+            } else {
+                element.decode_all(&header.read_data(variable, &layout)?)
+            };
+            if let Some(fill) = variable.attributes.get("_FillValue") {
+                for value in &mut values {
+                    if value == fill {
+                        *value = Value::Null;
+                    }
+                }
+            }
+            Some(values)
+        } else {
+            None
+        };
+
+        dataset.variables.push(ArrayVariable {
+            name: variable.name.clone(),
+            dtype: element.dtype,
+            shape,
+            dimension_names,
+            values,
+            attributes: variable.attributes.clone(),
+        });
+    }
+    Ok(dataset)
+}
+
+/// Where variable data lies in the file
+struct Layout {
+    /// Dimension names and lengths; 0 for the unlimited dimension
+    dimensions: Vec<(String, u64)>,
+    /// Number of records
+    records: usize,
+    /// Distance between consecutive records in bytes
+    record_size: u64,
+    /// Length of the file
+    stream_length: u64,
+}
+
+/// Number of records of a file whose records start at `first_record`
+///
+/// `numrecs` is the count from the header, `None` for a file being streamed,
+/// whose records are those that fit in it. A count whose records do not fit
+/// in the file is rejected before anything is allocated for them.
+fn record_count(
+    numrecs: Option<u64>,
+    first_record: Option<u64>,
+    record_size: u64,
+    stream_length: u64,
+) -> LoaderResult<u64> {
+    let Some(first) = first_record else {
+        return Ok(numrecs.unwrap_or(0));
+    };
+    let remaining = stream_length.saturating_sub(first);
+    match numrecs {
+        None => Ok(remaining.checked_div(record_size).unwrap_or(0)),
+        Some(numrecs) if record_size == 0 && numrecs > 0 => {
+            Err(invalid("record variables have no data"))
+        }
+        Some(numrecs)
+            if numrecs
+                .checked_mul(record_size)
+                .is_none_or(|bytes| bytes > remaining) =>
+        {
+            Err(invalid(&format!(
+                "{numrecs} records extend past the end of the file"
+            )))
+        }
+        Some(numrecs) => Ok(numrecs),
+    }
+}
+
+/// Whether a variable extends along the unlimited dimension
+fn is_record(variable: &Variable, dimensions: &[(String, u64)]) -> bool {
+    variable
+        .dimensions
+        .first()
+        .is_some_and(|id| dimensions[*id].1 == 0)
+}
+
+/// Bytes of a fixed variable, or of one record of a record variable
+fn record_bytes(variable: &Variable, dimensions: &[(String, u64)]) -> LoaderResult<u64> {
+    let (_, size) = element(variable.nc_type)?;
+    variable
+        .dimensions
+        .iter()
+        .map(|id| dimensions[*id].1)
+        .filter(|length| *length != 0)
+        .try_fold(size as u64, u64::checked_mul)
+        .ok_or_else(|| invalid(&format!("variable '{}' is too large", variable.name)))
+}
+
+/// Element encoding and size in bytes of an `nc_type`
+fn element(nc_type: u32) -> LoaderResult<(Element, usize)> {
+    let (dtype, size) = match nc_type {
+        1 => (ArrayDtype::Int(8), 1),
+        NC_CHAR => (ArrayDtype::String, 1),
+        3 => (ArrayDtype::Int(16), 2),
+        4 => (ArrayDtype::Int(32), 4),
+        5 => (ArrayDtype::Float(32), 4),
+        6 => (ArrayDtype::Float(64), 8),
+        7 => (ArrayDtype::UInt(8), 1),
+        8 => (ArrayDtype::UInt(16), 2),
+        9 => (ArrayDtype::UInt(32), 4),
+        10 => (ArrayDtype::Int(64), 8),
+        11 => (ArrayDtype::UInt(64), 8),
+        other => return Err(invalid(&format!("unknown data type {other}"))),
+    };
+    Ok((Element::number(dtype, size, true), size))
+}
+
+/// Reader of the big-endian header
+struct Header<R> {
+    reader: R,
+    version: u8,
+}
+
+impl<R: Read + Seek> Header<R> {
+    fn new(mut reader: R) -> LoaderResult<Self> {
+        let mut magic = [0; 8];
+        let read = reader.read(&mut magic)?;
+        if magic[..read].starts_with(HDF5_SIGNATURE) {
+            return Err(LoaderError::InvalidFormat(
+                "NetCDF-4 (HDF5) files are not supported; convert them with `nccopy -k cdf5`"
+                    .to_string(),
+            ));
+        }
+        match magic {
+            [b'C', b'D', b'F', version @ (1 | 2 | 5), ..] if read >= 4 => {
+                Ok(Self { reader, version })
+            }
+            _ => Err(LoaderError::InvalidFormat(
+                "Not a NetCDF classic file".to_string(),
+            )),
+        }
+    }
+
+    fn streaming_marker(&self) -> u64 {
+        if self.version == 5 {
+            u64::MAX
+        } else {
+            u64::from(u32::MAX)
+        }
+    }
+
+    fn read_u32(&mut self) -> LoaderResult<u32> {
+        let mut bytes = [0; 4];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(u32::from_be_bytes(bytes))
+    }
+
+    fn read_u64(&mut self) -> LoaderResult<u64> {
+        let mut bytes = [0; 8];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    /// Lengths and counts: 64-bit in CDF-5, 32-bit otherwise
+    fn read_non_neg(&mut self) -> LoaderResult<u64> {
+        if self.version == 5 {
+            self.read_u64()
+        } else {
+            self.read_u32().map(u64::from)
+        }
+    }
+
+    /// File offsets: 32-bit in CDF-1, 64-bit otherwise
+    fn read_offset(&mut self) -> LoaderResult<u64> {
+        if self.version == 1 {
+            self.read_u32().map(u64::from)
+        } else {
+            self.read_u64()
+        }
+    }
+
+    fn read_padded(&mut self, length: u64) -> LoaderResult<Vec<u8>> {
+        let length = usize::try_from(length).map_err(|_| invalid("header entry too long"))?;
+        let padded = length
+            .checked_add((4 - length % 4) % 4)
+            .ok_or_else(|| invalid("header entry too long"))?;
+        let mut bytes = Vec::new();
+        (&mut self.reader)
+            .take(padded as u64)
+            .read_to_end(&mut bytes)?;
+        if bytes.len() < length {
+            return Err(invalid("header ends early"));
+        }
+        bytes.truncate(length);
+        Ok(bytes)
+    }
+
+    fn read_name(&mut self) -> LoaderResult<String> {
+        let length = self.read_non_neg()?;
+        let bytes = self.read_padded(length)?;
+        String::from_utf8(bytes).map_err(|_| invalid("name is not UTF-8"))
+    }
+
+    /// Number of entries of a list with the given tag; zero if absent
+    fn read_list_length(&mut self, tag: u32) -> LoaderResult<u64> {
+        let found = self.read_u32()?;
+        let length = self.read_non_neg()?;
+        if found == tag || (found == 0 && length == 0) {
+            Ok(length)
+        } else {
+            Err(invalid(&format!(
+                "expected list tag {tag:#x}, found {found:#x}"
+            )))
+        }
+    }
+
+    fn read_attributes(&mut self) -> LoaderResult<Map<String, Value>> {
+        let mut attributes = Map::new();
+        for _ in 0..self.read_list_length(NC_ATTRIBUTE)? {
+            let name = self.read_name()?;
+            let nc_type = self.read_u32()?;
+            let count = self.read_non_neg()?;
+            let (element, size) = element(nc_type)?;
+            let bytes = self.read_padded(count.saturating_mul(size as u64))?;
+            let value = if nc_type == NC_CHAR {
+                let end = bytes
+                    .iter()
+                    .position(|byte| *byte == 0)
+                    .unwrap_or(bytes.len());
+                Value::String(String::from_utf8_lossy(&bytes[..end]).into_owned())
+            } else {
+                let mut values = element.decode_all(&bytes);
+                if values.len() == 1 {
+                    values.remove(0)
+                } else {
+                    Value::Array(values)
+                }
+            };
+            attributes.insert(name, value);
+        }
+        Ok(attributes)
+    }
+
+    fn read_variable(&mut self, dimension_count: usize) -> LoaderResult<Variable> {
+        let name = self.read_name()?;
+        let mut dimensions = Vec::new();
+        for _ in 0..self.read_non_neg()? {
+            let id = usize::try_from(self.read_non_neg()?)
+                .ok()
+                .filter(|id| *id < dimension_count)
+                .ok_or_else(|| invalid(&format!("variable '{name}' has an unknown dimension")))?;
+            dimensions.push(id);
+        }
+        Ok(Variable {
+            attributes: self.read_attributes()?,
+            nc_type: self.read_u32()?,
+            vsize: self.read_non_neg()?,
+            begin: self.read_offset()?,
+            name,
+            dimensions,
+        })
+    }
+
+    /// Data of a variable, with the records of a record variable joined
+    fn read_data(&mut self, variable: &Variable, layout: &Layout) -> LoaderResult<Vec<u8>> {
+        let length = usize::try_from(record_bytes(variable, &layout.dimensions)?)
+            .map_err(|_| invalid("variable too large"))?;
+        if !is_record(variable, &layout.dimensions) {
+            return self.read_at(variable.begin, length, layout.stream_length, &variable.name);
+        }
+        let too_large = || invalid(&format!("variable '{}' is too large", variable.name));
+        let mut bytes =
+            Vec::with_capacity(length.checked_mul(layout.records).ok_or_else(too_large)?);
+        for record in 0..layout.records as u64 {
+            let start = record
+                .checked_mul(layout.record_size)
+                .and_then(|offset| offset.checked_add(variable.begin))
+                .ok_or_else(too_large)?;
+            bytes.extend(self.read_at(start, length, layout.stream_length, &variable.name)?);
+        }
+        Ok(bytes)
+    }
+
+    /// Read variable data, checking that it lies within the file
+    fn read_at(
+        &mut self,
+        start: u64,
+        length: usize,
+        stream_length: u64,
+        variable: &str,
+    ) -> LoaderResult<Vec<u8>> {
+        if start.saturating_add(length as u64) > stream_length {
+            return Err(invalid(&format!(
+                "data of variable '{variable}' extends past the end of the file"
+            )));
+        }
+        self.reader.seek(SeekFrom::Start(start))?;
+        let mut bytes = vec![0; length];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+fn invalid(message: &str) -> LoaderError {
+    LoaderError::InvalidFormat(format!("Invalid NetCDF file: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// CDF-1 file with a fixed `depth(z)` variable, a record variable
+    /// `level(time)` and a `site` attribute
+    fn sample() -> Vec<u8> {
+        fn name(bytes: &mut Vec<u8>, name: &str) {
+            bytes.extend((name.len() as u32).to_be_bytes());
+            bytes.extend(name.as_bytes());
+            bytes.resize(bytes.len().next_multiple_of(4), 0);
+        }
+        let mut bytes = b"CDF\x01".to_vec();
+        bytes.extend(2u32.to_be_bytes()); // numrecs
+        bytes.extend([NC_DIMENSION, 2].iter().flat_map(|v| v.to_be_bytes()));
+        name(&mut bytes, "time");
+        bytes.extend(0u32.to_be_bytes());
+        name(&mut bytes, "z");
+        bytes.extend(3u32.to_be_bytes());
+        bytes.extend([NC_ATTRIBUTE, 1].iter().flat_map(|v| v.to_be_bytes()));
+        name(&mut bytes, "site");
+        bytes.extend([NC_CHAR, 4].iter().flat_map(|v| v.to_be_bytes()));
+        bytes.extend(b"pier");
+        bytes.extend([NC_VARIABLE, 2].iter().flat_map(|v| v.to_be_bytes()));
+
+        // Two variable entries of 40 and 72 bytes follow
+        let header_length = bytes.len() + 40 + 72;
+        name(&mut bytes, "depth");
+        bytes.extend([1, 1].iter().flat_map(|v: &u32| v.to_be_bytes()));
+        bytes.extend([0, 0].iter().flat_map(|v: &u32| v.to_be_bytes()));
+        bytes.extend([4, 12].iter().flat_map(|v: &u32| v.to_be_bytes()));
+        bytes.extend((header_length as u32).to_be_bytes());
+        name(&mut bytes, "level");
+        bytes.extend([1, 0].iter().flat_map(|v: &u32| v.to_be_bytes()));
+        bytes.extend([NC_ATTRIBUTE, 1].iter().flat_map(|v| v.to_be_bytes()));
+        name(&mut bytes, "_FillValue");
+        bytes.extend([6, 1].iter().flat_map(|v: &u32| v.to_be_bytes()));
+        bytes.extend((-1.0f64).to_be_bytes());
+        bytes.extend([6, 8].iter().flat_map(|v: &u32| v.to_be_bytes()));
+        bytes.extend(((header_length + 12) as u32).to_be_bytes());
+        assert_eq!(bytes.len(), header_length);
+
+        bytes.extend([10i32, 20, 30].iter().flat_map(|v| v.to_be_bytes()));
+        bytes.extend([1.5f64, -1.0].iter().flat_map(|v| v.to_be_bytes()));
+        bytes
+    }
+
+    #[tokio::test]
+    async fn test_netcdf_loader() {
+        let dataset = read_netcdf(Cursor::new(sample()), true).expect("read");
+        assert_eq!(dataset.attributes["site"], json!("pier"));
+        assert_eq!(dataset.variables[0].dtype, ArrayDtype::Int(32));
+        assert_eq!(
+            dataset.variables[0].values,
+            Some(vec![json!(10), json!(20), json!(30)])
+        );
+        assert_eq!(dataset.variables[1].shape, vec![2]);
+        assert_eq!(dataset.variables[1].dimension_names, vec!["time"]);
+        assert_eq!(
+            dataset.variables[1].values,
+            Some(vec![json!(1.5), Value::Null])
+        );
+
+        let mut schema = SchemaDefinition::default();
+        schema.classes.insert(
+            "Profile".to_string(),
+            ClassDefinition {
+                tree_root: Some(true),
+                attributes: [(
+                    "depth".to_string(),
+                    SlotDefinition {
+                        range: Some("integer".to_string()),
+                        array: Some(ArrayExpression {
+                            dimensions: vec![DimensionExpression {
+                                exact_cardinality: Some(4),
+                                ..Default::default()
+                            }],
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            },
+        );
+        let error = NetCdfLoader::new()
+            .load_bytes(&sample(), &schema, &LoadOptions::default())
+            .await
+            .expect_err("depth has 3 levels");
+        assert!(error.to_string().contains("length 3, expected 4"));
+        assert!(read_netcdf(Cursor::new(HDF5_SIGNATURE.to_vec()), false).is_err());
+
+        let mut bytes = sample();
+        bytes[4..8].copy_from_slice(&1_000_000_000u32.to_be_bytes());
+        let error = read_netcdf(Cursor::new(bytes), false).expect_err("numrecs past the end");
+        assert!(
+            error
+                .to_string()
+                .contains("records extend past the end of the file")
+        );
+    }
+}
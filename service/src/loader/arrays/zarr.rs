@@ -0,0 +1,858 @@
+//! Zarr store loader and dumper
+//!
+//! Reads Zarr v2 (`.zgroup`, `.zarray`, `.zattrs`) and v3 (`zarr.json`)
+//! stores from the file system. A store holding a single array yields a
+//! dataset with that array, named after the store directory; a group yields
+//! its child arrays. Chunks may be uncompressed or use the `zlib` or `gzip`
+//! codec; chunks missing from the store hold the fill value.
+//!
+//! Stores are written as uncompressed Zarr v2, each array in a single chunk
+//! with its dimension names in `_ARRAY_DIMENSIONS` as xarray expects.
+
+use super::{
+    ArrayDataset, ArrayDtype, ArrayOptions, ArrayVariable, Element, build_instance, check_name,
+    instance_datasets,
+};
+use crate::loader::normalize::apply_normalizers;
+use crate::loader::traits::{
+    DataDumper, DataInstance, DataLoader, DumpOptions, DumperError, DumperResult, LoadOptions,
+    LoaderError, LoaderResult,
+};
+use async_trait::async_trait;
+use linkml_core::prelude::*;
+use serde_json::{Map, Value};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Loader for Zarr stores
+pub struct ZarrLoader {
+    options: ArrayOptions,
+}
+
+impl Default for ZarrLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ZarrLoader {
+    /// Create a new Zarr loader
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            options: ArrayOptions::default(),
+        }
+    }
+
+    /// Create a Zarr loader with options
+    #[must_use]
+    pub fn with_options(options: ArrayOptions) -> Self {
+        Self { options }
+    }
+}
+
+#[async_trait]
+impl DataLoader for ZarrLoader {
+    fn name(&self) -> &'static str {
+        "zarr"
+    }
+
+    fn description(&self) -> &'static str {
+        "Loads array data from Zarr v2 and v3 stores"
+    }
+
+    fn supported_extensions(&self) -> Vec<&str> {
+        vec![".zarr"]
+    }
+
+    async fn load_file(
+        &self,
+        path: &Path,
+        schema: &SchemaDefinition,
+        options: &LoadOptions,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        let store = path.to_path_buf();
+        let array_options = self.options.clone();
+        let dataset = tokio::task::spawn_blocking(move || read_store(&store, &array_options))
+            .await
+            .map_err(|e| LoaderError::Other(Box::new(e)))??;
+        let instance = build_instance(
+            dataset,
+            schema,
+            options,
+            &path.display().to_string(),
+            "zarr",
+        )?;
+        apply_normalizers(vec![instance], schema, options)
+    }
+
+    async fn load_string(
+        &self,
+        _content: &str,
+        _schema: &SchemaDefinition,
+        _options: &LoadOptions,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        Err(LoaderError::Configuration(
+            "Zarr stores are directories; use load_file".to_string(),
+        ))
+    }
+
+    async fn load_bytes(
+        &self,
+        _data: &[u8],
+        _schema: &SchemaDefinition,
+        _options: &LoadOptions,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        Err(LoaderError::Configuration(
+            "Zarr stores are directories; use load_file".to_string(),
+        ))
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> LoaderResult<()> {
+        if schema.classes.is_empty() {
+            return Err(LoaderError::SchemaValidation(
+                "Schema has no classes to load Zarr data into".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Read the arrays and attributes of a Zarr group or array
+///
+/// # Errors
+///
+/// Returns an error if the path is not a Zarr store, if its metadata is
+/// invalid or uses unsupported codecs, or if a chunk cannot be read.
+pub fn read_store(store: &Path, options: &ArrayOptions) -> LoaderResult<ArrayDataset> {
+    let node = match &options.group {
+        Some(group) => store.join(group.trim_matches('/')),
+        None => store.to_path_buf(),
+    };
+    let name = node
+        .file_name()
+        .map(|name| name.to_string_lossy().trim_end_matches(".zarr").to_string())
+        .unwrap_or_default();
+
+    let (attributes, arrays) = if node.join("zarr.json").is_file() {
+        let metadata = read_json(&node.join("zarr.json"))?;
+        let attributes = object(metadata.get("attributes"));
+        if metadata.get("node_type").and_then(Value::as_str) == Some("array") {
+            (attributes, vec![(name, ZarrArray::v3(&node, &metadata)?)])
+        } else {
+            let mut arrays = Vec::new();
+            for (child_name, child) in children(&node)? {
+                let path = child.join("zarr.json");
+                if path.is_file() {
+                    let metadata = read_json(&path)?;
+                    if metadata.get("node_type").and_then(Value::as_str) == Some("array") {
+                        arrays.push((child_name, ZarrArray::v3(&child, &metadata)?));
+                    }
+                }
+            }
+            (attributes, arrays)
+        }
+    } else if node.join(".zarray").is_file() {
+        let array = ZarrArray::v2(&node)?;
+        (array.attributes.clone(), vec![(name, array)])
+    } else if node.join(".zgroup").is_file() {
+        let attributes = read_attributes(&node)?;
+        let mut arrays = Vec::new();
+        for (child_name, child) in children(&node)? {
+            if child.join(".zarray").is_file() {
+                arrays.push((child_name, ZarrArray::v2(&child)?));
+            }
+        }
+        (attributes, arrays)
+    } else {
+        return Err(LoaderError::InvalidFormat(format!(
+            "{} is not a Zarr store",
+            node.display()
+        )));
+    };
+
+    let mut variables = Vec::new();
+    for (name, array) in arrays {
+        let values = if options.load_values {
+            Some(array.read_values()?)
+        } else {
+            None
+        };
+        variables.push(ArrayVariable {
+            name,
+            dtype: array.element.dtype,
+            shape: array.shape,
+            dimension_names: array.dimension_names,
+            values,
+            attributes: array.attributes,
+        });
+    }
+    Ok(ArrayDataset {
+        attributes,
+        variables,
+    })
+}
+
+/// Dumper writing instances to Zarr stores
+///
+/// A single instance is written to the root group of the store; several
+/// instances each to a group of their own.
+#[derive(Default)]
+pub struct ZarrDumper;
+
+impl ZarrDumper {
+    /// Create a new Zarr dumper
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl DataDumper for ZarrDumper {
+    fn name(&self) -> &'static str {
+        "zarr"
+    }
+
+    fn description(&self) -> &'static str {
+        "Dumps array data to Zarr v2 stores"
+    }
+
+    fn supported_extensions(&self) -> Vec<&str> {
+        vec![".zarr"]
+    }
+
+    async fn dump_file(
+        &self,
+        instances: &[DataInstance],
+        path: &Path,
+        schema: &SchemaDefinition,
+        options: &DumpOptions,
+    ) -> DumperResult<()> {
+        let mut datasets = instance_datasets(instances, schema, options)?;
+        let (root, groups) = if datasets.len() == 1 {
+            (datasets.remove(0).1, Vec::new())
+        } else {
+            (ArrayDataset::default(), datasets)
+        };
+        let store = path.to_path_buf();
+        tokio::task::spawn_blocking(move || write_store(&store, &root, &groups))
+            .await
+            .map_err(|e| DumperError::Other(Box::new(e)))?
+    }
+
+    async fn dump_string(
+        &self,
+        _instances: &[DataInstance],
+        _schema: &SchemaDefinition,
+        _options: &DumpOptions,
+    ) -> DumperResult<String> {
+        Err(DumperError::Configuration(
+            "Zarr stores are directories; use dump_file".to_string(),
+        ))
+    }
+
+    async fn dump_bytes(
+        &self,
+        _instances: &[DataInstance],
+        _schema: &SchemaDefinition,
+        _options: &DumpOptions,
+    ) -> DumperResult<Vec<u8>> {
+        Err(DumperError::Configuration(
+            "Zarr stores are directories; use dump_file".to_string(),
+        ))
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> DumperResult<()> {
+        if schema.classes.is_empty() {
+            return Err(DumperError::SchemaValidation(
+                "Schema has no classes to dump Zarr data from".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Write a dataset to the root group of a Zarr v2 store and `groups` to
+/// child groups of the same name
+///
+/// # Errors
+///
+/// Returns an error if a name cannot be used in the store, if values do not
+/// fit the data type of their array, or if the store cannot be written.
+pub fn write_store(
+    store: &Path,
+    dataset: &ArrayDataset,
+    groups: &[(String, ArrayDataset)],
+) -> DumperResult<()> {
+    std::fs::create_dir_all(store)?;
+    write_json(
+        &store.join(".zgroup"),
+        &serde_json::json!({"zarr_format": 2}),
+    )?;
+    write_json(
+        &store.join(".zattrs"),
+        &Value::Object(dataset.attributes.clone()),
+    )?;
+    for variable in &dataset.variables {
+        check_name(&variable.name)?;
+        write_array(&store.join(&variable.name), variable)?;
+    }
+    for (name, group) in groups {
+        check_name(name)?;
+        write_store(&store.join(name), group, &[])?;
+    }
+    Ok(())
+}
+
+/// Write an array as a single uncompressed chunk
+fn write_array(path: &Path, variable: &ArrayVariable) -> DumperResult<()> {
+    let values = variable.values.as_deref().unwrap_or_default();
+    let element = Element::for_values(variable.dtype, values, true);
+    let data = element
+        .encode_all(values)
+        .map_err(|e| DumperError::TypeConversion(format!("Array '{}': {e}", variable.name)))?;
+    let dtype = match variable.dtype {
+        ArrayDtype::Bool => "|b1".to_string(),
+        ArrayDtype::Int(bits) => format!("<i{}", bits / 8),
+        ArrayDtype::UInt(bits) => format!("<u{}", bits / 8),
+        ArrayDtype::Float(bits) => format!("<f{}", bits / 8),
+        ArrayDtype::String => format!("<U{}", element.size / 4),
+    };
+    let fill_value = match variable.dtype {
+        ArrayDtype::Float(_) => Value::from("NaN"),
+        _ => Value::Null,
+    };
+    let chunks: Vec<usize> = variable
+        .shape
+        .iter()
+        .map(|length| (*length).max(1))
+        .collect();
+
+    std::fs::create_dir_all(path)?;
+    write_json(
+        &path.join(".zarray"),
+        &serde_json::json!({
+            "zarr_format": 2,
+            "shape": variable.shape,
+            "chunks": chunks,
+            "dtype": dtype,
+            "compressor": null,
+            "fill_value": fill_value,
+            "order": "C",
+            "filters": null,
+        }),
+    )?;
+    let mut attributes = variable.attributes.clone();
+    if !variable.dimension_names.is_empty() {
+        attributes.insert(
+            "_ARRAY_DIMENSIONS".to_string(),
+            Value::from(variable.dimension_names.clone()),
+        );
+    }
+    write_json(&path.join(".zattrs"), &Value::Object(attributes))?;
+    if !data.is_empty() {
+        let key = vec!["0"; variable.shape.len().max(1)].join(".");
+        std::fs::write(path.join(key), data)?;
+    }
+    Ok(())
+}
+
+/// Write a JSON document of the store
+fn write_json(path: &Path, value: &Value) -> DumperResult<()> {
+    let json =
+        serde_json::to_vec_pretty(value).map_err(|e| DumperError::Serialization(e.to_string()))?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Byte-to-byte codec of chunks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Codec {
+    Zlib,
+    Gzip,
+    Crc32c,
+}
+
+/// Metadata of a Zarr array
+#[derive(Debug)]
+struct ZarrArray {
+    path: PathBuf,
+    shape: Vec<usize>,
+    chunks: Vec<usize>,
+    element: Element,
+    fill_value: Value,
+    column_major: bool,
+    /// Codecs in the order they were applied when writing
+    codecs: Vec<Codec>,
+    /// Prefix of chunk keys (`c` in Zarr v3)
+    key_prefix: Option<&'static str>,
+    separator: String,
+    dimension_names: Vec<String>,
+    attributes: Map<String, Value>,
+}
+
+impl ZarrArray {
+    /// Array described by the `.zarray` of a Zarr v2 store
+    fn v2(path: &Path) -> LoaderResult<Self> {
+        let metadata = read_json(&path.join(".zarray"))?;
+        let mut attributes = read_attributes(path)?;
+        let dimension_names = match attributes.remove("_ARRAY_DIMENSIONS") {
+            Some(Value::Array(names)) => names
+                .iter()
+                .map(|name| name.as_str().unwrap_or_default().to_string())
+                .collect(),
+            _ => Vec::new(),
+        };
+        let dtype = metadata
+            .get("dtype")
+            .and_then(Value::as_str)
+            .ok_or_else(|| {
+                invalid(
+                    path,
+                    "dtype must be a string; structured types are not supported",
+                )
+            })?;
+
+        let mut codecs = Vec::new();
+        if let Some(compressor) = metadata.get("compressor").filter(|value| !value.is_null()) {
+            codecs.push(codec(path, compressor.get("id"))?);
+        }
+        if metadata
+            .get("filters")
+            .and_then(Value::as_array)
+            .is_some_and(|filters| !filters.is_empty())
+        {
+            return Err(invalid(path, "filters are not supported"));
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            shape: dimensions(path, metadata.get("shape"))?,
+            chunks: dimensions(path, metadata.get("chunks"))?,
+            element: v2_element(path, dtype)?,
+            fill_value: fill_value(metadata.get("fill_value")),
+            column_major: metadata.get("order").and_then(Value::as_str) == Some("F"),
+            codecs,
+            key_prefix: None,
+            separator: metadata
+                .get("dimension_separator")
+                .and_then(Value::as_str)
+                .unwrap_or(".")
+                .to_string(),
+            dimension_names,
+            attributes,
+        })
+    }
+
+    /// Array described by the `zarr.json` of a Zarr v3 store
+    fn v3(path: &Path, metadata: &Value) -> LoaderResult<Self> {
+        let shape = dimensions(path, metadata.get("shape"))?;
+        let chunk_grid = metadata.get("chunk_grid").unwrap_or(&Value::Null);
+        if chunk_grid.get("name").and_then(Value::as_str) != Some("regular") {
+            return Err(invalid(path, "only regular chunk grids are supported"));
+        }
+        let chunks = dimensions(path, chunk_grid.pointer("/configuration/chunk_shape"))?;
+
+        let key_encoding = metadata.get("chunk_key_encoding").unwrap_or(&Value::Null);
+        let v2_keys = key_encoding.get("name").and_then(Value::as_str) == Some("v2");
+        let separator = key_encoding
+            .pointer("/configuration/separator")
+            .and_then(Value::as_str)
+            .unwrap_or(if v2_keys { "." } else { "/" })
+            .to_string();
+
+        let mut big_endian = false;
+        let mut codecs = Vec::new();
+        for entry in metadata
+            .get("codecs")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            match entry.get("name").and_then(Value::as_str) {
+                Some("bytes") => {
+                    big_endian = entry
+                        .pointer("/configuration/endian")
+                        .and_then(Value::as_str)
+                        == Some("big");
+                }
+                Some("transpose") => {
+                    let identity = entry
+                        .pointer("/configuration/order")
+                        .and_then(Value::as_array)
+                        .is_some_and(|order| {
+                            order
+                                .iter()
+                                .enumerate()
+                                .all(|(index, axis)| axis.as_u64() == Some(index as u64))
+                        });
+                    if !identity {
+                        return Err(invalid(path, "the transpose codec is not supported"));
+                    }
+                }
+                name => codecs.push(codec(path, name.map(Value::from).as_ref())?),
+            }
+        }
+
+        let data_type = metadata
+            .get("data_type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid(path, "data_type must be a string"))?;
+        let element = v3_element(path, data_type, big_endian)?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            shape,
+            chunks,
+            element,
+            fill_value: fill_value(metadata.get("fill_value")),
+            column_major: false,
+            codecs,
+            key_prefix: (!v2_keys).then_some("c"),
+            separator,
+            dimension_names: metadata
+                .get("dimension_names")
+                .and_then(Value::as_array)
+                .map(|names| {
+                    names
+                        .iter()
+                        .map(|name| name.as_str().unwrap_or_default().to_string())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            attributes: object(metadata.get("attributes")),
+        })
+    }
+
+    /// File of the chunk at the given grid coordinates
+    fn chunk_path(&self, coordinates: &[usize]) -> PathBuf {
+        let mut parts: Vec<String> = self.key_prefix.map(str::to_string).into_iter().collect();
+        if coordinates.is_empty() && self.key_prefix.is_none() {
+            parts.push("0".to_string());
+        }
+        parts.extend(coordinates.iter().map(ToString::to_string));
+        self.path.join(parts.join(&self.separator))
+    }
+
+    /// All elements in row-major order
+    fn read_values(&self) -> LoaderResult<Vec<Value>> {
+        if self.chunks.len() != self.shape.len() {
+            return Err(invalid(
+                &self.path,
+                "chunk shape does not match the array shape",
+            ));
+        }
+        let total: usize = self.shape.iter().product();
+        let mut values = vec![self.fill_value.clone(); total];
+        if total == 0 {
+            return Ok(values);
+        }
+        if self.chunks.contains(&0) {
+            return Err(invalid(&self.path, "chunk shape has an empty dimension"));
+        }
+
+        let grid: Vec<usize> = self
+            .shape
+            .iter()
+            .zip(&self.chunks)
+            .map(|(length, chunk)| length.div_ceil(*chunk))
+            .collect();
+        let chunk_length: usize = self.chunks.iter().product();
+        for chunk_index in 0..grid.iter().product() {
+            let coordinates = unravel(chunk_index, &grid, false);
+            let path = self.chunk_path(&coordinates);
+            if !path.is_file() {
+                continue;
+            }
+            let elements = self
+                .element
+                .decode_all(&self.decode(std::fs::read(&path)?)?);
+            if elements.len() < chunk_length {
+                return Err(LoaderError::InvalidFormat(format!(
+                    "Chunk {} has {} elements, expected {chunk_length}",
+                    path.display(),
+                    elements.len()
+                )));
+            }
+
+            for (offset, value) in elements.into_iter().take(chunk_length).enumerate() {
+                let local = unravel(offset, &self.chunks, self.column_major);
+                let mut flat = 0;
+                let mut inside = true;
+                for (dimension, length) in self.shape.iter().enumerate() {
+                    let index = coordinates[dimension] * self.chunks[dimension] + local[dimension];
+                    inside &= index < *length;
+                    flat = flat * length + index;
+                }
+                if inside {
+                    values[flat] = value;
+                }
+            }
+        }
+        Ok(values)
+    }
+
+    /// Undo the byte-to-byte codecs of a chunk
+    fn decode(&self, mut bytes: Vec<u8>) -> LoaderResult<Vec<u8>> {
+        for codec in self.codecs.iter().rev() {
+            bytes = match codec {
+                Codec::Crc32c => {
+                    bytes.truncate(bytes.len().saturating_sub(4));
+                    bytes
+                }
+                Codec::Zlib => {
+                    let mut decoded = Vec::new();
+                    flate2::read::ZlibDecoder::new(bytes.as_slice()).read_to_end(&mut decoded)?;
+                    decoded
+                }
+                Codec::Gzip => {
+                    let mut decoded = Vec::new();
+                    flate2::read::GzDecoder::new(bytes.as_slice()).read_to_end(&mut decoded)?;
+                    decoded
+                }
+            };
+        }
+        Ok(bytes)
+    }
+}
+
+/// Indices of a flat offset within a shape
+fn unravel(mut offset: usize, shape: &[usize], column_major: bool) -> Vec<usize> {
+    let mut indices = vec![0; shape.len()];
+    let order: Vec<usize> = if column_major {
+        (0..shape.len()).collect()
+    } else {
+        (0..shape.len()).rev().collect()
+    };
+    for dimension in order {
+        indices[dimension] = offset % shape[dimension];
+        offset /= shape[dimension];
+    }
+    indices
+}
+
+/// Element type of a Zarr v2 `dtype` such as `<f8` or `|b1`
+fn v2_element(path: &Path, dtype: &str) -> LoaderResult<Element> {
+    let (byte_order, rest) = match dtype.chars().next() {
+        Some(order @ ('<' | '>' | '|' | '=')) => (order, &dtype[1..]),
+        _ => ('|', dtype),
+    };
+    let big_endian = byte_order == '>';
+    let unsupported = || invalid(path, &format!("unsupported dtype '{dtype}'"));
+    let mut chars = rest.chars();
+    let kind = chars.next().ok_or_else(unsupported)?;
+    let size: usize = chars.as_str().parse().map_err(|_| unsupported())?;
+    let bits = u8::try_from(size * 8).map_err(|_| unsupported())?;
+    Ok(match (kind, size) {
+        ('b', 1) => Element::number(ArrayDtype::Bool, 1, big_endian),
+        ('i', 1 | 2 | 4 | 8) => Element::number(ArrayDtype::Int(bits), size, big_endian),
+        ('u', 1 | 2 | 4 | 8) => Element::number(ArrayDtype::UInt(bits), size, big_endian),
+        ('f', 4 | 8) => Element::number(ArrayDtype::Float(bits), size, big_endian),
+        ('U', _) => Element {
+            dtype: ArrayDtype::String,
+            size: size * 4,
+            big_endian,
+            utf32: true,
+        },
+        ('S', _) => Element::number(ArrayDtype::String, size, big_endian),
+        _ => return Err(unsupported()),
+    })
+}
+
+/// Element type of a Zarr v3 `data_type` such as `float64`
+fn v3_element(path: &Path, data_type: &str, big_endian: bool) -> LoaderResult<Element> {
+    let (dtype, size) = match data_type {
+        "bool" => (ArrayDtype::Bool, 1),
+        "int8" => (ArrayDtype::Int(8), 1),
+        "int16" => (ArrayDtype::Int(16), 2),
+        "int32" => (ArrayDtype::Int(32), 4),
+        "int64" => (ArrayDtype::Int(64), 8),
+        "uint8" => (ArrayDtype::UInt(8), 1),
+        "uint16" => (ArrayDtype::UInt(16), 2),
+        "uint32" => (ArrayDtype::UInt(32), 4),
+        "uint64" => (ArrayDtype::UInt(64), 8),
+        "float32" => (ArrayDtype::Float(32), 4),
+        "float64" => (ArrayDtype::Float(64), 8),
+        _ => {
+            return Err(invalid(
+                path,
+                &format!("unsupported data_type '{data_type}'"),
+            ));
+        }
+    };
+    Ok(Element::number(dtype, size, big_endian))
+}
+
+/// Codec named by a compressor `id` or codec `name`
+fn codec(path: &Path, name: Option<&Value>) -> LoaderResult<Codec> {
+    match name.and_then(Value::as_str) {
+        Some("zlib") => Ok(Codec::Zlib),
+        Some("gzip") => Ok(Codec::Gzip),
+        Some("crc32c") => Ok(Codec::Crc32c),
+        Some(name) => Err(invalid(
+            path,
+            &format!("unsupported codec '{name}'; supported are zlib and gzip"),
+        )),
+        None => Err(invalid(path, "codec without a name")),
+    }
+}
+
+/// JSON value of a fill value; NaN and infinities become `null`
+fn fill_value(value: Option<&Value>) -> Value {
+    match value {
+        Some(Value::String(text)) if matches!(text.as_str(), "NaN" | "Infinity" | "-Infinity") => {
+            Value::Null
+        }
+        Some(value) => value.clone(),
+        None => Value::Null,
+    }
+}
+
+fn dimensions(path: &Path, value: Option<&Value>) -> LoaderResult<Vec<usize>> {
+    value
+        .and_then(Value::as_array)
+        .ok_or_else(|| invalid(path, "shape must be an array"))?
+        .iter()
+        .map(|length| {
+            length
+                .as_u64()
+                .and_then(|length| usize::try_from(length).ok())
+                .ok_or_else(|| invalid(path, "shape must hold non-negative integers"))
+        })
+        .collect()
+}
+
+/// Child directories of a group, sorted by name
+fn children(group: &Path) -> LoaderResult<Vec<(String, PathBuf)>> {
+    let mut children = Vec::new();
+    for entry in std::fs::read_dir(group)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            children.push((
+                entry.file_name().to_string_lossy().into_owned(),
+                entry.path(),
+            ));
+        }
+    }
+    children.sort();
+    Ok(children)
+}
+
+fn read_json(path: &Path) -> LoaderResult<Value> {
+    let content = std::fs::read_to_string(path)?;
+    serde_json::from_str(&content)
+        .map_err(|e| LoaderError::Parse(format!("Invalid Zarr metadata {}: {e}", path.display())))
+}
+
+fn read_attributes(node: &Path) -> LoaderResult<Map<String, Value>> {
+    let path = node.join(".zattrs");
+    if path.is_file() {
+        Ok(object(Some(&read_json(&path)?)))
+    } else {
+        Ok(Map::new())
+    }
+}
+
+fn object(value: Option<&Value>) -> Map<String, Value> {
+    value
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn invalid(path: &Path, message: &str) -> LoaderError {
+    LoaderError::InvalidFormat(format!("Zarr array {}: {message}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_zarr_loader() {
+        let store = tempfile::tempdir().expect("temp dir");
+        let root = store.path();
+        std::fs::write(root.join(".zgroup"), r#"{"zarr_format": 2}"#).expect("write");
+        std::fs::write(root.join(".zattrs"), r#"{"station": "north"}"#).expect("write");
+        std::fs::create_dir(root.join("temperature")).expect("dir");
+        std::fs::write(
+            root.join("temperature/.zarray"),
+            r#"{"zarr_format": 2, "shape": [3, 2], "chunks": [2, 2], "dtype": "<f8",
+                "compressor": null, "fill_value": "NaN", "order": "C", "filters": null}"#,
+        )
+        .expect("write");
+        let chunk: Vec<u8> = [1.0f64, 2.0, 3.0, 4.0]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+        std::fs::write(root.join("temperature/0.0"), &chunk).expect("write");
+
+        let mut schema = SchemaDefinition::default();
+        schema.classes.insert(
+            "Series".to_string(),
+            ClassDefinition {
+                attributes: [
+                    (
+                        "temperature".to_string(),
+                        SlotDefinition {
+                            range: Some("float".to_string()),
+                            array: Some(ArrayExpression {
+                                exact_number_dimensions: Some(2),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                    ),
+                    ("station".to_string(), SlotDefinition::default()),
+                ]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            },
+        );
+        let options = LoadOptions {
+            target_class: Some("Series".to_string()),
+            ..Default::default()
+        };
+
+        let instances = ZarrLoader::new()
+            .load_file(root, &schema, &options)
+            .await
+            .expect("load");
+        assert_eq!(
+            instances[0].data["temperature"],
+            json!([[1.0, 2.0], [3.0, 4.0], [null, null]])
+        );
+        assert_eq!(instances[0].data["station"], json!("north"));
+
+        let copy = tempfile::tempdir().expect("temp dir");
+        ZarrDumper::new()
+            .dump_file(&instances, copy.path(), &schema, &DumpOptions::default())
+            .await
+            .expect("dump");
+        let reloaded = ZarrLoader::new()
+            .load_file(copy.path(), &schema, &options)
+            .await
+            .expect("reload");
+        assert_eq!(reloaded[0].data, instances[0].data);
+
+        if let Some(slot) = schema
+            .classes
+            .get_mut("Series")
+            .and_then(|class| class.attributes.get_mut("temperature"))
+        {
+            slot.range = Some("integer".to_string());
+        }
+        let loader = ZarrLoader::with_options(ArrayOptions {
+            load_values: false,
+            ..Default::default()
+        });
+        let error = loader
+            .load_file(root, &schema, &options)
+            .await
+            .expect_err("float data in an integer slot");
+        assert!(error.to_string().contains("float64"));
+    }
+}
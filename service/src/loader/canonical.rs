@@ -0,0 +1,411 @@
+//! Canonical serialization of instance data
+//!
+//! With `DumpOptions::canonical` set, the JSON and YAML dumpers write
+//! instances in canonical `LinkML` form, so exporting the same data twice
+//! gives byte-identical output and diffs only show real changes:
+//!
+//! - slots are emitted in schema order: inherited slots first, then mixin
+//!   slots, then the class's own slots and attributes; keys that are not
+//!   slots of the class follow in alphabetical order
+//! - objects of a class with an identifier are written as references (their
+//!   identifier) unless the slot is `inlined`, `inlined_as_list` or
+//!   `inlined_as_dict`; objects of classes without an identifier are always
+//!   inlined
+//! - inlined multivalued slots are written as a dictionary keyed by
+//!   identifier, unless `inlined_as_list` is set
+//! - nulls (unless `include_nulls` is set) and empty collections are dropped
+//!
+//! Unlike the plain dumpers, no `@type` key is added to top-level instances,
+//! as in the Python `LinkML` dumpers. A `@type` already present in the data
+//! is kept as the first key and, like a type designator slot, selects the
+//! class of inlined objects.
+
+use super::inlined::{class_slots, nested_class};
+use super::traits::{DataInstance, DumpOptions, DumperError, DumperResult};
+use linkml_core::prelude::*;
+use serde_json::{Map, Value as JsonValue};
+use serde_yaml::{Mapping, Value as YamlValue};
+
+/// Key naming the class of an object
+const TYPE_KEY: &str = "@type";
+
+/// Converts instances into canonical form
+///
+/// The result is a YAML value because its mappings keep insertion order; it
+/// serializes to JSON unchanged.
+pub struct Canonicalizer<'a> {
+    schema: &'a SchemaDefinition,
+    include_nulls: bool,
+}
+
+impl<'a> Canonicalizer<'a> {
+    /// Create a canonicalizer for a schema
+    #[must_use]
+    pub fn new(schema: &'a SchemaDefinition, options: &DumpOptions) -> Self {
+        Self {
+            schema,
+            include_nulls: options.include_nulls,
+        }
+    }
+
+    /// Canonical form of several instances, in order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an instance cannot be put in canonical form, see
+    /// [`Self::instance`].
+    pub fn instances(&self, instances: &[DataInstance]) -> DumperResult<Vec<YamlValue>> {
+        instances
+            .iter()
+            .map(|instance| self.instance(instance))
+            .collect()
+    }
+
+    /// Canonical form of one instance
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an object written as a dictionary keyed by
+    /// identifier has no identifier, or one that is not a scalar.
+    pub fn instance(&self, instance: &DataInstance) -> DumperResult<YamlValue> {
+        let data: Map<String, JsonValue> = instance
+            .data
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        Ok(YamlValue::Mapping(self.object(
+            &instance.class_name,
+            &data,
+            None,
+        )?))
+    }
+
+    /// Canonical mapping of an object of `class_name`, leaving out `skip`
+    fn object(
+        &self,
+        class_name: &str,
+        data: &Map<String, JsonValue>,
+        skip: Option<&str>,
+    ) -> DumperResult<Mapping> {
+        let mut mapping = Mapping::new();
+        if let Some(value) = data.get(TYPE_KEY) {
+            mapping.insert(YamlValue::from(TYPE_KEY), to_yaml(value));
+        }
+
+        let slots = class_slots(self.schema, class_name);
+        for slot in &slots {
+            if Some(slot.name.as_str()) == skip {
+                continue;
+            }
+            if let Some(value) = data.get(&slot.name)
+                && let Some(value) = self.slot_value(slot, value)?
+            {
+                mapping.insert(YamlValue::from(slot.name.as_str()), value);
+            }
+        }
+
+        let mut extra: Vec<(&String, &JsonValue)> = data
+            .iter()
+            .filter(|(key, _)| {
+                key.as_str() != TYPE_KEY
+                    && Some(key.as_str()) != skip
+                    && !slots.iter().any(|slot| slot.name == **key)
+            })
+            .collect();
+        extra.sort_by_key(|(key, _)| *key);
+        for (key, value) in extra {
+            if let Some(value) = self.plain_value(value) {
+                mapping.insert(YamlValue::from(key.as_str()), value);
+            }
+        }
+        Ok(mapping)
+    }
+
+    /// Canonical value of a slot, or `None` if it is left out
+    fn slot_value(
+        &self,
+        slot: &SlotDefinition,
+        value: &JsonValue,
+    ) -> DumperResult<Option<YamlValue>> {
+        let Some(range) = slot
+            .range
+            .as_deref()
+            .filter(|range| self.schema.classes.contains_key(*range))
+        else {
+            return Ok(self.plain_value(value));
+        };
+        let identifier = self.identifier_slot(range);
+        let inlined = identifier.is_none()
+            || slot.inlined == Some(true)
+            || slot.inlined_as_list == Some(true)
+            || slot.inlined_as_dict == Some(true);
+
+        if slot.multivalued != Some(true) {
+            return if inlined {
+                self.nested(range, value, None)
+            } else {
+                Ok(reference(identifier.as_deref(), value))
+            };
+        }
+
+        let items = collection_items(identifier.as_deref(), value);
+        if items.is_empty() {
+            return Ok(None);
+        }
+        match identifier.as_deref() {
+            Some(id) if inlined && slot.inlined_as_list != Some(true) => {
+                let mut entries = Mapping::new();
+                for item in &items {
+                    let key = dictionary_key(&slot.name, id, item)?;
+                    let entry = self.nested(range, item, Some(id))?;
+                    entries.insert(
+                        YamlValue::from(key),
+                        entry.unwrap_or(YamlValue::Mapping(Mapping::new())),
+                    );
+                }
+                Ok(Some(YamlValue::Mapping(entries)))
+            }
+            _ => {
+                let mut sequence = Vec::with_capacity(items.len());
+                for item in &items {
+                    let item = if inlined {
+                        self.nested(range, item, None)?
+                    } else {
+                        reference(identifier.as_deref(), item)
+                    };
+                    sequence.extend(item);
+                }
+                Ok(Some(YamlValue::Sequence(sequence)))
+            }
+        }
+    }
+
+    /// Canonical form of an inlined object, honouring a type designator
+    fn nested(
+        &self,
+        range: &str,
+        value: &JsonValue,
+        skip: Option<&str>,
+    ) -> DumperResult<Option<YamlValue>> {
+        let JsonValue::Object(data) = value else {
+            return Ok(self.plain_value(value));
+        };
+        let class_name = nested_class(self.schema, value, range);
+        let mapping = self.object(&class_name, data, skip)?;
+        Ok((!mapping.is_empty() || skip.is_some()).then_some(YamlValue::Mapping(mapping)))
+    }
+
+    /// Value of a non-object slot or unknown key, dropping nulls and empty
+    /// collections
+    fn plain_value(&self, value: &JsonValue) -> Option<YamlValue> {
+        match value {
+            JsonValue::Null if !self.include_nulls => None,
+            JsonValue::Array(items) if items.is_empty() => None,
+            JsonValue::Object(map) if map.is_empty() => None,
+            JsonValue::Array(items) => Some(YamlValue::Sequence(
+                items
+                    .iter()
+                    .filter_map(|item| self.plain_value(item))
+                    .collect(),
+            )),
+            JsonValue::Object(map) => {
+                let mut keys: Vec<&String> = map.keys().collect();
+                keys.sort();
+                Some(YamlValue::Mapping(
+                    keys.into_iter()
+                        .filter_map(|key| {
+                            let value = self.plain_value(&map[key])?;
+                            Some((YamlValue::from(key.as_str()), value))
+                        })
+                        .collect(),
+                ))
+            }
+            _ => Some(to_yaml(value)),
+        }
+    }
+
+    /// Name of the identifier (or key) slot of a class
+    fn identifier_slot(&self, class_name: &str) -> Option<String> {
+        let slots = class_slots(self.schema, class_name);
+        slots
+            .iter()
+            .find(|slot| slot.identifier == Some(true))
+            .or_else(|| slots.iter().find(|slot| slot.key == Some(true)))
+            .map(|slot| slot.name.clone())
+    }
+}
+
+/// Identifier of a referenced object, or the value itself if already a
+/// reference
+fn reference(identifier: Option<&str>, value: &JsonValue) -> Option<YamlValue> {
+    match (identifier, value) {
+        (Some(id), JsonValue::Object(data)) => data.get(id).map(to_yaml),
+        (_, JsonValue::Null) => None,
+        _ => Some(to_yaml(value)),
+    }
+}
+
+/// Key of an item of a slot written as a dictionary keyed by identifier
+///
+/// Scalar identifiers are written as strings, as YAML and JSON keys are.
+fn dictionary_key(slot: &str, identifier: &str, item: &JsonValue) -> DumperResult<String> {
+    match item.get(identifier) {
+        Some(JsonValue::String(key)) => Ok(key.clone()),
+        Some(key @ (JsonValue::Number(_) | JsonValue::Bool(_))) => Ok(key.to_string()),
+        Some(JsonValue::Null) | None => Err(DumperError::Serialization(format!(
+            "Entry of '{slot}' has no identifier '{identifier}' to key it by"
+        ))),
+        Some(key) => Err(DumperError::Serialization(format!(
+            "Identifier '{identifier}' of an entry of '{slot}' is not a scalar: {key}"
+        ))),
+    }
+}
+
+/// Items of a multivalued slot given as a list or as a dictionary keyed by
+/// identifier; dictionary entries get their key as identifier
+fn collection_items(identifier: Option<&str>, value: &JsonValue) -> Vec<JsonValue> {
+    match (value, identifier) {
+        (JsonValue::Array(items), _) => items.clone(),
+        (JsonValue::Object(entries), Some(id)) => entries
+            .iter()
+            .map(|(key, entry)| match entry {
+                JsonValue::Object(data) => {
+                    let mut data = data.clone();
+                    data.entry(id.to_string())
+                        .or_insert_with(|| JsonValue::from(key.as_str()));
+                    JsonValue::Object(data)
+                }
+                _ => JsonValue::Object(Map::from_iter([(
+                    id.to_string(),
+                    JsonValue::from(key.as_str()),
+                )])),
+            })
+            .collect(),
+        (JsonValue::Null, _) => Vec::new(),
+        _ => vec![value.clone()],
+    }
+}
+
+fn to_yaml(value: &JsonValue) -> YamlValue {
+    // Every JSON value has a YAML representation
+    serde_yaml::to_value(value).unwrap_or(YamlValue::Null)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn slot(range: &str) -> SlotDefinition {
+        SlotDefinition {
+            range: Some(range.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_canonical_order_and_inlining() {
+        let mut schema = SchemaDefinition::default();
+        let mut id = slot("string");
+        id.identifier = Some(true);
+        schema.slots.insert("id".to_string(), id);
+        schema.slots.insert("name".to_string(), slot("string"));
+        let mut employer = slot("Organization");
+        employer.inlined = Some(false);
+        schema.slots.insert("employer".to_string(), employer);
+        let mut friends = slot("Person");
+        friends.multivalued = Some(true);
+        friends.inlined = Some(true);
+        schema.slots.insert("friends".to_string(), friends);
+        let mut addresses = slot("Address");
+        addresses.multivalued = Some(true);
+        schema.slots.insert("addresses".to_string(), addresses);
+
+        let class = |slots: &[&str]| ClassDefinition {
+            slots: slots.iter().map(ToString::to_string).collect(),
+            ..Default::default()
+        };
+        schema
+            .classes
+            .insert("Named".to_string(), class(&["id", "name"]));
+        let mut person = class(&["employer", "friends", "addresses"]);
+        person.is_a = Some("Named".to_string());
+        schema.classes.insert("Person".to_string(), person);
+        schema
+            .classes
+            .insert("Organization".to_string(), class(&["id"]));
+        let mut address = class(&[]);
+        address
+            .attributes
+            .insert("street".to_string(), slot("string"));
+        schema.classes.insert("Address".to_string(), address);
+
+        let instance = DataInstance {
+            class_name: "Person".to_string(),
+            data: HashMap::from([
+                ("zodiac".to_string(), json!("Leo")),
+                ("addresses".to_string(), json!([{"street": "Main St"}])),
+                ("employer".to_string(), json!({"id": "ORG1"})),
+                (
+                    "friends".to_string(),
+                    json!([{"name": "Bob", "id": "P2", "friends": []}]),
+                ),
+                ("name".to_string(), json!("Ada")),
+                ("nickname".to_string(), JsonValue::Null),
+                ("id".to_string(), json!("P1")),
+            ]),
+            id: None,
+            metadata: HashMap::new(),
+        };
+
+        let canonical = Canonicalizer::new(&schema, &DumpOptions::default())
+            .instance(&instance)
+            .expect("canonical form");
+        assert_eq!(
+            serde_yaml::to_string(&canonical).expect("yaml"),
+            "id: P1\nname: Ada\nemployer: ORG1\nfriends:\n  P2:\n    name: Bob\n\
+             addresses:\n- street: Main St\nzodiac: Leo\n"
+        );
+    }
+
+    #[test]
+    fn test_dictionary_keys() {
+        let mut schema = SchemaDefinition::default();
+        let mut id = slot("integer");
+        id.identifier = Some(true);
+        schema.slots.insert("id".to_string(), id);
+        let mut parts = slot("Part");
+        parts.multivalued = Some(true);
+        parts.inlined_as_dict = Some(true);
+        schema.slots.insert("parts".to_string(), parts);
+        for (name, slots) in [("Part", vec!["id"]), ("Machine", vec!["parts"])] {
+            let class = ClassDefinition {
+                slots: slots.into_iter().map(ToString::to_string).collect(),
+                ..Default::default()
+            };
+            schema.classes.insert(name.to_string(), class);
+        }
+        let canonicalizer = Canonicalizer::new(&schema, &DumpOptions::default());
+        let machine = |parts: JsonValue| DataInstance {
+            class_name: "Machine".to_string(),
+            data: HashMap::from([("parts".to_string(), parts)]),
+            id: None,
+            metadata: HashMap::new(),
+        };
+
+        let canonical = canonicalizer
+            .instance(&machine(json!([{"id": 7}, {"id": true}])))
+            .expect("canonical form");
+        assert_eq!(
+            serde_yaml::to_string(&canonical).expect("YAML"),
+            "parts:\n  '7': {}\n  'true': {}\n"
+        );
+        assert!(canonicalizer.instance(&machine(json!([{}]))).is_err());
+        assert!(
+            canonicalizer
+                .instance(&machine(json!([{"id": [1]}])))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,424 @@
+//! Column-mapping profiles for CSV/TSV loading
+//!
+//! A profile describes how the columns of a third-party table map onto the
+//! slots of a `LinkML` class, so partner files can be ingested without
+//! renaming their columns first:
+//!
+//! ```yaml
+//! name: partner_a
+//! header_case: snake
+//! strict: true
+//! ignore: ["Internal Ref"]
+//! columns:
+//!   Surname: last_name
+//!   Status:
+//!     slot: status
+//!     transforms: [trim, upper]
+//!     lookup:
+//!       A: ACTIVE
+//!       I: INACTIVE
+//! ```
+//!
+//! Headers listed under `columns` are matched exactly, then after applying
+//! `header_case`; other headers are normalized with `header_case` and used as
+//! slot names. With `strict`, the loader rejects files whose columns do not
+//! match slots of the target class or that lack a required slot.
+
+use csv::StringRecord;
+use indexmap::IndexMap;
+use linkml_core::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::traits::{LoaderError, LoaderResult};
+
+/// Column-mapping profile applied by `CsvLoader`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ColumnProfile {
+    /// Profile name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Profile description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Normalization applied to headers before matching
+    #[serde(default)]
+    pub header_case: HeaderCase,
+
+    /// Mappings from source headers to slots
+    #[serde(default)]
+    pub columns: IndexMap<String, ColumnRule>,
+
+    /// Source headers to drop
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+
+    /// Reject unknown columns and missing required slots
+    #[serde(default)]
+    pub strict: bool,
+}
+
+/// Header normalization
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HeaderCase {
+    /// Use headers as they are
+    #[default]
+    Preserve,
+    /// Lowercase headers
+    Lower,
+    /// Convert headers to `snake_case`
+    Snake,
+    /// Convert headers to `camelCase`
+    Camel,
+}
+
+/// Mapping of one source column
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ColumnRule {
+    /// Plain rename to a slot
+    Slot(String),
+    /// Rename with value transformations
+    Mapping(ColumnMapping),
+}
+
+/// Detailed mapping of one source column
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnMapping {
+    /// Target slot, defaulting to the normalized header
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slot: Option<String>,
+
+    /// Transformations applied to values in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transforms: Vec<ValueTransform>,
+
+    /// Replacements for source values, applied after the transformations
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub lookup: HashMap<String, String>,
+
+    /// Value used when the cell is empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+/// Transformation of a cell value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueTransform {
+    /// Remove surrounding whitespace
+    Trim,
+    /// Lowercase the value
+    Lower,
+    /// Uppercase the value
+    Upper,
+    /// Convert the value to `snake_case`
+    Snake,
+}
+
+/// Where a source column ends up after applying a profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnTarget<'a> {
+    /// The column fills this slot
+    Slot(String, Option<&'a ColumnMapping>),
+    /// The column is dropped
+    Ignored,
+}
+
+impl ColumnProfile {
+    /// Parse a profile from YAML
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the YAML is not a valid profile
+    pub fn from_yaml_str(content: &str) -> LoaderResult<Self> {
+        serde_yaml::from_str(content)
+            .map_err(|e| LoaderError::Configuration(format!("Invalid column profile: {e}")))
+    }
+
+    /// Read a profile from a YAML file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid profile
+    pub fn from_file(path: &Path) -> LoaderResult<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_yaml_str(&content)
+    }
+
+    /// Resolve the target of every source header
+    #[must_use]
+    pub fn resolve(&self, headers: &[String]) -> Vec<ColumnTarget<'_>> {
+        headers
+            .iter()
+            .map(|header| {
+                let normalized = self.header_case.apply(header);
+                if self.ignore.iter().any(|ignored| {
+                    ignored == header || self.header_case.apply(ignored) == normalized
+                }) {
+                    return ColumnTarget::Ignored;
+                }
+
+                let rule = self.columns.get(header).or_else(|| {
+                    self.columns
+                        .iter()
+                        .find(|(source, _)| self.header_case.apply(source) == normalized)
+                        .map(|(_, rule)| rule)
+                });
+                match rule {
+                    Some(ColumnRule::Slot(slot)) => ColumnTarget::Slot(slot.clone(), None),
+                    Some(ColumnRule::Mapping(mapping)) => ColumnTarget::Slot(
+                        mapping.slot.clone().unwrap_or(normalized),
+                        Some(mapping),
+                    ),
+                    None => ColumnTarget::Slot(normalized, None),
+                }
+            })
+            .collect()
+    }
+
+    /// Reject resolved columns that do not fit `class_name` when strict
+    ///
+    /// # Errors
+    ///
+    /// Returns a schema validation error listing every header problem
+    pub fn check_headers(
+        &self,
+        targets: &[ColumnTarget<'_>],
+        class_name: &str,
+        schema: &SchemaDefinition,
+    ) -> LoaderResult<()> {
+        if !self.strict {
+            return Ok(());
+        }
+        let problems = Self::header_problems(targets, class_name, schema);
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(LoaderError::SchemaValidation(problems.join("; ")))
+        }
+    }
+
+    /// Check resolved columns against the slots of `class_name`
+    ///
+    /// Returns one message per unknown column or missing required slot.
+    #[must_use]
+    pub fn header_problems(
+        targets: &[ColumnTarget<'_>],
+        class_name: &str,
+        schema: &SchemaDefinition,
+    ) -> Vec<String> {
+        let slots = class_slots(class_name, schema);
+        let mapped: Vec<&str> = targets
+            .iter()
+            .filter_map(|target| match target {
+                ColumnTarget::Slot(slot, _) => Some(slot.as_str()),
+                ColumnTarget::Ignored => None,
+            })
+            .collect();
+
+        let mut problems: Vec<String> = mapped
+            .iter()
+            .filter(|slot| !slots.iter().any(|(name, _)| name == *slot))
+            .map(|slot| format!("Column '{slot}' is not a slot of class '{class_name}'"))
+            .collect();
+        problems.extend(
+            slots
+                .iter()
+                .filter(|(name, slot)| {
+                    slot.required.unwrap_or(false) && !mapped.contains(&name.as_str())
+                })
+                .map(|(name, _)| {
+                    format!("Required slot '{name}' of class '{class_name}' has no column")
+                }),
+        );
+        problems
+    }
+}
+
+impl HeaderCase {
+    /// Normalize a header
+    #[must_use]
+    pub fn apply(self, header: &str) -> String {
+        let header = header.trim();
+        match self {
+            Self::Preserve => header.to_string(),
+            Self::Lower => header.to_lowercase(),
+            Self::Snake => words(header).join("_"),
+            Self::Camel => words(header)
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.clone()
+                    } else {
+                        let mut chars = word.chars();
+                        chars.next().map_or_else(String::new, |first| {
+                            first.to_uppercase().chain(chars).collect()
+                        })
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ColumnMapping {
+    /// Apply transformations, lookup and default to a cell value
+    #[must_use]
+    pub fn apply(&self, value: &str) -> String {
+        let mut value = value.to_string();
+        for transform in &self.transforms {
+            value = match transform {
+                ValueTransform::Trim => value.trim().to_string(),
+                ValueTransform::Lower => value.to_lowercase(),
+                ValueTransform::Upper => value.to_uppercase(),
+                ValueTransform::Snake => words(&value).join("_"),
+            };
+        }
+        if let Some(replacement) = self.lookup.get(&value) {
+            value.clone_from(replacement);
+        }
+        if value.trim().is_empty()
+            && let Some(default) = &self.default
+        {
+            value.clone_from(default);
+        }
+        value
+    }
+}
+
+/// Map headers and values of a record through resolved column targets.
+///
+/// Ignored columns come back empty so the loader skips them.
+pub(crate) fn apply_to_record(targets: &[ColumnTarget<'_>], record: &StringRecord) -> StringRecord {
+    record
+        .iter()
+        .enumerate()
+        .map(|(i, value)| match targets.get(i) {
+            Some(ColumnTarget::Ignored) => String::new(),
+            Some(ColumnTarget::Slot(_, Some(mapping))) => mapping.apply(value),
+            _ => value.to_string(),
+        })
+        .collect()
+}
+
+/// Slot names of resolved columns, empty for ignored columns
+pub(crate) fn target_slots(targets: &[ColumnTarget<'_>]) -> Vec<String> {
+    targets
+        .iter()
+        .map(|target| match target {
+            ColumnTarget::Slot(slot, _) => slot.clone(),
+            ColumnTarget::Ignored => String::new(),
+        })
+        .collect()
+}
+
+/// Split text into lowercase words at separators and case changes
+fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+    for c in text.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            previous_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && previous_lower && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        previous_lower = c.is_lowercase() || c.is_numeric();
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Slots of a class including inherited slots and attributes
+fn class_slots(class_name: &str, schema: &SchemaDefinition) -> Vec<(String, SlotDefinition)> {
+    let Some(class_def) = schema.classes.get(class_name) else {
+        return Vec::new();
+    };
+    let mut slots = class_def
+        .is_a
+        .as_deref()
+        .map(|parent| class_slots(parent, schema))
+        .unwrap_or_default();
+    for name in &class_def.slots {
+        let slot = class_def
+            .slot_usage
+            .get(name)
+            .or_else(|| schema.slots.get(name))
+            .cloned()
+            .unwrap_or_default();
+        slots.push((name.clone(), slot));
+    }
+    slots.extend(
+        class_def
+            .attributes
+            .iter()
+            .map(|(name, slot)| (name.clone(), slot.clone())),
+    );
+    slots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILE: &str = r#"
+name: partner_a
+header_case: snake
+strict: true
+ignore: ["Internal Ref"]
+columns:
+  Surname: last_name
+  Status:
+    slot: status
+    transforms: [trim, upper]
+    lookup:
+      A: ACTIVE
+      I: INACTIVE
+"#;
+
+    #[test]
+    fn test_resolve_and_apply() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let profile = ColumnProfile::from_yaml_str(PROFILE)?;
+        let headers: Vec<String> = ["SURNAME", "First Name", "status ", "Internal Ref"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let targets = profile.resolve(&headers);
+
+        assert_eq!(
+            targets[0],
+            ColumnTarget::Slot("last_name".to_string(), None)
+        );
+        assert_eq!(
+            targets[1],
+            ColumnTarget::Slot("first_name".to_string(), None)
+        );
+        assert!(matches!(&targets[2], ColumnTarget::Slot(slot, Some(_)) if slot == "status"));
+        assert_eq!(targets[3], ColumnTarget::Ignored);
+
+        let record = StringRecord::from(vec!["Doe", "Jane", " a ", "x-17"]);
+        let mapped = apply_to_record(&targets, &record);
+        assert_eq!(
+            mapped.iter().collect::<Vec<_>>(),
+            ["Doe", "Jane", "ACTIVE", ""]
+        );
+
+        assert_eq!(HeaderCase::Camel.apply("First Name"), "firstName");
+        assert_eq!(HeaderCase::Snake.apply("firstName"), "first_name");
+        Ok(())
+    }
+}
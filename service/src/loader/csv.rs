@@ -2,14 +2,22 @@
 //!
 //! This module provides functionality to load CSV/TSV files into `LinkML`
 //! data instances and dump instances back to CSV/TSV format.
+//!
+//! Multivalued slots are stored as separated lists within a cell, and with a
+//! path separator configured nested objects are spread over dotted-path
+//! columns such as `address.street`. Both conventions apply symmetrically to
+//! loading and dumping so tables round-trip.
 
 use async_trait::async_trait;
 use csv::{ReaderBuilder, StringRecord, WriterBuilder};
+use encoding_rs::{Encoding, WINDOWS_1252};
 use linkml_core::prelude::*;
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use std::path::Path;
 
+use super::column_profile::{ColumnProfile, apply_to_record, target_slots};
+use super::normalize::{NORMALIZE_ANNOTATION, apply_normalizers};
 use super::traits::{
     DataDumper, DataInstance, DataLoader, DumpOptions, DumperError, DumperResult, LoadOptions,
     LoaderError, LoaderResult,
@@ -39,8 +47,21 @@ pub struct CsvOptions {
     /// Whether to use flexible parsing (variable field counts)
     pub flexible: bool,
 
-    /// Encoding (currently only UTF-8 supported)
+    /// Encoding label such as `utf-8`, `utf-16le` or `windows-1252`, or
+    /// `auto` to detect it from a byte order mark, falling back to UTF-8 and
+    /// then Windows-1252
     pub encoding: String,
+
+    /// Detect the delimiter from the first lines instead of using `delimiter`
+    pub sniff_delimiter: bool,
+
+    /// Separator between the values of multivalued slots. When unset, the
+    /// loader splits on `;`, `|` or `,` and the dumper joins with `;`
+    pub list_separator: Option<char>,
+
+    /// Separator in column names addressing fields of nested objects, such
+    /// as `.` for `address.street`. When unset, columns are top-level slots
+    pub path_separator: Option<char>,
 }
 
 impl Default for CsvOptions {
@@ -54,6 +75,9 @@ impl Default for CsvOptions {
             trim: true,
             flexible: false,
             encoding: "utf-8".to_string(),
+            sniff_delimiter: false,
+            list_separator: None,
+            path_separator: None,
         }
     }
 }
@@ -67,11 +91,149 @@ impl CsvOptions {
             ..Default::default()
         }
     }
+
+    /// Create options that detect the delimiter and encoding of the input
+    #[must_use]
+    pub fn sniffed() -> Self {
+        Self {
+            encoding: "auto".to_string(),
+            sniff_delimiter: true,
+            ..Default::default()
+        }
+    }
+
+    /// Set the separator between values of multivalued slots
+    #[must_use]
+    pub fn with_list_separator(mut self, separator: char) -> Self {
+        self.list_separator = Some(separator);
+        self
+    }
+
+    /// Map nested objects to columns named by paths joined with `separator`
+    #[must_use]
+    pub fn with_path_separator(mut self, separator: char) -> Self {
+        self.path_separator = Some(separator);
+        self
+    }
+}
+
+/// Delimiters considered when sniffing, in order of preference
+const SNIFF_CANDIDATES: [u8; 4] = [b',', b'\t', b';', b'|'];
+
+/// Number of lines inspected when sniffing the delimiter
+const SNIFF_LINES: usize = 10;
+
+/// Guess the delimiter of `content` from its first lines.
+///
+/// Picks the candidate occurring the same, non-zero number of times outside
+/// quotes on every inspected line, preferring the most frequent one.
+fn sniff_delimiter(content: &str, quote: u8) -> Option<u8> {
+    let lines: Vec<&str> = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .take(SNIFF_LINES)
+        .collect();
+
+    let mut best: Option<(u8, usize)> = None;
+    for candidate in SNIFF_CANDIDATES {
+        let mut counts = lines.iter().map(|line| {
+            let mut in_quotes = false;
+            line.bytes()
+                .filter(|&b| {
+                    if b == quote {
+                        in_quotes = !in_quotes;
+                    }
+                    !in_quotes && b == candidate
+                })
+                .count()
+        });
+        let first = counts.next()?;
+        if first > 0
+            && counts.all(|count| count == first)
+            && best.is_none_or(|(_, count)| first > count)
+        {
+            best = Some((candidate, first));
+        }
+    }
+    best.map(|(delimiter, _)| delimiter)
+}
+
+/// Decode raw input according to an encoding label or `auto`
+fn decode_input(data: &[u8], label: &str) -> LoaderResult<String> {
+    let (text, encoding, had_errors) = if label.eq_ignore_ascii_case("auto") {
+        if let Some((encoding, bom_length)) = Encoding::for_bom(data) {
+            let (text, had_errors) = encoding.decode_without_bom_handling(&data[bom_length..]);
+            (text, encoding, had_errors)
+        } else if let Ok(text) = std::str::from_utf8(data) {
+            return Ok(text.to_string());
+        } else {
+            let (text, had_errors) = WINDOWS_1252.decode_without_bom_handling(data);
+            (text, WINDOWS_1252, had_errors)
+        }
+    } else {
+        let encoding = Encoding::for_label(label.as_bytes())
+            .ok_or_else(|| LoaderError::Configuration(format!("Unsupported encoding '{label}'")))?;
+        let (text, had_errors) = encoding.decode_with_bom_removal(data);
+        (text, encoding, had_errors)
+    };
+
+    if had_errors {
+        return Err(LoaderError::Parse(format!(
+            "Input is not valid {}",
+            encoding.name()
+        )));
+    }
+    Ok(text.into_owned())
+}
+
+/// Insert `value` into `data` at the nested object `path`
+fn insert_path(data: &mut HashMap<String, JsonValue>, path: &[&str], value: JsonValue) {
+    let Some((first, rest)) = path.split_first() else {
+        return;
+    };
+    if rest.is_empty() {
+        data.insert((*first).to_string(), value);
+        return;
+    }
+
+    let mut current = data
+        .entry((*first).to_string())
+        .or_insert_with(|| JsonValue::Object(serde_json::Map::new()));
+    for segment in rest {
+        if !current.is_object() {
+            *current = JsonValue::Object(serde_json::Map::new());
+        }
+        let JsonValue::Object(map) = current else {
+            unreachable!("replaced by an object above");
+        };
+        current = map.entry((*segment).to_string()).or_insert(JsonValue::Null);
+    }
+    *current = value;
+}
+
+/// Find the slot named `slot_name` as used by `class_name`
+pub(super) fn class_slot<'a>(
+    schema: &'a SchemaDefinition,
+    class_name: &str,
+    slot_name: &str,
+) -> Option<&'a SlotDefinition> {
+    let mut current = schema.classes.get(class_name);
+    while let Some(class_def) = current {
+        if let Some(attribute) = class_def.attributes.get(slot_name) {
+            return Some(attribute);
+        }
+        current = class_def
+            .is_a
+            .as_ref()
+            .and_then(|parent| schema.classes.get(parent));
+    }
+    schema.slots.get(slot_name)
 }
 
 /// CSV data loader
 pub struct CsvLoader {
     options: CsvOptions,
+    profile: Option<ColumnProfile>,
 }
 
 impl CsvLoader {
@@ -80,13 +242,17 @@ impl CsvLoader {
     pub fn new() -> Self {
         Self {
             options: CsvOptions::default(),
+            profile: None,
         }
     }
 
     /// Create a new CSV loader with custom options
     #[must_use]
     pub fn with_options(options: CsvOptions) -> Self {
-        Self { options }
+        Self {
+            options,
+            profile: None,
+        }
     }
 
     /// Create a TSV loader
@@ -94,9 +260,17 @@ impl CsvLoader {
     pub fn tsv() -> Self {
         Self {
             options: CsvOptions::tsv(),
+            profile: None,
         }
     }
 
+    /// Map source columns to slots through a column-mapping profile
+    #[must_use]
+    pub fn with_profile(mut self, profile: ColumnProfile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
     /// Parse a CSV record into a data instance
     fn parse_record(
         &self,
@@ -135,6 +309,17 @@ impl CsvLoader {
                 continue;
             }
 
+            // Dotted-path columns fill fields of nested objects
+            if let Some(separator) = self.options.path_separator
+                && field_name.contains(separator)
+                && !schema.slots.contains_key(field_name)
+            {
+                let path: Vec<&str> = field_name.split(separator).collect();
+                let json_value = self.convert_path_value(value, &path, class_name, schema)?;
+                insert_path(&mut data, &path, json_value);
+                continue;
+            }
+
             // Check if this is an identifier field
             if let Some(slot_def) = schema.slots.get(field_name)
                 && slot_def.identifier == Some(true)
@@ -166,6 +351,15 @@ impl CsvLoader {
         if let Some(slot_def) = schema.slots.get(field_name)
             && let Some(range) = &slot_def.range
         {
+            // Annotated slots are converted by the normalization pass
+            if slot_def
+                .annotations
+                .as_ref()
+                .is_some_and(|a| a.contains_key(NORMALIZE_ANNOTATION))
+            {
+                return Ok(JsonValue::String(value.trim().to_string()));
+            }
+
             return self.convert_typed_value(value, range, slot_def);
         }
 
@@ -173,6 +367,30 @@ impl CsvLoader {
         Ok(JsonValue::String(value.to_string()))
     }
 
+    /// Convert the value of a nested field addressed by a column path
+    fn convert_path_value(
+        &self,
+        value: &str,
+        path: &[&str],
+        class_name: &str,
+        schema: &SchemaDefinition,
+    ) -> LoaderResult<JsonValue> {
+        let mut owner = class_name.to_string();
+        let mut slot = None;
+        for segment in path {
+            slot = class_slot(schema, &owner, segment);
+            owner = slot.and_then(|s| s.range.clone()).unwrap_or_default();
+        }
+
+        match slot {
+            Some(slot_def) => {
+                let range = slot_def.range.as_deref().unwrap_or("string");
+                self.convert_typed_value(value, range, slot_def)
+            }
+            None => Ok(JsonValue::String(value.to_string())),
+        }
+    }
+
     /// Convert value based on type
     fn convert_typed_value(
         &self,
@@ -188,8 +406,10 @@ impl CsvLoader {
 
         // Handle multivalued fields
         if slot_def.multivalued == Some(true) {
-            // Split by common delimiters
-            let values: Vec<&str> = if trimmed.contains(';') {
+            // Split by the configured separator or common delimiters
+            let values: Vec<&str> = if let Some(separator) = self.options.list_separator {
+                trimmed.split(separator).map(str::trim).collect()
+            } else if trimmed.contains(';') {
                 trimmed.split(';').map(str::trim).collect()
             } else if trimmed.contains('|') {
                 trimmed.split('|').map(str::trim).collect()
@@ -347,8 +567,8 @@ impl DataLoader for CsvLoader {
         schema: &SchemaDefinition,
         options: &LoadOptions,
     ) -> LoaderResult<Vec<DataInstance>> {
-        let content = tokio::fs::read_to_string(path).await?;
-        self.load_string(&content, schema, options).await
+        let data = tokio::fs::read(path).await?;
+        self.load_bytes(&data, schema, options).await
     }
 
     async fn load_string(
@@ -357,8 +577,15 @@ impl DataLoader for CsvLoader {
         schema: &SchemaDefinition,
         options: &LoadOptions,
     ) -> LoaderResult<Vec<DataInstance>> {
+        let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+        let delimiter = if self.options.sniff_delimiter {
+            sniff_delimiter(content, self.options.quote).unwrap_or(self.options.delimiter)
+        } else {
+            self.options.delimiter
+        };
+
         let mut reader = ReaderBuilder::new()
-            .delimiter(self.options.delimiter)
+            .delimiter(delimiter)
             .has_headers(self.options.has_headers)
             .quote(self.options.quote)
             .double_quote(self.options.double_quote)
@@ -381,6 +608,16 @@ impl DataLoader for CsvLoader {
             ));
         };
 
+        // Map source headers to slots through the column profile
+        let targets = self
+            .profile
+            .as_ref()
+            .map(|profile| profile.resolve(&headers));
+        let headers = match &targets {
+            Some(targets) => target_slots(targets),
+            None => headers,
+        };
+
         // Determine target class
         let target_class = if let Some(class) = &options.target_class {
             class.clone()
@@ -392,6 +629,10 @@ impl DataLoader for CsvLoader {
             ));
         };
 
+        if let (Some(profile), Some(targets)) = (&self.profile, &targets) {
+            profile.check_headers(targets, &target_class, schema)?;
+        }
+
         // Load records
         let mut instances = Vec::new();
         let mut error_count = 0;
@@ -406,6 +647,10 @@ impl DataLoader for CsvLoader {
 
             match result {
                 Ok(record) => {
+                    let record = match &targets {
+                        Some(targets) => apply_to_record(targets, &record),
+                        None => record,
+                    };
                     match self.parse_record(
                         &record,
                         &headers,
@@ -443,7 +688,7 @@ impl DataLoader for CsvLoader {
             eprintln!("Total errors skipped: {error_count}");
         }
 
-        Ok(instances)
+        apply_normalizers(instances, schema, options)
     }
 
     async fn load_bytes(
@@ -452,8 +697,7 @@ impl DataLoader for CsvLoader {
         schema: &SchemaDefinition,
         options: &LoadOptions,
     ) -> LoaderResult<Vec<DataInstance>> {
-        let content = String::from_utf8(data.to_vec())
-            .map_err(|e| LoaderError::Parse(format!("Invalid UTF-8: {e}")))?;
+        let content = decode_input(data, &self.options.encoding)?;
         self.load_string(&content, schema, options).await
     }
 
@@ -681,11 +925,11 @@ impl CsvDumper {
             JsonValue::Number(n) => n.to_string(),
             JsonValue::String(s) => s.clone(),
             JsonValue::Array(arr) => {
-                // Join array values with semicolon
+                // Join array values with the list separator
                 arr.iter()
                     .map(|v| self.value_to_string(v))
                     .collect::<Vec<_>>()
-                    .join(";")
+                    .join(&self.options.list_separator.unwrap_or(';').to_string())
             }
             JsonValue::Object(_) => {
                 // Serialize as JSON for complex objects
@@ -694,6 +938,43 @@ impl CsvDumper {
         }
     }
 
+    /// Replace headers of object-valued fields by paths to their leaf fields
+    fn expand_nested_headers(
+        headers: Vec<String>,
+        instances: &[&DataInstance],
+        separator: char,
+    ) -> Vec<String> {
+        let mut expanded = Vec::new();
+        for header in headers {
+            let mut paths = Vec::new();
+            for instance in instances {
+                if let Some(JsonValue::Object(map)) = instance.data.get(&header) {
+                    collect_leaf_paths(&header, map, separator, &mut paths);
+                }
+            }
+            if paths.is_empty() {
+                expanded.push(header);
+            } else {
+                expanded.extend(paths);
+            }
+        }
+        expanded
+    }
+
+    /// Look up a field, following a column path into nested objects
+    fn field_value<'a>(&self, instance: &'a DataInstance, field: &str) -> Option<&'a JsonValue> {
+        if let Some(value) = instance.data.get(field) {
+            return Some(value);
+        }
+        let separator = self.options.path_separator?;
+        let mut segments = field.split(separator);
+        let mut current = instance.data.get(segments.next()?)?;
+        for segment in segments {
+            current = current.get(segment)?;
+        }
+        Some(current)
+    }
+
     /// Collect all slots including inherited ones
     fn collect_all_slots(
         &self,
@@ -721,6 +1002,23 @@ impl CsvDumper {
     }
 }
 
+/// Append the paths of the non-object fields of `map` below `prefix`
+fn collect_leaf_paths(
+    prefix: &str,
+    map: &serde_json::Map<String, JsonValue>,
+    separator: char,
+    paths: &mut Vec<String>,
+) {
+    for (key, value) in map {
+        let path = format!("{prefix}{separator}{key}");
+        if let JsonValue::Object(nested) = value {
+            collect_leaf_paths(&path, nested, separator, paths);
+        } else if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+}
+
 impl Default for CsvDumper {
     fn default() -> Self {
         Self::new()
@@ -803,6 +1101,11 @@ impl DataDumper for CsvDumper {
             instances_to_dump.iter().map(|&i| i.clone()).collect();
         let mut headers = self.get_headers(&class_name, schema, &instances_for_headers);
 
+        // Spread nested objects over dotted-path columns
+        if let Some(separator) = self.options.path_separator {
+            headers = Self::expand_nested_headers(headers, &instances_to_dump, separator);
+        }
+
         // Apply field mappings in reverse
         let reverse_mappings: HashMap<String, String> = options
             .field_mappings
@@ -837,7 +1140,7 @@ impl DataDumper for CsvDumper {
                 // Reverse map header to field name
                 let field_name = options.field_mappings.get(header).unwrap_or(header);
 
-                let value = if let Some(json_value) = instance.data.get(field_name) {
+                let value = if let Some(json_value) = self.field_value(instance, field_name) {
                     if json_value.is_null() && !options.include_nulls {
                         String::new()
                     } else {
@@ -1125,6 +1428,144 @@ p1\tAlice\t30\talice@example.com\ttag1;tag2
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_sniffed_delimiter_and_encoding()
+    -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let schema = create_test_schema();
+        let loader = CsvLoader::with_options(CsvOptions::sniffed().with_list_separator('|'));
+
+        // Semicolon-separated Windows-1252 input, as exported by spreadsheet tools
+        let mut data = b"id;name;age;email;tags\n".to_vec();
+        data.extend_from_slice(b"p1;Jos\xe9;30;jose@example.com;a|b, c\n");
+
+        let options = LoadOptions {
+            target_class: Some("Person".to_string()),
+            ..Default::default()
+        };
+        let instances = loader.load_bytes(&data, &schema, &options).await?;
+        assert_eq!(instances.len(), 1);
+        assert_eq!(
+            instances[0].data.get("name"),
+            Some(&JsonValue::String("Jos\u{e9}".to_string()))
+        );
+        assert_eq!(
+            instances[0].data.get("tags"),
+            Some(&serde_json::json!(["a", "b, c"]))
+        );
+
+        assert_eq!(sniff_delimiter("a\tb\tc\n1\t2\t3\n", b'"'), Some(b'\t'));
+        assert_eq!(sniff_delimiter("a,\"b;c\"\n1,2\n", b'"'), Some(b','));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_nested_columns_round_trip() -> std::result::Result<(), Box<dyn std::error::Error>>
+    {
+        let mut schema = create_test_schema();
+        let mut address = ClassDefinition::default();
+        address.attributes.insert(
+            "street".to_string(),
+            SlotDefinition {
+                range: Some("string".to_string()),
+                ..Default::default()
+            },
+        );
+        address.attributes.insert(
+            "number".to_string(),
+            SlotDefinition {
+                range: Some("integer".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert("Address".to_string(), address);
+        schema.slots.insert(
+            "address".to_string(),
+            SlotDefinition {
+                range: Some("Address".to_string()),
+                ..Default::default()
+            },
+        );
+        if let Some(person) = schema.classes.get_mut("Person") {
+            person.slots.push("address".to_string());
+        }
+
+        let csv_options = CsvOptions::default()
+            .with_path_separator('.')
+            .with_list_separator('|');
+        let loader = CsvLoader::with_options(csv_options.clone());
+        let dumper = CsvDumper::with_options(csv_options);
+
+        let csv_content = "id,name,tags,address.street,address.number\n\
+                           p1,Alice,x|y,Main Street,12\n";
+        let options = LoadOptions {
+            target_class: Some("Person".to_string()),
+            ..Default::default()
+        };
+        let instances = loader.load_string(csv_content, &schema, &options).await?;
+        assert_eq!(
+            instances[0].data.get("address"),
+            Some(&serde_json::json!({"street": "Main Street", "number": 12}))
+        );
+
+        let output = dumper
+            .dump_string(&instances, &schema, &DumpOptions::default())
+            .await?;
+        let header = output.lines().next().unwrap_or_default();
+        assert!(header.contains("address.street"));
+        assert!(header.contains("address.number"));
+        assert!(output.contains("x|y"));
+
+        let reloaded = loader.load_string(&output, &schema, &options).await?;
+        assert_eq!(reloaded[0].data, instances[0].data);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_column_profile() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let schema = create_test_schema();
+        let profile = ColumnProfile::from_yaml_str(
+            r"
+header_case: snake
+strict: true
+ignore: [Internal Ref]
+columns:
+  Person ID: id
+  Full Name:
+    slot: name
+    transforms: [trim]
+  Labels: tags
+",
+        )?;
+        let loader = CsvLoader::new().with_profile(profile.clone());
+        let options = LoadOptions {
+            target_class: Some("Person".to_string()),
+            ..Default::default()
+        };
+
+        let csv_content = "Person ID,Full Name,Age,Internal Ref,Labels\n\
+                           p1, Alice ,30,x-17,a;b\n";
+        let instances = loader.load_string(csv_content, &schema, &options).await?;
+        assert_eq!(instances[0].id, Some("p1".to_string()));
+        assert_eq!(
+            instances[0].data.get("name"),
+            Some(&JsonValue::String("Alice".to_string()))
+        );
+        assert_eq!(
+            instances[0].data.get("tags"),
+            Some(&serde_json::json!(["a", "b"]))
+        );
+        assert!(!instances[0].data.contains_key("internal_ref"));
+
+        // Unknown columns and missing required slots are rejected
+        let result = loader
+            .load_string("Person ID,Nickname\np1,Al\n", &schema, &options)
+            .await;
+        let message = result.err().map(|e| e.to_string()).unwrap_or_default();
+        assert!(message.contains("'nickname' is not a slot"));
+        assert!(message.contains("Required slot 'name'"));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_type_conversion() -> std::result::Result<(), Box<dyn std::error::Error>> {
         let schema = create_test_schema();
@@ -0,0 +1,215 @@
+//! Deployment of generated DDL to `PostgreSQL`
+
+use super::migration::{MigrationPlan, PostgresCatalog};
+use super::options::DatabaseOptions;
+use super::pool::DatabasePool;
+use crate::loader::traits::{LoaderError, LoaderResult};
+use sqlx::Row;
+use sqlx::postgres::{PgPool, PgPoolOptions};
+use tracing::{debug, info};
+
+/// Deploys schemas to a `PostgreSQL` database by migrating its catalog
+pub struct SqlDeployer {
+    pool: PgPool,
+    schema_name: String,
+}
+
+impl SqlDeployer {
+    /// Connect to the database of `options.connection_string`, deploying to
+    /// `options.schema_name` (`public` by default)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection string is not a `PostgreSQL` DSN or
+    /// the connection fails
+    pub async fn connect(options: &DatabaseOptions) -> LoaderResult<Self> {
+        if !options.connection_string.starts_with("postgres://")
+            && !options.connection_string.starts_with("postgresql://")
+        {
+            return Err(LoaderError::Configuration(
+                "Schema deployment requires a PostgreSQL connection string".to_string(),
+            ));
+        }
+        let pool = PgPoolOptions::new()
+            .max_connections(options.max_connections)
+            .connect(&options.connection_string)
+            .await
+            .map_err(|e| {
+                LoaderError::Configuration(format!("Failed to connect to PostgreSQL: {e}"))
+            })?;
+        Self::with_pool(
+            DatabasePool::PostgreSQL(pool),
+            options.schema_name.as_deref(),
+        )
+    }
+
+    /// Deploy through an existing pool
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool is not a `PostgreSQL` pool
+    pub fn with_pool(pool: DatabasePool, schema_name: Option<&str>) -> LoaderResult<Self> {
+        match pool {
+            DatabasePool::PostgreSQL(pool) => Ok(Self {
+                pool,
+                schema_name: schema_name.unwrap_or("public").to_string(),
+            }),
+            DatabasePool::MySQL(_) => Err(LoaderError::Configuration(
+                "Schema deployment is only supported for PostgreSQL".to_string(),
+            )),
+        }
+    }
+
+    /// Read the tables, enum types, constraints and indexes of the schema
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a catalog query fails
+    pub async fn read_catalog(&self) -> LoaderResult<PostgresCatalog> {
+        let mut catalog = PostgresCatalog::default();
+
+        for row in self
+            .fetch(
+                "SELECT c.table_name::text, c.column_name::text FROM information_schema.columns c \
+                 JOIN information_schema.tables t \
+                 ON t.table_schema = c.table_schema AND t.table_name = c.table_name \
+                 WHERE c.table_schema = $1 AND t.table_type = 'BASE TABLE'",
+            )
+            .await?
+        {
+            let (table, column) = Self::pair(&row)?;
+            catalog.tables.entry(table).or_default().insert(column);
+        }
+
+        for row in self
+            .fetch(
+                "SELECT t.typname::text, e.enumlabel::text FROM pg_type t \
+                 JOIN pg_enum e ON e.enumtypid = t.oid \
+                 JOIN pg_namespace n ON n.oid = t.typnamespace \
+                 WHERE n.nspname = $1 ORDER BY t.typname, e.enumsortorder",
+            )
+            .await?
+        {
+            let (type_name, label) = Self::pair(&row)?;
+            catalog.enum_types.entry(type_name).or_default().push(label);
+        }
+
+        for row in self
+            .fetch(
+                "SELECT table_name::text, constraint_name::text FROM information_schema.table_constraints \
+                 WHERE table_schema = $1",
+            )
+            .await?
+        {
+            let (table, constraint) = Self::pair(&row)?;
+            catalog
+                .constraints
+                .entry(table)
+                .or_default()
+                .insert(constraint);
+        }
+
+        for row in self
+            .fetch("SELECT indexname::text, tablename::text FROM pg_indexes WHERE schemaname = $1")
+            .await?
+        {
+            let (index, _) = Self::pair(&row)?;
+            catalog.indexes.insert(index);
+        }
+
+        debug!(
+            "Read catalog of schema {}: {} tables, {} enum types",
+            self.schema_name,
+            catalog.tables.len(),
+            catalog.enum_types.len()
+        );
+        Ok(catalog)
+    }
+
+    /// Plan the migration of the database to the generated `PostgreSQL` DDL
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the catalog cannot be read
+    pub async fn plan(&self, ddl: &str) -> LoaderResult<MigrationPlan> {
+        Ok(MigrationPlan::new(ddl, &self.read_catalog().await?))
+    }
+
+    /// Apply a plan in a single transaction, rolling back on the first
+    /// failing statement
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a statement fails or the transaction cannot be
+    /// committed
+    pub async fn apply(&self, plan: &MigrationPlan) -> LoaderResult<()> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            LoaderError::Io(std::io::Error::other(format!(
+                "Failed to begin PostgreSQL transaction: {e}"
+            )))
+        })?;
+
+        let search_path = format!(
+            "SET LOCAL search_path TO \"{}\"",
+            self.schema_name.replace('"', "\"\"")
+        );
+        sqlx::query(&search_path)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                LoaderError::Io(std::io::Error::other(format!(
+                    "Failed to select schema {}: {e}",
+                    self.schema_name
+                )))
+            })?;
+
+        for statement in &plan.statements {
+            debug!("Executing: {statement}");
+            sqlx::query(statement)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    LoaderError::Io(std::io::Error::other(format!(
+                        "Failed to execute `{statement}`: {e}"
+                    )))
+                })?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            LoaderError::Io(std::io::Error::other(format!(
+                "Failed to commit PostgreSQL transaction: {e}"
+            )))
+        })?;
+        info!(
+            "Applied {} statements to schema {}",
+            plan.statements.len(),
+            self.schema_name
+        );
+        Ok(())
+    }
+
+    /// Run a catalog query bound to the schema name
+    async fn fetch(&self, query: &str) -> LoaderResult<Vec<sqlx::postgres::PgRow>> {
+        sqlx::query(query)
+            .bind(&self.schema_name)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                LoaderError::Io(std::io::Error::other(format!(
+                    "Failed to read PostgreSQL catalog: {e}"
+                )))
+            })
+    }
+
+    /// First two text columns of a catalog row
+    fn pair(row: &sqlx::postgres::PgRow) -> LoaderResult<(String, String)> {
+        let column = |index: usize| {
+            row.try_get::<String, _>(index).map_err(|e| {
+                LoaderError::Io(std::io::Error::other(format!(
+                    "Failed to read PostgreSQL catalog row: {e}"
+                )))
+            })
+        };
+        Ok((column(0)?, column(1)?))
+    }
+}
@@ -0,0 +1,531 @@
+//! Migration planning for `PostgreSQL` schema deployment
+//!
+//! Compares the DDL of the SQL generator with the catalog of a live database
+//! and plans the statements that bring the database up to the schema. Plans
+//! are additive: missing enum types and values, tables, columns, constraints
+//! and indexes are created, while objects the schema does not declare are
+//! reported and kept. Foreign keys are added once all tables exist, so the
+//! order of the generated tables does not matter.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Objects of a `PostgreSQL` schema, as read from its catalog
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PostgresCatalog {
+    /// Column names of each table
+    pub tables: BTreeMap<String, BTreeSet<String>>,
+    /// Labels of each enum type, in sort order
+    pub enum_types: BTreeMap<String, Vec<String>>,
+    /// Constraint names of each table
+    pub constraints: BTreeMap<String, BTreeSet<String>>,
+    /// Index names
+    pub indexes: BTreeSet<String>,
+}
+
+/// Statements that migrate a database to a schema
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MigrationPlan {
+    /// Statements to execute, in order and without terminating semicolons
+    pub statements: Vec<String>,
+    /// Differences the plan does not resolve
+    pub warnings: Vec<String>,
+}
+
+/// Table-level constraint of a generated table
+struct TableConstraint {
+    name: String,
+    definition: String,
+    foreign_key: bool,
+}
+
+/// Statement of the generated DDL
+enum DdlStatement {
+    EnumType {
+        name: String,
+        labels: Vec<String>,
+        sql: String,
+    },
+    Table {
+        name: String,
+        columns: Vec<(String, String)>,
+        constraints: Vec<TableConstraint>,
+    },
+    Index {
+        name: String,
+        sql: String,
+    },
+}
+
+/// Statements of a plan, grouped by the order they run in
+#[derive(Default)]
+struct PlanBuilder {
+    types: Vec<String>,
+    tables: Vec<String>,
+    columns: Vec<String>,
+    constraints: Vec<String>,
+    indexes: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl PlanBuilder {
+    /// Create an enum type, or add the labels it lacks
+    fn enum_type(&mut self, name: &str, labels: &[String], sql: String, catalog: &PostgresCatalog) {
+        let Some(existing) = catalog.enum_types.get(name) else {
+            self.types.push(sql);
+            return;
+        };
+        for label in labels.iter().filter(|label| !existing.contains(label)) {
+            let label = label.replace('\'', "''");
+            self.types.push(format!(
+                "ALTER TYPE {name} ADD VALUE IF NOT EXISTS '{label}'"
+            ));
+        }
+    }
+
+    /// Create a table, deferring its foreign keys until all tables exist
+    fn new_table(
+        &mut self,
+        name: &str,
+        columns: &[(String, String)],
+        constraints: &[TableConstraint],
+    ) {
+        let items: Vec<String> = columns
+            .iter()
+            .map(|(column, definition)| format!("{column} {definition}"))
+            .chain(
+                constraints
+                    .iter()
+                    .filter(|constraint| !constraint.foreign_key)
+                    .map(|constraint| {
+                        format!("CONSTRAINT {} {}", constraint.name, constraint.definition)
+                    }),
+            )
+            .collect();
+        self.tables.push(format!(
+            "CREATE TABLE {name} (\n    {}\n)",
+            items.join(",\n    ")
+        ));
+        self.add_constraints(
+            name,
+            constraints
+                .iter()
+                .filter(|constraint| constraint.foreign_key),
+        );
+    }
+
+    /// Add the columns and constraints an existing table lacks
+    fn existing_table(
+        &mut self,
+        name: &str,
+        columns: &[(String, String)],
+        constraints: &[TableConstraint],
+        catalog: &PostgresCatalog,
+    ) {
+        let existing = catalog.tables.get(name).cloned().unwrap_or_default();
+        for (column, definition) in columns {
+            if existing.contains(column) {
+                continue;
+            }
+            let upper = definition.to_uppercase();
+            if upper.contains("NOT NULL") && !upper.contains("DEFAULT") {
+                self.warnings.push(format!(
+                    "Column {name}.{column} is added as NOT NULL without a default \
+                     and fails if the table has rows"
+                ));
+            }
+            self.columns.push(format!(
+                "ALTER TABLE {name} ADD COLUMN {column} {definition}"
+            ));
+        }
+        for column in &existing {
+            if !columns.iter().any(|(declared, _)| declared == column) {
+                self.warnings.push(format!(
+                    "Column {name}.{column} is not in the schema and is kept"
+                ));
+            }
+        }
+        let existing_constraints = catalog.constraints.get(name);
+        self.add_constraints(
+            name,
+            constraints.iter().filter(|constraint| {
+                existing_constraints.is_none_or(|names| !names.contains(&constraint.name))
+            }),
+        );
+    }
+
+    fn add_constraints<'a>(
+        &mut self,
+        table: &str,
+        constraints: impl Iterator<Item = &'a TableConstraint>,
+    ) {
+        self.constraints.extend(constraints.map(|constraint| {
+            format!(
+                "ALTER TABLE {table} ADD CONSTRAINT {} {}",
+                constraint.name, constraint.definition
+            )
+        }));
+    }
+}
+
+impl MigrationPlan {
+    /// Plan the migration of a database with `catalog` to the generated
+    /// `PostgreSQL` DDL
+    #[must_use]
+    pub fn new(ddl: &str, catalog: &PostgresCatalog) -> Self {
+        let mut builder = PlanBuilder::default();
+        let mut declared_tables = BTreeSet::new();
+
+        for statement in split_top_level(&strip_comments(ddl), ';') {
+            match parse_statement(statement) {
+                Some(DdlStatement::EnumType { name, labels, sql }) => {
+                    builder.enum_type(&name, &labels, sql, catalog);
+                }
+                Some(DdlStatement::Table {
+                    name,
+                    columns,
+                    constraints,
+                }) => {
+                    if catalog.tables.contains_key(&name) {
+                        builder.existing_table(&name, &columns, &constraints, catalog);
+                    } else {
+                        builder.new_table(&name, &columns, &constraints);
+                    }
+                    declared_tables.insert(name);
+                }
+                Some(DdlStatement::Index { name, sql }) => {
+                    if !catalog.indexes.contains(&name) {
+                        builder.indexes.push(sql);
+                    }
+                }
+                None => builder.warnings.push(format!(
+                    "Skipped unsupported statement: {}",
+                    statement.lines().next().unwrap_or_default()
+                )),
+            }
+        }
+
+        for table in catalog.tables.keys() {
+            if !declared_tables.contains(table) {
+                builder
+                    .warnings
+                    .push(format!("Table {table} is not in the schema and is kept"));
+            }
+        }
+
+        let PlanBuilder {
+            mut types,
+            tables,
+            columns,
+            constraints,
+            indexes,
+            warnings,
+        } = builder;
+        types.extend(tables);
+        types.extend(columns);
+        types.extend(constraints);
+        types.extend(indexes);
+        Self {
+            statements: types,
+            warnings,
+        }
+    }
+
+    /// Whether the database already matches the schema
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.statements.is_empty()
+    }
+
+    /// SQL script of the plan, with the warnings as comments
+    #[must_use]
+    pub fn to_sql(&self) -> String {
+        let mut script = String::new();
+        for warning in &self.warnings {
+            script.push_str("-- WARNING: ");
+            script.push_str(warning);
+            script.push('\n');
+        }
+        for statement in &self.statements {
+            script.push_str(statement);
+            script.push_str(";\n");
+        }
+        script
+    }
+}
+
+/// Remove `--` comments outside of string literals
+fn strip_comments(ddl: &str) -> String {
+    let mut stripped = String::with_capacity(ddl.len());
+    for line in ddl.lines() {
+        let mut quoted = false;
+        let mut previous = None;
+        let mut end = line.len();
+        for (index, ch) in line.char_indices() {
+            match ch {
+                '\'' => quoted = !quoted,
+                '-' if !quoted && previous == Some('-') => {
+                    end = index - 1;
+                    break;
+                }
+                _ => {}
+            }
+            previous = Some(ch);
+        }
+        stripped.push_str(&line[..end]);
+        stripped.push('\n');
+    }
+    stripped
+}
+
+/// Split on a separator outside of string literals and parentheses
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut quoted = false;
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '\'' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted => depth = depth.saturating_sub(1),
+            _ if ch == separator && !quoted && depth == 0 => {
+                parts.push(text[start..index].trim());
+                start = index + ch.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(text[start..].trim());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+/// Text between the first `(` and the last `)`
+fn parenthesized(text: &str) -> Option<&str> {
+    let start = text.find('(')?;
+    let end = text.rfind(')')?;
+    (start < end).then(|| &text[start + 1..end])
+}
+
+/// Parse a statement of the generated DDL
+fn parse_statement(statement: &str) -> Option<DdlStatement> {
+    let tokens: Vec<String> = statement
+        .split_whitespace()
+        .take(6)
+        .map(str::to_uppercase)
+        .collect();
+    let tokens: Vec<&str> = tokens.iter().map(String::as_str).collect();
+    let words: Vec<&str> = statement.split_whitespace().collect();
+    match tokens.as_slice() {
+        ["CREATE", "TYPE", _, "AS", "ENUM", ..] => Some(DdlStatement::EnumType {
+            name: words[2].to_lowercase(),
+            labels: split_top_level(parenthesized(statement)?, ',')
+                .into_iter()
+                .map(|label| label.trim_matches('\'').replace("''", "'"))
+                .collect(),
+            sql: statement.to_string(),
+        }),
+        ["CREATE", "TABLE", ..] => {
+            let name_index = if tokens.get(2..5) == Some(&["IF", "NOT", "EXISTS"]) {
+                5
+            } else {
+                2
+            };
+            let name = words.get(name_index)?.split('(').next()?.to_lowercase();
+            let mut columns = Vec::new();
+            let mut constraints = Vec::new();
+            for item in split_top_level(parenthesized(statement)?, ',') {
+                let (first, rest) = item.split_once(char::is_whitespace)?;
+                let rest = rest.trim();
+                match first.to_uppercase().as_str() {
+                    "CONSTRAINT" => {
+                        let (constraint_name, definition) = rest.split_once(char::is_whitespace)?;
+                        let definition = definition.trim();
+                        constraints.push(TableConstraint {
+                            name: constraint_name.to_lowercase(),
+                            foreign_key: definition.to_uppercase().starts_with("FOREIGN"),
+                            definition: definition.to_string(),
+                        });
+                    }
+                    "PRIMARY" | "FOREIGN" | "UNIQUE" | "CHECK" => {
+                        constraints.push(TableConstraint {
+                            name: default_constraint_name(&name, item),
+                            foreign_key: first.eq_ignore_ascii_case("FOREIGN"),
+                            definition: item.to_string(),
+                        });
+                    }
+                    _ => columns.push((first.to_lowercase(), rest.to_string())),
+                }
+            }
+            Some(DdlStatement::Table {
+                name,
+                columns,
+                constraints,
+            })
+        }
+        ["CREATE", "INDEX", ..] | ["CREATE", "UNIQUE", "INDEX", ..] => {
+            let name_index = if tokens[1] == "UNIQUE" { 3 } else { 2 };
+            Some(DdlStatement::Index {
+                name: words.get(name_index)?.to_lowercase(),
+                sql: statement.to_string(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Name `PostgreSQL` gives an unnamed table constraint
+fn default_constraint_name(table: &str, definition: &str) -> String {
+    let upper = definition.to_uppercase();
+    // Columns of the first parenthesized list, e.g. `FOREIGN KEY (a, b)`
+    let columns = || {
+        definition
+            .split_once('(')
+            .and_then(|(_, rest)| rest.split_once(')'))
+            .map(|(columns, _)| {
+                columns
+                    .split(',')
+                    .map(|column| column.trim().to_lowercase())
+                    .collect::<Vec<_>>()
+                    .join("_")
+            })
+            .unwrap_or_default()
+    };
+    if upper.starts_with("PRIMARY") {
+        format!("{table}_pkey")
+    } else if upper.starts_with("FOREIGN") {
+        format!("{table}_{}_fkey", columns())
+    } else if upper.starts_with("UNIQUE") {
+        format!("{table}_{}_key", columns())
+    } else {
+        format!("{table}_check")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DDL: &str = "-- SQL DDL generated from LinkML schema
+-- Dialect: postgresql
+
+-- Enum Types
+CREATE TYPE status AS ENUM ('open', 'closed');
+-- Tables
+CREATE TABLE person (
+    id INTEGER PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    employer INTEGER,
+    CONSTRAINT fk_person_employer FOREIGN KEY (employer) REFERENCES organization(id)
+);
+
+CREATE INDEX idx_person_employer ON person(employer);
+
+CREATE TABLE organization (
+    id INTEGER PRIMARY KEY,
+    status status
+);
+
+-- Junction Tables
+CREATE TABLE organization_person (
+    organization_id INTEGER NOT NULL,
+    person_id INTEGER NOT NULL,
+    PRIMARY KEY (organization_id, person_id),
+    FOREIGN KEY (person_id) REFERENCES person(id)
+);
+";
+
+    #[test]
+    fn test_plan_for_empty_database() {
+        let plan = MigrationPlan::new(DDL, &PostgresCatalog::default());
+        assert_eq!(plan.statements.len(), 7);
+        assert_eq!(
+            plan.statements[0],
+            "CREATE TYPE status AS ENUM ('open', 'closed')"
+        );
+        // Tables are created before any foreign key is added
+        assert!(plan.statements[1].starts_with("CREATE TABLE person ("));
+        assert!(!plan.statements[1].contains("FOREIGN KEY"));
+        assert!(plan.statements[3].contains(
+            "CONSTRAINT organization_person_pkey PRIMARY KEY (organization_id, person_id)"
+        ));
+        assert_eq!(
+            plan.statements[4],
+            "ALTER TABLE person ADD CONSTRAINT fk_person_employer FOREIGN KEY (employer) REFERENCES organization(id)"
+        );
+        assert_eq!(
+            plan.statements[5],
+            "ALTER TABLE organization_person ADD CONSTRAINT organization_person_person_id_fkey FOREIGN KEY (person_id) REFERENCES person(id)"
+        );
+        assert_eq!(
+            plan.statements[6],
+            "CREATE INDEX idx_person_employer ON person(employer)"
+        );
+        assert!(plan.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_plan_against_existing_catalog() {
+        let names = |names: &[&str]| names.iter().map(ToString::to_string).collect();
+        let catalog = PostgresCatalog {
+            tables: [
+                ("person".to_string(), names(&["id", "nickname"])),
+                ("organization".to_string(), names(&["id", "status"])),
+                (
+                    "organization_person".to_string(),
+                    names(&["organization_id", "person_id"]),
+                ),
+                ("audit_log".to_string(), names(&["id"])),
+            ]
+            .into_iter()
+            .collect(),
+            enum_types: [("status".to_string(), vec!["open".to_string()])]
+                .into_iter()
+                .collect(),
+            constraints: [
+                ("person".to_string(), names(&["person_pkey"])),
+                (
+                    "organization_person".to_string(),
+                    names(&[
+                        "organization_person_pkey",
+                        "organization_person_person_id_fkey",
+                    ]),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            indexes: names(&["idx_person_employer"]),
+        };
+
+        let plan = MigrationPlan::new(DDL, &catalog);
+        assert_eq!(
+            plan.statements,
+            vec![
+                "ALTER TYPE status ADD VALUE IF NOT EXISTS 'closed'",
+                "ALTER TABLE person ADD COLUMN name VARCHAR(255) NOT NULL",
+                "ALTER TABLE person ADD COLUMN employer INTEGER",
+                "ALTER TABLE person ADD CONSTRAINT fk_person_employer FOREIGN KEY (employer) REFERENCES organization(id)",
+            ]
+        );
+        assert_eq!(
+            plan.warnings,
+            vec![
+                "Column person.name is added as NOT NULL without a default and fails if the table has rows",
+                "Column person.nickname is not in the schema and is kept",
+                "Table audit_log is not in the schema and is kept",
+            ]
+        );
+        assert!(plan.to_sql().contains("-- WARNING: Table audit_log"));
+
+        let migrated = MigrationPlan::new(
+            "CREATE TABLE audit_log (\n    id INTEGER PRIMARY KEY\n);",
+            &PostgresCatalog {
+                tables: [("audit_log".to_string(), names(&["id"]))]
+                    .into_iter()
+                    .collect(),
+                ..Default::default()
+            },
+        );
+        assert!(migrated.is_empty());
+    }
+}
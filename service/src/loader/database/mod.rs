@@ -5,14 +5,18 @@
 
 mod column_info;
 mod converters;
+mod deploy;
 mod dumper;
 mod loader;
+mod migration;
 mod options;
 mod pool;
 
 pub use column_info::ColumnInfo;
+pub use deploy::SqlDeployer;
 pub use dumper::DatabaseDumper;
 pub use loader::DatabaseLoader;
+pub use migration::{MigrationPlan, PostgresCatalog};
 pub use options::{DatabaseOptions, ForeignKeyRelation};
 
 // Re-export for backward compatibility
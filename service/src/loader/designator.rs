@@ -0,0 +1,258 @@
+//! Type designators
+//!
+//! A slot with `designates_type: true` holds the class of the object it
+//! belongs to, which lets a slot whose range is a base class hold objects of
+//! its subclasses:
+//!
+//! ```yaml
+//! animals:
+//!   - category: Dog        # class name
+//!     barks: true
+//!   - category: ex:Cat     # CURIE or URI of the class
+//!     purrs: true
+//! ```
+//!
+//! As in Python `LinkML`, a designator value may be a class name, the
+//! `class_uri` of a class (as written or expanded), or the CURIE or URI of
+//! the class name in the default prefix. The [`TypeDesignator`] resolves
+//! these values for the loaders, which instantiate the designated class, and
+//! for the validator, which validates against it.
+
+use super::inlined::class_slots;
+use crate::validator::suggest;
+use indexmap::IndexMap;
+use linkml_core::prelude::*;
+use linkml_core::utils::{expand_curie, is_subclass_of};
+use serde_json::{Map, Value};
+
+/// Resolves the classes named by type designator slots
+pub struct TypeDesignator<'a> {
+    schema: &'a SchemaDefinition,
+    prefixes: IndexMap<String, String>,
+}
+
+impl<'a> TypeDesignator<'a> {
+    /// Create a type designator resolver for a schema
+    #[must_use]
+    pub fn new(schema: &'a SchemaDefinition) -> Self {
+        let prefixes = schema
+            .prefixes
+            .iter()
+            .map(|(prefix, definition)| {
+                let expansion = match definition {
+                    PrefixDefinition::Simple(expansion) => expansion.clone(),
+                    PrefixDefinition::Complex { prefix_prefix, .. } => prefix_prefix.clone(),
+                };
+                (prefix.clone(), expansion)
+            })
+            .collect();
+        Self { schema, prefixes }
+    }
+
+    /// Name of the type designator slot of a class, including inherited
+    /// slots and `slot_usage`
+    #[must_use]
+    pub fn designator_slot(&self, class_name: &str) -> Option<String> {
+        class_slots(self.schema, class_name)
+            .into_iter()
+            .find(|slot| slot.designates_type == Some(true))
+            .map(|slot| slot.name)
+    }
+
+    /// Class named by a designator value
+    #[must_use]
+    pub fn resolve(&self, value: &str) -> Option<&'a str> {
+        if let Some((name, _)) = self.schema.classes.get_key_value(value) {
+            return Some(name);
+        }
+        let uri = self.expand(value);
+        self.schema
+            .classes
+            .iter()
+            .find(|(name, class)| {
+                let class_uri = class.class_uri.as_deref();
+                class_uri == Some(value)
+                    || class_uri.is_some_and(|class_uri| self.expand(class_uri) == uri)
+                    || self.default_uri(name).is_some_and(|default| default == uri)
+            })
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Class designated by `data`, an object expected to be an instance of
+    /// `class_name`
+    ///
+    /// Returns `None` if the class has no designator slot or `data` does not
+    /// set it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value names no class of the schema, or a
+    /// class that is neither `class_name` nor one of its descendants.
+    pub fn designated_class(&self, data: &Value, class_name: &str) -> Result<Option<&'a str>> {
+        let Some(slot) = self.designator_slot(class_name) else {
+            return Ok(None);
+        };
+        let Some(value) = data.get(&slot).and_then(Value::as_str) else {
+            return Ok(None);
+        };
+        let Some(designated) = self.resolve(value) else {
+            // Suggest the classes the value may be meant to name
+            let candidates = self
+                .schema
+                .classes
+                .keys()
+                .filter(|name| is_subclass_of(self.schema, name, class_name).unwrap_or(false));
+            let suggestions = suggest::near_misses(
+                value,
+                candidates.map(String::as_str),
+                suggest::suggestion_distance(self.schema),
+            );
+            let message = format!("Type designator '{slot}' names unknown class '{value}'");
+            return Err(LinkMLError::data_validation(
+                match suggest::did_you_mean(&suggestions) {
+                    Some(hint) => format!("{message}; {hint}"),
+                    None => message,
+                },
+            ));
+        };
+        if !is_subclass_of(self.schema, designated, class_name)? {
+            return Err(LinkMLError::data_validation(format!(
+                "Type designator '{slot}' names class '{designated}', \
+                 which is not a subclass of '{class_name}'"
+            )));
+        }
+        Ok(Some(designated))
+    }
+
+    /// Class designated by an object of unknown class
+    ///
+    /// Looks for a designator slot of any class that `object` sets to a
+    /// value naming a class.
+    #[must_use]
+    pub fn infer_class(&self, object: &Map<String, Value>) -> Option<&'a str> {
+        let mut class_names: Vec<&String> = self.schema.classes.keys().collect();
+        class_names.sort();
+        class_names.into_iter().find_map(|class_name| {
+            let slot = self.designator_slot(class_name)?;
+            self.resolve(object.get(&slot)?.as_str()?)
+        })
+    }
+
+    fn expand(&self, value: &str) -> String {
+        expand_curie(value, &self.prefixes).unwrap_or_else(|_| value.to_string())
+    }
+
+    /// URI of a class name in the default prefix
+    fn default_uri(&self, class_name: &str) -> Option<String> {
+        let prefix = self.schema.default_prefix.as_deref()?;
+        let expansion = self.prefixes.get(prefix)?;
+        Some(format!("{expansion}{class_name}"))
+    }
+}
+
+/// Whether `class_name` is `target` or one of its descendants, so that
+/// instances of designated subclasses pass a target class filter
+pub(crate) fn is_instance_of(schema: &SchemaDefinition, class_name: &str, target: &str) -> bool {
+    is_subclass_of(schema, class_name, target).unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition {
+            default_prefix: Some("ex".to_string()),
+            ..Default::default()
+        };
+        schema.prefixes.insert(
+            "ex".to_string(),
+            PrefixDefinition::Simple("https://example.org/".to_string()),
+        );
+        schema.prefixes.insert(
+            "schema".to_string(),
+            PrefixDefinition::Simple("http://schema.org/".to_string()),
+        );
+        schema.slots.insert(
+            "category".to_string(),
+            SlotDefinition {
+                range: Some("uriorcurie".to_string()),
+                designates_type: Some(true),
+                ..Default::default()
+            },
+        );
+
+        let animal = ClassDefinition {
+            slots: vec!["category".to_string()],
+            ..Default::default()
+        };
+        schema.classes.insert("Animal".to_string(), animal);
+        for name in ["Dog", "Cat"] {
+            let class = ClassDefinition {
+                is_a: Some("Animal".to_string()),
+                ..Default::default()
+            };
+            schema.classes.insert(name.to_string(), class);
+        }
+        if let Some(cat) = schema.classes.get_mut("Cat") {
+            cat.class_uri = Some("schema:Cat".to_string());
+        }
+        schema
+            .classes
+            .insert("Plant".to_string(), ClassDefinition::default());
+        schema
+    }
+
+    #[test]
+    fn test_designated_class_resolution() {
+        let schema = schema();
+        let designator = TypeDesignator::new(&schema);
+        assert_eq!(
+            designator.designator_slot("Dog").as_deref(),
+            Some("category")
+        );
+
+        for (value, expected) in [
+            ("Dog", "Dog"),
+            ("ex:Dog", "Dog"),
+            ("https://example.org/Dog", "Dog"),
+            ("schema:Cat", "Cat"),
+            ("http://schema.org/Cat", "Cat"),
+        ] {
+            let data = json!({"category": value});
+            assert_eq!(
+                designator
+                    .designated_class(&data, "Animal")
+                    .expect("designated class"),
+                Some(expected),
+                "{value}"
+            );
+        }
+
+        assert_eq!(
+            designator
+                .designated_class(&json!({}), "Animal")
+                .expect("no designator value"),
+            None
+        );
+        assert!(
+            designator
+                .designated_class(&json!({"category": "Unicorn"}), "Animal")
+                .is_err()
+        );
+        assert!(
+            designator
+                .designated_class(&json!({"category": "Cat"}), "Dog")
+                .is_err()
+        );
+        let typo = designator
+            .designated_class(&json!({"category": "Dgo"}), "Animal")
+            .expect_err("unknown class");
+        assert!(typo.to_string().contains("did you mean 'Dog'?"), "{typo}");
+        assert_eq!(
+            designator.infer_class(json!({"category": "ex:Cat"}).as_object().expect("object")),
+            Some("Cat")
+        );
+    }
+}
@@ -14,6 +14,7 @@ use std::path::Path;
 use std::sync::Arc;
 use timestamp_core::{TimestampError, TimestampService};
 
+use super::normalize::apply_normalizers;
 use super::traits::{DataInstance, DataLoader, LoadOptions, LoaderError, LoaderResult};
 
 /// Options specific to Excel loading
@@ -160,7 +161,7 @@ impl ExcelLoader {
             }
         }
 
-        Ok(instances)
+        apply_normalizers(instances, schema, options)
     }
 
     /// Extract headers from the first row or generate them
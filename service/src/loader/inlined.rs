@@ -0,0 +1,307 @@
+//! Dictionary-inlined collections
+//!
+//! The Python `LinkML` dumpers write a multivalued slot whose range class has
+//! an identifier as a dictionary keyed by that identifier (`inlined_as_dict`),
+//! and accept a compact form when the class has a single slot besides its
+//! key:
+//!
+//! ```yaml
+//! persons:                 # expanded form
+//!   P1:
+//!     name: Ada
+//! labels:                  # compact form, `Label` has slots `id` and `value`
+//!   L1: first label
+//! ```
+//!
+//! The [`InlinedExpander`] rewrites both forms into the list of objects the
+//! rest of the pipeline works with, adding the key to every entry. The JSON
+//! and YAML loaders apply it to every instance, and the validation normalizer
+//! applies it before validating. Canonical dumps write such collections back
+//! as dictionaries, so data produced by Python round-trips.
+
+use super::designator::TypeDesignator;
+use super::traits::DataInstance;
+use linkml_core::prelude::*;
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+/// Rewrites dictionary-inlined collections into lists of objects
+pub struct InlinedExpander<'a> {
+    schema: &'a SchemaDefinition,
+}
+
+impl<'a> InlinedExpander<'a> {
+    /// Create an expander for a schema
+    #[must_use]
+    pub fn new(schema: &'a SchemaDefinition) -> Self {
+        Self { schema }
+    }
+
+    /// Expand the collections of a loaded instance in place
+    ///
+    /// Returns the paths of the expanded collections.
+    pub fn expand_instance(&self, instance: &mut DataInstance) -> Vec<String> {
+        let mut data = Value::Object(instance.data.drain().collect());
+        let expanded = self.expand(&mut data, &instance.class_name);
+        if let Value::Object(map) = data {
+            instance.data = map.into_iter().collect();
+        }
+        expanded
+    }
+
+    /// Expand the collections of `data`, an instance of `class_name`, and of
+    /// its nested objects in place
+    ///
+    /// Returns the paths of the expanded collections, such as `$.persons`.
+    pub fn expand(&self, data: &mut Value, class_name: &str) -> Vec<String> {
+        let mut expanded = Vec::new();
+        self.expand_object(data, class_name, "$", &mut expanded);
+        expanded
+    }
+
+    /// List form of a collection given as a dictionary keyed by the
+    /// identifier of `range`
+    ///
+    /// Returns `None` if the value is not a dictionary, `range` has no
+    /// identifier, or a compact entry cannot be assigned to a single value
+    /// slot.
+    #[must_use]
+    pub fn expand_collection(&self, value: &Value, range: &str) -> Option<Vec<Value>> {
+        let Value::Object(entries) = value else {
+            return None;
+        };
+        let slots = class_slots(self.schema, range);
+        let key = slots
+            .iter()
+            .find(|slot| slot.identifier == Some(true))
+            .or_else(|| slots.iter().find(|slot| slot.key == Some(true)))?;
+        let mut value_slots = slots.iter().filter(|slot| slot.name != key.name);
+        let value_slot = match (value_slots.next(), value_slots.next()) {
+            (Some(slot), None) => Some(slot),
+            _ => None,
+        };
+
+        entries
+            .iter()
+            .map(|(id, entry)| {
+                let mut object = match entry {
+                    Value::Object(object) => object.clone(),
+                    Value::Null => Map::new(),
+                    // Compact form: the value of the only non-key slot
+                    _ => Map::from_iter([(value_slot?.name.clone(), entry.clone())]),
+                };
+                object
+                    .entry(key.name.clone())
+                    .or_insert_with(|| Value::String(id.clone()));
+                Some(Value::Object(object))
+            })
+            .collect()
+    }
+
+    fn expand_object(
+        &self,
+        data: &mut Value,
+        class_name: &str,
+        path: &str,
+        expanded: &mut Vec<String>,
+    ) {
+        let Some(object) = data.as_object_mut() else {
+            return;
+        };
+        for slot in class_slots(self.schema, class_name) {
+            let Some(range) = slot
+                .range
+                .as_deref()
+                .filter(|range| self.schema.classes.contains_key(*range))
+            else {
+                continue;
+            };
+            let Some(value) = object.get_mut(&slot.name) else {
+                continue;
+            };
+            let path = format!("{path}.{}", slot.name);
+
+            if slot.multivalued == Some(true)
+                && let Some(items) = self.expand_collection(value, range)
+            {
+                *value = Value::Array(items);
+                expanded.push(path.clone());
+            }
+            match value {
+                Value::Array(items) => {
+                    for (index, item) in items.iter_mut().enumerate() {
+                        let class = nested_class(self.schema, item, range);
+                        self.expand_object(item, &class, &format!("{path}[{index}]"), expanded);
+                    }
+                }
+                Value::Object(_) => {
+                    let class = nested_class(self.schema, value, range);
+                    self.expand_object(value, &class, &path, expanded);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Class of a nested object: its `@type` if that names a class, else the
+/// class named by its type designator, else the slot range
+pub(crate) fn nested_class(schema: &SchemaDefinition, value: &Value, range: &str) -> String {
+    value
+        .get("@type")
+        .and_then(Value::as_str)
+        .filter(|name| schema.classes.contains_key(*name))
+        .or_else(|| {
+            TypeDesignator::new(schema)
+                .designated_class(value, range)
+                .ok()
+                .flatten()
+        })
+        .unwrap_or(range)
+        .to_string()
+}
+
+/// Slots of a class in schema order, ancestors and mixins first, with
+/// `slot_usage` applied
+pub(crate) fn class_slots(schema: &SchemaDefinition, class_name: &str) -> Vec<SlotDefinition> {
+    let mut slots = Vec::new();
+    collect_slots(schema, class_name, &mut slots, &mut HashSet::new());
+    slots
+}
+
+fn collect_slots(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    slots: &mut Vec<SlotDefinition>,
+    visited: &mut HashSet<String>,
+) {
+    if !visited.insert(class_name.to_string()) {
+        return;
+    }
+    let Some(class) = schema.classes.get(class_name) else {
+        return;
+    };
+    for parent in class.is_a.iter().chain(&class.mixins) {
+        collect_slots(schema, parent, slots, visited);
+    }
+
+    let own = class
+        .slots
+        .iter()
+        .filter_map(|name| {
+            let mut slot = schema.slots.get(name)?.clone();
+            slot.name.clone_from(name);
+            Some(slot)
+        })
+        .chain(class.attributes.iter().map(|(name, attribute)| {
+            let mut slot = attribute.clone();
+            slot.name.clone_from(name);
+            slot
+        }));
+    for slot in own {
+        if !slots.iter().any(|existing| existing.name == slot.name) {
+            slots.push(slot);
+        }
+    }
+
+    for (name, usage) in &class.slot_usage {
+        if let Some(slot) = slots.iter_mut().find(|slot| slot.name == *name) {
+            if usage.range.is_some() {
+                slot.range.clone_from(&usage.range);
+            }
+            if usage.multivalued.is_some() {
+                slot.multivalued = usage.multivalued;
+            }
+            if usage.identifier.is_some() {
+                slot.identifier = usage.identifier;
+            }
+            if usage.inlined.is_some() {
+                slot.inlined = usage.inlined;
+            }
+            if usage.inlined_as_list.is_some() {
+                slot.inlined_as_list = usage.inlined_as_list;
+            }
+            if usage.inlined_as_dict.is_some() {
+                slot.inlined_as_dict = usage.inlined_as_dict;
+            }
+            if usage.designates_type.is_some() {
+                slot.designates_type = usage.designates_type;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn slot(range: &str) -> SlotDefinition {
+        SlotDefinition {
+            range: Some(range.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_expand_dict_and_compact_forms() {
+        let mut schema = SchemaDefinition::default();
+        let mut id = slot("string");
+        id.identifier = Some(true);
+        schema.slots.insert("id".to_string(), id);
+        for name in ["name", "value"] {
+            schema.slots.insert(name.to_string(), slot("string"));
+        }
+        for (name, range) in [("persons", "Person"), ("labels", "Label")] {
+            let mut collection = slot(range);
+            collection.multivalued = Some(true);
+            collection.inlined_as_dict = Some(true);
+            schema.slots.insert(name.to_string(), collection);
+        }
+
+        let class = |slots: &[&str]| ClassDefinition {
+            slots: slots.iter().map(ToString::to_string).collect(),
+            ..Default::default()
+        };
+        schema
+            .classes
+            .insert("Container".to_string(), class(&["persons"]));
+        schema
+            .classes
+            .insert("Person".to_string(), class(&["id", "name", "labels"]));
+        schema
+            .classes
+            .insert("Label".to_string(), class(&["id", "value"]));
+
+        let mut data = json!({
+            "persons": {
+                "P1": {"name": "Ada", "labels": {"L1": "first", "L2": null}},
+                "P2": null
+            }
+        });
+        let expanded = InlinedExpander::new(&schema).expand(&mut data, "Container");
+
+        assert_eq!(
+            data,
+            json!({
+                "persons": [
+                    {
+                        "id": "P1",
+                        "name": "Ada",
+                        "labels": [{"id": "L1", "value": "first"}, {"id": "L2"}]
+                    },
+                    {"id": "P2"}
+                ]
+            })
+        );
+        assert_eq!(expanded, ["$.persons", "$.persons[0].labels"]);
+
+        // Persons have two non-key slots, so compact entries are rejected
+        let compact = json!({"P1": "Ada"});
+        assert!(
+            InlinedExpander::new(&schema)
+                .expand_collection(&compact, "Person")
+                .is_none()
+        );
+    }
+}
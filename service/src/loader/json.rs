@@ -2,6 +2,10 @@
 //!
 //! This module provides functionality to load and dump `LinkML` data in JSON format.
 
+use super::canonical::Canonicalizer;
+use super::designator::{TypeDesignator, is_instance_of};
+use super::inlined::InlinedExpander;
+use super::normalize::apply_normalizers;
 use super::traits::{
     DataDumper, DataInstance, DataLoader, DumpOptions, DumperError, DumperResult, LoadOptions,
     LoaderError, LoaderResult,
@@ -88,7 +92,7 @@ impl DataLoader for JsonLoader {
 
                         // Apply class filtering if specified in options
                         if let Some(ref target_class) = options.target_class
-                            && instance.class_name != *target_class
+                            && !is_instance_of(schema, &instance.class_name, target_class)
                         {
                             continue;
                         }
@@ -115,7 +119,7 @@ impl DataLoader for JsonLoader {
 
                 // Apply class filtering if specified in options
                 if let Some(ref target_class) = options.target_class
-                    && instance.class_name != *target_class
+                    && !is_instance_of(schema, &instance.class_name, target_class)
                 {
                     return Ok(vec![]);
                 }
@@ -133,7 +137,16 @@ impl DataLoader for JsonLoader {
             }
         };
 
-        Ok(instances)
+        let expander = InlinedExpander::new(schema);
+        let instances = instances
+            .into_iter()
+            .map(|mut instance| {
+                expander.expand_instance(&mut instance);
+                instance
+            })
+            .collect();
+
+        apply_normalizers(instances, schema, options)
     }
 
     async fn load_bytes(
@@ -335,9 +348,12 @@ impl JsonLoader {
         obj: Map<String, Value>,
         schema: &SchemaDefinition,
     ) -> LoaderResult<DataInstance> {
-        // Try to determine class from @type field or structure
+        // Try to determine class from @type field, type designator or structure
+        let designated = TypeDesignator::new(schema).infer_class(&obj);
         let class_name = if let Some(Value::String(type_val)) = obj.get("@type") {
             type_val.clone()
+        } else if let Some(designated) = designated {
+            designated.to_string()
         } else {
             // Try to infer from structure
             self.infer_class(&obj, schema)?
@@ -429,9 +445,15 @@ impl DataDumper for JsonDumper {
     async fn dump_string(
         &self,
         instances: &[DataInstance],
-        _schema: &SchemaDefinition,
+        schema: &SchemaDefinition,
         options: &DumpOptions,
     ) -> DumperResult<String> {
+        if options.canonical {
+            let canonical = Canonicalizer::new(schema, options).instances(instances)?;
+            return serde_json::to_string_pretty(&canonical)
+                .map_err(|e| DumperError::Serialization(e.to_string()));
+        }
+
         let json_instances: Vec<Value> = instances
             .iter()
             .map(|instance| {
@@ -640,4 +662,37 @@ mod tests {
         assert_eq!(parsed[0]["name"], "Alice");
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_json_loader_type_designator() -> std::result::Result<(), anyhow::Error> {
+        let mut schema = SchemaDefinition::default();
+        let category = SlotDefinition {
+            designates_type: Some(true),
+            ..Default::default()
+        };
+        schema.slots.insert("category".to_string(), category);
+        let animal = ClassDefinition {
+            slots: vec!["category".to_string()],
+            ..Default::default()
+        };
+        schema.classes.insert("Animal".to_string(), animal);
+        let dog = ClassDefinition {
+            is_a: Some("Animal".to_string()),
+            ..Default::default()
+        };
+        schema.classes.insert("Dog".to_string(), dog);
+
+        let loader = JsonLoader::new();
+        let options = LoadOptions {
+            target_class: Some("Animal".to_string()),
+            ..Default::default()
+        };
+        let instances = loader
+            .load_string(r#"[{"category": "Dog"}]"#, &schema, &options)
+            .await?;
+
+        assert_eq!(instances.len(), 1);
+        assert_eq!(instances[0].class_name, "Dog");
+        Ok(())
+    }
 }
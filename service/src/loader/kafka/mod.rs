@@ -0,0 +1,19 @@
+//! Schema-enforced Kafka streams for `LinkML`
+//!
+//! A [`KafkaValidator`] consumes JSON records from an input topic, validates
+//! each against a target class as it arrives and produces it, unchanged, to a
+//! valid or an invalid output topic. The outcome travels with the record as
+//! headers: `linkml-valid`, `linkml-target-class` and the JSON
+//! `ValidationReport` in `linkml-validation-report`. Offsets are committed
+//! only after the record has been produced, so a restarted validator resumes
+//! without losing records. Works with any Kafka-compatible broker, including
+//! Redpanda.
+
+mod record;
+mod stream;
+
+pub use record::{
+    KafkaOptions, REPORT_HEADER, RecordValidator, TARGET_CLASS_HEADER, VALID_HEADER,
+    ValidatedRecord,
+};
+pub use stream::{KafkaStats, KafkaValidator};
@@ -0,0 +1,228 @@
+//! Validation and routing of single stream records
+
+use crate::validator::engine::ValidationEngine;
+use crate::validator::report::{ValidationIssue, ValidationReport};
+use linkml_core::prelude::*;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Header carrying `true` or `false` for the validity of a record
+pub const VALID_HEADER: &str = "linkml-valid";
+
+/// Header carrying the class records are validated against
+pub const TARGET_CLASS_HEADER: &str = "linkml-target-class";
+
+/// Header carrying the validation report as JSON
+pub const REPORT_HEADER: &str = "linkml-validation-report";
+
+/// Prefix of the headers written by the validator; incoming headers with it
+/// are replaced
+pub const HEADER_PREFIX: &str = "linkml-";
+
+/// Validator name of issues about records that cannot be validated
+const VALIDATOR_NAME: &str = "kafka";
+
+/// Kafka validation stream options
+#[derive(Debug, Clone)]
+pub struct KafkaOptions {
+    /// Bootstrap servers, e.g. `localhost:9092`
+    pub brokers: String,
+
+    /// Consumer group of the validator
+    pub group_id: String,
+
+    /// Topic the records to validate are consumed from
+    pub input_topic: String,
+
+    /// Topic valid records are produced to
+    pub valid_topic: String,
+
+    /// Topic invalid records are produced to
+    pub invalid_topic: String,
+
+    /// Class the records are validated against
+    pub target_class: String,
+
+    /// Additional client settings, e.g. `security.protocol` or `sasl.*`
+    pub client_config: HashMap<String, String>,
+
+    /// How long producing a record may wait for delivery
+    pub delivery_timeout: Duration,
+}
+
+impl KafkaOptions {
+    /// Options validating `input_topic` against `target_class`, producing to
+    /// `{input_topic}.valid` and `{input_topic}.invalid`
+    #[must_use]
+    pub fn new(
+        brokers: impl Into<String>,
+        input_topic: impl Into<String>,
+        target_class: impl Into<String>,
+    ) -> Self {
+        let input_topic = input_topic.into();
+        Self {
+            brokers: brokers.into(),
+            group_id: "linkml-validator".to_string(),
+            valid_topic: format!("{input_topic}.valid"),
+            invalid_topic: format!("{input_topic}.invalid"),
+            input_topic,
+            target_class: target_class.into(),
+            client_config: HashMap::new(),
+            delivery_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Outcome of validating a record
+#[derive(Debug, Clone)]
+pub struct ValidatedRecord {
+    /// Whether the record is valid
+    pub valid: bool,
+
+    /// Validation report of the record
+    pub report: ValidationReport,
+}
+
+impl ValidatedRecord {
+    /// Topic the record is routed to
+    #[must_use]
+    pub fn topic<'a>(&self, options: &'a KafkaOptions) -> &'a str {
+        if self.valid {
+            &options.valid_topic
+        } else {
+            &options.invalid_topic
+        }
+    }
+
+    /// Headers attached to the routed record
+    #[must_use]
+    pub fn headers(&self, target_class: &str) -> Vec<(&'static str, String)> {
+        vec![
+            (VALID_HEADER, self.valid.to_string()),
+            (TARGET_CLASS_HEADER, target_class.to_string()),
+            (
+                REPORT_HEADER,
+                serde_json::to_string(&self.report).unwrap_or_default(),
+            ),
+        ]
+    }
+}
+
+/// Validates record payloads against a class of a schema
+pub struct RecordValidator {
+    engine: ValidationEngine,
+    schema_id: String,
+    target_class: String,
+}
+
+impl RecordValidator {
+    /// Create a validator of instances of `target_class`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema lacks the class or the validation
+    /// engine cannot be built for it
+    pub fn new(schema: &SchemaDefinition, target_class: impl Into<String>) -> Result<Self> {
+        let target_class = target_class.into();
+        if !schema.classes.contains_key(&target_class) {
+            return Err(LinkMLError::config(format!(
+                "Schema {} has no class {target_class}",
+                schema.name
+            )));
+        }
+        Ok(Self {
+            engine: ValidationEngine::new(schema)?,
+            schema_id: schema.id.clone(),
+            target_class,
+        })
+    }
+
+    /// Validate a JSON payload; missing and malformed payloads are invalid
+    pub async fn validate(&self, payload: Option<&[u8]>) -> ValidatedRecord {
+        let report = match payload.map(serde_json::from_slice::<serde_json::Value>) {
+            None => self.rejection("Record has no payload"),
+            Some(Err(e)) => self.rejection(&format!("Record payload is not valid JSON: {e}")),
+            Some(Ok(value)) => self
+                .engine
+                .validate_as_class(&value, &self.target_class, None)
+                .await
+                .unwrap_or_else(|e| self.rejection(&format!("Validation failed: {e}"))),
+        };
+        ValidatedRecord {
+            valid: report.valid,
+            report,
+        }
+    }
+
+    /// Report of a record that cannot be validated
+    fn rejection(&self, message: &str) -> ValidationReport {
+        let mut report = ValidationReport::new(self.schema_id.clone());
+        report.target_class = Some(self.target_class.clone());
+        report.add_issue(ValidationIssue::error(message, "$", VALIDATOR_NAME));
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::{ClassDefinition, SlotDefinition};
+
+    fn validator() -> RecordValidator {
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/orders".to_string(),
+            name: "orders".to_string(),
+            ..Default::default()
+        };
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                name: "id".to_string(),
+                identifier: Some(true),
+                required: Some(true),
+                range: Some("string".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Order".to_string(),
+            ClassDefinition {
+                name: "Order".to_string(),
+                slots: vec!["id".to_string()],
+                ..Default::default()
+            },
+        );
+        assert!(RecordValidator::new(&schema, "Invoice").is_err());
+        RecordValidator::new(&schema, "Order").expect("validator")
+    }
+
+    #[tokio::test]
+    async fn test_record_routing() {
+        let validator = validator();
+        let options = KafkaOptions::new("localhost:9092", "orders", "Order");
+
+        let valid = validator.validate(Some(br#"{"id": "o1"}"#)).await;
+        assert!(valid.valid);
+        assert_eq!(valid.topic(&options), "orders.valid");
+
+        let invalid = validator.validate(Some(br#"{"total": 3}"#)).await;
+        assert!(!invalid.valid);
+        assert_eq!(invalid.topic(&options), "orders.invalid");
+
+        let malformed = validator.validate(Some(b"{not json")).await;
+        assert!(!malformed.valid);
+        assert!(
+            malformed.report.issues[0]
+                .message
+                .starts_with("Record payload is not valid JSON")
+        );
+
+        let tombstone = validator.validate(None).await;
+        let headers = tombstone.headers("Order");
+        assert_eq!(headers[0], (VALID_HEADER, "false".to_string()));
+        assert_eq!(headers[1], (TARGET_CLASS_HEADER, "Order".to_string()));
+        let report: serde_json::Value =
+            serde_json::from_str(&headers[2].1).expect("report header is JSON");
+        assert_eq!(report["issues"][0]["message"], "Record has no payload");
+    }
+}
@@ -0,0 +1,181 @@
+//! Kafka consumer and producer of the validation stream
+
+use super::record::{HEADER_PREFIX, KafkaOptions, RecordValidator};
+use crate::loader::traits::{LoaderError, LoaderResult};
+use linkml_core::prelude::*;
+use rdkafka::ClientConfig;
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::message::{Header, Headers, Message, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord, Producer};
+use rdkafka::util::Timeout;
+use std::future::Future;
+use tracing::{debug, info};
+
+/// Numbers of records routed by a validation stream
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KafkaStats {
+    /// Records produced to the valid topic
+    pub valid: u64,
+    /// Records produced to the invalid topic
+    pub invalid: u64,
+}
+
+/// Consumes records from a topic, validates them and produces them to the
+/// valid or invalid topic
+pub struct KafkaValidator {
+    options: KafkaOptions,
+    validator: RecordValidator,
+    consumer: StreamConsumer,
+    producer: FutureProducer,
+}
+
+impl KafkaValidator {
+    /// Connect a validator of `options.input_topic` against
+    /// `options.target_class`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema lacks the target class, or the
+    /// consumer or producer cannot be created
+    pub fn new(schema: &SchemaDefinition, options: KafkaOptions) -> LoaderResult<Self> {
+        let validator = RecordValidator::new(schema, options.target_class.clone())
+            .map_err(|e| LoaderError::Configuration(e.to_string()))?;
+
+        let mut config = ClientConfig::new();
+        config.set("bootstrap.servers", &options.brokers);
+        for (key, value) in &options.client_config {
+            config.set(key, value);
+        }
+
+        // Offsets are committed once the record has been produced
+        let consumer: StreamConsumer = config
+            .clone()
+            .set("group.id", &options.group_id)
+            .set("enable.auto.commit", "false")
+            .set("auto.offset.reset", "earliest")
+            .create()
+            .map_err(|e| {
+                LoaderError::Configuration(format!("Failed to create Kafka consumer: {e}"))
+            })?;
+        consumer
+            .subscribe(&[options.input_topic.as_str()])
+            .map_err(|e| {
+                LoaderError::Configuration(format!(
+                    "Failed to subscribe to {}: {e}",
+                    options.input_topic
+                ))
+            })?;
+        let producer: FutureProducer = config.create().map_err(|e| {
+            LoaderError::Configuration(format!("Failed to create Kafka producer: {e}"))
+        })?;
+
+        Ok(Self {
+            options,
+            validator,
+            consumer,
+            producer,
+        })
+    }
+
+    /// Validate and route the next record, returning whether it was valid
+    ///
+    /// The record keeps its key, payload and headers; the validity, target
+    /// class and validation report are added as headers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if consuming, producing or committing fails
+    pub async fn process_next(&self) -> LoaderResult<bool> {
+        let message = self.consumer.recv().await.map_err(|e| {
+            LoaderError::Io(std::io::Error::other(format!(
+                "Failed to consume Kafka record: {e}"
+            )))
+        })?;
+        let record = self.validator.validate(message.payload()).await;
+        let topic = record.topic(&self.options);
+
+        let mut headers = OwnedHeaders::new();
+        if let Some(incoming) = message.headers() {
+            for header in incoming.iter() {
+                if !header.key.starts_with(HEADER_PREFIX) {
+                    headers = headers.insert(header);
+                }
+            }
+        }
+        for (key, value) in record.headers(&self.options.target_class) {
+            headers = headers.insert(Header {
+                key,
+                value: Some(value.as_str()),
+            });
+        }
+
+        let mut output: FutureRecord<'_, [u8], [u8]> = FutureRecord::to(topic).headers(headers);
+        if let Some(key) = message.key() {
+            output = output.key(key);
+        }
+        if let Some(payload) = message.payload() {
+            output = output.payload(payload);
+        }
+        self.producer
+            .send(output, Timeout::After(self.options.delivery_timeout))
+            .await
+            .map_err(|(e, _)| {
+                LoaderError::Io(std::io::Error::other(format!(
+                    "Failed to produce Kafka record to {topic}: {e}"
+                )))
+            })?;
+        self.consumer
+            .commit_message(&message, CommitMode::Async)
+            .map_err(|e| {
+                LoaderError::Io(std::io::Error::other(format!(
+                    "Failed to commit Kafka offset: {e}"
+                )))
+            })?;
+
+        debug!(
+            "Routed record at offset {} of partition {} to {topic}",
+            message.offset(),
+            message.partition()
+        );
+        Ok(record.valid)
+    }
+
+    /// Validate and route records until `shutdown` completes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if processing a record fails or pending records
+    /// cannot be flushed
+    pub async fn run_until(&self, shutdown: impl Future<Output = ()>) -> LoaderResult<KafkaStats> {
+        let mut stats = KafkaStats::default();
+        tokio::pin!(shutdown);
+        loop {
+            tokio::select! {
+                () = &mut shutdown => break,
+                valid = self.process_next() => {
+                    if valid? {
+                        stats.valid += 1;
+                    } else {
+                        stats.invalid += 1;
+                    }
+                }
+            }
+        }
+
+        self.producer
+            .flush(Timeout::After(self.options.delivery_timeout))
+            .map_err(|e| {
+                LoaderError::Io(std::io::Error::other(format!(
+                    "Failed to flush Kafka producer: {e}"
+                )))
+            })?;
+        info!(
+            "Validated {} records from {}: {} valid, {} invalid",
+            stats.valid + stats.invalid,
+            self.options.input_topic,
+            stats.valid,
+            stats.invalid
+        );
+        Ok(stats)
+    }
+}
@@ -5,20 +5,32 @@
 //! and external formats.
 
 pub mod api;
+#[cfg(feature = "scientific")]
+pub mod arrays;
+pub mod canonical;
+pub mod column_profile;
 pub mod csv;
 #[cfg(feature = "database")]
 pub mod database;
 pub mod dbms_executor;
+pub mod designator;
 pub mod excel;
+pub mod inlined;
 pub mod json;
 pub mod json_v2;
+#[cfg(feature = "kafka")]
+pub mod kafka;
+pub mod neo4j;
+pub mod normalize;
 pub mod rdf;
 pub mod traits;
 pub mod traits_v2;
 pub mod typedb;
+pub mod typedb_executor;
 pub mod typedb_integration;
 pub mod xml;
 pub mod xml_impl;
+pub mod xsd;
 pub mod yaml;
 pub mod yaml_v2;
 
@@ -26,20 +38,39 @@ pub use api::{
     ApiDumper, ApiLoader, ApiOptions, AuthConfig, EndpointConfig, PaginationConfig,
     PaginationStyle, RetryConfig,
 };
+#[cfg(feature = "scientific")]
+pub use arrays::{ArrayOptions, Hdf5Dumper, NetCdfLoader, ZarrDumper, ZarrLoader};
+pub use canonical::Canonicalizer;
+pub use column_profile::{ColumnMapping, ColumnProfile, ColumnRule, HeaderCase, ValueTransform};
 pub use csv::{CsvDumper, CsvLoader, CsvOptions};
 #[cfg(feature = "database")]
-pub use database::{DatabaseDumper, DatabaseLoader, DatabaseOptions, ForeignKeyRelation};
+pub use database::{
+    DatabaseDumper, DatabaseLoader, DatabaseOptions, ForeignKeyRelation, MigrationPlan,
+    PostgresCatalog, SqlDeployer,
+};
 pub use dbms_executor::DBMSServiceExecutor;
+pub use designator::TypeDesignator;
 pub use excel::{ExcelLoader, ExcelOptions};
+pub use inlined::InlinedExpander;
 pub use json::{JsonDumper, JsonLoader};
+#[cfg(feature = "kafka")]
+pub use kafka::{KafkaOptions, KafkaStats, KafkaValidator};
+pub use neo4j::{CypherExecutor, Neo4jDumper, Neo4jHttpExecutor, Neo4jLoader, Neo4jOptions};
+pub use normalize::{
+    ByteSizeNormalizer, CurrencyNormalizer, NormalizationContext, Normalized, NormalizerRegistry,
+    ValueNormalizer,
+};
 pub use rdf::{RdfDumper, RdfLoader, RdfOptions, RdfSerializationFormat};
 pub use traits::{
     DataDumper, DataInstance, DataLoader, DumpOptions, DumperError, DumperResult, LoadOptions,
     LoaderError, LoaderResult,
 };
 pub use typedb::{TypeDBDumper, TypeDBLoader, TypeDBOptions};
+pub use typedb_executor::{TypeDBBulkExecutor, TypeDBExecutorError, TypeDBRetryPolicy};
 pub use typedb_integration::{
-    TypeDBIntegrationDumper, TypeDBIntegrationLoader, TypeDBIntegrationOptions, TypeDBQueryExecutor,
+    TypeDB3HttpExecutor, TypeDBIntegrationDumper, TypeDBIntegrationLoader,
+    TypeDBIntegrationOptions, TypeDBQueryExecutor,
 };
-pub use xml::{XmlDumper, XmlLoader};
+pub use xml::{XmlDumper, XmlLoader, XmlNode};
+pub use xsd::XsdModel;
 pub use yaml::{YamlDumper, YamlLoader};
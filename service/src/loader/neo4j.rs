@@ -0,0 +1,896 @@
+//! `Neo4j` integration for `LinkML`
+//!
+//! Loads and dumps instances using the property graph model of
+//! [`crate::generator::cypher`]: an instance is a node labelled with its class
+//! and the ancestors of the class, its scalar slots are node properties and
+//! its object-valued slots are relationships to the nodes of the referenced
+//! instances, typed by the slot name in `UPPER_SNAKE` case.
+//!
+//! Nodes of classes with an identifier slot are merged on the identifier, so
+//! dumping is idempotent; relationships can only start and end at such nodes.
+//! Inlined objects become nodes of their own. When loading, references are
+//! read back as identifiers and each node becomes an instance of its most
+//! specific class.
+//!
+//! Statements are run through a [`CypherExecutor`];
+//! [`Neo4jHttpExecutor`] speaks the `Neo4j` HTTP API.
+
+use super::normalize::apply_normalizers;
+use super::traits::{
+    DataDumper, DataInstance, DataLoader, DumpOptions, DumperError, DumperResult, LoadOptions,
+    LoaderError, LoaderResult,
+};
+use crate::generator::base::induced_slots;
+use crate::generator::cypher::{
+    class_labels, identifier_slot, object_range, quote_name, relationship_type,
+};
+use async_trait::async_trait;
+use linkml_core::prelude::*;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// `Neo4j` integration options
+#[derive(Debug, Clone)]
+pub struct Neo4jOptions {
+    /// Database name in `Neo4j`
+    pub database: String,
+
+    /// `Neo4j` label to `LinkML` class mapping
+    pub label_mapping: HashMap<String, String>,
+
+    /// Number of nodes or relationships written per statement
+    pub batch_size: usize,
+}
+
+impl Default for Neo4jOptions {
+    fn default() -> Self {
+        Self {
+            database: "neo4j".to_string(),
+            label_mapping: HashMap::new(),
+            batch_size: 1000,
+        }
+    }
+}
+
+impl Neo4jOptions {
+    /// Label of the nodes of a class
+    fn label(&self, class_name: &str) -> String {
+        self.label_mapping
+            .iter()
+            .find(|(_, class)| *class == class_name)
+            .map_or_else(|| class_name.to_string(), |(label, _)| label.clone())
+    }
+
+    /// Class of a label
+    fn class_name<'a>(&'a self, label: &'a str) -> &'a str {
+        self.label_mapping.get(label).map_or(label, String::as_str)
+    }
+}
+
+/// Cypher statement executor
+///
+/// This trait abstracts the execution of Cypher statements, allowing the
+/// loader and dumper to work with any `Neo4j` driver.
+#[async_trait]
+pub trait CypherExecutor: Send + Sync {
+    /// Run a statement with parameters in its own transaction, returning one
+    /// object per result row mapping column names to values
+    async fn run(
+        &self,
+        statement: &str,
+        parameters: Value,
+        database: &str,
+    ) -> std::result::Result<Vec<Map<String, Value>>, Box<dyn std::error::Error>>;
+}
+
+/// Statement executor using the `Neo4j` HTTP API
+///
+/// Every statement is committed in a transaction of its own through
+/// `/db/{database}/tx/commit`, authenticating with basic authentication.
+pub struct Neo4jHttpExecutor {
+    base_url: String,
+    username: String,
+    password: String,
+    timeout: Duration,
+    client: reqwest::Client,
+}
+
+impl Neo4jHttpExecutor {
+    /// Create an executor for the HTTP endpoint of a `Neo4j` server, e.g.
+    /// `http://localhost:7474`
+    pub fn new(
+        base_url: impl Into<String>,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            username: username.into(),
+            password: password.into(),
+            timeout: Duration::from_secs(30),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Set the timeout of each HTTP request
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+#[async_trait]
+impl CypherExecutor for Neo4jHttpExecutor {
+    async fn run(
+        &self,
+        statement: &str,
+        parameters: Value,
+        database: &str,
+    ) -> std::result::Result<Vec<Map<String, Value>>, Box<dyn std::error::Error>> {
+        let body: Value = self
+            .client
+            .post(format!("{}/db/{database}/tx/commit", self.base_url))
+            .timeout(self.timeout)
+            .basic_auth(&self.username, Some(&self.password))
+            .json(&json!({
+                "statements": [{ "statement": statement, "parameters": parameters }]
+            }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(result_rows(&body)?)
+    }
+}
+
+/// Rows of the first result of a `Neo4j` HTTP API response
+fn result_rows(body: &Value) -> std::result::Result<Vec<Map<String, Value>>, String> {
+    if let Some(error) = body
+        .get("errors")
+        .and_then(Value::as_array)
+        .and_then(|errors| errors.first())
+    {
+        return Err(format!(
+            "Neo4j statement failed ({}): {}",
+            error
+                .get("code")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown"),
+            error.get("message").and_then(Value::as_str).unwrap_or("")
+        ));
+    }
+    let Some(result) = body
+        .get("results")
+        .and_then(Value::as_array)
+        .and_then(|results| results.first())
+    else {
+        return Ok(Vec::new());
+    };
+    let columns: Vec<&str> = result
+        .get("columns")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    Ok(result
+        .get("data")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.get("row").and_then(Value::as_array))
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| (*column).to_string())
+                .zip(row.iter().cloned())
+                .collect()
+        })
+        .collect())
+}
+
+/// `Neo4j` loader using an abstract statement executor
+pub struct Neo4jLoader<E: CypherExecutor> {
+    options: Neo4jOptions,
+    executor: E,
+}
+
+impl<E: CypherExecutor> Neo4jLoader<E> {
+    /// Create a new `Neo4j` loader
+    pub fn new(options: Neo4jOptions, executor: E) -> Self {
+        Self { options, executor }
+    }
+
+    /// Most specific schema class among the labels of a node
+    fn node_class(&self, labels: &Value, schema: &SchemaDefinition) -> Option<String> {
+        labels
+            .as_array()?
+            .iter()
+            .filter_map(Value::as_str)
+            .map(|label| self.options.class_name(label))
+            .filter(|class_name| schema.classes.contains_key(*class_name))
+            .max_by_key(|class_name| class_labels(schema, class_name).len())
+            .map(str::to_string)
+    }
+
+    /// Instance of a node returned by the load query
+    fn node_instance(
+        &self,
+        row: &Map<String, Value>,
+        schema: &SchemaDefinition,
+    ) -> Option<DataInstance> {
+        let class_name = self.node_class(row.get("labels")?, schema)?;
+        let mut data: HashMap<String, Value> = row
+            .get("properties")
+            .and_then(Value::as_object)
+            .map(|properties| properties.clone().into_iter().collect())
+            .unwrap_or_default();
+
+        let links = row
+            .get("links")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for slot in induced_slots(schema, &class_name) {
+            let Some(range) = object_range(schema, &slot) else {
+                continue;
+            };
+            let relationship = relationship_type(&slot.name);
+            let targets: Vec<Value> = links
+                .iter()
+                .filter(|link| link.get("type").and_then(Value::as_str) == Some(&relationship))
+                .map(|link| {
+                    let properties = link.get("properties").cloned().unwrap_or(Value::Null);
+                    let target_class = link
+                        .get("labels")
+                        .and_then(|labels| self.node_class(labels, schema))
+                        .unwrap_or_else(|| range.to_string());
+                    // Targets without an identifier are read back inlined
+                    identifier_slot(schema, &target_class)
+                        .and_then(|key| properties.get(key).cloned())
+                        .unwrap_or(properties)
+                })
+                .collect();
+            if targets.is_empty() {
+                continue;
+            }
+            let value = if slot.multivalued == Some(true) {
+                Value::Array(targets)
+            } else {
+                targets.into_iter().next().unwrap_or(Value::Null)
+            };
+            data.insert(slot.name, value);
+        }
+
+        let id = identifier_slot(schema, &class_name)
+            .and_then(|key| data.get(&key))
+            .map(|value| match value {
+                Value::String(id) => id.clone(),
+                other => other.to_string(),
+            });
+        Some(DataInstance {
+            class_name,
+            data,
+            id,
+            metadata: HashMap::new(),
+        })
+    }
+}
+
+#[async_trait]
+impl<E: CypherExecutor> DataLoader for Neo4jLoader<E> {
+    fn name(&self) -> &'static str {
+        "neo4j"
+    }
+
+    fn description(&self) -> &'static str {
+        "Load data from Neo4j, mapping node labels to classes and relationships to object-valued slots"
+    }
+
+    fn supported_extensions(&self) -> Vec<&str> {
+        vec![] // Neo4j loader doesn't use file extensions
+    }
+
+    async fn load_file(
+        &self,
+        _path: &std::path::Path,
+        _schema: &SchemaDefinition,
+        _options: &LoadOptions,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        Err(LoaderError::InvalidFormat(
+            "Neo4j loader does not support file loading".to_string(),
+        ))
+    }
+
+    async fn load_string(
+        &self,
+        _content: &str,
+        schema: &SchemaDefinition,
+        options: &LoadOptions,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        let labels: Vec<String> = match &options.target_class {
+            Some(target_class) => vec![self.options.label(target_class)],
+            None => schema
+                .classes
+                .keys()
+                .map(|class_name| self.options.label(class_name))
+                .collect(),
+        };
+        let mut statement = "MATCH (n) WHERE any(label IN labels(n) WHERE label IN $labels) \
+             RETURN labels(n) AS labels, properties(n) AS properties, \
+             [(n)-[r]->(m) | {type: type(r), labels: labels(m), properties: properties(m)}] AS links"
+            .to_string();
+        if let Some(limit) = options.limit {
+            statement.push_str(&format!(" LIMIT {limit}"));
+        }
+        debug!("Executing statement: {}", statement);
+
+        let rows = self
+            .executor
+            .run(
+                &statement,
+                json!({ "labels": labels }),
+                &self.options.database,
+            )
+            .await
+            .map_err(|e| {
+                LoaderError::Io(std::io::Error::other(format!(
+                    "Failed to query Neo4j nodes: {e}"
+                )))
+            })?;
+        let instances: Vec<DataInstance> = rows
+            .iter()
+            .filter_map(|row| self.node_instance(row, schema))
+            .collect();
+        info!("Loaded {} instances from Neo4j", instances.len());
+        apply_normalizers(instances, schema, options)
+    }
+
+    async fn load_bytes(
+        &self,
+        _data: &[u8],
+        _schema: &SchemaDefinition,
+        _options: &LoadOptions,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        Err(LoaderError::InvalidFormat(
+            "Neo4j loader does not support raw bytes loading".to_string(),
+        ))
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> LoaderResult<()> {
+        if schema.classes.is_empty() {
+            return Err(LoaderError::SchemaValidation(
+                "Schema has no classes to load Neo4j nodes into".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A node to be written, with the relationships starting at it
+struct GraphNode {
+    class_name: String,
+    key: Option<Value>,
+    properties: Map<String, Value>,
+    /// Slot, range class and identifier of each referenced node
+    links: Vec<(String, String, Value)>,
+}
+
+/// `Neo4j` dumper using an abstract statement executor
+pub struct Neo4jDumper<E: CypherExecutor> {
+    options: Neo4jOptions,
+    executor: E,
+}
+
+impl<E: CypherExecutor> Neo4jDumper<E> {
+    /// Create a new `Neo4j` dumper
+    pub fn new(options: Neo4jOptions, executor: E) -> Self {
+        Self { options, executor }
+    }
+
+    /// Add the node of an instance, and of the objects inlined in it, to
+    /// `nodes`, returning the identifier of the node
+    fn collect_node(
+        class_name: &str,
+        data: &Map<String, Value>,
+        schema: &SchemaDefinition,
+        nodes: &mut Vec<GraphNode>,
+    ) -> DumperResult<Option<Value>> {
+        if !schema.classes.contains_key(class_name) {
+            return Err(DumperError::SchemaValidation(format!(
+                "Class {class_name} not found in schema"
+            )));
+        }
+        let slots: HashMap<String, SlotDefinition> = induced_slots(schema, class_name)
+            .into_iter()
+            .map(|slot| (slot.name.clone(), slot))
+            .collect();
+        let key_slot = identifier_slot(schema, class_name);
+        let key = key_slot
+            .as_ref()
+            .and_then(|key_slot| data.get(key_slot))
+            .filter(|key| !key.is_null())
+            .cloned();
+        if key_slot.is_some() && key.is_none() {
+            return Err(DumperError::SchemaValidation(format!(
+                "Instance of {class_name} has no value for its identifier {}",
+                key_slot.unwrap_or_default()
+            )));
+        }
+
+        let mut node = GraphNode {
+            class_name: class_name.to_string(),
+            key: key.clone(),
+            properties: Map::new(),
+            links: Vec::new(),
+        };
+        for (slot_name, value) in data {
+            if slot_name.starts_with('_') || value.is_null() {
+                continue;
+            }
+            let slot = slots.get(slot_name);
+            if let Some(range) = slot.and_then(|slot| object_range(schema, slot)) {
+                for target in Self::targets(value, range, schema)? {
+                    let target = match target {
+                        Value::Object(object) => Self::collect_node(range, &object, schema, nodes)?
+                            .ok_or_else(|| {
+                                DumperError::TypeConversion(format!(
+                                    "Objects inlined in {class_name}.{slot_name} cannot be linked, \
+                                     {range} has no identifier slot"
+                                ))
+                            })?,
+                        reference => reference,
+                    };
+                    node.links
+                        .push((slot_name.clone(), range.to_string(), target));
+                }
+            } else {
+                node.properties.insert(
+                    slot_name.clone(),
+                    property_value(class_name, slot_name, value)?,
+                );
+            }
+        }
+        if key.is_none() && !node.links.is_empty() {
+            return Err(DumperError::SchemaValidation(format!(
+                "Class {class_name} needs an identifier slot for its relationships"
+            )));
+        }
+        nodes.push(node);
+        Ok(key)
+    }
+
+    /// References or inlined objects of an object-valued slot value, with
+    /// objects inlined as a dictionary keyed by identifier expanded
+    fn targets(value: &Value, range: &str, schema: &SchemaDefinition) -> DumperResult<Vec<Value>> {
+        Ok(match value {
+            Value::Array(values) => values.clone(),
+            Value::Object(object) => match identifier_slot(schema, range) {
+                Some(key_slot) if !object.contains_key(&key_slot) => object
+                    .iter()
+                    .map(|(key, entry)| {
+                        let mut entry = match entry {
+                            Value::Object(entry) => entry.clone(),
+                            _ => Map::new(),
+                        };
+                        entry.insert(key_slot.clone(), Value::String(key.clone()));
+                        Value::Object(entry)
+                    })
+                    .collect(),
+                _ => vec![value.clone()],
+            },
+            reference => vec![reference.clone()],
+        })
+    }
+
+    /// Write nodes, merged on their identifier where they have one
+    async fn write_nodes(
+        &self,
+        nodes: &[GraphNode],
+        schema: &SchemaDefinition,
+    ) -> DumperResult<()> {
+        let mut by_class: Vec<(&str, Vec<&GraphNode>)> = Vec::new();
+        for node in nodes {
+            match by_class
+                .iter_mut()
+                .find(|(class, _)| *class == node.class_name)
+            {
+                Some((_, class_nodes)) => class_nodes.push(node),
+                None => by_class.push((&node.class_name, vec![node])),
+            }
+        }
+
+        for (class_name, class_nodes) in by_class {
+            let labels: Vec<String> = class_labels(schema, class_name)
+                .iter()
+                .map(|class| quote_name(&self.options.label(class)))
+                .collect();
+            let statement = match identifier_slot(schema, class_name) {
+                Some(key_slot) => {
+                    let mut statement = format!(
+                        "UNWIND $rows AS row MERGE (n:{} {{{}: row.key}}) SET n += row.properties",
+                        labels[0],
+                        quote_name(&key_slot)
+                    );
+                    if labels.len() > 1 {
+                        statement.push_str(&format!(" SET n:{}", labels[1..].join(":")));
+                    }
+                    statement
+                }
+                None => format!(
+                    "UNWIND $rows AS row CREATE (n:{}) SET n = row.properties",
+                    labels.join(":")
+                ),
+            };
+            for batch in class_nodes.chunks(self.options.batch_size.max(1)) {
+                let rows: Vec<Value> = batch
+                    .iter()
+                    .map(|node| json!({ "key": node.key, "properties": node.properties }))
+                    .collect();
+                self.executor
+                    .run(&statement, json!({ "rows": rows }), &self.options.database)
+                    .await
+                    .map_err(|e| {
+                        DumperError::Io(std::io::Error::other(format!(
+                            "Failed to write {class_name} nodes: {e}"
+                        )))
+                    })?;
+            }
+            debug!("Wrote {} {} nodes", class_nodes.len(), class_name);
+        }
+        Ok(())
+    }
+
+    /// Write the relationships of nodes, returning how many were written
+    async fn write_relationships(
+        &self,
+        nodes: &[GraphNode],
+        schema: &SchemaDefinition,
+    ) -> DumperResult<usize> {
+        // Links grouped by source class, slot and range class
+        type Group<'a> = (&'a str, &'a str, &'a str);
+        let mut groups: Vec<(Group, Vec<Value>)> = Vec::new();
+        for node in nodes {
+            for (slot_name, range, target) in &node.links {
+                let group = (node.class_name.as_str(), slot_name.as_str(), range.as_str());
+                let link = json!({ "source": node.key, "target": target });
+                match groups.iter_mut().find(|(key, _)| *key == group) {
+                    Some((_, links)) => links.push(link),
+                    None => groups.push((group, vec![link])),
+                }
+            }
+        }
+
+        let mut written = 0;
+        for ((class_name, slot_name, range), links) in groups {
+            let (Some(source_key), Some(target_key)) = (
+                identifier_slot(schema, class_name),
+                identifier_slot(schema, range),
+            ) else {
+                return Err(DumperError::SchemaValidation(format!(
+                    "Relationship {class_name}.{slot_name} needs identifier slots on \
+                     {class_name} and {range}"
+                )));
+            };
+            let statement = format!(
+                "UNWIND $links AS link MATCH (a:{} {{{}: link.source}}) \
+                 MATCH (b:{} {{{}: link.target}}) MERGE (a)-[:{}]->(b) RETURN count(b) AS linked",
+                quote_name(&self.options.label(class_name)),
+                quote_name(&source_key),
+                quote_name(&self.options.label(range)),
+                quote_name(&target_key),
+                quote_name(&relationship_type(slot_name))
+            );
+            for batch in links.chunks(self.options.batch_size.max(1)) {
+                let rows = self
+                    .executor
+                    .run(
+                        &statement,
+                        json!({ "links": batch }),
+                        &self.options.database,
+                    )
+                    .await
+                    .map_err(|e| {
+                        DumperError::Io(std::io::Error::other(format!(
+                            "Failed to write {class_name}.{slot_name} relationships: {e}"
+                        )))
+                    })?;
+                let linked = rows
+                    .first()
+                    .and_then(|row| row.get("linked"))
+                    .and_then(Value::as_u64)
+                    .map_or(batch.len(), |linked| {
+                        usize::try_from(linked).unwrap_or(usize::MAX)
+                    });
+                if linked < batch.len() {
+                    warn!(
+                        "{} references of {}.{} did not match a {} node",
+                        batch.len() - linked,
+                        class_name,
+                        slot_name,
+                        range
+                    );
+                }
+                written += linked.min(batch.len());
+            }
+        }
+        Ok(written)
+    }
+}
+
+#[async_trait]
+impl<E: CypherExecutor> DataDumper for Neo4jDumper<E> {
+    fn name(&self) -> &'static str {
+        "neo4j"
+    }
+
+    fn description(&self) -> &'static str {
+        "Dump data to Neo4j, mapping classes to node labels and object-valued slots to relationships"
+    }
+
+    fn supported_extensions(&self) -> Vec<&str> {
+        vec![] // Neo4j dumper doesn't use file extensions
+    }
+
+    async fn dump_file(
+        &self,
+        _instances: &[DataInstance],
+        _path: &std::path::Path,
+        _schema: &SchemaDefinition,
+        _options: &DumpOptions,
+    ) -> DumperResult<()> {
+        Err(DumperError::Configuration(
+            "Neo4j dumper does not support file dumping".to_string(),
+        ))
+    }
+
+    async fn dump_string(
+        &self,
+        instances: &[DataInstance],
+        schema: &SchemaDefinition,
+        _options: &DumpOptions,
+    ) -> DumperResult<String> {
+        // Collect every node before writing, so that invalid data is
+        // rejected without touching the database
+        let mut nodes = Vec::new();
+        for instance in instances {
+            let data: Map<String, Value> = instance
+                .data
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+            Self::collect_node(&instance.class_name, &data, schema, &mut nodes)?;
+        }
+
+        // Nodes first, so that relationships find both of their ends
+        self.write_nodes(&nodes, schema).await?;
+        let relationships = self.write_relationships(&nodes, schema).await?;
+
+        let summary = format!(
+            "Successfully dumped {} nodes and {relationships} relationships to Neo4j",
+            nodes.len()
+        );
+        info!("{}", summary);
+        Ok(summary)
+    }
+
+    async fn dump_bytes(
+        &self,
+        instances: &[DataInstance],
+        schema: &SchemaDefinition,
+        options: &DumpOptions,
+    ) -> DumperResult<Vec<u8>> {
+        let result = self.dump_string(instances, schema, options).await?;
+        Ok(result.into_bytes())
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> DumperResult<()> {
+        if schema.classes.is_empty() {
+            return Err(DumperError::SchemaValidation(
+                "Schema has no classes to dump as Neo4j nodes".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Node property value of a scalar slot; `Neo4j` properties hold scalars
+/// and lists of scalars only
+fn property_value(class_name: &str, slot_name: &str, value: &Value) -> DumperResult<Value> {
+    let nested = match value {
+        Value::Object(_) => true,
+        Value::Array(values) => values
+            .iter()
+            .any(|value| value.is_object() || value.is_array()),
+        _ => false,
+    };
+    if nested {
+        return Err(DumperError::TypeConversion(format!(
+            "{class_name}.{slot_name} holds nested values, which Neo4j properties cannot store"
+        )));
+    }
+    Ok(value.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Executor recording statements and answering with canned rows
+    #[derive(Default)]
+    struct RecordingExecutor {
+        statements: Mutex<Vec<(String, Value)>>,
+        rows: Vec<Map<String, Value>>,
+    }
+
+    #[async_trait]
+    impl CypherExecutor for RecordingExecutor {
+        async fn run(
+            &self,
+            statement: &str,
+            parameters: Value,
+            _database: &str,
+        ) -> std::result::Result<Vec<Map<String, Value>>, Box<dyn std::error::Error>> {
+            self.statements
+                .lock()
+                .expect("statements lock")
+                .push((statement.to_string(), parameters));
+            Ok(self.rows.clone())
+        }
+    }
+
+    fn create_test_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::default();
+        for (name, range, multivalued) in [
+            ("id", "string", false),
+            ("name", "string", false),
+            ("employer", "Organization", false),
+            ("knows", "Person", true),
+        ] {
+            schema.slots.insert(
+                name.to_string(),
+                SlotDefinition {
+                    range: Some(range.to_string()),
+                    identifier: Some(name == "id"),
+                    multivalued: Some(multivalued),
+                    ..Default::default()
+                },
+            );
+        }
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "name".to_string(), "knows".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Employee".to_string(),
+            ClassDefinition {
+                is_a: Some("Person".to_string()),
+                slots: vec!["employer".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Organization".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "name".to_string()],
+                ..Default::default()
+            },
+        );
+        schema
+    }
+
+    fn instance(class_name: &str, data: Value) -> DataInstance {
+        DataInstance {
+            class_name: class_name.to_string(),
+            data: data
+                .as_object()
+                .cloned()
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+            id: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_neo4j_dumper() {
+        let schema = create_test_schema();
+        let dumper = Neo4jDumper::new(Neo4jOptions::default(), RecordingExecutor::default());
+        let instances = [
+            instance("Person", json!({"id": "p1", "name": "Ada"})),
+            instance(
+                "Employee",
+                json!({
+                    "id": "p2",
+                    "knows": ["p1"],
+                    "employer": {"id": "o1", "name": "Analytical Engines"}
+                }),
+            ),
+        ];
+        let summary = dumper
+            .dump_string(&instances, &schema, &DumpOptions::default())
+            .await
+            .expect("dump");
+        assert!(summary.contains("3 nodes and 2 relationships"));
+
+        {
+            let statements = dumper.executor.statements.lock().expect("statements lock");
+            assert_eq!(
+                statements[0].0,
+                "UNWIND $rows AS row MERGE (n:Person {id: row.key}) SET n += row.properties"
+            );
+            assert_eq!(
+                statements[1].0,
+                "UNWIND $rows AS row MERGE (n:Organization {id: row.key}) SET n += row.properties"
+            );
+            assert!(statements[2].0.ends_with("SET n:Person"));
+            assert!(statements.iter().any(|(statement, parameters)| {
+                statement.contains("MATCH (a:Employee {id: link.source})")
+                    && statement.contains("MERGE (a)-[:EMPLOYER]->(b)")
+                    && parameters["links"][0]["target"] == "o1"
+            }));
+        }
+
+        let nested = instance("Person", json!({"id": "p3", "name": {"first": "Ada"}}));
+        let error = dumper
+            .dump_string(&[nested], &schema, &DumpOptions::default())
+            .await
+            .expect_err("nested property");
+        assert!(error.to_string().contains("nested values"));
+    }
+
+    #[tokio::test]
+    async fn test_neo4j_loader() {
+        let schema = create_test_schema();
+        let row = json!({
+            "labels": ["Person", "Employee"],
+            "properties": {"id": "p2"},
+            "links": [
+                {"type": "EMPLOYER", "labels": ["Organization"], "properties": {"id": "o1"}},
+                {"type": "KNOWS", "labels": ["Person"], "properties": {"id": "p1"}},
+                {"type": "UNRELATED", "labels": ["Person"], "properties": {"id": "p9"}}
+            ]
+        });
+        let executor = RecordingExecutor {
+            rows: vec![row.as_object().cloned().unwrap_or_default()],
+            ..Default::default()
+        };
+        let loader = Neo4jLoader::new(Neo4jOptions::default(), executor);
+        let instances = loader
+            .load_string("", &schema, &LoadOptions::default())
+            .await
+            .expect("load");
+        assert_eq!(instances.len(), 1);
+        assert_eq!(instances[0].class_name, "Employee");
+        assert_eq!(instances[0].id.as_deref(), Some("p2"));
+        assert_eq!(instances[0].data["employer"], json!("o1"));
+        assert_eq!(instances[0].data["knows"], json!(["p1"]));
+        assert!(!instances[0].data.contains_key("UNRELATED"));
+    }
+
+    #[test]
+    fn test_result_rows() {
+        let body = json!({
+            "results": [{"columns": ["n", "m"], "data": [{"row": [1, "a"], "meta": []}]}],
+            "errors": []
+        });
+        let rows = result_rows(&body).expect("rows");
+        assert_eq!(rows[0]["n"], json!(1));
+        assert_eq!(rows[0]["m"], json!("a"));
+
+        let failed = json!({
+            "results": [],
+            "errors": [{"code": "Neo.ClientError.Statement.SyntaxError", "message": "Invalid input"}]
+        });
+        assert!(result_rows(&failed).is_err());
+    }
+}
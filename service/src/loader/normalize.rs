@@ -0,0 +1,946 @@
+//! Value normalization for loaded data
+//!
+//! Messy source values are normalized into schema-friendly values after
+//! loading, driven by slot annotations:
+//!
+//! ```yaml
+//! slots:
+//!   price:
+//!     range: decimal
+//!     annotations:
+//!       normalize: currency          # "€1.234,56" -> 1234.56 (exact)
+//!       currency_slot: price_currency # receives "EUR"
+//!       default_currency: USD         # used without a currency, or for "$"
+//!       decimal_separator: ","        # read "1,234" as 1.234 (default: detected)
+//!   file_size:
+//!     range: integer
+//!     annotations:
+//!       normalize: byte_size          # "3.5 MB" -> 3500000
+//!       byte_size_base: binary        # treat "MB" as MiB (default: decimal)
+//! ```
+//!
+//! The built-in normalizers are `currency` and `byte_size`. Additional
+//! normalizers implement [`ValueNormalizer`] and are registered on a
+//! [`NormalizerRegistry`] passed through `LoadOptions::normalizers`.
+
+use super::traits::{DataInstance, LoadOptions, LoaderError, LoaderResult};
+use linkml_core::annotations::{AnnotationValue, Annotations};
+use linkml_core::prelude::*;
+use serde_json::{Number, Value as JsonValue};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use tracing::warn;
+
+/// Slot annotation selecting the normalizer
+pub const NORMALIZE_ANNOTATION: &str = "normalize";
+
+/// Result of normalizing a single value
+#[derive(Debug, Clone, PartialEq)]
+pub struct Normalized {
+    /// Normalized value for the annotated slot
+    pub value: JsonValue,
+    /// Values for companion slots (e.g. the currency code slot)
+    pub companions: Vec<(String, JsonValue)>,
+}
+
+impl Normalized {
+    /// A normalized value without companion slots
+    #[must_use]
+    pub fn value(value: JsonValue) -> Self {
+        Self {
+            value,
+            companions: Vec::new(),
+        }
+    }
+}
+
+/// Context passed to a normalizer
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizationContext<'a> {
+    /// Name of the slot being normalized
+    pub slot_name: &'a str,
+    /// Effective slot definition
+    pub slot: &'a SlotDefinition,
+}
+
+impl NormalizationContext<'_> {
+    /// String value of a slot annotation
+    #[must_use]
+    pub fn annotation(&self, key: &str) -> Option<&str> {
+        match self.slot.annotations.as_ref()?.get(key)? {
+            AnnotationValue::String(value) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Decimal separator set by the `decimal_separator` annotation, or `None`
+    /// to tell it from each value
+    ///
+    /// # Errors
+    /// Returns `LoaderError::Configuration` if the annotation is neither `.`
+    /// nor `,`
+    pub fn decimal_separator(&self) -> LoaderResult<Option<char>> {
+        match self.annotation("decimal_separator") {
+            None => Ok(None),
+            Some(".") => Ok(Some('.')),
+            Some(",") => Ok(Some(',')),
+            Some(other) => Err(LoaderError::Configuration(format!(
+                "Invalid decimal_separator '{other}' on slot '{}' (expected '.' or ',')",
+                self.slot_name
+            ))),
+        }
+    }
+}
+
+/// A value normalization plugin
+pub trait ValueNormalizer: Send + Sync {
+    /// Name used in the `normalize` slot annotation
+    fn name(&self) -> &str;
+
+    /// Normalize a single (non-null, non-array) value
+    ///
+    /// # Errors
+    /// Returns `LoaderError::TypeConversion` if the value cannot be normalized
+    fn normalize(
+        &self,
+        value: &JsonValue,
+        context: &NormalizationContext<'_>,
+    ) -> LoaderResult<Normalized>;
+}
+
+/// Registry of value normalizers keyed by annotation name
+#[derive(Clone)]
+pub struct NormalizerRegistry {
+    normalizers: HashMap<String, Arc<dyn ValueNormalizer>>,
+}
+
+impl fmt::Debug for NormalizerRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names: Vec<&String> = self.normalizers.keys().collect();
+        names.sort();
+        f.debug_struct("NormalizerRegistry")
+            .field("normalizers", &names)
+            .finish()
+    }
+}
+
+impl Default for NormalizerRegistry {
+    fn default() -> Self {
+        Self::new()
+            .with_normalizer(CurrencyNormalizer)
+            .with_normalizer(ByteSizeNormalizer)
+    }
+}
+
+impl NormalizerRegistry {
+    /// Create an empty registry (use `default()` for the built-in normalizers)
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            normalizers: HashMap::new(),
+        }
+    }
+
+    /// Register a normalizer, replacing any existing one with the same name
+    #[must_use]
+    pub fn with_normalizer(mut self, normalizer: impl ValueNormalizer + 'static) -> Self {
+        self.register(Arc::new(normalizer));
+        self
+    }
+
+    /// Register a normalizer, replacing any existing one with the same name
+    pub fn register(&mut self, normalizer: Arc<dyn ValueNormalizer>) {
+        self.normalizers
+            .insert(normalizer.name().to_string(), normalizer);
+    }
+
+    /// Names of the registered normalizers
+    #[must_use]
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.normalizers.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Normalize all annotated slots of an instance in place
+    ///
+    /// Null values are left untouched; arrays are normalized element-wise
+    /// (companion slots are only filled for single values).
+    ///
+    /// # Errors
+    /// Returns `LoaderError::Configuration` for an unknown normalizer and
+    /// `LoaderError::TypeConversion` for values that cannot be normalized
+    pub fn normalize_instance(
+        &self,
+        instance: &mut DataInstance,
+        schema: &SchemaDefinition,
+    ) -> LoaderResult<()> {
+        let class = schema.classes.get(&instance.class_name);
+        let mut companions = Vec::new();
+
+        for (field, value) in &mut instance.data {
+            let Some(slot) = effective_slot(schema, class, field) else {
+                continue;
+            };
+            let Some(AnnotationValue::String(name)) = slot
+                .annotations
+                .as_ref()
+                .and_then(|annotations| annotations.get(NORMALIZE_ANNOTATION))
+            else {
+                continue;
+            };
+            let normalizer = self.normalizers.get(name).ok_or_else(|| {
+                LoaderError::Configuration(format!(
+                    "Unknown normalizer '{name}' on slot '{field}' (available: {})",
+                    self.names().join(", ")
+                ))
+            })?;
+            let context = NormalizationContext {
+                slot_name: field,
+                slot,
+            };
+
+            match value {
+                JsonValue::Null => {}
+                JsonValue::Array(items) => {
+                    for item in items.iter_mut().filter(|item| !item.is_null()) {
+                        *item = normalizer.normalize(item, &context)?.value;
+                    }
+                }
+                _ => {
+                    let result = normalizer.normalize(value, &context)?;
+                    *value = result.value;
+                    companions.extend(result.companions);
+                }
+            }
+        }
+
+        for (slot, value) in companions {
+            // Values present in the source win over derived ones
+            let existing = instance.data.entry(slot).or_insert(JsonValue::Null);
+            if existing.is_null() || existing.as_str().is_some_and(str::is_empty) {
+                *existing = value;
+            }
+        }
+        Ok(())
+    }
+
+    /// Normalize a batch of loaded instances
+    ///
+    /// With `skip_invalid`, instances that fail normalization are dropped with
+    /// a warning; otherwise the first failure is returned.
+    ///
+    /// # Errors
+    /// Returns the first normalization error unless `skip_invalid` is set
+    pub fn normalize_instances(
+        &self,
+        instances: Vec<DataInstance>,
+        schema: &SchemaDefinition,
+        skip_invalid: bool,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        let mut normalized = Vec::with_capacity(instances.len());
+        for mut instance in instances {
+            match self.normalize_instance(&mut instance, schema) {
+                Ok(()) => normalized.push(instance),
+                Err(e) if skip_invalid => {
+                    warn!("Skipping {} instance: {e}", instance.class_name);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(normalized)
+    }
+}
+
+/// Whether any slot in the schema requests normalization
+#[must_use]
+pub fn schema_has_normalizers(schema: &SchemaDefinition) -> bool {
+    let annotated = |annotations: &Option<Annotations>| {
+        annotations
+            .as_ref()
+            .is_some_and(|a| a.contains_key(NORMALIZE_ANNOTATION))
+    };
+    schema
+        .slots
+        .values()
+        .any(|slot| annotated(&slot.annotations))
+        || schema.classes.values().any(|class| {
+            class
+                .attributes
+                .values()
+                .chain(class.slot_usage.values())
+                .any(|slot| annotated(&slot.annotations))
+        })
+}
+
+/// Apply the normalizers configured in `options` (or the built-in ones) to loaded instances
+///
+/// This is a no-op for schemas without `normalize` annotations.
+///
+/// # Errors
+/// Returns the first normalization error unless `options.skip_invalid` is set
+pub fn apply_normalizers(
+    instances: Vec<DataInstance>,
+    schema: &SchemaDefinition,
+    options: &LoadOptions,
+) -> LoaderResult<Vec<DataInstance>> {
+    if !schema_has_normalizers(schema) {
+        return Ok(instances);
+    }
+    match &options.normalizers {
+        Some(registry) => registry.normalize_instances(instances, schema, options.skip_invalid),
+        None => NormalizerRegistry::default().normalize_instances(
+            instances,
+            schema,
+            options.skip_invalid,
+        ),
+    }
+}
+
+/// Slot definition governing a field: class attribute or slot usage, then the global slot
+fn effective_slot<'a>(
+    schema: &'a SchemaDefinition,
+    class: Option<&'a ClassDefinition>,
+    field: &str,
+) -> Option<&'a SlotDefinition> {
+    let has_annotations = |slot: &&SlotDefinition| slot.annotations.is_some();
+    class
+        .and_then(|class| class.attributes.get(field))
+        .or_else(|| {
+            class
+                .and_then(|class| class.slot_usage.get(field))
+                .filter(has_annotations)
+        })
+        .or_else(|| schema.slots.get(field))
+}
+
+fn conversion_error(
+    context: &NormalizationContext<'_>,
+    value: &JsonValue,
+    kind: &str,
+) -> LoaderError {
+    LoaderError::TypeConversion(format!(
+        "Cannot normalize {value} in slot '{}' as {kind}",
+        context.slot_name
+    ))
+}
+
+fn json_number(value: f64) -> Option<JsonValue> {
+    Number::from_f64(value).map(JsonValue::Number)
+}
+
+/// Parse a number written with thousands separators in either US (`1,234.56`)
+/// or European (`1.234,56`) style
+///
+/// With a `decimal_separator`, the other of `.` and `,` groups digits.
+/// Without one, the separator is told from the value: the last of `.` and `,`
+/// when both occur, and a single `,` unless three digits follow it, so
+/// `1,234` reads as 1234 and `12,5` as 12.5.
+fn parse_grouped_number(text: &str, decimal_separator: Option<char>) -> Option<f64> {
+    canonical_number(text, decimal_separator)?.parse().ok()
+}
+
+/// Like [`parse_grouped_number`], but returns the number as text without
+/// group separators and with `.` as the decimal point, keeping all digits
+fn canonical_number(text: &str, decimal_separator: Option<char>) -> Option<String> {
+    let text: String = text
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '\'' && *c != '_')
+        .collect();
+    if let Some(separator) = decimal_separator {
+        let grouping = if separator == ',' { '.' } else { ',' };
+        let canonical: String = text
+            .chars()
+            .filter(|c| *c != grouping)
+            .map(|c| if c == separator { '.' } else { c })
+            .collect();
+        return finite(canonical);
+    }
+    let last_comma = text.rfind(',');
+    let last_dot = text.rfind('.');
+
+    let canonical = match (last_comma, last_dot) {
+        // "1.234,56": dot groups, comma is the decimal separator
+        (Some(comma), Some(dot)) if comma > dot => text.replace('.', "").replace(',', "."),
+        // "1,234.56": comma groups
+        (Some(_), Some(_)) => text.replace(',', ""),
+        // "1234,56" (decimal comma) vs "1,234" (grouping)
+        (Some(comma), None) => {
+            let decimals = text.len() - comma - 1;
+            if text.matches(',').count() == 1 && decimals != 3 {
+                text.replace(',', ".")
+            } else {
+                text.replace(',', "")
+            }
+        }
+        _ => text,
+    };
+    finite(canonical)
+}
+
+/// `number` if it is a finite number
+fn finite(number: String) -> Option<String> {
+    number
+        .parse::<f64>()
+        .is_ok_and(f64::is_finite)
+        .then_some(number)
+}
+
+/// JSON value of an amount: a number if `f64` holds all its digits,
+/// otherwise the amount as a string so no digits are lost
+fn amount_value(amount: &str) -> Option<JsonValue> {
+    let number = amount.parse::<f64>().ok()?;
+    if significant_digits(amount) <= f64::DIGITS as usize {
+        json_number(number)
+    } else {
+        Some(JsonValue::String(amount.to_string()))
+    }
+}
+
+/// Significant digits of a number written without an exponent
+fn significant_digits(number: &str) -> usize {
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    let digits: String = integer
+        .chars()
+        .chain(fraction.trim_end_matches('0').chars())
+        .filter(char::is_ascii_digit)
+        .collect();
+    digits.trim_start_matches('0').len()
+}
+
+/// Normalizes currency strings to a decimal amount plus ISO 4217 code
+///
+/// Understands symbols (`$`, `€`, `£`, `¥`, `₹`, ...), leading or trailing
+/// ISO codes (`USD 12`, `12.50 EUR`), thousands separators in US and European
+/// style, and accounting negatives (`(12.00)`). The detected code is written to
+/// the slot named by the `currency_slot` annotation, falling back to
+/// `default_currency` when the value carries no currency.
+///
+/// Amounts are kept exactly: those with more significant digits than `f64`
+/// holds (more than 15) become decimal strings instead of numbers.
+///
+/// Symbols several currencies use (`$`, `kr`, `¥`) resolve to
+/// `default_currency` only if it is one of them; otherwise the currency slot
+/// is left unset rather than guessed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CurrencyNormalizer;
+
+/// Currency written with an amount
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Denomination {
+    /// ISO 4217 code of the currency
+    Code(String),
+    /// Symbol shared by the currencies with these codes
+    Ambiguous(&'static [&'static str]),
+}
+
+impl Denomination {
+    /// ISO 4217 code, resolving an ambiguous symbol to `default` if the
+    /// symbol denotes it
+    fn resolve(self, default: Option<&str>) -> Option<String> {
+        match self {
+            Self::Code(code) => Some(code),
+            Self::Ambiguous(codes) => default
+                .filter(|default| codes.contains(default))
+                .map(str::to_string),
+        }
+    }
+}
+
+impl CurrencyNormalizer {
+    const SYMBOLS: &'static [(&'static str, &'static [&'static str])] = &[
+        ("US$", &["USD"]),
+        ("CA$", &["CAD"]),
+        ("A$", &["AUD"]),
+        ("NZ$", &["NZD"]),
+        ("HK$", &["HKD"]),
+        ("R$", &["BRL"]),
+        ("CHF", &["CHF"]),
+        ("$", &["USD", "CAD", "AUD", "NZD", "HKD", "SGD", "MXN"]),
+        ("€", &["EUR"]),
+        ("£", &["GBP"]),
+        ("¥", &["JPY", "CNY"]),
+        ("₹", &["INR"]),
+        ("₩", &["KRW"]),
+        ("₽", &["RUB"]),
+        ("₺", &["TRY"]),
+        ("₪", &["ILS"]),
+        ("₫", &["VND"]),
+        ("฿", &["THB"]),
+        ("zł", &["PLN"]),
+        ("kr", &["SEK", "NOK", "DKK", "ISK"]),
+    ];
+
+    /// Split a currency string into amount, as a canonical decimal number,
+    /// and currency
+    fn parse(
+        text: &str,
+        decimal_separator: Option<char>,
+    ) -> Option<(String, Option<Denomination>)> {
+        let mut rest = text.trim();
+        let mut negative = false;
+        if let Some(inner) = rest.strip_prefix('(').and_then(|r| r.strip_suffix(')')) {
+            negative = true;
+            rest = inner.trim();
+        }
+        if let Some(stripped) = rest.strip_prefix('-') {
+            negative = !negative;
+            rest = stripped.trim();
+        }
+
+        let mut currency = None;
+        // ISO code before or after the amount
+        let alphabetic = |s: &str| s.len() == 3 && s.chars().all(|c| c.is_ascii_uppercase());
+        if let Some((code, amount)) = rest.split_once(char::is_whitespace)
+            && alphabetic(code)
+        {
+            currency = Some(Denomination::Code(code.to_string()));
+            rest = amount.trim();
+        } else if let Some((amount, code)) = rest.rsplit_once(char::is_whitespace)
+            && alphabetic(code)
+        {
+            currency = Some(Denomination::Code(code.to_string()));
+            rest = amount.trim();
+        }
+
+        // Symbols before or after the amount
+        for (symbol, codes) in Self::SYMBOLS {
+            if let Some(stripped) = rest
+                .strip_prefix(symbol)
+                .or_else(|| rest.strip_suffix(symbol))
+            {
+                currency.get_or_insert_with(|| match codes {
+                    [code] => Denomination::Code((*code).to_string()),
+                    _ => Denomination::Ambiguous(codes),
+                });
+                rest = stripped.trim();
+                break;
+            }
+        }
+        if let Some(stripped) = rest.strip_prefix('-') {
+            negative = !negative;
+            rest = stripped.trim();
+        }
+
+        let amount = canonical_number(rest, decimal_separator)?;
+        let amount = match (negative, amount.strip_prefix('-')) {
+            (true, Some(magnitude)) => magnitude.to_string(),
+            (true, None) => format!("-{}", amount.trim_start_matches('+')),
+            (false, _) => amount,
+        };
+        Some((amount, currency))
+    }
+}
+
+impl ValueNormalizer for CurrencyNormalizer {
+    fn name(&self) -> &'static str {
+        "currency"
+    }
+
+    fn normalize(
+        &self,
+        value: &JsonValue,
+        context: &NormalizationContext<'_>,
+    ) -> LoaderResult<Normalized> {
+        let (amount, currency) = match value {
+            JsonValue::Number(_) => (Some(value.clone()), None),
+            JsonValue::String(text) => match Self::parse(text, context.decimal_separator()?) {
+                Some((amount, currency)) => (amount_value(&amount), currency),
+                None => (None, None),
+            },
+            _ => (None, None),
+        };
+        let amount = amount.ok_or_else(|| conversion_error(context, value, "currency"))?;
+
+        let mut normalized = Normalized::value(amount);
+        if let Some(target) = context.annotation("currency_slot") {
+            let default = context.annotation("default_currency");
+            let code = match currency {
+                Some(currency) => currency.resolve(default),
+                None => default.map(str::to_string),
+            };
+            if let Some(code) = code {
+                normalized
+                    .companions
+                    .push((target.to_string(), JsonValue::String(code)));
+            }
+        }
+        Ok(normalized)
+    }
+}
+
+/// Normalizes human-readable sizes (`3.5 MB`, `512KiB`, `1.2G`) to a byte count
+///
+/// SI prefixes are decimal by default; set the `byte_size_base: binary`
+/// annotation to read `KB`/`MB`/... as powers of 1024. IEC units (`KiB`,
+/// `MiB`, ...) are always binary. Numbers honor the `decimal_separator`
+/// annotation like currency amounts.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ByteSizeNormalizer;
+
+impl ByteSizeNormalizer {
+    fn multiplier(unit: &str, binary_si: bool) -> Option<f64> {
+        let unit = unit.trim().to_ascii_lowercase();
+        let unit = unit.trim_end_matches("ytes").trim_end_matches("yte");
+        let si = |exp: i32| {
+            if binary_si {
+                1024_f64.powi(exp)
+            } else {
+                1000_f64.powi(exp)
+            }
+        };
+        let value = match unit {
+            "" | "b" => 1.0,
+            "k" | "kb" => si(1),
+            "m" | "mb" => si(2),
+            "g" | "gb" => si(3),
+            "t" | "tb" => si(4),
+            "p" | "pb" => si(5),
+            "ki" | "kib" => 1024_f64,
+            "mi" | "mib" => 1024_f64.powi(2),
+            "gi" | "gib" => 1024_f64.powi(3),
+            "ti" | "tib" => 1024_f64.powi(4),
+            "pi" | "pib" => 1024_f64.powi(5),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn parse(text: &str, binary_si: bool, decimal_separator: Option<char>) -> Option<u64> {
+        let text = text.trim();
+        let split = text
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ',' || c == '_'))
+            .unwrap_or(text.len());
+        let (number, unit) = text.split_at(split);
+        let number = parse_grouped_number(number, decimal_separator)?;
+        let bytes = number * Self::multiplier(unit, binary_si)?;
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_precision_loss,
+            clippy::cast_sign_loss
+        )]
+        let bytes = (bytes >= 0.0 && bytes < u64::MAX as f64).then(|| bytes.round() as u64);
+        bytes
+    }
+}
+
+impl ValueNormalizer for ByteSizeNormalizer {
+    fn name(&self) -> &'static str {
+        "byte_size"
+    }
+
+    fn normalize(
+        &self,
+        value: &JsonValue,
+        context: &NormalizationContext<'_>,
+    ) -> LoaderResult<Normalized> {
+        let binary_si = context.annotation("byte_size_base") == Some("binary");
+        let bytes = match value {
+            JsonValue::Number(n) => n.as_u64(),
+            JsonValue::String(text) => Self::parse(text, binary_si, context.decimal_separator()?),
+            _ => None,
+        }
+        .ok_or_else(|| conversion_error(context, value, "byte size"))?;
+        Ok(Normalized::value(JsonValue::from(bytes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn annotated_slot(range: &str, annotations: &[(&str, &str)]) -> SlotDefinition {
+        SlotDefinition {
+            range: Some(range.to_string()),
+            annotations: Some(
+                annotations
+                    .iter()
+                    .map(|(k, v)| ((*k).to_string(), AnnotationValue::String((*v).to_string())))
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn create_test_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("normalize_test");
+        schema.slots.insert(
+            "price".to_string(),
+            annotated_slot(
+                "decimal",
+                &[
+                    ("normalize", "currency"),
+                    ("currency_slot", "price_currency"),
+                    ("default_currency", "EUR"),
+                ],
+            ),
+        );
+        schema.slots.insert(
+            "size".to_string(),
+            annotated_slot("integer", &[("normalize", "byte_size")]),
+        );
+        schema.classes.insert(
+            "Item".to_string(),
+            ClassDefinition {
+                slots: vec!["price".to_string(), "size".to_string()],
+                attributes: [(
+                    "memory".to_string(),
+                    annotated_slot(
+                        "integer",
+                        &[("normalize", "byte_size"), ("byte_size_base", "binary")],
+                    ),
+                )]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            },
+        );
+        schema
+    }
+
+    fn instance(data: JsonValue) -> DataInstance {
+        DataInstance {
+            class_name: "Item".to_string(),
+            data: serde_json::from_value(data).expect("object"),
+            id: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_currency_parsing() {
+        let cases = [
+            ("US$1,234.56", 1234.56, Some("USD")),
+            ("€1.234,56", 1234.56, Some("EUR")),
+            ("1 234,5 EUR", 1234.5, Some("EUR")),
+            ("GBP 12", 12.0, Some("GBP")),
+            ("(45.10)", -45.10, None),
+            ("-£3.50", -3.5, Some("GBP")),
+            ("12,5", 12.5, None),
+            ("1,000", 1000.0, None),
+        ];
+        for (text, amount, currency) in cases {
+            let (parsed, code) = CurrencyNormalizer::parse(text, None).expect(text);
+            let parsed: f64 = parsed.parse().expect("number");
+            assert!((parsed - amount).abs() < 1e-9, "{text}: {parsed}");
+            assert_eq!(
+                code.and_then(|code| code.resolve(None)).as_deref(),
+                currency,
+                "{text}"
+            );
+        }
+        assert!(CurrencyNormalizer::parse("twelve dollars", None).is_none());
+
+        let (amount, code) = CurrencyNormalizer::parse("1,234 kr", Some(',')).expect("kr");
+        assert_eq!(amount, "1.234");
+        assert_eq!(
+            code,
+            Some(Denomination::Ambiguous(&["SEK", "NOK", "DKK", "ISK"]))
+        );
+        let (amount, _) = CurrencyNormalizer::parse("$1,234", Some('.')).expect("$");
+        assert_eq!(amount, "1234");
+        let (amount, _) = CurrencyNormalizer::parse("(€ 1.000,50)", None).expect("€");
+        assert_eq!(amount, "-1000.50");
+    }
+
+    #[test]
+    fn test_currency_amount_precision() {
+        assert_eq!(amount_value("1234.50"), Some(json!(1234.5)));
+        assert_eq!(amount_value("-0.000123"), Some(json!(-0.000_123)));
+        assert_eq!(
+            amount_value("999999999999999"),
+            Some(json!(999_999_999_999_999.0))
+        );
+        assert_eq!(
+            amount_value("12345678901234567.89"),
+            Some(json!("12345678901234567.89"))
+        );
+        assert_eq!(
+            amount_value("0.1234567890123456789"),
+            Some(json!("0.1234567890123456789"))
+        );
+
+        let slot = annotated_slot("decimal", &[]);
+        let context = NormalizationContext {
+            slot_name: "price",
+            slot: &slot,
+        };
+        let normalized = CurrencyNormalizer
+            .normalize(&json!("USD 12,345,678,901,234,567.89"), &context)
+            .expect("normalizes");
+        assert_eq!(normalized.value, json!("12345678901234567.89"));
+        let normalized = CurrencyNormalizer
+            .normalize(&json!(18_446_744_073_709_551_615_u64), &context)
+            .expect("normalizes");
+        assert_eq!(normalized.value, json!(u64::MAX));
+    }
+
+    #[test]
+    fn test_ambiguous_currency_symbols() {
+        let normalize = |value: &str, annotations: &[(&str, &str)]| {
+            let slot = annotated_slot("decimal", annotations);
+            let context = NormalizationContext {
+                slot_name: "price",
+                slot: &slot,
+            };
+            CurrencyNormalizer.normalize(&json!(value), &context)
+        };
+        let companions = |value: &str, default_currency: &str| {
+            normalize(
+                value,
+                &[
+                    ("currency_slot", "price_currency"),
+                    ("default_currency", default_currency),
+                ],
+            )
+            .expect("normalizes")
+            .companions
+        };
+
+        assert_eq!(
+            companions("$5", "CAD"),
+            [("price_currency".to_string(), json!("CAD"))]
+        );
+        assert_eq!(companions("$5", "EUR"), [], "not guessed");
+        assert_eq!(
+            companions("100 kr", "NOK"),
+            [("price_currency".to_string(), json!("NOK"))]
+        );
+        assert_eq!(
+            companions("€5", "USD"),
+            [("price_currency".to_string(), json!("EUR"))]
+        );
+        assert!(matches!(
+            normalize("5", &[("decimal_separator", ";")]),
+            Err(LoaderError::Configuration(_))
+        ));
+    }
+
+    #[test]
+    fn test_byte_size_parsing() {
+        assert_eq!(
+            ByteSizeNormalizer::parse("3.5 MB", false, None),
+            Some(3_500_000)
+        );
+        assert_eq!(
+            ByteSizeNormalizer::parse("3.5 MB", true, None),
+            Some(3_670_016)
+        );
+        assert_eq!(
+            ByteSizeNormalizer::parse("512KiB", false, None),
+            Some(524_288)
+        );
+        assert_eq!(
+            ByteSizeNormalizer::parse("1.2G", false, None),
+            Some(1_200_000_000)
+        );
+        assert_eq!(ByteSizeNormalizer::parse("42 bytes", false, None), Some(42));
+        assert_eq!(ByteSizeNormalizer::parse("1,024", false, None), Some(1024));
+        assert_eq!(
+            ByteSizeNormalizer::parse("1,024 KB", false, Some(',')),
+            Some(1024)
+        );
+        assert_eq!(ByteSizeNormalizer::parse("3 parsecs", false, None), None);
+    }
+
+    #[test]
+    fn test_normalize_instance() {
+        let schema = create_test_schema();
+        let registry = NormalizerRegistry::default();
+
+        let mut item =
+            instance(json!({"price": "US$1,234.56", "size": "3.5 MB", "memory": "2 MB"}));
+        registry
+            .normalize_instance(&mut item, &schema)
+            .expect("normalizes");
+        assert_eq!(item.data["price"], json!(1234.56));
+        assert_eq!(item.data["price_currency"], json!("USD"));
+        assert_eq!(item.data["size"], json!(3_500_000));
+        assert_eq!(
+            item.data["memory"],
+            json!(2_097_152),
+            "attribute annotation"
+        );
+
+        let mut item = instance(json!({"price": "99", "price_currency": "CHF"}));
+        registry
+            .normalize_instance(&mut item, &schema)
+            .expect("normalizes");
+        assert_eq!(item.data["price"], json!(99.0));
+        assert_eq!(
+            item.data["price_currency"],
+            json!("CHF"),
+            "source value wins"
+        );
+
+        let mut item = instance(json!({"price": "12"}));
+        registry
+            .normalize_instance(&mut item, &schema)
+            .expect("normalizes");
+        assert_eq!(
+            item.data["price_currency"],
+            json!("EUR"),
+            "default currency"
+        );
+    }
+
+    #[test]
+    fn test_invalid_values_and_skip_invalid() {
+        let schema = create_test_schema();
+        let registry = NormalizerRegistry::default();
+        let instances = vec![
+            instance(json!({"size": "lots"})),
+            instance(json!({"size": "1 KB", "price": null})),
+        ];
+
+        assert!(matches!(
+            registry.normalize_instances(instances.clone(), &schema, false),
+            Err(LoaderError::TypeConversion(_))
+        ));
+        let kept = registry
+            .normalize_instances(instances, &schema, true)
+            .expect("invalid instances skipped");
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].data["size"], json!(1000));
+        assert!(kept[0].data["price"].is_null());
+    }
+
+    #[test]
+    fn test_custom_normalizer_plugin() {
+        struct Uppercase;
+        impl ValueNormalizer for Uppercase {
+            fn name(&self) -> &'static str {
+                "uppercase"
+            }
+            fn normalize(
+                &self,
+                value: &JsonValue,
+                _context: &NormalizationContext<'_>,
+            ) -> LoaderResult<Normalized> {
+                Ok(Normalized::value(json!(
+                    value.as_str().unwrap_or_default().to_uppercase()
+                )))
+            }
+        }
+
+        let mut schema = create_test_schema();
+        schema.slots.insert(
+            "code".to_string(),
+            annotated_slot("string", &[("normalize", "uppercase")]),
+        );
+        let mut item = instance(json!({"code": ["ab", "cd"]}));
+
+        assert!(matches!(
+            NormalizerRegistry::default().normalize_instance(&mut item, &schema),
+            Err(LoaderError::Configuration(_))
+        ));
+
+        let registry = NormalizerRegistry::default().with_normalizer(Uppercase);
+        registry
+            .normalize_instance(&mut item, &schema)
+            .expect("custom normalizer registered");
+        assert_eq!(item.data["code"], json!(["AB", "CD"]));
+    }
+}
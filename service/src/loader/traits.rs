@@ -5,8 +5,12 @@ use linkml_core::prelude::*;
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 use thiserror::Error;
 
+use super::normalize::NormalizerRegistry;
+use crate::object_storage::{ObjectStorage, object_uri};
+
 /// Error type for data loading operations
 #[derive(Debug, Error)]
 pub enum LoaderError {
@@ -171,10 +175,14 @@ pub struct LoadOptions {
 
     /// Custom field mappings
     pub field_mappings: HashMap<String, String>,
+
+    /// Value normalizers applied to annotated slots (built-ins when `None`)
+    pub normalizers: Option<Arc<NormalizerRegistry>>,
 }
 
 /// Options for dumping data
 #[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct DumpOptions {
     /// Whether to include metadata
     pub include_metadata: bool,
@@ -193,6 +201,9 @@ pub struct DumpOptions {
 
     /// Classes to include in dump (None means all)
     pub include_classes: Option<Vec<String>>,
+
+    /// Write instances in canonical form (see [`super::canonical`])
+    pub canonical: bool,
 }
 
 /// Trait for data loaders
@@ -231,6 +242,25 @@ pub trait DataLoader: Send + Sync {
         options: &LoadOptions,
     ) -> LoaderResult<Vec<DataInstance>>;
 
+    /// Load data from a local path or an object store URI such as
+    /// `s3://bucket/people.json`
+    async fn load_location(
+        &self,
+        location: &Path,
+        schema: &SchemaDefinition,
+        options: &LoadOptions,
+        storage: &ObjectStorage,
+    ) -> LoaderResult<Vec<DataInstance>> {
+        if object_uri(location).is_none() {
+            return self.load_file(location, schema, options).await;
+        }
+        let data = storage
+            .read(location)
+            .await
+            .map_err(|e| LoaderError::Other(Box::new(e)))?;
+        self.load_bytes(&data, schema, options).await
+    }
+
     /// Validate that the loader can handle the given schema
     ///
     /// # Errors
@@ -276,6 +306,26 @@ pub trait DataDumper: Send + Sync {
         options: &DumpOptions,
     ) -> DumperResult<Vec<u8>>;
 
+    /// Dump data to a local path or an object store URI such as
+    /// `s3://bucket/people.json`
+    async fn dump_location(
+        &self,
+        instances: &[DataInstance],
+        location: &Path,
+        schema: &SchemaDefinition,
+        options: &DumpOptions,
+        storage: &ObjectStorage,
+    ) -> DumperResult<()> {
+        if object_uri(location).is_none() {
+            return self.dump_file(instances, location, schema, options).await;
+        }
+        let data = self.dump_bytes(instances, schema, options).await?;
+        storage
+            .write(location, data)
+            .await
+            .map_err(|e| DumperError::Other(Box::new(e)))
+    }
+
     /// Validate that the dumper can handle the given schema
     ///
     /// # Errors
@@ -7,9 +7,11 @@ use super::dbms_executor::DBMSServiceExecutor;
 use super::traits::{
     DataDumper, DataInstance, DataLoader, DumpOptions, DumperResult, LoadOptions, LoaderResult,
 };
+use super::typedb_executor::TypeDBBulkExecutor;
 use super::typedb_integration::{
     TypeDBIntegrationDumper, TypeDBIntegrationLoader, TypeDBIntegrationOptions,
 };
+use crate::config::TypeQLDialect;
 use async_trait::async_trait;
 use linkml_core::prelude::*;
 use std::collections::HashMap;
@@ -38,6 +40,9 @@ pub struct TypeDBOptions {
 
     /// Include inferred attributes
     pub include_inferred: bool,
+
+    /// `TypeQL` dialect spoken by the server
+    pub dialect: TypeQLDialect,
 }
 
 impl Default for TypeDBOptions {
@@ -53,6 +58,7 @@ impl Default for TypeDBOptions {
             infer_types: true,
             create_if_not_exists: false,
             include_inferred: config.typedb.include_inferred,
+            dialect: config.typedb.dialect,
         }
     }
 }
@@ -61,7 +67,7 @@ impl Default for TypeDBOptions {
 ///
 /// This loader requires a DBMS service instance to perform all `TypeDB` operations.
 pub struct TypeDBLoader<S: dbms_core::DBMSService + 'static> {
-    inner: TypeDBIntegrationLoader<DBMSServiceExecutor<S>>,
+    inner: TypeDBIntegrationLoader<TypeDBBulkExecutor<DBMSServiceExecutor<S>>>,
 }
 
 impl<S> TypeDBLoader<S>
@@ -80,10 +86,12 @@ where
             infer_types: options.infer_types,
             include_inferred: options.include_inferred,
             query_timeout_ms: 30000, // Default timeout
+            dialect: options.dialect,
         };
 
-        // Create executor using DBMS service
-        let executor = DBMSServiceExecutor::new(dbms_service);
+        // Create executor using DBMS service, limited and retried per the
+        // typedb configuration
+        let executor = TypeDBBulkExecutor::new(DBMSServiceExecutor::new(dbms_service));
 
         Self {
             inner: TypeDBIntegrationLoader::new(integration_options, executor),
@@ -145,7 +153,7 @@ where
 ///
 /// This dumper requires a DBMS service instance to perform all `TypeDB` operations.
 pub struct TypeDBDumper<S: dbms_core::DBMSService + 'static> {
-    inner: TypeDBIntegrationDumper<DBMSServiceExecutor<S>>,
+    inner: TypeDBIntegrationDumper<TypeDBBulkExecutor<DBMSServiceExecutor<S>>>,
 }
 
 impl<S> TypeDBDumper<S>
@@ -164,10 +172,12 @@ where
             infer_types: options.infer_types,
             include_inferred: options.include_inferred,
             query_timeout_ms: 30000, // Default timeout
+            dialect: options.dialect,
         };
 
-        // Create executor using DBMS service
-        let executor = DBMSServiceExecutor::new(dbms_service);
+        // Create executor using DBMS service, limited and retried per the
+        // typedb configuration
+        let executor = TypeDBBulkExecutor::new(DBMSServiceExecutor::new(dbms_service));
 
         Self {
             inner: TypeDBIntegrationDumper::new(integration_options, executor),
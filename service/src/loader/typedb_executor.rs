@@ -0,0 +1,489 @@
+//! Rate-limited, retrying execution of bulk `TypeQL` queries
+//!
+//! [`TypeDBBulkExecutor`] wraps any [`TypeDBQueryExecutor`] (the DBMS service
+//! executor, [`TypeDB3HttpExecutor`](super::TypeDB3HttpExecutor) or one of
+//! a downstream crate) with the connection handling bulk loads need:
+//!
+//! - at most [`TypeDBConfig::pool_size`] queries in flight at a time, shared by
+//!   all callers of the executor
+//! - retries of transient failures (timeouts, refused connections, overloaded
+//!   servers) with exponential backoff, starting from
+//!   [`TypeDBConfig::retry_delay_ms`] for up to [`TypeDBConfig::max_retries`]
+//!   retries
+//! - failures mapped to [`TypeDBExecutorError`], which tells transient
+//!   failures from rejected queries and converts into [`LinkMLError`]
+//!
+//! The wrapper implements [`TypeDBQueryExecutor`] itself, so it can be handed
+//! to [`TypeDBIntegrationLoader`](super::TypeDBIntegrationLoader) and
+//! [`TypeDBIntegrationDumper`](super::TypeDBIntegrationDumper) unchanged.
+
+use super::typedb_integration::TypeDBQueryExecutor;
+use crate::config::TypeDBConfig;
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use linkml_core::error::LinkMLError;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::Semaphore;
+use tracing::warn;
+
+/// Failure of a `TypeDB` operation
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TypeDBExecutorError {
+    /// The server could not be reached
+    #[error("TypeDB connection failed: {0}")]
+    Connection(String),
+
+    /// The operation did not complete in time
+    #[error("TypeDB operation timed out: {0}")]
+    Timeout(String),
+
+    /// The server is overloaded or temporarily unavailable
+    #[error("TypeDB server unavailable: {0}")]
+    Unavailable(String),
+
+    /// The credentials or access token were rejected
+    #[error("TypeDB authentication failed: {0}")]
+    Authentication(String),
+
+    /// The server rejected the query
+    #[error("TypeDB rejected the query: {0}")]
+    Query(String),
+
+    /// A transient failure persisted through all retries
+    #[error("TypeDB operation failed after {attempts} attempts: {last}")]
+    RetriesExhausted {
+        /// Number of attempts made
+        attempts: u32,
+        /// Failure of the last attempt
+        last: Box<TypeDBExecutorError>,
+    },
+}
+
+impl TypeDBExecutorError {
+    /// Map an executor error to its kind
+    ///
+    /// HTTP client errors are classified by their kind and status; errors of
+    /// other executors, such as the DBMS service, by their message.
+    #[must_use]
+    pub fn classify(error: &(dyn std::error::Error + 'static)) -> Self {
+        let message = error.to_string();
+        if let Some(error) = error.downcast_ref::<reqwest::Error>() {
+            if error.is_timeout() {
+                return Self::Timeout(message);
+            }
+            if error.is_connect() {
+                return Self::Connection(message);
+            }
+            if let Some(status) = error.status() {
+                return Self::from_status(status.as_u16(), message);
+            }
+        }
+
+        let lower = message.to_lowercase();
+        if lower.contains("timed out") || lower.contains("timeout") {
+            Self::Timeout(message)
+        } else if lower.contains("unauthorized")
+            || lower.contains("access token")
+            || lower.contains("sign-in")
+            || lower.contains("authentication")
+        {
+            Self::Authentication(message)
+        } else if let Some(status) = [429, 502, 503, 504]
+            .into_iter()
+            .find(|status| lower.contains(&format!("({status}")))
+        {
+            Self::from_status(status, message)
+        } else if lower.contains("unavailable") || lower.contains("too many requests") {
+            Self::Unavailable(message)
+        } else if lower.contains("connection") || lower.contains("connect") {
+            Self::Connection(message)
+        } else {
+            Self::Query(message)
+        }
+    }
+
+    fn from_status(status: u16, message: String) -> Self {
+        match status {
+            401 | 403 => Self::Authentication(message),
+            408 | 504 => Self::Timeout(message),
+            429 | 502 | 503 => Self::Unavailable(message),
+            _ => Self::Query(message),
+        }
+    }
+
+    /// Whether retrying the operation may succeed
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::Connection(_) | Self::Timeout(_) | Self::Unavailable(_)
+        )
+    }
+}
+
+impl From<TypeDBExecutorError> for LinkMLError {
+    fn from(error: TypeDBExecutorError) -> Self {
+        match error {
+            TypeDBExecutorError::Authentication(_) => LinkMLError::config(error.to_string()),
+            _ => LinkMLError::service(error.to_string()),
+        }
+    }
+}
+
+/// Retries of transient failures with exponential backoff
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDBRetryPolicy {
+    /// Retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry
+    pub initial_delay: Duration,
+    /// Upper bound of the delay between retries
+    pub max_delay: Duration,
+    /// Factor the delay grows by after each retry
+    pub backoff_factor: f64,
+}
+
+impl TypeDBRetryPolicy {
+    /// Policy of the `typedb` configuration section
+    #[must_use]
+    pub fn from_config(config: &TypeDBConfig) -> Self {
+        Self {
+            max_retries: config.max_retries,
+            initial_delay: Duration::from_millis(config.retry_delay_ms),
+            max_delay: Duration::from_millis(config.query_timeout_ms.max(config.retry_delay_ms)),
+            backoff_factor: 2.0,
+        }
+    }
+
+    /// Policy that never retries
+    #[must_use]
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            initial_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            backoff_factor: 1.0,
+        }
+    }
+
+    /// Delay before the retry following one after `delay`
+    #[must_use]
+    pub fn next_delay(&self, delay: Duration) -> Duration {
+        delay.mul_f64(self.backoff_factor).min(self.max_delay)
+    }
+}
+
+impl Default for TypeDBRetryPolicy {
+    fn default() -> Self {
+        Self::from_config(&crate::config::get_config().typedb)
+    }
+}
+
+/// Query executor limiting concurrency and retrying transient failures
+pub struct TypeDBBulkExecutor<E: TypeDBQueryExecutor> {
+    executor: E,
+    concurrency: usize,
+    permits: Arc<Semaphore>,
+    retry_policy: TypeDBRetryPolicy,
+}
+
+impl<E: TypeDBQueryExecutor> TypeDBBulkExecutor<E> {
+    /// Wrap an executor with the limits of the `typedb` configuration section
+    pub fn new(executor: E) -> Self {
+        Self::with_config(executor, &crate::config::get_config().typedb)
+    }
+
+    /// Wrap an executor with the limits of a `TypeDB` configuration
+    pub fn with_config(executor: E, config: &TypeDBConfig) -> Self {
+        Self::with_concurrency(executor, config.pool_size)
+            .with_retry_policy(TypeDBRetryPolicy::from_config(config))
+    }
+
+    /// Wrap an executor running at most `concurrency` queries at a time,
+    /// without retries
+    pub fn with_concurrency(executor: E, concurrency: usize) -> Self {
+        let concurrency = concurrency.max(1);
+        Self {
+            executor,
+            concurrency,
+            permits: Arc::new(Semaphore::new(concurrency)),
+            retry_policy: TypeDBRetryPolicy::none(),
+        }
+    }
+
+    /// Set the retry policy
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: TypeDBRetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Maximum number of queries in flight
+    #[must_use]
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Retry policy of transient failures
+    #[must_use]
+    pub fn retry_policy(&self) -> &TypeDBRetryPolicy {
+        &self.retry_policy
+    }
+
+    /// The wrapped executor
+    pub fn inner(&self) -> &E {
+        &self.executor
+    }
+
+    /// Run a match query, returning its results as `JSON`
+    ///
+    /// # Errors
+    ///
+    /// Returns the classified failure of the query
+    pub async fn query(&self, query: &str, database: &str) -> Result<String, TypeDBExecutorError> {
+        self.run(|| self.executor.execute_query(query, database))
+            .await
+    }
+
+    /// Run a define query
+    ///
+    /// # Errors
+    ///
+    /// Returns the classified failure of the query
+    pub async fn define(&self, query: &str, database: &str) -> Result<(), TypeDBExecutorError> {
+        self.run(|| self.executor.execute_define(query, database))
+            .await
+    }
+
+    /// Run an insert query
+    ///
+    /// # Errors
+    ///
+    /// Returns the classified failure of the query
+    pub async fn insert(&self, query: &str, database: &str) -> Result<(), TypeDBExecutorError> {
+        self.run(|| self.executor.execute_insert(query, database))
+            .await
+    }
+
+    /// Run insert queries concurrently, returning how many were run
+    ///
+    /// Stops at the first query that fails after its retries; queries already
+    /// in flight complete.
+    ///
+    /// # Errors
+    ///
+    /// Returns the classified failure of the first failing query
+    pub async fn insert_all<Q: AsRef<str>>(
+        &self,
+        queries: &[Q],
+        database: &str,
+    ) -> Result<usize, TypeDBExecutorError> {
+        stream::iter(queries)
+            .map(Ok)
+            .try_for_each_concurrent(self.concurrency, |query| {
+                self.insert(query.as_ref(), database)
+            })
+            .await?;
+        Ok(queries.len())
+    }
+
+    /// Run an operation under a permit, retrying transient failures
+    async fn run<T, F, Fut>(&self, operation: F) -> Result<T, TypeDBExecutorError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, Box<dyn std::error::Error>>>,
+    {
+        let mut delay = self.retry_policy.initial_delay;
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let error = {
+                let _permit = self.permits.acquire().await.map_err(|e| {
+                    TypeDBExecutorError::Unavailable(format!("executor is shut down: {e}"))
+                })?;
+                match operation().await {
+                    Ok(value) => return Ok(value),
+                    Err(error) => TypeDBExecutorError::classify(error.as_ref()),
+                }
+            };
+            if !error.is_transient() {
+                return Err(error);
+            }
+            if attempts > self.retry_policy.max_retries {
+                return Err(if attempts == 1 {
+                    error
+                } else {
+                    TypeDBExecutorError::RetriesExhausted {
+                        attempts,
+                        last: Box::new(error),
+                    }
+                });
+            }
+            warn!(
+                "{error}; retrying in {delay:?} ({attempts}/{})",
+                self.retry_policy.max_retries
+            );
+            tokio::time::sleep(delay).await;
+            delay = self.retry_policy.next_delay(delay);
+        }
+    }
+}
+
+#[async_trait]
+impl<E: TypeDBQueryExecutor> TypeDBQueryExecutor for TypeDBBulkExecutor<E> {
+    async fn execute_query(
+        &self,
+        query: &str,
+        database: &str,
+    ) -> std::result::Result<String, Box<dyn std::error::Error>> {
+        Ok(self.query(query, database).await?)
+    }
+
+    async fn execute_define(
+        &self,
+        query: &str,
+        database: &str,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        Ok(self.define(query, database).await?)
+    }
+
+    async fn execute_insert(
+        &self,
+        query: &str,
+        database: &str,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        Ok(self.insert(query, database).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Fails the first inserts of each query with scripted errors and tracks
+    /// the highest number of queries in flight
+    #[derive(Default)]
+    struct FlakyExecutor {
+        failures: Mutex<Vec<(String, &'static str)>>,
+        in_flight: AtomicUsize,
+        peak: AtomicUsize,
+        inserted: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl TypeDBQueryExecutor for FlakyExecutor {
+        async fn execute_query(
+            &self,
+            _query: &str,
+            _database: &str,
+        ) -> std::result::Result<String, Box<dyn std::error::Error>> {
+            Err("TypeDB rejected the access token".into())
+        }
+
+        async fn execute_define(
+            &self,
+            _query: &str,
+            _database: &str,
+        ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+            Err("[TQL03] syntax error near 'entty'".into())
+        }
+
+        async fn execute_insert(
+            &self,
+            query: &str,
+            _database: &str,
+        ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+            let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            let failure = {
+                let mut failures = self.failures.lock().expect("lock");
+                let position = failures.iter().position(|(failing, _)| failing == query);
+                position.map(|position| failures.remove(position).1)
+            };
+            if let Some(message) = failure {
+                return Err(message.into());
+            }
+            self.inserted.lock().expect("lock").push(query.to_string());
+            Ok(())
+        }
+    }
+
+    fn policy(max_retries: u32) -> TypeDBRetryPolicy {
+        TypeDBRetryPolicy {
+            max_retries,
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(4),
+            backoff_factor: 2.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_executor_limits_and_retries() {
+        let executor = FlakyExecutor::default();
+        executor.failures.lock().expect("lock").extend([
+            (
+                "insert $p isa person, has age 1;".to_string(),
+                "TypeDB query failed (503 Service Unavailable): overloaded",
+            ),
+            (
+                "insert $p isa person, has age 1;".to_string(),
+                "connection reset by peer",
+            ),
+        ]);
+        let bulk = TypeDBBulkExecutor::with_concurrency(executor, 2).with_retry_policy(policy(2));
+
+        let queries: Vec<String> = (0..6)
+            .map(|age| format!("insert $p isa person, has age {age};"))
+            .collect();
+        assert_eq!(bulk.insert_all(&queries, "db").await, Ok(6));
+        assert_eq!(bulk.inner().inserted.lock().expect("lock").len(), 6);
+        assert!(bulk.inner().peak.load(Ordering::SeqCst) <= 2);
+
+        // Rejected queries and credentials are not retried
+        assert!(matches!(
+            bulk.define("define entty person;", "db").await,
+            Err(TypeDBExecutorError::Query(_))
+        ));
+        let err = bulk
+            .query("match $p isa person;", "db")
+            .await
+            .expect_err("auth");
+        assert!(matches!(err, TypeDBExecutorError::Authentication(_)));
+        assert!(matches!(
+            LinkMLError::from(err),
+            LinkMLError::ConfigError(_)
+        ));
+
+        // A transient failure outlasting the retries reports the attempts
+        let query = "insert $p isa person, has age 7;";
+        bulk.inner()
+            .failures
+            .lock()
+            .expect("lock")
+            .extend(std::iter::repeat_n(
+                (query.to_string(), "operation timed out"),
+                3,
+            ));
+        assert_eq!(
+            bulk.insert(query, "db").await,
+            Err(TypeDBExecutorError::RetriesExhausted {
+                attempts: 3,
+                last: Box::new(TypeDBExecutorError::Timeout(
+                    "operation timed out".to_string()
+                )),
+            })
+        );
+        assert_eq!(
+            policy(2).next_delay(Duration::from_millis(3)),
+            Duration::from_millis(4)
+        );
+    }
+}
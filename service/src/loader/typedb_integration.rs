@@ -2,16 +2,24 @@
 //!
 //! This module provides a proper integration with `TypeDB` through the DBMS service,
 //! avoiding circular dependencies while maintaining the single source of truth principle.
+//!
+//! Queries are written in the `TypeQL` dialect selected by
+//! [`TypeDBIntegrationOptions::dialect`], which defaults to the `typedb.dialect`
+//! configuration option. `TypeDB` 3.x servers can be reached without the DBMS
+//! service through [`TypeDB3HttpExecutor`], which speaks the 3.x HTTP protocol.
 
 use super::traits::{
     DataDumper, DataInstance, DataLoader, DumpOptions, DumperError, DumperResult, LoadOptions,
     LoaderError, LoaderResult,
 };
+use crate::config::TypeQLDialect;
 use async_trait::async_trait;
 use linkml_core::prelude::*;
-use serde_json::{Map, Value};
+use serde_json::{Map, Value, json};
 use std::collections::HashMap;
 use std::fmt::Write;
+use std::time::Duration;
+use tokio::sync::Mutex;
 use tracing::{debug, info};
 
 /// `TypeDB` integration options
@@ -37,6 +45,9 @@ pub struct TypeDBIntegrationOptions {
 
     /// Query timeout in milliseconds
     pub query_timeout_ms: u64,
+
+    /// `TypeQL` dialect of the generated queries
+    pub dialect: TypeQLDialect,
 }
 
 impl Default for TypeDBIntegrationOptions {
@@ -50,6 +61,7 @@ impl Default for TypeDBIntegrationOptions {
             infer_types: true,
             include_inferred: config.typedb.include_inferred,
             query_timeout_ms: config.typedb.query_timeout_ms,
+            dialect: config.typedb.dialect,
         }
     }
 }
@@ -82,6 +94,174 @@ pub trait TypeDBQueryExecutor: Send + Sync {
     ) -> std::result::Result<(), Box<dyn std::error::Error>>;
 }
 
+/// Query executor speaking the `TypeDB` 3.x HTTP protocol
+///
+/// Signs in once with username and password and sends every query as a
+/// one-shot transaction to `/v1/query`: match queries in a `read`
+/// transaction, inserts in a committed `write` transaction and definitions
+/// in a committed `schema` transaction. Concept rows are returned in the
+/// shape the parsers of this module expect, one object per row mapping
+/// variable names to concepts with `label`, `iid`, `value` and `value_type`.
+/// Use it with [`TypeQLDialect::V3`] options.
+pub struct TypeDB3HttpExecutor {
+    base_url: String,
+    username: String,
+    password: String,
+    timeout: Duration,
+    client: reqwest::Client,
+    token: Mutex<Option<String>>,
+}
+
+impl TypeDB3HttpExecutor {
+    /// Create an executor for the HTTP endpoint of a `TypeDB` 3.x server,
+    /// e.g. `http://localhost:8000`
+    pub fn new(
+        base_url: impl Into<String>,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            username: username.into(),
+            password: password.into(),
+            timeout: Duration::from_millis(crate::config::get_config().typedb.query_timeout_ms),
+            client: reqwest::Client::new(),
+            token: Mutex::new(None),
+        }
+    }
+
+    /// Set the timeout of each HTTP request
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Access token, signing in on first use
+    async fn token(&self) -> std::result::Result<String, Box<dyn std::error::Error>> {
+        let mut token = self.token.lock().await;
+        if let Some(token) = token.as_ref() {
+            return Ok(token.clone());
+        }
+        let body: Value = self
+            .client
+            .post(format!("{}/v1/signin", self.base_url))
+            .timeout(self.timeout)
+            .json(&json!({ "username": self.username, "password": self.password }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        let signed_in = body
+            .get("token")
+            .and_then(Value::as_str)
+            .ok_or("TypeDB sign-in response has no token")?
+            .to_string();
+        *token = Some(signed_in.clone());
+        Ok(signed_in)
+    }
+
+    /// Run a query in a one-shot transaction, signing in again once if the
+    /// token has expired
+    async fn run(
+        &self,
+        query: &str,
+        database: &str,
+        transaction_type: &str,
+    ) -> std::result::Result<Value, Box<dyn std::error::Error>> {
+        let request = json!({
+            "databaseName": database,
+            "transactionType": transaction_type,
+            "query": query,
+            "commit": transaction_type != "read",
+        });
+        for attempt in 0..2 {
+            let token = self.token().await?;
+            let response = self
+                .client
+                .post(format!("{}/v1/query", self.base_url))
+                .timeout(self.timeout)
+                .bearer_auth(token)
+                .json(&request)
+                .send()
+                .await?;
+            let status = response.status();
+            if status == reqwest::StatusCode::UNAUTHORIZED && attempt == 0 {
+                *self.token.lock().await = None;
+                continue;
+            }
+            let body: Value = response.json().await.unwrap_or(Value::Null);
+            if !status.is_success() {
+                let message = body
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .unwrap_or_else(|| status.canonical_reason().unwrap_or("unknown error"));
+                return Err(format!("TypeDB query failed ({status}): {message}").into());
+            }
+            return Ok(body);
+        }
+        Err("TypeDB rejected the access token".into())
+    }
+}
+
+#[async_trait]
+impl TypeDBQueryExecutor for TypeDB3HttpExecutor {
+    async fn execute_query(
+        &self,
+        query: &str,
+        database: &str,
+    ) -> std::result::Result<String, Box<dyn std::error::Error>> {
+        let body = self.run(query, database, "read").await?;
+        Ok(concept_rows(&body).to_string())
+    }
+
+    async fn execute_define(
+        &self,
+        query: &str,
+        database: &str,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        self.run(query, database, "schema").await.map(|_| ())
+    }
+
+    async fn execute_insert(
+        &self,
+        query: &str,
+        database: &str,
+    ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+        self.run(query, database, "write").await.map(|_| ())
+    }
+}
+
+/// Concept rows of a `TypeDB` 3.x query answer as one object per row
+fn concept_rows(body: &Value) -> Value {
+    let rows = body
+        .get("answers")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .map(|answer| {
+            let data = answer
+                .get("data")
+                .and_then(Value::as_object)
+                .into_iter()
+                .flatten()
+                .map(|(var, concept)| {
+                    let mut concept = concept.clone();
+                    if let Some(fields) = concept.as_object_mut()
+                        && let Some(value_type) = fields.remove("valueType")
+                    {
+                        fields.insert("value_type".to_string(), value_type);
+                    }
+                    (var.clone(), concept)
+                })
+                .collect();
+            Value::Object(data)
+        })
+        .collect();
+    Value::Array(rows)
+}
+
 /// `TypeDB` loader using an abstract query executor
 pub struct TypeDBIntegrationLoader<E: TypeDBQueryExecutor> {
     options: TypeDBIntegrationOptions,
@@ -96,7 +276,10 @@ impl<E: TypeDBQueryExecutor> TypeDBIntegrationLoader<E> {
 
     /// Get all entity types from `TypeDB`
     async fn get_entity_types(&self) -> LoaderResult<Vec<TypeInfo>> {
-        let query = "match $x sub entity; get $x;";
+        let query = match self.options.dialect {
+            TypeQLDialect::V2 => "match $x sub entity; get $x;",
+            TypeQLDialect::V3 => "match entity $x; select $x;",
+        };
         let result = self
             .executor
             .execute_query(query, &self.options.database_name)
@@ -112,7 +295,10 @@ impl<E: TypeDBQueryExecutor> TypeDBIntegrationLoader<E> {
 
     /// Get all relation types from `TypeDB`
     async fn get_relation_types(&self) -> LoaderResult<Vec<TypeInfo>> {
-        let query = "match $x sub relation; get $x;";
+        let query = match self.options.dialect {
+            TypeQLDialect::V2 => "match $x sub relation; get $x;",
+            TypeQLDialect::V3 => "match relation $x; select $x;",
+        };
         let result = self
             .executor
             .execute_query(query, &self.options.database_name)
@@ -160,7 +346,14 @@ impl<E: TypeDBQueryExecutor> TypeDBIntegrationLoader<E> {
 
     /// Get attributes owned by a type
     async fn get_type_attributes(&self, type_name: &str) -> LoaderResult<Vec<AttributeInfo>> {
-        let query = format!("match $type type {type_name}; $type owns $attr; get $attr;");
+        let query = match self.options.dialect {
+            TypeQLDialect::V2 => {
+                format!("match $type type {type_name}; $type owns $attr; get $attr;")
+            }
+            TypeQLDialect::V3 => {
+                format!("match $type label {type_name}; $type owns $attr; select $attr;")
+            }
+        };
 
         let result = self
             .executor
@@ -207,7 +400,14 @@ impl<E: TypeDBQueryExecutor> TypeDBIntegrationLoader<E> {
 
     /// Get roles for a relation type
     async fn get_relation_roles(&self, relation_name: &str) -> LoaderResult<Vec<RoleInfo>> {
-        let query = format!("match $rel type {relation_name}; $rel relates $role; get $role;");
+        let query = match self.options.dialect {
+            TypeQLDialect::V2 => {
+                format!("match $rel type {relation_name}; $rel relates $role; get $role;")
+            }
+            TypeQLDialect::V3 => {
+                format!("match $rel label {relation_name}; $rel relates $role; select $role;")
+            }
+        };
 
         let result = self
             .executor
@@ -269,13 +469,17 @@ impl<E: TypeDBQueryExecutor> TypeDBIntegrationLoader<E> {
             write!(query, " $x has {} $attr_{};", attr.name, attr.name)
                 .expect("write! to String should never fail");
         }
-        query.push_str(" get $x");
+        query.push_str(match self.options.dialect {
+            TypeQLDialect::V2 => " get $x",
+            TypeQLDialect::V3 => " select $x",
+        });
         for attr in attributes {
             write!(query, ", $attr_{}", attr.name).expect("write! to String should never fail");
         }
         query.push(';');
 
-        if self.options.include_inferred {
+        // TypeDB 3.x has no rules, so there is nothing to infer
+        if self.options.include_inferred && self.options.dialect == TypeQLDialect::V2 {
             query = format!("match {} infer true;", query.trim_start_matches("match "));
         }
 
@@ -485,9 +689,13 @@ impl<E: TypeDBQueryExecutor> TypeDBIntegrationDumper<E> {
 
         let mut define_query = String::new();
 
+        let dialect = self.options.dialect;
         if is_relation {
-            write!(define_query, "define {type_name} sub relation")
-                .expect("write! to String should never fail");
+            match dialect {
+                TypeQLDialect::V2 => write!(define_query, "define {type_name} sub relation"),
+                TypeQLDialect::V3 => write!(define_query, "define relation {type_name}"),
+            }
+            .expect("write! to String should never fail");
 
             // Add roles based on object-valued slots
             for slot_name in &class_def.slots {
@@ -501,8 +709,11 @@ impl<E: TypeDBQueryExecutor> TypeDBIntegrationDumper<E> {
                 }
             }
         } else {
-            write!(define_query, "define {type_name} sub entity")
-                .expect("write! to String should never fail");
+            match dialect {
+                TypeQLDialect::V2 => write!(define_query, "define {type_name} sub entity"),
+                TypeQLDialect::V3 => write!(define_query, "define entity {type_name}"),
+            }
+            .expect("write! to String should never fail");
         }
 
         // Add attributes
@@ -512,13 +723,19 @@ impl<E: TypeDBQueryExecutor> TypeDBIntegrationDumper<E> {
                 && !schema.classes.contains_key(range)
             {
                 let attr_name = to_snake_case(slot_name);
-                let value_type = linkml_range_to_typedb_value_type(range);
+                let value_type = linkml_range_to_typedb_value_type(range, dialect);
 
                 // Define attribute type if needed
-                write!(
-                    define_query,
-                    "; {attr_name} sub attribute, value {value_type}"
-                )
+                match dialect {
+                    TypeQLDialect::V2 => write!(
+                        define_query,
+                        "; {attr_name} sub attribute, value {value_type}"
+                    ),
+                    TypeQLDialect::V3 => write!(
+                        define_query,
+                        "; attribute {attr_name}, value {value_type}"
+                    ),
+                }
                 .expect("LinkML operation should succeed");
 
                 // Type owns attribute
@@ -666,15 +883,15 @@ impl<E: TypeDBQueryExecutor> TypeDBIntegrationDumper<E> {
         }
 
         // Build insert part
-        let mut insert_part = format!(
-            "insert $rel ({}) isa {}",
-            role_players
-                .iter()
-                .map(|(role, var)| format!("{role}: ${var}"))
-                .collect::<Vec<_>>()
-                .join(", "),
-            type_name
-        );
+        let players = role_players
+            .iter()
+            .map(|(role, var)| format!("{role}: ${var}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut insert_part = match self.options.dialect {
+            TypeQLDialect::V2 => format!("insert $rel ({players}) isa {type_name}"),
+            TypeQLDialect::V3 => format!("insert $rel isa {type_name}, links ({players})"),
+        };
 
         // Add attributes
         for (slot_name, value) in &instance.data {
@@ -837,14 +1054,15 @@ fn to_snake_case(s: &str) -> String {
     result
 }
 
-fn linkml_range_to_typedb_value_type(range: &str) -> &str {
-    match range {
-        "integer" => "long",
-        "float" => "double",
-        "boolean" => "boolean",
-        "string" => "string",
-        "date" | "datetime" => "datetime",
-        "time" => "datetime",
+fn linkml_range_to_typedb_value_type(range: &str, dialect: TypeQLDialect) -> &'static str {
+    match (range, dialect) {
+        ("integer", TypeQLDialect::V2) => "long",
+        ("integer", TypeQLDialect::V3) => "integer",
+        ("float", _) => "double",
+        ("boolean", _) => "boolean",
+        ("date", TypeQLDialect::V3) => "date",
+        ("decimal", TypeQLDialect::V3) => "decimal",
+        ("date" | "datetime" | "time", _) => "datetime",
         _ => "string",
     }
 }
@@ -889,11 +1107,18 @@ mod tests {
 
     #[test]
     fn test_type_conversions() {
-        assert_eq!(linkml_range_to_typedb_value_type("integer"), "long");
-        assert_eq!(linkml_range_to_typedb_value_type("float"), "double");
-        assert_eq!(linkml_range_to_typedb_value_type("boolean"), "boolean");
-        assert_eq!(linkml_range_to_typedb_value_type("string"), "string");
-        assert_eq!(linkml_range_to_typedb_value_type("datetime"), "datetime");
+        let v2 = TypeQLDialect::V2;
+        assert_eq!(linkml_range_to_typedb_value_type("integer", v2), "long");
+        assert_eq!(linkml_range_to_typedb_value_type("float", v2), "double");
+        assert_eq!(linkml_range_to_typedb_value_type("boolean", v2), "boolean");
+        assert_eq!(linkml_range_to_typedb_value_type("string", v2), "string");
+        assert_eq!(linkml_range_to_typedb_value_type("datetime", v2), "datetime");
+        assert_eq!(linkml_range_to_typedb_value_type("date", v2), "datetime");
+
+        let v3 = TypeQLDialect::V3;
+        assert_eq!(linkml_range_to_typedb_value_type("integer", v3), "integer");
+        assert_eq!(linkml_range_to_typedb_value_type("date", v3), "date");
+        assert_eq!(linkml_range_to_typedb_value_type("datetime", v3), "datetime");
     }
 
     #[test]
@@ -907,4 +1132,123 @@ mod tests {
         assert!(json_value_to_typeql(&Value::Null).is_err());
         Ok(())
     }
+
+    #[derive(Default)]
+    struct RecordingExecutor(std::sync::Mutex<Vec<String>>);
+
+    #[async_trait]
+    impl TypeDBQueryExecutor for RecordingExecutor {
+        async fn execute_query(
+            &self,
+            _query: &str,
+            _database: &str,
+        ) -> std::result::Result<String, Box<dyn std::error::Error>> {
+            Ok("[]".to_string())
+        }
+
+        async fn execute_define(
+            &self,
+            query: &str,
+            _database: &str,
+        ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+            self.0.lock().expect("lock").push(query.to_string());
+            Ok(())
+        }
+
+        async fn execute_insert(
+            &self,
+            query: &str,
+            _database: &str,
+        ) -> std::result::Result<(), Box<dyn std::error::Error>> {
+            self.0.lock().expect("lock").push(query.to_string());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_typeql_3_queries() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut schema = SchemaDefinition::default();
+        for (name, range) in [("id", "string"), ("age", "integer"), ("member", "Person")] {
+            schema.slots.insert(
+                name.to_string(),
+                SlotDefinition {
+                    range: Some(range.to_string()),
+                    ..Default::default()
+                },
+            );
+        }
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "age".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Membership".to_string(),
+            ClassDefinition {
+                slots: vec!["member".to_string()],
+                ..Default::default()
+            },
+        );
+        let options = TypeDBIntegrationOptions {
+            database_name: "test".to_string(),
+            type_mapping: HashMap::new(),
+            attribute_mapping: HashMap::new(),
+            batch_size: 10,
+            infer_types: false,
+            include_inferred: false,
+            query_timeout_ms: 1000,
+            dialect: TypeQLDialect::V3,
+        };
+        let dumper = TypeDBIntegrationDumper::new(options, RecordingExecutor::default());
+        let instance = |class_name: &str, data: Value| DataInstance {
+            class_name: class_name.to_string(),
+            data: serde_json::from_value(data).expect("object"),
+            id: None,
+            metadata: HashMap::new(),
+        };
+        dumper
+            .dump_string(
+                &[
+                    instance("Person", json!({"age": 36})),
+                    instance("Membership", json!({"member": {"id": "p1"}})),
+                ],
+                &schema,
+                &DumpOptions::default(),
+            )
+            .await?;
+
+        let mut queries = dumper.executor.0.lock().expect("lock").clone();
+        queries.sort();
+        assert_eq!(
+            queries,
+            [
+                "define entity person; attribute id, value string; person owns id; \
+                 attribute age, value integer; person owns age;",
+                "define relation membership, relates member;",
+                "insert $x isa person, has age 36;",
+                "match $member isa person, has id \"p1\";  insert $rel isa membership, links (member: $member)",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_concept_rows() {
+        let body = json!({
+            "answerType": "conceptRows",
+            "answers": [{
+                "data": {
+                    "attr": {"kind": "attributeType", "label": "name", "valueType": "string"}
+                }
+            }]
+        });
+        assert_eq!(
+            concept_rows(&body),
+            json!([
+                {"attr": {"kind": "attributeType", "label": "name", "value_type": "string"}}
+            ])
+        );
+    }
 }
@@ -1,22 +1,157 @@
 //! XML loader and dumper for `LinkML`
 //!
 //! This module provides functionality to load and dump `LinkML` data in XML format.
-
+//!
+//! Namespaced elements and attributes are matched to classes and slots through
+//! the schema's prefixes: names in the default prefix's namespace map to plain
+//! names, names matching a `class_uri` or `slot_uri` map to that element, and
+//! other prefixed names are kept as CURIEs such as `dc:creator`. Whether a slot
+//! is written as an attribute or a child element can be configured per slot,
+//! either on the loader and dumper or with an `xml_node` annotation.
+
+use super::csv::class_slot;
 use super::traits::{
     DataDumper, DataInstance, DataLoader, DumpOptions, DumperError, DumperResult, LoadOptions,
     LoaderError, LoaderResult,
 };
+use super::xsd::XsdModel;
 use async_trait::async_trait;
+use linkml_core::annotations::AnnotationValue;
 use linkml_core::prelude::*;
+use quick_xml::name::ResolveResult;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fmt::Write;
 
+/// Slot annotation choosing between `attribute` and `element` serialization
+pub const XML_NODE_ANNOTATION: &str = "xml_node";
+
+/// How a slot is represented in XML
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XmlNode {
+    /// An attribute of the instance element
+    Attribute,
+    /// A child element of the instance element
+    Element,
+}
+
+/// Resolution of namespaced XML names to schema element names
+struct SchemaNames {
+    /// Namespace URI to prefix
+    prefixes: HashMap<String, String>,
+    /// Namespace of the schema's default prefix
+    default_namespace: Option<String>,
+    /// Expanded `class_uri`/`slot_uri` to class or slot name
+    uris: HashMap<String, String>,
+}
+
+impl SchemaNames {
+    fn new(schema: &SchemaDefinition) -> Self {
+        let expansions: HashMap<&str, &str> = schema
+            .prefixes
+            .iter()
+            .map(|(prefix, definition)| {
+                let expansion = match definition {
+                    PrefixDefinition::Simple(uri) => uri.as_str(),
+                    PrefixDefinition::Complex { prefix_prefix, .. } => prefix_prefix.as_str(),
+                };
+                (prefix.as_str(), expansion)
+            })
+            .collect();
+        let expand = |curie: &str| match curie.split_once(':') {
+            Some((prefix, local)) if expansions.contains_key(prefix) => {
+                format!("{}{local}", expansions[prefix])
+            }
+            _ => curie.to_string(),
+        };
+
+        let mut uris = HashMap::new();
+        for (name, class_def) in &schema.classes {
+            if let Some(uri) = &class_def.class_uri {
+                uris.insert(expand(uri), name.clone());
+            }
+            for (attr_name, attr_def) in &class_def.attributes {
+                if let Some(uri) = &attr_def.slot_uri {
+                    uris.insert(expand(uri), attr_name.clone());
+                }
+            }
+        }
+        for (name, slot_def) in &schema.slots {
+            if let Some(uri) = &slot_def.slot_uri {
+                uris.insert(expand(uri), name.clone());
+            }
+        }
+
+        Self {
+            prefixes: expansions
+                .iter()
+                .map(|(prefix, uri)| ((*uri).to_string(), (*prefix).to_string()))
+                .collect(),
+            default_namespace: schema
+                .default_prefix
+                .as_deref()
+                .and_then(|prefix| expansions.get(prefix))
+                .map(|uri| (*uri).to_string()),
+            uris,
+        }
+    }
+
+    /// Schema name of an element or attribute in `namespace`
+    fn resolve(&self, namespace: Option<&str>, local: &str) -> String {
+        let Some(namespace) = namespace else {
+            return local.to_string();
+        };
+        if let Some(name) = self.uris.get(&format!("{namespace}{local}")) {
+            return name.clone();
+        }
+        if self.default_namespace.as_deref() == Some(namespace) {
+            return local.to_string();
+        }
+        match self.prefixes.get(namespace) {
+            Some(prefix) => format!("{prefix}:{local}"),
+            None => local.to_string(),
+        }
+    }
+}
+
+/// Namespace URI of a resolved XML name
+fn namespace_uri(resolved: &ResolveResult) -> Option<String> {
+    match resolved {
+        ResolveResult::Bound(namespace) => {
+            Some(String::from_utf8_lossy(namespace.as_ref()).to_string())
+        }
+        ResolveResult::Unbound | ResolveResult::Unknown(_) => None,
+    }
+}
+
+/// Configured node kind of a slot, falling back to its `xml_node` annotation
+fn configured_node(
+    nodes: &HashMap<String, XmlNode>,
+    schema: &SchemaDefinition,
+    class_name: &str,
+    slot_name: &str,
+) -> Option<XmlNode> {
+    if let Some(node) = nodes.get(slot_name) {
+        return Some(*node);
+    }
+    let slot_def = class_slot(schema, class_name, slot_name)?;
+    match slot_def.annotations.as_ref()?.get(XML_NODE_ANNOTATION)? {
+        AnnotationValue::String(node) if node == "attribute" => Some(XmlNode::Attribute),
+        AnnotationValue::String(node) if node == "element" => Some(XmlNode::Element),
+        _ => None,
+    }
+}
+
 /// `XML` loader for `LinkML` data
 pub struct XmlLoader {
     /// Input file path
     file_path: Option<String>,
     /// Root element name
     root_element: String,
+    /// Per-slot choice between attributes and child elements
+    slot_nodes: HashMap<String, XmlNode>,
+    /// XSD consulted for names the schema does not type
+    xsd: Option<XsdModel>,
 }
 
 impl XmlLoader {
@@ -26,9 +161,25 @@ impl XmlLoader {
         Self {
             file_path: None,
             root_element: "data".to_string(),
+            slot_nodes: HashMap::new(),
+            xsd: None,
         }
     }
 
+    /// Only read `slot` from attributes or from child elements
+    #[must_use]
+    pub fn with_slot_node(mut self, slot: &str, node: XmlNode) -> Self {
+        self.slot_nodes.insert(slot.to_string(), node);
+        self
+    }
+
+    /// Type values by the element and attribute declarations of an XSD
+    #[must_use]
+    pub fn with_xsd(mut self, xsd: XsdModel) -> Self {
+        self.xsd = Some(xsd);
+        self
+    }
+
     /// Set the input file path
     #[must_use]
     pub fn with_file(mut self, path: &str) -> Self {
@@ -43,6 +194,44 @@ impl XmlLoader {
         self
     }
 
+    /// Add a value read from the attribute or element `slot_name`
+    fn insert_value(
+        &self,
+        instance: &mut DataInstance,
+        slot_name: &str,
+        text: &str,
+        schema: &SchemaDefinition,
+    ) -> LoaderResult<()> {
+        let slot_def = class_slot(schema, &instance.class_name, slot_name);
+        let range = slot_def
+            .and_then(|slot| slot.range.as_deref())
+            .or_else(|| self.xsd.as_ref()?.range_of(slot_name));
+        let value = typed_value(text, range)?;
+
+        if slot_def.and_then(|slot| slot.identifier) == Some(true) {
+            instance.id = Some(text.to_string());
+        }
+
+        let multivalued = slot_def.and_then(|slot| slot.multivalued) == Some(true)
+            || self
+                .xsd
+                .as_ref()
+                .is_some_and(|xsd| xsd.is_repeated(slot_name));
+        if multivalued {
+            match instance
+                .data
+                .entry(slot_name.to_string())
+                .or_insert_with(|| Value::Array(Vec::new()))
+            {
+                Value::Array(values) => values.push(value),
+                existing => *existing = Value::Array(vec![existing.take(), value]),
+            }
+        } else {
+            instance.data.insert(slot_name.to_string(), value);
+        }
+        Ok(())
+    }
+
     fn check_circular_references(&self, schema: &SchemaDefinition) -> LoaderResult<()> {
         use std::collections::HashSet;
 
@@ -111,68 +300,112 @@ impl DataLoader for XmlLoader {
 
     async fn load_string(
         &self,
-        _content: &str,
-        _schema: &SchemaDefinition,
+        content: &str,
+        schema: &SchemaDefinition,
         _options: &LoadOptions,
     ) -> LoaderResult<Vec<DataInstance>> {
-        // Basic XML parsing implementation using quick-xml
-        use quick_xml::Reader;
+        use quick_xml::NsReader;
         use quick_xml::events::Event;
-        use std::collections::HashMap;
 
-        let mut reader = Reader::from_str(_content);
+        let names = SchemaNames::new(schema);
+        let mut reader = NsReader::from_str(content);
         reader.trim_text(true);
 
         let mut instances = Vec::new();
         let mut current_instance: Option<DataInstance> = None;
         let mut current_element = String::new();
-        let mut current_values = HashMap::new();
 
         loop {
-            match reader.read_event() {
-                Ok(Event::Start(ref e)) => {
-                    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                    if _schema.classes.contains_key(&name) {
-                        // Start of a new instance
-                        current_instance = Some(DataInstance {
+            let (resolved, event) = reader
+                .read_resolved_event()
+                .map_err(|e| LoaderError::InvalidFormat(format!("XML parsing error: {e}")))?;
+            let namespace = namespace_uri(&resolved);
+
+            match event {
+                Event::Start(ref e) | Event::Empty(ref e) => {
+                    let is_empty = matches!(event, Event::Empty(_));
+                    let name = names.resolve(
+                        namespace.as_deref(),
+                        &String::from_utf8_lossy(e.local_name().as_ref()),
+                    );
+
+                    if current_instance.is_none() && schema.classes.contains_key(&name) {
+                        // Start of a new instance, whose attributes are slots
+                        let mut instance = DataInstance {
                             id: None,
-                            class_name: name.clone(),
+                            class_name: name,
                             data: HashMap::new(),
                             metadata: HashMap::new(),
-                        });
-                        current_values.clear();
-                    } else {
+                        };
+                        for attribute in e.attributes() {
+                            let attribute = attribute.map_err(|err| {
+                                LoaderError::Parse(format!("XML attribute error: {err}"))
+                            })?;
+                            let key = attribute.key;
+                            if key.as_namespace_binding().is_some() {
+                                continue;
+                            }
+                            let (attribute_ns, local) = reader.resolve_attribute(key);
+                            let slot_name = names.resolve(
+                                namespace_uri(&attribute_ns).as_deref(),
+                                &String::from_utf8_lossy(local.as_ref()),
+                            );
+                            if configured_node(
+                                &self.slot_nodes,
+                                schema,
+                                &instance.class_name,
+                                &slot_name,
+                            ) == Some(XmlNode::Element)
+                            {
+                                continue;
+                            }
+                            let value = attribute.unescape_value().map_err(|err| {
+                                LoaderError::Parse(format!("XML attribute value error: {err}"))
+                            })?;
+                            self.insert_value(&mut instance, &slot_name, &value, schema)?;
+                        }
+
+                        if is_empty {
+                            instances.push(instance);
+                        } else {
+                            current_instance = Some(instance);
+                        }
+                    } else if !is_empty {
                         current_element = name;
                     }
                 }
-                Ok(Event::Text(e)) => {
-                    if !current_element.is_empty() {
+                Event::Text(e) => {
+                    if let Some(instance) = current_instance.as_mut()
+                        && !current_element.is_empty()
+                        && configured_node(
+                            &self.slot_nodes,
+                            schema,
+                            &instance.class_name,
+                            &current_element,
+                        ) != Some(XmlNode::Attribute)
+                    {
                         let text = e.unescape().unwrap_or_default().to_string();
                         if !text.trim().is_empty() {
-                            current_values
-                                .insert(current_element.clone(), serde_json::Value::String(text));
+                            self.insert_value(instance, &current_element, &text, schema)?;
                         }
                     }
                 }
-                Ok(Event::End(ref e)) => {
-                    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                    if let Some(ref mut instance) = current_instance
-                        && instance.class_name == name
+                Event::End(ref e) => {
+                    let name = names.resolve(
+                        namespace.as_deref(),
+                        &String::from_utf8_lossy(e.local_name().as_ref()),
+                    );
+                    if current_instance
+                        .as_ref()
+                        .is_some_and(|instance| instance.class_name == name)
+                        && let Some(instance) = current_instance.take()
                     {
                         // End of instance
-                        instance.data.clone_from(&current_values);
-                        instances.push(instance.clone());
-                        current_instance = None;
-                        current_values.clear();
+                        instances.push(instance);
                     }
                     current_element.clear();
                 }
-                Ok(Event::Eof) => break,
-                Err(e) => {
-                    return Err(LoaderError::InvalidFormat(format!(
-                        "XML parsing error: {e}"
-                    )));
-                }
+                Event::Eof => break,
                 _ => {}
             }
         }
@@ -260,6 +493,8 @@ pub struct XmlDumper {
     root_element: String,
     /// `XML` namespace
     namespace: Option<String>,
+    /// Per-slot choice between attributes and child elements
+    slot_nodes: HashMap<String, XmlNode>,
 }
 
 impl XmlDumper {
@@ -270,9 +505,17 @@ impl XmlDumper {
             pretty,
             root_element: "data".to_string(),
             namespace: None,
+            slot_nodes: HashMap::new(),
         }
     }
 
+    /// Write `slot` as an attribute or as child elements
+    #[must_use]
+    pub fn with_slot_node(mut self, slot: &str, node: XmlNode) -> Self {
+        self.slot_nodes.insert(slot.to_string(), node);
+        self
+    }
+
     /// Set the root element name
     #[must_use]
     pub fn with_root_element(mut self, root: &str) -> Self {
@@ -286,6 +529,37 @@ impl XmlDumper {
         self.namespace = Some(ns.to_string());
         self
     }
+
+    /// Open the root element, declaring the schema prefixes used by CURIE-named slots
+    fn write_root_start(
+        &self,
+        xml: &mut String,
+        instances: &[DataInstance],
+        schema: &SchemaDefinition,
+    ) {
+        write!(xml, "<{}", self.root_element).expect("write! to String should never fail");
+        if let Some(ns) = &self.namespace {
+            write!(xml, " xmlns=\"{}\"", escape_xml(ns))
+                .expect("write! to String should never fail");
+        }
+        let mut declared = std::collections::BTreeMap::new();
+        for key in instances.iter().flat_map(|instance| instance.data.keys()) {
+            if let Some((prefix, _)) = key.split_once(':')
+                && let Some(definition) = schema.prefixes.get(prefix)
+            {
+                let uri = match definition {
+                    PrefixDefinition::Simple(uri) => uri,
+                    PrefixDefinition::Complex { prefix_prefix, .. } => prefix_prefix,
+                };
+                declared.insert(prefix, uri);
+            }
+        }
+        for (prefix, uri) in declared {
+            write!(xml, " xmlns:{prefix}=\"{}\"", escape_xml(uri))
+                .expect("write! to String should never fail");
+        }
+        xml.push_str(">\n");
+    }
 }
 
 impl Default for XmlDumper {
@@ -323,7 +597,7 @@ impl DataDumper for XmlDumper {
     async fn dump_string(
         &self,
         instances: &[DataInstance],
-        _schema: &SchemaDefinition,
+        schema: &SchemaDefinition,
         options: &DumpOptions,
     ) -> DumperResult<String> {
         let mut xml = String::new();
@@ -335,12 +609,7 @@ impl DataDumper for XmlDumper {
         );
 
         // Root element
-        if let Some(ns) = &self.namespace {
-            writeln!(xml, "<{} xmlns=\"{}\">", self.root_element, ns)
-                .expect("writeln! to String should never fail");
-        } else {
-            writeln!(xml, "<{}>", self.root_element).expect("writeln! to String should never fail");
-        }
+        self.write_root_start(&mut xml, instances, schema);
 
         // Convert instances to XML
         for instance in instances {
@@ -349,14 +618,22 @@ impl DataDumper for XmlDumper {
             }
             write!(xml, "<{}", instance.class_name).expect("write! to String should never fail");
 
-            // Add simple attributes
+            // Add attributes: configured slots, otherwise short strings
+            let node_of = |key: &str| {
+                configured_node(&self.slot_nodes, schema, &instance.class_name, key).or(
+                    match instance.data.get(key) {
+                        Some(Value::String(s)) if !s.contains('\n') && s.len() < 50 => {
+                            Some(XmlNode::Attribute)
+                        }
+                        _ => None,
+                    },
+                )
+            };
             for (key, value) in &instance.data {
-                if let Value::String(s) = value
-                    && !s.contains('\n')
-                    && s.len() < 50
+                if node_of(key) == Some(XmlNode::Attribute)
+                    && matches!(value, Value::String(_) | Value::Number(_) | Value::Bool(_))
                 {
-                    use std::fmt::Write;
-                    write!(xml, " {}=\"{}\"", key, escape_xml(s))
+                    write!(xml, " {}=\"{}\"", key, value_to_xml_string(value))
                         .expect("write to string cannot fail");
                 }
             }
@@ -368,8 +645,13 @@ impl DataDumper for XmlDumper {
 
             // Add complex elements
             for (key, value) in &instance.data {
+                if node_of(key) == Some(XmlNode::Attribute)
+                    && matches!(value, Value::String(_) | Value::Number(_) | Value::Bool(_))
+                {
+                    continue;
+                }
                 match value {
-                    Value::String(s) if s.contains('\n') || s.len() >= 50 => {
+                    Value::String(s) => {
                         if self.pretty || options.pretty_print {
                             xml.push_str("    ");
                         }
@@ -554,6 +836,29 @@ impl DataDumper for XmlDumper {
     }
 }
 
+/// Convert XML text to the `JSON` type of a slot range
+fn typed_value(text: &str, range: Option<&str>) -> LoaderResult<Value> {
+    let trimmed = text.trim();
+    match range {
+        Some("integer") => trimmed
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| LoaderError::TypeConversion(format!("Cannot parse '{text}' as integer"))),
+        Some("float" | "double" | "decimal") => trimmed
+            .parse::<f64>()
+            .map(Value::from)
+            .map_err(|_| LoaderError::TypeConversion(format!("Cannot parse '{text}' as float"))),
+        Some("boolean") => match trimmed {
+            "true" | "1" => Ok(Value::Bool(true)),
+            "false" | "0" => Ok(Value::Bool(false)),
+            _ => Err(LoaderError::TypeConversion(format!(
+                "Cannot parse '{text}' as boolean"
+            ))),
+        },
+        _ => Ok(Value::String(text.to_string())),
+    }
+}
+
 /// Escape `XML` special characters
 fn escape_xml(s: &str) -> String {
     s.replace('&', "&amp;")
@@ -578,6 +883,141 @@ fn value_to_xml_string(value: &Value) -> String {
 mod tests {
     use super::*;
 
+    fn namespaced_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition {
+            name: "library".to_string(),
+            default_prefix: Some("lib".to_string()),
+            ..Default::default()
+        };
+        schema.prefixes.insert(
+            "lib".to_string(),
+            PrefixDefinition::Simple("https://example.org/library/".to_string()),
+        );
+        schema.prefixes.insert(
+            "dc".to_string(),
+            PrefixDefinition::Simple("http://purl.org/dc/terms/".to_string()),
+        );
+        schema.classes.insert(
+            "Book".to_string(),
+            ClassDefinition {
+                slots: vec![
+                    "id".to_string(),
+                    "creator".to_string(),
+                    "pages".to_string(),
+                    "keyword".to_string(),
+                ],
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                identifier: Some(true),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "creator".to_string(),
+            SlotDefinition {
+                slot_uri: Some("dc:creator".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "pages".to_string(),
+            SlotDefinition {
+                range: Some("integer".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "keyword".to_string(),
+            SlotDefinition {
+                multivalued: Some(true),
+                ..Default::default()
+            },
+        );
+        schema
+    }
+
+    #[tokio::test]
+    async fn test_namespaced_loading() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let schema = namespaced_schema();
+        let xml = r#"<?xml version="1.0"?>
+            <lib:data xmlns:lib="https://example.org/library/"
+                      xmlns:terms="http://purl.org/dc/terms/"
+                      xmlns:x="urn:example:extra">
+              <lib:Book id="b1" terms:creator="Ursula K. Le Guin" x:shelf="A3">
+                <lib:pages>248</lib:pages>
+                <lib:keyword>fantasy</lib:keyword>
+                <lib:keyword>islands</lib:keyword>
+              </lib:Book>
+              <lib:Book id="b2" pages="12"/>
+            </lib:data>"#;
+
+        let instances = XmlLoader::new()
+            .load_string(xml, &schema, &LoadOptions::default())
+            .await?;
+        assert_eq!(instances.len(), 2);
+        let book = &instances[0];
+        assert_eq!(book.id.as_deref(), Some("b1"));
+        assert_eq!(
+            book.data.get("creator"),
+            Some(&serde_json::json!("Ursula K. Le Guin"))
+        );
+        assert_eq!(book.data.get("shelf"), Some(&serde_json::json!("A3")));
+        assert_eq!(book.data.get("pages"), Some(&serde_json::json!(248)));
+        assert_eq!(
+            book.data.get("keyword"),
+            Some(&serde_json::json!(["fantasy", "islands"]))
+        );
+        assert_eq!(instances[1].data.get("pages"), Some(&serde_json::json!(12)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_slot_nodes_and_xsd() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let schema = namespaced_schema();
+        let instances = vec![DataInstance {
+            class_name: "Book".to_string(),
+            data: std::collections::HashMap::from([
+                ("id".to_string(), serde_json::json!("b1")),
+                ("pages".to_string(), serde_json::json!(248)),
+                ("dc:rights".to_string(), serde_json::json!("CC-BY")),
+            ]),
+            id: Some("b1".to_string()),
+            metadata: std::collections::HashMap::new(),
+        }];
+
+        let dumper = XmlDumper::new(true)
+            .with_slot_node("pages", XmlNode::Attribute)
+            .with_slot_node("id", XmlNode::Element);
+        let xml = dumper
+            .dump_string(&instances, &schema, &DumpOptions::default())
+            .await?;
+        assert!(xml.contains("xmlns:dc=\"http://purl.org/dc/terms/\""));
+        assert!(xml.contains("pages=\"248\""));
+        assert!(xml.contains("<id>b1</id>"));
+
+        let xsd = XsdModel::parse(
+            r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+                 <xs:attribute name="year" type="xs:int"/>
+               </xs:schema>"#,
+        )?;
+        let loaded = XmlLoader::new()
+            .with_xsd(xsd)
+            .with_slot_node("id", XmlNode::Element)
+            .load_string(
+                r#"<data><Book id="ignored" year="1968"><id>b1</id></Book></data>"#,
+                &schema,
+                &LoadOptions::default(),
+            )
+            .await?;
+        assert_eq!(loaded[0].data.get("year"), Some(&serde_json::json!(1968)));
+        assert_eq!(loaded[0].id.as_deref(), Some("b1"));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_xml_dumper() -> std::result::Result<(), Box<dyn std::error::Error>> {
         let instances = vec![DataInstance {
@@ -0,0 +1,203 @@
+//! Minimal XML Schema (XSD) reader used to type XML input
+//!
+//! Only the parts of an XSD that matter for typing values are read: the
+//! declared types of elements and attributes, named simple types derived by
+//! restriction, and whether an element may repeat. The XML loader consults
+//! this model for names that the `LinkML` schema does not type itself.
+
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use super::traits::{LoaderError, LoaderResult};
+
+/// Element and attribute typing extracted from an XSD
+#[derive(Debug, Clone, Default)]
+pub struct XsdModel {
+    /// Declared type of each element or attribute name, without prefix
+    types: HashMap<String, String>,
+    /// Base type of each named simple type
+    simple_types: HashMap<String, String>,
+    /// Elements declared with `maxOccurs` above one
+    repeated: HashSet<String>,
+}
+
+impl XsdModel {
+    /// Parse an XSD document
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not well-formed XML
+    pub fn parse(content: &str) -> LoaderResult<Self> {
+        let mut reader = Reader::from_str(content);
+        reader.trim_text(true);
+
+        let mut model = Self::default();
+        // Declarations currently open, so inline restrictions type them
+        let mut open: Vec<Option<String>> = Vec::new();
+        let mut simple_type: Option<String> = None;
+
+        loop {
+            let event = reader
+                .read_event()
+                .map_err(|e| LoaderError::Parse(format!("XSD parsing error: {e}")))?;
+            match event {
+                Event::Start(ref e) | Event::Empty(ref e) => {
+                    let is_empty = matches!(event, Event::Empty(_));
+                    let declared = model.read_declaration(e)?;
+                    match e.local_name().as_ref() {
+                        b"element" | b"attribute" if !is_empty => open.push(declared),
+                        b"simpleType" => simple_type = attribute(e, b"name")?,
+                        b"restriction" => {
+                            if let Some(base) = attribute(e, b"base")? {
+                                let base = local(&base).to_string();
+                                if let Some(name) = &simple_type {
+                                    model.simple_types.insert(name.clone(), base);
+                                } else if let Some(Some(name)) = open.last() {
+                                    model.types.entry(name.clone()).or_insert(base);
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Event::End(ref e) => match e.local_name().as_ref() {
+                    b"element" | b"attribute" => {
+                        open.pop();
+                    }
+                    b"simpleType" => simple_type = None,
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        Ok(model)
+    }
+
+    /// Read an XSD file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed
+    pub fn from_file(path: &Path) -> LoaderResult<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Self::parse(&content)
+    }
+
+    /// `LinkML` range corresponding to the XSD type of `name`
+    #[must_use]
+    pub fn range_of(&self, name: &str) -> Option<&'static str> {
+        let mut type_name = self.types.get(name)?.as_str();
+        // Follow named simple types down to a built-in type
+        for _ in 0..self.simple_types.len() {
+            match self.simple_types.get(type_name) {
+                Some(base) => type_name = base,
+                None => break,
+            }
+        }
+        builtin_range(type_name)
+    }
+
+    /// Whether `name` is declared as a repeating element
+    #[must_use]
+    pub fn is_repeated(&self, name: &str) -> bool {
+        self.repeated.contains(name)
+    }
+
+    /// Record an element or attribute declaration, returning its name
+    fn read_declaration(&mut self, e: &BytesStart) -> LoaderResult<Option<String>> {
+        if !matches!(e.local_name().as_ref(), b"element" | b"attribute") {
+            return Ok(None);
+        }
+        let Some(name) = attribute(e, b"name")? else {
+            return Ok(None);
+        };
+        if let Some(type_name) = attribute(e, b"type")? {
+            self.types
+                .insert(name.clone(), local(&type_name).to_string());
+        }
+        if attribute(e, b"maxOccurs")?
+            .is_some_and(|max| max == "unbounded" || max.parse::<u32>().is_ok_and(|n| n > 1))
+        {
+            self.repeated.insert(name.clone());
+        }
+        Ok(Some(name))
+    }
+}
+
+/// Value of an attribute of an XSD tag
+fn attribute(e: &BytesStart, key: &[u8]) -> LoaderResult<Option<String>> {
+    e.try_get_attribute(key)
+        .map_err(|err| LoaderError::Parse(format!("XSD attribute error: {err}")))?
+        .map(|attr| {
+            attr.unescape_value()
+                .map(std::borrow::Cow::into_owned)
+                .map_err(|err| LoaderError::Parse(format!("XSD attribute error: {err}")))
+        })
+        .transpose()
+}
+
+/// Strip the namespace prefix from a qualified name
+fn local(name: &str) -> &str {
+    name.rsplit_once(':').map_or(name, |(_, local)| local)
+}
+
+/// `LinkML` range of a built-in XSD type
+fn builtin_range(type_name: &str) -> Option<&'static str> {
+    Some(match type_name {
+        "string" | "normalizedString" | "token" | "language" | "Name" | "NCName" | "ID"
+        | "IDREF" => "string",
+        "anyURI" => "uri",
+        "boolean" => "boolean",
+        "integer" | "int" | "long" | "short" | "byte" | "nonNegativeInteger"
+        | "positiveInteger" | "negativeInteger" | "nonPositiveInteger" | "unsignedInt"
+        | "unsignedLong" | "unsignedShort" | "unsignedByte" => "integer",
+        "decimal" => "decimal",
+        "float" => "float",
+        "double" => "double",
+        "date" => "date",
+        "dateTime" => "datetime",
+        "time" => "time",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xsd_typing() -> LoaderResult<()> {
+        let xsd = r#"<?xml version="1.0"?>
+            <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+              <xs:simpleType name="Age">
+                <xs:restriction base="xs:nonNegativeInteger"/>
+              </xs:simpleType>
+              <xs:element name="Person">
+                <xs:complexType>
+                  <xs:sequence>
+                    <xs:element name="age" type="Age"/>
+                    <xs:element name="height">
+                      <xs:simpleType><xs:restriction base="xs:decimal"/></xs:simpleType>
+                    </xs:element>
+                    <xs:element name="alias" type="xs:string" maxOccurs="unbounded"/>
+                  </xs:sequence>
+                  <xs:attribute name="active" type="xs:boolean"/>
+                </xs:complexType>
+              </xs:element>
+            </xs:schema>"#;
+
+        let model = XsdModel::parse(xsd)?;
+        assert_eq!(model.range_of("age"), Some("integer"));
+        assert_eq!(model.range_of("height"), Some("decimal"));
+        assert_eq!(model.range_of("active"), Some("boolean"));
+        assert_eq!(model.range_of("alias"), Some("string"));
+        assert_eq!(model.range_of("Person"), None);
+        assert!(model.is_repeated("alias"));
+        assert!(!model.is_repeated("age"));
+        Ok(())
+    }
+}
@@ -2,6 +2,10 @@
 //!
 //! This module provides functionality to load and dump `LinkML` data in YAML format.
 
+use super::canonical::Canonicalizer;
+use super::designator::{TypeDesignator, is_instance_of};
+use super::inlined::InlinedExpander;
+use super::normalize::apply_normalizers;
 use super::traits::{
     DataDumper, DataInstance, DataLoader, DumpOptions, DumperError, DumperResult, LoadOptions,
     LoaderError, LoaderResult,
@@ -93,7 +97,7 @@ impl DataLoader for YamlLoader {
 
                         // Apply class filtering if specified in options
                         if let Some(ref target_class) = options.target_class
-                            && instance.class_name != *target_class
+                            && !is_instance_of(schema, &instance.class_name, target_class)
                         {
                             continue;
                         }
@@ -120,7 +124,7 @@ impl DataLoader for YamlLoader {
 
                 // Apply class filtering if specified in options
                 if let Some(ref target_class) = options.target_class
-                    && instance.class_name != *target_class
+                    && !is_instance_of(schema, &instance.class_name, target_class)
                 {
                     return Ok(vec![]);
                 }
@@ -138,7 +142,16 @@ impl DataLoader for YamlLoader {
             }
         };
 
-        Ok(instances)
+        let expander = InlinedExpander::new(schema);
+        let instances = instances
+            .into_iter()
+            .map(|mut instance| {
+                expander.expand_instance(&mut instance);
+                instance
+            })
+            .collect();
+
+        apply_normalizers(instances, schema, options)
     }
 
     async fn load_bytes(
@@ -286,9 +299,12 @@ impl YamlLoader {
         obj: Map<String, Value>,
         schema: &SchemaDefinition,
     ) -> LoaderResult<DataInstance> {
-        // Try to determine class from @type field or structure
+        // Try to determine class from @type field, type designator or structure
+        let designated = TypeDesignator::new(schema).infer_class(&obj);
         let class_name = if let Some(Value::String(type_val)) = obj.get("@type") {
             type_val.clone()
+        } else if let Some(designated) = designated {
+            designated.to_string()
         } else if let Some(Value::String(type_val)) = obj.get("type") {
             type_val.clone()
         } else {
@@ -391,9 +407,19 @@ impl DataDumper for YamlDumper {
     async fn dump_string(
         &self,
         instances: &[DataInstance],
-        _schema: &SchemaDefinition,
-        _options: &DumpOptions,
+        schema: &SchemaDefinition,
+        options: &DumpOptions,
     ) -> DumperResult<String> {
+        if options.canonical {
+            let mut canonical = Canonicalizer::new(schema, options).instances(instances)?;
+            let yaml = if canonical.len() == 1 {
+                serde_yaml::to_string(&canonical.remove(0))
+            } else {
+                serde_yaml::to_string(&canonical)
+            };
+            return yaml.map_err(|e| DumperError::Serialization(e.to_string()));
+        }
+
         let yaml_instances: std::result::Result<Vec<serde_yaml::Value>, DumperError> = instances
             .iter()
             .map(|instance| {
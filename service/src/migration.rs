@@ -902,6 +902,7 @@ where
                     mixin: None,
                     is_a: None,
                     mixins: vec![],
+                    attribute_groups: vec![],
                     slots: vec![],
                     slot_usage: IndexMap::new(),
                     attributes: IndexMap::new(),
@@ -919,6 +920,9 @@ where
                     todos: vec![],
                     see_also: vec![],
                     deprecated: None,
+                    deprecated_element_has_exact_replacement: None,
+                    deprecated_element_has_possible_replacement: None,
+                    status: None,
                     examples: vec![],
                     exact_mappings: vec![],
                     close_mappings: vec![],
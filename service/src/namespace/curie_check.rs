@@ -0,0 +1,185 @@
+//! Checks that the CURIEs of a schema resolve
+//!
+//! The `class_uri`, `slot_uri`, `*_mappings` and permissible value `meaning`
+//! of a schema (see [`term_references`]) must each be an absolute URI, a
+//! plain name resolved against the default prefix, or a CURIE whose prefix
+//! the schema declares or the [`CurieResolver`] has built in. When an
+//! undeclared prefix is known to prefixcommons (see [`super::prefixcommons`])
+//! or the prefix registry of the resolver, the declaration to add is
+//! suggested.
+
+use std::fmt;
+
+use linkml_core::types::SchemaDefinition;
+
+use super::curie_resolver::CurieResolver;
+use super::curie_resolver::utils::{is_absolute_uri, split_curie};
+use super::prefix_registry::PrefixExpansion;
+use crate::ontology::{TermReference, term_references};
+
+/// Why a URI or CURIE of a schema does not resolve
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CurieProblem {
+    /// Neither an absolute URI, a plain name nor a well-formed CURIE
+    Malformed,
+    /// A CURIE whose prefix is not declared
+    UndeclaredPrefix {
+        /// The prefix as written
+        prefix: String,
+        /// Expansion of the prefix according to a prefix registry, if known
+        expansion: Option<PrefixExpansion>,
+    },
+}
+
+/// A URI or CURIE of a schema that does not resolve
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurieIssue {
+    /// Where the value occurs
+    pub reference: TermReference,
+    /// Why it does not resolve
+    pub problem: CurieProblem,
+}
+
+impl CurieIssue {
+    /// The prefix declaration that resolves the value, as `(prefix, expansion)`
+    #[must_use]
+    pub fn declaration(&self) -> Option<(&str, &str)> {
+        match &self.problem {
+            CurieProblem::UndeclaredPrefix {
+                prefix,
+                expansion: Some(known),
+            } => Some((prefix, &known.expansion)),
+            _ => None,
+        }
+    }
+
+    /// How to resolve the value
+    #[must_use]
+    pub fn suggestion(&self) -> String {
+        match (&self.problem, self.declaration()) {
+            (CurieProblem::Malformed, _) => {
+                "Use an absolute URI or a CURIE of the form 'prefix:local_id'".to_string()
+            }
+            (_, Some((prefix, expansion))) => {
+                format!("Declare prefix '{prefix}' as '{expansion}'")
+            }
+            (CurieProblem::UndeclaredPrefix { prefix, .. }, None) => {
+                format!("Declare prefix '{prefix}' under `prefixes`")
+            }
+        }
+    }
+}
+
+impl fmt::Display for CurieIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reference = &self.reference;
+        write!(
+            f,
+            "{} of {} '{}' ",
+            reference.field,
+            reference.element_type.replace('_', " "),
+            reference.element_name
+        )?;
+        match &self.problem {
+            CurieProblem::Malformed => {
+                write!(f, "is not a valid URI or CURIE: '{}'", reference.term)
+            }
+            CurieProblem::UndeclaredPrefix { prefix, .. } => write!(
+                f,
+                "uses undeclared prefix '{prefix}' in '{}'",
+                reference.term
+            ),
+        }
+    }
+}
+
+/// URIs and CURIEs of `schema` that do not resolve against its own prefixes
+#[must_use]
+pub fn check_curies(schema: &SchemaDefinition) -> Vec<CurieIssue> {
+    check_curies_with(schema, &CurieResolver::from_schema(schema))
+}
+
+/// URIs and CURIEs of `schema` that do not resolve against `resolver`
+///
+/// Use this to check the elements of a schema against the prefixes of the
+/// schema with its imports merged in, or to suggest declarations from the
+/// prefix registry set on `resolver`. Prefixes the registry resolves are
+/// still reported, as the schema does not declare them.
+#[must_use]
+pub fn check_curies_with(schema: &SchemaDefinition, resolver: &CurieResolver) -> Vec<CurieIssue> {
+    term_references(schema)
+        .into_iter()
+        .filter_map(|reference| {
+            let problem = curie_problem(&reference.term, resolver)?;
+            Some(CurieIssue { reference, problem })
+        })
+        .collect()
+}
+
+fn curie_problem(term: &str, resolver: &CurieResolver) -> Option<CurieProblem> {
+    if is_absolute_uri(term) {
+        return None;
+    }
+    if let Some((prefix, _)) = split_curie(term) {
+        if resolver.get_prefix(prefix).is_some() {
+            return None;
+        }
+        return Some(CurieProblem::UndeclaredPrefix {
+            prefix: prefix.to_string(),
+            expansion: resolver.suggest_prefix(prefix),
+        });
+    }
+    let is_name = !term.is_empty() && !term.contains(':') && !term.contains(char::is_whitespace);
+    (!is_name).then_some(CurieProblem::Malformed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = "\
+id: https://example.org/processes
+name: processes
+default_prefix: ex
+prefixes:
+  ex: https://example.org/processes/
+classes:
+  Process:
+    class_uri: ex:Process
+    exact_mappings: [GO:0008150, 'GO 0008150']
+    close_mappings: [https://example.org/other/Process]
+slots:
+  part_of:
+    slot_uri: BFO:0000050
+    exact_mappings: [skos:broader, myonto:partOf, part_of]
+";
+
+    #[test]
+    fn test_check_curies() {
+        let schema: SchemaDefinition = serde_yaml::from_str(SCHEMA).expect("valid schema");
+        let issues = check_curies(&schema);
+        let messages: Vec<String> = issues.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "exact_mappings of class 'Process' uses undeclared prefix 'GO' in 'GO:0008150'",
+                "exact_mappings of class 'Process' is not a valid URI or CURIE: 'GO 0008150'",
+                "slot_uri of slot 'part_of' uses undeclared prefix 'BFO' in 'BFO:0000050'",
+                "exact_mappings of slot 'part_of' uses undeclared prefix 'myonto' in 'myonto:partOf'",
+            ]
+        );
+        assert_eq!(
+            issues[0].declaration(),
+            Some(("GO", "http://purl.obolibrary.org/obo/GO_"))
+        );
+        assert_eq!(
+            issues[0].suggestion(),
+            "Declare prefix 'GO' as 'http://purl.obolibrary.org/obo/GO_'"
+        );
+        assert_eq!(issues[3].declaration(), None);
+
+        let mut resolver = CurieResolver::from_schema(&schema);
+        resolver.add_prefix("GO", "http://purl.obolibrary.org/obo/GO_");
+        assert_eq!(check_curies_with(&schema, &resolver).len(), 3);
+    }
+}
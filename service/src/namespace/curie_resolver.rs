@@ -2,10 +2,17 @@
 //!
 //! This module provides comprehensive CURIE/URI resolution matching
 //! the Kapernikov `LinkML` implementation's namespace handling.
+//!
+//! With a [`PrefixRegistry`] attached, CURIEs whose prefix is not declared
+//! expand through the registry (bundled prefixcommons prefixes, optionally
+//! prefix.cc) instead of being returned as-is or failing in strict mode.
 
+use super::prefix_registry::{PrefixExpansion, PrefixRegistry, bundled_expansion};
 use linkml_core::prelude::*;
 use regex::Regex;
 use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::debug;
 
 /// Regular expression for valid CURIE format
 static CURIE_REGEX: std::sync::LazyLock<Regex> = std::sync::LazyLock::new(|| {
@@ -36,6 +43,9 @@ pub struct CurieResolver {
 
     /// Strict mode - fail on unknown prefixes
     strict: bool,
+
+    /// Registry resolving prefixes that are not declared
+    registry: Option<Arc<PrefixRegistry>>,
 }
 
 impl Default for CurieResolver {
@@ -54,6 +64,7 @@ impl CurieResolver {
             default_prefix: None,
             base_uri: None,
             strict: false,
+            registry: None,
         };
 
         // Add standard prefixes
@@ -126,6 +137,23 @@ impl CurieResolver {
         self.strict = strict;
     }
 
+    /// Resolve undeclared prefixes through `registry`
+    pub fn set_registry(&mut self, registry: Arc<PrefixRegistry>) {
+        self.registry = Some(registry);
+    }
+
+    /// Suggested expansion of an undeclared prefix
+    ///
+    /// Asks the registry when one is set, and the bundled prefixcommons
+    /// prefixes otherwise.
+    #[must_use]
+    pub fn suggest_prefix(&self, prefix: &str) -> Option<PrefixExpansion> {
+        match &self.registry {
+            Some(registry) => registry.lookup(prefix),
+            None => bundled_expansion(prefix),
+        }
+    }
+
     /// Check if a string is a CURIE
     pub fn is_curie(&self, s: &str) -> bool {
         CURIE_REGEX.is_match(s)
@@ -140,8 +168,9 @@ impl CurieResolver {
     ///
     /// # Errors
     ///
-    /// Returns an error when the prefix in the provided CURIE is unknown and
-    /// the resolver is configured to operate in strict mode.
+    /// Returns an error when the prefix in the provided CURIE is unknown,
+    /// including to the registry if one is set, and the resolver is
+    /// configured to operate in strict mode.
     pub fn expand_curie(&self, curie: &str) -> Result<String> {
         // Check if it's already a URI
         if self.is_uri(curie) {
@@ -162,8 +191,26 @@ impl CurieResolver {
             // Look up the prefix
             if let Some(uri_base) = self.prefixes.get(prefix) {
                 return Ok(format!("{uri_base}{local}"));
-            } else if self.strict {
-                return Err(LinkMLError::service(format!("Unknown prefix: {prefix}")));
+            }
+            if let Some(registry) = &self.registry
+                && let Some(known) = registry.lookup(prefix)
+            {
+                debug!(
+                    "Resolved undeclared prefix '{prefix}' to '{}' from {}",
+                    known.expansion, known.source
+                );
+                return Ok(format!("{}{local}", known.expansion));
+            }
+            if self.strict {
+                let hint = self
+                    .suggest_prefix(prefix)
+                    .map(|known| {
+                        format!("; declare it as '{}' ({})", known.expansion, known.source)
+                    })
+                    .unwrap_or_default();
+                return Err(LinkMLError::service(format!(
+                    "Unknown prefix: {prefix}{hint}"
+                )));
             }
             // In non-strict mode, return as-is
             return Ok(curie.to_string());
@@ -466,6 +513,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_registry_resolution() {
+        let mut resolver = CurieResolver::new();
+        resolver.set_strict(true);
+        let error = resolver
+            .expand_curie("GO:0008150")
+            .expect_err("GO is not declared");
+        assert!(
+            error
+                .to_string()
+                .contains("declare it as 'http://purl.obolibrary.org/obo/GO_' (prefixcommons)")
+        );
+        assert_eq!(
+            resolver.suggest_prefix("GO").map(|known| known.expansion),
+            Some("http://purl.obolibrary.org/obo/GO_".to_string())
+        );
+
+        resolver.set_registry(Arc::new(PrefixRegistry::bundled()));
+        assert_eq!(
+            resolver
+                .expand_curie("GO:0008150")
+                .expect("Should resolve through the registry"),
+            "http://purl.obolibrary.org/obo/GO_0008150"
+        );
+        assert!(resolver.expand_curie("myonto:Thing").is_err());
+    }
+
     #[test]
     fn test_namespace_context() {
         let mut resolver = CurieResolver::new();
@@ -1,10 +1,16 @@
 //! Namespace and CURIE management for LinkML
 //!
 //! This module provides comprehensive namespace handling including
-//! CURIE expansion/contraction, URI resolution, and namespace contexts.
+//! CURIE expansion/contraction, URI resolution, namespace contexts, checks
+//! of the CURIEs of a schema and registries of well-known prefixes.
 
+pub mod curie_check;
 pub mod curie_resolver;
+pub mod prefix_registry;
+pub mod prefixcommons;
 
+pub use curie_check::{CurieIssue, CurieProblem, check_curies, check_curies_with};
+pub use prefix_registry::{PREFIX_CC_URL, PrefixExpansion, PrefixRegistry, PrefixSource};
 pub use curie_resolver::{
     CurieResolver, NamespaceContext,
     utils::{is_absolute_uri, join_uri, local_from_uri, make_curie, split_curie},
@@ -0,0 +1,312 @@
+//! Registries of well-known prefixes for resolving undeclared prefixes
+//!
+//! A [`PrefixRegistry`] knows the expansion of prefixes a schema does not
+//! declare: the bundled prefixcommons subset (see [`super::prefixcommons`])
+//! and, optionally, the community registry at [prefix.cc](https://prefix.cc).
+//! prefix.cc answers are cached in memory and, with a cache directory, in a
+//! JSON file that later runs reuse. In offline mode the registry never
+//! touches the network and only answers from the bundled prefixes and that
+//! cache.
+//!
+//! Attached to a [`CurieResolver`](super::CurieResolver) with
+//! [`set_registry`](super::CurieResolver::set_registry), the registry lets
+//! CURIEs with undeclared prefixes expand instead of failing in strict mode.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use linkml_core::error::{LinkMLError, Result};
+use parking_lot::RwLock;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{debug, warn};
+
+use super::prefixcommons;
+use crate::ontology::ols::block_on;
+use crate::schema::mapping_suggest::http_client;
+
+/// Base URL of the public prefix.cc service
+pub const PREFIX_CC_URL: &str = "https://prefix.cc";
+
+/// File name of the on-disk cache within the cache directory
+const CACHE_FILE: &str = "prefix-cc-cache.json";
+
+/// Where the expansion of a prefix comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrefixSource {
+    /// The bundled prefixcommons subset
+    Prefixcommons,
+    /// The prefix.cc registry
+    PrefixCc,
+}
+
+impl fmt::Display for PrefixSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Prefixcommons => write!(f, "prefixcommons"),
+            Self::PrefixCc => write!(f, "prefix.cc"),
+        }
+    }
+}
+
+/// Expansion of a prefix found in a registry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrefixExpansion {
+    /// The prefix as registered, which may differ in case from the one
+    /// looked up
+    pub prefix: String,
+    /// The namespace URI the prefix expands to
+    pub expansion: String,
+    /// The registry that knows the prefix
+    pub source: PrefixSource,
+}
+
+/// Cached prefix.cc answers
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct PrefixCache {
+    /// Expansion of each prefix looked up, `None` for prefixes prefix.cc
+    /// does not know
+    #[serde(default)]
+    prefixes: HashMap<String, Option<String>>,
+}
+
+/// Registry of well-known prefixes
+pub struct PrefixRegistry {
+    base_url: Option<String>,
+    client: Client,
+    offline: bool,
+    cache_file: Option<PathBuf>,
+    cache: RwLock<PrefixCache>,
+}
+
+impl fmt::Debug for PrefixRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrefixRegistry")
+            .field("base_url", &self.base_url)
+            .field("offline", &self.offline)
+            .field("cache_file", &self.cache_file)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PrefixRegistry {
+    /// Create a registry of the bundled prefixcommons prefixes only
+    #[must_use]
+    pub fn bundled() -> Self {
+        Self {
+            base_url: None,
+            client: http_client(),
+            offline: false,
+            cache_file: None,
+            cache: RwLock::new(PrefixCache::default()),
+        }
+    }
+
+    /// Look up prefixes missing from the bundled ones on prefix.cc
+    #[must_use]
+    pub fn with_prefix_cc(self) -> Self {
+        self.with_base_url(PREFIX_CC_URL)
+    }
+
+    /// Look up prefixes missing from the bundled ones on a prefix.cc mirror
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into().trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Persist prefix.cc answers in `dir`, loading the answers cached there
+    /// before
+    ///
+    /// An unreadable cache file is ignored with a warning.
+    #[must_use]
+    pub fn with_cache_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        let file = dir.as_ref().join(CACHE_FILE);
+        match std::fs::read_to_string(&file) {
+            Ok(content) => match serde_json::from_str(&content) {
+                Ok(cache) => self.cache = RwLock::new(cache),
+                Err(e) => warn!("Ignoring invalid prefix cache {}: {e}", file.display()),
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => warn!("Ignoring unreadable prefix cache {}: {e}", file.display()),
+        }
+        self.cache_file = Some(file);
+        self
+    }
+
+    /// Persist prefix.cc answers in the user cache directory
+    /// (`~/.cache/linkml/prefixes` on Linux)
+    #[must_use]
+    pub fn with_default_cache_dir(self) -> Self {
+        match dirs::cache_dir() {
+            Some(dir) => self.with_cache_dir(dir.join("linkml").join("prefixes")),
+            None => self,
+        }
+    }
+
+    /// Answer only from the bundled prefixes and the cache, without network
+    /// access
+    #[must_use]
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Expansion of `prefix`, from the bundled prefixes or else prefix.cc
+    ///
+    /// prefix.cc is only asked when enabled, and not in offline mode unless
+    /// the answer is cached. A failing request is logged and treated as an
+    /// unknown prefix; use [`Self::lookup_remote`] to handle it.
+    #[must_use]
+    pub fn lookup(&self, prefix: &str) -> Option<PrefixExpansion> {
+        if let Some(known) = bundled_expansion(prefix) {
+            return Some(known);
+        }
+        self.base_url.as_ref()?;
+        let cached = self.cache.read().prefixes.get(prefix).cloned();
+        let expansion = match cached {
+            Some(cached) => cached,
+            None if self.offline => None,
+            None => block_on(self.lookup_remote(prefix)).unwrap_or_else(|e| {
+                debug!("Cannot look up prefix '{prefix}': {e}");
+                None
+            }),
+        }?;
+        Some(PrefixExpansion {
+            prefix: prefix.to_string(),
+            expansion,
+            source: PrefixSource::PrefixCc,
+        })
+    }
+
+    /// Expansion of `prefix` according to prefix.cc
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if prefix.cc is not enabled, or if the answer is not
+    /// cached and the registry is offline or the request fails.
+    pub async fn lookup_remote(&self, prefix: &str) -> Result<Option<String>> {
+        let Some(base_url) = &self.base_url else {
+            return Err(LinkMLError::config("prefix.cc lookups are not enabled"));
+        };
+        if let Some(cached) = self.cache.read().prefixes.get(prefix) {
+            return Ok(cached.clone());
+        }
+        if self.offline {
+            return Err(LinkMLError::config(format!(
+                "Prefix '{prefix}' is not in the offline prefix cache"
+            )));
+        }
+        if !prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+        {
+            return Ok(None);
+        }
+
+        let url = format!("{base_url}/{prefix}.file.json");
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| LinkMLError::service(format!("Request to {url} failed: {e}")))?;
+        let status = response.status();
+        let expansion = if status == StatusCode::NOT_FOUND {
+            None
+        } else if status.is_success() {
+            let body: Value = response.json().await.map_err(|e| {
+                LinkMLError::deserialization(format!("Invalid response from {url}: {e}"))
+            })?;
+            body.get(prefix).and_then(Value::as_str).map(str::to_string)
+        } else {
+            return Err(LinkMLError::service(format!(
+                "Request to {url} failed with status {status}"
+            )));
+        };
+
+        self.cache
+            .write()
+            .prefixes
+            .insert(prefix.to_string(), expansion.clone());
+        self.persist();
+        Ok(expansion)
+    }
+
+    fn persist(&self) {
+        let Some(file) = &self.cache_file else {
+            return;
+        };
+        let result = file
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| {
+                let json = serde_json::to_string(&*self.cache.read())?;
+                std::fs::write(file, json)
+            });
+        if let Err(e) = result {
+            warn!("Failed to write prefix cache {}: {e}", file.display());
+        }
+    }
+}
+
+impl Default for PrefixRegistry {
+    fn default() -> Self {
+        Self::bundled()
+    }
+}
+
+/// Expansion of `prefix` among the bundled prefixcommons prefixes
+pub(crate) fn bundled_expansion(prefix: &str) -> Option<PrefixExpansion> {
+    let (registered, expansion) = prefixcommons::lookup(prefix)?;
+    Some(PrefixExpansion {
+        prefix: registered.to_string(),
+        expansion: expansion.to_string(),
+        source: PrefixSource::Prefixcommons,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offline_lookup() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let cache = serde_json::json!({
+            "prefixes": {"dbo": "http://dbpedia.org/ontology/", "nope": null}
+        });
+        std::fs::write(dir.path().join(CACHE_FILE), cache.to_string()).expect("write cache");
+
+        let registry = PrefixRegistry::bundled()
+            .with_base_url("http://localhost:1")
+            .with_cache_dir(dir.path())
+            .offline(true);
+        let go = registry.lookup("GO").expect("bundled prefix");
+        assert_eq!(go.expansion, "http://purl.obolibrary.org/obo/GO_");
+        assert_eq!(go.source, PrefixSource::Prefixcommons);
+
+        let dbo = registry.lookup("dbo").expect("cached prefix");
+        assert_eq!(dbo.expansion, "http://dbpedia.org/ontology/");
+        assert_eq!(dbo.source, PrefixSource::PrefixCc);
+        assert_eq!(registry.lookup("nope"), None);
+        assert_eq!(registry.lookup("uncached"), None);
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("runtime");
+        assert!(
+            runtime
+                .block_on(registry.lookup_remote("uncached"))
+                .is_err()
+        );
+        assert!(
+            runtime
+                .block_on(PrefixRegistry::bundled().lookup_remote("dbo"))
+                .is_err()
+        );
+    }
+}
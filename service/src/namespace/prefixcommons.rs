@@ -0,0 +1,115 @@
+//! Well-known prefixes from the prefixcommons registry
+//!
+//! A bundled subset of the [prefixcommons](https://github.com/prefixcommons)
+//! contexts (`semweb_context` and `obo_context`), used to suggest a prefix
+//! declaration when a schema uses a CURIE whose prefix it does not declare.
+
+/// Prefix declarations bundled from prefixcommons, as `(prefix, expansion)`
+pub const PREFIXCOMMONS: &[(&str, &str)] = &[
+    // Semantic web vocabularies
+    ("bibo", "http://purl.org/ontology/bibo/"),
+    ("dc", "http://purl.org/dc/elements/1.1/"),
+    ("dcat", "http://www.w3.org/ns/dcat#"),
+    ("dcterms", "http://purl.org/dc/terms/"),
+    ("foaf", "http://xmlns.com/foaf/0.1/"),
+    ("geo", "http://www.w3.org/2003/01/geo/wgs84_pos#"),
+    ("oboInOwl", "http://www.geneontology.org/formats/oboInOwl#"),
+    ("org", "http://www.w3.org/ns/org#"),
+    ("owl", "http://www.w3.org/2002/07/owl#"),
+    ("pav", "http://purl.org/pav/"),
+    ("prov", "http://www.w3.org/ns/prov#"),
+    ("qudt", "http://qudt.org/schema/qudt/"),
+    ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("schema", "http://schema.org/"),
+    ("sh", "http://www.w3.org/ns/shacl#"),
+    ("sio", "http://semanticscience.org/resource/"),
+    ("skos", "http://www.w3.org/2004/02/skos/core#"),
+    ("time", "http://www.w3.org/2006/time#"),
+    ("vcard", "http://www.w3.org/2006/vcard/ns#"),
+    ("void", "http://rdfs.org/ns/void#"),
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+    // LinkML
+    ("biolink", "https://w3id.org/biolink/vocab/"),
+    ("linkml", "https://w3id.org/linkml/"),
+    // OBO Foundry ontologies
+    ("BFO", "http://purl.obolibrary.org/obo/BFO_"),
+    ("CHEBI", "http://purl.obolibrary.org/obo/CHEBI_"),
+    ("CL", "http://purl.obolibrary.org/obo/CL_"),
+    ("DOID", "http://purl.obolibrary.org/obo/DOID_"),
+    ("ECO", "http://purl.obolibrary.org/obo/ECO_"),
+    ("ENVO", "http://purl.obolibrary.org/obo/ENVO_"),
+    ("FBbt", "http://purl.obolibrary.org/obo/FBbt_"),
+    ("GO", "http://purl.obolibrary.org/obo/GO_"),
+    ("HP", "http://purl.obolibrary.org/obo/HP_"),
+    ("IAO", "http://purl.obolibrary.org/obo/IAO_"),
+    ("MAXO", "http://purl.obolibrary.org/obo/MAXO_"),
+    ("MONDO", "http://purl.obolibrary.org/obo/MONDO_"),
+    ("MP", "http://purl.obolibrary.org/obo/MP_"),
+    ("NCBITaxon", "http://purl.obolibrary.org/obo/NCBITaxon_"),
+    ("NCIT", "http://purl.obolibrary.org/obo/NCIT_"),
+    ("OBI", "http://purl.obolibrary.org/obo/OBI_"),
+    ("PATO", "http://purl.obolibrary.org/obo/PATO_"),
+    ("PO", "http://purl.obolibrary.org/obo/PO_"),
+    ("PR", "http://purl.obolibrary.org/obo/PR_"),
+    ("RO", "http://purl.obolibrary.org/obo/RO_"),
+    ("SO", "http://purl.obolibrary.org/obo/SO_"),
+    ("UBERON", "http://purl.obolibrary.org/obo/UBERON_"),
+    ("UO", "http://purl.obolibrary.org/obo/UO_"),
+    ("ZFA", "http://purl.obolibrary.org/obo/ZFA_"),
+    // Other identifier namespaces
+    ("DOI", "http://dx.doi.org/"),
+    ("EFO", "http://www.ebi.ac.uk/efo/EFO_"),
+    ("HGNC", "http://identifiers.org/hgnc/"),
+    ("MESH", "http://id.nlm.nih.gov/mesh/"),
+    ("NCBIGene", "http://identifiers.org/ncbigene/"),
+    ("OMIM", "http://omim.org/entry/"),
+    ("ORCID", "https://orcid.org/"),
+    ("Orphanet", "http://www.orpha.net/ORDO/Orphanet_"),
+    ("PMID", "http://www.ncbi.nlm.nih.gov/pubmed/"),
+    ("ROR", "https://ror.org/"),
+    ("UniProtKB", "http://identifiers.org/uniprot/"),
+    ("wikidata", "http://www.wikidata.org/entity/"),
+];
+
+/// Bundled prefixcommons entry for `prefix`, as `(prefix, expansion)`
+///
+/// An exact match is preferred; otherwise the prefix is matched ignoring
+/// case, so `ncbitaxon` finds `NCBITaxon`.
+#[must_use]
+pub fn lookup(prefix: &str) -> Option<(&'static str, &'static str)> {
+    PREFIXCOMMONS
+        .iter()
+        .find(|(known, _)| *known == prefix)
+        .or_else(|| {
+            PREFIXCOMMONS
+                .iter()
+                .find(|(known, _)| known.eq_ignore_ascii_case(prefix))
+        })
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(
+            lookup("GO"),
+            Some(("GO", "http://purl.obolibrary.org/obo/GO_"))
+        );
+        assert_eq!(
+            lookup("ncbitaxon").map(|(prefix, _)| prefix),
+            Some("NCBITaxon")
+        );
+        assert_eq!(lookup("no_such_prefix"), None);
+
+        let unique: HashSet<String> = PREFIXCOMMONS
+            .iter()
+            .map(|(prefix, _)| prefix.to_lowercase())
+            .collect();
+        assert_eq!(unique.len(), PREFIXCOMMONS.len(), "prefixes are unique");
+    }
+}
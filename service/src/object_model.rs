@@ -0,0 +1,758 @@
+//! Typed access to validated instance data
+//!
+//! Validated instances are plain `serde_json::Value`s, which leaves
+//! application code poking at JSON with string keys and guessing types.
+//! [`ObjectModel`] holds validated instances together with their schema and
+//! hands out [`TypedInstance`]s that read slots the way the schema defines
+//! them:
+//!
+//! - [`TypedInstance::get_slot`] resolves the slot for the instance's class
+//!   (inheritance, mixins, attributes and `slot_usage`) and returns a
+//!   [`SlotValue`] typed by the slot's range
+//! - [`TypedInstance::get`] coerces a slot to a Rust type such as `i64`,
+//!   `chrono::NaiveDate` or `Vec<String>`
+//! - [`TypedInstance::follow`] navigates to inlined objects and resolves
+//!   references to other instances by identifier
+//!
+//! ```rust,no_run
+//! # use linkml_service::object_model::ObjectModel;
+//! # async fn example(schema: linkml_core::types::SchemaDefinition, data: serde_json::Value) -> linkml_core::error::Result<()> {
+//! let mut model = ObjectModel::new(schema)?;
+//! let id = model.insert("Person", data).await?;
+//! if let Some(person) = id.as_deref().and_then(|id| model.get(id)) {
+//!     let age: Option<i64> = person.get("age")?;
+//!     for employer in person.follow("employed_by")? {
+//!         println!("{:?} works for {:?}", age, employer.id());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::schema_view::SchemaView;
+use crate::validator::engine::ValidationEngine;
+use chrono::{DateTime, NaiveDate, Utc};
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::{SchemaDefinition, SlotDefinition};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Validated instances with schema-aware access
+pub struct ObjectModel {
+    view: SchemaView,
+    engine: ValidationEngine,
+    default_range: String,
+    entries: Vec<Entry>,
+    index: HashMap<String, usize>,
+}
+
+struct Entry {
+    class_name: String,
+    value: Value,
+    root: bool,
+}
+
+impl ObjectModel {
+    /// Create an empty object model for a schema
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema's imports cannot be resolved or its
+    /// validators cannot be built
+    pub fn new(schema: SchemaDefinition) -> Result<Self> {
+        let engine = ValidationEngine::new(&schema)?;
+        let default_range = schema
+            .default_range
+            .clone()
+            .unwrap_or_else(|| "string".to_string());
+        Ok(Self {
+            view: SchemaView::new(schema)?,
+            engine,
+            default_range,
+            entries: Vec::new(),
+            index: HashMap::new(),
+        })
+    }
+
+    /// The schema view used to resolve slots
+    pub fn schema_view(&self) -> &SchemaView {
+        &self.view
+    }
+
+    /// Validate an instance of `class_name` and add it to the model
+    ///
+    /// Returns the instance's identifier, if its class has one. Inlined
+    /// objects with identifiers are indexed as well, so references to them
+    /// can be followed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a data validation error if the instance is invalid, or if its
+    /// identifier is already in use
+    pub async fn insert(&mut self, class_name: &str, value: Value) -> Result<Option<String>> {
+        let report = self
+            .engine
+            .validate_as_class(&value, class_name, None)
+            .await?;
+        if !report.valid {
+            let messages: Vec<&str> = report.errors().map(|e| e.message.as_str()).collect();
+            return Err(LinkMLError::data_validation(format!(
+                "Invalid {class_name} instance: {}",
+                messages.join("; ")
+            )));
+        }
+        self.insert_unchecked(class_name, value)
+    }
+
+    /// Add an instance that has already been validated
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `class_name` is not in the schema or the
+    /// instance's identifier is already in use
+    pub fn insert_unchecked(&mut self, class_name: &str, value: Value) -> Result<Option<String>> {
+        let id = self.index_nested(class_name, &value)?;
+        self.push(class_name, value, true, id.clone())?;
+        Ok(id)
+    }
+
+    /// Wrap a value as an instance of `class_name` without adding it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `class_name` is not in the schema
+    pub fn instance<'m>(&'m self, class_name: &str, value: &'m Value) -> Result<TypedInstance<'m>> {
+        if self.view.get_class(class_name)?.is_none() {
+            return Err(LinkMLError::schema_validation(format!(
+                "Class '{class_name}' is not defined in the schema"
+            )));
+        }
+        Ok(TypedInstance {
+            model: self,
+            class_name: class_name.to_string(),
+            value,
+        })
+    }
+
+    /// Instance with the given identifier
+    pub fn get(&self, id: &str) -> Option<TypedInstance<'_>> {
+        self.index.get(id).map(|&i| self.entry(i))
+    }
+
+    /// Instances added with [`Self::insert`], in insertion order
+    pub fn instances(&self) -> impl Iterator<Item = TypedInstance<'_>> {
+        (0..self.entries.len())
+            .filter(|&i| self.entries[i].root)
+            .map(|i| self.entry(i))
+    }
+
+    /// Added instances of `class_name` or one of its subclasses
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class hierarchy cannot be resolved
+    pub fn instances_of(&self, class_name: &str) -> Result<Vec<TypedInstance<'_>>> {
+        let mut classes = self.view.class_descendants(class_name)?;
+        classes.push(class_name.to_string());
+        Ok(self
+            .instances()
+            .filter(|instance| classes.contains(&instance.class_name))
+            .collect())
+    }
+
+    fn entry(&self, i: usize) -> TypedInstance<'_> {
+        let entry = &self.entries[i];
+        TypedInstance {
+            model: self,
+            class_name: entry.class_name.clone(),
+            value: &entry.value,
+        }
+    }
+
+    fn push(
+        &mut self,
+        class_name: &str,
+        value: Value,
+        root: bool,
+        id: Option<String>,
+    ) -> Result<()> {
+        if let Some(id) = id {
+            if self.index.contains_key(&id) {
+                return Err(LinkMLError::data_validation(format!(
+                    "Duplicate identifier '{id}' for {class_name} instance"
+                )));
+            }
+            self.index.insert(id, self.entries.len());
+        }
+        self.entries.push(Entry {
+            class_name: class_name.to_string(),
+            value,
+            root,
+        });
+        Ok(())
+    }
+
+    /// Index inlined objects below `value`; returns the identifier of `value`
+    fn index_nested(&mut self, class_name: &str, value: &Value) -> Result<Option<String>> {
+        let Some(object) = value.as_object() else {
+            return Ok(None);
+        };
+        for slot_name in self.class_slot_names(class_name)? {
+            let slot = self.resolve_slot(class_name, &slot_name)?;
+            let range = slot.range.as_deref().unwrap_or(&self.default_range);
+            if self.view.get_class(range)?.is_none() {
+                continue;
+            }
+            let range = range.to_string();
+            let Some(value) = object.get(&slot_name) else {
+                continue;
+            };
+            let nested: Vec<&Value> = if slot.multivalued.unwrap_or(false) {
+                values_of(value).collect()
+            } else {
+                vec![value]
+            };
+            for nested in nested {
+                if nested.is_object() {
+                    let id = self.index_nested(&range, nested)?;
+                    if id.is_some() {
+                        self.push(&range, nested.clone(), false, id)?;
+                    }
+                }
+            }
+        }
+        self.identifier_of(class_name, object)
+    }
+
+    fn identifier_of(
+        &self,
+        class_name: &str,
+        object: &serde_json::Map<String, Value>,
+    ) -> Result<Option<String>> {
+        let mut identifier = None;
+        for slot_name in self.class_slot_names(class_name)? {
+            if self.resolve_slot(class_name, &slot_name)?.identifier == Some(true) {
+                identifier = Some(slot_name);
+                break;
+            }
+        }
+        let Some(slot) = identifier else {
+            return Ok(None);
+        };
+        Ok(object.get(&slot).map(|id| match id {
+            Value::String(id) => id.clone(),
+            other => other.to_string(),
+        }))
+    }
+
+    /// Slots of a class, including inherited slots and attributes
+    fn class_slot_names(&self, class_name: &str) -> Result<Vec<String>> {
+        let induced = self.view.induced_class(class_name)?;
+        let mut names = induced.slots;
+        for name in induced.attributes.into_keys() {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        Ok(names)
+    }
+
+    /// Slot definition in the context of a class
+    fn resolve_slot(&self, class_name: &str, slot_name: &str) -> Result<SlotDefinition> {
+        let induced = self.view.induced_class(class_name)?;
+        if let Some(attribute) = induced.attributes.get(slot_name) {
+            let mut slot = attribute.clone();
+            if let Some(usage) = induced.slot_usage.get(slot_name) {
+                if usage.range.is_some() {
+                    slot.range.clone_from(&usage.range);
+                }
+                if usage.multivalued.is_some() {
+                    slot.multivalued = usage.multivalued;
+                }
+            }
+            return Ok(slot);
+        }
+        if !induced.slots.iter().any(|name| name == slot_name) {
+            return Err(LinkMLError::schema_validation(format!(
+                "Slot '{slot_name}' is not applicable to class '{class_name}'"
+            )));
+        }
+        self.view.induced_slot(slot_name, class_name)
+    }
+
+    /// Built-in type a range maps to, following `typeof` chains
+    fn primitive_of(&self, range: &str) -> Result<Primitive> {
+        for name in self.view.type_ancestors(range, true)? {
+            if let Some(primitive) = Primitive::from_type_name(&name) {
+                return Ok(primitive);
+            }
+        }
+        Ok(Primitive::String)
+    }
+}
+
+impl fmt::Debug for ObjectModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ObjectModel")
+            .field("instances", &self.entries.len())
+            .field("identifiers", &self.index.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An instance of a schema class with typed slot access
+#[derive(Clone)]
+pub struct TypedInstance<'m> {
+    model: &'m ObjectModel,
+    class_name: String,
+    value: &'m Value,
+}
+
+impl<'m> TypedInstance<'m> {
+    /// Class the instance is read as
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// The underlying JSON value
+    pub fn value(&self) -> &'m Value {
+        self.value
+    }
+
+    /// Value of the class's identifier slot, if any
+    pub fn id(&self) -> Option<String> {
+        let object = self.value.as_object()?;
+        self.model
+            .identifier_of(&self.class_name, object)
+            .ok()
+            .flatten()
+    }
+
+    /// Slots applicable to the instance's class, including inherited ones
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class cannot be resolved
+    pub fn slot_names(&self) -> Result<Vec<String>> {
+        self.model.class_slot_names(&self.class_name)
+    }
+
+    /// Typed value of a slot, or `None` if it is absent or null
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slot is not applicable to the class or the
+    /// value does not match the slot's range
+    pub fn get_slot(&self, slot_name: &str) -> Result<Option<SlotValue<'m>>> {
+        let slot = self.model.resolve_slot(&self.class_name, slot_name)?;
+        let value = match self.value.get(slot_name) {
+            None | Some(Value::Null) => return Ok(None),
+            Some(value) => value,
+        };
+        let range = slot.range.as_deref().unwrap_or(&self.model.default_range);
+        let range = self.range_kind(range)?;
+
+        if slot.multivalued.unwrap_or(false) {
+            let items = values_of(value)
+                .map(|item| self.typed(slot_name, &range, item))
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(Some(SlotValue::List(items)));
+        }
+        self.typed(slot_name, &range, value).map(Some)
+    }
+
+    /// Slot value coerced to a Rust type, or `None` if absent or null
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slot is not applicable to the class or the
+    /// value cannot be converted to `T`
+    pub fn get<T: FromSlotValue<'m>>(&self, slot_name: &str) -> Result<Option<T>> {
+        self.get_slot(slot_name)?
+            .map(T::from_slot_value)
+            .transpose()
+    }
+
+    /// Slot value coerced to a Rust type, failing if it is absent
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slot is absent, not applicable to the class,
+    /// or cannot be converted to `T`
+    pub fn require<T: FromSlotValue<'m>>(&self, slot_name: &str) -> Result<T> {
+        self.get(slot_name)?.ok_or_else(|| {
+            LinkMLError::data_validation(format!(
+                "{} instance has no value for '{slot_name}'",
+                self.class_name
+            ))
+        })
+    }
+
+    /// Objects a class-ranged slot points to, inlined or referenced
+    ///
+    /// References are resolved by identifier against the instances in the
+    /// model. Returns an empty list if the slot is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slot's range is not a class or a referenced
+    /// identifier is not in the model
+    pub fn follow(&self, slot_name: &str) -> Result<Vec<TypedInstance<'m>>> {
+        let mut objects = Vec::new();
+        let values = match self.get_slot(slot_name)? {
+            None => return Ok(objects),
+            Some(SlotValue::List(items)) => items,
+            Some(value) => vec![value],
+        };
+        for value in values {
+            match value {
+                SlotValue::Object(object) => objects.push(object),
+                SlotValue::Reference { class_name, id } => {
+                    let target = self.model.get(id).ok_or_else(|| {
+                        LinkMLError::data_validation(format!(
+                            "Dangling reference '{id}' in {}.{slot_name} (expected {class_name})",
+                            self.class_name
+                        ))
+                    })?;
+                    objects.push(target);
+                }
+                _ => {
+                    return Err(LinkMLError::schema_validation(format!(
+                        "Slot '{slot_name}' of class '{}' does not have a class range",
+                        self.class_name
+                    )));
+                }
+            }
+        }
+        Ok(objects)
+    }
+
+    fn range_kind(&self, range: &str) -> Result<RangeKind> {
+        let view = &self.model.view;
+        Ok(if view.get_class(range)?.is_some() {
+            RangeKind::Class(range.to_string())
+        } else if view.get_enum(range)?.is_some() {
+            RangeKind::Enum
+        } else {
+            RangeKind::Primitive(self.model.primitive_of(range)?)
+        })
+    }
+
+    fn typed(&self, slot_name: &str, range: &RangeKind, value: &'m Value) -> Result<SlotValue<'m>> {
+        let mismatch = |expected: &str| {
+            LinkMLError::coercion(
+                format!("{value} in {}.{slot_name}", self.class_name),
+                expected,
+            )
+        };
+        match range {
+            RangeKind::Class(class_name) => match value {
+                Value::Object(_) => Ok(SlotValue::Object(TypedInstance {
+                    model: self.model,
+                    class_name: class_name.clone(),
+                    value,
+                })),
+                Value::String(id) => Ok(SlotValue::Reference {
+                    class_name: class_name.clone(),
+                    id,
+                }),
+                _ => Err(mismatch(class_name)),
+            },
+            RangeKind::Enum => value
+                .as_str()
+                .map(SlotValue::Enum)
+                .ok_or_else(|| mismatch("permissible value")),
+            RangeKind::Primitive(primitive) => primitive
+                .coerce(value)
+                .ok_or_else(|| mismatch(primitive.name())),
+        }
+    }
+}
+
+impl fmt::Debug for TypedInstance<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedInstance")
+            .field("class_name", &self.class_name)
+            .field("value", self.value)
+            .finish()
+    }
+}
+
+/// A slot value typed by the slot's range
+#[derive(Debug, Clone)]
+pub enum SlotValue<'m> {
+    /// String-like value (`string`, `uri`, `uriorcurie`, ...)
+    String(&'m str),
+    /// `integer` value
+    Integer(i64),
+    /// `float`, `double` or `decimal` value
+    Float(f64),
+    /// `boolean` value
+    Boolean(bool),
+    /// `date` value
+    Date(NaiveDate),
+    /// `datetime` value
+    DateTime(DateTime<Utc>),
+    /// Permissible value of an enum
+    Enum(&'m str),
+    /// Inlined object of a class range
+    Object(TypedInstance<'m>),
+    /// Identifier of an object of a class range
+    Reference {
+        /// Range class
+        class_name: String,
+        /// Identifier of the referenced object
+        id: &'m str,
+    },
+    /// Values of a multivalued slot
+    List(Vec<SlotValue<'m>>),
+}
+
+impl SlotValue<'_> {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::String(_) => "string",
+            Self::Integer(_) => "integer",
+            Self::Float(_) => "float",
+            Self::Boolean(_) => "boolean",
+            Self::Date(_) => "date",
+            Self::DateTime(_) => "datetime",
+            Self::Enum(_) => "enum",
+            Self::Object(_) => "object",
+            Self::Reference { .. } => "reference",
+            Self::List(_) => "list",
+        }
+    }
+}
+
+/// Conversion from a [`SlotValue`] to a Rust type
+pub trait FromSlotValue<'m>: Sized {
+    /// Convert the value
+    ///
+    /// # Errors
+    ///
+    /// Returns a coercion error if the value has a different type
+    fn from_slot_value(value: SlotValue<'m>) -> Result<Self>;
+}
+
+macro_rules! from_slot_value {
+    ($ty:ty, $name:literal, $($pattern:pat => $result:expr),+) => {
+        impl<'m> FromSlotValue<'m> for $ty {
+            fn from_slot_value(value: SlotValue<'m>) -> Result<Self> {
+                match value {
+                    $($pattern => Ok($result),)+
+                    other => Err(LinkMLError::coercion(other.kind(), $name)),
+                }
+            }
+        }
+    };
+}
+
+from_slot_value!(String, "String",
+    SlotValue::String(s) | SlotValue::Enum(s) | SlotValue::Reference { id: s, .. } => s.to_string());
+from_slot_value!(&'m str, "&str",
+    SlotValue::String(s) | SlotValue::Enum(s) | SlotValue::Reference { id: s, .. } => s);
+from_slot_value!(i64, "i64", SlotValue::Integer(n) => n);
+from_slot_value!(f64, "f64", SlotValue::Float(n) => n, SlotValue::Integer(n) => n as f64);
+from_slot_value!(bool, "bool", SlotValue::Boolean(b) => b);
+from_slot_value!(NaiveDate, "NaiveDate", SlotValue::Date(d) => d);
+from_slot_value!(DateTime<Utc>, "DateTime<Utc>", SlotValue::DateTime(d) => d);
+from_slot_value!(TypedInstance<'m>, "TypedInstance", SlotValue::Object(o) => o);
+
+impl<'m, T: FromSlotValue<'m>> FromSlotValue<'m> for Vec<T> {
+    fn from_slot_value(value: SlotValue<'m>) -> Result<Self> {
+        match value {
+            SlotValue::List(items) => items.into_iter().map(T::from_slot_value).collect(),
+            single => Ok(vec![T::from_slot_value(single)?]),
+        }
+    }
+}
+
+enum RangeKind {
+    Class(String),
+    Enum,
+    Primitive(Primitive),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Primitive {
+    String,
+    Integer,
+    Float,
+    Boolean,
+    Date,
+    DateTime,
+}
+
+impl Primitive {
+    fn from_type_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "string" | "str" | "uri" | "uriorcurie" | "curie" | "ncname" | "objectidentifier"
+            | "nodeidentifier" | "jsonpointer" | "jsonpath" | "sparqlpath" => Self::String,
+            "integer" | "int" => Self::Integer,
+            "float" | "double" | "decimal" => Self::Float,
+            "boolean" | "bool" => Self::Boolean,
+            "date" => Self::Date,
+            "datetime" => Self::DateTime,
+            _ => return None,
+        })
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Integer => "integer",
+            Self::Float => "float",
+            Self::Boolean => "boolean",
+            Self::Date => "date",
+            Self::DateTime => "datetime",
+        }
+    }
+
+    /// Read a JSON value as this type, accepting numbers and booleans
+    /// written as strings
+    fn coerce(self, value: &Value) -> Option<SlotValue<'_>> {
+        match (self, value) {
+            (Self::String, Value::String(s)) => Some(SlotValue::String(s)),
+            (Self::Integer, Value::Number(n)) => n.as_i64().map(SlotValue::Integer),
+            (Self::Integer, Value::String(s)) => s.trim().parse().ok().map(SlotValue::Integer),
+            (Self::Float, Value::Number(n)) => n.as_f64().map(SlotValue::Float),
+            (Self::Float, Value::String(s)) => s.trim().parse().ok().map(SlotValue::Float),
+            (Self::Boolean, Value::Bool(b)) => Some(SlotValue::Boolean(*b)),
+            (Self::Boolean, Value::String(s)) => s.trim().parse().ok().map(SlotValue::Boolean),
+            (Self::Date, Value::String(s)) => NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d")
+                .ok()
+                .map(SlotValue::Date),
+            (Self::DateTime, Value::String(s)) => DateTime::parse_from_rfc3339(s.trim())
+                .ok()
+                .map(|dt| SlotValue::DateTime(dt.with_timezone(&Utc))),
+            _ => None,
+        }
+    }
+}
+
+/// Items of a multivalued value; a single value is its own only item
+fn values_of(value: &Value) -> Box<dyn Iterator<Item = &Value> + '_> {
+    match value {
+        Value::Array(items) => Box::new(items.iter()),
+        // Dict-inlined collections are keyed by identifier
+        Value::Object(map) if map.values().all(Value::is_object) && !map.is_empty() => {
+            Box::new(map.values())
+        }
+        other => Box::new(std::iter::once(other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::ClassDefinition;
+    use serde_json::json;
+
+    fn slot(range: &str) -> SlotDefinition {
+        SlotDefinition {
+            range: Some(range.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("people");
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                identifier: Some(true),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert("name".to_string(), slot("string"));
+        schema.slots.insert("age".to_string(), slot("integer"));
+        schema.slots.insert("birth_date".to_string(), slot("date"));
+        schema
+            .slots
+            .insert("employed_by".to_string(), slot("Organization"));
+        schema.classes.insert(
+            "Organization".to_string(),
+            ClassDefinition {
+                name: "Organization".to_string(),
+                slots: vec!["id".to_string(), "name".to_string()],
+                ..Default::default()
+            },
+        );
+        let mut person = ClassDefinition {
+            name: "Person".to_string(),
+            slots: ["id", "name", "age", "birth_date", "employed_by"]
+                .map(String::from)
+                .to_vec(),
+            ..Default::default()
+        };
+        person.attributes.insert(
+            "aliases".to_string(),
+            SlotDefinition {
+                multivalued: Some(true),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert("Person".to_string(), person);
+        schema
+    }
+
+    #[test]
+    fn test_typed_access_and_references() {
+        let mut model = ObjectModel::new(schema()).expect("model for schema");
+        model
+            .insert_unchecked("Organization", json!({"id": "org:1", "name": "Acme"}))
+            .expect("organization inserted");
+        let id = model
+            .insert_unchecked(
+                "Person",
+                json!({
+                    "id": "p:1",
+                    "name": "Ada",
+                    "age": "36",
+                    "birth_date": "1815-12-10",
+                    "employed_by": "org:1",
+                    "aliases": ["Countess", "Enchantress"]
+                }),
+            )
+            .expect("person inserted");
+        assert_eq!(id.as_deref(), Some("p:1"));
+
+        let person = model.get("p:1").expect("person by id");
+        assert_eq!(person.get::<i64>("age").expect("age coerced"), Some(36));
+        assert_eq!(
+            person
+                .require::<NaiveDate>("birth_date")
+                .expect("birth date"),
+            NaiveDate::from_ymd_opt(1815, 12, 10).expect("valid date")
+        );
+        assert_eq!(
+            person
+                .get::<Vec<&str>>("aliases")
+                .expect("aliases")
+                .expect("aliases present"),
+            ["Countess", "Enchantress"]
+        );
+        let employers = person.follow("employed_by").expect("employer reference");
+        assert_eq!(
+            employers[0]
+                .require::<String>("name")
+                .expect("employer name"),
+            "Acme"
+        );
+        assert_eq!(model.instances().count(), 2);
+
+        // Wrong types, unknown slots and dangling references are errors
+        assert!(person.get::<i64>("name").is_err());
+        assert!(person.get_slot("salary").is_err());
+        let orphan = json!({"employed_by": "org:404"});
+        let orphan = model.instance("Person", &orphan).expect("orphan instance");
+        assert!(orphan.follow("employed_by").is_err());
+        assert!(
+            model
+                .insert_unchecked("Organization", json!({"id": "org:1"}))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,324 @@
+//! Object store locations for schema and data I/O
+//!
+//! Schemas, data files and generated artifacts can live in `s3://`, `gs://`
+//! and `az://` object stores instead of the local file system. With the
+//! `object-store` feature such URIs are accepted by
+//! [`SchemaLoader::load_file`](crate::parser::SchemaLoader::load_file)
+//! (including imports relative to a remote schema), by
+//! [`DataLoader::load_location`](crate::loader::DataLoader::load_location) and
+//! [`DataDumper::dump_location`](crate::loader::DataDumper::dump_location),
+//! and by the CLI, so the service runs against a data lake without a local
+//! staging step.
+//!
+//! Credentials come from the provider's standard environment variables
+//! (`AWS_*`, `GOOGLE_*`, `AZURE_*`) and can be set or overridden per key with
+//! [`ObjectStoreOptions`], e.g. `--storage-option aws_region=eu-west-1`. Keys
+//! follow the `object_store` configuration names; keys another provider
+//! understands are ignored, so one set of options can serve several stores.
+
+use linkml_core::error::{LinkMLError, Result};
+use std::collections::BTreeMap;
+#[cfg(not(feature = "object-store"))]
+use std::future::Ready;
+use std::path::Path;
+
+/// URI schemes served by object stores
+pub const OBJECT_STORE_SCHEMES: [&str; 3] = ["s3", "gs", "az"];
+
+/// Whether `location` is an object store URI such as `s3://bucket/key`
+#[must_use]
+pub fn is_object_uri(location: &str) -> bool {
+    location.split_once("://").is_some_and(|(scheme, rest)| {
+        !rest.is_empty() && OBJECT_STORE_SCHEMES.contains(&scheme.to_ascii_lowercase().as_str())
+    })
+}
+
+/// The object store URI of a path, if it is one
+#[must_use]
+pub fn object_uri(path: &Path) -> Option<&str> {
+    path.to_str().filter(|location| is_object_uri(location))
+}
+
+/// Credential and client configuration of object stores
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ObjectStoreOptions {
+    /// Configuration key to value, e.g. `aws_region` or
+    /// `google_service_account`
+    pub options: BTreeMap<String, String>,
+}
+
+impl ObjectStoreOptions {
+    /// Options relying on the environment only
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a configuration key
+    #[must_use]
+    pub fn with_option(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options.insert(key.into(), value.into());
+        self
+    }
+
+    /// Parse `key=value` pairs, e.g. from the command line
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pair has no `=`
+    pub fn from_pairs(pairs: &[String]) -> Result<Self> {
+        let mut options = Self::new();
+        for pair in pairs {
+            let (key, value) = pair.split_once('=').ok_or_else(|| {
+                LinkMLError::config(format!(
+                    "Invalid storage option '{pair}'. Expected 'key=value' format."
+                ))
+            })?;
+            options = options.with_option(key.trim(), value.trim());
+        }
+        Ok(options)
+    }
+}
+
+/// Reads and writes locations that are either local paths or object store
+/// URIs
+#[derive(Debug, Clone, Default)]
+pub struct ObjectStorage {
+    options: ObjectStoreOptions,
+}
+
+impl ObjectStorage {
+    /// Storage using the given object store configuration
+    #[must_use]
+    pub fn new(options: ObjectStoreOptions) -> Self {
+        Self { options }
+    }
+
+    /// Object store configuration of this storage
+    #[must_use]
+    pub fn options(&self) -> &ObjectStoreOptions {
+        &self.options
+    }
+
+    /// Read the contents of a location
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the location cannot be read
+    pub async fn read(&self, location: &Path) -> Result<Vec<u8>> {
+        match object_uri(location) {
+            Some(uri) => self.read_object(uri).await,
+            None => tokio::fs::read(location).await.map_err(|e| {
+                LinkMLError::io_error(format!("Failed to read {}: {e}", location.display()))
+            }),
+        }
+    }
+
+    /// Read a location as UTF-8 text
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the location cannot be read or is not UTF-8
+    pub async fn read_to_string(&self, location: &Path) -> Result<String> {
+        String::from_utf8(self.read(location).await?).map_err(|e| {
+            LinkMLError::io_error(format!("{} is not valid UTF-8: {e}", location.display()))
+        })
+    }
+
+    /// Write a location, creating missing local parent directories
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the location cannot be written
+    pub async fn write(&self, location: &Path, contents: impl Into<Vec<u8>>) -> Result<()> {
+        let contents = contents.into();
+        if let Some(uri) = object_uri(location) {
+            return self.write_object(uri, contents).await;
+        }
+        if let Some(parent) = location.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(location, contents).await.map_err(|e| {
+            LinkMLError::io_error(format!("Failed to write {}: {e}", location.display()))
+        })
+    }
+
+    /// Whether a location exists
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object store cannot be queried
+    pub async fn exists(&self, location: &Path) -> Result<bool> {
+        match object_uri(location) {
+            Some(uri) => self.object_exists(uri).await,
+            None => Ok(tokio::fs::try_exists(location).await.unwrap_or(false)),
+        }
+    }
+
+    #[cfg(feature = "object-store")]
+    async fn read_object(&self, uri: &str) -> Result<Vec<u8>> {
+        let (store, path) = remote::open(uri, &self.options)?;
+        let result = store.get(&path).await.map_err(|e| remote::error(uri, &e))?;
+        let bytes = result.bytes().await.map_err(|e| remote::error(uri, &e))?;
+        Ok(bytes.to_vec())
+    }
+
+    #[cfg(feature = "object-store")]
+    async fn write_object(&self, uri: &str, contents: Vec<u8>) -> Result<()> {
+        let (store, path) = remote::open(uri, &self.options)?;
+        store
+            .put(&path, contents.into())
+            .await
+            .map_err(|e| remote::error(uri, &e))?;
+        Ok(())
+    }
+
+    #[cfg(feature = "object-store")]
+    async fn object_exists(&self, uri: &str) -> Result<bool> {
+        let (store, path) = remote::open(uri, &self.options)?;
+        match store.head(&path).await {
+            Ok(_) => Ok(true),
+            Err(object_store::Error::NotFound { .. }) => Ok(false),
+            Err(e) => Err(remote::error(uri, &e)),
+        }
+    }
+
+    #[cfg(not(feature = "object-store"))]
+    fn read_object(&self, uri: &str) -> Ready<Result<Vec<u8>>> {
+        feature_disabled(uri)
+    }
+
+    #[cfg(not(feature = "object-store"))]
+    fn write_object(&self, uri: &str, _contents: Vec<u8>) -> Ready<Result<()>> {
+        feature_disabled(uri)
+    }
+
+    #[cfg(not(feature = "object-store"))]
+    fn object_exists(&self, uri: &str) -> Ready<Result<bool>> {
+        feature_disabled(uri)
+    }
+}
+
+#[cfg(not(feature = "object-store"))]
+fn feature_disabled<T>(uri: &str) -> Ready<Result<T>> {
+    std::future::ready(Err(LinkMLError::config(format!(
+        "{uri} is an object store location, which requires the object-store feature"
+    ))))
+}
+
+#[cfg(feature = "object-store")]
+mod remote {
+    use super::ObjectStoreOptions;
+    use linkml_core::error::{LinkMLError, Result};
+    use object_store::ObjectStore;
+    use object_store::aws::{AmazonS3Builder, AmazonS3ConfigKey};
+    use object_store::azure::{AzureConfigKey, MicrosoftAzureBuilder};
+    use object_store::gcp::{GoogleCloudStorageBuilder, GoogleConfigKey};
+    use object_store::path::Path as ObjectPath;
+    use url::Url;
+
+    /// Client of the store holding `uri` and the object's path in it
+    pub(super) fn open(
+        uri: &str,
+        options: &ObjectStoreOptions,
+    ) -> Result<(Box<dyn ObjectStore>, ObjectPath)> {
+        let url = Url::parse(uri)
+            .map_err(|e| LinkMLError::config(format!("Invalid object store URI {uri}: {e}")))?;
+        let path = ObjectPath::from_url_path(url.path())
+            .map_err(|e| LinkMLError::config(format!("Invalid object path in {uri}: {e}")))?;
+        let config = options.options.iter();
+
+        let store: Box<dyn ObjectStore> = match url.scheme().to_ascii_lowercase().as_str() {
+            "s3" => Box::new(
+                config
+                    .filter_map(|(key, value)| {
+                        Some((key.parse::<AmazonS3ConfigKey>().ok()?, value))
+                    })
+                    .fold(
+                        AmazonS3Builder::from_env().with_url(uri),
+                        |builder, (key, value)| builder.with_config(key, value),
+                    )
+                    .build()
+                    .map_err(|e| error(uri, &e))?,
+            ),
+            "gs" => Box::new(
+                config
+                    .filter_map(|(key, value)| Some((key.parse::<GoogleConfigKey>().ok()?, value)))
+                    .fold(
+                        GoogleCloudStorageBuilder::from_env().with_url(uri),
+                        |builder, (key, value)| builder.with_config(key, value),
+                    )
+                    .build()
+                    .map_err(|e| error(uri, &e))?,
+            ),
+            "az" => Box::new(
+                config
+                    .filter_map(|(key, value)| Some((key.parse::<AzureConfigKey>().ok()?, value)))
+                    .fold(
+                        MicrosoftAzureBuilder::from_env().with_url(uri),
+                        |builder, (key, value)| builder.with_config(key, value),
+                    )
+                    .build()
+                    .map_err(|e| error(uri, &e))?,
+            ),
+            scheme => {
+                return Err(LinkMLError::config(format!(
+                    "Unsupported object store scheme '{scheme}' in {uri}"
+                )));
+            }
+        };
+        Ok((store, path))
+    }
+
+    pub(super) fn error(uri: &str, error: &object_store::Error) -> LinkMLError {
+        LinkMLError::io_error(format!("Object store request for {uri} failed: {error}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_uri_detection() {
+        assert!(is_object_uri("s3://lake/schemas/person.yaml"));
+        assert!(is_object_uri("GS://bucket/data.json"));
+        assert!(is_object_uri("az://container/data.yaml"));
+        assert!(!is_object_uri("s3://"));
+        assert!(!is_object_uri("https://example.org/schema.yaml"));
+        assert!(!is_object_uri("schemas/person.yaml"));
+        assert_eq!(
+            object_uri(Path::new("s3://lake/person.yaml")),
+            Some("s3://lake/person.yaml")
+        );
+
+        let options = ObjectStoreOptions::from_pairs(&[
+            "aws_region = eu-west-1".to_string(),
+            "aws_endpoint=http://localhost:9000".to_string(),
+        ])
+        .expect("options");
+        assert_eq!(options.options["aws_region"], "eu-west-1");
+        assert_eq!(options.options["aws_endpoint"], "http://localhost:9000");
+        assert!(ObjectStoreOptions::from_pairs(&["region".to_string()]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_local_round_trip() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let target = dir.path().join("out/nested/schema.yaml");
+        let storage = ObjectStorage::default();
+
+        assert!(!storage.exists(&target).await.expect("exists"));
+        storage
+            .write(&target, "id: https://example.org/x\n")
+            .await
+            .expect("write");
+        assert!(storage.exists(&target).await.expect("exists"));
+        assert_eq!(
+            storage.read_to_string(&target).await.expect("read"),
+            "id: https://example.org/x\n"
+        );
+    }
+}
@@ -0,0 +1,370 @@
+//! Ontologies loaded from local OBO and OWL files
+//!
+//! [`OntologyIndex`] keeps the terms of an ontology with their labels,
+//! synonyms and obsoletion status, and indexes their relationships in both
+//! directions so that `reachable_from` queries over large ontologies stay
+//! linear. OBO `is_a` and OWL `rdfs:subClassOf` between named classes are
+//! stored as [`SUBCLASS_OF`]; OBO `relationship:` lines keep the relation
+//! name of the file (e.g. `part_of`). Existential restrictions of OWL files
+//! are not interpreted.
+//!
+//! Terms are identified by CURIE when they have an OBO Foundry IRI and by
+//! IRI otherwise; queries may use either form.
+
+use super::{OntologyProvider, SUBCLASS_OF, obo_curie};
+use linkml_core::error::{LinkMLError, Result};
+use oxigraph::io::{RdfFormat, RdfParser};
+use oxigraph::model::{NamedOrBlankNode, Term as RdfTerm};
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::path::Path;
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const RDFS_LABEL: &str = "http://www.w3.org/2000/01/rdf-schema#label";
+const RDFS_SUBCLASS_OF: &str = "http://www.w3.org/2000/01/rdf-schema#subClassOf";
+const OWL_CLASS: &str = "http://www.w3.org/2002/07/owl#Class";
+const OWL_DEPRECATED: &str = "http://www.w3.org/2002/07/owl#deprecated";
+const HAS_EXACT_SYNONYM: &str = "http://www.geneontology.org/formats/oboInOwl#hasExactSynonym";
+
+/// Term of an ontology
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Term {
+    /// CURIE or IRI of the term
+    pub id: String,
+    /// Primary label
+    pub label: Option<String>,
+    /// Exact synonyms
+    pub synonyms: Vec<String>,
+    /// Whether the term is obsolete
+    pub obsolete: bool,
+}
+
+/// Ontology held in memory, indexed for lookups and traversal
+#[derive(Debug, Clone, Default)]
+pub struct OntologyIndex {
+    terms: HashMap<String, Term>,
+    /// Relationships by subject: `(relationship, object)`
+    parents: HashMap<String, Vec<(String, String)>>,
+    /// Relationships by object: `(relationship, subject)`
+    children: HashMap<String, Vec<(String, String)>>,
+}
+
+impl OntologyIndex {
+    /// Create an empty index
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load an ontology file, choosing the syntax by extension: `.obo`,
+    /// `.owl`/`.rdf` (RDF/XML), `.ttl` (Turtle) or `.nt` (N-Triples)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or has an
+    /// unsupported extension.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_lowercase);
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            LinkMLError::io_error(format!("Failed to read '{}': {e}", path.display()))
+        })?;
+        match extension.as_deref() {
+            Some("obo") => Ok(Self::from_obo(&content)),
+            Some("owl" | "rdf") => Self::from_rdf(&content, RdfFormat::RdfXml),
+            Some("ttl") => Self::from_rdf(&content, RdfFormat::Turtle),
+            Some("nt") => Self::from_rdf(&content, RdfFormat::NTriples),
+            _ => Err(LinkMLError::config(format!(
+                "Unsupported ontology file '{}': expected .obo, .owl, .rdf, .ttl or .nt",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Parse the `[Term]` stanzas of an OBO flat file
+    ///
+    /// Unknown tags and other stanza types are ignored.
+    #[must_use]
+    pub fn from_obo(content: &str) -> Self {
+        let mut index = Self::new();
+        let mut current: Option<Term> = None;
+        let mut in_term = false;
+        let mut edges: Vec<(String, String)> = Vec::new();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.starts_with('[') {
+                if let Some(term) = current.take() {
+                    index.insert_term(term, std::mem::take(&mut edges));
+                }
+                in_term = line == "[Term]";
+                continue;
+            }
+            if !in_term {
+                continue;
+            }
+            let Some((tag, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = strip_obo_comment(value.trim());
+            let term = current.get_or_insert_with(Term::default);
+            match tag {
+                "id" => term.id = value.to_string(),
+                "name" => term.label = Some(value.to_string()),
+                "is_obsolete" => term.obsolete = value == "true",
+                "is_a" => edges.push((SUBCLASS_OF.to_string(), value.to_string())),
+                "relationship" => {
+                    if let Some((relationship, object)) = value.split_once(' ') {
+                        edges.push((relationship.to_string(), object.trim().to_string()));
+                    }
+                }
+                "synonym" if value.contains("EXACT") => {
+                    if let Some(text) = value.split('"').nth(1) {
+                        term.synonyms.push(text.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(term) = current {
+            index.insert_term(term, edges);
+        }
+        index
+    }
+
+    /// Parse the named classes of an OWL ontology serialized as RDF
+    ///
+    /// # Errors
+    ///
+    /// Returns a parse error if the content is not valid in `format`.
+    pub fn from_rdf(content: &str, format: RdfFormat) -> Result<Self> {
+        let mut terms: HashMap<String, Term> = HashMap::new();
+        let mut edges: Vec<(String, String)> = Vec::new();
+
+        for quad in RdfParser::from_format(format).for_reader(Cursor::new(content.as_bytes())) {
+            let quad = quad.map_err(|e| LinkMLError::parse(format!("Invalid ontology: {e}")))?;
+            let NamedOrBlankNode::NamedNode(subject) = &quad.subject else {
+                continue;
+            };
+            let id = normalize(subject.as_str());
+            match (quad.predicate.as_str(), &quad.object) {
+                (RDF_TYPE, RdfTerm::NamedNode(object)) if object.as_str() == OWL_CLASS => {
+                    let term = terms.entry(id.clone()).or_default();
+                    term.id = id;
+                }
+                (RDFS_SUBCLASS_OF, RdfTerm::NamedNode(object)) => {
+                    edges.push((id, normalize(object.as_str())));
+                }
+                (RDFS_LABEL, RdfTerm::Literal(label)) => {
+                    terms.entry(id).or_default().label = Some(label.value().to_string());
+                }
+                (HAS_EXACT_SYNONYM, RdfTerm::Literal(synonym)) => {
+                    terms
+                        .entry(id)
+                        .or_default()
+                        .synonyms
+                        .push(synonym.value().to_string());
+                }
+                (OWL_DEPRECATED, RdfTerm::Literal(flag)) => {
+                    terms.entry(id).or_default().obsolete = flag.value() == "true";
+                }
+                _ => {}
+            }
+        }
+
+        let mut index = Self::new();
+        for term in terms.into_values().filter(|term| !term.id.is_empty()) {
+            index.terms.insert(term.id.clone(), term);
+        }
+        for (subject, object) in edges {
+            index.insert_edge(&subject, SUBCLASS_OF, &object);
+        }
+        Ok(index)
+    }
+
+    /// Look up a term by CURIE or IRI
+    #[must_use]
+    pub fn term(&self, id: &str) -> Option<&Term> {
+        self.terms.get(&normalize(id))
+    }
+
+    /// Number of terms
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Whether the index has no terms
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    fn insert_term(&mut self, term: Term, edges: Vec<(String, String)>) {
+        if term.id.is_empty() {
+            return;
+        }
+        for (relationship, object) in edges {
+            self.insert_edge(&term.id, &relationship, &object);
+        }
+        self.terms.insert(term.id.clone(), term);
+    }
+
+    fn insert_edge(&mut self, subject: &str, relationship: &str, object: &str) {
+        self.parents
+            .entry(subject.to_string())
+            .or_default()
+            .push((relationship.to_string(), object.to_string()));
+        self.children
+            .entry(object.to_string())
+            .or_default()
+            .push((relationship.to_string(), subject.to_string()));
+    }
+}
+
+impl OntologyProvider for OntologyIndex {
+    fn neighbors(
+        &self,
+        _ontology: Option<&str>,
+        node: &str,
+        relationship_types: &[String],
+        up: bool,
+    ) -> Result<Vec<String>> {
+        let relationships: Vec<&str> = relationship_types
+            .iter()
+            .map(|relationship| normalize_relationship(relationship))
+            .collect();
+        let edges = if up { &self.parents } else { &self.children };
+        Ok(edges
+            .get(&normalize(node))
+            .into_iter()
+            .flatten()
+            .filter(|(relationship, _)| relationships.contains(&relationship.as_str()))
+            .map(|(_, term)| term.clone())
+            .collect())
+    }
+
+    fn contains(&self, _ontology: Option<&str>, term: &str) -> Result<bool> {
+        Ok(self.terms.contains_key(&normalize(term)))
+    }
+}
+
+/// CURIE of OBO Foundry IRIs, anything else unchanged
+fn normalize(id: &str) -> String {
+    obo_curie(id).unwrap_or_else(|| id.to_string())
+}
+
+fn normalize_relationship(relationship: &str) -> &str {
+    match relationship {
+        "is_a" | RDFS_SUBCLASS_OF => SUBCLASS_OF,
+        other => other,
+    }
+}
+
+/// Value of an OBO tag without trailing `! comment` and `{qualifiers}`
+fn strip_obo_comment(value: &str) -> &str {
+    let value = value.split(" !").next().unwrap_or(value);
+    let value = if value.ends_with('}') {
+        value.rsplit_once(" {").map_or(value, |(value, _)| value)
+    } else {
+        value
+    };
+    value.trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OBO: &str = r#"format-version: 1.2
+ontology: cl
+
+[Term]
+id: CL:0000000
+name: cell
+
+[Term]
+id: CL:0000540
+name: neuron
+synonym: "nerve cell" EXACT []
+is_a: CL:0000000 ! cell
+
+[Term]
+id: CL:0000100
+name: motor neuron
+is_a: CL:0000540 {source="x"} ! neuron
+relationship: part_of UBERON:0001016 ! nervous system
+
+[Term]
+id: CL:0000001
+name: obsolete thing
+is_obsolete: true
+
+[Typedef]
+id: part_of
+name: part of
+"#;
+
+    const TTL: &str = r#"
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix obo: <http://purl.obolibrary.org/obo/> .
+obo:GO_0008150 a owl:Class ; rdfs:label "biological_process" .
+obo:GO_0009987 a owl:Class ; rdfs:label "cellular process" ;
+    rdfs:subClassOf obo:GO_0008150 .
+obo:GO_0000001 a owl:Class ; owl:deprecated true .
+"#;
+
+    #[test]
+    fn test_obo_and_owl_indexes() {
+        let cl = OntologyIndex::from_obo(OBO);
+        assert_eq!(cl.len(), 4);
+        let neuron = cl.term("CL:0000540").expect("neuron term");
+        assert_eq!(neuron.label.as_deref(), Some("neuron"));
+        assert_eq!(neuron.synonyms, vec!["nerve cell"]);
+        assert!(cl.term("CL:0000001").expect("obsolete term").obsolete);
+        assert!(
+            cl.contains(None, "http://purl.obolibrary.org/obo/CL_0000540")
+                .expect("lookup by IRI")
+        );
+
+        let is_a = vec!["is_a".to_string()];
+        assert_eq!(
+            cl.neighbors(None, "CL:0000540", &is_a, false)
+                .expect("children"),
+            vec!["CL:0000100"]
+        );
+        assert_eq!(
+            cl.neighbors(None, "CL:0000100", &is_a, true)
+                .expect("parents"),
+            vec!["CL:0000540"]
+        );
+        let part_of = vec!["part_of".to_string()];
+        assert_eq!(
+            cl.neighbors(None, "CL:0000100", &part_of, true)
+                .expect("part_of targets"),
+            vec!["UBERON:0001016"]
+        );
+
+        let go = OntologyIndex::from_rdf(TTL, RdfFormat::Turtle).expect("Turtle ontology");
+        assert_eq!(go.len(), 3);
+        assert_eq!(
+            go.term("GO:0009987")
+                .expect("cellular process term")
+                .label
+                .as_deref(),
+            Some("cellular process")
+        );
+        assert!(go.term("GO:0000001").expect("deprecated term").obsolete);
+        let subclass = vec![SUBCLASS_OF.to_string()];
+        assert_eq!(
+            go.neighbors(None, "GO:0008150", &subclass, false)
+                .expect("subclasses"),
+            vec!["GO:0009987"]
+        );
+        assert!(OntologyIndex::from_rdf("not turtle <", RdfFormat::Turtle).is_err());
+    }
+}
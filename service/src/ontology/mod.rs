@@ -0,0 +1,191 @@
+//! Ontology access
+//!
+//! Schemas point into ontologies in several places: `slot_uri` and
+//! `class_uri`, the `*_mappings` of classes and slots, the `meaning` of
+//! permissible values, and the `reachable_from` queries of dynamic enums.
+//! This module provides [`OntologyProvider`]s that answer questions about
+//! those terms:
+//!
+//! - [`OntologyIndex`] loads a local OBO or OWL file into memory
+//! - [`OlsProvider`] queries the Ontology Lookup Service, caches the answers
+//!   in memory and optionally on disk, and can run offline from that cache
+//! - [`OntologyCatalog`] routes each query to the provider of its ontology,
+//!   chosen by the ontology name of the query or the prefix of the term
+//!
+//! ```rust,no_run
+//! use linkml_service::ontology::{OlsProvider, OntologyCatalog, OntologyIndex};
+//! use std::sync::Arc;
+//!
+//! # fn main() -> linkml_core::error::Result<()> {
+//! let catalog = OntologyCatalog::new()
+//!     .with_ontology("cl", Arc::new(OntologyIndex::from_file("cl.obo")?))
+//!     .with_fallback(Arc::new(OlsProvider::new().with_default_cache_dir()));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A catalog is passed to the validation engine with
+//! `set_ontology_provider` for dynamic enums, and to
+//! [`find_unknown_terms`] or the `unknown-mapping` lint rule to verify the
+//! terms a schema refers to.
+
+pub mod local;
+pub mod ols;
+pub mod terms;
+
+pub use crate::validator::dynamic_enum::{OntologyProvider, SUBCLASS_OF};
+pub use local::{OntologyIndex, Term};
+pub use ols::OlsProvider;
+pub use terms::{TermReference, find_unknown_terms, term_references};
+
+use linkml_core::error::{LinkMLError, Result};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Namespace of OBO Foundry term IRIs
+pub const OBO_PURL: &str = "http://purl.obolibrary.org/obo/";
+
+/// CURIE of an OBO Foundry term IRI, e.g. `GO:0008150` for
+/// `http://purl.obolibrary.org/obo/GO_0008150`
+#[must_use]
+pub fn obo_curie(iri: &str) -> Option<String> {
+    let local = iri.strip_prefix(OBO_PURL)?;
+    let (prefix, id) = local.split_once('_')?;
+    (!prefix.is_empty() && !id.is_empty()).then(|| format!("{prefix}:{id}"))
+}
+
+/// OBO Foundry IRI of a CURIE, e.g. `http://purl.obolibrary.org/obo/GO_0008150`
+/// for `GO:0008150`
+#[must_use]
+pub fn obo_iri(curie: &str) -> Option<String> {
+    let (prefix, id) = curie.split_once(':')?;
+    if prefix.is_empty() || id.is_empty() || id.starts_with("//") {
+        return None;
+    }
+    Some(format!("{OBO_PURL}{prefix}_{id}"))
+}
+
+/// Lower-case name of the ontology a query is about
+///
+/// An explicit ontology such as `obo:cl` or `CL` wins; otherwise the prefix
+/// of a CURIE or OBO IRI term is used.
+#[must_use]
+pub fn ontology_name(ontology: Option<&str>, term: &str) -> Option<String> {
+    if let Some(ontology) = ontology {
+        let name = ontology.strip_prefix("obo:").unwrap_or(ontology);
+        return Some(name.to_lowercase());
+    }
+    let curie = obo_curie(term);
+    let curie = curie.as_deref().unwrap_or(term);
+    let (prefix, local) = curie.split_once(':')?;
+    (!local.starts_with("//")).then(|| prefix.to_lowercase())
+}
+
+/// Providers for individual ontologies, with an optional fallback
+#[derive(Clone, Default)]
+pub struct OntologyCatalog {
+    ontologies: HashMap<String, Arc<dyn OntologyProvider>>,
+    fallback: Option<Arc<dyn OntologyProvider>>,
+}
+
+impl OntologyCatalog {
+    /// Create an empty catalog
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer queries about ontology `name` (case-insensitive, e.g. `go`)
+    /// with `provider`
+    #[must_use]
+    pub fn with_ontology(mut self, name: &str, provider: Arc<dyn OntologyProvider>) -> Self {
+        self.ontologies.insert(name.to_lowercase(), provider);
+        self
+    }
+
+    /// Answer queries about ontologies without a provider of their own with
+    /// `provider`
+    #[must_use]
+    pub fn with_fallback(mut self, provider: Arc<dyn OntologyProvider>) -> Self {
+        self.fallback = Some(provider);
+        self
+    }
+
+    /// Provider answering queries about `term`
+    #[must_use]
+    pub fn provider_for(
+        &self,
+        ontology: Option<&str>,
+        term: &str,
+    ) -> Option<&Arc<dyn OntologyProvider>> {
+        ontology_name(ontology, term)
+            .and_then(|name| self.ontologies.get(&name))
+            .or(self.fallback.as_ref())
+    }
+
+    fn require(&self, ontology: Option<&str>, term: &str) -> Result<&Arc<dyn OntologyProvider>> {
+        self.provider_for(ontology, term).ok_or_else(|| {
+            LinkMLError::config(format!(
+                "No ontology provider for '{}'",
+                ontology.unwrap_or(term)
+            ))
+        })
+    }
+}
+
+impl OntologyProvider for OntologyCatalog {
+    fn neighbors(
+        &self,
+        ontology: Option<&str>,
+        node: &str,
+        relationship_types: &[String],
+        up: bool,
+    ) -> Result<Vec<String>> {
+        self.require(ontology, node)?
+            .neighbors(ontology, node, relationship_types, up)
+    }
+
+    fn contains(&self, ontology: Option<&str>, term: &str) -> Result<bool> {
+        self.require(ontology, term)?.contains(ontology, term)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::dynamic_enum::InMemoryOntology;
+
+    #[test]
+    fn test_catalog_routing() {
+        assert_eq!(
+            obo_curie("http://purl.obolibrary.org/obo/GO_0008150").as_deref(),
+            Some("GO:0008150")
+        );
+        assert_eq!(
+            obo_iri("GO:0008150").as_deref(),
+            Some("http://purl.obolibrary.org/obo/GO_0008150")
+        );
+        assert_eq!(obo_iri("http://example.org/x"), None);
+        assert_eq!(ontology_name(Some("obo:cl"), "x").as_deref(), Some("cl"));
+        assert_eq!(ontology_name(None, "GO:1").as_deref(), Some("go"));
+        assert_eq!(ontology_name(None, "http://example.org/x"), None);
+
+        let go = InMemoryOntology::new().with_subclass("GO:2", "GO:1");
+        let other = InMemoryOntology::new().with_subclass("X:2", "X:1");
+        let catalog = OntologyCatalog::new().with_ontology("GO", Arc::new(go));
+        assert!(
+            catalog
+                .contains(None, "GO:2")
+                .expect("term of a registered ontology")
+        );
+        assert!(catalog.contains(None, "X:2").is_err());
+
+        let catalog = catalog.with_fallback(Arc::new(other));
+        assert!(catalog.contains(None, "X:2").expect("term of the fallback"));
+        assert!(
+            !catalog
+                .contains(Some("go"), "X:2")
+                .expect("term of a named ontology")
+        );
+    }
+}
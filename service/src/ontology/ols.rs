@@ -0,0 +1,366 @@
+//! Ontology Lookup Service provider
+//!
+//! [`OlsProvider`] answers term lookups and `rdfs:subClassOf` traversal
+//! from the OLS REST API. Every answer is cached in memory and, with a cache
+//! directory, in a JSON file that later runs reuse. In offline mode the
+//! provider never touches the network and only answers from that cache, so
+//! validation in CI or air-gapped environments is reproducible once the
+//! cache has been filled by an online run.
+//!
+//! [`OntologyProvider`] is synchronous: called from a multi-threaded Tokio
+//! runtime the provider blocks the current worker for the request, called
+//! outside a runtime it runs the request on a temporary one. The async
+//! [`OlsProvider::term_exists`] and [`OlsProvider::related`] avoid blocking.
+
+use super::{OntologyProvider, SUBCLASS_OF, obo_curie, obo_iri, ontology_name};
+use crate::schema::mapping_suggest::{OLS_BASE_URL, http_client};
+use linkml_core::error::{LinkMLError, Result};
+use parking_lot::RwLock;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// File name of the on-disk cache within the cache directory
+const CACHE_FILE: &str = "ols-cache.json";
+
+/// Page size of OLS hierarchy requests
+const PAGE_SIZE: usize = 500;
+
+/// Cached OLS answers
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct OlsCache {
+    /// Whether a term exists, by CURIE or IRI
+    #[serde(default)]
+    terms: HashMap<String, bool>,
+    /// Parents or children of a term, by [`neighbors_key`]
+    #[serde(default)]
+    neighbors: HashMap<String, Vec<String>>,
+}
+
+/// Ontology provider backed by the Ontology Lookup Service
+pub struct OlsProvider {
+    base_url: String,
+    client: Client,
+    offline: bool,
+    cache_file: Option<PathBuf>,
+    cache: RwLock<OlsCache>,
+}
+
+impl OlsProvider {
+    /// Create a provider for the public EBI OLS instance
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_base_url(OLS_BASE_URL)
+    }
+
+    /// Create a provider for a self-hosted OLS instance
+    #[must_use]
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            client: http_client(),
+            offline: false,
+            cache_file: None,
+            cache: RwLock::new(OlsCache::default()),
+        }
+    }
+
+    /// Persist answers in `dir`, loading the answers cached there before
+    ///
+    /// An unreadable cache file is ignored with a warning.
+    #[must_use]
+    pub fn with_cache_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        let file = dir.as_ref().join(CACHE_FILE);
+        match std::fs::read_to_string(&file) {
+            Ok(content) => match serde_json::from_str(&content) {
+                Ok(cache) => self.cache = RwLock::new(cache),
+                Err(e) => warn!("Ignoring invalid ontology cache {}: {e}", file.display()),
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => warn!("Ignoring unreadable ontology cache {}: {e}", file.display()),
+        }
+        self.cache_file = Some(file);
+        self
+    }
+
+    /// Persist answers in the user cache directory (`~/.cache/linkml/ontology`
+    /// on Linux)
+    #[must_use]
+    pub fn with_default_cache_dir(self) -> Self {
+        match dirs::cache_dir() {
+            Some(dir) => self.with_cache_dir(dir.join("linkml").join("ontology")),
+            None => self,
+        }
+    }
+
+    /// Answer only from the cache, without network access
+    #[must_use]
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Whether OLS knows a term, given as CURIE or IRI
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the answer is not cached and the provider is
+    /// offline or the request fails.
+    pub async fn term_exists(&self, term: &str) -> Result<bool> {
+        if let Some(known) = self.cache.read().terms.get(term) {
+            return Ok(*known);
+        }
+        self.ensure_online(term)?;
+
+        let param = if term.contains("://") {
+            ("iri", term.to_string())
+        } else {
+            ("obo_id", term.to_string())
+        };
+        let url = format!("{}/api/terms", self.base_url);
+        let known = match self.get_json(&url, &[param]).await? {
+            Some(body) => !embedded_terms(&body).is_empty(),
+            None => false,
+        };
+
+        self.cache.write().terms.insert(term.to_string(), known);
+        self.persist();
+        Ok(known)
+    }
+
+    /// Direct superclasses (`up`) or subclasses of a term
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ontology of the term cannot be determined, or
+    /// if the answer is not cached and the provider is offline or the
+    /// request fails.
+    pub async fn related(
+        &self,
+        ontology: Option<&str>,
+        node: &str,
+        up: bool,
+    ) -> Result<Vec<String>> {
+        let name = ontology_name(ontology, node).ok_or_else(|| {
+            LinkMLError::config(format!("Cannot determine the ontology of '{node}'"))
+        })?;
+        let key = neighbors_key(&name, node, up);
+        if let Some(terms) = self.cache.read().neighbors.get(&key) {
+            return Ok(terms.clone());
+        }
+        self.ensure_online(node)?;
+
+        let iri = if node.contains("://") {
+            node.to_string()
+        } else {
+            obo_iri(node)
+                .ok_or_else(|| LinkMLError::config(format!("'{node}' is not a CURIE or IRI")))?
+        };
+        // OLS expects the term IRI URL-encoded twice in the path
+        let encoded: String = url::form_urlencoded::byte_serialize(iri.as_bytes()).collect();
+        let encoded: String = url::form_urlencoded::byte_serialize(encoded.as_bytes()).collect();
+        let url = format!(
+            "{}/api/ontologies/{name}/terms/{encoded}/{}",
+            self.base_url,
+            if up { "parents" } else { "children" }
+        );
+
+        let mut terms = Vec::new();
+        let mut page = 0;
+        loop {
+            let params = [("page", page.to_string()), ("size", PAGE_SIZE.to_string())];
+            let Some(body) = self.get_json(&url, &params).await? else {
+                break;
+            };
+            terms.extend(embedded_terms(&body));
+            let total_pages = body
+                .pointer("/page/totalPages")
+                .and_then(Value::as_u64)
+                .unwrap_or(0);
+            page += 1;
+            if page >= total_pages {
+                break;
+            }
+        }
+
+        self.cache.write().neighbors.insert(key, terms.clone());
+        self.persist();
+        Ok(terms)
+    }
+
+    fn ensure_online(&self, term: &str) -> Result<()> {
+        if self.offline {
+            return Err(LinkMLError::config(format!(
+                "'{term}' is not in the offline ontology cache"
+            )));
+        }
+        Ok(())
+    }
+
+    /// GET a JSON body, or `None` if OLS does not know the resource
+    async fn get_json(&self, url: &str, params: &[(&str, String)]) -> Result<Option<Value>> {
+        let response = self
+            .client
+            .get(url)
+            .query(params)
+            .send()
+            .await
+            .map_err(|e| LinkMLError::service(format!("Request to {url} failed: {e}")))?;
+        let status = response.status();
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(LinkMLError::service(format!(
+                "Request to {url} failed with status {status}"
+            )));
+        }
+        response
+            .json()
+            .await
+            .map(Some)
+            .map_err(|e| LinkMLError::deserialization(format!("Invalid response from {url}: {e}")))
+    }
+
+    fn persist(&self) {
+        let Some(file) = &self.cache_file else {
+            return;
+        };
+        let result = file
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| {
+                let json = serde_json::to_string(&*self.cache.read())?;
+                std::fs::write(file, json)
+            });
+        if let Err(e) = result {
+            warn!("Failed to write ontology cache {}: {e}", file.display());
+        }
+    }
+}
+
+impl Default for OlsProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OntologyProvider for OlsProvider {
+    fn neighbors(
+        &self,
+        ontology: Option<&str>,
+        node: &str,
+        relationship_types: &[String],
+        up: bool,
+    ) -> Result<Vec<String>> {
+        if let Some(relationship) = relationship_types
+            .iter()
+            .find(|relationship| !matches!(relationship.as_str(), SUBCLASS_OF | "is_a"))
+        {
+            return Err(LinkMLError::config(format!(
+                "The OLS provider only traverses {SUBCLASS_OF}, not '{relationship}'"
+            )));
+        }
+        block_on(self.related(ontology, node, up))
+    }
+
+    fn contains(&self, _ontology: Option<&str>, term: &str) -> Result<bool> {
+        block_on(self.term_exists(term))
+    }
+}
+
+fn neighbors_key(ontology: &str, node: &str, up: bool) -> String {
+    format!(
+        "{ontology} {node} {}",
+        if up { "parents" } else { "children" }
+    )
+}
+
+/// CURIEs, or IRIs of non-OBO terms, of the `_embedded.terms` of a response
+fn embedded_terms(body: &Value) -> Vec<String> {
+    body.pointer("/_embedded/terms")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|term| {
+            term.get("obo_id")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .or_else(|| {
+                    let iri = term.get("iri").and_then(Value::as_str)?;
+                    Some(obo_curie(iri).unwrap_or_else(|| iri.to_string()))
+                })
+        })
+        .collect()
+}
+
+/// Run a request from synchronous code
+pub(crate) fn block_on<T>(future: impl Future<Output = Result<T>>) -> Result<T> {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(|| handle.block_on(future))
+        }
+        Ok(_) => Err(LinkMLError::service(
+            "Remote lookups from synchronous code need a multi-threaded Tokio runtime; \
+             fill the cache with the async lookups first or use offline mode",
+        )),
+        Err(_) => tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| LinkMLError::service(format!("Failed to create runtime: {e}")))?
+            .block_on(future),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offline_cache() {
+        let dir = tempfile::tempdir().expect("cache directory");
+        let cache = serde_json::json!({
+            "terms": {"GO:0008150": true, "GO:9999999": false},
+            "neighbors": {"go GO:0008150 children": ["GO:0009987"]}
+        });
+        std::fs::write(dir.path().join(CACHE_FILE), cache.to_string()).expect("write cache");
+
+        let provider = OlsProvider::with_base_url("http://localhost:1")
+            .with_cache_dir(dir.path())
+            .offline(true);
+        assert!(provider.contains(None, "GO:0008150").expect("cached term"));
+        assert!(
+            !provider
+                .contains(None, "GO:9999999")
+                .expect("cached absence")
+        );
+        assert!(provider.contains(None, "GO:0000001").is_err());
+
+        let subclass = vec![SUBCLASS_OF.to_string()];
+        assert_eq!(
+            provider
+                .neighbors(None, "GO:0008150", &subclass, false)
+                .expect("cached children"),
+            vec!["GO:0009987"]
+        );
+        assert!(
+            provider
+                .neighbors(None, "GO:0008150", &["part_of".to_string()], false)
+                .is_err()
+        );
+
+        let body = serde_json::json!({"_embedded": {"terms": [
+            {"obo_id": "GO:1", "iri": "http://purl.obolibrary.org/obo/GO_1"},
+            {"iri": "http://purl.obolibrary.org/obo/GO_2"},
+            {"iri": "http://example.org/x"}
+        ]}});
+        assert_eq!(
+            embedded_terms(&body),
+            vec!["GO:1", "GO:2", "http://example.org/x"]
+        );
+    }
+}
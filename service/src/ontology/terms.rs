@@ -0,0 +1,204 @@
+//! Verification of the ontology terms a schema refers to
+//!
+//! [`find_unknown_terms`] collects the `class_uri`, `slot_uri`,
+//! `*_mappings` and permissible value `meaning` of a schema and asks an
+//! [`OntologyProvider`] whether each term exists. Terms in the namespace of
+//! the schema itself are skipped, as are terms the provider cannot answer
+//! for (no provider for the ontology, offline cache miss, network failure):
+//! only terms the provider positively does not know are reported.
+//!
+//! Pass an [`OntologyCatalog`](super::OntologyCatalog) so that every term is
+//! checked against the ontology of its prefix rather than a single index.
+
+use super::OntologyProvider;
+use linkml_core::prelude::*;
+use std::collections::HashMap;
+use tracing::debug;
+
+/// Term referenced by a schema element
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TermReference {
+    /// Kind of element: `class`, `slot` or `permissible_value`
+    pub element_type: &'static str,
+    /// Name of the element; `Class.attribute` for attributes and
+    /// `Enum.value` for permissible values
+    pub element_name: String,
+    /// Field holding the term, e.g. `slot_uri` or `exact_mappings`
+    pub field: &'static str,
+    /// The term as written in the schema
+    pub term: String,
+}
+
+/// Terms of `schema` that `provider` does not know
+#[must_use]
+pub fn find_unknown_terms(
+    schema: &SchemaDefinition,
+    provider: &dyn OntologyProvider,
+) -> Vec<TermReference> {
+    let mut answers: HashMap<String, bool> = HashMap::new();
+    term_references(schema)
+        .into_iter()
+        .filter(|reference| !is_local(schema, &reference.term))
+        .filter(|reference| {
+            let known = answers.entry(reference.term.clone()).or_insert_with(|| {
+                match provider.contains(None, &reference.term) {
+                    Ok(known) => known,
+                    Err(e) => {
+                        debug!("Cannot verify term '{}': {e}", reference.term);
+                        true
+                    }
+                }
+            });
+            !*known
+        })
+        .collect()
+}
+
+/// All terms referenced by the classes, slots and enums of a schema
+#[must_use]
+pub fn term_references(schema: &SchemaDefinition) -> Vec<TermReference> {
+    let mut references = Vec::new();
+    for (name, class) in &schema.classes {
+        let mut push = |field, term: &String| {
+            references.push(TermReference {
+                element_type: "class",
+                element_name: name.clone(),
+                field,
+                term: term.clone(),
+            });
+        };
+        if let Some(uri) = &class.class_uri {
+            push("class_uri", uri);
+        }
+        for (field, terms) in [
+            ("exact_mappings", &class.exact_mappings),
+            ("close_mappings", &class.close_mappings),
+            ("related_mappings", &class.related_mappings),
+            ("narrow_mappings", &class.narrow_mappings),
+            ("broad_mappings", &class.broad_mappings),
+        ] {
+            for term in terms {
+                push(field, term);
+            }
+        }
+        for (attribute_name, attribute) in &class.attributes {
+            slot_references(
+                &format!("{name}.{attribute_name}"),
+                attribute,
+                &mut references,
+            );
+        }
+    }
+    for (name, slot) in &schema.slots {
+        slot_references(name, slot, &mut references);
+    }
+    for (enum_name, enum_def) in &schema.enums {
+        for value in &enum_def.permissible_values {
+            if let PermissibleValue::Complex {
+                text,
+                meaning: Some(meaning),
+                ..
+            } = value
+            {
+                references.push(TermReference {
+                    element_type: "permissible_value",
+                    element_name: format!("{enum_name}.{text}"),
+                    field: "meaning",
+                    term: meaning.clone(),
+                });
+            }
+        }
+    }
+    references
+}
+
+fn slot_references(name: &str, slot: &SlotDefinition, references: &mut Vec<TermReference>) {
+    let mut push = |field, term: &String| {
+        references.push(TermReference {
+            element_type: "slot",
+            element_name: name.to_string(),
+            field,
+            term: term.clone(),
+        });
+    };
+    if let Some(uri) = &slot.slot_uri {
+        push("slot_uri", uri);
+    }
+    for (field, terms) in [
+        ("exact_mappings", &slot.exact_mappings),
+        ("close_mappings", &slot.close_mappings),
+        ("related_mappings", &slot.related_mappings),
+        ("narrow_mappings", &slot.narrow_mappings),
+        ("broad_mappings", &slot.broad_mappings),
+    ] {
+        for term in terms {
+            push(field, term);
+        }
+    }
+}
+
+/// Whether a term is in the namespace of the schema itself
+fn is_local(schema: &SchemaDefinition, term: &str) -> bool {
+    if let Some(prefix) = &schema.default_prefix
+        && term
+            .split_once(':')
+            .is_some_and(|(term_prefix, _)| term_prefix == prefix)
+    {
+        return true;
+    }
+    !schema.id.is_empty() && term.starts_with(&schema.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ontology::OntologyIndex;
+
+    #[test]
+    fn test_find_unknown_terms() {
+        let go = OntologyIndex::from_obo(
+            "[Term]\nid: GO:0008150\nname: biological_process\n\n[Term]\nid: GO:0009987\n",
+        );
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/schema".to_string(),
+            default_prefix: Some("ex".to_string()),
+            ..Default::default()
+        };
+        schema.classes.insert(
+            "Process".to_string(),
+            ClassDefinition {
+                class_uri: Some("ex:Process".to_string()),
+                exact_mappings: vec!["GO:0008150".to_string(), "GO:0000000".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "part_of".to_string(),
+            SlotDefinition {
+                slot_uri: Some("https://example.org/schema/part_of".to_string()),
+                close_mappings: vec!["GO:0000000".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.enums.insert(
+            "Kind".to_string(),
+            EnumDefinition {
+                permissible_values: vec![PermissibleValue::Complex {
+                    text: "cellular".to_string(),
+                    description: None,
+                    meaning: Some("GO:0009987".to_string()),
+                    is_a: None,
+                }],
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(term_references(&schema).len(), 6);
+        let unknown = find_unknown_terms(&schema, &go);
+        assert_eq!(unknown.len(), 2);
+        assert_eq!(unknown[0].element_name, "Process");
+        assert_eq!(unknown[0].field, "exact_mappings");
+        assert_eq!(unknown[1].element_type, "slot");
+        assert_eq!(unknown[1].field, "close_mappings");
+    }
+}
@@ -0,0 +1,26 @@
+//! Schema parsers reading through a [`FileSystemOperations`] adapter
+//!
+//! [`Parser`](super::Parser) reads schema files with `std::fs`; the parsers in
+//! this module read them through the file system adapter instead, so they
+//! honour its sandbox and can be driven asynchronously.
+//!
+//! [`FileSystemOperations`]: crate::file_system_adapter::FileSystemOperations
+
+use linkml_core::{error::Result, types::SchemaDefinition};
+use std::path::Path;
+
+pub mod json;
+pub mod yaml;
+
+pub use json::JsonParserV2;
+pub use yaml::YamlParserV2;
+
+/// Async version of the `SchemaParser` trait
+#[async_trait::async_trait]
+pub trait AsyncSchemaParser: Send + Sync {
+    /// Parse schema from string content
+    async fn parse_str(&self, content: &str) -> Result<SchemaDefinition>;
+
+    /// Parse schema from file
+    async fn parse_file(&self, path: &Path) -> Result<SchemaDefinition>;
+}
@@ -1,4 +1,8 @@
 //! Import resolution for `LinkML` schemas
+//!
+//! [`ImportResolver`] resolves the imports of a schema from files and URLs.
+//! The resolver in [`advanced`] adds import settings, aliases, selective
+//! imports and object storage locations; it is re-exported from this module.
 
 use linkml_core::{
     error::{LinkMLError, Result},
@@ -9,6 +13,10 @@ use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+pub mod advanced;
+
+pub use advanced::{ImportResolverV2, ImportSpec};
+
 /// Import resolver for handling schema imports
 #[derive(Debug)]
 pub struct ImportResolver {
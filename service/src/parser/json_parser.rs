@@ -2,12 +2,17 @@
 
 use linkml_core::{
     error::{LinkMLError, Result},
+    traits::SchemaFormat,
     types::SchemaDefinition,
 };
+use serde_yaml::Value;
 use std::fs;
 use std::path::Path;
 
 use super::SchemaParser;
+use super::recovery::{self, SchemaParseIssue};
+use crate::schema::attribute_groups::expanded;
+use crate::validator::source_map::SourceMap;
 
 /// `JSON` parser implementation
 #[derive(Default, Clone)]
@@ -19,12 +24,44 @@ impl JsonParser {
     pub const fn new() -> Self {
         Self
     }
+
+    /// Parse `JSON` content, leaving out invalid schema entries and
+    /// returning them as issues with their line and column
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinkMLError::ParseError` if the content is not well-formed
+    /// `JSON` or not an object, or if it fails to parse in a way no single
+    /// entry accounts for
+    pub fn parse_recovering(
+        &self,
+        content: &str,
+    ) -> Result<(SchemaDefinition, Vec<SchemaParseIssue>)> {
+        let error = match serde_json::from_str(content) {
+            Ok(schema) => return Ok((expanded(schema), Vec::new())),
+            Err(e) => LinkMLError::parse_at(
+                format!("JSON parsing error: {e}"),
+                format!("line {}, column {}", e.line(), e.column()),
+            ),
+        };
+        // Well-formed JSON that does not match the model is checked entry by entry
+        let Ok(document) = serde_json::from_str::<Value>(content) else {
+            return Err(error);
+        };
+        let source_map = SourceMap::parse(content).ok();
+        let (schema, issues) =
+            recovery::recover(document, SchemaFormat::Json, source_map.as_ref())?;
+        if issues.is_empty() {
+            return Err(error);
+        }
+        Ok((schema, issues))
+    }
 }
 
 impl SchemaParser for JsonParser {
     fn parse_str(&self, content: &str) -> Result<SchemaDefinition> {
-        serde_json::from_str(content)
-            .map_err(|e| LinkMLError::parse(format!("JSON parsing error: {e}")))
+        let (schema, issues) = self.parse_recovering(content)?;
+        recovery::into_result("JSON", schema, &issues)
     }
 
     fn parse_file(&self, path: &Path) -> Result<SchemaDefinition> {
@@ -73,4 +110,26 @@ mod tests {
             panic!("Expected ParseError");
         }
     }
+
+    #[test]
+    fn test_parse_recovering_locates_invalid_entry() -> linkml_core::Result<()> {
+        let json = r#"{
+  "id": "https://example.org/test",
+  "name": "test_schema",
+  "classes": {
+    "Person": {"slots": "name"},
+    "Place": {}
+  }
+}"#;
+
+        let parser = JsonParser::new();
+        let (schema, issues) = parser.parse_recovering(json)?;
+
+        assert!(schema.classes.contains_key("Place"));
+        assert!(!schema.classes.contains_key("Person"));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].key, "classes.Person.slots");
+        assert_eq!((issues[0].line, issues[0].column), (Some(5), Some(25)));
+        Ok(())
+    }
 }
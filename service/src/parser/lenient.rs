@@ -0,0 +1,332 @@
+//! Best-effort loading of work-in-progress schemas
+//!
+//! [`SchemaLoader::load_lenient`](super::SchemaLoader::load_lenient) loads
+//! whatever part of a schema can be loaded: entries that do not match the
+//! model are left out, imports that cannot be resolved are skipped, and
+//! ranges naming no class, type or enum as well as URIs and mappings that
+//! are malformed or use an undeclared prefix are kept. Each of these is
+//! reported as an [`UnresolvedElement`] located in the schema text, so
+//! editors and linters can work with schemas that do not load yet.
+
+use std::fmt;
+
+use linkml_core::types::SchemaDefinition;
+use serde::{Deserialize, Serialize};
+
+use super::recovery::SchemaParseIssue;
+use crate::namespace::{CurieProblem, CurieResolver, check_curies_with};
+use crate::ontology::TermReference;
+use crate::validator::source_map::SourceMap;
+
+/// Built-in `LinkML` types usable as ranges without being declared
+pub const BUILTIN_RANGES: &[&str] = &[
+    "string",
+    "integer",
+    "float",
+    "double",
+    "decimal",
+    "boolean",
+    "date",
+    "datetime",
+    "date_or_datetime",
+    "time",
+    "uri",
+    "uriorcurie",
+    "curie",
+    "ncname",
+    "objectidentifier",
+    "nodeidentifier",
+    "jsonpointer",
+    "jsonpath",
+    "sparqlpath",
+];
+
+/// Kind of element a lenient load could not resolve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnresolvedKind {
+    /// An entry that does not match the model and was left out
+    InvalidEntry,
+    /// An import that could not be loaded
+    MissingImport,
+    /// A range naming no class, type or enum
+    UnknownRange,
+    /// A URI or mapping that is neither a URI nor a well-formed CURIE
+    MalformedCurie,
+    /// A CURIE whose prefix is not declared
+    UndeclaredPrefix,
+}
+
+/// An element of a leniently loaded schema that could not be resolved
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnresolvedElement {
+    /// What could not be resolved
+    pub kind: UnresolvedKind,
+    /// The entry, import, range name or CURIE
+    pub name: String,
+    /// Where it occurs in the schema and why it is unresolved
+    pub issue: SchemaParseIssue,
+}
+
+impl fmt::Display for UnresolvedElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.issue.fmt(f)
+    }
+}
+
+/// A schema loaded as far as possible, with what could not be resolved
+#[derive(Debug, Clone)]
+pub struct LenientSchema {
+    /// The schema with its resolvable imports merged in
+    pub schema: SchemaDefinition,
+    /// Elements left out or left unresolved: invalid entries, then missing
+    /// imports, then unknown ranges, then unresolved CURIEs
+    pub unresolved: Vec<UnresolvedElement>,
+}
+
+impl LenientSchema {
+    /// Whether everything resolved, as for a regular load
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.unresolved.is_empty()
+    }
+}
+
+impl UnresolvedElement {
+    /// An entry left out of the schema by the parser
+    #[must_use]
+    pub fn invalid_entry(issue: SchemaParseIssue) -> Self {
+        Self {
+            kind: UnresolvedKind::InvalidEntry,
+            name: issue.key.clone(),
+            issue,
+        }
+    }
+
+    /// An import that failed to load, listed at `key` of the schema
+    pub(crate) fn missing_import(
+        import: &str,
+        key: &str,
+        reason: &str,
+        source_map: Option<&SourceMap>,
+    ) -> Self {
+        Self {
+            kind: UnresolvedKind::MissingImport,
+            name: import.to_string(),
+            issue: SchemaParseIssue::new(
+                key.to_string(),
+                format!("import '{import}' could not be resolved: {reason}"),
+                source_map,
+            ),
+        }
+    }
+}
+
+/// Ranges of the slots, attributes and slot usages of `local` that name no
+/// class, type or enum of the resolved schema
+///
+/// Only elements written in `local` are checked, so the issues can be
+/// located in its text.
+#[must_use]
+pub fn unknown_ranges(
+    local: &SchemaDefinition,
+    resolved: &SchemaDefinition,
+    source_map: Option<&SourceMap>,
+) -> Vec<UnresolvedElement> {
+    let is_known = |range: &str| {
+        BUILTIN_RANGES.contains(&range)
+            || resolved.classes.contains_key(range)
+            || resolved.types.contains_key(range)
+            || resolved.enums.contains_key(range)
+    };
+    let mut ranges: Vec<(String, &str)> = local
+        .slots
+        .iter()
+        .filter_map(|(name, slot)| Some((format!("slots.{name}"), slot.range.as_deref()?)))
+        .collect();
+    for (class_name, class) in &local.classes {
+        for (section, slots) in [
+            ("attributes", &class.attributes),
+            ("slot_usage", &class.slot_usage),
+        ] {
+            ranges.extend(slots.iter().filter_map(|(name, slot)| {
+                Some((
+                    format!("classes.{class_name}.{section}.{name}"),
+                    slot.range.as_deref()?,
+                ))
+            }));
+        }
+    }
+    ranges
+        .into_iter()
+        .filter(|(_, range)| !is_known(range))
+        .map(|(element, range)| UnresolvedElement {
+            kind: UnresolvedKind::UnknownRange,
+            name: range.to_string(),
+            issue: SchemaParseIssue::new(
+                format!("{element}.range"),
+                format!("range '{range}' is not a class, type or enum"),
+                source_map,
+            ),
+        })
+        .collect()
+}
+
+/// URIs and mappings of `local` that are malformed or use a prefix
+/// declared neither by `local` nor by the imports merged into `resolved`
+///
+/// Only elements written in `local` are checked, so the issues can be
+/// located in its text. See [`crate::namespace::check_curies`].
+#[must_use]
+pub fn unresolved_curies(
+    local: &SchemaDefinition,
+    resolved: &SchemaDefinition,
+    source_map: Option<&SourceMap>,
+) -> Vec<UnresolvedElement> {
+    check_curies_with(local, &CurieResolver::from_schema(resolved))
+        .into_iter()
+        .map(|issue| {
+            let term = &issue.reference.term;
+            let (kind, message) = match &issue.problem {
+                CurieProblem::Malformed => (
+                    UnresolvedKind::MalformedCurie,
+                    format!("'{term}' is not a valid URI or CURIE"),
+                ),
+                CurieProblem::UndeclaredPrefix { prefix, expansion } => {
+                    let known = expansion
+                        .as_ref()
+                        .map(|known| format!(" ({}: '{}')", known.source, known.expansion))
+                        .unwrap_or_default();
+                    (
+                        UnresolvedKind::UndeclaredPrefix,
+                        format!("prefix '{prefix}' of '{term}' is not declared{known}"),
+                    )
+                }
+            };
+            UnresolvedElement {
+                kind,
+                name: term.clone(),
+                issue: SchemaParseIssue::new(
+                    format!(
+                        "{}.{}",
+                        element_key(local, &issue.reference),
+                        issue.reference.field
+                    ),
+                    message,
+                    source_map,
+                ),
+            }
+        })
+        .collect()
+}
+
+/// Key path of the element holding a term
+fn element_key(local: &SchemaDefinition, reference: &TermReference) -> String {
+    let name = &reference.element_name;
+    match (reference.element_type, name.split_once('.')) {
+        ("class", _) => format!("classes.{name}"),
+        ("slot", Some((class, attribute))) if !local.slots.contains_key(name) => {
+            format!("classes.{class}.attributes.{attribute}")
+        }
+        ("permissible_value", Some((enum_name, value))) => {
+            format!("enums.{enum_name}.permissible_values.{value}")
+        }
+        _ => format!("slots.{name}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::SchemaLoader;
+    use tempfile::TempDir;
+
+    const SCHEMA: &str = "\
+id: https://example.org/people
+name: people
+imports:
+  - missing_schema
+  - places
+classes:
+  Person:
+    slots: name
+  Employee:
+    attributes:
+      employer:
+        range: Organisation
+      home:
+        range: Place
+slots:
+  name:
+    range: string
+    exact_mappings: [schema:name, foaf:name]
+  age:
+    range: Years
+";
+
+    const PLACES: &str = "\
+id: https://example.org/places
+name: places
+classes:
+  Place: {}
+";
+
+    #[tokio::test]
+    async fn test_load_lenient() -> std::result::Result<(), anyhow::Error> {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("people.yaml");
+        tokio::fs::write(&path, SCHEMA).await?;
+        tokio::fs::write(dir.path().join("places.yaml"), PLACES).await?;
+
+        let loaded = SchemaLoader::new().load_lenient(&path).await?;
+        let found: Vec<(UnresolvedKind, &str, Option<usize>)> = loaded
+            .unresolved
+            .iter()
+            .map(|element| (element.kind, element.name.as_str(), element.issue.line))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (
+                    UnresolvedKind::InvalidEntry,
+                    "classes.Person.slots",
+                    Some(8)
+                ),
+                (UnresolvedKind::MissingImport, "missing_schema", Some(4)),
+                (UnresolvedKind::UnknownRange, "Years", Some(20)),
+                (UnresolvedKind::UnknownRange, "Organisation", Some(12)),
+                (UnresolvedKind::UndeclaredPrefix, "foaf:name", Some(18)),
+            ]
+        );
+        assert_eq!(
+            loaded.unresolved[4].issue.message,
+            "prefix 'foaf' of 'foaf:name' is not declared \
+             (prefixcommons: 'http://xmlns.com/foaf/0.1/')"
+        );
+        assert!(!loaded.is_complete());
+
+        // What does resolve is loaded, imports included
+        let schema = &loaded.schema;
+        assert!(schema.classes.contains_key("Employee"));
+        assert!(schema.classes.contains_key("Place"));
+        assert!(schema.slots.contains_key("name"));
+        assert_eq!(schema.imports, ["missing_schema", "places"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_load_lenient_of_valid_schema() -> std::result::Result<(), anyhow::Error> {
+        let loaded = SchemaLoader::new()
+            .load_string_lenient(PLACES, "yaml")
+            .await?;
+        assert!(loaded.is_complete());
+        assert!(loaded.schema.classes.contains_key("Place"));
+        assert!(
+            SchemaLoader::new()
+                .load_string_lenient("classes: [", "yaml")
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
+}
@@ -9,21 +9,30 @@ use linkml_core::{
 };
 use std::path::Path;
 
+pub mod adapter;
 pub mod import_resolver;
+#[deprecated(
+    since = "2.0.0",
+    note = "use `linkml_service::parser::import_resolver`"
+)]
 pub mod import_resolver_v2;
 pub mod json_parser;
+#[deprecated(since = "2.0.0", note = "use `linkml_service::parser::adapter`")]
 pub mod json_parser_v2;
+pub mod lenient;
+pub mod recovery;
 pub mod schema_loader;
 pub mod yaml_parser;
+#[deprecated(since = "2.0.0", note = "use `linkml_service::parser::adapter`")]
 pub mod yaml_parser_v2;
 
-pub use import_resolver::ImportResolver;
-pub use import_resolver_v2::{ImportResolverV2, ImportSpec};
+pub use adapter::{AsyncSchemaParser, JsonParserV2, YamlParserV2};
+pub use import_resolver::{ImportResolver, ImportResolverV2, ImportSpec};
 pub use json_parser::JsonParser;
-pub use json_parser_v2::JsonParserV2;
+pub use lenient::{LenientSchema, UnresolvedElement, UnresolvedKind};
+pub use recovery::SchemaParseIssue;
 pub use schema_loader::SchemaLoader;
 pub use yaml_parser::YamlParser;
-pub use yaml_parser_v2::{AsyncSchemaParser, YamlParserV2};
 
 /// Trait for schema parsers
 pub trait SchemaParser: Send + Sync {
@@ -116,6 +125,26 @@ impl Parser {
             _ => Err(LinkMLError::parse(format!("Unsupported format: {format}"))),
         }
     }
+
+    /// Parse schema from string with specified format, leaving out invalid
+    /// entries and returning them as issues
+    ///
+    /// # Errors
+    ///
+    /// Returns a `LinkMLError` if:
+    /// - Format is not supported
+    /// - The content is not well-formed
+    pub fn parse_recovering(
+        &self,
+        content: &str,
+        format: &str,
+    ) -> Result<(SchemaDefinition, Vec<SchemaParseIssue>)> {
+        match format {
+            "yaml" | "yml" => self.yaml.parse_recovering(content),
+            "json" => self.json.parse_recovering(content),
+            _ => Err(LinkMLError::parse(format!("Unsupported format: {format}"))),
+        }
+    }
 }
 
 impl Default for Parser {
@@ -0,0 +1,325 @@
+//! Schema parsing that recovers from invalid elements
+//!
+//! A schema that is well-formed YAML or `JSON` can still fail to match the
+//! `LinkML` model, e.g. a class whose `slots` is a string instead of a list.
+//! Rather than stopping at the first such error, the document is checked
+//! entry by entry: top-level keys one at a time and the elements of
+//! `classes`, `slots`, `enums` and the other element sections one by one.
+//! Each invalid entry is reported as a [`SchemaParseIssue`] naming the
+//! offending key and its line and column, and is left out of the schema, so
+//! a single pass reports every problem.
+
+use std::fmt::{self, Write};
+
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::traits::SchemaFormat;
+use linkml_core::types::SchemaDefinition;
+use serde::{Deserialize, Serialize};
+use serde_yaml::{Mapping, Value};
+
+use crate::schema::attribute_groups::expanded;
+use crate::validator::source_map::SourceMap;
+
+/// Top-level keys whose entries are checked one element at a time
+pub const ELEMENT_SECTIONS: &[&str] = &[
+    "prefixes",
+    "classes",
+    "slots",
+    "types",
+    "enums",
+    "subsets",
+    "attribute_groups",
+];
+
+/// Fields every schema needs; missing or invalid ones are replaced by empty
+/// strings so the rest of the schema can still be read
+const REQUIRED_FIELDS: &[&str] = &["id", "name"];
+
+/// A recoverable problem found while parsing a schema
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaParseIssue {
+    /// What is wrong with the value
+    pub message: String,
+    /// Key path of the offending value, e.g. `classes.Person.slots`
+    pub key: String,
+    /// Line of the offending value (1-based), when known
+    pub line: Option<usize>,
+    /// Column of the offending value (1-based), when known
+    pub column: Option<usize>,
+}
+
+impl SchemaParseIssue {
+    pub(crate) fn new(key: String, message: String, source_map: Option<&SourceMap>) -> Self {
+        let location = source_map.and_then(|map| map.location(&key));
+        Self {
+            message,
+            key,
+            line: location.map(|location| location.line),
+            column: location.map(|location| location.column),
+        }
+    }
+
+    /// Location in the format of [`LinkMLError::ParseError`] locations
+    #[must_use]
+    pub fn location(&self) -> Option<String> {
+        let line = self.line?;
+        Some(format!("line {line}, column {}", self.column.unwrap_or(1)))
+    }
+}
+
+impl fmt::Display for SchemaParseIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(location) = self.location() {
+            write!(f, "{location}: ")?;
+        }
+        write!(f, "{}: {}", self.key, self.message)
+    }
+}
+
+/// Deserialize a parsed schema document, leaving out invalid entries
+///
+/// The document is held as a YAML value, whose mappings keep the order of
+/// the elements, and entries are checked as text in the `format` the schema
+/// was written in, so scalars are read as they would be from the original.
+/// `source_map` locates the issues in that text.
+///
+/// # Errors
+///
+/// Returns an error if the document is not a mapping.
+pub fn recover(
+    document: Value,
+    format: SchemaFormat,
+    source_map: Option<&SourceMap>,
+) -> Result<(SchemaDefinition, Vec<SchemaParseIssue>)> {
+    let Value::Mapping(fields) = document else {
+        return Err(LinkMLError::parse_at(
+            "Schema must be a mapping of schema fields",
+            "line 1, column 1",
+        ));
+    };
+    let mut issues = Vec::new();
+    let mut valid = Mapping::new();
+    for (key, value) in fields {
+        match value {
+            Value::Mapping(entries)
+                if key
+                    .as_str()
+                    .is_some_and(|key| ELEMENT_SECTIONS.contains(&key)) =>
+            {
+                let mut kept = Mapping::new();
+                for (name, entry) in entries {
+                    let section = Value::Mapping(Mapping::from_iter([(name, entry)]));
+                    match probe(&key, &section, format) {
+                        Ok(()) => {
+                            if let Value::Mapping(section) = section {
+                                kept.extend(section);
+                            }
+                        }
+                        Err((path, message)) => {
+                            issues.push(SchemaParseIssue::new(path, message, source_map));
+                        }
+                    }
+                }
+                valid.insert(key, Value::Mapping(kept));
+            }
+            value => match probe(&key, &value, format) {
+                Ok(()) => {
+                    valid.insert(key, value);
+                }
+                Err((path, message)) => {
+                    issues.push(SchemaParseIssue::new(path, message, source_map));
+                }
+            },
+        }
+    }
+    for field in REQUIRED_FIELDS {
+        if !valid.contains_key(*field) {
+            if !issues.iter().any(|issue| issue.key == *field) {
+                issues.push(SchemaParseIssue::new(
+                    (*field).to_string(),
+                    format!("missing field `{field}`"),
+                    source_map,
+                ));
+            }
+            valid.insert(Value::from(*field), Value::from(""));
+        }
+    }
+
+    let schema = deserialize(&valid, format)
+        .map_err(|(path, message)| LinkMLError::parse(format!("{path}: {message}")))?;
+    Ok((expanded(schema), issues))
+}
+
+/// Check that a single top-level key deserializes, returning the key path
+/// and message of the error if not
+fn probe(
+    key: &Value,
+    value: &Value,
+    format: SchemaFormat,
+) -> std::result::Result<(), (String, String)> {
+    let mut document = Mapping::new();
+    for field in REQUIRED_FIELDS {
+        document.insert(Value::from(*field), Value::from(""));
+    }
+    document.insert(key.clone(), value.clone());
+    deserialize(&document, format)
+        .map(drop)
+        .map_err(|(path, message)| {
+            let path = if path == "." {
+                key.as_str().unwrap_or("?").to_string()
+            } else {
+                path
+            };
+            (path, message)
+        })
+}
+
+/// Deserialize a schema document from its text in `format`, returning the
+/// key path and message of the error if it fails
+fn deserialize(
+    document: &Mapping,
+    format: SchemaFormat,
+) -> std::result::Result<SchemaDefinition, (String, String)> {
+    fn failure<E: fmt::Display>(err: &serde_path_to_error::Error<E>) -> (String, String) {
+        let path = err.path().to_string();
+        let message = err.inner().to_string();
+        // YAML errors repeat the path, and positions refer to the
+        // re-serialized entry rather than the schema file
+        let message = message
+            .strip_prefix(&format!("{path}: "))
+            .map_or(message.clone(), str::to_string);
+        let message = match message.rsplit_once(" at line ") {
+            Some((message, position))
+                if position
+                    .split(" column ")
+                    .all(|number| number.parse::<usize>().is_ok()) =>
+            {
+                message.to_string()
+            }
+            _ => message,
+        };
+        (path, message)
+    }
+
+    let unserializable = |err: &dyn fmt::Display| (".".to_string(), err.to_string());
+    match format {
+        SchemaFormat::Yaml => {
+            let text = serde_yaml::to_string(document).map_err(|err| unserializable(&err))?;
+            serde_path_to_error::deserialize(serde_yaml::Deserializer::from_str(&text))
+                .map_err(|err| failure(&err))
+        }
+        SchemaFormat::Json => {
+            let text = serde_json::to_string(document).map_err(|err| unserializable(&err))?;
+            serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_str(&text))
+                .map_err(|err| failure(&err))
+        }
+    }
+}
+
+/// The schema if no issues were found, otherwise an error listing them all
+///
+/// # Errors
+///
+/// Returns a [`LinkMLError::ParseError`] located at the first issue.
+pub fn into_result(
+    format: &str,
+    schema: SchemaDefinition,
+    issues: &[SchemaParseIssue],
+) -> Result<SchemaDefinition> {
+    let Some(first) = issues.first() else {
+        return Ok(schema);
+    };
+    let message = if let [_] = issues {
+        format!("{format} parsing error: {}: {}", first.key, first.message)
+    } else {
+        let mut message = format!("{format} parsing error: {} problems", issues.len());
+        for issue in issues {
+            let _ = write!(message, "\n  {issue}");
+        }
+        message
+    };
+    Err(match first.location() {
+        Some(location) => LinkMLError::parse_at(message, location),
+        None => LinkMLError::parse(message),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = "\
+id: https://example.org/people
+name: people
+version: [1, 2]
+classes:
+  Person:
+    slots: name
+  Organization:
+    description: A group
+slots:
+  name:
+    range: string
+  age:
+    required: maybe
+";
+
+    fn parse(content: &str) -> Result<(SchemaDefinition, Vec<SchemaParseIssue>)> {
+        let document: Value =
+            serde_yaml::from_str(content).map_err(|err| LinkMLError::parse(err.to_string()))?;
+        recover(
+            document,
+            SchemaFormat::Yaml,
+            SourceMap::parse(content).ok().as_ref(),
+        )
+    }
+
+    #[test]
+    fn test_reports_every_invalid_entry() -> Result<()> {
+        let (schema, issues) = parse(SCHEMA)?;
+        let found: Vec<(&str, Option<usize>, Option<usize>)> = issues
+            .iter()
+            .map(|issue| (issue.key.as_str(), issue.line, issue.column))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("version", Some(3), Some(1)),
+                ("classes.Person.slots", Some(6), Some(12)),
+                ("slots.age.required", Some(13), Some(15)),
+            ]
+        );
+        assert!(issues[1].message.contains("expected a sequence"));
+
+        // Valid entries are kept, in order
+        assert_eq!(schema.name, "people");
+        assert_eq!(schema.version, None);
+        assert_eq!(schema.classes.keys().collect::<Vec<_>>(), ["Organization"]);
+        assert_eq!(schema.slots.keys().collect::<Vec<_>>(), ["name"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_fields_and_error() -> Result<()> {
+        let (schema, issues) = parse("name: [people]\nclasses:\n  Person: {}\n")?;
+        assert_eq!(
+            issues
+                .iter()
+                .map(|issue| issue.key.as_str())
+                .collect::<Vec<_>>(),
+            ["name", "id"]
+        );
+        assert!(schema.classes.contains_key("Person"));
+
+        match into_result("YAML", schema, &issues) {
+            Err(LinkMLError::ParseError { message, location }) => {
+                assert!(message.starts_with("YAML parsing error: 2 problems"));
+                assert!(message.contains("\n  line 1, column 1: name: invalid type"));
+                assert_eq!(location.as_deref(), Some("line 1, column 1"));
+            }
+            other => panic!("Expected ParseError, got {other:?}"),
+        }
+        assert!(parse("- not a schema\n").is_err());
+        Ok(())
+    }
+}
@@ -9,12 +9,17 @@ use reqwest;
 use std::path::{Path, PathBuf};
 use tokio::fs;
 
+use super::lenient::{self, LenientSchema, UnresolvedElement};
+use super::recovery::SchemaParseIssue;
 use super::{ImportResolverV2, Parser};
+use crate::object_storage::{ObjectStorage, object_uri};
+use crate::validator::source_map::SourceMap;
 
 /// Loader for `LinkML` schemas from various sources
 pub struct SchemaLoader {
     parser: Parser,
     http_client: reqwest::Client,
+    storage: ObjectStorage,
 }
 
 impl SchemaLoader {
@@ -24,9 +29,17 @@ impl SchemaLoader {
         Self {
             parser: Parser::new(),
             http_client: reqwest::Client::new(),
+            storage: ObjectStorage::default(),
         }
     }
 
+    /// Use the given storage for `s3://`, `gs://` and `az://` locations
+    #[must_use]
+    pub fn with_storage(mut self, storage: ObjectStorage) -> Self {
+        self.storage = storage;
+        self
+    }
+
     /// Load a schema from a file path
     /// Returns an error if the operation fails
     ///
@@ -34,6 +47,9 @@ impl SchemaLoader {
     ///
     pub async fn load_file(&self, path: impl AsRef<Path>) -> Result<SchemaDefinition> {
         let path = path.as_ref();
+        if let Some(uri) = object_uri(path) {
+            return self.load_object(uri).await;
+        }
 
         // Read file content
         let content = fs::read_to_string(path)
@@ -49,43 +65,7 @@ impl SchemaLoader {
         // Parse the schema
         let schema = self.parser.parse_str(&content, extension)?;
 
-        // Set up import settings with the file's parent directory as search path
-        let mut settings = ImportSettings::default();
-        if let Some(parent) = path.parent() {
-            settings
-                .search_paths
-                .push(parent.to_string_lossy().to_string());
-        }
-
-        // Use schema settings if available
-        if let Some(schema_settings) = &schema.settings
-            && let Some(import_settings) = &schema_settings.imports
-        {
-            settings = import_settings.clone();
-
-            // Resolve relative search paths from schema settings
-            if let Some(parent) = path.parent() {
-                // Make relative paths absolute based on schema location
-                settings.search_paths = settings
-                    .search_paths
-                    .iter()
-                    .map(|p| {
-                        let path_buf = PathBuf::from(p);
-                        if path_buf.is_relative() {
-                            parent.join(path_buf).to_string_lossy().to_string()
-                        } else {
-                            p.clone()
-                        }
-                    })
-                    .collect();
-
-                // Also add the parent directory if not already present
-                let parent_str = parent.to_string_lossy().to_string();
-                if !settings.search_paths.contains(&parent_str) {
-                    settings.search_paths.push(parent_str);
-                }
-            }
-        }
+        let settings = Self::file_import_settings(&schema, path);
 
         // Resolve imports using enhanced resolver
         let import_resolver = ImportResolverV2::with_settings(settings);
@@ -168,6 +148,32 @@ impl SchemaLoader {
         import_resolver.resolve_imports(&schema).await
     }
 
+    /// Load a schema from an object store URI such as `s3://bucket/schema.yaml`
+    ///
+    /// Relative imports resolve against the schema's location in the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object cannot be read or parsed, or an import
+    /// cannot be resolved
+    pub async fn load_object(&self, uri: &str) -> Result<SchemaDefinition> {
+        let content = self.storage.read_to_string(Path::new(uri)).await?;
+        let format = if Path::new(uri)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+        {
+            "json"
+        } else {
+            "yaml"
+        };
+        let schema = self.parser.parse_str(&content, format)?;
+
+        let settings = Self::object_import_settings(&schema, uri);
+        self.import_resolver(settings)
+            .resolve_imports(&schema)
+            .await
+    }
+
     /// Load a schema from a string with specified format
     /// Returns an error if the operation fails
     ///
@@ -187,6 +193,202 @@ impl SchemaLoader {
         let import_resolver = ImportResolverV2::with_settings(settings);
         import_resolver.resolve_imports(&schema).await
     }
+
+    /// Load a schema file as far as possible, reporting what cannot be
+    /// resolved instead of failing
+    ///
+    /// Entries that do not match the model are left out, imports that cannot
+    /// be loaded are skipped, and ranges naming no class, type or enum and
+    /// CURIEs with undeclared prefixes are kept; each is returned as an
+    /// [`UnresolvedElement`] located in the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not well-formed
+    /// YAML or `JSON`
+    pub async fn load_lenient(&self, path: impl AsRef<Path>) -> Result<LenientSchema> {
+        let path = path.as_ref();
+        let uri = object_uri(path);
+        let content = match uri {
+            Some(uri) => self.storage.read_to_string(Path::new(uri)).await?,
+            None => fs::read_to_string(path)
+                .await
+                .map_err(|e| LinkMLError::service(format!("Failed to read file: {e}")))?,
+        };
+        let format = if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+        {
+            "json"
+        } else {
+            "yaml"
+        };
+
+        let (schema, issues) = self.parser.parse_recovering(&content, format)?;
+        let settings = match uri {
+            Some(uri) => Self::object_import_settings(&schema, uri),
+            None => Self::file_import_settings(&schema, path),
+        };
+        Ok(self
+            .resolve_leniently(schema, issues, settings, &content)
+            .await)
+    }
+
+    /// Load a schema from a string as far as possible, reporting what cannot
+    /// be resolved instead of failing
+    ///
+    /// See [`load_lenient`](Self::load_lenient).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the format is not supported or the content is not
+    /// well-formed
+    pub async fn load_string_lenient(&self, content: &str, format: &str) -> Result<LenientSchema> {
+        let (schema, issues) = self.parser.parse_recovering(content, format)?;
+        let settings = Self::schema_import_settings(&schema);
+        Ok(self
+            .resolve_leniently(schema, issues, settings, content)
+            .await)
+    }
+
+    /// Merge the imports of a leniently parsed schema that can be resolved
+    /// and collect everything that cannot
+    async fn resolve_leniently(
+        &self,
+        local: SchemaDefinition,
+        issues: Vec<SchemaParseIssue>,
+        settings: ImportSettings,
+        content: &str,
+    ) -> LenientSchema {
+        let source_map = SourceMap::parse(content).ok();
+        let mut unresolved: Vec<UnresolvedElement> = issues
+            .into_iter()
+            .map(UnresolvedElement::invalid_entry)
+            .collect();
+
+        // The resolver stops at the first failing import, so each is tried alone
+        let mut resolvable = local.clone();
+        resolvable.imports.clear();
+        for (index, import) in local.imports.iter().enumerate() {
+            let mut single = local.clone();
+            single.imports = vec![import.clone()];
+            match self
+                .import_resolver(settings.clone())
+                .resolve_imports(&single)
+                .await
+            {
+                Ok(_) => resolvable.imports.push(import.clone()),
+                Err(err) => unresolved.push(UnresolvedElement::missing_import(
+                    import,
+                    &format!("imports[{index}]"),
+                    &err.to_string(),
+                    source_map.as_ref(),
+                )),
+            }
+        }
+        let mut schema = match self
+            .import_resolver(settings)
+            .resolve_imports(&resolvable)
+            .await
+        {
+            Ok(schema) => schema,
+            Err(err) => {
+                // Imports that resolve alone can still conflict with each other
+                unresolved.push(UnresolvedElement::missing_import(
+                    &resolvable.imports.join(", "),
+                    "imports",
+                    &err.to_string(),
+                    source_map.as_ref(),
+                ));
+                resolvable
+            }
+        };
+        schema.imports.clone_from(&local.imports);
+
+        unresolved.extend(lenient::unknown_ranges(
+            &local,
+            &schema,
+            source_map.as_ref(),
+        ));
+        unresolved.extend(lenient::unresolved_curies(
+            &local,
+            &schema,
+            source_map.as_ref(),
+        ));
+        LenientSchema { schema, unresolved }
+    }
+
+    /// Import resolver reading object store imports through this loader's
+    /// storage
+    fn import_resolver(&self, settings: ImportSettings) -> ImportResolverV2 {
+        ImportResolverV2::with_settings(settings).with_storage(self.storage.clone())
+    }
+
+    /// Import settings declared by a schema, or the defaults
+    fn schema_import_settings(schema: &SchemaDefinition) -> ImportSettings {
+        schema
+            .settings
+            .as_ref()
+            .and_then(|schema_settings| schema_settings.imports.clone())
+            .unwrap_or_default()
+    }
+
+    /// Import settings of a schema in an object store, resolving relative
+    /// imports against its location
+    fn object_import_settings(schema: &SchemaDefinition, uri: &str) -> ImportSettings {
+        let mut settings = Self::schema_import_settings(schema);
+        if settings.base_url.is_none()
+            && let Ok(parsed_url) = url::Url::parse(uri)
+            && let Ok(base) = parsed_url.join("./")
+        {
+            settings.base_url = Some(base.to_string());
+        }
+        settings
+    }
+
+    /// Import settings of a schema file: its own settings, with relative
+    /// search paths resolved against its directory, which is searched too
+    fn file_import_settings(schema: &SchemaDefinition, path: &Path) -> ImportSettings {
+        // Set up import settings with the file's parent directory as search path
+        let mut settings = ImportSettings::default();
+        if let Some(parent) = path.parent() {
+            settings
+                .search_paths
+                .push(parent.to_string_lossy().to_string());
+        }
+
+        // Use schema settings if available
+        if let Some(schema_settings) = &schema.settings
+            && let Some(import_settings) = &schema_settings.imports
+        {
+            settings = import_settings.clone();
+
+            // Resolve relative search paths from schema settings
+            if let Some(parent) = path.parent() {
+                // Make relative paths absolute based on schema location
+                settings.search_paths = settings
+                    .search_paths
+                    .iter()
+                    .map(|p| {
+                        let path_buf = PathBuf::from(p);
+                        if path_buf.is_relative() {
+                            parent.join(path_buf).to_string_lossy().to_string()
+                        } else {
+                            p.clone()
+                        }
+                    })
+                    .collect();
+
+                // Also add the parent directory if not already present
+                let parent_str = parent.to_string_lossy().to_string();
+                if !settings.search_paths.contains(&parent_str) {
+                    settings.search_paths.push(parent_str);
+                }
+            }
+        }
+
+        settings
+    }
 }
 
 impl Default for SchemaLoader {
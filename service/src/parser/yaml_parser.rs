@@ -2,12 +2,17 @@
 
 use linkml_core::{
     error::{LinkMLError, Result},
+    traits::SchemaFormat,
     types::SchemaDefinition,
 };
+use serde_yaml::Value;
 use std::fs;
 use std::path::Path;
 
 use super::SchemaParser;
+use super::recovery::{self, SchemaParseIssue};
+use crate::schema::attribute_groups::expanded;
+use crate::validator::source_map::SourceMap;
 
 /// `YAML` parser implementation
 #[derive(Default, Clone)]
@@ -31,19 +36,47 @@ impl YamlParser {
     pub fn parse(&self, content: &str) -> Result<SchemaDefinition> {
         self.parse_str(content)
     }
-}
 
-impl SchemaParser for YamlParser {
-    fn parse_str(&self, content: &str) -> Result<SchemaDefinition> {
-        serde_yaml::from_str(content).map_err(|e| {
-            LinkMLError::parse_at(
+    /// Parse `YAML` content, leaving out invalid schema entries and
+    /// returning them as issues with their line and column
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinkMLError::ParseError` if the content is not well-formed
+    /// `YAML` or not a mapping, or if it fails to parse in a way no single
+    /// entry accounts for
+    pub fn parse_recovering(
+        &self,
+        content: &str,
+    ) -> Result<(SchemaDefinition, Vec<SchemaParseIssue>)> {
+        let error = match serde_yaml::from_str(content) {
+            Ok(schema) => return Ok((expanded(schema), Vec::new())),
+            Err(e) => LinkMLError::parse_at(
                 format!("YAML parsing error: {e}"),
                 e.location().map_or_else(
                     || "unknown location".to_string(),
                     |l| format!("line {}, column {}", l.line(), l.column()),
                 ),
-            )
-        })
+            ),
+        };
+        // Well-formed YAML that does not match the model is checked entry by entry
+        let Ok(document) = serde_yaml::from_str::<Value>(content) else {
+            return Err(error);
+        };
+        let source_map = SourceMap::parse(content).ok();
+        let (schema, issues) =
+            recovery::recover(document, SchemaFormat::Yaml, source_map.as_ref())?;
+        if issues.is_empty() {
+            return Err(error);
+        }
+        Ok((schema, issues))
+    }
+}
+
+impl SchemaParser for YamlParser {
+    fn parse_str(&self, content: &str) -> Result<SchemaDefinition> {
+        let (schema, issues) = self.parse_recovering(content)?;
+        recovery::into_result("YAML", schema, &issues)
     }
 
     fn parse_file(&self, path: &Path) -> Result<SchemaDefinition> {
@@ -123,4 +156,29 @@ slots:
             panic!("Expected ParseError");
         }
     }
+
+    #[test]
+    fn test_parse_reports_all_invalid_entries() {
+        let yaml = r"
+id: https://example.org/test
+name: test_schema
+classes:
+  Person:
+    slots: name
+slots:
+  age:
+    multivalued: often
+";
+
+        let parser = YamlParser::new();
+        match parser.parse_str(yaml) {
+            Err(LinkMLError::ParseError { message, location }) => {
+                assert!(message.contains("2 problems"));
+                assert!(message.contains("line 6, column 12: classes.Person.slots"));
+                assert!(message.contains("line 9, column 18: slots.age.multivalued"));
+                assert_eq!(location.as_deref(), Some("line 6, column 12"));
+            }
+            other => panic!("Expected ParseError, got {other:?}"),
+        }
+    }
 }
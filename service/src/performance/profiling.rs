@@ -3,6 +3,7 @@
 //! This module provides tools to profile and optimize performance-critical
 //! sections of the `LinkML` validation engine.
 
+use crate::utils::timestamp::wire_timestamp;
 use parking_lot::Mutex;
 use std::collections::HashMap;
 use std::fmt::Write;
@@ -10,7 +11,6 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 use std::time::{SystemTime, UNIX_EPOCH};
-use timestamp_service::wiring::wire_timestamp;
 
 /// Local timestamp service trait for profiling
 pub trait LocalTimestampService: Send + Sync {
@@ -226,7 +226,7 @@ impl Profiler {
 
 impl Default for Profiler {
     fn default() -> Self {
-        Self::new(wire_timestamp().into_inner())
+        Self::new(wire_timestamp())
     }
 }
 
@@ -299,7 +299,7 @@ mod tests {
 
     #[test]
     fn test_profiler() {
-        let profiler = Profiler::new(wire_timestamp().into_inner());
+        let profiler = Profiler::new(wire_timestamp());
 
         // Time some operations
         profiler.time("test_op", || {
@@ -325,7 +325,7 @@ mod tests {
 
     #[test]
     fn test_timing_guard() {
-        let profiler = Profiler::new(wire_timestamp().into_inner());
+        let profiler = Profiler::new(wire_timestamp());
 
         {
             let _guard = TimingGuard::new(&profiler, "test_scope");
@@ -1,6 +1,8 @@
 //! Plugin discovery mechanisms
 //!
 //! This module provides various strategies for discovering plugins in the filesystem.
+//! Plugins declared in a project manifest, with versions locked across machines,
+//! are resolved by [`ProjectResolver`](super::project::ProjectResolver).
 
 use super::{Deserialize, HashMap, LinkMLError, PluginInfo, Serialize};
 use glob::glob;
@@ -304,6 +306,14 @@ pub enum EntryPoint {
         /// Instance configuration
         config: Option<serde_json::Value>,
     },
+    /// Program speaking the subprocess plugin protocol over stdio
+    Subprocess {
+        /// Program, relative to the manifest if it contains a path separator
+        command: String,
+        /// Program arguments
+        #[serde(default)]
+        args: Vec<String>,
+    },
 }
 
 /// Build configuration
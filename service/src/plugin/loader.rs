@@ -1,7 +1,8 @@
 //! Dynamic plugin loading
 //!
 //! This module handles loading plugins from various sources including
-//! native libraries, Python modules, JavaScript, and WebAssembly.
+//! native libraries, Python modules, JavaScript, WebAssembly, and programs
+//! speaking the subprocess plugin protocol.
 
 use super::{EntryPoint, LinkMLError, Plugin, PluginManifest, Result};
 use std::fs;
@@ -35,7 +36,7 @@ pub struct DynamicLoader {
     builtin_registry: super::BuiltinPluginRegistry,
     /// Native library loader (disabled for safety)
     native_loader: NativeLoader,
-    /// Python plugin loader (requires feature flag)
+    /// Python plugin loader (out of process)
     python_loader: PythonLoader,
     /// JavaScript plugin loader (requires feature flag)
     js_loader: JavaScriptLoader,
@@ -108,7 +109,9 @@ impl DynamicLoader {
                 self.native_loader
                     .load_plugin(base_dir, library, symbol.as_deref())
             }
-            EntryPoint::Python { module, class } => self.python_loader.load_plugin(module, class),
+            EntryPoint::Python { module, class } => self
+                .python_loader
+                .load_plugin(base_dir, manifest, module, class),
             EntryPoint::JavaScript { module, export } => {
                 self.js_loader
                     .load_plugin(base_dir, module, export.as_deref())
@@ -117,6 +120,14 @@ impl DynamicLoader {
                 self.wasm_loader
                     .load_plugin(base_dir, module, config.as_ref())
             }
+            EntryPoint::Subprocess { command, args } => {
+                Ok(Box::new(super::subprocess::from_entry_point(
+                    manifest.plugin.clone(),
+                    base_dir,
+                    command,
+                    args,
+                )))
+            }
         }
     }
 }
@@ -150,7 +161,7 @@ impl NativeLoader {
     }
 }
 
-/// Python plugin loader using `PyO3`
+/// Python plugin loader, running `LinkML` Python plugins out of process
 struct PythonLoader;
 
 impl PythonLoader {
@@ -158,19 +169,30 @@ impl PythonLoader {
         Self
     }
 
-    /// Load a Python plugin
-    ///
-    /// # Errors
+    /// Load a Python plugin class
     ///
-    /// Returns error because Python plugin support requires `PyO3` integration
-    /// and the 'python' feature to be enabled.
-    fn load_plugin(&self, _module: &str, _class: &str) -> Result<Box<dyn Plugin>> {
-        // Python integration would require PyO3
-        // For now, return an error indicating Python plugins need PyO3 integration
-        Err(LinkMLError::ServiceError(
-            "Python plugin support requires PyO3 integration. \
-             Please enable the 'python' feature."
-                .to_string(),
+    /// The plugin runs through the bundled protocol host of
+    /// [`SubprocessPlugin::python`](super::subprocess::SubprocessPlugin::python),
+    /// with the manifest's directory on the `PYTHONPATH`.
+    fn load_plugin(
+        &self,
+        base_dir: &Path,
+        manifest: &PluginManifest,
+        module: &str,
+        class: &str,
+    ) -> Result<Box<dyn Plugin>> {
+        let python_path = std::env::var_os("PYTHONPATH").map_or_else(
+            || base_dir.as_os_str().to_owned(),
+            |existing| {
+                let mut paths = vec![base_dir.to_path_buf()];
+                paths.extend(std::env::split_paths(&existing));
+                std::env::join_paths(paths).unwrap_or(existing)
+            },
+        );
+        Ok(Box::new(
+            super::subprocess::SubprocessPlugin::python(manifest.plugin.clone(), module, class)
+                .current_dir(base_dir)
+                .env("PYTHONPATH", python_path.to_string_lossy()),
         ))
     }
 }
@@ -328,7 +350,7 @@ impl<O: TimeoutService> SandboxedPlugin<O> {
             plugin,
             sandbox,
             timeout_service,
-            timestamp_service: timestamp_service::wiring::wire_sync_timestamp(),
+            timestamp_service: crate::utils::timestamp::wire_sync_timestamp(),
         }
     }
 
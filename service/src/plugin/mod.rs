@@ -18,14 +18,21 @@ pub mod builtin_plugins;
 pub mod compatibility;
 pub mod discovery;
 pub mod loader;
+pub mod project;
 pub mod registry;
+pub mod subprocess;
 
 pub use api::{PluginCapability, PluginMetadata, PluginSDK};
 pub use builtin_plugins::BuiltinPluginRegistry;
 pub use compatibility::{CompatibilityChecker, CompatibilityRules};
 pub use discovery::{DiscoveryStrategy, EntryPoint, PluginDiscovery, PluginManifest};
 pub use loader::{DynamicLoader, FsAccessMode, PluginLoader, PluginSandbox, ResourceLimits};
+pub use project::{
+    LockFile, LockedPlugin, PROJECT_LOCK_FILE, PROJECT_MANIFEST_FILE, PluginRequirement,
+    PluginSource, ProjectManifest, ProjectResolver, ResolvedPlugin,
+};
 pub use registry::{PluginRegistration, PluginRegistry};
+pub use subprocess::SubprocessPlugin;
 
 // Core plugin types are already defined in this module
 
@@ -317,7 +324,7 @@ impl PluginManager {
     /// Create a new plugin manager
     pub fn new(logger: Arc<dyn LoggerService<Error = LoggerError>>) -> Self {
         Self {
-            registry: PluginRegistry::new(timestamp_service::wiring::wire_timestamp().into_inner()),
+            registry: PluginRegistry::new(crate::utils::timestamp::wire_timestamp()),
             loader: DynamicLoader::new(),
             discovery: PluginDiscovery::new(),
             compatibility: CompatibilityChecker::new(),
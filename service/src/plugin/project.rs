@@ -0,0 +1,1026 @@
+//! Project plugin manifests and lock files
+//!
+//! A project declares the plugins it uses in [`PROJECT_MANIFEST_FILE`], each
+//! with a version requirement and a source: a local path, a git repository,
+//! or a crates.io-compatible registry (the default):
+//!
+//! ```toml
+//! [plugins.shacl-extras]
+//! version = "^0.3"
+//! path = "plugins/shacl-extras"
+//!
+//! [plugins.typeql-plus]
+//! version = "1.2"
+//! git = "https://github.com/example/typeql-plus"
+//! tag = "v1.2.0"
+//!
+//! [plugins.linkml-plugin-owl]
+//! version = "^2.1"
+//! ```
+//!
+//! [`ProjectResolver`] fetches each plugin into a cache directory, reads the
+//! plugin manifest found by [`PluginDiscovery`], checks its version and runs
+//! its build command. The exact version, git commit and a SHA-256 checksum
+//! of the plugin's files are recorded in [`PROJECT_LOCK_FILE`]; later
+//! resolutions reuse the locked versions and commits and fail if the files no
+//! longer match their checksums. Checksums skip the `target`, `build` and
+//! `dist` directories, where build commands should write their output. In
+//! locked mode the lock file must already cover the manifest.
+
+use super::discovery::{DiscoveryStrategy, PluginDiscovery, PluginManifest};
+use super::{Deserialize, LinkMLError, Serialize};
+use indexmap::IndexMap;
+use linkml_core::error::Result;
+use semver::{Version, VersionReq};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+use walkdir::WalkDir;
+
+/// File declaring the plugins of a project
+pub const PROJECT_MANIFEST_FILE: &str = "linkml-plugins.toml";
+
+/// File recording the resolved plugins of a project
+pub const PROJECT_LOCK_FILE: &str = "linkml-plugins.lock";
+
+/// API of the default plugin registry
+pub const CRATES_IO_API: &str = "https://crates.io/api/v1";
+
+/// Plugins declared by a project
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectManifest {
+    /// Requirements by plugin name
+    #[serde(default)]
+    pub plugins: IndexMap<String, PluginRequirement>,
+}
+
+/// Version and source of a plugin a project uses
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginRequirement {
+    /// Acceptable plugin versions
+    pub version: VersionReq,
+    /// Directory of the plugin, relative to the project
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    /// Git repository of the plugin
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git: Option<String>,
+    /// Git branch to check out
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Git tag to check out
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// Git commit to check out
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rev: Option<String>,
+    /// API of the registry to download the plugin from, crates.io by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry: Option<String>,
+    /// Package name in the registry, the plugin name by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    /// Expected checksum of the plugin's files (`sha256:<hex>`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+}
+
+/// Where a plugin comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginSource {
+    /// Local directory, relative to the project
+    Path(PathBuf),
+    /// Git repository
+    Git {
+        /// Repository URL
+        url: String,
+        /// Branch, tag or commit to check out, the default branch if `None`
+        reference: Option<GitReference>,
+    },
+    /// Crates.io-compatible registry
+    Registry {
+        /// Registry API
+        api: String,
+        /// Package name
+        package: String,
+    },
+}
+
+/// Git reference of a plugin source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitReference {
+    /// Head of a branch
+    Branch(String),
+    /// Tag
+    Tag(String),
+    /// Commit
+    Rev(String),
+}
+
+impl PluginSource {
+    /// Lock file form of the source, without the resolved git commit
+    #[must_use]
+    pub fn id(&self) -> String {
+        match self {
+            Self::Path(path) => format!("path+{}", path.to_string_lossy().replace('\\', "/")),
+            Self::Git { url, reference } => match reference {
+                None => format!("git+{url}"),
+                Some(GitReference::Branch(branch)) => format!("git+{url}?branch={branch}"),
+                Some(GitReference::Tag(tag)) => format!("git+{url}?tag={tag}"),
+                Some(GitReference::Rev(rev)) => format!("git+{url}?rev={rev}"),
+            },
+            Self::Registry { api, package } => format!("registry+{api}#{package}"),
+        }
+    }
+}
+
+impl PluginRequirement {
+    /// Source of the plugin
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the requirement combines sources or sets git
+    /// references without a git source.
+    pub fn source(&self, name: &str) -> Result<PluginSource> {
+        let references: Vec<GitReference> = [
+            self.branch.clone().map(GitReference::Branch),
+            self.tag.clone().map(GitReference::Tag),
+            self.rev.clone().map(GitReference::Rev),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if references.len() > 1 {
+            return Err(LinkMLError::config(format!(
+                "Plugin '{name}' sets more than one of branch, tag and rev"
+            )));
+        }
+        let registry = self.registry.is_some() || self.package.is_some();
+
+        match (&self.path, &self.git) {
+            (Some(path), None) if references.is_empty() && !registry => {
+                Ok(PluginSource::Path(path.clone()))
+            }
+            (None, Some(url)) if !registry => Ok(PluginSource::Git {
+                url: url.clone(),
+                reference: references.into_iter().next(),
+            }),
+            (None, None) if references.is_empty() => Ok(PluginSource::Registry {
+                api: self
+                    .registry
+                    .as_deref()
+                    .unwrap_or(CRATES_IO_API)
+                    .trim_end_matches('/')
+                    .to_string(),
+                package: self.package.clone().unwrap_or_else(|| name.to_string()),
+            }),
+            _ => Err(LinkMLError::config(format!(
+                "Plugin '{name}' must have exactly one source: a path, a git repository \
+                 (with an optional branch, tag or rev) or a registry"
+            ))),
+        }
+    }
+}
+
+impl ProjectManifest {
+    /// Parse a project manifest
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest is invalid.
+    pub fn from_toml_str(content: &str) -> Result<Self> {
+        let manifest: Self = toml::from_str(content)
+            .map_err(|e| LinkMLError::parse(format!("Invalid plugin project manifest: {e}")))?;
+        for (name, requirement) in &manifest.plugins {
+            requirement.source(name)?;
+        }
+        Ok(manifest)
+    }
+
+    /// Read a project manifest
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is invalid.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_toml_str(&content).map_err(|e| LinkMLError::ParseError {
+            message: e.to_string(),
+            location: Some(path.to_string_lossy().to_string()),
+        })
+    }
+}
+
+/// Resolved plugins of a project
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockFile {
+    /// Lock file format version
+    pub version: u32,
+    /// Resolved plugins, by name
+    #[serde(default, rename = "plugin")]
+    pub plugins: Vec<LockedPlugin>,
+}
+
+/// A resolved plugin
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedPlugin {
+    /// Plugin name in the project manifest
+    pub name: String,
+    /// Exact version
+    pub version: Version,
+    /// Source, with the resolved commit for git sources (`git+<url>#<commit>`)
+    pub source: String,
+    /// Checksum of the plugin's files (`sha256:<hex>`)
+    pub checksum: String,
+}
+
+impl Default for LockFile {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            plugins: Vec::new(),
+        }
+    }
+}
+
+impl LockFile {
+    /// Parse a lock file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock file is invalid.
+    pub fn from_toml_str(content: &str) -> Result<Self> {
+        toml::from_str(content)
+            .map_err(|e| LinkMLError::parse(format!("Invalid plugin lock file: {e}")))
+    }
+
+    /// Read a lock file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is invalid.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_toml_str(&content)
+    }
+
+    /// Lock file contents
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lock file cannot be serialized.
+    pub fn to_toml_string(&self) -> Result<String> {
+        let body =
+            toml::to_string(self).map_err(|e| LinkMLError::SerializationError(e.to_string()))?;
+        Ok(format!(
+            "# Generated by linkml from {PROJECT_MANIFEST_FILE}; do not edit\n{body}"
+        ))
+    }
+
+    /// Locked plugin of a name
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&LockedPlugin> {
+        self.plugins.iter().find(|plugin| plugin.name == name)
+    }
+}
+
+impl LockedPlugin {
+    /// Resolved git commit of the source
+    #[must_use]
+    pub fn commit(&self) -> Option<&str> {
+        self.source
+            .starts_with("git+")
+            .then(|| self.source.rsplit_once('#').map(|(_, commit)| commit))
+            .flatten()
+    }
+
+    /// Whether the entry still satisfies a requirement
+    fn satisfies(&self, requirement: &PluginRequirement, source: &PluginSource) -> bool {
+        let source_matches = match source {
+            PluginSource::Git { .. } => self
+                .source
+                .rsplit_once('#')
+                .is_some_and(|(id, _)| id == source.id()),
+            _ => self.source == source.id(),
+        };
+        source_matches && requirement.version.matches(&self.version)
+    }
+}
+
+/// A plugin fetched, built and verified for a project
+#[derive(Debug, Clone)]
+pub struct ResolvedPlugin {
+    /// Plugin name in the project manifest
+    pub name: String,
+    /// Directory holding the plugin
+    pub directory: PathBuf,
+    /// Path of the plugin manifest
+    pub manifest_path: PathBuf,
+    /// Plugin manifest
+    pub manifest: PluginManifest,
+    /// Lock file entry
+    pub locked: LockedPlugin,
+}
+
+/// Resolves the plugins of a project manifest
+#[derive(Debug, Clone)]
+pub struct ProjectResolver {
+    project_dir: PathBuf,
+    cache_dir: PathBuf,
+    locked: bool,
+    build: bool,
+    client: reqwest::Client,
+}
+
+impl ProjectResolver {
+    /// Create a resolver for the project in a directory
+    ///
+    /// Git and registry plugins are cached in `~/.linkml/plugin-cache`.
+    #[must_use]
+    pub fn new(project_dir: impl Into<PathBuf>) -> Self {
+        let project_dir = project_dir.into();
+        let cache_dir = dirs::home_dir().map_or_else(
+            || project_dir.join(".linkml").join("plugin-cache"),
+            |home| home.join(".linkml").join("plugin-cache"),
+        );
+        Self {
+            project_dir,
+            cache_dir,
+            locked: false,
+            build: true,
+            client: crate::schema::mapping_suggest::http_client(),
+        }
+    }
+
+    /// Cache git checkouts and registry downloads in a directory
+    #[must_use]
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = cache_dir.into();
+        self
+    }
+
+    /// Require the lock file to cover the manifest, never changing it
+    #[must_use]
+    pub fn locked(mut self, locked: bool) -> Self {
+        self.locked = locked;
+        self
+    }
+
+    /// Whether to run the build commands of plugin manifests
+    #[must_use]
+    pub fn build(mut self, build: bool) -> Self {
+        self.build = build;
+        self
+    }
+
+    /// Resolve the project's manifest against its lock file and write the
+    /// updated lock file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest cannot be read, a plugin cannot be
+    /// resolved, or the lock file cannot be written.
+    pub async fn resolve_project(&self) -> Result<Vec<ResolvedPlugin>> {
+        let manifest = ProjectManifest::from_file(&self.project_dir.join(PROJECT_MANIFEST_FILE))?;
+        let lock_path = self.project_dir.join(PROJECT_LOCK_FILE);
+        let lock = if lock_path.is_file() {
+            Some(LockFile::from_file(&lock_path)?)
+        } else {
+            None
+        };
+
+        let (plugins, updated) = self.resolve(&manifest, lock.as_ref()).await?;
+        if lock.as_ref() != Some(&updated) {
+            tokio::fs::write(&lock_path, updated.to_toml_string()?).await?;
+        }
+        Ok(plugins)
+    }
+
+    /// Resolve the plugins of a manifest, reusing the versions and commits
+    /// of a lock file where they still satisfy the manifest
+    ///
+    /// Returns the plugins and the lock file describing them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a plugin cannot be fetched or built, its version
+    /// does not satisfy the manifest, its files do not match a recorded
+    /// checksum, or in locked mode the lock file does not cover the manifest.
+    pub async fn resolve(
+        &self,
+        manifest: &ProjectManifest,
+        lock: Option<&LockFile>,
+    ) -> Result<(Vec<ResolvedPlugin>, LockFile)> {
+        let mut plugins = Vec::new();
+        for (name, requirement) in &manifest.plugins {
+            let source = requirement.source(name)?;
+            let locked = lock
+                .and_then(|lock| lock.get(name))
+                .filter(|locked| locked.satisfies(requirement, &source));
+            if self.locked && locked.is_none() {
+                return Err(LinkMLError::config(format!(
+                    "Plugin '{name}' is not locked in {PROJECT_LOCK_FILE} as required by \
+                     {PROJECT_MANIFEST_FILE}; resolve without locked mode to update it"
+                )));
+            }
+            plugins.push(
+                self.resolve_plugin(name, requirement, &source, locked)
+                    .await?,
+            );
+        }
+
+        let mut entries: Vec<LockedPlugin> =
+            plugins.iter().map(|plugin| plugin.locked.clone()).collect();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        let updated = LockFile {
+            plugins: entries,
+            ..LockFile::default()
+        };
+        if self.locked && lock != Some(&updated) {
+            return Err(LinkMLError::config(format!(
+                "{PROJECT_LOCK_FILE} is out of date with {PROJECT_MANIFEST_FILE}"
+            )));
+        }
+        Ok((plugins, updated))
+    }
+
+    async fn resolve_plugin(
+        &self,
+        name: &str,
+        requirement: &PluginRequirement,
+        source: &PluginSource,
+        locked: Option<&LockedPlugin>,
+    ) -> Result<ResolvedPlugin> {
+        let (directory, source_id) = match source {
+            PluginSource::Path(path) => (self.project_dir.join(path), source.id()),
+            PluginSource::Git { url, reference } => {
+                let commit = locked.and_then(LockedPlugin::commit);
+                let (directory, commit) =
+                    self.checkout(name, url, reference.as_ref(), commit).await?;
+                (directory, format!("{}#{commit}", source.id()))
+            }
+            PluginSource::Registry { api, package } => {
+                let version = match locked {
+                    Some(locked) => locked.version.clone(),
+                    None => {
+                        self.latest_version(api, package, &requirement.version)
+                            .await?
+                    }
+                };
+                (self.download(api, package, &version).await?, source.id())
+            }
+        };
+
+        let manifest_path = PluginDiscovery::new()
+            .discover(&directory, DiscoveryStrategy::Shallow)?
+            .into_iter()
+            .min()
+            .ok_or_else(|| {
+                LinkMLError::config(format!(
+                    "Plugin '{name}' has no plugin manifest in {}",
+                    directory.display()
+                ))
+            })?;
+        let manifest = read_plugin_manifest(&manifest_path)?;
+        let version = manifest.plugin.version.clone();
+        if !requirement.version.matches(&version) {
+            return Err(LinkMLError::config(format!(
+                "Plugin '{name}' has version {version}, which does not satisfy {}",
+                requirement.version
+            )));
+        }
+
+        // Checksums cover the sources, so they are taken before building
+        let checksum = directory_checksum(&directory)?;
+        let expected = locked
+            .map(|locked| (locked.checksum.as_str(), PROJECT_LOCK_FILE))
+            .into_iter()
+            .chain(
+                requirement
+                    .checksum
+                    .as_deref()
+                    .map(|checksum| (checksum, PROJECT_MANIFEST_FILE)),
+            );
+        for (expected, file) in expected {
+            if expected != checksum {
+                return Err(LinkMLError::config(format!(
+                    "Checksum mismatch for plugin '{name}': {file} records {expected}, \
+                     the files in {} have {checksum}",
+                    directory.display()
+                )));
+            }
+        }
+
+        if self.build
+            && let Some(command) = manifest.build.as_ref().and_then(|b| b.command.as_ref())
+        {
+            let build = manifest.build.as_ref();
+            let mut shell = shell_command(command);
+            shell.current_dir(
+                directory.join(build.and_then(|b| b.directory.as_deref()).unwrap_or(".")),
+            );
+            if let Some(env) = build.and_then(|b| b.env.as_ref()) {
+                shell.envs(env);
+            }
+            run(&mut shell, &format!("Building plugin '{name}'")).await?;
+        }
+
+        Ok(ResolvedPlugin {
+            name: name.to_string(),
+            directory,
+            manifest_path,
+            manifest,
+            locked: LockedPlugin {
+                name: name.to_string(),
+                version,
+                source: source_id,
+                checksum,
+            },
+        })
+    }
+
+    /// Check out a git source in the cache, returning the checkout and its
+    /// commit
+    async fn checkout(
+        &self,
+        name: &str,
+        url: &str,
+        reference: Option<&GitReference>,
+        commit: Option<&str>,
+    ) -> Result<(PathBuf, String)> {
+        // Anything starting with `-` would be read as a git option
+        let revisions = [
+            commit,
+            reference.map(|reference| match reference {
+                GitReference::Branch(name) | GitReference::Tag(name) | GitReference::Rev(name) => {
+                    name.as_str()
+                }
+            }),
+        ];
+        if let Some(revision) = revisions
+            .into_iter()
+            .flatten()
+            .find(|revision| revision.starts_with('-'))
+        {
+            return Err(LinkMLError::config(format!(
+                "Invalid git revision '{revision}' for plugin '{name}'"
+            )));
+        }
+
+        let url_hash = format!("{:x}", Sha256::digest(url.as_bytes()));
+        let directory = self
+            .cache_dir
+            .join("git")
+            .join(format!("{name}-{}", &url_hash[..16]));
+        let what = format!("Fetching plugin '{name}' from {url}");
+        if directory.join(".git").is_dir() {
+            run(
+                git(&directory).args(["fetch", "--quiet", "--tags", "--force", "origin"]),
+                &what,
+            )
+            .await?;
+        } else {
+            tokio::fs::create_dir_all(&self.cache_dir.join("git")).await?;
+            let mut clone = Command::new("git");
+            clone.args(["clone", "--quiet", "--", url]).arg(&directory);
+            run(&mut clone, &what).await?;
+        }
+
+        let target = match (commit, reference) {
+            (Some(commit), _) => commit.to_string(),
+            (None, Some(GitReference::Branch(branch))) => format!("origin/{branch}"),
+            (None, Some(GitReference::Tag(tag))) => format!("refs/tags/{tag}"),
+            (None, Some(GitReference::Rev(rev))) => rev.clone(),
+            (None, None) => "origin/HEAD".to_string(),
+        };
+        run(
+            git(&directory).args(["checkout", "--quiet", "--detach", &target, "--"]),
+            &format!("Checking out {target} of plugin '{name}'"),
+        )
+        .await?;
+        let output = git(&directory)
+            .args(["rev-parse", "HEAD"])
+            .output()
+            .await
+            .map_err(|e| LinkMLError::service(format!("Cannot run git: {e}")))?;
+        let commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if !output.status.success() || commit.is_empty() {
+            return Err(LinkMLError::service(format!(
+                "Cannot determine the commit of plugin '{name}'"
+            )));
+        }
+        Ok((directory, commit))
+    }
+
+    /// Highest version of a registry package satisfying a requirement
+    async fn latest_version(
+        &self,
+        api: &str,
+        package: &str,
+        requirement: &VersionReq,
+    ) -> Result<Version> {
+        let versions = self.versions(api, package).await?;
+        versions
+            .iter()
+            .filter(|version| !version.yanked)
+            .filter_map(|version| Version::parse(&version.num).ok())
+            .filter(|version| requirement.matches(version))
+            .max()
+            .ok_or_else(|| {
+                LinkMLError::config(format!(
+                    "No version of '{package}' in {api} satisfies {requirement}"
+                ))
+            })
+    }
+
+    /// Published versions of a registry package
+    async fn versions(&self, api: &str, package: &str) -> Result<Vec<RegistryVersion>> {
+        let url = format!("{api}/crates/{package}/versions");
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| LinkMLError::service(format!("Request to {url} failed: {e}")))?;
+        let body: RegistryVersions = response
+            .json()
+            .await
+            .map_err(|e| LinkMLError::service(format!("Invalid response from {url}: {e}")))?;
+        Ok(body.versions)
+    }
+
+    /// Download and unpack a registry package into the cache, verifying the
+    /// archive against the registry's checksum
+    async fn download(&self, api: &str, package: &str, version: &Version) -> Result<PathBuf> {
+        let directory = self.cache_dir.join("registry");
+        let unpacked = directory.join(format!("{package}-{version}"));
+        if unpacked.is_dir() {
+            return Ok(unpacked);
+        }
+
+        let expected = self
+            .versions(api, package)
+            .await?
+            .into_iter()
+            .find(|candidate| candidate.num == version.to_string())
+            .and_then(|candidate| candidate.checksum)
+            .ok_or_else(|| {
+                LinkMLError::service(format!("{api} has no checksum for {package} {version}"))
+            })?;
+        let url = format!("{api}/crates/{package}/{version}/download");
+        let archive = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| LinkMLError::service(format!("Request to {url} failed: {e}")))?
+            .bytes()
+            .await
+            .map_err(|e| LinkMLError::service(format!("Download of {url} failed: {e}")))?;
+        let actual = format!("{:x}", Sha256::digest(&archive));
+        if actual != expected {
+            return Err(LinkMLError::service(format!(
+                "Checksum mismatch for {package} {version}: {api} records {expected}, \
+                 the download has {actual}"
+            )));
+        }
+
+        tokio::fs::create_dir_all(&directory).await?;
+        let archive_path = directory.join(format!("{package}-{version}.crate"));
+        tokio::fs::write(&archive_path, &archive).await?;
+        let mut tar = Command::new("tar");
+        tar.arg("-xzf").arg(&archive_path).arg("-C").arg(&directory);
+        run(&mut tar, &format!("Unpacking {package} {version}")).await?;
+        tokio::fs::remove_file(&archive_path).await?;
+        Ok(unpacked)
+    }
+}
+
+/// Response of the versions endpoint of a crates.io-compatible registry
+#[derive(Debug, Deserialize)]
+struct RegistryVersions {
+    versions: Vec<RegistryVersion>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RegistryVersion {
+    num: String,
+    #[serde(default)]
+    yanked: bool,
+    #[serde(default)]
+    checksum: Option<String>,
+}
+
+/// Read a plugin manifest
+fn read_plugin_manifest(path: &Path) -> Result<PluginManifest> {
+    let content = std::fs::read_to_string(path)?;
+    toml::from_str(&content).map_err(|e| LinkMLError::ParseError {
+        message: format!("Invalid plugin manifest: {e}"),
+        location: Some(path.to_string_lossy().to_string()),
+    })
+}
+
+/// SHA-256 over the relative paths and contents of the files in a
+/// directory, skipping VCS metadata and build output
+///
+/// Only the top-level directories of those names are skipped, so source
+/// directories such as `src/build` are still covered.
+fn directory_checksum(directory: &Path) -> Result<String> {
+    const SKIPPED: &[&str] = &[".git", "target", "node_modules", "dist", "build"];
+    let mut files = Vec::new();
+    for entry in WalkDir::new(directory).into_iter().filter_entry(|entry| {
+        entry.depth() != 1
+            || !SKIPPED
+                .iter()
+                .any(|skipped| entry.file_name().to_string_lossy() == *skipped)
+    }) {
+        let entry = entry.map_err(|e| {
+            LinkMLError::IoError(std::io::Error::other(format!(
+                "Failed to read plugin directory {}: {e}",
+                directory.display()
+            )))
+        })?;
+        if entry.file_type().is_file() {
+            let relative = entry
+                .path()
+                .strip_prefix(directory)
+                .unwrap_or(entry.path())
+                .to_string_lossy()
+                .replace('\\', "/");
+            files.push((relative, entry.into_path()));
+        }
+    }
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for (relative, path) in files {
+        let content = std::fs::read(&path)?;
+        hasher.update(relative.as_bytes());
+        hasher.update([0]);
+        hasher.update((content.len() as u64).to_le_bytes());
+        hasher.update(&content);
+    }
+    Ok(format!("sha256:{:x}", hasher.finalize()))
+}
+
+/// Git command in a repository
+fn git(directory: &Path) -> Command {
+    let mut command = Command::new("git");
+    command.arg("-C").arg(directory);
+    command
+}
+
+/// Command line run by the platform shell
+fn shell_command(command_line: &str) -> Command {
+    if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", command_line]);
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.args(["-c", command_line]);
+        command
+    }
+}
+
+/// Run a command to completion
+async fn run(command: &mut Command, what: &str) -> Result<()> {
+    let output = command
+        .output()
+        .await
+        .map_err(|e| LinkMLError::service(format!("{what}: cannot run command: {e}")))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(LinkMLError::service(format!(
+            "{what} failed with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn plugin_manifest(version: &str) -> String {
+        format!(
+            r#"
+[plugin]
+id = "shacl-extras"
+name = "SHACL extras"
+description = "Additional SHACL shapes"
+version = "{version}"
+plugin_type = "Generator"
+linkml_version = ">=2.0.0"
+dependencies = []
+capabilities = ["CodeGeneration"]
+
+[entry_point]
+type = "Native"
+library = "libshacl_extras.so"
+
+[build]
+command = "mkdir -p build && echo built > build/out.log"
+"#
+        )
+    }
+
+    fn project(version: &str) -> std::result::Result<TempDir, Box<dyn std::error::Error>> {
+        let project = TempDir::new()?;
+        let plugin_dir = project.path().join("plugins").join("shacl-extras");
+        std::fs::create_dir_all(&plugin_dir)?;
+        std::fs::write(plugin_dir.join("plugin.toml"), plugin_manifest(version))?;
+        std::fs::write(
+            project.path().join(PROJECT_MANIFEST_FILE),
+            "[plugins.shacl-extras]\nversion = \"^0.3\"\npath = \"plugins/shacl-extras\"\n",
+        )?;
+        Ok(project)
+    }
+
+    #[test]
+    fn test_project_manifest_sources() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let manifest = ProjectManifest::from_toml_str(
+            r#"
+[plugins.local]
+version = "^0.3"
+path = "plugins/local"
+
+[plugins.remote]
+version = "1.2"
+git = "https://github.com/example/remote"
+tag = "v1.2.0"
+
+[plugins.published]
+version = "^2"
+package = "linkml-plugin-owl"
+"#,
+        )?;
+        let sources: Vec<String> = manifest
+            .plugins
+            .iter()
+            .map(|(name, requirement)| requirement.source(name).map(|source| source.id()))
+            .collect::<Result<_>>()?;
+        assert_eq!(
+            sources,
+            [
+                "path+plugins/local",
+                "git+https://github.com/example/remote?tag=v1.2.0",
+                "registry+https://crates.io/api/v1#linkml-plugin-owl",
+            ]
+        );
+
+        let ambiguous = "[plugins.x]\nversion = \"1\"\npath = \"x\"\ngit = \"https://x\"\n";
+        assert!(ProjectManifest::from_toml_str(ambiguous).is_err());
+        let stray_tag = "[plugins.x]\nversion = \"1\"\ntag = \"v1\"\n";
+        assert!(ProjectManifest::from_toml_str(stray_tag).is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_resolve_path_plugin_and_lock()
+    -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let project = project("0.3.1")?;
+        let resolver = ProjectResolver::new(project.path());
+
+        // Locked mode needs an existing lock file
+        assert!(
+            resolver
+                .clone()
+                .locked(true)
+                .resolve_project()
+                .await
+                .is_err()
+        );
+
+        let plugins = resolver.resolve_project().await?;
+        assert_eq!(plugins.len(), 1);
+        assert_eq!(plugins[0].locked.version, Version::new(0, 3, 1));
+        assert_eq!(plugins[0].locked.source, "path+plugins/shacl-extras");
+        assert!(plugins[0].directory.join("build/out.log").is_file());
+
+        let lock = LockFile::from_file(&project.path().join(PROJECT_LOCK_FILE))?;
+        assert_eq!(lock.plugins, [plugins[0].locked.clone()]);
+        // Build output does not change the checksum
+        resolver.clone().locked(true).resolve_project().await?;
+
+        // Changed plugin files no longer match the lock file
+        std::fs::write(
+            plugins[0].directory.join("plugin.toml"),
+            plugin_manifest("0.3.2"),
+        )?;
+        let error = resolver.resolve_project().await.unwrap_err().to_string();
+        assert!(error.contains("Checksum mismatch for plugin 'shacl-extras'"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_resolve_git_plugin_keeps_locked_commit()
+    -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let repository = TempDir::new()?;
+        let commit = |message: &str| {
+            std::process::Command::new("git")
+                .arg("-C")
+                .arg(repository.path())
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.org"])
+                .args(["commit", "--quiet", "--allow-empty", "-am", message])
+                .status()
+        };
+        std::process::Command::new("git")
+            .args(["init", "--quiet"])
+            .arg(repository.path())
+            .status()?;
+        std::fs::write(
+            repository.path().join("plugin.toml"),
+            plugin_manifest("0.3.1"),
+        )?;
+        std::process::Command::new("git")
+            .arg("-C")
+            .arg(repository.path())
+            .args(["add", "plugin.toml"])
+            .status()?;
+        commit("first")?;
+
+        let project = TempDir::new()?;
+        let cache = TempDir::new()?;
+        let manifest = ProjectManifest::from_toml_str(&format!(
+            "[plugins.shacl-extras]\nversion = \"^0.3\"\ngit = \"{}\"\n",
+            repository.path().display()
+        ))?;
+        let resolver = ProjectResolver::new(project.path())
+            .with_cache_dir(cache.path())
+            .build(false);
+        let (_, lock) = resolver.resolve(&manifest, None).await?;
+        let first = lock.plugins[0].commit().map(str::to_string);
+        assert_eq!(first.as_deref().map(str::len), Some(40));
+
+        // New upstream commits are only picked up without the lock file
+        std::fs::write(
+            repository.path().join("plugin.toml"),
+            plugin_manifest("0.3.2"),
+        )?;
+        commit("second")?;
+        let (plugins, relocked) = resolver.resolve(&manifest, Some(&lock)).await?;
+        assert_eq!(relocked, lock);
+        assert_eq!(plugins[0].manifest.plugin.version, Version::new(0, 3, 1));
+        let (plugins, updated) = resolver.resolve(&manifest, None).await?;
+        assert_ne!(updated.plugins[0].commit(), first.as_deref());
+        assert_eq!(plugins[0].manifest.plugin.version, Version::new(0, 3, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_checksum_skips_only_top_level_build_output()
+    -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let plugin = TempDir::new()?;
+        std::fs::create_dir_all(plugin.path().join("build"))?;
+        std::fs::create_dir_all(plugin.path().join("src/build"))?;
+        std::fs::write(plugin.path().join("src/build/mod.rs"), "fn a() {}")?;
+        let before = directory_checksum(plugin.path())?;
+
+        std::fs::write(plugin.path().join("build/out.log"), "built")?;
+        assert_eq!(directory_checksum(plugin.path())?, before);
+
+        std::fs::write(plugin.path().join("src/build/mod.rs"), "fn b() {}")?;
+        assert_ne!(directory_checksum(plugin.path())?, before);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_resolve_rejects_option_like_git_revision()
+    -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let project = TempDir::new()?;
+        let cache = TempDir::new()?;
+        let manifest = ProjectManifest::from_toml_str(
+            "[plugins.remote]\nversion = \"^1\"\ngit = \"https://github.com/example/remote\"\nrev = \"--upload-pack=touch pwned\"\n",
+        )?;
+        let error = ProjectResolver::new(project.path())
+            .with_cache_dir(cache.path())
+            .build(false)
+            .resolve(&manifest, None)
+            .await
+            .expect_err("option-like revision is rejected")
+            .to_string();
+        assert!(error.contains("Invalid git revision '--upload-pack=touch pwned'"));
+        assert!(!cache.path().join("git").exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_resolve_rejects_unsatisfied_version()
+    -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let project = project("0.4.0")?;
+        let error = ProjectResolver::new(project.path())
+            .build(false)
+            .resolve_project()
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("does not satisfy ^0.3"));
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+"""Host running a Python LinkML generator or validation plugin for linkml-rs
+
+Usage: python -c <this file> <module> <class>
+
+Speaks the subprocess plugin protocol: newline-delimited JSON-RPC 2.0 over
+stdin and stdout. Generators are subclasses of `linkml.utils.generator.Generator`
+and are constructed with the schema and the requested format; validation
+plugins implement `process(instance, context)` as in `linkml.validator`.
+"""
+
+import importlib
+import json
+import os
+import sys
+import tempfile
+import traceback
+
+PROTOCOL_VERSION = 1
+
+
+class Host:
+    def __init__(self, module_name, class_name):
+        self.plugin_class = getattr(importlib.import_module(module_name), class_name)
+        self.config = {}
+
+    def initialize(self, params):
+        self.config = params.get("config") or {}
+        formats = getattr(self.plugin_class, "valid_formats", None) or []
+        return {"protocol_version": PROTOCOL_VERSION, "formats": list(formats)}
+
+    def generate(self, params):
+        options = {**self.config, **(params.get("options") or {})}
+        if params.get("format"):
+            options["format"] = params["format"]
+        with SchemaFile(params["schema"]) as path:
+            generator = self.plugin_class(path, **options)
+            return {"output": generator.serialize()}
+
+    def validate(self, params):
+        from linkml.validator.validation_context import ValidationContext
+        from linkml_runtime.utils.schemaview import SchemaView
+
+        options = {**self.config, **(params.get("options") or {})}
+        target_class = options.pop("target_class", None)
+        with SchemaFile(params["schema"]) as path:
+            schema = SchemaView(path).schema
+        plugin = self.plugin_class(**options)
+        context = ValidationContext(schema, target_class)
+        errors, warnings = [], []
+        for result in plugin.process(params["data"], context):
+            severity = str(getattr(result.severity, "value", result.severity)).upper()
+            issue = {"message": result.message, "path": None, "code": result.type}
+            (errors if severity in ("ERROR", "FATAL") else warnings).append(issue)
+        return {"valid": not errors, "errors": errors, "warnings": warnings}
+
+
+class SchemaFile:
+    """Schema written to a temporary file, as LinkML generators expect a path"""
+
+    def __init__(self, schema):
+        self.schema = schema
+        self.path = None
+
+    def __enter__(self):
+        handle, self.path = tempfile.mkstemp(suffix=".yaml")
+        with os.fdopen(handle, "w") as file:
+            json.dump(self.schema, file)
+        return self.path
+
+    def __exit__(self, *exc):
+        os.unlink(self.path)
+
+
+def main():
+    protocol = sys.stdout
+    # Output of the plugin itself must not corrupt the protocol stream
+    sys.stdout = sys.stderr
+    host = Host(sys.argv[1], sys.argv[2])
+    methods = {
+        "initialize": host.initialize,
+        "generate": host.generate,
+        "validate": host.validate,
+    }
+    for line in sys.stdin:
+        if not line.strip():
+            continue
+        request = json.loads(line)
+        response = {"jsonrpc": "2.0", "id": request.get("id")}
+        method = request.get("method")
+        if method == "shutdown":
+            response["result"] = None
+        elif method not in methods:
+            response["error"] = {"code": -32601, "message": f"Unknown method {method}"}
+        else:
+            try:
+                response["result"] = methods[method](request.get("params") or {})
+            except Exception as error:
+                response["error"] = {
+                    "code": -32000,
+                    "message": f"{type(error).__name__}: {error}",
+                    "data": traceback.format_exc(),
+                }
+        protocol.write(json.dumps(response) + "\n")
+        protocol.flush()
+        if method == "shutdown":
+            break
+
+
+if __name__ == "__main__":
+    main()
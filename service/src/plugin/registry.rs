@@ -586,7 +586,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_plugin_registration() -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let timestamp_service = timestamp_service::wiring::wire_timestamp().into_inner();
+        let timestamp_service = crate::utils::timestamp::wire_timestamp();
         let registry = PluginRegistry::new(timestamp_service);
 
         let plugin = Box::new(MockPlugin {
@@ -622,7 +622,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_plugin_lifecycle() -> std::result::Result<(), Box<dyn std::error::Error>> {
-        let timestamp_service = timestamp_service::wiring::wire_timestamp().into_inner();
+        let timestamp_service = crate::utils::timestamp::wire_timestamp();
         let registry = PluginRegistry::new(timestamp_service);
 
         let plugin = Box::new(MockPlugin {
@@ -0,0 +1,601 @@
+//! Out-of-process plugins
+//!
+//! A [`SubprocessPlugin`] runs a plugin as a child process and talks to it
+//! with newline-delimited JSON-RPC 2.0 over its stdin and stdout, so plugins
+//! can be written in any language. The methods of the protocol are:
+//!
+//! | Method       | Params                        | Result                          |
+//! |--------------|-------------------------------|---------------------------------|
+//! | `initialize` | `protocol_version`, `config`  | `protocol_version`, `formats`   |
+//! | `generate`   | `schema`, `format`, `options` | `output`                        |
+//! | `validate`   | `schema`, `data`, `options`   | a [`ValidationResult`]          |
+//! | `shutdown`   |                               | `null`, after which it exits    |
+//!
+//! Schemas are sent as their `LinkML` `JSON` form. Notifications of method
+//! `log` with a `message` param are forwarded to the tracing log; the child's
+//! stderr is inherited.
+//!
+//! Existing Python `LinkML` generators and validation plugins need no
+//! changes: [`SubprocessPlugin::python`] runs them through a bundled host
+//! that implements the protocol for a module and class.
+
+use super::{
+    Deserialize, GeneratorPlugin, HashMap, LinkMLError, Path, PathBuf, Plugin, PluginContext,
+    PluginInfo, PluginStatus, Result, SchemaDefinition, ValidationResult, ValidatorPlugin,
+};
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::any::Any;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
+use tokio::sync::Mutex;
+
+/// Version of the subprocess plugin protocol
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Host running Python `LinkML` plugins
+const PYTHON_HOST: &str = include_str!("python_host.py");
+
+/// Environment variable naming the Python interpreter for Python plugins
+pub const PYTHON_ENV: &str = "LINKML_PYTHON";
+
+/// Plugin running in a child process
+pub struct SubprocessPlugin {
+    info: PluginInfo,
+    program: String,
+    args: Vec<String>,
+    working_dir: Option<PathBuf>,
+    env: HashMap<String, String>,
+    timeout: Duration,
+    status: parking_lot::Mutex<PluginStatus>,
+    formats: Vec<String>,
+    connection: Mutex<Option<Connection>>,
+}
+
+/// Pipes of a running plugin process
+struct Connection {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    next_id: u64,
+}
+
+/// Result of the `initialize` method
+#[derive(Debug, Deserialize)]
+struct Initialized {
+    protocol_version: u32,
+    #[serde(default)]
+    formats: Vec<String>,
+}
+
+impl SubprocessPlugin {
+    /// Plugin run by a program
+    #[must_use]
+    pub fn new(info: PluginInfo, program: impl Into<String>, args: Vec<String>) -> Self {
+        Self {
+            info,
+            program: program.into(),
+            args,
+            working_dir: None,
+            env: HashMap::new(),
+            timeout: Duration::from_secs(300),
+            status: parking_lot::Mutex::new(PluginStatus::Uninitialized),
+            formats: Vec::new(),
+            connection: Mutex::new(None),
+        }
+    }
+
+    /// Python `LinkML` plugin class, run by the interpreter in
+    /// [`PYTHON_ENV`] or `python3`
+    #[must_use]
+    pub fn python(info: PluginInfo, module: &str, class: &str) -> Self {
+        let python = std::env::var(PYTHON_ENV).unwrap_or_else(|_| "python3".to_string());
+        Self::new(
+            info,
+            python,
+            vec![
+                "-c".to_string(),
+                PYTHON_HOST.to_string(),
+                module.to_string(),
+                class.to_string(),
+            ],
+        )
+    }
+
+    /// Run the process in a directory
+    #[must_use]
+    pub fn current_dir(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.working_dir = Some(directory.into());
+        self
+    }
+
+    /// Set an environment variable of the process
+    #[must_use]
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.insert(key.into(), value.into());
+        self
+    }
+
+    /// Time allowed for each call, five minutes by default
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Start the process and initialize the plugin with a configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the process cannot be started or does not
+    /// initialize with a supported protocol version.
+    pub async fn start(&mut self, config: &HashMap<String, Value>) -> Result<()> {
+        *self.status.get_mut() = PluginStatus::Initializing;
+        let connection = match self.spawn() {
+            Ok(connection) => connection,
+            Err(e) => {
+                *self.status.get_mut() = PluginStatus::Error;
+                return Err(e);
+            }
+        };
+        *self.connection.get_mut() = Some(connection);
+
+        let params = json!({"protocol_version": PROTOCOL_VERSION, "config": config});
+        let initialized = self
+            .call("initialize", params)
+            .await
+            .and_then(|result| {
+                serde_json::from_value::<Initialized>(result).map_err(|e| {
+                    LinkMLError::service(format!(
+                        "Plugin '{}' answered initialize with an invalid result: {e}",
+                        self.info.id
+                    ))
+                })
+            })
+            .and_then(|initialized| {
+                if initialized.protocol_version == PROTOCOL_VERSION {
+                    Ok(initialized)
+                } else {
+                    Err(LinkMLError::service(format!(
+                        "Plugin '{}' speaks protocol version {}, expected {PROTOCOL_VERSION}",
+                        self.info.id, initialized.protocol_version
+                    )))
+                }
+            });
+        match initialized {
+            Ok(initialized) => {
+                self.formats = initialized.formats;
+                *self.status.get_mut() = PluginStatus::Ready;
+                Ok(())
+            }
+            Err(e) => {
+                *self.status.get_mut() = PluginStatus::Error;
+                *self.connection.get_mut() = None;
+                Err(e)
+            }
+        }
+    }
+
+    /// Call a method of the plugin
+    ///
+    /// A plugin that does not answer in time, or whose pipes fail, is killed
+    /// and put in the [`PluginStatus::Error`] state: a response may be half
+    /// read, so the connection cannot be used for another call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the plugin is not running, does not answer in
+    /// time, or answers with an error.
+    pub async fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let mut guard = self.connection.lock().await;
+        let connection = guard.as_mut().ok_or_else(|| {
+            LinkMLError::service(format!("Plugin '{}' is not running", self.info.id))
+        })?;
+        let error = match tokio::time::timeout(self.timeout, connection.call(method, params)).await
+        {
+            Ok(Ok(response)) => return self.result(method, response),
+            Ok(Err(e)) => format!("Plugin '{}' failed in {method}: {e}", self.info.id),
+            Err(_) => format!(
+                "Plugin '{}' did not answer {method} within {:?}",
+                self.info.id, self.timeout
+            ),
+        };
+        if let Some(mut connection) = guard.take()
+            && let Err(e) = connection.child.kill().await
+        {
+            tracing::warn!("Cannot kill plugin '{}': {e}", self.info.id);
+        }
+        *self.status.lock() = PluginStatus::Error;
+        Err(LinkMLError::service(error))
+    }
+
+    /// Result of a response, or its error
+    fn result(&self, method: &str, mut response: Value) -> Result<Value> {
+        if let Some(error) = response.get("error") {
+            let message = error
+                .get("message")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown error");
+            return Err(LinkMLError::service(format!(
+                "Plugin '{}' failed in {method}: {message}",
+                self.info.id
+            )));
+        }
+        Ok(response
+            .get_mut("result")
+            .map(Value::take)
+            .unwrap_or_default())
+    }
+
+    /// Spawn the process
+    fn spawn(&self) -> Result<Connection> {
+        let mut command = Command::new(&self.program);
+        command
+            .args(&self.args)
+            .envs(&self.env)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .kill_on_drop(true);
+        if let Some(directory) = &self.working_dir {
+            command.current_dir(directory);
+        }
+        let mut child = command.spawn().map_err(|e| {
+            LinkMLError::service(format!(
+                "Cannot start plugin '{}' with {}: {e}",
+                self.info.id, self.program
+            ))
+        })?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            return Err(LinkMLError::service(format!(
+                "Cannot open the pipes of plugin '{}'",
+                self.info.id
+            )));
+        };
+        Ok(Connection {
+            child,
+            stdin,
+            stdout: BufReader::new(stdout),
+            next_id: 1,
+        })
+    }
+}
+
+impl Connection {
+    /// Send a request and read lines until its response
+    async fn call(&mut self, method: &str, params: Value) -> std::io::Result<Value> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let request = json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params});
+        let mut line = serde_json::to_string(&request)?;
+        line.push('\n');
+        self.stdin.write_all(line.as_bytes()).await?;
+        self.stdin.flush().await?;
+
+        loop {
+            line.clear();
+            if self.stdout.read_line(&mut line).await? == 0 {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "the process exited",
+                ));
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+            let message: Value = serde_json::from_str(&line)?;
+            if message.get("id").and_then(Value::as_u64) == Some(id) {
+                return Ok(message);
+            }
+            if message.get("method").and_then(Value::as_str) == Some("log")
+                && let Some(text) = message.pointer("/params/message").and_then(Value::as_str)
+            {
+                tracing::info!("{text}");
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Plugin for SubprocessPlugin {
+    fn info(&self) -> &PluginInfo {
+        &self.info
+    }
+
+    async fn initialize(&mut self, context: PluginContext) -> Result<()> {
+        if self.working_dir.is_none() {
+            self.working_dir = Some(context.working_dir.clone());
+        }
+        self.start(&context.config).await
+    }
+
+    async fn shutdown(&mut self) -> Result<()> {
+        *self.status.get_mut() = PluginStatus::ShuttingDown;
+        let answered = self.call("shutdown", Value::Null).await;
+        if let Some(mut connection) = self.connection.get_mut().take() {
+            drop(connection.stdin);
+            if answered.is_err()
+                || tokio::time::timeout(Duration::from_secs(5), connection.child.wait())
+                    .await
+                    .is_err()
+            {
+                connection.child.kill().await?;
+            }
+        }
+        *self.status.get_mut() = PluginStatus::Shutdown;
+        Ok(())
+    }
+
+    fn validate_config(&self, _config: &HashMap<String, Value>) -> Result<()> {
+        Ok(())
+    }
+
+    fn status(&self) -> PluginStatus {
+        *self.status.lock()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[async_trait]
+impl GeneratorPlugin for SubprocessPlugin {
+    fn supported_formats(&self) -> Vec<String> {
+        self.formats.clone()
+    }
+
+    async fn generate(
+        &self,
+        schema: &SchemaDefinition,
+        format: &str,
+        options: HashMap<String, Value>,
+    ) -> Result<String> {
+        let params = json!({"schema": schema, "format": format, "options": options});
+        let result = self.call("generate", params).await?;
+        result
+            .get("output")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| {
+                LinkMLError::service(format!(
+                    "Plugin '{}' answered generate without an output",
+                    self.info.id
+                ))
+            })
+    }
+
+    fn options_schema(&self) -> Value {
+        json!({"type": "object"})
+    }
+
+    fn as_generator_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_generator_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[async_trait]
+impl ValidatorPlugin for SubprocessPlugin {
+    async fn validate(
+        &self,
+        schema: &SchemaDefinition,
+        data: &Value,
+        options: HashMap<String, Value>,
+    ) -> Result<ValidationResult> {
+        let params = json!({"schema": schema, "data": data, "options": options});
+        let result = self.call("validate", params).await?;
+        serde_json::from_value(result).map_err(|e| {
+            LinkMLError::service(format!(
+                "Plugin '{}' answered validate with an invalid result: {e}",
+                self.info.id
+            ))
+        })
+    }
+
+    fn options_schema(&self) -> Value {
+        json!({"type": "object"})
+    }
+}
+
+/// Subprocess plugin for a manifest entry point, relative to the manifest's
+/// directory
+pub(super) fn from_entry_point(
+    info: PluginInfo,
+    base_dir: &Path,
+    command: &str,
+    args: &[String],
+) -> SubprocessPlugin {
+    let program = if command.contains('/') || command.contains('\\') {
+        base_dir.join(command).to_string_lossy().to_string()
+    } else {
+        command.to_string()
+    };
+    SubprocessPlugin::new(info, program, args.to_vec()).current_dir(base_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::PluginType;
+    use super::*;
+    use semver::{Version, VersionReq};
+    use tempfile::TempDir;
+
+    fn info() -> PluginInfo {
+        PluginInfo {
+            id: "subprocess-test".to_string(),
+            name: "Subprocess Test".to_string(),
+            description: "Test".to_string(),
+            version: Version::new(1, 0, 0),
+            plugin_type: PluginType::Generator,
+            author: None,
+            license: None,
+            homepage: None,
+            linkml_version: VersionReq::STAR,
+            dependencies: vec![],
+            capabilities: vec![],
+        }
+    }
+
+    fn schema() -> SchemaDefinition {
+        SchemaDefinition {
+            id: "https://example.org/people".to_string(),
+            name: "people".to_string(),
+            ..Default::default()
+        }
+    }
+
+    const PROTOCOL_PLUGIN: &str = r#"
+import json, sys
+for line in sys.stdin:
+    request = json.loads(line)
+    method, params = request["method"], request.get("params")
+    if method == "initialize":
+        result = {"protocol_version": 1, "formats": ["names"]}
+    elif method == "generate":
+        print(json.dumps({"jsonrpc": "2.0", "method": "log", "params": {"message": "generating"}}))
+        result = {"output": params["schema"]["name"] + ":" + params["format"]}
+    elif method == "validate":
+        missing = [{"message": "name is required", "path": "$.name", "code": "required"}]
+        valid = "name" in params["data"]
+        result = {"valid": valid, "errors": [] if valid else missing, "warnings": []}
+    elif method == "shutdown":
+        result = None
+    else:
+        print(json.dumps({"jsonrpc": "2.0", "id": request["id"], "error": {"code": -32601, "message": "no " + method}}), flush=True)
+        continue
+    print(json.dumps({"jsonrpc": "2.0", "id": request["id"], "result": result}), flush=True)
+    if method == "shutdown":
+        break
+"#;
+
+    #[tokio::test]
+    async fn test_protocol_plugin() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let directory = TempDir::new()?;
+        std::fs::write(directory.path().join("plugin.py"), PROTOCOL_PLUGIN)?;
+        let mut plugin = from_entry_point(
+            info(),
+            directory.path(),
+            "python3",
+            &["plugin.py".to_string()],
+        );
+        plugin.start(&HashMap::new()).await?;
+        assert_eq!(plugin.status(), PluginStatus::Ready);
+        assert_eq!(plugin.supported_formats(), ["names"]);
+
+        let output = plugin.generate(&schema(), "names", HashMap::new()).await?;
+        assert_eq!(output, "people:names");
+
+        let result = plugin
+            .validate(&schema(), &json!({"age": 3}), HashMap::new())
+            .await?;
+        assert!(!result.valid);
+        assert_eq!(result.errors[0].path.as_deref(), Some("$.name"));
+
+        let error = plugin.call("explode", Value::Null).await.unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("Plugin 'subprocess-test' failed in explode: no explode")
+        );
+
+        plugin.shutdown().await?;
+        assert_eq!(plugin.status(), PluginStatus::Shutdown);
+        assert!(plugin.call("generate", Value::Null).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_python_generator_host() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let directory = TempDir::new()?;
+        std::fs::write(
+            directory.path().join("upper_gen.py"),
+            r#"
+import json
+
+class UpperGenerator:
+    valid_formats = ["upper", "lower"]
+
+    def __init__(self, schema, format="upper", **kwargs):
+        with open(schema) as file:
+            self.name = json.load(file)["name"]
+        self.format = format
+
+    def serialize(self):
+        print("plugin output stays off the protocol stream")
+        return self.name.upper() if self.format == "upper" else self.name
+
+class Broken:
+    def __init__(self, schema, **kwargs):
+        raise ValueError("no schema for you")
+"#,
+        )?;
+        let path = directory.path().to_string_lossy().to_string();
+
+        let mut plugin = SubprocessPlugin::python(info(), "upper_gen", "UpperGenerator")
+            .env("PYTHONPATH", &path);
+        plugin.start(&HashMap::new()).await?;
+        assert_eq!(plugin.supported_formats(), ["upper", "lower"]);
+        let output = plugin.generate(&schema(), "upper", HashMap::new()).await?;
+        assert_eq!(output, "PEOPLE");
+        plugin.shutdown().await?;
+
+        let mut broken =
+            SubprocessPlugin::python(info(), "upper_gen", "Broken").env("PYTHONPATH", &path);
+        broken.start(&HashMap::new()).await?;
+        let error = broken
+            .generate(&schema(), "upper", HashMap::new())
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("ValueError: no schema for you"));
+        broken.shutdown().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_timeout_kills_plugin() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let directory = TempDir::new()?;
+        std::fs::write(
+            directory.path().join("plugin.py"),
+            r#"
+import json, sys, time
+for line in sys.stdin:
+    request = json.loads(line)
+    if request["method"] == "generate":
+        time.sleep(30)
+    result = {"protocol_version": 1, "formats": ["slow"]}
+    print(json.dumps({"jsonrpc": "2.0", "id": request["id"], "result": result}), flush=True)
+"#,
+        )?;
+        let mut plugin = from_entry_point(
+            info(),
+            directory.path(),
+            "python3",
+            &["plugin.py".to_string()],
+        )
+        .timeout(Duration::from_secs(2));
+        plugin.start(&HashMap::new()).await?;
+
+        let error = plugin
+            .generate(&schema(), "slow", HashMap::new())
+            .await
+            .expect_err("generate times out");
+        assert!(error.to_string().contains("did not answer generate"));
+        assert_eq!(plugin.status(), PluginStatus::Error);
+        let error = plugin
+            .call("generate", Value::Null)
+            .await
+            .expect_err("connection dropped");
+        assert!(error.to_string().contains("is not running"));
+        Ok(())
+    }
+}
@@ -14,5 +14,8 @@ pub use crate::factory::{create_linkml_service, create_linkml_service_with_confi
 // Re-export parser utilities
 pub use crate::parser::{JsonParser, Parser, SchemaParser, YamlParser};
 
+// Re-export schema construction
+pub use crate::schema::builder::{ClassBuilder, EnumBuilder, SchemaBuilder, SlotBuilder};
+
 // Re-export validation types
 pub use crate::validator::ValidationReport;
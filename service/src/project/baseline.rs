@@ -0,0 +1,205 @@
+//! Validation baselines
+//!
+//! A baseline records the issues a data set is known to have, so `linkml
+//! check` can adopt validation on legacy data: accepted issues pass, new
+//! issues fail, and fixed issues are reported so the baseline can shrink.
+
+use crate::validator::report::{Severity, ValidationIssue, ValidationReport};
+use linkml_core::error::{LinkMLError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// An issue identified independently of its position in the report
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BaselineIssue {
+    /// Stable error code, or the validator name if the issue has none
+    pub code: String,
+    /// JSON path of the issue
+    pub path: String,
+    /// Issue message
+    pub message: String,
+}
+
+impl BaselineIssue {
+    fn of(issue: &ValidationIssue) -> Self {
+        Self {
+            code: issue
+                .code
+                .clone()
+                .unwrap_or_else(|| issue.validator.clone()),
+            path: issue.path.clone(),
+            message: issue.message.clone(),
+        }
+    }
+}
+
+/// Accepted errors and warnings per data file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationBaseline {
+    /// Data file, relative to the project root, to its accepted issues
+    #[serde(default)]
+    pub files: BTreeMap<String, BTreeSet<BaselineIssue>>,
+}
+
+/// Difference between validation reports and a baseline
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BaselineComparison {
+    /// Issues missing from the baseline, per file
+    pub new: BTreeMap<String, Vec<BaselineIssue>>,
+    /// Baseline issues no longer reported, per file
+    pub fixed: BTreeMap<String, Vec<BaselineIssue>>,
+}
+
+impl BaselineComparison {
+    /// Whether no new issues were found
+    #[must_use]
+    pub fn passes(&self) -> bool {
+        self.new.is_empty()
+    }
+
+    /// Number of new issues
+    #[must_use]
+    pub fn new_count(&self) -> usize {
+        self.new.values().map(Vec::len).sum()
+    }
+
+    /// Number of fixed issues
+    #[must_use]
+    pub fn fixed_count(&self) -> usize {
+        self.fixed.values().map(Vec::len).sum()
+    }
+}
+
+impl ValidationBaseline {
+    /// Baseline accepting every error and warning of the reports
+    #[must_use]
+    pub fn from_reports(reports: &[(String, &ValidationReport)]) -> Self {
+        let files = reports
+            .iter()
+            .map(|(file, report)| (file.clone(), Self::issues(report)))
+            .filter(|(_, issues)| !issues.is_empty())
+            .collect();
+        Self { files }
+    }
+
+    /// Load a baseline; a missing file is an empty baseline
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or parsed
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            LinkMLError::io_error(format!("Failed to read baseline {}: {e}", path.display()))
+        })?;
+        serde_json::from_str(&content)
+            .map_err(|e| LinkMLError::config(format!("Invalid baseline {}: {e}", path.display())))
+    }
+
+    /// Save the baseline as JSON, creating missing parent directories
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| LinkMLError::SerializationError(e.to_string()))?;
+        std::fs::write(path, content + "\n").map_err(|e| {
+            LinkMLError::io_error(format!("Failed to write baseline {}: {e}", path.display()))
+        })
+    }
+
+    /// Compare validation reports with the baseline
+    #[must_use]
+    pub fn compare(&self, reports: &[(String, &ValidationReport)]) -> BaselineComparison {
+        let mut comparison = BaselineComparison::default();
+        let empty = BTreeSet::new();
+        let mut seen = BTreeSet::new();
+        for (file, report) in reports {
+            seen.insert(file.as_str());
+            let accepted = self.files.get(file).unwrap_or(&empty);
+            let current = Self::issues(report);
+            let new: Vec<_> = current.difference(accepted).cloned().collect();
+            let fixed: Vec<_> = accepted.difference(&current).cloned().collect();
+            if !new.is_empty() {
+                comparison.new.insert(file.clone(), new);
+            }
+            if !fixed.is_empty() {
+                comparison.fixed.insert(file.clone(), fixed);
+            }
+        }
+        // Files that disappeared from the data set no longer have their issues
+        for (file, accepted) in &self.files {
+            if !seen.contains(file.as_str()) {
+                comparison
+                    .fixed
+                    .insert(file.clone(), accepted.iter().cloned().collect());
+            }
+        }
+        comparison
+    }
+
+    fn issues(report: &ValidationReport) -> BTreeSet<BaselineIssue> {
+        report
+            .issues
+            .iter()
+            .filter(|issue| matches!(issue.severity, Severity::Error | Severity::Warning))
+            .map(BaselineIssue::of)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(messages: &[&str]) -> ValidationReport {
+        let mut report = ValidationReport::new("https://example.org/people");
+        for message in messages {
+            report.add_issue(ValidationIssue::error(*message, "$.age", "range_validator"));
+        }
+        report
+    }
+
+    #[test]
+    fn test_baseline_comparison() {
+        let before = report(&["age is negative", "age is not an integer"]);
+        let baseline = ValidationBaseline::from_reports(&[
+            ("data/a.yaml".to_string(), &before),
+            ("data/clean.yaml".to_string(), &report(&[])),
+            ("data/removed.yaml".to_string(), &report(&["stale"])),
+        ]);
+        assert_eq!(baseline.files.len(), 2);
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("baselines/people.json");
+        assert_eq!(
+            ValidationBaseline::load(&path).expect("missing"),
+            ValidationBaseline::default()
+        );
+        baseline.save(&path).expect("save");
+        let baseline = ValidationBaseline::load(&path).expect("load");
+
+        let unchanged = baseline.compare(&[
+            ("data/a.yaml".to_string(), &before),
+            ("data/removed.yaml".to_string(), &report(&["stale"])),
+        ]);
+        assert!(unchanged.passes());
+        assert_eq!(unchanged.fixed_count(), 0);
+
+        let after = report(&["age is negative", "age exceeds 150"]);
+        let changed = baseline.compare(&[("data/a.yaml".to_string(), &after)]);
+        assert!(!changed.passes());
+        assert_eq!(changed.new["data/a.yaml"][0].message, "age exceeds 150");
+        assert_eq!(changed.fixed_count(), 2);
+        assert!(changed.fixed.contains_key("data/removed.yaml"));
+    }
+}
@@ -0,0 +1,279 @@
+//! `LinkML` project files
+//!
+//! A `linkml.yaml` at the root of a repository describes the project once so
+//! commands no longer need long flag lists and CI reduces to `linkml check`:
+//!
+//! ```yaml
+//! name: registry
+//! schemas:
+//!   - schema/registry.yaml
+//! lint:
+//!   config: .linkmllint.yaml
+//!   strict: true
+//!   min_quality: 75
+//! validate:
+//!   - data: [data/people]
+//!     class: Person
+//!     baseline: baselines/people.json
+//!   - data: [data/legacy]
+//!     schema: schema/legacy.yaml
+//!     rules: rules/legacy.yaml
+//! generate:
+//!   - generator: python
+//!     output: gen/python
+//!   - generator: jsonschema
+//!     output: gen/registry.schema.json
+//!     options: [include_docs=false]
+//! ```
+//!
+//! Commands find the file by walking up from the working directory, or take
+//! it from `--project`. The first schema is the default of commands given
+//! none; data sets supply the schema, class and rules of the files under
+//! them; generation targets and baselines are run by `linkml check`. Relative
+//! paths resolve against the directory of the project file.
+
+mod baseline;
+
+pub use baseline::{BaselineComparison, BaselineIssue, ValidationBaseline};
+
+use linkml_core::error::{LinkMLError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// File name of project files
+pub const PROJECT_FILE: &str = "linkml.yaml";
+
+/// Lint policy of a project
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintPolicy {
+    /// Lint configuration file, instead of a `.linkmllint.yaml` next to the
+    /// schema
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<PathBuf>,
+    /// Fail on lint errors
+    #[serde(default)]
+    pub strict: bool,
+    /// Minimum schema quality score
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_quality: Option<f64>,
+}
+
+/// Data validated against a schema
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DataSet {
+    /// Data files and directories
+    pub data: Vec<PathBuf>,
+    /// Schema, the project's default schema if omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<PathBuf>,
+    /// Target class, tree root detection if omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    /// Rule configuration with per-rule severity overrides
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rules: Option<PathBuf>,
+    /// Known issues accepted until fixed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<PathBuf>,
+}
+
+/// Artifact generated from a schema
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenerationTarget {
+    /// Generator name, as for `linkml generate -g`
+    pub generator: String,
+    /// Output file or directory
+    pub output: PathBuf,
+    /// Schema, the project's default schema if omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<PathBuf>,
+    /// Generator options (`key=value`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+}
+
+/// Contents of a `linkml.yaml` project file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectManifest {
+    /// Project name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Schemas of the project, the first being the default
+    #[serde(default)]
+    pub schemas: Vec<PathBuf>,
+    /// Lint policy
+    #[serde(default)]
+    pub lint: LintPolicy,
+    /// Data sets to validate
+    #[serde(default)]
+    pub validate: Vec<DataSet>,
+    /// Generation pipeline
+    #[serde(default)]
+    pub generate: Vec<GenerationTarget>,
+}
+
+/// A project manifest with its paths resolved against the project root
+#[derive(Debug, Clone, PartialEq)]
+pub struct Project {
+    /// Directory of the project file
+    pub root: PathBuf,
+    /// Manifest with absolute or root-relative paths resolved
+    pub manifest: ProjectManifest,
+}
+
+impl Project {
+    /// Load a project file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid manifest
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            LinkMLError::io_error(format!(
+                "Failed to read project file {}: {e}",
+                path.display()
+            ))
+        })?;
+        let manifest: ProjectManifest = serde_yaml::from_str(&content).map_err(|e| {
+            LinkMLError::config(format!("Invalid project file {}: {e}", path.display()))
+        })?;
+        let root = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        let root = std::path::absolute(root).map_err(|e| {
+            LinkMLError::io_error(format!("Failed to resolve {}: {e}", root.display()))
+        })?;
+        Ok(Self::new(root, manifest))
+    }
+
+    /// Project of a manifest rooted at `root`
+    #[must_use]
+    pub fn new(root: PathBuf, mut manifest: ProjectManifest) -> Self {
+        let resolve = |path: &mut PathBuf| {
+            if path.is_relative() {
+                *path = root.join(&*path);
+            }
+        };
+        manifest.schemas.iter_mut().for_each(resolve);
+        manifest.lint.config.iter_mut().for_each(resolve);
+        for data_set in &mut manifest.validate {
+            data_set.data.iter_mut().for_each(resolve);
+            data_set.schema.iter_mut().for_each(resolve);
+            data_set.rules.iter_mut().for_each(resolve);
+            data_set.baseline.iter_mut().for_each(resolve);
+        }
+        for target in &mut manifest.generate {
+            resolve(&mut target.output);
+            target.schema.iter_mut().for_each(resolve);
+        }
+        Self { root, manifest }
+    }
+
+    /// Find the nearest project file in `start` or its ancestors
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a project file is found but cannot be loaded
+    pub fn discover(start: &Path) -> Result<Option<Self>> {
+        for dir in start.ancestors() {
+            let path = dir.join(PROJECT_FILE);
+            if path.is_file() {
+                return Self::load(&path).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Schema of commands given none
+    #[must_use]
+    pub fn default_schema(&self) -> Option<&Path> {
+        self.manifest.schemas.first().map(PathBuf::as_path)
+    }
+
+    /// Schema of a data set or generation target
+    #[must_use]
+    pub fn schema_or_default<'a>(&'a self, schema: Option<&'a PathBuf>) -> Option<&'a Path> {
+        schema
+            .map(PathBuf::as_path)
+            .or_else(|| self.default_schema())
+    }
+
+    /// Data set containing a data file, if any
+    #[must_use]
+    pub fn data_set_for(&self, file: &Path) -> Option<&DataSet> {
+        let file = std::path::absolute(file).unwrap_or_else(|_| file.to_path_buf());
+        self.manifest
+            .validate
+            .iter()
+            .find(|data_set| data_set.data.iter().any(|data| file.starts_with(data)))
+    }
+
+    /// A path relative to the project root, for reports and baselines
+    #[must_use]
+    pub fn relative(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_discovery_and_resolution() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("data/people/nested")).expect("mkdir");
+        std::fs::write(
+            root.join(PROJECT_FILE),
+            "name: registry\nschemas: [schema/registry.yaml]\nlint:\n  strict: true\n  min_quality: 75\nvalidate:\n  - data: [data/people]\n    class: Person\n    baseline: baselines/people.json\n  - data: [data/legacy]\n    schema: schema/legacy.yaml\ngenerate:\n  - generator: python\n    output: gen/python\n",
+        )
+        .expect("write project");
+
+        let project = Project::discover(&root.join("data/people/nested"))
+            .expect("discover")
+            .expect("project");
+        assert_eq!(project.root, root);
+        assert_eq!(project.manifest.name.as_deref(), Some("registry"));
+        assert!(project.manifest.lint.strict);
+        assert_eq!(
+            project.default_schema(),
+            Some(root.join("schema/registry.yaml").as_path())
+        );
+
+        let people = project
+            .data_set_for(&root.join("data/people/nested/alice.yaml"))
+            .expect("data set");
+        assert_eq!(people.class.as_deref(), Some("Person"));
+        assert_eq!(
+            people.baseline.as_deref(),
+            Some(root.join("baselines/people.json").as_path())
+        );
+        assert_eq!(
+            project.schema_or_default(people.schema.as_ref()),
+            project.default_schema()
+        );
+        let legacy = &project.manifest.validate[1];
+        assert_eq!(
+            project.schema_or_default(legacy.schema.as_ref()),
+            Some(root.join("schema/legacy.yaml").as_path())
+        );
+        assert!(project.data_set_for(&root.join("other/x.yaml")).is_none());
+        assert_eq!(project.manifest.generate[0].output, root.join("gen/python"));
+        assert_eq!(
+            project.relative(&root.join("data/people/a.yaml")),
+            "data/people/a.yaml"
+        );
+
+        std::fs::write(root.join(PROJECT_FILE), "schemas: []\nunknown: 1\n").expect("write");
+        assert!(Project::load(&root.join(PROJECT_FILE)).is_err());
+    }
+}
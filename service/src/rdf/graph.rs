@@ -0,0 +1,185 @@
+//! In-memory RDF graph indexed by subject
+
+use oxigraph::io::{RdfFormat, RdfParseError, RdfParser};
+use oxigraph::model::{NamedOrBlankNode, Term};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::io::Cursor;
+
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// Node of an RDF graph
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Node {
+    /// Named node
+    Iri(String),
+    /// Blank node with its label
+    Blank(String),
+    /// Literal with its datatype IRI and language tag
+    Literal {
+        /// Lexical value
+        value: String,
+        /// Datatype IRI
+        datatype: String,
+        /// Language tag of language-tagged strings
+        language: Option<String>,
+    },
+}
+
+impl Node {
+    /// IRI of a named node
+    pub fn iri(&self) -> Option<&str> {
+        match self {
+            Self::Iri(iri) => Some(iri),
+            _ => None,
+        }
+    }
+
+    /// Lexical value of a literal
+    pub fn literal(&self) -> Option<&str> {
+        match self {
+            Self::Literal { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Iri(iri) => write!(f, "<{iri}>"),
+            Self::Blank(label) => write!(f, "_:{label}"),
+            Self::Literal {
+                value,
+                language: Some(language),
+                ..
+            } => write!(f, "\"{value}\"@{language}"),
+            Self::Literal {
+                value, datatype, ..
+            } => match datatype.strip_prefix(XSD) {
+                Some("string") => write!(f, "\"{value}\""),
+                Some(local) => write!(f, "\"{value}\"^^xsd:{local}"),
+                None => write!(f, "\"{value}\"^^<{datatype}>"),
+            },
+        }
+    }
+}
+
+/// Triples of a graph indexed by subject
+#[derive(Debug, Default)]
+pub struct Graph {
+    /// `(predicate, object)` pairs by subject
+    statements: HashMap<Node, Vec<(String, Node)>>,
+    /// Subjects in order of first appearance
+    subjects: Vec<Node>,
+}
+
+impl Graph {
+    /// Parse the triples of `content` serialized in `format`
+    ///
+    /// # Errors
+    ///
+    /// Returns the parse error if the content is not valid in `format`.
+    pub fn parse(content: &str, format: RdfFormat) -> Result<Self, RdfParseError> {
+        let mut graph = Self::default();
+        for quad in RdfParser::from_format(format).for_reader(Cursor::new(content.as_bytes())) {
+            let quad = quad?;
+            let subject = match &quad.subject {
+                NamedOrBlankNode::NamedNode(node) => Node::Iri(node.as_str().to_string()),
+                NamedOrBlankNode::BlankNode(node) => Node::Blank(node.as_str().to_string()),
+            };
+            let object = match &quad.object {
+                Term::NamedNode(node) => Node::Iri(node.as_str().to_string()),
+                Term::BlankNode(node) => Node::Blank(node.as_str().to_string()),
+                Term::Literal(literal) => Node::Literal {
+                    value: literal.value().to_string(),
+                    datatype: literal.datatype().as_str().to_string(),
+                    language: literal.language().map(String::from),
+                },
+                #[allow(unreachable_patterns)]
+                _ => continue,
+            };
+            let statements = graph.statements.entry(subject.clone()).or_default();
+            if statements.is_empty() {
+                graph.subjects.push(subject);
+            }
+            statements.push((quad.predicate.as_str().to_string(), object));
+        }
+        Ok(graph)
+    }
+
+    /// Subjects in order of first appearance
+    pub fn subjects(&self) -> &[Node] {
+        &self.subjects
+    }
+
+    /// Objects of `subject` for `predicate`
+    pub fn objects<'a>(
+        &'a self,
+        subject: &Node,
+        predicate: &str,
+    ) -> impl Iterator<Item = &'a Node> {
+        self.statements
+            .get(subject)
+            .into_iter()
+            .flatten()
+            .filter(move |(p, _)| p == predicate)
+            .map(|(_, object)| object)
+    }
+
+    /// First object of `subject` for `predicate`
+    pub fn object(&self, subject: &Node, predicate: &str) -> Option<&Node> {
+        self.objects(subject, predicate).next()
+    }
+
+    /// Predicates of the statements about `subject`
+    pub fn predicates<'a>(&'a self, subject: &Node) -> impl Iterator<Item = &'a str> {
+        self.statements
+            .get(subject)
+            .into_iter()
+            .flatten()
+            .map(|(predicate, _)| predicate.as_str())
+    }
+
+    /// First literal of any of `predicates`, in order of preference
+    pub fn literal(&self, subject: &Node, predicates: &[&str]) -> Option<String> {
+        predicates
+            .iter()
+            .find_map(|predicate| self.objects(subject, predicate).find_map(Node::literal))
+            .map(String::from)
+    }
+
+    /// Whether `subject` has any of `types` as `rdf:type`
+    pub fn is_a(&self, subject: &Node, types: &[&str]) -> bool {
+        self.objects(subject, &format!("{RDF}type"))
+            .filter_map(Node::iri)
+            .any(|iri| types.contains(&iri))
+    }
+
+    /// Named subjects typed with any of `types`
+    pub fn typed(&self, types: &[&str]) -> Vec<String> {
+        self.subjects
+            .iter()
+            .filter(|subject| self.is_a(subject, types))
+            .filter_map(|subject| subject.iri().map(String::from))
+            .collect()
+    }
+
+    /// Members of an RDF list
+    pub fn list(&self, head: &Node) -> Vec<Node> {
+        let mut members = Vec::new();
+        let mut node = head.clone();
+        let mut seen = HashSet::new();
+        while node != Node::Iri(format!("{RDF}nil")) && seen.insert(node.clone()) {
+            if let Some(first) = self.object(&node, &format!("{RDF}first")) {
+                members.push(first.clone());
+            }
+            match self.object(&node, &format!("{RDF}rest")) {
+                Some(rest) => node = rest.clone(),
+                None => break,
+            }
+        }
+        members
+    }
+}
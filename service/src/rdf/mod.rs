@@ -0,0 +1,8 @@
+//! RDF support shared by the RDF-reading parts of the service
+//!
+//! The `ShEx` engine and the OWL introspector both read RDF into the
+//! subject-indexed [`Graph`] of this module.
+
+pub mod graph;
+
+pub use graph::{Graph, Node};
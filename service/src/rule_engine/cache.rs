@@ -46,7 +46,7 @@ impl Default for CacheConfig {
 impl CacheConfig {
     /// Create cache config from `LinkML` service configuration
     #[must_use]
-    pub fn from_service_config(config: &linkml_core::configuration_v2::RuleCacheConfig) -> Self {
+    pub fn from_service_config(config: &linkml_core::configuration::RuleCacheConfig) -> Self {
         Self {
             max_entries: config.max_entries,
             ttl: Duration::from_secs(config.ttl_seconds),
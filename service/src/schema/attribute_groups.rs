@@ -0,0 +1,175 @@
+//! Attribute groups
+//!
+//! Attribute groups are named sets of attributes, such as audit fields, that
+//! classes include by name instead of inheriting them from a mixin class:
+//!
+//! ```yaml
+//! attribute_groups:
+//!   audit_fields:
+//!     description: Who changed a record and when
+//!     attributes:
+//!       created_at:
+//!         range: datetime
+//!       created_by:
+//!         range: string
+//! classes:
+//!   Person:
+//!     attribute_groups: [audit_fields]
+//!     attributes:
+//!       name:
+//!         range: string
+//! ```
+//!
+//! Parsers expand the groups a schema defines into the attributes of the
+//! including classes, so validators and generators see ordinary attributes.
+//! Each expanded attribute records its group in a
+//! [`GROUP_ANNOTATION`] annotation. Attributes or slots a class declares itself
+//! win over group members of the same name. Groups defined in imported
+//! schemas are expanded once imports are resolved, where an unknown group is
+//! an error.
+
+use linkml_core::annotations::{AnnotationValue, Annotations};
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::{SchemaDefinition, SlotDefinition};
+
+/// Annotation naming the group an expanded attribute came from
+pub const GROUP_ANNOTATION: &str = "linkml:attribute_group";
+
+/// A class including an attribute group the schema does not define
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedGroup {
+    /// Including class
+    pub class: String,
+    /// Name of the missing group
+    pub group: String,
+}
+
+/// Expand the attribute groups included by classes into their attributes
+///
+/// Expansion is idempotent: attributes already present are left alone.
+/// Returns the included groups the schema does not define.
+pub fn expand_attribute_groups(schema: &mut SchemaDefinition) -> Vec<UnresolvedGroup> {
+    let mut unresolved = Vec::new();
+    for (class_name, class) in &mut schema.classes {
+        for group_name in &class.attribute_groups {
+            let Some(group) = schema.attribute_groups.get(group_name) else {
+                unresolved.push(UnresolvedGroup {
+                    class: class_name.clone(),
+                    group: group_name.clone(),
+                });
+                continue;
+            };
+            for (attribute_name, attribute) in &group.attributes {
+                if class.attributes.contains_key(attribute_name)
+                    || class.slots.contains(attribute_name)
+                {
+                    continue;
+                }
+                let mut attribute = attribute.clone();
+                if attribute.name.is_empty() {
+                    attribute.name.clone_from(attribute_name);
+                }
+                attribute
+                    .annotations
+                    .get_or_insert_with(Annotations::new)
+                    .insert(
+                        GROUP_ANNOTATION.to_string(),
+                        AnnotationValue::String(group_name.clone()),
+                    );
+                class.attributes.insert(attribute_name.clone(), attribute);
+            }
+        }
+    }
+    unresolved
+}
+
+/// A parsed schema with the attribute groups it defines expanded
+///
+/// Groups that may come from imports are left for [`resolve_attribute_groups`].
+#[must_use]
+pub fn expanded(mut schema: SchemaDefinition) -> SchemaDefinition {
+    expand_attribute_groups(&mut schema);
+    schema
+}
+
+/// Expand attribute groups of a schema whose imports are resolved
+///
+/// # Errors
+///
+/// Returns an error if a class includes a group that is not defined
+pub fn resolve_attribute_groups(schema: &mut SchemaDefinition) -> Result<()> {
+    let unresolved = expand_attribute_groups(schema);
+    if unresolved.is_empty() {
+        return Ok(());
+    }
+    let missing = unresolved
+        .iter()
+        .map(|missing| format!("'{}' in class '{}'", missing.group, missing.class))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Err(LinkMLError::schema_validation(format!(
+        "Unknown attribute groups: {missing}"
+    )))
+}
+
+/// The group an attribute was expanded from, if any
+#[must_use]
+pub fn attribute_group_of(attribute: &SlotDefinition) -> Option<&str> {
+    match attribute.annotations.as_ref()?.get(GROUP_ANNOTATION)? {
+        AnnotationValue::String(group) => Some(group),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{SchemaParser, YamlParser};
+
+    #[test]
+    fn test_attribute_group_expansion() {
+        let mut schema = YamlParser::new()
+            .parse_str(
+                r"
+id: https://example.org/registry
+name: registry
+attribute_groups:
+  audit_fields:
+    attributes:
+      created_at:
+        range: datetime
+      created_by:
+        range: string
+classes:
+  Person:
+    attribute_groups: [audit_fields]
+    attributes:
+      created_by:
+        range: Person
+  Dataset:
+    attribute_groups: [audit_fields, provenance_fields]
+",
+            )
+            .expect("schema");
+
+        let person = &schema.classes["Person"];
+        assert!(person.attributes.keys().eq(["created_by", "created_at"]));
+        assert_eq!(
+            person.attributes["created_by"].range.as_deref(),
+            Some("Person")
+        );
+        assert_eq!(attribute_group_of(&person.attributes["created_by"]), None);
+        let created_at = &person.attributes["created_at"];
+        assert_eq!(created_at.name, "created_at");
+        assert_eq!(attribute_group_of(created_at), Some("audit_fields"));
+
+        // The imported group is still missing once imports are resolved
+        let before = schema.clone();
+        let err = resolve_attribute_groups(&mut schema).expect_err("unknown group");
+        assert!(
+            err.to_string()
+                .contains("'provenance_fields' in class 'Dataset'")
+        );
+        assert_eq!(schema, before);
+    }
+}
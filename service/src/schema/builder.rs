@@ -3,13 +3,26 @@
 //! Provides a fluent API for programmatically constructing LinkML schemas.
 //! This builder enables incremental schema construction with type-safe operations
 //! and validates the resulting schema structure.
+//!
+//! [`SchemaBuilder::build`] assembles the schema as given, which suits
+//! generated schemas such as the ones inferred from data.
+//! [`SchemaBuilder::try_build`] also checks it: undeclared classes, slots,
+//! ranges and prefixes, elements added twice, invalid patterns and bounds.
 
 use indexmap::IndexMap;
 use linkml_core::LinkMLError;
-use linkml_core::types::{ClassDefinition, PrefixDefinition, SchemaDefinition, SlotDefinition};
+use linkml_core::types::{
+    ClassDefinition, EnumDefinition, PermissibleValue, PrefixDefinition, SchemaDefinition,
+    SlotDefinition,
+};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fmt::Write;
 use std::sync::Arc;
 use timestamp_core::{TimestampError, TimestampService};
 
+use crate::parser::lenient::BUILTIN_RANGES;
+
 /// Result type for schema builder operations
 pub type BuilderResult<T> = Result<T, LinkMLError>;
 
@@ -18,19 +31,30 @@ pub type BuilderResult<T> = Result<T, LinkMLError>;
 /// # Example
 ///
 /// ```rust
-/// use linkml_service::inference::builder::SchemaBuilder;
+/// use linkml_service::SchemaBuilder;
 ///
 /// let schema = SchemaBuilder::new("person_schema", "PersonSchema")
 ///     .with_description("Schema for person data")
 ///     .with_version("1.0.0")
 ///     .add_prefix("schema", "http://schema.org/")
+///     .add_import("linkml:types")
+///     .add_enum("Status")
+///         .add_permissible_value("active")
+///         .add_permissible_value("retired")
+///         .finish()
+///     .add_slot("age")
+///         .with_range("integer")
+///         .with_minimum_value(0)
+///         .finish()
 ///     .add_class("Person")
 ///         .with_description("A person entity")
 ///         .add_attribute("name", "string", true, false)
-///         .add_attribute("age", "integer", false, false)
 ///         .add_attribute("emails", "string", false, true)
+///         .add_attribute("status", "Status", false, false)
+///         .use_slot("age")
 ///         .finish()
-///     .build();
+///     .try_build()
+///     .expect("schema is valid");
 /// ```
 pub struct SchemaBuilder {
     schema_id: String,
@@ -42,8 +66,12 @@ pub struct SchemaBuilder {
     default_prefix: Option<String>,
     default_range: Option<String>,
     prefixes: IndexMap<String, PrefixDefinition>,
+    imports: Vec<String>,
     classes: IndexMap<String, ClassDefinition>,
     slots: IndexMap<String, SlotDefinition>,
+    enums: IndexMap<String, EnumDefinition>,
+    /// Elements added more than once, as `kind 'name'`
+    duplicates: Vec<String>,
     timestamp_service: Option<Arc<dyn TimestampService<Error = TimestampError>>>,
 }
 
@@ -72,8 +100,11 @@ impl SchemaBuilder {
             default_prefix: None,
             default_range: None,
             prefixes,
+            imports: Vec::new(),
             classes: IndexMap::new(),
             slots: IndexMap::new(),
+            enums: IndexMap::new(),
+            duplicates: Vec::new(),
             timestamp_service: None,
         }
     }
@@ -184,6 +215,12 @@ impl SchemaBuilder {
         self
     }
 
+    /// Import another schema, e.g. `linkml:types`
+    pub fn add_import(mut self, import: impl Into<String>) -> Self {
+        self.imports.push(import.into());
+        self
+    }
+
     /// Start building a class definition
     ///
     /// # Arguments
@@ -210,6 +247,19 @@ impl SchemaBuilder {
         SlotBuilder::new(self, name.into())
     }
 
+    /// Start building an enum definition
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the enum
+    ///
+    /// # Returns
+    ///
+    /// An `EnumBuilder` for fluent enum construction
+    pub fn add_enum(self, name: impl Into<String>) -> EnumBuilder {
+        EnumBuilder::new(self, name.into())
+    }
+
     /// Build the final schema definition
     ///
     /// # Returns
@@ -229,12 +279,13 @@ impl SchemaBuilder {
             license: self.license,
             default_prefix: self.default_prefix,
             prefixes: self.prefixes,
-            imports: Vec::new(),
+            imports: self.imports,
             classes: self.classes,
             slots: self.slots,
             types: IndexMap::new(),
-            enums: IndexMap::new(),
+            enums: self.enums,
             subsets: IndexMap::new(),
+            attribute_groups: IndexMap::new(),
             default_range: self.default_range,
             generation_date,
             source_file: None,
@@ -249,19 +300,183 @@ impl SchemaBuilder {
         }
     }
 
+    /// Build the schema after checking that it is consistent
+    ///
+    /// References to classes, slots, enums and prefixes must name elements
+    /// of the schema; ranges may also name built-in types. When the schema
+    /// imports anything besides `linkml:types`, names that are not found may
+    /// come from the imports and are not reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SchemaValidationError` listing every problem found.
+    pub fn try_build(self) -> BuilderResult<SchemaDefinition> {
+        let mut problems = self.duplicates.clone();
+        let schema = self.build();
+        problems.extend(schema_problems(&schema));
+        let Some(first) = problems.first() else {
+            return Ok(schema);
+        };
+        let message = if let [_] = problems.as_slice() {
+            format!("Invalid schema '{}': {first}", schema.name)
+        } else {
+            let mut message = format!(
+                "Invalid schema '{}': {} problems",
+                schema.name,
+                problems.len()
+            );
+            for problem in &problems {
+                let _ = write!(message, "\n  {problem}");
+            }
+            message
+        };
+        Err(LinkMLError::schema_validation(message))
+    }
+
     /// Internal method to add a class after building
     fn add_class_internal(mut self, name: String, class_def: ClassDefinition) -> Self {
-        self.classes.insert(name, class_def);
+        if self.classes.insert(name.clone(), class_def).is_some() {
+            self.duplicates
+                .push(format!("class '{name}' is defined more than once"));
+        }
         self
     }
 
     /// Internal method to add a slot after building
     fn add_slot_internal(mut self, name: String, slot_def: SlotDefinition) -> Self {
-        self.slots.insert(name, slot_def);
+        if self.slots.insert(name.clone(), slot_def).is_some() {
+            self.duplicates
+                .push(format!("slot '{name}' is defined more than once"));
+        }
+        self
+    }
+
+    /// Internal method to add an enum after building
+    fn add_enum_internal(mut self, name: String, enum_def: EnumDefinition) -> Self {
+        if self.enums.insert(name.clone(), enum_def).is_some() {
+            self.duplicates
+                .push(format!("enum '{name}' is defined more than once"));
+        }
         self
     }
 }
 
+/// Problems of a built schema, in the order of its elements
+pub(crate) fn schema_problems(schema: &SchemaDefinition) -> Vec<String> {
+    let mut problems = Vec::new();
+    if schema.id.trim().is_empty() {
+        problems.push("schema id is empty".to_string());
+    }
+    if schema.name.trim().is_empty() {
+        problems.push("schema name is empty".to_string());
+    }
+    if let Some(prefix) = &schema.default_prefix
+        && !schema.prefixes.contains_key(prefix)
+    {
+        problems.push(format!("default prefix '{prefix}' is not declared"));
+    }
+
+    // Names not found here may be defined by imports other than the types
+    let checks_references = schema.imports.iter().all(|import| import == "linkml:types");
+    let is_range = |range: &str| {
+        BUILTIN_RANGES.contains(&range)
+            || schema.classes.contains_key(range)
+            || schema.enums.contains_key(range)
+            || schema.types.contains_key(range)
+    };
+
+    for (name, slot) in &schema.slots {
+        problems.extend(slot_problems(&format!("slot '{name}'"), slot));
+        if checks_references {
+            if let Some(range) = &slot.range
+                && !is_range(range)
+            {
+                problems.push(format!("slot '{name}' has unknown range '{range}'"));
+            }
+            if let Some(domain) = &slot.domain
+                && !schema.classes.contains_key(domain)
+            {
+                problems.push(format!("slot '{name}' has unknown domain '{domain}'"));
+            }
+        }
+    }
+    for (name, class) in &schema.classes {
+        for (attribute_name, attribute) in &class.attributes {
+            let element = format!("attribute '{name}.{attribute_name}'");
+            problems.extend(slot_problems(&element, attribute));
+            if checks_references
+                && let Some(range) = &attribute.range
+                && !is_range(range)
+            {
+                problems.push(format!("{element} has unknown range '{range}'"));
+            }
+        }
+        let identifiers = class
+            .attributes
+            .values()
+            .chain(class.slots.iter().filter_map(|slot| schema.slots.get(slot)))
+            .filter(|slot| slot.identifier == Some(true))
+            .count();
+        if identifiers > 1 {
+            problems.push(format!("class '{name}' has {identifiers} identifier slots"));
+        }
+        if !checks_references {
+            continue;
+        }
+        for parent in class.is_a.iter().chain(&class.mixins) {
+            if !schema.classes.contains_key(parent) {
+                problems.push(format!(
+                    "class '{name}' inherits from unknown class '{parent}'"
+                ));
+            }
+        }
+        for slot in &class.slots {
+            if !schema.slots.contains_key(slot) {
+                problems.push(format!("class '{name}' uses unknown slot '{slot}'"));
+            }
+        }
+    }
+    for (name, enum_def) in &schema.enums {
+        let mut seen = HashSet::new();
+        for value in &enum_def.permissible_values {
+            if !seen.insert(value.text()) {
+                problems.push(format!(
+                    "enum '{name}' lists permissible value '{}' more than once",
+                    value.text()
+                ));
+            }
+        }
+    }
+    problems
+}
+
+/// Problems of the constraints of a slot or attribute
+fn slot_problems(element: &str, slot: &SlotDefinition) -> Vec<String> {
+    let mut problems = Vec::new();
+    if let Some(pattern) = &slot.pattern
+        && let Err(err) = regex::Regex::new(pattern)
+    {
+        problems.push(format!("{element} has invalid pattern '{pattern}': {err}"));
+    }
+    if let (Some(minimum), Some(maximum)) = (
+        slot.minimum_value.as_ref().and_then(Value::as_f64),
+        slot.maximum_value.as_ref().and_then(Value::as_f64),
+    ) && minimum > maximum
+    {
+        problems.push(format!(
+            "{element} has minimum value {minimum} above its maximum value {maximum}"
+        ));
+    }
+    if let (Some(minimum), Some(maximum)) = (slot.min_length, slot.max_length)
+        && minimum > maximum
+    {
+        problems.push(format!(
+            "{element} has minimum length {minimum} above its maximum length {maximum}"
+        ));
+    }
+    problems
+}
+
 /// Builder for constructing class definitions
 pub struct ClassBuilder {
     schema_builder: SchemaBuilder,
@@ -274,6 +489,7 @@ pub struct ClassBuilder {
     slots: Vec<String>,
     attributes: IndexMap<String, SlotDefinition>,
     tree_root: bool,
+    status: Option<String>,
 }
 
 impl ClassBuilder {
@@ -289,6 +505,7 @@ impl ClassBuilder {
             slots: Vec::new(),
             attributes: IndexMap::new(),
             tree_root: false,
+            status: None,
         }
     }
 
@@ -328,6 +545,12 @@ impl ClassBuilder {
         self
     }
 
+    /// Set the approval status, e.g. `draft`
+    pub fn with_status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
     /// Add a reference to an existing slot definition
     pub fn use_slot(mut self, slot_name: impl Into<String>) -> Self {
         self.slots.push(slot_name.into());
@@ -409,6 +632,7 @@ impl ClassBuilder {
             mixin: if self.is_mixin { Some(true) } else { None },
             is_a: self.is_a,
             mixins: self.mixins,
+            attribute_groups: Vec::new(),
             slots: self.slots,
             slot_usage: IndexMap::new(),
             attributes: self.attributes,
@@ -424,6 +648,9 @@ impl ClassBuilder {
             see_also: Vec::new(),
             examples: Vec::new(),
             deprecated: None,
+            deprecated_element_has_exact_replacement: None,
+            deprecated_element_has_possible_replacement: None,
+            status: self.status,
             todos: Vec::new(),
             notes: Vec::new(),
             comments: Vec::new(),
@@ -448,8 +675,15 @@ pub struct SlotBuilder {
     required: bool,
     multivalued: bool,
     identifier: bool,
+    key: Option<bool>,
     pattern: Option<String>,
     domain: Option<String>,
+    minimum_value: Option<Value>,
+    maximum_value: Option<Value>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    slot_uri: Option<String>,
+    status: Option<String>,
 }
 
 impl SlotBuilder {
@@ -462,8 +696,15 @@ impl SlotBuilder {
             required: false,
             multivalued: false,
             identifier: false,
+            key: None,
             pattern: None,
             domain: None,
+            minimum_value: None,
+            maximum_value: None,
+            min_length: None,
+            max_length: None,
+            slot_uri: None,
+            status: None,
         }
     }
 
@@ -509,6 +750,48 @@ impl SlotBuilder {
         self
     }
 
+    /// Mark the slot as a key, unique among the values of its container
+    pub fn key(mut self) -> Self {
+        self.key = Some(true);
+        self
+    }
+
+    /// Set the smallest value allowed
+    pub fn with_minimum_value(mut self, minimum: impl Into<Value>) -> Self {
+        self.minimum_value = Some(minimum.into());
+        self
+    }
+
+    /// Set the largest value allowed
+    pub fn with_maximum_value(mut self, maximum: impl Into<Value>) -> Self {
+        self.maximum_value = Some(maximum.into());
+        self
+    }
+
+    /// Set the minimum length of string values
+    pub fn with_min_length(mut self, length: usize) -> Self {
+        self.min_length = Some(length);
+        self
+    }
+
+    /// Set the maximum length of string values
+    pub fn with_max_length(mut self, length: usize) -> Self {
+        self.max_length = Some(length);
+        self
+    }
+
+    /// Set the URI of the slot
+    pub fn with_slot_uri(mut self, uri: impl Into<String>) -> Self {
+        self.slot_uri = Some(uri.into());
+        self
+    }
+
+    /// Set the approval status, e.g. `draft`
+    pub fn with_status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
     /// Finish building this slot and return to schema builder
     pub fn finish(self) -> SchemaBuilder {
         let slot_def = SlotDefinition {
@@ -518,8 +801,15 @@ impl SlotBuilder {
             required: Some(self.required),
             multivalued: Some(self.multivalued),
             identifier: Some(self.identifier),
+            key: self.key,
             pattern: self.pattern,
             domain: self.domain,
+            minimum_value: self.minimum_value,
+            maximum_value: self.maximum_value,
+            min_length: self.min_length,
+            max_length: self.max_length,
+            slot_uri: self.slot_uri,
+            status: self.status,
             ..Default::default()
         };
 
@@ -528,6 +818,82 @@ impl SlotBuilder {
     }
 }
 
+/// Builder for constructing enum definitions
+pub struct EnumBuilder {
+    schema_builder: SchemaBuilder,
+    enum_name: String,
+    description: Option<String>,
+    permissible_values: Vec<PermissibleValue>,
+    status: Option<String>,
+}
+
+impl EnumBuilder {
+    fn new(schema_builder: SchemaBuilder, enum_name: String) -> Self {
+        Self {
+            schema_builder,
+            enum_name,
+            description: None,
+            permissible_values: Vec::new(),
+            status: None,
+        }
+    }
+
+    /// Set the enum description
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the approval status, e.g. `draft`
+    pub fn with_status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    /// Add a permissible value
+    pub fn add_permissible_value(mut self, text: impl Into<String>) -> Self {
+        self.permissible_values
+            .push(PermissibleValue::Simple(text.into()));
+        self
+    }
+
+    /// Add a permissible value with a description and a meaning URI or CURIE
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The value as written in data
+    /// * `description` - Optional description of the value
+    /// * `meaning` - Optional ontology term the value stands for
+    pub fn add_permissible_value_with_meaning(
+        mut self,
+        text: impl Into<String>,
+        description: Option<String>,
+        meaning: Option<String>,
+    ) -> Self {
+        self.permissible_values.push(PermissibleValue::Complex {
+            text: text.into(),
+            description,
+            meaning,
+            is_a: None,
+        });
+        self
+    }
+
+    /// Finish building this enum and return to schema builder
+    pub fn finish(self) -> SchemaBuilder {
+        let enum_def = EnumDefinition {
+            name: self.enum_name.clone(),
+            description: self.description,
+            permissible_values: self.permissible_values,
+            status: self.status,
+            ..Default::default()
+        };
+
+        self.schema_builder
+            .add_enum_internal(self.enum_name, enum_def)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -569,14 +935,14 @@ mod tests {
             .build();
 
         assert!(schema.classes.contains_key("Person"));
-        let person_class = schema.classes.get("Person").unwrap();
+        let person_class = schema.classes.get("Person").expect("Person class");
         assert_eq!(
             person_class.description,
             Some("A person entity".to_string())
         );
         assert_eq!(person_class.attributes.len(), 2);
 
-        let name_attr = person_class.attributes.get("name").unwrap();
+        let name_attr = person_class.attributes.get("name").expect("name attribute");
         assert_eq!(name_attr.range, Some("string".to_string()));
         assert_eq!(name_attr.required, Some(true));
         assert_eq!(name_attr.multivalued, Some(false));
@@ -598,8 +964,14 @@ mod tests {
         assert!(schema.classes.contains_key("Person"));
         assert!(schema.classes.contains_key("Organization"));
 
-        let org_class = schema.classes.get("Organization").unwrap();
-        let employees = org_class.attributes.get("employees").unwrap();
+        let org_class = schema
+            .classes
+            .get("Organization")
+            .expect("Organization class");
+        let employees = org_class
+            .attributes
+            .get("employees")
+            .expect("employees attribute");
         assert_eq!(employees.range, Some("Person".to_string()));
         assert_eq!(employees.multivalued, Some(true));
     }
@@ -617,10 +989,10 @@ mod tests {
             .finish()
             .build();
 
-        let entity = schema.classes.get("Entity").unwrap();
+        let entity = schema.classes.get("Entity").expect("Entity class");
         assert_eq!(entity.abstract_, Some(true));
 
-        let person = schema.classes.get("Person").unwrap();
+        let person = schema.classes.get("Person").expect("Person class");
         assert_eq!(person.is_a, Some("Entity".to_string()));
     }
 
@@ -637,10 +1009,13 @@ mod tests {
             .finish()
             .build();
 
-        let timestamped = schema.classes.get("Timestamped").unwrap();
+        let timestamped = schema
+            .classes
+            .get("Timestamped")
+            .expect("Timestamped class");
         assert_eq!(timestamped.mixin, Some(true));
 
-        let person = schema.classes.get("Person").unwrap();
+        let person = schema.classes.get("Person").expect("Person class");
         assert_eq!(person.mixins, vec!["Timestamped".to_string()]);
     }
 
@@ -659,12 +1034,12 @@ mod tests {
             .build();
 
         assert!(schema.slots.contains_key("email"));
-        let email_slot = schema.slots.get("email").unwrap();
+        let email_slot = schema.slots.get("email").expect("email slot");
         assert_eq!(email_slot.range, Some("string".to_string()));
         assert_eq!(email_slot.required, Some(true));
         assert!(email_slot.pattern.is_some());
 
-        let person = schema.classes.get("Person").unwrap();
+        let person = schema.classes.get("Person").expect("Person class");
         assert_eq!(person.slots, vec!["email".to_string()]);
     }
 
@@ -710,7 +1085,7 @@ mod tests {
             .finish()
             .build();
 
-        let container = schema.classes.get("Container").unwrap();
+        let container = schema.classes.get("Container").expect("Container class");
         assert_eq!(container.tree_root, Some(true));
     }
 
@@ -728,7 +1103,7 @@ mod tests {
         if let PrefixDefinition::Complex {
             prefix_prefix,
             prefix_reference,
-        } = schema.prefixes.get("obo").unwrap()
+        } = schema.prefixes.get("obo").expect("obo prefix")
         {
             assert_eq!(prefix_prefix, "http://purl.obolibrary.org/obo/");
             assert_eq!(
@@ -739,4 +1114,123 @@ mod tests {
             panic!("Expected complex prefix definition");
         }
     }
+
+    #[test]
+    fn test_enums_imports_and_constraints() {
+        let schema = SchemaBuilder::new("test", "Test")
+            .add_import("linkml:types")
+            .add_enum("Status")
+            .with_description("Employment status")
+            .add_permissible_value("active")
+            .add_permissible_value_with_meaning("retired", None, Some("ex:Retired".to_string()))
+            .finish()
+            .add_slot("age")
+            .with_range("integer")
+            .with_minimum_value(0)
+            .with_maximum_value(150)
+            .finish()
+            .add_slot("code")
+            .with_range("string")
+            .key()
+            .with_min_length(2)
+            .with_max_length(8)
+            .finish()
+            .build();
+
+        assert_eq!(schema.imports, vec!["linkml:types".to_string()]);
+        let status = schema.enums.get("Status").expect("Status enum");
+        let values: Vec<&str> = status
+            .permissible_values
+            .iter()
+            .map(PermissibleValue::text)
+            .collect();
+        assert_eq!(values, ["active", "retired"]);
+
+        let age = schema.slots.get("age").expect("age slot");
+        assert_eq!(age.minimum_value, Some(Value::from(0)));
+        assert_eq!(age.maximum_value, Some(Value::from(150)));
+        let code = schema.slots.get("code").expect("code slot");
+        assert_eq!(code.key, Some(true));
+        assert_eq!((code.min_length, code.max_length), (Some(2), Some(8)));
+    }
+
+    #[test]
+    fn test_try_build_accepts_consistent_schema() {
+        let schema = SchemaBuilder::new("https://example.org/people", "people")
+            .with_default_prefix("linkml")
+            .add_enum("Status")
+            .add_permissible_value("active")
+            .finish()
+            .add_slot("id")
+            .with_range("string")
+            .identifier()
+            .finish()
+            .add_class("Person")
+            .use_slot("id")
+            .add_attribute("status", "Status", false, false)
+            .add_attribute("employer", "Organization", false, false)
+            .finish()
+            .add_class("Organization")
+            .finish()
+            .try_build();
+
+        assert!(schema.is_ok(), "{schema:?}");
+    }
+
+    #[test]
+    fn test_try_build_reports_every_problem() {
+        let result = SchemaBuilder::new("test", "Test")
+            .with_default_prefix("ex")
+            .add_enum("Status")
+            .add_permissible_value("active")
+            .add_permissible_value("active")
+            .finish()
+            .add_slot("age")
+            .with_range("Years")
+            .with_minimum_value(10)
+            .with_maximum_value(1)
+            .finish()
+            .add_slot("code")
+            .with_pattern("[a-z")
+            .finish()
+            .add_class("Person")
+            .finish()
+            .add_class("Person")
+            .is_a("Agent")
+            .use_slot("email")
+            .finish()
+            .try_build();
+
+        let Err(LinkMLError::SchemaValidationError { message, .. }) = result else {
+            panic!("Expected SchemaValidationError, got {result:?}");
+        };
+        assert!(
+            message.starts_with("Invalid schema 'Test': 8 problems"),
+            "{message}"
+        );
+        for problem in [
+            "class 'Person' is defined more than once",
+            "default prefix 'ex' is not declared",
+            "slot 'age' has minimum value 10 above its maximum value 1",
+            "slot 'age' has unknown range 'Years'",
+            "slot 'code' has invalid pattern '[a-z'",
+            "class 'Person' inherits from unknown class 'Agent'",
+            "class 'Person' uses unknown slot 'email'",
+            "enum 'Status' lists permissible value 'active' more than once",
+        ] {
+            assert!(
+                message.contains(problem),
+                "missing '{problem}' in {message}"
+            );
+        }
+
+        // Names may come from imports, which are not checked
+        let imported = SchemaBuilder::new("test", "Test")
+            .add_import("core")
+            .add_class("Person")
+            .is_a("Agent")
+            .finish()
+            .try_build();
+        assert!(imported.is_ok());
+    }
 }
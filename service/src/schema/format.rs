@@ -0,0 +1,700 @@
+//! Canonical schema formatting for `LinkML`
+//!
+//! This module rewrites schema documents into a canonical YAML layout so
+//! that schemas diff cleanly regardless of who edited them last:
+//!
+//! - schema-level and element-level keys follow the metamodel order, with
+//!   unknown keys appended alphabetically
+//! - element maps (`classes`, `slots`, `attributes`, `permissible_values`,
+//!   ...) keep their authored order unless `sort_elements` is set
+//! - multiline strings have trailing whitespace and trailing blank lines
+//!   removed and are emitted as literal blocks
+//! - scalars are only quoted when a plain scalar would change meaning
+//!
+//! Formatting works on the YAML document rather than on
+//! [`SchemaDefinition`], so keys the Rust model does not know about are
+//! preserved. Comments are dropped unless `lossless` is set, in which case
+//! comments above a key and after it on its line move with the key;
+//! comments inside sequences are still dropped.
+
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
+use serde_yaml::{Mapping, Value};
+use std::fmt::Write as _;
+use std::path::Path;
+
+use super::lossless::{Entry, LosslessDocument, key_text};
+
+/// Schema-level keys in canonical order
+const SCHEMA_KEY_ORDER: &[&str] = &[
+    "id",
+    "name",
+    "title",
+    "description",
+    "version",
+    "license",
+    "status",
+    "contributors",
+    "categories",
+    "keywords",
+    "see_also",
+    "generation_date",
+    "source_file",
+    "metamodel_version",
+    "prefixes",
+    "default_prefix",
+    "default_range",
+    "imports",
+    "settings",
+    "subsets",
+    "types",
+    "enums",
+    "slots",
+    "attribute_groups",
+    "classes",
+    "annotations",
+];
+
+/// Element-level keys in canonical order, shared by all element kinds
+const ELEMENT_KEY_ORDER: &[&str] = &[
+    "name",
+    "title",
+    "description",
+    "aliases",
+    "deprecated",
+    "deprecated_element_has_exact_replacement",
+    "deprecated_element_has_possible_replacement",
+    "status",
+    "is_a",
+    "abstract",
+    "mixin",
+    "mixins",
+    "attribute_groups",
+    "tree_root",
+    "class_uri",
+    "slot_uri",
+    "enum_uri",
+    "type_uri",
+    "uri",
+    "typeof",
+    "base",
+    "repr",
+    "slots",
+    "slot_usage",
+    "attributes",
+    "domain",
+    "range",
+    "required",
+    "recommended",
+    "identifier",
+    "key",
+    "designates_type",
+    "multivalued",
+    "inlined",
+    "inlined_as_list",
+    "inlined_as_dict",
+    "inverse",
+    "ifabsent",
+    "pattern",
+    "minimum_value",
+    "maximum_value",
+    "equals_string",
+    "equals_string_in",
+    "unit",
+    "array",
+    "text",
+    "meaning",
+    "code_set",
+    "permissible_values",
+    "reachable_from",
+    "matches",
+    "concepts",
+    "any_of",
+    "all_of",
+    "exactly_one_of",
+    "none_of",
+    "rules",
+    "unique_keys",
+    "if_required",
+    "exact_mappings",
+    "close_mappings",
+    "related_mappings",
+    "narrow_mappings",
+    "broad_mappings",
+    "mappings",
+    "see_also",
+    "in_subset",
+    "comments",
+    "notes",
+    "todos",
+    "examples",
+    "annotations",
+];
+
+/// Schema-level keys whose values are maps of named elements
+const SCHEMA_ELEMENT_MAPS: &[&str] = &[
+    "classes",
+    "slots",
+    "types",
+    "enums",
+    "subsets",
+    "attribute_groups",
+];
+
+/// Element-level keys whose values are maps of named elements
+const NESTED_ELEMENT_MAPS: &[&str] = &["attributes", "slot_usage", "permissible_values"];
+
+/// Words YAML 1.1 tooling reads as booleans or null when left unquoted
+const AMBIGUOUS_WORDS: &[&str] = &[
+    "y", "Y", "yes", "Yes", "YES", "n", "N", "no", "No", "NO", "on", "On", "ON", "off", "Off",
+    "OFF", "true", "True", "TRUE", "false", "False", "FALSE", "null", "Null", "NULL", "~",
+];
+
+/// Options for schema formatting
+#[derive(Debug, Clone)]
+pub struct FormatOptions {
+    /// Spaces per indentation level
+    pub indent: usize,
+
+    /// Sort named elements alphabetically instead of keeping authored order
+    pub sort_elements: bool,
+
+    /// Keep comments, moving them with the keys they annotate
+    pub lossless: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            indent: 2,
+            sort_elements: false,
+            lossless: false,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Set the indentation width
+    #[must_use]
+    pub fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = indent.max(1);
+        self
+    }
+
+    /// Sort named elements alphabetically
+    #[must_use]
+    pub fn with_sorted_elements(mut self, sort: bool) -> Self {
+        self.sort_elements = sort;
+        self
+    }
+
+    /// Keep comments when formatting
+    #[must_use]
+    pub fn with_lossless(mut self, lossless: bool) -> Self {
+        self.lossless = lossless;
+        self
+    }
+}
+
+/// Result of formatting a schema file
+#[derive(Debug, Clone)]
+pub struct FormatOutcome {
+    /// Whether the formatted output differs from the file content
+    pub changed: bool,
+
+    /// Formatted document
+    pub formatted: String,
+}
+
+/// Where in the schema document a mapping sits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Context {
+    /// The schema document itself
+    Schema,
+    /// A map from element names to element definitions
+    ElementMap,
+    /// A single element definition
+    Element,
+    /// Anything else; key order is kept as authored
+    Verbatim,
+}
+
+impl Context {
+    pub(crate) fn child(self, key: &Value) -> Self {
+        let key = key.as_str().unwrap_or_default();
+        match self {
+            Self::Schema if SCHEMA_ELEMENT_MAPS.contains(&key) => Self::ElementMap,
+            Self::Element if NESTED_ELEMENT_MAPS.contains(&key) => Self::ElementMap,
+            Self::ElementMap => Self::Element,
+            _ => Self::Verbatim,
+        }
+    }
+
+    fn key_order(self) -> Option<&'static [&'static str]> {
+        match self {
+            Self::Schema => Some(SCHEMA_KEY_ORDER),
+            Self::Element => Some(ELEMENT_KEY_ORDER),
+            Self::ElementMap | Self::Verbatim => None,
+        }
+    }
+}
+
+/// Canonical schema formatter
+#[derive(Debug, Clone, Default)]
+pub struct SchemaFormatter {
+    options: FormatOptions,
+}
+
+impl SchemaFormatter {
+    /// Create a formatter with the given options
+    pub fn new(options: FormatOptions) -> Self {
+        Self { options }
+    }
+
+    /// Format a YAML or JSON schema document
+    pub fn format_str(&self, content: &str) -> Result<String> {
+        let value: Value = serde_yaml::from_str(content)
+            .map_err(|err| LinkMLError::parse(format!("Invalid schema document: {err}")))?;
+        if !self.options.lossless {
+            return self.format_value(&value);
+        }
+        // JSON documents have no comments to keep
+        let Ok(document) = LosslessDocument::parse(content) else {
+            return self.format_value(&value);
+        };
+        let Value::Mapping(map) = &value else {
+            return Err(LinkMLError::parse("Schema document must be a mapping"));
+        };
+        let mut out = String::new();
+        self.write_mapping(map, 0, Context::Schema, Some(document.entries()), &mut out)?;
+        for comment in document.trailing_comments() {
+            let _ = writeln!(out, "{comment}");
+        }
+        Ok(out)
+    }
+
+    /// Format a parsed schema document
+    pub fn format_value(&self, value: &Value) -> Result<String> {
+        let Value::Mapping(map) = value else {
+            return Err(LinkMLError::parse("Schema document must be a mapping"));
+        };
+        let mut out = String::new();
+        self.write_mapping(map, 0, Context::Schema, None, &mut out)?;
+        Ok(out)
+    }
+
+    /// Spaces per indentation level
+    pub(crate) fn indent(&self) -> usize {
+        self.options.indent
+    }
+
+    /// Format a schema definition
+    pub fn format_schema(&self, schema: &SchemaDefinition) -> Result<String> {
+        let value = serde_yaml::to_value(schema)
+            .map_err(|err| LinkMLError::serialization(err.to_string()))?;
+        self.format_value(&value)
+    }
+
+    /// Whether a document is already in canonical form
+    pub fn check_str(&self, content: &str) -> Result<bool> {
+        Ok(self.format_str(content)? == content)
+    }
+
+    /// Format a schema file, rewriting it in place when `write` is set
+    pub fn format_file(&self, path: &Path, write: bool) -> Result<FormatOutcome> {
+        let content = std::fs::read_to_string(path)?;
+        let formatted = self
+            .format_str(&content)
+            .map_err(|err| LinkMLError::parse_at(err.to_string(), path.display().to_string()))?;
+        let changed = formatted != content;
+        if changed && write {
+            std::fs::write(path, &formatted)?;
+        }
+        Ok(FormatOutcome { changed, formatted })
+    }
+
+    /// Entries of a mapping in canonical order
+    fn ordered_entries<'a>(
+        &self,
+        map: &'a Mapping,
+        context: Context,
+    ) -> Vec<(&'a Value, &'a Value)> {
+        let mut entries: Vec<_> = map.iter().collect();
+        if let Some(order) = context.key_order() {
+            let rank = |key: &Value| {
+                key.as_str()
+                    .and_then(|key| order.iter().position(|known| *known == key))
+                    .unwrap_or(order.len())
+            };
+            // Stable sort: known keys by rank, unknown keys alphabetically after them
+            entries.sort_by(|(a, _), (b, _)| {
+                rank(a).cmp(&rank(b)).then_with(|| {
+                    match (rank(a) == order.len(), a.as_str(), b.as_str()) {
+                        (true, Some(a), Some(b)) => a.cmp(b),
+                        _ => std::cmp::Ordering::Equal,
+                    }
+                })
+            });
+        } else if context == Context::ElementMap && self.options.sort_elements {
+            entries.sort_by(|(a, _), (b, _)| a.as_str().cmp(&b.as_str()));
+        }
+        entries
+    }
+
+    /// Write the entries of a mapping, with the comments of the matching
+    /// source entries when given
+    fn write_mapping(
+        &self,
+        map: &Mapping,
+        indent: usize,
+        context: Context,
+        comments: Option<&[Entry]>,
+        out: &mut String,
+    ) -> Result<()> {
+        for (key, value) in self.ordered_entries(map, context) {
+            let source = comments.and_then(|entries| {
+                let key = key_text(key)?;
+                entries.iter().find(|entry| entry.key == key)
+            });
+            self.write_entry(key, value, indent, context, source, out)?;
+        }
+        Ok(())
+    }
+
+    /// Write a `key: value` entry of a mapping in `context`, preceded by the
+    /// comments of its source entry and with its inline comment
+    pub(crate) fn write_entry(
+        &self,
+        key: &Value,
+        value: &Value,
+        indent: usize,
+        context: Context,
+        source: Option<&Entry>,
+        out: &mut String,
+    ) -> Result<()> {
+        let step = self.options.indent;
+        let pad = " ".repeat(indent);
+        for comment in source
+            .map(|entry| entry.leading.as_slice())
+            .unwrap_or_default()
+        {
+            let _ = writeln!(out, "{pad}{comment}");
+        }
+        out.push_str(&pad);
+        out.push_str(&scalar_text(key, indent + step, true)?);
+        out.push(':');
+        let start = out.len();
+        match value {
+            Value::Mapping(child) if !child.is_empty() => {
+                out.push('\n');
+                let children = source.and_then(|entry| entry.children.as_deref());
+                self.write_mapping(child, indent + step, context.child(key), children, out)?;
+            }
+            Value::Sequence(items) if !items.is_empty() => {
+                out.push('\n');
+                self.write_sequence(items, indent + step, out)?;
+            }
+            Value::Null => out.push('\n'),
+            _ => {
+                let _ = writeln!(out, " {}", scalar_text(value, indent + step, false)?);
+            }
+        }
+        if let Some(comment) = source.and_then(|entry| entry.inline.as_deref()) {
+            let line_end = out[start..].find('\n').map_or(out.len(), |end| start + end);
+            out.insert_str(line_end, &format!(" {}", comment.trim_start()));
+        }
+        Ok(())
+    }
+
+    fn write_sequence(&self, items: &[Value], indent: usize, out: &mut String) -> Result<()> {
+        for item in items {
+            match item {
+                Value::Mapping(map) if !map.is_empty() => {
+                    // Render the mapping two columns deeper, then hang its first line on the dash
+                    let mut nested = String::new();
+                    self.write_mapping(map, indent + 2, Context::Verbatim, None, &mut nested)?;
+                    out.push_str(&" ".repeat(indent));
+                    out.push_str("- ");
+                    out.push_str(&nested[indent + 2..]);
+                }
+                Value::Sequence(inner) if !inner.is_empty() => {
+                    let mut nested = String::new();
+                    self.write_sequence(inner, indent + 2, &mut nested)?;
+                    out.push_str(&" ".repeat(indent));
+                    out.push_str("- ");
+                    out.push_str(&nested[indent + 2..]);
+                }
+                _ => {
+                    let _ = writeln!(
+                        out,
+                        "{}- {}",
+                        " ".repeat(indent),
+                        scalar_text(item, indent + 2, false)?
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Format a schema document with default options
+pub fn format_schema_str(content: &str) -> Result<String> {
+    SchemaFormatter::default().format_str(content)
+}
+
+/// Render a scalar (or empty collection) as YAML text
+fn scalar_text(value: &Value, block_indent: usize, is_key: bool) -> Result<String> {
+    match value {
+        Value::Null => Ok("null".to_string()),
+        Value::Bool(flag) => Ok(flag.to_string()),
+        Value::Number(number) => Ok(number.to_string()),
+        Value::String(text) => Ok(string_text(text, block_indent, is_key)),
+        Value::Sequence(items) if items.is_empty() && !is_key => Ok("[]".to_string()),
+        Value::Mapping(map) if map.is_empty() && !is_key => Ok("{}".to_string()),
+        Value::Tagged(tagged) => Err(LinkMLError::not_implemented(format!(
+            "Formatting tagged YAML values ({}) is not supported",
+            tagged.tag
+        ))),
+        Value::Sequence(_) | Value::Mapping(_) => Err(LinkMLError::not_implemented(
+            "Formatting complex mapping keys is not supported",
+        )),
+    }
+}
+
+/// Render a string, choosing between plain, quoted and literal block styles
+fn string_text(text: &str, block_indent: usize, is_key: bool) -> String {
+    if !is_key && text.contains('\n') {
+        let normalized = normalize_multiline(text);
+        if normalized.contains('\n') {
+            if let Some(block) = literal_block(&normalized, block_indent) {
+                return block;
+            }
+            return quoted(&normalized);
+        }
+        return string_text(&normalized, block_indent, is_key);
+    }
+    if is_plain_safe(text) {
+        text.to_string()
+    } else {
+        quoted(text)
+    }
+}
+
+/// Strip trailing whitespace from every line and drop trailing blank lines
+fn normalize_multiline(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    let end = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |last| last + 1);
+    lines[..end].join("\n")
+}
+
+/// Render a `|-` literal block, or `None` when indentation would be ambiguous
+fn literal_block(text: &str, indent: usize) -> Option<String> {
+    let first = text.lines().next()?;
+    if first.starts_with([' ', '\t']) {
+        return None;
+    }
+    let pad = " ".repeat(indent);
+    let mut block = String::from("|-");
+    for line in text.lines() {
+        block.push('\n');
+        if !line.is_empty() {
+            block.push_str(&pad);
+            block.push_str(line);
+        }
+    }
+    Some(block)
+}
+
+/// Double-quoted YAML string; JSON string escaping is valid YAML
+fn quoted(text: &str) -> String {
+    serde_json::to_string(text).unwrap_or_else(|_| format!("\"{}\"", text.escape_default()))
+}
+
+/// Whether a string can be written as a plain scalar without changing meaning
+fn is_plain_safe(text: &str) -> bool {
+    let Some(first) = text.chars().next() else {
+        return false;
+    };
+    if AMBIGUOUS_WORDS.contains(&text)
+        || "-?:,[]{}#&*!|>'\"%@`".contains(first)
+        || first.is_whitespace()
+        || text.ends_with(char::is_whitespace)
+        || text.ends_with(':')
+        || text.contains(": ")
+        || text.contains(" #")
+        || text.contains(['\t', '\r'])
+        || text.chars().any(char::is_control)
+    {
+        return false;
+    }
+    // Anything that does not read back as the same string (numbers, dates, ...) gets quoted
+    matches!(
+        serde_yaml::from_str::<Value>(text),
+        Ok(Value::String(parsed)) if parsed == text
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"
+classes:
+  Person:
+    slots: [name, age]
+    description: A human being
+    is_a: Thing
+    x_custom: kept
+  Thing:
+    abstract: true
+name: people
+id: https://example.org/people
+slots:
+  age:
+    range: integer
+    description: "Age in years   \nsecond line  \n\n"
+  name:
+    identifier: true
+prefixes:
+  linkml: https://w3id.org/linkml/
+  ex: https://example.org/
+version: "1.0"
+"#;
+
+    #[test]
+    fn test_canonical_key_order() {
+        let formatted = format_schema_str(SCHEMA).expect("schema should format");
+        let expected = r#"id: https://example.org/people
+name: people
+version: "1.0"
+prefixes:
+  linkml: https://w3id.org/linkml/
+  ex: https://example.org/
+slots:
+  age:
+    description: |-
+      Age in years
+      second line
+    range: integer
+  name:
+    identifier: true
+classes:
+  Person:
+    description: A human being
+    is_a: Thing
+    slots:
+      - name
+      - age
+    x_custom: kept
+  Thing:
+    abstract: true
+"#;
+        assert_eq!(formatted, expected);
+    }
+
+    #[test]
+    fn test_formatting_is_idempotent_and_preserves_values() {
+        let source = r##"
+id: https://example.org/s
+name: s
+annotations:
+  flags: ["yes", "no", "~", "", " padded ", "a: b", "#tag", "2024-01-01", "12", "1.5e3"]
+  nested:
+    - key: value
+      other: [1, 2]
+    - []
+    - {}
+"##;
+        let formatter = SchemaFormatter::default();
+        let once = formatter.format_str(source).expect("first pass");
+        let twice = formatter.format_str(&once).expect("second pass");
+        assert_eq!(once, twice);
+
+        let original: Value = serde_yaml::from_str(source).expect("source parses");
+        let reparsed: Value = serde_yaml::from_str(&once).expect("output parses");
+        assert_eq!(original, reparsed);
+        assert!(formatter.check_str(&once).expect("check runs"));
+        assert!(!formatter.check_str(source).expect("check runs"));
+    }
+
+    #[test]
+    fn test_sorted_elements() {
+        let sorter = SchemaFormatter::new(FormatOptions::default().with_sorted_elements(true));
+        let formatted = sorter
+            .format_str("id: x\nname: x\nclasses:\n  Zebra: {}\n  Ant:\n    attributes:\n      z: {}\n      a: {}\n")
+            .expect("schema should format");
+        assert_eq!(
+            formatted,
+            "id: x\nname: x\nclasses:\n  Ant:\n    attributes:\n      a: {}\n      z: {}\n  Zebra: {}\n"
+        );
+    }
+
+    #[test]
+    fn test_lossless_keeps_comments() {
+        let source = "\
+# People schema
+classes:
+  Person:  # the central class
+    # Inherited from the upper ontology
+    is_a: Thing
+    description: A person
+name: people
+id: https://example.org/people   # stable
+# trailing note
+";
+        let lossless = SchemaFormatter::new(FormatOptions::default().with_lossless(true));
+        let formatted = lossless.format_str(source).expect("schema should format");
+        let expected = "\
+id: https://example.org/people # stable
+name: people
+# People schema
+classes:
+  Person: # the central class
+    description: A person
+    # Inherited from the upper ontology
+    is_a: Thing
+# trailing note
+";
+        assert_eq!(formatted, expected);
+        assert_eq!(
+            lossless.format_str(&formatted).expect("second pass"),
+            formatted
+        );
+        assert!(
+            !format_schema_str(source)
+                .expect("schema should format")
+                .contains('#')
+        );
+    }
+
+    #[test]
+    fn test_format_file_check_and_write() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("schema.yaml");
+        std::fs::write(&path, "name: s\nid: s\n").expect("write schema");
+
+        let formatter = SchemaFormatter::default();
+        let outcome = formatter.format_file(&path, false).expect("check file");
+        assert!(outcome.changed);
+        assert_eq!(
+            std::fs::read_to_string(&path).expect("read schema"),
+            "name: s\nid: s\n"
+        );
+
+        formatter.format_file(&path, true).expect("format file");
+        assert_eq!(
+            std::fs::read_to_string(&path).expect("read schema"),
+            "id: s\nname: s\n"
+        );
+        assert!(
+            !formatter
+                .format_file(&path, false)
+                .expect("recheck")
+                .changed
+        );
+    }
+}
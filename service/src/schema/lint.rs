@@ -1,12 +1,57 @@
 //! Schema linting functionality for `LinkML`
 //!
 //! This module provides tools to check schema quality and compliance.
-
+//!
+//! Rules are registered in a [`LintRuleRegistry`] and configured through a
+//! [`LintConfig`], usually loaded from a `.linkmllint.yaml` file next to the
+//! schema (or in a parent directory):
+//!
+//! ```yaml
+//! extends: recommended      # or `none` to enable only the rules listed below
+//! rules:
+//!   naming-convention: error
+//!   missing-documentation:
+//!     level: warning
+//!   element-ordering:
+//!     level: info           # off by default
+//!   schema-metadata: disabled
+//! ignore:
+//!   - "^Legacy"             # element names to skip
+//! ```
+//!
+//! Rules with mechanical fixes (renaming to the naming conventions, adding
+//! description stubs, sorting elements, removing unused definitions,
+//! promoting identical attributes to global slots, declaring well-known
+//! prefixes) are applied by
+//! [`SchemaLinter::fix`].
+//!
+//! [`UnknownMappingRule`] checks mappings and URIs against ontologies and is
+//! registered explicitly with the ontology provider to use:
+//!
+//! ```rust,ignore
+//! registry.register(move || Box::new(UnknownMappingRule::new(Arc::clone(&catalog))));
+//! ```
+
+use crate::inheritance::{InheritanceResolver, MroProblem};
+use crate::namespace::{CurieProblem, check_curies};
+use crate::ontology::{OntologyProvider, find_unknown_terms};
+use crate::schema::attribute_groups::attribute_group_of;
+use crate::schema_view::status::Deprecation;
+use indexmap::IndexMap;
 use linkml_core::prelude::*;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Lint configuration file names, in lookup order
+pub const LINT_CONFIG_FILES: &[&str] = &[".linkmllint.yaml", ".linkmllint.yml"];
+
+/// Description added to undocumented elements by `--fix`
+pub const DESCRIPTION_STUB: &str = "TODO: add description";
 
 /// Lint severity levels
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -79,6 +124,228 @@ pub trait LintRule: Send + Sync {
     /// # Errors
     /// Returns error if fixing issues fails or encounters invalid schema state.
     fn fix(&self, schema: &mut SchemaDefinition, issues: &[LintIssue]) -> Result<usize>;
+
+    /// Whether the rule runs unless disabled (rules returning `false` must be enabled in configuration)
+    fn enabled_by_default(&self) -> bool {
+        true
+    }
+
+    /// Apply rule-specific options from the lint configuration
+    ///
+    /// # Errors
+    /// Returns error if an option is not supported by the rule.
+    fn configure(&mut self, _options: &HashMap<String, serde_json::Value>) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Factory creating a fresh instance of a lint rule
+pub type LintRuleFactory = Arc<dyn Fn() -> Box<dyn LintRule> + Send + Sync>;
+
+/// Registry of available lint rules, keyed by rule name
+#[derive(Clone)]
+pub struct LintRuleRegistry {
+    factories: IndexMap<String, LintRuleFactory>,
+}
+
+impl std::fmt::Debug for LintRuleRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LintRuleRegistry")
+            .field("rules", &self.factories.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl Default for LintRuleRegistry {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+impl LintRuleRegistry {
+    /// Create an empty registry
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            factories: IndexMap::new(),
+        }
+    }
+
+    /// Create a registry with the built-in rules
+    #[must_use]
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.register(|| Box::new(NamingConventionRule));
+        registry.register(|| Box::new(MissingDocumentationRule));
+        registry.register(|| Box::new(UnusedDefinitionsRule));
+        registry.register(|| Box::new(SlotConsistencyRule));
+        registry.register(|| Box::new(TypeSafetyRule));
+        registry.register(|| Box::new(SchemaMetadataRule));
+        registry.register(|| Box::new(ElementOrderingRule));
+        registry.register(|| Box::new(SlotDomainRule));
+        registry.register(|| Box::new(InheritanceOrderRule));
+        registry.register(|| Box::new(GlobalSlotReuseRule));
+        registry.register(|| Box::new(AttributeGroupExtractionRule::default()));
+        registry.register(|| Box::new(DeprecatedReferenceRule));
+        registry.register(|| Box::new(UnresolvedCurieRule));
+        registry
+    }
+
+    /// Register a rule, replacing any rule with the same name
+    pub fn register<F>(&mut self, factory: F)
+    where
+        F: Fn() -> Box<dyn LintRule> + Send + Sync + 'static,
+    {
+        let name = factory().name().to_string();
+        self.factories.insert(name, Arc::new(factory));
+    }
+
+    /// Create an instance of a rule
+    #[must_use]
+    pub fn create(&self, name: &str) -> Option<Box<dyn LintRule>> {
+        self.factories
+            .get(&normalize_rule_name(name))
+            .map(|factory| factory())
+    }
+
+    /// Names of all registered rules
+    #[must_use]
+    pub fn names(&self) -> Vec<&str> {
+        self.factories.keys().map(String::as_str).collect()
+    }
+
+    /// Instances of every rule that is enabled by default
+    #[must_use]
+    pub fn default_rules(&self) -> Vec<Box<dyn LintRule>> {
+        self.factories
+            .values()
+            .map(|factory| factory())
+            .filter(|rule| rule.enabled_by_default())
+            .collect()
+    }
+}
+
+/// Rule names are kebab-case; `snake_case` spellings are accepted as well
+fn normalize_rule_name(name: &str) -> String {
+    name.trim().replace('_', "-")
+}
+
+/// Severity level configured for a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    /// Report issues as errors
+    Error,
+    /// Report issues as warnings
+    #[serde(alias = "warn")]
+    Warning,
+    /// Report issues as informational messages
+    Info,
+    /// Do not run the rule
+    #[serde(alias = "off", alias = "ignore")]
+    Disabled,
+}
+
+impl RuleLevel {
+    /// Lint severity for this level, `None` when the rule is disabled
+    #[must_use]
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            Self::Error => Some(Severity::Error),
+            Self::Warning => Some(Severity::Warning),
+            Self::Info => Some(Severity::Info),
+            Self::Disabled => None,
+        }
+    }
+}
+
+/// Configuration of a single rule
+///
+/// Accepts either a bare level (`naming-convention: error`) or a mapping with
+/// a `level` and rule-specific options.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RuleSettingsRepr")]
+pub struct RuleSettings {
+    /// Severity level; the rule default when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<RuleLevel>,
+    /// Rule-specific options
+    #[serde(flatten)]
+    pub options: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RuleSettingsRepr {
+    Level(RuleLevel),
+    Settings {
+        #[serde(default)]
+        level: Option<RuleLevel>,
+        #[serde(flatten)]
+        options: HashMap<String, serde_json::Value>,
+    },
+}
+
+impl From<RuleSettingsRepr> for RuleSettings {
+    fn from(repr: RuleSettingsRepr) -> Self {
+        match repr {
+            RuleSettingsRepr::Level(level) => Self {
+                level: Some(level),
+                options: HashMap::new(),
+            },
+            RuleSettingsRepr::Settings { level, options } => Self { level, options },
+        }
+    }
+}
+
+/// Lint configuration, usually loaded from `.linkmllint.yaml`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LintConfig {
+    /// Base rule set: `recommended` (the default rules) or `none`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+    /// Per-rule configuration
+    #[serde(default)]
+    pub rules: IndexMap<String, RuleSettings>,
+    /// Regular expressions matching element names to skip
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+}
+
+impl LintConfig {
+    /// Parse a configuration from YAML (or JSON)
+    ///
+    /// # Errors
+    /// Returns error if the document is not a valid lint configuration.
+    pub fn from_yaml_str(content: &str) -> Result<Self> {
+        serde_yaml::from_str(content)
+            .map_err(|e| LinkMLError::config(format!("Invalid lint configuration: {e}")))
+    }
+
+    /// Load a configuration file
+    ///
+    /// # Errors
+    /// Returns error if the file cannot be read or parsed.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            LinkMLError::io_error(format!(
+                "Failed to read lint configuration {}: {e}",
+                path.display()
+            ))
+        })?;
+        Self::from_yaml_str(&content)
+    }
+
+    /// Find the nearest `.linkmllint.yaml` in `start` or one of its ancestors
+    #[must_use]
+    pub fn discover(start: &Path) -> Option<PathBuf> {
+        start.ancestors().find_map(|dir| {
+            LINT_CONFIG_FILES
+                .iter()
+                .map(|name| dir.join(name))
+                .find(|candidate| candidate.is_file())
+        })
+    }
 }
 
 /// Options for linting
@@ -91,26 +358,85 @@ pub struct LintOptions {
 
     /// Ignore patterns
     pub ignore_patterns: Vec<Regex>,
+
+    /// Severity overrides keyed by rule name
+    pub severity_overrides: HashMap<String, Severity>,
 }
 
 impl Default for LintOptions {
     fn default() -> Self {
+        Self::default_with(&LintRuleRegistry::with_defaults())
+    }
+}
+
+impl LintOptions {
+    /// Build options from a lint configuration
+    ///
+    /// # Errors
+    /// Returns error for unknown rules, unknown base rule sets, invalid rule
+    /// options, or invalid ignore patterns.
+    pub fn from_config(config: &LintConfig, registry: &LintRuleRegistry) -> Result<Self> {
+        let mut options = match config.extends.as_deref() {
+            None | Some("recommended" | "default") => Self::default_with(registry),
+            Some("none") => Self {
+                rules: Vec::new(),
+                rule_config: HashMap::new(),
+                ignore_patterns: Vec::new(),
+                severity_overrides: HashMap::new(),
+            },
+            Some(other) => {
+                return Err(LinkMLError::config(format!(
+                    "Unknown lint base '{other}' (expected 'recommended' or 'none')"
+                )));
+            }
+        };
+
+        for (name, settings) in &config.rules {
+            let name = normalize_rule_name(name);
+            let mut rule = registry.create(&name).ok_or_else(|| {
+                LinkMLError::config(format!(
+                    "Unknown lint rule '{name}' (available: {})",
+                    registry.names().join(", ")
+                ))
+            })?;
+            options.rules.retain(|existing| existing.name() != name);
+
+            // Mentioning a rule without a level enables it at its default severity
+            match settings.level.map(RuleLevel::severity) {
+                Some(None) => continue,
+                Some(Some(severity)) => {
+                    options.severity_overrides.insert(name.clone(), severity);
+                }
+                None => {}
+            }
+            if !settings.options.is_empty() {
+                rule.configure(&settings.options)?;
+                options
+                    .rule_config
+                    .insert(name.clone(), settings.options.clone());
+            }
+            options.rules.push(rule);
+        }
+
+        for pattern in &config.ignore {
+            let regex = Regex::new(pattern).map_err(|e| {
+                LinkMLError::config(format!("Invalid lint ignore pattern '{pattern}': {e}"))
+            })?;
+            options.ignore_patterns.push(regex);
+        }
+
+        Ok(options)
+    }
+
+    fn default_with(registry: &LintRuleRegistry) -> Self {
         Self {
-            rules: vec![
-                Box::new(NamingConventionRule),
-                Box::new(MissingDocumentationRule),
-                Box::new(UnusedDefinitionsRule),
-                Box::new(SlotConsistencyRule),
-                Box::new(TypeSafetyRule),
-                Box::new(SchemaMetadataRule),
-            ],
+            rules: registry.default_rules(),
             rule_config: HashMap::new(),
             ignore_patterns: Vec::new(),
+            severity_overrides: HashMap::new(),
         }
     }
-}
 
-impl LintOptions {
     /// Apply configuration from a map
     pub fn apply_config(&mut self, config: HashMap<String, serde_json::Value>) {
         // Store rule configurations
@@ -248,8 +574,15 @@ impl SchemaLinter {
         // Run each rule
         for rule in &self.options.rules {
             let issues = rule.check(schema);
+            let severity = self.options.severity_overrides.get(rule.name()).copied();
 
-            for issue in issues {
+            for mut issue in issues {
+                if self.is_ignored(&issue) {
+                    continue;
+                }
+                if let Some(severity) = severity {
+                    issue.severity = severity;
+                }
                 if issue.fixable {
                     fixable_issues.push(issue.clone());
                 }
@@ -264,35 +597,48 @@ impl SchemaLinter {
     }
 
     /// Fix issues in schema
-    /// Returns an error if the operation fails
+    ///
+    /// Rules with fixable issues in `result` are applied in rule order. Each
+    /// rule re-checks the schema first, so fixes see the effect of earlier
+    /// fixes (e.g. description stubs are added under the renamed element).
+    /// `result` is replaced by a fresh lint of the fixed schema.
     ///
     /// # Errors
     ///
+    /// Returns an error if a rule fails to apply its fixes
     pub fn fix(&self, schema: &mut SchemaDefinition, result: &mut LintResult) -> Result<usize> {
         let mut total_fixed = 0;
 
-        // Group issues by rule
-        let mut issues_by_rule: HashMap<String, Vec<&LintIssue>> = HashMap::new();
-        for issue in &result.fixable_issues {
-            issues_by_rule
-                .entry(issue.rule.clone())
-                .or_default()
-                .push(issue);
-        }
+        let rules_with_fixes: HashSet<&str> = result
+            .fixable_issues
+            .iter()
+            .map(|issue| issue.rule.as_str())
+            .collect();
 
-        // Apply fixes for each rule
         for rule in &self.options.rules {
-            if let Some(rule_issues) = issues_by_rule.get(rule.name()) {
-                // Convert &Vec<&LintIssue> to Vec<LintIssue>
-                let issues: Vec<LintIssue> =
-                    rule_issues.iter().map(|&issue| issue.clone()).collect();
-                let fixed = rule.fix(schema, &issues)?;
-                total_fixed += fixed;
+            if !rules_with_fixes.contains(rule.name()) {
+                continue;
             }
+            let issues: Vec<LintIssue> = rule
+                .check(schema)
+                .into_iter()
+                .filter(|issue| issue.fixable && !self.is_ignored(issue))
+                .collect();
+            total_fixed += rule.fix(schema, &issues)?;
         }
 
+        *result = self.lint(schema)?;
         Ok(total_fixed)
     }
+
+    fn is_ignored(&self, issue: &LintIssue) -> bool {
+        issue.element_name.as_deref().is_some_and(|name| {
+            self.options
+                .ignore_patterns
+                .iter()
+                .any(|pattern| pattern.is_match(name))
+        })
+    }
 }
 
 // Built-in lint rules
@@ -330,7 +676,7 @@ impl LintRule for NamingConventionRule {
                     line: None,
                     column: None,
                     suggestion: Some(format!("Rename to '{}'", to_pascal_case(class_name))),
-                    fixable: false,
+                    fixable: true,
                 });
             }
         }
@@ -348,7 +694,7 @@ impl LintRule for NamingConventionRule {
                     line: None,
                     column: None,
                     suggestion: Some(format!("Rename to '{}'", to_snake_case(slot_name))),
-                    fixable: false,
+                    fixable: true,
                 });
             }
         }
@@ -356,9 +702,36 @@ impl LintRule for NamingConventionRule {
         issues
     }
 
-    fn fix(&self, _schema: &mut SchemaDefinition, _issues: &[LintIssue]) -> Result<usize> {
-        // Naming changes require manual intervention
-        Ok(0)
+    fn fix(&self, schema: &mut SchemaDefinition, issues: &[LintIssue]) -> Result<usize> {
+        let pascal_case = Regex::new(r"^[A-Z][a-zA-Z0-9]*$").expect("valid regex pattern");
+        let snake_case = Regex::new(r"^[a-z][a-z0-9_]*$").expect("valid regex pattern");
+        let mut fixed = 0;
+
+        for issue in issues {
+            let Some(old) = issue.element_name.as_deref() else {
+                continue;
+            };
+            // Renames that would collide or still violate the convention are left alone
+            match issue.element_type.as_deref() {
+                Some("class") => {
+                    let new = to_pascal_case(old);
+                    if pascal_case.is_match(&new) && !schema.classes.contains_key(&new) {
+                        rename_class(schema, old, &new);
+                        fixed += 1;
+                    }
+                }
+                Some("slot") => {
+                    let new = to_snake_case(old);
+                    if snake_case.is_match(&new) && !schema.slots.contains_key(&new) {
+                        rename_slot(schema, old, &new);
+                        fixed += 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(fixed)
     }
 }
 
@@ -382,61 +755,76 @@ impl LintRule for MissingDocumentationRule {
     fn check(&self, schema: &SchemaDefinition) -> Vec<LintIssue> {
         let mut issues = Vec::new();
 
-        // Check schema description
-        if schema.description.is_none() {
+        let elements = std::iter::once(("schema", &schema.name, schema.description.as_ref()))
+            .chain(
+                schema
+                    .classes
+                    .iter()
+                    .map(|(name, class)| ("class", name, class.description.as_ref())),
+            )
+            .chain(
+                schema
+                    .slots
+                    .iter()
+                    .map(|(name, slot)| ("slot", name, slot.description.as_ref())),
+            );
+
+        for (element_type, name, description) in elements {
+            let (message, fixable) = match description {
+                None => ("has no description", true),
+                // Stubs added by `--fix` stay visible until they are replaced
+                Some(text) if text.trim() == DESCRIPTION_STUB => {
+                    ("has a placeholder description", false)
+                }
+                Some(_) => continue,
+            };
+            let subject = if element_type == "schema" {
+                "Schema".to_string()
+            } else {
+                let mut title = element_type.to_string();
+                title[..1].make_ascii_uppercase();
+                format!("{title} '{name}'")
+            };
             issues.push(LintIssue {
                 rule: self.name().to_string(),
                 severity: self.severity(),
-                message: "Schema has no description".to_string(),
-                element_type: Some("schema".to_string()),
-                element_name: Some(schema.name.clone()),
+                message: format!("{subject} {message}"),
+                element_type: Some(element_type.to_string()),
+                element_name: Some(name.clone()),
                 line: None,
                 column: None,
-                suggestion: Some("Add a description field to the schema".to_string()),
-                fixable: false,
+                suggestion: Some(format!("Add a description to the {element_type}")),
+                fixable,
             });
         }
 
-        // Check class descriptions
-        for (name, class) in &schema.classes {
-            if class.description.is_none() {
-                issues.push(LintIssue {
-                    rule: self.name().to_string(),
-                    severity: self.severity(),
-                    message: format!("Class '{name}' has no description"),
-                    element_type: Some("class".to_string()),
-                    element_name: Some(name.clone()),
-                    line: None,
-                    column: None,
-                    suggestion: Some("Add a description to the class".to_string()),
-                    fixable: false,
-                });
-            }
-        }
+        issues
+    }
 
-        // Check slot descriptions
-        for (name, slot) in &schema.slots {
-            if slot.description.is_none() {
-                issues.push(LintIssue {
-                    rule: self.name().to_string(),
-                    severity: self.severity(),
-                    message: format!("Slot '{name}' has no description"),
-                    element_type: Some("slot".to_string()),
-                    element_name: Some(name.clone()),
-                    line: None,
-                    column: None,
-                    suggestion: Some("Add a description to the slot".to_string()),
-                    fixable: false,
-                });
+    fn fix(&self, schema: &mut SchemaDefinition, issues: &[LintIssue]) -> Result<usize> {
+        let mut fixed = 0;
+
+        for issue in issues {
+            let description = match (issue.element_type.as_deref(), &issue.element_name) {
+                (Some("schema"), _) => Some(&mut schema.description),
+                (Some("class"), Some(name)) => schema
+                    .classes
+                    .get_mut(name)
+                    .map(|class| &mut class.description),
+                (Some("slot"), Some(name)) => {
+                    schema.slots.get_mut(name).map(|slot| &mut slot.description)
+                }
+                _ => None,
+            };
+            if let Some(description) = description
+                && description.is_none()
+            {
+                *description = Some(DESCRIPTION_STUB.to_string());
+                fixed += 1;
             }
         }
 
-        issues
-    }
-
-    fn fix(&self, _schema: &mut SchemaDefinition, _issues: &[LintIssue]) -> Result<usize> {
-        // Documentation must be added manually
-        Ok(0)
+        Ok(fixed)
     }
 }
 
@@ -736,74 +1124,1200 @@ impl LintRule for SchemaMetadataRule {
     }
 }
 
-// Helper functions
-
-fn to_pascal_case(s: &str) -> String {
-    s.split('_')
-        .map(|word| {
-            let mut chars = word.chars();
-            match chars.next() {
-                None => String::new(),
-                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-            }
-        })
-        .collect()
+/// Element ordering rule (opt-in)
+///
+/// Reports classes, slots, enums and types that are not listed in
+/// alphabetical order, which keeps diffs of large schemas reviewable.
+#[derive(Default)]
+struct ElementOrderingRule;
+
+impl ElementOrderingRule {
+    fn first_unsorted<V>(elements: &IndexMap<String, V>) -> Option<(&String, &String)> {
+        elements
+            .keys()
+            .zip(elements.keys().skip(1))
+            .find(|(previous, next)| previous > next)
+    }
 }
 
-fn to_snake_case(s: &str) -> String {
-    let mut result = String::new();
-    let mut prev_upper = false;
+impl LintRule for ElementOrderingRule {
+    fn name(&self) -> &'static str {
+        "element-ordering"
+    }
 
-    for (i, ch) in s.chars().enumerate() {
-        if ch.is_uppercase() && i > 0 && !prev_upper {
-            result.push('_');
-        }
-        result.push(
-            ch.to_lowercase()
-                .next()
-                .expect("to_lowercase() always produces at least one char"),
-        );
-        prev_upper = ch.is_uppercase();
+    fn description(&self) -> &'static str {
+        "Check that classes, slots, enums and types are sorted by name"
     }
 
-    result
-}
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+    fn enabled_by_default(&self) -> bool {
+        false
+    }
 
-    #[test]
-    fn test_naming_convention_rule() {
-        let mut schema = SchemaDefinition::default();
+    fn check(&self, schema: &SchemaDefinition) -> Vec<LintIssue> {
+        let sections = [
+            ("classes", Self::first_unsorted(&schema.classes)),
+            ("slots", Self::first_unsorted(&schema.slots)),
+            ("enums", Self::first_unsorted(&schema.enums)),
+            ("types", Self::first_unsorted(&schema.types)),
+        ];
 
-        // Add badly named class
-        let class = ClassDefinition::default();
-        schema.classes.insert("bad_class_name".to_string(), class);
+        sections
+            .into_iter()
+            .filter_map(|(section, unsorted)| {
+                let (previous, next) = unsorted?;
+                Some(LintIssue {
+                    rule: self.name().to_string(),
+                    severity: self.severity(),
+                    message: format!(
+                        "{section} are not sorted by name ('{next}' follows '{previous}')"
+                    ),
+                    element_type: Some(section.to_string()),
+                    element_name: None,
+                    line: None,
+                    column: None,
+                    suggestion: Some(format!("Sort {section} alphabetically")),
+                    fixable: true,
+                })
+            })
+            .collect()
+    }
 
-        // Add badly named slot
-        let slot = SlotDefinition::default();
-        schema.slots.insert("BadSlotName".to_string(), slot);
+    fn fix(&self, schema: &mut SchemaDefinition, issues: &[LintIssue]) -> Result<usize> {
+        let mut fixed = 0;
+        for issue in issues {
+            match issue.element_type.as_deref() {
+                Some("classes") => schema.classes.sort_keys(),
+                Some("slots") => schema.slots.sort_keys(),
+                Some("enums") => schema.enums.sort_keys(),
+                Some("types") => schema.types.sort_keys(),
+                _ => continue,
+            }
+            fixed += 1;
+        }
+        Ok(fixed)
+    }
+}
 
-        let rule = NamingConventionRule::default();
-        let issues = rule.check(&schema);
+// Helper functions
 
-        assert_eq!(issues.len(), 2);
-        assert!(issues[0].message.contains("PascalCase"));
-        assert!(issues[1].message.contains("snake_case"));
+/// Slot domain rule
+///
+/// A global slot with a `domain` may only be used by that class and its
+/// descendants (through `is_a` or mixins).
+#[derive(Default)]
+struct SlotDomainRule;
+
+impl LintRule for SlotDomainRule {
+    fn name(&self) -> &'static str {
+        "slot-domain"
     }
 
-    #[test]
-    fn test_unused_definitions_rule() {
-        let mut schema = SchemaDefinition::default();
+    fn description(&self) -> &'static str {
+        "Check that classes only use slots within their declared domain"
+    }
 
-        // Add unused slot
-        let slot = SlotDefinition::default();
-        schema.slots.insert("unused_slot".to_string(), slot);
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
 
-        // Add class that doesn't use the slot
-        let class = ClassDefinition::default();
+    fn check(&self, schema: &SchemaDefinition) -> Vec<LintIssue> {
+        let mut issues = Vec::new();
+
+        for (class_name, class) in &schema.classes {
+            let ancestors = class_ancestors(schema, class_name);
+            let mut seen = HashSet::new();
+            for slot_name in class.slots.iter().chain(class.slot_usage.keys()) {
+                if !seen.insert(slot_name) {
+                    continue;
+                }
+                let Some(domain) = schema
+                    .slots
+                    .get(slot_name)
+                    .and_then(|slot| slot.domain.as_ref())
+                else {
+                    continue;
+                };
+                if !schema.classes.contains_key(domain) || ancestors.contains(domain) {
+                    continue;
+                }
+                issues.push(LintIssue {
+                    rule: self.name().to_string(),
+                    severity: self.severity(),
+                    message: format!(
+                        "Class '{class_name}' uses slot '{slot_name}' outside its domain '{domain}'"
+                    ),
+                    element_type: Some("class".to_string()),
+                    element_name: Some(class_name.clone()),
+                    line: None,
+                    column: None,
+                    suggestion: Some(format!(
+                        "Make '{class_name}' a descendant of '{domain}', widen the domain of \
+                         '{slot_name}' or define a class attribute instead"
+                    )),
+                    fixable: false,
+                });
+            }
+        }
+
+        issues
+    }
+
+    fn fix(&self, _schema: &mut SchemaDefinition, _issues: &[LintIssue]) -> Result<usize> {
+        // Which side of the domain is wrong needs a human decision
+        Ok(0)
+    }
+}
+
+/// Inheritance order rule
+///
+/// Reports classes whose hierarchy has a cycle or no C3 linearization, and
+/// slots that unrelated ancestors of a class refine differently, so that
+/// only the order of its parents decides what the class inherits.
+#[derive(Default)]
+struct InheritanceOrderRule;
+
+impl LintRule for InheritanceOrderRule {
+    fn name(&self) -> &'static str {
+        "inheritance-order"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check that class hierarchies have a consistent, unambiguous resolution order"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check(&self, schema: &SchemaDefinition) -> Vec<LintIssue> {
+        let resolver = InheritanceResolver::new(schema);
+        let mut reported = HashSet::new();
+        let mut issues = Vec::new();
+
+        for class_name in schema.classes.keys() {
+            for diagnostic in resolver.mro_diagnostics(class_name) {
+                // Problems of an ancestor show up for each of its descendants
+                if !reported.insert((diagnostic.class.clone(), diagnostic.message.clone())) {
+                    continue;
+                }
+                let severity = match diagnostic.problem {
+                    MroProblem::Cycle | MroProblem::Inconsistent => Severity::Error,
+                    MroProblem::Ambiguous => Severity::Warning,
+                };
+                issues.push(LintIssue {
+                    rule: self.name().to_string(),
+                    severity,
+                    message: diagnostic.message,
+                    element_type: Some("class".to_string()),
+                    element_name: Some(diagnostic.class),
+                    line: None,
+                    column: None,
+                    suggestion: diagnostic.suggestion,
+                    fixable: false,
+                });
+            }
+        }
+
+        issues
+    }
+
+    fn fix(&self, _schema: &mut SchemaDefinition, _issues: &[LintIssue]) -> Result<usize> {
+        // Reordering parents changes what classes inherit
+        Ok(0)
+    }
+}
+
+/// Global slot reuse rule
+///
+/// Reports class attributes that duplicate a global slot, and attributes
+/// defined identically in several classes that could be promoted to a shared
+/// global slot.
+#[derive(Default)]
+struct GlobalSlotReuseRule;
+
+impl GlobalSlotReuseRule {
+    /// Attribute definitions by name, with the classes defining each
+    /// distinct definition (ignoring the `name` field)
+    fn attribute_groups(
+        schema: &SchemaDefinition,
+    ) -> IndexMap<&String, Vec<(SlotDefinition, Vec<&String>)>> {
+        let mut groups: IndexMap<&String, Vec<(SlotDefinition, Vec<&String>)>> = IndexMap::new();
+        for (class_name, class) in &schema.classes {
+            for (attribute_name, attribute) in class
+                .attributes
+                .iter()
+                .filter(|(_, attribute)| attribute_group_of(attribute).is_none())
+            {
+                let definition = unnamed(attribute);
+                let variants = groups.entry(attribute_name).or_default();
+                match variants.iter_mut().find(|(known, _)| *known == definition) {
+                    Some((_, classes)) => classes.push(class_name),
+                    None => variants.push((definition, vec![class_name])),
+                }
+            }
+        }
+        groups
+    }
+}
+
+impl LintRule for GlobalSlotReuseRule {
+    fn name(&self) -> &'static str {
+        "global-slot-reuse"
+    }
+
+    fn description(&self) -> &'static str {
+        "Suggest global slots for attributes redefined across classes"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn check(&self, schema: &SchemaDefinition) -> Vec<LintIssue> {
+        let mut issues = Vec::new();
+
+        for (attribute_name, variants) in Self::attribute_groups(schema) {
+            let global = schema.slots.get(attribute_name).map(unnamed);
+            for (definition, classes) in variants {
+                let class_list = classes
+                    .iter()
+                    .map(|name| format!("'{name}'"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let (message, suggestion, fixable) = match &global {
+                    Some(global) => (
+                        format!(
+                            "Attribute '{attribute_name}' of {class_list} redefines the global \
+                             slot '{attribute_name}'"
+                        ),
+                        "Use the global slot and refine it with slot_usage".to_string(),
+                        *global == definition,
+                    ),
+                    None if classes.len() > 1 => (
+                        format!(
+                            "Attribute '{attribute_name}' is defined identically in {class_list}"
+                        ),
+                        format!("Promote '{attribute_name}' to a shared global slot"),
+                        true,
+                    ),
+                    None => continue,
+                };
+                issues.push(LintIssue {
+                    rule: self.name().to_string(),
+                    severity: self.severity(),
+                    message,
+                    element_type: Some("slot".to_string()),
+                    element_name: Some(attribute_name.clone()),
+                    line: None,
+                    column: None,
+                    suggestion: Some(suggestion),
+                    fixable,
+                });
+            }
+        }
+
+        issues
+    }
+
+    fn fix(&self, schema: &mut SchemaDefinition, issues: &[LintIssue]) -> Result<usize> {
+        let mut fixed = 0;
+
+        for issue in issues.iter().filter(|issue| issue.fixable) {
+            let Some(attribute_name) = &issue.element_name else {
+                continue;
+            };
+            let Some(definition) = schema.slots.get(attribute_name).map(unnamed).or_else(|| {
+                schema
+                    .classes
+                    .values()
+                    .find_map(|class| class.attributes.get(attribute_name).map(unnamed))
+            }) else {
+                continue;
+            };
+
+            // Only identical attributes are replaced by the global slot
+            let mut promoted = false;
+            for class in schema.classes.values_mut() {
+                if class.attributes.get(attribute_name).map(unnamed).as_ref() != Some(&definition) {
+                    continue;
+                }
+                class.attributes.shift_remove(attribute_name);
+                if !class.slots.contains(attribute_name) {
+                    class.slots.push(attribute_name.clone());
+                }
+                promoted = true;
+            }
+            if promoted {
+                schema
+                    .slots
+                    .entry(attribute_name.clone())
+                    .or_insert_with(|| SlotDefinition {
+                        name: attribute_name.clone(),
+                        ..definition
+                    });
+                fixed += 1;
+            }
+        }
+
+        Ok(fixed)
+    }
+}
+
+/// Attribute group extraction rule
+///
+/// Reports sets of slots and attributes that several classes declare
+/// identically, which could be extracted into an attribute group the classes
+/// include. Attributes already expanded from a group are not counted.
+struct AttributeGroupExtractionRule {
+    /// Smallest number of shared slots worth a group
+    min_size: usize,
+}
+
+impl Default for AttributeGroupExtractionRule {
+    fn default() -> Self {
+        Self { min_size: 3 }
+    }
+}
+
+/// A slot reference, or an attribute with its definition
+type ClassMember = (String, Option<SlotDefinition>);
+
+impl AttributeGroupExtractionRule {
+    /// Slots and own attributes of a class, ordered by name
+    fn members(class: &ClassDefinition) -> Vec<ClassMember> {
+        let mut members: Vec<ClassMember> = class
+            .slots
+            .iter()
+            .map(|slot| (slot.clone(), None))
+            .chain(
+                class
+                    .attributes
+                    .iter()
+                    .filter(|(_, attribute)| attribute_group_of(attribute).is_none())
+                    .map(|(name, attribute)| (name.clone(), Some(unnamed(attribute)))),
+            )
+            .collect();
+        members.sort_by(|(a, _), (b, _)| a.cmp(b));
+        members
+    }
+
+    /// Member sets shared by at least two classes, with those classes
+    fn shared_groups(&self, schema: &SchemaDefinition) -> Vec<(Vec<ClassMember>, Vec<String>)> {
+        let classes: Vec<_> = schema
+            .classes
+            .iter()
+            .map(|(name, class)| (name, Self::members(class)))
+            .collect();
+        let mut groups: Vec<(Vec<ClassMember>, Vec<String>)> = Vec::new();
+        for (index, (first, first_members)) in classes.iter().enumerate() {
+            for (second, second_members) in &classes[index + 1..] {
+                let common: Vec<ClassMember> = first_members
+                    .iter()
+                    .filter(|member| second_members.contains(member))
+                    .cloned()
+                    .collect();
+                if common.len() < self.min_size {
+                    continue;
+                }
+                let position = groups.iter().position(|(members, _)| *members == common);
+                let position = position.unwrap_or_else(|| {
+                    groups.push((common, Vec::new()));
+                    groups.len() - 1
+                });
+                let classes = &mut groups[position].1;
+                for name in [*first, *second] {
+                    if !classes.contains(name) {
+                        classes.push(name.clone());
+                    }
+                }
+            }
+        }
+        groups
+    }
+}
+
+impl LintRule for AttributeGroupExtractionRule {
+    fn name(&self) -> &'static str {
+        "attribute-group-extraction"
+    }
+
+    fn description(&self) -> &'static str {
+        "Suggest attribute groups for slot sets repeated across classes"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn check(&self, schema: &SchemaDefinition) -> Vec<LintIssue> {
+        self.shared_groups(schema)
+            .into_iter()
+            .map(|(members, classes)| {
+                let quote = |names: Vec<&String>| {
+                    names
+                        .iter()
+                        .map(|name| format!("'{name}'"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                LintIssue {
+                    rule: self.name().to_string(),
+                    severity: self.severity(),
+                    message: format!(
+                        "Classes {} share the slots {}",
+                        quote(classes.iter().collect()),
+                        quote(members.iter().map(|(name, _)| name).collect())
+                    ),
+                    element_type: Some("class".to_string()),
+                    element_name: classes.first().cloned(),
+                    line: None,
+                    column: None,
+                    suggestion: Some(
+                        "Extract the slots into an attribute group and include it with \
+                         attribute_groups"
+                            .to_string(),
+                    ),
+                    fixable: false,
+                }
+            })
+            .collect()
+    }
+
+    fn fix(&self, _schema: &mut SchemaDefinition, _issues: &[LintIssue]) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn configure(&mut self, options: &HashMap<String, serde_json::Value>) -> Result<()> {
+        for (key, value) in options {
+            match (key.as_str(), value.as_u64()) {
+                ("min_size", Some(min_size)) if min_size >= 2 => {
+                    self.min_size = usize::try_from(min_size).unwrap_or(usize::MAX);
+                }
+                _ => {
+                    return Err(LinkMLError::config(format!(
+                        "Unsupported option '{key}: {value}' for attribute-group-extraction; \
+                         expected min_size of at least 2"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Deprecated reference rule
+///
+/// Reports classes and slots that are not deprecated themselves but inherit
+/// from, use or range over a deprecated class or slot, so that new parts of
+/// a model do not build on elements about to be removed. References to an
+/// element with a `deprecated_element_has_exact_replacement` are fixed by
+/// referring to the replacement instead.
+#[derive(Default)]
+struct DeprecatedReferenceRule;
+
+/// A reference from a class or slot to another class or slot
+struct ElementReference<'a> {
+    /// `class` or `slot`, for the referring element
+    referrer_type: &'static str,
+    referrer: &'a str,
+    /// Field holding the reference, e.g. `is_a` or `range`
+    field: &'static str,
+    /// Whether the referenced element is a class rather than a slot
+    to_class: bool,
+    target: &'a str,
+}
+
+impl DeprecatedReferenceRule {
+    /// References of the classes and slots that are not deprecated
+    fn references(schema: &SchemaDefinition) -> Vec<ElementReference<'_>> {
+        fn ranges(slots: &IndexMap<String, SlotDefinition>) -> Vec<&String> {
+            slots
+                .values()
+                .filter_map(|slot| slot.range.as_ref())
+                .collect()
+        }
+
+        let mut references = Vec::new();
+        for (name, class) in &schema.classes {
+            if class.deprecated.is_some() {
+                continue;
+            }
+            let targets: [(&'static str, bool, Vec<&String>); 6] = [
+                ("is_a", true, class.is_a.iter().collect()),
+                ("mixins", true, class.mixins.iter().collect()),
+                ("slots", false, class.slots.iter().collect()),
+                ("slot_usage", false, class.slot_usage.keys().collect()),
+                ("attributes", true, ranges(&class.attributes)),
+                ("slot_usage", true, ranges(&class.slot_usage)),
+            ];
+            references.extend(targets.into_iter().flat_map(|(field, to_class, targets)| {
+                targets.into_iter().map(move |target| ElementReference {
+                    referrer_type: "class",
+                    referrer: name,
+                    field,
+                    to_class,
+                    target,
+                })
+            }));
+        }
+        for (name, slot) in &schema.slots {
+            if slot.deprecated.is_some() {
+                continue;
+            }
+            let targets: [(&'static str, bool, Vec<&String>); 3] = [
+                ("is_a", false, slot.is_a.iter().collect()),
+                ("mixins", false, slot.mixins.iter().collect()),
+                ("range", true, slot.range.iter().collect()),
+            ];
+            references.extend(targets.into_iter().flat_map(|(field, to_class, targets)| {
+                targets.into_iter().map(move |target| ElementReference {
+                    referrer_type: "slot",
+                    referrer: name,
+                    field,
+                    to_class,
+                    target,
+                })
+            }));
+        }
+        references
+    }
+
+    /// The deprecation of the class or slot a reference points to
+    fn deprecation_of(
+        schema: &SchemaDefinition,
+        reference: &ElementReference,
+    ) -> Option<Deprecation> {
+        if reference.to_class {
+            let class = schema.classes.get(reference.target)?;
+            Deprecation::of_class(reference.target, class)
+        } else {
+            let slot = schema.slots.get(reference.target)?;
+            Deprecation::of_slot(reference.target, slot)
+        }
+    }
+}
+
+impl LintRule for DeprecatedReferenceRule {
+    fn name(&self) -> &'static str {
+        "deprecated-reference"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check that classes and slots do not refer to deprecated elements"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn check(&self, schema: &SchemaDefinition) -> Vec<LintIssue> {
+        let mut reported = HashSet::new();
+        let mut issues = Vec::new();
+
+        for reference in Self::references(schema) {
+            let Some(deprecation) = Self::deprecation_of(schema, &reference) else {
+                continue;
+            };
+            // A slot listed in `slots` and refined in `slot_usage` is reported once
+            if !reported.insert((reference.referrer, reference.to_class, reference.target)) {
+                continue;
+            }
+            let target_type = if reference.to_class { "class" } else { "slot" };
+            let referrer_label = match reference.referrer_type {
+                "class" => "Class",
+                _ => "Slot",
+            };
+            let (suggestion, fixable) = match (
+                &deprecation.exact_replacement,
+                &deprecation.possible_replacement,
+            ) {
+                (Some(exact), _) => (format!("Refer to '{exact}' instead"), true),
+                (None, Some(possible)) => {
+                    (format!("Consider referring to '{possible}' instead"), false)
+                }
+                (None, None) => (
+                    format!(
+                        "Stop using '{}' or deprecate '{}' as well",
+                        reference.target, reference.referrer
+                    ),
+                    false,
+                ),
+            };
+            issues.push(LintIssue {
+                rule: self.name().to_string(),
+                severity: self.severity(),
+                message: format!(
+                    "{referrer_label} '{}' refers to deprecated {target_type} '{}' in {}",
+                    reference.referrer, reference.target, reference.field
+                ),
+                element_type: Some(reference.referrer_type.to_string()),
+                element_name: Some(reference.referrer.to_string()),
+                line: None,
+                column: None,
+                suggestion: Some(suggestion),
+                fixable,
+            });
+        }
+
+        issues
+    }
+
+    fn fix(&self, schema: &mut SchemaDefinition, issues: &[LintIssue]) -> Result<usize> {
+        let exact = |deprecation: Option<Deprecation>| {
+            deprecation
+                .and_then(|deprecation| Some((deprecation.name, deprecation.exact_replacement?)))
+        };
+        let class_replacements: HashMap<String, String> = schema
+            .classes
+            .iter()
+            .filter_map(|(name, class)| exact(Deprecation::of_class(name, class)))
+            .collect();
+        let slot_replacements: HashMap<String, String> = schema
+            .slots
+            .iter()
+            .filter_map(|(name, slot)| exact(Deprecation::of_slot(name, slot)))
+            .collect();
+        let referrers: HashSet<(&str, &str)> = issues
+            .iter()
+            .filter(|issue| issue.fixable)
+            .filter_map(|issue| {
+                Some((
+                    issue.element_type.as_deref()?,
+                    issue.element_name.as_deref()?,
+                ))
+            })
+            .collect();
+
+        fn replace(name: &mut String, replacements: &HashMap<String, String>) -> usize {
+            match replacements.get(name.as_str()) {
+                Some(replacement) => {
+                    name.clone_from(replacement);
+                    1
+                }
+                None => 0,
+            }
+        }
+
+        let mut fixed = 0;
+        for (name, class) in &mut schema.classes {
+            if !referrers.contains(&("class", name.as_str())) {
+                continue;
+            }
+            for parent in class.is_a.iter_mut().chain(&mut class.mixins) {
+                fixed += replace(parent, &class_replacements);
+            }
+            for slot in &mut class.slots {
+                fixed += replace(slot, &slot_replacements);
+            }
+            let mut seen = HashSet::new();
+            class.slots.retain(|slot| seen.insert(slot.clone()));
+            for (old, new) in &slot_replacements {
+                if !class.slot_usage.contains_key(new)
+                    && rename_key(&mut class.slot_usage, old, new.clone()).is_some()
+                {
+                    fixed += 1;
+                }
+            }
+            for slot in class
+                .attributes
+                .values_mut()
+                .chain(class.slot_usage.values_mut())
+            {
+                if let Some(range) = &mut slot.range {
+                    fixed += replace(range, &class_replacements);
+                }
+            }
+        }
+        for (name, slot) in &mut schema.slots {
+            if !referrers.contains(&("slot", name.as_str())) {
+                continue;
+            }
+            for parent in slot.is_a.iter_mut().chain(&mut slot.mixins) {
+                fixed += replace(parent, &slot_replacements);
+            }
+            if let Some(range) = &mut slot.range {
+                fixed += replace(range, &class_replacements);
+            }
+        }
+
+        Ok(fixed)
+    }
+}
+
+/// Slot definition with its name cleared, for structural comparison
+fn unnamed(slot: &SlotDefinition) -> SlotDefinition {
+    SlotDefinition {
+        name: String::new(),
+        ..slot.clone()
+    }
+}
+
+/// A class and all its ancestors through `is_a` and mixins
+fn class_ancestors(schema: &SchemaDefinition, class_name: &str) -> HashSet<String> {
+    let mut ancestors = HashSet::new();
+    let mut pending = vec![class_name.to_string()];
+    while let Some(name) = pending.pop() {
+        if let Some(class) = schema.classes.get(&name) {
+            pending.extend(
+                class
+                    .is_a
+                    .iter()
+                    .chain(&class.mixins)
+                    .filter(|parent| !ancestors.contains(*parent))
+                    .cloned(),
+            );
+        }
+        ancestors.insert(name);
+    }
+    ancestors
+}
+
+/// Rename a map key in place, keeping its position
+fn rename_key<'a, K: Hash + Eq, V>(
+    map: &'a mut IndexMap<K, V>,
+    old: &K,
+    new: K,
+) -> Option<&'a mut V> {
+    let (index, _, value) = map.shift_remove_full(old)?;
+    let (last, _) = map.insert_full(new, value);
+    map.move_index(last, index);
+    map.get_index_mut(index).map(|(_, value)| value)
+}
+
+fn replace_name(name: &mut String, old: &str, new: &str) {
+    if name == old {
+        *name = new.to_string();
+    }
+}
+
+/// Every slot definition of a schema: global slots, slot usages and attributes
+fn slot_definitions_mut(
+    schema: &mut SchemaDefinition,
+) -> impl Iterator<Item = &mut SlotDefinition> {
+    schema
+        .slots
+        .values_mut()
+        .chain(schema.classes.values_mut().flat_map(|class| {
+            class
+                .slot_usage
+                .values_mut()
+                .chain(class.attributes.values_mut())
+        }))
+        .chain(
+            schema
+                .attribute_groups
+                .values_mut()
+                .flat_map(|group| group.attributes.values_mut()),
+        )
+}
+
+/// Visit the conditions of a rule, including nested composite conditions
+fn visit_rule_conditions(
+    conditions: &mut RuleConditions,
+    visit: &mut impl FnMut(&mut RuleConditions),
+) {
+    visit(conditions);
+    if let Some(composite) = &mut conditions.composite_conditions {
+        for nested in [
+            &mut composite.any_of,
+            &mut composite.all_of,
+            &mut composite.exactly_one_of,
+            &mut composite.none_of,
+        ]
+        .into_iter()
+        .flatten()
+        .flatten()
+        {
+            visit_rule_conditions(nested, visit);
+        }
+    }
+}
+
+/// Visit the pre-, post- and else-conditions of every rule of a class
+fn visit_class_rule_conditions(
+    class: &mut ClassDefinition,
+    visit: &mut impl FnMut(&mut RuleConditions),
+) {
+    for rule in &mut class.rules {
+        for conditions in [
+            &mut rule.preconditions,
+            &mut rule.postconditions,
+            &mut rule.else_conditions,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            visit_rule_conditions(conditions, visit);
+        }
+    }
+}
+
+/// Rename the ranges of boolean slot expressions, recursively
+fn rename_expression_ranges(
+    expressions: [&mut Option<Vec<AnonymousSlotExpression>>; 4],
+    old: &str,
+    new: &str,
+) {
+    for expression in expressions.into_iter().flatten().flatten() {
+        if let Some(range) = &mut expression.range {
+            replace_name(range, old, new);
+        }
+        rename_expression_ranges(
+            [
+                &mut expression.any_of,
+                &mut expression.all_of,
+                &mut expression.exactly_one_of,
+                &mut expression.none_of,
+            ],
+            old,
+            new,
+        );
+    }
+}
+
+/// Rename the class ranges of a slot condition
+fn rename_condition_ranges(condition: &mut SlotCondition, old: &str, new: &str) {
+    if let Some(range) = &mut condition.range {
+        replace_name(range, old, new);
+    }
+    rename_expression_ranges(
+        [
+            &mut condition.any_of,
+            &mut condition.all_of,
+            &mut condition.exactly_one_of,
+            &mut condition.none_of,
+        ],
+        old,
+        new,
+    );
+}
+
+/// Rename a class and update every reference to it
+fn rename_class(schema: &mut SchemaDefinition, old: &str, new: &str) {
+    if let Some(class) = rename_key(&mut schema.classes, &old.to_string(), new.to_string()) {
+        class.name = new.to_string();
+    }
+
+    for class in schema.classes.values_mut() {
+        for reference in [
+            &mut class.is_a,
+            &mut class.deprecated_element_has_exact_replacement,
+            &mut class.deprecated_element_has_possible_replacement,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            replace_name(reference, old, new);
+        }
+        class
+            .mixins
+            .iter_mut()
+            .for_each(|mixin| replace_name(mixin, old, new));
+        for requirement in class.if_required.iter_mut().flat_map(IndexMap::values_mut) {
+            if let Some(condition) = &mut requirement.condition {
+                rename_condition_ranges(condition, old, new);
+            }
+        }
+        visit_class_rule_conditions(class, &mut |conditions| {
+            for condition in conditions
+                .slot_conditions
+                .iter_mut()
+                .flat_map(IndexMap::values_mut)
+            {
+                rename_condition_ranges(condition, old, new);
+            }
+        });
+    }
+    for slot in slot_definitions_mut(schema) {
+        for reference in [&mut slot.range, &mut slot.domain].into_iter().flatten() {
+            replace_name(reference, old, new);
+        }
+        rename_expression_ranges(
+            [
+                &mut slot.any_of,
+                &mut slot.all_of,
+                &mut slot.exactly_one_of,
+                &mut slot.none_of,
+            ],
+            old,
+            new,
+        );
+    }
+}
+
+/// Rename a slot and update every reference to it
+fn rename_slot(schema: &mut SchemaDefinition, old: &str, new: &str) {
+    let old_key = old.to_string();
+    if let Some(slot) = rename_key(&mut schema.slots, &old_key, new.to_string()) {
+        slot.name = new.to_string();
+    }
+
+    for class in schema.classes.values_mut() {
+        class
+            .slots
+            .iter_mut()
+            .chain(
+                class
+                    .unique_keys
+                    .values_mut()
+                    .flat_map(|key| key.unique_key_slots.iter_mut()),
+            )
+            .for_each(|slot| replace_name(slot, old, new));
+        if let Some(usage) = rename_key(&mut class.slot_usage, &old_key, new.to_string()) {
+            replace_name(&mut usage.name, old, new);
+        }
+        if let Some(if_required) = &mut class.if_required {
+            rename_key(if_required, &old_key, new.to_string());
+            for required in if_required
+                .values_mut()
+                .filter_map(|requirement| requirement.then_required.as_mut())
+            {
+                required
+                    .iter_mut()
+                    .for_each(|slot| replace_name(slot, old, new));
+            }
+        }
+        visit_class_rule_conditions(class, &mut |conditions| {
+            if let Some(slot_conditions) = &mut conditions.slot_conditions {
+                rename_key(slot_conditions, &old_key, new.to_string());
+            }
+        });
+    }
+    for slot in slot_definitions_mut(schema) {
+        for reference in [
+            &mut slot.is_a,
+            &mut slot.inverse,
+            &mut slot.deprecated_element_has_exact_replacement,
+            &mut slot.deprecated_element_has_possible_replacement,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            replace_name(reference, old, new);
+        }
+        slot.mixins
+            .iter_mut()
+            .for_each(|mixin| replace_name(mixin, old, new));
+    }
+}
+
+fn to_pascal_case(s: &str) -> String {
+    s.split(['_', '-', ' '])
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+            }
+        })
+        .collect()
+}
+
+/// Unknown mapping rule
+///
+/// Reports `class_uri`, `slot_uri`, `*_mappings` and permissible value
+/// `meaning` terms that the ontology provider does not know, e.g. misspelled
+/// or withdrawn identifiers. Terms the provider cannot answer for are not
+/// reported.
+pub struct UnknownMappingRule {
+    provider: Arc<dyn OntologyProvider>,
+}
+
+impl UnknownMappingRule {
+    /// Create the rule checking terms against `provider`
+    #[must_use]
+    pub fn new(provider: Arc<dyn OntologyProvider>) -> Self {
+        Self { provider }
+    }
+}
+
+impl LintRule for UnknownMappingRule {
+    fn name(&self) -> &'static str {
+        "unknown-mapping"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check that mappings and URIs refer to existing ontology terms"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, schema: &SchemaDefinition) -> Vec<LintIssue> {
+        find_unknown_terms(schema, self.provider.as_ref())
+            .into_iter()
+            .map(|reference| LintIssue {
+                rule: self.name().to_string(),
+                severity: self.severity(),
+                message: format!(
+                    "{} of {} '{}' refers to unknown term '{}'",
+                    reference.field,
+                    reference.element_type.replace('_', " "),
+                    reference.element_name,
+                    reference.term
+                ),
+                element_type: Some(reference.element_type.to_string()),
+                element_name: Some(reference.element_name),
+                line: None,
+                column: None,
+                suggestion: Some(
+                    "Check the identifier or replace an obsolete term with its successor"
+                        .to_string(),
+                ),
+                fixable: false,
+            })
+            .collect()
+    }
+
+    fn fix(&self, _schema: &mut SchemaDefinition, _issues: &[LintIssue]) -> Result<usize> {
+        // The intended term cannot be derived from an unknown one
+        Ok(0)
+    }
+}
+
+/// Unresolved CURIE rule
+///
+/// Reports `class_uri`, `slot_uri`, `*_mappings` and permissible value
+/// `meaning` values that are malformed or use a prefix the schema does not
+/// declare. Undeclared prefixes known to prefixcommons are fixed by
+/// declaring them.
+struct UnresolvedCurieRule;
+
+impl LintRule for UnresolvedCurieRule {
+    fn name(&self) -> &'static str {
+        "unresolved-curie"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check that URIs and mappings are well-formed CURIEs with declared prefixes"
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, schema: &SchemaDefinition) -> Vec<LintIssue> {
+        check_curies(schema)
+            .into_iter()
+            .map(|issue| LintIssue {
+                rule: self.name().to_string(),
+                severity: match issue.problem {
+                    CurieProblem::Malformed => Severity::Error,
+                    CurieProblem::UndeclaredPrefix { .. } => self.severity(),
+                },
+                message: issue.to_string(),
+                element_type: Some(issue.reference.element_type.to_string()),
+                element_name: Some(issue.reference.element_name.clone()),
+                line: None,
+                column: None,
+                suggestion: Some(issue.suggestion()),
+                fixable: issue.declaration().is_some(),
+            })
+            .collect()
+    }
+
+    fn fix(&self, schema: &mut SchemaDefinition, issues: &[LintIssue]) -> Result<usize> {
+        let elements: HashSet<(&str, &str)> = issues
+            .iter()
+            .filter(|issue| issue.fixable)
+            .filter_map(|issue| {
+                Some((
+                    issue.element_type.as_deref()?,
+                    issue.element_name.as_deref()?,
+                ))
+            })
+            .collect();
+        let declarations: Vec<(String, String)> = check_curies(schema)
+            .iter()
+            .filter(|issue| {
+                elements.contains(&(
+                    issue.reference.element_type,
+                    issue.reference.element_name.as_str(),
+                ))
+            })
+            .filter_map(|issue| {
+                let (prefix, expansion) = issue.declaration()?;
+                Some((prefix.to_string(), expansion.to_string()))
+            })
+            .collect();
+
+        let mut fixed = 0;
+        for (prefix, expansion) in declarations {
+            if !schema.prefixes.contains_key(&prefix) {
+                schema
+                    .prefixes
+                    .insert(prefix, PrefixDefinition::Simple(expansion));
+                fixed += 1;
+            }
+        }
+        Ok(fixed)
+    }
+}
+
+fn to_snake_case(s: &str) -> String {
+    let mut result = String::new();
+    let mut prev_upper = false;
+
+    for (i, ch) in s.chars().enumerate() {
+        if matches!(ch, '-' | ' ') {
+            result.push('_');
+            prev_upper = false;
+            continue;
+        }
+        if ch.is_uppercase() && i > 0 && !prev_upper && !result.ends_with('_') {
+            result.push('_');
+        }
+        result.push(
+            ch.to_lowercase()
+                .next()
+                .expect("to_lowercase() always produces at least one char"),
+        );
+        prev_upper = ch.is_uppercase();
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+
+    #[test]
+    fn test_naming_convention_rule() {
+        let mut schema = SchemaDefinition::default();
+
+        // Add badly named class
+        let class = ClassDefinition::default();
+        schema.classes.insert("bad_class_name".to_string(), class);
+
+        // Add badly named slot
+        let slot = SlotDefinition::default();
+        schema.slots.insert("BadSlotName".to_string(), slot);
+
+        let rule = NamingConventionRule::default();
+        let issues = rule.check(&schema);
+
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("PascalCase"));
+        assert!(issues[1].message.contains("snake_case"));
+    }
+
+    #[test]
+    fn test_unused_definitions_rule() {
+        let mut schema = SchemaDefinition::default();
+
+        // Add unused slot
+        let slot = SlotDefinition::default();
+        schema.slots.insert("unused_slot".to_string(), slot);
+
+        // Add class that doesn't use the slot
+        let class = ClassDefinition::default();
         schema.classes.insert("MyClass".to_string(), class);
 
         let rule = UnusedDefinitionsRule::default();
@@ -813,4 +2327,594 @@ mod tests {
         assert!(issues[0].message.contains("never used"));
         assert!(issues[0].fixable);
     }
+
+    #[test]
+    fn test_lint_config_parsing() {
+        let config = LintConfig::from_yaml_str(
+            r#"
+extends: recommended
+rules:
+  naming_convention: error
+  missing-documentation:
+    level: warn
+  element-ordering: {}
+  schema-metadata: disabled
+ignore:
+  - "^Legacy"
+"#,
+        )
+        .expect("valid configuration");
+
+        assert_eq!(
+            config.rules["naming_convention"].level,
+            Some(RuleLevel::Error)
+        );
+        assert_eq!(
+            config.rules["missing-documentation"].level,
+            Some(RuleLevel::Warning)
+        );
+        assert_eq!(config.rules["element-ordering"].level, None);
+
+        let options = LintOptions::from_config(&config, &LintRuleRegistry::with_defaults())
+            .expect("known rules");
+        let names: Vec<&str> = options.rules.iter().map(|rule| rule.name()).collect();
+        assert!(names.contains(&"element-ordering"), "opt-in rule enabled");
+        assert!(!names.contains(&"schema-metadata"), "rule disabled");
+        assert_eq!(
+            options.severity_overrides.get("naming-convention"),
+            Some(&Severity::Error)
+        );
+        assert_eq!(options.ignore_patterns.len(), 1);
+
+        let unknown =
+            LintConfig::from_yaml_str("rules:\n  no-such-rule: error\n").expect("valid YAML");
+        assert!(LintOptions::from_config(&unknown, &LintRuleRegistry::with_defaults()).is_err());
+    }
+
+    #[test]
+    fn test_severity_overrides_and_ignore() {
+        let mut schema = SchemaDefinition::default();
+        schema
+            .classes
+            .insert("bad_class".to_string(), ClassDefinition::default());
+        schema
+            .classes
+            .insert("legacy_thing".to_string(), ClassDefinition::default());
+
+        let config = LintConfig {
+            extends: Some("none".to_string()),
+            rules: [(
+                "naming-convention".to_string(),
+                RuleSettings {
+                    level: Some(RuleLevel::Error),
+                    ..RuleSettings::default()
+                },
+            )]
+            .into_iter()
+            .collect(),
+            ignore: vec!["^legacy".to_string()],
+        };
+        let options = LintOptions::from_config(&config, &LintRuleRegistry::with_defaults())
+            .expect("valid configuration");
+        let result = SchemaLinter::new(options).lint(&schema).expect("lint");
+
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].element_name.as_deref(), Some("bad_class"));
+        assert_eq!(result.issues[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_fix_naming_and_descriptions() {
+        let mut schema = SchemaDefinition {
+            name: "test".to_string(),
+            description: Some("Test schema".to_string()),
+            ..Default::default()
+        };
+        schema.classes.insert(
+            "named_thing".to_string(),
+            ClassDefinition {
+                slots: vec!["FullName".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                is_a: Some("named_thing".to_string()),
+                slots: vec!["friend".to_string()],
+                description: Some("A person".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "FullName".to_string(),
+            SlotDefinition {
+                range: Some("string".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "friend".to_string(),
+            SlotDefinition {
+                range: Some("named_thing".to_string()),
+                description: Some("A friend".to_string()),
+                ..Default::default()
+            },
+        );
+
+        let linter = SchemaLinter::new(LintOptions::default());
+        let mut result = linter.lint(&schema).expect("lint");
+        let fixed = linter.fix(&mut schema, &mut result).expect("fix");
+
+        assert_eq!(fixed, 4, "two renames and two description stubs");
+        assert!(schema.classes.contains_key("NamedThing"));
+        assert_eq!(
+            schema.classes.get_index_of("NamedThing"),
+            Some(0),
+            "position kept"
+        );
+        assert_eq!(schema.classes["Person"].is_a.as_deref(), Some("NamedThing"));
+        assert_eq!(schema.classes["NamedThing"].slots, vec!["full_name"]);
+        assert_eq!(schema.slots["friend"].range.as_deref(), Some("NamedThing"));
+        assert_eq!(
+            schema.slots["full_name"].description.as_deref(),
+            Some(DESCRIPTION_STUB)
+        );
+        assert!(
+            result
+                .issues
+                .iter()
+                .all(|issue| issue.rule != "naming-convention"),
+            "result refreshed after fixing"
+        );
+        assert!(
+            result
+                .issues
+                .iter()
+                .any(|issue| issue.message.contains("placeholder")),
+            "stubs remain visible"
+        );
+    }
+
+    #[test]
+    fn test_fix_renames_every_reference() {
+        let slot = |description: &str| SlotDefinition {
+            description: Some(description.to_string()),
+            ..Default::default()
+        };
+        let slot_conditions =
+            |condition: SlotCondition| Some(IndexMap::from([("ZipCode".to_string(), condition)]));
+
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/test".to_string(),
+            name: "test".to_string(),
+            description: Some("Test schema".to_string()),
+            version: Some("1.0.0".to_string()),
+            license: Some("CC0".to_string()),
+            ..Default::default()
+        };
+        schema.classes.insert(
+            "postal_address".to_string(),
+            ClassDefinition {
+                description: Some("An address".to_string()),
+                slots: vec!["ZipCode".to_string(), "street".to_string()],
+                unique_keys: IndexMap::from([(
+                    "address_key".to_string(),
+                    UniqueKeyDefinition {
+                        unique_key_slots: vec!["ZipCode".to_string(), "street".to_string()],
+                        ..Default::default()
+                    },
+                )]),
+                if_required: Some(IndexMap::from([(
+                    "ZipCode".to_string(),
+                    ConditionalRequirement {
+                        condition: None,
+                        then_required: Some(vec!["street".to_string()]),
+                    },
+                )])),
+                rules: vec![Rule {
+                    preconditions: Some(RuleConditions {
+                        slot_conditions: slot_conditions(SlotCondition {
+                            required: Some(true),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    postconditions: Some(RuleConditions {
+                        composite_conditions: Some(CompositeConditions {
+                            any_of: Some(vec![RuleConditions {
+                                slot_conditions: slot_conditions(SlotCondition {
+                                    pattern: Some("^[0-9]{5}$".to_string()),
+                                    ..Default::default()
+                                }),
+                                ..Default::default()
+                            }]),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                description: Some("A person".to_string()),
+                slots: vec!["residence".to_string()],
+                if_required: Some(IndexMap::from([(
+                    "residence".to_string(),
+                    ConditionalRequirement {
+                        condition: Some(SlotCondition {
+                            range: Some("postal_address".to_string()),
+                            ..Default::default()
+                        }),
+                        then_required: Some(vec!["residence".to_string()]),
+                    },
+                )])),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Address".to_string(),
+            ClassDefinition {
+                description: Some("An address".to_string()),
+                slots: vec!["zip".to_string()],
+                deprecated: Some("Superseded".to_string()),
+                deprecated_element_has_exact_replacement: Some("postal_address".to_string()),
+                ..Default::default()
+            },
+        );
+        schema
+            .slots
+            .insert("ZipCode".to_string(), slot("A postal code"));
+        schema
+            .slots
+            .insert("street".to_string(), slot("A street name"));
+        schema.slots.insert(
+            "residence".to_string(),
+            SlotDefinition {
+                any_of: Some(vec![
+                    AnonymousSlotExpression {
+                        range: Some("postal_address".to_string()),
+                        ..Default::default()
+                    },
+                    AnonymousSlotExpression {
+                        range: Some("string".to_string()),
+                        ..Default::default()
+                    },
+                ]),
+                exactly_one_of: Some(vec![AnonymousSlotExpression {
+                    all_of: Some(vec![AnonymousSlotExpression {
+                        range: Some("postal_address".to_string()),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                }]),
+                ..slot("Where a person lives")
+            },
+        );
+        schema.slots.insert(
+            "zip".to_string(),
+            SlotDefinition {
+                deprecated: Some("Superseded".to_string()),
+                deprecated_element_has_exact_replacement: Some("ZipCode".to_string()),
+                ..slot("A postal code")
+            },
+        );
+
+        let linter = SchemaLinter::new(LintOptions::default());
+        let mut result = linter.lint(&schema).expect("lint");
+        let fixed = linter.fix(&mut schema, &mut result).expect("fix");
+        assert_eq!(fixed, 2, "one class and one slot renamed");
+
+        let serialized = serde_json::to_string(&schema).expect("serialize fixed schema");
+        for old in ["postal_address", "ZipCode"] {
+            assert!(!serialized.contains(old), "'{old}' still referenced");
+        }
+        let relinted = linter.lint(&schema).expect("lint fixed schema");
+        assert!(
+            relinted.issues.is_empty(),
+            "fixed schema lints cleanly: {:?}",
+            relinted.issues
+        );
+    }
+
+    #[test]
+    fn test_element_ordering_rule() {
+        let mut schema = SchemaDefinition::default();
+        for name in ["Zebra", "Apple", "Mango"] {
+            schema
+                .classes
+                .insert(name.to_string(), ClassDefinition::default());
+        }
+
+        let rule = ElementOrderingRule;
+        assert!(!rule.enabled_by_default());
+        let issues = rule.check(&schema);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].element_type.as_deref(), Some("classes"));
+
+        assert_eq!(rule.fix(&mut schema, &issues).expect("fix"), 1);
+        let names: Vec<&String> = schema.classes.keys().collect();
+        assert_eq!(names, ["Apple", "Mango", "Zebra"]);
+        assert!(rule.check(&schema).is_empty());
+    }
+
+    #[test]
+    fn test_discover_config() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let nested = dir.path().join("schemas/core");
+        std::fs::create_dir_all(&nested).expect("create dirs");
+        assert_eq!(LintConfig::discover(&nested), None);
+
+        let config_path = dir.path().join(".linkmllint.yaml");
+        std::fs::write(&config_path, "extends: recommended\n").expect("write config");
+        assert_eq!(LintConfig::discover(&nested), Some(config_path));
+    }
+
+    #[test]
+    fn test_slot_domain_rule() {
+        let mut schema = SchemaDefinition::default();
+        schema.slots.insert(
+            "employer".to_string(),
+            SlotDefinition {
+                domain: Some("Person".to_string()),
+                ..Default::default()
+            },
+        );
+        let uses_employer = ClassDefinition {
+            slots: vec!["employer".to_string()],
+            ..Default::default()
+        };
+        schema
+            .classes
+            .insert("Person".to_string(), ClassDefinition::default());
+        schema.classes.insert(
+            "Employee".to_string(),
+            ClassDefinition {
+                is_a: Some("Person".to_string()),
+                ..uses_employer.clone()
+            },
+        );
+        schema
+            .classes
+            .insert("Organization".to_string(), uses_employer);
+
+        let issues = SlotDomainRule.check(&schema);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].element_name.as_deref(), Some("Organization"));
+        assert_eq!(issues[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_inheritance_order_rule() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/order
+name: order
+classes:
+  Entity: {}
+  Agent:
+    is_a: Entity
+  Person:
+    mixins: [Entity, Agent]
+  Employee:
+    is_a: Person
+  Left:
+    mixin: true
+    slot_usage:
+      id:
+        range: integer
+  Right:
+    mixin: true
+    slot_usage:
+      id:
+        range: string
+  Record:
+    mixins: [Left, Right]
+slots:
+  id: {}
+",
+        )?;
+
+        let issues = InheritanceOrderRule.check(&schema);
+        assert_eq!(issues.len(), 2);
+        // Reported for Person only, not again for Employee
+        assert_eq!(issues[0].element_name.as_deref(), Some("Person"));
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert!(
+            issues[0]
+                .suggestion
+                .as_deref()
+                .is_some_and(|suggestion| suggestion.contains("List 'Agent' before 'Entity'"))
+        );
+        assert_eq!(issues[1].element_name.as_deref(), Some("Record"));
+        assert_eq!(issues[1].severity, Severity::Warning);
+        assert!(issues[1].message.contains("slot 'id'"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_global_slot_reuse_rule() {
+        let mut schema = SchemaDefinition::default();
+        let string_slot = SlotDefinition {
+            range: Some("string".to_string()),
+            ..Default::default()
+        };
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                name: "id".to_string(),
+                ..string_slot.clone()
+            },
+        );
+        for class_name in ["Person", "Organization"] {
+            let mut class = ClassDefinition::default();
+            class
+                .attributes
+                .insert("id".to_string(), string_slot.clone());
+            class
+                .attributes
+                .insert("label".to_string(), string_slot.clone());
+            schema.classes.insert(class_name.to_string(), class);
+        }
+        // A differing definition is reported but not promoted
+        schema.classes["Organization"]
+            .attributes
+            .insert("code".to_string(), string_slot.clone());
+        schema.classes["Person"].attributes.insert(
+            "code".to_string(),
+            SlotDefinition {
+                range: Some("integer".to_string()),
+                ..Default::default()
+            },
+        );
+
+        let rule = GlobalSlotReuseRule;
+        let issues = rule.check(&schema);
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message.contains("redefines the global slot 'id'"));
+        assert!(issues[1].message.contains("'label' is defined identically"));
+
+        assert_eq!(rule.fix(&mut schema, &issues).expect("fixable"), 2);
+        assert!(schema.slots.contains_key("label"));
+        for class in schema.classes.values() {
+            assert_eq!(class.slots, ["id", "label"]);
+            assert!(class.attributes.keys().eq(["code"]));
+        }
+        assert!(rule.check(&schema).is_empty());
+    }
+
+    #[test]
+    fn test_attribute_group_extraction_rule() {
+        let mut schema = SchemaDefinition::default();
+        let audit = ["created_at", "created_by", "updated_at"];
+        for class_name in ["Person", "Dataset", "Project"] {
+            let mut class = ClassDefinition {
+                slots: audit.iter().map(ToString::to_string).collect(),
+                ..Default::default()
+            };
+            class.slots.push("name".to_string());
+            schema.classes.insert(class_name.to_string(), class);
+        }
+        // Sharing fewer slots than the minimum is not reported
+        schema.classes["Project"].slots.truncate(2);
+
+        let mut rule = AttributeGroupExtractionRule::default();
+        let issues = rule.check(&schema);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].message,
+            "Classes 'Person', 'Dataset' share the slots 'created_at', 'created_by', 'name', \
+             'updated_at'"
+        );
+
+        let options = HashMap::from([("min_size".to_string(), serde_json::json!(2))]);
+        rule.configure(&options).expect("min_size");
+        assert_eq!(rule.check(&schema).len(), 2);
+        let invalid = HashMap::from([("min_size".to_string(), serde_json::json!(1))]);
+        assert!(rule.configure(&invalid).is_err());
+
+        // Attributes expanded from a group are not suggested again
+        let expanded = crate::parser::YamlParser::new()
+            .parse(
+                "id: x\nname: x\nattribute_groups:\n  audit:\n    attributes: {a: {}, b: {}, c: {}}\n\
+                 classes:\n  A: {attribute_groups: [audit]}\n  B: {attribute_groups: [audit]}\n",
+            )
+            .expect("schema");
+        assert!(
+            AttributeGroupExtractionRule::default()
+                .check(&expanded)
+                .is_empty()
+        );
+        assert!(GlobalSlotReuseRule.check(&expanded).is_empty());
+    }
+
+    #[test]
+    fn test_deprecated_reference_rule() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/people
+name: people
+classes:
+  Person:
+    deprecated: Use Agent
+    deprecated_element_has_exact_replacement: Agent
+    slots: [age]
+  Agent: {}
+  Employee:
+    is_a: Person
+    slots: [age, name]
+    slot_usage:
+      age:
+        required: true
+slots:
+  name: {}
+  age:
+    deprecated: Ages go stale
+    deprecated_element_has_possible_replacement: birth_date
+  birth_date: {}
+  manager:
+    range: Person
+",
+        )?;
+
+        let rule = DeprecatedReferenceRule;
+        let issues = rule.check(&schema);
+        let messages: Vec<&str> = issues.iter().map(|issue| issue.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "Class 'Employee' refers to deprecated class 'Person' in is_a",
+                "Class 'Employee' refers to deprecated slot 'age' in slots",
+                "Slot 'manager' refers to deprecated class 'Person' in range",
+            ]
+        );
+        assert_eq!(
+            issues[1].suggestion.as_deref(),
+            Some("Consider referring to 'birth_date' instead")
+        );
+        assert!(issues[0].fixable && !issues[1].fixable);
+
+        // Only exact replacements are applied
+        assert_eq!(rule.fix(&mut schema, &issues)?, 2);
+        assert_eq!(schema.classes["Employee"].is_a.as_deref(), Some("Agent"));
+        assert_eq!(schema.slots["manager"].range.as_deref(), Some("Agent"));
+        assert_eq!(rule.check(&schema).len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unresolved_curie_rule() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/processes
+name: processes
+classes:
+  Process:
+    exact_mappings: [GO:0008150, myonto:Process]
+    close_mappings: ['GO 0008150']
+",
+        )?;
+
+        let rule = UnresolvedCurieRule;
+        let issues = rule.check(&schema);
+        assert_eq!(issues.len(), 3);
+        assert_eq!(
+            issues[0].suggestion.as_deref(),
+            Some("Declare prefix 'GO' as 'http://purl.obolibrary.org/obo/GO_'")
+        );
+        assert!(issues[0].fixable && !issues[1].fixable);
+        assert_eq!(issues[2].severity, Severity::Error);
+
+        assert_eq!(rule.fix(&mut schema, &issues)?, 1);
+        assert!(matches!(
+            &schema.prefixes["GO"],
+            PrefixDefinition::Simple(uri) if uri == "http://purl.obolibrary.org/obo/GO_"
+        ));
+        assert_eq!(rule.check(&schema).len(), 2);
+        Ok(())
+    }
 }
@@ -0,0 +1,629 @@
+//! Lossless schema edits
+//!
+//! Loading a schema into [`SchemaDefinition`] and serializing it again drops
+//! YAML comments, reorders keys and rewrites every value in its canonical
+//! form, so a one-line fix shows up as a rewrite of the whole file in code
+//! review. A [`LosslessDocument`] records which lines of the YAML text each
+//! key of a block mapping spans, together with the comments around it.
+//! [`LosslessDocument::update`] compares the schema before and after an
+//! automated edit and touches only the lines of the keys that changed:
+//!
+//! - changed values are rewritten in place, keeping a trailing comment on
+//!   single-line entries
+//! - removed keys are deleted with the comment lines directly above them
+//! - added keys are inserted after the key preceding them in the edited
+//!   schema
+//!
+//! Everything else, including keys the model does not know about, stays
+//! byte for byte as written. Values inside sequences and flow collections
+//! are rewritten as a whole when any part of them changes.
+
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
+use serde_yaml::{Mapping, Value};
+use yaml_rust2::parser::{Event, Parser};
+use yaml_rust2::scanner::Marker;
+
+use super::attribute_groups::expanded;
+use super::format::{Context, FormatOptions, SchemaFormatter};
+
+/// A key of a block mapping and the lines it spans
+#[derive(Debug, Clone)]
+pub(crate) struct Entry {
+    /// Key text
+    pub(crate) key: String,
+    /// Column of the key, which is the indentation of its mapping
+    pub(crate) indent: usize,
+    /// Line of the key (0-based)
+    pub(crate) line: usize,
+    /// End of the entry (exclusive), before trailing blank and comment lines
+    pub(crate) end: usize,
+    /// First of the comment lines directly above the key
+    pub(crate) comment_start: usize,
+    /// Comment lines between the previous key and this one
+    pub(crate) leading: Vec<String>,
+    /// Comment after the key or its value on the key line, with the
+    /// whitespace before it
+    pub(crate) inline: Option<String>,
+    /// Entries of the value, when it is a block mapping
+    pub(crate) children: Option<Vec<Entry>>,
+}
+
+impl Entry {
+    fn new(key: String, indent: usize, line: usize) -> Self {
+        Self {
+            key,
+            indent,
+            line,
+            end: line + 1,
+            comment_start: line,
+            leading: Vec::new(),
+            inline: None,
+            children: None,
+        }
+    }
+}
+
+/// Block mapping being read while building a document
+enum Frame {
+    Block {
+        entries: Vec<Entry>,
+        expecting_value: bool,
+    },
+    /// Flow mapping or sequence, read as a single value; counts the
+    /// collections open inside it
+    Opaque(usize),
+}
+
+impl Frame {
+    /// Advance past the value of the current entry of a block mapping,
+    /// which holds `children` when it is a block mapping itself
+    fn finish_value(&mut self, children: Option<Vec<Entry>>) {
+        if let Self::Block {
+            entries,
+            expecting_value,
+        } = self
+        {
+            if let Some(last) = entries.last_mut() {
+                last.children = children;
+            }
+            *expecting_value = false;
+        }
+    }
+}
+
+/// YAML schema text with the line span of every block mapping key
+#[derive(Debug, Clone)]
+pub struct LosslessDocument {
+    lines: Vec<String>,
+    entries: Vec<Entry>,
+    /// Comment lines after the last key
+    trailing: Vec<String>,
+    /// Spaces per indentation level of nested mappings
+    indent: usize,
+    trailing_newline: bool,
+}
+
+impl LosslessDocument {
+    /// Map the first document of YAML text whose root is a block mapping
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not well-formed YAML or its root is
+    /// not a block mapping, e.g. a `JSON` schema.
+    pub fn parse(content: &str) -> Result<Self> {
+        let lines: Vec<String> = content.lines().map(str::to_string).collect();
+        let mut entries = read_entries(content, &lines)?;
+        let trailing_start = entries.last().map_or(0, |last| last.end);
+        let trailing_start = annotate(&lines, &mut entries, 0, trailing_start);
+        let trailing = comment_lines(&lines[trailing_start.min(lines.len())..]);
+        let indent = entries
+            .iter()
+            .find_map(|entry| entry.children.as_ref()?.first().map(|child| child.indent))
+            .filter(|indent| *indent > 0)
+            .unwrap_or(2);
+        Ok(Self {
+            lines,
+            entries,
+            trailing,
+            indent,
+            trailing_newline: content.ends_with('\n'),
+        })
+    }
+
+    /// Keys of the root mapping with their lines and comments
+    pub(crate) fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Comment lines after the last key of the document
+    pub(crate) fn trailing_comments(&self) -> &[String] {
+        &self.trailing
+    }
+
+    /// The text with the changes from `before` to `after` applied
+    ///
+    /// `before` and `after` are schema documents in the form the schema
+    /// model serializes to; `before` must describe this text. Keys are
+    /// matched by name, so values the model writes differently from the
+    /// text, such as a single slot name instead of a list, are only
+    /// rewritten when they change.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either document is not a mapping or a changed
+    /// value cannot be rendered as YAML.
+    pub fn update(&self, before: &Value, after: &Value) -> Result<String> {
+        let (Value::Mapping(before), Value::Mapping(after)) = (before, after) else {
+            return Err(LinkMLError::parse("Schema document must be a mapping"));
+        };
+        let formatter = SchemaFormatter::new(FormatOptions::default().with_indent(self.indent));
+        let mut edits = Vec::new();
+        update_entries(
+            &self.entries,
+            before,
+            after,
+            0,
+            Context::Schema,
+            &formatter,
+            &mut edits,
+        )?;
+
+        // Apply edits from the end so line numbers stay valid; replacements
+        // go before insertions at the same line, and insertions at the same
+        // line are applied last to first so they end up in order
+        let mut edits: Vec<(usize, Edit)> = edits.into_iter().enumerate().collect();
+        edits.sort_by(|(a_order, a), (b_order, b)| {
+            b.start
+                .cmp(&a.start)
+                .then_with(|| (a.end == a.start).cmp(&(b.end == b.start)))
+                .then_with(|| b_order.cmp(a_order))
+        });
+        let mut lines = self.lines.clone();
+        for (_, edit) in edits {
+            lines.splice(edit.start..edit.end, edit.lines);
+        }
+        let mut text = lines.join("\n");
+        if self.trailing_newline && !text.is_empty() {
+            text.push('\n');
+        }
+        Ok(text)
+    }
+}
+
+/// Collect the edits turning the `entries` of a block mapping from `before`
+/// into `after`
+fn update_entries(
+    entries: &[Entry],
+    before: &Mapping,
+    after: &Mapping,
+    indent: usize,
+    context: Context,
+    formatter: &SchemaFormatter,
+    edits: &mut Vec<Edit>,
+) -> Result<()> {
+    let indent = entries.first().map_or(indent, |entry| entry.indent);
+
+    for entry in entries {
+        let old = find_key(before, &entry.key);
+        let new = find_key(after, &entry.key);
+        match (old, new) {
+            // Keys unknown to the model are kept as written
+            (None, None) => {}
+            (Some(_), None) => {
+                edits.push(Edit::new(entry.comment_start, entry.end, Vec::new()));
+            }
+            (Some((_, old)), Some((_, new))) if old == new => {}
+            (Some((_, Value::Mapping(old))), Some((key, Value::Mapping(new))))
+                if entry.children.is_some() && !new.is_empty() =>
+            {
+                let children = entry.children.as_deref().unwrap_or_default();
+                update_entries(
+                    children,
+                    old,
+                    new,
+                    indent + formatter.indent(),
+                    context.child(key),
+                    formatter,
+                    edits,
+                )?;
+            }
+            (_, Some((key, new))) => {
+                let mut rendered = render(formatter, key, new, entry.indent, context)?;
+                if entry.end == entry.line + 1
+                    && rendered.len() == 1
+                    && let Some(comment) = &entry.inline
+                {
+                    rendered[0].push_str(comment);
+                }
+                edits.push(Edit::new(entry.line, entry.end, rendered));
+            }
+        }
+    }
+
+    // Keys missing from the text, placed after the closest key
+    // preceding them in the edited schema
+    let mut anchor = entries.first().map_or(0, |first| first.comment_start);
+    for (key, value) in after {
+        let name = key_text(key).unwrap_or_default();
+        if let Some(entry) = entries.iter().find(|entry| entry.key == name) {
+            anchor = entry.end;
+            continue;
+        }
+        if before.get(key) == Some(value) {
+            continue;
+        }
+        let rendered = render(formatter, key, value, indent, context)?;
+        edits.push(Edit::new(anchor, anchor, rendered));
+    }
+    Ok(())
+}
+
+/// Replacement of a range of lines; insertions have an empty range
+struct Edit {
+    start: usize,
+    end: usize,
+    lines: Vec<String>,
+}
+
+impl Edit {
+    fn new(start: usize, end: usize, lines: Vec<String>) -> Self {
+        Self { start, end, lines }
+    }
+}
+
+/// Rewrite a schema file after an automated edit, touching only the lines
+/// of the keys that changed
+///
+/// `before` is the schema as loaded from `original`, `after` the edited
+/// schema. Both are compared with attribute groups expanded, as the CLI
+/// loads schemas.
+///
+/// # Errors
+///
+/// Returns an error if `original` is not a YAML block mapping or a schema
+/// cannot be serialized.
+pub fn update_schema_text(
+    original: &str,
+    before: &SchemaDefinition,
+    after: &SchemaDefinition,
+) -> Result<String> {
+    let document = LosslessDocument::parse(original)?;
+    let to_value = |schema: &SchemaDefinition| {
+        serde_yaml::to_value(expanded(schema.clone()))
+            .map_err(|err| LinkMLError::serialization(err.to_string()))
+    };
+    document.update(&to_value(before)?, &to_value(after)?)
+}
+
+/// Entries of the block mapping at the root of a YAML document, with the
+/// lines they span before trimming
+fn read_entries(content: &str, lines: &[String]) -> Result<Vec<Entry>> {
+    let chars: Vec<char> = content.chars().collect();
+    // Line after the content of a node ending where the next token starts
+    let end_line = |marker: Marker| {
+        if marker.index() >= chars.len() {
+            lines.len()
+        } else {
+            marker.line().saturating_sub(1)
+        }
+    };
+
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut root = None;
+    let mut parser = Parser::new_from_str(content);
+    loop {
+        let (event, marker) = parser
+            .next_token()
+            .map_err(|err| LinkMLError::parse(format!("Invalid schema document: {err}")))?;
+        match event {
+            Event::StreamEnd | Event::DocumentEnd => break,
+            Event::MappingStart(..) | Event::SequenceStart(..) => {
+                if let Some(Frame::Opaque(depth)) = stack.last_mut() {
+                    *depth += 1;
+                } else if matches!(event, Event::MappingStart(..))
+                    && chars.get(marker.index()) != Some(&'{')
+                {
+                    stack.push(Frame::Block {
+                        entries: Vec::new(),
+                        expecting_value: false,
+                    });
+                } else if stack.is_empty() {
+                    return Err(not_block_mapping());
+                } else {
+                    stack.push(Frame::Opaque(1));
+                }
+            }
+            Event::Scalar(text, ..) => match stack.last_mut() {
+                Some(Frame::Block {
+                    entries,
+                    expecting_value,
+                }) => {
+                    if *expecting_value {
+                        *expecting_value = false;
+                    } else {
+                        let line = marker.line().saturating_sub(1);
+                        if let Some(previous) = entries.last_mut() {
+                            previous.end = line;
+                        }
+                        entries.push(Entry::new(text, marker.col(), line));
+                        *expecting_value = true;
+                    }
+                }
+                Some(Frame::Opaque(_)) => {}
+                None => {
+                    return Err(not_block_mapping());
+                }
+            },
+            Event::Alias(_) => {
+                if let Some(frame) = stack.last_mut() {
+                    frame.finish_value(None);
+                }
+            }
+            Event::MappingEnd | Event::SequenceEnd => {
+                let finished = match stack.last_mut() {
+                    Some(Frame::Opaque(depth)) if *depth > 1 => {
+                        *depth -= 1;
+                        continue;
+                    }
+                    Some(Frame::Block { entries, .. }) => {
+                        if let Some(last) = entries.last_mut() {
+                            last.end = end_line(marker);
+                        }
+                        Some(std::mem::take(entries))
+                    }
+                    _ => None,
+                };
+                stack.pop();
+                match stack.last_mut() {
+                    Some(frame) => frame.finish_value(finished),
+                    None => root = finished,
+                }
+            }
+            Event::Nothing | Event::StreamStart | Event::DocumentStart => {}
+        }
+    }
+
+    root.ok_or_else(not_block_mapping)
+}
+
+fn not_block_mapping() -> LinkMLError {
+    LinkMLError::parse("Lossless editing needs a YAML block mapping at the document root")
+}
+
+/// Set the end, comment range, leading and inline comments of entries
+/// whose first key starts after `start`, returning the end of the last
+fn annotate(lines: &[String], entries: &mut [Entry], start: usize, end: usize) -> usize {
+    let mut previous_end = start;
+    for entry in entries.iter_mut() {
+        entry.end = trimmed_end(lines, entry.line, entry.end.min(lines.len()), entry.indent);
+        entry.leading = comment_lines(&lines[previous_end.min(entry.line)..entry.line]);
+        entry.comment_start = entry.line;
+        while entry.comment_start > previous_end
+            && is_comment(&lines[entry.comment_start - 1])
+            && indentation(&lines[entry.comment_start - 1]) == entry.indent
+        {
+            entry.comment_start -= 1;
+        }
+        entry.inline = lines.get(entry.line).and_then(|line| inline_comment(line));
+        if let Some(children) = &mut entry.children {
+            annotate(lines, children, entry.line + 1, entry.end);
+        }
+        previous_end = entry.end;
+    }
+    entries.last().map_or(end, |last| last.end.max(start))
+}
+
+/// End of an entry without the blank and comment lines before the next key
+fn trimmed_end(lines: &[String], line: usize, mut end: usize, indent: usize) -> usize {
+    while end > line + 1 {
+        let text = &lines[end - 1];
+        if text.trim().is_empty() || (is_comment(text) && indentation(text) <= indent) {
+            end -= 1;
+        } else {
+            break;
+        }
+    }
+    end.max(line + 1)
+}
+
+fn is_comment(line: &str) -> bool {
+    line.trim_start().starts_with('#')
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// The comment lines among `lines`, without indentation
+fn comment_lines(lines: &[String]) -> Vec<String> {
+    lines
+        .iter()
+        .filter(|line| is_comment(line))
+        .map(|line| line.trim().to_string())
+        .collect()
+}
+
+/// Comment ending a line, with the whitespace before it
+///
+/// A `#` starts a comment when the text before it still reads as YAML,
+/// which rules out `#` inside quoted strings.
+fn inline_comment(line: &str) -> Option<String> {
+    line.match_indices('#').find_map(|(index, _)| {
+        let before = &line[..index];
+        if !before.ends_with([' ', '\t']) || before.trim().is_empty() {
+            return None;
+        }
+        let code = before.trim_end();
+        serde_yaml::from_str::<Value>(code.trim_start().trim_start_matches("- "))
+            .ok()
+            .map(|_| line[code.len()..].to_string())
+    })
+}
+
+/// Entry of a mapping whose key is written as `key`
+fn find_key<'a>(map: &'a Mapping, key: &str) -> Option<(&'a Value, &'a Value)> {
+    map.iter()
+        .find(|(candidate, _)| key_text(candidate).as_deref() == Some(key))
+}
+
+/// Text of a mapping key as written in YAML
+pub(crate) fn key_text(key: &Value) -> Option<String> {
+    match key {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        _ => None,
+    }
+}
+
+/// Lines of a `key: value` entry at `indent`
+fn render(
+    formatter: &SchemaFormatter,
+    key: &Value,
+    value: &Value,
+    indent: usize,
+    context: Context,
+) -> Result<Vec<String>> {
+    let mut out = String::new();
+    formatter.write_entry(key, value, indent, context, None, &mut out)?;
+    Ok(out.lines().map(str::to_string).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = "\
+# People schema
+id: https://example.org/people
+name: people
+prefixes:
+  linkml: https://w3id.org/linkml/
+classes:
+  # The central class
+  Person:
+    slots: [name]  # flow list kept
+    description: A person   # who
+    x_custom: kept
+  Organization:
+    description: A group
+slots:
+  name:
+    range: string
+  # No longer used
+  age:
+    range: integer
+
+# end of schema
+";
+
+    fn model(content: &str) -> Result<(SchemaDefinition, Value)> {
+        let schema: SchemaDefinition =
+            serde_yaml::from_str(content).map_err(|err| LinkMLError::parse(err.to_string()))?;
+        let value = serde_yaml::to_value(&schema)
+            .map_err(|err| LinkMLError::serialization(err.to_string()))?;
+        Ok((schema, value))
+    }
+
+    #[test]
+    fn test_entries_and_comments() -> Result<()> {
+        let document = LosslessDocument::parse(SCHEMA)?;
+        let keys: Vec<(&str, usize, usize)> = document
+            .entries()
+            .iter()
+            .map(|entry| (entry.key.as_str(), entry.line, entry.end))
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                ("id", 1, 2),
+                ("name", 2, 3),
+                ("prefixes", 3, 5),
+                ("classes", 5, 13),
+                ("slots", 13, 19)
+            ]
+        );
+        assert_eq!(document.entries()[0].leading, ["# People schema"]);
+        assert_eq!(document.trailing_comments(), ["# end of schema"]);
+
+        let classes = document.entries()[3]
+            .children
+            .as_deref()
+            .unwrap_or_default();
+        assert_eq!(classes[0].leading, ["# The central class"]);
+        assert_eq!(classes[0].comment_start, 6);
+        let person = classes[0].children.as_deref().unwrap_or_default();
+        assert_eq!(person[0].inline.as_deref(), Some("  # flow list kept"));
+        assert_eq!(person[1].inline.as_deref(), Some("   # who"));
+        assert!(LosslessDocument::parse("{\"id\": \"x\"}").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_touches_only_changed_lines() -> Result<()> {
+        let (before, _) = model(SCHEMA)?;
+        let mut after = before.clone();
+        if let Some(person) = after.classes.get_mut("Person") {
+            person.description = Some("A human being".to_string());
+        }
+        if let Some(organization) = after.classes.get_mut("Organization") {
+            organization.is_a = Some("Agent".to_string());
+        }
+        after.slots.shift_remove("age");
+        after.version = Some("1.1".to_string());
+
+        let updated = update_schema_text(SCHEMA, &before, &after)?;
+        let expected = "\
+# People schema
+id: https://example.org/people
+name: people
+version: \"1.1\"
+prefixes:
+  linkml: https://w3id.org/linkml/
+classes:
+  # The central class
+  Person:
+    slots: [name]  # flow list kept
+    description: A human being   # who
+    x_custom: kept
+  Organization:
+    description: A group
+    is_a: Agent
+slots:
+  name:
+    range: string
+
+# end of schema
+";
+        assert_eq!(updated, expected);
+
+        // Nothing changed, nothing rewritten
+        assert_eq!(update_schema_text(SCHEMA, &before, &before)?, SCHEMA);
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_rewrites_changed_sequences_and_new_elements() -> Result<()> {
+        let content = "id: s\nname: s\nclasses:\n  Person:\n    slots:\n      - name\n";
+        let (before, _) = model(content)?;
+        let mut after = before.clone();
+        if let Some(person) = after.classes.get_mut("Person") {
+            person.slots.push("age".to_string());
+        }
+        after.slots.insert(
+            "age".to_string(),
+            linkml_core::types::SlotDefinition {
+                name: "age".to_string(),
+                range: Some("integer".to_string()),
+                ..Default::default()
+            },
+        );
+        let updated = update_schema_text(content, &before, &after)?;
+        assert_eq!(
+            updated,
+            "id: s\nname: s\nclasses:\n  Person:\n    slots:\n      - name\n      - age\nslots:\n  age:\n    name: age\n    range: integer\n"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,826 @@
+//! Ontology mapping suggestions for schema elements
+//!
+//! Queries terminology services (OLS, BioPortal) with class and slot names and
+//! aliases, ranks the returned terms lexically against the element, and emits
+//! candidate `skos:exactMatch` / `skos:closeMatch` mappings as an SSSOM table.
+//! The schema itself is never modified: curators review the SSSOM file and
+//! copy accepted rows into `exact_mappings` / `close_mappings`.
+
+use crate::schema_view::search::tokenize;
+use async_trait::async_trait;
+use linkml_core::prelude::*;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+/// Default OLS base URL
+pub const OLS_BASE_URL: &str = "https://www.ebi.ac.uk/ols4";
+
+/// Default BioPortal base URL
+pub const BIOPORTAL_BASE_URL: &str = "https://data.bioontology.org";
+
+/// A term returned by a terminology service
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TermCandidate {
+    /// Compact identifier (e.g. `GO:0008150`), or the IRI if no CURIE is known
+    pub id: String,
+    /// Preferred label
+    pub label: String,
+    /// Full IRI
+    pub iri: Option<String>,
+    /// Source ontology (e.g. `go`, `NCIT`)
+    pub ontology: Option<String>,
+    /// Textual definition
+    pub definition: Option<String>,
+    /// Synonyms of the term
+    pub synonyms: Vec<String>,
+}
+
+/// A service that can look up ontology terms by text
+#[async_trait]
+pub trait TerminologyService: Send + Sync {
+    /// Service name, recorded as the mapping provider
+    fn name(&self) -> &str;
+
+    /// Search for terms matching `query`, returning at most `limit` candidates
+    ///
+    /// # Errors
+    /// Returns an error if the service cannot be reached or answers with an
+    /// unexpected payload
+    async fn search(&self, query: &str, limit: usize) -> Result<Vec<TermCandidate>>;
+}
+
+/// Ontology Lookup Service (OLS4) client
+#[derive(Debug, Clone)]
+pub struct OlsClient {
+    base_url: String,
+    ontologies: Vec<String>,
+    client: Client,
+}
+
+impl OlsClient {
+    /// Create a client for the public EBI OLS instance
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_base_url(OLS_BASE_URL)
+    }
+
+    /// Create a client for a custom OLS instance
+    #[must_use]
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            ontologies: Vec::new(),
+            client: http_client(),
+        }
+    }
+
+    /// Restrict searches to these ontologies (e.g. `go`, `uberon`)
+    #[must_use]
+    pub fn with_ontologies(mut self, ontologies: Vec<String>) -> Self {
+        self.ontologies = ontologies;
+        self
+    }
+}
+
+impl Default for OlsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TerminologyService for OlsClient {
+    fn name(&self) -> &'static str {
+        "OLS"
+    }
+
+    async fn search(&self, query: &str, limit: usize) -> Result<Vec<TermCandidate>> {
+        let mut params = vec![
+            ("q", query.to_string()),
+            ("rows", limit.to_string()),
+            ("type", "class,property".to_string()),
+        ];
+        if !self.ontologies.is_empty() {
+            params.push(("ontology", self.ontologies.join(",")));
+        }
+
+        let body = get_json(
+            &self.client,
+            &format!("{}/api/search", self.base_url),
+            &params,
+        )
+        .await?;
+        Ok(parse_ols_response(&body))
+    }
+}
+
+/// BioPortal client (requires an API key)
+#[derive(Debug, Clone)]
+pub struct BioPortalClient {
+    base_url: String,
+    api_key: String,
+    ontologies: Vec<String>,
+    client: Client,
+}
+
+impl BioPortalClient {
+    /// Create a client for the public BioPortal instance
+    #[must_use]
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self::with_base_url(BIOPORTAL_BASE_URL, api_key)
+    }
+
+    /// Create a client for a custom BioPortal/OntoPortal instance
+    #[must_use]
+    pub fn with_base_url(base_url: impl Into<String>, api_key: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            api_key: api_key.into(),
+            ontologies: Vec::new(),
+            client: http_client(),
+        }
+    }
+
+    /// Restrict searches to these ontology acronyms (e.g. `NCIT`, `SNOMEDCT`)
+    #[must_use]
+    pub fn with_ontologies(mut self, ontologies: Vec<String>) -> Self {
+        self.ontologies = ontologies;
+        self
+    }
+}
+
+#[async_trait]
+impl TerminologyService for BioPortalClient {
+    fn name(&self) -> &'static str {
+        "BioPortal"
+    }
+
+    async fn search(&self, query: &str, limit: usize) -> Result<Vec<TermCandidate>> {
+        let mut params = vec![
+            ("q", query.to_string()),
+            ("pagesize", limit.to_string()),
+            ("apikey", self.api_key.clone()),
+            ("display_links", "true".to_string()),
+            ("display_context", "false".to_string()),
+        ];
+        if !self.ontologies.is_empty() {
+            params.push(("ontologies", self.ontologies.join(",")));
+        }
+
+        let body = get_json(&self.client, &format!("{}/search", self.base_url), &params).await?;
+        Ok(parse_bioportal_response(&body))
+    }
+}
+
+pub(crate) fn http_client() -> Client {
+    Client::builder()
+        .timeout(Duration::from_secs(30))
+        .user_agent(concat!("linkml-service/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .unwrap_or_default()
+}
+
+async fn get_json(client: &Client, url: &str, params: &[(&str, String)]) -> Result<Value> {
+    let response = client
+        .get(url)
+        .query(params)
+        .send()
+        .await
+        .map_err(|e| LinkMLError::service(format!("Request to {url} failed: {e}")))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(LinkMLError::service(format!(
+            "Request to {url} failed with status {status}"
+        )));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| LinkMLError::deserialization(format!("Invalid response from {url}: {e}")))
+}
+
+/// First string of a value that is either a string or an array of strings
+fn first_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Array(items) => items.iter().find_map(|v| v.as_str().map(str::to_string)),
+        _ => None,
+    }
+}
+
+fn string_list(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(s) => vec![s.clone()],
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Parse an OLS `/api/search` response body
+#[must_use]
+pub fn parse_ols_response(body: &Value) -> Vec<TermCandidate> {
+    let Some(docs) = body.pointer("/response/docs").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+
+    docs.iter()
+        .filter_map(|doc| {
+            let label = doc.get("label").and_then(first_string)?;
+            let iri = doc.get("iri").and_then(first_string);
+            let id = doc
+                .get("obo_id")
+                .and_then(first_string)
+                .or_else(|| doc.get("short_form").and_then(first_string))
+                .or_else(|| iri.clone())?;
+            Some(TermCandidate {
+                id,
+                label,
+                iri,
+                ontology: doc.get("ontology_name").and_then(first_string),
+                definition: doc.get("description").and_then(first_string),
+                synonyms: doc.get("synonym").map(string_list).unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+/// Parse a BioPortal `/search` response body
+#[must_use]
+pub fn parse_bioportal_response(body: &Value) -> Vec<TermCandidate> {
+    let Some(collection) = body.get("collection").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+
+    collection
+        .iter()
+        .filter_map(|item| {
+            let iri = item.get("@id").and_then(first_string)?;
+            let label = item.get("prefLabel").and_then(first_string)?;
+            let ontology = item
+                .pointer("/links/ontology")
+                .and_then(Value::as_str)
+                .and_then(|url| url.rsplit('/').next())
+                .map(str::to_string);
+            let id = item.get("notation").and_then(first_string).map_or_else(
+                || iri.clone(),
+                |notation| match &ontology {
+                    Some(acronym) if !notation.contains(':') => {
+                        format!("{acronym}:{notation}")
+                    }
+                    _ => notation,
+                },
+            );
+            Some(TermCandidate {
+                id,
+                label,
+                iri: Some(iri),
+                ontology,
+                definition: item.get("definition").and_then(first_string),
+                synonyms: item.get("synonym").map(string_list).unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+/// Options for mapping suggestion
+#[derive(Debug, Clone)]
+pub struct SuggestOptions {
+    /// Candidates requested per query from each service
+    pub candidates_per_query: usize,
+    /// Maximum suggestions kept per element
+    pub max_suggestions_per_element: usize,
+    /// Minimum score for a `skos:exactMatch` suggestion
+    pub exact_threshold: f64,
+    /// Minimum score for a `skos:closeMatch` suggestion
+    pub close_threshold: f64,
+    /// Suggest mappings for classes
+    pub include_classes: bool,
+    /// Suggest mappings for slots
+    pub include_slots: bool,
+    /// Skip elements that already declare exact or close mappings
+    pub skip_mapped: bool,
+}
+
+impl Default for SuggestOptions {
+    fn default() -> Self {
+        Self {
+            candidates_per_query: 10,
+            max_suggestions_per_element: 3,
+            exact_threshold: 0.95,
+            close_threshold: 0.8,
+            include_classes: true,
+            include_slots: true,
+            skip_mapped: true,
+        }
+    }
+}
+
+/// A candidate mapping awaiting curator review
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MappingSuggestion {
+    /// Schema element CURIE (`prefix:ElementName`)
+    pub subject_id: String,
+    /// Schema element name
+    pub subject_label: String,
+    /// Schema element kind (`owl class` or `owl object property`)
+    pub subject_type: String,
+    /// `skos:exactMatch` or `skos:closeMatch`
+    pub predicate_id: String,
+    /// Suggested term
+    pub object: TermCandidate,
+    /// Lexical confidence in `0.0..=1.0`
+    pub confidence: f64,
+    /// Terminology service that returned the term
+    pub provider: String,
+    /// Query that produced the term
+    pub matched_query: String,
+}
+
+/// Suggests ontology mappings for schema elements
+pub struct MappingSuggester {
+    services: Vec<Arc<dyn TerminologyService>>,
+    options: SuggestOptions,
+}
+
+impl MappingSuggester {
+    /// Create a suggester querying the given services
+    #[must_use]
+    pub fn new(services: Vec<Arc<dyn TerminologyService>>) -> Self {
+        Self {
+            services,
+            options: SuggestOptions::default(),
+        }
+    }
+
+    /// Set suggestion options
+    #[must_use]
+    pub fn with_options(mut self, options: SuggestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Suggest mappings for all classes and slots of a schema
+    ///
+    /// Service failures for individual queries are logged and skipped so one
+    /// unavailable service does not abort the run.
+    ///
+    /// # Errors
+    /// Returns an error if no terminology service is configured
+    pub async fn suggest(&self, schema: &SchemaDefinition) -> Result<Vec<MappingSuggestion>> {
+        if self.services.is_empty() {
+            return Err(LinkMLError::config(
+                "No terminology service configured for mapping suggestions",
+            ));
+        }
+
+        let prefix = schema
+            .default_prefix
+            .clone()
+            .unwrap_or_else(|| schema.name.clone());
+        let mut suggestions = Vec::new();
+
+        for element in self.elements(schema) {
+            let mut element_suggestions = Vec::new();
+            let mut seen = HashSet::new();
+            for query in &element.queries {
+                for service in &self.services {
+                    let candidates = match service
+                        .search(query, self.options.candidates_per_query)
+                        .await
+                    {
+                        Ok(candidates) => candidates,
+                        Err(e) => {
+                            warn!("{} search for '{query}' failed: {e}", service.name());
+                            continue;
+                        }
+                    };
+                    for candidate in candidates {
+                        if !seen.insert(candidate.id.clone()) {
+                            continue;
+                        }
+                        if let Some(suggestion) =
+                            self.rate(&prefix, &element, query, service.name(), candidate)
+                        {
+                            element_suggestions.push(suggestion);
+                        }
+                    }
+                }
+            }
+
+            element_suggestions.sort_by(|a, b| {
+                b.confidence
+                    .total_cmp(&a.confidence)
+                    .then_with(|| a.object.id.cmp(&b.object.id))
+            });
+            element_suggestions.truncate(self.options.max_suggestions_per_element);
+            suggestions.extend(element_suggestions);
+        }
+
+        Ok(suggestions)
+    }
+
+    /// Elements to look up, with their query strings
+    fn elements(&self, schema: &SchemaDefinition) -> Vec<ElementQuery> {
+        let mut elements = Vec::new();
+        if self.options.include_classes {
+            for (name, class) in &schema.classes {
+                if self.options.skip_mapped
+                    && (!class.exact_mappings.is_empty() || !class.close_mappings.is_empty())
+                {
+                    continue;
+                }
+                elements.push(ElementQuery::new(
+                    name,
+                    "owl class",
+                    &class.aliases,
+                    class.description.as_deref(),
+                ));
+            }
+        }
+        if self.options.include_slots {
+            for (name, slot) in &schema.slots {
+                if self.options.skip_mapped
+                    && (!slot.exact_mappings.is_empty() || !slot.close_mappings.is_empty())
+                {
+                    continue;
+                }
+                elements.push(ElementQuery::new(
+                    name,
+                    "owl object property",
+                    &slot.aliases,
+                    slot.description.as_deref(),
+                ));
+            }
+        }
+        elements
+    }
+
+    /// Score a candidate against an element and classify it
+    fn rate(
+        &self,
+        prefix: &str,
+        element: &ElementQuery,
+        query: &str,
+        provider: &str,
+        candidate: TermCandidate,
+    ) -> Option<MappingSuggestion> {
+        let label_score = std::iter::once(&candidate.label)
+            .chain(&candidate.synonyms)
+            .map(|label| lexical_similarity(query, label))
+            .fold(0.0_f64, f64::max);
+
+        // Overlapping definitions nudge near-misses upward, never past exact
+        let definition_bonus = match (&element.description, &candidate.definition) {
+            (Some(description), Some(definition)) => 0.05 * token_overlap(description, definition),
+            _ => 0.0,
+        };
+        let confidence = (label_score + definition_bonus).min(1.0);
+
+        let predicate = if label_score >= self.options.exact_threshold {
+            "skos:exactMatch"
+        } else if confidence >= self.options.close_threshold {
+            "skos:closeMatch"
+        } else {
+            return None;
+        };
+
+        Some(MappingSuggestion {
+            subject_id: format!("{prefix}:{}", element.name),
+            subject_label: element.name.clone(),
+            subject_type: element.kind.to_string(),
+            predicate_id: predicate.to_string(),
+            object: candidate,
+            confidence: (confidence * 1000.0).round() / 1000.0,
+            provider: provider.to_string(),
+            matched_query: query.to_string(),
+        })
+    }
+}
+
+/// An element and the texts it is looked up by
+struct ElementQuery {
+    name: String,
+    kind: &'static str,
+    description: Option<String>,
+    queries: Vec<String>,
+}
+
+impl ElementQuery {
+    fn new(name: &str, kind: &'static str, aliases: &[String], description: Option<&str>) -> Self {
+        let mut queries = vec![tokenize(name).join(" ")];
+        for alias in aliases {
+            let query = tokenize(alias).join(" ");
+            if !query.is_empty() && !queries.contains(&query) {
+                queries.push(query);
+            }
+        }
+        Self {
+            name: name.to_string(),
+            kind,
+            description: description.map(str::to_string),
+            queries,
+        }
+    }
+}
+
+/// Similarity of two labels after tokenizing (case, separators and word order insensitive)
+fn lexical_similarity(a: &str, b: &str) -> f64 {
+    let mut a_tokens = tokenize(a);
+    let mut b_tokens = tokenize(b);
+    if a_tokens.is_empty() || b_tokens.is_empty() {
+        return 0.0;
+    }
+    if a_tokens == b_tokens {
+        return 1.0;
+    }
+    a_tokens.sort();
+    b_tokens.sort();
+    if a_tokens == b_tokens {
+        return 0.97;
+    }
+    strsim::jaro_winkler(&a_tokens.join(" "), &b_tokens.join(" ")) * 0.94
+}
+
+/// Fraction of the tokens in `a` that also occur in `b`
+fn token_overlap(a: &str, b: &str) -> f64 {
+    let a_tokens: HashSet<String> = tokenize(a).into_iter().filter(|t| t.len() > 3).collect();
+    if a_tokens.is_empty() {
+        return 0.0;
+    }
+    let b_tokens: HashSet<String> = tokenize(b).into_iter().collect();
+    #[allow(clippy::cast_precision_loss)]
+    let overlap = a_tokens.intersection(&b_tokens).count() as f64 / a_tokens.len() as f64;
+    overlap
+}
+
+/// Render suggestions as an SSSOM TSV mapping set with a YAML metadata header
+#[must_use]
+pub fn to_sssom_tsv(schema: &SchemaDefinition, suggestions: &[MappingSuggestion]) -> String {
+    let prefix = schema
+        .default_prefix
+        .clone()
+        .unwrap_or_else(|| schema.name.clone());
+    let mut out = String::new();
+
+    // Writing to a String cannot fail
+    let _ = writeln!(out, "# curie_map:");
+    let _ = writeln!(out, "#   skos: http://www.w3.org/2004/02/skos/core#");
+    let _ = writeln!(out, "#   semapv: https://w3id.org/semapv/vocab/");
+    if let Some(expansion) = schema.prefixes.get(&prefix).map(prefix_expansion) {
+        let _ = writeln!(out, "#   {prefix}: {expansion}");
+    }
+    let mapping_set_id = schema.id.trim_end_matches('/');
+    let _ = writeln!(
+        out,
+        "# mapping_set_id: {mapping_set_id}/mapping-suggestions.sssom.tsv"
+    );
+    let _ = writeln!(
+        out,
+        "# mapping_set_description: Candidate mappings for {} pending curator review",
+        schema.name
+    );
+    let _ = writeln!(
+        out,
+        "# license: https://creativecommons.org/publicdomain/zero/1.0/"
+    );
+    let _ = writeln!(
+        out,
+        "# mapping_tool: linkml-service {}",
+        env!("CARGO_PKG_VERSION")
+    );
+
+    out.push_str(
+        "subject_id\tsubject_label\tsubject_type\tpredicate_id\tobject_id\tobject_label\t\
+         object_source\tmapping_justification\tconfidence\tmapping_provider\tcomment\n",
+    );
+    for s in suggestions {
+        let _ = writeln!(
+            out,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\tsemapv:LexicalMatching\t{}\t{}\t{}",
+            tsv(&s.subject_id),
+            tsv(&s.subject_label),
+            tsv(&s.subject_type),
+            s.predicate_id,
+            tsv(&s.object.id),
+            tsv(&s.object.label),
+            tsv(s.object.ontology.as_deref().unwrap_or("")),
+            s.confidence,
+            tsv(&s.provider),
+            tsv(&format!("query: {}", s.matched_query)),
+        );
+    }
+    out
+}
+
+fn prefix_expansion(prefix: &PrefixDefinition) -> String {
+    match prefix {
+        PrefixDefinition::Simple(uri) => uri.clone(),
+        PrefixDefinition::Complex {
+            prefix_reference, ..
+        } => prefix_reference.clone().unwrap_or_default(),
+    }
+}
+
+/// Strip characters that would break a TSV cell
+fn tsv(value: &str) -> String {
+    value.replace(['\t', '\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    /// Terminology service answering from a fixed table
+    struct StaticService {
+        terms: HashMap<String, Vec<TermCandidate>>,
+    }
+
+    #[async_trait]
+    impl TerminologyService for StaticService {
+        fn name(&self) -> &'static str {
+            "static"
+        }
+
+        async fn search(&self, query: &str, limit: usize) -> Result<Vec<TermCandidate>> {
+            let mut terms = self.terms.get(query).cloned().unwrap_or_default();
+            terms.truncate(limit);
+            Ok(terms)
+        }
+    }
+
+    fn term(id: &str, label: &str) -> TermCandidate {
+        TermCandidate {
+            id: id.to_string(),
+            label: label.to_string(),
+            iri: None,
+            ontology: id.split(':').next().map(str::to_lowercase),
+            definition: None,
+            synonyms: Vec::new(),
+        }
+    }
+
+    fn create_test_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("biosample");
+        schema.id = "https://example.org/biosample".to_string();
+        schema.default_prefix = Some("ex".to_string());
+        schema
+            .classes
+            .insert("BiologicalProcess".to_string(), ClassDefinition::default());
+        schema.classes.insert(
+            "Organism".to_string(),
+            ClassDefinition {
+                exact_mappings: vec!["NCBITaxon:1".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "body_weight".to_string(),
+            SlotDefinition {
+                aliases: vec!["weight".to_string()],
+                ..Default::default()
+            },
+        );
+        schema
+    }
+
+    fn create_test_service() -> StaticService {
+        let mut terms = HashMap::new();
+        terms.insert(
+            "biological process".to_string(),
+            vec![
+                term("GO:0008150", "biological_process"),
+                term("GO:0009987", "cellular process"),
+            ],
+        );
+        terms.insert(
+            "body weight".to_string(),
+            vec![term("CMO:0000012", "body weights")],
+        );
+        terms.insert("weight".to_string(), vec![term("PATO:0000128", "weight")]);
+        terms.insert(
+            "organism".to_string(),
+            vec![term("OBI:0100026", "organism")],
+        );
+        StaticService { terms }
+    }
+
+    #[tokio::test]
+    async fn test_suggest_classifies_matches() {
+        let schema = create_test_schema();
+        let suggester = MappingSuggester::new(vec![Arc::new(create_test_service())]);
+        let suggestions = suggester
+            .suggest(&schema)
+            .await
+            .expect("service configured");
+
+        let process: Vec<_> = suggestions
+            .iter()
+            .filter(|s| s.subject_label == "BiologicalProcess")
+            .collect();
+        assert_eq!(process.len(), 1, "unrelated terms are dropped");
+        assert_eq!(process[0].object.id, "GO:0008150");
+        assert_eq!(process[0].predicate_id, "skos:exactMatch");
+        assert_eq!(process[0].subject_id, "ex:BiologicalProcess");
+
+        let weight: Vec<_> = suggestions
+            .iter()
+            .filter(|s| s.subject_label == "body_weight")
+            .collect();
+        assert_eq!(weight.len(), 2, "aliases are queried too");
+        assert_eq!(weight[0].object.id, "PATO:0000128");
+        assert_eq!(weight[0].predicate_id, "skos:exactMatch");
+        assert_eq!(weight[1].predicate_id, "skos:closeMatch");
+
+        assert!(
+            suggestions.iter().all(|s| s.subject_label != "Organism"),
+            "already mapped elements are skipped"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_suggest_requires_service() {
+        let suggester = MappingSuggester::new(Vec::new());
+        assert!(suggester.suggest(&create_test_schema()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sssom_output() {
+        let schema = create_test_schema();
+        let suggester = MappingSuggester::new(vec![Arc::new(create_test_service())]);
+        let suggestions = suggester
+            .suggest(&schema)
+            .await
+            .expect("service configured");
+        let tsv = to_sssom_tsv(&schema, &suggestions);
+
+        assert!(tsv.contains(
+            "# mapping_set_id: https://example.org/biosample/mapping-suggestions.sssom.tsv"
+        ));
+        let header = tsv
+            .lines()
+            .find(|line| !line.starts_with('#'))
+            .expect("header row");
+        assert!(header.starts_with("subject_id\tsubject_label"));
+        assert!(tsv.contains(
+            "ex:BiologicalProcess\tBiologicalProcess\towl class\tskos:exactMatch\tGO:0008150\tbiological_process\tgo\tsemapv:LexicalMatching\t1\tstatic"
+        ));
+    }
+
+    #[test]
+    fn test_parse_ols_response() {
+        let body = json!({
+            "response": { "docs": [
+                {
+                    "iri": "http://purl.obolibrary.org/obo/GO_0008150",
+                    "obo_id": "GO:0008150",
+                    "label": "biological_process",
+                    "ontology_name": "go",
+                    "description": ["A biological process is..."],
+                    "synonym": ["physiological process"]
+                },
+                { "iri": "http://example.org/no-label" }
+            ]}
+        });
+        let terms = parse_ols_response(&body);
+        assert_eq!(terms.len(), 1);
+        assert_eq!(terms[0].id, "GO:0008150");
+        assert_eq!(terms[0].ontology.as_deref(), Some("go"));
+        assert_eq!(terms[0].synonyms, vec!["physiological process".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_bioportal_response() {
+        let body = json!({
+            "collection": [{
+                "@id": "http://purl.bioontology.org/ontology/NCIT/C12434",
+                "prefLabel": "Blood",
+                "notation": "C12434",
+                "definition": ["A liquid tissue..."],
+                "links": { "ontology": "https://data.bioontology.org/ontologies/NCIT" }
+            }]
+        });
+        let terms = parse_bioportal_response(&body);
+        assert_eq!(terms.len(), 1);
+        assert_eq!(terms[0].id, "NCIT:C12434");
+        assert_eq!(terms[0].ontology.as_deref(), Some("NCIT"));
+        assert_eq!(terms[0].definition.as_deref(), Some("A liquid tissue..."));
+    }
+
+    #[test]
+    fn test_lexical_similarity() {
+        assert!((lexical_similarity("body weight", "Body_Weight") - 1.0).abs() < f64::EPSILON);
+        assert!(lexical_similarity("weight body", "body weight") > 0.95);
+        assert!(lexical_similarity("organism", "cellular process") < 0.7);
+    }
+}
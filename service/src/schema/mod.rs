@@ -1,14 +1,39 @@
 //! Schema manipulation and analysis tools
 //!
 //! This module provides utilities for working with LinkML schemas,
-//! including diff, merge, patch, and lint functionality.
+//! including programmatic construction, diff, merge, patch, profile, lint, format,
+//! lossless editing, quality scoring, mapping suggestion and attribute group expansion
+//! functionality.
 
+pub mod attribute_groups;
+pub mod builder;
 pub mod diff;
+pub mod format;
 pub mod lint;
+pub mod lossless;
+pub mod mapping_suggest;
 pub mod merge;
 pub mod patch;
+pub mod profile;
+pub mod quality;
 
+pub use attribute_groups::{UnresolvedGroup, expand_attribute_groups, resolve_attribute_groups};
+pub use builder::{BuilderResult, ClassBuilder, EnumBuilder, SchemaBuilder, SlotBuilder};
 pub use diff::{DiffOptions, DiffResult, SchemaDiff};
-pub use lint::{LintOptions, LintResult, LintRule, SchemaLinter, Severity};
+pub use format::{FormatOptions, FormatOutcome, SchemaFormatter, format_schema_str};
+pub use lint::{
+    LintConfig, LintOptions, LintResult, LintRule, LintRuleRegistry, RuleLevel, RuleSettings,
+    SchemaLinter, Severity,
+};
+pub use lossless::{LosslessDocument, update_schema_text};
+pub use mapping_suggest::{
+    BioPortalClient, MappingSuggester, MappingSuggestion, OlsClient, SuggestOptions, TermCandidate,
+    TerminologyService,
+};
 pub use merge::{MergeOptions, MergeResult, SchemaMerge};
-pub use patch::{PatchOptions, PatchResult, SchemaPatch, SchemaPatcher, create_patch_from_diff};
+pub use patch::{
+    PatchConflict, PatchOperation, PatchOptions, PatchResult, SchemaPatch, SchemaPatcher,
+    create_patch, create_patch_from_diff,
+};
+pub use profile::{ProfileChecker, ProfileReport, ProfileViolation, ProfileViolationKind};
+pub use quality::{QualityMetric, QualityReport, QualityScorer, QualityWeights};
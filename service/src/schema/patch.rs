@@ -2,12 +2,32 @@
 //!
 //! This module provides tools to apply patches to schemas, enabling
 //! controlled schema evolution and migration.
+//!
+//! A [`SchemaPatch`] is a list of JSON Patch (RFC 6902) operations on the
+//! YAML or `JSON` form of a schema, e.g. `/classes/Person/description`, so
+//! any schema field can be patched. As a `LinkML` delta extension, a path
+//! token that is neither an index nor `-` addresses the entry of a list
+//! equal to it: `/classes/Person/slots/age` is the `age` slot of `Person`
+//! wherever it is listed, and adding it appends it.
+//!
+//! Each operation is checked by reading the schema back, so an operation
+//! that would leave an invalid schema fails on its own.
+//! [`SchemaPatcher::check_patch`] reports everything that keeps a patch
+//! from applying cleanly, [`SchemaPatcher::apply_atomic`] applies all of a
+//! patch or nothing, and [`create_patch`] computes the patch turning one
+//! schema into another.
+
+use std::fmt::{self, Write};
 
 use linkml_core::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use serde_yaml::{Mapping, Value as Document};
 
-use super::diff::DiffResult;
+use super::builder::schema_problems;
+use super::diff::{DiffOptions, DiffResult, SchemaDiff};
+use super::lossless::update_schema_text;
+use crate::parser::recovery::ELEMENT_SECTIONS;
 
 /// A patch operation to apply to a schema
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,24 +82,68 @@ pub enum PatchOperation {
 }
 
 /// A collection of patch operations
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SchemaPatch {
     /// Patch operations to apply
     pub operations: Vec<PatchOperation>,
 
     /// Optional description of the patch
+    #[serde(default)]
     pub description: Option<String>,
 
     /// Version this patch migrates from
+    #[serde(default)]
     pub from_version: Option<String>,
 
     /// Version this patch migrates to
+    #[serde(default)]
     pub to_version: Option<String>,
 
     /// Whether this is a breaking change
+    #[serde(default)]
     pub breaking: bool,
 }
 
+impl SchemaPatch {
+    /// Create a non-breaking patch of the given operations
+    #[must_use]
+    pub fn new(operations: Vec<PatchOperation>) -> Self {
+        Self {
+            operations,
+            ..Self::default()
+        }
+    }
+
+    /// Read a patch document from YAML or `JSON`
+    ///
+    /// Both a patch with its `operations` and metadata and a plain JSON
+    /// Patch array of operations are accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not a patch document.
+    pub fn parse(content: &str) -> Result<Self> {
+        let document: Document = serde_yaml::from_str(content)
+            .map_err(|e| LinkMLError::parse(format!("Invalid patch document: {e}")))?;
+        let patch = if document.is_sequence() {
+            serde_yaml::from_value(document).map(Self::new)
+        } else {
+            serde_yaml::from_value(document)
+        };
+        patch.map_err(|e| LinkMLError::parse(format!("Invalid patch document: {e}")))
+    }
+
+    /// The operations as a JSON Patch document
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the operations cannot be serialized.
+    pub fn to_json_patch(&self) -> Result<String> {
+        serde_json::to_string_pretty(&self.operations)
+            .map_err(|e| LinkMLError::serialization(e.to_string()))
+    }
+}
+
 /// Options for applying patches
 #[derive(Debug, Clone)]
 pub struct PatchOptions {
@@ -123,6 +187,26 @@ pub struct PatchResult {
     pub warnings: Vec<String>,
 }
 
+/// Something that keeps a patch from applying cleanly to a schema
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatchConflict {
+    /// Index of the operation that cannot be applied, or `None` for
+    /// conflicts of the patch as a whole and problems of the patched schema
+    pub operation: Option<usize>,
+
+    /// Why the patch does not apply
+    pub reason: String,
+}
+
+impl fmt::Display for PatchConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.operation {
+            Some(index) => write!(f, "operation {index}: {}", self.reason),
+            None => f.write_str(&self.reason),
+        }
+    }
+}
+
 /// Schema patcher for applying patches to schemas
 pub struct SchemaPatcher {
     options: PatchOptions,
@@ -136,13 +220,16 @@ impl SchemaPatcher {
     }
 
     /// Apply a patch to a schema
-    /// Returns an error if the operation fails
+    ///
+    /// Operations that fail are skipped, or end patching in strict mode.
     ///
     /// # Errors
     ///
+    /// Returns an error if the patch is breaking and breaking changes are
+    /// not allowed, or in strict mode if an operation fails.
     pub fn apply_patch(
         &self,
-        mut schema: SchemaDefinition,
+        schema: SchemaDefinition,
         patch: &SchemaPatch,
     ) -> Result<PatchResult> {
         // Check if breaking changes are allowed
@@ -152,8 +239,9 @@ impl SchemaPatcher {
             ));
         }
 
+        let mut document = to_document(&schema).map_err(LinkMLError::serialization)?;
         let mut result = PatchResult {
-            schema: schema.clone(),
+            schema,
             applied_operations: Vec::new(),
             skipped_operations: Vec::new(),
             warnings: Vec::new(),
@@ -161,8 +249,8 @@ impl SchemaPatcher {
 
         // Apply each operation
         for operation in &patch.operations {
-            match self.apply_operation(&mut schema, operation) {
-                Ok(()) => {
+            match apply_operation(&mut document, operation) {
+                Ok(schema) => {
                     result.applied_operations.push(operation.clone());
 
                     // Validate if requested
@@ -176,8 +264,10 @@ impl SchemaPatcher {
                             return Err(e);
                         }
                     }
+                    result.schema = schema;
                 }
-                Err(e) => {
+                Err(reason) => {
+                    let e = LinkMLError::service(reason);
                     if self.options.strict {
                         return Err(e);
                     }
@@ -188,239 +278,460 @@ impl SchemaPatcher {
             }
         }
 
-        result.schema = schema;
         Ok(result)
     }
 
-    /// Apply a single patch operation
-    fn apply_operation(
+    /// Check whether a patch applies cleanly to a schema
+    ///
+    /// Every operation is tried in turn, as by
+    /// [`apply_patch`](Self::apply_patch), and those that fail are reported
+    /// along with a breaking patch that is not allowed and the problems the
+    /// patch introduces into the schema, such as a slot whose range class
+    /// was removed. A patch without conflicts can be applied with
+    /// [`apply_atomic`](Self::apply_atomic).
+    #[must_use]
+    pub fn check_patch(
         &self,
-        schema: &mut SchemaDefinition,
-        operation: &PatchOperation,
-    ) -> Result<()> {
-        match operation {
-            PatchOperation::Add { path, value } => self.apply_add(schema, path, value),
-            PatchOperation::Remove { path } => self.apply_remove(schema, path),
-            PatchOperation::Replace { path, value } => self.apply_replace(schema, path, value),
-            PatchOperation::Move { from, path } => self.apply_move(schema, from, path),
-            PatchOperation::Copy { from, path } => self.apply_copy(schema, from, path),
-            PatchOperation::Test { path, value } => self.apply_test(schema, path, value),
-        }
+        schema: &SchemaDefinition,
+        patch: &SchemaPatch,
+    ) -> Vec<PatchConflict> {
+        self.dry_run(schema, patch).0
     }
 
-    /// Apply an add operation
-    fn apply_add(&self, schema: &mut SchemaDefinition, path: &str, value: &Value) -> Result<()> {
-        let parts: Vec<&str> = path.trim_start_matches('/').split('/').collect();
-
-        match parts.as_slice() {
-            ["classes", class_name] => {
-                let class_def: ClassDefinition = serde_json::from_value(value.clone())
-                    .map_err(|e| LinkMLError::parse(format!("Invalid class definition: {e}")))?;
-                schema.classes.insert((*class_name).to_string(), class_def);
-                Ok(())
+    /// Apply a patch to a schema completely or not at all
+    ///
+    /// The schema is only changed if [`check_patch`](Self::check_patch)
+    /// finds no conflicts.
+    ///
+    /// # Errors
+    ///
+    /// Returns a schema validation error listing the conflicts if the patch
+    /// does not apply cleanly.
+    pub fn apply_atomic(&self, schema: &mut SchemaDefinition, patch: &SchemaPatch) -> Result<()> {
+        let (conflicts, patched) = self.dry_run(schema, patch);
+        let message = match conflicts.as_slice() {
+            [] => {
+                *schema = patched;
+                return Ok(());
             }
-            ["classes", class_name, "slots", slot_name] => {
-                if let Some(class) = schema.classes.get_mut(*class_name) {
-                    class.slots.push((*slot_name).to_string());
-                    Ok(())
-                } else {
-                    Err(LinkMLError::service(format!(
-                        "Class '{class_name}' not found"
-                    )))
+            [conflict] => format!("Patch cannot be applied: {conflict}"),
+            conflicts => {
+                let mut message = format!("Patch cannot be applied: {} conflicts", conflicts.len());
+                for conflict in conflicts {
+                    let _ = write!(message, "\n  {conflict}");
                 }
+                message
             }
-            ["slots", slot_name] => {
-                let slot_def: SlotDefinition = serde_json::from_value(value.clone())
-                    .map_err(|e| LinkMLError::parse(format!("Invalid slot definition: {e}")))?;
-                schema.slots.insert((*slot_name).to_string(), slot_def);
-                Ok(())
-            }
-            ["types", type_name] => {
-                let type_def: TypeDefinition = serde_json::from_value(value.clone())
-                    .map_err(|e| LinkMLError::parse(format!("Invalid type definition: {e}")))?;
-                schema.types.insert((*type_name).to_string(), type_def);
-                Ok(())
+        };
+        Err(LinkMLError::schema_validation(message))
+    }
+
+    /// Apply a patch to the text of a YAML or JSON schema
+    ///
+    /// YAML schemas are edited in place, so only the lines of the elements
+    /// the patch changes are rewritten and comments and key order are kept.
+    /// JSON schemas are written out again in full.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema cannot be parsed or the patch cannot
+    /// be applied.
+    pub fn apply_patch_to_str(
+        &self,
+        content: &str,
+        patch: &SchemaPatch,
+    ) -> Result<(PatchResult, String)> {
+        let schema: SchemaDefinition = serde_yaml::from_str(content)
+            .map_err(|e| LinkMLError::parse(format!("Invalid schema document: {e}")))?;
+        let result = self.apply_patch(schema.clone(), patch)?;
+        let text = if content.trim_start().starts_with('{') {
+            serde_json::to_string_pretty(&result.schema)
+                .map_err(|e| LinkMLError::serialization(e.to_string()))?
+        } else {
+            update_schema_text(content, &schema, &result.schema)?
+        };
+        Ok((result, text))
+    }
+
+    /// Try every operation of a patch, returning the conflicts and the
+    /// schema with the operations that apply
+    fn dry_run(
+        &self,
+        schema: &SchemaDefinition,
+        patch: &SchemaPatch,
+    ) -> (Vec<PatchConflict>, SchemaDefinition) {
+        let mut conflicts = Vec::new();
+        if patch.breaking && !self.options.allow_breaking {
+            conflicts.push(PatchConflict {
+                operation: None,
+                reason: "patch contains breaking changes but breaking changes are not allowed"
+                    .to_string(),
+            });
+        }
+        let mut document = match to_document(schema) {
+            Ok(document) => document,
+            Err(reason) => {
+                conflicts.push(PatchConflict {
+                    operation: None,
+                    reason,
+                });
+                return (conflicts, schema.clone());
             }
-            ["enums", enum_name] => {
-                let enum_def: EnumDefinition = serde_json::from_value(value.clone())
-                    .map_err(|e| LinkMLError::parse(format!("Invalid enum definition: {e}")))?;
-                schema.enums.insert((*enum_name).to_string(), enum_def);
-                Ok(())
+        };
+
+        let mut patched = schema.clone();
+        for (index, operation) in patch.operations.iter().enumerate() {
+            match apply_operation(&mut document, operation) {
+                Ok(schema) => patched = schema,
+                Err(reason) => conflicts.push(PatchConflict {
+                    operation: Some(index),
+                    reason,
+                }),
             }
-            _ => Err(LinkMLError::service(format!(
-                "Unsupported path for add: {path}"
-            ))),
         }
+
+        // Only problems the patch introduces count against it
+        let existing = schema_problems(schema);
+        conflicts.extend(
+            schema_problems(&patched)
+                .into_iter()
+                .filter(|problem| !existing.contains(problem))
+                .map(|reason| PatchConflict {
+                    operation: None,
+                    reason,
+                }),
+        );
+        (conflicts, patched)
     }
 
-    /// Apply a remove operation
-    fn apply_remove(&self, schema: &mut SchemaDefinition, path: &str) -> Result<()> {
-        let parts: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    /// Validate a schema
+    fn validate_schema(&self, schema: &SchemaDefinition) -> Result<()> {
+        // Basic validation - could be expanded
+        if schema.name.is_empty() {
+            return Err(LinkMLError::schema_validation("Schema name is empty"));
+        }
+        Ok(())
+    }
+}
 
-        match parts.as_slice() {
-            ["classes", class_name] => {
-                schema.classes.shift_remove(*class_name).ok_or_else(|| {
-                    LinkMLError::service(format!("Class '{class_name}' not found"))
-                })?;
-                Ok(())
-            }
-            ["classes", class_name, "slots", slot_name] => {
-                if let Some(class) = schema.classes.get_mut(*class_name) {
-                    class.slots.retain(|s| s != slot_name);
-                    Ok(())
-                } else {
-                    Err(LinkMLError::service(format!(
-                        "Class '{class_name}' not found"
-                    )))
-                }
-            }
-            ["slots", slot_name] => {
-                schema
-                    .slots
-                    .shift_remove(*slot_name)
-                    .ok_or_else(|| LinkMLError::service(format!("Slot '{slot_name}' not found")))?;
-                Ok(())
-            }
-            ["types", type_name] => {
-                schema
-                    .types
-                    .shift_remove(*type_name)
-                    .ok_or_else(|| LinkMLError::service(format!("Type '{type_name}' not found")))?;
-                Ok(())
-            }
-            ["enums", enum_name] => {
-                schema
-                    .enums
-                    .shift_remove(*enum_name)
-                    .ok_or_else(|| LinkMLError::service(format!("Enum '{enum_name}' not found")))?;
-                Ok(())
-            }
-            _ => Err(LinkMLError::service(format!(
-                "Unsupported path for remove: {path}"
-            ))),
+/// The schema as a patchable document, with every element section present
+/// so elements can be added to sections the schema leaves out
+fn to_document(schema: &SchemaDefinition) -> std::result::Result<Document, String> {
+    let mut document = serde_yaml::to_value(schema).map_err(|e| e.to_string())?;
+    if let Document::Mapping(fields) = &mut document {
+        for section in ELEMENT_SECTIONS {
+            fields
+                .entry(Document::from(*section))
+                .or_insert_with(|| Document::Mapping(Mapping::new()));
         }
     }
+    Ok(document)
+}
 
-    /// Apply a replace operation
-    fn apply_replace(
-        &self,
-        schema: &mut SchemaDefinition,
-        path: &str,
-        value: &Value,
-    ) -> Result<()> {
-        let parts: Vec<&str> = path.trim_start_matches('/').split('/').collect();
-
-        match parts.as_slice() {
-            ["classes", class_name] => {
-                let class_def: ClassDefinition = serde_json::from_value(value.clone())
-                    .map_err(|e| LinkMLError::parse(format!("Invalid class definition: {e}")))?;
-                schema.classes.insert((*class_name).to_string(), class_def);
-                Ok(())
+/// Apply an operation to a schema document, returning the schema it then
+/// describes
+///
+/// The document is only changed if the operation applies and leaves a valid
+/// schema.
+fn apply_operation(
+    document: &mut Document,
+    operation: &PatchOperation,
+) -> std::result::Result<SchemaDefinition, String> {
+    let mut patched = document.clone();
+    let written = match operation {
+        PatchOperation::Add { path, value } => {
+            let value = to_yaml(value)?;
+            add(&mut patched, &tokens(path)?, value.clone())?;
+            Some((path, value))
+        }
+        PatchOperation::Remove { path } => {
+            remove(&mut patched, &tokens(path)?)?;
+            None
+        }
+        PatchOperation::Replace { path, value } => {
+            let value = to_yaml(value)?;
+            let target = get_mut(&mut patched, &tokens(path)?)
+                .ok_or_else(|| format!("'{path}' does not exist"))?;
+            *target = value.clone();
+            Some((path, value))
+        }
+        PatchOperation::Move { from, path } => {
+            if path.starts_with(&format!("{from}/")) {
+                return Err(format!("cannot move '{from}' into itself"));
             }
-            ["classes", class_name, "description"] => {
-                if let Some(class) = schema.classes.get_mut(*class_name) {
-                    class.description = value.as_str().map(std::string::ToString::to_string);
-                    Ok(())
-                } else {
-                    Err(LinkMLError::service(format!(
-                        "Class '{class_name}' not found"
-                    )))
-                }
+            let value = remove(&mut patched, &tokens(from)?)?;
+            add(&mut patched, &tokens(path)?, value.clone())?;
+            Some((path, value))
+        }
+        PatchOperation::Copy { from, path } => {
+            let value = get(&patched, &tokens(from)?)
+                .cloned()
+                .ok_or_else(|| format!("'{from}' does not exist"))?;
+            add(&mut patched, &tokens(path)?, value.clone())?;
+            Some((path, value))
+        }
+        PatchOperation::Test { path, value } => {
+            let actual =
+                get(&patched, &tokens(path)?).ok_or_else(|| format!("'{path}' does not exist"))?;
+            let actual = serde_json::to_value(actual).map_err(|e| e.to_string())?;
+            if actual != *value {
+                return Err(format!(
+                    "test failed at '{path}': expected {value}, found {actual}"
+                ));
             }
-            ["slots", slot_name, "required"] => {
-                if let Some(slot) = schema.slots.get_mut(*slot_name) {
-                    slot.required = value.as_bool();
-                    Ok(())
-                } else {
-                    Err(LinkMLError::service(format!(
-                        "Slot '{slot_name}' not found"
-                    )))
+            None
+        }
+    };
+
+    let schema: SchemaDefinition = serde_path_to_error::deserialize(patched.clone())
+        .map_err(|e| format!("patched schema is invalid at {}: {}", e.path(), e.inner()))?;
+
+    // Keys the schema model does not know are dropped when it is read
+    if let Some((path, value)) = written
+        && !is_empty(&value)
+    {
+        let read_back = serde_yaml::to_value(&schema).map_err(|e| e.to_string())?;
+        if get(&read_back, &tokens(path)?).is_none() {
+            return Err(format!("'{path}' is not a schema field"));
+        }
+    }
+
+    *document = patched;
+    Ok(schema)
+}
+
+/// Add a value at a path
+///
+/// Adding to a list field the schema leaves out, by appending with `-`,
+/// `0` or the value itself as the last token, creates the list.
+fn add(
+    document: &mut Document,
+    tokens: &[String],
+    value: Document,
+) -> std::result::Result<(), String> {
+    let Some((last, parents)) = tokens.split_last() else {
+        *document = value;
+        return Ok(());
+    };
+    let appends = last == "-" || last == "0" || value.as_str() == Some(last.as_str());
+    if appends
+        && let Some((list, owner)) = parents.split_last()
+        && let Some(Document::Mapping(fields)) = get_mut(document, owner)
+        && !fields.contains_key(list.as_str())
+    {
+        fields.insert(
+            Document::from(list.as_str()),
+            Document::Sequence(Vec::new()),
+        );
+    }
+
+    match get_mut(document, parents) {
+        Some(Document::Mapping(fields)) => {
+            fields.insert(Document::from(last.as_str()), value);
+            Ok(())
+        }
+        Some(Document::Sequence(items)) => {
+            if last == "-" {
+                items.push(value);
+            } else if is_index(last) {
+                match last.parse::<usize>() {
+                    Ok(index) if index <= items.len() => items.insert(index, value),
+                    _ => return Err(format!("'{}' is out of range", pointer(tokens))),
                 }
+            } else if items
+                .iter()
+                .any(|item| item.as_str() == Some(last.as_str()))
+            {
+                return Err(format!(
+                    "'{last}' is already listed at '{}'",
+                    pointer(parents)
+                ));
+            } else {
+                items.push(value);
             }
-            _ => Err(LinkMLError::service(format!(
-                "Unsupported path for replace: {path}"
-            ))),
+            Ok(())
         }
+        Some(_) => Err(format!("'{}' is not a mapping or list", pointer(parents))),
+        None => Err(format!("'{}' does not exist", pointer(parents))),
     }
+}
 
-    /// Apply a move operation
-    fn apply_move(&self, schema: &mut SchemaDefinition, from: &str, to: &str) -> Result<()> {
-        // Extract value from source
-        let value = self.extract_value(schema, from)?;
+/// Remove the value at a path, returning it
+fn remove(document: &mut Document, tokens: &[String]) -> std::result::Result<Document, String> {
+    let Some((last, parents)) = tokens.split_last() else {
+        return Err("the whole schema cannot be removed".to_string());
+    };
+    let missing = || format!("'{}' does not exist", pointer(tokens));
+    match get_mut(document, parents) {
+        Some(Document::Mapping(fields)) => fields.shift_remove(last.as_str()).ok_or_else(missing),
+        Some(Document::Sequence(items)) => {
+            let index = position(items, last).ok_or_else(missing)?;
+            Ok(items.remove(index))
+        }
+        _ => Err(missing()),
+    }
+}
 
-        // Remove from source
-        self.apply_remove(schema, from)?;
+/// The value at a path
+fn get<'a>(document: &'a Document, tokens: &[String]) -> Option<&'a Document> {
+    tokens.iter().try_fold(document, |node, token| match node {
+        Document::Mapping(fields) => fields.get(token.as_str()),
+        Document::Sequence(items) => items.get(position(items, token)?),
+        _ => None,
+    })
+}
 
-        // Add to destination
-        self.apply_add(schema, to, &value)?;
+/// The value at a path, for editing
+fn get_mut<'a>(document: &'a mut Document, tokens: &[String]) -> Option<&'a mut Document> {
+    tokens.iter().try_fold(document, |node, token| match node {
+        Document::Mapping(fields) => fields.get_mut(token.as_str()),
+        Document::Sequence(items) => {
+            let index = position(items, token)?;
+            items.get_mut(index)
+        }
+        _ => None,
+    })
+}
 
-        Ok(())
+/// Position in a list addressed by a path token: an index, or the entry
+/// equal to the token
+fn position(items: &[Document], token: &str) -> Option<usize> {
+    if is_index(token) {
+        return token.parse().ok().filter(|index| *index < items.len());
     }
+    items.iter().position(|item| item.as_str() == Some(token))
+}
 
-    /// Apply a copy operation
-    fn apply_copy(&self, schema: &mut SchemaDefinition, from: &str, to: &str) -> Result<()> {
-        // Extract value from source
-        let value = self.extract_value(schema, from)?;
-
-        // Add to destination (without removing source)
-        self.apply_add(schema, to, &value)?;
+/// Whether a path token is a list index: digits without leading zeros
+fn is_index(token: &str) -> bool {
+    !token.is_empty()
+        && token.bytes().all(|byte| byte.is_ascii_digit())
+        && (token == "0" || !token.starts_with('0'))
+}
 
-        Ok(())
+/// The reference tokens of a JSON Pointer
+fn tokens(path: &str) -> std::result::Result<Vec<String>, String> {
+    if path.is_empty() {
+        return Ok(Vec::new());
     }
+    let pointer = path
+        .strip_prefix('/')
+        .ok_or_else(|| format!("'{path}' is not a JSON Pointer"))?;
+    Ok(pointer
+        .split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
 
-    /// Apply a test operation
-    fn apply_test(&self, schema: &SchemaDefinition, path: &str, expected: &Value) -> Result<()> {
-        let actual = self.extract_value(schema, path)?;
+/// The JSON Pointer of reference tokens
+fn pointer(tokens: &[String]) -> String {
+    let mut pointer = String::new();
+    for token in tokens {
+        pointer.push('/');
+        pointer.push_str(&token.replace('~', "~0").replace('/', "~1"));
+    }
+    pointer
+}
 
-        if actual == *expected {
-            Ok(())
-        } else {
-            Err(LinkMLError::service(format!(
-                "Test failed at path '{path}': expected {expected:?}, got {actual:?}"
-            )))
-        }
+/// Whether a value is left out when the schema is written
+fn is_empty(value: &Document) -> bool {
+    match value {
+        Document::Null => true,
+        Document::Sequence(items) => items.is_empty(),
+        Document::Mapping(fields) => fields.is_empty(),
+        _ => false,
     }
+}
 
-    /// Extract a value from the schema at the given path
-    fn extract_value(&self, schema: &SchemaDefinition, path: &str) -> Result<Value> {
-        let parts: Vec<&str> = path.trim_start_matches('/').split('/').collect();
-
-        match parts.as_slice() {
-            ["classes", class_name] => schema
-                .classes
-                .get(*class_name)
-                .ok_or_else(|| LinkMLError::service(format!("Class '{class_name}' not found")))
-                .and_then(|c| {
-                    serde_json::to_value(c).map_err(|e| {
-                        LinkMLError::service(format!(
-                            "Failed to serialize class '{class_name}': {e}"
-                        ))
-                    })
-                }),
-            ["slots", slot_name] => schema
-                .slots
-                .get(*slot_name)
-                .ok_or_else(|| LinkMLError::service(format!("Slot '{slot_name}' not found")))
-                .and_then(|s| {
-                    serde_json::to_value(s).map_err(|e| {
-                        LinkMLError::service(format!("Failed to serialize slot '{slot_name}': {e}"))
-                    })
-                }),
-            _ => Err(LinkMLError::service(format!("Unsupported path: {path}"))),
-        }
+fn to_yaml(value: &Value) -> std::result::Result<Document, String> {
+    serde_yaml::to_value(value).map_err(|e| e.to_string())
+}
+
+fn to_json(value: &Document) -> std::result::Result<Value, String> {
+    serde_json::to_value(value).map_err(|e| e.to_string())
+}
+
+/// Create the patch that turns one schema into another
+///
+/// Unlike [`create_patch_from_diff`], the patch carries complete values, so
+/// applying it to `before` gives `after`. Changed mappings are patched key
+/// by key, while changed lists and scalars are replaced as a whole. The
+/// patch is breaking if [`SchemaDiff`] finds breaking changes.
+///
+/// # Errors
+///
+/// Returns an error if either schema cannot be serialized or compared.
+pub fn create_patch(before: &SchemaDefinition, after: &SchemaDefinition) -> Result<SchemaPatch> {
+    let document = |schema| to_document(schema).map_err(LinkMLError::serialization);
+    let mut operations = Vec::new();
+    diff_documents(
+        &mut Vec::new(),
+        &document(before)?,
+        &document(after)?,
+        &mut operations,
+    )
+    .map_err(LinkMLError::serialization)?;
+    let diff = SchemaDiff::new(DiffOptions::default()).diff(before, after)?;
+
+    Ok(SchemaPatch {
+        operations,
+        description: Some(format!("Changes to schema '{}'", after.name)),
+        from_version: before.version.clone(),
+        to_version: after.version.clone(),
+        breaking: !diff.breaking_changes.is_empty(),
+    })
+}
+
+/// Append the operations turning `before` into `after`, both at `path`
+fn diff_documents(
+    path: &mut Vec<String>,
+    before: &Document,
+    after: &Document,
+    operations: &mut Vec<PatchOperation>,
+) -> std::result::Result<(), String> {
+    if before == after {
+        return Ok(());
     }
+    let (Document::Mapping(old), Document::Mapping(new)) = (before, after) else {
+        operations.push(PatchOperation::Replace {
+            path: pointer(path),
+            value: to_json(after)?,
+        });
+        return Ok(());
+    };
 
-    /// Validate a schema
-    fn validate_schema(&self, schema: &SchemaDefinition) -> Result<()> {
-        // Basic validation - could be expanded
-        if schema.name.is_empty() {
-            return Err(LinkMLError::schema_validation("Schema name is empty"));
+    for key in old.keys().filter(|key| !new.contains_key(*key)) {
+        path.push(key_token(key)?);
+        operations.push(PatchOperation::Remove {
+            path: pointer(path),
+        });
+        path.pop();
+    }
+    for (key, value) in new {
+        path.push(key_token(key)?);
+        match old.get(key) {
+            Some(previous) => diff_documents(path, previous, value, operations)?,
+            None => operations.push(PatchOperation::Add {
+                path: pointer(path),
+                value: to_json(value)?,
+            }),
         }
-        Ok(())
+        path.pop();
     }
+    Ok(())
+}
+
+fn key_token(key: &Document) -> std::result::Result<String, String> {
+    key.as_str()
+        .map(str::to_string)
+        .ok_or_else(|| format!("unsupported schema key {key:?}"))
 }
 
 /// Create a patch from a diff result
+///
+/// The diff only names the changed elements, so added classes and slots are
+/// added empty; use [`create_patch`] for a patch that reproduces the new
+/// schema.
 #[must_use]
 pub fn create_patch_from_diff(diff: &DiffResult) -> SchemaPatch {
     let mut operations = Vec::new();
@@ -565,4 +876,205 @@ mod tests {
             .expect("Should apply patch");
         assert!(!result.schema.slots.contains_key("old_slot"));
     }
+
+    const PEOPLE: &str = "\
+id: https://example.org/people
+name: people
+version: 1.0.0
+classes:
+  Person:
+    description: A person
+    slots:
+      - name
+      - nickname
+slots:
+  name:
+    range: string
+  nickname:
+    range: string
+";
+
+    fn people() -> SchemaDefinition {
+        serde_yaml::from_str(PEOPLE).expect("Should parse schema")
+    }
+
+    #[test]
+    fn test_check_patch_and_apply_atomic() {
+        let patch = SchemaPatch::parse(
+            "\
+- op: add
+  path: /classes/Person/slots/age
+  value: age
+- op: replace
+  path: /classes/Robot/description
+  value: A machine
+- op: add
+  path: /classes/Person/colour
+  value: red
+- op: test
+  path: /slots/name/range
+  value: string
+",
+        )
+        .expect("Should parse patch");
+        assert_eq!(patch.operations.len(), 4);
+
+        let mut schema = people();
+        let patcher = SchemaPatcher::new(PatchOptions::default());
+        let conflicts = patcher.check_patch(&schema, &patch);
+        assert_eq!(
+            conflicts
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "operation 1: '/classes/Robot/description' does not exist",
+                "operation 2: '/classes/Person/colour' is not a schema field",
+                "class 'Person' uses unknown slot 'age'",
+            ]
+        );
+
+        let error = patcher
+            .apply_atomic(&mut schema, &patch)
+            .expect_err("Should not apply");
+        assert!(
+            error
+                .to_string()
+                .contains("Patch cannot be applied: 3 conflicts")
+        );
+        assert_eq!(schema, people());
+
+        let patch = SchemaPatch::new(vec![
+            PatchOperation::Add {
+                path: "/slots/age".to_string(),
+                value: serde_json::json!({"name": "age", "range": "integer"}),
+            },
+            PatchOperation::Add {
+                path: "/classes/Person/slots/age".to_string(),
+                value: serde_json::json!("age"),
+            },
+            PatchOperation::Remove {
+                path: "/classes/Person/slots/nickname".to_string(),
+            },
+        ]);
+        assert!(patcher.check_patch(&schema, &patch).is_empty());
+        patcher
+            .apply_atomic(&mut schema, &patch)
+            .expect("Should apply patch");
+        assert_eq!(schema.classes["Person"].slots, ["name", "age"]);
+        assert_eq!(schema.slots["age"].range.as_deref(), Some("integer"));
+    }
+
+    #[test]
+    fn test_create_patch_round_trips() {
+        // Removing a class is breaking
+        let mut before = people();
+        before
+            .classes
+            .insert("Pet".to_string(), ClassDefinition::default());
+        let after: SchemaDefinition = serde_yaml::from_str(
+            "\
+id: https://example.org/people
+name: people
+version: 2.0.0
+imports:
+  - linkml:types
+classes:
+  Person:
+    description: Someone
+    slots:
+      - name
+  Place:
+    attributes:
+      city:
+        range: string
+        required: true
+slots:
+  name:
+    range: string
+",
+        )
+        .expect("Should parse schema");
+
+        let patch = create_patch(&before, &after).expect("Should create patch");
+        assert!(patch.breaking);
+        assert_eq!(patch.from_version.as_deref(), Some("1.0.0"));
+        assert_eq!(patch.to_version.as_deref(), Some("2.0.0"));
+
+        // The patch survives its JSON Patch form
+        let patch = SchemaPatch {
+            operations: SchemaPatch::parse(&patch.to_json_patch().expect("Should serialize"))
+                .expect("Should parse patch")
+                .operations,
+            ..patch
+        };
+        let mut schema = before.clone();
+        SchemaPatcher::new(PatchOptions {
+            allow_breaking: true,
+            ..Default::default()
+        })
+        .apply_atomic(&mut schema, &patch)
+        .expect("Should apply patch");
+        assert_eq!(schema, after);
+
+        let unchanged = create_patch(&before, &before).expect("Should create patch");
+        assert!(unchanged.operations.is_empty());
+        assert!(!unchanged.breaking);
+    }
+
+    #[test]
+    fn test_apply_patch_to_str_keeps_comments() {
+        let content = "\
+# Test schema
+id: https://example.org/test
+name: test
+slots:
+  # Kept for older data
+  name:
+    range: string   # plain text
+  old_slot:
+    range: string
+";
+        let patch = SchemaPatch {
+            operations: vec![
+                PatchOperation::Remove {
+                    path: "/slots/old_slot".to_string(),
+                },
+                PatchOperation::Add {
+                    path: "/classes/Person".to_string(),
+                    value: serde_json::json!({"name": "Person", "slots": ["name"]}),
+                },
+            ],
+            description: None,
+            from_version: None,
+            to_version: None,
+            breaking: true,
+        };
+        let options = PatchOptions {
+            allow_breaking: true,
+            ..Default::default()
+        };
+
+        let (result, text) = SchemaPatcher::new(options)
+            .apply_patch_to_str(content, &patch)
+            .expect("Should apply patch");
+        assert_eq!(result.applied_operations.len(), 2);
+        assert_eq!(
+            text,
+            "\
+# Test schema
+id: https://example.org/test
+name: test
+classes:
+  Person:
+    name: Person
+    slots:
+      - name
+slots:
+  # Kept for older data
+  name:
+    range: string   # plain text
+"
+        );
+    }
 }
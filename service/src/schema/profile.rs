@@ -0,0 +1,660 @@
+//! Constraint-only schema profiles
+//!
+//! A profile adapts a shared base schema to a national or organizational
+//! context by tightening its constraints without redefining its structure.
+//! It is an ordinary schema whose elements reuse the names of the base
+//! schema:
+//!
+//! ```yaml
+//! id: https://example.org/person-profile-de
+//! name: person_profile_de
+//! classes:
+//!   Person:
+//!     slot_usage:
+//!       postal_code:
+//!         required: true
+//!         pattern: "^[0-9]{5}$"
+//! slots:
+//!   age:
+//!     maximum_value: 150
+//! enums:
+//!   Country:
+//!     permissible_values:
+//!       DE:
+//!       AT:
+//! ```
+//!
+//! Classes may only carry `slot_usage` for slots they already have; global
+//! slots may narrow their range, become required, gain a pattern and tighten
+//! numeric and length bounds; enums may only keep a subset of their values.
+//! New enums and derived types may be added to serve as narrower ranges, but
+//! new classes and slots may not. [`ProfileChecker::check`] verifies that a
+//! profile is a valid restriction and [`ProfileChecker::apply`] produces the
+//! effective schema.
+
+use linkml_core::prelude::*;
+use linkml_core::utils::merge_slot_definitions;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Way in which a profile fails to be a restriction of its base
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileViolationKind {
+    /// A class or slot that the base schema does not define
+    NewElement,
+    /// A change to inheritance, slot membership, cardinality or inlining
+    StructuralChange,
+    /// A range that is not the base range or a specialization of it
+    WidenedRange,
+    /// A required slot made optional
+    RelaxedRequired,
+    /// A value or length bound looser than in the base schema
+    WidenedBounds,
+    /// A pattern replaced by a different one, which cannot be proven narrower
+    ReplacedPattern,
+    /// A permissible value the base schema does not allow
+    AddedValue,
+}
+
+/// Profile element that is not a valid restriction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileViolation {
+    /// Kind of violation
+    pub kind: ProfileViolationKind,
+    /// Element type (class, slot, enum)
+    pub element_type: String,
+    /// Element name
+    pub element_name: String,
+    /// Slot of a class `slot_usage`, if the violation is there
+    pub slot: Option<String>,
+    /// Description of the violation
+    pub message: String,
+}
+
+/// Result of checking a profile against its base schema
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfileReport {
+    /// Violations found, in schema order
+    pub violations: Vec<ProfileViolation>,
+}
+
+impl ProfileReport {
+    /// Whether the profile only tightens constraints of the base schema
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Checks and applies profiles of a base schema
+pub struct ProfileChecker<'a> {
+    base: &'a SchemaDefinition,
+}
+
+impl<'a> ProfileChecker<'a> {
+    /// Create a checker for profiles of `base`
+    #[must_use]
+    pub fn new(base: &'a SchemaDefinition) -> Self {
+        Self { base }
+    }
+
+    /// Check that `profile` only tightens constraints of the base schema
+    #[must_use]
+    pub fn check(&self, profile: &SchemaDefinition) -> ProfileReport {
+        let mut report = ProfileReport::default();
+        self.check_classes(profile, &mut report);
+
+        for (slot_name, slot) in &profile.slots {
+            match self.base.slots.get(slot_name) {
+                Some(base_slot) => {
+                    let mut location = Location::new("slot", slot_name, None);
+                    self.check_slot(profile, base_slot, slot, &mut location);
+                    report.violations.extend(location.violations);
+                }
+                None => report.violations.push(violation(
+                    ProfileViolationKind::NewElement,
+                    ("slot", slot_name, None),
+                    format!("Profile defines slot '{slot_name}', which the base schema lacks"),
+                )),
+            }
+        }
+
+        for (enum_name, enum_def) in &profile.enums {
+            if let Some(base_enum) = self.base.enums.get(enum_name) {
+                let allowed = pv_texts(&base_enum.permissible_values);
+                for value in pv_texts(&enum_def.permissible_values).difference(&allowed) {
+                    report.violations.push(violation(
+                        ProfileViolationKind::AddedValue,
+                        ("enum", enum_name, None),
+                        format!("Enum '{enum_name}' adds permissible value '{value}'"),
+                    ));
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Effective schema of the base schema restricted by `profile`
+    ///
+    /// The result keeps the structure of the base schema and takes its `id`,
+    /// `name` and, when set, `version` and `description` from the profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns a schema validation error listing the violations if the
+    /// profile is not a valid restriction.
+    pub fn apply(&self, profile: &SchemaDefinition) -> Result<SchemaDefinition> {
+        let report = self.check(profile);
+        if !report.is_valid() {
+            let messages: Vec<&str> = report
+                .violations
+                .iter()
+                .map(|violation| violation.message.as_str())
+                .collect();
+            return Err(LinkMLError::schema_validation(format!(
+                "Profile '{}' is not a valid restriction of '{}': {}",
+                profile.name,
+                self.base.name,
+                messages.join("; ")
+            )));
+        }
+
+        let mut schema = self.base.clone();
+        schema.id.clone_from(&profile.id);
+        schema.name.clone_from(&profile.name);
+        if profile.version.is_some() {
+            schema.version.clone_from(&profile.version);
+        }
+        if profile.description.is_some() {
+            schema.description.clone_from(&profile.description);
+        }
+
+        for (class_name, class) in &profile.classes {
+            let Some(target) = schema.classes.get_mut(class_name) else {
+                continue;
+            };
+            if class.abstract_.is_some() {
+                target.abstract_ = class.abstract_;
+            }
+            for (slot_name, usage) in &class.slot_usage {
+                let merged = match target.slot_usage.get(slot_name) {
+                    Some(existing) => merge_slot_definitions(existing, usage),
+                    None => usage.clone(),
+                };
+                target.slot_usage.insert(slot_name.clone(), merged);
+            }
+        }
+        for (slot_name, slot) in &profile.slots {
+            if let Some(target) = schema.slots.get_mut(slot_name) {
+                *target = merge_slot_definitions(target, slot);
+            }
+        }
+        for (enum_name, enum_def) in &profile.enums {
+            match schema.enums.get_mut(enum_name) {
+                Some(target) if !enum_def.permissible_values.is_empty() => {
+                    target
+                        .permissible_values
+                        .clone_from(&enum_def.permissible_values);
+                }
+                Some(_) => {}
+                None => {
+                    schema.enums.insert(enum_name.clone(), enum_def.clone());
+                }
+            }
+        }
+        for (type_name, type_def) in &profile.types {
+            schema
+                .types
+                .entry(type_name.clone())
+                .or_insert_with(|| type_def.clone());
+        }
+
+        Ok(schema)
+    }
+
+    fn check_classes(&self, profile: &SchemaDefinition, report: &mut ProfileReport) {
+        for (class_name, class) in &profile.classes {
+            let Some(base_class) = self.base.classes.get(class_name) else {
+                report.violations.push(violation(
+                    ProfileViolationKind::NewElement,
+                    ("class", class_name, None),
+                    format!("Profile defines class '{class_name}', which the base schema lacks"),
+                ));
+                continue;
+            };
+
+            let mut structural = Vec::new();
+            if class.is_a.is_some() && class.is_a != base_class.is_a {
+                structural.push("changes is_a".to_string());
+            }
+            if !class.mixins.is_empty() && class.mixins != base_class.mixins {
+                structural.push("changes mixins".to_string());
+            }
+            let base_slots = self.class_slot_names(class_name);
+            for slot_name in class.slots.iter().chain(class.attributes.keys()) {
+                if !base_slots.contains(slot_name) {
+                    structural.push(format!("adds slot '{slot_name}'"));
+                }
+            }
+            for change in structural {
+                report.violations.push(violation(
+                    ProfileViolationKind::StructuralChange,
+                    ("class", class_name, None),
+                    format!("Class '{class_name}' {change}"),
+                ));
+            }
+
+            for (slot_name, usage) in &class.slot_usage {
+                let mut location = Location::new("class", class_name, Some(slot_name));
+                match self.effective_slot(class_name, slot_name) {
+                    Some(base_slot) => self.check_slot(profile, &base_slot, usage, &mut location),
+                    None => location.push(
+                        ProfileViolationKind::StructuralChange,
+                        format!("refines slot '{slot_name}', which the class does not have"),
+                    ),
+                }
+                report.violations.extend(location.violations);
+            }
+        }
+    }
+
+    /// Check one slot refinement against the base definition
+    fn check_slot(
+        &self,
+        profile: &SchemaDefinition,
+        base: &SlotDefinition,
+        refined: &SlotDefinition,
+        location: &mut Location,
+    ) {
+        if let Some(range) = &refined.range {
+            let base_range = base
+                .range
+                .as_deref()
+                .or(self.base.default_range.as_deref())
+                .unwrap_or("string");
+            if !self.narrows_range(profile, range, base_range) {
+                location.push(
+                    ProfileViolationKind::WidenedRange,
+                    format!("widens range '{base_range}' to '{range}'"),
+                );
+            }
+        }
+        if base.required == Some(true) && refined.required == Some(false) {
+            location.push(
+                ProfileViolationKind::RelaxedRequired,
+                "makes a required slot optional".to_string(),
+            );
+        }
+
+        let structure = [
+            ("multivalued", base.multivalued, refined.multivalued),
+            ("identifier", base.identifier, refined.identifier),
+            ("key", base.key, refined.key),
+            ("inlined", base.inlined, refined.inlined),
+            (
+                "inlined_as_list",
+                base.inlined_as_list,
+                refined.inlined_as_list,
+            ),
+            (
+                "inlined_as_dict",
+                base.inlined_as_dict,
+                refined.inlined_as_dict,
+            ),
+        ];
+        for (name, before, after) in structure {
+            if after.is_some() && after.unwrap_or(false) != before.unwrap_or(false) {
+                location.push(
+                    ProfileViolationKind::StructuralChange,
+                    format!("changes {name}"),
+                );
+            }
+        }
+
+        check_bounds(base, refined, location);
+
+        if let (Some(before), Some(after)) = (&base.pattern, &refined.pattern)
+            && before != after
+        {
+            location.push(
+                ProfileViolationKind::ReplacedPattern,
+                format!("replaces pattern '{before}' with '{after}'"),
+            );
+        }
+        if !base.permissible_values.is_empty() {
+            let allowed = pv_texts(&base.permissible_values);
+            for value in pv_texts(&refined.permissible_values).difference(&allowed) {
+                location.push(
+                    ProfileViolationKind::AddedValue,
+                    format!("adds permissible value '{value}'"),
+                );
+            }
+        }
+        if let (Some(before), Some(after)) = (&base.equals_string_in, &refined.equals_string_in) {
+            for value in after.iter().filter(|value| !before.contains(value)) {
+                location.push(
+                    ProfileViolationKind::AddedValue,
+                    format!("adds allowed string '{value}'"),
+                );
+            }
+        }
+    }
+
+    /// Whether `range` is `base_range` or a specialization of it: a
+    /// descendant class, a type derived from it, or an enum whose values the
+    /// base enum allows
+    fn narrows_range(&self, profile: &SchemaDefinition, range: &str, base_range: &str) -> bool {
+        if range == base_range {
+            return true;
+        }
+        if self.base.classes.contains_key(base_range) {
+            return self.class_ancestors(range).contains(base_range);
+        }
+        if let Some(base_enum) = self.base.enums.get(base_range) {
+            let allowed = pv_texts(&base_enum.permissible_values);
+            return profile
+                .enums
+                .get(range)
+                .or_else(|| self.base.enums.get(range))
+                .is_some_and(|narrow| pv_texts(&narrow.permissible_values).is_subset(&allowed));
+        }
+
+        // Follow derived types up to the base range
+        let mut seen = HashSet::new();
+        let mut current = range;
+        while seen.insert(current) {
+            let Some(base_type) = profile
+                .types
+                .get(current)
+                .or_else(|| self.base.types.get(current))
+                .and_then(|type_def| type_def.base_type.as_deref())
+            else {
+                return false;
+            };
+            if base_type == base_range {
+                return true;
+            }
+            current = base_type;
+        }
+        false
+    }
+
+    /// A class and its ancestors through `is_a` and mixins in the base schema
+    fn class_ancestors(&self, class_name: &str) -> HashSet<String> {
+        let mut ancestors = HashSet::new();
+        let mut pending = vec![class_name.to_string()];
+        while let Some(name) = pending.pop() {
+            if !ancestors.insert(name.clone()) {
+                continue;
+            }
+            if let Some(class) = self.base.classes.get(&name) {
+                pending.extend(class.is_a.iter().chain(&class.mixins).cloned());
+            }
+        }
+        ancestors
+    }
+
+    /// Slots and attributes of a base class, including inherited ones
+    fn class_slot_names(&self, class_name: &str) -> HashSet<String> {
+        self.class_ancestors(class_name)
+            .iter()
+            .filter_map(|name| self.base.classes.get(name))
+            .flat_map(|class| class.slots.iter().chain(class.attributes.keys()).cloned())
+            .collect()
+    }
+
+    /// Base definition of a slot as used by a class, with the `slot_usage`
+    /// of the class applied
+    fn effective_slot(&self, class_name: &str, slot_name: &str) -> Option<SlotDefinition> {
+        if !self.class_slot_names(class_name).contains(slot_name) {
+            return None;
+        }
+        let mut slot = self
+            .class_ancestors(class_name)
+            .iter()
+            .filter_map(|name| self.base.classes.get(name))
+            .find_map(|class| class.attributes.get(slot_name))
+            .or_else(|| self.base.slots.get(slot_name))
+            .cloned()
+            .unwrap_or_default();
+        if let Some(usage) = self
+            .base
+            .classes
+            .get(class_name)
+            .and_then(|class| class.slot_usage.get(slot_name))
+        {
+            slot = merge_slot_definitions(&slot, usage);
+        }
+        Some(slot)
+    }
+}
+
+/// Violations collected for one profile element
+struct Location {
+    element_type: &'static str,
+    element_name: String,
+    slot: Option<String>,
+    violations: Vec<ProfileViolation>,
+}
+
+impl Location {
+    fn new(element_type: &'static str, element_name: &str, slot: Option<&String>) -> Self {
+        Self {
+            element_type,
+            element_name: element_name.to_string(),
+            slot: slot.cloned(),
+            violations: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, kind: ProfileViolationKind, change: String) {
+        let subject = match &self.slot {
+            Some(slot) => format!("Slot '{slot}' of class '{}'", self.element_name),
+            None => format!("Slot '{}'", self.element_name),
+        };
+        self.violations.push(violation(
+            kind,
+            (self.element_type, &self.element_name, self.slot.as_ref()),
+            format!("{subject} {change}"),
+        ));
+    }
+}
+
+fn violation(
+    kind: ProfileViolationKind,
+    (element_type, element_name, slot): (&str, &String, Option<&String>),
+    message: String,
+) -> ProfileViolation {
+    ProfileViolation {
+        kind,
+        element_type: element_type.to_string(),
+        element_name: element_name.clone(),
+        slot: slot.cloned(),
+        message,
+    }
+}
+
+/// Report value and length bounds that are looser than the base ones
+fn check_bounds(base: &SlotDefinition, refined: &SlotDefinition, location: &mut Location) {
+    let numeric = |value: Option<&Value>| value.and_then(Value::as_f64);
+    if let (Some(before), Some(after)) = (
+        numeric(base.minimum_value.as_ref()),
+        numeric(refined.minimum_value.as_ref()),
+    ) && after < before
+    {
+        location.push(
+            ProfileViolationKind::WidenedBounds,
+            format!("lowers minimum_value from {before} to {after}"),
+        );
+    }
+    if let (Some(before), Some(after)) = (
+        numeric(base.maximum_value.as_ref()),
+        numeric(refined.maximum_value.as_ref()),
+    ) && after > before
+    {
+        location.push(
+            ProfileViolationKind::WidenedBounds,
+            format!("raises maximum_value from {before} to {after}"),
+        );
+    }
+    if let (Some(before), Some(after)) = (base.min_length, refined.min_length)
+        && after < before
+    {
+        location.push(
+            ProfileViolationKind::WidenedBounds,
+            format!("lowers min_length from {before} to {after}"),
+        );
+    }
+    if let (Some(before), Some(after)) = (base.max_length, refined.max_length)
+        && after > before
+    {
+        location.push(
+            ProfileViolationKind::WidenedBounds,
+            format!("raises max_length from {before} to {after}"),
+        );
+    }
+}
+
+fn pv_texts(values: &[PermissibleValue]) -> HashSet<&str> {
+    values.iter().map(PermissibleValue::text).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_schema() -> SchemaDefinition {
+        serde_yaml::from_str(
+            r"
+id: https://example.org/person
+name: person
+classes:
+  Named:
+    slots: [name]
+  Person:
+    is_a: Named
+    slots: [age, country, postal_code]
+  Employee:
+    is_a: Person
+slots:
+  name:
+    required: true
+  age:
+    range: integer
+    minimum_value: 0
+    maximum_value: 200
+  country:
+    range: Country
+  postal_code:
+    range: string
+  employer:
+    range: Person
+enums:
+  Country:
+    permissible_values:
+      DE:
+      AT:
+      FR:
+",
+        )
+        .expect("valid base schema")
+    }
+
+    #[test]
+    fn test_valid_profile_is_applied() {
+        let profile: SchemaDefinition = serde_yaml::from_str(
+            r#"
+id: https://example.org/person-de
+name: person_de
+classes:
+  Person:
+    slot_usage:
+      postal_code:
+        required: true
+        pattern: "^[0-9]{5}$"
+      country:
+        range: DachCountry
+slots:
+  age:
+    maximum_value: 150
+  employer:
+    range: Employee
+enums:
+  DachCountry:
+    permissible_values:
+      DE:
+      AT:
+"#,
+        )
+        .expect("valid profile");
+
+        let base = base_schema();
+        let checker = ProfileChecker::new(&base);
+        assert!(checker.check(&profile).is_valid());
+
+        let schema = checker.apply(&profile).expect("valid restriction");
+        assert_eq!(schema.name, "person_de");
+        let postal_code = &schema.classes["Person"].slot_usage["postal_code"];
+        assert_eq!(postal_code.required, Some(true));
+        assert_eq!(schema.slots["age"].maximum_value, Some(Value::from(150)));
+        assert_eq!(schema.slots["age"].minimum_value, Some(Value::from(0)));
+        assert!(schema.enums.contains_key("DachCountry"));
+    }
+
+    #[test]
+    fn test_invalid_profile_is_reported() {
+        let profile: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/person-broken
+name: person_broken
+classes:
+  Person:
+    slots: [nickname]
+    slot_usage:
+      name:
+        required: false
+      age:
+        range: float
+        minimum_value: -1
+  Pet: {}
+slots:
+  nickname: {}
+enums:
+  Country:
+    permissible_values:
+      DE:
+      US:
+",
+        )
+        .expect("valid profile");
+
+        let base = base_schema();
+        let checker = ProfileChecker::new(&base);
+        let report = checker.check(&profile);
+        let kinds: Vec<ProfileViolationKind> = report
+            .violations
+            .iter()
+            .map(|violation| violation.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ProfileViolationKind::StructuralChange,
+                ProfileViolationKind::RelaxedRequired,
+                ProfileViolationKind::WidenedRange,
+                ProfileViolationKind::WidenedBounds,
+                ProfileViolationKind::NewElement,
+                ProfileViolationKind::NewElement,
+                ProfileViolationKind::AddedValue,
+            ]
+        );
+        assert_eq!(
+            report.violations[1].message,
+            "Slot 'name' of class 'Person' makes a required slot optional"
+        );
+        assert!(checker.apply(&profile).is_err());
+    }
+}
@@ -0,0 +1,493 @@
+//! Composite schema quality score
+//!
+//! Four measures of schema health each score from 0 to 100:
+//!
+//! - **lint**: issues reported by the lint rules, weighted by severity
+//! - **documentation**: share of classes, slots, attributes, enums and types
+//!   with a description
+//! - **mappings**: share of classes and slots with a URI or ontology mapping,
+//!   and of permissible values with a `meaning`
+//! - **complexity**: penalties for deep `is_a` chains and classes with very
+//!   many slots
+//!
+//! The quality score is their weighted mean. [`QualityReport`] serializes to
+//! JSON and renders as an SVG badge, so repositories can display schema
+//! health and gate on a minimum score.
+
+use super::lint::{LintOptions, SchemaLinter};
+use linkml_core::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Points deducted from the lint score per error
+const LINT_ERROR_PENALTY: f64 = 10.0;
+
+/// Points deducted from the lint score per warning
+const LINT_WARNING_PENALTY: f64 = 3.0;
+
+/// Points deducted from the lint score per info
+const LINT_INFO_PENALTY: f64 = 1.0;
+
+/// Deepest `is_a` chain that is not penalized
+pub const MAX_INHERITANCE_DEPTH: usize = 5;
+
+/// Most slots a class may have, inherited ones included, without penalty
+pub const MAX_CLASS_SLOTS: usize = 30;
+
+/// Points deducted from the complexity score per level beyond
+/// [`MAX_INHERITANCE_DEPTH`] and per class beyond [`MAX_CLASS_SLOTS`]
+const COMPLEXITY_PENALTY: f64 = 10.0;
+
+/// Relative weights of the quality measures
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct QualityWeights {
+    /// Weight of the lint score
+    pub lint: f64,
+    /// Weight of the documentation coverage
+    pub documentation: f64,
+    /// Weight of the mapping coverage
+    pub mappings: f64,
+    /// Weight of the complexity score
+    pub complexity: f64,
+}
+
+impl Default for QualityWeights {
+    fn default() -> Self {
+        Self {
+            lint: 0.4,
+            documentation: 0.3,
+            mappings: 0.15,
+            complexity: 0.15,
+        }
+    }
+}
+
+/// Score of one quality measure
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QualityMetric {
+    /// Score from 0 to 100
+    pub score: f64,
+    /// Weight in the quality score
+    pub weight: f64,
+    /// What the score is based on
+    pub detail: String,
+}
+
+/// Quality score of a schema
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QualityReport {
+    /// Schema name
+    pub schema: String,
+    /// Weighted quality score from 0 to 100
+    pub score: f64,
+    /// Letter grade of the score
+    pub grade: String,
+    /// Lint score
+    pub lint: QualityMetric,
+    /// Documentation coverage
+    pub documentation: QualityMetric,
+    /// Mapping coverage
+    pub mappings: QualityMetric,
+    /// Complexity score
+    pub complexity: QualityMetric,
+}
+
+impl QualityReport {
+    /// Whether the score reaches `min_score`
+    #[must_use]
+    pub fn passes(&self, min_score: f64) -> bool {
+        self.score >= min_score
+    }
+
+    /// Render the score as a shields-style SVG badge
+    #[must_use]
+    pub fn to_badge_svg(&self) -> String {
+        let label = "schema quality";
+        let value = format!("{:.0}/100 {}", self.score.floor(), self.grade);
+        let color = match self.grade.as_str() {
+            "A" => "#4c1",
+            "B" => "#97ca00",
+            "C" => "#dfb317",
+            "D" => "#fe7d37",
+            _ => "#e05d44",
+        };
+        // Approximate Verdana 11px text widths, padded by 5px on each side
+        let label_width = text_width(label) + 10;
+        let value_width = text_width(&value) + 10;
+        let width = label_width + value_width;
+        format!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {value}">
+  <title>{label}: {value}</title>
+  <linearGradient id="s" x2="0" y2="100%">
+    <stop offset="0" stop-color="#bbb" stop-opacity=".1"/>
+    <stop offset="1" stop-opacity=".1"/>
+  </linearGradient>
+  <clipPath id="r">
+    <rect width="{width}" height="20" rx="3" fill="#fff"/>
+  </clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{label_width}" height="20" fill="#555"/>
+    <rect x="{label_width}" width="{value_width}" height="20" fill="{color}"/>
+    <rect width="{width}" height="20" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="{label_x}" y="14">{label}</text>
+    <text x="{value_x}" y="14">{value}</text>
+  </g>
+</svg>
+"##,
+            label_x = label_width / 2,
+            value_x = label_width + value_width / 2,
+        )
+    }
+}
+
+/// Computes quality reports of schemas
+pub struct QualityScorer {
+    linter: SchemaLinter,
+    weights: QualityWeights,
+}
+
+impl QualityScorer {
+    /// Create a scorer linting with `lint_options`
+    #[must_use]
+    pub fn new(lint_options: LintOptions) -> Self {
+        Self {
+            linter: SchemaLinter::new(lint_options),
+            weights: QualityWeights::default(),
+        }
+    }
+
+    /// Set the weights of the quality measures
+    #[must_use]
+    pub fn with_weights(mut self, weights: QualityWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// Score a schema
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if linting fails
+    pub fn score(&self, schema: &SchemaDefinition) -> Result<QualityReport> {
+        let lint_result = self.linter.lint(schema)?;
+        let (errors, warnings, infos) = (
+            lint_result.error_count(),
+            lint_result.warning_count(),
+            lint_result.info_count(),
+        );
+        let lint = QualityMetric {
+            score: (100.0
+                - LINT_ERROR_PENALTY * count(errors)
+                - LINT_WARNING_PENALTY * count(warnings)
+                - LINT_INFO_PENALTY * count(infos))
+            .max(0.0),
+            weight: self.weights.lint,
+            detail: format!("{errors} errors, {warnings} warnings, {infos} infos"),
+        };
+        let documentation = coverage_metric(
+            documentation_coverage(schema),
+            self.weights.documentation,
+            "documented",
+        );
+        let mappings = coverage_metric(mapping_coverage(schema), self.weights.mappings, "mapped");
+        let complexity = complexity_metric(schema, self.weights.complexity);
+
+        let metrics = [&lint, &documentation, &mappings, &complexity];
+        let total_weight: f64 = metrics.iter().map(|metric| metric.weight).sum();
+        let score = if total_weight > 0.0 {
+            metrics
+                .iter()
+                .map(|metric| metric.score * metric.weight)
+                .sum::<f64>()
+                / total_weight
+        } else {
+            0.0
+        };
+        let score = (score * 10.0).round() / 10.0;
+
+        Ok(QualityReport {
+            schema: schema.name.clone(),
+            score,
+            grade: grade(score).to_string(),
+            lint,
+            documentation,
+            mappings,
+            complexity,
+        })
+    }
+}
+
+/// Letter grade of a score
+fn grade(score: f64) -> &'static str {
+    match score {
+        s if s >= 90.0 => "A",
+        s if s >= 80.0 => "B",
+        s if s >= 70.0 => "C",
+        s if s >= 60.0 => "D",
+        _ => "F",
+    }
+}
+
+/// Count as a float, for score arithmetic
+fn count(n: usize) -> f64 {
+    crate::utils::usize_to_f64(n)
+}
+
+/// Approximate rendered width of badge text in pixels
+fn text_width(text: &str) -> usize {
+    text.chars().count() * 7
+}
+
+/// Metric of a `(covered, total)` coverage; an empty schema is fully covered
+fn coverage_metric((covered, total): (usize, usize), weight: f64, verb: &str) -> QualityMetric {
+    QualityMetric {
+        score: if total == 0 {
+            100.0
+        } else {
+            count(covered) * 100.0 / count(total)
+        },
+        weight,
+        detail: format!("{covered} of {total} elements {verb}"),
+    }
+}
+
+/// Classes, slots, attributes, enums and types with a description
+fn documentation_coverage(schema: &SchemaDefinition) -> (usize, usize) {
+    let descriptions = schema
+        .classes
+        .values()
+        .map(|class| &class.description)
+        .chain(schema.slots.values().map(|slot| &slot.description))
+        .chain(
+            schema
+                .classes
+                .values()
+                .flat_map(|class| class.attributes.values())
+                .map(|attribute| &attribute.description),
+        )
+        .chain(schema.enums.values().map(|enum_def| &enum_def.description))
+        .chain(schema.types.values().map(|type_def| &type_def.description));
+    let (mut documented, mut total) = (0, 0);
+    for description in descriptions {
+        total += 1;
+        if description
+            .as_deref()
+            .is_some_and(|text| !text.trim().is_empty())
+        {
+            documented += 1;
+        }
+    }
+    (documented, total)
+}
+
+/// Classes and slots with a URI or mapping, and permissible values with a
+/// meaning; enums whose values come from an ontology count as one mapped
+/// element
+fn mapping_coverage(schema: &SchemaDefinition) -> (usize, usize) {
+    let (mut mapped, mut total) = (0, 0);
+    let mut tally = |is_mapped: bool| {
+        total += 1;
+        if is_mapped {
+            mapped += 1;
+        }
+    };
+    for class in schema.classes.values() {
+        tally(
+            class.class_uri.is_some()
+                || [
+                    &class.exact_mappings,
+                    &class.close_mappings,
+                    &class.related_mappings,
+                    &class.narrow_mappings,
+                    &class.broad_mappings,
+                ]
+                .iter()
+                .any(|mappings| !mappings.is_empty()),
+        );
+    }
+    let attributes = schema
+        .classes
+        .values()
+        .flat_map(|class| class.attributes.values());
+    for slot in schema.slots.values().chain(attributes) {
+        tally(
+            slot.slot_uri.is_some()
+                || [
+                    &slot.exact_mappings,
+                    &slot.close_mappings,
+                    &slot.related_mappings,
+                    &slot.narrow_mappings,
+                    &slot.broad_mappings,
+                ]
+                .iter()
+                .any(|mappings| !mappings.is_empty()),
+        );
+    }
+    for enum_def in schema.enums.values() {
+        if enum_def.is_dynamic() || enum_def.code_set.is_some() {
+            tally(true);
+            continue;
+        }
+        for value in &enum_def.permissible_values {
+            tally(matches!(
+                value,
+                PermissibleValue::Complex {
+                    meaning: Some(_),
+                    ..
+                }
+            ));
+        }
+    }
+    (mapped, total)
+}
+
+/// Complexity score: penalties for `is_a` chains deeper than
+/// [`MAX_INHERITANCE_DEPTH`] and classes with more than [`MAX_CLASS_SLOTS`]
+/// slots
+fn complexity_metric(schema: &SchemaDefinition, weight: f64) -> QualityMetric {
+    let mut max_depth = 0;
+    let mut max_slots = 0;
+    let mut penalty = 0.0;
+    for class_name in schema.classes.keys() {
+        let depth = inheritance_depth(schema, class_name);
+        let slots = class_slot_count(schema, class_name);
+        max_depth = max_depth.max(depth);
+        max_slots = max_slots.max(slots);
+        penalty += COMPLEXITY_PENALTY * count(depth.saturating_sub(MAX_INHERITANCE_DEPTH));
+        if slots > MAX_CLASS_SLOTS {
+            penalty += COMPLEXITY_PENALTY;
+        }
+    }
+    QualityMetric {
+        score: (100.0 - penalty).max(0.0),
+        weight,
+        detail: format!("inheritance depth up to {max_depth}, up to {max_slots} slots per class"),
+    }
+}
+
+/// Number of `is_a` ancestors of a class
+fn inheritance_depth(schema: &SchemaDefinition, class_name: &str) -> usize {
+    let mut visited = HashSet::from([class_name]);
+    let mut current = schema.classes.get(class_name);
+    let mut depth = 0;
+    while let Some(parent) = current.and_then(|class| class.is_a.as_deref()) {
+        if !visited.insert(parent) {
+            break;
+        }
+        depth += 1;
+        current = schema.classes.get(parent);
+    }
+    depth
+}
+
+/// Number of distinct slots and attributes of a class and its ancestors and
+/// mixins
+fn class_slot_count(schema: &SchemaDefinition, class_name: &str) -> usize {
+    let mut slots = HashSet::new();
+    let mut visited = HashSet::new();
+    let mut pending = vec![class_name];
+    while let Some(name) = pending.pop() {
+        if !visited.insert(name) {
+            continue;
+        }
+        let Some(class) = schema.classes.get(name) else {
+            continue;
+        };
+        slots.extend(class.slots.iter().map(String::as_str));
+        slots.extend(class.attributes.keys().map(String::as_str));
+        pending.extend(class.is_a.as_deref());
+        pending.extend(class.mixins.iter().map(String::as_str));
+    }
+    slots.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+
+    fn schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/people".to_string(),
+            name: "people".to_string(),
+            description: Some("People".to_string()),
+            ..Default::default()
+        };
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                name: "Person".to_string(),
+                description: Some("A person".to_string()),
+                class_uri: Some("schema:Person".to_string()),
+                slots: vec!["name".to_string(), "age".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "name".to_string(),
+            SlotDefinition {
+                name: "name".to_string(),
+                description: Some("Full name".to_string()),
+                range: Some("string".to_string()),
+                exact_mappings: vec!["schema:name".to_string()],
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "age".to_string(),
+            SlotDefinition {
+                name: "age".to_string(),
+                range: Some("integer".to_string()),
+                ..Default::default()
+            },
+        );
+        schema
+    }
+
+    #[test]
+    fn test_quality_score() -> anyhow::Result<()> {
+        let report = QualityScorer::new(LintOptions::default()).score(&schema())?;
+        assert!((report.documentation.score - 200.0 / 3.0).abs() < 1e-9);
+        assert_eq!(report.documentation.detail, "2 of 3 elements documented");
+        assert_eq!(report.mappings.detail, "2 of 3 elements mapped");
+        assert!((report.complexity.score - 100.0).abs() < f64::EPSILON);
+        assert!(report.score > 0.0 && report.score <= 100.0);
+        assert_eq!(report.grade, grade(report.score));
+        assert!(report.passes(report.score));
+        assert!(!report.passes(report.score + 1.0));
+
+        let json = serde_json::to_value(&report)?;
+        assert_eq!(json["schema"], "people");
+        assert!(json["lint"]["weight"].is_number());
+
+        let svg = report.to_badge_svg();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains(&format!("{:.0}/100 {}", report.score.floor(), report.grade)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_complexity_penalties() {
+        let mut schema = schema();
+        for level in 0..7 {
+            schema.classes.insert(
+                format!("Level{level}"),
+                ClassDefinition {
+                    is_a: (level > 0).then(|| format!("Level{}", level - 1)),
+                    ..Default::default()
+                },
+            );
+        }
+        assert_eq!(inheritance_depth(&schema, "Level6"), 6);
+        let metric = complexity_metric(&schema, 1.0);
+        assert!((metric.score - 90.0).abs() < f64::EPSILON);
+        assert_eq!(
+            metric.detail,
+            "inheritance depth up to 6, up to 2 slots per class"
+        );
+        assert_eq!(grade(95.0), "A");
+        assert_eq!(grade(59.9), "F");
+    }
+}
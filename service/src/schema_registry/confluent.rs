@@ -0,0 +1,877 @@
+//! Publishing to a Confluent-compatible schema registry
+//!
+//! [`RegistryPublisher`] generates the JSON Schema or Avro artifact of a
+//! schema, checks it against the versions already registered under a
+//! subject and registers it as a new version. The `LinkML` schema travels
+//! along in the version metadata (`linkml.schema`), so later checks compare
+//! `LinkML` schemas instead of generated artifacts.
+//!
+//! Compatibility follows the registry's levels: `BACKWARD` means data
+//! written with the previous version can be read with the new one,
+//! `FORWARD` the reverse, `FULL` both, and the `_TRANSITIVE` variants check
+//! every registered version instead of only the latest. Versions registered
+//! without a `LinkML` schema are left to the registry's own check.
+
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::{SchemaDefinition, SlotDefinition};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::info;
+
+use crate::generator::base::induced_slots;
+use crate::generator::{AvroGenerator, Generator, JsonSchemaGenerator};
+use crate::schema::{DiffOptions, SchemaDiff};
+
+/// Version metadata property holding the `LinkML` schema (JSON)
+pub const LINKML_SCHEMA_PROPERTY: &str = "linkml.schema";
+
+/// Media type of schema registry requests and responses
+const CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+
+/// Artifact registered for a schema
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArtifactFormat {
+    /// Avro schema of [`AvroGenerator`]
+    #[default]
+    Avro,
+    /// JSON Schema of [`JsonSchemaGenerator`]
+    JsonSchema,
+}
+
+impl ArtifactFormat {
+    /// Value of `schemaType` in registry requests
+    #[must_use]
+    pub fn schema_type(self) -> &'static str {
+        match self {
+            Self::Avro => "AVRO",
+            Self::JsonSchema => "JSON",
+        }
+    }
+
+    /// Generate the artifact of `schema`, rooted at `class_name` if given
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if generation fails or the class does not exist.
+    pub fn generate(self, schema: &SchemaDefinition, class_name: Option<&str>) -> Result<String> {
+        match self {
+            Self::Avro => {
+                let generator = match class_name {
+                    Some(class_name) => AvroGenerator::new().with_root_class(class_name),
+                    None => AvroGenerator::new(),
+                };
+                generator.generate(schema)
+            }
+            Self::JsonSchema => {
+                let generated = JsonSchemaGenerator::new().generate(schema)?;
+                let Some(class_name) = class_name else {
+                    return Ok(generated);
+                };
+                if !schema.classes.contains_key(class_name) {
+                    return Err(LinkMLError::config(format!(
+                        "Class '{class_name}' is not defined in the schema"
+                    )));
+                }
+                let mut json_schema: Value = serde_json::from_str(&generated)?;
+                if let Value::Object(root) = &mut json_schema {
+                    root.remove("oneOf");
+                    root.insert(
+                        "$ref".to_string(),
+                        json!(format!("#/definitions/{class_name}")),
+                    );
+                }
+                Ok(serde_json::to_string_pretty(&json_schema)?)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ArtifactFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Avro => write!(f, "avro"),
+            Self::JsonSchema => write!(f, "json-schema"),
+        }
+    }
+}
+
+impl FromStr for ArtifactFormat {
+    type Err = LinkMLError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "avro" => Ok(Self::Avro),
+            "json-schema" | "jsonschema" | "json" => Ok(Self::JsonSchema),
+            other => Err(LinkMLError::config(format!(
+                "Unknown artifact format '{other}' (expected avro or json-schema)"
+            ))),
+        }
+    }
+}
+
+/// Compatibility level of a registry subject
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CompatibilityLevel {
+    /// No checks
+    None,
+    /// New version reads data of the latest version
+    #[default]
+    Backward,
+    /// New version reads data of all versions
+    BackwardTransitive,
+    /// Latest version reads data of the new version
+    Forward,
+    /// All versions read data of the new version
+    ForwardTransitive,
+    /// Backward and forward against the latest version
+    Full,
+    /// Backward and forward against all versions
+    FullTransitive,
+}
+
+impl CompatibilityLevel {
+    /// Whether the new version must read data of earlier versions
+    #[must_use]
+    pub fn backward(self) -> bool {
+        matches!(
+            self,
+            Self::Backward | Self::BackwardTransitive | Self::Full | Self::FullTransitive
+        )
+    }
+
+    /// Whether earlier versions must read data of the new version
+    #[must_use]
+    pub fn forward(self) -> bool {
+        matches!(
+            self,
+            Self::Forward | Self::ForwardTransitive | Self::Full | Self::FullTransitive
+        )
+    }
+
+    /// Whether every registered version is checked, not only the latest
+    #[must_use]
+    pub fn transitive(self) -> bool {
+        matches!(
+            self,
+            Self::BackwardTransitive | Self::ForwardTransitive | Self::FullTransitive
+        )
+    }
+}
+
+impl fmt::Display for CompatibilityLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self {
+            Self::None => "NONE",
+            Self::Backward => "BACKWARD",
+            Self::BackwardTransitive => "BACKWARD_TRANSITIVE",
+            Self::Forward => "FORWARD",
+            Self::ForwardTransitive => "FORWARD_TRANSITIVE",
+            Self::Full => "FULL",
+            Self::FullTransitive => "FULL_TRANSITIVE",
+        };
+        f.write_str(level)
+    }
+}
+
+impl FromStr for CompatibilityLevel {
+    type Err = LinkMLError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_ascii_uppercase().replace('-', "_").as_str() {
+            "NONE" => Ok(Self::None),
+            "BACKWARD" => Ok(Self::Backward),
+            "BACKWARD_TRANSITIVE" => Ok(Self::BackwardTransitive),
+            "FORWARD" => Ok(Self::Forward),
+            "FORWARD_TRANSITIVE" => Ok(Self::ForwardTransitive),
+            "FULL" => Ok(Self::Full),
+            "FULL_TRANSITIVE" => Ok(Self::FullTransitive),
+            _ => Err(LinkMLError::config(format!(
+                "Unknown compatibility level '{value}'"
+            ))),
+        }
+    }
+}
+
+/// Changes between two `LinkML` schemas that break `level`
+///
+/// `BACKWARD:` messages name what the current schema lacks to read data of
+/// the previous one, `FORWARD:` messages the reverse. Undefined classes,
+/// types, enums and enum values come from the schema diff; slots are
+/// compared on the induced slots of each class, so attributes and inherited
+/// slots count too.
+///
+/// # Errors
+///
+/// Returns an error if the schemas cannot be compared.
+pub fn compatibility_violations(
+    previous: &SchemaDefinition,
+    current: &SchemaDefinition,
+    level: CompatibilityLevel,
+) -> Result<Vec<String>> {
+    let mut violations = Vec::new();
+    if level.backward() {
+        for change in unreadable_changes(previous, current)? {
+            violations.push(format!("BACKWARD: {change}"));
+        }
+    }
+    if level.forward() {
+        for change in unreadable_changes(current, previous)? {
+            violations.push(format!("FORWARD: {change}"));
+        }
+    }
+    Ok(violations)
+}
+
+/// Why data written with `writer` cannot be read with `reader`, sorted
+fn unreadable_changes(writer: &SchemaDefinition, reader: &SchemaDefinition) -> Result<Vec<String>> {
+    let diff = SchemaDiff::new(DiffOptions::default()).diff(writer, reader)?;
+    let mut changes = Vec::new();
+    for class_name in &diff.removed_classes {
+        changes.push(format!("class '{class_name}' is not defined"));
+    }
+    for type_name in &diff.removed_types {
+        changes.push(format!("type '{type_name}' is not defined"));
+    }
+    for enum_name in &diff.removed_enums {
+        changes.push(format!("enum '{enum_name}' is not defined"));
+    }
+    for enum_diff in &diff.modified_enums {
+        for value in &enum_diff.removed_values {
+            changes.push(format!("enum '{}' has no value '{value}'", enum_diff.name));
+        }
+    }
+
+    for class_name in reader.classes.keys() {
+        if !writer.classes.contains_key(class_name) {
+            continue;
+        }
+        let written = induced_slots(writer, class_name);
+        for slot in induced_slots(reader, class_name) {
+            let name = &slot.name;
+            let old = written.iter().find(|old| old.name == *name);
+            let required = |slot: &SlotDefinition| {
+                slot.required == Some(true) || slot.identifier == Some(true)
+            };
+            if required(&slot) && !old.is_some_and(required) {
+                changes.push(format!("class '{class_name}' requires slot '{name}'"));
+            }
+            let Some(old) = old else {
+                continue;
+            };
+            if old.range != slot.range {
+                changes.push(format!(
+                    "slot '{name}' of class '{class_name}' has range {} instead of {}",
+                    slot.range.as_deref().unwrap_or("the default"),
+                    old.range.as_deref().unwrap_or("the default")
+                ));
+            }
+            if old.multivalued.unwrap_or(false) != slot.multivalued.unwrap_or(false) {
+                let cardinality = if slot.multivalued == Some(true) {
+                    "multivalued"
+                } else {
+                    "single-valued"
+                };
+                changes.push(format!(
+                    "slot '{name}' of class '{class_name}' is {cardinality}"
+                ));
+            }
+        }
+    }
+    changes.sort();
+    Ok(changes)
+}
+
+/// Schema as sent to and returned by the registry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistrySchema {
+    /// The artifact
+    pub schema: String,
+    /// `AVRO`, `JSON` or `PROTOBUF`; the registry omits it for Avro
+    #[serde(default = "avro_schema_type")]
+    pub schema_type: String,
+    /// Version metadata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<SchemaMetadata>,
+}
+
+fn avro_schema_type() -> String {
+    "AVRO".to_string()
+}
+
+impl RegistrySchema {
+    /// Artifact of `schema` carrying the `LinkML` schema in its metadata
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the artifact cannot be generated.
+    pub fn generate(
+        schema: &SchemaDefinition,
+        format: ArtifactFormat,
+        class_name: Option<&str>,
+    ) -> Result<Self> {
+        let properties = BTreeMap::from([(
+            LINKML_SCHEMA_PROPERTY.to_string(),
+            serde_json::to_string(schema)?,
+        )]);
+        Ok(Self {
+            schema: format.generate(schema, class_name)?,
+            schema_type: format.schema_type().to_string(),
+            metadata: Some(SchemaMetadata { properties }),
+        })
+    }
+
+    /// The `LinkML` schema stored in the metadata, if any
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stored schema is not valid.
+    pub fn linkml_schema(&self) -> Result<Option<SchemaDefinition>> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.properties.get(LINKML_SCHEMA_PROPERTY))
+            .map(|schema| serde_json::from_str(schema).map_err(Into::into))
+            .transpose()
+    }
+}
+
+/// Metadata of a registered version
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaMetadata {
+    /// Free-form properties
+    #[serde(default)]
+    pub properties: BTreeMap<String, String>,
+}
+
+/// A registered version of a subject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubjectVersion {
+    /// Subject name
+    pub subject: String,
+    /// Registry-wide schema id
+    pub id: u32,
+    /// Version within the subject
+    pub version: u32,
+    /// The registered schema
+    #[serde(flatten)]
+    pub schema: RegistrySchema,
+}
+
+/// Client of the REST API of a Confluent-compatible schema registry
+pub struct ConfluentClient {
+    base_url: String,
+    credentials: Option<(String, String)>,
+    timeout: Duration,
+    client: reqwest::Client,
+}
+
+impl ConfluentClient {
+    /// Create a client for a registry, e.g. `http://localhost:8081`
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            credentials: None,
+            timeout: Duration::from_secs(30),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Authenticate with basic authentication, e.g. a Confluent Cloud API
+    /// key and secret
+    #[must_use]
+    pub fn with_basic_auth(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Set the timeout of each HTTP request
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Versions registered under a subject, oldest first; empty for an
+    /// unknown subject
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registry cannot be reached or fails.
+    pub async fn versions(&self, subject: &str) -> Result<Vec<u32>> {
+        let response = self
+            .request(reqwest::Method::GET, &["subjects", subject, "versions"])?
+            .send()
+            .await
+            .map_err(request_error)?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(Vec::new());
+        }
+        let mut versions: Vec<u32> = read_json(response).await?;
+        versions.sort_unstable();
+        Ok(versions)
+    }
+
+    /// A registered version of a subject
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version does not exist or the registry cannot
+    /// be reached.
+    pub async fn version(&self, subject: &str, version: u32) -> Result<SubjectVersion> {
+        let version = version.to_string();
+        let response = self
+            .request(
+                reqwest::Method::GET,
+                &["subjects", subject, "versions", &version],
+            )?
+            .send()
+            .await
+            .map_err(request_error)?;
+        read_json(response).await
+    }
+
+    /// Compatibility level of a subject, or the global level if the subject
+    /// has none
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registry cannot be reached or fails.
+    pub async fn compatibility_level(&self, subject: &str) -> Result<CompatibilityLevel> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Config {
+            #[serde(alias = "compatibility")]
+            compatibility_level: CompatibilityLevel,
+        }
+
+        let response = self
+            .request(reqwest::Method::GET, &["config", subject])?
+            .query(&[("defaultToGlobal", "true")])
+            .send()
+            .await
+            .map_err(request_error)?;
+        let response = if response.status() == reqwest::StatusCode::NOT_FOUND {
+            self.request(reqwest::Method::GET, &["config"])?
+                .send()
+                .await
+                .map_err(request_error)?
+        } else {
+            response
+        };
+        Ok(read_json::<Config>(response).await?.compatibility_level)
+    }
+
+    /// Whether the registry considers `schema` compatible with the latest
+    /// version of a subject under the subject's level
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registry cannot be reached or rejects the
+    /// schema as invalid.
+    pub async fn is_compatible(&self, subject: &str, schema: &RegistrySchema) -> Result<bool> {
+        #[derive(Deserialize)]
+        struct Compatibility {
+            is_compatible: bool,
+        }
+
+        let response = self
+            .request(
+                reqwest::Method::POST,
+                &["compatibility", "subjects", subject, "versions", "latest"],
+            )?
+            .json(schema)
+            .send()
+            .await
+            .map_err(request_error)?;
+        Ok(read_json::<Compatibility>(response).await?.is_compatible)
+    }
+
+    /// Register `schema` under a subject and return its schema id
+    ///
+    /// Registering a schema the subject already has returns the existing id.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registry rejects the schema, e.g. as
+    /// incompatible, or cannot be reached.
+    pub async fn register(&self, subject: &str, schema: &RegistrySchema) -> Result<u32> {
+        #[derive(Deserialize)]
+        struct Registered {
+            id: u32,
+        }
+
+        let response = self
+            .request(reqwest::Method::POST, &["subjects", subject, "versions"])?
+            .json(schema)
+            .send()
+            .await
+            .map_err(request_error)?;
+        Ok(read_json::<Registered>(response).await?.id)
+    }
+
+    fn request(
+        &self,
+        method: reqwest::Method,
+        segments: &[&str],
+    ) -> Result<reqwest::RequestBuilder> {
+        let mut url = reqwest::Url::parse(&self.base_url).map_err(|e| {
+            LinkMLError::config(format!(
+                "Invalid schema registry URL '{}': {e}",
+                self.base_url
+            ))
+        })?;
+        url.path_segments_mut()
+            .map_err(|()| {
+                LinkMLError::config(format!("Invalid schema registry URL '{}'", self.base_url))
+            })?
+            .pop_if_empty()
+            .extend(segments);
+        let mut request = self
+            .client
+            .request(method, url)
+            .timeout(self.timeout)
+            .header(reqwest::header::ACCEPT, CONTENT_TYPE)
+            .header(reqwest::header::CONTENT_TYPE, CONTENT_TYPE);
+        if let Some((username, password)) = &self.credentials {
+            request = request.basic_auth(username, Some(password));
+        }
+        Ok(request)
+    }
+}
+
+fn request_error(error: reqwest::Error) -> LinkMLError {
+    LinkMLError::service(format!("Schema registry request failed: {error}"))
+}
+
+/// Body of a successful response, or the registry's error message
+async fn read_json<T: serde::de::DeserializeOwned>(response: reqwest::Response) -> Result<T> {
+    let status = response.status();
+    let body = response.text().await.map_err(request_error)?;
+    if !status.is_success() {
+        let message = serde_json::from_str::<Value>(&body)
+            .ok()
+            .and_then(|error| error["message"].as_str().map(str::to_string))
+            .unwrap_or(body);
+        return Err(LinkMLError::service(format!(
+            "Schema registry returned {status}: {message}"
+        )));
+    }
+    serde_json::from_str(&body).map_err(|e| {
+        LinkMLError::deserialization(format!("Unexpected schema registry response: {e}"))
+    })
+}
+
+/// Result of checking a schema against the versions of a subject
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompatibilityReport {
+    /// Level checked
+    pub level: CompatibilityLevel,
+    /// Versions compared as `LinkML` schemas
+    pub checked_versions: Vec<u32>,
+    /// Versions without a `LinkML` schema, left to the registry's check
+    pub registry_checked_versions: Vec<u32>,
+    /// Breaking changes, prefixed with the version they break
+    pub violations: Vec<String>,
+}
+
+impl CompatibilityReport {
+    /// Whether the schema may be registered
+    #[must_use]
+    pub fn is_compatible(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Publishes generated artifacts of `LinkML` schemas to a registry
+pub struct RegistryPublisher {
+    client: ConfluentClient,
+    format: ArtifactFormat,
+    class_name: Option<String>,
+    level: Option<CompatibilityLevel>,
+}
+
+impl RegistryPublisher {
+    /// Create a publisher registering Avro schemas through `client`
+    #[must_use]
+    pub fn new(client: ConfluentClient) -> Self {
+        Self {
+            client,
+            format: ArtifactFormat::default(),
+            class_name: None,
+            level: None,
+        }
+    }
+
+    /// Set the artifact format
+    #[must_use]
+    pub fn with_format(mut self, format: ArtifactFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Generate the artifact for one class instead of the whole schema
+    #[must_use]
+    pub fn with_class(mut self, class_name: impl Into<String>) -> Self {
+        self.class_name = Some(class_name.into());
+        self
+    }
+
+    /// Check this level instead of the level configured for the subject
+    #[must_use]
+    pub fn with_compatibility(mut self, level: CompatibilityLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Generate the artifact of `schema` and check it against the versions
+    /// registered under `subject`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the artifact cannot be generated, the registry
+    /// cannot be reached or a registered `LinkML` schema is invalid.
+    pub async fn check(
+        &self,
+        subject: &str,
+        schema: &SchemaDefinition,
+    ) -> Result<(RegistrySchema, CompatibilityReport)> {
+        let artifact = RegistrySchema::generate(schema, self.format, self.class_name.as_deref())?;
+        let level = match self.level {
+            Some(level) => level,
+            None => self.client.compatibility_level(subject).await?,
+        };
+        let mut report = CompatibilityReport {
+            level,
+            ..CompatibilityReport::default()
+        };
+
+        let versions = self.client.versions(subject).await?;
+        let Some(&latest) = versions.last() else {
+            return Ok((artifact, report));
+        };
+        if level == CompatibilityLevel::None {
+            return Ok((artifact, report));
+        }
+        let compared = if level.transitive() {
+            versions.clone()
+        } else {
+            vec![latest]
+        };
+
+        let mut seen = HashSet::new();
+        for version in compared {
+            let registered = self.client.version(subject, version).await?;
+            let Some(previous) = registered.schema.linkml_schema()? else {
+                report.registry_checked_versions.push(version);
+                continue;
+            };
+            report.checked_versions.push(version);
+            for violation in compatibility_violations(&previous, schema, level)? {
+                if seen.insert(violation.clone()) {
+                    report
+                        .violations
+                        .push(format!("version {version}: {violation}"));
+                }
+            }
+        }
+        if !report.registry_checked_versions.is_empty()
+            && !self.client.is_compatible(subject, &artifact).await?
+        {
+            report.violations.push(format!(
+                "version {latest}: the registry rejected the {} artifact",
+                self.format
+            ));
+        }
+        Ok((artifact, report))
+    }
+
+    /// Check `schema` against `subject` and register it if compatible
+    ///
+    /// Returns the schema id and the compatibility report.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the check fails or finds breaking changes, or the
+    /// registry rejects the schema.
+    pub async fn publish(
+        &self,
+        subject: &str,
+        schema: &SchemaDefinition,
+    ) -> Result<(u32, CompatibilityReport)> {
+        let (artifact, report) = self.check(subject, schema).await?;
+        if !report.is_compatible() {
+            return Err(LinkMLError::schema_validation(format!(
+                "Schema '{}' is not {} compatible with subject '{subject}': {}",
+                schema.name,
+                report.level,
+                report.violations.join("; ")
+            )));
+        }
+        let id = self.client.register(subject, &artifact).await?;
+        info!(
+            "Registered schema '{}' under subject '{subject}' with id {id}",
+            schema.name
+        );
+        Ok((id, report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use axum::extract::{Path, State};
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+    use std::sync::{Arc, Mutex};
+
+    fn schema(extra: &str) -> SchemaDefinition {
+        let yaml = format!(
+            "id: https://example.org/orders
+name: orders
+default_range: string
+enums:
+  Status:
+    permissible_values:
+      open:
+      closed:
+classes:
+  Order:
+    attributes:
+      id:
+        identifier: true
+      status:
+        range: Status
+{extra}"
+        );
+        Parser::new()
+            .parse_str(&yaml, "yaml")
+            .expect("valid schema")
+    }
+
+    #[test]
+    fn test_compatibility_directions() -> Result<()> {
+        let v1 = schema("");
+        let optional = schema("      note:\n        range: string\n");
+        let required = schema("      total:\n        range: decimal\n        required: true\n");
+
+        // An optional slot can be read both ways
+        assert!(compatibility_violations(&v1, &optional, CompatibilityLevel::Full)?.is_empty());
+
+        // Old orders lack the required total
+        let violations = compatibility_violations(&v1, &required, CompatibilityLevel::Full)?;
+        assert_eq!(
+            violations,
+            ["BACKWARD: class 'Order' requires slot 'total'"]
+        );
+        assert!(compatibility_violations(&v1, &required, CompatibilityLevel::Forward)?.is_empty());
+
+        // New orders may carry a status the old schema does not know
+        let mut added_value = v1.clone();
+        added_value.enums["Status"].permissible_values.push(
+            linkml_core::types::PermissibleValue::Simple("held".to_string()),
+        );
+        let violations = compatibility_violations(&v1, &added_value, CompatibilityLevel::Full)?;
+        assert_eq!(violations, ["FORWARD: enum 'Status' has no value 'held'"]);
+
+        assert_eq!(
+            "full-transitive".parse::<CompatibilityLevel>()?,
+            CompatibilityLevel::FullTransitive
+        );
+        assert_eq!(
+            "json".parse::<ArtifactFormat>()?,
+            ArtifactFormat::JsonSchema
+        );
+        Ok(())
+    }
+
+    /// Registered versions of the one subject of a fake registry
+    type Registered = Arc<Mutex<Vec<RegistrySchema>>>;
+
+    async fn fake_registry(registered: Registered) -> String {
+        async fn versions(State(registered): State<Registered>) -> Json<Vec<u32>> {
+            let count = registered.lock().expect("registry lock").len();
+            Json((1..=u32::try_from(count).expect("version count fits u32")).collect())
+        }
+        async fn version(
+            State(registered): State<Registered>,
+            Path((subject, version)): Path<(String, u32)>,
+        ) -> Json<SubjectVersion> {
+            let index = usize::try_from(version).expect("version fits usize") - 1;
+            let schema = registered.lock().expect("registry lock")[index].clone();
+            Json(SubjectVersion {
+                subject,
+                id: version + 100,
+                version,
+                schema,
+            })
+        }
+        async fn register(
+            State(registered): State<Registered>,
+            Json(schema): Json<RegistrySchema>,
+        ) -> Json<Value> {
+            let mut registered = registered.lock().expect("registry lock");
+            registered.push(schema);
+            Json(json!({ "id": registered.len() + 100 }))
+        }
+
+        let router = Router::new()
+            .route(
+                "/config/{subject}",
+                get(|| async { Json(json!({"compatibilityLevel": "BACKWARD"})) }),
+            )
+            .route("/subjects/{subject}/versions", get(versions).post(register))
+            .route("/subjects/{subject}/versions/{version}", get(version))
+            .route(
+                "/compatibility/subjects/{subject}/versions/latest",
+                post(|| async { Json(json!({"is_compatible": true})) }),
+            )
+            .with_state(registered);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind fake registry");
+        let address = listener.local_addr().expect("fake registry address");
+        tokio::spawn(async move { axum::serve(listener, router).await });
+        format!("http://{address}")
+    }
+
+    #[tokio::test]
+    async fn test_publish_checks_registered_versions() -> Result<()> {
+        let registered = Registered::default();
+        let url = fake_registry(Arc::clone(&registered)).await;
+        let publisher = RegistryPublisher::new(ConfluentClient::new(url)).with_class("Order");
+
+        let (id, report) = publisher.publish("orders-value", &schema("")).await?;
+        assert_eq!(id, 101);
+        assert_eq!(report.level, CompatibilityLevel::Backward);
+        let artifact: Value =
+            serde_json::from_str(&registered.lock().expect("registry lock")[0].schema)?;
+        assert_eq!(artifact["name"], "Order");
+
+        let note = schema("      note:\n        range: string\n");
+        let (id, report) = publisher.publish("orders-value", &note).await?;
+        assert_eq!((id, report.checked_versions), (102, vec![1]));
+
+        let total = schema("      total:\n        range: decimal\n        required: true\n");
+        let err = publisher
+            .publish("orders-value", &total)
+            .await
+            .expect_err("incompatible with version 2");
+        assert!(
+            err.to_string()
+                .contains("version 2: BACKWARD: class 'Order' requires slot 'total'")
+        );
+        assert_eq!(registered.lock().expect("registry lock").len(), 2);
+        Ok(())
+    }
+}
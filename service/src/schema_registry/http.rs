@@ -0,0 +1,245 @@
+//! HTTP routes of the [`MultiTenantRegistry`]
+//!
+//! | Method | Path | |
+//! |---|---|---|
+//! | `GET` | `/tenants/{tenant}/schemas` | Registered versions |
+//! | `POST` | `/tenants/{tenant}/schemas` | Register a schema, see [`RegisterRequest`] |
+//! | `GET` | `/tenants/{tenant}/schemas/{name:version}` | The schema |
+//! | `DELETE` | `/tenants/{tenant}/schemas/{name:version}` | Unregister |
+//! | `POST` | `/tenants/{tenant}/validate` | Validate, see [`RegistryValidateRequest`] |
+//!
+//! A reference without `:version` means the latest registered version.
+
+use axum::{
+    Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
+    routing::{get, post},
+};
+use linkml_core::error::LinkMLError;
+use linkml_core::types::SchemaDefinition;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+use super::{MultiTenantRegistry, SchemaRef};
+
+/// Body of a schema registration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterRequest {
+    /// The schema
+    pub schema: SchemaDefinition,
+    /// Version to register under; defaults to the version the schema declares
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+/// Body of a validation against a registered schema
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistryValidateRequest {
+    /// Schema reference as `name:version` or `name`
+    pub schema: String,
+    /// Data to validate
+    pub data: Value,
+    /// Class to validate the data as
+    pub class_name: String,
+}
+
+/// Routes registering schemas per tenant and validating against them
+pub fn registry_router(registry: Arc<MultiTenantRegistry>) -> Router {
+    Router::new()
+        .route(
+            "/tenants/{tenant}/schemas",
+            get(list_schemas).post(register_schema),
+        )
+        .route(
+            "/tenants/{tenant}/schemas/{reference}",
+            get(get_schema).delete(remove_schema),
+        )
+        .route("/tenants/{tenant}/validate", post(validate))
+        .with_state(registry)
+}
+
+async fn list_schemas(
+    State(registry): State<Arc<MultiTenantRegistry>>,
+    Path(tenant): Path<String>,
+) -> Response {
+    Json(registry.list(&tenant)).into_response()
+}
+
+async fn register_schema(
+    State(registry): State<Arc<MultiTenantRegistry>>,
+    Path(tenant): Path<String>,
+    Json(request): Json<RegisterRequest>,
+) -> Response {
+    match registry
+        .register(&tenant, request.schema, request.version.as_deref())
+        .await
+    {
+        Ok(version) => (StatusCode::CREATED, Json(version)).into_response(),
+        Err(e) => error_response(&e),
+    }
+}
+
+async fn get_schema(
+    State(registry): State<Arc<MultiTenantRegistry>>,
+    Path((tenant, reference)): Path<(String, String)>,
+) -> Response {
+    let reference = match reference.parse::<SchemaRef>() {
+        Ok(reference) => reference,
+        Err(e) => return error_response(&e),
+    };
+    match registry.get(&tenant, &reference).await {
+        Ok(Some(loaded)) => Json(loaded.schema().as_ref()).into_response(),
+        Ok(None) => not_registered(&tenant, &reference),
+        Err(e) => error_response(&e),
+    }
+}
+
+async fn remove_schema(
+    State(registry): State<Arc<MultiTenantRegistry>>,
+    Path((tenant, reference)): Path<(String, String)>,
+) -> Response {
+    let reference = match reference.parse::<SchemaRef>() {
+        Ok(reference) => reference,
+        Err(e) => return error_response(&e),
+    };
+    match registry.remove(&tenant, &reference) {
+        0 => not_registered(&tenant, &reference),
+        removed => Json(json!({ "removed": removed })).into_response(),
+    }
+}
+
+async fn validate(
+    State(registry): State<Arc<MultiTenantRegistry>>,
+    Path(tenant): Path<String>,
+    Json(request): Json<RegistryValidateRequest>,
+) -> Response {
+    let reference = match request.schema.parse::<SchemaRef>() {
+        Ok(reference) => reference,
+        Err(e) => return error_response(&e),
+    };
+    let loaded = match registry.get(&tenant, &reference).await {
+        Ok(Some(loaded)) => loaded,
+        Ok(None) => return not_registered(&tenant, &reference),
+        Err(e) => return error_response(&e),
+    };
+    match loaded
+        .validate(&request.data, &request.class_name, None)
+        .await
+    {
+        Ok(report) => Json(report).into_response(),
+        Err(e) => error_response(&e),
+    }
+}
+
+fn not_registered(tenant: &str, reference: &SchemaRef) -> Response {
+    let message = format!("Schema '{reference}' is not registered for tenant '{tenant}'");
+    (StatusCode::NOT_FOUND, Json(json!({ "error": message }))).into_response()
+}
+
+fn error_response(error: &LinkMLError) -> Response {
+    let status = match error {
+        LinkMLError::ConfigError(_)
+        | LinkMLError::SchemaValidationError { .. }
+        | LinkMLError::ParseError { .. } => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    (status, Json(json!({ "error": error.to_string() }))).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    async fn call(
+        router: &Router,
+        method: &str,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+            .expect("valid request");
+        let response = router
+            .clone()
+            .oneshot(request)
+            .await
+            .expect("router responds");
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("response body");
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_registry_endpoints() {
+        let router = registry_router(Arc::new(MultiTenantRegistry::new()));
+        let schema = json!({
+            "id": "https://example.org/people",
+            "name": "people",
+            "default_range": "string",
+            "classes": {
+                "Person": {
+                    "name": "Person",
+                    "attributes": {
+                        "name": {"name": "name", "required": true}
+                    }
+                }
+            }
+        });
+
+        let body = json!({ "schema": schema.clone() });
+        let (status, _) = call(&router, "POST", "/tenants/acme/schemas", Some(body)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "no version given");
+
+        let body = json!({ "schema": schema, "version": "1.0.0" });
+        let (status, version) = call(&router, "POST", "/tenants/acme/schemas", Some(body)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(version["tenant"], "acme");
+        assert_eq!(version["version"], "1.0.0");
+
+        let (status, listed) = call(&router, "GET", "/tenants/acme/schemas", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(listed.as_array().map(Vec::len), Some(1));
+        let (status, stored) =
+            call(&router, "GET", "/tenants/acme/schemas/people:1.0.0", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stored["name"], "people");
+
+        let request = json!({
+            "schema": "people:1.0.0",
+            "data": {"age": 3},
+            "class_name": "Person"
+        });
+        let (status, report) = call(
+            &router,
+            "POST",
+            "/tenants/acme/validate",
+            Some(request.clone()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(report["valid"], false);
+        assert_eq!(report["schema_version"]["version"], "1.0.0");
+
+        let (status, _) = call(&router, "POST", "/tenants/other/validate", Some(request)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = call(&router, "DELETE", "/tenants/acme/schemas/people", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = call(&router, "GET", "/tenants/acme/schemas/people", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,562 @@
+//! Registry of named schemas that reload when their source changes
+//!
+//! A [`SchemaRegistry`] loads each registered schema from a file or URL and
+//! keeps the current version behind an atomic pointer. [`SchemaRegistry::watch`]
+//! polls the sources; a changed source is re-parsed and checked before the new
+//! version replaces the old one. Validation requests take a snapshot of the
+//! current version, so requests in flight during a reload finish on the
+//! version they started with, and every report names the version it used.
+//!
+//! A new version is only swapped in if it loads, has no lint errors and a
+//! validation engine can be built for it; otherwise the old version stays
+//! active and the reload reports the error.
+//!
+//! For serve mode and remote clients, a [`MultiTenantRegistry`] holds schemas
+//! registered per tenant under `name:version`, optionally stored through the
+//! storage repositories. Validation requests then name a [`SchemaRef`]
+//! instead of carrying the schema; [`registry_router`] exposes both over
+//! HTTP. Generated artifacts are published to Confluent-compatible
+//! registries through [`confluent`].
+
+pub mod confluent;
+pub mod http;
+pub mod tenants;
+
+pub use confluent::{ArtifactFormat, CompatibilityLevel, ConfluentClient, RegistryPublisher};
+pub use http::registry_router;
+pub use tenants::MultiTenantRegistry;
+
+use arc_swap::ArcSwap;
+use chrono::{DateTime, Utc};
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::parser::SchemaLoader;
+use crate::schema::{LintOptions, SchemaLinter, Severity};
+use crate::validator::cache::ValidatorCacheKey;
+use crate::validator::{ValidationEngine, ValidationOptions, ValidationReport};
+
+/// Where a registered schema is loaded from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaSource {
+    /// Local file or object store location such as `s3://bucket/schema.yaml`
+    File(PathBuf),
+    /// `HTTP(S)` URL
+    Url(String),
+}
+
+impl SchemaSource {
+    /// Source for a location given on the command line or in a request
+    #[must_use]
+    pub fn parse(location: &str) -> Self {
+        if location.starts_with("http://") || location.starts_with("https://") {
+            Self::Url(location.to_string())
+        } else {
+            Self::File(PathBuf::from(location))
+        }
+    }
+}
+
+impl fmt::Display for SchemaSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Url(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+/// Reference to a registered schema as `name:version`, or `name` for its
+/// latest version
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SchemaRef {
+    /// Schema name
+    pub name: String,
+    /// Version; `None` refers to the latest registered version
+    pub version: Option<String>,
+}
+
+impl SchemaRef {
+    /// Reference to the latest version of a schema
+    #[must_use]
+    pub fn latest(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: None,
+        }
+    }
+}
+
+impl FromStr for SchemaRef {
+    type Err = LinkMLError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, version) = match s.split_once(':') {
+            Some((name, version)) => (name, Some(version)),
+            None => (s, None),
+        };
+        if name.is_empty() || version.is_some_and(str::is_empty) {
+            return Err(LinkMLError::config(format!(
+                "Invalid schema reference '{s}', expected 'name' or 'name:version'"
+            )));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            version: version.map(str::to_string),
+        })
+    }
+}
+
+impl fmt::Display for SchemaRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.version {
+            Some(version) => write!(f, "{}:{version}", self.name),
+            None => f.write_str(&self.name),
+        }
+    }
+}
+
+/// Version metadata of a loaded schema, included in validation reports
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaVersion {
+    /// Tenant owning the schema in a [`MultiTenantRegistry`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+    /// Name the schema is registered under
+    pub name: String,
+    /// Registered version, or the version declared by the schema
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Revision, starting at 1 and incremented by every reload
+    pub revision: u64,
+    /// Hash of the schema content
+    pub content_hash: String,
+    /// File, URL or request the version was loaded from
+    pub source: String,
+    /// When the version was loaded
+    pub loaded_at: DateTime<Utc>,
+}
+
+impl SchemaVersion {
+    /// Metadata of a schema loaded now
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        version: Option<String>,
+        revision: u64,
+        schema: &SchemaDefinition,
+        source: impl Into<String>,
+    ) -> Self {
+        Self {
+            tenant: None,
+            name: name.into(),
+            version,
+            revision,
+            content_hash: ValidatorCacheKey::schema_content_hash(schema),
+            source: source.into(),
+            loaded_at: Utc::now(),
+        }
+    }
+
+    /// Reference to this version in `name:version` form
+    #[must_use]
+    pub fn reference(&self) -> SchemaRef {
+        SchemaRef {
+            name: self.name.clone(),
+            version: self.version.clone(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(tenant) = &self.tenant {
+            write!(f, "{tenant}/")?;
+        }
+        let hash = self.content_hash.get(..12).unwrap_or(&self.content_hash);
+        write!(
+            f,
+            "{} r{} ({hash}, {})",
+            self.reference(),
+            self.revision,
+            self.source
+        )
+    }
+}
+
+/// One loaded version of a registered schema
+pub struct LoadedSchema {
+    version: SchemaVersion,
+    schema: Arc<SchemaDefinition>,
+    engine: ValidationEngine,
+}
+
+impl LoadedSchema {
+    /// Check a schema with `linter` and build its validation engine
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema has lint errors or no validation
+    /// engine can be built for it.
+    pub(crate) fn prepare(
+        linter: &SchemaLinter,
+        schema: SchemaDefinition,
+        version: SchemaVersion,
+    ) -> Result<Self> {
+        let lint = linter.lint(&schema)?;
+        let errors: Vec<&str> = lint
+            .issues
+            .iter()
+            .filter(|issue| issue.severity == Severity::Error)
+            .map(|issue| issue.message.as_str())
+            .collect();
+        if !errors.is_empty() {
+            return Err(LinkMLError::schema_validation(format!(
+                "Schema {} from {} has {} lint errors: {}",
+                version.reference(),
+                version.source,
+                errors.len(),
+                errors.join("; ")
+            )));
+        }
+
+        let engine = ValidationEngine::new(&schema)?;
+        Ok(Self {
+            version,
+            schema: Arc::new(schema),
+            engine,
+        })
+    }
+
+    /// Version metadata
+    #[must_use]
+    pub fn version(&self) -> &SchemaVersion {
+        &self.version
+    }
+
+    /// The schema of this version
+    #[must_use]
+    pub fn schema(&self) -> &Arc<SchemaDefinition> {
+        &self.schema
+    }
+
+    /// Validate data as an instance of a class of this version
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class does not exist or validation fails to run.
+    pub async fn validate(
+        &self,
+        data: &Value,
+        class_name: &str,
+        options: Option<ValidationOptions>,
+    ) -> Result<ValidationReport> {
+        let mut report = self
+            .engine
+            .validate_as_class(data, class_name, options)
+            .await?;
+        report.schema_version = Some(self.version.clone());
+        Ok(report)
+    }
+}
+
+/// Modification time and size of a file source, to skip unchanged files
+type FileStamp = (SystemTime, u64);
+
+struct RegisteredSchema {
+    source: SchemaSource,
+    current: ArcSwap<LoadedSchema>,
+    stamp: Mutex<Option<FileStamp>>,
+    /// Serializes reloads of this schema
+    reloading: tokio::sync::Mutex<()>,
+}
+
+/// Registry of named schemas with hot reloading
+pub struct SchemaRegistry {
+    loader: SchemaLoader,
+    linter: SchemaLinter,
+    schemas: RwLock<HashMap<String, Arc<RegisteredSchema>>>,
+}
+
+impl Default for SchemaRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SchemaRegistry {
+    /// Create an empty registry
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_loader(SchemaLoader::new())
+    }
+
+    /// Create an empty registry loading schemas with the given loader
+    #[must_use]
+    pub fn with_loader(loader: SchemaLoader) -> Self {
+        Self {
+            loader,
+            linter: SchemaLinter::new(LintOptions::default()),
+            schemas: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Check new versions with the given linter instead of the recommended
+    /// rules; versions with lint errors are not loaded
+    #[must_use]
+    pub fn with_linter(mut self, linter: SchemaLinter) -> Self {
+        self.linter = linter;
+        self
+    }
+
+    /// Load a schema and register it under `name` as version 1
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is taken, or the schema cannot be loaded
+    /// or fails its checks.
+    pub async fn register(&self, name: &str, source: SchemaSource) -> Result<SchemaVersion> {
+        if self.schemas.read().contains_key(name) {
+            return Err(LinkMLError::service(format!(
+                "Schema '{name}' is already registered"
+            )));
+        }
+
+        let stamp = file_stamp(&source).await;
+        let schema = self.load(&source).await?;
+        let version =
+            SchemaVersion::new(name, schema.version.clone(), 1, &schema, source.to_string());
+        let loaded = LoadedSchema::prepare(&self.linter, schema, version.clone())?;
+
+        let registered = Arc::new(RegisteredSchema {
+            source,
+            current: ArcSwap::from_pointee(loaded),
+            stamp: Mutex::new(stamp),
+            reloading: tokio::sync::Mutex::new(()),
+        });
+        let mut schemas = self.schemas.write();
+        if schemas.contains_key(name) {
+            return Err(LinkMLError::service(format!(
+                "Schema '{name}' is already registered"
+            )));
+        }
+        schemas.insert(name.to_string(), registered);
+        drop(schemas);
+
+        info!("Registered schema {version}");
+        Ok(version)
+    }
+
+    /// Remove a schema; validations already running on it still finish
+    pub fn unregister(&self, name: &str) -> bool {
+        self.schemas.write().remove(name).is_some()
+    }
+
+    /// Snapshot of the current version of a schema
+    ///
+    /// The snapshot stays valid after a reload, so a caller can run several
+    /// validations against one consistent version.
+    #[must_use]
+    pub fn current(&self, name: &str) -> Option<Arc<LoadedSchema>> {
+        self.schemas
+            .read()
+            .get(name)
+            .map(|registered| registered.current.load_full())
+    }
+
+    /// Current versions of all registered schemas, sorted by name
+    #[must_use]
+    pub fn versions(&self) -> Vec<SchemaVersion> {
+        let mut versions: Vec<SchemaVersion> = self
+            .schemas
+            .read()
+            .values()
+            .map(|registered| registered.current.load().version.clone())
+            .collect();
+        versions.sort_by(|a, b| a.name.cmp(&b.name));
+        versions
+    }
+
+    /// Validate data against the current version of a registered schema
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no schema has this name, the class does not exist
+    /// or validation fails to run.
+    pub async fn validate(
+        &self,
+        name: &str,
+        data: &Value,
+        class_name: &str,
+        options: Option<ValidationOptions>,
+    ) -> Result<ValidationReport> {
+        let loaded = self
+            .current(name)
+            .ok_or_else(|| LinkMLError::service(format!("Schema '{name}' is not registered")))?;
+        loaded.validate(data, class_name, options).await
+    }
+
+    /// Reload a schema if its source changed
+    ///
+    /// Returns the new version after a swap, or `None` if the content is
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no schema has this name, or the changed schema
+    /// cannot be loaded or fails its checks; the previous version then stays
+    /// active.
+    pub async fn reload(&self, name: &str) -> Result<Option<SchemaVersion>> {
+        let registered =
+            self.schemas.read().get(name).cloned().ok_or_else(|| {
+                LinkMLError::service(format!("Schema '{name}' is not registered"))
+            })?;
+        let _reloading = registered.reloading.lock().await;
+
+        let stamp = file_stamp(&registered.source).await;
+        if stamp.is_some() && stamp == *registered.stamp.lock() {
+            return Ok(None);
+        }
+
+        let schema = self.load(&registered.source).await?;
+        let current = registered.current.load_full();
+        let version = SchemaVersion::new(
+            name,
+            schema.version.clone(),
+            current.version.revision + 1,
+            &schema,
+            registered.source.to_string(),
+        );
+        if version.content_hash == current.version.content_hash {
+            *registered.stamp.lock() = stamp;
+            return Ok(None);
+        }
+
+        let loaded = LoadedSchema::prepare(&self.linter, schema, version.clone())?;
+        registered.current.store(Arc::new(loaded));
+        *registered.stamp.lock() = stamp;
+
+        info!("Reloaded schema {version}");
+        Ok(Some(version))
+    }
+
+    /// Reload every registered schema whose source changed
+    ///
+    /// Returns the outcome per schema name, sorted by name.
+    pub async fn reload_all(&self) -> Vec<(String, Result<Option<SchemaVersion>>)> {
+        let mut names: Vec<String> = self.schemas.read().keys().cloned().collect();
+        names.sort();
+
+        let mut outcomes = Vec::with_capacity(names.len());
+        for name in names {
+            let outcome = self.reload(&name).await;
+            outcomes.push((name, outcome));
+        }
+        outcomes
+    }
+
+    /// Poll the sources of all registered schemas every `interval`
+    ///
+    /// Failed reloads are logged and retried at the next poll. Abort the
+    /// returned task to stop watching.
+    pub fn watch(self: Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                for (name, outcome) in self.reload_all().await {
+                    if let Err(e) = outcome {
+                        warn!("Keeping current version of schema '{name}': {e}");
+                    }
+                }
+            }
+        })
+    }
+
+    async fn load(&self, source: &SchemaSource) -> Result<SchemaDefinition> {
+        match source {
+            SchemaSource::File(path) => self.loader.load_file(path).await,
+            SchemaSource::Url(url) => self.loader.load_url(url).await,
+        }
+    }
+}
+
+/// Modification time and size of a local file source
+async fn file_stamp(source: &SchemaSource) -> Option<FileStamp> {
+    let SchemaSource::File(path) = source else {
+        return None;
+    };
+    let metadata = tokio::fs::metadata(path).await.ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SCHEMA: &str = "id: https://example.org/people
+name: people
+default_range: string
+classes:
+  Person:
+    attributes:
+      name:
+        required: true
+      age:
+        description: Age in years
+";
+
+    #[tokio::test]
+    async fn test_reload_swaps_version() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("people.yaml");
+        std::fs::write(&path, SCHEMA)?;
+
+        let registry = SchemaRegistry::new();
+        let version = registry
+            .register("people", SchemaSource::File(path.clone()))
+            .await?;
+        assert_eq!(version.revision, 1);
+        assert!(registry.reload("people").await?.is_none());
+
+        let data = json!({"name": "Ada", "age": "three"});
+        let report = registry.validate("people", &data, "Person", None).await?;
+        assert!(report.valid);
+        assert_eq!(report.schema_version.map(|v| v.revision), Some(1));
+
+        // Requests that took the old version keep it across the swap
+        let in_flight = registry.current("people").expect("registered");
+        std::fs::write(
+            &path,
+            SCHEMA.replace("description: Age in years", "range: integer"),
+        )?;
+        let reloaded = registry.reload("people").await?.expect("changed schema");
+        assert_eq!(reloaded.revision, 2);
+        assert_ne!(reloaded.content_hash, version.content_hash);
+
+        let report = registry.validate("people", &data, "Person", None).await?;
+        assert!(!report.valid);
+        assert_eq!(report.schema_version.map(|v| v.revision), Some(2));
+        let report = in_flight.validate(&data, "Person", None).await?;
+        assert_eq!(report.schema_version.map(|v| v.revision), Some(1));
+
+        // A broken version is rejected and the current one stays active
+        std::fs::write(&path, "classes: [")?;
+        assert!(registry.reload("people").await.is_err());
+        assert_eq!(registry.versions()[0].revision, 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,378 @@
+//! Schemas registered per tenant under `name:version`
+//!
+//! Clients register a schema once and then validate against it by reference,
+//! without shipping the schema with every request. Each tenant has its own
+//! namespace of names. With a [`SchemaRepository`] every registration is also
+//! stored, so a restarted service finds registered schemas again on first use.
+
+use indexmap::IndexMap;
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
+use parking_lot::RwLock;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::info;
+
+use super::{LoadedSchema, SchemaRef, SchemaVersion};
+use crate::schema::{LintOptions, SchemaLinter};
+use crate::storage::SchemaRepository;
+use crate::validator::{ValidationOptions, ValidationReport};
+
+/// Versions of one schema name in registration order; the last is the latest
+type Versions = IndexMap<String, Arc<LoadedSchema>>;
+
+/// Schema registry with a separate namespace per tenant
+pub struct MultiTenantRegistry {
+    linter: SchemaLinter,
+    repository: Option<SchemaRepository>,
+    tenants: RwLock<HashMap<String, HashMap<String, Versions>>>,
+}
+
+impl Default for MultiTenantRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MultiTenantRegistry {
+    /// Create an empty registry that keeps schemas in memory only
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            linter: SchemaLinter::new(LintOptions::default()),
+            repository: None,
+            tenants: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Check registered schemas with the given linter instead of the
+    /// recommended rules; schemas with lint errors are rejected
+    #[must_use]
+    pub fn with_linter(mut self, linter: SchemaLinter) -> Self {
+        self.linter = linter;
+        self
+    }
+
+    /// Store registrations in `repository` and load schemas missing from
+    /// memory from it
+    #[must_use]
+    pub fn with_repository(mut self, repository: SchemaRepository) -> Self {
+        self.repository = Some(repository);
+        self
+    }
+
+    /// Register a schema for a tenant
+    ///
+    /// The version defaults to the one declared by the schema. Registering
+    /// the same content again under a name and version is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tenant name is invalid, no version is given
+    /// or declared, the name and version are taken by different content, the
+    /// schema fails its checks, or storing it fails.
+    pub async fn register(
+        &self,
+        tenant: &str,
+        mut schema: SchemaDefinition,
+        version: Option<&str>,
+    ) -> Result<SchemaVersion> {
+        check_tenant(tenant)?;
+        let version = version
+            .map(str::to_string)
+            .or_else(|| schema.version.clone())
+            .ok_or_else(|| {
+                LinkMLError::config(format!(
+                    "Schema '{}' declares no version and none was given",
+                    schema.name
+                ))
+            })?;
+        schema.version = Some(version.clone());
+        let reference = SchemaRef {
+            name: schema.name.clone(),
+            version: Some(version.clone()),
+        };
+
+        let mut metadata = SchemaVersion::new(
+            &schema.name,
+            Some(version.clone()),
+            1,
+            &schema,
+            "registration",
+        );
+        metadata.tenant = Some(tenant.to_string());
+        if let Some(existing) = self.lookup(tenant, &reference) {
+            return same_content(existing.version(), &metadata);
+        }
+
+        let stored = storage_schema(tenant, &schema);
+        let loaded = LoadedSchema::prepare(&self.linter, schema, metadata.clone())?;
+        if let Some(repository) = &self.repository {
+            repository.save(&stored, metadata.loaded_at).await?;
+        }
+        let loaded = self.insert(tenant, loaded)?;
+        info!("Registered schema {}", loaded.version());
+        Ok(loaded.version().clone())
+    }
+
+    /// Schema of a tenant held in memory
+    #[must_use]
+    pub fn lookup(&self, tenant: &str, reference: &SchemaRef) -> Option<Arc<LoadedSchema>> {
+        let tenants = self.tenants.read();
+        let versions = tenants.get(tenant)?.get(&reference.name)?;
+        match &reference.version {
+            Some(version) => versions.get(version).cloned(),
+            None => versions.last().map(|(_, loaded)| Arc::clone(loaded)),
+        }
+    }
+
+    /// Schema of a tenant, loaded from the repository if not held in memory
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the repository fails or the stored schema
+    /// fails its checks.
+    pub async fn get(
+        &self,
+        tenant: &str,
+        reference: &SchemaRef,
+    ) -> Result<Option<Arc<LoadedSchema>>> {
+        if let Some(loaded) = self.lookup(tenant, reference) {
+            return Ok(Some(loaded));
+        }
+        let Some(repository) = &self.repository else {
+            return Ok(None);
+        };
+        let storage_name = format!("{tenant}/{}", reference.name);
+        let Some(stored) = repository
+            .latest(&storage_name, reference.version.as_deref())
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let mut schema = stored.schema;
+        schema.name.clone_from(&reference.name);
+        let mut metadata =
+            SchemaVersion::new(&schema.name, stored.version, 1, &schema, "repository");
+        metadata.tenant = Some(tenant.to_string());
+        let loaded = LoadedSchema::prepare(&self.linter, schema, metadata)?;
+        self.insert(tenant, loaded).map(Some)
+    }
+
+    /// Validate data against a registered schema of a tenant
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema is not registered, the class does not
+    /// exist or validation fails to run.
+    pub async fn validate(
+        &self,
+        tenant: &str,
+        reference: &SchemaRef,
+        data: &Value,
+        class_name: &str,
+        options: Option<ValidationOptions>,
+    ) -> Result<ValidationReport> {
+        let loaded = self.get(tenant, reference).await?.ok_or_else(|| {
+            LinkMLError::service(format!(
+                "Schema '{reference}' is not registered for tenant '{tenant}'"
+            ))
+        })?;
+        loaded.validate(data, class_name, options).await
+    }
+
+    /// Schemas of a tenant held in memory, sorted by name in registration
+    /// order per name
+    #[must_use]
+    pub fn list(&self, tenant: &str) -> Vec<SchemaVersion> {
+        let tenants = self.tenants.read();
+        let Some(schemas) = tenants.get(tenant) else {
+            return Vec::new();
+        };
+        let mut names: Vec<&String> = schemas.keys().collect();
+        names.sort();
+        names
+            .into_iter()
+            .flat_map(|name| schemas[name].values())
+            .map(|loaded| loaded.version().clone())
+            .collect()
+    }
+
+    /// Tenants with registered schemas, sorted
+    #[must_use]
+    pub fn tenants(&self) -> Vec<String> {
+        let mut tenants: Vec<String> = self.tenants.read().keys().cloned().collect();
+        tenants.sort();
+        tenants
+    }
+
+    /// Remove a version, or all versions of a name, from memory
+    ///
+    /// Stored registrations are kept. Returns the number of removed versions.
+    pub fn remove(&self, tenant: &str, reference: &SchemaRef) -> usize {
+        let mut tenants = self.tenants.write();
+        let Some(schemas) = tenants.get_mut(tenant) else {
+            return 0;
+        };
+        let removed = match &reference.version {
+            Some(version) => schemas.get_mut(&reference.name).map_or(0, |versions| {
+                usize::from(versions.shift_remove(version).is_some())
+            }),
+            None => schemas
+                .remove(&reference.name)
+                .map_or(0, |versions| versions.len()),
+        };
+        schemas.retain(|_, versions| !versions.is_empty());
+        if schemas.is_empty() {
+            tenants.remove(tenant);
+        }
+        removed
+    }
+
+    /// Add a loaded schema unless a concurrent registration added its
+    /// version first; returns the version held afterwards
+    fn insert(&self, tenant: &str, loaded: LoadedSchema) -> Result<Arc<LoadedSchema>> {
+        let metadata = loaded.version().clone();
+        let version = metadata.version.clone().unwrap_or_default();
+        let mut tenants = self.tenants.write();
+        let versions = tenants
+            .entry(tenant.to_string())
+            .or_default()
+            .entry(metadata.name.clone())
+            .or_default();
+        if let Some(existing) = versions.get(&version) {
+            let existing = Arc::clone(existing);
+            drop(tenants);
+            same_content(existing.version(), &metadata)?;
+            return Ok(existing);
+        }
+        let loaded = Arc::new(loaded);
+        versions.insert(version, Arc::clone(&loaded));
+        Ok(loaded)
+    }
+}
+
+/// `existing` if `registered` has the same content, a conflict otherwise
+fn same_content(existing: &SchemaVersion, registered: &SchemaVersion) -> Result<SchemaVersion> {
+    if existing.content_hash == registered.content_hash {
+        Ok(existing.clone())
+    } else {
+        Err(LinkMLError::config(format!(
+            "Schema '{}' is already registered for tenant '{}' with different content",
+            existing.reference(),
+            registered.tenant.as_deref().unwrap_or_default()
+        )))
+    }
+}
+
+fn check_tenant(tenant: &str) -> Result<()> {
+    if tenant.is_empty() || tenant.contains('/') {
+        return Err(LinkMLError::config(format!(
+            "Invalid tenant name '{tenant}', expected a non-empty name without '/'"
+        )));
+    }
+    Ok(())
+}
+
+/// Schema as stored in the repository, named `tenant/name` to keep tenants
+/// apart
+fn storage_schema(tenant: &str, schema: &SchemaDefinition) -> SchemaDefinition {
+    let mut stored = schema.clone();
+    stored.name = format!("{tenant}/{}", schema.name);
+    stored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use crate::storage::FileRecordStore;
+    use serde_json::json;
+
+    fn schema(age_range: &str) -> SchemaDefinition {
+        let yaml = format!(
+            "id: https://example.org/people
+name: people
+version: 1.0.0
+default_range: string
+classes:
+  Person:
+    attributes:
+      name:
+        required: true
+      age:
+        range: {age_range}
+"
+        );
+        Parser::new()
+            .parse_str(&yaml, "yaml")
+            .expect("valid schema")
+    }
+
+    #[tokio::test]
+    async fn test_register_and_validate_by_reference() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let repository = SchemaRepository::new(Arc::new(FileRecordStore::new(dir.path())));
+        let registry = MultiTenantRegistry::new().with_repository(repository.clone());
+
+        let v1 = registry.register("acme", schema("string"), None).await?;
+        assert_eq!(v1.to_string().split(' ').next(), Some("acme/people:1.0.0"));
+        let again = registry.register("acme", schema("string"), None).await?;
+        assert_eq!(again.content_hash, v1.content_hash);
+        assert!(
+            registry
+                .register("acme", schema("integer"), None)
+                .await
+                .is_err()
+        );
+        registry
+            .register("acme", schema("integer"), Some("2.0.0"))
+            .await?;
+        assert!(
+            registry
+                .register("a/b", schema("string"), None)
+                .await
+                .is_err()
+        );
+
+        let data = json!({"name": "Ada", "age": "three"});
+        let pinned: SchemaRef = "people:1.0.0".parse()?;
+        let report = registry
+            .validate("acme", &pinned, &data, "Person", None)
+            .await?;
+        assert!(report.valid);
+        let latest = SchemaRef::latest("people");
+        let report = registry
+            .validate("acme", &latest, &data, "Person", None)
+            .await?;
+        assert!(!report.valid);
+        assert_eq!(
+            report.schema_version.and_then(|v| v.version).as_deref(),
+            Some("2.0.0")
+        );
+
+        // Tenants do not see each other's schemas
+        assert!(
+            registry
+                .validate("other", &pinned, &data, "Person", None)
+                .await
+                .is_err()
+        );
+        assert_eq!(registry.tenants(), ["acme"]);
+        assert_eq!(registry.list("acme").len(), 2);
+
+        // A new instance finds registrations in the repository
+        let restarted = MultiTenantRegistry::new().with_repository(repository);
+        let loaded = restarted
+            .get("acme", &pinned)
+            .await?
+            .expect("stored schema");
+        assert_eq!(loaded.schema().name, "people");
+        assert_eq!(loaded.version().source, "repository");
+        assert_eq!(restarted.remove("acme", &SchemaRef::latest("people")), 1);
+        assert!(restarted.lookup("acme", &pinned).is_none());
+        Ok(())
+    }
+}
@@ -191,6 +191,16 @@ impl ClassView {
             .collect()
     }
 
+    /// Get recommended slots for this class
+    #[must_use]
+    pub fn recommended_slots(&self) -> Vec<&str> {
+        self.resolved_slots
+            .iter()
+            .filter(|(_, slot)| slot.recommended.unwrap_or(false))
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
     /// Get optional slots for this class
     #[must_use]
     pub fn optional_slots(&self) -> Vec<&str> {
@@ -7,13 +7,24 @@
 pub mod analysis;
 pub mod class_view;
 pub mod navigation;
+pub mod search;
 pub mod slot_view;
+pub mod status;
 pub mod view;
+pub mod workspace;
 
 pub use class_view::{ClassView, ClassViewBuilder};
 pub use slot_view::{SlotView, SlotViewBuilder};
+pub use status::{
+    Deprecation, DraftPolicy, ElementStatus, deprecated_elements, draft_elements, without_drafts,
+};
 pub use view::{ElementType, SchemaView, SchemaViewError};
+pub use workspace::{
+    AlignmentEvidence, AlignmentSuggestion, MappingOverlap, MappingReference, MappingRelation,
+    SharedClassName, SharedPrefix, WorkspaceElement, WorkspaceView,
+};
 
 // Re-export commonly used types
 pub use analysis::{SchemaStatistics, UsageInfo};
 pub use navigation::{InheritanceChain, SlotResolution};
+pub use search::{SearchField, SearchHit, SearchIndex, SearchOptions};
@@ -220,6 +220,18 @@ impl<'a> SlotResolution<'a> {
         Ok(slot.required.unwrap_or(false))
     }
 
+    /// Check if a slot is recommended in a class context
+    /// Returns an error if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaViewError::ElementNotFound` if the slot is not found
+    /// Returns schema view errors if slot resolution fails
+    pub fn is_recommended(&self, slot_name: &str, class_name: &str) -> Result<bool> {
+        let slot = self.resolve_slot(slot_name, class_name)?;
+        Ok(slot.recommended.unwrap_or(false))
+    }
+
     /// Check if a slot is multivalued in a class context
     /// Returns an error if the operation fails
     ///
@@ -237,6 +249,9 @@ impl<'a> SlotResolution<'a> {
         if usage.required.is_some() {
             slot.required = usage.required;
         }
+        if usage.recommended.is_some() {
+            slot.recommended = usage.recommended;
+        }
         if usage.multivalued.is_some() {
             slot.multivalued = usage.multivalued;
         }
@@ -0,0 +1,717 @@
+//! Ranked fuzzy search over schema elements
+//!
+//! Classes, slots, types, enums and subsets are indexed by name, title,
+//! aliases, description and mapping CURIEs. Queries are matched exactly, by
+//! prefix, by substring and fuzzily (Jaro-Winkler over normalized tokens), with
+//! optional synonym expansion. The same index backs `SchemaView::search`, the
+//! `linkml search` command, editor workspace symbols and the search index
+//! shipped with generated documentation.
+
+use linkml_core::annotations::{AnnotationValue, Annotations};
+use linkml_core::types::{PermissibleValue, SchemaDefinition};
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use super::view::ElementType;
+
+/// Schema element field a search hit matched on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchField {
+    /// Element name
+    Name,
+    /// `title` annotation
+    Title,
+    /// Alias
+    Alias,
+    /// Description text
+    Description,
+    /// Mapping CURIE or URI (`class_uri`, `exact_mappings`, `meaning`, ...)
+    Mapping,
+}
+
+impl SearchField {
+    /// Relative weight of a match on this field
+    fn weight(self) -> f64 {
+        match self {
+            Self::Name => 1.0,
+            Self::Title => 0.95,
+            Self::Alias | Self::Mapping => 0.9,
+            Self::Description => 0.7,
+        }
+    }
+}
+
+/// A single ranked search result
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SearchHit {
+    /// Element name
+    pub name: String,
+    /// Element type
+    pub element_type: ElementType,
+    /// Field that produced the best match
+    pub field: SearchField,
+    /// Text of the matched field
+    pub matched_text: String,
+    /// Relevance score in `0.0..=1.0`
+    pub score: f64,
+}
+
+/// Options controlling a schema search
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    /// Maximum number of hits returned
+    pub limit: usize,
+    /// Minimum score for a hit to be returned
+    pub min_score: f64,
+    /// Restrict results to these element types (empty means all)
+    pub element_types: Vec<ElementType>,
+    /// Whether descriptions are searched
+    pub include_descriptions: bool,
+    /// Synonym groups used to expand queries (term -> synonyms)
+    pub synonyms: HashMap<String, Vec<String>>,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            limit: 20,
+            min_score: 0.6,
+            element_types: Vec::new(),
+            include_descriptions: true,
+            synonyms: HashMap::new(),
+        }
+    }
+}
+
+impl SearchOptions {
+    /// Set the maximum number of hits
+    #[must_use]
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the minimum score
+    #[must_use]
+    pub fn with_min_score(mut self, min_score: f64) -> Self {
+        self.min_score = min_score;
+        self
+    }
+
+    /// Restrict results to the given element types
+    #[must_use]
+    pub fn with_element_types(mut self, element_types: Vec<ElementType>) -> Self {
+        self.element_types = element_types;
+        self
+    }
+
+    /// Set whether descriptions are searched
+    #[must_use]
+    pub fn with_descriptions(mut self, include: bool) -> Self {
+        self.include_descriptions = include;
+        self
+    }
+
+    /// Register synonyms for a term
+    #[must_use]
+    pub fn with_synonyms(
+        mut self,
+        term: impl Into<String>,
+        synonyms: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.synonyms
+            .entry(term.into().to_lowercase())
+            .or_default()
+            .extend(synonyms.into_iter().map(|s| s.into().to_lowercase()));
+        self
+    }
+
+    /// The query followed by all of its synonyms (in both directions)
+    fn expand(&self, query: &str) -> Vec<String> {
+        let query = query.trim().to_lowercase();
+        let mut expanded = vec![query.clone()];
+        for (term, synonyms) in &self.synonyms {
+            if *term == query || synonyms.contains(&query) {
+                for candidate in std::iter::once(term).chain(synonyms) {
+                    if !expanded.contains(candidate) {
+                        expanded.push(candidate.clone());
+                    }
+                }
+            }
+        }
+        expanded
+    }
+}
+
+/// Indexed schema element
+#[derive(Debug, Clone)]
+struct IndexEntry {
+    name: String,
+    element_type: ElementType,
+    fields: Vec<(SearchField, String)>,
+}
+
+/// Search index over all elements of a schema
+#[derive(Debug, Clone, Default)]
+pub struct SearchIndex {
+    entries: Vec<IndexEntry>,
+}
+
+impl SearchIndex {
+    /// Build an index for a schema
+    #[must_use]
+    pub fn build(schema: &SchemaDefinition) -> Self {
+        let mut entries = Vec::new();
+
+        for (name, class) in &schema.classes {
+            let mut fields = Self::common_fields(
+                name,
+                class.annotations.as_ref(),
+                &class.aliases,
+                class.description.as_deref(),
+            );
+            let mappings = class
+                .class_uri
+                .iter()
+                .chain(&class.exact_mappings)
+                .chain(&class.close_mappings)
+                .chain(&class.related_mappings)
+                .chain(&class.narrow_mappings)
+                .chain(&class.broad_mappings);
+            fields.extend(mappings.map(|m| (SearchField::Mapping, m.clone())));
+            entries.push(IndexEntry {
+                name: name.clone(),
+                element_type: ElementType::Class,
+                fields,
+            });
+        }
+
+        for (name, slot) in &schema.slots {
+            let mut fields = Self::common_fields(
+                name,
+                slot.annotations.as_ref(),
+                &slot.aliases,
+                slot.description.as_deref(),
+            );
+            let mappings = slot
+                .slot_uri
+                .iter()
+                .chain(&slot.exact_mappings)
+                .chain(&slot.close_mappings)
+                .chain(&slot.related_mappings)
+                .chain(&slot.narrow_mappings)
+                .chain(&slot.broad_mappings);
+            fields.extend(mappings.map(|m| (SearchField::Mapping, m.clone())));
+            entries.push(IndexEntry {
+                name: name.clone(),
+                element_type: ElementType::Slot,
+                fields,
+            });
+        }
+
+        for (name, type_def) in &schema.types {
+            let mut fields = Self::common_fields(
+                name,
+                type_def.annotations.as_ref(),
+                &[],
+                type_def.description.as_deref(),
+            );
+            fields.extend(
+                type_def
+                    .uri
+                    .iter()
+                    .map(|uri| (SearchField::Mapping, uri.clone())),
+            );
+            entries.push(IndexEntry {
+                name: name.clone(),
+                element_type: ElementType::Type,
+                fields,
+            });
+        }
+
+        for (name, enum_def) in &schema.enums {
+            let mut fields = Self::common_fields(
+                name,
+                enum_def.annotations.as_ref(),
+                &[],
+                enum_def.description.as_deref(),
+            );
+            for pv in &enum_def.permissible_values {
+                if let PermissibleValue::Complex {
+                    meaning: Some(meaning),
+                    ..
+                } = pv
+                {
+                    fields.push((SearchField::Mapping, meaning.clone()));
+                }
+            }
+            entries.push(IndexEntry {
+                name: name.clone(),
+                element_type: ElementType::Enum,
+                fields,
+            });
+        }
+
+        for (name, subset) in &schema.subsets {
+            entries.push(IndexEntry {
+                name: name.clone(),
+                element_type: ElementType::Subset,
+                fields: Self::common_fields(name, None, &[], subset.description.as_deref()),
+            });
+        }
+
+        Self { entries }
+    }
+
+    /// Number of indexed elements
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the index is empty
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Search the index, returning hits ordered by descending score
+    #[must_use]
+    pub fn search(&self, query: &str, options: &SearchOptions) -> Vec<SearchHit> {
+        let queries = options.expand(query);
+        if queries.iter().all(|q| normalize(q).is_empty()) {
+            return Vec::new();
+        }
+
+        let mut hits: Vec<SearchHit> = self
+            .entries
+            .iter()
+            .filter(|entry| {
+                options.element_types.is_empty()
+                    || options.element_types.contains(&entry.element_type)
+            })
+            .filter_map(|entry| Self::best_hit(entry, &queries, options))
+            .filter(|hit| hit.score >= options.min_score)
+            .collect();
+
+        hits.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        hits.truncate(options.limit);
+        hits
+    }
+
+    /// Export the index as JSON documents for a static documentation search page
+    ///
+    /// Each document carries an `id` anchor, the element name and type, and
+    /// the searchable text grouped by field.
+    #[must_use]
+    pub fn to_docs_index(&self) -> Value {
+        self.to_docs_index_with(|element_type, name| {
+            Some(format!(
+                "{}-{}",
+                element_type_label(element_type),
+                name.to_lowercase()
+            ))
+        })
+    }
+
+    /// Export the index with the `id` anchors of a particular documentation
+    /// generator
+    ///
+    /// Elements for which `anchor` returns `None` have no page section to
+    /// link to and are left out.
+    #[must_use]
+    pub fn to_docs_index_with(
+        &self,
+        anchor: impl Fn(ElementType, &str) -> Option<String>,
+    ) -> Value {
+        let documents: Vec<Value> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let id = anchor(entry.element_type, &entry.name)?;
+                let collect = |field: SearchField| -> Vec<&str> {
+                    entry
+                        .fields
+                        .iter()
+                        .filter(|(f, _)| *f == field)
+                        .map(|(_, text)| text.as_str())
+                        .collect()
+                };
+                Some(json!({
+                    "id": id,
+                    "name": entry.name,
+                    "type": element_type_label(entry.element_type),
+                    "title": collect(SearchField::Title).first(),
+                    "aliases": collect(SearchField::Alias),
+                    "description": collect(SearchField::Description).first(),
+                    "mappings": collect(SearchField::Mapping),
+                }))
+            })
+            .collect();
+        json!({ "version": 1, "documents": documents })
+    }
+
+    /// Names and types of all indexed elements, in index order
+    pub fn elements(&self) -> impl Iterator<Item = (&str, ElementType)> {
+        self.entries
+            .iter()
+            .map(|entry| (entry.name.as_str(), entry.element_type))
+    }
+
+    fn common_fields(
+        name: &str,
+        annotations: Option<&Annotations>,
+        aliases: &[String],
+        description: Option<&str>,
+    ) -> Vec<(SearchField, String)> {
+        let mut fields = vec![(SearchField::Name, name.to_string())];
+        if let Some(AnnotationValue::String(title)) =
+            annotations.and_then(|annotations| annotations.get("title"))
+        {
+            fields.push((SearchField::Title, title.clone()));
+        }
+        fields.extend(aliases.iter().map(|a| (SearchField::Alias, a.clone())));
+        if let Some(description) = description {
+            fields.push((SearchField::Description, description.to_string()));
+        }
+        fields
+    }
+
+    fn best_hit(
+        entry: &IndexEntry,
+        queries: &[String],
+        options: &SearchOptions,
+    ) -> Option<SearchHit> {
+        let mut best: Option<SearchHit> = None;
+
+        for (index, query) in queries.iter().enumerate() {
+            // Matches via a synonym rank slightly below direct matches
+            let query_weight = if index == 0 { 1.0 } else { 0.9 };
+            for (field, text) in &entry.fields {
+                if *field == SearchField::Description && !options.include_descriptions {
+                    continue;
+                }
+                let score = match field {
+                    SearchField::Description => score_description(query, text),
+                    SearchField::Mapping => score_mapping(query, text),
+                    _ => score_term(query, text),
+                } * field.weight()
+                    * query_weight;
+
+                if score > 0.0 && best.as_ref().is_none_or(|hit| score > hit.score) {
+                    best = Some(SearchHit {
+                        name: entry.name.clone(),
+                        element_type: entry.element_type,
+                        field: *field,
+                        matched_text: text.clone(),
+                        score,
+                    });
+                }
+            }
+        }
+
+        best
+    }
+}
+
+/// Label used for element types in exported indexes and CLI output
+#[must_use]
+pub fn element_type_label(element_type: ElementType) -> &'static str {
+    match element_type {
+        ElementType::Class => "class",
+        ElementType::Slot => "slot",
+        ElementType::Type => "type",
+        ElementType::Enum => "enum",
+        ElementType::Subset => "subset",
+    }
+}
+
+/// Split camelCase, snake_case, kebab-case and punctuation into lowercase tokens
+pub(crate) fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            if c.is_uppercase() && previous_lower && !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            previous_lower = c.is_lowercase() || c.is_ascii_digit();
+            current.extend(c.to_lowercase());
+        } else {
+            previous_lower = false;
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// Normalized form: lowercase tokens joined by single spaces
+fn normalize(text: &str) -> String {
+    tokenize(text).join(" ")
+}
+
+/// Score a short term (name, title, alias) against a query
+fn score_term(query: &str, text: &str) -> f64 {
+    let query = normalize(query);
+    let text = normalize(text);
+    if query.is_empty() || text.is_empty() {
+        return 0.0;
+    }
+
+    if text == query {
+        return 1.0;
+    }
+    if text.starts_with(&query) {
+        return 0.9;
+    }
+    if text.split(' ').any(|token| token.starts_with(&query)) {
+        return 0.85;
+    }
+    if text.contains(&query) {
+        return 0.8;
+    }
+
+    // Fuzzy: whole-string similarity or average best per-token similarity
+    let whole = strsim::jaro_winkler(&query, &text);
+    let text_tokens: Vec<&str> = text.split(' ').collect();
+    let query_tokens: Vec<&str> = query.split(' ').collect();
+    #[allow(clippy::cast_precision_loss)]
+    let per_token = query_tokens
+        .iter()
+        .map(|q| {
+            text_tokens
+                .iter()
+                .map(|t| strsim::jaro_winkler(q, t))
+                .fold(0.0_f64, f64::max)
+        })
+        .sum::<f64>()
+        / query_tokens.len() as f64;
+
+    // Fuzzy matches never outrank literal ones
+    whole.max(per_token) * 0.78
+}
+
+/// Score a mapping CURIE/URI: the full value or its local part
+fn score_mapping(query: &str, mapping: &str) -> f64 {
+    if mapping.eq_ignore_ascii_case(query.trim()) {
+        return 1.0;
+    }
+    let local = mapping.rsplit([':', '/', '#']).next().unwrap_or(mapping);
+    // Only literal matches on mappings; fuzzy CURIE matches are noise
+    let score = score_term(query, local);
+    if score >= 0.8 { score } else { 0.0 }
+}
+
+/// Score free text by the fraction of query tokens it contains
+fn score_description(query: &str, text: &str) -> f64 {
+    let query_tokens = tokenize(query);
+    if query_tokens.is_empty() {
+        return 0.0;
+    }
+    let text_tokens = tokenize(text);
+
+    let matched = query_tokens
+        .iter()
+        .filter(|q| {
+            text_tokens
+                .iter()
+                .any(|t| t == *q || (q.len() >= 4 && strsim::jaro_winkler(q, t) >= 0.92))
+        })
+        .count();
+
+    #[allow(clippy::cast_precision_loss)]
+    let fraction = matched as f64 / query_tokens.len() as f64;
+    fraction * 0.9
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::{ClassDefinition, EnumDefinition, SlotDefinition};
+
+    fn create_test_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("search_test");
+
+        let mut annotations = Annotations::new();
+        annotations.insert(
+            "title".to_string(),
+            AnnotationValue::String("Human Being".to_string()),
+        );
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                description: Some("A living individual".to_string()),
+                aliases: vec!["individual".to_string()],
+                class_uri: Some("schema:Person".to_string()),
+                exact_mappings: vec!["foaf:Agent".to_string()],
+                annotations: Some(annotations),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "PersonalAddress".to_string(),
+            ClassDefinition {
+                description: Some("Where somebody lives".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert(
+            "Organization".to_string(),
+            ClassDefinition {
+                description: Some("A company or institution".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "birth_date".to_string(),
+            SlotDefinition {
+                description: Some("Date on which a person was born".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.enums.insert(
+            "EmploymentStatus".to_string(),
+            EnumDefinition {
+                permissible_values: vec![PermissibleValue::Complex {
+                    text: "EMPLOYED".to_string(),
+                    description: None,
+                    meaning: Some("NCIT:C25172".to_string()),
+                    is_a: None,
+                }],
+                ..Default::default()
+            },
+        );
+
+        schema
+    }
+
+    fn names(hits: &[SearchHit]) -> Vec<&str> {
+        hits.iter().map(|h| h.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_exact_match_ranks_first() {
+        let index = SearchIndex::build(&create_test_schema());
+        let hits = index.search("person", &SearchOptions::default());
+
+        assert_eq!(hits[0].name, "Person");
+        assert_eq!(hits[0].field, SearchField::Name);
+        assert!((hits[0].score - 1.0).abs() < f64::EPSILON);
+        assert_eq!(hits[1].name, "PersonalAddress");
+    }
+
+    #[test]
+    fn test_fuzzy_match_tolerates_typos() {
+        let index = SearchIndex::build(&create_test_schema());
+        let hits = index.search("organisaton", &SearchOptions::default());
+        assert_eq!(names(&hits).first(), Some(&"Organization"));
+
+        let hits = index.search("birthdate", &SearchOptions::default());
+        assert_eq!(names(&hits).first(), Some(&"birth_date"));
+    }
+
+    #[test]
+    fn test_title_alias_description_and_mapping_fields() {
+        let index = SearchIndex::build(&create_test_schema());
+        let options = SearchOptions::default();
+
+        let hit = &index.search("human being", &options)[0];
+        assert_eq!(
+            (hit.name.as_str(), hit.field),
+            ("Person", SearchField::Title)
+        );
+
+        let hit = &index.search("individual", &options)[0];
+        assert_eq!(
+            (hit.name.as_str(), hit.field),
+            ("Person", SearchField::Alias)
+        );
+
+        let hit = &index.search("company", &options)[0];
+        assert_eq!(
+            (hit.name.as_str(), hit.field),
+            ("Organization", SearchField::Description)
+        );
+
+        let hit = &index.search("foaf:Agent", &options)[0];
+        assert_eq!(
+            (hit.name.as_str(), hit.field),
+            ("Person", SearchField::Mapping)
+        );
+
+        let hit = &index.search("C25172", &options)[0];
+        assert_eq!(hit.name, "EmploymentStatus");
+    }
+
+    #[test]
+    fn test_synonyms_expand_query() {
+        let index = SearchIndex::build(&create_test_schema());
+        assert!(!names(&index.search("firm", &SearchOptions::default())).contains(&"Organization"));
+
+        let options = SearchOptions::default().with_synonyms("organization", ["firm", "business"]);
+        let hits = index.search("firm", &options);
+        assert_eq!(hits[0].name, "Organization");
+        assert!(
+            hits[0].score < 1.0,
+            "synonym matches rank below direct ones"
+        );
+    }
+
+    #[test]
+    fn test_filters_and_limits() {
+        let index = SearchIndex::build(&create_test_schema());
+
+        let options = SearchOptions::default().with_element_types(vec![ElementType::Slot]);
+        let hits = index.search("person", &options);
+        assert!(hits.iter().all(|h| h.element_type == ElementType::Slot));
+        assert_eq!(names(&hits), vec!["birth_date"]);
+
+        let options = SearchOptions::default().with_limit(1);
+        assert_eq!(index.search("person", &options).len(), 1);
+
+        assert!(index.search("   ", &SearchOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_docs_index() {
+        let index = SearchIndex::build(&create_test_schema());
+        let docs = index.to_docs_index();
+        let documents = docs["documents"].as_array().expect("documents array");
+        assert_eq!(documents.len(), index.len());
+
+        let person = documents
+            .iter()
+            .find(|d| d["name"] == "Person")
+            .expect("Person indexed");
+        assert_eq!(person["id"], "class-person");
+        assert_eq!(person["title"], "Human Being");
+        assert_eq!(person["mappings"][1], "foaf:Agent");
+
+        let classes = index.to_docs_index_with(|element_type, name| {
+            (element_type == ElementType::Class).then(|| name.to_string())
+        });
+        let documents = classes["documents"].as_array().expect("documents array");
+        assert!(documents.iter().all(|d| d["type"] == "class"));
+        assert_eq!(documents.len(), create_test_schema().classes.len());
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(tokenize("PersonalAddress"), vec!["personal", "address"]);
+        assert_eq!(tokenize("birth_date"), vec!["birth", "date"]);
+        assert_eq!(tokenize("HTTPServer2"), vec!["httpserver2"]);
+    }
+}
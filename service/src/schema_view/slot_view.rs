@@ -143,6 +143,12 @@ impl SlotView {
         self.definition.required.unwrap_or(false)
     }
 
+    /// Check if this slot is recommended
+    #[must_use]
+    pub fn is_recommended(&self) -> bool {
+        self.definition.recommended.unwrap_or(false)
+    }
+
     /// Check if this slot is multivalued
     #[must_use]
     pub fn is_multivalued(&self) -> bool {
@@ -202,6 +208,9 @@ impl SlotView {
             if override_def.required.is_some() {
                 resolved.required = override_def.required;
             }
+            if override_def.recommended.is_some() {
+                resolved.recommended = override_def.recommended;
+            }
             if override_def.multivalued.is_some() {
                 resolved.multivalued = override_def.multivalued;
             }
@@ -311,6 +320,19 @@ impl SlotView {
         classes
     }
 
+    /// Get classes where this slot is recommended (considering overrides)
+    #[must_use]
+    pub fn recommended_in_classes(&self) -> Vec<String> {
+        self.used_by_classes
+            .iter()
+            .filter(|class_name| {
+                self.in_class(class_name)
+                    .is_ok_and(|resolved| resolved.recommended.unwrap_or(false))
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Get classes where this slot is optional (considering overrides)
     #[must_use]
     pub fn optional_in_classes(&self) -> Vec<String> {
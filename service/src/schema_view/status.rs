@@ -0,0 +1,424 @@
+//! Approval status and deprecation of schema elements
+//!
+//! Classes, slots and enums can carry a `status` recording how far they are
+//! through review: `draft`, `testing` or `production`. Values may be written
+//! as plain words, CURIEs such as `bibo:draft` or full URIs; only the last
+//! segment counts.
+//!
+//! A [`DraftPolicy`] decides what validation and generation do with draft
+//! elements, so un-approved parts of a model do not end up in production
+//! data or artifacts. [`without_drafts`] removes them from a schema, along
+//! with everything that depends on them.
+//!
+//! Classes and slots at the other end of their life are `deprecated`, and
+//! may name the element to migrate to in
+//! `deprecated_element_has_exact_replacement` or
+//! `deprecated_element_has_possible_replacement`. A [`Deprecation`] gathers
+//! these into the message shown by the validator and the linter.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+use serde::{Deserialize, Serialize};
+
+use super::view::ElementType;
+
+/// Approval status of a schema element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ElementStatus {
+    /// Under development and not approved for use
+    Draft,
+    /// Approved for testing but not for production
+    Testing,
+    /// Approved for production use
+    Production,
+}
+
+impl ElementStatus {
+    /// Read a `status` value, ignoring any prefix or namespace
+    ///
+    /// `released` and `published` are read as [`Self::Production`]. Returns
+    /// `None` for other values.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let word = value
+            .rsplit([':', '/', '#'])
+            .next()
+            .unwrap_or(value)
+            .trim()
+            .to_ascii_lowercase();
+        match word.as_str() {
+            "draft" => Some(Self::Draft),
+            "testing" | "test" => Some(Self::Testing),
+            "production" | "released" | "published" => Some(Self::Production),
+            _ => None,
+        }
+    }
+
+    /// Status recorded in a `status` field, if it is recognized
+    #[must_use]
+    pub fn of(status: Option<&String>) -> Option<Self> {
+        status.and_then(|status| Self::parse(status))
+    }
+
+    /// The status as written in schemas
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Testing => "testing",
+            Self::Production => "production",
+        }
+    }
+}
+
+impl fmt::Display for ElementStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ElementStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| format!("Unknown element status '{s}'"))
+    }
+}
+
+/// What validation and generation do with draft elements
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DraftPolicy {
+    /// Draft elements are used like any other
+    #[default]
+    Include,
+    /// Draft elements are used, with a warning
+    Warn,
+    /// Draft elements are left out: data using them is invalid and
+    /// generated artifacts do not contain them
+    Exclude,
+}
+
+/// Whether an element's `status` is draft
+pub(crate) fn is_draft(status: Option<&String>) -> bool {
+    ElementStatus::of(status) == Some(ElementStatus::Draft)
+}
+
+/// Classes, slots and enums of a schema whose status is draft, in schema
+/// order
+///
+/// Draft attributes are listed as slots named `Class.attribute`.
+#[must_use]
+pub fn draft_elements(schema: &SchemaDefinition) -> Vec<(ElementType, String)> {
+    let mut drafts = Vec::new();
+    for (name, class) in &schema.classes {
+        if is_draft(class.status.as_ref()) {
+            drafts.push((ElementType::Class, name.clone()));
+        }
+        drafts.extend(
+            class
+                .attributes
+                .iter()
+                .filter(|(_, attribute)| is_draft(attribute.status.as_ref()))
+                .map(|(attribute, _)| (ElementType::Slot, format!("{name}.{attribute}"))),
+        );
+    }
+    drafts.extend(
+        schema
+            .slots
+            .iter()
+            .filter(|(_, slot)| is_draft(slot.status.as_ref()))
+            .map(|(name, _)| (ElementType::Slot, name.clone())),
+    );
+    drafts.extend(
+        schema
+            .enums
+            .iter()
+            .filter(|(_, enum_def)| is_draft(enum_def.status.as_ref()))
+            .map(|(name, _)| (ElementType::Enum, name.clone())),
+    );
+    drafts
+}
+
+/// A deprecated class or slot and what replaces it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deprecation {
+    /// Whether a class or a slot is deprecated
+    pub element_type: ElementType,
+    /// Name of the deprecated element
+    pub name: String,
+    /// Why the element is deprecated, as given in `deprecated`
+    pub reason: String,
+    /// Element replacing it one to one
+    pub exact_replacement: Option<String>,
+    /// Element that may replace it
+    pub possible_replacement: Option<String>,
+}
+
+impl Deprecation {
+    /// The deprecation of a class, if it is deprecated
+    #[must_use]
+    pub fn of_class(name: &str, class: &ClassDefinition) -> Option<Self> {
+        Some(Self {
+            element_type: ElementType::Class,
+            name: name.to_string(),
+            reason: class.deprecated.clone()?,
+            exact_replacement: class.deprecated_element_has_exact_replacement.clone(),
+            possible_replacement: class.deprecated_element_has_possible_replacement.clone(),
+        })
+    }
+
+    /// The deprecation of a slot or attribute, if it is deprecated
+    #[must_use]
+    pub fn of_slot(name: &str, slot: &SlotDefinition) -> Option<Self> {
+        Some(Self {
+            element_type: ElementType::Slot,
+            name: name.to_string(),
+            reason: slot.deprecated.clone()?,
+            exact_replacement: slot.deprecated_element_has_exact_replacement.clone(),
+            possible_replacement: slot.deprecated_element_has_possible_replacement.clone(),
+        })
+    }
+
+    /// How to migrate away from the element, if a replacement is named
+    #[must_use]
+    pub fn hint(&self) -> Option<String> {
+        match (&self.exact_replacement, &self.possible_replacement) {
+            (Some(exact), _) => Some(format!("replace it with '{exact}'")),
+            (None, Some(possible)) => Some(format!("consider replacing it with '{possible}'")),
+            (None, None) => None,
+        }
+    }
+}
+
+impl fmt::Display for Deprecation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.element_type {
+            ElementType::Class => "Class",
+            ElementType::Slot => "Slot",
+            ElementType::Type => "Type",
+            ElementType::Enum => "Enum",
+            ElementType::Subset => "Subset",
+        };
+        write!(f, "{kind} '{}' is deprecated", self.name)?;
+        let reason = self.reason.trim();
+        if !reason.is_empty() {
+            write!(f, ": {}", reason.trim_end_matches('.'))?;
+        }
+        if let Some(hint) = self.hint() {
+            write!(f, "; {hint}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Deprecated classes, slots and attributes of a schema, in schema order
+///
+/// Deprecated attributes are named `Class.attribute`.
+#[must_use]
+pub fn deprecated_elements(schema: &SchemaDefinition) -> Vec<Deprecation> {
+    let mut deprecated = Vec::new();
+    for (name, class) in &schema.classes {
+        deprecated.extend(Deprecation::of_class(name, class));
+        deprecated.extend(class.attributes.iter().filter_map(|(attribute, slot)| {
+            Deprecation::of_slot(&format!("{name}.{attribute}"), slot)
+        }));
+    }
+    deprecated.extend(
+        schema
+            .slots
+            .iter()
+            .filter_map(|(name, slot)| Deprecation::of_slot(name, slot)),
+    );
+    deprecated
+}
+
+/// The schema without its draft elements and the elements depending on them
+///
+/// Besides draft classes, slots, attributes and enums, this removes classes
+/// inheriting from a removed class and slots and attributes whose range or
+/// parent was removed, until nothing else depends on a draft. Removed slots
+/// are also dropped from the `slots` and `slot_usage` of the classes left.
+#[must_use]
+pub fn without_drafts(schema: &SchemaDefinition) -> SchemaDefinition {
+    let mut classes: HashSet<&str> = HashSet::new();
+    let mut slots: HashSet<&str> = HashSet::new();
+    let enums: HashSet<&str> = schema
+        .enums
+        .iter()
+        .filter(|(_, enum_def)| is_draft(enum_def.status.as_ref()))
+        .map(|(name, _)| name.as_str())
+        .collect();
+
+    // Inheritance and ranges can chain, so repeat until nothing changes
+    loop {
+        let before = classes.len() + slots.len();
+        let removed_range = |slot: &SlotDefinition| {
+            slot.range
+                .as_deref()
+                .is_some_and(|range| classes.contains(range) || enums.contains(range))
+        };
+        let new_slots: Vec<&str> = schema
+            .slots
+            .iter()
+            .filter(|(name, slot)| {
+                !slots.contains(name.as_str())
+                    && (is_draft(slot.status.as_ref())
+                        || removed_range(slot)
+                        || slot
+                            .is_a
+                            .as_deref()
+                            .is_some_and(|parent| slots.contains(parent)))
+            })
+            .map(|(name, _)| name.as_str())
+            .collect();
+        let new_classes: Vec<&str> = schema
+            .classes
+            .iter()
+            .filter(|(name, class)| {
+                !classes.contains(name.as_str())
+                    && (is_draft(class.status.as_ref())
+                        || class
+                            .is_a
+                            .iter()
+                            .chain(&class.mixins)
+                            .any(|parent| classes.contains(parent.as_str())))
+            })
+            .map(|(name, _)| name.as_str())
+            .collect();
+        slots.extend(new_slots);
+        classes.extend(new_classes);
+        if classes.len() + slots.len() == before {
+            break;
+        }
+    }
+
+    let mut approved = schema.clone();
+    approved
+        .classes
+        .retain(|name, _| !classes.contains(name.as_str()));
+    approved
+        .slots
+        .retain(|name, _| !slots.contains(name.as_str()));
+    approved
+        .enums
+        .retain(|name, _| !enums.contains(name.as_str()));
+    let removed_range = |slot: &SlotDefinition| {
+        slot.range
+            .as_deref()
+            .is_some_and(|range| classes.contains(range) || enums.contains(range))
+    };
+    for class in approved.classes.values_mut() {
+        class.slots.retain(|slot| !slots.contains(slot.as_str()));
+        class
+            .slot_usage
+            .retain(|slot, _| !slots.contains(slot.as_str()));
+        class.attributes.retain(|_, attribute| {
+            !is_draft(attribute.status.as_ref()) && !removed_range(attribute)
+        });
+    }
+    approved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = "\
+id: https://example.org/people
+name: people
+classes:
+  Person:
+    slots: [name, pet]
+    attributes:
+      nickname:
+        status: draft
+      mood:
+        range: Mood
+  Pet:
+    status: bibo:draft
+  Dog:
+    is_a: Pet
+slots:
+  name:
+    status: production
+  pet:
+    range: Dog
+enums:
+  Mood:
+    status: http://purl.org/ontology/bibo/status/draft
+    permissible_values: [happy]
+";
+
+    #[test]
+    fn test_parse_status() {
+        assert_eq!(ElementStatus::parse("Draft"), Some(ElementStatus::Draft));
+        assert_eq!(
+            ElementStatus::parse("bibo:draft"),
+            Some(ElementStatus::Draft)
+        );
+        assert_eq!(
+            "released".parse::<ElementStatus>(),
+            Ok(ElementStatus::Production)
+        );
+        assert_eq!(ElementStatus::parse("forthcoming"), None);
+        assert_eq!(ElementStatus::Testing.to_string(), "testing");
+    }
+
+    #[test]
+    fn test_deprecation_messages() {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            "\
+id: https://example.org/people
+name: people
+classes:
+  Person:
+    deprecated: Use Agent.
+    deprecated_element_has_possible_replacement: Agent
+  Agent: {}
+slots:
+  age:
+    deprecated: ''
+    deprecated_element_has_exact_replacement: birth_date
+  birth_date: {}
+",
+        )
+        .expect("valid schema");
+        let messages: Vec<String> = deprecated_elements(&schema)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "Class 'Person' is deprecated: Use Agent; consider replacing it with 'Agent'",
+                "Slot 'age' is deprecated; replace it with 'birth_date'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_drafts_and_their_dependents_are_removed() {
+        let schema: SchemaDefinition = serde_yaml::from_str(SCHEMA).expect("valid schema");
+        assert_eq!(
+            draft_elements(&schema),
+            [
+                (ElementType::Slot, "Person.nickname".to_string()),
+                (ElementType::Class, "Pet".to_string()),
+                (ElementType::Enum, "Mood".to_string()),
+            ]
+        );
+
+        let approved = without_drafts(&schema);
+        assert_eq!(approved.classes.keys().collect::<Vec<_>>(), ["Person"]);
+        assert_eq!(approved.slots.keys().collect::<Vec<_>>(), ["name"]);
+        assert!(approved.enums.is_empty());
+        let person = &approved.classes["Person"];
+        assert_eq!(person.slots, ["name"]);
+        assert!(person.attributes.is_empty());
+    }
+}
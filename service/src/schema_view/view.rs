@@ -1,8 +1,12 @@
 //! Main `SchemaView` API for schema introspection
 
+use indexmap::IndexMap;
 use linkml_core::{
+    config::PerformanceConfig,
     error::{LinkMLError, Result},
+    types::interned::SchemaRepresentation,
     types::{ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition, TypeDefinition},
+    utils::merge_slot_definitions,
 };
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
@@ -11,11 +15,16 @@ use std::sync::{Arc, RwLock};
 use super::analysis::UsageIndex;
 use super::class_view::ClassView;
 use super::navigation::{NavigationCache, SlotResolution};
+use super::search::{SearchHit, SearchIndex, SearchOptions};
 use super::slot_view::SlotView;
+use super::status::{ElementStatus, draft_elements, without_drafts};
+use crate::generator::base::induced_slots;
+use crate::inheritance::{InheritanceResolver, MroDiagnostic};
 use crate::parser::{ImportResolver, SchemaLoader};
 
 /// Type of schema element
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ElementType {
     /// Class definition
     Class,
@@ -227,6 +236,42 @@ impl SchemaView {
         Ok(ancestors)
     }
 
+    /// Method resolution order of a class: its C3 linearization, starting
+    /// with the class
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class is not found, or its hierarchy has a
+    /// cycle or no consistent linearization; the message suggests a fix.
+    pub fn mro(&self, name: &str) -> Result<Vec<String>> {
+        let merged = self
+            .merged_schema
+            .read()
+            .map_err(|_| SchemaViewError::CacheError("Failed to acquire read lock".into()))?;
+        if !merged.classes.contains_key(name) {
+            return Err(SchemaViewError::ElementNotFound(format!("Class '{name}'")).into());
+        }
+        InheritanceResolver::new(&merged).mro(name)
+    }
+
+    /// Problems with the resolution order of a class, each with a suggested
+    /// fix: a cycle or inconsistent order of its ancestors, or slots that
+    /// unrelated ancestors refine differently
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class is not found.
+    pub fn mro_diagnostics(&self, name: &str) -> Result<Vec<MroDiagnostic>> {
+        let merged = self
+            .merged_schema
+            .read()
+            .map_err(|_| SchemaViewError::CacheError("Failed to acquire read lock".into()))?;
+        if !merged.classes.contains_key(name) {
+            return Err(SchemaViewError::ElementNotFound(format!("Class '{name}'")).into());
+        }
+        Ok(InheritanceResolver::new(&merged).mro_diagnostics(name))
+    }
+
     /// Get all descendant classes (subclasses) of a class
     /// Returns an error if the operation fails
     ///
@@ -449,6 +494,44 @@ impl SchemaView {
         Ok(index)
     }
 
+    // === Search ===
+
+    /// Search schema elements by name, title, alias, description and mappings
+    /// using default options
+    /// Returns an error if the operation fails
+    ///
+    /// # Errors
+    ///
+    pub fn search(&self, query: &str) -> Result<Vec<SearchHit>> {
+        self.search_with_options(query, &SearchOptions::default())
+    }
+
+    /// Search schema elements with explicit options (limits, type filters, synonyms)
+    /// Returns an error if the operation fails
+    ///
+    /// # Errors
+    ///
+    pub fn search_with_options(
+        &self,
+        query: &str,
+        options: &SearchOptions,
+    ) -> Result<Vec<SearchHit>> {
+        Ok(self.search_index()?.search(query, options))
+    }
+
+    /// Build a search index over all elements of the merged schema
+    /// Returns an error if the operation fails
+    ///
+    /// # Errors
+    ///
+    pub fn search_index(&self) -> Result<SearchIndex> {
+        let merged = self
+            .merged_schema
+            .read()
+            .map_err(|_| SchemaViewError::CacheError("Failed to acquire read lock".into()))?;
+        Ok(SearchIndex::build(&merged))
+    }
+
     /// Check if a class should be inlined
     /// Returns an error if the operation fails
     ///
@@ -506,6 +589,71 @@ impl SchemaView {
         Ok(())
     }
 
+    /// Materialize the schema as a standalone artifact
+    ///
+    /// Like `gen-linkml --materialize-attributes`, every class carries all
+    /// of its induced slots as `attributes`: inherited slots and attributes,
+    /// with `slot_usage` of the class and its ancestors applied and unset
+    /// metaslots taken from parent slots. Imported elements are already
+    /// merged into the view, so `imports` are dropped, and structured
+    /// patterns are expanded into `pattern`s.
+    /// Returns an error if the operation fails
+    ///
+    /// # Errors
+    ///
+    pub fn materialize(&self) -> Result<SchemaDefinition> {
+        let merged = self
+            .merged_schema
+            .read()
+            .map_err(|_| SchemaViewError::CacheError("Failed to acquire read lock".into()))?;
+
+        let mut schema = merged.clone();
+        schema.imports.clear();
+        for (class_name, class) in &mut schema.classes {
+            let mut attributes = IndexMap::new();
+            for slot in induced_slots(&merged, class_name) {
+                let mut slot = Self::inherit_slot_metaslots(&merged, slot);
+                self.materialize_slot_pattern(&mut slot)?;
+                attributes.insert(slot.name.clone(), slot);
+            }
+            class.attributes = attributes;
+        }
+        for slot in schema.slots.values_mut() {
+            self.materialize_slot_pattern(slot)?;
+        }
+        Ok(schema)
+    }
+
+    /// Fill the unset metaslots of a slot from its `is_a` ancestors and mixins
+    fn inherit_slot_metaslots(schema: &SchemaDefinition, slot: SlotDefinition) -> SlotDefinition {
+        let mut resolved = slot;
+        let mut visited = HashSet::new();
+        let mut pending: Vec<String> = resolved
+            .is_a
+            .iter()
+            .chain(&resolved.mixins)
+            .cloned()
+            .collect();
+        while let Some(parent_name) = pending.pop() {
+            if !visited.insert(parent_name.clone()) {
+                continue;
+            }
+            if let Some(parent) = schema.slots.get(&parent_name) {
+                resolved = merge_slot_definitions(parent, &resolved);
+                pending.extend(parent.is_a.iter().chain(&parent.mixins).cloned());
+            }
+        }
+        resolved
+    }
+
+    /// Expand the structured pattern of a slot into its `pattern`
+    fn materialize_slot_pattern(&self, slot: &mut SlotDefinition) -> Result<()> {
+        if let Some(structured_pattern) = &slot.structured_pattern {
+            slot.pattern = Some(self.structured_pattern_to_regex(structured_pattern)?);
+        }
+        Ok(())
+    }
+
     /// Convert a structured pattern to a regular expression
     fn structured_pattern_to_regex(
         &self,
@@ -580,6 +728,81 @@ impl SchemaView {
         Ok(None)
     }
 
+    /// Get the approval status of a class, slot or enum
+    ///
+    /// Returns `None` when the element has no status or one that is not
+    /// recognized.
+    /// Returns an error if the operation fails
+    ///
+    /// # Errors
+    ///
+    pub fn element_status(&self, name: &str) -> Result<Option<ElementStatus>> {
+        let merged = self
+            .merged_schema
+            .read()
+            .map_err(|_| SchemaViewError::CacheError("Failed to acquire read lock".into()))?;
+
+        let status = merged
+            .classes
+            .get(name)
+            .map(|class| class.status.as_ref())
+            .or_else(|| merged.slots.get(name).map(|slot| slot.status.as_ref()))
+            .or_else(|| {
+                merged
+                    .enums
+                    .get(name)
+                    .map(|enum_def| enum_def.status.as_ref())
+            })
+            .flatten();
+        Ok(ElementStatus::of(status))
+    }
+
+    /// Get the classes, slots, attributes and enums whose status is draft
+    /// Returns an error if the operation fails
+    ///
+    /// # Errors
+    ///
+    pub fn draft_elements(&self) -> Result<Vec<(ElementType, String)>> {
+        let merged = self
+            .merged_schema
+            .read()
+            .map_err(|_| SchemaViewError::CacheError("Failed to acquire read lock".into()))?;
+
+        Ok(draft_elements(&merged))
+    }
+
+    /// Get the merged schema without its draft elements and the elements
+    /// depending on them
+    /// Returns an error if the operation fails
+    ///
+    /// # Errors
+    ///
+    pub fn without_drafts(&self) -> Result<SchemaDefinition> {
+        let merged = self
+            .merged_schema
+            .read()
+            .map_err(|_| SchemaViewError::CacheError("Failed to acquire read lock".into()))?;
+
+        Ok(without_drafts(&merged))
+    }
+
+    /// Get the merged schema in the representation selected by `config`
+    ///
+    /// With [`PerformanceConfig::intern_strings`] set, its strings are
+    /// interned in the global string pool.
+    /// Returns an error if the operation fails
+    ///
+    /// # Errors
+    ///
+    pub fn representation(&self, config: &PerformanceConfig) -> Result<SchemaRepresentation> {
+        let merged = self
+            .merged_schema
+            .read()
+            .map_err(|_| SchemaViewError::CacheError("Failed to acquire read lock".into()))?;
+
+        Ok(SchemaRepresentation::from_config(merged.clone(), config))
+    }
+
     // === Class Hierarchy Methods ===
 
     /// Get direct parent classes only (not full ancestry)
@@ -0,0 +1,643 @@
+//! Cross-schema queries over a family of schemas
+//!
+//! Organizations often maintain several related schemas that do not import
+//! each other, such as one schema per department or product. A
+//! [`WorkspaceView`] holds a [`SchemaView`] per schema and answers questions
+//! across them: which prefixes they share (and whether they agree on the
+//! namespace), which class names several schemas define, which external terms
+//! elements of different schemas map to, and which elements are candidates
+//! for alignment.
+
+use indexmap::IndexMap;
+use linkml_core::error::Result;
+use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+use super::view::{ElementType, SchemaView, SchemaViewError};
+
+/// An element defined by one schema of a workspace
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct WorkspaceElement {
+    /// Name of the defining schema
+    pub schema: String,
+    /// Kind of element, a class or a slot
+    pub element_type: ElementType,
+    /// Element name
+    pub name: String,
+}
+
+/// A prefix declared by several schemas
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SharedPrefix {
+    /// Prefix name
+    pub prefix: String,
+    /// Namespace each declaring schema expands the prefix to
+    pub expansions: BTreeMap<String, String>,
+}
+
+impl SharedPrefix {
+    /// Whether all schemas expand the prefix to the same namespace
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        let mut namespaces = self.expansions.values();
+        namespaces
+            .next()
+            .is_none_or(|first| namespaces.all(|namespace| namespace == first))
+    }
+}
+
+/// A class name defined by several schemas
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SharedClassName {
+    /// Class name
+    pub name: String,
+    /// Expanded `class_uri` of the class in each defining schema, if set
+    pub class_uris: BTreeMap<String, Option<String>>,
+}
+
+impl SharedClassName {
+    /// Whether every schema gives the class the same explicit URI, making
+    /// the classes one concept rather than homonyms
+    #[must_use]
+    pub fn is_same_concept(&self) -> bool {
+        let mut uris = self.class_uris.values();
+        match uris.next() {
+            Some(Some(first)) => uris.all(|uri| uri.as_ref() == Some(first)),
+            _ => false,
+        }
+    }
+}
+
+/// How an element refers to an external term
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MappingRelation {
+    /// The term is the element's `class_uri` or `slot_uri`
+    Uri,
+    /// Listed in `exact_mappings`
+    Exact,
+    /// Listed in `close_mappings`
+    Close,
+    /// Listed in `related_mappings`
+    Related,
+    /// Listed in `narrow_mappings`
+    Narrow,
+    /// Listed in `broad_mappings`
+    Broad,
+}
+
+impl MappingRelation {
+    /// How strongly the relation ties an element to the term
+    fn weight(self) -> f64 {
+        match self {
+            Self::Uri | Self::Exact => 1.0,
+            Self::Close => 0.8,
+            Self::Related => 0.6,
+            Self::Narrow | Self::Broad => 0.5,
+        }
+    }
+}
+
+/// An element referring to an external term
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MappingReference {
+    /// Referring element
+    pub element: WorkspaceElement,
+    /// How the element refers to the term
+    pub relation: MappingRelation,
+}
+
+/// An external term referred to by elements of several schemas
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MappingOverlap {
+    /// Expanded URI of the term
+    pub term: String,
+    /// Referring elements, ordered by schema and name
+    pub references: Vec<MappingReference>,
+}
+
+/// Why two elements of different schemas may describe the same thing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AlignmentEvidence {
+    /// Both elements refer to the same external term
+    SharedTerm {
+        /// Expanded URI of the term
+        term: String,
+        /// How the left element refers to it
+        left: MappingRelation,
+        /// How the right element refers to it
+        right: MappingRelation,
+    },
+    /// The element names match once case and separators are ignored
+    SimilarName,
+}
+
+/// Candidate alignment between elements of two schemas
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AlignmentSuggestion {
+    /// Element of the schema added to the workspace first
+    pub left: WorkspaceElement,
+    /// Element of the other schema
+    pub right: WorkspaceElement,
+    /// Strongest evidence for the alignment
+    pub evidence: AlignmentEvidence,
+    /// Confidence between 0 and 1
+    pub confidence: f64,
+}
+
+/// Confidence of an alignment of two elements with the same name
+const SAME_NAME_CONFIDENCE: f64 = 0.6;
+/// Confidence of an alignment of names equal up to case and separators
+const SIMILAR_NAME_CONFIDENCE: f64 = 0.5;
+/// Confidence of an alignment through exact mappings to the same term
+const SHARED_TERM_CONFIDENCE: f64 = 0.95;
+
+/// Several related schemas viewed together
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceView {
+    /// Views by schema name, in the order schemas were added
+    views: IndexMap<String, SchemaView>,
+}
+
+impl WorkspaceView {
+    /// Create an empty workspace
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a workspace of schemas
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if two schemas have the same name
+    pub fn from_schemas(schemas: impl IntoIterator<Item = SchemaDefinition>) -> Result<Self> {
+        let mut workspace = Self::new();
+        for schema in schemas {
+            workspace.add_schema(schema)?;
+        }
+        Ok(workspace)
+    }
+
+    /// Load a workspace from schema files
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a schema cannot be loaded or two schemas have the
+    /// same name
+    pub async fn load_from_files(paths: &[impl AsRef<Path>]) -> Result<Self> {
+        let mut workspace = Self::new();
+        for path in paths {
+            workspace.add_view(SchemaView::load_from_file(path).await?)?;
+        }
+        Ok(workspace)
+    }
+
+    /// Add a schema to the workspace
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the workspace already has a schema of that name
+    pub fn add_schema(&mut self, schema: SchemaDefinition) -> Result<()> {
+        self.add_view(SchemaView::new(schema)?)
+    }
+
+    /// Add the view of a schema to the workspace
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the workspace already has a schema of that name
+    pub fn add_view(&mut self, view: SchemaView) -> Result<()> {
+        let name = view.schema_name()?;
+        if self.views.contains_key(&name) {
+            return Err(SchemaViewError::LoadError(format!(
+                "Workspace already contains a schema named '{name}'"
+            ))
+            .into());
+        }
+        self.views.insert(name, view);
+        Ok(())
+    }
+
+    /// Names of the schemas, in the order they were added
+    pub fn schema_names(&self) -> impl Iterator<Item = &str> {
+        self.views.keys().map(String::as_str)
+    }
+
+    /// View of one schema
+    #[must_use]
+    pub fn view(&self, schema: &str) -> Option<&SchemaView> {
+        self.views.get(schema)
+    }
+
+    /// Number of schemas
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    /// Whether the workspace has no schemas
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+    }
+
+    /// Prefixes declared by more than one schema, ordered by prefix
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a schema view cannot be read
+    pub fn shared_prefixes(&self) -> Result<Vec<SharedPrefix>> {
+        let mut prefixes: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+        for (schema, view) in &self.views {
+            for (prefix, namespace) in view.get_prefixes()? {
+                prefixes
+                    .entry(prefix)
+                    .or_default()
+                    .insert(schema.clone(), namespace);
+            }
+        }
+        Ok(prefixes
+            .into_iter()
+            .filter(|(_, expansions)| expansions.len() > 1)
+            .map(|(prefix, expansions)| SharedPrefix { prefix, expansions })
+            .collect())
+    }
+
+    /// Class names defined by more than one schema, ordered by name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a schema view cannot be read
+    pub fn same_named_classes(&self) -> Result<Vec<SharedClassName>> {
+        let mut classes: BTreeMap<String, BTreeMap<String, Option<String>>> = BTreeMap::new();
+        for (schema, view) in &self.views {
+            for (name, class) in view.all_classes()? {
+                let uri = class
+                    .class_uri
+                    .as_deref()
+                    .map(|uri| view.expand_curie(uri))
+                    .transpose()?;
+                classes.entry(name).or_default().insert(schema.clone(), uri);
+            }
+        }
+        Ok(classes
+            .into_iter()
+            .filter(|(_, class_uris)| class_uris.len() > 1)
+            .map(|(name, class_uris)| SharedClassName { name, class_uris })
+            .collect())
+    }
+
+    /// External terms referred to by elements of more than one schema,
+    /// ordered by term
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a schema view cannot be read
+    pub fn mapping_overlaps(&self) -> Result<Vec<MappingOverlap>> {
+        let mut terms: BTreeMap<String, Vec<MappingReference>> = BTreeMap::new();
+        for (term, reference) in self.mapping_references()? {
+            terms.entry(term).or_default().push(reference);
+        }
+        Ok(terms
+            .into_iter()
+            .filter(|(_, references)| {
+                references
+                    .iter()
+                    .any(|reference| reference.element.schema != references[0].element.schema)
+            })
+            .map(|(term, references)| MappingOverlap { term, references })
+            .collect())
+    }
+
+    /// Candidate alignments between classes and slots of different schemas,
+    /// most confident first
+    ///
+    /// Elements referring to the same external term are candidates, unless
+    /// both already use it as their URI and so are aligned. Classes and slots
+    /// whose names match up to case and separators are weaker candidates.
+    /// Each pair is suggested once, with its strongest evidence.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a schema view cannot be read
+    pub fn alignment_suggestions(&self) -> Result<Vec<AlignmentSuggestion>> {
+        let order: HashMap<&str, usize> = self
+            .views
+            .keys()
+            .enumerate()
+            .map(|(index, schema)| (schema.as_str(), index))
+            .collect();
+        let key = |a: &WorkspaceElement, b: &WorkspaceElement| {
+            if order[a.schema.as_str()] < order[b.schema.as_str()] {
+                (a.clone(), b.clone())
+            } else {
+                (b.clone(), a.clone())
+            }
+        };
+        let overlaps = self.mapping_overlaps()?;
+        // Pairs sharing a URI are the same concept already
+        let aligned: HashSet<_> = overlaps
+            .iter()
+            .flat_map(|overlap| {
+                let uris: Vec<_> = overlap
+                    .references
+                    .iter()
+                    .filter(|reference| reference.relation == MappingRelation::Uri)
+                    .map(|reference| &reference.element)
+                    .collect();
+                let pairs: Vec<_> = uris
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, a)| uris[i + 1..].iter().map(|b| key(a, b)))
+                    .collect();
+                pairs
+            })
+            .collect();
+
+        let mut best: IndexMap<(WorkspaceElement, WorkspaceElement), AlignmentSuggestion> =
+            IndexMap::new();
+        let mut suggest = |a: &WorkspaceElement,
+                           b: &WorkspaceElement,
+                           evidence: AlignmentEvidence,
+                           confidence: f64| {
+            if a.schema == b.schema || a.element_type != b.element_type {
+                return;
+            }
+            let key = key(a, b);
+            if aligned.contains(&key)
+                || best
+                    .get(&key)
+                    .is_some_and(|existing| existing.confidence >= confidence)
+            {
+                return;
+            }
+            let (left, right) = key.clone();
+            best.insert(
+                key,
+                AlignmentSuggestion {
+                    left,
+                    right,
+                    evidence,
+                    confidence,
+                },
+            );
+        };
+
+        for overlap in overlaps {
+            for (i, a) in overlap.references.iter().enumerate() {
+                for b in &overlap.references[i + 1..] {
+                    let confidence =
+                        SHARED_TERM_CONFIDENCE * a.relation.weight() * b.relation.weight();
+                    let evidence = AlignmentEvidence::SharedTerm {
+                        term: overlap.term.clone(),
+                        left: a.relation,
+                        right: b.relation,
+                    };
+                    suggest(&a.element, &b.element, evidence, confidence);
+                }
+            }
+        }
+
+        let mut by_name: BTreeMap<(String, bool), Vec<WorkspaceElement>> = BTreeMap::new();
+        for element in self.elements()? {
+            let is_class = element.element_type == ElementType::Class;
+            by_name
+                .entry((normalize_name(&element.name), is_class))
+                .or_default()
+                .push(element);
+        }
+        for elements in by_name.values() {
+            for (i, a) in elements.iter().enumerate() {
+                for b in &elements[i + 1..] {
+                    let confidence = if a.name == b.name {
+                        SAME_NAME_CONFIDENCE
+                    } else {
+                        SIMILAR_NAME_CONFIDENCE
+                    };
+                    suggest(a, b, AlignmentEvidence::SimilarName, confidence);
+                }
+            }
+        }
+
+        let mut suggestions: Vec<_> = best.into_values().collect();
+        suggestions.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        Ok(suggestions)
+    }
+
+    /// Classes and slots of all schemas, ordered by schema and name
+    fn elements(&self) -> Result<Vec<WorkspaceElement>> {
+        let mut elements = Vec::new();
+        for (schema, view) in &self.views {
+            let mut classes = view.all_class_names()?;
+            classes.sort();
+            let mut slots = view.all_slot_names()?;
+            slots.sort();
+            let element = |element_type, name| WorkspaceElement {
+                schema: schema.clone(),
+                element_type,
+                name,
+            };
+            elements.extend(
+                classes
+                    .into_iter()
+                    .map(|name| element(ElementType::Class, name)),
+            );
+            elements.extend(
+                slots
+                    .into_iter()
+                    .map(|name| element(ElementType::Slot, name)),
+            );
+        }
+        Ok(elements)
+    }
+
+    /// Expanded external terms referred to by classes and slots, ordered by
+    /// schema and element name
+    fn mapping_references(&self) -> Result<Vec<(String, MappingReference)>> {
+        let mut references = Vec::new();
+        for (schema, view) in &self.views {
+            let classes: BTreeMap<_, _> = view.all_classes()?.into_iter().collect();
+            let slots: BTreeMap<_, _> = view.all_slots()?.into_iter().collect();
+            let mut push = |element_type, name: &str, terms: Vec<(&String, MappingRelation)>| {
+                for (term, relation) in terms {
+                    let reference = MappingReference {
+                        element: WorkspaceElement {
+                            schema: schema.clone(),
+                            element_type,
+                            name: name.to_string(),
+                        },
+                        relation,
+                    };
+                    references.push((view.expand_curie(term)?, reference));
+                }
+                Ok::<_, linkml_core::error::LinkMLError>(())
+            };
+            for (name, class) in &classes {
+                push(ElementType::Class, name, class_terms(class))?;
+            }
+            for (name, slot) in &slots {
+                push(ElementType::Slot, name, slot_terms(slot))?;
+            }
+        }
+        Ok(references)
+    }
+}
+
+/// External terms a class refers to
+fn class_terms(class: &ClassDefinition) -> Vec<(&String, MappingRelation)> {
+    let mut terms: Vec<_> = class
+        .class_uri
+        .iter()
+        .map(|uri| (uri, MappingRelation::Uri))
+        .collect();
+    terms.extend(mappings(
+        &class.exact_mappings,
+        &class.close_mappings,
+        &class.related_mappings,
+        &class.narrow_mappings,
+        &class.broad_mappings,
+    ));
+    terms
+}
+
+/// External terms a slot refers to
+fn slot_terms(slot: &SlotDefinition) -> Vec<(&String, MappingRelation)> {
+    let mut terms: Vec<_> = slot
+        .slot_uri
+        .iter()
+        .map(|uri| (uri, MappingRelation::Uri))
+        .collect();
+    terms.extend(mappings(
+        &slot.exact_mappings,
+        &slot.close_mappings,
+        &slot.related_mappings,
+        &slot.narrow_mappings,
+        &slot.broad_mappings,
+    ));
+    terms
+}
+
+/// Mapping lists with their relations
+fn mappings<'a>(
+    exact: &'a [String],
+    close: &'a [String],
+    related: &'a [String],
+    narrow: &'a [String],
+    broad: &'a [String],
+) -> impl Iterator<Item = (&'a String, MappingRelation)> {
+    [
+        (exact, MappingRelation::Exact),
+        (close, MappingRelation::Close),
+        (related, MappingRelation::Related),
+        (narrow, MappingRelation::Narrow),
+        (broad, MappingRelation::Broad),
+    ]
+    .into_iter()
+    .flat_map(|(terms, relation)| terms.iter().map(move |term| (term, relation)))
+}
+
+/// Name with case and separators removed, so `person_record` and
+/// `PersonRecord` compare equal
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{SchemaParser, YamlParser};
+
+    fn schema(yaml: &str) -> SchemaDefinition {
+        YamlParser::new().parse_str(yaml).expect("schema")
+    }
+
+    #[test]
+    fn test_workspace_queries() {
+        let hr = schema(
+            r"
+id: https://example.org/hr
+name: hr
+prefixes:
+  schema: http://schema.org/
+  ex: https://example.org/hr/
+classes:
+  Person:
+    class_uri: schema:Person
+  Employee:
+    exact_mappings: [schema:Person]
+slots:
+  birth_date:
+    slot_uri: schema:birthDate
+",
+        );
+        let crm = schema(
+            r"
+id: https://example.org/crm
+name: crm
+prefixes:
+  schema: http://schema.org/
+  ex: https://example.org/crm/
+classes:
+  Person:
+    class_uri: schema:Person
+  Customer:
+    close_mappings: [schema:Person]
+slots:
+  BirthDate:
+    range: date
+",
+        );
+        let mut workspace = WorkspaceView::from_schemas([hr, crm.clone()]).expect("workspace");
+        assert!(workspace.add_schema(crm).is_err());
+        assert_eq!(workspace.schema_names().collect::<Vec<_>>(), ["hr", "crm"]);
+
+        let prefixes = workspace.shared_prefixes().expect("prefixes");
+        assert_eq!(
+            prefixes
+                .iter()
+                .map(|shared| (shared.prefix.as_str(), shared.is_consistent()))
+                .collect::<Vec<_>>(),
+            [("ex", false), ("schema", true)]
+        );
+
+        let classes = workspace.same_named_classes().expect("classes");
+        assert_eq!(classes.len(), 1);
+        assert_eq!(classes[0].name, "Person");
+        assert!(classes[0].is_same_concept());
+
+        let overlaps = workspace.mapping_overlaps().expect("overlaps");
+        assert_eq!(overlaps.len(), 1);
+        assert_eq!(overlaps[0].term, "http://schema.org/Person");
+        assert_eq!(overlaps[0].references.len(), 4);
+
+        let suggestions = workspace.alignment_suggestions().expect("suggestions");
+        let pairs: Vec<_> = suggestions
+            .iter()
+            .map(|s| (s.left.name.as_str(), s.right.name.as_str()))
+            .collect();
+        // Both Person classes use schema:Person as their URI and are aligned
+        // already; the employee is a closer match than the customer
+        assert_eq!(
+            pairs[..3],
+            [
+                ("Employee", "Person"),
+                ("Employee", "Customer"),
+                ("Person", "Customer")
+            ]
+        );
+        assert!(!pairs.contains(&("Person", "Person")));
+        let birth_date = suggestions
+            .iter()
+            .find(|s| s.left.name == "birth_date")
+            .expect("similar slot names");
+        assert_eq!(birth_date.right.name, "BirthDate");
+        assert_eq!(birth_date.evidence, AlignmentEvidence::SimilarName);
+    }
+}
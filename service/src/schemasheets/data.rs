@@ -0,0 +1,694 @@
+//! Data-entry workbooks for the instances of a schema
+//!
+//! Where the other SchemaSheets modules round-trip the schema itself, this
+//! module round-trips data. [`DataSheetsGenerator`] writes one sheet per
+//! concrete class with a column per induced slot. The header of a column
+//! carries the slot description as a comment, enum slots get a dropdown of
+//! their permissible values and boolean slots a `TRUE`/`FALSE` dropdown.
+//! Existing instances can be written into the sheets for editing.
+//!
+//! [`DataSheetsLoader`] reads a filled workbook back. Cells are converted to
+//! the ranges of their slots and every row is validated against its class;
+//! problems are reported with the sheet, row and column they come from.
+//! Multivalued slots hold their values separated by `|` in one cell.
+
+use calamine::{Data, Range};
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::{SchemaDefinition, SlotDefinition};
+use rust_xlsxwriter::{DataValidation, Format, Formula, Note, Workbook, Worksheet};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::parser::workbook_sheets;
+use crate::generator::base::induced_slots;
+use crate::loader::traits::DataInstance;
+use crate::validator::{Severity, ValidationEngine};
+
+/// Hidden sheet holding the values of enum dropdowns
+pub const LISTS_SHEET: &str = "_lists";
+
+/// Separator of the values of a multivalued slot within a cell
+pub const VALUE_SEPARATOR: char = '|';
+
+/// Last row data validation extends to
+const MAX_ROW: u32 = 1_048_575;
+
+/// Generator of data-entry workbooks
+#[derive(Debug, Clone, Default)]
+pub struct DataSheetsGenerator {
+    classes: Option<Vec<String>>,
+}
+
+impl DataSheetsGenerator {
+    /// Create a generator with a sheet for every concrete class
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only generate sheets for these classes, in this order
+    #[must_use]
+    pub fn with_classes(mut self, classes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.classes = Some(classes.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Generate a data-entry workbook holding `instances`
+    ///
+    /// Instances of classes without a sheet are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a selected class is not defined or the workbook
+    /// cannot be built
+    pub fn generate_buffer(
+        &self,
+        schema: &SchemaDefinition,
+        instances: &[DataInstance],
+    ) -> Result<Vec<u8>> {
+        let classes = self.sheet_classes(schema)?;
+        let header_format = Format::new().set_bold();
+        let mut workbook = Workbook::new();
+        // Enum name to its column in the lists sheet, in first-use order
+        let mut lists: Vec<(String, Vec<String>)> = Vec::new();
+
+        for class_name in &classes {
+            let slots = induced_slots(schema, class_name);
+            let sheet = workbook.add_worksheet();
+            sheet
+                .set_name(class_name)
+                .map_err(|e| xlsx_error(&format!("Invalid sheet name '{class_name}'"), &e))?;
+            write_headers(sheet, schema, &slots, &header_format, &mut lists)?;
+
+            let rows = instances
+                .iter()
+                .filter(|instance| instance.class_name == *class_name);
+            for (row, instance) in (1..).zip(rows) {
+                for (col, slot) in (0..).zip(&slots) {
+                    if let Some(value) = instance.data.get(&slot.name) {
+                        write_value(sheet, row, col, value)?;
+                    }
+                }
+            }
+            sheet
+                .set_freeze_panes(1, 0)
+                .map_err(|e| xlsx_error("Failed to freeze headers", &e))?;
+        }
+
+        if !lists.is_empty() {
+            let sheet = workbook.add_worksheet();
+            sheet
+                .set_name(LISTS_SHEET)
+                .map_err(|e| xlsx_error("Failed to add the lists sheet", &e))?;
+            sheet.set_hidden(true);
+            for (col, (enum_name, values)) in (0..).zip(&lists) {
+                write_cell(sheet, 0, col, enum_name)?;
+                for (row, value) in (1..).zip(values) {
+                    write_cell(sheet, row, col, value)?;
+                }
+            }
+        }
+
+        workbook
+            .save_to_buffer()
+            .map_err(|e| xlsx_error("Failed to generate Excel file", &e))
+    }
+
+    /// Generate a data-entry workbook file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the workbook cannot be built or written
+    pub async fn generate_file(
+        &self,
+        schema: &SchemaDefinition,
+        instances: &[DataInstance],
+        output_path: &Path,
+    ) -> Result<()> {
+        let buffer = self.generate_buffer(schema, instances)?;
+        if let Some(parent) = output_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(output_path, buffer).await?;
+        Ok(())
+    }
+
+    fn sheet_classes(&self, schema: &SchemaDefinition) -> Result<Vec<String>> {
+        let Some(classes) = &self.classes else {
+            return Ok(schema
+                .classes
+                .iter()
+                .filter(|(_, class)| class.abstract_ != Some(true) && class.mixin != Some(true))
+                .map(|(name, _)| name.clone())
+                .collect());
+        };
+        if let Some(unknown) = classes.iter().find(|c| !schema.classes.contains_key(*c)) {
+            return Err(LinkMLError::config(format!(
+                "Class '{unknown}' is not defined in schema '{}'",
+                schema.name
+            )));
+        }
+        Ok(classes.clone())
+    }
+}
+
+/// Write the header row of a class sheet with comments and dropdowns
+fn write_headers(
+    sheet: &mut Worksheet,
+    schema: &SchemaDefinition,
+    slots: &[SlotDefinition],
+    header_format: &Format,
+    lists: &mut Vec<(String, Vec<String>)>,
+) -> Result<()> {
+    for (col, slot) in (0..).zip(slots) {
+        sheet
+            .write_with_format(0, col, &slot.name, header_format)
+            .map_err(|e| xlsx_error(&format!("Failed to write header '{}'", slot.name), &e))?;
+        if let Some(description) = &slot.description {
+            sheet
+                .insert_note(0, col, &Note::new(description))
+                .map_err(|e| xlsx_error(&format!("Failed to comment '{}'", slot.name), &e))?;
+        }
+
+        // Dropdowns only fit cells holding a single value
+        if slot.multivalued == Some(true) {
+            continue;
+        }
+        let validation = match slot.range.as_deref() {
+            Some("boolean") => DataValidation::new()
+                .allow_list_strings(&["TRUE", "FALSE"])
+                .map_err(|e| xlsx_error("Failed to build boolean dropdown", &e))?,
+            Some(range) if schema.enums.contains_key(range) => {
+                let list_col = lists
+                    .iter()
+                    .position(|(name, _)| name == range)
+                    .unwrap_or_else(|| {
+                        let values = schema.enums[range]
+                            .permissible_values
+                            .iter()
+                            .map(|value| value.text().to_string())
+                            .collect();
+                        lists.push((range.to_string(), values));
+                        lists.len() - 1
+                    });
+                let letter = rust_xlsxwriter::utility::column_number_to_name(
+                    u16::try_from(list_col).unwrap_or(u16::MAX),
+                );
+                let count = lists[list_col].1.len().max(1) + 1;
+                DataValidation::new().allow_list_formula(Formula::new(format!(
+                    "={LISTS_SHEET}!${letter}$2:${letter}${count}"
+                )))
+            }
+            _ => continue,
+        };
+        sheet
+            .add_data_validation(1, col, MAX_ROW, col, &validation)
+            .map_err(|e| xlsx_error(&format!("Failed to add dropdown to '{}'", slot.name), &e))?;
+    }
+    Ok(())
+}
+
+fn write_value(sheet: &mut Worksheet, row: u32, col: u16, value: &Value) -> Result<()> {
+    let result = match value {
+        Value::Null => return Ok(()),
+        Value::Bool(b) => sheet.write_boolean(row, col, *b),
+        Value::Number(n) => sheet.write_number(row, col, n.as_f64().unwrap_or_default()),
+        Value::String(s) => sheet.write_string(row, col, s),
+        Value::Array(values) => {
+            let joined: Vec<String> = values.iter().map(cell_text).collect();
+            sheet.write_string(row, col, joined.join(&VALUE_SEPARATOR.to_string()))
+        }
+        Value::Object(_) => sheet.write_string(row, col, value.to_string()),
+    };
+    result
+        .map(|_| ())
+        .map_err(|e| xlsx_error(&format!("Failed to write cell {row}:{col}"), &e))
+}
+
+fn write_cell(sheet: &mut Worksheet, row: u32, col: u16, text: &str) -> Result<()> {
+    sheet
+        .write_string(row, col, text)
+        .map(|_| ())
+        .map_err(|e| xlsx_error(&format!("Failed to write cell {row}:{col}"), &e))
+}
+
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn xlsx_error(context: &str, error: &rust_xlsxwriter::XlsxError) -> LinkMLError {
+    LinkMLError::other(format!("{context}: {error}"))
+}
+
+/// Problem with a row of a data sheet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataSheetsIssue {
+    /// Sheet, named after the class
+    pub sheet: String,
+    /// Row number as shown by spreadsheet applications, starting at 1
+    pub row: usize,
+    /// Column header, if the problem is with one column
+    pub column: Option<String>,
+    /// What is wrong
+    pub message: String,
+}
+
+impl std::fmt::Display for DataSheetsIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} row {}", self.sheet, self.row)?;
+        if let Some(column) = &self.column {
+            write!(f, " column '{column}'")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Instances loaded from a data-entry workbook
+#[derive(Debug, Clone, Default)]
+pub struct DataSheets {
+    /// One instance per non-empty row, in sheet and row order
+    pub instances: Vec<DataInstance>,
+    /// Problems found while loading and validating
+    pub issues: Vec<DataSheetsIssue>,
+}
+
+impl DataSheets {
+    /// Whether every row loaded and validated without errors
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Loader of filled data-entry workbooks
+#[derive(Debug, Clone)]
+pub struct DataSheetsLoader {
+    validate: bool,
+}
+
+impl Default for DataSheetsLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DataSheetsLoader {
+    /// Create a loader validating every instance against its class
+    #[must_use]
+    pub fn new() -> Self {
+        Self { validate: true }
+    }
+
+    /// Whether to validate the loaded instances
+    #[must_use]
+    pub fn with_validation(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Load a workbook file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or validation cannot run
+    pub async fn load_file(&self, schema: &SchemaDefinition, path: &Path) -> Result<DataSheets> {
+        let bytes = tokio::fs::read(path).await.map_err(|e| {
+            LinkMLError::io_error(format!("Failed to read {}: {e}", path.display()))
+        })?;
+        self.load_bytes(schema, bytes).await
+    }
+
+    /// Load a workbook held in memory
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not an Excel workbook or validation
+    /// cannot run
+    pub async fn load_bytes(
+        &self,
+        schema: &SchemaDefinition,
+        bytes: Vec<u8>,
+    ) -> Result<DataSheets> {
+        self.load_sheets(schema, workbook_sheets(bytes)?).await
+    }
+
+    /// Load sheets given as name and cell range, e.g. read from a Google
+    /// spreadsheet
+    ///
+    /// Sheets not named after a class of the schema are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation cannot run
+    pub async fn load_sheets(
+        &self,
+        schema: &SchemaDefinition,
+        sheets: impl IntoIterator<Item = (String, Range<Data>)>,
+    ) -> Result<DataSheets> {
+        let mut loaded = DataSheets::default();
+        // Row number of each instance for reporting validation issues
+        let mut rows = Vec::new();
+        for (sheet_name, range) in sheets {
+            if !schema.classes.contains_key(&sheet_name) {
+                continue;
+            }
+            for (row, instance) in read_sheet(schema, &sheet_name, &range, &mut loaded.issues) {
+                rows.push(row);
+                loaded.instances.push(instance);
+            }
+        }
+
+        if self.validate && !loaded.instances.is_empty() {
+            let engine = ValidationEngine::new(schema)?;
+            for (instance, row) in loaded.instances.iter().zip(rows) {
+                let data = Value::Object(instance.data.clone().into_iter().collect());
+                let report = engine
+                    .validate_as_class(&data, &instance.class_name, None)
+                    .await?;
+                loaded.issues.extend(
+                    report
+                        .issues
+                        .into_iter()
+                        .filter(|issue| issue.severity == Severity::Error)
+                        .map(|issue| DataSheetsIssue {
+                            sheet: instance.class_name.clone(),
+                            row,
+                            column: issue_column(&issue.path),
+                            message: issue.message,
+                        }),
+                );
+            }
+        }
+        Ok(loaded)
+    }
+}
+
+/// Instances of the non-empty rows of a class sheet with their row numbers
+fn read_sheet(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    range: &Range<Data>,
+    issues: &mut Vec<DataSheetsIssue>,
+) -> Vec<(usize, DataInstance)> {
+    let mut rows = range.rows();
+    let Some(headers) = rows.next() else {
+        return Vec::new();
+    };
+    let slots: HashMap<String, SlotDefinition> = induced_slots(schema, class_name)
+        .into_iter()
+        .map(|slot| (slot.name.clone(), slot))
+        .collect();
+    let columns: Vec<Option<&SlotDefinition>> = headers
+        .iter()
+        .map(|header| {
+            let header = header.to_string();
+            let header = header.trim();
+            if header.is_empty() {
+                return None;
+            }
+            let slot = slots.get(header);
+            if slot.is_none() {
+                issues.push(DataSheetsIssue {
+                    sheet: class_name.to_string(),
+                    row: 1,
+                    column: Some(header.to_string()),
+                    message: format!("'{header}' is not a slot of class '{class_name}'"),
+                });
+            }
+            slot
+        })
+        .collect();
+    let identifier = slots
+        .values()
+        .find(|slot| slot.identifier == Some(true))
+        .map(|slot| slot.name.clone());
+
+    (2..)
+        .zip(rows)
+        .filter_map(|(row, cells)| {
+            let data: HashMap<String, Value> = columns
+                .iter()
+                .zip(cells)
+                .filter_map(|(slot, cell)| {
+                    let slot = (*slot)?;
+                    cell_value(schema, slot, cell).map(|value| (slot.name.clone(), value))
+                })
+                .collect();
+            if data.is_empty() {
+                return None;
+            }
+            let id = identifier
+                .as_ref()
+                .and_then(|key| data.get(key))
+                .map(cell_text);
+            let metadata = HashMap::from([("row".to_string(), row.to_string())]);
+            Some((
+                row,
+                DataInstance {
+                    class_name: class_name.to_string(),
+                    data,
+                    id,
+                    metadata,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Value of a cell converted to the range of its slot; `None` for an empty
+/// cell
+///
+/// Text that does not convert is kept as a string, so validation reports it.
+fn cell_value(schema: &SchemaDefinition, slot: &SlotDefinition, cell: &Data) -> Option<Value> {
+    if matches!(cell, Data::Empty) || cell.to_string().trim().is_empty() {
+        return None;
+    }
+    let range = base_range(schema, slot.range.as_deref().unwrap_or("string"));
+    if slot.multivalued == Some(true) {
+        let values = cell
+            .to_string()
+            .split(VALUE_SEPARATOR)
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .map(|text| text_value(range, text))
+            .collect();
+        return Some(Value::Array(values));
+    }
+    Some(match (range, cell) {
+        ("integer", Data::Int(i)) => Value::from(*i),
+        #[allow(clippy::cast_possible_truncation)]
+        ("integer", Data::Float(f)) if f.fract() == 0.0 => Value::from(*f as i64),
+        ("float" | "double" | "decimal", Data::Int(i)) => Value::from(*i),
+        ("float" | "double" | "decimal", Data::Float(f)) => Value::from(*f),
+        ("boolean", Data::Bool(b)) => Value::Bool(*b),
+        (range, cell) => text_value(range, cell.to_string().trim()),
+    })
+}
+
+fn text_value(range: &str, text: &str) -> Value {
+    let converted = match range {
+        "integer" => text.parse::<i64>().ok().map(Value::from),
+        "float" | "double" | "decimal" => text.parse::<f64>().ok().map(Value::from),
+        "boolean" => match text.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "1" => Some(Value::Bool(true)),
+            "false" | "no" | "0" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        _ => None,
+    };
+    converted.unwrap_or_else(|| Value::String(text.to_string()))
+}
+
+/// Built-in type a range resolves to through `typeof`
+fn base_range<'a>(schema: &'a SchemaDefinition, range: &'a str) -> &'a str {
+    let mut range = range;
+    for _ in 0..=schema.types.len() {
+        match schema.types.get(range).and_then(|t| t.base_type.as_deref()) {
+            Some(base) => range = base,
+            None => break,
+        }
+    }
+    range
+}
+
+/// Column of a validation issue from its JSON path, e.g. `$.age`
+fn issue_column(path: &str) -> Option<String> {
+    let column = path
+        .trim_start_matches('$')
+        .trim_start_matches('.')
+        .split(['.', '['])
+        .next()?;
+    (!column.is_empty()).then(|| column.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::{ClassDefinition, EnumDefinition, PermissibleValue};
+    use serde_json::json;
+
+    fn schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/people".to_string(),
+            name: "people".to_string(),
+            ..Default::default()
+        };
+        let mut person = ClassDefinition {
+            name: "Person".to_string(),
+            ..Default::default()
+        };
+        for (name, slot) in [
+            (
+                "id",
+                SlotDefinition {
+                    identifier: Some(true),
+                    ..Default::default()
+                },
+            ),
+            (
+                "name",
+                SlotDefinition {
+                    required: Some(true),
+                    description: Some("Full name".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "age",
+                SlotDefinition {
+                    range: Some("integer".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "status",
+                SlotDefinition {
+                    range: Some("Status".to_string()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "nicknames",
+                SlotDefinition {
+                    multivalued: Some(true),
+                    ..Default::default()
+                },
+            ),
+        ] {
+            person.attributes.insert(
+                name.to_string(),
+                SlotDefinition {
+                    name: name.to_string(),
+                    ..slot
+                },
+            );
+        }
+        schema.classes.insert("Person".to_string(), person);
+        schema.enums.insert(
+            "Status".to_string(),
+            EnumDefinition {
+                name: "Status".to_string(),
+                permissible_values: vec![
+                    PermissibleValue::Simple("active".to_string()),
+                    PermissibleValue::Simple("retired".to_string()),
+                ],
+                ..Default::default()
+            },
+        );
+        schema
+    }
+
+    fn person(data: Value) -> DataInstance {
+        DataInstance {
+            class_name: "Person".to_string(),
+            data: serde_json::from_value(data).expect("object"),
+            id: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_data_sheets_round_trip() -> anyhow::Result<()> {
+        let schema = schema();
+        let ada = person(json!({
+            "id": "p1",
+            "name": "Ada",
+            "age": 36,
+            "status": "retired",
+            "nicknames": ["Countess", "Enchantress"],
+        }));
+        let bytes = DataSheetsGenerator::new().generate_buffer(&schema, &[ada])?;
+
+        let sheets = workbook_sheets(bytes.clone())?;
+        let names: Vec<&str> = sheets.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["Person", LISTS_SHEET]);
+        let headers: Vec<String> = sheets[0]
+            .1
+            .rows()
+            .next()
+            .into_iter()
+            .flatten()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(headers, ["id", "name", "age", "status", "nicknames"]);
+        assert_eq!(
+            sheets[1].1.get_value((2, 0)),
+            Some(&Data::String("retired".to_string()))
+        );
+
+        let loaded = DataSheetsLoader::new().load_bytes(&schema, bytes).await?;
+        assert!(loaded.is_valid(), "{:?}", loaded.issues);
+        let instance = &loaded.instances[0];
+        assert_eq!(instance.id.as_deref(), Some("p1"));
+        assert_eq!(instance.data["age"], json!(36));
+        assert_eq!(
+            instance.data["nicknames"],
+            json!(["Countess", "Enchantress"])
+        );
+        assert_eq!(instance.metadata["row"], "2");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_filled_rows_are_validated() -> anyhow::Result<()> {
+        let schema = schema();
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet().set_name("Person")?;
+        for (col, header) in (0..).zip(["id", "name", "age", "shoe_size"]) {
+            sheet.write_string(0, col, header)?;
+        }
+        sheet.write_string(1, 0, "p1")?;
+        sheet.write_string(1, 1, "Ada")?;
+        sheet.write_string(1, 2, "36")?;
+        // Row 3 lacks the required name; row 4 is empty and skipped
+        sheet.write_string(2, 0, "p2")?;
+        sheet.write_number(2, 2, 41)?;
+        sheet.write_string(4, 0, "p3")?;
+        sheet.write_string(4, 1, "Grace")?;
+        let bytes = workbook.save_to_buffer()?;
+
+        let loaded = DataSheetsLoader::new().load_bytes(&schema, bytes).await?;
+        assert_eq!(loaded.instances.len(), 3);
+        assert_eq!(loaded.instances[0].data["age"], json!(36));
+        assert_eq!(loaded.instances[1].data["age"], json!(41));
+        assert_eq!(loaded.instances[2].metadata["row"], "5");
+        assert!(
+            loaded
+                .issues
+                .iter()
+                .any(|issue| issue.row == 1 && issue.column.as_deref() == Some("shoe_size"))
+        );
+        assert!(
+            loaded
+                .issues
+                .iter()
+                .any(|issue| issue.row == 3 && issue.message.contains("name"))
+        );
+        assert!(!loaded.issues.iter().any(|issue| issue.row == 2));
+        Ok(())
+    }
+}
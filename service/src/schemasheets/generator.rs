@@ -166,6 +166,38 @@ impl SchemaSheetsGenerator {
     /// - The Excel file cannot be created or written
     /// - The schema contains invalid data
     pub async fn generate_file(&self, schema: &SchemaDefinition, output_path: &Path) -> Result<()> {
+        let buffer = self.generate_buffer(schema)?;
+
+        // Ensure parent directory exists
+        if let Some(parent) = output_path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                LinkMLError::IoError(std::io::Error::new(
+                    e.kind(),
+                    format!("Failed to create parent directory: {e}"),
+                ))
+            })?;
+        }
+
+        // Write buffer to file asynchronously
+        tokio::fs::write(output_path, buffer).await.map_err(|e| {
+            LinkMLError::IoError(std::io::Error::new(
+                e.kind(),
+                format!(
+                    "Failed to write Excel file to {}: {e}",
+                    output_path.display()
+                ),
+            ))
+        })?;
+
+        Ok(())
+    }
+
+    /// Generate a SchemaSheets format Excel workbook in memory
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the workbook cannot be built
+    pub fn generate_buffer(&self, schema: &SchemaDefinition) -> Result<Vec<u8>> {
         let mut workbook = Workbook::new();
 
         // Generate main schema sheet
@@ -814,32 +846,9 @@ impl SchemaSheetsGenerator {
         }
 
         // Generate workbook to buffer
-        let buffer = workbook
+        workbook
             .save_to_buffer()
-            .map_err(|e| LinkMLError::other(format!("Failed to generate Excel file: {e}")))?;
-
-        // Ensure parent directory exists
-        if let Some(parent) = output_path.parent() {
-            tokio::fs::create_dir_all(parent).await.map_err(|e| {
-                LinkMLError::IoError(std::io::Error::new(
-                    e.kind(),
-                    format!("Failed to create parent directory: {e}"),
-                ))
-            })?;
-        }
-
-        // Write buffer to file asynchronously
-        tokio::fs::write(output_path, buffer).await.map_err(|e| {
-            LinkMLError::IoError(std::io::Error::new(
-                e.kind(),
-                format!(
-                    "Failed to write Excel file to {}: {e}",
-                    output_path.display()
-                ),
-            ))
-        })?;
-
-        Ok(())
+            .map_err(|e| LinkMLError::other(format!("Failed to generate Excel file: {e}")))
     }
 
     /// Add data validation dropdowns to the schema sheet
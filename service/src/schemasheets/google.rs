@@ -0,0 +1,747 @@
+//! Google Sheets as SchemaSheets source and target
+//!
+//! `sheets2schema` and `schema2sheets` accept a spreadsheet URL such as
+//! `https://docs.google.com/spreadsheets/d/<id>/edit` in place of an `.xlsx`
+//! file. [`GoogleSheetsClient`] reads every tab of the spreadsheet into the
+//! cell ranges taken by [`SchemaSheetsParser::parse_sheets`] and writes the
+//! sheets of a generated workbook back, one tab per sheet. Only cell values
+//! travel; formatting and data validation stay with Excel files.
+//!
+//! Requests authenticate as a service account, given as its JSON key file,
+//! or with an OAuth access token. Signing the token requests of a service
+//! account needs the `google-sheets` feature. The spreadsheet must be shared
+//! with the service account's e-mail address. Requests are spaced to stay
+//! within the per-minute quota of the Sheets API and retried with backoff
+//! when the API answers `429 Too Many Requests` anyway.
+
+use calamine::{Data, Range};
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
+use reqwest::{Client, Method, Response, StatusCode};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+use super::parser::workbook_sheets;
+use super::{SchemaSheetsGenerator, SchemaSheetsParser};
+
+/// Sheets API v4 endpoint
+pub const SHEETS_API_URL: &str = "https://sheets.googleapis.com/v4/spreadsheets";
+
+/// OAuth scope to read and write spreadsheets
+pub const SHEETS_SCOPE: &str = "https://www.googleapis.com/auth/spreadsheets";
+
+/// Requests per minute allowed per user by the default Sheets API quota
+pub const DEFAULT_REQUESTS_PER_MINUTE: u32 = 60;
+
+/// Environment variable naming a service account key file
+pub const CREDENTIALS_ENV: &str = "GOOGLE_APPLICATION_CREDENTIALS";
+
+/// Environment variable holding an OAuth access token
+pub const ACCESS_TOKEN_ENV: &str = "GOOGLE_OAUTH_ACCESS_TOKEN";
+
+const SPREADSHEET_URL_PREFIX: &str = "https://docs.google.com/spreadsheets/d/";
+const DEFAULT_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+const JWT_BEARER_GRANT: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+const MAX_RETRIES: u32 = 5;
+
+/// The spreadsheet ID of a Google Sheets URL
+#[must_use]
+pub fn spreadsheet_id(location: &str) -> Option<&str> {
+    let id = location
+        .strip_prefix(SPREADSHEET_URL_PREFIX)?
+        .split(['/', '?', '#'])
+        .next()?;
+    (!id.is_empty()).then_some(id)
+}
+
+/// The spreadsheet ID of a path given as a Google Sheets URL
+#[must_use]
+pub fn spreadsheet_id_of(path: &Path) -> Option<&str> {
+    path.to_str().and_then(spreadsheet_id)
+}
+
+/// Key of a service account as downloaded from the Google Cloud console
+#[derive(Clone, Deserialize)]
+pub struct ServiceAccountKey {
+    /// E-mail address of the service account
+    pub client_email: String,
+    /// PEM encoded RSA private key
+    pub private_key: String,
+    /// OAuth token endpoint
+    #[serde(default = "default_token_uri")]
+    pub token_uri: String,
+}
+
+fn default_token_uri() -> String {
+    DEFAULT_TOKEN_URI.to_string()
+}
+
+impl fmt::Debug for ServiceAccountKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServiceAccountKey")
+            .field("client_email", &self.client_email)
+            .field("token_uri", &self.token_uri)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ServiceAccountKey {
+    /// Read a JSON key file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a service
+    /// account key
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            LinkMLError::io_error(format!(
+                "Failed to read service account key {}: {e}",
+                path.display()
+            ))
+        })?;
+        serde_json::from_str(&contents).map_err(|e| {
+            LinkMLError::config(format!(
+                "{} is not a service account key: {e}",
+                path.display()
+            ))
+        })
+    }
+}
+
+/// How requests to the Sheets API authenticate
+#[derive(Debug, Clone)]
+pub enum GoogleCredentials {
+    /// Tokens requested for a service account
+    ServiceAccount(ServiceAccountKey),
+    /// A ready OAuth access token, e.g. from `gcloud auth print-access-token`
+    AccessToken(String),
+}
+
+impl GoogleCredentials {
+    /// Credentials from a key file, else the key file named by
+    /// `GOOGLE_APPLICATION_CREDENTIALS`, else the token in
+    /// `GOOGLE_OAUTH_ACCESS_TOKEN`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no credentials are configured or the key file is
+    /// invalid
+    pub fn discover(key_file: Option<&Path>) -> Result<Self> {
+        if let Some(path) = key_file {
+            return ServiceAccountKey::from_file(path).map(Self::ServiceAccount);
+        }
+        if let Some(path) = std::env::var_os(CREDENTIALS_ENV) {
+            return ServiceAccountKey::from_file(Path::new(&path)).map(Self::ServiceAccount);
+        }
+        if let Ok(token) = std::env::var(ACCESS_TOKEN_ENV) {
+            return Ok(Self::AccessToken(token));
+        }
+        Err(LinkMLError::config(format!(
+            "No Google credentials: pass a service account key file or set \
+             {CREDENTIALS_ENV} or {ACCESS_TOKEN_ENV}"
+        )))
+    }
+}
+
+/// Title and tabs of a spreadsheet
+#[derive(Debug, Clone)]
+pub struct Spreadsheet {
+    /// Spreadsheet title
+    pub title: String,
+    /// Tab names and cell values in tab order
+    pub sheets: Vec<(String, Range<Data>)>,
+}
+
+/// Client of the Google Sheets API
+pub struct GoogleSheetsClient {
+    http: Client,
+    api_url: String,
+    credentials: GoogleCredentials,
+    token: Mutex<Option<(String, Instant)>>,
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl GoogleSheetsClient {
+    /// Create a client within the default request quota
+    #[must_use]
+    pub fn new(credentials: GoogleCredentials) -> Self {
+        Self {
+            http: Client::new(),
+            api_url: SHEETS_API_URL.to_string(),
+            credentials,
+            token: Mutex::new(None),
+            min_interval: Duration::ZERO,
+            last_request: Mutex::new(None),
+        }
+        .with_requests_per_minute(DEFAULT_REQUESTS_PER_MINUTE)
+    }
+
+    /// Send requests to another Sheets API endpoint
+    #[must_use]
+    pub fn with_api_url(mut self, api_url: impl Into<String>) -> Self {
+        self.api_url = api_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Space requests to send at most this many per minute; 0 sends them
+    /// unthrottled
+    #[must_use]
+    pub fn with_requests_per_minute(mut self, requests_per_minute: u32) -> Self {
+        self.min_interval = if requests_per_minute == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_millis(60_000 / u64::from(requests_per_minute))
+        };
+        self
+    }
+
+    /// Read the values of every tab of a spreadsheet
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if authentication fails or the API rejects a request
+    pub async fn read(&self, spreadsheet_id: &str) -> Result<Spreadsheet> {
+        let (title, tabs) = self.tabs(spreadsheet_id).await?;
+        if tabs.is_empty() {
+            return Ok(Spreadsheet {
+                title,
+                sheets: Vec::new(),
+            });
+        }
+
+        let mut query: Vec<(&str, String)> =
+            tabs.iter().map(|tab| ("ranges", a1_tab(tab))).collect();
+        query.push(("valueRenderOption", "UNFORMATTED_VALUE".to_string()));
+        query.push(("majorDimension", "ROWS".to_string()));
+        let url = format!("{}/{spreadsheet_id}/values:batchGet", self.api_url);
+        let response = self.send(Method::GET, &url, &query, None).await?;
+
+        let value_ranges = response["valueRanges"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        let sheets = tabs
+            .into_iter()
+            .enumerate()
+            .map(|(index, tab)| {
+                let rows = value_ranges
+                    .get(index)
+                    .and_then(|range| range["values"].as_array())
+                    .map_or(&[][..], Vec::as_slice);
+                (tab, values_range(rows))
+            })
+            .collect();
+        Ok(Spreadsheet { title, sheets })
+    }
+
+    /// Replace the values of the given tabs, adding tabs that do not exist
+    ///
+    /// Other tabs of the spreadsheet are left alone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if authentication fails or the API rejects a request
+    pub async fn write(
+        &self,
+        spreadsheet_id: &str,
+        sheets: &[(String, Range<Data>)],
+    ) -> Result<()> {
+        let (_, tabs) = self.tabs(spreadsheet_id).await?;
+        let new_tabs: Vec<Value> = sheets
+            .iter()
+            .filter(|(name, _)| !tabs.contains(name))
+            .map(|(name, _)| json!({ "addSheet": { "properties": { "title": name } } }))
+            .collect();
+        if !new_tabs.is_empty() {
+            let url = format!("{}/{spreadsheet_id}:batchUpdate", self.api_url);
+            self.send(
+                Method::POST,
+                &url,
+                &[],
+                Some(&json!({ "requests": new_tabs })),
+            )
+            .await?;
+        }
+
+        let ranges: Vec<String> = sheets.iter().map(|(name, _)| a1_tab(name)).collect();
+        let url = format!("{}/{spreadsheet_id}/values:batchClear", self.api_url);
+        self.send(Method::POST, &url, &[], Some(&json!({ "ranges": ranges })))
+            .await?;
+
+        let data: Vec<Value> = sheets
+            .iter()
+            .map(|(name, range)| {
+                json!({
+                    "range": a1_tab(name),
+                    "majorDimension": "ROWS",
+                    "values": range_values(range),
+                })
+            })
+            .collect();
+        let body = json!({ "valueInputOption": "RAW", "data": data });
+        let url = format!("{}/{spreadsheet_id}/values:batchUpdate", self.api_url);
+        self.send(Method::POST, &url, &[], Some(&body)).await?;
+        Ok(())
+    }
+
+    /// Parse a spreadsheet in SchemaSheets format
+    ///
+    /// The schema ID defaults to the spreadsheet title.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the spreadsheet or parsing a tab fails
+    pub async fn read_schema(
+        &self,
+        spreadsheet_id: &str,
+        parser: &SchemaSheetsParser,
+        schema_id: Option<&str>,
+    ) -> Result<SchemaDefinition> {
+        let spreadsheet = self.read(spreadsheet_id).await?;
+        let schema_id = schema_id.map_or_else(|| title_id(&spreadsheet.title), str::to_string);
+        parser.parse_sheets(spreadsheet.sheets, &schema_id)
+    }
+
+    /// Write a schema to a spreadsheet in SchemaSheets format
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if generating the sheets or writing them fails
+    pub async fn write_schema(
+        &self,
+        spreadsheet_id: &str,
+        generator: &SchemaSheetsGenerator,
+        schema: &SchemaDefinition,
+    ) -> Result<()> {
+        let sheets = workbook_sheets(generator.generate_buffer(schema)?)?;
+        self.write(spreadsheet_id, &sheets).await
+    }
+
+    /// Title and tab names of a spreadsheet
+    async fn tabs(&self, spreadsheet_id: &str) -> Result<(String, Vec<String>)> {
+        let url = format!("{}/{spreadsheet_id}", self.api_url);
+        let query = [(
+            "fields",
+            "properties.title,sheets.properties.title".to_string(),
+        )];
+        let response = self.send(Method::GET, &url, &query, None).await?;
+        let title = response["properties"]["title"]
+            .as_str()
+            .unwrap_or(spreadsheet_id)
+            .to_string();
+        let tabs = response["sheets"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|sheet| sheet["properties"]["title"].as_str())
+            .map(str::to_string)
+            .collect();
+        Ok((title, tabs))
+    }
+
+    /// Send a request within the quota, retrying when the API is throttling
+    async fn send(
+        &self,
+        method: Method,
+        url: &str,
+        query: &[(&str, String)],
+        body: Option<&Value>,
+    ) -> Result<Value> {
+        let mut attempt = 0;
+        loop {
+            self.wait_turn().await;
+            let token = self.access_token().await?;
+            let mut request = self
+                .http
+                .request(method.clone(), url)
+                .bearer_auth(token)
+                .query(query);
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+            let response = request
+                .send()
+                .await
+                .map_err(|e| LinkMLError::service(format!("Google Sheets request failed: {e}")))?;
+
+            let status = response.status();
+            if (status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error())
+                && attempt < MAX_RETRIES
+            {
+                let delay = retry_after(&response)
+                    .unwrap_or_else(|| Duration::from_millis(500) * 2u32.pow(attempt));
+                attempt += 1;
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+            return read_json(response).await;
+        }
+    }
+
+    /// Wait until the next request fits the quota
+    async fn wait_turn(&self) {
+        if self.min_interval.is_zero() {
+            return;
+        }
+        // Holding the lock while sleeping queues concurrent requests
+        let mut last_request = self.last_request.lock().await;
+        if let Some(last) = *last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                tokio::time::sleep(self.min_interval.saturating_sub(elapsed)).await;
+            }
+        }
+        *last_request = Some(Instant::now());
+    }
+
+    /// Access token, requesting a new one for a service account when the
+    /// cached token is about to expire
+    async fn access_token(&self) -> Result<String> {
+        let key = match &self.credentials {
+            GoogleCredentials::AccessToken(token) => return Ok(token.clone()),
+            GoogleCredentials::ServiceAccount(key) => key,
+        };
+        let mut cached = self.token.lock().await;
+        if let Some((token, expires)) = cached.as_ref() {
+            if Instant::now() < *expires {
+                return Ok(token.clone());
+            }
+        }
+
+        #[derive(Deserialize)]
+        struct TokenResponse {
+            access_token: String,
+            expires_in: u64,
+        }
+
+        let assertion = sign_assertion(key)?;
+        let response = self
+            .http
+            .post(&key.token_uri)
+            .form(&[
+                ("grant_type", JWT_BEARER_GRANT),
+                ("assertion", assertion.as_str()),
+            ])
+            .send()
+            .await
+            .map_err(|e| LinkMLError::service(format!("Google token request failed: {e}")))?;
+        let response: TokenResponse = serde_json::from_value(read_json(response).await?)
+            .map_err(|e| LinkMLError::service(format!("Invalid Google token response: {e}")))?;
+        let expires = Instant::now() + Duration::from_secs(response.expires_in.saturating_sub(60));
+        *cached = Some((response.access_token.clone(), expires));
+        Ok(response.access_token)
+    }
+}
+
+/// Signed token request of a service account
+#[cfg(feature = "google-sheets")]
+fn sign_assertion(key: &ServiceAccountKey) -> Result<String> {
+    use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+
+    let now = chrono::Utc::now().timestamp();
+    let claims = json!({
+        "iss": key.client_email,
+        "scope": SHEETS_SCOPE,
+        "aud": key.token_uri,
+        "iat": now,
+        "exp": now + 3600,
+    });
+    let signing_key = EncodingKey::from_rsa_pem(key.private_key.as_bytes()).map_err(|e| {
+        LinkMLError::config(format!(
+            "Invalid private key of service account {}: {e}",
+            key.client_email
+        ))
+    })?;
+    encode(&Header::new(Algorithm::RS256), &claims, &signing_key)
+        .map_err(|e| LinkMLError::service(format!("Failed to sign token request: {e}")))
+}
+
+#[cfg(not(feature = "google-sheets"))]
+fn sign_assertion(key: &ServiceAccountKey) -> Result<String> {
+    Err(LinkMLError::config(format!(
+        "Authenticating as service account {} requires the google-sheets feature; \
+         set {ACCESS_TOKEN_ENV} to use an access token instead",
+        key.client_email
+    )))
+}
+
+/// Body of a successful response, or the API's error message
+async fn read_json(response: Response) -> Result<Value> {
+    let status = response.status();
+    let text = response
+        .text()
+        .await
+        .map_err(|e| LinkMLError::service(format!("Failed to read Google API response: {e}")))?;
+    let body: Value = serde_json::from_str(&text).unwrap_or(Value::Null);
+    if status.is_success() {
+        return Ok(body);
+    }
+    let message = body["error"]["message"]
+        .as_str()
+        .or_else(|| body["error_description"].as_str())
+        .unwrap_or(&text);
+    Err(LinkMLError::service(format!(
+        "Google API request failed with {status}: {message}"
+    )))
+}
+
+/// Delay requested by a `Retry-After` header in seconds
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// A1 notation of a whole tab
+fn a1_tab(name: &str) -> String {
+    format!("'{}'", name.replace('\'', "''"))
+}
+
+/// Schema ID derived from a spreadsheet title
+fn title_id(title: &str) -> String {
+    let id: String = title
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if id.is_empty() {
+        "schema".to_string()
+    } else {
+        id
+    }
+}
+
+/// Cell range of the rows returned by the API
+fn values_range(rows: &[Value]) -> Range<Data> {
+    let width = rows
+        .iter()
+        .filter_map(Value::as_array)
+        .map(Vec::len)
+        .max()
+        .unwrap_or(0);
+    if rows.is_empty() || width == 0 {
+        return Range::empty();
+    }
+    let last_row = u32::try_from(rows.len() - 1).unwrap_or(u32::MAX);
+    let last_col = u32::try_from(width - 1).unwrap_or(u32::MAX);
+    let mut range = Range::new((0, 0), (last_row, last_col));
+    for (row, cells) in (0..=last_row).zip(rows) {
+        for (col, cell) in (0..=last_col).zip(cells.as_array().into_iter().flatten()) {
+            range.set_value((row, col), cell_data(cell));
+        }
+    }
+    range
+}
+
+fn cell_data(cell: &Value) -> Data {
+    match cell {
+        Value::Null => Data::Empty,
+        Value::Bool(b) => Data::Bool(*b),
+        Value::Number(n) => n
+            .as_i64()
+            .map_or_else(|| Data::Float(n.as_f64().unwrap_or_default()), Data::Int),
+        Value::String(s) if s.is_empty() => Data::Empty,
+        Value::String(s) => Data::String(s.clone()),
+        other => Data::String(other.to_string()),
+    }
+}
+
+/// Rows of a cell range as sent to the API
+fn range_values(range: &Range<Data>) -> Vec<Vec<Value>> {
+    range
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|cell| match cell {
+                    Data::Empty => json!(""),
+                    Data::Bool(b) => json!(b),
+                    Data::Int(i) => json!(i),
+                    Data::Float(f) => json!(f),
+                    Data::String(s) => json!(s),
+                    other => json!(other.to_string()),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::body::Bytes;
+    use axum::extract::State;
+    use axum::http::{HeaderMap, Uri};
+    use axum::response::{IntoResponse, Response as AxumResponse};
+    use linkml_core::types::{ClassDefinition, SlotDefinition};
+    use std::sync::Arc;
+
+    /// In-memory spreadsheet answering like the Sheets API
+    #[derive(Default)]
+    struct FakeSheets {
+        tabs: Vec<(String, Vec<Value>)>,
+        requests: usize,
+    }
+
+    async fn handle(
+        State(sheets): State<Arc<parking_lot::Mutex<FakeSheets>>>,
+        method: Method,
+        uri: Uri,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> AxumResponse {
+        let mut sheets = sheets.lock();
+        sheets.requests += 1;
+        if sheets.requests == 1 {
+            return (StatusCode::TOO_MANY_REQUESTS, [("retry-after", "0")]).into_response();
+        }
+        if headers.get("authorization").and_then(|v| v.to_str().ok()) != Some("Bearer token") {
+            return StatusCode::UNAUTHORIZED.into_response();
+        }
+        let body: Value = serde_json::from_slice(&body).unwrap_or(Value::Null);
+        let tab = |range: &str| range.trim_matches('\'').replace("''", "'");
+        let response = match (method, uri.path()) {
+            (Method::GET, "/sheet-1") => json!({
+                "properties": { "title": "Sales Model" },
+                "sheets": sheets.tabs.iter()
+                    .map(|(name, _)| json!({ "properties": { "title": name } }))
+                    .collect::<Vec<_>>(),
+            }),
+            (Method::GET, "/sheet-1/values:batchGet") => json!({
+                "valueRanges": sheets.tabs.iter()
+                    .map(|(_, rows)| json!({ "values": rows }))
+                    .collect::<Vec<_>>(),
+            }),
+            (Method::POST, "/sheet-1:batchUpdate") => {
+                for request in body["requests"].as_array().into_iter().flatten() {
+                    let name = request["addSheet"]["properties"]["title"]
+                        .as_str()
+                        .unwrap_or_default();
+                    sheets.tabs.push((name.to_string(), Vec::new()));
+                }
+                json!({})
+            }
+            (Method::POST, "/sheet-1/values:batchClear") => {
+                for range in body["ranges"].as_array().into_iter().flatten() {
+                    let name = tab(range.as_str().unwrap_or_default());
+                    for (_, rows) in sheets.tabs.iter_mut().filter(|(n, _)| *n == name) {
+                        rows.clear();
+                    }
+                }
+                json!({})
+            }
+            (Method::POST, "/sheet-1/values:batchUpdate") => {
+                assert_eq!(body["valueInputOption"], "RAW");
+                for data in body["data"].as_array().into_iter().flatten() {
+                    let name = tab(data["range"].as_str().unwrap_or_default());
+                    let values = data["values"].as_array().cloned().unwrap_or_default();
+                    for (_, rows) in sheets.tabs.iter_mut().filter(|(n, _)| *n == name) {
+                        rows.clone_from(&values);
+                    }
+                }
+                json!({})
+            }
+            _ => return StatusCode::NOT_FOUND.into_response(),
+        };
+        axum::Json(response).into_response()
+    }
+
+    #[test]
+    fn test_spreadsheet_id() {
+        assert_eq!(
+            spreadsheet_id("https://docs.google.com/spreadsheets/d/1AbC-d_9/edit#gid=0"),
+            Some("1AbC-d_9")
+        );
+        assert_eq!(
+            spreadsheet_id_of(Path::new("https://docs.google.com/spreadsheets/d/1AbC")),
+            Some("1AbC")
+        );
+        assert_eq!(spreadsheet_id("schema.xlsx"), None);
+        assert_eq!(
+            spreadsheet_id("https://docs.google.com/spreadsheets/d/"),
+            None
+        );
+        assert_eq!(title_id("Sales Model"), "sales_model");
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_through_sheets_api() -> anyhow::Result<()> {
+        let fake = Arc::new(parking_lot::Mutex::new(FakeSheets {
+            tabs: vec![("Notes".to_string(), vec![json!(["keep me"])])],
+            requests: 0,
+        }));
+        let router = Router::new().fallback(handle).with_state(Arc::clone(&fake));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        let client = GoogleSheetsClient::new(GoogleCredentials::AccessToken("token".to_string()))
+            .with_api_url(format!("http://{address}/"))
+            .with_requests_per_minute(6000);
+
+        let mut schema = SchemaDefinition {
+            id: "sales".to_string(),
+            name: "sales".to_string(),
+            ..Default::default()
+        };
+        let mut order = ClassDefinition {
+            name: "Order".to_string(),
+            description: Some("A customer order".to_string()),
+            ..Default::default()
+        };
+        order.attributes.insert(
+            "quantity".to_string(),
+            SlotDefinition {
+                name: "quantity".to_string(),
+                range: Some("integer".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert("Order".to_string(), order);
+
+        client
+            .write_schema("sheet-1", &SchemaSheetsGenerator::new(), &schema)
+            .await?;
+        {
+            let fake = fake.lock();
+            assert!(fake.tabs.len() > 1, "generated sheets are added as tabs");
+            assert_eq!(fake.tabs[0].1, vec![json!(["keep me"])]);
+        }
+
+        let parsed = client
+            .read_schema("sheet-1", &SchemaSheetsParser::new(), None)
+            .await?;
+        // The settings tab carries the schema ID over the spreadsheet title
+        assert_eq!(parsed.id, "sales");
+        let order = &parsed.classes["Order"];
+        assert_eq!(order.description.as_deref(), Some("A customer order"));
+        assert_eq!(
+            order.attributes["quantity"].range.as_deref(),
+            Some("integer")
+        );
+
+        let unauthorized =
+            GoogleSheetsClient::new(GoogleCredentials::AccessToken("other".to_string()))
+                .with_api_url(format!("http://{address}"))
+                .with_requests_per_minute(0);
+        assert!(unauthorized.read("sheet-1").await.is_err());
+        Ok(())
+    }
+}
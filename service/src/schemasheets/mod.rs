@@ -44,13 +44,21 @@
 //! - `parser`: Parse SchemaSheets format Excel files into LinkML schemas
 //! - `generator`: Generate SchemaSheets format Excel files from LinkML schemas
 //! - `types`: Common types and utilities for SchemaSheets processing
+//! - `data`: Data-entry workbooks with a sheet per class for round-tripping
+//!   instance data
+//! - `google`: Read and write SchemaSheets in Google spreadsheets through the
+//!   Sheets API
 
 pub mod config;
+pub mod data;
 pub mod generator;
+pub mod google;
 pub mod parser;
 pub mod types;
 
 pub use config::SchemaSheetsConfig;
+pub use data::{DataSheets, DataSheetsGenerator, DataSheetsIssue, DataSheetsLoader};
 pub use generator::SchemaSheetsGenerator;
+pub use google::{GoogleCredentials, GoogleSheetsClient};
 pub use parser::SchemaSheetsParser;
 pub use types::{SchemaSheetRow, SchemaSheetType};
@@ -4,7 +4,7 @@
 //! preserving all metadata for lossless roundtrip conversion.
 
 use super::types::{ColumnMapping, SchemaSheetRow, SchemaSheetType};
-use calamine::{Data, Reader, Xlsx};
+use calamine::{Data, Range, Reader, Xlsx};
 use linkml_core::error::{LinkMLError, Result};
 use linkml_core::types::{
     ClassDefinition, EnumDefinition, PermissibleValue, PrefixDefinition, SchemaDefinition,
@@ -52,10 +52,6 @@ impl SchemaSheetsParser {
         let file_bytes = std::fs::read(path)
             .map_err(|e| LinkMLError::io_error(format!("Failed to read file: {e}")))?;
 
-        let cursor = Cursor::new(file_bytes);
-        let mut workbook: Xlsx<_> = Xlsx::new(cursor)
-            .map_err(|e| LinkMLError::parse(format!("Failed to parse Excel file: {e}")))?;
-
         // Determine schema ID
         let schema_id = schema_id.unwrap_or_else(|| {
             path.file_stem()
@@ -63,28 +59,50 @@ impl SchemaSheetsParser {
                 .unwrap_or("schema")
         });
 
-        // Parse all sheets
+        self.parse_bytes(file_bytes, schema_id)
+    }
+
+    /// Parse an Excel workbook held in memory
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the bytes are not an Excel workbook or a sheet is
+    /// invalid (in strict mode)
+    pub fn parse_bytes(&self, bytes: Vec<u8>, schema_id: &str) -> Result<SchemaDefinition> {
+        self.parse_sheets(workbook_sheets(bytes)?, schema_id)
+    }
+
+    /// Parse sheets given as name and cell range in workbook order
+    ///
+    /// This is the entry point for sheets that do not come from an Excel
+    /// file, such as a Google spreadsheet.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a sheet is invalid (in strict mode)
+    pub fn parse_sheets(
+        &self,
+        sheets: impl IntoIterator<Item = (String, Range<Data>)>,
+        schema_id: &str,
+    ) -> Result<SchemaDefinition> {
         let mut schema = SchemaDefinition {
             id: schema_id.to_string(),
             name: schema_id.to_string(),
             ..Default::default()
         };
 
-        // Get sheet names
-        let sheet_names = workbook.sheet_names().clone();
-
-        for sheet_name in sheet_names {
+        for (sheet_name, range) in sheets {
             // Skip metadata sheets
             if sheet_name.to_lowercase() == "prefixes"
                 || sheet_name.to_lowercase() == "types"
                 || sheet_name.to_lowercase() == "settings"
             {
-                self.parse_metadata_sheet(&mut workbook, &sheet_name, &mut schema)?;
+                self.parse_metadata_sheet(&range, &sheet_name, &mut schema)?;
                 continue;
             }
 
             // Parse schema content sheet
-            self.parse_schema_sheet(&mut workbook, &sheet_name, &mut schema)?;
+            self.parse_schema_sheet(&range, &sheet_name, &mut schema)?;
         }
 
         Ok(schema)
@@ -93,18 +111,14 @@ impl SchemaSheetsParser {
     /// Parse a metadata sheet (prefixes, types, settings)
     fn parse_metadata_sheet(
         &self,
-        workbook: &mut Xlsx<Cursor<Vec<u8>>>,
+        range: &Range<Data>,
         sheet_name: &str,
         schema: &mut SchemaDefinition,
     ) -> Result<()> {
-        let range = workbook
-            .worksheet_range(sheet_name)
-            .map_err(|e| LinkMLError::parse(format!("Failed to read sheet '{sheet_name}': {e}")))?;
-
         match sheet_name.to_lowercase().as_str() {
-            "prefixes" => self.parse_prefixes_sheet(&range, schema),
-            "types" => self.parse_types_sheet(&range, schema),
-            "settings" => self.parse_settings_sheet(&range, schema),
+            "prefixes" => self.parse_prefixes_sheet(range, schema),
+            "types" => self.parse_types_sheet(range, schema),
+            "settings" => self.parse_settings_sheet(range, schema),
             _ => Ok(()),
         }
     }
@@ -248,14 +262,10 @@ impl SchemaSheetsParser {
     /// Parse a schema content sheet (classes, slots, enums)
     fn parse_schema_sheet(
         &self,
-        workbook: &mut Xlsx<Cursor<Vec<u8>>>,
+        range: &Range<Data>,
         sheet_name: &str,
         schema: &mut SchemaDefinition,
     ) -> Result<()> {
-        let range = workbook
-            .worksheet_range(sheet_name)
-            .map_err(|e| LinkMLError::parse(format!("Failed to read sheet '{sheet_name}': {e}")))?;
-
         let rows: Vec<Vec<Data>> = range.rows().map(<[calamine::Data]>::to_vec).collect();
         if rows.is_empty() {
             return Ok(());
@@ -656,6 +666,7 @@ impl SchemaSheetsParser {
                     .get("meaning")
                     .cloned()
                     .or_else(|| row.mappings.values().next().cloned()),
+                is_a: None,
             }
         } else {
             PermissibleValue::Simple(value.to_string())
@@ -856,6 +867,27 @@ impl SchemaSheetsParser {
     }
 }
 
+/// Sheets of an Excel workbook held in memory, in workbook order
+///
+/// # Errors
+///
+/// Returns error if the bytes are not an Excel workbook or a sheet cannot be
+/// read
+pub fn workbook_sheets(bytes: Vec<u8>) -> Result<Vec<(String, Range<Data>)>> {
+    let mut workbook: Xlsx<_> = Xlsx::new(Cursor::new(bytes))
+        .map_err(|e| LinkMLError::parse(format!("Failed to parse Excel file: {e}")))?;
+    workbook
+        .sheet_names()
+        .into_iter()
+        .map(|sheet_name| {
+            let range = workbook.worksheet_range(&sheet_name).map_err(|e| {
+                LinkMLError::parse(format!("Failed to read sheet '{sheet_name}': {e}"))
+            })?;
+            Ok((sheet_name, range))
+        })
+        .collect()
+}
+
 impl Default for SchemaSheetsParser {
     fn default() -> Self {
         Self::new()
@@ -4,7 +4,7 @@
 //! configuration instead of hardcoded values.
 
 use thiserror::Error;
-use linkml_core::configuration_v2::SecurityLimitsConfig;
+use linkml_core::configuration::SecurityLimitsConfig;
 
 /// Validation errors
 #[derive(Debug, Error)]
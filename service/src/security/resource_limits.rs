@@ -94,9 +94,7 @@ impl Default for ResourceLimits {
 impl ResourceLimits {
     /// Create resource limits from `LinkML` service configuration
     #[must_use]
-    pub fn from_service_config(
-        config: &linkml_core::configuration_v2::SecurityLimitsConfig,
-    ) -> Self {
+    pub fn from_service_config(config: &linkml_core::configuration::SecurityLimitsConfig) -> Self {
         Self {
             max_validation_time: Duration::from_millis(config.max_validation_time_ms),
             max_memory_usage: config.max_memory_usage_bytes,
@@ -371,7 +369,7 @@ mod tests {
             max_validation_time: Duration::from_millis(100),
             ..Default::default()
         };
-        let timestamp_service = timestamp_service::wiring::wire_timestamp().into_inner();
+        let timestamp_service = crate::utils::timestamp::wire_timestamp();
         let mut monitor = ResourceMonitor::new(limits, timestamp_service);
 
         // Initialize the timestamp
@@ -393,7 +391,7 @@ mod tests {
 
     #[test]
     fn test_memory_tracking() {
-        let timestamp_service = timestamp_service::wiring::wire_timestamp().into_inner();
+        let timestamp_service = crate::utils::timestamp::wire_timestamp();
         let monitor = ResourceMonitor::new(ResourceLimits::default(), timestamp_service);
 
         // Allocate some memory
@@ -411,7 +409,7 @@ mod tests {
             max_parallel_validators: 2,
             ..Default::default()
         };
-        let timestamp_service = timestamp_service::wiring::wire_timestamp().into_inner();
+        let timestamp_service = crate::utils::timestamp::wire_timestamp();
         let monitor = Arc::new(ResourceMonitor::new(limits, timestamp_service));
 
         // Start two ops (should succeed)
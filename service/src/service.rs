@@ -9,7 +9,7 @@ use linkml_core::{
     config::LinkMLConfig,
     error::{LinkMLError, Result},
     traits::{LinkMLService, LinkMLServiceExt, SchemaFormat},
-    types::{SchemaDefinition, ValidationReport},
+    types::{SchemaDefinition, ValidationReport, interned::SchemaRepresentation},
 };
 
 use crate::config::configuration_integration::{
@@ -19,6 +19,7 @@ use crate::factory::LinkMLServiceDependencies;
 use crate::integration::CacheServiceAdapter;
 use crate::parser::{ImportResolver, Parser};
 use crate::validator::cache::CompiledValidatorCache;
+use crate::validator::multi_layer_cache::{MultiLayerCache, MultiLayerCacheConfig};
 
 use parking_lot::RwLock;
 use serde_json::json;
@@ -89,6 +90,22 @@ where
     random_service: Arc<R>,
 }
 
+/// Compiled validator cache of a service
+///
+/// Backed by a persistent cache when `multi_layer_cache.l3_directory` is
+/// configured, so instances sharing the directory reuse compiled validators.
+fn compiled_validator_cache(
+    cache: &Arc<dyn CacheService<Error = cache_core::CacheError>>,
+) -> Result<Arc<CompiledValidatorCache>> {
+    let cache_adapter = Arc::new(CacheServiceAdapter::new(cache.clone()));
+    let mut validator_cache = CompiledValidatorCache::new().with_cache_service(cache_adapter);
+    if let Some(config) = MultiLayerCacheConfig::persistent(crate::config::get_config()) {
+        let persistent_cache = MultiLayerCache::new(config, None)?;
+        validator_cache = validator_cache.with_persistent_cache(Arc::new(persistent_cache));
+    }
+    Ok(Arc::new(validator_cache))
+}
+
 impl<T, E, C, O, R> LinkMLServiceImpl<T, E, C, O, R>
 where
     T: TaskManagementService,
@@ -108,9 +125,7 @@ where
         let config = Arc::new(RwLock::new(default_config));
 
         // Create validator cache with RootReal cache service integration
-        let cache_adapter = Arc::new(CacheServiceAdapter::new(deps.cache.clone()));
-        let validator_cache =
-            Arc::new(CompiledValidatorCache::new().with_cache_service(cache_adapter));
+        let validator_cache = compiled_validator_cache(&deps.cache)?;
 
         Ok(Self {
             config,
@@ -149,9 +164,7 @@ where
         let config = Arc::new(RwLock::new(config));
 
         // Create validator cache with RootReal cache service integration
-        let cache_adapter = Arc::new(CacheServiceAdapter::new(deps.cache.clone()));
-        let validator_cache =
-            Arc::new(CompiledValidatorCache::new().with_cache_service(cache_adapter));
+        let validator_cache = compiled_validator_cache(&deps.cache)?;
 
         Ok(Self {
             config,
@@ -202,6 +215,18 @@ where
             .await
             .map_err(|e| LinkMLError::service(format!("Logger error: {e}")))?;
 
+        let config_source = crate::config::config_source();
+        if config_source.is_fallback() {
+            self.logger
+                .warn(&format!(
+                    "LinkML configuration fallback: config_source=\"{config_source}\"; \
+                     set {} or pass an explicit configuration path",
+                    crate::config::CONFIG_ENV_VAR
+                ))
+                .await
+                .map_err(|e| LinkMLError::service(format!("Logger error: {e}")))?;
+        }
+
         // Register configuration hot-reload handler
         self.setup_config_reload().await?;
 
@@ -495,6 +520,17 @@ where
         &self.dbms_service
     }
 
+    /// Schema, report and audit repositories stored through the DBMS service
+    ///
+    /// Uses [`crate::storage::DEFAULT_STORAGE_DATABASE`]; call
+    /// [`crate::storage::Storage::initialize`] once before first use.
+    pub fn storage(&self) -> crate::storage::Storage {
+        crate::storage::Storage::new(Arc::new(crate::storage::DbmsRecordStore::new(
+            self.dbms_service.clone(),
+            crate::storage::DEFAULT_STORAGE_DATABASE,
+        )))
+    }
+
     /// Get the timeout service
     pub const fn timeout_service(&self) -> &Arc<O> {
         &self.timeout_service
@@ -987,6 +1023,21 @@ where
     O: TimeoutService + Send + Sync,
     R: RandomService + Send + Sync,
 {
+    /// Load a schema in the representation selected by the configuration
+    ///
+    /// With `performance.intern_strings` set (the `StringInterning`
+    /// performance feature of the service configuration), the schema is
+    /// held with its strings interned in the global string pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema cannot be loaded
+    pub async fn load_schema_representation(&self, path: &Path) -> Result<SchemaRepresentation> {
+        let schema = self.load_schema(path).await?;
+        let performance = self.config.read().performance.clone();
+        Ok(SchemaRepresentation::from_config(schema, &performance))
+    }
+
     async fn get_timestamp_nanos(&self) -> Result<i64> {
         self.timestamp
             .now_utc()
@@ -1167,6 +1218,16 @@ impl ConfigurationChangeHandler for LinkMLConfigWatcherHandler {
 
         Ok(())
     }
+
+    async fn on_restart_required(&self, settings: &[String]) -> Result<()> {
+        self.logger
+            .warn(&format!(
+                "LinkML configuration changes take effect after a restart: {}",
+                settings.join(", ")
+            ))
+            .await
+            .map_err(|e| LinkMLError::service(format!("Logger error: {e}")))
+    }
 }
 
 #[async_trait]
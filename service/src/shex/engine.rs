@@ -0,0 +1,602 @@
+//! Validation of RDF graphs against shape expressions
+
+use std::collections::{HashMap, HashSet};
+
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::error_codes::ErrorCode;
+use linkml_core::prelude::*;
+use oxigraph::io::RdfFormat;
+
+use super::shapes::{Facets, Shape, ShapeSchema, TripleConstraint, ValueExpr};
+use crate::generator::shex::ShExOptions;
+use crate::rdf::{Graph, Node};
+use crate::validator::report::{ValidationIssue, ValidationReport};
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+const VALIDATOR: &str = "shex";
+
+/// Validator checking RDF graphs against the shapes of a schema
+#[derive(Debug, Clone)]
+pub struct ShExValidator {
+    schema_id: String,
+    shapes: ShapeSchema,
+}
+
+impl ShExValidator {
+    /// Create a validator with the shapes the default `ShEx` options generate
+    #[must_use]
+    pub fn new(schema: &SchemaDefinition) -> Self {
+        Self::with_options(schema, &ShExOptions::default())
+    }
+
+    /// Create a validator with the shapes generated with `options`
+    #[must_use]
+    pub fn with_options(schema: &SchemaDefinition, options: &ShExOptions) -> Self {
+        Self {
+            schema_id: schema.id.clone(),
+            shapes: ShapeSchema::from_schema(schema, options),
+        }
+    }
+
+    /// Shapes nodes are validated against
+    #[must_use]
+    pub fn shapes(&self) -> &ShapeSchema {
+        &self.shapes
+    }
+
+    /// Validate every node of a graph against the shapes of its `rdf:type`s
+    ///
+    /// Nodes without the type of a class are not validated on their own but
+    /// only where they are the value of a slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not valid in `format`.
+    pub fn validate_str(&self, content: &str, format: RdfFormat) -> Result<ValidationReport> {
+        let graph = Graph::parse(content, format)
+            .map_err(|e| LinkMLError::parse(format!("Invalid RDF: {e}")))?;
+        let mut focus = Vec::new();
+        for subject in graph.subjects() {
+            for (class_name, shape) in &self.shapes.shapes {
+                let typed = graph
+                    .objects(subject, RDF_TYPE)
+                    .any(|node| matches!(node, Node::Iri(iri) if shape.types.first() == Some(iri)));
+                if typed {
+                    focus.push((subject.clone(), class_name.as_str()));
+                }
+            }
+        }
+        Ok(self.validate_focus(&graph, &focus))
+    }
+
+    /// Validate the nodes of a shape map, pairs of a node and the class whose
+    /// shape it must conform to
+    ///
+    /// Nodes are IRIs, or blank node labels prefixed with `_:`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not valid in `format` or a class
+    /// has no shape.
+    pub fn validate_str_with_shape_map(
+        &self,
+        content: &str,
+        format: RdfFormat,
+        shape_map: &[(&str, &str)],
+    ) -> Result<ValidationReport> {
+        let graph = Graph::parse(content, format)
+            .map_err(|e| LinkMLError::parse(format!("Invalid RDF: {e}")))?;
+        let focus = shape_map
+            .iter()
+            .map(|(node, class_name)| {
+                if !self.shapes.shapes.contains_key(*class_name) {
+                    return Err(LinkMLError::config(format!(
+                        "No shape for class '{class_name}'"
+                    )));
+                }
+                let node = match node.strip_prefix("_:") {
+                    Some(label) => Node::Blank(label.to_string()),
+                    None => Node::Iri((*node).to_string()),
+                };
+                Ok((node, *class_name))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.validate_focus(&graph, &focus))
+    }
+
+    fn validate_focus(&self, graph: &Graph, focus: &[(Node, &str)]) -> ValidationReport {
+        let mut report = ValidationReport::new(self.schema_id.clone());
+        let mut typing = Typing {
+            shapes: &self.shapes,
+            graph,
+            results: HashMap::new(),
+            assumed: HashSet::new(),
+        };
+        for (node, class_name) in focus {
+            let mut issues = Vec::new();
+            typing.check(node, class_name, &mut issues);
+            for issue in issues {
+                report.add_issue(issue);
+            }
+        }
+        report.stats.total_validated = focus.len();
+        report.stats.validators_executed = 1;
+        report
+    }
+}
+
+/// Shape checks of one validation run
+///
+/// A check that is reached again while in progress, through a cycle of shape
+/// references, is assumed to succeed, so recursive shapes are checked
+/// coinductively. Failures are memoized, and so are successes that did not
+/// rely on such an assumption.
+struct Typing<'a> {
+    shapes: &'a ShapeSchema,
+    graph: &'a Graph,
+    results: HashMap<(Node, String), bool>,
+    assumed: HashSet<(Node, String)>,
+}
+
+impl Typing<'_> {
+    /// Check a node against the shape of a class, collecting the violations
+    fn check(&mut self, node: &Node, class_name: &str, issues: &mut Vec<ValidationIssue>) -> bool {
+        let key = (node.clone(), class_name.to_string());
+        if !self.assumed.insert(key.clone()) {
+            return true;
+        }
+        let before = issues.len();
+        if let Some(shape) = self.shapes.shapes.get(class_name) {
+            self.check_shape(node, shape, issues);
+        }
+        let result = issues.len() == before;
+        self.assumed.remove(&key);
+        if !result || self.assumed.is_empty() {
+            self.results.insert(key, result);
+        }
+        result
+    }
+
+    /// Whether a node conforms to the shape of a class
+    fn conforms(&mut self, node: &Node, class_name: &str) -> bool {
+        let key = (node.clone(), class_name.to_string());
+        match self.results.get(&key) {
+            Some(&result) => result,
+            // Violations inside the value are summarized by its referrer
+            None => self.check(node, class_name, &mut Vec::new()),
+        }
+    }
+
+    fn check_shape(&mut self, node: &Node, shape: &Shape, issues: &mut Vec<ValidationIssue>) {
+        let graph = self.graph;
+        let class_name = shape.class_name.as_str();
+        if matches!(node, Node::Literal { .. }) {
+            issues.push(issue(
+                node,
+                class_name,
+                ErrorCode::TypeMismatch,
+                format!("Literal {node} cannot conform to shape {class_name}"),
+            ));
+            return;
+        }
+
+        let typed = graph
+            .objects(node, RDF_TYPE)
+            .any(|value| matches!(value, Node::Iri(iri) if shape.types.contains(iri)));
+        if !typed {
+            issues.push(issue(
+                node,
+                class_name,
+                ErrorCode::InvalidInstanceType,
+                format!("{node} does not have the type of {class_name} or a subclass"),
+            ));
+        }
+
+        for constraint in &shape.constraints {
+            self.check_constraint(node, shape, constraint, issues);
+        }
+
+        if shape.closed {
+            let mut reported = HashSet::new();
+            for predicate in graph.predicates(node) {
+                if predicate != RDF_TYPE
+                    && !shape.constraints.iter().any(|c| c.predicate == predicate)
+                    && reported.insert(predicate)
+                {
+                    issues.push(
+                        issue(
+                            node,
+                            class_name,
+                            ErrorCode::UnknownSlot,
+                            format!("Predicate <{predicate}> is not allowed by closed shape {class_name}"),
+                        )
+                        .with_context("predicate", serde_json::json!(predicate)),
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_constraint(
+        &mut self,
+        node: &Node,
+        shape: &Shape,
+        constraint: &TripleConstraint,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        let graph = self.graph;
+        let class_name = shape.class_name.as_str();
+        let mut count = 0;
+        for value in graph.objects(node, &constraint.predicate) {
+            match self.check_value(value, &constraint.value) {
+                Ok(()) => count += 1,
+                Err((code, reason)) => issues.push(
+                    issue(
+                        node,
+                        class_name,
+                        code,
+                        format!("{}: {reason}", constraint.slot_name),
+                    )
+                    .with_context("slot", serde_json::json!(constraint.slot_name))
+                    .with_context("predicate", serde_json::json!(constraint.predicate))
+                    .with_context("value", serde_json::json!(value.to_string())),
+                ),
+            }
+        }
+
+        let violation = if count < constraint.min {
+            Some(if count == 0 {
+                (
+                    ErrorCode::RequiredMissing,
+                    format!("{}: required value is missing", constraint.slot_name),
+                )
+            } else {
+                (
+                    ErrorCode::CardinalityViolation,
+                    format!(
+                        "{}: {count} values, at least {} expected",
+                        constraint.slot_name, constraint.min
+                    ),
+                )
+            })
+        } else {
+            constraint.max.filter(|&max| count > max).map(|max| {
+                (
+                    ErrorCode::CardinalityViolation,
+                    format!(
+                        "{}: {count} values, at most {max} expected",
+                        constraint.slot_name
+                    ),
+                )
+            })
+        };
+        if let Some((code, message)) = violation {
+            issues.push(
+                issue(node, class_name, code, message)
+                    .with_context("slot", serde_json::json!(constraint.slot_name))
+                    .with_context("predicate", serde_json::json!(constraint.predicate)),
+            );
+        }
+    }
+
+    /// Check a value, returning the error code and reason of a violation
+    fn check_value(
+        &mut self,
+        value: &Node,
+        expr: &ValueExpr,
+    ) -> std::result::Result<(), (ErrorCode, String)> {
+        match expr {
+            ValueExpr::Iri => match value {
+                Node::Iri(_) => Ok(()),
+                _ => Err((ErrorCode::TypeMismatch, format!("{value} is not an IRI"))),
+            },
+            ValueExpr::Shape(class_name) => {
+                if self.conforms(value, class_name) {
+                    Ok(())
+                } else {
+                    Err((
+                        ErrorCode::InvalidInstanceType,
+                        format!("{value} does not conform to shape {class_name}"),
+                    ))
+                }
+            }
+            ValueExpr::ValueSet { literals, iris } => {
+                let permitted = match value {
+                    Node::Literal { value, .. } => literals.contains(value),
+                    Node::Iri(iri) => iris.contains(iri),
+                    Node::Blank(_) => false,
+                };
+                if permitted {
+                    Ok(())
+                } else {
+                    Err((
+                        ErrorCode::InvalidEnumValue,
+                        format!("{value} is not a permissible value"),
+                    ))
+                }
+            }
+            ValueExpr::Datatype { datatype, facets } => match value {
+                Node::Literal {
+                    value: lexical,
+                    datatype: actual,
+                    ..
+                } if actual == datatype => check_literal(lexical, datatype, facets)
+                    .map_err(|(code, reason)| (code, format!("{value} {reason}"))),
+                _ => Err((
+                    ErrorCode::TypeMismatch,
+                    format!(
+                        "{value} is not a literal of datatype xsd:{}",
+                        datatype.strip_prefix(XSD).unwrap_or(datatype)
+                    ),
+                )),
+            },
+        }
+    }
+}
+
+/// Violation of the shape of a class by a node
+fn issue(node: &Node, class_name: &str, code: ErrorCode, message: String) -> ValidationIssue {
+    ValidationIssue::error(message, node.to_string(), VALIDATOR)
+        .with_error_code(code)
+        .with_context("shape", serde_json::json!(class_name))
+}
+
+/// Check the lexical form of a literal of an XSD datatype and its facets
+fn check_literal(
+    lexical: &str,
+    datatype: &str,
+    facets: &Facets,
+) -> std::result::Result<(), (ErrorCode, String)> {
+    let local = datatype.strip_prefix(XSD).unwrap_or(datatype);
+    let number = match local {
+        "integer" => Some(
+            lexical
+                .parse::<i64>()
+                .ok()
+                .and_then(|_| lexical.parse::<f64>().ok()),
+        ),
+        "decimal" | "float" | "double" => Some(lexical.parse::<f64>().ok()),
+        _ => None,
+    };
+    let well_formed = match local {
+        "integer" | "decimal" | "float" | "double" => number.flatten().is_some(),
+        "boolean" => matches!(lexical, "true" | "false" | "1" | "0"),
+        "date" => chrono::NaiveDate::parse_from_str(lexical, "%Y-%m-%d").is_ok(),
+        "dateTime" => {
+            chrono::DateTime::parse_from_rfc3339(lexical).is_ok()
+                || chrono::NaiveDateTime::parse_from_str(lexical, "%Y-%m-%dT%H:%M:%S%.f").is_ok()
+        }
+        "time" => chrono::NaiveTime::parse_from_str(lexical, "%H:%M:%S%.f").is_ok(),
+        _ => true,
+    };
+    if !well_formed {
+        return Err((
+            ErrorCode::TypeMismatch,
+            format!("is not a valid xsd:{local}"),
+        ));
+    }
+
+    if let Some(number) = number.flatten() {
+        if facets.min_inclusive.is_some_and(|min| number < min) {
+            return Err((
+                ErrorCode::RangeViolation,
+                format!(
+                    "is below the minimum {}",
+                    facets.min_inclusive.unwrap_or_default()
+                ),
+            ));
+        }
+        if facets.max_inclusive.is_some_and(|max| number > max) {
+            return Err((
+                ErrorCode::RangeViolation,
+                format!(
+                    "is above the maximum {}",
+                    facets.max_inclusive.unwrap_or_default()
+                ),
+            ));
+        }
+    }
+    let length = lexical.chars().count();
+    if let Some(min) = facets.min_length.filter(|&min| length < min) {
+        return Err((
+            ErrorCode::StringConstraintViolation,
+            format!("is shorter than {min} characters"),
+        ));
+    }
+    if let Some(max) = facets.max_length.filter(|&max| length > max) {
+        return Err((
+            ErrorCode::StringConstraintViolation,
+            format!("is longer than {max} characters"),
+        ));
+    }
+    if let Some(pattern) = facets
+        .pattern
+        .as_ref()
+        .filter(|pattern| !pattern.is_match(lexical))
+    {
+        return Err((
+            ErrorCode::PatternMismatch,
+            format!("does not match the pattern {}", pattern.as_str()),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Configure = fn(&mut SlotDefinition);
+
+    fn schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/people".to_string(),
+            name: "people".to_string(),
+            default_prefix: Some("ex".to_string()),
+            ..Default::default()
+        };
+        schema.prefixes.insert(
+            "ex".to_string(),
+            PrefixDefinition::Simple("https://example.org/people/".to_string()),
+        );
+        let slots: [(&str, Option<&str>, Configure); 7] = [
+            ("id", None, |s| s.identifier = Some(true)),
+            ("name", None, |s| {
+                s.required = Some(true);
+                s.pattern = Some("^[A-Z]".to_string());
+            }),
+            ("age", Some("integer"), |s| {
+                s.minimum_value = Some(serde_json::json!(0));
+            }),
+            ("status", Some("Status"), |_| {}),
+            ("friends", Some("Person"), |s| {
+                s.multivalued = Some(true);
+            }),
+            ("address", Some("Address"), |_| {}),
+            ("street", None, |s| s.required = Some(true)),
+        ];
+        for (name, range, configure) in slots {
+            let mut slot = SlotDefinition {
+                name: name.to_string(),
+                range: range.map(String::from),
+                ..Default::default()
+            };
+            configure(&mut slot);
+            schema.slots.insert(name.to_string(), slot);
+        }
+        for (name, slots) in [
+            (
+                "Person",
+                vec!["id", "name", "age", "status", "friends", "address"],
+            ),
+            ("Address", vec!["street"]),
+        ] {
+            schema.classes.insert(
+                name.to_string(),
+                ClassDefinition {
+                    name: name.to_string(),
+                    slots: slots.into_iter().map(String::from).collect(),
+                    ..Default::default()
+                },
+            );
+        }
+        schema.classes.insert(
+            "Employee".to_string(),
+            ClassDefinition {
+                name: "Employee".to_string(),
+                is_a: Some("Person".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.enums.insert(
+            "Status".to_string(),
+            EnumDefinition {
+                name: "Status".to_string(),
+                permissible_values: vec![
+                    PermissibleValue::Simple("ACTIVE".to_string()),
+                    PermissibleValue::Simple("RETIRED".to_string()),
+                ],
+                ..Default::default()
+            },
+        );
+        schema
+    }
+
+    const PREFIXES: &str = r"
+@prefix ex: <https://example.org/people/> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+";
+
+    fn validate(data: &str) -> ValidationReport {
+        ShExValidator::new(&schema())
+            .validate_str(&format!("{PREFIXES}{data}"), RdfFormat::Turtle)
+            .expect("data should parse")
+    }
+
+    #[test]
+    fn test_conforming_graph() {
+        let report = validate(
+            r#"
+ex:alice a ex:Person ;
+    ex:name "Alice" ;
+    ex:age 42 ;
+    ex:status "ACTIVE" ;
+    ex:friends ex:bob ;
+    ex:address [ a ex:Address ; ex:street "Main St" ] .
+ex:bob a ex:Employee ; ex:name "Bob" .
+"#,
+        );
+        assert!(report.valid, "{:?}", report.issues);
+        // The typed address is validated on its own as well
+        assert_eq!(report.stats.total_validated, 3);
+    }
+
+    #[test]
+    fn test_violations() {
+        let report = validate(
+            r#"
+ex:carol a ex:Person ;
+    ex:age -1 ;
+    ex:status "UNKNOWN" ;
+    ex:friends "dave" ;
+    ex:address [ ex:city "Springfield" ] .
+ex:erin a ex:Person ; ex:name "erin" , "Erin" .
+"#,
+        );
+        let codes: Vec<(String, ErrorCode)> = report
+            .issues
+            .iter()
+            .map(|issue| (issue.path.clone(), issue.error_code()))
+            .collect();
+        let carol = "<https://example.org/people/carol>".to_string();
+        let erin = "<https://example.org/people/erin>".to_string();
+        assert_eq!(
+            codes,
+            [
+                (carol.clone(), ErrorCode::RequiredMissing),
+                (carol.clone(), ErrorCode::RangeViolation),
+                (carol.clone(), ErrorCode::InvalidEnumValue),
+                (carol.clone(), ErrorCode::TypeMismatch),
+                (carol, ErrorCode::InvalidInstanceType),
+                (erin, ErrorCode::PatternMismatch),
+            ]
+        );
+        assert_eq!(report.issues[0].context["slot"], "name");
+    }
+
+    #[test]
+    fn test_closed_shapes_and_shape_map() {
+        let options = ShExOptions {
+            closed_shapes: true,
+            ..Default::default()
+        };
+        let validator = ShExValidator::with_options(&schema(), &options);
+        let data = format!(
+            r#"{PREFIXES}
+ex:frank ex:name "Frank" ; ex:nickname "Frankie" .
+"#
+        );
+        let report = validator
+            .validate_str_with_shape_map(
+                &data,
+                RdfFormat::Turtle,
+                &[("https://example.org/people/frank", "Person")],
+            )
+            .expect("data should parse");
+        let codes: Vec<ErrorCode> = report
+            .issues
+            .iter()
+            .map(ValidationIssue::error_code)
+            .collect();
+        assert_eq!(
+            codes,
+            [ErrorCode::InvalidInstanceType, ErrorCode::UnknownSlot]
+        );
+        assert!(
+            validator
+                .validate_str_with_shape_map(&data, RdfFormat::Turtle, &[("_:b0", "Nothing")])
+                .is_err()
+        );
+    }
+}
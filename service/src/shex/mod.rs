@@ -0,0 +1,35 @@
+//! `ShEx` validation of RDF graphs
+//!
+//! The [`ShExGenerator`](crate::generator::ShExGenerator) writes the shape
+//! expressions of a schema; this module checks RDF graphs against the same
+//! shapes without an external `ShEx` engine. [`ShapeSchema`] holds the shapes
+//! derived from a schema and [`ShExValidator`] evaluates them:
+//!
+//! - every node typed with a class is checked against the shape of that
+//!   class, or the nodes of an explicit shape map against the given shapes
+//! - values of class ranges are checked recursively; cycles of references
+//!   are assumed to conform, as in `ShEx` semantics
+//! - violations are reported as [`ValidationIssue`](crate::validator::report::ValidationIssue)s
+//!   of validator `shex`, with the node as path and the shape, slot and
+//!   predicate as context
+//!
+//! ```rust,no_run
+//! use linkml_service::shex::ShExValidator;
+//! use oxigraph::io::RdfFormat;
+//! # use linkml_core::prelude::*;
+//!
+//! # fn example(schema: &SchemaDefinition) -> linkml_core::error::Result<()> {
+//! let data = std::fs::read_to_string("people.ttl")?;
+//! let report = ShExValidator::new(schema).validate_str(&data, RdfFormat::Turtle)?;
+//! for issue in &report.issues {
+//!     println!("{issue}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod engine;
+pub mod shapes;
+
+pub use engine::ShExValidator;
+pub use shapes::{Facets, Shape, ShapeSchema, TripleConstraint, ValueExpr};
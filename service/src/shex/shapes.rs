@@ -0,0 +1,250 @@
+//! Shape expressions derived from a `LinkML` schema
+//!
+//! The derivation follows [`ShExGenerator`](crate::generator::ShExGenerator):
+//! one shape per class with a type constraint on `rdf:type` (`EXTRA`, so
+//! other types are allowed), and one triple constraint per induced slot with
+//! its cardinality from `required`/`multivalued`. Identifier slots name the
+//! focus node itself and have no triple constraint.
+
+use indexmap::IndexMap;
+use linkml_core::prelude::*;
+use regex::Regex;
+
+use crate::generator::base::{class_ancestry, induced_slots};
+use crate::generator::shex::ShExOptions;
+use crate::namespace::CurieResolver;
+
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// Shapes of all classes of a schema, by class name
+#[derive(Debug, Clone, Default)]
+pub struct ShapeSchema {
+    /// Shape of each class
+    pub shapes: IndexMap<String, Shape>,
+}
+
+/// Shape of a class
+#[derive(Debug, Clone)]
+pub struct Shape {
+    /// Name of the class
+    pub class_name: String,
+    /// IRIs of the class, first, and its descendants, one of which the
+    /// focus node must have as `rdf:type`
+    pub types: Vec<String>,
+    /// Whether predicates without a triple constraint are rejected
+    pub closed: bool,
+    /// Constraints on the slots of the class
+    pub constraints: Vec<TripleConstraint>,
+}
+
+/// Constraint on the values of one predicate
+#[derive(Debug, Clone)]
+pub struct TripleConstraint {
+    /// Slot the constraint is derived from
+    pub slot_name: String,
+    /// Predicate IRI
+    pub predicate: String,
+    /// Constraint each value must satisfy
+    pub value: ValueExpr,
+    /// Minimum number of values
+    pub min: usize,
+    /// Maximum number of values, unbounded if `None`
+    pub max: Option<usize>,
+}
+
+/// Constraint on a single value
+#[derive(Debug, Clone)]
+pub enum ValueExpr {
+    /// Any IRI
+    Iri,
+    /// Literal of a datatype, with facets
+    Datatype {
+        /// Datatype IRI
+        datatype: String,
+        /// Facets the lexical form must satisfy
+        facets: Facets,
+    },
+    /// Node conforming to the shape of a class
+    Shape(String),
+    /// Literal with one of the given lexical forms, or one of the given IRIs
+    ValueSet {
+        /// Permissible texts
+        literals: Vec<String>,
+        /// Expanded meanings of the permissible values
+        iris: Vec<String>,
+    },
+}
+
+/// Facets of a datatype constraint
+#[derive(Debug, Clone, Default)]
+pub struct Facets {
+    /// Pattern the lexical form must contain a match of
+    pub pattern: Option<Regex>,
+    /// Inclusive lower bound of numeric values
+    pub min_inclusive: Option<f64>,
+    /// Inclusive upper bound of numeric values
+    pub max_inclusive: Option<f64>,
+    /// Minimum length in characters
+    pub min_length: Option<usize>,
+    /// Maximum length in characters
+    pub max_length: Option<usize>,
+}
+
+impl ShapeSchema {
+    /// Derive the shapes of a schema
+    ///
+    /// Of the generation options only `closed_shapes` affects validation.
+    #[must_use]
+    pub fn from_schema(schema: &SchemaDefinition, options: &ShExOptions) -> Self {
+        let resolver = CurieResolver::from_schema(schema);
+        let namespace = default_namespace(schema);
+        let iri = |uri: Option<&String>, name: &str| match uri {
+            Some(uri) => resolver.expand_curie(uri).unwrap_or_else(|_| uri.clone()),
+            None => format!("{namespace}{name}"),
+        };
+
+        let shapes = schema
+            .classes
+            .iter()
+            .map(|(class_name, class)| {
+                let descendants = schema.classes.iter().filter(|(name, _)| {
+                    *name != class_name
+                        && class_ancestry(schema, name)
+                            .iter()
+                            .any(|ancestor| ancestor == class_name)
+                });
+                let types = std::iter::once((class_name, class))
+                    .chain(descendants)
+                    .map(|(name, class)| iri(class.class_uri.as_ref(), name))
+                    .collect();
+                let constraints = induced_slots(schema, class_name)
+                    .into_iter()
+                    .filter(|slot| slot.identifier != Some(true))
+                    .map(|slot| {
+                        let (min, max) = match (
+                            slot.required.unwrap_or(false),
+                            slot.multivalued.unwrap_or(false),
+                        ) {
+                            (true, false) => (1, Some(1)),
+                            (false, false) => (0, Some(1)),
+                            (true, true) => (1, None),
+                            (false, true) => (0, None),
+                        };
+                        TripleConstraint {
+                            predicate: iri(slot.slot_uri.as_ref(), &slot.name),
+                            value: value_expr(schema, &resolver, &slot),
+                            slot_name: slot.name,
+                            min,
+                            max,
+                        }
+                    })
+                    .collect();
+                let shape = Shape {
+                    class_name: class_name.clone(),
+                    types,
+                    closed: options.closed_shapes,
+                    constraints,
+                };
+                (class_name.clone(), shape)
+            })
+            .collect();
+        Self { shapes }
+    }
+}
+
+/// Namespace of elements without an explicit URI
+fn default_namespace(schema: &SchemaDefinition) -> String {
+    let expansion = schema
+        .default_prefix
+        .as_ref()
+        .and_then(|prefix| schema.prefixes.get(prefix))
+        .map(|definition| match definition {
+            PrefixDefinition::Simple(uri) => uri.clone(),
+            PrefixDefinition::Complex {
+                prefix_reference, ..
+            } => prefix_reference.clone().unwrap_or_default(),
+        });
+    match expansion {
+        Some(namespace) if !namespace.is_empty() => namespace,
+        _ => format!("{}/", schema.id.trim_end_matches(['/', '#'])),
+    }
+}
+
+/// Value constraint of a slot
+fn value_expr(
+    schema: &SchemaDefinition,
+    resolver: &CurieResolver,
+    slot: &SlotDefinition,
+) -> ValueExpr {
+    let range = slot.range.as_deref().unwrap_or("string");
+    if schema.classes.contains_key(range) {
+        // References to classes with identifiers are plain IRIs unless inlined
+        let referenced = slot.inlined != Some(true)
+            && induced_slots(schema, range)
+                .iter()
+                .any(|slot| slot.identifier == Some(true));
+        return if referenced {
+            ValueExpr::Iri
+        } else {
+            ValueExpr::Shape(range.to_string())
+        };
+    }
+    if let Some(enum_def) = schema.enums.get(range) {
+        let mut literals = Vec::new();
+        let mut iris = Vec::new();
+        for value in &enum_def.permissible_values {
+            literals.push(value.text().to_string());
+            if let PermissibleValue::Complex {
+                meaning: Some(meaning),
+                ..
+            } = value
+            {
+                iris.push(
+                    resolver
+                        .expand_curie(meaning)
+                        .unwrap_or_else(|_| meaning.clone()),
+                );
+            }
+        }
+        return ValueExpr::ValueSet { literals, iris };
+    }
+
+    let datatype = match builtin_type(schema, range) {
+        "uri" | "uriorcurie" => return ValueExpr::Iri,
+        "integer" | "int" => "integer",
+        "float" => "float",
+        "double" => "double",
+        "decimal" => "decimal",
+        "boolean" | "bool" => "boolean",
+        "date" => "date",
+        "datetime" => "dateTime",
+        "time" => "time",
+        _ => "string",
+    };
+    let bound = |value: Option<&serde_json::Value>| value.and_then(serde_json::Value::as_f64);
+    ValueExpr::Datatype {
+        datatype: format!("{XSD}{datatype}"),
+        facets: Facets {
+            pattern: slot
+                .pattern
+                .as_deref()
+                .and_then(|pattern| Regex::new(pattern).ok()),
+            min_inclusive: bound(slot.minimum_value.as_ref()),
+            max_inclusive: bound(slot.maximum_value.as_ref()),
+            min_length: slot.min_length,
+            max_length: slot.max_length,
+        },
+    }
+}
+
+/// Built-in type a type range derives from
+fn builtin_type<'a>(schema: &'a SchemaDefinition, range: &'a str) -> &'a str {
+    let mut range = range;
+    for _ in 0..=schema.types.len() {
+        match schema.types.get(range).and_then(|t| t.base_type.as_deref()) {
+            Some(base_type) => range = base_type,
+            None => break,
+        }
+    }
+    range
+}
@@ -0,0 +1,482 @@
+//! Validation trend analytics over stored reports
+//!
+//! Error-rate time series broken down per class, slot or error code, and
+//! JSON/CSV exports of them for BI dashboards. [`analytics_router`] serves
+//! the same data over HTTP for mounting on the REST API service.
+
+use super::{ErrorRatePoint, RecordFilter, ReportRepository, StoredReport, TrendBucket};
+use crate::validator::report::ValidationIssue;
+use axum::{
+    Router,
+    extract::{Query, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use linkml_core::error::{LinkMLError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::str::FromStr;
+
+/// What an error-rate series is broken down by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SeriesDimension {
+    /// Class the data was validated as
+    Class,
+    /// Slot an issue was reported on (last property of its path)
+    Slot,
+    /// Stable error code of an issue
+    ErrorCode,
+}
+
+impl fmt::Display for SeriesDimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Class => "class",
+            Self::Slot => "slot",
+            Self::ErrorCode => "error_code",
+        })
+    }
+}
+
+impl FromStr for SeriesDimension {
+    type Err = LinkMLError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "class" => Ok(Self::Class),
+            "slot" => Ok(Self::Slot),
+            "code" | "error_code" | "error-code" => Ok(Self::ErrorCode),
+            other => Err(LinkMLError::config(format!(
+                "Unknown series dimension '{other}' (expected class, slot or code)"
+            ))),
+        }
+    }
+}
+
+/// Error statistics of one class, slot or error code in one period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeriesPoint {
+    /// First day of the period
+    pub period_start: NaiveDate,
+    /// Dimension of the series
+    pub dimension: SeriesDimension,
+    /// Class name, slot name or error code
+    pub key: String,
+    /// Reports the key could occur in: reports of the class for
+    /// [`SeriesDimension::Class`], all reports of the period otherwise
+    pub reports: usize,
+    /// Reports with at least one error for the key
+    pub affected_reports: usize,
+    /// Errors for the key
+    pub errors: usize,
+    /// Warnings for the key
+    pub warnings: usize,
+    /// Share of affected reports (0.0 to 1.0)
+    pub error_rate: f64,
+    /// Average errors per report
+    pub errors_per_report: f64,
+}
+
+#[derive(Default)]
+struct Tally {
+    affected_reports: usize,
+    errors: usize,
+    warnings: usize,
+}
+
+/// Group reports into periods and compute error rates per class, slot or error code
+///
+/// Points are ordered by period, then by key.
+pub fn issue_series(
+    reports: &[StoredReport],
+    bucket: TrendBucket,
+    dimension: SeriesDimension,
+) -> Vec<SeriesPoint> {
+    let mut periods: BTreeMap<NaiveDate, Vec<&StoredReport>> = BTreeMap::new();
+    for report in reports {
+        periods
+            .entry(bucket.period_start(report.recorded_at.date_naive()))
+            .or_default()
+            .push(report);
+    }
+
+    let mut points = Vec::new();
+    for (period_start, reports) in periods {
+        let mut tallies: BTreeMap<String, (usize, Tally)> = BTreeMap::new();
+        for stored in &reports {
+            let report = &stored.report;
+            if dimension == SeriesDimension::Class {
+                let class = report
+                    .target_class
+                    .clone()
+                    .unwrap_or_else(|| "-".to_string());
+                let (scope, tally) = tallies.entry(class).or_default();
+                *scope += 1;
+                tally.errors += report.errors().count();
+                tally.warnings += report.warnings().count();
+                if !report.valid {
+                    tally.affected_reports += 1;
+                }
+                continue;
+            }
+
+            let mut affected = BTreeSet::new();
+            for issue in report.errors() {
+                let key = issue_key(issue, dimension);
+                tallies.entry(key.clone()).or_default().1.errors += 1;
+                affected.insert(key);
+            }
+            for issue in report.warnings() {
+                tallies
+                    .entry(issue_key(issue, dimension))
+                    .or_default()
+                    .1
+                    .warnings += 1;
+            }
+            for key in affected {
+                if let Some((_, tally)) = tallies.get_mut(&key) {
+                    tally.affected_reports += 1;
+                }
+            }
+        }
+
+        for (key, (scope, tally)) in tallies {
+            let scope = if dimension == SeriesDimension::Class {
+                scope
+            } else {
+                reports.len()
+            };
+            points.push(SeriesPoint {
+                period_start,
+                dimension,
+                key,
+                reports: scope,
+                affected_reports: tally.affected_reports,
+                errors: tally.errors,
+                warnings: tally.warnings,
+                error_rate: tally.affected_reports as f64 / scope as f64,
+                errors_per_report: tally.errors as f64 / scope as f64,
+            });
+        }
+    }
+    points
+}
+
+fn issue_key(issue: &ValidationIssue, dimension: SeriesDimension) -> String {
+    match dimension {
+        SeriesDimension::Class => String::new(),
+        SeriesDimension::Slot => slot_of_path(&issue.path).unwrap_or("-").to_string(),
+        SeriesDimension::ErrorCode => issue.error_code().as_str().to_string(),
+    }
+}
+
+/// Last property name of an issue path such as `$.persons[0].age` or `/persons/0/age`
+fn slot_of_path(path: &str) -> Option<&str> {
+    path.split(['.', '/'])
+        .map(|segment| segment.split('[').next().unwrap_or(segment))
+        .rfind(|segment| {
+            !segment.is_empty() && *segment != "$" && !segment.chars().all(|c| c.is_ascii_digit())
+        })
+}
+
+impl ReportRepository {
+    /// Error rates of the reports passing `filter`, per period and class, slot or error code
+    pub async fn issue_series(
+        &self,
+        filter: &RecordFilter,
+        bucket: TrendBucket,
+        dimension: SeriesDimension,
+    ) -> Result<Vec<SeriesPoint>> {
+        Ok(issue_series(&self.find(filter).await?, bucket, dimension))
+    }
+}
+
+/// Export format for analytics data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    /// JSON array of objects
+    #[default]
+    Json,
+    /// CSV with a header row
+    Csv,
+}
+
+impl ExportFormat {
+    /// MIME type of the format
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Csv => "text/csv; charset=utf-8",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = LinkMLError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            other => Err(LinkMLError::config(format!(
+                "Unknown export format '{other}' (expected json or csv)"
+            ))),
+        }
+    }
+}
+
+/// A row of an analytics export
+pub trait CsvRow {
+    /// Column names
+    const HEADER: &'static [&'static str];
+
+    /// Values in column order
+    fn fields(&self) -> Vec<String>;
+}
+
+impl CsvRow for ErrorRatePoint {
+    const HEADER: &'static [&'static str] = &[
+        "period_start",
+        "reports",
+        "failed_reports",
+        "errors",
+        "warnings",
+        "failure_rate",
+        "errors_per_report",
+    ];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.period_start.to_string(),
+            self.reports.to_string(),
+            self.failed_reports.to_string(),
+            self.errors.to_string(),
+            self.warnings.to_string(),
+            format!("{:.4}", self.failure_rate),
+            format!("{:.4}", self.errors_per_report),
+        ]
+    }
+}
+
+impl CsvRow for SeriesPoint {
+    const HEADER: &'static [&'static str] = &[
+        "period_start",
+        "dimension",
+        "key",
+        "reports",
+        "affected_reports",
+        "errors",
+        "warnings",
+        "error_rate",
+        "errors_per_report",
+    ];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.period_start.to_string(),
+            self.dimension.to_string(),
+            self.key.clone(),
+            self.reports.to_string(),
+            self.affected_reports.to_string(),
+            self.errors.to_string(),
+            self.warnings.to_string(),
+            format!("{:.4}", self.error_rate),
+            format!("{:.4}", self.errors_per_report),
+        ]
+    }
+}
+
+/// Render rows in `format`
+pub fn export<T: Serialize + CsvRow>(rows: &[T], format: ExportFormat) -> Result<String> {
+    match format {
+        ExportFormat::Json => Ok(serde_json::to_string_pretty(rows)?),
+        ExportFormat::Csv => Ok(to_csv(rows)),
+    }
+}
+
+fn to_csv<T: CsvRow>(rows: &[T]) -> String {
+    let mut out = T::HEADER.join(",");
+    out.push('\n');
+    for row in rows {
+        let fields: Vec<String> = row.fields().iter().map(|f| csv_field(f)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Query parameters of `GET /analytics/error-rates`
+#[derive(Debug, Default, Deserialize)]
+pub struct ErrorRateQuery {
+    /// Only reports for this schema name
+    pub schema: Option<String>,
+    /// Only reports for this schema version
+    pub schema_version: Option<String>,
+    /// Only reports stored at or after this date (`YYYY-MM-DD` or RFC 3339)
+    pub since: Option<String>,
+    /// Only reports stored before this date (`YYYY-MM-DD` or RFC 3339)
+    pub until: Option<String>,
+    /// `day` (default), `week` or `month`
+    pub bucket: Option<String>,
+    /// `class`, `slot` or `code`; overall error rates when absent
+    pub by: Option<String>,
+    /// `json` (default) or `csv`
+    pub format: Option<String>,
+}
+
+impl ErrorRateQuery {
+    fn filter(&self) -> Result<RecordFilter> {
+        let mut filter = RecordFilter::default();
+        if let Some(schema) = &self.schema {
+            filter = filter.with_schema(schema.clone());
+        }
+        if let Some(version) = &self.schema_version {
+            filter = filter.with_version(version.clone());
+        }
+        if let Some(since) = &self.since {
+            filter = filter.with_since(parse_time(since)?);
+        }
+        if let Some(until) = &self.until {
+            filter = filter.with_until(parse_time(until)?);
+        }
+        Ok(filter)
+    }
+
+    /// Compute the requested series and render it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for invalid parameters or if the reports cannot be read
+    pub async fn run(&self, reports: &ReportRepository) -> Result<(ExportFormat, String)> {
+        let filter = self.filter()?;
+        let bucket = self
+            .bucket
+            .as_deref()
+            .map_or(Ok(TrendBucket::Day), str::parse)?;
+        let format = self
+            .format
+            .as_deref()
+            .map_or(Ok(ExportFormat::Json), str::parse)?;
+        let body = match self.by.as_deref() {
+            Some(by) => export(
+                &reports.issue_series(&filter, bucket, by.parse()?).await?,
+                format,
+            )?,
+            None => export(&reports.error_rate_trend(&filter, bucket).await?, format)?,
+        };
+        Ok((format, body))
+    }
+}
+
+fn parse_time(value: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|at| at.with_timezone(&Utc))
+        .or_else(|_| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|date| date.and_time(NaiveTime::MIN).and_utc())
+        })
+        .map_err(|_| LinkMLError::config(format!("Invalid date '{value}'")))
+}
+
+/// Routes serving error-rate analytics from `reports`
+///
+/// `GET /analytics/error-rates` takes the parameters of [`ErrorRateQuery`].
+pub fn analytics_router(reports: ReportRepository) -> Router {
+    Router::new()
+        .route("/analytics/error-rates", get(error_rates))
+        .with_state(reports)
+}
+
+async fn error_rates(
+    State(reports): State<ReportRepository>,
+    Query(query): Query<ErrorRateQuery>,
+) -> Response {
+    match query.run(&reports).await {
+        Ok((format, body)) => {
+            ([(header::CONTENT_TYPE, format.content_type())], body).into_response()
+        }
+        Err(LinkMLError::ConfigError(message)) => {
+            (StatusCode::BAD_REQUEST, message).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::report::{ValidationIssue, ValidationReport};
+    use chrono::TimeZone;
+
+    fn stored(day: u32, class: &str, paths: &[&str]) -> StoredReport {
+        let mut report = ValidationReport::new("people");
+        report.target_class = Some(class.to_string());
+        for path in paths {
+            report.add_issue(ValidationIssue::error("bad value", *path, "TypeValidator"));
+        }
+        StoredReport {
+            schema_name: "people".to_string(),
+            schema_version: None,
+            source: None,
+            recorded_at: Utc
+                .with_ymd_and_hms(2025, 3, day, 12, 0, 0)
+                .single()
+                .expect("valid timestamp"),
+            report,
+        }
+    }
+
+    #[test]
+    fn test_series_per_class_and_slot() {
+        let reports = vec![
+            stored(3, "Person", &["$.persons[0].age", "$.persons[1].age"]),
+            stored(3, "Person", &[]),
+            stored(3, "Address", &["$.street"]),
+            stored(4, "Person", &["/persons/2/name"]),
+        ];
+
+        let by_class = issue_series(&reports, TrendBucket::Day, SeriesDimension::Class);
+        assert_eq!(by_class.len(), 3);
+        let person = &by_class[1];
+        assert_eq!((person.key.as_str(), person.reports), ("Person", 2));
+        assert_eq!((person.affected_reports, person.errors), (1, 2));
+        assert!((person.error_rate - 0.5).abs() < f64::EPSILON);
+
+        let by_slot = issue_series(&reports, TrendBucket::Week, SeriesDimension::Slot);
+        let keys: Vec<_> = by_slot.iter().map(|p| p.key.as_str()).collect();
+        assert_eq!(keys, ["age", "name", "street"]);
+        assert_eq!((by_slot[0].reports, by_slot[0].affected_reports), (4, 1));
+        assert_eq!(by_slot[0].errors, 2);
+    }
+
+    #[test]
+    fn test_csv_export_quotes_fields() {
+        let point = SeriesPoint {
+            period_start: NaiveDate::from_ymd_opt(2025, 3, 3).expect("valid date"),
+            dimension: SeriesDimension::Slot,
+            key: "a,b".to_string(),
+            reports: 2,
+            affected_reports: 1,
+            errors: 1,
+            warnings: 0,
+            error_rate: 0.5,
+            errors_per_report: 0.5,
+        };
+        let csv = export(&[point], ExportFormat::Csv).expect("CSV export");
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines[0], SeriesPoint::HEADER.join(","));
+        assert_eq!(lines[1], "2025-03-03,slot,\"a,b\",2,1,1,0,0.5000,0.5000");
+    }
+}
@@ -0,0 +1,280 @@
+//! Record store backed by a `TypeDB` database via the DBMS service
+
+use super::{RecordFilter, RecordKind, RecordStore, StoredRecord};
+use async_trait::async_trait;
+use chrono::{DateTime, SubsecRound, TimeDelta, Utc};
+use dbms_core::{DBMSService, DatabaseConfig};
+use linkml_core::error::{LinkMLError, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+/// Database used when none is configured
+pub const DEFAULT_STORAGE_DATABASE: &str = "linkml_storage";
+
+/// `TypeQL` schema deployed by [`DbmsRecordStore::initialize`]
+///
+/// Filterable fields are attributes of their own; the full record is kept
+/// as JSON in `record-body`.
+pub const STORAGE_TYPEQL_SCHEMA: &str = "define
+
+record-id sub attribute, value string;
+record-kind sub attribute, value string;
+schema-name sub attribute, value string;
+schema-version sub attribute, value string;
+recorded-at sub attribute, value datetime;
+record-body sub attribute, value string;
+
+linkml-record sub entity,
+    owns record-id @key,
+    owns record-kind,
+    owns schema-name,
+    owns schema-version,
+    owns recorded-at,
+    owns record-body;
+";
+
+/// Record store persisting records in a `TypeDB` database
+pub struct DbmsRecordStore {
+    dbms_service: Arc<dyn DBMSService<Error = dbms_core::DBMSError>>,
+    database: String,
+}
+
+impl DbmsRecordStore {
+    /// Create a store using `database`
+    pub fn new(
+        dbms_service: Arc<dyn DBMSService<Error = dbms_core::DBMSError>>,
+        database: impl Into<String>,
+    ) -> Self {
+        Self {
+            dbms_service,
+            database: database.into(),
+        }
+    }
+
+    /// Name of the database records are stored in
+    pub fn database(&self) -> &str {
+        &self.database
+    }
+}
+
+#[async_trait]
+impl RecordStore for DbmsRecordStore {
+    fn name(&self) -> &'static str {
+        "dbms"
+    }
+
+    async fn initialize(&self) -> Result<()> {
+        if self
+            .dbms_service
+            .get_database_status(&self.database)
+            .await
+            .is_err()
+        {
+            self.dbms_service
+                .create_database(&self.database, DatabaseConfig::default())
+                .await
+                .map_err(|e| {
+                    LinkMLError::service(format!("Failed to create storage database: {e}"))
+                })?;
+        }
+        self.dbms_service
+            .deploy_schema(&self.database, STORAGE_TYPEQL_SCHEMA)
+            .await
+            .map_err(|e| LinkMLError::service(format!("Failed to deploy storage schema: {e}")))
+    }
+
+    async fn insert(&self, record: &StoredRecord) -> Result<()> {
+        let query = insert_query(record)?;
+        self.dbms_service
+            .execute_string_query(&self.database, &query)
+            .await
+            .map_err(|e| {
+                LinkMLError::service(format!("Failed to store {} record: {e}", record.kind))
+            })?;
+        Ok(())
+    }
+
+    async fn query(&self, kind: RecordKind, filter: &RecordFilter) -> Result<Vec<StoredRecord>> {
+        let query = match_query(kind, filter);
+        let result = self
+            .dbms_service
+            .execute_string_query(&self.database, &query)
+            .await
+            .map_err(|e| LinkMLError::service(format!("Failed to query {kind} records: {e}")))?;
+        Ok(filter.apply(parse_rows(&result)?))
+    }
+}
+
+/// `TypeQL` insert statement for a record
+///
+/// `TypeDB` datetimes have millisecond precision, so the record is stored
+/// with its time truncated to milliseconds, keeping the body in step with
+/// the `recorded-at` attribute queries filter on.
+fn insert_query(record: &StoredRecord) -> Result<String> {
+    let record = StoredRecord {
+        recorded_at: record.recorded_at.trunc_subsecs(3),
+        ..record.clone()
+    };
+    let body = serde_json::to_string(&record)?;
+    let mut query = format!(
+        "insert $r isa linkml-record, has record-id {}, has record-kind {}",
+        string_literal(&record.id),
+        string_literal(record.kind.as_str())
+    );
+    if let Some(name) = &record.schema_name {
+        write!(query, ", has schema-name {}", string_literal(name))
+            .expect("write! to String should never fail");
+    }
+    if let Some(version) = &record.schema_version {
+        write!(query, ", has schema-version {}", string_literal(version))
+            .expect("write! to String should never fail");
+    }
+    write!(
+        query,
+        ", has recorded-at {}, has record-body {};",
+        datetime_literal(&record.recorded_at),
+        string_literal(&body)
+    )
+    .expect("write! to String should never fail");
+    Ok(query)
+}
+
+/// `TypeQL` match query selecting the bodies of records passing `filter`
+///
+/// The limit is applied after decoding so it always keeps the newest records.
+/// The time bounds are widened to whole milliseconds, so the query keeps
+/// every record [`RecordFilter::apply`] then selects exactly.
+fn match_query(kind: RecordKind, filter: &RecordFilter) -> String {
+    let mut query = format!(
+        "match $r isa linkml-record, has record-kind {}, has recorded-at $at, has record-body $body",
+        string_literal(kind.as_str())
+    );
+    if let Some(name) = &filter.schema_name {
+        write!(query, ", has schema-name {}", string_literal(name))
+            .expect("write! to String should never fail");
+    }
+    if let Some(version) = &filter.schema_version {
+        write!(query, ", has schema-version {}", string_literal(version))
+            .expect("write! to String should never fail");
+    }
+    query.push(';');
+    if let Some(since) = &filter.since {
+        write!(
+            query,
+            " $at >= {};",
+            datetime_literal(&since.trunc_subsecs(3))
+        )
+        .expect("write! to String should never fail");
+    }
+    if let Some(until) = &filter.until {
+        write!(query, " $at < {};", datetime_literal(&ceil_millis(*until)))
+            .expect("write! to String should never fail");
+    }
+    query.push_str(" get $body;");
+    query
+}
+
+/// Decode the `body` column of a query result
+fn parse_rows(result: &str) -> Result<Vec<StoredRecord>> {
+    let rows: Vec<HashMap<String, Value>> = serde_json::from_str(result).map_err(|e| {
+        LinkMLError::deserialization(format!("Unexpected storage query result: {e}"))
+    })?;
+    rows.iter()
+        .filter_map(|row| row.get("body"))
+        .map(|body| {
+            let body = body.as_str().ok_or_else(|| {
+                LinkMLError::deserialization("Stored record body is not a string")
+            })?;
+            serde_json::from_str(body)
+                .map_err(|e| LinkMLError::deserialization(format!("Invalid stored record: {e}")))
+        })
+        .collect()
+}
+
+fn string_literal(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn datetime_literal(value: &DateTime<Utc>) -> String {
+    value.format("%Y-%m-%dT%H:%M:%S%.3f").to_string()
+}
+
+/// `value` rounded up to whole milliseconds
+fn ceil_millis(value: DateTime<Utc>) -> DateTime<Utc> {
+    let truncated = value.trunc_subsecs(3);
+    if truncated == value {
+        value
+    } else {
+        truncated + TimeDelta::milliseconds(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    #[test]
+    fn test_queries_escape_and_filter() {
+        let record = StoredRecord::new(
+            RecordKind::Audit,
+            Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5)
+                .single()
+                .expect("valid timestamp"),
+            json!({"note": "say \"hi\""}),
+        )
+        .with_schema("people", Some("1.0".to_string()));
+        let insert = insert_query(&record).expect("insert query");
+        assert!(insert.contains("has record-kind \"audit\""));
+        assert!(insert.contains("has recorded-at 2025-01-02T03:04:05.000"));
+        assert!(insert.contains(r#"\\\"hi\\\""#));
+
+        let filter = RecordFilter::default().with_schema("people").with_since(
+            Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .single()
+                .expect("valid timestamp"),
+        );
+        let query = match_query(RecordKind::ValidationReport, &filter);
+        assert_eq!(
+            query,
+            "match $r isa linkml-record, has record-kind \"validation_report\", \
+             has recorded-at $at, has record-body $body, has schema-name \"people\"; \
+             $at >= 2025-01-01T00:00:00.000; get $body;"
+        );
+
+        let rows = json!([{ "body": serde_json::to_string(&record).expect("record serializes") }])
+            .to_string();
+        assert_eq!(parse_rows(&rows).expect("rows parse"), vec![record]);
+    }
+
+    #[test]
+    fn test_sub_millisecond_times() {
+        let at = |nanos| {
+            Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5)
+                .single()
+                .expect("valid timestamp")
+                + TimeDelta::nanoseconds(nanos)
+        };
+        let record = StoredRecord::new(RecordKind::Audit, at(5_000_500), json!({}));
+        let insert = insert_query(&record).expect("insert query");
+        assert!(insert.contains("has recorded-at 2025-01-02T03:04:05.005,"));
+        assert!(insert.contains(r#"\"recorded_at\":\"2025-01-02T03:04:05.005Z\""#));
+
+        // The query keeps every record the filter then selects
+        let stored = StoredRecord {
+            recorded_at: at(5_000_000),
+            ..record
+        };
+        let filter = RecordFilter::default()
+            .with_since(at(4_999_999))
+            .with_until(at(5_000_001));
+        assert_eq!(filter.apply([stored]).len(), 1);
+        assert!(
+            match_query(RecordKind::Audit, &filter)
+                .contains("$at >= 2025-01-02T03:04:05.004; $at < 2025-01-02T03:04:05.006;")
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! Record store keeping JSON lines files in a local directory
+
+use super::{RecordFilter, RecordKind, RecordStore, StoredRecord};
+use async_trait::async_trait;
+use linkml_core::error::{LinkMLError, Result};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+
+/// Record store with one `<kind>.jsonl` file per record kind
+#[derive(Debug, Clone)]
+pub struct FileRecordStore {
+    root: PathBuf,
+}
+
+impl FileRecordStore {
+    /// Create a store rooted at `root`
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Directory holding the record files
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn path(&self, kind: RecordKind) -> PathBuf {
+        self.root.join(format!("{kind}.jsonl"))
+    }
+}
+
+#[async_trait]
+impl RecordStore for FileRecordStore {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    async fn initialize(&self) -> Result<()> {
+        tokio::fs::create_dir_all(&self.root).await?;
+        Ok(())
+    }
+
+    async fn insert(&self, record: &StoredRecord) -> Result<()> {
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
+        tokio::fs::create_dir_all(&self.root).await?;
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.path(record.kind))
+            .await?;
+        file.write_all(line.as_bytes()).await?;
+        file.flush().await?;
+        Ok(())
+    }
+
+    async fn query(&self, kind: RecordKind, filter: &RecordFilter) -> Result<Vec<StoredRecord>> {
+        let path = self.path(kind);
+        let content = match tokio::fs::read_to_string(&path).await {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        let records = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                serde_json::from_str(line).map_err(|e| {
+                    LinkMLError::deserialization(format!(
+                        "Invalid record at {}:{}: {e}",
+                        path.display(),
+                        index + 1
+                    ))
+                })
+            })
+            .collect::<Result<Vec<StoredRecord>>>()?;
+        Ok(filter.apply(records))
+    }
+}
@@ -0,0 +1,337 @@
+//! Persistent storage for schemas, validation reports and audit records
+//!
+//! Records are kept in a [`RecordStore`]. [`DbmsRecordStore`] persists them
+//! in a `TypeDB` database through `RootReal`'s DBMS service (see
+//! [`crate::factory::create_linkml_storage`]); [`FileRecordStore`] keeps
+//! them as JSON lines in a local directory for use without a DBMS, e.g.
+//! from the CLI.
+//!
+//! The typed repositories on top of a store answer the usual questions:
+//! which schema versions were stored, which reports exist for a schema
+//! version or date range, and how error rates develop over time.
+//! [`analytics`] breaks error rates down per class, slot or error code and
+//! exports them for dashboards.
+
+pub mod analytics;
+pub mod dbms;
+pub mod file;
+pub mod repositories;
+
+pub use analytics::{ExportFormat, SeriesDimension, SeriesPoint, analytics_router};
+pub use dbms::{DEFAULT_STORAGE_DATABASE, DbmsRecordStore, STORAGE_TYPEQL_SCHEMA};
+pub use file::FileRecordStore;
+pub use repositories::{
+    AuditRecord, AuditRepository, ErrorRatePoint, ReportRepository, SchemaRepository, StoredReport,
+    StoredSchema, TrendBucket,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use linkml_core::error::{LinkMLError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Kind of a stored record
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordKind {
+    /// A schema snapshot
+    Schema,
+    /// A validation report
+    ValidationReport,
+    /// An audit record
+    Audit,
+}
+
+impl RecordKind {
+    /// Stable name used in storage backends
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Schema => "schema",
+            Self::ValidationReport => "validation_report",
+            Self::Audit => "audit",
+        }
+    }
+}
+
+impl fmt::Display for RecordKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RecordKind {
+    type Err = LinkMLError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "schema" => Ok(Self::Schema),
+            "validation_report" => Ok(Self::ValidationReport),
+            "audit" => Ok(Self::Audit),
+            other => Err(LinkMLError::deserialization(format!(
+                "Unknown record kind '{other}'"
+            ))),
+        }
+    }
+}
+
+/// A record as held by a [`RecordStore`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredRecord {
+    /// Unique record id
+    pub id: String,
+    /// Record kind
+    pub kind: RecordKind,
+    /// Name of the schema the record belongs to
+    pub schema_name: Option<String>,
+    /// Version of that schema
+    pub schema_version: Option<String>,
+    /// When the record was stored
+    pub recorded_at: DateTime<Utc>,
+    /// Record payload
+    pub body: Value,
+}
+
+impl StoredRecord {
+    /// Create a record with a fresh id
+    pub fn new(kind: RecordKind, recorded_at: DateTime<Utc>, body: Value) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            kind,
+            schema_name: None,
+            schema_version: None,
+            recorded_at,
+            body,
+        }
+    }
+
+    /// Attach the schema name and version
+    #[must_use]
+    pub fn with_schema(mut self, name: impl Into<String>, version: Option<String>) -> Self {
+        self.schema_name = Some(name.into());
+        self.schema_version = version;
+        self
+    }
+}
+
+/// Filter for record queries
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecordFilter {
+    /// Only records of this schema
+    pub schema_name: Option<String>,
+    /// Only records of this schema version
+    pub schema_version: Option<String>,
+    /// Only records stored at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only records stored before this time
+    pub until: Option<DateTime<Utc>>,
+    /// Keep only the most recent records
+    pub limit: Option<usize>,
+}
+
+impl RecordFilter {
+    /// Restrict to a schema
+    #[must_use]
+    pub fn with_schema(mut self, name: impl Into<String>) -> Self {
+        self.schema_name = Some(name.into());
+        self
+    }
+
+    /// Restrict to a schema version
+    #[must_use]
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.schema_version = Some(version.into());
+        self
+    }
+
+    /// Restrict to records stored at or after `since`
+    #[must_use]
+    pub fn with_since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Restrict to records stored before `until`
+    #[must_use]
+    pub fn with_until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Keep only the `limit` most recent records
+    #[must_use]
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Check whether a record passes the filter (ignoring the limit)
+    pub fn matches(&self, record: &StoredRecord) -> bool {
+        self.schema_name
+            .as_ref()
+            .is_none_or(|name| record.schema_name.as_ref() == Some(name))
+            && self
+                .schema_version
+                .as_ref()
+                .is_none_or(|version| record.schema_version.as_ref() == Some(version))
+            && self.since.is_none_or(|since| record.recorded_at >= since)
+            && self.until.is_none_or(|until| record.recorded_at < until)
+    }
+
+    /// Filter, sort by time and limit records
+    ///
+    /// Backends call this on their raw results so every store returns
+    /// records the same way: oldest first, at most `limit` of the newest.
+    pub fn apply(&self, records: impl IntoIterator<Item = StoredRecord>) -> Vec<StoredRecord> {
+        let mut records: Vec<StoredRecord> =
+            records.into_iter().filter(|r| self.matches(r)).collect();
+        records.sort_by(|a, b| a.recorded_at.cmp(&b.recorded_at).then(a.id.cmp(&b.id)));
+        if let Some(limit) = self.limit
+            && records.len() > limit
+        {
+            records.drain(..records.len() - limit);
+        }
+        records
+    }
+}
+
+/// Backend holding stored records
+#[async_trait]
+pub trait RecordStore: Send + Sync {
+    /// Name of the backend
+    fn name(&self) -> &'static str;
+
+    /// Prepare the backend (create databases, schemas or directories)
+    async fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Store a record
+    async fn insert(&self, record: &StoredRecord) -> Result<()>;
+
+    /// Records of `kind` passing `filter`, oldest first
+    async fn query(&self, kind: RecordKind, filter: &RecordFilter) -> Result<Vec<StoredRecord>>;
+}
+
+/// Schema, report and audit repositories sharing one store
+#[derive(Clone)]
+pub struct Storage {
+    store: Arc<dyn RecordStore>,
+    schemas: SchemaRepository,
+    reports: ReportRepository,
+    audit: AuditRepository,
+}
+
+impl Storage {
+    /// Create repositories over a store
+    pub fn new(store: Arc<dyn RecordStore>) -> Self {
+        Self {
+            schemas: SchemaRepository::new(store.clone()),
+            reports: ReportRepository::new(store.clone()),
+            audit: AuditRepository::new(store.clone()),
+            store,
+        }
+    }
+
+    /// Prepare the underlying store
+    pub async fn initialize(&self) -> Result<()> {
+        self.store.initialize().await
+    }
+
+    /// The underlying store
+    pub fn store(&self) -> &Arc<dyn RecordStore> {
+        &self.store
+    }
+
+    /// Schema repository
+    pub fn schemas(&self) -> &SchemaRepository {
+        &self.schemas
+    }
+
+    /// Validation report repository
+    pub fn reports(&self) -> &ReportRepository {
+        &self.reports
+    }
+
+    /// Audit repository
+    pub fn audit(&self) -> &AuditRepository {
+        &self.audit
+    }
+}
+
+impl fmt::Debug for Storage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Storage")
+            .field("store", &self.store.name())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn record(schema: &str, version: &str, day: u32) -> StoredRecord {
+        StoredRecord::new(
+            RecordKind::Audit,
+            Utc.with_ymd_and_hms(2025, 3, day, 12, 0, 0)
+                .single()
+                .expect("valid timestamp"),
+            Value::Null,
+        )
+        .with_schema(schema, Some(version.to_string()))
+    }
+
+    #[test]
+    fn test_filter_apply_sorts_and_limits() {
+        let records = vec![
+            record("a", "1", 3),
+            record("a", "1", 1),
+            record("b", "1", 2),
+            record("a", "2", 4),
+            record("a", "1", 2),
+        ];
+        let filter = RecordFilter::default()
+            .with_schema("a")
+            .with_version("1")
+            .with_limit(2);
+        let days: Vec<u32> = filter
+            .apply(records.clone())
+            .iter()
+            .map(|r| chrono::Datelike::day(&r.recorded_at))
+            .collect();
+        assert_eq!(days, vec![2, 3]);
+
+        let filter = RecordFilter::default()
+            .with_since(
+                Utc.with_ymd_and_hms(2025, 3, 2, 0, 0, 0)
+                    .single()
+                    .expect("valid timestamp"),
+            )
+            .with_until(
+                Utc.with_ymd_and_hms(2025, 3, 4, 0, 0, 0)
+                    .single()
+                    .expect("valid timestamp"),
+            );
+        assert_eq!(filter.apply(records).len(), 3);
+    }
+
+    #[test]
+    fn test_record_kind_round_trip() {
+        for kind in [
+            RecordKind::Schema,
+            RecordKind::ValidationReport,
+            RecordKind::Audit,
+        ] {
+            assert_eq!(
+                kind.as_str().parse::<RecordKind>().expect("kind parses"),
+                kind
+            );
+        }
+        assert!("other".parse::<RecordKind>().is_err());
+    }
+}
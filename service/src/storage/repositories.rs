@@ -0,0 +1,454 @@
+//! Typed repositories over a [`RecordStore`]
+
+use super::{RecordFilter, RecordKind, RecordStore, StoredRecord};
+use crate::validator::report::ValidationReport;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// A stored schema snapshot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredSchema {
+    /// Schema name
+    pub name: String,
+    /// Schema version, if the schema declares one
+    pub version: Option<String>,
+    /// SHA-256 of the schema's JSON serialization
+    pub content_hash: String,
+    /// When the snapshot was stored
+    pub recorded_at: DateTime<Utc>,
+    /// The schema
+    pub schema: SchemaDefinition,
+}
+
+/// A stored validation report with its context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredReport {
+    /// Name of the schema validated against
+    pub schema_name: String,
+    /// Version of that schema
+    pub schema_version: Option<String>,
+    /// Validated data source (file path, URL, dataset name)
+    pub source: Option<String>,
+    /// When the report was stored
+    pub recorded_at: DateTime<Utc>,
+    /// The report
+    pub report: ValidationReport,
+}
+
+/// An audit record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// Who performed the action
+    pub actor: String,
+    /// What was done (e.g. `schema.store`, `data.validate`)
+    pub action: String,
+    /// What the action was performed on
+    pub target: String,
+    /// Schema involved, if any
+    pub schema_name: Option<String>,
+    /// Version of that schema
+    pub schema_version: Option<String>,
+    /// When the action happened
+    pub recorded_at: DateTime<Utc>,
+    /// Additional details
+    #[serde(default)]
+    pub details: Value,
+}
+
+/// Repository of schema snapshots
+#[derive(Clone)]
+pub struct SchemaRepository {
+    store: Arc<dyn RecordStore>,
+}
+
+impl SchemaRepository {
+    /// Create a repository over `store`
+    pub fn new(store: Arc<dyn RecordStore>) -> Self {
+        Self { store }
+    }
+
+    /// Store a snapshot of `schema`
+    ///
+    /// Storing the same content again for a name and version returns the
+    /// existing snapshot instead of adding a new one.
+    pub async fn save(
+        &self,
+        schema: &SchemaDefinition,
+        recorded_at: DateTime<Utc>,
+    ) -> Result<StoredSchema> {
+        let content_hash = format!("{:x}", Sha256::digest(serde_json::to_vec(schema)?));
+        if let Some(existing) = self.latest(&schema.name, schema.version.as_deref()).await?
+            && existing.content_hash == content_hash
+        {
+            return Ok(existing);
+        }
+        let stored = StoredSchema {
+            name: schema.name.clone(),
+            version: schema.version.clone(),
+            content_hash,
+            recorded_at,
+            schema: schema.clone(),
+        };
+        let record = StoredRecord::new(
+            RecordKind::Schema,
+            recorded_at,
+            serde_json::to_value(&stored)?,
+        )
+        .with_schema(&stored.name, stored.version.clone());
+        self.store.insert(&record).await?;
+        Ok(stored)
+    }
+
+    /// Most recent snapshot of a schema, optionally of a specific version
+    pub async fn latest(&self, name: &str, version: Option<&str>) -> Result<Option<StoredSchema>> {
+        let mut filter = RecordFilter::default().with_schema(name).with_limit(1);
+        if let Some(version) = version {
+            filter = filter.with_version(version);
+        }
+        Ok(self.find(&filter).await?.pop())
+    }
+
+    /// All snapshots of a schema, oldest first
+    pub async fn history(&self, name: &str) -> Result<Vec<StoredSchema>> {
+        self.find(&RecordFilter::default().with_schema(name)).await
+    }
+
+    /// Snapshots passing `filter`, oldest first
+    pub async fn find(&self, filter: &RecordFilter) -> Result<Vec<StoredSchema>> {
+        decode_all(self.store.query(RecordKind::Schema, filter).await?)
+    }
+}
+
+/// Repository of validation reports
+#[derive(Clone)]
+pub struct ReportRepository {
+    store: Arc<dyn RecordStore>,
+}
+
+impl ReportRepository {
+    /// Create a repository over `store`
+    pub fn new(store: Arc<dyn RecordStore>) -> Self {
+        Self { store }
+    }
+
+    /// Store a validation report
+    pub async fn save(&self, report: &StoredReport) -> Result<()> {
+        let record = StoredRecord::new(
+            RecordKind::ValidationReport,
+            report.recorded_at,
+            serde_json::to_value(report)?,
+        )
+        .with_schema(&report.schema_name, report.schema_version.clone());
+        self.store.insert(&record).await
+    }
+
+    /// Reports passing `filter` (schema, version, date range), oldest first
+    pub async fn find(&self, filter: &RecordFilter) -> Result<Vec<StoredReport>> {
+        decode_all(
+            self.store
+                .query(RecordKind::ValidationReport, filter)
+                .await?,
+        )
+    }
+
+    /// Error rates of the reports passing `filter`, per period
+    pub async fn error_rate_trend(
+        &self,
+        filter: &RecordFilter,
+        bucket: TrendBucket,
+    ) -> Result<Vec<ErrorRatePoint>> {
+        Ok(error_rate_trend(&self.find(filter).await?, bucket))
+    }
+}
+
+/// Repository of audit records
+#[derive(Clone)]
+pub struct AuditRepository {
+    store: Arc<dyn RecordStore>,
+}
+
+impl AuditRepository {
+    /// Create a repository over `store`
+    pub fn new(store: Arc<dyn RecordStore>) -> Self {
+        Self { store }
+    }
+
+    /// Store an audit record
+    pub async fn record(&self, entry: &AuditRecord) -> Result<()> {
+        let mut record = StoredRecord::new(
+            RecordKind::Audit,
+            entry.recorded_at,
+            serde_json::to_value(entry)?,
+        );
+        record.schema_name.clone_from(&entry.schema_name);
+        record.schema_version.clone_from(&entry.schema_version);
+        self.store.insert(&record).await
+    }
+
+    /// Audit records passing `filter`, oldest first
+    pub async fn find(&self, filter: &RecordFilter) -> Result<Vec<AuditRecord>> {
+        decode_all(self.store.query(RecordKind::Audit, filter).await?)
+    }
+}
+
+/// Period used to group reports in trends
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrendBucket {
+    /// Calendar days
+    #[default]
+    Day,
+    /// ISO weeks, starting on Monday
+    Week,
+    /// Calendar months
+    Month,
+}
+
+impl TrendBucket {
+    /// First day of the period containing `date`
+    pub fn period_start(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Day => date,
+            Self::Week => date - Duration::days(i64::from(date.weekday().num_days_from_monday())),
+            Self::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+}
+
+impl fmt::Display for TrendBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+        })
+    }
+}
+
+impl FromStr for TrendBucket {
+    type Err = LinkMLError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "day" | "daily" => Ok(Self::Day),
+            "week" | "weekly" => Ok(Self::Week),
+            "month" | "monthly" => Ok(Self::Month),
+            other => Err(LinkMLError::config(format!(
+                "Unknown trend bucket '{other}' (expected day, week or month)"
+            ))),
+        }
+    }
+}
+
+/// Error statistics of the reports in one period
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorRatePoint {
+    /// First day of the period
+    pub period_start: NaiveDate,
+    /// Number of reports
+    pub reports: usize,
+    /// Number of reports that failed validation
+    pub failed_reports: usize,
+    /// Total errors across reports
+    pub errors: usize,
+    /// Total warnings across reports
+    pub warnings: usize,
+    /// Share of failed reports (0.0 to 1.0)
+    pub failure_rate: f64,
+    /// Average errors per report
+    pub errors_per_report: f64,
+}
+
+/// Group reports into periods and compute their error rates
+pub fn error_rate_trend(reports: &[StoredReport], bucket: TrendBucket) -> Vec<ErrorRatePoint> {
+    let mut periods: BTreeMap<NaiveDate, Vec<&StoredReport>> = BTreeMap::new();
+    for report in reports {
+        periods
+            .entry(bucket.period_start(report.recorded_at.date_naive()))
+            .or_default()
+            .push(report);
+    }
+    periods
+        .into_iter()
+        .map(|(period_start, reports)| {
+            let failed_reports = reports.iter().filter(|r| !r.report.valid).count();
+            let errors: usize = reports.iter().map(|r| r.report.errors().count()).sum();
+            let warnings: usize = reports.iter().map(|r| r.report.warnings().count()).sum();
+            ErrorRatePoint {
+                period_start,
+                reports: reports.len(),
+                failed_reports,
+                errors,
+                warnings,
+                failure_rate: failed_reports as f64 / reports.len() as f64,
+                errors_per_report: errors as f64 / reports.len() as f64,
+            }
+        })
+        .collect()
+}
+
+fn decode_all<T: serde::de::DeserializeOwned>(records: Vec<StoredRecord>) -> Result<Vec<T>> {
+    records
+        .into_iter()
+        .map(|record| {
+            serde_json::from_value(record.body).map_err(|e| {
+                LinkMLError::deserialization(format!(
+                    "Invalid {} record {}: {e}",
+                    record.kind, record.id
+                ))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{FileRecordStore, Storage};
+    use crate::validator::report::ValidationIssue;
+    use chrono::TimeZone;
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, day, 9, 30, 0)
+            .single()
+            .expect("valid date")
+    }
+
+    fn report(errors: usize) -> ValidationReport {
+        let mut report = ValidationReport::new("people");
+        for index in 0..errors {
+            report.add_issue(ValidationIssue::error(
+                format!("error {index}"),
+                "$.name",
+                "required",
+            ));
+        }
+        report
+    }
+
+    fn stored(version: &str, day: u32, errors: usize) -> StoredReport {
+        StoredReport {
+            schema_name: "people".to_string(),
+            schema_version: Some(version.to_string()),
+            source: Some(format!("data/{day}.json")),
+            recorded_at: at(day),
+            report: report(errors),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_repositories_round_trip() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let storage = Storage::new(Arc::new(FileRecordStore::new(dir.path())));
+        storage.initialize().await.expect("initialized");
+
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/people".to_string(),
+            name: "people".to_string(),
+            version: Some("1.0.0".to_string()),
+            ..Default::default()
+        };
+        let first = storage.schemas().save(&schema, at(1)).await.expect("saved");
+        let again = storage.schemas().save(&schema, at(2)).await.expect("saved");
+        assert_eq!(first, again);
+        schema.version = Some("1.1.0".to_string());
+        storage.schemas().save(&schema, at(3)).await.expect("saved");
+        assert_eq!(
+            storage
+                .schemas()
+                .history("people")
+                .await
+                .expect("history")
+                .len(),
+            2
+        );
+        let latest = storage
+            .schemas()
+            .latest("people", None)
+            .await
+            .expect("query")
+            .expect("stored schema");
+        assert_eq!(latest.version.as_deref(), Some("1.1.0"));
+
+        for (version, day, errors) in [("1.0.0", 1, 0), ("1.0.0", 1, 2), ("1.1.0", 4, 1)] {
+            storage
+                .reports()
+                .save(&stored(version, day, errors))
+                .await
+                .expect("saved");
+        }
+        let filter = RecordFilter::default()
+            .with_schema("people")
+            .with_version("1.0.0");
+        assert_eq!(
+            storage.reports().find(&filter).await.expect("query").len(),
+            2
+        );
+        let recent = RecordFilter::default().with_since(at(2));
+        assert_eq!(
+            storage.reports().find(&recent).await.expect("query").len(),
+            1
+        );
+
+        storage
+            .audit()
+            .record(&AuditRecord {
+                actor: "ci".to_string(),
+                action: "data.validate".to_string(),
+                target: "data/1.json".to_string(),
+                schema_name: Some("people".to_string()),
+                schema_version: Some("1.0.0".to_string()),
+                recorded_at: at(1),
+                details: Value::Null,
+            })
+            .await
+            .expect("recorded");
+        let audit = storage
+            .audit()
+            .find(&RecordFilter::default())
+            .await
+            .expect("query");
+        assert_eq!(audit[0].action, "data.validate");
+    }
+
+    #[test]
+    fn test_error_rate_trend() {
+        // 2025-03-03 is a Monday
+        let reports = [
+            stored("1", 3, 0),
+            stored("1", 4, 3),
+            stored("1", 10, 1),
+            stored("1", 11, 0),
+        ];
+
+        let daily = error_rate_trend(&reports, TrendBucket::Day);
+        assert_eq!(daily.len(), 4);
+
+        let weekly = error_rate_trend(&reports, TrendBucket::Week);
+        assert_eq!(weekly.len(), 2);
+        assert_eq!(
+            Some(weekly[0].period_start),
+            NaiveDate::from_ymd_opt(2025, 3, 3)
+        );
+        assert_eq!(weekly[0].failed_reports, 1);
+        assert_eq!(weekly[0].errors, 3);
+        assert!((weekly[0].failure_rate - 0.5).abs() < f64::EPSILON);
+        assert!((weekly[0].errors_per_report - 1.5).abs() < f64::EPSILON);
+        assert_eq!(
+            Some(weekly[1].period_start),
+            NaiveDate::from_ymd_opt(2025, 3, 10)
+        );
+
+        let monthly = error_rate_trend(&reports, TrendBucket::Month);
+        assert_eq!(monthly.len(), 1);
+        assert_eq!(monthly[0].reports, 4);
+    }
+}
@@ -1,5 +1,7 @@
 //! Schema transformation module
 
 pub mod inheritance_resolver;
+pub mod profile;
 pub mod schema_diff;
 pub mod schema_merger;
+pub mod sssom_translator;
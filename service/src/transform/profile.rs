@@ -0,0 +1,470 @@
+//! Profile subsets of large schemas
+//!
+//! A profile spec selects the classes, and optionally the slots, a consumer
+//! needs from a core schema and tightens their constraints:
+//!
+//! ```yaml
+//! name: person_contact
+//! classes: [Person]
+//! slots: [name, email, address]
+//! slot_usage:
+//!   Person:
+//!     email:
+//!       required: true
+//! enum_values:
+//!   Country: [DE, AT]
+//! ```
+//!
+//! [`ProfileDeriver::derive`] keeps the selected classes together with
+//! everything they depend on: ancestors and mixins, the classes, enums and
+//! types used as ranges, and parent slots. Identifier and required slots are
+//! always kept so that data conforming to the profile still conforms to the
+//! core schema. The overrides are applied with
+//! [`ProfileChecker`], so they may only tighten constraints.
+
+use std::collections::HashSet;
+
+use indexmap::{IndexMap, IndexSet};
+use linkml_core::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::generator::base::{class_ancestry, induced_slots};
+use crate::schema::profile::ProfileChecker;
+
+/// Selection and tightening overrides of a profile
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProfileSpec {
+    /// Name of the profile schema
+    pub name: String,
+    /// `id` of the profile schema, `<base id>/<name>` if unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// `version` of the profile schema
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// `description` of the profile schema
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Classes to keep
+    pub classes: Vec<String>,
+    /// Slots to keep in all kept classes; all slots if empty
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub slots: Vec<String>,
+    /// Also keep the subclasses of the selected classes
+    pub include_descendants: bool,
+    /// `slot_usage` to add, by class and slot
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub slot_usage: IndexMap<String, IndexMap<String, SlotDefinition>>,
+    /// Refinements of global slots, by slot
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub slot_overrides: IndexMap<String, SlotDefinition>,
+    /// Permissible values to keep, by enum
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub enum_values: IndexMap<String, Vec<String>>,
+}
+
+impl ProfileSpec {
+    /// Parse a profile spec from YAML
+    ///
+    /// # Errors
+    ///
+    /// Returns a parse error if the YAML is not a valid spec.
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        serde_yaml::from_str(content)
+            .map_err(|e| LinkMLError::parse(format!("Invalid profile spec: {e}")))
+    }
+}
+
+/// Derives profile schemas from a base schema
+pub struct ProfileDeriver<'a> {
+    base: &'a SchemaDefinition,
+}
+
+impl<'a> ProfileDeriver<'a> {
+    /// Create a deriver of profiles of `base`
+    #[must_use]
+    pub fn new(base: &'a SchemaDefinition) -> Self {
+        Self { base }
+    }
+
+    /// Derive the profile schema of a spec
+    ///
+    /// # Errors
+    ///
+    /// Returns a schema validation error if the spec selects classes or
+    /// slots the base schema lacks, or if its overrides do not only tighten
+    /// constraints.
+    pub fn derive(&self, spec: &ProfileSpec) -> Result<SchemaDefinition> {
+        let trimmed = self.trim(spec)?;
+
+        let mut profile = SchemaDefinition {
+            id: spec.id.clone().unwrap_or_else(|| {
+                format!(
+                    "{}/{}",
+                    self.base.id.trim_end_matches(['/', '#']),
+                    spec.name
+                )
+            }),
+            name: spec.name.clone(),
+            version: spec.version.clone(),
+            description: spec.description.clone(),
+            slots: spec.slot_overrides.clone(),
+            ..SchemaDefinition::default()
+        };
+        for (class_name, usage) in &spec.slot_usage {
+            let class = profile.classes.entry(class_name.clone()).or_default();
+            class.name.clone_from(class_name);
+            class.slot_usage.clone_from(usage);
+        }
+        for (enum_name, texts) in &spec.enum_values {
+            // Keep the definitions of the values, not only their texts
+            let values = trimmed
+                .enums
+                .get(enum_name)
+                .into_iter()
+                .flat_map(|enum_def| &enum_def.permissible_values)
+                .filter(|value| texts.iter().any(|text| text == value.text()))
+                .cloned()
+                .collect();
+            profile.enums.insert(
+                enum_name.clone(),
+                EnumDefinition {
+                    name: enum_name.clone(),
+                    permissible_values: values,
+                    ..EnumDefinition::default()
+                },
+            );
+        }
+        ProfileChecker::new(&trimmed).apply(&profile)
+    }
+
+    /// Base schema reduced to the selection of a spec and its dependencies
+    fn trim(&self, spec: &ProfileSpec) -> Result<SchemaDefinition> {
+        let base = self.base;
+        if let Some(missing) = spec
+            .classes
+            .iter()
+            .find(|name| !base.classes.contains_key(*name))
+        {
+            return Err(LinkMLError::schema_validation(format!(
+                "Profile '{}' selects class '{missing}', which '{}' lacks",
+                spec.name, base.name
+            )));
+        }
+        let known_slots: HashSet<&String> = base
+            .slots
+            .keys()
+            .chain(
+                base.classes
+                    .values()
+                    .flat_map(|class| class.attributes.keys()),
+            )
+            .collect();
+        if let Some(missing) = spec.slots.iter().find(|name| !known_slots.contains(name)) {
+            return Err(LinkMLError::schema_validation(format!(
+                "Profile '{}' selects slot '{missing}', which '{}' lacks",
+                spec.name, base.name
+            )));
+        }
+
+        let mut closure = Closure::default();
+        let mut pending: Vec<String> = spec.classes.clone();
+        if spec.include_descendants {
+            pending.extend(
+                base.classes
+                    .keys()
+                    .filter(|name| {
+                        class_ancestry(base, name)
+                            .iter()
+                            .any(|ancestor| spec.classes.contains(ancestor))
+                    })
+                    .cloned(),
+            );
+        }
+        if let Some(range) = &base.default_range {
+            closure.add_range(base, range, &mut pending);
+        }
+        while let Some(class_name) = pending.pop() {
+            if !closure.classes.insert(class_name.clone()) {
+                continue;
+            }
+            pending.extend(class_ancestry(base, &class_name).into_iter().skip(1));
+            for slot in induced_slots(base, &class_name) {
+                let selected = spec.slots.is_empty()
+                    || spec.slots.contains(&slot.name)
+                    || slot.identifier == Some(true)
+                    || slot.required == Some(true);
+                if selected {
+                    closure.add_slot(base, &slot, &mut pending);
+                }
+            }
+        }
+
+        let mut schema = base.clone();
+        schema
+            .classes
+            .retain(|name, _| closure.classes.contains(name));
+        for class in schema.classes.values_mut() {
+            class.slots.retain(|name| closure.slots.contains(name));
+            class
+                .attributes
+                .retain(|name, _| closure.slots.contains(name));
+            class
+                .slot_usage
+                .retain(|name, _| closure.slots.contains(name));
+            class.unique_keys.retain(|_, key| {
+                key.unique_key_slots
+                    .iter()
+                    .all(|name| closure.slots.contains(name))
+            });
+        }
+        schema.slots.retain(|name, _| closure.slots.contains(name));
+        for slot in schema.slots.values_mut() {
+            if slot
+                .domain
+                .as_ref()
+                .is_some_and(|domain| !closure.classes.contains(domain))
+            {
+                slot.domain = None;
+            }
+            if slot
+                .inverse
+                .as_ref()
+                .is_some_and(|inverse| !closure.slots.contains(inverse))
+            {
+                slot.inverse = None;
+            }
+        }
+        schema.enums.retain(|name, _| closure.enums.contains(name));
+        schema.types.retain(|name, _| closure.types.contains(name));
+        Ok(schema)
+    }
+}
+
+/// Elements a selection depends on
+#[derive(Default)]
+struct Closure {
+    classes: IndexSet<String>,
+    slots: IndexSet<String>,
+    enums: IndexSet<String>,
+    types: IndexSet<String>,
+}
+
+impl Closure {
+    /// Keep a slot, its parent slots and the ranges of all of them
+    ///
+    /// The global definition is followed as well, since `slot_usage` may
+    /// have narrowed the range of the induced slot.
+    fn add_slot<'a>(
+        &mut self,
+        base: &'a SchemaDefinition,
+        slot: &'a SlotDefinition,
+        pending: &mut Vec<String>,
+    ) {
+        let mut stack: Vec<(&str, &SlotDefinition)> = vec![(&slot.name, slot)];
+        stack.extend(
+            base.slots
+                .get(&slot.name)
+                .map(|global| (slot.name.as_str(), global)),
+        );
+        while let Some((name, slot)) = stack.pop() {
+            self.slots.insert(name.to_string());
+            let expressions = [
+                &slot.any_of,
+                &slot.all_of,
+                &slot.exactly_one_of,
+                &slot.none_of,
+            ];
+            let ranges = slot.range.iter().chain(
+                expressions
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .filter_map(|expression| expression.range.as_ref()),
+            );
+            for range in ranges {
+                self.add_range(base, range, pending);
+            }
+            for parent in slot.is_a.iter().chain(&slot.mixins) {
+                if !self.slots.contains(parent)
+                    && let Some(definition) = base.slots.get(parent)
+                {
+                    stack.push((parent, definition));
+                }
+            }
+        }
+    }
+
+    /// Keep the class, enum or type a range names
+    fn add_range(&mut self, base: &SchemaDefinition, range: &str, pending: &mut Vec<String>) {
+        if base.classes.contains_key(range) {
+            pending.push(range.to_string());
+        } else if base.enums.contains_key(range) {
+            self.enums.insert(range.to_string());
+        } else {
+            let mut range = Some(range);
+            while let Some(name) = range.filter(|name| base.types.contains_key(*name)) {
+                if !self.types.insert(name.to_string()) {
+                    break;
+                }
+                range = base.types[name].base_type.as_deref();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_schema() -> SchemaDefinition {
+        serde_yaml::from_str(
+            r"
+id: https://example.org/core
+name: core
+classes:
+  NamedThing:
+    slots: [id]
+  Person:
+    is_a: NamedThing
+    slots: [name, email, age, address, country, employer]
+  Employee:
+    is_a: Person
+  Organization:
+    is_a: NamedThing
+    slots: [name]
+  Address:
+    attributes:
+      street: {}
+      country:
+        range: Country
+slots:
+  id:
+    identifier: true
+  name:
+    required: true
+  email:
+    pattern: '@'
+  age:
+    range: Age
+  address:
+    range: Address
+    inlined: true
+  country:
+    range: Country
+  employer:
+    range: Organization
+types:
+  Age:
+    base_type: integer
+enums:
+  Country:
+    permissible_values:
+      DE:
+        description: Germany
+      AT:
+      FR:
+",
+        )
+        .expect("valid base schema")
+    }
+
+    #[test]
+    fn test_profile_keeps_selection_and_dependencies() {
+        let spec = ProfileSpec::from_yaml(
+            r"
+name: person_contact
+classes: [Person]
+slots: [email, address, country]
+slot_usage:
+  Person:
+    email:
+      required: true
+enum_values:
+  Country: [DE]
+",
+        )
+        .expect("valid spec");
+        let base = base_schema();
+        let profile = ProfileDeriver::new(&base)
+            .derive(&spec)
+            .expect("valid profile");
+
+        assert_eq!(profile.name, "person_contact");
+        assert_eq!(profile.id, "https://example.org/core/person_contact");
+        let classes: Vec<&String> = profile.classes.keys().collect();
+        assert_eq!(classes, ["NamedThing", "Person", "Address"]);
+        // Identifier and required slots stay with the selected ones
+        assert_eq!(
+            profile.classes["Person"].slots,
+            ["name", "email", "address", "country"]
+        );
+        let slots: Vec<&String> = profile.slots.keys().collect();
+        assert_eq!(slots, ["id", "name", "email", "address", "country"]);
+        assert!(profile.types.is_empty());
+
+        assert_eq!(
+            profile.classes["Person"].slot_usage["email"].required,
+            Some(true)
+        );
+        let values = &profile.enums["Country"].permissible_values;
+        assert_eq!(values.len(), 1);
+        assert!(matches!(
+            &values[0],
+            PermissibleValue::Complex { description: Some(description), .. } if description == "Germany"
+        ));
+    }
+
+    #[test]
+    fn test_profile_closure_over_ranges_and_descendants() {
+        let spec = ProfileSpec {
+            name: "people".to_string(),
+            classes: vec!["Person".to_string()],
+            include_descendants: true,
+            ..ProfileSpec::default()
+        };
+        let base = base_schema();
+        let profile = ProfileDeriver::new(&base)
+            .derive(&spec)
+            .expect("valid profile");
+
+        for class in [
+            "NamedThing",
+            "Person",
+            "Employee",
+            "Organization",
+            "Address",
+        ] {
+            assert!(profile.classes.contains_key(class), "{class} is kept");
+        }
+        assert!(profile.types.contains_key("Age"));
+        assert!(profile.enums.contains_key("Country"));
+    }
+
+    #[test]
+    fn test_invalid_profile_spec_is_rejected() {
+        let base = base_schema();
+        let deriver = ProfileDeriver::new(&base);
+
+        let unknown = ProfileSpec {
+            name: "pets".to_string(),
+            classes: vec!["Pet".to_string()],
+            ..ProfileSpec::default()
+        };
+        assert!(deriver.derive(&unknown).is_err());
+
+        let relaxed = ProfileSpec::from_yaml(
+            r"
+name: relaxed
+classes: [Person]
+slot_usage:
+  Person:
+    name:
+      required: false
+",
+        )
+        .expect("valid spec");
+        assert!(deriver.derive(&relaxed).is_err());
+    }
+}
@@ -0,0 +1,645 @@
+//! Translation of instance data between schemas with SSSOM mappings
+//!
+//! An SSSOM mapping set relates elements of a source schema (subjects) to
+//! elements of a target schema (objects). [`SssomTranslator`] resolves the
+//! mappings against both schemas and rewrites instances of the source schema:
+//!
+//! - the class of an instance, and of nested objects, by class mappings
+//! - slot names by slot mappings, per class including inherited slots
+//! - enum values by mappings between the `meaning`s of permissible values
+//!
+//! Elements are identified by their `class_uri`/`slot_uri`/`meaning`, or by
+//! their name in the default prefix namespace. When several mappings apply,
+//! the one with the predicate listed first in
+//! [`TranslationConfig::predicates`] wins, then the most confident one. Every
+//! mapping applied is recorded in the [`Translation`] and, as JSON, in the
+//! [`PROVENANCE_METADATA_KEY`] metadata of the translated instance.
+
+use std::collections::HashMap;
+
+use linkml_core::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::generator::base::induced_slots;
+use crate::generator::sssom::SssomMappingSet;
+use crate::loader::traits::DataInstance;
+use crate::namespace::CurieResolver;
+
+/// Metadata key of the applied mappings, as a JSON array, in translated
+/// instances
+pub const PROVENANCE_METADATA_KEY: &str = "sssom_provenance";
+
+/// Metadata key of the class an instance was translated from
+pub const SOURCE_CLASS_METADATA_KEY: &str = "source_class";
+
+/// Configuration of the translation
+#[derive(Debug, Clone)]
+pub struct TranslationConfig {
+    /// Mapping predicates used for translation, in order of preference;
+    /// mappings with other predicates are ignored
+    pub predicates: Vec<String>,
+    /// Minimum confidence of mappings used; mappings without a confidence
+    /// are used
+    pub min_confidence: f64,
+    /// Translate elements without a mapping to target elements of the same
+    /// name
+    pub same_name_fallback: bool,
+}
+
+impl Default for TranslationConfig {
+    fn default() -> Self {
+        Self {
+            predicates: [
+                "skos:exactMatch",
+                "owl:equivalentClass",
+                "owl:equivalentProperty",
+                "owl:sameAs",
+                "skos:closeMatch",
+            ]
+            .map(String::from)
+            .to_vec(),
+            min_confidence: 0.0,
+            same_name_fallback: true,
+        }
+    }
+}
+
+/// A mapping applied during translation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppliedMapping {
+    /// JSON path of the translated class, slot or value in the source
+    /// instance
+    pub path: String,
+    /// Source element or value
+    pub source: String,
+    /// Target element or value
+    pub target: String,
+    /// `subject_id` of the mapping
+    pub subject_id: String,
+    /// `predicate_id` of the mapping
+    pub predicate_id: String,
+    /// `object_id` of the mapping
+    pub object_id: String,
+    /// `confidence` of the mapping
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+}
+
+/// Result of translating an instance
+#[derive(Debug, Clone)]
+pub struct Translation {
+    /// Instance of the target schema
+    pub instance: DataInstance,
+    /// Mappings applied, in document order
+    pub applied: Vec<AppliedMapping>,
+    /// JSON paths of source slots without a target, which were dropped
+    pub unmapped: Vec<String>,
+}
+
+/// Mapping resolved to a target element
+#[derive(Debug, Clone)]
+struct Resolved {
+    target: String,
+    record: usize,
+    rank: usize,
+    confidence: f64,
+}
+
+/// Translator of instances between two schemas
+#[derive(Debug, Clone)]
+pub struct SssomTranslator {
+    source: SchemaDefinition,
+    target: SchemaDefinition,
+    mappings: SssomMappingSet,
+    config: TranslationConfig,
+    classes: HashMap<String, Resolved>,
+    slots: HashMap<String, Resolved>,
+    /// Target texts by source enum and text
+    values: HashMap<(String, String), Resolved>,
+}
+
+impl SssomTranslator {
+    /// Create a translator with the default configuration
+    #[must_use]
+    pub fn new(
+        source: &SchemaDefinition,
+        target: &SchemaDefinition,
+        mappings: &SssomMappingSet,
+    ) -> Self {
+        Self::with_config(source, target, mappings, TranslationConfig::default())
+    }
+
+    /// Create a translator with the given configuration
+    #[must_use]
+    pub fn with_config(
+        source: &SchemaDefinition,
+        target: &SchemaDefinition,
+        mappings: &SssomMappingSet,
+        config: TranslationConfig,
+    ) -> Self {
+        let source_resolver = resolver(source, mappings);
+        let target_resolver = resolver(target, mappings);
+        let source_index = ElementIndex::new(source, &source_resolver);
+        let target_index = ElementIndex::new(target, &target_resolver);
+        let predicates: Vec<String> = config
+            .predicates
+            .iter()
+            .map(|predicate| expand(&source_resolver, predicate))
+            .collect();
+
+        let mut translator = Self {
+            source: source.clone(),
+            target: target.clone(),
+            mappings: mappings.clone(),
+            config,
+            classes: HashMap::new(),
+            slots: HashMap::new(),
+            values: HashMap::new(),
+        };
+        for (record, mapping) in mappings.mappings.iter().enumerate() {
+            let predicate = expand(&source_resolver, &mapping.predicate_id);
+            let Some(rank) = predicates.iter().position(|p| *p == predicate) else {
+                continue;
+            };
+            let confidence = mapping.confidence.unwrap_or(1.0);
+            if confidence < translator.config.min_confidence {
+                continue;
+            }
+            let subject = expand(&source_resolver, &mapping.subject_id);
+            let object = expand(&target_resolver, &mapping.object_id);
+            let resolved = |target: &String| Resolved {
+                target: target.clone(),
+                record,
+                rank,
+                confidence,
+            };
+            if let (Some(from), Some(to)) = (
+                source_index.classes.get(&subject),
+                target_index.classes.get(&object),
+            ) {
+                prefer(&mut translator.classes, from.clone(), resolved(to));
+            }
+            if let (Some(from), Some(to)) = (
+                source_index.slots.get(&subject),
+                target_index.slots.get(&object),
+            ) {
+                prefer(&mut translator.slots, from.clone(), resolved(to));
+            }
+            if let (Some(from), Some((_, to))) = (
+                source_index.values.get(&subject),
+                target_index.values.get(&object),
+            ) {
+                prefer(&mut translator.values, from.clone(), resolved(to));
+            }
+        }
+        translator
+    }
+
+    /// Target class of a source class, if it has a mapping
+    #[must_use]
+    pub fn class_mapping(&self, class_name: &str) -> Option<&str> {
+        self.classes
+            .get(class_name)
+            .map(|resolved| resolved.target.as_str())
+    }
+
+    /// Target slot of a source slot, if it has a mapping
+    #[must_use]
+    pub fn slot_mapping(&self, slot_name: &str) -> Option<&str> {
+        self.slots
+            .get(slot_name)
+            .map(|resolved| resolved.target.as_str())
+    }
+
+    /// Translate an instance of the source schema
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class of the instance has no target class.
+    pub fn translate(&self, instance: &DataInstance) -> Result<Translation> {
+        let mut run = Run::default();
+        let target_class = self
+            .target_class(&instance.class_name, None, "$", &mut run)
+            .ok_or_else(|| {
+                LinkMLError::data_validation(format!(
+                    "No mapping of class '{}' to the target schema",
+                    instance.class_name
+                ))
+            })?;
+        let data = self.translate_object(
+            &instance.class_name,
+            &target_class,
+            instance.data.iter(),
+            "$",
+            &mut run,
+        );
+
+        let mut metadata = instance.metadata.clone();
+        metadata.insert(
+            SOURCE_CLASS_METADATA_KEY.to_string(),
+            instance.class_name.clone(),
+        );
+        metadata.insert(
+            PROVENANCE_METADATA_KEY.to_string(),
+            serde_json::to_string(&run.applied)?,
+        );
+        Ok(Translation {
+            instance: DataInstance {
+                class_name: target_class,
+                data: data.into_iter().collect(),
+                id: instance.id.clone(),
+                metadata,
+            },
+            applied: run.applied,
+            unmapped: run.unmapped,
+        })
+    }
+
+    /// Target class of a source class, falling back to the range of the
+    /// target slot and then to a class of the same name
+    fn target_class(
+        &self,
+        class_name: &str,
+        target_range: Option<&str>,
+        path: &str,
+        run: &mut Run,
+    ) -> Option<String> {
+        if let Some(resolved) = self.classes.get(class_name) {
+            run.apply(self, path, class_name, resolved);
+            return Some(resolved.target.clone());
+        }
+        target_range
+            .filter(|range| self.target.classes.contains_key(*range))
+            .or_else(|| {
+                (self.config.same_name_fallback && self.target.classes.contains_key(class_name))
+                    .then_some(class_name)
+            })
+            .map(String::from)
+    }
+
+    fn translate_object<'a>(
+        &self,
+        source_class: &str,
+        target_class: &str,
+        data: impl Iterator<Item = (&'a String, &'a Value)>,
+        path: &str,
+        run: &mut Run,
+    ) -> Map<String, Value> {
+        let source_slots = induced_slots(&self.source, source_class);
+        let target_slots = induced_slots(&self.target, target_class);
+        let mut translated = Map::new();
+        for (key, value) in data {
+            let slot_path = format!("{path}.{key}");
+            let target_name = match self.slots.get(key) {
+                Some(resolved) => {
+                    run.apply(self, &slot_path, key, resolved);
+                    Some(resolved.target.clone())
+                }
+                None => (self.config.same_name_fallback
+                    && target_slots.iter().any(|slot| slot.name == *key))
+                .then(|| key.clone()),
+            };
+            let Some(target_name) = target_name else {
+                run.unmapped.push(slot_path);
+                continue;
+            };
+            let source_range = source_slots
+                .iter()
+                .find(|slot| slot.name == *key)
+                .and_then(|slot| slot.range.as_deref());
+            let target_range = target_slots
+                .iter()
+                .find(|slot| slot.name == target_name)
+                .and_then(|slot| slot.range.as_deref());
+            let value = self.translate_value(value, source_range, target_range, &slot_path, run);
+            translated.insert(target_name, value);
+        }
+        translated
+    }
+
+    fn translate_value(
+        &self,
+        value: &Value,
+        source_range: Option<&str>,
+        target_range: Option<&str>,
+        path: &str,
+        run: &mut Run,
+    ) -> Value {
+        match value {
+            Value::Array(items) => Value::Array(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| {
+                        let item_path = format!("{path}[{index}]");
+                        self.translate_value(item, source_range, target_range, &item_path, run)
+                    })
+                    .collect(),
+            ),
+            Value::Object(object) => {
+                let Some(source_class) =
+                    source_range.filter(|range| self.source.classes.contains_key(*range))
+                else {
+                    return value.clone();
+                };
+                if let Some(target_class) = self.target_class(source_class, target_range, path, run)
+                {
+                    Value::Object(self.translate_object(
+                        source_class,
+                        &target_class,
+                        object.iter(),
+                        path,
+                        run,
+                    ))
+                } else {
+                    run.unmapped.push(path.to_string());
+                    Value::Null
+                }
+            }
+            Value::String(text) => {
+                let key = (source_range.unwrap_or_default().to_string(), text.clone());
+                match self.values.get(&key) {
+                    Some(resolved) => {
+                        run.apply(self, path, text, resolved);
+                        Value::String(resolved.target.clone())
+                    }
+                    None => value.clone(),
+                }
+            }
+            _ => value.clone(),
+        }
+    }
+}
+
+/// State of one translation
+#[derive(Default)]
+struct Run {
+    applied: Vec<AppliedMapping>,
+    unmapped: Vec<String>,
+}
+
+impl Run {
+    fn apply(
+        &mut self,
+        translator: &SssomTranslator,
+        path: &str,
+        source: &str,
+        resolved: &Resolved,
+    ) {
+        let record = &translator.mappings.mappings[resolved.record];
+        self.applied.push(AppliedMapping {
+            path: path.to_string(),
+            source: source.to_string(),
+            target: resolved.target.clone(),
+            subject_id: record.subject_id.clone(),
+            predicate_id: record.predicate_id.clone(),
+            object_id: record.object_id.clone(),
+            confidence: record.confidence,
+        });
+    }
+}
+
+/// Keep the preferred of two mappings of the same source element
+fn prefer<K: std::hash::Hash + Eq>(
+    resolved: &mut HashMap<K, Resolved>,
+    key: K,
+    candidate: Resolved,
+) {
+    let better = |current: &Resolved| {
+        (candidate.rank, -candidate.confidence) < (current.rank, -current.confidence)
+    };
+    match resolved.get(&key) {
+        Some(current) if !better(current) => {}
+        _ => {
+            resolved.insert(key, candidate);
+        }
+    }
+}
+
+/// Elements of a schema by IRI
+struct ElementIndex {
+    classes: HashMap<String, String>,
+    slots: HashMap<String, String>,
+    /// Enum and text of permissible values by meaning
+    values: HashMap<String, (String, String)>,
+}
+
+impl ElementIndex {
+    fn new(schema: &SchemaDefinition, resolver: &CurieResolver) -> Self {
+        let iri =
+            |uri: Option<&String>, name: &str| expand(resolver, uri.map_or(name, String::as_str));
+        let mut index = Self {
+            classes: HashMap::new(),
+            slots: HashMap::new(),
+            values: HashMap::new(),
+        };
+        for (name, class) in &schema.classes {
+            index
+                .classes
+                .insert(iri(class.class_uri.as_ref(), name), name.clone());
+            for (attribute_name, attribute) in &class.attributes {
+                index
+                    .slots
+                    .entry(iri(attribute.slot_uri.as_ref(), attribute_name))
+                    .or_insert_with(|| attribute_name.clone());
+            }
+        }
+        for (name, slot) in &schema.slots {
+            index
+                .slots
+                .insert(iri(slot.slot_uri.as_ref(), name), name.clone());
+        }
+        for (enum_name, enum_def) in &schema.enums {
+            for value in &enum_def.permissible_values {
+                if let PermissibleValue::Complex {
+                    text,
+                    meaning: Some(meaning),
+                    ..
+                } = value
+                {
+                    index
+                        .values
+                        .entry(expand(resolver, meaning))
+                        .or_insert_with(|| (enum_name.clone(), text.clone()));
+                }
+            }
+        }
+        index
+    }
+}
+
+/// Resolver of the prefixes of a schema and a mapping set, the mapping set
+/// taking precedence
+fn resolver(schema: &SchemaDefinition, mappings: &SssomMappingSet) -> CurieResolver {
+    let mut resolver = CurieResolver::from_schema(schema);
+    for (prefix, expansion) in &mappings.curie_map {
+        resolver.add_prefix(prefix, expansion);
+    }
+    // Names without a prefix belong to the default namespace
+    if schema
+        .default_prefix
+        .as_ref()
+        .is_none_or(|prefix| resolver.get_prefix(prefix).is_none())
+    {
+        resolver.set_base_uri(&format!("{}/", schema.id.trim_end_matches(['/', '#'])));
+    }
+    resolver
+}
+
+fn expand(resolver: &CurieResolver, id: &str) -> String {
+    resolver.expand_curie(id).unwrap_or_else(|_| id.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schemas()
+    -> std::result::Result<(SchemaDefinition, SchemaDefinition), Box<dyn std::error::Error>> {
+        let source = serde_yaml::from_str(
+            r"
+id: https://example.com/people
+name: people
+prefixes:
+  people: https://example.com/people/
+  status: https://example.com/status/
+default_prefix: people
+classes:
+  Person:
+    attributes:
+      name: {}
+      nickname: {}
+      status:
+        range: PersonStatus
+      address:
+        range: Address
+        inlined: true
+  Address:
+    attributes:
+      street: {}
+enums:
+  PersonStatus:
+    permissible_values:
+      ALIVE:
+        meaning: status:living
+",
+        )?;
+        let target = serde_yaml::from_str(
+            r"
+id: https://example.com/registry
+name: registry
+prefixes:
+  registry: https://example.com/registry/
+  vital: https://example.com/vital/
+default_prefix: registry
+classes:
+  Individual:
+    attributes:
+      full_name: {}
+      state:
+        range: LifeState
+      location:
+        range: Location
+  Location:
+    attributes:
+      street: {}
+enums:
+  LifeState:
+    permissible_values:
+      living:
+        meaning: vital:alive
+",
+        )?;
+        Ok((source, target))
+    }
+
+    const MAPPINGS: &str = "# curie_map:\n\
+                            #   people: https://example.com/people/\n\
+                            #   registry: https://example.com/registry/\n\
+                            #   status: https://example.com/status/\n\
+                            #   vital: https://example.com/vital/\n\
+                            subject_id\tpredicate_id\tobject_id\tconfidence\n\
+                            people:Person\tskos:exactMatch\tregistry:Individual\t\n\
+                            people:name\tskos:closeMatch\tregistry:full_name\t0.8\n\
+                            people:name\tskos:exactMatch\tregistry:full_name\t0.95\n\
+                            people:status\tskos:exactMatch\tregistry:state\t\n\
+                            people:address\tskos:exactMatch\tregistry:location\t\n\
+                            people:nickname\tskos:broadMatch\tregistry:full_name\t\n\
+                            status:living\tskos:exactMatch\tvital:alive\t\n";
+
+    #[test]
+    fn test_translate_instance() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let (source, target) = schemas()?;
+        let mappings = SssomMappingSet::from_tsv(MAPPINGS)?;
+        let translator = SssomTranslator::new(&source, &target, &mappings);
+
+        let instance = DataInstance {
+            class_name: "Person".to_string(),
+            data: serde_json::from_value(serde_json::json!({
+                "name": "Ada",
+                "nickname": "Countess",
+                "status": "ALIVE",
+                "address": {"street": "St James's Square"}
+            }))?,
+            id: Some("ada".to_string()),
+            metadata: HashMap::new(),
+        };
+        let translation = translator.translate(&instance)?;
+
+        assert_eq!(translation.instance.class_name, "Individual");
+        assert_eq!(translation.instance.id.as_deref(), Some("ada"));
+        let data = &translation.instance.data;
+        assert_eq!(data["full_name"], "Ada");
+        assert_eq!(data["state"], "living");
+        assert_eq!(data["location"]["street"], "St James's Square");
+        assert!(!data.contains_key("nickname"));
+        assert_eq!(translation.unmapped, vec!["$.nickname".to_string()]);
+
+        // The exact match is preferred over the more general close match
+        let name = translation
+            .applied
+            .iter()
+            .find(|applied| applied.path == "$.name")
+            .ok_or("no mapping applied to name")?;
+        assert_eq!(name.predicate_id, "skos:exactMatch");
+        assert_eq!(name.confidence, Some(0.95));
+        let value = translation
+            .applied
+            .iter()
+            .find(|applied| applied.path == "$.status" && applied.source == "ALIVE")
+            .ok_or("no mapping applied to the status value")?;
+        assert_eq!(value.target, "living");
+
+        let provenance: Vec<AppliedMapping> =
+            serde_json::from_str(&translation.instance.metadata[PROVENANCE_METADATA_KEY])?;
+        assert_eq!(provenance, translation.applied);
+        assert_eq!(
+            translation.instance.metadata[SOURCE_CLASS_METADATA_KEY],
+            "Person"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_translate_unmapped_class() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let (source, target) = schemas()?;
+        let mappings = SssomMappingSet::from_tsv(MAPPINGS)?;
+        let config = TranslationConfig {
+            min_confidence: 0.99,
+            ..TranslationConfig::default()
+        };
+        let translator = SssomTranslator::with_config(&source, &target, &mappings, config);
+        assert_eq!(translator.class_mapping("Person"), Some("Individual"));
+        assert_eq!(translator.slot_mapping("name"), None);
+
+        let instance = DataInstance {
+            class_name: "Address".to_string(),
+            data: HashMap::new(),
+            id: None,
+            metadata: HashMap::new(),
+        };
+        assert!(translator.translate(&instance).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+//! Logger used when the `RootReal` logger service is not wired
+//!
+//! [`TracingLogger`] forwards log messages to `tracing`, so standalone tools
+//! such as the `linkml` binary log to the installed subscriber without the
+//! `RootReal` logger service. [`wire_logger`] picks the wired logger service
+//! instead when the `rootreal-services` feature is enabled.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use linkml_core::error::Result;
+use logger_core::{LogEntry, LogLevel, LoggerError, LoggerService};
+
+/// `LoggerService` forwarding to `tracing`
+///
+/// The level filter is that of the `tracing` subscriber, so
+/// [`LoggerService::set_level`] has no effect.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingLogger;
+
+#[async_trait]
+impl LoggerService for TracingLogger {
+    type Error = LoggerError;
+
+    async fn debug(&self, message: &str) -> std::result::Result<(), Self::Error> {
+        tracing::debug!("{message}");
+        Ok(())
+    }
+
+    async fn info(&self, message: &str) -> std::result::Result<(), Self::Error> {
+        tracing::info!("{message}");
+        Ok(())
+    }
+
+    async fn warn(&self, message: &str) -> std::result::Result<(), Self::Error> {
+        tracing::warn!("{message}");
+        Ok(())
+    }
+
+    async fn error(&self, message: &str) -> std::result::Result<(), Self::Error> {
+        tracing::error!("{message}");
+        Ok(())
+    }
+
+    async fn log(&self, level: LogLevel, message: &str) -> std::result::Result<(), Self::Error> {
+        match level {
+            LogLevel::Debug => tracing::debug!("{message}"),
+            LogLevel::Warn => tracing::warn!("{message}"),
+            LogLevel::Error => tracing::error!("{message}"),
+            _ => tracing::info!("{message}"),
+        }
+        Ok(())
+    }
+
+    async fn log_entry(&self, entry: &LogEntry) -> std::result::Result<(), Self::Error> {
+        tracing::info!("{entry:?}");
+        Ok(())
+    }
+
+    async fn set_level(&self, _level: LogLevel) -> std::result::Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn flush(&self) -> std::result::Result<(), Self::Error> {
+        Ok(())
+    }
+
+    async fn shutdown(&self) -> std::result::Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Default `LoggerService` for components created without one
+///
+/// The `RootReal` logger service, wired to [`super::timestamp::wire_timestamp`],
+/// with the `rootreal-services` feature, otherwise the [`TracingLogger`].
+///
+/// # Errors
+///
+/// Returns an error if the `RootReal` logger service cannot be wired.
+pub fn wire_logger() -> Result<Arc<dyn LoggerService<Error = LoggerError>>> {
+    #[cfg(feature = "rootreal-services")]
+    {
+        logger_service::wiring::wire_logger(
+            super::timestamp::wire_timestamp(),
+            logger_core::LoggerConfig::default(),
+        )
+        .map(|logger| logger.into_inner())
+        .map_err(|e| {
+            linkml_core::error::LinkMLError::service(format!("Failed to wire logger: {e}"))
+        })
+    }
+    #[cfg(not(feature = "rootreal-services"))]
+    {
+        Ok(Arc::new(TracingLogger))
+    }
+}
@@ -3,8 +3,10 @@
 //! This module contains various utility functions and helpers used throughout
 //! the LinkML service.
 
+pub mod logging;
 pub mod safe_cast;
 pub mod timestamp;
 
+pub use logging::TracingLogger;
 pub use safe_cast::*;
-pub use timestamp::{SyncTimestampUtils, TimestampUtils};
+pub use timestamp::{SyncTimestampUtils, SystemClock, TimestampUtils};
@@ -6,16 +6,16 @@
 use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use linkml_core::error::{LinkMLError, Result};
 use std::sync::Arc;
-use timestamp_core::TimestampService;
+use timestamp_core::{SyncTimestampService, TimestampError, TimestampService};
 
 /// Timestamp utilities that wrap `TimestampService` functionality
 pub struct TimestampUtils {
-    service: Arc<dyn TimestampService<Error = timestamp_core::TimestampError>>,
+    service: Arc<dyn TimestampService<Error = TimestampError>>,
 }
 
 impl TimestampUtils {
     /// Create new timestamp utilities with a `TimestampService`
-    pub fn new(service: Arc<dyn TimestampService<Error = timestamp_core::TimestampError>>) -> Self {
+    pub fn new(service: Arc<dyn TimestampService<Error = TimestampError>>) -> Self {
         Self { service }
     }
 
@@ -200,7 +200,7 @@ pub struct SyncTimestampUtils {
 
 impl SyncTimestampUtils {
     /// Create new sync timestamp utilities.
-    pub fn new(service: Arc<dyn TimestampService<Error = timestamp_core::TimestampError>>) -> Self {
+    pub fn new(service: Arc<dyn TimestampService<Error = TimestampError>>) -> Self {
         Self {
             utils: Arc::new(TimestampUtils::new(service)),
         }
@@ -269,15 +269,193 @@ impl SyncTimestampUtils {
     }
 }
 
+/// `TimestampService` reading the system clock directly
+///
+/// A lightweight default for standalone tools such as the `linkml` binary,
+/// which run without the `RootReal` service wiring.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[async_trait::async_trait]
+impl TimestampService for SystemClock {
+    type Error = TimestampError;
+
+    async fn now_utc(&self) -> std::result::Result<DateTime<Utc>, Self::Error> {
+        Ok(Utc::now())
+    }
+
+    async fn now_local(&self) -> std::result::Result<DateTime<chrono::Local>, Self::Error> {
+        Ok(chrono::Local::now())
+    }
+
+    async fn system_time(&self) -> std::result::Result<std::time::SystemTime, Self::Error> {
+        Ok(std::time::SystemTime::now())
+    }
+
+    async fn parse_iso8601(
+        &self,
+        timestamp: &str,
+    ) -> std::result::Result<DateTime<Utc>, Self::Error> {
+        DateTime::parse_from_rfc3339(timestamp)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|e| TimestampError::parse_error(format!("Parse error: {e}")))
+    }
+
+    async fn format_iso8601(
+        &self,
+        timestamp: &DateTime<Utc>,
+    ) -> std::result::Result<String, Self::Error> {
+        Ok(timestamp.to_rfc3339())
+    }
+
+    async fn duration_since(
+        &self,
+        earlier: &DateTime<Utc>,
+    ) -> std::result::Result<chrono::TimeDelta, Self::Error> {
+        Ok(Utc::now() - *earlier)
+    }
+
+    async fn unix_timestamp_to_datetime(
+        &self,
+        seconds: i64,
+    ) -> std::result::Result<DateTime<Utc>, Self::Error> {
+        DateTime::from_timestamp(seconds, 0)
+            .ok_or_else(|| TimestampError::parse_error("Invalid Unix timestamp".to_string()))
+    }
+
+    async fn add_duration(
+        &self,
+        timestamp: &DateTime<Utc>,
+        duration: chrono::TimeDelta,
+    ) -> std::result::Result<DateTime<Utc>, Self::Error> {
+        Ok(*timestamp + duration)
+    }
+
+    async fn subtract_duration(
+        &self,
+        timestamp: &DateTime<Utc>,
+        duration: chrono::TimeDelta,
+    ) -> std::result::Result<DateTime<Utc>, Self::Error> {
+        Ok(*timestamp - duration)
+    }
+
+    async fn duration_between(
+        &self,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> std::result::Result<chrono::TimeDelta, Self::Error> {
+        Ok(*to - *from)
+    }
+}
+
+impl SyncTimestampService for SystemClock {
+    type Error = TimestampError;
+
+    fn now_utc(&self) -> std::result::Result<DateTime<Utc>, Self::Error> {
+        Ok(Utc::now())
+    }
+
+    fn now_local(&self) -> std::result::Result<DateTime<chrono::Local>, Self::Error> {
+        Ok(chrono::Local::now())
+    }
+
+    fn system_time(&self) -> std::result::Result<std::time::SystemTime, Self::Error> {
+        Ok(std::time::SystemTime::now())
+    }
+
+    fn parse_iso8601(&self, timestamp: &str) -> std::result::Result<DateTime<Utc>, Self::Error> {
+        DateTime::parse_from_rfc3339(timestamp)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|e| TimestampError::parse_error(format!("Parse error: {e}")))
+    }
+
+    fn format_iso8601(
+        &self,
+        timestamp: &DateTime<Utc>,
+    ) -> std::result::Result<String, Self::Error> {
+        Ok(timestamp.to_rfc3339())
+    }
+
+    fn duration_since(
+        &self,
+        earlier: &DateTime<Utc>,
+    ) -> std::result::Result<chrono::TimeDelta, Self::Error> {
+        Ok(Utc::now() - *earlier)
+    }
+
+    fn unix_timestamp_to_datetime(
+        &self,
+        seconds: i64,
+    ) -> std::result::Result<DateTime<Utc>, Self::Error> {
+        DateTime::from_timestamp(seconds, 0)
+            .ok_or_else(|| TimestampError::parse_error("Invalid Unix timestamp".to_string()))
+    }
+
+    fn add_duration(
+        &self,
+        timestamp: &DateTime<Utc>,
+        duration: chrono::TimeDelta,
+    ) -> std::result::Result<DateTime<Utc>, Self::Error> {
+        Ok(*timestamp + duration)
+    }
+
+    fn subtract_duration(
+        &self,
+        timestamp: &DateTime<Utc>,
+        duration: chrono::TimeDelta,
+    ) -> std::result::Result<DateTime<Utc>, Self::Error> {
+        Ok(*timestamp - duration)
+    }
+
+    fn duration_between(
+        &self,
+        from: &DateTime<Utc>,
+        to: &DateTime<Utc>,
+    ) -> std::result::Result<chrono::TimeDelta, Self::Error> {
+        Ok(*to - *from)
+    }
+}
+
+/// Default `TimestampService` for components created without one
+///
+/// The wired `RootReal` timestamp service with the `rootreal-services`
+/// feature, otherwise the [`SystemClock`].
+#[must_use]
+pub fn wire_timestamp() -> Arc<dyn TimestampService<Error = TimestampError>> {
+    #[cfg(feature = "rootreal-services")]
+    {
+        timestamp_service::wiring::wire_timestamp().into_inner()
+    }
+    #[cfg(not(feature = "rootreal-services"))]
+    {
+        Arc::new(SystemClock)
+    }
+}
+
+/// Default `SyncTimestampService` for components created without one
+///
+/// The wired `RootReal` timestamp service with the `rootreal-services`
+/// feature, otherwise the [`SystemClock`].
+#[must_use]
+pub fn wire_sync_timestamp() -> Arc<dyn SyncTimestampService<Error = TimestampError>> {
+    #[cfg(feature = "rootreal-services")]
+    {
+        timestamp_service::wiring::wire_sync_timestamp()
+    }
+    #[cfg(not(feature = "rootreal-services"))]
+    {
+        Arc::new(SystemClock)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::{Datelike, Timelike};
-    use timestamp_service::wiring::wire_timestamp;
 
     #[tokio::test]
     async fn test_timestamp_utils() -> Result<()> {
-        let ts_service = wire_timestamp().into_inner();
+        let ts_service = wire_timestamp();
         let utils = TimestampUtils::new(ts_service);
 
         // Test getting current time
@@ -304,9 +482,21 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_system_clock() -> Result<()> {
+        let utils = TimestampUtils::new(Arc::new(SystemClock));
+        assert!(utils.now().await?.timestamp() > 0);
+        let parsed = SystemClock
+            .parse_iso8601("2024-01-15T10:30:00Z")
+            .await
+            .map_err(|e| LinkMLError::service(e.to_string()))?;
+        assert_eq!(parsed.hour(), 10);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_sync_utils() -> Result<()> {
-        let ts_service = wire_timestamp().into_inner();
+        let ts_service = wire_timestamp();
         let utils = SyncTimestampUtils::new(ts_service);
 
         // Test blocking operations
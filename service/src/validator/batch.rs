@@ -0,0 +1,423 @@
+//! Batch validation of data sets against several schemas
+//!
+//! A batch manifest maps data sets to the schema and class they are
+//! validated against:
+//!
+//! ```yaml
+//! datasets:
+//!   - name: people
+//!     schema: schemas/people.yaml
+//!     class: Person
+//!     data: [data/people]
+//!   - name: samples
+//!     schema: schemas/biosamples.yaml
+//!     data: [data/samples/batch-1.json, data/samples/batch-2.json]
+//!     rules: rules/samples.yaml
+//! ```
+//!
+//! [`BatchValidator`] validates the data sets concurrently on a thread pool
+//! sized from the [`ValidatorConfig`] and aggregates the results into a
+//! [`BatchSummary`]. Every data set gets its own status and exit code, so CI
+//! can tell data that fails validation (1) from a data set that could not be
+//! validated at all (2). Relative paths resolve against the directory of the
+//! manifest.
+
+use super::discovery;
+use super::engine::{ValidationEngine, ValidationOptions};
+use super::report::{ValidationIssue, ValidationReport};
+use super::rule_config::ValidationRuleConfig;
+use crate::config::ValidatorConfig;
+use crate::parser::Parser;
+use linkml_core::error::{LinkMLError, Result};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// A data set of a batch manifest
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BatchDataset {
+    /// Name used in the summary, the first data path if omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Schema the data is validated against
+    pub schema: PathBuf,
+    /// Target class, tree root detection if omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    /// Data files and directories
+    pub data: Vec<PathBuf>,
+    /// Rule configuration with per-rule severity overrides
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rules: Option<PathBuf>,
+}
+
+impl BatchDataset {
+    /// Name of the data set in the summary
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| {
+            self.data
+                .first()
+                .map_or_else(String::new, |path| path.display().to_string())
+        })
+    }
+}
+
+/// Contents of a batch manifest
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BatchManifest {
+    /// Data sets to validate
+    pub datasets: Vec<BatchDataset>,
+}
+
+impl BatchManifest {
+    /// Load a manifest (YAML or JSON), resolving its relative paths against
+    /// the manifest's directory
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid manifest
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            LinkMLError::io_error(format!(
+                "Failed to read batch manifest {}: {e}",
+                path.display()
+            ))
+        })?;
+        let mut manifest: Self = serde_yaml::from_str(&content).map_err(|e| {
+            LinkMLError::config(format!("Invalid batch manifest {}: {e}", path.display()))
+        })?;
+        let root = path.parent().unwrap_or_else(|| Path::new(""));
+        manifest.resolve(root);
+        Ok(manifest)
+    }
+
+    /// Resolve relative paths against `root`
+    pub fn resolve(&mut self, root: &Path) {
+        let resolve = |path: &mut PathBuf| {
+            if path.is_relative() {
+                *path = root.join(&*path);
+            }
+        };
+        for dataset in &mut self.datasets {
+            resolve(&mut dataset.schema);
+            dataset.data.iter_mut().for_each(resolve);
+            dataset.rules.iter_mut().for_each(resolve);
+        }
+    }
+}
+
+/// Outcome of validating a data set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatasetStatus {
+    /// All files are valid
+    Passed,
+    /// At least one file has validation errors
+    Failed,
+    /// The data set could not be validated (unreadable schema, data or rules)
+    Error,
+}
+
+impl DatasetStatus {
+    /// Process exit code of the status
+    #[must_use]
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Self::Passed => 0,
+            Self::Failed => 1,
+            Self::Error => 2,
+        }
+    }
+}
+
+/// Validation result of one data file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileSummary {
+    /// Data file
+    pub path: PathBuf,
+    /// Whether the file is valid
+    pub valid: bool,
+    /// Number of errors
+    pub errors: usize,
+    /// Number of warnings
+    pub warnings: usize,
+    /// Issues reported for the file
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl FileSummary {
+    fn new(path: PathBuf, report: ValidationReport) -> Self {
+        Self {
+            path,
+            valid: report.valid,
+            errors: report.stats.error_count,
+            warnings: report.stats.warning_count,
+            issues: report.issues,
+        }
+    }
+}
+
+/// Validation result of one data set
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatasetSummary {
+    /// Data set name
+    pub name: String,
+    /// Schema the data set was validated against
+    pub schema: PathBuf,
+    /// Target class, if given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    /// Outcome
+    pub status: DatasetStatus,
+    /// Exit code of the outcome
+    pub exit_code: i32,
+    /// Number of invalid files
+    pub invalid_files: usize,
+    /// Total number of errors
+    pub errors: usize,
+    /// Total number of warnings
+    pub warnings: usize,
+    /// Wall-clock validation time in milliseconds
+    pub duration_ms: u64,
+    /// Why the data set could not be validated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Results of the data set's files
+    pub files: Vec<FileSummary>,
+}
+
+/// Aggregated result of a batch validation
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchSummary {
+    /// Number of data sets that passed
+    pub passed: usize,
+    /// Number of data sets that failed validation
+    pub failed: usize,
+    /// Number of data sets that could not be validated
+    pub errored: usize,
+    /// Highest exit code of all data sets
+    pub exit_code: i32,
+    /// Results in manifest order
+    pub datasets: Vec<DatasetSummary>,
+}
+
+impl BatchSummary {
+    fn new(datasets: Vec<DatasetSummary>) -> Self {
+        let count = |status| {
+            datasets
+                .iter()
+                .filter(|dataset| dataset.status == status)
+                .count()
+        };
+        Self {
+            passed: count(DatasetStatus::Passed),
+            failed: count(DatasetStatus::Failed),
+            errored: count(DatasetStatus::Error),
+            exit_code: datasets
+                .iter()
+                .map(|dataset| dataset.exit_code)
+                .max()
+                .unwrap_or(0),
+            datasets,
+        }
+    }
+}
+
+/// Validates the data sets of a batch manifest concurrently
+pub struct BatchValidator {
+    thread_pool: rayon::ThreadPool,
+}
+
+impl BatchValidator {
+    /// Create a batch validator running `threads` data sets at a time
+    ///
+    /// # Errors
+    ///
+    /// Returns a `LinkMLError` if thread pool creation fails
+    pub fn new(threads: usize) -> Result<Self> {
+        let thread_pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .map_err(|e| LinkMLError::service(format!("Failed to create thread pool: {e}")))?;
+        Ok(Self { thread_pool })
+    }
+
+    /// Create a batch validator sized from the validator configuration
+    ///
+    /// Data sets are validated one at a time when parallel validation is
+    /// disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `LinkMLError` if thread pool creation fails
+    pub fn from_config(config: &ValidatorConfig) -> Result<Self> {
+        Self::new(if config.enable_parallel {
+            config.thread_count
+        } else {
+            1
+        })
+    }
+
+    /// Number of data sets validated at a time
+    #[must_use]
+    pub fn threads(&self) -> usize {
+        self.thread_pool.current_num_threads()
+    }
+
+    /// Validate all data sets of a manifest
+    #[must_use]
+    pub fn validate(&self, manifest: &BatchManifest) -> BatchSummary {
+        let datasets = self.thread_pool.install(|| {
+            manifest
+                .datasets
+                .par_iter()
+                .map(Self::validate_dataset)
+                .collect()
+        });
+        BatchSummary::new(datasets)
+    }
+
+    fn validate_dataset(dataset: &BatchDataset) -> DatasetSummary {
+        let start = Instant::now();
+        let (status, files, error) = match Self::validate_files(dataset) {
+            Ok(files) if files.iter().all(|file| file.valid) => {
+                (DatasetStatus::Passed, files, None)
+            }
+            Ok(files) => (DatasetStatus::Failed, files, None),
+            Err(err) => (DatasetStatus::Error, Vec::new(), Some(err.to_string())),
+        };
+        DatasetSummary {
+            name: dataset.display_name(),
+            schema: dataset.schema.clone(),
+            class: dataset.class.clone(),
+            status,
+            exit_code: status.exit_code(),
+            invalid_files: files.iter().filter(|file| !file.valid).count(),
+            errors: files.iter().map(|file| file.errors).sum(),
+            warnings: files.iter().map(|file| file.warnings).sum(),
+            duration_ms: u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX),
+            error,
+            files,
+        }
+    }
+
+    fn validate_files(dataset: &BatchDataset) -> Result<Vec<FileSummary>> {
+        let schema = Parser::new().parse_file(&dataset.schema)?;
+        let engine = ValidationEngine::new(&schema)
+            .map_err(|err| LinkMLError::service(format!("Failed to build validator: {err}")))?;
+        let options = ValidationOptions {
+            rule_config: dataset
+                .rules
+                .as_deref()
+                .map(ValidationRuleConfig::from_file)
+                .transpose()?
+                .map(Arc::new),
+            ..ValidationOptions::default()
+        };
+
+        let mut files = Vec::new();
+        for path in discovery::collect_data_files(&dataset.data)? {
+            let mut value = load_data(&path)?;
+            discovery::strip_schema_key(&mut value);
+            let report = futures::executor::block_on(async {
+                match &dataset.class {
+                    Some(class) => {
+                        engine
+                            .validate_as_class(&value, class, Some(options.clone()))
+                            .await
+                    }
+                    None => engine.validate(&value, Some(options.clone())).await,
+                }
+            })?;
+            files.push(FileSummary::new(path, report));
+        }
+        Ok(files)
+    }
+}
+
+/// Read a YAML or JSON data file
+fn load_data(path: &Path) -> Result<Value> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        LinkMLError::io_error(format!("Failed to read data file {}: {e}", path.display()))
+    })?;
+    if matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("yaml" | "yml")
+    ) {
+        serde_yaml::from_str(&content).map_err(|e| {
+            LinkMLError::data_validation(format!("YAML parse error in {}: {e}", path.display()))
+        })
+    } else {
+        serde_json::from_str(&content).map_err(|e| {
+            LinkMLError::data_validation(format!("JSON parse error in {}: {e}", path.display()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_validation_summary() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("data/people")).expect("mkdir");
+        std::fs::write(
+            root.join("people.yaml"),
+            "id: https://example.org/people\nname: people\nclasses:\n  Person:\n    tree_root: true\n    attributes:\n      name:\n        range: string\n        required: true\n      age:\n        range: integer\n",
+        )
+        .expect("write schema");
+        std::fs::write(
+            root.join("data/people/alice.yaml"),
+            "name: Alice\nage: 30\n",
+        )
+        .expect("write data");
+        std::fs::write(root.join("data/bob.json"), r#"{"age": "old"}"#).expect("write data");
+        std::fs::write(
+            root.join("batch.yaml"),
+            "datasets:\n  - name: people\n    schema: people.yaml\n    class: Person\n    data: [data/people]\n  - name: broken\n    schema: people.yaml\n    class: Person\n    data: [data/bob.json]\n  - schema: missing.yaml\n    data: [data/people]\n",
+        )
+        .expect("write manifest");
+
+        let manifest = BatchManifest::load(&root.join("batch.yaml")).expect("manifest");
+        assert_eq!(manifest.datasets[0].schema, root.join("people.yaml"));
+        let validator = BatchValidator::new(2).expect("validator");
+        let summary = validator.validate(&manifest);
+
+        assert_eq!((summary.passed, summary.failed, summary.errored), (1, 1, 1));
+        assert_eq!(summary.exit_code, 2);
+        let statuses: Vec<_> = summary
+            .datasets
+            .iter()
+            .map(|dataset| (dataset.name.as_str(), dataset.status, dataset.exit_code))
+            .collect();
+        let unnamed = root.join("data/people").display().to_string();
+        assert_eq!(
+            statuses,
+            [
+                ("people", DatasetStatus::Passed, 0),
+                ("broken", DatasetStatus::Failed, 1),
+                (unnamed.as_str(), DatasetStatus::Error, 2),
+            ]
+        );
+        let broken = &summary.datasets[1];
+        assert_eq!(broken.invalid_files, 1);
+        assert!(broken.errors >= 2);
+        assert!(!broken.files[0].issues.is_empty());
+        assert!(summary.datasets[2].error.is_some());
+
+        let json = serde_json::to_value(&summary).expect("json");
+        assert_eq!(json["datasets"][1]["status"], "failed");
+
+        std::fs::write(root.join("batch.yaml"), "datasets: []\nschemas: []\n").expect("write");
+        assert!(BatchManifest::load(&root.join("batch.yaml")).is_err());
+    }
+}
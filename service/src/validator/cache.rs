@@ -5,6 +5,7 @@
 //! `CacheService` for distributed caching support.
 
 use super::compiled::{CompilationOptions, CompiledValidator};
+use super::multi_layer_cache::MultiLayerCache;
 use blake3::Hasher;
 use linkml_core::error::Result as LinkMLResult;
 use linkml_core::prelude::*;
@@ -40,52 +41,41 @@ impl ValidatorCacheKey {
     /// Create a new cache key
     #[must_use]
     pub fn new(schema: &SchemaDefinition, class_name: &str, options: &CompilationOptions) -> Self {
-        // Calculate schema hash
-        let schema_hash = Self::hash_schema(schema);
-
-        // Calculate options hash
-        let options_hash = Self::hash_options(*options);
+        Self::with_schema_hash(
+            schema,
+            Self::schema_content_hash(schema),
+            class_name,
+            options,
+        )
+    }
 
+    /// Create a cache key from a precomputed [`schema_content_hash`]
+    ///
+    /// [`schema_content_hash`]: Self::schema_content_hash
+    #[must_use]
+    pub fn with_schema_hash(
+        schema: &SchemaDefinition,
+        schema_hash: String,
+        class_name: &str,
+        options: &CompilationOptions,
+    ) -> Self {
         Self {
             schema_id: schema.id.clone(),
             schema_hash,
             class_name: class_name.to_string(),
-            options_hash,
+            options_hash: Self::hash_options(*options),
         }
     }
 
-    /// Generate hash for schema
-    fn hash_schema(schema: &SchemaDefinition) -> String {
+    /// Hash of the full schema content
+    ///
+    /// Any change to the schema changes the hash, so validators cached under
+    /// it can be shared by all service instances loading the same schema.
+    #[must_use]
+    pub fn schema_content_hash(schema: &SchemaDefinition) -> String {
         let mut hasher = Hasher::new();
-
-        // Hash relevant schema parts
-        hasher.update(schema.id.as_bytes());
-        hasher.update(schema.name.as_bytes());
-        hasher.update(
-            schema
-                .version
-                .as_ref()
-                .map_or(b"", std::string::String::as_bytes),
-        );
-
-        // Hash classes
-        for (name, class) in &schema.classes {
-            hasher.update(name.as_bytes());
-            hasher.update(class.name.as_bytes());
-            if let Some(parent) = &class.is_a {
-                hasher.update(parent.as_bytes());
-            }
-        }
-
-        // Hash slots
-        for (name, slot) in &schema.slots {
-            hasher.update(name.as_bytes());
-            hasher.update(slot.name.as_bytes());
-            if let Some(pattern) = &slot.pattern {
-                hasher.update(pattern.as_bytes());
-            }
-        }
-
+        // Serializing a schema into a hasher cannot fail
+        let _ = serde_json::to_writer(&mut hasher, schema);
         hasher.finalize().to_hex().to_string()
     }
 
@@ -150,6 +140,9 @@ pub struct CompiledValidatorCache {
 
     /// Optional `RootReal` `CacheService` integration
     cache_service: Option<Arc<dyn CacheService>>,
+
+    /// Optional persistent cache shared with other service instances
+    persistent_cache: Option<Arc<MultiLayerCache>>,
 }
 
 impl Default for CompiledValidatorCache {
@@ -174,6 +167,7 @@ impl CompiledValidatorCache {
             max_validators,
             max_memory_bytes,
             cache_service: None,
+            persistent_cache: None,
         }
     }
 
@@ -184,6 +178,18 @@ impl CompiledValidatorCache {
         self
     }
 
+    /// Back the cache with a persistent multi-layer cache
+    ///
+    /// Validators missing locally are looked up in the persistent cache before
+    /// they are compiled, and newly compiled validators are written to it. With
+    /// an L3 directory shared by several service instances, a cold instance
+    /// loads the validators of known schemas instead of compiling them again.
+    #[must_use]
+    pub fn with_persistent_cache(mut self, persistent_cache: Arc<MultiLayerCache>) -> Self {
+        self.persistent_cache = Some(persistent_cache);
+        self
+    }
+
     /// Get a compiled validator from cache
     pub async fn get(&self, key: &ValidatorCacheKey) -> Option<Arc<CompiledValidator>> {
         // Try local cache first
@@ -208,6 +214,17 @@ impl CompiledValidatorCache {
             }
         }
 
+        // Try the persistent cache, which also serves validators compiled by
+        // other service instances
+        if let Some(persistent_cache) = &self.persistent_cache
+            && let Some(validator) = persistent_cache.get(key).await
+        {
+            self.insert_local(key, Arc::clone(&validator));
+            let mut stats = self.stats.write();
+            stats.hits += 1;
+            return Some(validator);
+        }
+
         let mut stats = self.stats.write();
         stats.misses += 1;
 
@@ -222,32 +239,15 @@ impl CompiledValidatorCache {
     /// - Serialization fails
     /// - Cache operations fail
     pub fn put(&self, key: &ValidatorCacheKey, validator: CompiledValidator) -> LinkMLResult<()> {
-        let validator_memory = Self::estimate_validator_memory(&validator);
         let validator_arc = Arc::new(validator);
+        self.insert_local(key, Arc::clone(&validator_arc));
 
-        // Check if we need to evict
+        // Store in the persistent cache if available; a failure only costs a
+        // later recompilation
+        if let Some(persistent_cache) = &self.persistent_cache
+            && let Err(e) = persistent_cache.put(key, &validator_arc)
         {
-            let mut cache = self.local_cache.write();
-            let mut stats = self.stats.write();
-
-            // Evict based on memory limit or validator count
-            while !cache.is_empty()
-                && (cache.len() >= self.max_validators
-                    || stats.memory_bytes + validator_memory > self.max_memory_bytes)
-            {
-                // Remove oldest entry (simple LRU strategy)
-                if let Some(first_key) = cache.keys().next().cloned()
-                    && let Some(removed) = cache.remove(&first_key)
-                {
-                    let removed_memory = Self::estimate_validator_memory(&removed);
-                    stats.memory_bytes = stats.memory_bytes.saturating_sub(removed_memory);
-                    stats.evictions += 1;
-                }
-            }
-
-            cache.insert(key.clone(), Arc::clone(&validator_arc));
-            stats.memory_bytes += validator_memory;
-            stats.cached_validators = cache.len();
+            tracing::warn!("Failed to store validator {key} in persistent cache: {e}");
         }
 
         // Store in distributed cache if available
@@ -262,8 +262,37 @@ impl CompiledValidatorCache {
         Ok(())
     }
 
+    /// Insert a validator into the local cache, evicting entries over the limits
+    fn insert_local(&self, key: &ValidatorCacheKey, validator: Arc<CompiledValidator>) {
+        let validator_memory = Self::estimate_validator_memory(&validator);
+        let mut cache = self.local_cache.write();
+        let mut stats = self.stats.write();
+
+        // Evict based on memory limit or validator count
+        while !cache.is_empty()
+            && (cache.len() >= self.max_validators
+                || stats.memory_bytes + validator_memory > self.max_memory_bytes)
+        {
+            // Remove oldest entry (simple LRU strategy)
+            if let Some(first_key) = cache.keys().next().cloned()
+                && let Some(removed) = cache.remove(&first_key)
+            {
+                let removed_memory = Self::estimate_validator_memory(&removed);
+                stats.memory_bytes = stats.memory_bytes.saturating_sub(removed_memory);
+                stats.evictions += 1;
+            }
+        }
+
+        cache.insert(key.clone(), validator);
+        stats.memory_bytes += validator_memory;
+        stats.cached_validators = cache.len();
+    }
+
     /// Clear all cached validators
     ///
+    /// The persistent cache is left untouched, as other service instances may
+    /// share it.
+    ///
     /// # Errors
     ///
     /// Returns a `LinkMLError` if cache clearing fails
@@ -361,6 +390,7 @@ pub trait CacheService: Send + Sync {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::validator::multi_layer_cache::MultiLayerCacheConfig;
 
     #[tokio::test]
     async fn test_cache_key_generation() {
@@ -416,6 +446,71 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_persistent_cache_shared_between_instances() -> anyhow::Result<()> {
+        let directory = tempfile::tempdir()?;
+        let persistent = || -> anyhow::Result<CompiledValidatorCache> {
+            let config = MultiLayerCacheConfig {
+                l3_enabled: true,
+                l3_directory: Some(directory.path().to_string_lossy().into_owned()),
+                prefetch_related: false,
+                ..MultiLayerCacheConfig::default()
+            };
+            Ok(CompiledValidatorCache::new()
+                .with_persistent_cache(Arc::new(MultiLayerCache::new(config, None)?)))
+        };
+
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/people".to_string(),
+            ..Default::default()
+        };
+        let code = SlotDefinition {
+            name: "code".to_string(),
+            pattern: Some("^[A-Z]{3}$".to_string()),
+            ..Default::default()
+        };
+        schema.slots.insert("code".to_string(), code);
+        let class = ClassDefinition {
+            name: "Item".to_string(),
+            slots: vec!["code".to_string()],
+            ..Default::default()
+        };
+        schema.classes.insert("Item".to_string(), class.clone());
+
+        let options = CompilationOptions::default();
+        let key = ValidatorCacheKey::new(&schema, "Item", &options);
+        let validator = CompiledValidator::compile_class(&schema, "Item", &class, options)?;
+        assert_eq!(validator.compiled_patterns.len(), 1);
+
+        let first = persistent()?;
+        first.put(&key, validator)?;
+
+        // A cold instance sharing the directory loads the validator from disk
+        let second = persistent()?;
+        let mut loaded = None;
+        for _ in 0..50 {
+            loaded = second.get(&key).await;
+            if loaded.is_some() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        let loaded = loaded.expect("validator should be loaded from the persistent cache");
+        assert_eq!(loaded.compiled_patterns.len(), 1);
+        assert_eq!(second.stats().cached_validators, 1);
+
+        // A changed schema is keyed by a different content hash
+        let mut changed = schema.clone();
+        if let Some(slot) = changed.slots.get_mut("code") {
+            slot.pattern = Some("^[A-Z]{4}$".to_string());
+        }
+        assert_ne!(
+            key.schema_hash,
+            ValidatorCacheKey::new(&changed, "Item", &options).schema_hash
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_cache_eviction() -> anyhow::Result<()> {
         let cache = CompiledValidatorCache::with_config(2, 1024 * 1024);
@@ -59,7 +59,7 @@ impl Default for CacheWarmingConfig {
 impl CacheWarmingConfig {
     /// Create cache warming config from `LinkML` service configuration
     #[must_use]
-    pub fn from_service_config(config: &linkml_core::configuration_v2::CacheConfig) -> Self {
+    pub fn from_service_config(config: &linkml_core::configuration::CacheConfig) -> Self {
         // Derive settings from available cache config fields
         let max_entries = config.max_entries;
         let ttl_seconds = config.ttl_seconds;
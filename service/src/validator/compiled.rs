@@ -54,6 +54,14 @@ pub enum ValidationInstruction {
         field: String,
     },
 
+    /// Warn if a recommended field is missing
+    CheckRecommended {
+        /// `JSON` path to the value
+        path: String,
+        /// Field name to check
+        field: String,
+    },
+
     /// Validate against a compiled regex
     ValidatePattern {
         /// `JSON` path to the value
@@ -214,6 +222,26 @@ impl CompiledValidator {
         compiler.compile_class(class_name, class)
     }
 
+    /// Recompile the regex patterns of a deserialized validator
+    ///
+    /// Serialization keeps only `pattern_strings`, so a validator loaded from a
+    /// persistent cache must restore its patterns before it is executed.
+    ///
+    /// # Errors
+    /// Returns `LinkMLError::SchemaValidation` if a pattern is not a valid regex
+    pub fn restore_patterns(&mut self) -> LinkMLResult<()> {
+        self.compiled_patterns = self
+            .pattern_strings
+            .iter()
+            .map(|pattern| {
+                regex::Regex::new(pattern).map_err(|e| {
+                    LinkMLError::schema_validation(format!("Invalid regex pattern: {e}"))
+                })
+            })
+            .collect::<LinkMLResult<_>>()?;
+        Ok(())
+    }
+
     /// Execute compiled validation instructions
     pub fn execute(
         &self,
@@ -257,6 +285,9 @@ impl CompiledValidator {
             message: format!("Value {num} is out of range"),
             validator: self.name.clone(),
             code: Some("range_violation".to_string()),
+            error_code: None,
+            explanation: None,
+            fix: Vec::new(),
             context,
         }
     }
@@ -274,6 +305,10 @@ impl CompiledValidator {
                 self.validate_required_field(value, path, field)
             }
 
+            ValidationInstruction::CheckRecommended { path, field } => {
+                self.validate_recommended_field(value, path, field)
+            }
+
             ValidationInstruction::ValidatePattern { path, pattern_id } => {
                 self.validate_pattern(value, path, *pattern_id)
             }
@@ -347,6 +382,9 @@ impl CompiledValidator {
                 message: format!("Value '{s}' is not a permissible value"),
                 validator: self.name.clone(),
                 code: Some("enum_violation".to_string()),
+                error_code: None,
+                explanation: None,
+                fix: Vec::new(),
                 context,
             });
         }
@@ -387,6 +425,9 @@ impl CompiledValidator {
                     message: format!("Expected type {expected_type:?}, got {actual_type:?}"),
                     validator: self.name.clone(),
                     code: Some("type_mismatch".to_string()),
+                    error_code: None,
+                    explanation: None,
+                    fix: Vec::new(),
                     context,
                 });
             }
@@ -516,6 +557,9 @@ impl CompiledValidator {
                     message: format!("String length {len} is out of range"),
                     validator: self.name.clone(),
                     code: Some("length_violation".to_string()),
+                    error_code: None,
+                    explanation: None,
+                    fix: Vec::new(),
                     context: context_map,
                 });
             }
@@ -541,6 +585,35 @@ impl CompiledValidator {
                 message: format!("Required field '{field}' is missing"),
                 validator: self.name.clone(),
                 code: Some("required_field_missing".to_string()),
+                error_code: None,
+                explanation: None,
+                fix: Vec::new(),
+                context: HashMap::new(),
+            });
+        }
+        issues
+    }
+
+    /// Warn about a missing recommended field
+    fn validate_recommended_field(
+        &self,
+        value: &JsonValue,
+        path: &str,
+        field: &str,
+    ) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if let Some(obj) = value.as_object()
+            && !obj.contains_key(field)
+        {
+            issues.push(ValidationIssue {
+                severity: Severity::Warning,
+                path: path.to_string(),
+                message: format!("Recommended slot '{field}' is missing"),
+                validator: self.name.clone(),
+                code: None,
+                error_code: Some(ErrorCode::RecommendedMissing),
+                explanation: None,
+                fix: Vec::new(),
                 context: HashMap::new(),
             });
         }
@@ -575,6 +648,9 @@ impl CompiledValidator {
                 message: format!("Value does not match pattern: {}", pattern.as_str()),
                 validator: self.name.clone(),
                 code: Some("pattern_mismatch".to_string()),
+                error_code: None,
+                explanation: None,
+                fix: Vec::new(),
                 context,
             });
         }
@@ -678,6 +754,7 @@ impl CompiledValidator {
     fn update_instruction_path(&self, instruction: &mut ValidationInstruction, new_path: &str) {
         match instruction {
             ValidationInstruction::CheckRequired { path, .. }
+            | ValidationInstruction::CheckRecommended { path, .. }
             | ValidationInstruction::ValidatePattern { path, .. }
             | ValidationInstruction::ValidateRange { path, .. }
             | ValidationInstruction::ValidateLength { path, .. }
@@ -797,6 +874,11 @@ impl<'a> ValidatorCompiler<'a> {
                 path: "$".to_string(),
                 field: slot_name.to_string(),
             });
+        } else if slot.recommended == Some(true) {
+            instructions.push(ValidationInstruction::CheckRecommended {
+                path: "$".to_string(),
+                field: slot_name.to_string(),
+            });
         }
 
         // Pattern validation
@@ -841,12 +923,11 @@ impl<'a> ValidatorCompiler<'a> {
 
         // Enum validation
         if let Some(range) = &slot.range
-            && let Some(enum_def) = self.schema.enums.get(range)
             && self
                 .options
                 .contains(CompilationOptions::CACHE_PERMISSIBLE_VALUES)
+            && let Some(enum_id) = self.cache_enum(range)
         {
-            let enum_id = self.cache_enum(range, enum_def);
             instructions.push(ValidationInstruction::ValidateEnum {
                 path: path.clone(),
                 enum_id,
@@ -917,28 +998,24 @@ impl<'a> ValidatorCompiler<'a> {
         Ok(id)
     }
 
-    fn cache_enum(&mut self, name: &str, enum_def: &EnumDefinition) -> usize {
+    fn cache_enum(&mut self, name: &str) -> Option<usize> {
         if let Some(&id) = self.enum_map.get(name) {
-            return id;
+            return Some(id);
         }
 
-        let mut enum_set = std::collections::HashSet::new();
-        for value in &enum_def.permissible_values {
-            match value {
-                PermissibleValue::Simple(s) => {
-                    enum_set.insert(s.clone());
-                }
-                PermissibleValue::Complex { text, .. } => {
-                    enum_set.insert(text.clone());
-                }
-            }
-        }
+        let include_descendants = self
+            .schema
+            .settings
+            .as_ref()
+            .and_then(|settings| settings.validation.as_ref())
+            .is_some_and(ValidationSettings::accepts_descendant_values);
+        let enum_set = self.schema.enum_values(name, include_descendants)?;
 
         let id = self.cached_enums.len();
         self.cached_enums.push(enum_set);
         self.enum_map.insert(name.to_string(), id);
 
-        id
+        Some(id)
     }
 
     fn compile_type(&self, type_name: &str) -> CompiledType {
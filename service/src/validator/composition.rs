@@ -180,6 +180,9 @@ impl SchemaComposer {
         if usage.required.is_some() {
             base_slot.required = usage.required;
         }
+        if usage.recommended.is_some() {
+            base_slot.recommended = usage.recommended;
+        }
         if usage.multivalued.is_some() {
             base_slot.multivalued = usage.multivalued;
         }
@@ -198,6 +201,12 @@ impl SchemaComposer {
         if usage.description.is_some() {
             base_slot.description.clone_from(&usage.description);
         }
+        if usage.unit.is_some() {
+            base_slot.unit.clone_from(&usage.unit);
+        }
+        if usage.array.is_some() {
+            base_slot.array.clone_from(&usage.array);
+        }
         // Add more overrides as needed
     }
 
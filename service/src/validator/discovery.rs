@@ -0,0 +1,390 @@
+//! Schema and target class discovery for instance files
+//!
+//! `linkml validate data/` finds the schema and target class of each data
+//! file without `--schema`/`--class`, looking in order at:
+//!
+//! 1. a sidecar pointer next to the file, `person.yaml.linkml`:
+//!
+//!    ```yaml
+//!    schema: ../schema/person.yaml
+//!    class: Person
+//!    ```
+//!
+//! 2. a `$linkml` key in the document itself, either a mapping like the
+//!    sidecar or a string `../schema/person.yaml#Person`; the key is removed
+//!    before validation;
+//!
+//! 3. the nearest `.linkml.yaml` directory configuration in the file's
+//!    directory or its ancestors, with a default schema and class and
+//!    per-file overrides matched by glob relative to the configuration:
+//!
+//!    ```yaml
+//!    schema: ../schema/registry.yaml
+//!    class: Person
+//!    files:
+//!      "orgs/*.yaml": Organization
+//!      "legacy/**/*.json":
+//!        schema: ../schema/legacy.yaml
+//!        class: LegacyPerson
+//!    ```
+//!
+//! Relative schema paths resolve against the file declaring them. A pointer
+//! without a class leaves the class to the validator's tree root detection.
+
+use indexmap::IndexMap;
+use linkml_core::error::{LinkMLError, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Extension appended to a data file name to form its sidecar pointer
+pub const SIDECAR_EXTENSION: &str = "linkml";
+
+/// Document key pointing at the schema of the document
+pub const SCHEMA_KEY: &str = "$linkml";
+
+/// File name of directory configurations
+pub const DIRECTORY_CONFIG: &str = ".linkml.yaml";
+
+/// Extensions of the data files collected from directories
+pub const DATA_EXTENSIONS: [&str; 3] = ["yaml", "yml", "json"];
+
+/// Where the schema of a data file was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiscoverySource {
+    /// Sidecar pointer file
+    Sidecar(PathBuf),
+    /// `$linkml` key of the document
+    Document,
+    /// Directory configuration file
+    Directory(PathBuf),
+}
+
+impl fmt::Display for DiscoverySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sidecar(path) | Self::Directory(path) => write!(f, "{}", path.display()),
+            Self::Document => write!(f, "{SCHEMA_KEY} key"),
+        }
+    }
+}
+
+/// Schema and target class discovered for a data file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredSchema {
+    /// Schema file, resolved against the declaring file
+    pub schema: PathBuf,
+    /// Target class, if declared
+    pub class: Option<String>,
+    /// Where the schema was found
+    pub source: DiscoverySource,
+}
+
+/// Schema pointer of a sidecar file or `$linkml` mapping
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SchemaPointer {
+    schema: PathBuf,
+    #[serde(default, alias = "target_class")]
+    class: Option<String>,
+}
+
+/// Per-file entry of a directory configuration
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum FileRule {
+    Class(String),
+    Pointer {
+        #[serde(default)]
+        schema: Option<PathBuf>,
+        #[serde(default, alias = "target_class")]
+        class: Option<String>,
+    },
+}
+
+/// Contents of a `.linkml.yaml` directory configuration
+#[derive(Debug, Clone, Default, Deserialize)]
+struct DirectoryConfig {
+    #[serde(default)]
+    schema: Option<PathBuf>,
+    #[serde(default, alias = "target_class")]
+    class: Option<String>,
+    #[serde(default)]
+    files: IndexMap<String, FileRule>,
+}
+
+impl DirectoryConfig {
+    /// Schema and class of a file relative to the configuration directory
+    fn resolve(&self, relative: &Path) -> Result<(Option<&Path>, Option<&str>)> {
+        for (pattern, rule) in &self.files {
+            let glob = glob::Pattern::new(pattern).map_err(|e| {
+                LinkMLError::config(format!("Invalid file pattern '{pattern}': {e}"))
+            })?;
+            if !glob.matches_path(relative) {
+                continue;
+            }
+            return Ok(match rule {
+                FileRule::Class(class) => (self.schema.as_deref(), Some(class.as_str())),
+                FileRule::Pointer { schema, class } => (
+                    schema.as_deref().or(self.schema.as_deref()),
+                    class.as_deref().or(self.class.as_deref()),
+                ),
+            });
+        }
+        Ok((self.schema.as_deref(), self.class.as_deref()))
+    }
+}
+
+/// Discovers the schemas of data files, caching directory configurations
+#[derive(Debug, Default)]
+pub struct SchemaDiscovery {
+    configs: HashMap<PathBuf, Option<DirectoryConfig>>,
+}
+
+impl SchemaDiscovery {
+    /// Create a discovery without cached configurations
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Find the schema of a data file from its sidecar, the document's
+    /// `$linkml` key or the nearest directory configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pointer or configuration exists but is malformed
+    pub fn discover(
+        &mut self,
+        data_path: &Path,
+        document: &Value,
+    ) -> Result<Option<DiscoveredSchema>> {
+        let base = data_path.parent().unwrap_or_else(|| Path::new(""));
+
+        let mut sidecar = data_path.as_os_str().to_owned();
+        sidecar.push(format!(".{SIDECAR_EXTENSION}"));
+        let sidecar = PathBuf::from(sidecar);
+        if sidecar.is_file() {
+            let pointer: SchemaPointer = read_yaml(&sidecar)?;
+            return Ok(Some(DiscoveredSchema {
+                schema: base.join(pointer.schema),
+                class: pointer.class,
+                source: DiscoverySource::Sidecar(sidecar),
+            }));
+        }
+
+        if let Some(pointer) = document.get(SCHEMA_KEY) {
+            let pointer = document_pointer(pointer)?;
+            return Ok(Some(DiscoveredSchema {
+                schema: base.join(pointer.schema),
+                class: pointer.class,
+                source: DiscoverySource::Document,
+            }));
+        }
+
+        let absolute = std::path::absolute(data_path).map_err(|e| {
+            LinkMLError::io_error(format!("Failed to resolve {}: {e}", data_path.display()))
+        })?;
+        for dir in absolute.ancestors().skip(1) {
+            let Some(config) = self.config(dir)? else {
+                continue;
+            };
+            let relative = absolute.strip_prefix(dir).unwrap_or(&absolute);
+            if let (Some(schema), class) = config.resolve(relative)? {
+                return Ok(Some(DiscoveredSchema {
+                    schema: dir.join(schema),
+                    class: class.map(str::to_string),
+                    source: DiscoverySource::Directory(dir.join(DIRECTORY_CONFIG)),
+                }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Directory configuration of `dir`, read once
+    fn config(&mut self, dir: &Path) -> Result<Option<&DirectoryConfig>> {
+        if !self.configs.contains_key(dir) {
+            let path = dir.join(DIRECTORY_CONFIG);
+            let config = if path.is_file() {
+                Some(read_yaml(&path)?)
+            } else {
+                None
+            };
+            self.configs.insert(dir.to_path_buf(), config);
+        }
+        Ok(self.configs.get(dir).and_then(Option::as_ref))
+    }
+}
+
+/// Remove the `$linkml` key from a document before validation
+pub fn strip_schema_key(document: &mut Value) {
+    if let Value::Object(map) = document {
+        map.remove(SCHEMA_KEY);
+    }
+}
+
+/// Expand directories into the data files they contain, sorted by path
+///
+/// Hidden files, sidecar pointers and files without a data extension are
+/// skipped; files given explicitly are kept as is.
+///
+/// # Errors
+///
+/// Returns an error if a directory cannot be read
+pub fn collect_data_files(paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if !path.is_dir() {
+            files.push(path.clone());
+            continue;
+        }
+        let mut found = Vec::new();
+        for entry in walkdir::WalkDir::new(path).follow_links(true) {
+            let entry = entry.map_err(|e| {
+                LinkMLError::io_error(format!("Failed to read {}: {e}", path.display()))
+            })?;
+            let name = entry.file_name().to_string_lossy();
+            let is_data = entry
+                .path()
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| DATA_EXTENSIONS.contains(&ext));
+            if entry.file_type().is_file() && is_data && !name.starts_with('.') {
+                found.push(entry.into_path());
+            }
+        }
+        found.sort();
+        files.extend(found);
+    }
+    Ok(files)
+}
+
+/// Parse the value of a `$linkml` key
+fn document_pointer(value: &Value) -> Result<SchemaPointer> {
+    if let Value::String(pointer) = value {
+        let (schema, class) = match pointer.split_once('#') {
+            Some((schema, class)) => (schema, Some(class.to_string())),
+            None => (pointer.as_str(), None),
+        };
+        return Ok(SchemaPointer {
+            schema: PathBuf::from(schema),
+            class,
+        });
+    }
+    serde_json::from_value(value.clone())
+        .map_err(|e| LinkMLError::config(format!("Invalid {SCHEMA_KEY} schema pointer: {e}")))
+}
+
+fn read_yaml<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| LinkMLError::io_error(format!("Failed to read {}: {e}", path.display())))?;
+    serde_yaml::from_str(&content)
+        .map_err(|e| LinkMLError::config(format!("Invalid {}: {e}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_discovery_precedence() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("data/orgs")).expect("mkdir");
+        std::fs::create_dir_all(root.join("data/legacy")).expect("mkdir");
+        std::fs::write(
+            root.join("data").join(DIRECTORY_CONFIG),
+            "schema: ../schema/registry.yaml\nclass: Person\nfiles:\n  \"orgs/*.yaml\": Organization\n  \"legacy/*.json\":\n    schema: ../schema/legacy.yaml\n",
+        )
+        .expect("write config");
+        std::fs::write(
+            root.join("data/special.yaml.linkml"),
+            "schema: ../schema/special.yaml\nclass: Special\n",
+        )
+        .expect("write sidecar");
+
+        let mut discovery = SchemaDiscovery::new();
+        let empty = json!({});
+
+        let sidecar = discovery
+            .discover(&root.join("data/special.yaml"), &empty)
+            .expect("discover")
+            .expect("sidecar");
+        assert_eq!(sidecar.schema, root.join("data/../schema/special.yaml"));
+        assert_eq!(sidecar.class.as_deref(), Some("Special"));
+
+        let document = json!({"$linkml": "../other.yaml#Event", "id": "e1"});
+        let keyed = discovery
+            .discover(&root.join("data/orgs/event.yaml"), &document)
+            .expect("discover")
+            .expect("document key");
+        assert_eq!(keyed.source, DiscoverySource::Document);
+        assert_eq!(keyed.schema, root.join("data/orgs/../other.yaml"));
+        assert_eq!(keyed.class.as_deref(), Some("Event"));
+
+        let org = discovery
+            .discover(&root.join("data/orgs/acme.yaml"), &empty)
+            .expect("discover")
+            .expect("directory rule");
+        assert_eq!(org.schema, root.join("data/../schema/registry.yaml"));
+        assert_eq!(org.class.as_deref(), Some("Organization"));
+
+        let legacy = discovery
+            .discover(&root.join("data/legacy/old.json"), &empty)
+            .expect("discover")
+            .expect("directory pointer");
+        assert_eq!(legacy.schema, root.join("data/../schema/legacy.yaml"));
+        assert_eq!(legacy.class.as_deref(), Some("Person"));
+
+        let person = discovery
+            .discover(&root.join("data/alice.yaml"), &empty)
+            .expect("discover")
+            .expect("directory default");
+        assert_eq!(person.class.as_deref(), Some("Person"));
+        assert_eq!(
+            person.source,
+            DiscoverySource::Directory(root.join("data").join(DIRECTORY_CONFIG))
+        );
+
+        assert!(
+            discovery
+                .discover(&root.join("elsewhere.yaml"), &empty)
+                .expect("discover")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_collect_data_files() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("nested")).expect("mkdir");
+        for file in [
+            "b.yaml",
+            "a.json",
+            "nested/c.yml",
+            "b.yaml.linkml",
+            ".linkml.yaml",
+            "notes.txt",
+        ] {
+            std::fs::write(root.join(file), "{}").expect("write");
+        }
+
+        let files = collect_data_files(&[root.to_path_buf()]).expect("collect");
+        assert_eq!(
+            files,
+            vec![
+                root.join("a.json"),
+                root.join("b.yaml"),
+                root.join("nested/c.yml")
+            ]
+        );
+
+        let mut document = json!({"$linkml": "schema.yaml", "id": "x"});
+        strip_schema_key(&mut document);
+        assert_eq!(document, json!({"id": "x"}));
+    }
+}
@@ -0,0 +1,354 @@
+//! Dynamic enums
+//!
+//! Besides static `permissible_values`, an enum can define its values by
+//! query:
+//!
+//! ```yaml
+//! enums:
+//!   NeuronType:
+//!     reachable_from:
+//!       source_ontology: obo:cl
+//!       source_nodes: [CL:0000540]
+//!       relationship_types: [rdfs:subClassOf]
+//!   GoTerm:
+//!     matches:
+//!       identifier_pattern: "^GO:[0-9]{7}$"
+//!   PrimaryColour:
+//!     concepts: [ex:Red, ex:Green, ex:Blue]
+//! ```
+//!
+//! `concepts` and `matches` are evaluated locally. `reachable_from` walks an
+//! ontology through an [`OntologyProvider`]; the [`DynamicEnumResolver`]
+//! expands each query once and caches the resulting terms. A value is
+//! permissible if it is a static permissible value or satisfies any of the
+//! queries.
+
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::{EnumDefinition, MatchQuery, ReachabilityQuery};
+use parking_lot::RwLock;
+use regex::Regex;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+/// Relationship traversed when a query names none
+pub const SUBCLASS_OF: &str = "rdfs:subClassOf";
+
+/// Source of ontology terms and relationships for dynamic enums
+pub trait OntologyProvider: Send + Sync {
+    /// Terms directly related to `node` by one of `relationship_types`
+    ///
+    /// With `up`, returns the terms `node` points to (its parents),
+    /// otherwise the terms pointing to `node` (its children).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ontology cannot be queried.
+    fn neighbors(
+        &self,
+        ontology: Option<&str>,
+        node: &str,
+        relationship_types: &[String],
+        up: bool,
+    ) -> Result<Vec<String>>;
+
+    /// Whether the ontology contains a term
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ontology cannot be queried.
+    fn contains(&self, ontology: Option<&str>, term: &str) -> Result<bool>;
+}
+
+/// Ontology held in memory as `(subject, relationship, object)` edges
+///
+/// Answers queries for any source ontology, which suits small vocabularies
+/// and tests.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryOntology {
+    edges: Vec<(String, String, String)>,
+    terms: HashSet<String>,
+}
+
+impl InMemoryOntology {
+    /// Create an empty ontology
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a `subject relationship object` edge
+    #[must_use]
+    pub fn with_edge(
+        mut self,
+        subject: impl Into<String>,
+        relationship: impl Into<String>,
+        object: impl Into<String>,
+    ) -> Self {
+        let (subject, object) = (subject.into(), object.into());
+        self.terms.insert(subject.clone());
+        self.terms.insert(object.clone());
+        self.edges.push((subject, relationship.into(), object));
+        self
+    }
+
+    /// Add a `child rdfs:subClassOf parent` edge
+    #[must_use]
+    pub fn with_subclass(self, child: impl Into<String>, parent: impl Into<String>) -> Self {
+        self.with_edge(child, SUBCLASS_OF, parent)
+    }
+}
+
+impl OntologyProvider for InMemoryOntology {
+    fn neighbors(
+        &self,
+        _ontology: Option<&str>,
+        node: &str,
+        relationship_types: &[String],
+        up: bool,
+    ) -> Result<Vec<String>> {
+        Ok(self
+            .edges
+            .iter()
+            .filter(|(_, relationship, _)| relationship_types.contains(relationship))
+            .filter_map(|(subject, _, object)| {
+                let (from, to) = if up {
+                    (subject, object)
+                } else {
+                    (object, subject)
+                };
+                (from == node).then(|| to.clone())
+            })
+            .collect())
+    }
+
+    fn contains(&self, _ontology: Option<&str>, term: &str) -> Result<bool> {
+        Ok(self.terms.contains(term))
+    }
+}
+
+/// Evaluates `reachable_from`, `matches` and `concepts` of enums
+#[derive(Default)]
+pub struct DynamicEnumResolver {
+    provider: Option<Arc<dyn OntologyProvider>>,
+    reachable: RwLock<HashMap<String, Arc<HashSet<String>>>>,
+    patterns: RwLock<HashMap<String, Regex>>,
+}
+
+impl DynamicEnumResolver {
+    /// Create a resolver without an ontology provider
+    ///
+    /// Enums using `reachable_from` cannot be evaluated by it.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a resolver querying an ontology provider
+    #[must_use]
+    pub fn with_provider(provider: Arc<dyn OntologyProvider>) -> Self {
+        Self {
+            provider: Some(provider),
+            ..Self::default()
+        }
+    }
+
+    /// Whether `value` satisfies one of the queries of a dynamic enum
+    ///
+    /// Static permissible values are not consulted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the enum uses `reachable_from`, or `matches` with
+    /// only a source ontology, and no provider is configured, if a `matches`
+    /// query is empty, if a pattern is invalid, or if the provider fails.
+    pub fn contains(&self, enum_def: &EnumDefinition, value: &str) -> Result<bool> {
+        if enum_def.concepts.iter().any(|concept| concept == value) {
+            return Ok(true);
+        }
+        if let Some(query) = &enum_def.matches
+            && self.matches(query, value)?
+        {
+            return Ok(true);
+        }
+        if let Some(query) = &enum_def.reachable_from {
+            return Ok(self.reachable(query)?.contains(value));
+        }
+        Ok(false)
+    }
+
+    /// Terms reachable by a query, cached per query
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no provider is configured or the provider fails.
+    pub fn reachable(&self, query: &ReachabilityQuery) -> Result<Arc<HashSet<String>>> {
+        let key = serde_json::to_string(query)?;
+        if let Some(terms) = self.reachable.read().get(&key) {
+            return Ok(Arc::clone(terms));
+        }
+
+        let provider = self
+            .provider
+            .as_ref()
+            .ok_or_else(|| LinkMLError::config("reachable_from requires an ontology provider"))?;
+        let relationships = if query.relationship_types.is_empty() {
+            vec![SUBCLASS_OF.to_string()]
+        } else {
+            query.relationship_types.clone()
+        };
+        let ontology = query.source_ontology.as_deref();
+        let up = query.traverse_up == Some(true);
+        let max_depth = if query.is_direct == Some(true) {
+            1
+        } else {
+            usize::MAX
+        };
+
+        let mut terms = HashSet::new();
+        if query.include_self == Some(true) {
+            terms.extend(query.source_nodes.iter().cloned());
+        }
+        let mut visited: HashSet<String> = query.source_nodes.iter().cloned().collect();
+        let mut queue: VecDeque<(String, usize)> = query
+            .source_nodes
+            .iter()
+            .map(|node| (node.clone(), 0))
+            .collect();
+        while let Some((node, depth)) = queue.pop_front() {
+            if depth == max_depth {
+                continue;
+            }
+            for neighbor in provider.neighbors(ontology, &node, &relationships, up)? {
+                terms.insert(neighbor.clone());
+                if visited.insert(neighbor.clone()) {
+                    queue.push_back((neighbor, depth + 1));
+                }
+            }
+        }
+
+        let terms = Arc::new(terms);
+        self.reachable.write().insert(key, Arc::clone(&terms));
+        Ok(terms)
+    }
+
+    /// Whether a value matches a pattern query
+    ///
+    /// The source ontology is only checked when a provider is configured.
+    /// A query that constrains nothing, with neither a pattern nor a source
+    /// ontology that can be checked, is an error rather than matching every
+    /// value.
+    fn matches(&self, query: &MatchQuery, value: &str) -> Result<bool> {
+        if let Some(pattern) = &query.identifier_pattern {
+            if !self.pattern(pattern)?.is_match(value) {
+                return Ok(false);
+            }
+        }
+        match (&self.provider, &query.source_ontology) {
+            (Some(provider), Some(ontology)) => provider.contains(Some(ontology), value),
+            (_, None) if query.identifier_pattern.is_none() => Err(LinkMLError::schema_validation(
+                "Enum matches query needs an identifier_pattern or a source_ontology",
+            )),
+            (None, Some(_)) if query.identifier_pattern.is_none() => Err(LinkMLError::config(
+                "matches with only a source_ontology requires an ontology provider",
+            )),
+            _ => Ok(true),
+        }
+    }
+
+    fn pattern(&self, pattern: &str) -> Result<Regex> {
+        if let Some(regex) = self.patterns.read().get(pattern) {
+            return Ok(regex.clone());
+        }
+        let regex = Regex::new(pattern).map_err(|e| {
+            LinkMLError::schema_validation(format!("Invalid enum match pattern '{pattern}': {e}"))
+        })?;
+        self.patterns
+            .write()
+            .insert(pattern.to_string(), regex.clone());
+        Ok(regex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dynamic_enum_queries() {
+        let ontology = InMemoryOntology::new()
+            .with_subclass("CL:2", "CL:1")
+            .with_subclass("CL:3", "CL:2")
+            .with_edge("CL:4", "BFO:part_of", "CL:1");
+        let resolver = DynamicEnumResolver::with_provider(Arc::new(ontology));
+
+        let mut query = ReachabilityQuery {
+            source_nodes: vec!["CL:1".to_string()],
+            ..Default::default()
+        };
+        let terms = resolver.reachable(&query).expect("reachable terms");
+        assert_eq!(terms.len(), 2);
+        assert!(terms.contains("CL:3") && !terms.contains("CL:1"));
+
+        query.is_direct = Some(true);
+        query.include_self = Some(true);
+        let terms = resolver.reachable(&query).expect("reachable terms");
+        assert!(terms.contains("CL:1") && terms.contains("CL:2") && !terms.contains("CL:3"));
+
+        let up = ReachabilityQuery {
+            source_nodes: vec!["CL:3".to_string()],
+            traverse_up: Some(true),
+            ..Default::default()
+        };
+        assert!(resolver.reachable(&up).expect("ancestors").contains("CL:1"));
+
+        let enum_def = EnumDefinition {
+            matches: Some(MatchQuery {
+                identifier_pattern: Some("^GO:[0-9]{7}$".to_string()),
+                source_ontology: None,
+            }),
+            concepts: vec!["ex:Other".to_string()],
+            ..Default::default()
+        };
+        assert!(enum_def.is_dynamic());
+        assert!(
+            resolver
+                .contains(&enum_def, "GO:0008150")
+                .expect("pattern query")
+        );
+        assert!(
+            resolver
+                .contains(&enum_def, "ex:Other")
+                .expect("pattern query")
+        );
+        assert!(!resolver.contains(&enum_def, "GO:1").expect("pattern query"));
+
+        let unresolvable = EnumDefinition {
+            reachable_from: Some(query),
+            ..Default::default()
+        };
+        assert!(
+            DynamicEnumResolver::new()
+                .contains(&unresolvable, "CL:2")
+                .is_err()
+        );
+
+        for matches in [
+            MatchQuery::default(),
+            MatchQuery {
+                identifier_pattern: None,
+                source_ontology: Some("obo:go".to_string()),
+            },
+        ] {
+            let empty = EnumDefinition {
+                matches: Some(matches),
+                ..Default::default()
+            };
+            assert!(
+                DynamicEnumResolver::new()
+                    .contains(&empty, "anything")
+                    .is_err(),
+                "a query constraining nothing does not accept every value"
+            );
+        }
+    }
+}
@@ -4,11 +4,15 @@ use crate::performance::profiling::Profiler;
 use crate::utils::safe_cast::u128_to_u64_saturating;
 use linkml_core::{
     error::{LinkMLError, Result},
+    error_codes::ErrorCode,
     settings::SchemaSettings,
     types::{ClassDefinition, SchemaDefinition, SlotDefinition},
 };
+use parking_lot::Mutex;
+use rayon::prelude::*;
 use serde_json::Value;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 use timestamp_core::SyncTimestampService;
 
 use super::{
@@ -18,13 +22,24 @@ use super::{
     conditional_validator::ConditionalValidator,
     context::ValidationContext,
     default_applier::DefaultApplier,
+    dynamic_enum::OntologyProvider,
+    explain, fixes,
+    normalizer::Normalizer,
+    null_policy::{EmptyCollectionPolicy, NullHandling, NullPolicy, SlotCheck},
     recursion_checker::{RecursionTracker, check_recursion},
     report::{ValidationIssue, ValidationReport},
-    validators::{Validator, ValidatorRegistry},
+    rule_config::ValidationRuleConfig,
+    status, suggest,
+    timing::{self, SLOWEST_CONSTRAINTS},
+    validators::{CustomValidatorRegistry, Validator, ValidatorRegistry},
 };
 use crate::inheritance::InheritanceResolver;
+use crate::loader::TypeDesignator;
 use crate::namespace::CurieResolver;
-use crate::schema_view::SchemaView;
+use crate::schema_view::{DraftPolicy, SchemaView};
+
+/// Chunks per thread of parallel collection validation
+const CHUNKS_PER_THREAD: usize = 4;
 
 /// Options for validation
 #[derive(Default)]
@@ -45,6 +60,33 @@ pub struct ValidationOptions {
     pub fail_on_warning: Option<bool>,
     /// Custom validators to use
     pub custom_validators: Vec<Box<dyn Validator>>,
+    /// Per-rule severity overrides applied to reported issues
+    pub rule_config: Option<Arc<ValidationRuleConfig>>,
+    /// Whether to normalize lenient input (numeric strings, padded values,
+    /// alternative date formats) before validating
+    pub normalize: Option<bool>,
+    /// Whether normalization converts quantities to the `unit` of their slot
+    pub convert_units: Option<bool>,
+    /// How explicit `null` slot values are treated
+    pub null_policy: Option<NullPolicy>,
+    /// How empty lists are treated
+    pub empty_collection_policy: Option<EmptyCollectionPolicy>,
+    /// Whether to measure the time of each constraint and list the slowest
+    /// in the report statistics
+    pub time_constraints: Option<bool>,
+    /// Time a single constraint check on a slot may take; the errors of a
+    /// slower check are reported as warnings with a diagnostic. Enables
+    /// constraint timing.
+    pub slot_time_budget: Option<Duration>,
+    /// Whether to attach an explanation to each issue: the definition that
+    /// introduced the failed constraint, its effective value and a fix
+    pub explain: Option<bool>,
+    /// Whether to attach `JSON` Patch fixes to auto-fixable issues (see
+    /// [`super::fixes`])
+    pub fixes: Option<bool>,
+    /// What to do with data using classes, slots or enums whose status is
+    /// draft (see [`super::status`])
+    pub draft_elements: Option<DraftPolicy>,
 }
 
 impl Clone for ValidationOptions {
@@ -59,6 +101,16 @@ impl Clone for ValidationOptions {
             fail_on_warning: self.fail_on_warning,
             // We can't clone custom validators, so we just create an empty vec
             custom_validators: Vec::new(),
+            rule_config: self.rule_config.clone(),
+            normalize: self.normalize,
+            convert_units: self.convert_units,
+            null_policy: self.null_policy,
+            empty_collection_policy: self.empty_collection_policy,
+            time_constraints: self.time_constraints,
+            slot_time_budget: self.slot_time_budget,
+            explain: self.explain,
+            fixes: self.fixes,
+            draft_elements: self.draft_elements,
         }
     }
 }
@@ -105,6 +157,13 @@ impl ValidationOptions {
         self
     }
 
+    /// Set per-rule severity overrides
+    #[must_use]
+    pub fn with_rule_config(mut self, rule_config: ValidationRuleConfig) -> Self {
+        self.rule_config = Some(Arc::new(rule_config));
+        self
+    }
+
     /// Get the effective `fail_fast` setting
     #[must_use]
     pub fn fail_fast(&self) -> bool {
@@ -128,6 +187,59 @@ impl ValidationOptions {
     pub fn parallel(&self) -> bool {
         self.parallel.unwrap_or(false)
     }
+
+    /// Get the effective normalize setting
+    #[must_use]
+    pub fn normalize(&self) -> bool {
+        self.normalize.unwrap_or(false)
+    }
+
+    /// Get the effective unit conversion setting
+    #[must_use]
+    pub fn convert_units(&self) -> bool {
+        self.convert_units.unwrap_or(false)
+    }
+
+    /// Get the effective null policy
+    #[must_use]
+    pub fn null_policy(&self) -> NullPolicy {
+        self.null_policy.unwrap_or_default()
+    }
+
+    /// Get the effective empty collection policy
+    #[must_use]
+    pub fn empty_collection_policy(&self) -> EmptyCollectionPolicy {
+        self.empty_collection_policy.unwrap_or_default()
+    }
+
+    /// Get the effective constraint timing setting
+    #[must_use]
+    pub fn time_constraints(&self) -> bool {
+        self.time_constraints
+            .unwrap_or(self.slot_time_budget.is_some())
+    }
+
+    /// Get the effective explain setting
+    #[must_use]
+    pub fn explain(&self) -> bool {
+        self.explain.unwrap_or(false)
+    }
+
+    /// Get the effective fix-it patch setting
+    #[must_use]
+    pub fn fixes(&self) -> bool {
+        self.fixes.unwrap_or(false)
+    }
+
+    /// Get the effective draft element policy
+    #[must_use]
+    pub fn draft_elements(&self) -> DraftPolicy {
+        self.draft_elements.unwrap_or_default()
+    }
+
+    pub(crate) fn null_handling(&self) -> NullHandling {
+        NullHandling::new(self.null_policy(), self.empty_collection_policy())
+    }
 }
 
 /// Main validation engine
@@ -135,9 +247,13 @@ pub struct ValidationEngine {
     pub(crate) schema: Arc<SchemaDefinition>,
     registry: ValidatorRegistry,
     compiled_cache: Option<Arc<CompiledValidatorCache>>,
+    /// Content hash of the schema keying its compiled validators
+    schema_hash: OnceLock<String>,
     buffer_pools: Arc<ValidationBufferPools>,
     timestamp_service: Arc<dyn SyncTimestampService<Error = timestamp_core::TimestampError>>,
     profiler: Arc<Profiler>,
+    /// Rayon pool of parallel collection validation, with its thread count
+    thread_pool: Mutex<Option<(usize, Arc<rayon::ThreadPool>)>>,
 }
 
 impl ValidationEngine {
@@ -150,16 +266,16 @@ impl ValidationEngine {
         let schema = Arc::new(schema.clone());
         let registry = ValidatorRegistry::new(&schema)?;
         // Use wiring function for sync timestamp service
-        let timestamp_service = timestamp_service::wiring::wire_sync_timestamp();
-        let profiler = Arc::new(Profiler::new(
-            timestamp_service::wiring::wire_timestamp().into_inner(),
-        ));
+        let timestamp_service = crate::utils::timestamp::wire_sync_timestamp();
+        let profiler = Arc::new(Profiler::new(crate::utils::timestamp::wire_timestamp()));
 
         Ok(Self {
             schema,
             registry,
             compiled_cache: None,
+            schema_hash: OnceLock::new(),
             buffer_pools: Arc::new(ValidationBufferPools::new()),
+            thread_pool: Mutex::new(None),
             timestamp_service,
             profiler,
         })
@@ -180,15 +296,15 @@ impl ValidationEngine {
         let schema = Arc::new(schema.clone());
         let registry = ValidatorRegistry::new(&schema)?;
 
-        let profiler = Arc::new(Profiler::new(
-            timestamp_service::wiring::wire_timestamp().into_inner(),
-        ));
+        let profiler = Arc::new(Profiler::new(crate::utils::timestamp::wire_timestamp()));
 
         Ok(Self {
             schema,
             registry,
             compiled_cache: None,
+            schema_hash: OnceLock::new(),
             buffer_pools: Arc::new(ValidationBufferPools::new()),
+            thread_pool: Mutex::new(None),
             timestamp_service,
             profiler,
         })
@@ -206,17 +322,17 @@ impl ValidationEngine {
         let schema = Arc::new(schema.clone());
         let registry = ValidatorRegistry::new(&schema)?;
         // Use wiring function for sync timestamp service
-        let timestamp_service = timestamp_service::wiring::wire_sync_timestamp();
+        let timestamp_service = crate::utils::timestamp::wire_sync_timestamp();
 
         Ok(Self {
             schema,
             registry,
             compiled_cache: Some(cache),
+            schema_hash: OnceLock::new(),
             buffer_pools: Arc::new(ValidationBufferPools::new()),
+            thread_pool: Mutex::new(None),
             timestamp_service: timestamp_service.clone(),
-            profiler: Arc::new(Profiler::new(
-                timestamp_service::wiring::wire_timestamp().into_inner(),
-            )),
+            profiler: Arc::new(Profiler::new(crate::utils::timestamp::wire_timestamp())),
         })
     }
 
@@ -237,11 +353,11 @@ impl ValidationEngine {
             schema,
             registry,
             compiled_cache: Some(cache),
+            schema_hash: OnceLock::new(),
             buffer_pools: Arc::new(ValidationBufferPools::new()),
+            thread_pool: Mutex::new(None),
             timestamp_service,
-            profiler: Arc::new(Profiler::new(
-                timestamp_service::wiring::wire_timestamp().into_inner(),
-            )),
+            profiler: Arc::new(Profiler::new(crate::utils::timestamp::wire_timestamp())),
         })
     }
 
@@ -250,6 +366,44 @@ impl ValidationEngine {
         self.registry.add_validator(validator);
     }
 
+    /// Create a validation engine running the custom validators that the
+    /// schema's `custom_validator` annotations name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validator registry creation fails or an
+    /// annotation names a validator `validators` does not contain
+    pub fn with_custom_validators(
+        schema: &SchemaDefinition,
+        validators: &CustomValidatorRegistry,
+    ) -> Result<Self> {
+        let mut engine = Self::new(schema)?;
+        engine.set_custom_validators(validators)?;
+        Ok(engine)
+    }
+
+    /// Bind the custom validators that the schema's `custom_validator`
+    /// annotations name, replacing earlier bindings
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an annotation names a validator `validators` does
+    /// not contain
+    pub fn set_custom_validators(&mut self, validators: &CustomValidatorRegistry) -> Result<()> {
+        self.registry
+            .set_custom_validators(&self.schema, validators)
+    }
+
+    /// Evaluate `reachable_from` queries of dynamic enums with an ontology
+    /// provider
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the permissible value validator cannot be created
+    pub fn set_ontology_provider(&mut self, provider: Arc<dyn OntologyProvider>) -> Result<()> {
+        self.registry.set_ontology_provider(&self.schema, provider)
+    }
+
     /// Validate data against the schema
     ///
     /// # Errors
@@ -308,18 +462,54 @@ impl ValidationEngine {
         });
 
         // Check that the class exists
-        let class_def = profiler.time("validate_as_class.get_class", || {
+        profiler.time("validate_as_class.get_class", || {
             self.schema.classes.get(class_name).ok_or_else(|| {
-                LinkMLError::schema_validation(format!("Class '{class_name}' not found in schema"))
+                LinkMLError::schema_validation(suggest::unknown_class_message(
+                    &self.schema,
+                    class_name,
+                ))
             })
         })?;
 
         let mut report = ValidationReport::new(&self.schema.id);
+
+        // Dispatch to the subclass named by a type designator slot
+        let class_name = match TypeDesignator::new(&self.schema).designated_class(data, class_name)
+        {
+            Ok(designated) => designated.unwrap_or(class_name),
+            Err(LinkMLError::DataValidationError { message, .. }) => {
+                report.add_issue(
+                    ValidationIssue::error(message, "$", "type_designator")
+                        .with_error_code(ErrorCode::TypeMismatch),
+                );
+                class_name
+            }
+            Err(error) => return Err(error),
+        };
+        let class_def = self.schema.classes.get(class_name).ok_or_else(|| {
+            LinkMLError::schema_validation(suggest::unknown_class_message(&self.schema, class_name))
+        })?;
         report.target_class = Some(class_name.to_string());
 
         let mut context =
             ValidationContext::with_buffer_pools(self.schema.clone(), self.buffer_pools.clone());
 
+        // Normalize lenient input first so validators see canonical values
+        let normalized;
+        let data = if options.normalize() {
+            let mut value = data.clone();
+            let issues = Normalizer::new(&self.schema)
+                .with_unit_conversion(options.convert_units())
+                .normalize(&mut value, class_name);
+            for issue in issues {
+                report.add_issue(issue);
+            }
+            normalized = value;
+            &normalized
+        } else {
+            data
+        };
+
         // Validate the data
         self.validate_class_instance(
             data,
@@ -330,6 +520,15 @@ impl ValidationEngine {
             &options,
         )
         .await?;
+        let policy = options.draft_elements();
+        for issue in status::status_issues(&self.schema, class_name, data, "$", policy) {
+            report.add_issue(issue);
+        }
+        self.apply_rule_config(&mut report, 0, class_name, &options);
+        self.explain_issues(&mut report, 0, class_name, &options);
+        if options.fixes() {
+            fixes::attach_fixes(&self.schema, class_name, data, &mut report, 0);
+        }
 
         // Update statistics
         let end = self
@@ -341,6 +540,7 @@ impl ValidationEngine {
             .map_err(|e| LinkMLError::service(format!("Time calculation error: {e}")))?;
         report.stats.duration_ms = duration.as_millis().try_into().unwrap_or(u64::MAX);
         report.stats.total_validated = 1; // For now, we validate one root object
+        timing::retain_slowest(&mut report.stats.slowest_constraints, SLOWEST_CONSTRAINTS);
 
         // Sort issues by severity and path
         report.sort_issues();
@@ -348,6 +548,17 @@ impl ValidationEngine {
         Ok(report)
     }
 
+    /// Normalize data as an instance of a class without validating it
+    ///
+    /// Returns the normalized data and one info issue per change; see
+    /// [`Normalizer`] for the coercions applied.
+    #[must_use]
+    pub fn normalize(&self, data: &Value, class_name: &str) -> (Value, Vec<ValidationIssue>) {
+        let mut data = data.clone();
+        let issues = Normalizer::new(&self.schema).normalize(&mut data, class_name);
+        (data, issues)
+    }
+
     /// Validate a single instance of a class
     async fn validate_class_instance(
         &self,
@@ -358,7 +569,37 @@ impl ValidationEngine {
         report: &mut ValidationReport,
         options: &ValidationOptions,
     ) -> Result<()> {
-        let data = self.apply_defaults_and_prepare(data, context, report);
+        let compiled = self
+            .compiled_validator(class_name, class_def, report, options)
+            .await?;
+        self.validate_class_instance_with(
+            data,
+            class_name,
+            class_def,
+            compiled.as_deref(),
+            context,
+            report,
+            options,
+        )
+    }
+
+    /// Validate a single instance of a class with its compiled validator, if
+    /// one is used
+    ///
+    /// Synchronous, so that parallel validation can call it from rayon
+    /// threads without a Tokio runtime.
+    fn validate_class_instance_with(
+        &self,
+        data: &Value,
+        class_name: &str,
+        class_def: &ClassDefinition,
+        compiled: Option<&CompiledValidator>,
+        context: &mut ValidationContext,
+        report: &mut ValidationReport,
+        options: &ValidationOptions,
+    ) -> Result<()> {
+        let original = data;
+        let data = self.apply_defaults_and_prepare(original, context, report, options);
 
         self.setup_schema_analysis(class_name)?;
         self.check_recursion_constraints(&data, class_name, class_def, context, report);
@@ -373,12 +614,8 @@ impl ValidationEngine {
             return Ok(());
         }
 
-        if self
-            .try_validate_with_compiled_validator(
-                &data, class_name, class_def, context, report, options,
-            )
-            .await?
-        {
+        if let Some(compiled) = compiled {
+            Self::run_compiled_validator(compiled, &data, context, report, options);
             return Ok(());
         }
 
@@ -390,7 +627,7 @@ impl ValidationEngine {
         };
 
         let valid_slot_names =
-            self.validate_declared_slots(&data, obj, class_name, context, report, options);
+            self.validate_declared_slots(&data, original, class_name, context, report, options);
 
         self.audit_unknown_slots(obj, class_name, context, &valid_slot_names, report);
 
@@ -457,25 +694,34 @@ impl ValidationEngine {
         Ok(false)
     }
 
-    async fn try_validate_with_compiled_validator(
+    /// The cached compiled validator of a class, compiling it on a cache
+    /// miss, or `None` when compiled validators are not used
+    async fn compiled_validator(
         &self,
-        data: &Value,
         class_name: &str,
         class_def: &ClassDefinition,
-        context: &mut ValidationContext,
         report: &mut ValidationReport,
         options: &ValidationOptions,
-    ) -> Result<bool> {
-        if !options.use_cache() {
-            return Ok(false);
+    ) -> Result<Option<Arc<CompiledValidator>>> {
+        // Compiled validators do not know about null-handling policies
+        if !options.use_cache() || options.null_handling() != NullHandling::default() {
+            return Ok(None);
         }
 
         let Some(cache) = self.compiled_cache.as_ref() else {
-            return Ok(false);
+            return Ok(None);
         };
 
         let compilation_options = CompilationOptions::default();
-        let cache_key = ValidatorCacheKey::new(&self.schema, class_name, &compilation_options);
+        let schema_hash = self
+            .schema_hash
+            .get_or_init(|| ValidatorCacheKey::schema_content_hash(&self.schema));
+        let cache_key = ValidatorCacheKey::with_schema_hash(
+            &self.schema,
+            schema_hash.clone(),
+            class_name,
+            &compilation_options,
+        );
 
         let compiled_validator = if let Some(validator) = cache.get(&cache_key).await {
             report.stats.cache_hit_rate = cache.stats().hit_rate();
@@ -506,24 +752,31 @@ impl ValidationEngine {
 
             cache.put(&cache_key, validator)?;
 
-            let cache_key = ValidatorCacheKey::new(&self.schema, class_name, &compilation_options);
             cache
                 .get(&cache_key)
                 .await
                 .ok_or_else(|| LinkMLError::service("Failed to retrieve cached validator"))?
         };
+        Ok(Some(compiled_validator))
+    }
 
-        let issues = compiled_validator.execute(data, context);
+    fn run_compiled_validator(
+        compiled: &CompiledValidator,
+        data: &Value,
+        context: &mut ValidationContext,
+        report: &mut ValidationReport,
+        options: &ValidationOptions,
+    ) {
+        let issues = compiled.execute(data, context);
         for issue in issues {
             report.add_issue(issue);
             if options.fail_fast() && !report.valid {
-                return Ok(true);
+                return;
             }
         }
 
         report.stats.validators_executed += 1;
         context.pop_class();
-        Ok(true)
     }
 
     fn ensure_object_for_class<'a>(
@@ -555,7 +808,7 @@ impl ValidationEngine {
     fn validate_declared_slots(
         &self,
         data: &Value,
-        obj: &serde_json::Map<String, Value>,
+        original: &Value,
         class_name: &str,
         context: &mut ValidationContext,
         report: &mut ValidationReport,
@@ -571,26 +824,42 @@ impl ValidationEngine {
             .iter()
             .map(|(name, _)| name.clone())
             .collect();
+        let null_handling = options.null_handling();
 
         for (name, slot_def) in &effective_slots {
-            if let Some(value) = obj.get(name.as_str()) {
-                context.push_path(name.clone());
-                self.validate_slot_value(value, slot_def, context, report, options);
-                context.pop_path();
+            match null_handling.check(name, slot_def, original.get(name), data.get(name)) {
+                SlotCheck::Validate(value) => {
+                    context.push_path(name.clone());
+                    self.validate_slot_value(value, slot_def, context, report, options);
+                    context.pop_path();
 
-                if options.fail_fast() && !report.valid {
-                    break;
+                    if options.fail_fast() && !report.valid {
+                        break;
+                    }
                 }
-            } else if slot_def.required.unwrap_or(false) {
-                report.add_issue(ValidationIssue::error(
-                    format!("Required slot '{name}' is missing"),
-                    format!("{}.{name}", context.path()),
-                    "required_validator",
-                ));
-
-                if options.fail_fast() {
-                    break;
+                SlotCheck::Error(message) => {
+                    report.add_issue(
+                        ValidationIssue::error(
+                            message,
+                            format!("{}.{name}", context.path()),
+                            "required_validator",
+                        )
+                        .with_error_code(ErrorCode::RequiredMissing),
+                    );
+
+                    if options.fail_fast() {
+                        break;
+                    }
                 }
+                SlotCheck::Warning(message) => report.add_issue(
+                    ValidationIssue::warning(
+                        message,
+                        format!("{}.{name}", context.path()),
+                        "recommended_validator",
+                    )
+                    .with_error_code(ErrorCode::RecommendedMissing),
+                ),
+                SlotCheck::Skip => {}
             }
         }
 
@@ -613,6 +882,7 @@ impl ValidationEngine {
             .and_then(|v| v.allow_additional_properties)
             .unwrap_or(true);
 
+        let max_distance = suggest::suggestion_distance(&self.schema);
         for key in obj.keys() {
             if valid_slot_names.iter().any(|name| name == key) {
                 continue;
@@ -632,7 +902,15 @@ impl ValidationEngine {
                 )
             };
 
-            report.add_issue(issue);
+            let suggestions = suggest::near_misses(
+                key,
+                valid_slot_names.iter().map(String::as_str),
+                max_distance,
+            );
+            report.add_issue(
+                suggest::with_suggestions(issue, &suggestions)
+                    .with_error_code(ErrorCode::UnknownSlot),
+            );
         }
     }
 
@@ -691,12 +969,26 @@ impl ValidationEngine {
             self.registry.get_validators_for_slot(slot_def)
         });
 
+        // Validators bound by annotations of the slot, its class or the schema
+        let bound = match (context.current_class(), context.current_slot()) {
+            (Some(class_name), Some(slot_name)) => {
+                self.registry.bound_validators(class_name, slot_name)
+            }
+            _ => &[],
+        };
+
         // Run each validator
-        for validator in validators {
-            let validator_name = validator.name();
-            let issues = profiler.time(&format!("slot_validation.{validator_name}"), || {
-                validator.validate(value, slot_def, context)
-            });
+        for validator in validators
+            .into_iter()
+            .chain(
+                bound
+                    .iter()
+                    .map(|validator| validator.as_ref() as &dyn Validator),
+            )
+            .chain(options.custom_validators.iter().map(Box::as_ref))
+        {
+            let issues =
+                self.run_slot_validator(validator, value, slot_def, context, report, options);
 
             for issue in issues {
                 report.add_issue(issue);
@@ -706,17 +998,49 @@ impl ValidationEngine {
             }
             report.stats.validators_executed += 1;
         }
+    }
 
-        // Run custom validators if any
-        for validator in &options.custom_validators {
-            let issues = validator.validate(value, slot_def, context);
-            for issue in issues {
-                report.add_issue(issue);
-                if options.fail_fast() && !report.valid {
-                    return;
-                }
-            }
-            report.stats.validators_executed += 1;
+    /// Run one validator on a slot value, timing it when requested
+    fn run_slot_validator(
+        &self,
+        validator: &dyn Validator,
+        value: &Value,
+        slot_def: &SlotDefinition,
+        context: &mut ValidationContext,
+        report: &mut ValidationReport,
+        options: &ValidationOptions,
+    ) -> Vec<ValidationIssue> {
+        let validator_name = validator.name();
+        let started = options.time_constraints().then(Instant::now);
+        let issues = self
+            .profiler
+            .time(&format!("slot_validation.{validator_name}"), || {
+                validator.validate(value, slot_def, context)
+            });
+        let Some(started) = started else {
+            return issues;
+        };
+
+        let elapsed = started.elapsed();
+        let exceeded_budget = options.slot_time_budget.filter(|budget| elapsed > *budget);
+        timing::record(
+            &mut report.stats.slowest_constraints,
+            context.current_class().unwrap_or_default(),
+            &slot_def.name,
+            validator_name,
+            elapsed,
+            exceeded_budget.is_some(),
+        );
+        match exceeded_budget {
+            Some(budget) => timing::over_budget_issues(
+                issues,
+                &slot_def.name,
+                validator_name,
+                context.path(),
+                elapsed,
+                budget,
+            ),
+            None => issues,
         }
     }
 
@@ -730,6 +1054,13 @@ impl ValidationEngine {
             return Ok(type_str.to_string());
         }
 
+        // Then for a type designator slot naming a class
+        if let Some(obj) = data.as_object()
+            && let Some(designated) = TypeDesignator::new(&self.schema).infer_class(obj)
+        {
+            return Ok(designated.to_string());
+        }
+
         // If we can't infer, look for tree_root classes
         let tree_roots: Vec<_> = self
             .schema
@@ -751,7 +1082,8 @@ impl ValidationEngine {
     /// Validate a collection of instances with unique key constraints
     ///
     /// This method validates multiple instances and checks for unique key violations
-    /// across the entire collection.
+    /// across the entire collection. With the `parallel` option the instances
+    /// are validated by [`Self::validate_collection_parallel`].
     ///
     /// # Errors
     ///
@@ -762,6 +1094,11 @@ impl ValidationEngine {
         class_name: &str,
         options: Option<ValidationOptions>,
     ) -> Result<ValidationReport> {
+        if options.as_ref().is_some_and(ValidationOptions::parallel) {
+            return self
+                .validate_collection_parallel(instances, class_name, options)
+                .await;
+        }
         let start = self
             .timestamp_service
             .system_time()
@@ -770,7 +1107,7 @@ impl ValidationEngine {
 
         // Check that the class exists
         let _class_def = self.schema.classes.get(class_name).ok_or_else(|| {
-            LinkMLError::schema_validation(format!("Class '{class_name}' not found in schema"))
+            LinkMLError::schema_validation(suggest::unknown_class_message(&self.schema, class_name))
         })?;
 
         let mut report = ValidationReport::new(&self.schema.id);
@@ -783,6 +1120,7 @@ impl ValidationEngine {
 
         // Validate each instance
         for (index, instance) in instances.iter().enumerate() {
+            let first_issue = report.issues.len();
             let mut context = ValidationContext::with_buffer_pools(
                 self.schema.clone(),
                 self.buffer_pools.clone(),
@@ -804,6 +1142,7 @@ impl ValidationEngine {
                 &options,
             )
             .await?;
+            self.add_status_issues(&mut report, index, instance, class_name, &options);
 
             // Run unique key validation after each instance
             if let Some(unique_validator) = self.registry.unique_key_validator()
@@ -818,14 +1157,14 @@ impl ValidationEngine {
 
                 for issue in unique_issues {
                     report.add_issue(issue);
-                    if options.fail_fast() && !report.valid {
-                        return Ok(report);
-                    }
                 }
             }
 
             context.pop_path();
+            self.apply_rule_config(&mut report, first_issue, class_name, &options);
+            self.explain_issues(&mut report, first_issue, class_name, &options);
 
+            // Only errors left after the rule configuration stop the run
             if options.fail_fast() && !report.valid {
                 break;
             }
@@ -839,13 +1178,14 @@ impl ValidationEngine {
             .duration_since(start)
             .map_err(|e| LinkMLError::service(format!("Time calculation error: {e}")))?;
         report.stats.duration_ms = u128_to_u64_saturating(duration.as_millis());
+        timing::retain_slowest(&mut report.stats.slowest_constraints, SLOWEST_CONSTRAINTS);
         Ok(report)
     }
 
     /// Validate a collection in parallel
     ///
-    /// This method validates instances in parallel but still maintains
-    /// proper unique key tracking across the collection.
+    /// Runs [`Self::validate_collection_with_threads`] with the
+    /// `validator.thread_count` configuration option.
     ///
     /// # Errors
     ///
@@ -856,20 +1196,223 @@ impl ValidationEngine {
         class_name: &str,
         options: Option<ValidationOptions>,
     ) -> Result<ValidationReport> {
-        // For unique key validation, we need sequential processing
-        // to properly track duplicates, so delegate to sequential version
-        self.validate_collection(instances, class_name, options)
+        let threads = crate::config::get_config().validator.thread_count;
+        self.validate_collection_with_threads(instances, class_name, options, threads)
             .await
     }
 
+    /// Validate a collection in parallel on `threads` threads (0 for one per
+    /// CPU)
+    ///
+    /// The instances are split into chunks validated on a rayon pool, idle
+    /// threads stealing chunks from busy ones. The pool is kept for later
+    /// calls with the same thread count, and on a multi-threaded Tokio
+    /// runtime the calling worker hands its other tasks off while it waits.
+    /// Unique keys are then checked across the collection in instance order
+    /// and the issues are merged in instance order, so the report equals the
+    /// one of [`Self::validate_collection`]; with `fail_fast` it ends with the
+    /// first invalid instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the thread pool cannot be created or validation
+    /// fails
+    pub async fn validate_collection_with_threads(
+        &mut self,
+        instances: &[Value],
+        class_name: &str,
+        options: Option<ValidationOptions>,
+        threads: usize,
+    ) -> Result<ValidationReport> {
+        let start = self
+            .timestamp_service
+            .system_time()
+            .map_err(|e| LinkMLError::service(format!("Failed to get system time: {e}")))?;
+        let options = options.unwrap_or_default();
+        let schema = Arc::clone(&self.schema);
+        let class_def = schema.classes.get(class_name).ok_or_else(|| {
+            LinkMLError::schema_validation(suggest::unknown_class_message(&self.schema, class_name))
+        })?;
+
+        let mut report = ValidationReport::new(&self.schema.id);
+        report.target_class = Some(class_name.to_string());
+        if let Some(validator) = self.registry.unique_key_validator_mut() {
+            let _ = validator.reset();
+        }
+
+        // Resolved once, so the rayon threads validate without awaiting
+        let compiled = self
+            .compiled_validator(class_name, class_def, &mut report, &options)
+            .await?;
+        let thread_pool = self.thread_pool(threads)?;
+        // Several chunks per thread so threads finishing early can steal work
+        let chunk_size = instances
+            .len()
+            .div_ceil(thread_pool.current_num_threads() * CHUNKS_PER_THREAD)
+            .max(1);
+        let engine: &Self = self;
+        let validate_chunks = || -> Vec<Result<ValidationReport>> {
+            thread_pool.install(|| {
+                instances
+                    .par_chunks(chunk_size)
+                    .enumerate()
+                    .flat_map_iter(|(chunk, records)| {
+                        let options = &options;
+                        let compiled = compiled.as_deref();
+                        records.iter().enumerate().map(move |(offset, instance)| {
+                            let mut instance_report = ValidationReport::new(&engine.schema.id);
+                            let mut context = ValidationContext::with_buffer_pools(
+                                Arc::clone(&engine.schema),
+                                Arc::clone(&engine.buffer_pools),
+                            );
+                            context.push_path(format!("[{}]", chunk * chunk_size + offset));
+                            engine.validate_class_instance_with(
+                                instance,
+                                class_name,
+                                class_def,
+                                compiled,
+                                &mut context,
+                                &mut instance_report,
+                                options,
+                            )?;
+                            Ok(instance_report)
+                        })
+                    })
+                    .collect()
+            })
+        };
+        // Keep other tasks of a multi-threaded runtime running meanwhile
+        let instance_reports = match tokio::runtime::Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread => {
+                tokio::task::block_in_place(validate_chunks)
+            }
+            _ => validate_chunks(),
+        };
+
+        for (index, (instance, instance_report)) in
+            instances.iter().zip(instance_reports).enumerate()
+        {
+            let instance_report = instance_report?;
+            let first_issue = report.issues.len();
+            report.stats.validators_executed += instance_report.stats.validators_executed;
+            timing::merge(
+                &mut report.stats.slowest_constraints,
+                instance_report.stats.slowest_constraints,
+            );
+            for issue in instance_report.issues {
+                report.add_issue(issue);
+            }
+            self.add_status_issues(&mut report, index, instance, class_name, &options);
+
+            if let Some(unique_validator) = self.registry.unique_key_validator() {
+                let mut context = ValidationContext::with_buffer_pools(
+                    Arc::clone(&self.schema),
+                    Arc::clone(&self.buffer_pools),
+                );
+                context.push_path(format!("[{index}]"));
+                for issue in
+                    unique_validator.validate_instance(instance, class_def, &schema, &mut context)
+                {
+                    report.add_issue(issue);
+                }
+            }
+            self.apply_rule_config(&mut report, first_issue, class_name, &options);
+            self.explain_issues(&mut report, first_issue, class_name, &options);
+
+            // Only errors left after the rule configuration stop the run
+            if options.fail_fast() && !report.valid {
+                break;
+            }
+        }
+
+        let end = self
+            .timestamp_service
+            .system_time()
+            .map_err(|e| LinkMLError::service(format!("Failed to get system time: {e}")))?;
+        let duration = end
+            .duration_since(start)
+            .map_err(|e| LinkMLError::service(format!("Time calculation error: {e}")))?;
+        report.stats.duration_ms = u128_to_u64_saturating(duration.as_millis());
+        timing::retain_slowest(&mut report.stats.slowest_constraints, SLOWEST_CONSTRAINTS);
+        Ok(report)
+    }
+
+    /// Rayon pool with `threads` threads (0 for one per CPU), built on first
+    /// use and reused while the thread count stays the same
+    fn thread_pool(&self, threads: usize) -> Result<Arc<rayon::ThreadPool>> {
+        let mut cached = self.thread_pool.lock();
+        if let Some((count, pool)) = cached.as_ref()
+            && *count == threads
+        {
+            return Ok(Arc::clone(pool));
+        }
+        let pool = Arc::new(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .map_err(|e| LinkMLError::service(format!("Failed to create thread pool: {e}")))?,
+        );
+        *cached = Some((threads, Arc::clone(&pool)));
+        Ok(pool)
+    }
+
+    /// Apply configured severity overrides to the issues reported since `start`
+    fn apply_rule_config(
+        &self,
+        report: &mut ValidationReport,
+        start: usize,
+        class_name: &str,
+        options: &ValidationOptions,
+    ) {
+        if let Some(rule_config) = &options.rule_config {
+            let schema_keys = [self.schema.id.clone(), self.schema.name.clone()];
+            rule_config.apply_from(report, start, &schema_keys, Some(class_name));
+        }
+    }
+
+    /// Report the uses of draft and deprecated elements by the instance at
+    /// `index` of a collection
+    fn add_status_issues(
+        &self,
+        report: &mut ValidationReport,
+        index: usize,
+        instance: &Value,
+        class_name: &str,
+        options: &ValidationOptions,
+    ) {
+        let path = format!("$[{index}]");
+        let policy = options.draft_elements();
+        for issue in status::status_issues(&self.schema, class_name, instance, &path, policy) {
+            report.add_issue(issue);
+        }
+    }
+
+    /// Attach explanations to the issues reported since `start`
+    fn explain_issues(
+        &self,
+        report: &mut ValidationReport,
+        start: usize,
+        class_name: &str,
+        options: &ValidationOptions,
+    ) {
+        if options.explain() {
+            for issue in report.issues.iter_mut().skip(start) {
+                issue.explanation = Some(explain::explain(&self.schema, class_name, issue));
+            }
+        }
+    }
+
     /// Apply defaults and prepare data for validation
     fn apply_defaults_and_prepare(
         &self,
         data: &Value,
         context: &ValidationContext,
         report: &mut ValidationReport,
+        options: &ValidationOptions,
     ) -> Value {
         let mut data = data.clone();
+        // Slots that count as absent get their defaults like missing ones
+        options.null_handling().strip_absent(&mut data);
         let default_applier = DefaultApplier::from_schema(&self.schema);
         if let Err(e) = default_applier.apply_defaults(&mut data, &self.schema) {
             report.add_issue(ValidationIssue::warning(
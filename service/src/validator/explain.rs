@@ -0,0 +1,577 @@
+//! Explanations of validation issues
+//!
+//! With the `explain` validation option every issue is traced back to the
+//! constraint that produced it: the slot at the issue's path, the metaslot
+//! that failed, its effective value after inheritance, and the `slot_usage`,
+//! attribute or slot definition that introduced that value. Resolution
+//! follows `InheritanceResolver::resolve_slot_usage`, so the source named is
+//! the one a schema author has to edit to change the outcome.
+
+use linkml_core::error_codes::ErrorCode;
+use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+use super::report::ValidationIssue;
+use crate::inheritance::InheritanceResolver;
+
+/// Why a validation issue was reported and how to fix it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Explanation {
+    /// Class whose instance holds the offending value
+    pub class: String,
+    /// Slot at the issue's path
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slot: Option<String>,
+    /// Metaslot of the slot that failed, such as `pattern` or `maximum_value`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<String>,
+    /// Value of the metaslot after inheritance and `slot_usage`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective: Option<Value>,
+    /// Definition that introduced the effective value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<ConstraintSource>,
+    /// Suggested change to the data or the schema
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl Explanation {
+    /// Lines describing the explanation, for verbose text output
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        let element = match &self.slot {
+            Some(slot) => format!("{}.{slot}", self.class),
+            None => self.class.clone(),
+        };
+        match (&self.constraint, &self.effective) {
+            (Some(constraint), Some(effective)) => {
+                lines.push(format!(
+                    "constraint: {constraint} = {effective} on {element}"
+                ));
+            }
+            (Some(constraint), None) => {
+                lines.push(format!("constraint: {constraint} on {element}"));
+            }
+            _ => lines.push(format!("element: {element}")),
+        }
+        if let Some(source) = &self.source {
+            lines.push(format!("source: {source}"));
+        }
+        if let Some(suggestion) = &self.suggestion {
+            lines.push(format!("fix: {suggestion}"));
+        }
+        lines
+    }
+}
+
+/// Kind of definition a constraint comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConstraintOrigin {
+    /// `slot_usage` of a class
+    SlotUsage,
+    /// Attribute of a class
+    Attribute,
+    /// Schema-level slot definition
+    Slot,
+    /// Slot the slot inherits from through `is_a` or mixins
+    SlotAncestor,
+}
+
+/// Definition that introduced a constraint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConstraintSource {
+    /// Kind of definition
+    pub origin: ConstraintOrigin,
+    /// Class of a `slot_usage` or attribute, or name of a slot
+    pub element: String,
+    /// Classes from the instance's class to the defining class, when the
+    /// constraint is inherited
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inherited_through: Vec<String>,
+    /// Whether the constraint is inherited through a mixin
+    #[serde(default)]
+    pub mixin: bool,
+}
+
+impl fmt::Display for ConstraintSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.origin {
+            ConstraintOrigin::SlotUsage => write!(f, "slot_usage of {}", self.element)?,
+            ConstraintOrigin::Attribute => write!(f, "attribute of {}", self.element)?,
+            ConstraintOrigin::Slot => write!(f, "slot {}", self.element)?,
+            ConstraintOrigin::SlotAncestor => write!(f, "parent slot {}", self.element)?,
+        }
+        if self.inherited_through.len() > 1 {
+            write!(
+                f,
+                " (inherited through {}{})",
+                self.inherited_through.join(" → "),
+                if self.mixin { ", via mixin" } else { "" }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Explain an issue reported for an instance of a class
+#[must_use]
+pub fn explain(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    issue: &ValidationIssue,
+) -> Explanation {
+    let code = issue.error_code();
+    let Some((owner, slot)) = slot_at_path(schema, class_name, &issue.path) else {
+        let suggestion = (code == ErrorCode::UnknownSlot)
+            .then(|| format!("Remove the value, or declare the slot on {class_name}"));
+        return Explanation {
+            class: class_name.to_string(),
+            slot: None,
+            constraint: None,
+            effective: None,
+            source: None,
+            suggestion,
+        };
+    };
+
+    let effective = metaslots(&slot);
+    let constraint = failed_metaslot(code, &issue.message, &effective);
+    let value = constraint.and_then(|name| effective.get(name).cloned());
+    let source = constraint
+        .zip(value.as_ref())
+        .and_then(|(name, value)| constraint_source(schema, &owner, &slot.name, name, value));
+    let suggestion = suggest(code, constraint, value.as_ref(), &slot, source.as_ref());
+    Explanation {
+        class: owner,
+        slot: Some(slot.name),
+        constraint: constraint.map(str::to_string),
+        effective: value,
+        source,
+        suggestion,
+    }
+}
+
+/// Slot at a `JSON` path of an instance of a class, with the class that
+/// holds it
+#[must_use]
+pub fn slot_at_path(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    path: &str,
+) -> Option<(String, SlotDefinition)> {
+    let resolver = InheritanceResolver::new(schema);
+    let mut owner = class_name.to_string();
+    let mut found: Option<(String, SlotDefinition)> = None;
+    let properties = path
+        .trim_start_matches('$')
+        .split('.')
+        .map(|segment| segment.split('[').next().unwrap_or_default())
+        .filter(|segment| !segment.is_empty());
+    for property in properties {
+        if let Some((_, slot)) = &found {
+            // Descend into the class the previous slot ranges over
+            owner = slot
+                .range
+                .clone()
+                .filter(|range| schema.classes.contains_key(range))?;
+        }
+        let slot = resolver.resolve_slot_usage(&owner, property).ok()?;
+        found = Some((owner.clone(), slot));
+    }
+    found
+}
+
+/// Metaslots an error code can stem from, in the order they are checked
+fn candidate_metaslots(code: ErrorCode) -> &'static [&'static str] {
+    match code {
+        ErrorCode::PatternMismatch => &["pattern", "structured_pattern"],
+        ErrorCode::TypeMismatch | ErrorCode::InvalidInstanceType => &["range"],
+        ErrorCode::RequiredMissing => &["required", "identifier"],
+        ErrorCode::RecommendedMissing => &["recommended"],
+        ErrorCode::RangeViolation => &["minimum_value", "maximum_value", "range"],
+        ErrorCode::InvalidEnumValue => &["equals_string_in", "range"],
+        ErrorCode::CardinalityViolation => &["multivalued"],
+        ErrorCode::UniqueKeyViolation => &["identifier", "key", "unique"],
+        ErrorCode::BooleanConstraintFailed => &["any_of", "all_of", "exactly_one_of", "none_of"],
+        ErrorCode::ExpressionFailed => &["equals_expression"],
+        ErrorCode::StringConstraintViolation => &["min_length", "max_length", "equals_string_in"],
+        ErrorCode::UnitMismatch => &["unit"],
+        _ => &[],
+    }
+}
+
+/// Words of an issue message identifying one of several candidate metaslots
+fn message_hints(metaslot: &str) -> &'static [&'static str] {
+    match metaslot {
+        "minimum_value" => &["minimum", "less than", "below"],
+        "maximum_value" => &["maximum", "exceeds", "greater than", "above"],
+        "min_length" => &["min", "short", "at least"],
+        "max_length" => &["max", "long", "at most"],
+        _ => &[],
+    }
+}
+
+/// Metaslot the issue reports on: the candidate the message mentions, or
+/// the first candidate the slot sets
+fn failed_metaslot(
+    code: ErrorCode,
+    message: &str,
+    effective: &serde_json::Map<String, Value>,
+) -> Option<&'static str> {
+    let message = message.to_lowercase();
+    let set: Vec<&'static str> = candidate_metaslots(code)
+        .iter()
+        .copied()
+        .filter(|name| effective.get(*name).is_some_and(|value| !value.is_null()))
+        .collect();
+    set.iter()
+        .copied()
+        .find(|name| {
+            message_hints(name)
+                .iter()
+                .any(|hint| message.contains(hint))
+        })
+        .or_else(|| set.first().copied())
+}
+
+/// Metaslots a slot definition sets
+fn metaslots(slot: &SlotDefinition) -> serde_json::Map<String, Value> {
+    match serde_json::to_value(slot) {
+        Ok(Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    }
+}
+
+/// Whether a definition sets a metaslot to a value
+fn sets(definition: &SlotDefinition, metaslot: &str, value: &Value) -> bool {
+    metaslots(definition).get(metaslot) == Some(value)
+}
+
+/// Definition that introduced the effective value of a metaslot, searched
+/// in the precedence `resolve_slot_usage` applies: `slot_usage` of the
+/// nearest class, the nearest attribute, the schema slot, then the slot's
+/// ancestors. Tightened bounds are attributed to the definition whose bound
+/// took effect.
+fn constraint_source(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    slot_name: &str,
+    metaslot: &str,
+    value: &Value,
+) -> Option<ConstraintSource> {
+    let resolver = InheritanceResolver::new(schema);
+    let precedence = resolver.class_precedence(class_name);
+    let classes: Vec<(&String, &ClassDefinition)> = precedence
+        .iter()
+        .filter_map(|name| schema.classes.get(name).map(|class| (name, class)))
+        .collect();
+    let inherited = |origin: ConstraintOrigin, class: &String| {
+        let (inherited_through, mixin) = class_path(schema, class_name, class);
+        ConstraintSource {
+            origin,
+            element: class.clone(),
+            inherited_through,
+            mixin,
+        }
+    };
+
+    if let Some((name, _)) = classes.iter().find(|(_, class)| {
+        class
+            .slot_usage
+            .get(slot_name)
+            .is_some_and(|usage| sets(usage, metaslot, value))
+    }) {
+        return Some(inherited(ConstraintOrigin::SlotUsage, name));
+    }
+    if let Some((name, _)) = classes.iter().find(|(_, class)| {
+        class
+            .attributes
+            .get(slot_name)
+            .is_some_and(|attribute| sets(attribute, metaslot, value))
+    }) {
+        return Some(inherited(ConstraintOrigin::Attribute, name));
+    }
+
+    let global = schema.slots.get(slot_name)?;
+    if sets(global, metaslot, value) {
+        return Some(ConstraintSource {
+            origin: ConstraintOrigin::Slot,
+            element: slot_name.to_string(),
+            inherited_through: Vec::new(),
+            mixin: false,
+        });
+    }
+    let mut visited = HashSet::new();
+    let mut parents: VecDeque<&String> = global.is_a.iter().chain(&global.mixins).collect();
+    while let Some(parent_name) = parents.pop_front() {
+        if !visited.insert(parent_name) {
+            continue;
+        }
+        let Some(parent) = schema.slots.get(parent_name) else {
+            continue;
+        };
+        if sets(parent, metaslot, value) {
+            return Some(ConstraintSource {
+                origin: ConstraintOrigin::SlotAncestor,
+                element: parent_name.clone(),
+                inherited_through: Vec::new(),
+                mixin: false,
+            });
+        }
+        parents.extend(parent.is_a.iter().chain(&parent.mixins));
+    }
+    None
+}
+
+/// Shortest chain of classes from a class to one of its ancestors, and
+/// whether it passes through a mixin
+fn class_path(schema: &SchemaDefinition, from: &str, to: &str) -> (Vec<String>, bool) {
+    let mut reached: HashMap<&str, (&str, bool)> = HashMap::new();
+    let mut queue = VecDeque::from([from]);
+    while let Some(current) = queue.pop_front() {
+        if current == to {
+            break;
+        }
+        let Some(class) = schema.classes.get(current) else {
+            continue;
+        };
+        let parents = class
+            .is_a
+            .iter()
+            .map(|parent| (parent, false))
+            .chain(class.mixins.iter().map(|mixin| (mixin, true)));
+        for (parent, mixin) in parents {
+            if parent != from && !reached.contains_key(parent.as_str()) {
+                reached.insert(parent, (current, mixin));
+                queue.push_back(parent);
+            }
+        }
+    }
+
+    let mut path = vec![to.to_string()];
+    let mut through_mixin = false;
+    let mut current = to;
+    while current != from {
+        let Some(&(previous, mixin)) = reached.get(current) else {
+            return (Vec::new(), false);
+        };
+        through_mixin |= mixin;
+        path.push(previous.to_string());
+        current = previous;
+    }
+    path.reverse();
+    (path, through_mixin)
+}
+
+/// Suggested fix for a failed metaslot
+fn suggest(
+    code: ErrorCode,
+    metaslot: Option<&str>,
+    value: Option<&Value>,
+    slot: &SlotDefinition,
+    source: Option<&ConstraintSource>,
+) -> Option<String> {
+    let name = &slot.name;
+    let text = |value: &Value| match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    let change = match metaslot? {
+        "pattern" => format!("Use a value matching {}", text(value?)),
+        "structured_pattern" => "Use a value matching the structured pattern".to_string(),
+        "range" if code == ErrorCode::InvalidEnumValue => {
+            format!("Use one of the permissible values of {}", text(value?))
+        }
+        "range" => format!("Use a value of range {}", text(value?)),
+        "required" | "identifier" if code == ErrorCode::RequiredMissing => {
+            format!("Add a value for {name}")
+        }
+        "recommended" => format!("Add a value for {name}"),
+        "minimum_value" => format!("Use a value of at least {}", text(value?)),
+        "maximum_value" => format!("Use a value of at most {}", text(value?)),
+        "min_length" => format!("Use a value of at least {} characters", text(value?)),
+        "max_length" => format!("Use a value of at most {} characters", text(value?)),
+        "equals_string_in" => {
+            let allowed: Vec<String> = value?.as_array()?.iter().map(text).collect();
+            format!("Use one of {}", allowed.join(", "))
+        }
+        "multivalued" if value == Some(&Value::Bool(true)) => {
+            format!("Give a list of values for {name}")
+        }
+        "multivalued" => format!("Give a single value for {name}"),
+        "identifier" | "key" | "unique" => {
+            format!("Use a value of {name} no other instance uses")
+        }
+        "equals_expression" => format!("Use a value equal to {}", text(value?)),
+        "unit" => format!("Give {name} in the unit the slot declares"),
+        other => format!("Use a value satisfying the {other} expressions of {name}"),
+    };
+    Some(match (metaslot?, source) {
+        // A missing recommended value is a warning the data may keep
+        ("recommended", _) | (_, None) => change,
+        (metaslot, Some(source)) => format!("{change}, or relax {metaslot} in {source}"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r"
+id: https://example.org/people
+name: people
+classes:
+  HasAge:
+    mixin: true
+    slot_usage:
+      age:
+        maximum_value: 130
+  Named:
+    abstract: true
+    slots: [name]
+  Person:
+    is_a: Named
+    mixins: [HasAge]
+    slots: [age, status, address]
+    slot_usage:
+      name:
+        required: true
+  Address:
+    attributes:
+      postcode:
+        range: string
+        pattern: '^[0-9]{5}$'
+slots:
+  label:
+    pattern: '^[A-Z]'
+  name:
+    is_a: label
+    range: string
+  age:
+    range: integer
+    minimum_value: 0
+    maximum_value: 150
+  status:
+    range: string
+    equals_string_in: [active, retired]
+  address:
+    range: Address
+";
+
+    fn schema() -> SchemaDefinition {
+        serde_yaml::from_str(SCHEMA).expect("schema parses")
+    }
+
+    #[test]
+    fn test_tightened_bound_from_mixin_usage() {
+        let issue =
+            ValidationIssue::error("Value 140 exceeds maximum 130", "$.age", "range_validator");
+        let explanation = explain(&schema(), "Person", &issue);
+
+        assert_eq!(explanation.class, "Person");
+        assert_eq!(explanation.constraint.as_deref(), Some("maximum_value"));
+        assert_eq!(explanation.effective, Some(serde_json::json!(130)));
+        let source = explanation.source.as_ref().expect("source found");
+        assert_eq!(source.origin, ConstraintOrigin::SlotUsage);
+        assert_eq!(source.element, "HasAge");
+        assert!(source.mixin);
+        assert_eq!(
+            explanation.lines(),
+            [
+                "constraint: maximum_value = 130 on Person.age",
+                "source: slot_usage of HasAge (inherited through Person → HasAge, via mixin)",
+                "fix: Use a value of at most 130, or relax maximum_value in slot_usage of HasAge \
+                 (inherited through Person → HasAge, via mixin)",
+            ]
+        );
+
+        // The lower bound is the global slot's
+        let issue = ValidationIssue::error(
+            "Value -1 is less than minimum 0",
+            "$.age",
+            "range_validator",
+        );
+        let explanation = explain(&schema(), "Person", &issue);
+        assert_eq!(explanation.constraint.as_deref(), Some("minimum_value"));
+        assert_eq!(
+            explanation
+                .source
+                .map(|source| source.to_string())
+                .as_deref(),
+            Some("slot age")
+        );
+    }
+
+    #[test]
+    fn test_sources_across_definitions() {
+        let schema = schema();
+
+        let issue = ValidationIssue::error(
+            "Required field 'name' is missing",
+            "$.name",
+            "required_validator",
+        );
+        let explanation = explain(&schema, "Person", &issue);
+        assert_eq!(explanation.constraint.as_deref(), Some("required"));
+        assert_eq!(
+            explanation.suggestion.as_deref(),
+            Some("Add a value for name, or relax required in slot_usage of Person")
+        );
+
+        let issue = ValidationIssue::error(
+            "Value 'ada' does not match pattern",
+            "$.name",
+            "pattern_validator",
+        );
+        let source = explain(&schema, "Person", &issue)
+            .source
+            .expect("source found");
+        assert_eq!(source.origin, ConstraintOrigin::SlotAncestor);
+        assert_eq!(source.element, "label");
+
+        let issue = ValidationIssue::error(
+            "Value '1234' does not match pattern",
+            "$.address.postcode",
+            "pattern_validator",
+        );
+        let explanation = explain(&schema, "Person", &issue);
+        assert_eq!(explanation.class, "Address");
+        assert_eq!(
+            explanation
+                .source
+                .map(|source| source.to_string())
+                .as_deref(),
+            Some("attribute of Address")
+        );
+
+        let issue = ValidationIssue::error(
+            "Value 'gone' is not allowed",
+            "$.status",
+            "string_validator",
+        )
+        .with_error_code(ErrorCode::StringConstraintViolation);
+        assert_eq!(
+            explain(&schema, "Person", &issue).suggestion.as_deref(),
+            Some("Use one of active, retired, or relax equals_string_in in slot status")
+        );
+    }
+
+    #[test]
+    fn test_issue_without_slot() {
+        let issue =
+            ValidationIssue::error("Unknown slot 'nickname'", "$.nickname", "schema_validator")
+                .with_error_code(ErrorCode::UnknownSlot);
+        let explanation = explain(&schema(), "Person", &issue);
+        assert_eq!(explanation.slot, None);
+        assert_eq!(
+            explanation.suggestion.as_deref(),
+            Some("Remove the value, or declare the slot on Person")
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Fix-it patches for auto-fixable data errors
+//!
+//! Some issues have exactly one plausible repair: a number written as a
+//! string, a value padded with whitespace, an enum value in the wrong case.
+//! With the `fixes` validation option such issues carry `JSON` Patch
+//! operations (RFC 6902) that repair the instance: a `test` of the current
+//! value followed by a `replace` with the corrected one. Coercions are the
+//! ones the [`Normalizer`] applies; enum values are fixed when exactly one
+//! permissible value matches ignoring case and surrounding whitespace.
+//!
+//! [`apply_fixes`] applies the patches of a report to the validated instance,
+//! as `linkml validate --apply-fixes` does before writing the data back.
+
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::error_codes::ErrorCode;
+use linkml_core::types::SchemaDefinition;
+use serde_json::Value;
+
+use super::explain::slot_at_path;
+use super::normalizer::Normalizer;
+use super::report::{Severity, ValidationReport};
+use crate::schema::patch::PatchOperation;
+
+/// Attach fix-it patches to the issues reported since `start` for `data`,
+/// an instance of `class_name`
+pub fn attach_fixes(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    data: &Value,
+    report: &mut ValidationReport,
+    start: usize,
+) {
+    let mut normalized = data.clone();
+    // Changes reported by the normalizer are not needed, only its result
+    Normalizer::new(schema).normalize(&mut normalized, class_name);
+    for issue in report.issues.iter_mut().skip(start) {
+        if issue.severity == Severity::Info || !issue.fix.is_empty() {
+            continue;
+        }
+        let Some(pointer) = json_pointer(&issue.path) else {
+            continue;
+        };
+        let Some(current) = data.pointer(&pointer) else {
+            continue;
+        };
+        let fixed = if issue.error_code() == ErrorCode::InvalidEnumValue {
+            enum_fix(schema, class_name, &issue.path, current)
+        } else {
+            normalized
+                .pointer(&pointer)
+                .filter(|fixed| *fixed != current)
+                .cloned()
+        };
+        if let Some(fixed) = fixed {
+            issue.fix = vec![
+                PatchOperation::Test {
+                    path: pointer.clone(),
+                    value: current.clone(),
+                },
+                PatchOperation::Replace {
+                    path: pointer,
+                    value: fixed,
+                },
+            ];
+        }
+    }
+}
+
+/// Permissible value an invalid enum value is meant to be, if exactly one
+/// matches it ignoring case and surrounding whitespace
+fn enum_fix(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    path: &str,
+    current: &Value,
+) -> Option<Value> {
+    let text = current.as_str()?.trim().to_lowercase();
+    let (_, slot) = slot_at_path(schema, class_name, path)?;
+    let values = schema.enum_values(slot.range.as_deref()?, false)?;
+    let mut matches = values
+        .into_iter()
+        .filter(|value| value.to_lowercase() == text);
+    let fixed = matches.next()?;
+    matches.next().is_none().then_some(Value::String(fixed))
+}
+
+/// `JSON` Pointer of a `JSON` path such as `$.items[2].name`
+#[must_use]
+pub fn json_pointer(path: &str) -> Option<String> {
+    let mut rest = path.strip_prefix('$')?;
+    let mut pointer = String::new();
+    while !rest.is_empty() {
+        let segment;
+        if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']')?;
+            segment = &after[..end];
+            segment.parse::<usize>().ok()?;
+            rest = &after[end + 1..];
+        } else {
+            let after = rest.strip_prefix('.')?;
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            segment = &after[..end];
+            rest = &after[end..];
+        }
+        pointer.push('/');
+        pointer.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+    }
+    Some(pointer)
+}
+
+/// Apply the fix-it patches of a report to the instance it validated
+///
+/// Patches whose `test` no longer holds, because an earlier fix changed the
+/// same value, are skipped. Returns the number of issues fixed.
+///
+/// # Errors
+///
+/// Returns an error if a patch targets a location missing from `data`.
+pub fn apply_fixes(data: &mut Value, report: &ValidationReport) -> Result<usize> {
+    let mut fixed = 0;
+    for issue in report.issues.iter().filter(|issue| !issue.fix.is_empty()) {
+        let holds = issue.fix.iter().all(|operation| match operation {
+            PatchOperation::Test { path, value } => data.pointer(path) == Some(value),
+            _ => true,
+        });
+        if !holds {
+            continue;
+        }
+        for operation in &issue.fix {
+            if let PatchOperation::Replace { path, value } = operation {
+                let target = data.pointer_mut(path).ok_or_else(|| {
+                    LinkMLError::data_validation(format!("Fix targets missing value at {path}"))
+                })?;
+                *target = value.clone();
+            }
+        }
+        fixed += 1;
+    }
+    Ok(fixed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::report::ValidationIssue;
+    use serde_json::json;
+
+    const SCHEMA: &str = r"
+id: https://example.org/people
+name: people
+classes:
+  Person:
+    attributes:
+      name:
+        range: string
+      age:
+        range: integer
+      status:
+        range: Status
+      tags:
+        range: string
+        multivalued: true
+enums:
+  Status:
+    permissible_values:
+      ACTIVE: {}
+      retired: {}
+";
+
+    fn issue(message: &str, path: &str, code: ErrorCode) -> ValidationIssue {
+        ValidationIssue::error(message, path, "test").with_error_code(code)
+    }
+
+    #[test]
+    fn test_json_pointer() {
+        assert_eq!(json_pointer("$").as_deref(), Some(""));
+        assert_eq!(json_pointer("$.tags[2]").as_deref(), Some("/tags/2"));
+        assert_eq!(json_pointer("$.a/b.c~d").as_deref(), Some("/a~1b/c~0d"));
+        assert_eq!(json_pointer("name"), None);
+        assert_eq!(json_pointer("$.tags[x]"), None);
+    }
+
+    #[test]
+    fn test_fixes_are_attached_and_applied() -> std::result::Result<(), Box<dyn std::error::Error>>
+    {
+        let schema: SchemaDefinition = serde_yaml::from_str(SCHEMA)?;
+        let mut data = json!({
+            "name": "Ada",
+            "age": "36",
+            "status": " active",
+            "tags": ["math ", "poetry"]
+        });
+        let mut report = ValidationReport::new("people");
+        report.add_issue(issue("Expected integer", "$.age", ErrorCode::TypeMismatch));
+        report.add_issue(issue(
+            "Not permissible",
+            "$.status",
+            ErrorCode::InvalidEnumValue,
+        ));
+        report.add_issue(issue("Bad tag", "$.tags[0]", ErrorCode::PatternMismatch));
+        report.add_issue(issue(
+            "Too short",
+            "$.name",
+            ErrorCode::StringConstraintViolation,
+        ));
+
+        attach_fixes(&schema, "Person", &data, &mut report, 0);
+        let fixes: Vec<Value> = report
+            .issues
+            .iter()
+            .map(|issue| serde_json::to_value(&issue.fix))
+            .collect::<std::result::Result<_, _>>()?;
+        assert_eq!(
+            fixes[0],
+            json!([
+                {"op": "test", "path": "/age", "value": "36"},
+                {"op": "replace", "path": "/age", "value": 36}
+            ])
+        );
+        assert_eq!(
+            fixes[1][1],
+            json!({"op": "replace", "path": "/status", "value": "ACTIVE"})
+        );
+        assert_eq!(fixes[2][1]["value"], "math");
+        assert_eq!(fixes[3], json!([]));
+
+        assert_eq!(apply_fixes(&mut data, &report)?, 3);
+        assert_eq!(
+            data,
+            json!({"name": "Ada", "age": 36, "status": "ACTIVE", "tags": ["math", "poetry"]})
+        );
+        // Applying again finds the values already changed
+        assert_eq!(apply_fixes(&mut data, &report)?, 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,423 @@
+//! `JSON` Schema validation errors in `LinkML` terms
+//!
+//! `JSON` Schema generated from a `LinkML` schema lets clients validate forms
+//! before submitting them, but client-side validators such as Ajv report
+//! errors by `JSON` Pointer (`/addresses/1/zip`) and schema location
+//! (`#/definitions/Address/properties/zip/pattern`), while the validation
+//! engine reports slot paths (`$.addresses[1].zip`) and error codes.
+//!
+//! A [`JsonSchemaMapping`], generated next to the `JSON` Schema by the
+//! `json-schema-mapping` generator, records the class and slot each property
+//! of the `JSON` Schema stands for. [`JsonSchemaMapping::translate`] turns
+//! client-side errors into [`ValidationIssue`]s with the paths, messages and
+//! error codes the engine would report for the same data, so clients and
+//! servers can present failures the same way.
+
+use super::json_path::JsonPath;
+use super::report::{ValidationIssue, ValidationReport};
+use crate::generator::JsonSchemaGenerator;
+use linkml_core::error::Result;
+use linkml_core::error_codes::ErrorCode;
+use linkml_core::types::SchemaDefinition;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+
+/// Validator name of translated issues
+const VALIDATOR: &str = "json_schema";
+
+/// Slot behind a property of a generated `JSON` Schema definition
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PropertyMapping {
+    /// Slot name
+    pub slot: String,
+    /// Slot range
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<String>,
+    /// Class of the property's objects, for class ranges
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class: Option<String>,
+    /// Whether the property holds a list
+    #[serde(default)]
+    pub multivalued: bool,
+    /// Whether the slot is required
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// Properties of the `JSON` Schema definition of a class
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClassMapping {
+    /// Slots by `JSON` property name
+    pub properties: BTreeMap<String, PropertyMapping>,
+}
+
+/// Mapping from a generated `JSON` Schema back to the `LinkML` schema
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonSchemaMapping {
+    /// Id of the `LinkML` schema
+    pub schema_id: String,
+    /// Classes documents are validated as
+    #[serde(default)]
+    pub roots: Vec<String>,
+    /// Class definitions by name
+    pub classes: BTreeMap<String, ClassMapping>,
+}
+
+/// An error reported by a `JSON` Schema validator, in Ajv's output format
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSchemaError {
+    /// `JSON` Pointer to the failing value, empty for the document
+    #[serde(default)]
+    pub instance_path: String,
+    /// Location of the failing keyword in the `JSON` Schema
+    #[serde(default)]
+    pub schema_path: String,
+    /// Failing keyword, e.g. `required` or `pattern`
+    pub keyword: String,
+    /// Keyword-specific parameters, e.g. `missingProperty`
+    #[serde(default)]
+    pub params: Value,
+    /// Validator message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Where an instance path leads in the schema
+struct Location {
+    path: JsonPath,
+    /// Class of the value at the path
+    class: Option<String>,
+    /// Slot holding the value and the class declaring it
+    slot: Option<(Option<String>, String)>,
+    /// Whether every segment was found in the mapping
+    resolved: bool,
+}
+
+impl JsonSchemaMapping {
+    /// Mapping of the `JSON` Schema the `json-schema` generator produces for
+    /// `schema`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slots of a class cannot be collected
+    pub fn from_schema(schema: &SchemaDefinition) -> Result<Self> {
+        let mut classes = BTreeMap::new();
+        for (class_name, class) in &schema.classes {
+            let mut properties = BTreeMap::new();
+            for slot_name in JsonSchemaGenerator::collect_all_slots(class, schema)? {
+                let Some(slot) = schema.slots.get(&slot_name) else {
+                    continue;
+                };
+                properties.insert(
+                    slot_name.clone(),
+                    PropertyMapping {
+                        slot: slot_name,
+                        range: slot.range.clone(),
+                        class: slot
+                            .range
+                            .clone()
+                            .filter(|range| schema.classes.contains_key(range)),
+                        multivalued: slot.multivalued == Some(true),
+                        required: slot.required == Some(true),
+                    },
+                );
+            }
+            classes.insert(class_name.clone(), ClassMapping { properties });
+        }
+        Ok(Self {
+            schema_id: schema.id.clone(),
+            roots: JsonSchemaGenerator::root_classes(schema),
+            classes,
+        })
+    }
+
+    /// Translate a `JSON` Schema error into a validation issue
+    #[must_use]
+    pub fn translate(&self, error: &JsonSchemaError) -> ValidationIssue {
+        let mut location = self.locate(error);
+        let param = |name: &str| error.params.get(name).and_then(Value::as_str);
+        let class_label = |preposition: &str| {
+            location.class.as_deref().map_or_else(String::new, |class| {
+                format!(" {preposition} class '{class}'")
+            })
+        };
+        let (of_class, for_class) = (class_label("of"), class_label("for"));
+
+        let (message, code) = match (error.keyword.as_str(), param("missingProperty")) {
+            ("required", Some(missing)) => {
+                location.path.property(missing);
+                location.slot = Some((location.class.clone(), missing.to_string()));
+                (
+                    format!("Required slot '{missing}'{of_class} is missing"),
+                    ErrorCode::RequiredMissing,
+                )
+            }
+            _ if error.keyword == "additionalProperties" => {
+                let unknown = param("additionalProperty").unwrap_or_default();
+                location.path.property(unknown);
+                location.slot = Some((location.class.clone(), unknown.to_string()));
+                (
+                    format!("Slot '{unknown}' is not defined{for_class}"),
+                    ErrorCode::UnknownSlot,
+                )
+            }
+            (keyword, _) => {
+                let subject = match &location.slot {
+                    Some((Some(owner), slot)) => format!("Slot '{slot}' of class '{owner}'"),
+                    Some((None, slot)) => format!("Slot '{slot}'"),
+                    None => match &location.class {
+                        Some(class) => format!("Instance of class '{class}'"),
+                        None => "Instance".to_string(),
+                    },
+                };
+                let detail = error.message.as_deref().unwrap_or("is invalid");
+                (format!("{subject} {detail}"), keyword_code(keyword))
+            }
+        };
+
+        let mut issue = ValidationIssue::error(message, location.path.to_string(), VALIDATOR)
+            .with_error_code(code)
+            .with_context("keyword", json!(error.keyword))
+            .with_context("instance_path", json!(error.instance_path))
+            .with_context("schema_path", json!(error.schema_path));
+        if let Some(class) = location
+            .slot
+            .as_ref()
+            .and_then(|(owner, _)| owner.clone())
+            .or(location.class)
+        {
+            issue = issue.with_context("class", json!(class));
+        }
+        if let Some((_, slot)) = location.slot {
+            issue = issue.with_context("slot", json!(slot));
+        }
+        issue
+    }
+
+    /// Translate the errors of validating one document into a report
+    #[must_use]
+    pub fn translate_all(&self, errors: &[JsonSchemaError]) -> ValidationReport {
+        let mut report = ValidationReport::new(&self.schema_id);
+        if let [root] = self.roots.as_slice() {
+            report.target_class = Some(root.clone());
+        }
+        for error in errors {
+            report.add_issue(self.translate(error));
+        }
+        report
+    }
+
+    /// Follow the instance path of an error from the root class
+    ///
+    /// With several roots, the root whose path resolves to the definition
+    /// named in the schema path wins, then any root resolving the whole path.
+    fn locate(&self, error: &JsonSchemaError) -> Location {
+        let definition = error
+            .schema_path
+            .strip_prefix("#/definitions/")
+            .and_then(|rest| rest.split('/').next());
+        let candidates: Vec<Location> = if self.roots.is_empty() {
+            vec![self.walk(None, &error.instance_path)]
+        } else {
+            self.roots
+                .iter()
+                .map(|root| self.walk(Some(root), &error.instance_path))
+                .collect()
+        };
+        let owner = |location: &Location| {
+            location
+                .slot
+                .as_ref()
+                .and_then(|(owner, _)| owner.clone())
+                .or_else(|| location.class.clone())
+        };
+        let best = candidates
+            .iter()
+            .position(|location| {
+                location.resolved
+                    && (definition.is_none()
+                        || owner(location).as_deref() == definition
+                        || location.class.as_deref() == definition)
+            })
+            .or_else(|| candidates.iter().position(|location| location.resolved))
+            .unwrap_or(0);
+        candidates
+            .into_iter()
+            .nth(best)
+            .unwrap_or_else(|| self.walk(None, &error.instance_path))
+    }
+
+    fn walk(&self, root: Option<&str>, pointer: &str) -> Location {
+        let mut location = Location {
+            path: JsonPath::root(),
+            class: root.map(str::to_string),
+            slot: None,
+            resolved: true,
+        };
+        let mut in_list = false;
+        for segment in pointer.split('/').skip(1) {
+            let segment = segment.replace("~1", "/").replace("~0", "~");
+            if in_list && let Ok(index) = segment.parse::<usize>() {
+                location.path.index(index);
+                in_list = false;
+                continue;
+            }
+            location.path.property(&segment);
+            let owner = location.class.take();
+            let property = owner
+                .as_deref()
+                .and_then(|class| self.classes.get(class))
+                .and_then(|class| class.properties.get(&segment));
+            if let Some(property) = property {
+                location.slot = Some((owner, property.slot.clone()));
+                location.class.clone_from(&property.class);
+                in_list = property.multivalued;
+            } else {
+                location.slot = Some((owner, segment));
+                location.resolved = false;
+                in_list = false;
+            }
+        }
+        location
+    }
+}
+
+/// Error code of a failing `JSON` Schema keyword
+fn keyword_code(keyword: &str) -> ErrorCode {
+    match keyword {
+        "required" => ErrorCode::RequiredMissing,
+        "type" => ErrorCode::TypeMismatch,
+        "pattern" | "format" => ErrorCode::PatternMismatch,
+        "minimum" | "maximum" | "exclusiveMinimum" | "exclusiveMaximum" => {
+            ErrorCode::RangeViolation
+        }
+        "enum" | "const" => ErrorCode::InvalidEnumValue,
+        "minItems" | "maxItems" | "uniqueItems" => ErrorCode::CardinalityViolation,
+        "minLength" | "maxLength" => ErrorCode::StringConstraintViolation,
+        "additionalProperties" => ErrorCode::UnknownSlot,
+        "oneOf" | "anyOf" | "allOf" | "not" => ErrorCode::BooleanConstraintFailed,
+        _ => ErrorCode::DataValidation,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{SchemaParser, YamlParser};
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn test_translate_json_schema_errors() {
+        let schema = YamlParser::new()
+            .parse_str(
+                r"
+id: https://example.org/people
+name: people
+classes:
+  Person:
+    tree_root: true
+    slots: [name, age, addresses]
+  Address:
+    is_a: Place
+    slots: [zip]
+  Place:
+    abstract: true
+slots:
+  name:
+    required: true
+  age:
+    range: integer
+    minimum_value: 0
+  addresses:
+    range: Address
+    multivalued: true
+  zip:
+    pattern: '^[0-9]{5}$'
+",
+            )
+            .expect("schema");
+        let mapping = JsonSchemaMapping::from_schema(&schema).expect("mapping");
+        assert_eq!(mapping.roots, ["Person"]);
+        assert_eq!(
+            mapping.classes["Person"].properties["addresses"]
+                .class
+                .as_deref(),
+            Some("Address")
+        );
+
+        let errors: Vec<JsonSchemaError> = serde_json::from_value(json!([
+            {
+                "instancePath": "",
+                "schemaPath": "#/definitions/Person/required",
+                "keyword": "required",
+                "params": {"missingProperty": "name"},
+                "message": "must have required property 'name'"
+            },
+            {
+                "instancePath": "/age",
+                "schemaPath": "#/definitions/Person/properties/age/minimum",
+                "keyword": "minimum",
+                "params": {"comparison": ">=", "limit": 0},
+                "message": "must be >= 0"
+            },
+            {
+                "instancePath": "/addresses/1/zip",
+                "schemaPath": "#/definitions/Address/properties/zip/pattern",
+                "keyword": "pattern",
+                "params": {"pattern": "^[0-9]{5}$"},
+                "message": "must match pattern \"^[0-9]{5}$\""
+            },
+            {
+                "instancePath": "/addresses/0",
+                "schemaPath": "#/definitions/Address/additionalProperties",
+                "keyword": "additionalProperties",
+                "params": {"additionalProperty": "nickname"}
+            }
+        ]))
+        .expect("errors");
+        let report = mapping.translate_all(&errors);
+        assert!(!report.valid);
+        assert_eq!(report.target_class.as_deref(), Some("Person"));
+
+        let issues: Vec<_> = report
+            .issues
+            .iter()
+            .map(|issue| {
+                (
+                    issue.path.as_str(),
+                    issue.message.as_str(),
+                    issue.error_code(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                (
+                    "$.name",
+                    "Required slot 'name' of class 'Person' is missing",
+                    ErrorCode::RequiredMissing
+                ),
+                (
+                    "$.age",
+                    "Slot 'age' of class 'Person' must be >= 0",
+                    ErrorCode::RangeViolation
+                ),
+                (
+                    "$.addresses[1].zip",
+                    "Slot 'zip' of class 'Address' must match pattern \"^[0-9]{5}$\"",
+                    ErrorCode::PatternMismatch
+                ),
+                (
+                    "$.addresses[0].nickname",
+                    "Slot 'nickname' is not defined for class 'Address'",
+                    ErrorCode::UnknownSlot
+                ),
+            ]
+        );
+        assert_eq!(report.issues[2].context["slot"], "zip");
+        assert_eq!(report.issues[2].context["class"], "Address");
+    }
+}
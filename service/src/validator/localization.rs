@@ -0,0 +1,565 @@
+//! Localized validation reports
+//!
+//! Validation messages are written in English. To serve reports in other
+//! languages, extract the message templates into a translation bundle, have
+//! it translated, and localize reports with the translated bundle:
+//!
+//! ```text
+//! linkml messages --schema schema.yaml --format fluent --output en.ftl
+//! linkml validate --schema schema.yaml --messages de.ftl data.yaml
+//! ```
+//!
+//! A bundle has one message per stable error code (`lml1001`, ...) and one
+//! per schema-provided message, such as rule descriptions
+//! (`rule-person-0`). Bundles are written and read as Fluent (`.ftl`) or
+//! gettext (`.po`) files; in both, templates refer to issue data with Fluent
+//! placeables:
+//!
+//! - `{ $path }`, `{ $validator }` and `{ $code }` of the issue
+//! - `{ $detail }`, the original message with schema messages translated
+//! - any string or number in the issue context, e.g. `{ $expected }`
+//!
+//! A localized issue keeps its English message in the `source_message`
+//! context entry. Issues whose code has no translation keep their message,
+//! with translated schema messages substituted.
+
+use super::report::{ValidationIssue, ValidationReport};
+use indexmap::IndexMap;
+use linkml_core::error_codes::ErrorCode;
+use linkml_core::prelude::*;
+use std::fmt::Write;
+use std::path::Path;
+
+/// File format of a translation bundle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleFormat {
+    /// Fluent (`.ftl`)
+    Fluent,
+    /// gettext (`.po`, `.pot`)
+    Gettext,
+}
+
+impl BundleFormat {
+    /// Format of a bundle file, from its extension
+    #[must_use]
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "ftl" => Some(Self::Fluent),
+            "po" | "pot" => Some(Self::Gettext),
+            _ => None,
+        }
+    }
+}
+
+/// Message templates keyed by message id
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageBundle {
+    /// Language of the messages, e.g. `de` (from the gettext header)
+    pub locale: Option<String>,
+    messages: IndexMap<String, String>,
+}
+
+impl MessageBundle {
+    /// English templates of all error codes and of the messages provided by
+    /// `schema`
+    #[must_use]
+    pub fn extract(schema: Option<&SchemaDefinition>) -> Self {
+        let mut messages = IndexMap::new();
+        for &code in ErrorCode::ALL {
+            messages.insert(
+                code.anchor(),
+                format!("{} at {{ $path }}: {{ $detail }}", escape(code.summary())),
+            );
+        }
+        for (id, message) in schema.map(schema_messages).unwrap_or_default() {
+            messages.insert(id, escape(&message));
+        }
+        Self {
+            locale: Some("en".to_string()),
+            messages,
+        }
+    }
+
+    /// Template of a message
+    #[must_use]
+    pub fn message(&self, id: &str) -> Option<&str> {
+        self.messages.get(id).map(String::as_str)
+    }
+
+    /// Set the template of a message
+    pub fn insert(&mut self, id: impl Into<String>, template: impl Into<String>) {
+        self.messages.insert(id.into(), template.into());
+    }
+
+    /// Message ids and templates, in bundle order
+    pub fn messages(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.messages
+            .iter()
+            .map(|(id, template)| (id.as_str(), template.as_str()))
+    }
+
+    /// Render the bundle in a file format
+    #[must_use]
+    pub fn render(&self, format: BundleFormat) -> String {
+        match format {
+            BundleFormat::Fluent => self.to_fluent(),
+            BundleFormat::Gettext => self.to_gettext(),
+        }
+    }
+
+    /// Render the bundle as a Fluent resource
+    #[must_use]
+    pub fn to_fluent(&self) -> String {
+        let mut out = String::from("# LinkML validation messages\n");
+        if let Some(locale) = &self.locale {
+            // Writing to a String cannot fail
+            let _ = writeln!(out, "# Locale: {locale}");
+        }
+        for (id, template) in &self.messages {
+            let _ = writeln!(out, "\n{id} = {}", template.replace('\n', "\n    "));
+        }
+        out
+    }
+
+    /// Render the bundle as a gettext catalog
+    ///
+    /// Message ids are written as `msgctxt`, templates as `msgid`, and the
+    /// `msgstr` entries are left for translators.
+    #[must_use]
+    pub fn to_gettext(&self) -> String {
+        let mut out = String::from("msgid \"\"\nmsgstr \"\"\n");
+        let _ = writeln!(out, "\"Content-Type: text/plain; charset=UTF-8\\n\"");
+        if let Some(locale) = &self.locale {
+            let _ = writeln!(out, "\"Language: {locale}\\n\"");
+        }
+        for (id, template) in &self.messages {
+            let _ = writeln!(
+                out,
+                "\nmsgctxt \"{}\"\nmsgid \"{}\"\nmsgstr \"\"",
+                po_escape(id),
+                po_escape(template)
+            );
+        }
+        out
+    }
+
+    /// Parse a Fluent resource
+    ///
+    /// Only messages with a plain or multi-line value are read; comments,
+    /// terms and attributes are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for a line that is neither a message, a
+    /// continuation, nor a comment.
+    pub fn from_fluent(content: &str) -> Result<Self> {
+        let mut bundle = Self::default();
+        let mut current: Option<String> = None;
+        for (number, line) in content.lines().enumerate() {
+            if line.starts_with(' ') && !line.trim_start().starts_with('.') {
+                if let Some(id) = &current
+                    && let Some(template) = bundle.messages.get_mut(id)
+                {
+                    if !template.is_empty() {
+                        template.push('\n');
+                    }
+                    template.push_str(line.trim());
+                }
+                continue;
+            }
+            current = None;
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') || line.starts_with(' ') {
+                continue;
+            }
+            let (id, template) = trimmed.split_once('=').ok_or_else(|| {
+                LinkMLError::parse(format!(
+                    "Invalid Fluent message on line {}: {trimmed}",
+                    number + 1
+                ))
+            })?;
+            let id = id.trim();
+            if !id.starts_with('-') {
+                bundle.insert(id, template.trim());
+                current = Some(id.to_string());
+            }
+        }
+        Ok(bundle)
+    }
+
+    /// Parse a gettext catalog
+    ///
+    /// Entries are keyed by `msgctxt`; untranslated entries (empty `msgstr`)
+    /// fall back to their `msgid`. The locale is read from the `Language`
+    /// header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for a malformed string.
+    pub fn from_gettext(content: &str) -> Result<Self> {
+        let mut bundle = Self::default();
+        let mut entry = PoEntry::default();
+        let mut keyword = None;
+        for (number, line) in content.lines().enumerate() {
+            let line = line.trim();
+            let invalid =
+                || LinkMLError::parse(format!("Invalid gettext entry on line {}", number + 1));
+            if line.starts_with('#') {
+                continue;
+            }
+            if line.is_empty() {
+                entry.finish(&mut bundle);
+                keyword = None;
+                continue;
+            }
+
+            let (field, quoted) = if line.starts_with('"') {
+                (keyword.ok_or_else(invalid)?, line)
+            } else {
+                let (field, quoted) = line.split_once(' ').ok_or_else(invalid)?;
+                if field == "msgctxt" {
+                    entry.finish(&mut bundle);
+                }
+                keyword = Some(field);
+                entry.field(field).ok_or_else(invalid)?.clear();
+                (field, quoted.trim())
+            };
+            let text = po_unescape(quoted).ok_or_else(invalid)?;
+            entry.field(field).ok_or_else(invalid)?.push_str(&text);
+        }
+        entry.finish(&mut bundle);
+        Ok(bundle)
+    }
+
+    /// Read a bundle file in the format given by its extension
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, has an unknown
+    /// extension, or cannot be parsed.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let format = BundleFormat::from_path(path).ok_or_else(|| {
+            LinkMLError::config(format!(
+                "Unknown message bundle format for {} (expected .ftl or .po)",
+                path.display()
+            ))
+        })?;
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            LinkMLError::io_error(format!(
+                "Failed to read message bundle {}: {e}",
+                path.display()
+            ))
+        })?;
+        match format {
+            BundleFormat::Fluent => Self::from_fluent(&content),
+            BundleFormat::Gettext => Self::from_gettext(&content),
+        }
+    }
+}
+
+/// Translates validation reports with a message bundle
+pub struct ReportLocalizer {
+    source: MessageBundle,
+    translations: MessageBundle,
+}
+
+impl ReportLocalizer {
+    /// Create a localizer for reports on data of `schema`
+    #[must_use]
+    pub fn new(translations: MessageBundle, schema: Option<&SchemaDefinition>) -> Self {
+        Self {
+            source: MessageBundle::extract(schema),
+            translations,
+        }
+    }
+
+    /// Translate every issue of a report in place
+    pub fn localize(&self, report: &mut ValidationReport) {
+        for issue in &mut report.issues {
+            self.localize_issue(issue);
+        }
+    }
+
+    /// Translate one issue in place
+    pub fn localize_issue(&self, issue: &mut ValidationIssue) {
+        let mut detail = issue.message.clone();
+        for (id, source) in self.source.messages() {
+            if id.starts_with("lml") {
+                continue;
+            }
+            let source = render(source, &IndexMap::new());
+            if let Some(translation) = self.translations.message(id)
+                && detail.contains(&source)
+            {
+                detail = detail.replace(&source, &render(translation, &IndexMap::new()));
+            }
+        }
+
+        let code = issue.error_code();
+        let Some(template) = self.translations.message(&code.anchor()) else {
+            issue.message = detail;
+            return;
+        };
+        let mut variables: IndexMap<&str, String> = issue
+            .context
+            .iter()
+            .filter_map(|(key, value)| {
+                let value = match value {
+                    serde_json::Value::String(text) => text.clone(),
+                    serde_json::Value::Number(number) => number.to_string(),
+                    _ => return None,
+                };
+                Some((key.as_str(), value))
+            })
+            .collect();
+        variables.insert("path", issue.path.clone());
+        variables.insert("validator", issue.validator.clone());
+        variables.insert("code", code.as_str().to_string());
+        variables.insert("detail", detail);
+
+        let message = render(template, &variables);
+        let source = std::mem::replace(&mut issue.message, message);
+        issue
+            .context
+            .insert("source_message".to_string(), source.into());
+    }
+}
+
+/// Messages provided by a schema, keyed by message id
+fn schema_messages(schema: &SchemaDefinition) -> Vec<(String, String)> {
+    let mut messages = Vec::new();
+    let mut classes: Vec<(&String, &ClassDefinition)> = schema.classes.iter().collect();
+    classes.sort_by_key(|(name, _)| *name);
+    for (name, class) in classes {
+        for (index, rule) in class.rules.iter().enumerate() {
+            if let Some(description) = &rule.description {
+                messages.push((
+                    format!("rule-{}-{index}", message_id(name)),
+                    description.clone(),
+                ));
+            }
+        }
+    }
+    messages
+}
+
+/// Fluent identifier for a schema element name
+fn message_id(name: &str) -> String {
+    let mut id = String::new();
+    for ch in name.chars() {
+        if ch.is_ascii_alphanumeric() {
+            if ch.is_ascii_uppercase() && !id.is_empty() && !id.ends_with('-') {
+                id.push('-');
+            }
+            id.push(ch.to_ascii_lowercase());
+        } else if !id.ends_with('-') {
+            id.push('-');
+        }
+    }
+    id.trim_matches('-').to_string()
+}
+
+/// Escape literal braces as Fluent string literals
+fn escape(text: &str) -> String {
+    let mut out = String::new();
+    for ch in text.chars() {
+        match ch {
+            '{' => out.push_str("{\"{\"}"),
+            '}' => out.push_str("{\"}\"}"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Resolve the placeables of a template
+///
+/// `{ $name }` is replaced by the variable (left as is if unknown) and
+/// `{ "text" }` by the literal.
+fn render(template: &str, variables: &IndexMap<&str, String>) -> String {
+    let mut out = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let Some((placeable, remainder)) = split_placeable(&rest[start + 1..]) else {
+            out.push_str(&rest[start..]);
+            return out;
+        };
+        let inner = placeable.trim();
+        if let Some(value) = inner
+            .strip_prefix('$')
+            .and_then(|name| variables.get(name.trim()))
+        {
+            out.push_str(value);
+        } else if let Some(literal) = inner
+            .strip_prefix('"')
+            .and_then(|literal| literal.strip_suffix('"'))
+        {
+            out.push_str(literal);
+        } else {
+            out.push('{');
+            out.push_str(placeable);
+            out.push('}');
+        }
+        rest = remainder;
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Content of a placeable and the text after its closing brace; braces in
+/// string literals do not close it
+fn split_placeable(text: &str) -> Option<(&str, &str)> {
+    let mut in_literal = false;
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '"' => in_literal = !in_literal,
+            '}' if !in_literal => return Some((&text[..index], &text[index + 1..])),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[derive(Default)]
+struct PoEntry {
+    context: String,
+    id: String,
+    translation: String,
+}
+
+impl PoEntry {
+    fn field(&mut self, keyword: &str) -> Option<&mut String> {
+        match keyword {
+            "msgctxt" => Some(&mut self.context),
+            "msgid" => Some(&mut self.id),
+            "msgstr" => Some(&mut self.translation),
+            _ => None,
+        }
+    }
+
+    /// Add the entry to a bundle and reset it
+    fn finish(&mut self, bundle: &mut MessageBundle) {
+        let entry = std::mem::take(self);
+        if entry.context.is_empty() && entry.id.is_empty() {
+            // Header entry
+            bundle.locale = entry
+                .translation
+                .lines()
+                .find_map(|line| line.strip_prefix("Language:"))
+                .map(|locale| locale.trim().to_string())
+                .filter(|locale| !locale.is_empty())
+                .or(bundle.locale.take());
+        } else if !entry.context.is_empty() {
+            let template = if entry.translation.is_empty() {
+                entry.id
+            } else {
+                entry.translation
+            };
+            bundle.insert(entry.context, template);
+        }
+    }
+}
+
+fn po_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
+/// Content of a quoted gettext string
+fn po_unescape(quoted: &str) -> Option<String> {
+    let inner = quoted.strip_prefix('"')?.strip_suffix('"')?;
+    let mut out = String::new();
+    let mut chars = inner.chars();
+    while let Some(ch) = chars.next() {
+        if ch == '\\' {
+            match chars.next()? {
+                'n' => out.push('\n'),
+                't' => out.push('\t'),
+                other => out.push(other),
+            }
+        } else {
+            out.push(ch);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::default();
+        let person = ClassDefinition {
+            rules: vec![Rule {
+                description: Some("Minors need a guardian".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        schema.classes.insert("Person".to_string(), person);
+        schema
+    }
+
+    #[test]
+    fn test_bundle_round_trip() {
+        let bundle = MessageBundle::extract(Some(&schema()));
+        assert_eq!(
+            bundle.message("lml1003"),
+            Some("A required slot is missing or null at { $path }: { $detail }")
+        );
+        assert_eq!(
+            bundle.message("rule-person-0"),
+            Some("Minors need a guardian")
+        );
+
+        let fluent = MessageBundle::from_fluent(&bundle.to_fluent()).expect("fluent round trip");
+        assert_eq!(fluent.messages, bundle.messages);
+
+        let gettext =
+            MessageBundle::from_gettext(&bundle.to_gettext()).expect("gettext round trip");
+        assert_eq!(gettext.messages, bundle.messages);
+        assert_eq!(gettext.locale.as_deref(), Some("en"));
+    }
+
+    #[test]
+    fn test_localize_report() {
+        let translations = MessageBundle::from_fluent(
+            "lml1003 = Pflichtfeld fehlt bei { $path } ({ $detail })\n\
+             rule-person-0 = Minderjährige brauchen einen Vormund\n",
+        )
+        .expect("valid fluent bundle");
+        let localizer = ReportLocalizer::new(translations, Some(&schema()));
+
+        let mut report = ValidationReport::new("test");
+        report.add_issue(
+            ValidationIssue::error(
+                "Field 'guardian' is required by rule: Minors need a guardian",
+                "$.guardian",
+                "RuleEvaluator",
+            )
+            .with_error_code(ErrorCode::RuleViolation),
+        );
+        report.add_issue(
+            ValidationIssue::error("Required slot 'name' is missing", "$.name", "required")
+                .with_error_code(ErrorCode::RequiredMissing),
+        );
+        localizer.localize(&mut report);
+
+        assert_eq!(
+            report.issues[0].message,
+            "Field 'guardian' is required by rule: Minderjährige brauchen einen Vormund"
+        );
+        assert_eq!(
+            report.issues[1].message,
+            "Pflichtfeld fehlt bei $.name (Required slot 'name' is missing)"
+        );
+        assert_eq!(
+            report.issues[1].context["source_message"],
+            "Required slot 'name' is missing"
+        );
+    }
+}
@@ -53,6 +53,9 @@ impl OptimizedValidationIssue {
             path: self.path,
             validator: self.validator,
             code: self.code,
+            error_code: None,
+            explanation: None,
+            fix: Vec::new(),
             context,
         }
     }
@@ -78,6 +81,11 @@ pub enum InstructionVariant {
         /// Name of the field that must be present
         field: String,
     },
+    /// Check recommended field
+    CheckRecommended {
+        /// Name of the field that should be present
+        field: String,
+    },
     /// Pattern validation
     ValidatePattern {
         /// ID of the compiled regex pattern to validate against
@@ -131,6 +139,10 @@ impl CompactInstruction {
                 path,
                 variant: InstructionVariant::CheckRequired { field },
             },
+            ValidationInstruction::CheckRecommended { path, field } => Self {
+                path,
+                variant: InstructionVariant::CheckRecommended { field },
+            },
             ValidationInstruction::ValidatePattern { path, pattern_id } => Self {
                 path,
                 variant: InstructionVariant::ValidatePattern {
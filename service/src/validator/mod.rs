@@ -7,10 +7,12 @@
 //! - Constraint validation (required, pattern, range, cardinality, etc.)
 //! - Cross-field validation
 //! - Instance-based permissible values
+//! - Dynamic enums backed by ontology queries
 //! - Compiled validators for performance
 //! - Parallel validation support
 
 use linkml_core::types::SchemaDefinition;
+pub mod batch;
 pub mod buffer_pool;
 pub mod cache;
 pub mod cache_key_optimizer;
@@ -20,27 +22,46 @@ pub mod composition;
 pub mod conditional_validator;
 pub mod context;
 pub mod default_applier;
+pub mod discovery;
+pub mod dynamic_enum;
 pub mod engine;
 pub mod error_recovery;
+pub mod explain;
+pub mod fixes;
 pub mod instance_loader;
 pub mod interned_report;
 pub mod json_path;
+pub mod json_schema_errors;
+pub mod localization;
 pub mod memory_layout;
 pub mod memory_safety;
 pub mod multi_layer_cache;
+pub mod normalizer;
+pub mod null_policy;
 pub mod panic_prevention;
 pub mod parallel;
 pub mod pattern_validator;
 pub mod recursion_checker;
 pub mod report;
+pub mod report_formats;
 pub mod resource_limiter;
+pub mod rule_config;
 pub mod security;
+pub mod source_map;
+pub mod status;
 pub mod stress_test;
 pub mod string_interner;
+pub mod suggest;
+pub mod timing;
 pub mod ttl_manager;
 pub mod unique_key_validator;
+pub mod units;
 pub mod validators;
 
+pub use batch::{
+    BatchDataset, BatchManifest, BatchSummary, BatchValidator, DatasetStatus, DatasetSummary,
+    FileSummary,
+};
 pub use cache_warmer::{AccessEntry, WarmingStrategy};
 pub use composition::{ResolvedClass, SchemaComposer};
 pub use conditional_validator::{
@@ -48,13 +69,24 @@ pub use conditional_validator::{
 };
 pub use context::ValidationContext;
 pub use default_applier::{DefaultApplier, apply_defaults_to_instance};
+pub use discovery::{DiscoveredSchema, DiscoverySource, SchemaDiscovery};
+pub use dynamic_enum::{DynamicEnumResolver, InMemoryOntology, OntologyProvider};
 pub use engine::{ValidationEngine, ValidationOptions};
+pub use explain::{ConstraintOrigin, ConstraintSource, Explanation};
 pub use instance_loader::{InstanceConfig, InstanceData, InstanceLoader};
+pub use json_schema_errors::{ClassMapping, JsonSchemaError, JsonSchemaMapping, PropertyMapping};
+pub use localization::{BundleFormat, MessageBundle, ReportLocalizer};
+pub use normalizer::Normalizer;
+pub use null_policy::{EmptyCollectionPolicy, NullPolicy};
 pub use pattern_validator::{PatternTransformer, PatternValidator, validate_patterns};
 pub use recursion_checker::{RecursionTracker, check_recursion};
 pub use report::{Severity, ValidationIssue, ValidationReport};
+pub use rule_config::{RuleSet, RuleSeverity, ValidationRuleConfig};
+pub use source_map::{SourceLocation, SourceMap};
+pub use timing::ConstraintTiming;
 pub use unique_key_validator::{UniqueKeyIndex, UniqueKeyValidator, UniqueKeyViolation};
-pub use validators::Validator;
+pub use units::{Quantity, Unit};
+pub use validators::{CustomValidatorRegistry, Validator};
 
 use serde_json::Value;
 
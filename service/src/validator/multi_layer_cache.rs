@@ -51,6 +51,30 @@ impl Default for MultiLayerCacheConfig {
     }
 }
 
+impl MultiLayerCacheConfig {
+    /// Configuration of the persistent validator cache of a service
+    ///
+    /// Returns `None` unless `multi_layer_cache.l3_directory` is set. The TTLs
+    /// follow `performance.cache_ttl_levels` and the L1 size follows
+    /// `validator.compiled_cache_size`.
+    #[must_use]
+    pub fn persistent(config: &crate::config::LinkMLConfig) -> Option<Self> {
+        let directory = config.multi_layer_cache.l3_directory.clone()?;
+        let ttl_levels = &config.performance.cache_ttl_levels;
+        Some(Self {
+            l1_max_validators: config.validator.compiled_cache_size.max(1),
+            l1_ttl: Duration::from_secs(ttl_levels.l1_seconds),
+            l2_ttl: Duration::from_secs(ttl_levels.l2_seconds),
+            l3_enabled: true,
+            l3_directory: Some(directory),
+            l3_max_size_bytes: usize::try_from(config.multi_layer_cache.l3_max_size_bytes)
+                .unwrap_or(usize::MAX),
+            warm_on_startup: false,
+            prefetch_related: false,
+        })
+    }
+}
+
 /// Entry in L1 cache with timestamp
 struct L1Entry {
     validator: Arc<CompiledValidator>,
@@ -143,38 +167,40 @@ impl MultiLayerCache {
         };
 
         // Start background tasks for cache maintenance
-        let background_handle =
-            if config.warm_on_startup || config.l1_ttl < Duration::from_secs(3600) {
-                let l1_clone = l1_cache.clone();
-                let l1_ttl = config.l1_ttl;
-
-                Some(tokio::spawn(async move {
-                    let mut interval = tokio::time::interval(Duration::from_secs(60));
-                    loop {
-                        interval.tick().await;
-
-                        // Evict expired entries from L1
-                        let now = Instant::now();
-                        let mut cache = l1_clone.lock();
-
-                        // Collect expired keys
-                        let expired_keys: Vec<_> = cache
-                            .iter()
-                            .filter(|(_, entry)| now.duration_since(entry.inserted_at) > l1_ttl)
-                            .map(|(key, _)| key.clone())
-                            .collect();
-
-                        // Remove expired entries
-                        for key in expired_keys {
-                            cache.pop(&key);
-                        }
-
-                        drop(cache);
+        let background_handle = if (config.warm_on_startup
+            || config.l1_ttl < Duration::from_secs(3600))
+            && tokio::runtime::Handle::try_current().is_ok()
+        {
+            let l1_clone = l1_cache.clone();
+            let l1_ttl = config.l1_ttl;
+
+            Some(tokio::spawn(async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(60));
+                loop {
+                    interval.tick().await;
+
+                    // Evict expired entries from L1
+                    let now = Instant::now();
+                    let mut cache = l1_clone.lock();
+
+                    // Collect expired keys
+                    let expired_keys: Vec<_> = cache
+                        .iter()
+                        .filter(|(_, entry)| now.duration_since(entry.inserted_at) > l1_ttl)
+                        .map(|(key, _)| key.clone())
+                        .collect();
+
+                    // Remove expired entries
+                    for key in expired_keys {
+                        cache.pop(&key);
                     }
-                }))
-            } else {
-                None
-            };
+
+                    drop(cache);
+                }
+            }))
+        } else {
+            None
+        };
 
         Ok(Self {
             config,
@@ -558,8 +584,10 @@ impl MultiLayerCache {
     }
 
     fn deserialize_validator(data: &[u8]) -> Result<CompiledValidator> {
-        bincode::deserialize(data)
-            .map_err(|e| LinkMLError::service(format!("Failed to deserialize validator: {e}")))
+        let mut validator: CompiledValidator = bincode::deserialize(data)
+            .map_err(|e| LinkMLError::service(format!("Failed to deserialize validator: {e}")))?;
+        validator.restore_patterns()?;
+        Ok(validator)
     }
 
     /// Cancel all running tasks
@@ -635,9 +663,14 @@ impl DiskCache {
         let path = self.key_to_path(key);
 
         match tokio::fs::read(&path).await {
-            Ok(data) => bincode::deserialize(&data)
-                .map(Some)
-                .map_err(|e| LinkMLError::service(format!("Failed to deserialize from disk: {e}"))),
+            Ok(data) => {
+                let mut validator: CompiledValidator =
+                    bincode::deserialize(&data).map_err(|e| {
+                        LinkMLError::service(format!("Failed to deserialize from disk: {e}"))
+                    })?;
+                validator.restore_patterns()?;
+                Ok(Some(validator))
+            }
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
             Err(e) => Err(LinkMLError::service(format!(
                 "Failed to read from disk cache: {e}"
@@ -757,8 +790,9 @@ impl DiskCache {
     }
 
     fn key_to_path(&self, key: &ValidatorCacheKey) -> std::path::PathBuf {
-        let hash = key.to_string();
-        let (prefix, suffix) = hash.split_at(2.min(hash.len()));
+        // Schema IDs are usually URIs, so hash the key into a file name
+        let hash = blake3::hash(key.to_string().as_bytes()).to_hex();
+        let (prefix, suffix) = hash.split_at(2);
         std::path::Path::new(&self.directory)
             .join(prefix)
             .join(format!("{suffix}.cache"))
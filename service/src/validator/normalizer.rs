@@ -0,0 +1,527 @@
+//! Normalization of instance data before validation
+//!
+//! The Python `LinkML` loaders accept data that is close to, but not exactly,
+//! what the schema asks for: numbers written as strings, dates with a time
+//! part, values padded with whitespace. The [`Normalizer`] rewrites such data
+//! into the canonical form the validators expect and reports every change as
+//! an info-level issue, so lenient input stays visible in the report. The
+//! engine runs it before validating when `ValidationOptions::normalize` is
+//! set.
+//!
+//! | Range | Accepted | Normalized to |
+//! |-------|----------|---------------|
+//! | any primitive or enum | `" value "` | `"value"` |
+//! | `integer` | `"42"`, `42.0` | `42` |
+//! | `float`, `double`, `decimal` | `"4.2"` | `4.2` |
+//! | `boolean` | `"true"`, `"No"` | `true`, `false` |
+//! | `string` | `42`, `true` | `"42"`, `"true"` |
+//! | `date` | `"2023-01-01T10:00:00Z"`, `"2023/01/01"` | `"2023-01-01"` |
+//! | `datetime` | `"2023-01-01 10:00:00"`, `"2023-01-01"` | `"2023-01-01T10:00:00Z"` |
+//!
+//! Unlike the annotation-driven normalizers of `crate::loader::normalize`,
+//! this pass needs no schema changes. Datetimes without an offset are read as
+//! UTC. String values of non-key slots are finally rewritten according to the
+//! schema's `settings.defaults.string_serialization`. Collections inlined as
+//! a dictionary keyed by identifier, including the compact form, are expanded
+//! into lists first (see `crate::loader::inlined`).
+//!
+//! With [`Normalizer::with_unit_conversion`], quantities of slots with a
+//! `unit` are converted to the canonical unit of the slot (see
+//! [`super::units`]): `"1500 mg"` becomes `"1.5 g"`, or `1.5` if the slot
+//! range is numeric. Quantities whose unit cannot be converted are left for
+//! the unit validator to report.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat};
+use convert_case::{Case, Casing};
+use linkml_core::settings::StringSerialization;
+use linkml_core::types::{SchemaDefinition, SlotDefinition};
+use serde_json::{Number, Value};
+use std::collections::HashSet;
+
+use crate::loader::inlined::InlinedExpander;
+
+use super::report::ValidationIssue;
+use super::units::{self, Unit};
+
+/// Validator name of issues reported by the normalizer
+pub const NORMALIZER: &str = "normalizer";
+
+/// Naive datetime formats accepted for `date` and `datetime` ranges
+const NAIVE_DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+];
+
+/// Date formats accepted for `date` and `datetime` ranges
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%Y/%m/%d", "%Y%m%d"];
+
+/// Rewrites lenient instance data into canonical form
+pub struct Normalizer<'a> {
+    schema: &'a SchemaDefinition,
+    string_serialization: Option<StringSerialization>,
+    convert_units: bool,
+}
+
+/// How a slot range is normalized
+enum RangeKind<'r> {
+    Class(&'r str),
+    Enum,
+    Primitive(&'r str),
+}
+
+impl<'a> Normalizer<'a> {
+    /// Create a normalizer for a schema
+    #[must_use]
+    pub fn new(schema: &'a SchemaDefinition) -> Self {
+        let string_serialization = schema
+            .settings
+            .as_ref()
+            .and_then(|settings| settings.defaults.as_ref())
+            .and_then(|defaults| defaults.string_serialization)
+            .filter(|serialization| *serialization != StringSerialization::Exact);
+        Self {
+            schema,
+            string_serialization,
+            convert_units: false,
+        }
+    }
+
+    /// Convert quantities to the canonical unit of their slot
+    #[must_use]
+    pub fn with_unit_conversion(mut self, convert_units: bool) -> Self {
+        self.convert_units = convert_units;
+        self
+    }
+
+    /// Normalize `data` as an instance of `class_name` in place
+    ///
+    /// Nested objects are normalized according to their slot ranges. Returns
+    /// one info issue per change; values that cannot be coerced are left for
+    /// the validators to report.
+    pub fn normalize(&self, data: &mut Value, class_name: &str) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        self.normalize_object(data, class_name, "$", &mut issues);
+        issues
+    }
+
+    fn normalize_object(
+        &self,
+        data: &mut Value,
+        class_name: &str,
+        path: &str,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        let Some(object) = data.as_object_mut() else {
+            return;
+        };
+        for slot in self.class_slots(class_name) {
+            let Some(value) = object.get_mut(&slot.name) else {
+                continue;
+            };
+            let path = format!("{path}.{}", slot.name);
+
+            // Collections inlined as a dictionary keyed by identifier
+            if slot.multivalued == Some(true)
+                && let RangeKind::Class(range) = self.range_kind(&slot)
+                && let Some(items) =
+                    InlinedExpander::new(self.schema).expand_collection(value, range)
+            {
+                let expanded = Value::Array(items);
+                issues.push(
+                    ValidationIssue::info(
+                        "Expanded dictionary-inlined collection into a list",
+                        &path,
+                        NORMALIZER,
+                    )
+                    .with_code("normalized")
+                    .with_context("original", value.clone()),
+                );
+                *value = expanded;
+            }
+
+            match value {
+                Value::Array(items) => {
+                    for (index, item) in items.iter_mut().enumerate() {
+                        self.normalize_value(item, &slot, &format!("{path}[{index}]"), issues);
+                    }
+                }
+                _ => self.normalize_value(value, &slot, &path, issues),
+            }
+        }
+    }
+
+    fn normalize_value(
+        &self,
+        value: &mut Value,
+        slot: &SlotDefinition,
+        path: &str,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        if self.convert_units {
+            convert_unit(value, slot, &self.range_kind(slot), path, issues);
+        }
+
+        if value.is_object() || value.is_array() {
+            if let RangeKind::Class(class_name) = self.range_kind(slot) {
+                self.normalize_object(value, class_name, path, issues);
+            }
+            return;
+        }
+
+        if let Value::String(text) = value
+            && text.trim().len() != text.len()
+        {
+            let trimmed = Value::String(text.trim().to_string());
+            issues.push(coercion_issue("Trimmed whitespace", value, &trimmed, path));
+            *value = trimmed;
+        }
+
+        let RangeKind::Primitive(primitive) = self.range_kind(slot) else {
+            return;
+        };
+        if let Some(coerced) = coerce_primitive(value, primitive) {
+            issues.push(coercion_issue(
+                &format!("Coerced value to {primitive}"),
+                value,
+                &coerced,
+                path,
+            ));
+            *value = coerced;
+        }
+
+        let is_key = slot.identifier == Some(true) || slot.key == Some(true);
+        if let (Some(serialization), Value::String(text)) = (self.string_serialization, &*value)
+            && matches!(primitive, "string" | "str")
+            && !is_key
+        {
+            let serialized = Value::String(serialize_string(text, serialization));
+            if serialized != *value {
+                issues.push(coercion_issue(
+                    &format!("Applied {serialization:?} string serialization"),
+                    value,
+                    &serialized,
+                    path,
+                ));
+                *value = serialized;
+            }
+        }
+    }
+
+    /// Effective slots of a class, ancestors first, with `slot_usage` applied
+    fn class_slots(&self, class_name: &str) -> Vec<SlotDefinition> {
+        let mut slots: Vec<SlotDefinition> = Vec::new();
+        self.collect_slots(class_name, &mut slots, &mut HashSet::new());
+        slots
+    }
+
+    fn collect_slots(
+        &self,
+        class_name: &str,
+        slots: &mut Vec<SlotDefinition>,
+        visited: &mut HashSet<String>,
+    ) {
+        if !visited.insert(class_name.to_string()) {
+            return;
+        }
+        let Some(class) = self.schema.classes.get(class_name) else {
+            return;
+        };
+        for parent in class.is_a.iter().chain(&class.mixins) {
+            self.collect_slots(parent, slots, visited);
+        }
+
+        let own = class
+            .slots
+            .iter()
+            .filter_map(|name| {
+                let mut slot = self.schema.slots.get(name)?.clone();
+                slot.name.clone_from(name);
+                Some(slot)
+            })
+            .chain(class.attributes.iter().map(|(name, attribute)| {
+                let mut slot = attribute.clone();
+                slot.name.clone_from(name);
+                slot
+            }));
+        for slot in own {
+            match slots.iter_mut().find(|existing| existing.name == slot.name) {
+                Some(existing) => *existing = slot,
+                None => slots.push(slot),
+            }
+        }
+
+        for (name, usage) in &class.slot_usage {
+            if let Some(slot) = slots.iter_mut().find(|slot| slot.name == *name) {
+                if usage.range.is_some() {
+                    slot.range.clone_from(&usage.range);
+                }
+                if usage.multivalued.is_some() {
+                    slot.multivalued = usage.multivalued;
+                }
+                if usage.unit.is_some() {
+                    slot.unit.clone_from(&usage.unit);
+                }
+            }
+        }
+    }
+
+    fn range_kind<'s>(&'s self, slot: &'s SlotDefinition) -> RangeKind<'s> {
+        let mut range = slot
+            .range
+            .as_deref()
+            .or(self.schema.default_range.as_deref())
+            .unwrap_or("string");
+        if self.schema.classes.contains_key(range) {
+            return RangeKind::Class(range);
+        }
+        if self.schema.enums.contains_key(range) {
+            return RangeKind::Enum;
+        }
+        // Follow `typeof` to the built-in type, guarding against cycles
+        let mut seen = HashSet::new();
+        while let Some(base) = self
+            .schema
+            .types
+            .get(range)
+            .and_then(|ty| ty.base_type.as_deref())
+        {
+            if !seen.insert(range) {
+                break;
+            }
+            range = base;
+        }
+        RangeKind::Primitive(range)
+    }
+}
+
+/// Convert a quantity to the canonical unit of its slot
+fn convert_unit(
+    value: &mut Value,
+    slot: &SlotDefinition,
+    range: &RangeKind<'_>,
+    path: &str,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let Some(canonical) = units::slot_unit(slot).and_then(|code| Unit::parse(code).ok()) else {
+        return;
+    };
+    let bare = matches!(
+        range,
+        RangeKind::Primitive("integer" | "int" | "float" | "double" | "decimal")
+    );
+    if let Ok(Some(converted)) = units::to_canonical(value, &canonical, bare) {
+        issues.push(coercion_issue(
+            &format!("Converted quantity to {canonical}"),
+            value,
+            &converted,
+            path,
+        ));
+        *value = converted;
+    }
+}
+
+/// Canonical value for `value` under a built-in type, if it differs
+fn coerce_primitive(value: &Value, primitive: &str) -> Option<Value> {
+    match (primitive, value) {
+        ("integer" | "int", Value::String(text)) => text.parse::<i64>().ok().map(Value::from),
+        ("integer" | "int", Value::Number(number)) if number.is_f64() => {
+            let float = number.as_f64()?;
+            (float.fract() == 0.0 && float.abs() < 9.0e15).then(|| Value::from(whole_number(float)))
+        }
+        ("float" | "double" | "decimal", Value::String(text)) => text
+            .parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .map(Value::Number),
+        ("boolean" | "bool", Value::String(text)) => match text.to_ascii_lowercase().as_str() {
+            "true" | "yes" => Some(Value::Bool(true)),
+            "false" | "no" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        ("string" | "str", Value::Number(_) | Value::Bool(_)) => {
+            Some(Value::String(value.to_string()))
+        }
+        ("date", Value::String(text)) => {
+            let date = parse_date(text)
+                .or_else(|| {
+                    DateTime::parse_from_rfc3339(text)
+                        .ok()
+                        .map(|dt| dt.date_naive())
+                })
+                .or_else(|| parse_naive_datetime(text).map(|dt| dt.date()))?;
+            let canonical = date.format("%Y-%m-%d").to_string();
+            (canonical != *text).then_some(Value::String(canonical))
+        }
+        ("datetime", Value::String(text)) => {
+            if DateTime::parse_from_rfc3339(text).is_ok() {
+                return None;
+            }
+            let datetime = parse_naive_datetime(text)
+                .or_else(|| parse_date(text).and_then(|date| date.and_hms_opt(0, 0, 0)))?;
+            Some(Value::String(
+                datetime
+                    .and_utc()
+                    .to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            ))
+        }
+        _ => None,
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn whole_number(float: f64) -> i64 {
+    float as i64
+}
+
+fn parse_date(text: &str) -> Option<NaiveDate> {
+    DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(text, format).ok())
+}
+
+fn parse_naive_datetime(text: &str) -> Option<NaiveDateTime> {
+    NAIVE_DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+}
+
+fn serialize_string(text: &str, serialization: StringSerialization) -> String {
+    match serialization {
+        StringSerialization::Exact => text.to_string(),
+        StringSerialization::Lowercase => text.to_lowercase(),
+        StringSerialization::Uppercase => text.to_uppercase(),
+        StringSerialization::CamelCase => text.to_case(Case::Camel),
+        StringSerialization::SnakeCase => text.to_case(Case::Snake),
+        StringSerialization::PascalCase => text.to_case(Case::Pascal),
+    }
+}
+
+fn coercion_issue(message: &str, from: &Value, to: &Value, path: &str) -> ValidationIssue {
+    ValidationIssue::info(format!("{message}: {from} -> {to}"), path, NORMALIZER)
+        .with_code("normalized")
+        .with_context("original", from.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::report::Severity;
+    use linkml_core::prelude::*;
+    use linkml_core::settings::{DefaultSettings, SchemaSettings};
+    use serde_json::json;
+
+    fn slot(range: &str) -> SlotDefinition {
+        SlotDefinition {
+            range: Some(range.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_normalize_coerces_nested_values() {
+        let mut schema = SchemaDefinition::default();
+        let mut person = ClassDefinition::default();
+        for (name, range) in [
+            ("id", "string"),
+            ("nickname", "string"),
+            ("age", "integer"),
+            ("active", "boolean"),
+            ("born", "date"),
+            ("seen", "datetime"),
+            ("friends", "Person"),
+        ] {
+            person.attributes.insert(name.to_string(), slot(range));
+        }
+        person.attributes["id"].identifier = Some(true);
+        person.attributes["friends"].multivalued = Some(true);
+        schema.classes.insert("Person".to_string(), person);
+        schema.settings = Some(SchemaSettings {
+            defaults: Some(DefaultSettings {
+                string_serialization: Some(StringSerialization::Lowercase),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let mut data = json!({
+            "id": "P1",
+            "nickname": "  Bob ",
+            "age": "42",
+            "active": "Yes",
+            "born": "1980-05-01T08:00:00Z",
+            "seen": "2023-01-01 10:30:00",
+            "friends": [{"id": "P2", "age": 7.0, "born": "1990/02/03"}]
+        });
+        let issues = Normalizer::new(&schema).normalize(&mut data, "Person");
+
+        assert_eq!(
+            data,
+            json!({
+                "id": "P1",
+                "nickname": "bob",
+                "age": 42,
+                "active": true,
+                "born": "1980-05-01",
+                "seen": "2023-01-01T10:30:00Z",
+                "friends": [{"id": "P2", "age": 7, "born": "1990-02-03"}]
+            })
+        );
+        assert!(issues.iter().all(|issue| issue.severity == Severity::Info));
+        assert_eq!(issues.len(), 8);
+        assert!(issues.iter().any(|issue| issue.path == "$.friends[0].born"));
+
+        // Values that cannot be coerced are left for the validators
+        let mut invalid = json!({"age": "forty-two"});
+        assert!(
+            Normalizer::new(&schema)
+                .normalize(&mut invalid, "Person")
+                .is_empty()
+        );
+        assert_eq!(invalid, json!({"age": "forty-two"}));
+
+        // Collections keyed by identifier are expanded before normalizing
+        let mut keyed = json!({"id": "P1", "friends": {"P2": {"age": "7"}}});
+        let issues = Normalizer::new(&schema).normalize(&mut keyed, "Person");
+        assert_eq!(
+            keyed,
+            json!({"id": "P1", "friends": [{"id": "P2", "age": 7}]})
+        );
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].path, "$.friends");
+    }
+
+    #[test]
+    fn test_normalize_converts_units() {
+        let mut schema = SchemaDefinition::default();
+        let mut sample = ClassDefinition::default();
+        for (name, range) in [("mass", "float"), ("dose", "string"), ("volume", "float")] {
+            let mut attribute = slot(range);
+            attribute.unit = Some(UnitOfMeasure {
+                ucum_code: Some(if name == "volume" { "mL" } else { "g" }.to_string()),
+                ..Default::default()
+            });
+            sample.attributes.insert(name.to_string(), attribute);
+        }
+        schema.classes.insert("Sample".to_string(), sample);
+
+        let data = json!({"mass": "1500 mg", "dose": "2 kg", "volume": "3 g"});
+        let mut unchanged = data.clone();
+        assert!(
+            Normalizer::new(&schema)
+                .normalize(&mut unchanged, "Sample")
+                .is_empty()
+        );
+
+        let mut converted = data;
+        let issues = Normalizer::new(&schema)
+            .with_unit_conversion(true)
+            .normalize(&mut converted, "Sample");
+        assert_eq!(
+            converted,
+            json!({"mass": 1.5, "dose": "2000 g", "volume": "3 g"})
+        );
+        assert_eq!(issues.len(), 2);
+    }
+}
@@ -0,0 +1,233 @@
+//! Null-handling policies
+//!
+//! A slot of an instance can be absent, set to an explicit `null`, or set to
+//! an empty list. By default the validator only treats an absent slot as
+//! missing: `null` is checked like any other value and an empty list is a
+//! valid multivalued value. [`NullPolicy`] and [`EmptyCollectionPolicy`]
+//! change that per validation run:
+//!
+//! | data   | policy                            | optional slot | required slot |
+//! |--------|-----------------------------------|---------------|---------------|
+//! | absent | -                                 | ok            | error         |
+//! | `null` | [`NullPolicy::Validate`]          | type checked  | error         |
+//! | `null` | [`NullPolicy::Absent`]            | ok            | error         |
+//! | `null` | [`NullPolicy::Clear`]             | ok            | error         |
+//! | `[]`   | [`EmptyCollectionPolicy::Allow`]  | ok            | ok            |
+//! | `[]`   | [`EmptyCollectionPolicy::Absent`] | ok            | error         |
+//!
+//! Slots that count as absent get their `ifabsent` default before
+//! validation; a `null` under [`NullPolicy::Clear`] deliberately clears the
+//! value, so no default replaces it.
+//!
+//! An optional slot marked `recommended: true` that is missing by these
+//! rules is reported as a warning instead of being skipped.
+
+use linkml_core::types::SlotDefinition;
+use serde_json::Value;
+
+/// How an explicit `null` slot value is treated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NullPolicy {
+    /// `null` is validated like any other value
+    #[default]
+    Validate,
+    /// `null` is treated like an absent slot, so defaults apply to it
+    Absent,
+    /// `null` clears the value: optional slots accept it and no default
+    /// replaces it, required slots cannot be cleared
+    Clear,
+}
+
+/// How an empty list is treated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmptyCollectionPolicy {
+    /// An empty list is a valid value, even for required slots
+    #[default]
+    Allow,
+    /// An empty list is treated like an absent slot, so defaults apply to
+    /// it and required slots report it
+    Absent,
+}
+
+/// How a slot is set in an instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Presence {
+    Absent,
+    Null,
+    Empty,
+    Value,
+}
+
+impl Presence {
+    fn of(value: Option<&Value>) -> Self {
+        match value {
+            None => Self::Absent,
+            Some(Value::Null) => Self::Null,
+            Some(Value::Array(items)) if items.is_empty() => Self::Empty,
+            Some(_) => Self::Value,
+        }
+    }
+}
+
+/// What the engine does with one slot of an instance
+pub(crate) enum SlotCheck<'v> {
+    /// Run the slot validators on the value
+    Validate(&'v Value),
+    /// Leave the slot alone
+    Skip,
+    /// Report an error with this message
+    Error(String),
+    /// Report a warning with this message
+    Warning(String),
+}
+
+/// Null and empty collection policies of a validation run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct NullHandling {
+    null: NullPolicy,
+    empty: EmptyCollectionPolicy,
+}
+
+impl NullHandling {
+    pub(crate) fn new(null: NullPolicy, empty: EmptyCollectionPolicy) -> Self {
+        Self { null, empty }
+    }
+
+    fn counts_as_absent(self, presence: Presence) -> bool {
+        match presence {
+            Presence::Absent => true,
+            Presence::Null => self.null == NullPolicy::Absent,
+            Presence::Empty => self.empty == EmptyCollectionPolicy::Absent,
+            Presence::Value => false,
+        }
+    }
+
+    /// Remove the slots of an object that count as absent, so that defaults
+    /// are applied to them
+    pub(crate) fn strip_absent(self, data: &mut Value) {
+        if let Value::Object(object) = data {
+            object.retain(|_, value| !self.counts_as_absent(Presence::of(Some(value))));
+        }
+    }
+
+    /// Check slot `name` given its value in the input data and after
+    /// stripping and defaults were applied
+    pub(crate) fn check<'v>(
+        self,
+        name: &str,
+        slot: &SlotDefinition,
+        original: Option<&Value>,
+        prepared: Option<&'v Value>,
+    ) -> SlotCheck<'v> {
+        let required = slot.required == Some(true);
+        let presence = Presence::of(original);
+
+        // A null that clears the value is not validated; anything else left
+        // after stripping, including applied defaults, is
+        let cleared = presence == Presence::Null && self.null == NullPolicy::Clear;
+        if let Some(value) = prepared
+            && !cleared
+        {
+            return SlotCheck::Validate(value);
+        }
+        if !required {
+            return if slot.recommended == Some(true) {
+                SlotCheck::Warning(format!("Recommended slot '{name}' is missing"))
+            } else {
+                SlotCheck::Skip
+            };
+        }
+        SlotCheck::Error(match (presence, self.null) {
+            (Presence::Null, NullPolicy::Clear) => {
+                format!("Required slot '{name}' cannot be cleared with null")
+            }
+            (Presence::Null, _) => format!("Required slot '{name}' is null"),
+            (Presence::Empty, _) => format!("Required slot '{name}' is an empty list"),
+            _ => format!("Required slot '{name}' is missing"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn message(check: SlotCheck<'_>) -> Option<String> {
+        match check {
+            SlotCheck::Error(message) | SlotCheck::Warning(message) => Some(message),
+            SlotCheck::Validate(_) | SlotCheck::Skip => None,
+        }
+    }
+
+    #[test]
+    fn test_null_and_empty_policies() {
+        let required = SlotDefinition {
+            required: Some(true),
+            ..Default::default()
+        };
+        let optional = SlotDefinition::default();
+        let null = json!(null);
+        let empty = json!([]);
+
+        let default = NullHandling::default();
+        assert!(matches!(
+            default.check("a", &required, Some(&null), Some(&null)),
+            SlotCheck::Validate(_)
+        ));
+        assert!(matches!(
+            default.check("a", &required, Some(&empty), Some(&empty)),
+            SlotCheck::Validate(_)
+        ));
+        assert_eq!(
+            message(default.check("a", &required, None, None)).as_deref(),
+            Some("Required slot 'a' is missing")
+        );
+
+        let recommended = SlotDefinition {
+            recommended: Some(true),
+            ..Default::default()
+        };
+        assert!(matches!(
+            default.check("r", &recommended, None, None),
+            SlotCheck::Warning(message) if message == "Recommended slot 'r' is missing"
+        ));
+        assert!(matches!(
+            default.check("r", &recommended, Some(&null), Some(&null)),
+            SlotCheck::Validate(_)
+        ));
+
+        let clear = NullHandling::new(NullPolicy::Clear, EmptyCollectionPolicy::Absent);
+        assert!(matches!(
+            clear.check("a", &optional, Some(&null), Some(&null)),
+            SlotCheck::Skip
+        ));
+        assert_eq!(
+            message(clear.check("a", &required, Some(&null), Some(&null))).as_deref(),
+            Some("Required slot 'a' cannot be cleared with null")
+        );
+
+        let mut data = json!({"a": null, "b": [], "c": 1});
+        clear.strip_absent(&mut data);
+        assert_eq!(data, json!({"a": null, "c": 1}));
+        assert_eq!(
+            message(clear.check("b", &required, Some(&empty), None)).as_deref(),
+            Some("Required slot 'b' is an empty list")
+        );
+
+        let absent = NullHandling::new(NullPolicy::Absent, EmptyCollectionPolicy::Allow);
+        let mut data = json!({"a": null, "b": []});
+        absent.strip_absent(&mut data);
+        assert_eq!(data, json!({"b": []}));
+        assert_eq!(
+            message(absent.check("a", &required, Some(&null), None)).as_deref(),
+            Some("Required slot 'a' is null")
+        );
+        // A default applied to a null slot is validated
+        let default_value = json!("x");
+        assert!(matches!(
+            absent.check("a", &required, Some(&null), Some(&default_value)),
+            SlotCheck::Validate(_)
+        ));
+    }
+}
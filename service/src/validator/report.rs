@@ -1,5 +1,10 @@
 //! Validation report structures
 
+use super::explain::Explanation;
+use super::timing::ConstraintTiming;
+use crate::schema::patch::PatchOperation;
+use crate::schema_registry::SchemaVersion;
+use linkml_core::error_codes::ErrorCode;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
@@ -38,8 +43,18 @@ pub struct ValidationIssue {
     pub validator: String,
     /// Optional error code for programmatic handling
     pub code: Option<String>,
+    /// Stable error code; resolved from `code` or the validator when not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<ErrorCode>,
     /// Additional context information
     pub context: HashMap<String, serde_json::Value>,
+    /// Constraint source and suggested fix, set with the `explain` option
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<Explanation>,
+    /// `JSON` Patch operations that fix the instance, set with the `fixes`
+    /// option for auto-fixable issues
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fix: Vec<PatchOperation>,
 }
 
 impl ValidationIssue {
@@ -56,6 +71,9 @@ impl ValidationIssue {
             path: path.into(),
             validator: validator.into(),
             code: None,
+            error_code: None,
+            explanation: None,
+            fix: Vec::new(),
             context: HashMap::new(),
         }
     }
@@ -94,6 +112,24 @@ impl ValidationIssue {
         self
     }
 
+    /// Set the stable error code
+    #[must_use]
+    pub fn with_error_code(mut self, code: ErrorCode) -> Self {
+        self.error_code = Some(code);
+        self
+    }
+
+    /// Stable error code of this issue
+    ///
+    /// An explicitly set code wins; otherwise the free-form `code` is mapped
+    /// to a stable code, falling back to the default for the validator.
+    #[must_use]
+    pub fn error_code(&self) -> ErrorCode {
+        self.error_code
+            .or_else(|| self.code.as_deref().and_then(ErrorCode::from_legacy))
+            .unwrap_or_else(|| ErrorCode::for_validator(&self.validator))
+    }
+
     /// Add context information
     #[must_use]
     pub fn with_context(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
@@ -104,7 +140,14 @@ impl ValidationIssue {
 
 impl fmt::Display for ValidationIssue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}] {}: {}", self.severity, self.path, self.message)
+        write!(
+            f,
+            "[{}] {} {}: {}",
+            self.severity,
+            self.error_code(),
+            self.path,
+            self.message
+        )
     }
 }
 
@@ -119,12 +162,19 @@ pub struct ValidationStats {
     pub warning_count: usize,
     /// Number of info messages
     pub info_count: usize,
+    /// Number of missing recommended slots, included in `warning_count`
+    #[serde(default)]
+    pub recommended_missing: usize,
     /// Validation duration in milliseconds
     pub duration_ms: u64,
     /// Number of validators executed
     pub validators_executed: usize,
     /// Cache hit rate (0.0 to 1.0)
     pub cache_hit_rate: f64,
+    /// Constraints that took the most time, slowest first; only collected
+    /// with constraint timing enabled
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub slowest_constraints: Vec<ConstraintTiming>,
 }
 
 /// Complete validation report
@@ -140,6 +190,9 @@ pub struct ValidationReport {
     pub schema_id: String,
     /// Optional target class if specified
     pub target_class: Option<String>,
+    /// Version of a registered schema the data was validated against
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<SchemaVersion>,
 }
 
 impl ValidationReport {
@@ -151,11 +204,16 @@ impl ValidationReport {
             stats: ValidationStats::default(),
             schema_id: schema_id.into(),
             target_class: None,
+            schema_version: None,
         }
     }
 
     /// Add an issue to the report
-    pub fn add_issue(&mut self, issue: ValidationIssue) {
+    ///
+    /// The issue's stable error code is resolved and recorded so serialized
+    /// reports always carry it.
+    pub fn add_issue(&mut self, mut issue: ValidationIssue) {
+        issue.error_code = Some(issue.error_code());
         match issue.severity {
             Severity::Error => {
                 self.valid = false;
@@ -164,9 +222,31 @@ impl ValidationReport {
             Severity::Warning => self.stats.warning_count += 1,
             Severity::Info => self.stats.info_count += 1,
         }
+        if issue.error_code == Some(ErrorCode::RecommendedMissing) {
+            self.stats.recommended_missing += 1;
+        }
         self.issues.push(issue);
     }
 
+    /// Recompute the `valid` flag and issue counts after issues were changed in place
+    pub fn recompute_stats(&mut self) {
+        self.stats.error_count = 0;
+        self.stats.warning_count = 0;
+        self.stats.info_count = 0;
+        self.stats.recommended_missing = 0;
+        for issue in &self.issues {
+            match issue.severity {
+                Severity::Error => self.stats.error_count += 1,
+                Severity::Warning => self.stats.warning_count += 1,
+                Severity::Info => self.stats.info_count += 1,
+            }
+            if issue.error_code() == ErrorCode::RecommendedMissing {
+                self.stats.recommended_missing += 1;
+            }
+        }
+        self.valid = self.stats.error_count == 0;
+    }
+
     /// Get all errors
     pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
         self.issues.iter().filter(|i| i.severity == Severity::Error)
@@ -182,7 +262,7 @@ impl ValidationReport {
     /// Get a summary of the validation
     #[must_use]
     pub fn summary(&self) -> String {
-        if self.valid {
+        let summary = if self.valid {
             format!(
                 "Validation passed with {} warnings",
                 self.stats.warning_count
@@ -192,6 +272,10 @@ impl ValidationReport {
                 "Validation failed with {} errors and {} warnings",
                 self.stats.error_count, self.stats.warning_count
             )
+        };
+        match self.stats.recommended_missing {
+            0 => summary,
+            missing => format!("{summary} ({missing} recommended slots missing)"),
         }
     }
 
@@ -209,6 +293,9 @@ impl ValidationReport {
 impl fmt::Display for ValidationReport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "{}", self.summary())?;
+        if let Some(version) = &self.schema_version {
+            writeln!(f, "Schema version: {version}")?;
+        }
         if !self.issues.is_empty() {
             writeln!(
                 f,
@@ -219,6 +306,21 @@ Issues:"
                 writeln!(f, "  {issue}")?;
             }
         }
+        if !self.stats.slowest_constraints.is_empty() {
+            writeln!(f, "\nSlowest constraints:")?;
+            for timing in &self.stats.slowest_constraints {
+                writeln!(
+                    f,
+                    "  {}.{} {}: {:.3} ms in {} checks (max {:.3} ms)",
+                    timing.class,
+                    timing.slot,
+                    timing.validator,
+                    timing.total_us as f64 / 1000.0,
+                    timing.calls,
+                    timing.max_us as f64 / 1000.0
+                )?;
+            }
+        }
         Ok(())
     }
 }
@@ -0,0 +1,359 @@
+//! Machine-readable exports of validation reports
+//!
+//! Validation reports can be serialized as SARIF 2.1.0 (consumed by GitHub
+//! code scanning and most static-analysis dashboards) and as JUnit XML
+//! (consumed by CI test reporters). Both formats take one or more reports
+//! paired with the artifact (data file) they were produced for, so a single
+//! CLI run over several files yields a single document.
+
+use super::report::{Severity, ValidationIssue, ValidationReport};
+use indexmap::IndexMap;
+use serde_json::{Value, json};
+use std::fmt::Write;
+
+/// SARIF schema location
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF format version
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// Tool name reported in SARIF and JUnit output
+const TOOL_NAME: &str = "linkml-validate";
+
+/// Rule identifier for an issue: its stable error code
+fn rule_id(issue: &ValidationIssue) -> &'static str {
+    issue.error_code().as_str()
+}
+
+/// SARIF `level` for a severity
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+/// Line and column recorded in an issue's context, if any
+fn issue_region(issue: &ValidationIssue) -> Value {
+    let line = issue
+        .context
+        .get("line")
+        .and_then(Value::as_u64)
+        .unwrap_or(1);
+    let mut region = json!({ "startLine": line });
+    if let Some(column) = issue.context.get("column").and_then(Value::as_u64) {
+        region["startColumn"] = json!(column);
+    }
+    region
+}
+
+/// Build a SARIF 2.1.0 log for reports produced for the given artifacts
+///
+/// Each issue becomes a result whose `ruleId` is the issue's stable error
+/// code (e.g. `LML1003`). The JSON path of the failing value is
+/// recorded as a logical location; a `line`/`column` in the issue context is
+/// used for the physical region (defaulting to line 1).
+#[must_use]
+pub fn to_sarif(reports: &[(&str, &ValidationReport)]) -> Value {
+    let mut rules: IndexMap<&'static str, Value> = IndexMap::new();
+    let mut results = Vec::new();
+
+    for (artifact, report) in reports {
+        for issue in &report.issues {
+            let id = rule_id(issue);
+            let entry = rules.entry(id);
+            let rule_index = entry.index();
+            entry.or_insert_with(|| {
+                let code = issue.error_code();
+                json!({
+                    "id": id,
+                    "name": code.name(),
+                    "shortDescription": { "text": code.summary() },
+                    "defaultConfiguration": { "level": sarif_level(issue.severity) },
+                })
+            });
+
+            let mut properties = json!({
+                "validator": issue.validator,
+                "schemaId": report.schema_id,
+            });
+            if let Some(code) = &issue.code {
+                properties["validatorCode"] = json!(code);
+            }
+            if let Some(target) = &report.target_class {
+                properties["targetClass"] = json!(target);
+            }
+            if !issue.context.is_empty() {
+                properties["context"] = json!(issue.context);
+            }
+
+            results.push(json!({
+                "ruleId": id,
+                "ruleIndex": rule_index,
+                "level": sarif_level(issue.severity),
+                "message": { "text": issue.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": artifact },
+                        "region": issue_region(issue),
+                    },
+                    "logicalLocations": [{
+                        "fullyQualifiedName": issue.path,
+                        "kind": "member",
+                    }],
+                }],
+                "properties": properties,
+            }));
+        }
+    }
+
+    let artifacts: Vec<Value> = reports
+        .iter()
+        .map(|(artifact, _)| json!({ "location": { "uri": artifact } }))
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": TOOL_NAME,
+                    "informationUri": "https://linkml.io",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules.into_values().collect::<Vec<_>>(),
+                }
+            },
+            "artifacts": artifacts,
+            "results": results,
+        }]
+    })
+}
+
+/// Build a JUnit XML document for reports produced for the given artifacts
+///
+/// Each artifact becomes a `<testsuite>`. Every error is a failing test case
+/// and every warning or info message a passing one carrying the message in
+/// `<system-out>`; a report without issues yields a single passing case.
+#[must_use]
+pub fn to_junit_xml(reports: &[(&str, &ValidationReport)]) -> String {
+    let total_tests: usize = reports
+        .iter()
+        .map(|(_, report)| report.issues.len().max(1))
+        .sum();
+    let total_failures: usize = reports
+        .iter()
+        .map(|(_, report)| report.errors().count())
+        .sum();
+    #[allow(clippy::cast_precision_loss)]
+    let total_time = reports
+        .iter()
+        .map(|(_, report)| report.stats.duration_ms)
+        .sum::<u64>() as f64
+        / 1000.0;
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    // Writing to a String cannot fail
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"{TOOL_NAME}\" tests=\"{total_tests}\" failures=\"{total_failures}\" errors=\"0\" time=\"{total_time:.3}\">"
+    );
+    for (artifact, report) in reports {
+        write_junit_suite(&mut xml, artifact, report);
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn write_junit_suite(xml: &mut String, artifact: &str, report: &ValidationReport) {
+    let artifact = escape_xml(artifact);
+    #[allow(clippy::cast_precision_loss)]
+    let time = report.stats.duration_ms as f64 / 1000.0;
+
+    let _ = writeln!(
+        xml,
+        "  <testsuite name=\"{artifact}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\" time=\"{time:.3}\">",
+        report.issues.len().max(1),
+        report.errors().count(),
+    );
+    let _ = writeln!(
+        xml,
+        "    <properties>\n      <property name=\"schema_id\" value=\"{}\"/>",
+        escape_xml(&report.schema_id)
+    );
+    if let Some(target) = &report.target_class {
+        let _ = writeln!(
+            xml,
+            "      <property name=\"target_class\" value=\"{}\"/>",
+            escape_xml(target)
+        );
+    }
+    xml.push_str("    </properties>\n");
+
+    if report.issues.is_empty() {
+        let _ = writeln!(
+            xml,
+            "    <testcase classname=\"{artifact}\" name=\"valid\"/>"
+        );
+    }
+
+    for issue in &report.issues {
+        let classname = escape_xml(&issue.validator);
+        let name = escape_xml(if issue.path.is_empty() {
+            "$"
+        } else {
+            &issue.path
+        });
+        let message = escape_xml(&issue.message);
+        let _ = writeln!(
+            xml,
+            "    <testcase classname=\"{classname}\" name=\"{name}\">"
+        );
+        if issue.severity == Severity::Error {
+            let _ = writeln!(
+                xml,
+                "      <failure message=\"{message}\" type=\"{}\">{}</failure>",
+                escape_xml(rule_id(issue)),
+                escape_xml(&issue.to_string())
+            );
+        } else {
+            let _ = writeln!(
+                xml,
+                "      <system-out>{}</system-out>",
+                escape_xml(&issue.to_string())
+            );
+        }
+        xml.push_str("    </testcase>\n");
+    }
+    xml.push_str("  </testsuite>\n");
+}
+
+/// Escape text for use in XML attributes and character data
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters other than tab/newline are not allowed in XML 1.0
+            c if c.is_control() && c != '\t' && c != '\n' && c != '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl ValidationReport {
+    /// Serialize this report as a SARIF 2.1.0 log for the given artifact (data file)
+    #[must_use]
+    pub fn to_sarif(&self, artifact: &str) -> Value {
+        to_sarif(&[(artifact, self)])
+    }
+
+    /// Serialize this report as JUnit XML for the given artifact (data file)
+    #[must_use]
+    pub fn to_junit_xml(&self, artifact: &str) -> String {
+        to_junit_xml(&[(artifact, self)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_report() -> ValidationReport {
+        let mut report = ValidationReport::new("person_schema");
+        report.target_class = Some("Person".to_string());
+        report.add_issue(
+            ValidationIssue::error("Missing required slot 'name'", "$.people[0]", "required")
+                .with_code("E_REQUIRED")
+                .with_context("line", json!(4))
+                .with_context("column", json!(7)),
+        );
+        report.add_issue(ValidationIssue::warning(
+            "Value <unknown> & unchecked",
+            "$.people[1].age",
+            "range",
+        ));
+        report
+    }
+
+    #[test]
+    fn test_sarif_structure() {
+        let report = create_test_report();
+        let sarif = report.to_sarif("data/people.yaml");
+
+        assert_eq!(sarif["version"], SARIF_VERSION);
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], TOOL_NAME);
+        assert_eq!(
+            run["tool"]["driver"]["rules"].as_array().map(Vec::len),
+            Some(2)
+        );
+
+        let results = run["results"].as_array().expect("results array");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "LML1003");
+        assert_eq!(results[0]["properties"]["validatorCode"], "E_REQUIRED");
+        assert_eq!(results[0]["level"], "error");
+        let location = &results[0]["locations"][0];
+        assert_eq!(
+            location["physicalLocation"]["artifactLocation"]["uri"],
+            "data/people.yaml"
+        );
+        assert_eq!(location["physicalLocation"]["region"]["startLine"], 4);
+        assert_eq!(location["physicalLocation"]["region"]["startColumn"], 7);
+        assert_eq!(
+            location["logicalLocations"][0]["fullyQualifiedName"],
+            "$.people[0]"
+        );
+        assert_eq!(results[1]["ruleId"], "LML1004");
+        assert_eq!(results[1]["ruleIndex"], 1);
+        assert_eq!(results[1]["level"], "warning");
+        assert_eq!(results[1]["properties"]["targetClass"], "Person");
+    }
+
+    #[test]
+    fn test_sarif_shares_rules_across_artifacts() {
+        let first = create_test_report();
+        let second = create_test_report();
+        let sarif = to_sarif(&[("a.yaml", &first), ("b.yaml", &second)]);
+
+        let run = &sarif["runs"][0];
+        assert_eq!(
+            run["tool"]["driver"]["rules"].as_array().map(Vec::len),
+            Some(2)
+        );
+        assert_eq!(run["results"].as_array().map(Vec::len), Some(4));
+        assert_eq!(run["artifacts"][1]["location"]["uri"], "b.yaml");
+    }
+
+    #[test]
+    fn test_junit_xml() {
+        let report = create_test_report();
+        let xml = report.to_junit_xml("people.yaml");
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(xml.contains("<testsuites name=\"linkml-validate\" tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("<testsuite name=\"people.yaml\" tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("<property name=\"target_class\" value=\"Person\"/>"));
+        assert!(xml.contains(
+            "<failure message=\"Missing required slot &apos;name&apos;\" type=\"LML1003\">"
+        ));
+        assert!(xml.contains("Value &lt;unknown&gt; &amp; unchecked"));
+        assert_eq!(xml.matches("<testcase ").count(), 2);
+    }
+
+    #[test]
+    fn test_junit_xml_valid_report() {
+        let report = ValidationReport::new("schema");
+        let xml = report.to_junit_xml("ok.json");
+
+        assert!(xml.contains("tests=\"1\" failures=\"0\""));
+        assert!(xml.contains("<testcase classname=\"ok.json\" name=\"valid\"/>"));
+    }
+}
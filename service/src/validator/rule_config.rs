@@ -0,0 +1,409 @@
+//! Per-rule severity configuration for validation
+//!
+//! A rule configuration lets users downgrade, upgrade or disable individual
+//! checks, globally, per class, or per schema, similar to lint rule
+//! configuration. This makes it possible to adopt validation incrementally on
+//! legacy data:
+//!
+//! ```yaml
+//! rules:
+//!   unknown_slot: warning       # stable code name
+//!   LML1005: info               # stable code
+//! classes:
+//!   LegacyPerson:
+//!     pattern: off              # validator name (`pattern_validator`)
+//! schemas:
+//!   https://example.org/legacy:
+//!     rules:
+//!       required: warning
+//! ```
+//!
+//! A rule key matches an issue by its stable error code (`LML1001`), code
+//! name (`pattern_mismatch`), validator name with or without the `_validator`
+//! suffix, or legacy validator code. `"*"` matches every issue.
+//!
+//! When several entries match, the most specific wins: schema class rules,
+//! then class rules, then schema rules, then global rules.
+
+use super::report::{Severity, ValidationIssue, ValidationReport};
+use indexmap::IndexMap;
+use linkml_core::error::{LinkMLError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Severity assigned to a rule by configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+    /// Drop matching issues entirely
+    #[serde(alias = "ignore", alias = "none", alias = "disabled")]
+    Off,
+    /// Report matching issues as informational messages
+    #[serde(alias = "note")]
+    Info,
+    /// Report matching issues as warnings
+    #[serde(alias = "warn")]
+    Warning,
+    /// Report matching issues as errors
+    Error,
+}
+
+impl RuleSeverity {
+    /// Report severity for this setting, `None` when the rule is disabled
+    #[must_use]
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            Self::Off => None,
+            Self::Info => Some(Severity::Info),
+            Self::Warning => Some(Severity::Warning),
+            Self::Error => Some(Severity::Error),
+        }
+    }
+}
+
+/// Rule severities, optionally refined per class
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleSet {
+    /// Rule key to severity
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub rules: IndexMap<String, RuleSeverity>,
+    /// Class name to rule overrides applying to instances of that class
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub classes: IndexMap<String, IndexMap<String, RuleSeverity>>,
+}
+
+impl RuleSet {
+    fn is_empty(&self) -> bool {
+        self.rules.is_empty() && self.classes.is_empty()
+    }
+
+    fn class_rule(&self, class: Option<&str>, issue: &ValidationIssue) -> Option<RuleSeverity> {
+        class_rule(&self.classes, class, issue)
+    }
+}
+
+/// Validation rule configuration
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ValidationRuleConfig {
+    /// Rule key to severity, for every schema and class
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub rules: IndexMap<String, RuleSeverity>,
+    /// Class name to rule overrides, for every schema
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub classes: IndexMap<String, IndexMap<String, RuleSeverity>>,
+    /// Overrides applying to one schema, keyed by schema id or name
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub schemas: IndexMap<String, RuleSet>,
+}
+
+impl ValidationRuleConfig {
+    /// Create an empty configuration
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a configuration from YAML (or JSON)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not a valid rule configuration
+    pub fn from_yaml_str(content: &str) -> Result<Self> {
+        serde_yaml::from_str(content)
+            .map_err(|e| LinkMLError::config(format!("Invalid validation rule configuration: {e}")))
+    }
+
+    /// Load a configuration file (YAML or JSON)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            LinkMLError::io_error(format!(
+                "Failed to read rule configuration {}: {e}",
+                path.display()
+            ))
+        })?;
+        Self::from_yaml_str(&content)
+    }
+
+    /// Set the severity of a rule for every class
+    #[must_use]
+    pub fn with_rule(mut self, rule: impl Into<String>, severity: RuleSeverity) -> Self {
+        self.rules.insert(rule.into(), severity);
+        self
+    }
+
+    /// Set the severity of a rule for instances of one class
+    #[must_use]
+    pub fn with_class_rule(
+        mut self,
+        class: impl Into<String>,
+        rule: impl Into<String>,
+        severity: RuleSeverity,
+    ) -> Self {
+        self.classes
+            .entry(class.into())
+            .or_default()
+            .insert(rule.into(), severity);
+        self
+    }
+
+    /// Set the rule overrides for one schema (by id or name)
+    #[must_use]
+    pub fn with_schema_rules(mut self, schema: impl Into<String>, rules: RuleSet) -> Self {
+        self.schemas.insert(schema.into(), rules);
+        self
+    }
+
+    /// Whether the configuration contains no overrides
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+            && self.classes.is_empty()
+            && self.schemas.values().all(RuleSet::is_empty)
+    }
+
+    /// Configured severity for an issue, if any rule matches
+    #[must_use]
+    pub fn severity_for(
+        &self,
+        issue: &ValidationIssue,
+        schema_id: &str,
+        class: Option<&str>,
+    ) -> Option<RuleSeverity> {
+        let schema = self.schemas.get(schema_id);
+        schema
+            .and_then(|rules| rules.class_rule(class, issue))
+            .or_else(|| class_rule(&self.classes, class, issue))
+            .or_else(|| schema.and_then(|rules| lookup(&rules.rules, issue)))
+            .or_else(|| lookup(&self.rules, issue))
+    }
+
+    /// Apply the configuration to every issue of a report
+    ///
+    /// Issues are attributed to the report's target class. Statistics and the
+    /// `valid` flag are recomputed afterwards.
+    pub fn apply(&self, report: &mut ValidationReport) {
+        let class = report.target_class.clone();
+        let schema_keys = [report.schema_id.clone()];
+        self.apply_from(report, 0, &schema_keys, class.as_deref());
+    }
+
+    /// Apply the configuration to the issues added since `start`
+    ///
+    /// `schema_keys` are the identifiers the schema can be configured under
+    /// (id and name).
+    pub(crate) fn apply_from(
+        &self,
+        report: &mut ValidationReport,
+        start: usize,
+        schema_keys: &[String],
+        class: Option<&str>,
+    ) {
+        if self.is_empty() || start >= report.issues.len() {
+            return;
+        }
+        let schema_id = schema_keys
+            .iter()
+            .find(|key| self.schemas.contains_key(key.as_str()))
+            .map_or("", String::as_str);
+
+        let issues = report.issues.split_off(start);
+        for mut issue in issues {
+            match self.severity_for(&issue, schema_id, class) {
+                None => report.issues.push(issue),
+                Some(configured) => {
+                    let Some(severity) = configured.severity() else {
+                        continue;
+                    };
+                    if severity != issue.severity {
+                        issue.context.insert(
+                            "original_severity".to_string(),
+                            serde_json::json!(issue.severity),
+                        );
+                        issue.severity = severity;
+                    }
+                    report.issues.push(issue);
+                }
+            }
+        }
+        report.recompute_stats();
+    }
+}
+
+/// Find the rule matching an issue in a rule map
+fn lookup(rules: &IndexMap<String, RuleSeverity>, issue: &ValidationIssue) -> Option<RuleSeverity> {
+    rules
+        .iter()
+        .find(|(key, _)| key.as_str() != "*" && rule_matches(key, issue))
+        .or_else(|| rules.get_key_value("*"))
+        .map(|(_, severity)| *severity)
+}
+
+/// Find the rule matching an issue among the overrides for a class
+fn class_rule(
+    classes: &IndexMap<String, IndexMap<String, RuleSeverity>>,
+    class: Option<&str>,
+    issue: &ValidationIssue,
+) -> Option<RuleSeverity> {
+    class
+        .and_then(|class| classes.get(class))
+        .and_then(|rules| lookup(rules, issue))
+}
+
+/// Whether a rule key selects an issue
+fn rule_matches(key: &str, issue: &ValidationIssue) -> bool {
+    let key = key.trim();
+    let code = issue.error_code();
+    code.as_str().eq_ignore_ascii_case(key)
+        || code.name().eq_ignore_ascii_case(key)
+        || issue.validator.eq_ignore_ascii_case(key)
+        || issue
+            .validator
+            .strip_suffix("_validator")
+            .is_some_and(|short| short.eq_ignore_ascii_case(key))
+        || issue
+            .code
+            .as_deref()
+            .is_some_and(|legacy| legacy.eq_ignore_ascii_case(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_report() -> ValidationReport {
+        let mut report = ValidationReport::new("https://example.org/people");
+        report.target_class = Some("Person".to_string());
+        report.add_issue(ValidationIssue::error(
+            "Value 'abc' does not match pattern",
+            "$.zip",
+            "pattern_validator",
+        ));
+        report.add_issue(ValidationIssue::error(
+            "Required slot 'name' is missing",
+            "$.name",
+            "required_validator",
+        ));
+        report.add_issue(
+            ValidationIssue::warning("Unknown slot 'nick'", "$.nick", "schema_validator")
+                .with_error_code(linkml_core::error_codes::ErrorCode::UnknownSlot),
+        );
+        report
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let config = ValidationRuleConfig::from_yaml_str(
+            r"
+rules:
+  unknown_slot: error
+  LML1005: warn
+classes:
+  LegacyPerson:
+    pattern: off
+schemas:
+  legacy:
+    rules:
+      required: info
+",
+        )
+        .expect("valid configuration");
+
+        assert_eq!(config.rules["unknown_slot"], RuleSeverity::Error);
+        assert_eq!(config.rules["LML1005"], RuleSeverity::Warning);
+        assert_eq!(config.classes["LegacyPerson"]["pattern"], RuleSeverity::Off);
+        assert_eq!(
+            config.schemas["legacy"].rules["required"],
+            RuleSeverity::Info
+        );
+
+        assert!(ValidationRuleConfig::from_yaml_str("rules:\n  pattern: loud\n").is_err());
+        assert!(ValidationRuleConfig::from_yaml_str("rulez: {}\n").is_err());
+    }
+
+    #[test]
+    fn test_rule_selectors() {
+        let issue = ValidationIssue::error("bad", "$.zip", "pattern_validator")
+            .with_code("STRUCTURED_PATTERN_VIOLATION");
+        for key in [
+            "LML1001",
+            "lml1001",
+            "pattern_mismatch",
+            "pattern_validator",
+            "pattern",
+            "STRUCTURED_PATTERN_VIOLATION",
+        ] {
+            assert!(rule_matches(key, &issue), "{key}");
+        }
+        assert!(!rule_matches("required", &issue));
+    }
+
+    #[test]
+    fn test_apply_overrides() {
+        let mut report = create_test_report();
+        let config = ValidationRuleConfig::new()
+            .with_rule("pattern", RuleSeverity::Warning)
+            .with_rule("unknown_slot", RuleSeverity::Error);
+        config.apply(&mut report);
+
+        assert!(!report.valid, "unknown slot upgraded to error");
+        assert_eq!(report.stats.error_count, 2);
+        assert_eq!(report.stats.warning_count, 1);
+        let pattern = &report.issues[0];
+        assert_eq!(pattern.severity, Severity::Warning);
+        assert_eq!(pattern.context["original_severity"], "Error");
+
+        let mut report = create_test_report();
+        ValidationRuleConfig::new()
+            .with_rule("*", RuleSeverity::Off)
+            .with_rule("unknown_slot", RuleSeverity::Info)
+            .apply(&mut report);
+        assert!(report.valid);
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.stats.info_count, 1);
+    }
+
+    #[test]
+    fn test_class_and_schema_precedence() {
+        let config = ValidationRuleConfig::new()
+            .with_rule("required", RuleSeverity::Warning)
+            .with_class_rule("Person", "required", RuleSeverity::Off)
+            .with_schema_rules(
+                "https://example.org/people",
+                RuleSet {
+                    rules: [("pattern".to_string(), RuleSeverity::Off)]
+                        .into_iter()
+                        .collect(),
+                    ..RuleSet::default()
+                },
+            );
+
+        let issue = ValidationIssue::error("missing", "$.name", "required_validator");
+        assert_eq!(
+            config.severity_for(&issue, "", Some("Person")),
+            Some(RuleSeverity::Off)
+        );
+        assert_eq!(
+            config.severity_for(&issue, "", Some("Organization")),
+            Some(RuleSeverity::Warning)
+        );
+
+        let mut report = create_test_report();
+        config.apply(&mut report);
+        assert!(
+            report.valid,
+            "pattern disabled for schema, required for class"
+        );
+        assert_eq!(report.issues.len(), 1);
+
+        let mut report = create_test_report();
+        report.schema_id = "other".to_string();
+        config.apply(&mut report);
+        assert!(!report.valid, "schema rules do not apply to other schemas");
+    }
+}
@@ -0,0 +1,357 @@
+//! Source locations of validated values
+//!
+//! Issues locate the failing value by `JSON` path. A [`SourceMap`] records
+//! where each path starts in the YAML or `JSON` text the instance was loaded
+//! from, so issues can be mapped back to a line and column and rendered with
+//! a snippet of the file. [`SourceMap::locate`] stores the position under the
+//! `line` and `column` context keys, which SARIF reports use as the region.
+//!
+//! Scalars are located at their value, collections at the key introducing
+//! them and sequence items at the item. A path without a recorded position,
+//! such as a missing required slot, is located at its nearest ancestor.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use linkml_core::error::{LinkMLError, Result};
+use serde_json::{Value, json};
+use yaml_rust2::parser::{Event, Parser};
+use yaml_rust2::scanner::Marker;
+
+use super::report::{ValidationIssue, ValidationReport};
+
+/// Context key holding the line of an issue
+pub const LINE_KEY: &str = "line";
+
+/// Context key holding the column of an issue
+pub const COLUMN_KEY: &str = "column";
+
+/// Position in a source file, both 1-based
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// Line number
+    pub line: usize,
+    /// Column number, in characters
+    pub column: usize,
+}
+
+impl SourceLocation {
+    /// Location recorded in an issue's context, if any
+    #[must_use]
+    pub fn of_issue(issue: &ValidationIssue) -> Option<Self> {
+        let position = |key| {
+            issue
+                .context
+                .get(key)
+                .and_then(Value::as_u64)
+                .and_then(|value| usize::try_from(value).ok())
+        };
+        Some(Self {
+            line: position(LINE_KEY)?,
+            column: position(COLUMN_KEY).unwrap_or(1),
+        })
+    }
+}
+
+impl From<Marker> for SourceLocation {
+    fn from(marker: Marker) -> Self {
+        // Lines are 1-based but columns count from 0
+        Self {
+            line: marker.line(),
+            column: marker.col() + 1,
+        }
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Node being read while building a source map
+enum Frame {
+    Mapping {
+        path: Option<String>,
+        /// Key of the entry whose value is read next
+        key: Option<(String, Marker)>,
+        expecting_value: bool,
+        /// Path to locate at the first key; block mappings only start there
+        unlocated: Option<String>,
+    },
+    Sequence {
+        path: Option<String>,
+        index: usize,
+    },
+}
+
+impl Frame {
+    /// Path and position of the node starting at `marker` inside this one;
+    /// `None` for mapping keys
+    fn child(&mut self, event: &Event, marker: Marker) -> Option<(String, Marker)> {
+        match self {
+            Self::Mapping {
+                path,
+                key,
+                expecting_value,
+                ..
+            } => {
+                if !*expecting_value {
+                    *key = match event {
+                        Event::Scalar(text, ..) => Some((text.clone(), marker)),
+                        _ => None,
+                    };
+                    return None;
+                }
+                let (name, key_marker) = key.as_ref()?;
+                let marker = if matches!(event, Event::Scalar(..) | Event::Alias(_)) {
+                    marker
+                } else {
+                    *key_marker
+                };
+                Some((format!("{}.{name}", path.as_ref()?), marker))
+            }
+            Self::Sequence { path, index } => {
+                Some((format!("{}[{index}]", path.as_ref()?), marker))
+            }
+        }
+    }
+
+    /// Advance past a node that has been read completely
+    fn finish_child(&mut self) {
+        match self {
+            Self::Mapping {
+                expecting_value, ..
+            } => *expecting_value = !*expecting_value,
+            Self::Sequence { index, .. } => *index += 1,
+        }
+    }
+}
+
+/// Positions of the values of a YAML or `JSON` document by `JSON` path
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    positions: HashMap<String, SourceLocation>,
+    lines: Vec<String>,
+}
+
+impl SourceMap {
+    /// Map the first document of YAML or `JSON` text
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is not well-formed YAML or `JSON`.
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut positions = HashMap::new();
+        let mut stack: Vec<Frame> = Vec::new();
+        let mut parser = Parser::new_from_str(content);
+        loop {
+            let (event, marker) = parser
+                .next_token()
+                .map_err(|err| LinkMLError::parse(format!("Failed to map source: {err}")))?;
+            match event {
+                Event::StreamEnd | Event::DocumentEnd => break,
+                Event::Scalar(..)
+                | Event::Alias(_)
+                | Event::MappingStart(..)
+                | Event::SequenceStart(..) => {
+                    if let Some(Frame::Mapping {
+                        unlocated: unlocated @ Some(_),
+                        ..
+                    }) = stack.last_mut()
+                        && let Some(path) = unlocated.take()
+                    {
+                        positions.insert(path, SourceLocation::from(marker));
+                    }
+                    let is_entry_value = matches!(
+                        stack.last(),
+                        Some(Frame::Mapping {
+                            expecting_value: true,
+                            ..
+                        })
+                    );
+                    let node = match stack.last_mut() {
+                        Some(parent) => parent.child(&event, marker),
+                        None => Some(("$".to_string(), marker)),
+                    };
+                    if let Some((path, marker)) = &node {
+                        positions.insert(path.clone(), SourceLocation::from(*marker));
+                    }
+                    let path = node.map(|(path, _)| path);
+                    match event {
+                        Event::MappingStart(..) => stack.push(Frame::Mapping {
+                            unlocated: path.clone().filter(|_| !is_entry_value),
+                            path,
+                            key: None,
+                            expecting_value: false,
+                        }),
+                        Event::SequenceStart(..) => {
+                            stack.push(Frame::Sequence { path, index: 0 });
+                        }
+                        _ => {
+                            if let Some(parent) = stack.last_mut() {
+                                parent.finish_child();
+                            }
+                        }
+                    }
+                }
+                Event::MappingEnd | Event::SequenceEnd => {
+                    stack.pop();
+                    if let Some(parent) = stack.last_mut() {
+                        parent.finish_child();
+                    }
+                }
+                Event::Nothing | Event::StreamStart | Event::DocumentStart => {}
+            }
+        }
+        Ok(Self {
+            positions,
+            lines: content.lines().map(str::to_string).collect(),
+        })
+    }
+
+    /// Position of the value at a `JSON` path, or of its nearest mapped
+    /// ancestor
+    #[must_use]
+    pub fn location(&self, path: &str) -> Option<SourceLocation> {
+        let mut path = if path.starts_with('$') {
+            path.to_string()
+        } else if path.is_empty() {
+            "$".to_string()
+        } else {
+            format!("$.{path}")
+        };
+        loop {
+            if let Some(location) = self.positions.get(&path) {
+                return Some(*location);
+            }
+            let end = path.rfind(['.', '['])?;
+            path.truncate(end);
+        }
+    }
+
+    /// Record the positions of the issues of a report in their context
+    ///
+    /// Issues that already carry a line keep it.
+    pub fn locate(&self, report: &mut ValidationReport) {
+        for issue in &mut report.issues {
+            if issue.context.contains_key(LINE_KEY) {
+                continue;
+            }
+            if let Some(location) = self.location(&issue.path) {
+                issue
+                    .context
+                    .insert(LINE_KEY.to_string(), json!(location.line));
+                issue
+                    .context
+                    .insert(COLUMN_KEY.to_string(), json!(location.column));
+            }
+        }
+    }
+
+    /// Source lines ending at a location, with line numbers and a caret
+    /// under its column
+    ///
+    /// `context` lines before the located one are included.
+    #[must_use]
+    pub fn snippet(&self, location: SourceLocation, context: usize) -> Vec<String> {
+        if location.line == 0 || location.line > self.lines.len() {
+            return Vec::new();
+        }
+        let first = location.line.saturating_sub(context).max(1);
+        let width = location.line.to_string().len();
+        let mut snippet: Vec<String> = (first..=location.line)
+            .map(|number| format!("{number:>width$} | {}", self.lines[number - 1]))
+            .collect();
+        snippet.push(format!(
+            "{:width$} | {:>column$}",
+            "",
+            "^",
+            column = location.column
+        ));
+        snippet
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: &str = "\
+id: P1
+name: Ada
+age: \"36\"
+tags:
+  - math
+  - poetry
+address:
+  city: London
+friends:
+  - id: P2
+    age: 40
+";
+
+    fn location(map: &SourceMap, path: &str) -> Option<(usize, usize)> {
+        map.location(path)
+            .map(|location| (location.line, location.column))
+    }
+
+    #[test]
+    fn test_yaml_locations() -> Result<()> {
+        let map = SourceMap::parse(DATA)?;
+        assert_eq!(location(&map, "$"), Some((1, 1)));
+        assert_eq!(location(&map, "$.age"), Some((3, 6)));
+        assert_eq!(location(&map, "$.tags"), Some((4, 1)));
+        assert_eq!(location(&map, "$.tags[1]"), Some((6, 5)));
+        assert_eq!(location(&map, "$.address"), Some((7, 1)));
+        assert_eq!(location(&map, "$.address.city"), Some((8, 9)));
+        assert_eq!(location(&map, "$.friends[0]"), Some((10, 5)));
+        assert_eq!(location(&map, "$.friends[0].age"), Some((11, 10)));
+
+        // Paths without a value fall back to their ancestor
+        assert_eq!(location(&map, "$.address.postcode"), Some((7, 1)));
+        assert_eq!(location(&map, "$.friends[0].name"), Some((10, 5)));
+        assert_eq!(location(&map, "name"), Some((2, 7)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_locations() -> Result<()> {
+        let map = SourceMap::parse("{\n  \"name\": \"Ada\",\n  \"tags\": [\"math\", 3]\n}\n")?;
+        assert_eq!(location(&map, "$.name"), Some((2, 11)));
+        assert_eq!(location(&map, "$.tags[1]"), Some((3, 20)));
+        assert!(SourceMap::parse("{\"name\": ").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_locate_and_snippet() -> Result<()> {
+        let map = SourceMap::parse(DATA)?;
+        let mut report = ValidationReport::new("people");
+        report.add_issue(ValidationIssue::error("Expected integer", "$.age", "type"));
+        let mut placed = ValidationIssue::error("Placed", "$.age", "type");
+        placed.context.insert(LINE_KEY.to_string(), json!(1));
+        report.add_issue(placed);
+        map.locate(&mut report);
+
+        let location = SourceLocation::of_issue(&report.issues[0]);
+        assert_eq!(location, Some(SourceLocation { line: 3, column: 6 }));
+        assert_eq!(
+            SourceLocation::of_issue(&report.issues[1]),
+            Some(SourceLocation { line: 1, column: 1 })
+        );
+
+        let snippet = location.map(|location| map.snippet(location, 1));
+        assert_eq!(
+            snippet.as_deref(),
+            Some(
+                &[
+                    "2 | name: Ada".to_string(),
+                    "3 | age: \"36\"".to_string(),
+                    "  |      ^".to_string(),
+                ][..]
+            )
+        );
+        Ok(())
+    }
+}
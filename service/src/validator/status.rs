@@ -0,0 +1,266 @@
+//! Status-based validation gating and deprecation warnings
+//!
+//! Instance data is checked for the classes, slots and enums it uses (see
+//! [`crate::schema_view::status`]): the instance class and its ancestors,
+//! every slot with a value and the enum ranges of those slots, recursing into
+//! nested objects.
+//!
+//! - With a [`DraftPolicy`] other than `include`, each use of an element
+//!   whose `status` is `draft` is reported, as a warning under `warn` and as
+//!   an error under `exclude`.
+//! - Each instance of a deprecated class and each value of a deprecated slot
+//!   is reported as a warning, naming the replacement to migrate to when the
+//!   schema gives one.
+
+use linkml_core::error_codes::ErrorCode;
+use linkml_core::types::SchemaDefinition;
+use serde_json::Value;
+
+use super::report::ValidationIssue;
+use crate::generator::base::{class_ancestry, induced_slots};
+use crate::schema_view::status::{Deprecation, DraftPolicy, is_draft};
+
+/// Validator name of issues reported for draft elements
+pub const STATUS_VALIDATOR: &str = "status_validator";
+
+/// Validator name of issues reported for deprecated elements
+pub const DEPRECATION_VALIDATOR: &str = "deprecation_validator";
+
+/// Uses of draft and deprecated elements by `data` as an instance of
+/// `class_name` found at `path`
+///
+/// Draft elements are not reported under [`DraftPolicy::Include`].
+pub(crate) fn status_issues(
+    schema: &SchemaDefinition,
+    class_name: &str,
+    data: &Value,
+    path: &str,
+    policy: DraftPolicy,
+) -> Vec<ValidationIssue> {
+    let mut checker = StatusChecker {
+        schema,
+        policy,
+        issues: Vec::new(),
+    };
+    checker.check_object(class_name, data, path);
+    checker.issues
+}
+
+struct StatusChecker<'a> {
+    schema: &'a SchemaDefinition,
+    policy: DraftPolicy,
+    issues: Vec<ValidationIssue>,
+}
+
+impl StatusChecker<'_> {
+    fn check_object(&mut self, class_name: &str, data: &Value, path: &str) {
+        let schema = self.schema;
+        if let Some(deprecation) = schema
+            .classes
+            .get(class_name)
+            .and_then(|class| Deprecation::of_class(class_name, class))
+        {
+            self.deprecated(&deprecation, path);
+        }
+        for ancestor in class_ancestry(schema, class_name) {
+            if schema
+                .classes
+                .get(&ancestor)
+                .is_some_and(|class| is_draft(class.status.as_ref()))
+            {
+                let message = if ancestor == class_name {
+                    format!("Class '{class_name}' is a draft")
+                } else {
+                    format!("Class '{class_name}' inherits from draft class '{ancestor}'")
+                };
+                self.draft(message, path, "class", &ancestor);
+            }
+        }
+
+        let Some(object) = data.as_object() else {
+            return;
+        };
+        for slot in induced_slots(schema, class_name) {
+            let Some(value) = object.get(&slot.name).filter(|value| !value.is_null()) else {
+                continue;
+            };
+            let path = format!("{path}.{}", slot.name);
+            if let Some(deprecation) = Deprecation::of_slot(&slot.name, &slot) {
+                self.deprecated(&deprecation, &path);
+            }
+            if is_draft(slot.status.as_ref()) {
+                let message = format!("Slot '{}' is a draft", slot.name);
+                self.draft(message, &path, "slot", &slot.name);
+            }
+
+            let Some(range) = slot.range.as_deref() else {
+                continue;
+            };
+            if schema
+                .enums
+                .get(range)
+                .is_some_and(|enum_def| is_draft(enum_def.status.as_ref()))
+            {
+                let message = format!("Enum '{range}' of slot '{}' is a draft", slot.name);
+                self.draft(message, &path, "enum", range);
+            } else if schema.classes.contains_key(range) {
+                match value {
+                    Value::Array(items) => {
+                        for (index, item) in items.iter().enumerate() {
+                            if item.is_object() {
+                                self.check_object(range, item, &format!("{path}[{index}]"));
+                            }
+                        }
+                    }
+                    Value::Object(_) => self.check_object(range, value, &path),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn draft(&mut self, message: String, path: &str, element_type: &str, element: &str) {
+        let issue = match self.policy {
+            DraftPolicy::Include => return,
+            DraftPolicy::Warn => ValidationIssue::warning(message, path, STATUS_VALIDATOR),
+            DraftPolicy::Exclude => ValidationIssue::error(message, path, STATUS_VALIDATOR),
+        };
+        self.issues.push(
+            issue
+                .with_error_code(ErrorCode::UnapprovedElement)
+                .with_context("element_type", Value::from(element_type))
+                .with_context("element", Value::from(element)),
+        );
+    }
+
+    fn deprecated(&mut self, deprecation: &Deprecation, path: &str) {
+        let mut issue =
+            ValidationIssue::warning(deprecation.to_string(), path, DEPRECATION_VALIDATOR)
+                .with_error_code(ErrorCode::DeprecatedElement)
+                .with_context("element", Value::from(deprecation.name.as_str()));
+        if let Some(replacement) = deprecation
+            .exact_replacement
+            .as_ref()
+            .or(deprecation.possible_replacement.as_ref())
+        {
+            issue = issue.with_context("replacement", Value::from(replacement.as_str()));
+        }
+        self.issues.push(issue);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::report::Severity;
+    use serde_json::json;
+
+    const SCHEMA: &str = "\
+id: https://example.org/people
+name: people
+classes:
+  Person:
+    slots: [name, pets]
+  Pet:
+    slots: [name, nickname, mood]
+  Dog:
+    is_a: Pet
+    status: draft
+slots:
+  name: {}
+  nickname:
+    status: draft
+  mood:
+    range: Mood
+  pets:
+    range: Dog
+    multivalued: true
+enums:
+  Mood:
+    status: draft
+    permissible_values: [happy]
+";
+
+    #[test]
+    fn test_draft_issues() {
+        let schema: SchemaDefinition = serde_yaml::from_str(SCHEMA).expect("valid schema");
+        let data = json!({
+            "name": "Ann",
+            "pets": [{"name": "Rex", "nickname": "R", "mood": "happy"}]
+        });
+
+        assert!(status_issues(&schema, "Person", &data, "$", DraftPolicy::Include).is_empty());
+
+        let issues = status_issues(&schema, "Person", &data, "$", DraftPolicy::Exclude);
+        let found: Vec<(&str, &str)> = issues
+            .iter()
+            .map(|issue| (issue.path.as_str(), issue.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("$.pets[0]", "Class 'Dog' is a draft"),
+                ("$.pets[0].nickname", "Slot 'nickname' is a draft"),
+                ("$.pets[0].mood", "Enum 'Mood' of slot 'mood' is a draft"),
+            ]
+        );
+        assert!(issues.iter().all(|issue| issue.severity == Severity::Error
+            && issue.error_code() == ErrorCode::UnapprovedElement));
+
+        let warnings = status_issues(&schema, "Person", &data, "$", DraftPolicy::Warn);
+        assert!(
+            warnings
+                .iter()
+                .all(|issue| issue.severity == Severity::Warning)
+        );
+    }
+
+    #[test]
+    fn test_deprecation_warnings() {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            "\
+id: https://example.org/people
+name: people
+classes:
+  Person:
+    deprecated: Superseded by Agent
+    deprecated_element_has_possible_replacement: Agent
+    slots: [name, age]
+slots:
+  name: {}
+  age:
+    deprecated: Ages go stale
+    deprecated_element_has_exact_replacement: birth_date
+  birth_date: {}
+",
+        )
+        .expect("valid schema");
+
+        let issues = status_issues(
+            &schema,
+            "Person",
+            &json!({"name": "Ann"}),
+            "$",
+            DraftPolicy::Include,
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].message,
+            "Class 'Person' is deprecated: Superseded by Agent; consider replacing it with 'Agent'"
+        );
+
+        let issues = status_issues(
+            &schema,
+            "Person",
+            &json!({"name": "Ann", "age": 42}),
+            "$",
+            DraftPolicy::Include,
+        );
+        let age = &issues[1];
+        assert_eq!(age.path, "$.age");
+        assert_eq!(age.severity, Severity::Warning);
+        assert_eq!(age.error_code(), ErrorCode::DeprecatedElement);
+        assert_eq!(age.context["replacement"], "birth_date");
+        assert!(age.message.ends_with("replace it with 'birth_date'"));
+    }
+}
@@ -0,0 +1,153 @@
+//! Near-miss suggestions for unknown names and values
+//!
+//! A value outside an enum, an undeclared slot or an unknown class name is
+//! usually a typo of a valid one. Candidates within the edit distance set by
+//! the `suggestion_distance` validation setting are offered as "did you
+//! mean" hints in the issue message and listed under the `suggestions`
+//! context key.
+
+use linkml_core::settings::DEFAULT_SUGGESTION_DISTANCE;
+use linkml_core::types::SchemaDefinition;
+use serde_json::json;
+
+use super::report::ValidationIssue;
+
+/// Number of suggestions offered for one value
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// Context key listing the suggestions of an issue
+pub const SUGGESTIONS_KEY: &str = "suggestions";
+
+/// Maximum edit distance of suggestions for values validated against a schema
+#[must_use]
+pub fn suggestion_distance(schema: &SchemaDefinition) -> usize {
+    schema
+        .settings
+        .as_ref()
+        .and_then(|settings| settings.validation.as_ref())
+        .map_or(DEFAULT_SUGGESTION_DISTANCE, |validation| {
+            validation.suggestion_distance()
+        })
+}
+
+/// Candidates within `max_distance` edits of `value`, closest first
+///
+/// Case is ignored, and a candidate needs fewer edits than it has
+/// characters, so short names do not match everything. At most
+/// [`MAX_SUGGESTIONS`] candidates are returned.
+pub fn near_misses<'c>(
+    value: &str,
+    candidates: impl IntoIterator<Item = &'c str>,
+    max_distance: usize,
+) -> Vec<&'c str> {
+    if max_distance == 0 {
+        return Vec::new();
+    }
+    let value = value.to_lowercase();
+    let mut scored: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let distance = strsim::levenshtein(&value, &candidate.to_lowercase());
+            let length = candidate.chars().count().max(value.chars().count());
+            (distance <= max_distance && distance < length).then_some((distance, candidate))
+        })
+        .collect();
+    scored.sort_unstable();
+    scored.dedup_by(|a, b| a.1 == b.1);
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// "did you mean" hint naming suggestions, if there are any
+#[must_use]
+pub fn did_you_mean(suggestions: &[&str]) -> Option<String> {
+    let quoted: Vec<String> = suggestions.iter().map(|name| format!("'{name}'")).collect();
+    match quoted.as_slice() {
+        [] => None,
+        [only] => Some(format!("did you mean {only}?")),
+        [rest @ .., last] => Some(format!("did you mean {} or {last}?", rest.join(", "))),
+    }
+}
+
+/// Append the suggestions for a value to an issue's message and context
+#[must_use]
+pub fn with_suggestions(mut issue: ValidationIssue, suggestions: &[&str]) -> ValidationIssue {
+    if let Some(hint) = did_you_mean(suggestions) {
+        issue.message = format!("{}; {hint}", issue.message);
+        issue
+            .context
+            .insert(SUGGESTIONS_KEY.to_string(), json!(suggestions));
+    }
+    issue
+}
+
+/// Message for a class name the schema does not define, suggesting
+/// similarly named classes
+#[must_use]
+pub fn unknown_class_message(schema: &SchemaDefinition, class_name: &str) -> String {
+    let suggestions = near_misses(
+        class_name,
+        schema.classes.keys().map(String::as_str),
+        suggestion_distance(schema),
+    );
+    match did_you_mean(&suggestions) {
+        Some(hint) => format!("Class '{class_name}' not found in schema; {hint}"),
+        None => format!("Class '{class_name}' not found in schema"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::ClassDefinition;
+
+    #[test]
+    fn test_near_misses() {
+        let statuses = ["active", "inactive", "retired", "deceased"];
+        assert_eq!(near_misses("activ", statuses, 2), ["active"]);
+        assert_eq!(near_misses("activ", statuses, 3), ["active", "inactive"]);
+        assert_eq!(near_misses("RETIRED", statuses, 2), ["retired"]);
+        assert!(near_misses("unknown", statuses, 2).is_empty());
+        assert!(near_misses("activ", statuses, 0).is_empty());
+
+        // Short names need fewer edits than their length
+        assert!(near_misses("x", ["id", "a"], 2).is_empty());
+    }
+
+    #[test]
+    fn test_hint_and_issue() {
+        assert_eq!(did_you_mean(&[]), None);
+        assert_eq!(
+            did_you_mean(&["Person", "Persona", "Personnel"]).as_deref(),
+            Some("did you mean 'Person', 'Persona' or 'Personnel'?")
+        );
+
+        let issue = with_suggestions(
+            ValidationIssue::error("Unknown slot 'nmae'", "$.nmae", "schema_validator"),
+            &["name"],
+        );
+        assert_eq!(issue.message, "Unknown slot 'nmae'; did you mean 'name'?");
+        assert_eq!(issue.context[SUGGESTIONS_KEY], json!(["name"]));
+    }
+
+    #[test]
+    fn test_unknown_class_message() {
+        let mut schema = SchemaDefinition::new("people");
+        for name in ["Person", "Organization"] {
+            schema
+                .classes
+                .insert(name.to_string(), ClassDefinition::new(name));
+        }
+        assert_eq!(
+            unknown_class_message(&schema, "Persn"),
+            "Class 'Persn' not found in schema; did you mean 'Person'?"
+        );
+        assert_eq!(
+            unknown_class_message(&schema, "Vehicle"),
+            "Class 'Vehicle' not found in schema"
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Validation time attributed to individual constraints
+//!
+//! With constraint timing enabled the engine measures every validator call on
+//! a slot. The slowest constraints are listed in the report statistics, so a
+//! pathological pattern shows up by name instead of as an unexplained slow
+//! run. A call exceeding the slot time budget has its errors downgraded to
+//! warnings and gets a diagnostic naming the constraint and its cost.
+
+use linkml_core::error_codes::ErrorCode;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::Duration;
+
+use super::report::{Severity, ValidationIssue};
+use crate::utils::safe_cast::u128_to_u64_saturating;
+
+/// Number of constraints listed in the slowest-constraints section of a report
+pub const SLOWEST_CONSTRAINTS: usize = 10;
+
+/// Validator name of the diagnostics for exceeded time budgets
+const TIME_BUDGET_VALIDATOR: &str = "time_budget";
+
+/// Time spent checking one constraint of a slot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConstraintTiming {
+    /// Class whose slot was checked
+    pub class: String,
+    /// Slot the constraint belongs to
+    pub slot: String,
+    /// Validator checking the constraint
+    pub validator: String,
+    /// Number of checked values
+    pub calls: u64,
+    /// Total time in microseconds
+    pub total_us: u64,
+    /// Slowest single check in microseconds
+    pub max_us: u64,
+    /// Checks that exceeded the slot time budget
+    #[serde(default)]
+    pub over_budget: u64,
+}
+
+impl ConstraintTiming {
+    /// Total time spent checking the constraint
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        Duration::from_micros(self.total_us)
+    }
+
+    fn same_constraint(&self, other: &Self) -> bool {
+        self.class == other.class && self.slot == other.slot && self.validator == other.validator
+    }
+}
+
+/// Record one check of `validator` on `slot` of `class` that took `elapsed`
+pub(crate) fn record(
+    timings: &mut Vec<ConstraintTiming>,
+    class: &str,
+    slot: &str,
+    validator: &str,
+    elapsed: Duration,
+    over_budget: bool,
+) {
+    let micros = u128_to_u64_saturating(elapsed.as_micros());
+    let timing = ConstraintTiming {
+        class: class.to_string(),
+        slot: slot.to_string(),
+        validator: validator.to_string(),
+        calls: 1,
+        total_us: micros,
+        max_us: micros,
+        over_budget: u64::from(over_budget),
+    };
+    merge(timings, vec![timing]);
+}
+
+/// Add timings measured by another run, e.g. of one instance of a collection
+pub(crate) fn merge(timings: &mut Vec<ConstraintTiming>, other: Vec<ConstraintTiming>) {
+    for timing in other {
+        match timings.iter_mut().find(|t| t.same_constraint(&timing)) {
+            Some(existing) => {
+                existing.calls += timing.calls;
+                existing.total_us = existing.total_us.saturating_add(timing.total_us);
+                existing.max_us = existing.max_us.max(timing.max_us);
+                existing.over_budget += timing.over_budget;
+            }
+            None => timings.push(timing),
+        }
+    }
+}
+
+/// Keep the `limit` constraints with the most total time, slowest first
+pub(crate) fn retain_slowest(timings: &mut Vec<ConstraintTiming>, limit: usize) {
+    timings.sort_by(|a, b| {
+        b.total_us
+            .cmp(&a.total_us)
+            .then_with(|| b.max_us.cmp(&a.max_us))
+            .then_with(|| (&a.class, &a.slot, &a.validator).cmp(&(&b.class, &b.slot, &b.validator)))
+    });
+    timings.truncate(limit);
+}
+
+/// Issues of a check that exceeded the time budget of its slot
+///
+/// Errors become warnings marked with `time_budget_exceeded`, and a warning
+/// naming the constraint, its duration and the budget is appended.
+pub(crate) fn over_budget_issues(
+    issues: Vec<ValidationIssue>,
+    slot: &str,
+    validator: &str,
+    path: String,
+    elapsed: Duration,
+    budget: Duration,
+) -> Vec<ValidationIssue> {
+    let mut issues: Vec<ValidationIssue> = issues
+        .into_iter()
+        .map(|mut issue| {
+            if issue.severity == Severity::Error {
+                issue.severity = Severity::Warning;
+                issue
+                    .context
+                    .insert("time_budget_exceeded".to_string(), json!(true));
+            }
+            issue
+        })
+        .collect();
+    let elapsed_ms = u128_to_u64_saturating(elapsed.as_millis());
+    let budget_ms = u128_to_u64_saturating(budget.as_millis());
+    issues.push(
+        ValidationIssue::warning(
+            format!(
+                "Constraint '{validator}' on slot '{slot}' took {elapsed_ms} ms, over the \
+                 {budget_ms} ms time budget; its errors were reported as warnings"
+            ),
+            path,
+            TIME_BUDGET_VALIDATOR,
+        )
+        .with_error_code(ErrorCode::TimeBudgetExceeded)
+        .with_context("slot", json!(slot))
+        .with_context("constraint", json!(validator))
+        .with_context("elapsed_ms", json!(elapsed_ms))
+        .with_context("budget_ms", json!(budget_ms)),
+    );
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timings_and_budget() {
+        let mut timings = Vec::new();
+        let mut other = Vec::new();
+        for (first_run, slot, validator, micros, over_budget) in [
+            (true, "email", "pattern_validator", 40_000, true),
+            (true, "name", "type_validator", 5, false),
+            (false, "email", "pattern_validator", 2_000, false),
+            (false, "age", "range_validator", 9, false),
+        ] {
+            let run = if first_run { &mut timings } else { &mut other };
+            let elapsed = Duration::from_micros(micros);
+            record(run, "Person", slot, validator, elapsed, over_budget);
+        }
+        merge(&mut timings, other);
+
+        retain_slowest(&mut timings, 2);
+        assert_eq!(timings.len(), 2);
+        assert_eq!(timings[0].slot, "email");
+        assert_eq!(timings[0].calls, 2);
+        assert_eq!(timings[0].total(), Duration::from_millis(42));
+        assert_eq!(timings[0].max_us, 40_000);
+        assert_eq!(timings[0].over_budget, 1);
+        assert_eq!(timings[1].slot, "age");
+
+        let issues = over_budget_issues(
+            vec![ValidationIssue::error("no match", "$.email", "pattern")],
+            "email",
+            "pattern_validator",
+            "$.email".to_string(),
+            Duration::from_millis(40),
+            Duration::from_millis(10),
+        );
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].severity, Severity::Warning);
+        assert_eq!(issues[0].context["time_budget_exceeded"], json!(true));
+        assert_eq!(issues[1].error_code(), ErrorCode::TimeBudgetExceeded);
+        let message = &issues[1].message;
+        assert!(message.contains("took 40 ms, over the 10 ms time budget"));
+    }
+}
@@ -52,7 +52,7 @@ impl Default for TtlConfig {
 impl TtlConfig {
     /// Create TTL config from `LinkML` service configuration
     #[must_use]
-    pub fn from_service_config(config: &linkml_core::configuration_v2::PerformanceConfig) -> Self {
+    pub fn from_service_config(config: &linkml_core::configuration::PerformanceConfig) -> Self {
         Self {
             l1_base_ttl: Duration::from_secs(config.cache_ttl_levels.l1_seconds),
             l2_base_ttl: Duration::from_secs(config.cache_ttl_levels.l2_seconds),
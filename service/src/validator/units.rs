@@ -0,0 +1,711 @@
+//! UCUM units of measure
+//!
+//! A slot with a `unit` holds quantities, and the `ucum_code` of the unit is
+//! the canonical unit of the slot. Values are accepted in three shapes:
+//!
+//! | Shape | Example | Unit |
+//! |-------|---------|------|
+//! | number | `12.5` | the canonical unit |
+//! | string | `"12.5 mg"` | after the whitespace |
+//! | object | `{"value": 12.5, "unit": "mg"}` | `unit` (or `has_unit`, with `has_numeric_value`) |
+//!
+//! [`Unit`] parses the case-sensitive UCUM syntax: prefixed atoms such as
+//! `mg` or `kPa`, exponents (`m2`, `s-1`), products and quotients
+//! (`kg.m/s2`, `/min`), parentheses, integer factors and powers of ten
+//! (`10*3/uL`), and `{annotations}`, which are dimensionless. Two units are
+//! commensurable when they reduce to the same powers of the UCUM base units,
+//! and values convert between commensurable units, including the offset
+//! scales `Cel` and `[degF]`. Arbitrary units such as `[IU]` only convert to
+//! multiples of themselves.
+//!
+//! The atom table covers the SI units, the customary units common in
+//! measurement data, and the clinical units; codes outside it are reported
+//! as unknown rather than guessed.
+
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::{SlotDefinition, UnitOfMeasure};
+use serde_json::{Map, Number, Value};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Powers of the UCUM base units: meter, second, gram, radian, kelvin,
+/// coulomb and candela
+type Dimensions = [i32; 7];
+
+/// Prefixes allowed on metric atoms, longest first where they overlap
+const PREFIXES: &[(&str, f64)] = &[
+    ("da", 1e1),
+    ("Ki", 1024.0),
+    ("Mi", 1_048_576.0),
+    ("Gi", 1_073_741_824.0),
+    ("Ti", 1_099_511_627_776.0),
+    ("Y", 1e24),
+    ("Z", 1e21),
+    ("E", 1e18),
+    ("P", 1e15),
+    ("T", 1e12),
+    ("G", 1e9),
+    ("M", 1e6),
+    ("k", 1e3),
+    ("h", 1e2),
+    ("d", 1e-1),
+    ("c", 1e-2),
+    ("m", 1e-3),
+    ("u", 1e-6),
+    ("n", 1e-9),
+    ("p", 1e-12),
+    ("f", 1e-15),
+    ("a", 1e-18),
+    ("z", 1e-21),
+    ("y", 1e-24),
+];
+
+/// Avogadro's number as defined by UCUM
+const AVOGADRO: f64 = 6.022_136_7e23;
+
+/// A unit atom of the UCUM table
+struct Atom {
+    code: &'static str,
+    /// Size in base units
+    factor: f64,
+    dimensions: Dimensions,
+    /// Whether the atom takes prefixes
+    metric: bool,
+    /// Offset added before scaling to the base unit (temperature scales)
+    offset: f64,
+    /// Whether the atom is an arbitrary unit, commensurable only with itself
+    arbitrary: bool,
+}
+
+const fn atom(code: &'static str, factor: f64, dimensions: Dimensions, metric: bool) -> Atom {
+    Atom {
+        code,
+        factor,
+        dimensions,
+        metric,
+        offset: 0.0,
+        arbitrary: false,
+    }
+}
+
+const fn arbitrary(code: &'static str, metric: bool) -> Atom {
+    Atom {
+        arbitrary: true,
+        ..atom(code, 1.0, NONE, metric)
+    }
+}
+
+const NONE: Dimensions = [0, 0, 0, 0, 0, 0, 0];
+const LENGTH: Dimensions = [1, 0, 0, 0, 0, 0, 0];
+const AREA: Dimensions = [2, 0, 0, 0, 0, 0, 0];
+const VOLUME: Dimensions = [3, 0, 0, 0, 0, 0, 0];
+const TIME: Dimensions = [0, 1, 0, 0, 0, 0, 0];
+const FREQUENCY: Dimensions = [0, -1, 0, 0, 0, 0, 0];
+const MASS: Dimensions = [0, 0, 1, 0, 0, 0, 0];
+const ANGLE: Dimensions = [0, 0, 0, 1, 0, 0, 0];
+const SOLID_ANGLE: Dimensions = [0, 0, 0, 2, 0, 0, 0];
+const TEMPERATURE: Dimensions = [0, 0, 0, 0, 1, 0, 0];
+const CHARGE: Dimensions = [0, 0, 0, 0, 0, 1, 0];
+const LUMINOUS_INTENSITY: Dimensions = [0, 0, 0, 0, 0, 0, 1];
+const FORCE: Dimensions = [1, -2, 1, 0, 0, 0, 0];
+const PRESSURE: Dimensions = [-1, -2, 1, 0, 0, 0, 0];
+const ENERGY: Dimensions = [2, -2, 1, 0, 0, 0, 0];
+const POWER: Dimensions = [2, -3, 1, 0, 0, 0, 0];
+const CURRENT: Dimensions = [0, -1, 0, 0, 0, 1, 0];
+const VOLTAGE: Dimensions = [2, -2, 1, 0, 0, -1, 0];
+const CAPACITANCE: Dimensions = [-2, 2, -1, 0, 0, 2, 0];
+const RESISTANCE: Dimensions = [2, -1, 1, 0, 0, -2, 0];
+const CONDUCTANCE: Dimensions = [-2, 1, -1, 0, 0, 2, 0];
+const MAGNETIC_FLUX: Dimensions = [2, -1, 1, 0, 0, -1, 0];
+const FLUX_DENSITY: Dimensions = [0, -1, 1, 0, 0, -1, 0];
+const INDUCTANCE: Dimensions = [2, 0, 1, 0, 0, -2, 0];
+const LUMINOUS_FLUX: Dimensions = [0, 0, 0, 2, 0, 0, 1];
+const ILLUMINANCE: Dimensions = [-2, 0, 0, 2, 0, 0, 1];
+const DOSE: Dimensions = [2, -2, 0, 0, 0, 0, 0];
+
+/// The UCUM atoms known to the parser
+const ATOMS: &[Atom] = &[
+    // Base units
+    atom("m", 1.0, LENGTH, true),
+    atom("s", 1.0, TIME, true),
+    atom("g", 1.0, MASS, true),
+    atom("rad", 1.0, ANGLE, true),
+    atom("K", 1.0, TEMPERATURE, true),
+    atom("C", 1.0, CHARGE, true),
+    atom("cd", 1.0, LUMINOUS_INTENSITY, true),
+    // Dimensionless
+    atom("10*", 10.0, NONE, false),
+    atom("10^", 10.0, NONE, false),
+    atom("%", 1e-2, NONE, false),
+    atom("[ppth]", 1e-3, NONE, false),
+    atom("[ppm]", 1e-6, NONE, false),
+    atom("[ppb]", 1e-9, NONE, false),
+    atom("[pptr]", 1e-12, NONE, false),
+    atom("mol", AVOGADRO, NONE, true),
+    atom("sr", 1.0, SOLID_ANGLE, true),
+    atom("deg", std::f64::consts::PI / 180.0, ANGLE, false),
+    atom("gon", std::f64::consts::PI / 200.0, ANGLE, false),
+    // Derived SI units
+    atom("Hz", 1.0, FREQUENCY, true),
+    atom("N", 1e3, FORCE, true),
+    atom("Pa", 1e3, PRESSURE, true),
+    atom("J", 1e3, ENERGY, true),
+    atom("W", 1e3, POWER, true),
+    atom("A", 1.0, CURRENT, true),
+    atom("V", 1e3, VOLTAGE, true),
+    atom("F", 1e-3, CAPACITANCE, true),
+    atom("Ohm", 1e3, RESISTANCE, true),
+    atom("S", 1e-3, CONDUCTANCE, true),
+    atom("Wb", 1e3, MAGNETIC_FLUX, true),
+    atom("T", 1e3, FLUX_DENSITY, true),
+    atom("H", 1e3, INDUCTANCE, true),
+    atom("lm", 1.0, LUMINOUS_FLUX, true),
+    atom("lx", 1.0, ILLUMINANCE, true),
+    atom("Bq", 1.0, FREQUENCY, true),
+    atom("Gy", 1.0, DOSE, true),
+    atom("Sv", 1.0, DOSE, true),
+    atom("kat", AVOGADRO, FREQUENCY, true),
+    atom("U", AVOGADRO * 1e-6 / 60.0, FREQUENCY, true),
+    Atom {
+        offset: 273.15,
+        ..atom("Cel", 1.0, TEMPERATURE, true)
+    },
+    // Units accepted alongside SI
+    atom("l", 1e-3, VOLUME, true),
+    atom("L", 1e-3, VOLUME, true),
+    atom("ar", 1e2, AREA, true),
+    atom("t", 1e6, MASS, true),
+    atom("u", 1.660_540_2e-24, MASS, true),
+    atom("eV", 1.602_177_33e-16, ENERGY, true),
+    atom("bar", 1e8, PRESSURE, true),
+    atom("min", 60.0, TIME, false),
+    atom("h", 3600.0, TIME, false),
+    atom("d", 86_400.0, TIME, false),
+    atom("wk", 604_800.0, TIME, false),
+    atom("mo", 2_629_800.0, TIME, false),
+    atom("a", 31_557_600.0, TIME, false),
+    atom("atm", 1.013_25e8, PRESSURE, false),
+    atom("m[Hg]", 1.333_22e8, PRESSURE, true),
+    atom("m[H2O]", 9.806_65e6, PRESSURE, true),
+    atom("cal", 4184.0, ENERGY, true),
+    atom("[Cal]", 4_184_000.0, ENERGY, false),
+    // Customary units
+    atom("[in_i]", 0.0254, LENGTH, false),
+    atom("[ft_i]", 0.3048, LENGTH, false),
+    atom("[yd_i]", 0.9144, LENGTH, false),
+    atom("[mi_i]", 1609.344, LENGTH, false),
+    atom("[nmi_i]", 1852.0, LENGTH, false),
+    atom("[lb_av]", 453.592_37, MASS, false),
+    atom("[oz_av]", 28.349_523_125, MASS, false),
+    atom("[gal_us]", 3.785_411_784e-3, VOLUME, false),
+    atom("[qt_us]", 9.463_529_46e-4, VOLUME, false),
+    atom("[foz_us]", 2.957_352_956_25e-5, VOLUME, false),
+    atom("[psi]", 6.894_757_293_168e6, PRESSURE, false),
+    Atom {
+        offset: 459.67,
+        ..atom("[degF]", 5.0 / 9.0, TEMPERATURE, false)
+    },
+    // Information
+    atom("bit", 1.0, NONE, true),
+    atom("By", 8.0, NONE, true),
+    // Arbitrary units
+    arbitrary("[IU]", true),
+    arbitrary("[iU]", true),
+    arbitrary("[arb'U]", false),
+    arbitrary("[CFU]", false),
+    arbitrary("[pH]", false),
+];
+
+/// A parsed UCUM unit
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unit {
+    code: String,
+    factor: f64,
+    offset: f64,
+    dimensions: Dimensions,
+    arbitrary: BTreeMap<&'static str, i32>,
+}
+
+impl Unit {
+    /// Parse a UCUM code
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code is not valid UCUM or uses an atom that
+    /// is not in the table.
+    pub fn parse(code: &str) -> Result<Self> {
+        let mut parser = Parser {
+            code,
+            bytes: code.as_bytes(),
+            position: 0,
+        };
+        let mut unit = parser.main_term()?;
+        unit.code = code.to_string();
+        Ok(unit)
+    }
+
+    /// The code the unit was parsed from
+    #[must_use]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Whether values convert between this unit and `other`
+    #[must_use]
+    pub fn is_commensurable(&self, other: &Self) -> bool {
+        self.dimensions == other.dimensions && self.arbitrary == other.arbitrary
+    }
+
+    /// Convert `value` from this unit to `to`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the units are not commensurable.
+    pub fn convert(&self, value: f64, to: &Self) -> Result<f64> {
+        if !self.is_commensurable(to) {
+            return Err(LinkMLError::data_validation(format!(
+                "Unit '{}' cannot be converted to '{}'",
+                self.code, to.code
+            )));
+        }
+        let base = (value + self.offset) * self.factor;
+        Ok(round(base / to.factor - to.offset))
+    }
+
+    fn one() -> Self {
+        Self {
+            code: String::new(),
+            factor: 1.0,
+            offset: 0.0,
+            dimensions: NONE,
+            arbitrary: BTreeMap::new(),
+        }
+    }
+
+    fn from_atom(atom: &Atom, prefix: f64) -> Self {
+        let mut arbitrary = BTreeMap::new();
+        if atom.arbitrary {
+            arbitrary.insert(atom.code, 1);
+        }
+        Self {
+            code: String::new(),
+            factor: atom.factor * prefix,
+            offset: atom.offset,
+            dimensions: atom.dimensions,
+            arbitrary,
+        }
+    }
+
+    fn multiply(mut self, other: &Self, exponent: i32) -> Result<Self> {
+        if self.offset != 0.0 || other.offset != 0.0 {
+            return Err(LinkMLError::data_validation(
+                "Units on an offset scale (Cel, [degF]) cannot be combined with other units",
+            ));
+        }
+        self.factor *= other.factor.powi(exponent);
+        for (dimension, power) in self.dimensions.iter_mut().zip(other.dimensions) {
+            *dimension += power * exponent;
+        }
+        for (atom, power) in &other.arbitrary {
+            let entry = self.arbitrary.entry(atom).or_insert(0);
+            *entry += power * exponent;
+            if *entry == 0 {
+                self.arbitrary.remove(atom);
+            }
+        }
+        Ok(self)
+    }
+
+    fn pow(self, exponent: i32) -> Result<Self> {
+        if exponent == 1 {
+            return Ok(self);
+        }
+        Self::one().multiply(&self, exponent)
+    }
+}
+
+impl FromStr for Unit {
+    type Err = LinkMLError;
+
+    fn from_str(code: &str) -> Result<Self> {
+        Self::parse(code)
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.code)
+    }
+}
+
+/// Recursive-descent parser of UCUM codes
+struct Parser<'a> {
+    code: &'a str,
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn main_term(&mut self) -> Result<Unit> {
+        if self.bytes.is_empty() {
+            return Err(self.error("empty unit"));
+        }
+        let unit = if self.peek() == Some(b'/') {
+            self.position += 1;
+            Unit::one().multiply(&self.term()?, -1)?
+        } else {
+            self.term()?
+        };
+        match self.peek() {
+            None => Ok(unit),
+            Some(byte) => Err(self.error(&format!("unexpected '{}'", char::from(byte)))),
+        }
+    }
+
+    fn term(&mut self) -> Result<Unit> {
+        let mut unit = self.component()?;
+        loop {
+            match self.peek() {
+                Some(b'.') => {
+                    self.position += 1;
+                    unit = unit.multiply(&self.component()?, 1)?;
+                }
+                Some(b'/') => {
+                    self.position += 1;
+                    unit = unit.multiply(&self.component()?, -1)?;
+                }
+                _ => return Ok(unit),
+            }
+        }
+    }
+
+    fn component(&mut self) -> Result<Unit> {
+        match self.peek() {
+            Some(b'(') => {
+                self.position += 1;
+                let unit = self.term()?;
+                if self.peek() != Some(b')') {
+                    return Err(self.error("missing ')'"));
+                }
+                self.position += 1;
+                self.annotation()?;
+                Ok(unit)
+            }
+            Some(b'{') => {
+                self.annotation()?;
+                Ok(Unit::one())
+            }
+            _ => {
+                let start = self.position;
+                while let Some(byte) = self.peek() {
+                    match byte {
+                        b'.' | b'/' | b'(' | b')' | b'{' | b'}' => break,
+                        b'[' => {
+                            let close = self.bytes[self.position..]
+                                .iter()
+                                .position(|&b| b == b']')
+                                .ok_or_else(|| self.error("missing ']'"))?;
+                            self.position += close + 1;
+                        }
+                        byte if byte.is_ascii_graphic() => self.position += 1,
+                        _ => return Err(self.error("whitespace or non-ASCII character")),
+                    }
+                }
+                if start == self.position {
+                    return Err(self.error("missing unit"));
+                }
+                let unit = self.simple_unit(&self.code[start..self.position])?;
+                self.annotation()?;
+                Ok(unit)
+            }
+        }
+    }
+
+    /// An integer factor or an atom with optional prefix and exponent
+    fn simple_unit(&self, symbol: &str) -> Result<Unit> {
+        if symbol.bytes().all(|b| b.is_ascii_digit()) {
+            let factor: f64 = symbol
+                .parse()
+                .map_err(|_| self.error(&format!("invalid factor '{symbol}'")))?;
+            return Ok(Unit {
+                factor,
+                ..Unit::one()
+            });
+        }
+
+        let digits = symbol.len() - symbol.bytes().rev().take_while(u8::is_ascii_digit).count();
+        let mut split = digits;
+        if digits < symbol.len()
+            && digits > 0
+            && matches!(symbol.as_bytes()[digits - 1], b'+' | b'-')
+        {
+            split -= 1;
+        }
+        let (base, exponent) = if split == 0 || digits == symbol.len() {
+            (symbol, 1)
+        } else {
+            let exponent = symbol[split..]
+                .parse()
+                .map_err(|_| self.error(&format!("invalid exponent in '{symbol}'")))?;
+            (&symbol[..split], exponent)
+        };
+
+        let unit = lookup(base).ok_or_else(|| self.error(&format!("unknown unit '{base}'")))?;
+        unit.pow(exponent)
+    }
+
+    /// Skip an optional `{annotation}`
+    fn annotation(&mut self) -> Result<()> {
+        if self.peek() != Some(b'{') {
+            return Ok(());
+        }
+        let close = self.bytes[self.position..]
+            .iter()
+            .position(|&b| b == b'}')
+            .ok_or_else(|| self.error("missing '}'"))?;
+        self.position += close + 1;
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn error(&self, message: &str) -> LinkMLError {
+        LinkMLError::data_validation(format!("Invalid UCUM code '{}': {message}", self.code))
+    }
+}
+
+/// Unit of an atom, optionally prefixed
+fn lookup(symbol: &str) -> Option<Unit> {
+    if let Some(atom) = ATOMS.iter().find(|atom| atom.code == symbol) {
+        return Some(Unit::from_atom(atom, 1.0));
+    }
+    PREFIXES.iter().find_map(|(prefix, factor)| {
+        let rest = symbol.strip_prefix(prefix)?;
+        let atom = ATOMS.iter().find(|atom| atom.metric && atom.code == rest)?;
+        Some(Unit::from_atom(atom, *factor))
+    })
+}
+
+/// Round away floating-point noise from conversions
+fn round(value: f64) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    format!("{value:.12e}").parse().unwrap_or(value)
+}
+
+/// Canonical UCUM code of a slot, if it has a unit
+#[must_use]
+pub fn slot_unit(slot: &SlotDefinition) -> Option<&str> {
+    slot.unit.as_ref().and_then(UnitOfMeasure::code)
+}
+
+/// A numeric value with an optional unit
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quantity {
+    /// Magnitude
+    pub value: f64,
+    /// UCUM code; `None` means the canonical unit of the slot
+    pub unit: Option<String>,
+}
+
+impl Quantity {
+    /// Read a quantity from a number, a `"12.5 mg"` string or a
+    /// `{"value", "unit"}` object
+    ///
+    /// Returns `None` for values of any other shape.
+    #[must_use]
+    pub fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Number(number) => Some(Self {
+                value: number.as_f64()?,
+                unit: None,
+            }),
+            Value::String(text) => {
+                let text = text.trim();
+                if let Ok(value) = text.parse() {
+                    return Some(Self { value, unit: None });
+                }
+                let (number, unit) = text.split_once(char::is_whitespace)?;
+                Some(Self {
+                    value: number.parse().ok()?,
+                    unit: Some(unit.trim().to_string()),
+                })
+            }
+            Value::Object(object) => {
+                let number = object
+                    .get("value")
+                    .or_else(|| object.get("has_numeric_value"))?;
+                let value = match number {
+                    Value::Number(number) => number.as_f64()?,
+                    Value::String(text) => text.trim().parse().ok()?,
+                    _ => return None,
+                };
+                let unit = match object.get("unit").or_else(|| object.get("has_unit")) {
+                    None | Some(Value::Null) => None,
+                    Some(Value::String(unit)) => Some(unit.trim().to_string()),
+                    Some(_) => return None,
+                };
+                Some(Self { value, unit })
+            }
+            _ => None,
+        }
+    }
+
+    /// Parsed unit of the quantity, or `canonical` if it has none
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the unit is not valid UCUM.
+    pub fn unit_or(&self, canonical: &Unit) -> Result<Unit> {
+        match &self.unit {
+            Some(code) => Unit::parse(code),
+            None => Ok(canonical.clone()),
+        }
+    }
+}
+
+/// `value` converted to the `canonical` unit, if it carries another unit
+///
+/// Strings and objects keep their shape with the canonical unit; with
+/// `bare` the result is a plain number instead, for slots with a numeric
+/// range.
+///
+/// # Errors
+///
+/// Returns an error if the unit of the value is invalid or not
+/// commensurable with `canonical`.
+pub fn to_canonical(value: &Value, canonical: &Unit, bare: bool) -> Result<Option<Value>> {
+    let Some(quantity) = Quantity::from_value(value) else {
+        return Ok(None);
+    };
+    let Some(code) = &quantity.unit else {
+        return Ok(None);
+    };
+    let converted = if code == canonical.code() {
+        quantity.value
+    } else {
+        Unit::parse(code)?.convert(quantity.value, canonical)?
+    };
+    if code == canonical.code() && !bare {
+        return Ok(None);
+    }
+
+    let Some(number) = Number::from_f64(converted).map(Value::Number) else {
+        return Ok(None);
+    };
+    Ok(match value {
+        _ if bare => Some(number),
+        Value::String(_) => Some(Value::String(format!("{converted} {canonical}"))),
+        Value::Object(object) => {
+            let mut object: Map<String, Value> = object.clone();
+            let value_key = if object.contains_key("value") {
+                "value"
+            } else {
+                "has_numeric_value"
+            };
+            let unit_key = if object.contains_key("has_unit") {
+                "has_unit"
+            } else {
+                "unit"
+            };
+            object.insert(value_key.to_string(), number);
+            object.insert(unit_key.to_string(), Value::String(canonical.to_string()));
+            Some(Value::Object(object))
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn unit(code: &str) -> Unit {
+        Unit::parse(code).expect(code)
+    }
+
+    #[test]
+    fn test_parse_and_convert() {
+        let mg = unit("mg");
+        let g = unit("g");
+        assert!((mg.convert(12.5, &g).expect("commensurable") - 0.0125).abs() < f64::EPSILON);
+
+        let speed = unit("km/h");
+        let base = unit("m.s-1");
+        assert!(speed.is_commensurable(&base));
+        assert!((speed.convert(36.0, &base).expect("commensurable") - 10.0).abs() < 1e-9);
+        assert!(unit("kg.m/s2").is_commensurable(&unit("N")));
+        assert!(unit("mg/dL").is_commensurable(&unit("g/L")));
+        assert!(unit("10*3/uL").is_commensurable(&unit("/L")));
+        assert!(unit("mm[Hg]").is_commensurable(&unit("kPa")));
+        assert!(Unit::parse("{cells}/mL").is_ok());
+        assert!(!mg.is_commensurable(&unit("mL")));
+        assert!(mg.convert(1.0, &unit("mL")).is_err());
+
+        let celsius = unit("Cel");
+        let fahrenheit = unit("[degF]");
+        let kelvin = unit("K");
+        assert!((celsius.convert(100.0, &fahrenheit).expect("commensurable") - 212.0).abs() < 1e-9);
+        assert!((celsius.convert(0.0, &kelvin).expect("commensurable") - 273.15).abs() < 1e-9);
+        assert!(Unit::parse("Cel/h").is_err());
+
+        let iu = unit("[IU]/L");
+        assert!(iu.is_commensurable(&unit("m[IU]/mL")));
+        assert!(!iu.is_commensurable(&unit("/L")));
+
+        for invalid in ["", "mg /dL", "foo", "m/(s", "[in_i", "kmin"] {
+            assert!(Unit::parse(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_quantities() {
+        assert_eq!(
+            Quantity::from_value(&json!("12.5 mg")),
+            Some(Quantity {
+                value: 12.5,
+                unit: Some("mg".to_string())
+            })
+        );
+        assert_eq!(
+            Quantity::from_value(&json!(3)).expect("quantity").unit,
+            None
+        );
+        assert_eq!(
+            Quantity::from_value(&json!({"has_numeric_value": 2, "has_unit": "kg"}))
+                .expect("quantity")
+                .unit
+                .as_deref(),
+            Some("kg")
+        );
+        assert_eq!(Quantity::from_value(&json!("heavy")), None);
+
+        let g = unit("g");
+        assert_eq!(
+            to_canonical(&json!("1500 mg"), &g, false).expect("convertible"),
+            Some(json!("1.5 g"))
+        );
+        assert_eq!(
+            to_canonical(&json!({"value": 2, "unit": "kg"}), &g, false).expect("convertible"),
+            Some(json!({"value": 2000.0, "unit": "g"}))
+        );
+        assert_eq!(
+            to_canonical(&json!("2 g"), &g, true).expect("convertible"),
+            Some(json!(2.0))
+        );
+        assert_eq!(
+            to_canonical(&json!("2 g"), &g, false).expect("convertible"),
+            None
+        );
+        assert_eq!(
+            to_canonical(&json!(2), &g, true).expect("convertible"),
+            None
+        );
+        assert!(to_canonical(&json!("2 mL"), &g, false).is_err());
+    }
+}
@@ -3,7 +3,10 @@
 use super::utils::value_type;
 use super::{ValidationContext, ValidationIssue, Validator};
 use crate::utils::safe_cast::u64_to_f64_lossy;
+use crate::validator::dynamic_enum::{DynamicEnumResolver, OntologyProvider};
+use crate::validator::suggest;
 use linkml_core::annotations::AnnotationValue;
+use linkml_core::settings::ValidationSettings;
 use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
 use serde_json::Value;
 use std::collections::{HashMap, HashSet};
@@ -120,9 +123,13 @@ impl Validator for MultivaluedValidator {
 }
 
 /// Validator for permissible values (enums)
+///
+/// Values of dynamic enums (`reachable_from`, `matches`, `concepts`) are
+/// checked with a [`DynamicEnumResolver`].
 pub struct PermissibleValueValidator {
     name: String,
     schema: SchemaDefinition,
+    dynamic: DynamicEnumResolver,
 }
 
 impl PermissibleValueValidator {
@@ -135,21 +142,82 @@ impl PermissibleValueValidator {
         Ok(Self {
             name: "permissible_value_validator".to_string(),
             schema: schema.clone(),
+            dynamic: DynamicEnumResolver::new(),
         })
     }
 
-    fn get_enum_values(&self, enum_name: &str) -> Option<HashSet<String>> {
-        self.schema.enums.get(enum_name).map(|enum_def| {
-            enum_def
-                .permissible_values
-                .iter()
-                .map(|pv| match pv {
-                    linkml_core::types::PermissibleValue::Simple(s) => s.clone(),
-                    linkml_core::types::PermissibleValue::Complex { text, .. } => text.clone(),
-                })
-                .collect()
+    /// Create a permissible value validator that evaluates `reachable_from`
+    /// queries with an ontology provider
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the schema is invalid
+    pub fn with_ontology_provider(
+        schema: &SchemaDefinition,
+        provider: Arc<dyn OntologyProvider>,
+    ) -> Result<Self, linkml_core::error::LinkMLError> {
+        Ok(Self {
+            dynamic: DynamicEnumResolver::with_provider(provider),
+            ..Self::new(schema)?
         })
     }
+
+    fn get_enum_values(&self, enum_name: &str) -> Option<HashSet<String>> {
+        let include_descendants = self
+            .schema
+            .settings
+            .as_ref()
+            .and_then(|settings| settings.validation.as_ref())
+            .is_some_and(ValidationSettings::accepts_descendant_values);
+        self.schema.enum_values(enum_name, include_descendants)
+    }
+
+    fn check_value(
+        &self,
+        enum_name: &str,
+        enum_values: &HashSet<String>,
+        value: &Value,
+        path: &str,
+    ) -> Option<ValidationIssue> {
+        let Some(s) = value.as_str() else {
+            return (!value.is_null())
+                .then(|| ValidationIssue::error("Enum value must be a string", path, &self.name));
+        };
+        if enum_values.contains(s) {
+            return None;
+        }
+        let enum_def = self.schema.enums.get(enum_name)?;
+        if !enum_def.is_dynamic() {
+            let issue = ValidationIssue::error(
+                format!(
+                    "Value '{}' is not in permissible values: {:?}",
+                    s,
+                    enum_values.iter().take(5).cloned().collect::<Vec<_>>()
+                ),
+                path,
+                &self.name,
+            );
+            let suggestions = suggest::near_misses(
+                s,
+                enum_values.iter().map(String::as_str),
+                suggest::suggestion_distance(&self.schema),
+            );
+            return Some(suggest::with_suggestions(issue, &suggestions));
+        }
+        match self.dynamic.contains(enum_def, s) {
+            Ok(true) => None,
+            Ok(false) => Some(ValidationIssue::error(
+                format!("Value '{s}' is not a permissible value of dynamic enum '{enum_name}'"),
+                path,
+                &self.name,
+            )),
+            Err(e) => Some(ValidationIssue::warning(
+                format!("Value '{s}' of dynamic enum '{enum_name}' was not checked: {e}"),
+                path,
+                &self.name,
+            )),
+        }
+    }
 }
 
 impl Validator for PermissibleValueValidator {
@@ -165,43 +233,18 @@ impl Validator for PermissibleValueValidator {
         if let Some(range) = &slot.range
             && let Some(enum_values) = self.get_enum_values(range)
         {
-            let check_value = |v: &Value, path: &str| -> Option<ValidationIssue> {
-                if let Some(s) = v.as_str() {
-                    if enum_values.contains(s) {
-                        None
-                    } else {
-                        Some(ValidationIssue::error(
-                            format!(
-                                "Value '{}' is not in permissible values: {:?}",
-                                s,
-                                enum_values.iter().take(5).cloned().collect::<Vec<_>>()
-                            ),
-                            path,
-                            &self.name,
-                        ))
-                    }
-                } else if !v.is_null() {
-                    Some(ValidationIssue::error(
-                        "Enum value must be a string",
-                        path,
-                        &self.name,
-                    ))
-                } else {
-                    None
-                }
-            };
-
             if slot.multivalued.unwrap_or(false) {
                 if let Some(array) = value.as_array() {
                     for (i, element) in array.iter().enumerate() {
-                        if let Some(issue) =
-                            check_value(element, &format!("{}[{}]", context.path(), i))
-                        {
+                        let path = format!("{}[{}]", context.path(), i);
+                        if let Some(issue) = self.check_value(range, &enum_values, element, &path) {
                             issues.push(issue);
                         }
                     }
                 }
-            } else if let Some(issue) = check_value(value, &context.path()) {
+            } else if let Some(issue) =
+                self.check_value(range, &enum_values, value, &context.path())
+            {
                 issues.push(issue);
             }
         }
@@ -1,15 +1,21 @@
 //! Custom validator support for user-defined validation logic
 //!
 //! This module provides the infrastructure for creating custom validators
-//! that can be registered with the validation engine.
+//! that can be registered with the validation engine, either directly or
+//! through a [`CustomValidatorRegistry`] that schemas select validators from
+//! with [`CUSTOM_VALIDATOR_ANNOTATION`] annotations.
 
+use indexmap::IndexMap;
 use linkml_core::{
     Value,
+    annotations::{AnnotationValue, Annotations},
     error::{LinkMLError, Result},
-    types::SlotDefinition,
+    types::{SchemaDefinition, SlotDefinition},
 };
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::generator::base::{class_ancestry, induced_slots};
 use crate::validator::{context::ValidationContext, report::ValidationIssue};
 
 use super::Validator;
@@ -222,6 +228,200 @@ impl CustomValidatorBuilder {
     }
 }
 
+/// Annotation naming the custom validators of a schema element
+///
+/// The value is a validator name or a list of names:
+///
+/// ```yaml
+/// slots:
+///   orcid:
+///     annotations:
+///       custom_validator: orcid_checksum
+/// ```
+pub const CUSTOM_VALIDATOR_ANNOTATION: &str = "custom_validator";
+
+/// Custom validators that schemas refer to by name
+///
+/// Schema elements select validators with a [`CUSTOM_VALIDATOR_ANNOTATION`]
+/// annotation; [`Self::bind`] resolves the annotations of a schema when a
+/// validation engine is created. An annotation on a slot, attribute or
+/// `slot_usage` applies to values of that slot, one on a class to values of
+/// all slots of the class and its descendants, and one on the schema to all
+/// slot values. The validator's own [`AppliesTo`] still filters the slots.
+#[derive(Debug, Clone, Default)]
+pub struct CustomValidatorRegistry {
+    validators: IndexMap<String, Arc<CustomValidator>>,
+}
+
+impl CustomValidatorRegistry {
+    /// Create an empty registry
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a validator under its name, replacing any validator with
+    /// the same name
+    pub fn register(&mut self, validator: CustomValidator) {
+        self.validators
+            .insert(validator.name.clone(), Arc::new(validator));
+    }
+
+    /// Registry with a validator added
+    #[must_use]
+    pub fn with(mut self, validator: CustomValidator) -> Self {
+        self.register(validator);
+        self
+    }
+
+    /// Validator registered under a name
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Arc<CustomValidator>> {
+        self.validators.get(name)
+    }
+
+    /// Names of the registered validators, in registration order
+    #[must_use]
+    pub fn names(&self) -> Vec<&str> {
+        self.validators.keys().map(String::as_str).collect()
+    }
+
+    /// Resolve the [`CUSTOM_VALIDATOR_ANNOTATION`] annotations of a schema
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an annotation is not a name or list of names, or
+    /// names a validator that is not registered.
+    pub fn bind(&self, schema: &SchemaDefinition) -> Result<AnnotationBindings> {
+        let schema_names = self.annotated(schema.annotations.as_ref(), "the schema")?;
+        let mut slot_names = HashMap::new();
+        for (slot_name, slot) in &schema.slots {
+            let names =
+                self.annotated(slot.annotations.as_ref(), &format!("slot '{slot_name}'"))?;
+            slot_names.insert(slot_name.as_str(), names);
+        }
+        let mut class_names = HashMap::new();
+        let mut refinement_names = HashMap::new();
+        for (class_name, class) in &schema.classes {
+            let names =
+                self.annotated(class.annotations.as_ref(), &format!("class '{class_name}'"))?;
+            class_names.insert(class_name.as_str(), names);
+            for (slot_name, slot) in class.attributes.iter().chain(&class.slot_usage) {
+                let names = self.annotated(
+                    slot.annotations.as_ref(),
+                    &format!("slot '{slot_name}' of class '{class_name}'"),
+                )?;
+                refinement_names
+                    .entry((class_name.as_str(), slot_name.as_str()))
+                    .or_insert_with(Vec::new)
+                    .extend(names);
+            }
+        }
+
+        let mut bindings = HashMap::new();
+        for class_name in schema.classes.keys() {
+            let ancestry = class_ancestry(schema, class_name);
+            let class_level: Vec<&Arc<CustomValidator>> = ancestry
+                .iter()
+                .filter_map(|ancestor| class_names.get(ancestor.as_str()))
+                .flatten()
+                .copied()
+                .collect();
+            for slot in induced_slots(schema, class_name) {
+                let refinements = ancestry.iter().filter_map(|ancestor| {
+                    refinement_names.get(&(ancestor.as_str(), slot.name.as_str()))
+                });
+                let mut validators: Vec<Arc<CustomValidator>> = Vec::new();
+                for validator in schema_names
+                    .iter()
+                    .chain(&class_level)
+                    .chain(slot_names.get(slot.name.as_str()).into_iter().flatten())
+                    .chain(refinements.flatten())
+                {
+                    if !validators.iter().any(|known| Arc::ptr_eq(known, validator)) {
+                        validators.push(Arc::clone(validator));
+                    }
+                }
+                if !validators.is_empty() {
+                    bindings.insert((class_name.clone(), slot.name), validators);
+                }
+            }
+        }
+        Ok(AnnotationBindings { bindings })
+    }
+
+    /// Validators named by the annotations of an element
+    fn annotated(
+        &self,
+        annotations: Option<&Annotations>,
+        element: &str,
+    ) -> Result<Vec<&Arc<CustomValidator>>> {
+        let Some(value) = annotations.and_then(|a| a.get(CUSTOM_VALIDATOR_ANNOTATION)) else {
+            return Ok(Vec::new());
+        };
+        validator_names(value)
+            .ok_or_else(|| {
+                LinkMLError::config(format!(
+                    "The {CUSTOM_VALIDATOR_ANNOTATION} annotation of {element} must be a \
+                     validator name or a list of names"
+                ))
+            })?
+            .into_iter()
+            .map(|name| {
+                self.validators.get(name).ok_or_else(|| {
+                    LinkMLError::config(format!(
+                        "Unknown custom validator '{name}' in the {CUSTOM_VALIDATOR_ANNOTATION} \
+                         annotation of {element}; registered validators: {}",
+                        self.names().join(", ")
+                    ))
+                })
+            })
+            .collect()
+    }
+}
+
+/// Names in a [`CUSTOM_VALIDATOR_ANNOTATION`] value, `None` if it is not a
+/// name or list of names
+fn validator_names(value: &AnnotationValue) -> Option<Vec<&str>> {
+    match value {
+        AnnotationValue::String(name) => Some(vec![name.as_str()]),
+        AnnotationValue::Array(values) => values
+            .iter()
+            .map(|value| match value {
+                AnnotationValue::String(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect(),
+        // Expanded form `{tag: custom_validator, value: ...}`
+        AnnotationValue::Object(fields) => validator_names(fields.get("value")?),
+        _ => None,
+    }
+}
+
+/// Custom validators bound to the slots of each class by
+/// [`CUSTOM_VALIDATOR_ANNOTATION`] annotations
+#[derive(Debug, Clone, Default)]
+pub struct AnnotationBindings {
+    /// Validators by class and slot name
+    bindings: HashMap<(String, String), Vec<Arc<CustomValidator>>>,
+}
+
+impl AnnotationBindings {
+    /// Validators bound to a slot of a class
+    #[must_use]
+    pub fn validators_for(&self, class_name: &str, slot_name: &str) -> &[Arc<CustomValidator>] {
+        self.bindings
+            .get(&(class_name.to_string(), slot_name.to_string()))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether no validator is bound
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+}
+
 /// Helper functions for creating common custom validators
 pub mod helpers {
     use super::{
@@ -526,4 +726,99 @@ mod tests {
         assert!(issues.is_empty());
         Ok(())
     }
+
+    fn named_validator(name: &str) -> CustomValidator {
+        CustomValidatorBuilder::new(name)
+            .validate_with(|_value, _slot, _context| Vec::new())
+            .build()
+            .expect("should build custom validator")
+    }
+
+    #[test]
+    fn test_annotation_bindings() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/bindings
+name: bindings
+annotations:
+  custom_validator: audited
+classes:
+  Agent:
+    slots: [id, orcid]
+    annotations:
+      custom_validator: [agent_check]
+  Person:
+    is_a: Agent
+    attributes:
+      nickname:
+        annotations:
+          custom_validator: polite
+    slot_usage:
+      orcid:
+        annotations:
+          custom_validator:
+            tag: custom_validator
+            value: strict_orcid
+slots:
+  id: {}
+  orcid:
+    annotations:
+      custom_validator: orcid_checksum
+",
+        )?;
+        let registry = [
+            "audited",
+            "agent_check",
+            "polite",
+            "orcid_checksum",
+            "strict_orcid",
+        ]
+        .into_iter()
+        .fold(CustomValidatorRegistry::new(), |registry, name| {
+            registry.with(named_validator(name))
+        });
+
+        let bindings = registry.bind(&schema)?;
+        let names = |class: &str, slot: &str| -> Vec<String> {
+            bindings
+                .validators_for(class, slot)
+                .iter()
+                .map(|validator| validator.name().to_string())
+                .collect()
+        };
+        assert_eq!(names("Agent", "id"), ["audited", "agent_check"]);
+        assert_eq!(
+            names("Agent", "orcid"),
+            ["audited", "agent_check", "orcid_checksum"]
+        );
+        // Descendants inherit class annotations and slot usage of ancestors
+        assert_eq!(
+            names("Person", "orcid"),
+            ["audited", "agent_check", "orcid_checksum", "strict_orcid"]
+        );
+        assert_eq!(
+            names("Person", "nickname"),
+            ["audited", "agent_check", "polite"]
+        );
+        assert!(names("Person", "unknown").is_empty());
+
+        let partial = ["audited", "polite", "orcid_checksum", "strict_orcid"]
+            .into_iter()
+            .fold(CustomValidatorRegistry::new(), |registry, name| {
+                registry.with(named_validator(name))
+            });
+        let error = partial.bind(&schema).unwrap_err().to_string();
+        assert!(error.contains("Unknown custom validator 'agent_check'"));
+        assert!(error.contains("class 'Agent'"));
+
+        let mut invalid = SchemaDefinition::default();
+        let mut annotations = Annotations::new();
+        annotations.insert(
+            CUSTOM_VALIDATOR_ANNOTATION.to_string(),
+            AnnotationValue::Bool(true),
+        );
+        invalid.annotations = Some(annotations);
+        assert!(registry.bind(&invalid).is_err());
+        Ok(())
+    }
 }
@@ -2,7 +2,9 @@
 
 use serde_json::Value;
 
-use crate::validator::{context::ValidationContext, report::ValidationIssue};
+use crate::validator::{
+    context::ValidationContext, dynamic_enum::OntologyProvider, report::ValidationIssue,
+};
 use linkml_core::types::{SchemaDefinition, SlotDefinition};
 
 pub mod boolean_constraints;
@@ -18,6 +20,7 @@ pub mod rule_validator;
 pub mod string_constraints;
 pub mod type_validators;
 pub mod unique_key_validator;
+pub mod unit_validator;
 pub mod utils;
 
 pub use boolean_constraints::{
@@ -28,7 +31,8 @@ pub use constraint_validators::{
     MultivaluedValidator, PermissibleValueValidator, RequiredValidator,
 };
 pub use custom_validator::{
-    AppliesTo, CustomValidator, CustomValidatorBuilder, ValidationFunction, helpers,
+    AnnotationBindings, AppliesTo, CUSTOM_VALIDATOR_ANNOTATION, CustomValidator,
+    CustomValidatorBuilder, CustomValidatorRegistry, ValidationFunction, helpers,
 };
 pub use expression_validator::ExpressionValidator;
 pub use instance_validator::InstanceValidator;
@@ -39,6 +43,7 @@ pub use rule_validator::{RuleValidation, RuleValidator};
 pub use string_constraints::{EqualsStringInValidator, StructuredPatternValidator};
 pub use type_validators::*;
 pub use unique_key_validator::{UniqueKeyValidator, UniqueValueTracker};
+pub use unit_validator::UnitValidator;
 
 /// Trait for all validators
 pub trait Validator: Send + Sync {
@@ -60,6 +65,7 @@ pub struct ValidatorRegistry {
     rule_validator: Option<RuleValidator>,
     conditional_requirement_validator: Option<ConditionalRequirementValidator>,
     unique_key_validator: Option<UniqueKeyValidator>,
+    annotation_bindings: AnnotationBindings,
 }
 
 impl ValidatorRegistry {
@@ -88,6 +94,8 @@ impl ValidatorRegistry {
             // String constraint validators
             Box::new(EqualsStringInValidator::new()),
             Box::new(StructuredPatternValidator::new()),
+            // Unit validator for quantity slots
+            Box::new(UnitValidator::new()),
         ];
 
         // Create rule validator if schema has classes with rules
@@ -120,6 +128,7 @@ impl ValidatorRegistry {
             rule_validator,
             conditional_requirement_validator,
             unique_key_validator,
+            annotation_bindings: AnnotationBindings::default(),
         })
     }
 
@@ -163,6 +172,7 @@ impl ValidatorRegistry {
                     "StructuredPatternValidator" if slot.structured_pattern.is_some() => {
                         Some(validator.as_ref())
                     }
+                    "UnitValidator" if slot.unit.is_some() => Some(validator.as_ref()),
                     _ => None, // Skip validators that don't apply to this slot
                 }
             })
@@ -174,6 +184,56 @@ impl ValidatorRegistry {
         self.validators.push(validator);
     }
 
+    /// Bind the custom validators the schema's annotations name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an annotation names a validator the registry does
+    /// not contain.
+    pub fn set_custom_validators(
+        &mut self,
+        schema: &SchemaDefinition,
+        validators: &CustomValidatorRegistry,
+    ) -> Result<(), linkml_core::error::LinkMLError> {
+        self.annotation_bindings = validators.bind(schema)?;
+        Ok(())
+    }
+
+    /// Custom validators bound to a slot of a class by annotations
+    #[must_use]
+    pub fn bound_validators(
+        &self,
+        class_name: &str,
+        slot_name: &str,
+    ) -> &[std::sync::Arc<CustomValidator>] {
+        self.annotation_bindings.validators_for(class_name, slot_name)
+    }
+
+    /// Evaluate `reachable_from` queries of dynamic enums with an ontology
+    /// provider
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the permissible value validator cannot be created.
+    pub fn set_ontology_provider(
+        &mut self,
+        schema: &SchemaDefinition,
+        provider: std::sync::Arc<dyn OntologyProvider>,
+    ) -> Result<(), linkml_core::error::LinkMLError> {
+        let validator = Box::new(PermissibleValueValidator::with_ontology_provider(
+            schema, provider,
+        )?);
+        match self
+            .validators
+            .iter()
+            .position(|existing| existing.name() == validator.name())
+        {
+            Some(index) => self.validators[index] = validator,
+            None => self.validators.push(validator),
+        }
+        Ok(())
+    }
+
     /// Get the rule validator if available
     pub fn rule_validator(&self) -> Option<&RuleValidator> {
         self.rule_validator.as_ref()
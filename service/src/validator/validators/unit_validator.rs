@@ -0,0 +1,137 @@
+//! Unit validation for quantity slots
+//!
+//! Checks that quantities of slots with a `unit` carry a valid UCUM unit
+//! that converts to the unit of the slot; see [`crate::validator::units`]
+//! for the accepted value shapes. Values without a unit are taken to be in
+//! the unit of the slot, and values that are not quantities are left to the
+//! type validator.
+
+use super::{ValidationContext, ValidationIssue, Validator};
+use crate::validator::units::{Quantity, Unit, slot_unit};
+use linkml_core::error_codes::ErrorCode;
+use linkml_core::types::SlotDefinition;
+use serde_json::Value;
+
+/// Validator for the `unit` of quantity slots
+pub struct UnitValidator;
+
+impl Default for UnitValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UnitValidator {
+    /// Create a new unit validator
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn check(value: &Value, canonical: &Unit, path: &str) -> Option<ValidationIssue> {
+        let quantity = Quantity::from_value(value)?;
+        let code = quantity.unit.as_deref()?;
+        let issue = match Unit::parse(code) {
+            Err(e) => ValidationIssue::error(e.to_string(), path, "UnitValidator")
+                .with_code("INVALID_UNIT"),
+            Ok(unit) if !unit.is_commensurable(canonical) => ValidationIssue::error(
+                format!("Unit '{code}' cannot be converted to the slot unit '{canonical}'"),
+                path,
+                "UnitValidator",
+            )
+            .with_code("INCOMPATIBLE_UNIT"),
+            Ok(_) => return None,
+        };
+        Some(
+            issue
+                .with_error_code(ErrorCode::UnitMismatch)
+                .with_context("unit", Value::String(code.to_string()))
+                .with_context("expected_unit", Value::String(canonical.to_string())),
+        )
+    }
+}
+
+impl Validator for UnitValidator {
+    fn validate(
+        &self,
+        value: &Value,
+        slot: &SlotDefinition,
+        context: &mut ValidationContext,
+    ) -> Vec<ValidationIssue> {
+        let Some(code) = slot_unit(slot) else {
+            return Vec::new();
+        };
+        let canonical = match Unit::parse(code) {
+            Ok(unit) => unit,
+            Err(e) => {
+                return vec![
+                    ValidationIssue::error(
+                        format!("Slot '{}' has an invalid unit: {e}", slot.name),
+                        context.path(),
+                        "UnitValidator",
+                    )
+                    .with_code("INVALID_UNIT")
+                    .with_error_code(ErrorCode::UnitMismatch),
+                ];
+            }
+        };
+
+        match value {
+            Value::Array(items) => {
+                let mut issues = Vec::new();
+                for (index, item) in items.iter().enumerate() {
+                    context.push_index(index);
+                    issues.extend(Self::check(item, &canonical, &context.path()));
+                    context.pop_path();
+                }
+                issues
+            }
+            _ => Self::check(value, &canonical, &context.path())
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "UnitValidator"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::{SchemaDefinition, UnitOfMeasure};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_unit_validator() {
+        let slot = SlotDefinition {
+            name: "glucose".to_string(),
+            unit: Some(UnitOfMeasure {
+                ucum_code: Some("mmol/L".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let validator = UnitValidator::new();
+        let mut context = ValidationContext::new(Arc::new(SchemaDefinition::default()));
+
+        for valid in [
+            json!(5.4),
+            json!("5.4 mmol/L"),
+            json!({"value": 5400, "unit": "umol/L"}),
+            json!("not a quantity"),
+        ] {
+            assert!(validator.validate(&valid, &slot, &mut context).is_empty());
+        }
+
+        let issues = validator.validate(&json!(["97 mg/dL", "5 mmol/kg"]), &slot, &mut context);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].error_code(), ErrorCode::UnitMismatch);
+        assert!(issues[1].path.ends_with("[1]"));
+
+        let issues = validator.validate(&json!("5 mmol/Lx"), &slot, &mut context);
+        assert_eq!(issues[0].code.as_deref(), Some("INVALID_UNIT"));
+    }
+}
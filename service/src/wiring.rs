@@ -121,7 +121,7 @@ use timestamp_core::{TimestampError, TimestampService};
 /// ```rust,no_run
 /// use linkml_service::wiring::wire_linkml_service;
 /// use logger_service::wiring::wire_logger;
-/// use timestamp_service::wiring::wire_timestamp;
+/// use crate::utils::timestamp::wire_timestamp;
 /// use task_management_service::wiring::wire_task_management;
 /// use error_handling_service::wiring::wire_error_handling;
 /// use configuration_service::wiring::wire_configuration;
@@ -353,7 +353,7 @@ where
 ///
 /// ```rust,no_run
 /// use linkml_service::wiring::wire_minimal_linkml_service;
-/// use timestamp_service::wiring::wire_timestamp;
+/// use crate::utils::timestamp::wire_timestamp;
 /// use random_service::wiring::wire_random;
 ///
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -387,37 +387,29 @@ mod tests {
 
     #[test]
     fn test_wire_minimal_linkml_service() {
-        use logger_service::wiring::wire_logger;
+        use crate::utils::logging::wire_logger;
+        use crate::utils::timestamp::wire_timestamp;
         use random_service::wiring::wire_random;
-        use timestamp_service::wiring::wire_timestamp;
 
         let timestamp = wire_timestamp();
-        let logger = wire_logger(
-            timestamp.clone().into_inner(),
-            logger_core::LoggerConfig::default(),
-        )
-        .expect("Should create logger");
-        let random = wire_random(logger.into_inner(), timestamp.clone().into_inner(), None);
+        let logger = wire_logger().expect("Should create logger");
+        let random = wire_random(logger, timestamp.clone(), None);
 
-        let result = wire_minimal_linkml_service(timestamp.into_inner(), random.into_inner());
+        let result = wire_minimal_linkml_service(timestamp, random.into_inner());
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_handle_ownership() {
-        use logger_service::wiring::wire_logger;
+        use crate::utils::logging::wire_logger;
+        use crate::utils::timestamp::wire_timestamp;
         use random_service::wiring::wire_random;
-        use timestamp_service::wiring::wire_timestamp;
 
         let timestamp = wire_timestamp();
-        let logger = wire_logger(
-            timestamp.clone().into_inner(),
-            logger_core::LoggerConfig::default(),
-        )
-        .expect("Should create logger");
-        let random = wire_random(logger.into_inner(), timestamp.clone().into_inner(), None);
+        let logger = wire_logger().expect("Should create logger");
+        let random = wire_random(logger, timestamp.clone(), None);
 
-        let handle = wire_minimal_linkml_service(timestamp.into_inner(), random.into_inner())
+        let handle = wire_minimal_linkml_service(timestamp, random.into_inner())
             .expect("Should create minimal service");
 
         // Test that we can extract Arc from handle
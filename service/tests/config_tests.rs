@@ -1,16 +1,14 @@
 //! Comprehensive tests for configuration module
 
 use linkml_service::config::{
-    LinkMLConfig, get_config,
-    hot_reload::{ConfigHotReloader, get_hot_config, init_hot_reload},
-    load_config, load_default_config, load_environment_config, load_production_config,
+    LinkMLConfig, get_config, load_config, load_default_config, load_environment_config,
+    load_production_config,
     validation::{validate_config, validate_values},
 };
 use std::env;
 use std::fs;
 use std::path::Path;
-use tempfile::{NamedTempFile, TempDir};
-use tokio::time::{Duration, sleep};
+use tempfile::NamedTempFile;
 
 #[test]
 fn test_load_default_config() {
@@ -234,149 +232,6 @@ fn test_singleton_config() {
     assert_eq!(config1 as *const _, config2 as *const _);
 }
 
-#[tokio::test]
-async fn test_hot_reload_basic() {
-    // Create a temporary config file
-    let temp_dir = TempDir::new().expect("should create temp dir");
-    let config_path = temp_dir.path().join("test_config.yaml");
-
-    // Write initial config
-    let initial_config = r#"
-typedb:
-  server_address: "localhost:1729"
-  default_database: "test"
-  batch_size: 1000
-  connection_timeout_ms: 30000
-  query_timeout_ms: 10000
-  max_retries: 3
-  retry_delay_ms: 1000
-  pool_size: 10
-  include_inferred: false
-parser:
-  max_recursion_depth: 100
-  enable_cache: true
-  cache_ttl_seconds: 3600
-  max_file_size_bytes: 10485760
-  supported_formats: ["yaml", "json"]
-  max_import_depth: 10
-validator:
-  enable_parallel: true
-  thread_count: 4
-  batch_size: 100
-  timeout_ms: 30000
-  max_errors: 1000
-  fail_fast: false
-  compiled_cache_size: 1000
-generator:
-  output_directory: "./generated"
-  enable_formatting: true
-  include_docs: true
-  generator_options: {}
-cache:
-  max_entries: 10000
-  ttl_seconds: 3600
-  enable_compression: true
-  eviction_policy: "Lru"
-  expression_cache:
-    max_entries: 1000
-    ttl_seconds: 1800
-  rule_cache:
-    max_entries: 500
-    ttl_seconds: 7200
-performance:
-  enable_monitoring: true
-  memory_limit_bytes: 1073741824
-  cpu_limit_percent: 80
-  enable_string_interning: true
-  string_pool_size: 10000
-  enable_background_tasks: true
-  enable_cache_warming: true
-  background_task_interval_secs: 60
-  string_cache:
-    max_entries: 5000
-    max_string_length: 1024
-  memory_pool:
-    max_size_bytes: 104857600
-    chunk_size_bytes: 4096
-  cache_ttl_levels:
-    l1_seconds: 300
-    l2_seconds: 1800
-    l3_seconds: 7200
-    min_ttl_seconds: 60
-    max_ttl_seconds: 86400
-security_limits:
-  max_string_length: 65536
-  max_expression_depth: 100
-  max_constraint_count: 1000
-  max_cache_entries: 100000
-  max_function_args: 20
-  max_identifier_length: 255
-  max_json_size_bytes: 10485760
-  max_slots_per_class: 1000
-  max_classes_per_schema: 10000
-  max_validation_time_ms: 300000
-  max_memory_usage_bytes: 1073741824
-  max_parallel_validators: 100
-  max_cache_memory_bytes: 104857600
-  max_expression_time_ms: 1000
-  max_validation_errors: 1000
-network:
-  default_host: "127.0.0.1"
-  default_port: 8080
-  api_timeout_seconds: 30
-expression:
-  enable_cache: true
-  enable_compilation: true
-  cache_size: 1000
-  timeout_seconds: 1
-  max_recursion_depth: 50
-pattern_validator:
-  default_cache_size: 100
-multi_layer_cache:
-  l3_max_size_bytes: 1073741824
-background_services:
-  cache_ttl_check_interval_secs: 300
-  memory_cleanup_interval_secs: 600
-  panic_recovery_timeout_secs: 5
-  error_recovery_timeout_secs: 60
-cli:
-  default_iterations: 1000
-  progress_bar_template: "{spinner} [{bar}]"
-  progress_bar_finish_template: "Done!"
-"#;
-
-    fs::write(&config_path, initial_config).expect("should write initial config");
-
-    // Create hot reloader
-    let mut reloader = ConfigHotReloader::new(&config_path).expect("should create hot reloader");
-
-    // Get initial config
-    let initial = reloader.get_config();
-    assert_eq!(initial.typedb.batch_size, 1000);
-
-    // Start watching
-    reloader
-        .start_watching()
-        .await
-        .expect("should start watching");
-
-    // Subscribe to updates
-    let mut update_rx = reloader.subscribe();
-
-    // Modify the config file
-    let modified_config = initial_config.replace("batch_size: 1000", "batch_size: 2000");
-    fs::write(&config_path, modified_config).expect("should write modified config");
-
-    // Wait for file system event
-    sleep(Duration::from_millis(200)).await;
-
-    // Note: File watching in tests can be unreliable
-    // In a real environment, the update would be detected
-
-    // Stop watching
-    reloader.stop_watching();
-}
-
 #[test]
 fn test_invalid_config_format() {
     let temp_file = NamedTempFile::new().expect("should create temp file");
@@ -4,7 +4,7 @@ use linkml_core::types::SchemaDefinition;
 use linkml_core::types::{ClassDefinition, SlotDefinition};
 use linkml_service::validator::{
     ValidationEngine,
-    validators::{CustomValidatorBuilder, helpers},
+    validators::{CustomValidatorBuilder, CustomValidatorRegistry, helpers},
 };
 use serde_json::{Value, json};
 #[tokio::test]
@@ -324,3 +324,60 @@ fn test_custom_validator_builder_errors() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_custom_validators_bound_by_annotations() {
+    let schema: SchemaDefinition = serde_yaml::from_str(
+        r"
+id: https://example.org/products
+name: products
+classes:
+  Product:
+    slots: [sku, name]
+slots:
+  sku:
+    range: string
+    annotations:
+      custom_validator: uppercase
+  name:
+    range: string
+",
+    )
+    .expect("Test operation failed");
+
+    let uppercase = CustomValidatorBuilder::new("uppercase")
+        .validate_with(|value, _slot, context| match value {
+            Value::String(s) if *s != s.to_uppercase() => {
+                vec![linkml_service::validator::report::ValidationIssue::error(
+                    format!("'{s}' must be uppercase"),
+                    context.path(),
+                    "uppercase",
+                )]
+            }
+            _ => Vec::new(),
+        })
+        .build()
+        .expect("Test operation failed");
+    let validators = CustomValidatorRegistry::new().with(uppercase);
+    let engine = ValidationEngine::with_custom_validators(&schema, &validators)
+        .expect("Test operation failed");
+
+    let report = engine
+        .validate_as_class(&json!({"sku": "ABC-1", "name": "lower"}), "Product", None)
+        .await
+        .expect("Test operation failed");
+    assert!(report.valid);
+
+    let report = engine
+        .validate_as_class(&json!({"sku": "abc-1", "name": "lower"}), "Product", None)
+        .await
+        .expect("Test operation failed");
+    assert!(!report.valid);
+    let errors: Vec<_> = report.errors().collect();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.contains("'abc-1' must be uppercase"));
+
+    // Annotations naming unregistered validators fail at construction
+    let result = ValidationEngine::with_custom_validators(&schema, &CustomValidatorRegistry::new());
+    assert!(result.is_err());
+}
@@ -7,9 +7,10 @@
 use std::sync::Arc;
 use linkml_core::error::Result;
 use linkml_service::{
-    factory::{create_linkml_service, create_minimal_linkml_service},
-    factory_v2,
-    factory_v3,
+    factory::{
+        create_linkml_service, create_linkml_service_with_config,
+        create_linkml_service_with_dbms, create_minimal_linkml_service,
+    },
     service::{LinkMLService, MinimalLinkMLServiceImpl},
 };
 
@@ -159,59 +160,59 @@ async fn test_complete_factory_dependency_injection() {
     }
 }
 
-/// Test factory v2 configuration integration
+/// Test factory configuration integration
 #[tokio::test]
-async fn test_factory_v2_configuration_integration() {
-    // Test that factory_v2 properly integrates with configuration service
+async fn test_factory_configuration_integration() {
+    // Test that the factory properly integrates with configuration service
     let fixture = FactoryIntegrationFixture::new().await
         .expect("Test fixture should be created");
 
     // Create configuration for LinkML service
     let config = create_linkml_config();
 
-    // Test factory_v2 service creation with configuration
-    // Note: This may fail if factory_v2 isn't fully implemented yet
-    let result = factory_v2::create_linkml_service_with_config(config).await;
+    // Test factory service creation with configuration
+    // Note: This may fail if configuration integration isn't fully implemented yet
+    let result = create_linkml_service_with_config(config).await;
 
     match result {
         Ok(service) => {
             // Service should be created with proper configuration
             assert!(
                 Arc::strong_count(&service) >= 1,
-                "V2 factory should create service with configuration"
+                "Factory should create service with configuration"
             );
         }
         Err(error) => {
             // Configuration integration may not be complete
             let error_msg = format!("{error}");
-            println!("Factory V2 configuration integration: {error_msg}");
-            // This is acceptable if factory_v2 is still in development
+            println!("Factory configuration integration: {error_msg}");
+            // This is acceptable if configuration integration is still in development
         }
     }
 }
 
-/// Test factory v3 DBMS integration
+/// Test factory DBMS integration
 #[tokio::test]
-async fn test_factory_v3_dbms_integration() {
-    // Test that factory_v3 properly integrates with DBMS service
+async fn test_factory_dbms_integration() {
+    // Test that the factory properly integrates with DBMS service
     let fixture = FactoryIntegrationFixture::new().await
         .expect("Test fixture should be created");
 
-    // Test factory_v3 service creation with DBMS integration
-    let result = factory_v3::create_linkml_service_with_dbms().await;
+    // Test factory service creation with DBMS integration
+    let result = create_linkml_service_with_dbms().await;
 
     match result {
         Ok(service) => {
             // Service should be created with DBMS integration
             assert!(
                 Arc::strong_count(&service) >= 1,
-                "V3 factory should create service with DBMS integration"
+                "Factory should create service with DBMS integration"
             );
         }
         Err(error) => {
             // DBMS integration may not be complete or available in test environment
             let error_msg = format!("{error}");
-            println!("Factory V3 DBMS integration: {error_msg}");
+            println!("Factory DBMS integration: {error_msg}");
             // This is acceptable if DBMS is not available in test environment
         }
     }
@@ -365,8 +366,8 @@ async fn test_configuration_propagation() {
 
     let config = create_test_linkml_config();
 
-    // Test with factory_v2 if available
-    let result = factory_v2::create_linkml_service_with_config(config).await;
+    // Test with the configuration factory
+    let result = create_linkml_service_with_config(config).await;
 
     match result {
         Ok(_service) => {
@@ -0,0 +1,49 @@
+//! Build checks for the feature sets consumers depend on
+//!
+//! Consumers get the `RootReal` logger, timestamp and format identification
+//! services through the default `rootreal-services` feature, while the
+//! standalone `linkml-cli` builds the service without default features. Both
+//! feature sets must keep compiling. The checks run `cargo check` on the whole crate, so they are
+//! ignored by default: `cargo test --test feature_sets_test -- --ignored`.
+
+use std::path::Path;
+use std::process::Command;
+
+/// Runs `cargo check` on the package at `manifest` with extra arguments
+///
+/// Builds into its own target directory, as the one of the running tests
+/// stays locked.
+fn cargo_check(manifest: &str, args: &[&str]) {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join(manifest);
+    let status = Command::new(env!("CARGO"))
+        .args(["check", "--manifest-path"])
+        .arg(&manifest)
+        .arg("--target-dir")
+        .arg(Path::new(env!("CARGO_TARGET_TMPDIR")).join("feature_sets"))
+        .args(args)
+        .status()
+        .expect("cargo runs");
+    assert!(
+        status.success(),
+        "cargo check of {} {args:?} failed",
+        manifest.display()
+    );
+}
+
+#[test]
+#[ignore = "builds the crate with cargo check"]
+fn test_default_features_build() {
+    cargo_check("Cargo.toml", &[]);
+}
+
+#[test]
+#[ignore = "builds the crate with cargo check"]
+fn test_without_rootreal_services_build() {
+    cargo_check("Cargo.toml", &["--no-default-features"]);
+}
+
+#[test]
+#[ignore = "builds the crate with cargo check"]
+fn test_standalone_cli_build() {
+    cargo_check("../cli/Cargo.toml", &[]);
+}
@@ -0,0 +1,346 @@
+//! Conformance of slot resolution with `LinkML` inheritance semantics
+//!
+//! The fixtures record what `linkml-runtime`'s `SchemaView.induced_slot`
+//! yields for hierarchies where naive resolution goes wrong; the property
+//! tests compare `InheritanceResolver::resolve_slot_usage` with a direct port
+//! of the `linkml-runtime` algorithm on random hierarchies.
+
+use std::collections::HashSet;
+
+use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+use linkml_service::inheritance::resolver::{InheritanceResolver, get_inheritance_chain};
+use proptest::prelude::*;
+use serde_json::{Value, json};
+
+/// Schema and expected metaslots of induced slots
+struct Fixture {
+    name: &'static str,
+    schema: &'static str,
+    expected: Vec<(&'static str, &'static str, &'static str, Value)>,
+}
+
+fn fixtures() -> Vec<Fixture> {
+    vec![
+        Fixture {
+            // Ancestors are ranked by discovery: the mixin C is found before
+            // X, the parent of B, so C's usage wins over X's
+            name: "mixin before ancestors of is_a",
+            schema: r"
+id: https://example.org/f1
+name: f1
+classes:
+  X:
+    slots: [s]
+    slot_usage:
+      s:
+        description: from X
+        range: integer
+  B:
+    is_a: X
+  C:
+    mixin: true
+    slot_usage:
+      s:
+        description: from C
+  D:
+    is_a: B
+    mixins: [C]
+slots:
+  s:
+    description: global
+",
+            expected: vec![
+                ("D", "s", "description", json!("from C")),
+                ("D", "s", "range", json!("integer")),
+                ("B", "s", "description", json!("from X")),
+            ],
+        },
+        Fixture {
+            name: "nearest usage wins in a chain",
+            schema: r"
+id: https://example.org/f2
+name: f2
+classes:
+  A:
+    slots: [s]
+    slot_usage:
+      s:
+        required: true
+        pattern: '^a'
+  B:
+    is_a: A
+    slot_usage:
+      s:
+        required: false
+  C:
+    is_a: B
+slots:
+  s: {}
+",
+            expected: vec![
+                ("C", "s", "required", json!(false)),
+                ("C", "s", "pattern", json!("^a")),
+                ("A", "s", "required", json!(true)),
+            ],
+        },
+        Fixture {
+            name: "bounds tighten across usages",
+            schema: r"
+id: https://example.org/f3
+name: f3
+classes:
+  A:
+    slots: [n]
+    slot_usage:
+      n:
+        minimum_value: 10
+        maximum_value: 20
+  B:
+    is_a: A
+    slot_usage:
+      n:
+        minimum_value: 5
+        maximum_value: 15
+slots:
+  n:
+    range: integer
+    minimum_value: 0
+    maximum_value: 100
+",
+            expected: vec![
+                ("B", "n", "minimum_value", json!(10)),
+                ("B", "n", "maximum_value", json!(15)),
+                ("A", "n", "maximum_value", json!(20)),
+            ],
+        },
+        Fixture {
+            name: "usage over attribute over global slot over parent slot",
+            schema: r"
+id: https://example.org/f4
+name: f4
+classes:
+  Thing:
+    attributes:
+      label:
+        description: attribute
+  Named:
+    is_a: Thing
+    slot_usage:
+      label:
+        required: true
+slots:
+  text:
+    range: string
+    pattern: '\S'
+    description: parent
+  label:
+    is_a: text
+    description: global
+    required: false
+    multivalued: false
+",
+            expected: vec![
+                ("Named", "label", "required", json!(true)),
+                ("Named", "label", "description", json!("attribute")),
+                ("Named", "label", "multivalued", json!(false)),
+                ("Named", "label", "range", json!("string")),
+                ("Thing", "label", "required", json!(false)),
+            ],
+        },
+    ]
+}
+
+#[test]
+fn test_conformance_fixtures() -> Result<(), Box<dyn std::error::Error>> {
+    for fixture in fixtures() {
+        let schema: SchemaDefinition = serde_yaml::from_str(fixture.schema)?;
+        let resolver = InheritanceResolver::new(&schema);
+        for (class, slot, metaslot, value) in &fixture.expected {
+            let induced = serde_json::to_value(resolver.resolve_slot_usage(class, slot)?)?;
+            assert_eq!(
+                induced.get(*metaslot).cloned().unwrap_or(Value::Null),
+                *value,
+                "{}: {metaslot} of {class}.{slot}",
+                fixture.name
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Generated class: `is_a` and mixins among the classes before it, and the
+/// metaslots its `slot_usage` of `s` sets
+#[derive(Debug, Clone)]
+struct ClassSpec {
+    is_a: Option<usize>,
+    mixins: Vec<usize>,
+    description: bool,
+    required: Option<bool>,
+    maximum_value: Option<i64>,
+}
+
+fn arb_hierarchy() -> impl Strategy<Value = Vec<ClassSpec>> {
+    (2usize..8).prop_flat_map(|size| {
+        (0..size)
+            .map(|index| {
+                (
+                    prop::option::of(0..index.max(1)),
+                    prop::collection::vec(0..index.max(1), 0..3),
+                    any::<bool>(),
+                    prop::option::of(any::<bool>()),
+                    prop::option::of(0i64..100),
+                )
+                    .prop_map(
+                        move |(is_a, mixins, description, required, maximum_value)| {
+                            // The first class has no parents
+                            let parents = index > 0;
+                            let is_a = is_a.filter(|_| parents);
+                            let mut mixins: Vec<usize> = mixins
+                                .into_iter()
+                                .filter(|mixin| parents && Some(*mixin) != is_a)
+                                .collect();
+                            mixins.sort_unstable();
+                        mixins.dedup();
+                            ClassSpec {
+                                is_a,
+                                mixins,
+                                description,
+                                required,
+                                maximum_value,
+                            }
+                        },
+                    )
+            })
+            .collect::<Vec<_>>()
+    })
+}
+
+fn class_name(index: usize) -> String {
+    format!("C{index}")
+}
+
+fn build_schema(specs: &[ClassSpec]) -> SchemaDefinition {
+    let mut schema = SchemaDefinition {
+        id: "https://example.org/generated".to_string(),
+        name: "generated".to_string(),
+        ..Default::default()
+    };
+    let mut global = SlotDefinition::new("s");
+    global.description = Some("global".to_string());
+    global.maximum_value = Some(json!(1000));
+    schema.slots.insert("s".to_string(), global);
+
+    for (index, spec) in specs.iter().enumerate() {
+        let mut class = ClassDefinition::new(class_name(index));
+        class.is_a = spec.is_a.map(class_name);
+        class.mixins = spec.mixins.iter().copied().map(class_name).collect();
+        class.slots = vec!["s".to_string()];
+        let mut usage = SlotDefinition::default();
+        if spec.description {
+            usage.description = Some(format!("from {}", class_name(index)));
+        }
+        usage.required = spec.required;
+        usage.maximum_value = spec.maximum_value.map(|value| json!(value));
+        class.slot_usage.insert("s".to_string(), usage);
+        schema.classes.insert(class_name(index), class);
+    }
+    schema
+}
+
+/// `SchemaView._closure` of `linkml-runtime` over class parents
+fn reference_ancestors(schema: &SchemaDefinition, class: &str) -> Vec<String> {
+    let mut rv = vec![class.to_string()];
+    let mut visited: Vec<String> = Vec::new();
+    let mut todo = vec![class.to_string()];
+    while let Some(current) = todo.pop() {
+        visited.push(current.clone());
+        let definition = &schema.classes[&current];
+        let parents: Vec<&String> = definition.is_a.iter().chain(&definition.mixins).collect();
+        for parent in parents {
+            if !visited.contains(parent) && !rv.contains(parent) {
+                todo.push(parent.clone());
+                rv.push(parent.clone());
+            }
+        }
+    }
+    rv
+}
+
+/// `SchemaView.induced_slot` of `linkml-runtime` for the metaslots generated
+fn reference_induced(schema: &SchemaDefinition, class: &str) -> (Value, Value, Value) {
+    let slot = &schema.slots["s"];
+    let mut description = json!(slot.description);
+    let mut required = json!(slot.required);
+    let mut maximum_value = slot.maximum_value.clone().unwrap_or(Value::Null);
+    for ancestor in reference_ancestors(schema, class).iter().rev() {
+        let usage = &schema.classes[ancestor].slot_usage["s"];
+        if let Some(value) = &usage.description {
+            description = json!(value);
+        }
+        if let Some(value) = usage.required {
+            required = json!(value);
+        }
+        if let Some(value) = &usage.maximum_value {
+            maximum_value = match (maximum_value.as_i64(), value.as_i64()) {
+                (Some(current), Some(new)) => json!(current.min(new)),
+                _ => value.clone(),
+            };
+        }
+    }
+    (description, required, maximum_value)
+}
+
+proptest! {
+    #[test]
+    fn prop_resolution_matches_reference(specs in arb_hierarchy()) {
+        let schema = build_schema(&specs);
+        let resolver = InheritanceResolver::new(&schema);
+        for index in 0..specs.len() {
+            let class = class_name(index);
+            let resolved = resolver
+                .resolve_slot_usage(&class, "s")
+                .expect("slot is defined");
+            let (description, required, maximum_value) = reference_induced(&schema, &class);
+            prop_assert_eq!(json!(resolved.description), description);
+            prop_assert_eq!(json!(resolved.required), required);
+            prop_assert_eq!(resolved.maximum_value.unwrap_or(Value::Null), maximum_value);
+        }
+    }
+
+    #[test]
+    fn prop_precedence_lists_each_ancestor_once(specs in arb_hierarchy()) {
+        let schema = build_schema(&specs);
+        let resolver = InheritanceResolver::new(&schema);
+        for index in 0..specs.len() {
+            let class = class_name(index);
+            let precedence = resolver.class_precedence(&class);
+            prop_assert_eq!(&precedence[0], &class);
+            let unique: HashSet<&String> = precedence.iter().collect();
+            prop_assert_eq!(unique.len(), precedence.len());
+
+            // A C3 linearization, where one exists, ranks the same classes
+            if let Ok(chain) = get_inheritance_chain(&class, &schema) {
+                let linearized: HashSet<&String> = chain.iter().collect();
+                prop_assert_eq!(linearized, unique);
+            }
+        }
+    }
+
+    #[test]
+    fn prop_own_usage_wins(specs in arb_hierarchy()) {
+        let schema = build_schema(&specs);
+        let resolver = InheritanceResolver::new(&schema);
+        for (index, spec) in specs.iter().enumerate() {
+            let class = class_name(index);
+            let resolved = resolver
+                .resolve_slot_usage(&class, "s")
+                .expect("slot is defined");
+            if spec.description {
+                prop_assert_eq!(resolved.description, Some(format!("from {class}")));
+            }
+            if spec.required.is_some() {
+                prop_assert_eq!(resolved.required, spec.required);
+            }
+        }
+    }
+}
@@ -84,6 +84,7 @@ async fn test_enum_generation() {
                 text: "shipped".to_string(),
                 description: Some("Order has been shipped".to_string()),
                 meaning: None,
+                is_a: None,
             },
             PermissibleValue::Simple("delivered".to_string()),
             PermissibleValue::Simple("cancelled".to_string()),
@@ -107,6 +107,7 @@ async fn test_enum_json_schema() {
                 text: "shipped".to_string(),
                 description: Some("Order has been shipped".to_string()),
                 meaning: None,
+                is_a: None,
             },
             PermissibleValue::Simple("delivered".to_string()),
         ],
@@ -70,6 +70,7 @@ async fn test_enums_and_repeated_fields() {
                 text: "hr".to_string(),
                 description: Some("Human Resources".to_string()),
                 meaning: None,
+                is_a: None,
             },
         ],
         ..Default::default()
@@ -425,11 +425,13 @@ fn create_schema_with_enums() -> SchemaDefinition {
                 text: "active".to_string(),
                 description: Some("Active status".to_string()),
                 meaning: None,
+                is_a: None,
             },
             PermissibleValue::Complex {
                 text: "inactive".to_string(),
                 description: Some("Inactive status".to_string()),
                 meaning: None,
+                is_a: None,
             },
         ],
         ..Default::default()
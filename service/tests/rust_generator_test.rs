@@ -132,6 +132,7 @@ async fn test_enum_generation() {
                     text: "pending-review".to_string(),
                     description: Some("Awaiting review".to_string()),
                     meaning: None,
+                    is_a: None,
                 },
             ],
             ..Default::default()
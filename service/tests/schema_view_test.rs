@@ -274,3 +274,88 @@ fn test_schema_view_caching() {
     assert_eq!(induced1.name, induced2.name);
     assert_eq!(induced1.slots, induced2.slots);
 }
+
+#[test]
+fn test_materialize() {
+    let mut schema = create_test_schema();
+    schema.imports = vec!["linkml:types".to_string()];
+
+    let mut audit_slot = SlotDefinition::new("audit_field");
+    audit_slot.description = Some("Audit information".to_string());
+    audit_slot.pattern = Some("^[a-z]+$".to_string());
+    schema.slots.insert("audit_field".to_string(), audit_slot);
+    schema
+        .slots
+        .get_mut("extra_field")
+        .expect("extra_field slot")
+        .is_a = Some("audit_field".to_string());
+
+    let derived = schema
+        .classes
+        .get_mut("DerivedClass")
+        .expect("DerivedClass");
+    let mut usage = SlotDefinition::new("name");
+    usage.pattern = Some("^[A-Z]".to_string());
+    derived.slot_usage.insert("name".to_string(), usage);
+
+    let view = SchemaView::new(schema).expect("Failed to create SchemaView");
+    let materialized = view.materialize().expect("Failed to materialize schema");
+
+    assert!(materialized.imports.is_empty());
+    let derived = &materialized.classes["DerivedClass"];
+    let attributes: Vec<&String> = derived.attributes.keys().collect();
+    assert_eq!(attributes, ["extra_field", "id", "name"]);
+    // Usage of the class applies on top of the inherited slot
+    assert_eq!(
+        derived.attributes["name"].pattern.as_deref(),
+        Some("^[A-Z]")
+    );
+    assert_eq!(derived.attributes["name"].required, Some(true));
+    // Metaslots come from the parent slot unless the slot sets them
+    let extra = &derived.attributes["extra_field"];
+    assert_eq!(extra.pattern.as_deref(), Some("^[a-z]+$"));
+    assert_eq!(extra.range.as_deref(), Some("string"));
+
+    let mixed = &materialized.classes["MixedClass"];
+    assert!(mixed.attributes.contains_key("created_at"));
+    assert_eq!(
+        materialized.classes["BaseClass"].attributes["name"].pattern,
+        None
+    );
+}
+
+#[test]
+fn test_mro() {
+    let view = SchemaView::new(create_test_schema()).expect("Failed to create SchemaView");
+    assert_eq!(
+        view.mro("MixedClass").expect("MRO of MixedClass"),
+        ["MixedClass", "BaseClass", "TimestampMixin"]
+    );
+    assert!(view.mro("Missing").is_err());
+    assert!(
+        view.mro_diagnostics("MixedClass")
+            .expect("diagnostics of MixedClass")
+            .is_empty()
+    );
+
+    // BaseClass is listed before its descendant DerivedClass
+    let mut schema = create_test_schema();
+    schema
+        .classes
+        .get_mut("MixedClass")
+        .expect("MixedClass")
+        .mixins
+        .push("DerivedClass".to_string());
+    let view = SchemaView::new(schema).expect("Failed to create SchemaView");
+    assert!(view.mro("MixedClass").is_err());
+    let diagnostics = view
+        .mro_diagnostics("MixedClass")
+        .expect("diagnostics of MixedClass");
+    assert_eq!(diagnostics.len(), 1);
+    assert!(
+        diagnostics[0]
+            .suggestion
+            .as_deref()
+            .is_some_and(|suggestion| suggestion.contains("remove 'BaseClass'"))
+    );
+}
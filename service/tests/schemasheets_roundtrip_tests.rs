@@ -240,11 +240,13 @@ async fn test_roundtrip_complex_schema() {
                 text: "ACTIVE".to_string(),
                 description: Some("Active status".to_string()),
                 meaning: None,
+                is_a: None,
             },
             PermissibleValue::Complex {
                 text: "INACTIVE".to_string(),
                 description: Some("Inactive status".to_string()),
                 meaning: None,
+                is_a: None,
             },
         ],
         ..Default::default()
@@ -223,11 +223,13 @@ async fn test_validation_with_complex_permissible_values() {
                 text: "ACTIVE".to_string(),
                 description: Some("Active status".to_string()),
                 meaning: None,
+                is_a: None,
             },
             PermissibleValue::Complex {
                 text: "INACTIVE".to_string(),
                 description: Some("Inactive status".to_string()),
                 meaning: None,
+                is_a: None,
             },
         ],
         ..Default::default()
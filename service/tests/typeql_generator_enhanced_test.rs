@@ -205,6 +205,7 @@ async fn test_enum_generation() {
             text: "active".to_string(),
             description: Some("Currently active".to_string()),
             meaning: None,
+            is_a: None,
         });
     status_enum
         .permissible_values
@@ -212,6 +213,7 @@ async fn test_enum_generation() {
             text: "inactive".to_string(),
             description: Some("Inactive state".to_string()),
             meaning: None,
+            is_a: None,
         });
     schema.enums.insert("StatusEnum".to_string(), status_enum);
 
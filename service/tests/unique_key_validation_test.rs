@@ -1,8 +1,10 @@
 //! Integration tests for unique key validation
 
 use linkml_core::types::SchemaDefinition;
-use linkml_core::types::{ClassDefinition, Definition, SlotDefinition, UniqueKeyDefinition};
-use linkml_service::validator::{ValidationEngine, ValidationOptions};
+use linkml_core::types::{ClassDefinition, SlotDefinition, UniqueKeyDefinition};
+use linkml_service::validator::{
+    RuleSeverity, ValidationEngine, ValidationOptions, ValidationReport, ValidationRuleConfig,
+};
 use serde_json::json;
 /// Create a test schema with unique key constraints
 fn create_test_schema() -> SchemaDefinition {
@@ -48,17 +50,21 @@ fn create_test_schema() -> SchemaDefinition {
     ];
 
     // Email should be unique
-    let mut email_unique = UniqueKeyDefinition::default();
-    email_unique.unique_key_slots = vec!["email".to_string()];
-    email_unique.consider_nulls_inequal = Some(true);
+    let email_unique = UniqueKeyDefinition {
+        unique_key_slots: vec!["email".to_string()],
+        consider_nulls_inequal: Some(true),
+        ..Default::default()
+    };
     user_class
         .unique_keys
         .insert("unique_email".to_string(), email_unique);
 
     // Username should be unique
-    let mut username_unique = UniqueKeyDefinition::default();
-    username_unique.unique_key_slots = vec!["username".to_string()];
-    username_unique.consider_nulls_inequal = Some(true);
+    let username_unique = UniqueKeyDefinition {
+        unique_key_slots: vec!["username".to_string()],
+        consider_nulls_inequal: Some(true),
+        ..Default::default()
+    };
     user_class
         .unique_keys
         .insert("unique_username".to_string(), username_unique);
@@ -75,9 +81,11 @@ fn create_test_schema() -> SchemaDefinition {
     ];
 
     // Composite key: email + department must be unique
-    let mut composite_unique = UniqueKeyDefinition::default();
-    composite_unique.unique_key_slots = vec!["email".to_string(), "department".to_string()];
-    composite_unique.consider_nulls_inequal = Some(false); // Nulls are considered equal
+    let composite_unique = UniqueKeyDefinition {
+        unique_key_slots: vec!["email".to_string(), "department".to_string()],
+        consider_nulls_inequal: Some(false), // Nulls are considered equal
+        ..Default::default()
+    };
     employee_class
         .unique_keys
         .insert("unique_email_dept".to_string(), composite_unique);
@@ -325,8 +333,10 @@ async fn test_fail_fast_option() {
         }),
     ];
 
-    let mut options = ValidationOptions::default();
-    options.fail_fast = Some(true);
+    let options = ValidationOptions {
+        fail_fast: Some(true),
+        ..Default::default()
+    };
 
     let report = engine
         .validate_collection(&instances, "User", Some(options))
@@ -338,6 +348,49 @@ async fn test_fail_fast_option() {
     assert_eq!(errors.len(), 1); // Only first error due to fail_fast
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_fail_fast_after_rule_config() {
+    let schema = create_test_schema();
+    let mut engine = ValidationEngine::new(&schema).expect("Test operation failed");
+
+    let instances = vec![
+        json!({"id": "user1", "email": "a@example.com", "username": "a", "name": "A"}),
+        // Duplicate identifier, downgraded to a warning below
+        json!({"id": "user1", "email": "b@example.com", "username": "b", "name": "B"}),
+        // Missing name, the first error
+        json!({"id": "user3", "email": "c@example.com", "username": "c"}),
+        json!({"id": "user4", "email": "d@example.com", "username": "d"}),
+    ];
+    let options = |parallel| {
+        ValidationOptions {
+            fail_fast: Some(true),
+            parallel: Some(parallel),
+            explain: Some(true),
+            ..Default::default()
+        }
+        .with_rule_config(
+            ValidationRuleConfig::new().with_rule("UniqueKeyValidator", RuleSeverity::Warning),
+        )
+    };
+
+    for parallel in [false, true] {
+        let report = engine
+            .validate_collection(&instances, "User", Some(options(parallel)))
+            .await
+            .expect("Test operation failed");
+        assert!(!report.valid);
+        let duplicate = report
+            .warnings()
+            .find(|issue| issue.validator == "UniqueKeyValidator")
+            .expect("duplicate identifier reported as a warning");
+        assert!(duplicate.context.contains_key("original_severity"));
+        assert!(duplicate.explanation.is_some(), "explained");
+        let errors: Vec<_> = report.errors().collect();
+        assert_eq!(errors.len(), 1, "stops at the first remaining error");
+        assert!(errors[0].path.contains("[2]"), "{}", errors[0].path);
+    }
+}
+
 #[tokio::test]
 async fn test_reset_between_validations() {
     let schema = create_test_schema();
@@ -381,10 +434,10 @@ async fn test_large_collection_performance() {
     let mut instances = Vec::new();
     for i in 0..1000 {
         instances.push(json!({
-            "id": format!("user{}", i),
-            "email": format!("user{}@example.com", i),
-            "username": format!("user{}", i),
-            "name": format!("User {}", i)
+            "id": format!("user{i}"),
+            "email": format!("user{i}@example.com"),
+            "username": format!("user{i}"),
+            "name": format!("User {i}")
         }));
     }
 
@@ -410,7 +463,67 @@ async fn test_large_collection_performance() {
     // Performance assertion: should complete in reasonable time
     assert!(
         duration.as_millis() < 1000,
-        "Validation took too long: {:?}",
-        duration
+        "Validation took too long: {duration:?}"
     );
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_parallel_collection_matches_serial() {
+    let schema = create_test_schema();
+    let mut engine = ValidationEngine::new(&schema).expect("Test operation failed");
+
+    // Missing names and duplicate emails spread over many chunks
+    let instances: Vec<_> = (0..200)
+        .map(|i| {
+            let mut user = json!({
+                "id": format!("user{i}"),
+                "email": format!("user{}@example.com", i % 150),
+                "username": format!("user{i}"),
+                "name": format!("User {i}")
+            });
+            if i % 7 == 0 {
+                user.as_object_mut().expect("object").remove("name");
+            }
+            user
+        })
+        .collect();
+
+    let serial = engine
+        .validate_collection(&instances, "User", None)
+        .await
+        .expect("Test operation failed");
+    for threads in [1, 3, 8] {
+        let parallel = engine
+            .validate_collection_with_threads(&instances, "User", None, threads)
+            .await
+            .expect("Test operation failed");
+        assert_eq!(issues(&parallel), issues(&serial));
+        assert_eq!(parallel.stats.error_count, serial.stats.error_count);
+    }
+    assert!(serial.errors().any(|e| e.message.contains("unique_email")));
+
+    // Fail fast stops at the same issue with the parallel option
+    let fail_fast = |parallel| ValidationOptions {
+        fail_fast: Some(true),
+        parallel: Some(parallel),
+        ..Default::default()
+    };
+    let serial = engine
+        .validate_collection(&instances, "User", Some(fail_fast(false)))
+        .await
+        .expect("Test operation failed");
+    let parallel = engine
+        .validate_collection(&instances, "User", Some(fail_fast(true)))
+        .await
+        .expect("Test operation failed");
+    assert!(!parallel.valid);
+    assert_eq!(issues(&parallel), issues(&serial));
+}
+
+fn issues(report: &ValidationReport) -> Vec<(String, String)> {
+    report
+        .issues
+        .iter()
+        .map(|issue| (issue.path.clone(), issue.message.clone()))
+        .collect()
+}